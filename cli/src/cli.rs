@@ -480,6 +480,26 @@ impl CryptixCli {
                                         tprintln!(this, "{NOTIFY} {} {id}: {balance_strings}   {utxo_info}",style("balance".pad_to_width(8)).blue());
                                     }
 
+                                    this.term().refresh_prompt();
+                                },
+                                Events::TokenBalance {
+                                    balances,
+                                    id,
+                                } => {
+
+                                    if !this.is_mutted() || (this.is_mutted() && this.flags.get(Track::Balance)) {
+                                        let id = id.short();
+                                        if balances.is_empty() {
+                                            tprintln!(this, "{NOTIFY} {} {id}: {}",style("tokens".pad_to_width(8)).blue(),style("no token balances").dim());
+                                        }
+                                        for balance in balances.iter() {
+                                            let locked = if balance.locked_balance > 0 {
+                                                style(format!("({} locked)", balance.locked_balance)).dim().to_string()
+                                            } else { "".to_string() };
+                                            tprintln!(this, "{NOTIFY} {} {id}: {} {} {locked}",style("tokens".pad_to_width(8)).blue(),balance.balance,style(&balance.asset_id).dim());
+                                        }
+                                    }
+
                                     this.term().refresh_prompt();
                                 }
                             }
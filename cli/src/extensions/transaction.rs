@@ -115,7 +115,11 @@ impl TransactionExtension for TransactionRecord {
         };
 
         let transaction_type = transaction_data.kind();
-        let kind = transaction_type.style(&transaction_type.to_string());
+        let kind = if transaction_data.is_token() {
+            transaction_type.style(transaction_data.data_type())
+        } else {
+            transaction_type.style(&transaction_type.to_string())
+        };
 
         let maturity = current_daa_score.map(|score| self.maturity(score).to_string()).unwrap_or_default();
 
@@ -188,6 +192,18 @@ impl TransactionExtension for TransactionRecord {
                     }
                 }
             }
+            TransactionData::TokenIncoming { txid, transfers, .. }
+            | TransactionData::TokenOutgoing { txid, transfers, .. }
+            | TransactionData::TokenInternal { txid, transfers, .. } => {
+                lines.push(format!("{:>4}Transaction: {txid}  Token transfers: {}", "", transfers.len()));
+                for transfer in transfers {
+                    let amount = transaction_type.style_with_sign(transfer.amount.to_string().as_str(), history);
+                    let from = transfer.from_owner_id.as_deref().unwrap_or("-");
+                    let to = transfer.to_owner_id.as_deref().unwrap_or("-");
+                    lines.push(format!("{:>4}{amount} {}", "", style(&transfer.asset_id).blue()));
+                    lines.push(format!("{:>4}{}", "", style(format!("{from} → {to}")).dim()));
+                }
+            }
         }
 
         lines
@@ -3,7 +3,9 @@ use cryptix_bip32::secp256k1::XOnlyPublicKey;
 use cryptix_wallet_core::{
     account::{BIP32_ACCOUNT_KIND, KEYPAIR_ACCOUNT_KIND},
     message::{sign_message, verify_message, PersonalMessage},
+    messenger::{parse_messenger_public_key, send_message, sync_inbox, ConversationStore, MessageDirection, MessengerIdentity},
 };
+use workflow_core::time::unixtime_to_locale_string;
 
 use crate::imports::*;
 
@@ -17,7 +19,7 @@ impl Handler for Message {
     }
 
     fn help(&self, _ctx: &Arc<dyn Context>) -> &'static str {
-        "Sign a message or verify a message signature"
+        "Sign and verify messages, send and read messenger messages"
     }
 
     async fn handle(self: Arc<Self>, ctx: &Arc<dyn Context>, argv: Vec<String>, cmd: &str) -> cli::Result<()> {
@@ -55,6 +57,88 @@ impl Message {
 
                 self.verify(ctx, cryptix_address, signature, message).await?;
             }
+            "send" => {
+                if argv.len() < 2 || argv.len() > 3 {
+                    return self.display_help(ctx, argv).await;
+                }
+                let recipient = parse_messenger_public_key(argv[1].as_str())?;
+                let priority_fee_sompi = try_parse_optional_cryptix_as_sompi_i64(argv.get(2))?.unwrap_or(0);
+                let text = ctx.term().ask(false, "Message: ").await?;
+                if text.is_empty() {
+                    return Err(Error::custom("Message can not be empty"));
+                }
+
+                let account = ctx.wallet().account()?;
+                let (wallet_secret, payment_secret) = ctx.ask_wallet_secret(Some(&account)).await?;
+                let identity = MessengerIdentity::try_from_account(&account, wallet_secret.clone(), payment_secret.as_ref()).await?;
+                let store = self.conversation_store(&ctx).await?;
+                let abortable = Abortable::default();
+                let (summary, ids) = send_message(
+                    &account,
+                    &identity,
+                    &store,
+                    &recipient,
+                    text.as_str(),
+                    priority_fee_sompi.into(),
+                    wallet_secret,
+                    payment_secret,
+                    &abortable,
+                )
+                .await?;
+
+                tprintln!(ctx, "Send - {summary}");
+                tprintln!(ctx, "\nMessage sent, tx ids:");
+                for id in ids {
+                    tprintln!(ctx, "  {id}");
+                }
+                tprintln!(ctx);
+            }
+            "inbox" => {
+                let account = ctx.wallet().account()?;
+                let (wallet_secret, payment_secret) = ctx.ask_wallet_secret(Some(&account)).await?;
+                let identity = MessengerIdentity::try_from_account(&account, wallet_secret, payment_secret.as_ref()).await?;
+                let store = self.conversation_store(&ctx).await?;
+                let received = sync_inbox(&ctx.wallet().rpc_api(), &identity, &store).await?;
+
+                tprintln!(ctx, "Messenger public key: {}", identity.public_key().as_bytes().as_slice().to_hex());
+                tprintln!(ctx, "{received} new message(s)\n");
+                let conversations = store.conversations();
+                if conversations.is_empty() {
+                    tprintln!(ctx, "  (no conversations)");
+                }
+                for conversation in conversations {
+                    tprintln!(
+                        ctx,
+                        "  {}  {} message(s), {} unread, last {}",
+                        conversation.peer,
+                        conversation.messages,
+                        conversation.unread,
+                        unixtime_to_locale_string(conversation.last_timestamp)
+                    );
+                }
+                tprintln!(ctx);
+            }
+            "read" => {
+                if argv.len() != 2 {
+                    return self.display_help(ctx, argv).await;
+                }
+                let peer = parse_messenger_public_key(argv[1].as_str())?.as_bytes().as_slice().to_hex();
+                let store = self.conversation_store(&ctx).await?;
+                let messages = store.conversation(&peer);
+                if messages.is_empty() {
+                    tprintln!(ctx, "No messages with {peer}");
+                    return Ok(());
+                }
+                for message in messages {
+                    let direction = match message.direction {
+                        MessageDirection::Incoming => "<<",
+                        MessageDirection::Outgoing => ">>",
+                    };
+                    tprintln!(ctx, "{} {direction} {}", unixtime_to_locale_string(message.timestamp), message.text);
+                }
+                store.mark_read(&peer);
+                store.try_store().await?;
+            }
             v => {
                 tprintln!(ctx, "unknown command: '{v}'\r\n");
                 return self.display_help(ctx, argv).await;
@@ -72,6 +156,12 @@ impl Message {
                     "verify <cryptix_address> <signature>",
                     "Verify the signature against the message and cryptix_address. Prompts for message.",
                 ),
+                (
+                    "send <recipient_pubkey> [<priority fee>]",
+                    "Encrypt a message for the recipient messenger public key and send it with a 1 CPAY output to your change address. Prompts for message.",
+                ),
+                ("inbox", "Fetch new messages from the node message index and list conversations."),
+                ("read <peer_pubkey>", "Show the conversation with the peer messenger public key and mark it as read."),
             ],
             None,
         )?;
@@ -79,6 +169,13 @@ impl Message {
         Ok(())
     }
 
+    async fn conversation_store(&self, ctx: &Arc<CryptixCli>) -> Result<ConversationStore> {
+        let descriptor = ctx.wallet().descriptor().ok_or_else(|| Error::custom("Wallet is not open"))?;
+        let store = ConversationStore::try_new(&descriptor.filename)?;
+        store.try_load().await?;
+        Ok(store)
+    }
+
     async fn sign(self: Arc<Self>, ctx: Arc<CryptixCli>, cryptix_address: &str, message: &str) -> Result<()> {
         let cryptix_address = Address::try_from(cryptix_address)?;
         if cryptix_address.version != Version::PubKey {
@@ -29,6 +29,7 @@ pub mod ping;
 pub mod pskb;
 pub mod reload;
 pub mod rpc;
+pub mod script;
 pub mod select;
 pub mod send;
 pub mod server;
@@ -81,6 +82,7 @@ pub fn register_handlers(cli: &Arc<CryptixCli>) -> Result<()> {
             ping,
             pskb,
             reload,
+            script,
             select,
             send,
             server,
@@ -106,6 +106,17 @@ impl Monitor {
 
         ctx.list().await?;
 
+        if wallet.is_connected() {
+            // nodes without the fast rail return no HFA metrics; only show the rail when it is enabled
+            let request = GetMetricsRequest { hfa_metrics: true, ..Default::default() };
+            if let Ok(response) = wallet.rpc_api().get_metrics_call(None, request).await {
+                if let Some(hfa) = response.hfa_metrics.filter(|hfa| hfa.enabled) {
+                    tprintln!(ctx);
+                    tprintln!(ctx, "{} {}", style("fast".pad_to_width(8)).blue(), Self::format_hfa_metrics(&hfa));
+                }
+            }
+        }
+
         let events = events.lock().unwrap();
         events.iter().for_each(|event| match event.deref() {
             Events::DaaScoreChange { .. } => {}
@@ -130,4 +141,24 @@ impl Monitor {
 
         Ok(())
     }
+
+    fn format_hfa_metrics(hfa: &HfaMetrics) -> String {
+        let mode = match hfa.mode {
+            _ if hfa.operator_paused => style("paused by operator").red(),
+            RpcHfaMode::Normal => style("normal").green(),
+            RpcHfaMode::Degraded => style("degraded").yellow(),
+            RpcHfaMode::Paused => style("paused").red(),
+        };
+        format!(
+            "{mode}  pending: {}  locks: {}  confirmed: {}  rejected: {}  conflicts: {}  p95: {:.0} ms  arbiter queue: {}  mode changes: {}",
+            hfa.pending_intents.separated_string(),
+            hfa.active_locks.separated_string(),
+            hfa.fast_confirmed_count.separated_string(),
+            hfa.rejected_count.separated_string(),
+            hfa.lock_conflict_count.separated_string(),
+            hfa.confirm_latency_ms_p95,
+            hfa.fast_arbiter_queue_depth.separated_string(),
+            hfa.mode_transition_count.separated_string(),
+        )
+    }
 }
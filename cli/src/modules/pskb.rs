@@ -1,9 +1,11 @@
 #![allow(unused_imports)]
 
 use crate::imports::*;
+use crate::modules::token::Token;
 use cryptix_addresses::Prefix;
 use cryptix_consensus_core::tx::{TransactionOutpoint, UtxoEntry};
 use cryptix_wallet_core::account::pskb::finalize_pskt_one_or_more_sig_and_redeem_script;
+use cryptix_wallet_core::tx::TOKEN_CARRIER_OUTPUT_SOMPI;
 use cryptix_wallet_pskt::{
     prelude::{lock_script_sig_templating, script_sig_to_address, unlock_utxos_as_pskb, Bundle, Signer, PSKT},
     pskt::Inner,
@@ -166,6 +168,75 @@ impl Pskb {
                     }
                 }
             }
+            "token" => {
+                if argv.len() < 4 || argv.len() > 5 {
+                    return self.display_help(ctx, argv).await;
+                }
+                let subcommand = argv.remove(0);
+                let account = ctx.wallet().account()?;
+                let rpc = ctx.wallet().rpc_api().clone();
+                let asset_id = argv.remove(0);
+                let sender_address =
+                    if let Some(sender) = argv.get(2) { Address::try_from(sender.as_str())? } else { account.receive_address()? };
+
+                let (payload, destination, vault_entry) = match subcommand.as_str() {
+                    "send" | "mint" => {
+                        let recipient_address = Address::try_from(argv.remove(0).as_str())?;
+                        let amount = Token::parse_positive_u128(argv.remove(0).as_str(), "amountRaw")?;
+                        let (payload, _) = if subcommand == "send" {
+                            Token::prepare_transfer_payload(&rpc, asset_id.as_str(), &recipient_address, amount, &sender_address)
+                                .await?
+                        } else {
+                            Token::prepare_mint_payload(&rpc, asset_id.as_str(), &recipient_address, amount, &sender_address).await?
+                        };
+                        let destination =
+                            PaymentDestination::from(PaymentOutputs::from((sender_address.clone(), TOKEN_CARRIER_OUTPUT_SOMPI)));
+                        (payload, destination, None)
+                    }
+                    "buy-liquidity" => {
+                        let cpay_budget_sompi = Token::parse_positive_u64(argv.remove(0).as_str(), "cpayInSompi")?;
+                        let min_token_out = Token::parse_positive_u128(argv.remove(0).as_str(), "minTokenOutRaw")?;
+                        let trade =
+                            Token::prepare_buy_liquidity(&rpc, asset_id.as_str(), cpay_budget_sompi, min_token_out, &sender_address)
+                                .await?;
+                        (trade.payload, trade.destination, Some(trade.vault_entry))
+                    }
+                    "sell-liquidity" => {
+                        let token_in = Token::parse_positive_u128(argv.remove(0).as_str(), "tokenInRaw")?;
+                        let min_cpay_out_sompi = Token::parse_positive_u64(argv.remove(0).as_str(), "minCpayOutSompi")?;
+                        let trade =
+                            Token::prepare_sell_liquidity(&rpc, asset_id.as_str(), token_in, min_cpay_out_sompi, &sender_address)
+                                .await?;
+                        (trade.payload, trade.destination, Some(trade.vault_entry))
+                    }
+                    v => {
+                        terrorln!(ctx, "unknown command: '{v}'\r\n");
+                        return self.display_help(ctx, argv).await;
+                    }
+                };
+
+                let (wallet_secret, payment_secret) = ctx.ask_wallet_secret(None).await?;
+                let _ = ctx.notifier().show(Notification::Processing).await;
+                let abortable = Abortable::default();
+
+                let bundle = account
+                    .pskb_from_token_generator(
+                        destination,
+                        Fees::SenderPays(0),
+                        payload,
+                        sender_address,
+                        vault_entry,
+                        wallet_secret,
+                        payment_secret,
+                        &abortable,
+                    )
+                    .await?;
+
+                match bundle.serialize() {
+                    Ok(encoded) => tprintln!(ctx, "{encoded}"),
+                    Err(e) => return Err(e.into()),
+                }
+            }
             "sign" => {
                 if argv.len() != 1 {
                     return self.display_help(ctx, argv).await;
@@ -257,6 +328,10 @@ impl Pskb {
                 ("pskb script sign <pskb>", "Sign all PSKB's P2SH locked inputs"),
                 ("pskb script sign <pskb>", "Sign all PSKB's P2SH locked inputs"),
                 ("pskb script address <pskb>", "Prints P2SH address"),
+                ("pskb token send <assetId> <toAddress> <amountRaw> [senderAddress]", "Create a PSKB with a token transfer authorized by the sender address"),
+                ("pskb token mint <assetId> <toAddress> <amountRaw> [senderAddress]", "Create a PSKB with a token mint authorized by the sender address"),
+                ("pskb token buy-liquidity <assetId> <cpayInSompi> <minTokenOutRaw> [senderAddress]", "Create a PSKB buying tokens from the asset's liquidity pool"),
+                ("pskb token sell-liquidity <assetId> <tokenInRaw> <minCpayOutSompi> [senderAddress]", "Create a PSKB selling tokens to the asset's liquidity pool"),
             ],
             None,
         )?;
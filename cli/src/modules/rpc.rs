@@ -205,7 +205,16 @@ impl Rpc {
                 tprintln!(ctx, "ok");
             }
             RpcApiOps::GetMetrics => {
-                let result = rpc.get_metrics(true, true, true, true, true, true).await?;
+                let request = GetMetricsRequest {
+                    process_metrics: true,
+                    connection_metrics: true,
+                    bandwidth_metrics: true,
+                    consensus_metrics: true,
+                    storage_metrics: true,
+                    custom_metrics: true,
+                    hfa_metrics: true,
+                };
+                let result = rpc.get_metrics_call(None, request).await?;
                 self.println(&ctx, result);
             }
             RpcApiOps::GetSystemInfo => {
@@ -484,6 +493,93 @@ impl Rpc {
                 let result = rpc.get_token_asset_call(None, GetTokenAssetRequest { asset_id, at_block_hash }).await?;
                 self.println(&ctx, result);
             }
+            RpcApiOps::GetTokenBalanceProof => {
+                if argv.len() < 2 {
+                    return Err(Error::custom(
+                        "Usage: rpc get-token-balance-proof <assetId> <ownerId> [atBlockHash] [includeConsensusAnchor]",
+                    ));
+                }
+                let asset_id = argv.remove(0);
+                let owner_id = argv.remove(0);
+                let include_consensus_anchor = match argv.last().map(|value| value.parse::<bool>()) {
+                    Some(Ok(include)) => {
+                        argv.pop();
+                        include
+                    }
+                    _ => false,
+                };
+                let at_block_hash = Self::parse_optional_hash(argv.first())?;
+                let result = rpc
+                    .get_token_balance_proof_call(
+                        None,
+                        GetTokenBalanceProofRequest { asset_id, owner_id, at_block_hash, include_consensus_anchor },
+                    )
+                    .await?;
+                self.println(&ctx, result);
+            }
+            RpcApiOps::GetTokenAssetProof => {
+                if argv.is_empty() {
+                    return Err(Error::custom("Usage: rpc get-token-asset-proof <assetId> [atBlockHash]"));
+                }
+                let asset_id = argv.remove(0);
+                let at_block_hash = Self::parse_optional_hash(argv.first())?;
+                let result = rpc.get_token_asset_proof_call(None, GetTokenAssetProofRequest { asset_id, at_block_hash }).await?;
+                self.println(&ctx, result);
+            }
+            RpcApiOps::GetTokenAssetHistory => {
+                if argv.is_empty() {
+                    return Err(Error::custom("Usage: rpc get-token-asset-history <assetId> [atBlockHash]"));
+                }
+                let asset_id = argv.remove(0);
+                let at_block_hash = Self::parse_optional_hash(argv.first())?;
+                let result = rpc.get_token_asset_history_call(None, GetTokenAssetHistoryRequest { asset_id, at_block_hash }).await?;
+                self.println(&ctx, result);
+            }
+            RpcApiOps::GetPairPool => {
+                if argv.is_empty() {
+                    return Err(Error::custom("Usage: rpc get-pair-pool <poolId> [atBlockHash]"));
+                }
+                let pool_id = argv.remove(0);
+                let at_block_hash = Self::parse_optional_hash(argv.first())?;
+                let result = rpc.get_pair_pool_call(None, GetPairPoolRequest { pool_id, at_block_hash }).await?;
+                self.println(&ctx, result);
+            }
+            RpcApiOps::GetPairQuote => {
+                if argv.len() < 4 {
+                    return Err(Error::custom("Usage: rpc get-pair-quote <poolId> <assetInId> <in|out> <amount> [atBlockHash]"));
+                }
+                let pool_id = argv.remove(0);
+                let asset_in_id = argv.remove(0);
+                let exact_out = match argv.remove(0).as_str() {
+                    "in" => false,
+                    "out" => true,
+                    other => return Err(Error::custom(format!("invalid quote mode `{other}` (expected in|out)"))),
+                };
+                let amount = argv.remove(0);
+                let at_block_hash = Self::parse_optional_hash(argv.first())?;
+                let result = rpc
+                    .get_pair_quote_call(None, GetPairQuoteRequest { pool_id, asset_in_id, exact_out, amount, at_block_hash })
+                    .await?;
+                self.println(&ctx, result);
+            }
+            RpcApiOps::GetLiquidityCandles => {
+                if argv.len() < 4 {
+                    return Err(Error::custom(
+                        "Usage: rpc get-liquidity-candles <assetId> <intervalDaaScore> <fromDaaScore> <toDaaScore>",
+                    ));
+                }
+                let asset_id = argv.remove(0);
+                let interval_daa_score = argv.remove(0).parse::<u64>()?;
+                let from_daa_score = argv.remove(0).parse::<u64>()?;
+                let to_daa_score = argv.remove(0).parse::<u64>()?;
+                let result = rpc
+                    .get_liquidity_candles_call(
+                        None,
+                        GetLiquidityCandlesRequest { asset_id, interval_daa_score, from_daa_score, to_daa_score },
+                    )
+                    .await?;
+                self.println(&ctx, result);
+            }
             RpcApiOps::GetTokenOpStatus => {
                 if argv.is_empty() {
                     return Err(Error::custom("Usage: rpc get-token-op-status <txid> [atBlockHash]"));
@@ -519,10 +615,33 @@ impl Rpc {
                 self.println(&ctx, result);
             }
             RpcApiOps::GetTokenEvents => {
+                // trailing `key=value` arguments narrow the query, e.g. `owner=<ownerId> op=1,10 from=<daa>`
+                let (filters, argv): (Vec<String>, Vec<String>) = argv.into_iter().partition(|arg| arg.contains('='));
                 let after_sequence = argv.first().and_then(|value| value.parse::<u64>().ok()).unwrap_or(0);
                 let limit = argv.get(1).and_then(|value| value.parse::<u32>().ok()).unwrap_or(100);
                 let at_block_hash = Self::parse_optional_hash(argv.get(2))?;
-                let result = rpc.get_token_events_call(None, GetTokenEventsRequest { after_sequence, limit, at_block_hash }).await?;
+                let mut request = GetTokenEventsRequest { after_sequence, limit, at_block_hash, ..Default::default() };
+                for filter in filters {
+                    let (key, value) = filter.split_once('=').unwrap();
+                    match key {
+                        "owner" => request.owner_id = Some(value.to_string()),
+                        "asset" => request.asset_id = Some(value.to_string()),
+                        "op" => {
+                            for op in value.split(',') {
+                                let op_type = op.trim().parse::<u32>().map_err(|_| Error::custom(format!("invalid op type `{op}`")))?;
+                                request.op_types.push(op_type);
+                            }
+                        }
+                        "from" => request.from_daa_score = Some(value.parse::<u64>().map_err(|_| Error::custom("invalid `from` DAA score"))?),
+                        "to" => request.to_daa_score = Some(value.parse::<u64>().map_err(|_| Error::custom("invalid `to` DAA score"))?),
+                        _ => {
+                            return Err(Error::custom(
+                                "Usage: rpc get-token-events [afterSequence] [limit] [atBlockHash] [owner=<ownerId>] [asset=<assetId>] [op=<op,...>] [from=<daa>] [to=<daa>]",
+                            ))
+                        }
+                    }
+                }
+                let result = rpc.get_token_events_call(None, request).await?;
                 self.println(&ctx, result);
             }
             RpcApiOps::GetTokenAssets => {
@@ -582,6 +701,15 @@ impl Rpc {
                 let result = rpc.export_token_snapshot_call(None, ExportTokenSnapshotRequest { path }).await?;
                 self.println(&ctx, result);
             }
+            RpcApiOps::ExportTokenAnalytics => {
+                if argv.is_empty() {
+                    return Err(Error::custom("Usage: rpc export-token-analytics <directory> [atBlockHash]"));
+                }
+                let path = argv.remove(0);
+                let at_block_hash = Self::parse_optional_hash(argv.first())?;
+                let result = rpc.export_token_analytics_call(None, ExportTokenAnalyticsRequest { path, at_block_hash }).await?;
+                self.println(&ctx, result);
+            }
             RpcApiOps::ImportTokenSnapshot => {
                 if argv.is_empty() {
                     return Err(Error::custom("Usage: rpc import-token-snapshot <path>"));
@@ -636,6 +764,72 @@ impl Rpc {
                 let result = rpc.get_sc_snapshot_head_call(None, GetScSnapshotHeadRequest {}).await?;
                 self.println(&ctx, result);
             }
+            RpcApiOps::GetHfaConfig => {
+                let result = rpc.get_hfa_config_call(None, GetHfaConfigRequest {}).await?;
+                self.println(&ctx, result);
+            }
+            RpcApiOps::SetHfaConfig => {
+                // `key=value` arguments named after the config fields, e.g. `fast_ttl_ms=4000 paused=true`
+                if argv.is_empty() {
+                    return Err(Error::custom("Usage: rpc set-hfa-config <field>=<value> ..."));
+                }
+                let mut request = SetHfaConfigRequest::default();
+                for arg in argv {
+                    let (key, value) =
+                        arg.split_once('=').ok_or_else(|| Error::custom(format!("expected `<field>=<value>`, got `{arg}`")))?;
+                    let invalid = || Error::custom(format!("invalid value `{value}` for `{key}`"));
+                    match key {
+                        "cpu_low_water_ratio" => request.cpu_low_water_ratio = Some(value.parse::<f64>().map_err(|_| invalid())?),
+                        "min_feerate_floor" => request.min_feerate_floor = Some(value.parse::<f64>().map_err(|_| invalid())?),
+                        "paused" => request.paused = Some(value.parse::<bool>().map_err(|_| invalid())?),
+                        "fast_ttl_ms" => request.fast_ttl_ms = Some(value.parse::<u64>().map_err(|_| invalid())?),
+                        "clock_drift_max_ms" => request.clock_drift_max_ms = Some(value.parse::<u64>().map_err(|_| invalid())?),
+                        "terminal_retention_ms" => request.terminal_retention_ms = Some(value.parse::<u64>().map_err(|_| invalid())?),
+                        "max_pending_intents" => request.max_pending_intents = Some(value.parse::<u64>().map_err(|_| invalid())?),
+                        "max_locks" => request.max_locks = Some(value.parse::<u64>().map_err(|_| invalid())?),
+                        "max_terminal_entries" => request.max_terminal_entries = Some(value.parse::<u64>().map_err(|_| invalid())?),
+                        "max_terminal_bytes" => request.max_terminal_bytes = Some(value.parse::<u64>().map_err(|_| invalid())?),
+                        "max_inputs_per_intent" => request.max_inputs_per_intent = Some(value.parse::<u64>().map_err(|_| invalid())?),
+                        "max_outputs_per_intent" => {
+                            request.max_outputs_per_intent = Some(value.parse::<u64>().map_err(|_| invalid())?)
+                        }
+                        "seen_cache_ttl_ms" => request.seen_cache_ttl_ms = Some(value.parse::<u64>().map_err(|_| invalid())?),
+                        "microblock_interval_ms_normal" => {
+                            request.microblock_interval_ms_normal = Some(value.parse::<u64>().map_err(|_| invalid())?)
+                        }
+                        "microblock_interval_ms_degraded" => {
+                            request.microblock_interval_ms_degraded = Some(value.parse::<u64>().map_err(|_| invalid())?)
+                        }
+                        "revalidation_budget_normal" => {
+                            request.revalidation_budget_normal = Some(value.parse::<u64>().map_err(|_| invalid())?)
+                        }
+                        "revalidation_budget_degraded" => {
+                            request.revalidation_budget_degraded = Some(value.parse::<u64>().map_err(|_| invalid())?)
+                        }
+                        "normal_arbiter_queue_capacity" => {
+                            request.normal_arbiter_queue_capacity = Some(value.parse::<u64>().map_err(|_| invalid())?)
+                        }
+                        "fast_arbiter_queue_capacity" => {
+                            request.fast_arbiter_queue_capacity = Some(value.parse::<u64>().map_err(|_| invalid())?)
+                        }
+                        _ => return Err(Error::custom(format!("unknown HFA config field `{key}`"))),
+                    }
+                }
+                let result = rpc.set_hfa_config_call(None, request).await?;
+                self.println(&ctx, result);
+            }
+            RpcApiOps::GetMessagesByRecipientTag => {
+                if argv.is_empty() {
+                    return Err(Error::custom("Usage: rpc get-messages-by-recipient-tag <recipientTag> [cursor] [limit]"));
+                }
+                let recipient_tag = argv.remove(0);
+                let cursor =
+                    argv.first().map(|value| value.parse::<u64>()).transpose().map_err(|err| Error::custom(err.to_string()))?;
+                let limit = argv.get(1).map(|value| value.parse::<u32>()).transpose().map_err(|err| Error::custom(err.to_string()))?;
+                let request = GetMessagesByRecipientTagRequest::new(recipient_tag, None, cursor.unwrap_or(0), limit.unwrap_or(0));
+                let result = rpc.get_messages_by_recipient_tag_call(None, request).await?;
+                self.println(&ctx, result);
+            }
             _ => {
                 tprintln!(ctx, "rpc method exists but is not supported by the cli: '{op_str}'\r\n");
                 return Ok(());
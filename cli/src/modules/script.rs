@@ -0,0 +1,185 @@
+use crate::imports::*;
+use cryptix_consensus_core::{
+    config::params::Params,
+    tx::{PopulatedTransaction, Transaction, TransactionId, UtxoEntry},
+};
+use cryptix_rpc_core::RpcTransactionLookupRequest;
+use cryptix_txscript::{
+    debug::{DebugScript, ScriptTraceStep},
+    EngineFlags,
+};
+use std::collections::HashSet;
+
+#[derive(Default)]
+pub struct Script;
+
+#[async_trait]
+impl Handler for Script {
+    fn verb(&self, _ctx: &Arc<dyn Context>) -> Option<&'static str> {
+        Some("script")
+    }
+
+    fn help(&self, _ctx: &Arc<dyn Context>) -> &'static str {
+        "Trace the script execution of transaction inputs"
+    }
+
+    async fn handle(self: Arc<Self>, ctx: &Arc<dyn Context>, argv: Vec<String>, cmd: &str) -> cli::Result<()> {
+        let ctx = ctx.clone().downcast_arc::<CryptixCli>()?;
+        self.main(ctx, argv, cmd).await.map_err(|e| e.into())
+    }
+}
+
+impl Script {
+    async fn main(self: Arc<Self>, ctx: Arc<CryptixCli>, argv: Vec<String>, _cmd: &str) -> Result<()> {
+        if argv.is_empty() {
+            return self.display_help(ctx, argv).await;
+        }
+
+        match argv.first().unwrap().as_str() {
+            "debug" => {
+                if argv.len() < 3 || argv.len() > 4 || argv.get(3).is_some_and(|mode| mode != "step") {
+                    return self.display_help(ctx, argv).await;
+                }
+                let transaction_id = TransactionId::from_hex(argv[1].as_str())?;
+                let input_index = argv[2].parse::<usize>().map_err(|_| Error::custom("Invalid input index"))?;
+                self.debug(ctx, transaction_id, input_index, argv.len() == 4).await?;
+            }
+            v => {
+                tprintln!(ctx, "unknown command: '{v}'\r\n");
+                return self.display_help(ctx, argv).await;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn display_help(self: Arc<Self>, ctx: Arc<CryptixCli>, _argv: Vec<String>) -> Result<()> {
+        ctx.term().help(
+            &[(
+                "debug <txid> <input> [step]",
+                "Fetch the transaction and its UTXO entries from the node and trace the scripts of the input. \
+                 With 'step', waits for Enter after every opcode.",
+            )],
+            None,
+        )?;
+
+        Ok(())
+    }
+
+    async fn debug(
+        self: Arc<Self>,
+        ctx: Arc<CryptixCli>,
+        transaction_id: TransactionId,
+        input_index: usize,
+        step: bool,
+    ) -> Result<()> {
+        let rpc = ctx.wallet().rpc_api().clone();
+
+        let (transaction, block_daa_score) = fetch_transactions(&rpc, vec![transaction_id])
+            .await?
+            .remove(&transaction_id)
+            .ok_or_else(|| Error::custom(format!("Transaction {transaction_id} was not found by the node")))?;
+        if input_index >= transaction.inputs.len() {
+            return Err(Error::custom(format!("Transaction {transaction_id} has {} inputs", transaction.inputs.len())));
+        }
+
+        let previous_ids = transaction.inputs.iter().map(|input| input.previous_outpoint.transaction_id).collect::<HashSet<_>>();
+        let previous_transactions = fetch_transactions(&rpc, previous_ids).await?;
+        let entries = transaction
+            .inputs
+            .iter()
+            .enumerate()
+            .map(|(i, input)| {
+                let outpoint = input.previous_outpoint;
+                previous_transactions
+                    .get(&outpoint.transaction_id)
+                    .and_then(|(previous, daa_score)| {
+                        previous.outputs.get(outpoint.index as usize).map(|output| {
+                            UtxoEntry::new(
+                                output.value,
+                                output.script_public_key.clone(),
+                                daa_score.unwrap_or_default(),
+                                previous.is_coinbase(),
+                            )
+                        })
+                    })
+                    .ok_or_else(|| Error::custom(format!("Unable to fetch the UTXO entry of input #{i} {outpoint} from the node")))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Scripts run under the consensus rules active at the accepting block, or at the virtual for unaccepted transactions
+        let pov_daa_score = match block_daa_score {
+            Some(daa_score) => daa_score,
+            None => rpc.get_block_dag_info().await?.virtual_daa_score,
+        };
+        let params = Params::from(ctx.wallet().network_id()?);
+        let flags = EngineFlags { covenants_enabled: pov_daa_score >= params.covenants_activation_daa_score };
+
+        let populated = PopulatedTransaction::new(&transaction, entries);
+        let debug_result = DebugScript::new(&populated, input_index, flags).run();
+
+        tprintln!(
+            ctx,
+            "Input #{input_index} of transaction {transaction_id}, {} opcode(s), covenants {}\n",
+            debug_result.steps.len(),
+            if flags.covenants_enabled { "enabled" } else { "disabled" }
+        );
+        for trace_step in debug_result.steps.iter() {
+            print_step(&ctx, trace_step);
+            if step && trace_step.error.is_none() {
+                let answer = ctx.term().ask(false, "Press Enter for the next opcode, 'q' to stop: ").await?;
+                if answer.trim() == "q" {
+                    return Ok(());
+                }
+            }
+        }
+
+        match debug_result.result {
+            Ok(()) => tprintln!(ctx, "\nScript execution succeeded"),
+            Err(err) => terrorln!(ctx, "\nScript execution failed: {err}"),
+        }
+
+        Ok(())
+    }
+}
+
+async fn fetch_transactions(
+    rpc: &Arc<DynRpcApi>,
+    transaction_ids: impl IntoIterator<Item = TransactionId>,
+) -> Result<HashMap<TransactionId, (Transaction, Option<u64>)>> {
+    let entries = transaction_ids
+        .into_iter()
+        .map(|transaction_id| RpcTransactionLookupRequest { transaction_id, block_daa_score: None })
+        .collect::<Vec<_>>();
+    let response = rpc.get_transactions_by_ids(entries).await?;
+
+    let mut transactions = HashMap::new();
+    for entry in response.entries.into_iter() {
+        if let Some(transaction) = entry.transaction {
+            transactions.insert(entry.transaction_id, (Transaction::try_from(transaction)?, entry.block_daa_score));
+        }
+    }
+    Ok(transactions)
+}
+
+fn print_step(ctx: &Arc<CryptixCli>, step: &ScriptTraceStep) {
+    let data = if step.data.is_empty() { String::new() } else { step.data.as_slice().to_hex() };
+    let skipped = if step.executed { "" } else { " (skipped)" };
+    tprintln!(ctx, "[{} #{}] {} {data}{skipped}", step.script, step.index, step.opcode_name());
+    tprintln!(ctx, "    data stack: {}", format_stack(&step.data_stack));
+    if !step.alt_stack.is_empty() {
+        tprintln!(ctx, "    alt stack:  {}", format_stack(&step.alt_stack));
+    }
+    if !step.cond_stack.is_empty() {
+        tprintln!(ctx, "    cond stack: {:?}", step.cond_stack);
+    }
+    if let Some(err) = &step.error {
+        terrorln!(ctx, "    error: {err}");
+    }
+}
+
+fn format_stack(stack: &[Vec<u8>]) -> String {
+    let items =
+        stack.iter().map(|item| if item.is_empty() { "(empty)".to_string() } else { item.as_slice().to_hex() }).collect::<Vec<_>>();
+    format!("[{}]", items.join(", "))
+}
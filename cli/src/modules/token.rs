@@ -1,76 +1,41 @@
 use crate::imports::*;
 use cryptix_consensus_client::{TransactionOutpoint as ClientTransactionOutpoint, UtxoEntry, UtxoEntryReference};
 use cryptix_consensus_core::{
-    constants::{MAX_SOMPI, SOMPI_PER_CRYPTIX},
+    constants::MAX_SOMPI,
     tx::{ScriptPublicKey, TransactionId},
 };
 use cryptix_rpc_core::{GetLiquidityPoolStateRequest, GetLiquidityQuoteRequest, RpcLiquidityPoolState};
 use cryptix_wallet_core::account::GenerationNotifier;
-use cryptix_wallet_core::tx::{Generator, GeneratorSettings, GeneratorSummary, ScriptPaymentOutput, ScriptPaymentOutputs};
+use cryptix_wallet_core::tx::{
+    Generator, GeneratorSettings, GeneratorSummary, ScriptPaymentOutput, ScriptPaymentOutputs, TokenLiquidityCurve, TokenOperation,
+    TokenOperationPayload, TokenRecipient, TokenSupplyMode, CAT_DEFAULT_LIQUIDITY_CURVE_MODE,
+    CAT_INDIVIDUAL_MAX_VIRTUAL_CPAY_RESERVES_SOMPI, CAT_INDIVIDUAL_MAX_VIRTUAL_TOKEN_MULTIPLIER_BPS,
+    CAT_INDIVIDUAL_MIN_VIRTUAL_CPAY_RESERVES_SOMPI, CAT_INDIVIDUAL_MIN_VIRTUAL_TOKEN_MULTIPLIER_BPS,
+    CAT_INDIVIDUAL_VIRTUAL_CPAY_STEP_SOMPI, CAT_INDIVIDUAL_VIRTUAL_TOKEN_MULTIPLIER_STEP_BPS, CAT_LIQUIDITY_CURVE_MODE_AGGRESSIVE,
+    CAT_LIQUIDITY_CURVE_MODE_BASIC, CAT_LIQUIDITY_CURVE_MODE_INDIVIDUAL, CAT_LIQUIDITY_TOKEN_DECIMALS,
+    CAT_MAX_BATCH_TRANSFER_RECIPIENTS, CAT_MAX_DECIMALS, CAT_MAX_LIQUIDITY_FEE_BPS, CAT_MAX_LIQUIDITY_RECIPIENTS,
+    CAT_MAX_LIQUIDITY_SUPPLY_RAW, CAT_MAX_METADATA_LEN, CAT_MAX_NAME_LEN, CAT_MAX_PLATFORM_TAG_LEN, CAT_MAX_SYMBOL_LEN,
+    CAT_MIN_LIQUIDITY_FEE_BPS, CAT_MIN_LIQUIDITY_SEED_RESERVE_SOMPI, CAT_MIN_LIQUIDITY_SUPPLY_RAW, TOKEN_CARRIER_OUTPUT_SOMPI,
+};
 use std::collections::{BTreeMap, HashMap, HashSet};
 use workflow_core::time::Duration;
 
-const CAT_MAGIC: [u8; 3] = *b"CAT";
-const CAT_VERSION: u8 = 1;
-const CAT_CURRENT_TOKEN_VERSION: u8 = 1;
-const CAT_CURRENT_LIQUIDITY_CURVE_VERSION: u8 = 1;
-const CAT_LIQUIDITY_CURVE_MODE_BASIC: u8 = 0;
-const CAT_LIQUIDITY_CURVE_MODE_AGGRESSIVE: u8 = 1;
-const CAT_LIQUIDITY_CURVE_MODE_INDIVIDUAL: u8 = 2;
-const CAT_DEFAULT_LIQUIDITY_CURVE_MODE: u8 = CAT_LIQUIDITY_CURVE_MODE_BASIC;
-const CAT_FLAGS: u8 = 0;
-
-const CAT_OP_CREATE_ASSET: u8 = 0;
-const CAT_OP_TRANSFER: u8 = 1;
-const CAT_OP_MINT: u8 = 2;
-const CAT_OP_BURN: u8 = 3;
-const CAT_OP_CREATE_ASSET_WITH_MINT: u8 = 4;
-const CAT_OP_CREATE_LIQUIDITY_ASSET: u8 = 5;
-const CAT_OP_BUY_LIQUIDITY_EXACT_IN: u8 = 6;
-const CAT_OP_SELL_LIQUIDITY_EXACT_IN: u8 = 7;
-const CAT_OP_CLAIM_LIQUIDITY_FEES: u8 = 8;
-
-const CAT_MAX_NAME_LEN: usize = 32;
-const CAT_MAX_SYMBOL_LEN: usize = 10;
-const CAT_MAX_METADATA_LEN: usize = 256;
-const CAT_MAX_PLATFORM_TAG_LEN: usize = 50;
-const CAT_MAX_DECIMALS: u8 = 18;
-const CAT_MAX_LIQUIDITY_RECIPIENTS: usize = 2;
-const CAT_MIN_LIQUIDITY_FEE_BPS: u16 = 10;
-const CAT_MAX_LIQUIDITY_FEE_BPS: u16 = 1000;
-const LIQUIDITY_TOKEN_DECIMALS: u8 = 0;
-const MIN_LIQUIDITY_TOKEN_SUPPLY_RAW: u128 = 100_000;
 const LIQUIDITY_TOKEN_SUPPLY_RAW: u128 = 1_000_000;
 const DEFAULT_LIQUIDITY_TOKEN_SUPPLY_RAW: u128 = LIQUIDITY_TOKEN_SUPPLY_RAW;
-const MAX_LIQUIDITY_TOKEN_SUPPLY_RAW: u128 = 10_000_000;
-const MIN_LIQUIDITY_SEED_RESERVE_SOMPI: u64 = SOMPI_PER_CRYPTIX;
 const MIN_LIQUIDITY_REAL_TOKEN_RESERVE_RAW: u128 = 1;
 const INITIAL_VIRTUAL_CPAY_RESERVES_SOMPI: u64 = 250_000_000_000_000;
 const AGGRESSIVE_INITIAL_VIRTUAL_CPAY_RESERVES_SOMPI: u64 = 200_000_000_000_000;
-const INDIVIDUAL_MIN_VIRTUAL_CPAY_RESERVES_SOMPI: u64 = 100_000_000_000_000;
-const INDIVIDUAL_MAX_VIRTUAL_CPAY_RESERVES_SOMPI: u64 = 800_000_000_000_000;
-const INDIVIDUAL_VIRTUAL_CPAY_STEP_SOMPI: u64 = 10_000_000_000_000;
-const INDIVIDUAL_MIN_VIRTUAL_TOKEN_MULTIPLIER_BPS: u16 = 10_100;
-const INDIVIDUAL_MAX_VIRTUAL_TOKEN_MULTIPLIER_BPS: u16 = 20_000;
-const INDIVIDUAL_VIRTUAL_TOKEN_MULTIPLIER_STEP_BPS: u16 = 100;
 const VIRTUAL_TOKEN_MULTIPLIER_BPS_DENOMINATOR: u16 = 10_000;
 
-const DEFAULT_AUTH_INPUT_INDEX: u16 = 0;
-const LIQUIDITY_AUTH_INPUT_INDEX: u16 = 1;
 const LIQUIDITY_QUOTE_SIDE_BUY: u32 = 0;
 const LIQUIDITY_QUOTE_SIDE_SELL: u32 = 1;
-const TOKEN_CARRIER_OUTPUT_SOMPI: u64 = 1_000;
+const LIQUIDITY_QUOTE_SIDE_BUY_EXACT_OUT: u32 = 2;
+const LIQUIDITY_QUOTE_SIDE_SELL_EXACT_OUT: u32 = 3;
 const TOKEN_OWNER_BALANCES_PAGE_LIMIT: u32 = 512;
 const TOKEN_MONITOR_DEFAULT_INTERVAL_SECS: u64 = 5;
 const LIQUIDITY_VAULT_SCRIPT_VERSION: u16 = 0;
 const LIQUIDITY_VAULT_SCRIPT: [u8; 7] = [0x04, b'C', b'L', b'V', b'1', 0x75, 0x51];
 
-#[derive(Clone)]
-struct LiquidityRecipient {
-    address_version: u8,
-    address_payload: Vec<u8>,
-}
-
 struct CreateLiquidityOptions {
     sender: Option<Address>,
     metadata: Vec<u8>,
@@ -83,6 +48,32 @@ struct CreateLiquidityOptions {
     individual_virtual_token_multiplier_bps: u16,
 }
 
+impl CreateLiquidityOptions {
+    fn curve(&self) -> TokenLiquidityCurve {
+        match self.curve_mode {
+            CAT_LIQUIDITY_CURVE_MODE_AGGRESSIVE => TokenLiquidityCurve::Aggressive,
+            CAT_LIQUIDITY_CURVE_MODE_INDIVIDUAL => TokenLiquidityCurve::Individual {
+                virtual_cpay_reserves_sompi: self.individual_virtual_cpay_reserves_sompi,
+                virtual_token_multiplier_bps: self.individual_virtual_token_multiplier_bps,
+            },
+            _ => TokenLiquidityCurve::Basic,
+        }
+    }
+}
+
+/// A liquidity buy or sell ready to be built into a transaction: the CAT payload,
+/// the pool vault (and payout) outputs and the pool vault UTXO it spends.
+pub(crate) struct LiquidityTrade {
+    pub payload: Vec<u8>,
+    pub destination: PaymentDestination,
+    pub vault_entry: UtxoEntryReference,
+    pub asset_id: String,
+    pub amount_in: u128,
+    pub amount_out: u128,
+    pub pool_nonce: u64,
+    pub nonce: u64,
+}
+
 struct TokenSnapshot {
     totals: BTreeMap<String, u128>,
     labels: HashMap<String, String>,
@@ -90,7 +81,7 @@ struct TokenSnapshot {
 }
 
 #[derive(Default, Handler)]
-#[help("Token operations (CAT): send, mint, burn, create, create-mint, create-liquidity, buy-liquidity, sell-liquidity, claim-liquidity, balances, monitor")]
+#[help("Token operations (CAT): send, batch-send, approve, transfer-from, lock, mint, burn, transfer-mint-authority, renounce-mint-authority, update-metadata, create, create-mint, create-liquidity, buy-liquidity, sell-liquidity, buy-liquidity-out, sell-liquidity-out, claim-liquidity, create-pair, add-pair-liquidity, remove-pair-liquidity, swap-pair-in, swap-pair-out, balances, monitor")]
 pub struct Token;
 
 impl Token {
@@ -104,14 +95,28 @@ impl Token {
         let op = argv.remove(0);
         match op.as_str() {
             "send" => self.send(ctx, argv).await,
+            "batch-send" => self.batch_send(ctx, argv).await,
+            "approve" => self.approve(ctx, argv).await,
+            "transfer-from" => self.transfer_from(ctx, argv).await,
+            "lock" => self.lock(ctx, argv).await,
             "mint" => self.mint(ctx, argv).await,
             "burn" => self.burn(ctx, argv).await,
+            "transfer-mint-authority" => self.transfer_mint_authority(ctx, argv).await,
+            "renounce-mint-authority" => self.renounce_mint_authority(ctx, argv).await,
+            "update-metadata" => self.update_metadata(ctx, argv).await,
             "create" => self.create(ctx, argv).await,
             "create-mint" => self.create_with_mint(ctx, argv).await,
             "create-liquidity" => self.create_liquidity(ctx, argv).await,
             "buy-liquidity" => self.buy_liquidity(ctx, argv).await,
             "sell-liquidity" => self.sell_liquidity(ctx, argv).await,
+            "buy-liquidity-out" => self.buy_liquidity_out(ctx, argv).await,
+            "sell-liquidity-out" => self.sell_liquidity_out(ctx, argv).await,
             "claim-liquidity" => self.claim_liquidity(ctx, argv).await,
+            "create-pair" => self.create_pair(ctx, argv).await,
+            "add-pair-liquidity" => self.add_pair_liquidity(ctx, argv).await,
+            "remove-pair-liquidity" => self.remove_pair_liquidity(ctx, argv).await,
+            "swap-pair-in" => self.swap_pair_in(ctx, argv).await,
+            "swap-pair-out" => self.swap_pair_out(ctx, argv).await,
             "balances" => self.balances(ctx, argv).await,
             "monitor" => self.monitor(ctx, argv).await,
             v => {
@@ -138,12 +143,8 @@ impl Token {
         let sender_address =
             if let Some(sender) = argv.first() { Address::try_from(sender.as_str())? } else { account.receive_address()? };
 
-        let sender_owner_id = Self::resolve_owner_id(&rpc, &sender_address, "senderAddress").await?;
-        let recipient_owner_id = Self::resolve_owner_id(&rpc, &recipient_address, "toAddress").await?;
-        let nonce = Self::resolve_sender_nonce(&rpc, sender_owner_id.as_str(), Some(asset_id.as_str())).await?;
-
-        let payload =
-            Self::build_transfer_payload(asset_id.as_str(), recipient_owner_id.as_str(), amount, nonce, DEFAULT_AUTH_INPUT_INDEX)?;
+        let (payload, nonce) =
+            Self::prepare_transfer_payload(&rpc, asset_id.as_str(), &recipient_address, amount, &sender_address).await?;
 
         let (summary, ids) = Self::submit_payload_tx(&ctx, &account, payload, sender_address.clone()).await?;
         tprintln!(ctx, "Token send - {summary}");
@@ -165,6 +166,208 @@ impl Token {
         Ok(())
     }
 
+    async fn batch_send(self: Arc<Self>, ctx: Arc<CryptixCli>, mut argv: Vec<String>) -> Result<()> {
+        if argv.len() < 2 || argv.len() > 3 {
+            tprintln!(ctx, "usage: token batch-send <assetId> <toAddress:amountRaw[,toAddress:amountRaw...]> [senderAddress]");
+            tprintln!(
+                ctx,
+                "note: up to {CAT_MAX_BATCH_TRANSFER_RECIPIENTS} distinct recipients, all applied in one payload or not at all"
+            );
+            return Ok(());
+        }
+
+        let account = ctx.wallet().account()?;
+        let rpc = ctx.wallet().rpc_api().clone();
+
+        let asset_id = argv.remove(0);
+        let recipient_specs = argv.remove(0);
+        let sender_address =
+            if let Some(sender) = argv.first() { Address::try_from(sender.as_str())? } else { account.receive_address()? };
+
+        let mut recipients = Vec::new();
+        let mut total = 0u128;
+        for spec in recipient_specs.split(',').map(str::trim).filter(|spec| !spec.is_empty()) {
+            // Addresses contain a `:` after the network prefix, so split on the last one.
+            let (address, amount_raw) =
+                spec.rsplit_once(':').ok_or_else(|| Error::custom(format!("recipient `{spec}` must be <toAddress:amountRaw>")))?;
+            let recipient_address = Address::try_from(address)?;
+            let amount = Self::parse_positive_u128(amount_raw, "amountRaw")?;
+            total = total.checked_add(amount).ok_or_else(|| Error::custom("batch total amount overflows u128"))?;
+            recipients.push(TokenRecipient { address: recipient_address, amount });
+        }
+
+        let operation = TokenOperation::BatchTransfer { asset_id: asset_id.clone(), recipients: recipients.clone() };
+        let (payload, nonce) = Self::encode_operation(&rpc, &operation, &sender_address).await?;
+
+        let (summary, ids) = Self::submit_payload_tx(&ctx, &account, payload, sender_address.clone()).await?;
+        tprintln!(ctx, "Token batch send - {summary}");
+        tprintln!(
+            ctx,
+            "asset={} recipients={} total={} sender={} nonce={}",
+            style(asset_id).dim(),
+            recipients.len(),
+            total,
+            sender_address,
+            nonce
+        );
+        for TokenRecipient { address, amount } in recipients.iter() {
+            tprintln!(ctx, "  {address} <- {amount}");
+        }
+        tprintln!(ctx, "carrier output: {} sompi to sender address", TOKEN_CARRIER_OUTPUT_SOMPI);
+        tprintln!(ctx, "tx ids:");
+        for id in ids {
+            tprintln!(ctx, "  {id}");
+        }
+
+        Ok(())
+    }
+
+    async fn lock(self: Arc<Self>, ctx: Arc<CryptixCli>, mut argv: Vec<String>) -> Result<()> {
+        if argv.len() < 5 || argv.len() > 6 {
+            tprintln!(
+                ctx,
+                "usage: token lock <assetId> <beneficiaryAddress> <amountRaw> <releaseStartDaa> <releaseEndDaa> [senderAddress]"
+            );
+            tprintln!(ctx, "note: releases linearly between the two DAA scores; use releaseStartDaa=releaseEndDaa for a cliff");
+            return Ok(());
+        }
+
+        let account = ctx.wallet().account()?;
+        let rpc = ctx.wallet().rpc_api().clone();
+
+        let asset_id = argv.remove(0);
+        let beneficiary_address = Address::try_from(argv.remove(0).as_str())?;
+        let amount_raw = argv.remove(0);
+        let amount = Self::parse_positive_u128(amount_raw.as_str(), "amountRaw")?;
+        let release_start_daa_score = Self::parse_u64(argv.remove(0).as_str(), "releaseStartDaa")?;
+        let release_end_daa_score = Self::parse_u64(argv.remove(0).as_str(), "releaseEndDaa")?;
+        if release_end_daa_score < release_start_daa_score {
+            return Err(Error::custom("releaseEndDaa must be >= releaseStartDaa"));
+        }
+        let sender_address =
+            if let Some(sender) = argv.first() { Address::try_from(sender.as_str())? } else { account.receive_address()? };
+
+        let operation = TokenOperation::LockTokens {
+            asset_id: asset_id.clone(),
+            beneficiary: beneficiary_address.clone(),
+            amount,
+            release_start_daa_score,
+            release_end_daa_score,
+        };
+        let (payload, nonce) = Self::encode_operation(&rpc, &operation, &sender_address).await?;
+
+        let (summary, ids) = Self::submit_payload_tx(&ctx, &account, payload, sender_address.clone()).await?;
+        tprintln!(ctx, "Token lock - {summary}");
+        tprintln!(
+            ctx,
+            "asset={} amount={} beneficiary={} sender={} release_daa={}..{} nonce={}",
+            style(asset_id).dim(),
+            amount,
+            beneficiary_address,
+            sender_address,
+            release_start_daa_score,
+            release_end_daa_score,
+            nonce
+        );
+        tprintln!(ctx, "carrier output: {} sompi to sender address", TOKEN_CARRIER_OUTPUT_SOMPI);
+        tprintln!(ctx, "tx ids:");
+        for id in ids {
+            tprintln!(ctx, "  {id}");
+        }
+
+        Ok(())
+    }
+
+    async fn approve(self: Arc<Self>, ctx: Arc<CryptixCli>, mut argv: Vec<String>) -> Result<()> {
+        if argv.len() < 3 || argv.len() > 5 {
+            tprintln!(ctx, "usage: token approve <assetId> <spenderAddress> <amountRaw> [expiresDaaScore] [ownerAddress]");
+            tprintln!(ctx, "note: amountRaw=0 revokes the allowance; expiresDaaScore=0 (default) never expires");
+            return Ok(());
+        }
+
+        let account = ctx.wallet().account()?;
+        let rpc = ctx.wallet().rpc_api().clone();
+
+        let asset_id = argv.remove(0);
+        let spender_address = Address::try_from(argv.remove(0).as_str())?;
+        let amount_raw = argv.remove(0);
+        let amount = Self::parse_u128(amount_raw.as_str(), "amountRaw")?;
+        let expires_at_daa_score = if argv.is_empty() { 0 } else { Self::parse_u64(argv.remove(0).as_str(), "expiresDaaScore")? };
+        let owner_address =
+            if let Some(owner) = argv.first() { Address::try_from(owner.as_str())? } else { account.receive_address()? };
+
+        let operation =
+            TokenOperation::Approve { asset_id: asset_id.clone(), spender: spender_address.clone(), amount, expires_at_daa_score };
+        let (payload, nonce) = Self::encode_operation(&rpc, &operation, &owner_address).await?;
+
+        let (summary, ids) = Self::submit_payload_tx(&ctx, &account, payload, owner_address.clone()).await?;
+        tprintln!(ctx, "Token approve - {summary}");
+        tprintln!(
+            ctx,
+            "asset={} amount={} spender={} owner={} expires_daa_score={} nonce={}",
+            style(asset_id).dim(),
+            amount,
+            spender_address,
+            owner_address,
+            expires_at_daa_score,
+            nonce
+        );
+        tprintln!(ctx, "carrier output: {} sompi to owner address", TOKEN_CARRIER_OUTPUT_SOMPI);
+        tprintln!(ctx, "tx ids:");
+        for id in ids {
+            tprintln!(ctx, "  {id}");
+        }
+
+        Ok(())
+    }
+
+    async fn transfer_from(self: Arc<Self>, ctx: Arc<CryptixCli>, mut argv: Vec<String>) -> Result<()> {
+        if argv.len() < 4 || argv.len() > 5 {
+            tprintln!(ctx, "usage: token transfer-from <assetId> <fromAddress> <toAddress> <amountRaw> [spenderAddress]");
+            tprintln!(ctx, "note: amountRaw must be the raw integer token amount (u128 units)");
+            return Ok(());
+        }
+
+        let account = ctx.wallet().account()?;
+        let rpc = ctx.wallet().rpc_api().clone();
+
+        let asset_id = argv.remove(0);
+        let from_address = Address::try_from(argv.remove(0).as_str())?;
+        let recipient_address = Address::try_from(argv.remove(0).as_str())?;
+        let amount_raw = argv.remove(0);
+        let amount = Self::parse_positive_u128(amount_raw.as_str(), "amountRaw")?;
+        let spender_address =
+            if let Some(spender) = argv.first() { Address::try_from(spender.as_str())? } else { account.receive_address()? };
+
+        let operation = TokenOperation::TransferFrom {
+            asset_id: asset_id.clone(),
+            from: from_address.clone(),
+            to: recipient_address.clone(),
+            amount,
+        };
+        let (payload, nonce) = Self::encode_operation(&rpc, &operation, &spender_address).await?;
+
+        let (summary, ids) = Self::submit_payload_tx(&ctx, &account, payload, spender_address.clone()).await?;
+        tprintln!(ctx, "Token transfer-from - {summary}");
+        tprintln!(
+            ctx,
+            "asset={} amount={} from={} recipient={} spender={} nonce={}",
+            style(asset_id).dim(),
+            amount,
+            from_address,
+            recipient_address,
+            spender_address,
+            nonce
+        );
+        tprintln!(ctx, "carrier output: {} sompi to spender address", TOKEN_CARRIER_OUTPUT_SOMPI);
+        tprintln!(ctx, "tx ids:");
+        for id in ids {
+            tprintln!(ctx, "  {id}");
+        }
+
+        Ok(())
+    }
+
     async fn mint(self: Arc<Self>, ctx: Arc<CryptixCli>, mut argv: Vec<String>) -> Result<()> {
         if argv.len() < 3 || argv.len() > 4 {
             tprintln!(ctx, "usage: token mint <assetId> <toAddress> <amountRaw> [senderAddress]");
@@ -182,11 +385,8 @@ impl Token {
         let sender_address =
             if let Some(sender) = argv.first() { Address::try_from(sender.as_str())? } else { account.receive_address()? };
 
-        let sender_owner_id = Self::resolve_owner_id(&rpc, &sender_address, "senderAddress").await?;
-        let recipient_owner_id = Self::resolve_owner_id(&rpc, &recipient_address, "toAddress").await?;
-        let nonce = Self::resolve_sender_nonce(&rpc, sender_owner_id.as_str(), Some(asset_id.as_str())).await?;
-        let payload =
-            Self::build_mint_payload(asset_id.as_str(), recipient_owner_id.as_str(), amount, nonce, DEFAULT_AUTH_INPUT_INDEX)?;
+        let (payload, nonce) =
+            Self::prepare_mint_payload(&rpc, asset_id.as_str(), &recipient_address, amount, &sender_address).await?;
 
         let (summary, ids) = Self::submit_payload_tx(&ctx, &account, payload, sender_address.clone()).await?;
         tprintln!(ctx, "Token mint - {summary}");
@@ -224,9 +424,8 @@ impl Token {
         let sender_address =
             if let Some(sender) = argv.first() { Address::try_from(sender.as_str())? } else { account.receive_address()? };
 
-        let sender_owner_id = Self::resolve_owner_id(&rpc, &sender_address, "senderAddress").await?;
-        let nonce = Self::resolve_sender_nonce(&rpc, sender_owner_id.as_str(), Some(asset_id.as_str())).await?;
-        let payload = Self::build_burn_payload(asset_id.as_str(), amount, nonce, DEFAULT_AUTH_INPUT_INDEX)?;
+        let operation = TokenOperation::Burn { asset_id: asset_id.clone(), amount };
+        let (payload, nonce) = Self::encode_operation(&rpc, &operation, &sender_address).await?;
 
         let (summary, ids) = Self::submit_payload_tx(&ctx, &account, payload, sender_address.clone()).await?;
         tprintln!(ctx, "Token burn - {summary}");
@@ -240,6 +439,122 @@ impl Token {
         Ok(())
     }
 
+    async fn transfer_mint_authority(self: Arc<Self>, ctx: Arc<CryptixCli>, mut argv: Vec<String>) -> Result<()> {
+        if argv.len() < 2 || argv.len() > 3 {
+            tprintln!(ctx, "usage: token transfer-mint-authority <assetId> <newAuthorityAddress> [senderAddress]");
+            tprintln!(ctx, "note: sender must be the current mint authority");
+            return Ok(());
+        }
+
+        let account = ctx.wallet().account()?;
+        let rpc = ctx.wallet().rpc_api().clone();
+
+        let asset_id = argv.remove(0);
+        let new_authority_address = Address::try_from(argv.remove(0).as_str())?;
+        let sender_address =
+            if let Some(sender) = argv.first() { Address::try_from(sender.as_str())? } else { account.receive_address()? };
+
+        let operation =
+            TokenOperation::TransferMintAuthority { asset_id: asset_id.clone(), new_authority: new_authority_address.clone() };
+        let (payload, nonce) = Self::encode_operation(&rpc, &operation, &sender_address).await?;
+
+        let (summary, ids) = Self::submit_payload_tx(&ctx, &account, payload, sender_address.clone()).await?;
+        tprintln!(ctx, "Token transfer-mint-authority - {summary}");
+        tprintln!(
+            ctx,
+            "asset={} new_authority={} sender={} nonce={}",
+            style(asset_id).dim(),
+            new_authority_address,
+            sender_address,
+            nonce
+        );
+        tprintln!(ctx, "carrier output: {} sompi to sender address", TOKEN_CARRIER_OUTPUT_SOMPI);
+        tprintln!(ctx, "tx ids:");
+        for id in ids {
+            tprintln!(ctx, "  {id}");
+        }
+
+        Ok(())
+    }
+
+    async fn renounce_mint_authority(self: Arc<Self>, ctx: Arc<CryptixCli>, mut argv: Vec<String>) -> Result<()> {
+        if argv.is_empty() || argv.len() > 2 {
+            tprintln!(ctx, "usage: token renounce-mint-authority <assetId> [senderAddress]");
+            tprintln!(ctx, "note: irreversible; no further mints are possible for the asset");
+            return Ok(());
+        }
+
+        let account = ctx.wallet().account()?;
+        let rpc = ctx.wallet().rpc_api().clone();
+
+        let asset_id = argv.remove(0);
+        let sender_address =
+            if let Some(sender) = argv.first() { Address::try_from(sender.as_str())? } else { account.receive_address()? };
+
+        let operation = TokenOperation::RenounceMintAuthority { asset_id: asset_id.clone() };
+        let (payload, nonce) = Self::encode_operation(&rpc, &operation, &sender_address).await?;
+
+        let (summary, ids) = Self::submit_payload_tx(&ctx, &account, payload, sender_address.clone()).await?;
+        tprintln!(ctx, "Token renounce-mint-authority - {summary}");
+        tprintln!(ctx, "asset={} sender={} nonce={}", style(asset_id).dim(), sender_address, nonce);
+        tprintln!(ctx, "carrier output: {} sompi to sender address", TOKEN_CARRIER_OUTPUT_SOMPI);
+        tprintln!(ctx, "tx ids:");
+        for id in ids {
+            tprintln!(ctx, "  {id}");
+        }
+
+        Ok(())
+    }
+
+    async fn update_metadata(self: Arc<Self>, ctx: Arc<CryptixCli>, mut argv: Vec<String>) -> Result<()> {
+        if argv.len() < 2 || argv.len() > 4 {
+            tprintln!(ctx, "usage: token update-metadata <assetId> <metadataHex> [platformTag] [senderAddress]");
+            tprintln!(ctx, "note: sender must be the asset creator; use `-` as metadataHex for empty metadata");
+            return Ok(());
+        }
+
+        let account = ctx.wallet().account()?;
+        let rpc = ctx.wallet().rpc_api().clone();
+
+        let asset_id = argv.remove(0);
+        let metadata_hex = argv.remove(0);
+        let metadata = if metadata_hex == "-" {
+            Vec::new()
+        } else {
+            let normalized = metadata_hex.trim().strip_prefix("0x").unwrap_or(metadata_hex.trim());
+            Vec::<u8>::from_hex(normalized).map_err(|err| Error::custom(format!("metadataHex must be valid hex: {err}")))?
+        };
+        let platform_tag = if argv.is_empty() { String::new() } else { argv.remove(0) };
+        let sender_address =
+            if let Some(sender) = argv.first() { Address::try_from(sender.as_str())? } else { account.receive_address()? };
+
+        let operation = TokenOperation::UpdateMetadata {
+            asset_id: asset_id.clone(),
+            metadata: metadata.clone(),
+            platform_tag: Some(platform_tag.clone()),
+        };
+        let (payload, nonce) = Self::encode_operation(&rpc, &operation, &sender_address).await?;
+
+        let (summary, ids) = Self::submit_payload_tx(&ctx, &account, payload, sender_address.clone()).await?;
+        tprintln!(ctx, "Token update-metadata - {summary}");
+        tprintln!(
+            ctx,
+            "asset={} metadata_bytes={} platform_tag={} sender={} nonce={}",
+            style(asset_id).dim(),
+            metadata.len(),
+            platform_tag,
+            sender_address,
+            nonce
+        );
+        tprintln!(ctx, "carrier output: {} sompi to sender address", TOKEN_CARRIER_OUTPUT_SOMPI);
+        tprintln!(ctx, "tx ids:");
+        for id in ids {
+            tprintln!(ctx, "  {id}");
+        }
+
+        Ok(())
+    }
+
     async fn create(self: Arc<Self>, ctx: Arc<CryptixCli>, mut argv: Vec<String>) -> Result<()> {
         if argv.len() < 5 {
             tprintln!(ctx, "usage: token create <name> <symbol> <decimals> <uncapped|capped> <maxSupplyRaw> [--sender=<address>] [--mint-authority=<address>] [--metadata-hex=<hex>] [--platform-tag=<tag>]");
@@ -262,22 +577,17 @@ impl Token {
         let sender_address = sender_opt.unwrap_or(account.receive_address()?);
         let mint_authority_address = mint_authority_opt.unwrap_or_else(|| sender_address.clone());
 
-        let sender_owner_id = Self::resolve_owner_id(&rpc, &sender_address, "--sender").await?;
-        let mint_authority_owner_id = Self::resolve_owner_id(&rpc, &mint_authority_address, "--mint-authority").await?;
-        let nonce = Self::resolve_sender_nonce(&rpc, sender_owner_id.as_str(), None).await?;
-
-        let payload = Self::build_create_asset_payload(
-            name.as_str(),
-            symbol.as_str(),
+        let operation = TokenOperation::CreateAsset {
+            name: name.clone(),
+            symbol: symbol.clone(),
             decimals,
             supply_mode,
             max_supply,
-            mint_authority_owner_id.as_str(),
-            metadata.as_slice(),
-            platform_tag.as_str(),
-            nonce,
-            DEFAULT_AUTH_INPUT_INDEX,
-        )?;
+            mint_authority: Some(mint_authority_address.clone()),
+            metadata,
+            platform_tag: Some(platform_tag),
+        };
+        let (payload, nonce) = Self::encode_operation(&rpc, &operation, &sender_address).await?;
 
         let (summary, ids) = Self::submit_payload_tx(&ctx, &account, payload, sender_address.clone()).await?;
         tprintln!(ctx, "Token create - {summary}");
@@ -287,7 +597,7 @@ impl Token {
             name,
             symbol,
             decimals,
-            if supply_mode == 0 { "uncapped" } else { "capped" },
+            Self::supply_mode_label(supply_mode),
             max_supply,
             sender_address,
             mint_authority_address,
@@ -323,7 +633,7 @@ impl Token {
         if initial_mint_amount == 0 {
             return Err(Error::custom("initialMintAmountRaw must be greater than zero"));
         }
-        if supply_mode == 1 && initial_mint_amount > max_supply {
+        if supply_mode == TokenSupplyMode::Capped && initial_mint_amount > max_supply {
             return Err(Error::custom("initialMintAmountRaw exceeds maxSupplyRaw for capped token"));
         }
 
@@ -333,25 +643,19 @@ impl Token {
         let sender_address = sender_opt.unwrap_or(account.receive_address()?);
         let mint_authority_address = mint_authority_opt.unwrap_or_else(|| sender_address.clone());
 
-        let sender_owner_id = Self::resolve_owner_id(&rpc, &sender_address, "--sender").await?;
-        let mint_authority_owner_id = Self::resolve_owner_id(&rpc, &mint_authority_address, "--mint-authority").await?;
-        let initial_mint_to_owner_id = Self::resolve_owner_id(&rpc, &initial_mint_to_address, "initialMintToAddress").await?;
-        let nonce = Self::resolve_sender_nonce(&rpc, sender_owner_id.as_str(), None).await?;
-
-        let payload = Self::build_create_asset_with_mint_payload(
-            name.as_str(),
-            symbol.as_str(),
+        let operation = TokenOperation::CreateAssetWithMint {
+            name: name.clone(),
+            symbol: symbol.clone(),
             decimals,
             supply_mode,
             max_supply,
-            mint_authority_owner_id.as_str(),
-            metadata.as_slice(),
+            mint_authority: Some(mint_authority_address.clone()),
+            metadata,
             initial_mint_amount,
-            initial_mint_to_owner_id.as_str(),
-            platform_tag.as_str(),
-            nonce,
-            DEFAULT_AUTH_INPUT_INDEX,
-        )?;
+            initial_mint_to: Some(initial_mint_to_address.clone()),
+            platform_tag: Some(platform_tag),
+        };
+        let (payload, nonce) = Self::encode_operation(&rpc, &operation, &sender_address).await?;
 
         let (summary, ids) = Self::submit_payload_tx(&ctx, &account, payload, sender_address.clone()).await?;
         tprintln!(ctx, "Token create-mint - {summary}");
@@ -361,7 +665,7 @@ impl Token {
             name,
             symbol,
             decimals,
-            if supply_mode == 0 { "uncapped" } else { "capped" },
+            Self::supply_mode_label(supply_mode),
             max_supply,
             initial_mint_amount,
             initial_mint_to_address,
@@ -382,7 +686,7 @@ impl Token {
         if argv.len() < 6 {
             tprintln!(
                 ctx,
-                "usage: token create-liquidity <name> <symbol> <decimals=0> <maxSupplyRaw:{MIN_LIQUIDITY_TOKEN_SUPPLY_RAW}..={MAX_LIQUIDITY_TOKEN_SUPPLY_RAW}> <seedReserveSompi={MIN_LIQUIDITY_SEED_RESERVE_SOMPI}> <feeBps> [recipientAddress[,recipientAddress2]] [--launch-buy-sompi=<sompi>] [--launch-buy-min-token-out=<amountRaw>] [--sender=<address>] [--metadata-hex=<hex>] [--platform-tag=<tag>] [--liquidity-unlock-target-sompi=<sompi>] [--liquidity-mode=basic|aggressive|individual] [--liquidity-individual-fixed-cpay-million=<1.0..8.0>] [--liquidity-individual-supply-multiplier=<1.01..2.00>]"
+                "usage: token create-liquidity <name> <symbol> <decimals=0> <maxSupplyRaw:{CAT_MIN_LIQUIDITY_SUPPLY_RAW}..={CAT_MAX_LIQUIDITY_SUPPLY_RAW}> <seedReserveSompi={CAT_MIN_LIQUIDITY_SEED_RESERVE_SOMPI}> <feeBps> [recipientAddress[,recipientAddress2]] [--launch-buy-sompi=<sompi>] [--launch-buy-min-token-out=<amountRaw>] [--sender=<address>] [--metadata-hex=<hex>] [--platform-tag=<tag>] [--liquidity-unlock-target-sompi=<sompi>] [--liquidity-mode=basic|aggressive|individual] [--liquidity-individual-fixed-cpay-million=<1.0..8.0>] [--liquidity-individual-supply-multiplier=<1.01..2.00>]"
             );
             tprintln!(ctx, "defaults: maxSupplyRaw={DEFAULT_LIQUIDITY_TOKEN_SUPPLY_RAW}, decimals=0, seedReserveSompi=1 CPAY");
             return Ok(());
@@ -426,6 +730,7 @@ impl Token {
 
         let options = Self::parse_create_liquidity_options(argv)?;
         Self::validate_asset_identity_fields(name.as_str(), symbol.as_str(), options.metadata.as_slice(), decimals)?;
+        Self::validate_liquidity_create_parameters(decimals, max_supply, seed_reserve_sompi)?;
         if options.launch_buy_budget_sompi == 0 && options.launch_buy_min_token_out != 0 {
             return Err(Error::custom("launchBuyMinTokenOut must be 0 when launchBuySompi is 0"));
         }
@@ -466,27 +771,21 @@ impl Token {
             .ok_or_else(|| Error::custom("canonical launch buy exceeds provided budget"))?;
 
         let sender_address = options.sender.unwrap_or(account.receive_address()?);
-        let sender_owner_id = Self::resolve_owner_id(&rpc, &sender_address, "--sender").await?;
-        let nonce = Self::resolve_sender_nonce(&rpc, sender_owner_id.as_str(), None).await?;
-        let payload = Self::build_create_liquidity_asset_payload(
-            name.as_str(),
-            symbol.as_str(),
-            decimals,
+        let operation = TokenOperation::CreateLiquidityAsset {
+            name: name.clone(),
+            symbol: symbol.clone(),
             max_supply,
-            options.metadata.as_slice(),
+            metadata: options.metadata.clone(),
             seed_reserve_sompi,
             fee_bps,
-            recipients.as_slice(),
+            fee_recipients: recipients,
             launch_buy_sompi,
-            options.launch_buy_min_token_out,
-            options.platform_tag.as_str(),
-            options.liquidity_unlock_target_sompi,
-            options.curve_mode,
-            options.individual_virtual_cpay_reserves_sompi,
-            options.individual_virtual_token_multiplier_bps,
-            nonce,
-            DEFAULT_AUTH_INPUT_INDEX,
-        )?;
+            launch_buy_min_token_out: options.launch_buy_min_token_out,
+            platform_tag: Some(options.platform_tag.clone()),
+            liquidity_unlock_target_sompi: options.liquidity_unlock_target_sompi,
+            curve: options.curve(),
+        };
+        let (payload, nonce) = Self::encode_operation(&rpc, &operation, &sender_address).await?;
 
         let vault_value = seed_reserve_sompi
             .checked_add(launch_buy_sompi)
@@ -546,49 +845,136 @@ impl Token {
 
         let account = ctx.wallet().account()?;
         let rpc = ctx.wallet().rpc_api().clone();
-        let asset_id = Self::normalize_asset_id(argv.remove(0).as_str());
+        let asset_id = argv.remove(0);
         let cpay_budget_sompi = Self::parse_positive_u64(argv.remove(0).as_str(), "cpayInSompi")?;
         let min_token_out = Self::parse_positive_u128(argv.remove(0).as_str(), "minTokenOutRaw")?;
         let sender_address =
             if let Some(sender) = argv.first() { Address::try_from(sender.as_str())? } else { account.receive_address()? };
 
+        let trade = Self::prepare_buy_liquidity(&rpc, asset_id.as_str(), cpay_budget_sompi, min_token_out, &sender_address).await?;
+        let (summary, ids) = Self::submit_liquidity_transition_tx(
+            &ctx,
+            &account,
+            trade.payload,
+            trade.destination,
+            sender_address.clone(),
+            trade.vault_entry,
+        )
+        .await?;
+
+        tprintln!(ctx, "Token buy-liquidity - {summary}");
+        tprintln!(
+            ctx,
+            "asset={} cpayInSompi={} tokenOut={} minTokenOut={} sender={} poolNonce={} nonce={}",
+            style(trade.asset_id).dim(),
+            trade.amount_in,
+            trade.amount_out,
+            min_token_out,
+            sender_address,
+            trade.pool_nonce,
+            trade.nonce
+        );
+        if u128::from(cpay_budget_sompi) > trade.amount_in {
+            tprintln!(
+                ctx,
+                "buy budget={} canonicalSpend={} unusedBudget={}",
+                cpay_budget_sompi,
+                trade.amount_in,
+                u128::from(cpay_budget_sompi) - trade.amount_in
+            );
+        }
+        tprintln!(ctx, "tx ids:");
+        for id in ids {
+            tprintln!(ctx, "  {id}");
+        }
+        Ok(())
+    }
+
+    async fn sell_liquidity(self: Arc<Self>, ctx: Arc<CryptixCli>, mut argv: Vec<String>) -> Result<()> {
+        if argv.len() < 3 || argv.len() > 4 {
+            tprintln!(ctx, "usage: token sell-liquidity <assetId> <tokenInRaw> <minCpayOutSompi> [senderAddress]");
+            return Ok(());
+        }
+
+        let account = ctx.wallet().account()?;
+        let rpc = ctx.wallet().rpc_api().clone();
+        let asset_id = argv.remove(0);
+        let token_in = Self::parse_positive_u128(argv.remove(0).as_str(), "tokenInRaw")?;
+        let min_cpay_out_sompi = Self::parse_positive_u64(argv.remove(0).as_str(), "minCpayOutSompi")?;
+        let sender_address =
+            if let Some(sender) = argv.first() { Address::try_from(sender.as_str())? } else { account.receive_address()? };
+
+        let trade = Self::prepare_sell_liquidity(&rpc, asset_id.as_str(), token_in, min_cpay_out_sompi, &sender_address).await?;
+        let (summary, ids) = Self::submit_liquidity_transition_tx(
+            &ctx,
+            &account,
+            trade.payload,
+            trade.destination,
+            sender_address.clone(),
+            trade.vault_entry,
+        )
+        .await?;
+
+        tprintln!(ctx, "Token sell-liquidity - {summary}");
+        tprintln!(
+            ctx,
+            "asset={} tokenIn={} cpayOutSompi={} minCpayOutSompi={} sender={} poolNonce={} nonce={}",
+            style(trade.asset_id).dim(),
+            trade.amount_in,
+            trade.amount_out,
+            min_cpay_out_sompi,
+            sender_address,
+            trade.pool_nonce,
+            trade.nonce
+        );
+        tprintln!(ctx, "tx ids:");
+        for id in ids {
+            tprintln!(ctx, "  {id}");
+        }
+        Ok(())
+    }
+
+    async fn buy_liquidity_out(self: Arc<Self>, ctx: Arc<CryptixCli>, mut argv: Vec<String>) -> Result<()> {
+        if argv.len() < 3 || argv.len() > 4 {
+            tprintln!(ctx, "usage: token buy-liquidity-out <assetId> <tokenOutRaw> <maxCpayInSompi> [senderAddress]");
+            return Ok(());
+        }
+
+        let account = ctx.wallet().account()?;
+        let rpc = ctx.wallet().rpc_api().clone();
+        let asset_id = Self::normalize_asset_id(argv.remove(0).as_str());
+        let token_out = Self::parse_positive_u128(argv.remove(0).as_str(), "tokenOutRaw")?;
+        let max_cpay_in_sompi = Self::parse_positive_u64(argv.remove(0).as_str(), "maxCpayInSompi")?;
+        let sender_address =
+            if let Some(sender) = argv.first() { Address::try_from(sender.as_str())? } else { account.receive_address()? };
+
         let pool = Self::fetch_liquidity_pool(&rpc, asset_id.as_str()).await?;
         let quote = rpc
             .get_liquidity_quote_call(
                 None,
                 GetLiquidityQuoteRequest {
                     asset_id: asset_id.clone(),
-                    side: LIQUIDITY_QUOTE_SIDE_BUY,
-                    exact_in_amount: cpay_budget_sompi.to_string(),
+                    side: LIQUIDITY_QUOTE_SIDE_BUY_EXACT_OUT,
+                    exact_in_amount: token_out.to_string(),
                     at_block_hash: None,
                 },
             )
             .await?;
-        let quoted_token_out = Self::parse_u128(quote.amount_out.as_str(), "quote.amountOut")?;
         let cpay_in_sompi = Self::parse_positive_u64(quote.exact_in_amount.as_str(), "quote.exactInAmount")?;
-        if cpay_in_sompi > cpay_budget_sompi {
+        if cpay_in_sompi > max_cpay_in_sompi {
             return Err(Error::custom(format!(
-                "canonical buy input exceeds provided budget: canonical={} budget={}",
-                cpay_in_sompi, cpay_budget_sompi
-            )));
-        }
-        if quoted_token_out < min_token_out {
-            return Err(Error::custom(format!(
-                "minTokenOutRaw is above current quote: min={} quote={}",
-                min_token_out, quoted_token_out
+                "maxCpayInSompi is below current quote: max={} quote={}",
+                max_cpay_in_sompi, cpay_in_sompi
             )));
         }
 
-        let sender_owner_id = Self::resolve_owner_id(&rpc, &sender_address, "senderAddress").await?;
-        let nonce = Self::resolve_sender_nonce(&rpc, sender_owner_id.as_str(), Some(asset_id.as_str())).await?;
-        let payload = Self::build_buy_liquidity_payload(
-            asset_id.as_str(),
-            pool.pool_nonce,
-            cpay_in_sompi,
-            min_token_out,
-            nonce,
-            LIQUIDITY_AUTH_INPUT_INDEX,
-        )?;
+        let operation = TokenOperation::BuyLiquidityExactOut {
+            asset_id: asset_id.clone(),
+            expected_pool_nonce: pool.pool_nonce,
+            token_out,
+            max_cpay_in_sompi,
+        };
+        let (payload, nonce) = Self::encode_operation(&rpc, &operation, &sender_address).await?;
 
         let vault_value = Self::pool_vault_value(&pool)?
             .checked_add(cpay_in_sompi)
@@ -598,27 +984,18 @@ impl Token {
         let (summary, ids) =
             Self::submit_liquidity_transition_tx(&ctx, &account, payload, destination, sender_address.clone(), vault_entry).await?;
 
-        tprintln!(ctx, "Token buy-liquidity - {summary}");
+        tprintln!(ctx, "Token buy-liquidity-out - {summary}");
         tprintln!(
             ctx,
-            "asset={} cpayInSompi={} tokenOut={} minTokenOut={} sender={} poolNonce={} nonce={}",
+            "asset={} tokenOut={} cpayInSompi={} maxCpayInSompi={} sender={} poolNonce={} nonce={}",
             style(asset_id).dim(),
+            token_out,
             cpay_in_sompi,
-            quoted_token_out,
-            min_token_out,
+            max_cpay_in_sompi,
             sender_address,
             pool.pool_nonce,
             nonce
         );
-        if cpay_budget_sompi > cpay_in_sompi {
-            tprintln!(
-                ctx,
-                "buy budget={} canonicalSpend={} unusedBudget={}",
-                cpay_budget_sompi,
-                cpay_in_sompi,
-                cpay_budget_sompi - cpay_in_sompi
-            );
-        }
         tprintln!(ctx, "tx ids:");
         for id in ids {
             tprintln!(ctx, "  {id}");
@@ -626,17 +1003,17 @@ impl Token {
         Ok(())
     }
 
-    async fn sell_liquidity(self: Arc<Self>, ctx: Arc<CryptixCli>, mut argv: Vec<String>) -> Result<()> {
+    async fn sell_liquidity_out(self: Arc<Self>, ctx: Arc<CryptixCli>, mut argv: Vec<String>) -> Result<()> {
         if argv.len() < 3 || argv.len() > 4 {
-            tprintln!(ctx, "usage: token sell-liquidity <assetId> <tokenInRaw> <minCpayOutSompi> [senderAddress]");
+            tprintln!(ctx, "usage: token sell-liquidity-out <assetId> <cpayOutSompi> <maxTokenInRaw> [senderAddress]");
             return Ok(());
         }
 
         let account = ctx.wallet().account()?;
         let rpc = ctx.wallet().rpc_api().clone();
         let asset_id = Self::normalize_asset_id(argv.remove(0).as_str());
-        let token_in = Self::parse_positive_u128(argv.remove(0).as_str(), "tokenInRaw")?;
-        let min_cpay_out_sompi = Self::parse_positive_u64(argv.remove(0).as_str(), "minCpayOutSompi")?;
+        let cpay_out_sompi = Self::parse_positive_u64(argv.remove(0).as_str(), "cpayOutSompi")?;
+        let max_token_in = Self::parse_positive_u128(argv.remove(0).as_str(), "maxTokenInRaw")?;
         let sender_address =
             if let Some(sender) = argv.first() { Address::try_from(sender.as_str())? } else { account.receive_address()? };
 
@@ -647,111 +1024,334 @@ impl Token {
                 None,
                 GetLiquidityQuoteRequest {
                     asset_id: asset_id.clone(),
-                    side: LIQUIDITY_QUOTE_SIDE_SELL,
-                    exact_in_amount: token_in.to_string(),
+                    side: LIQUIDITY_QUOTE_SIDE_SELL_EXACT_OUT,
+                    exact_in_amount: cpay_out_sompi.to_string(),
                     at_block_hash: None,
                 },
             )
             .await?;
-        let quoted_cpay_out = Self::parse_u64(quote.amount_out.as_str(), "quote.amountOut")?;
-        if quoted_cpay_out < min_cpay_out_sompi {
+        let quoted_token_in = Self::parse_positive_u128(quote.exact_in_amount.as_str(), "quote.exactInAmount")?;
+        if quoted_token_in > max_token_in {
             return Err(Error::custom(format!(
-                "minCpayOutSompi is above current quote: min={} quote={}",
-                min_cpay_out_sompi, quoted_cpay_out
+                "maxTokenInRaw is below current quote: max={} quote={}",
+                max_token_in, quoted_token_in
             )));
         }
 
-        let sender_owner_id = Self::resolve_owner_id(&rpc, &sender_address, "senderAddress").await?;
-        let nonce = Self::resolve_sender_nonce(&rpc, sender_owner_id.as_str(), Some(asset_id.as_str())).await?;
-        let payload = Self::build_sell_liquidity_payload(
-            asset_id.as_str(),
-            pool.pool_nonce,
-            token_in,
-            min_cpay_out_sompi,
-            1,
-            nonce,
-            LIQUIDITY_AUTH_INPUT_INDEX,
-        )?;
+        let operation = TokenOperation::SellLiquidityExactOut {
+            asset_id: asset_id.clone(),
+            expected_pool_nonce: pool.pool_nonce,
+            cpay_out_sompi,
+            max_token_in,
+            cpay_receive_output_index: 1,
+        };
+        let (payload, nonce) = Self::encode_operation(&rpc, &operation, &sender_address).await?;
+
+        let vault_value = Self::pool_vault_value(&pool)?
+            .checked_sub(cpay_out_sompi)
+            .ok_or_else(|| Error::custom("vault value underflows after sell"))?;
+        let destination = Self::liquidity_vault_and_payout_destination(vault_value, cpay_out_sompi, &sender_address);
+        let vault_entry = Self::liquidity_vault_utxo_entry(&pool)?;
+        let (summary, ids) =
+            Self::submit_liquidity_transition_tx(&ctx, &account, payload, destination, sender_address.clone(), vault_entry).await?;
+
+        tprintln!(ctx, "Token sell-liquidity-out - {summary}");
+        tprintln!(
+            ctx,
+            "asset={} cpayOutSompi={} tokenIn={} maxTokenIn={} sender={} poolNonce={} nonce={}",
+            style(asset_id).dim(),
+            cpay_out_sompi,
+            quoted_token_in,
+            max_token_in,
+            sender_address,
+            pool.pool_nonce,
+            nonce
+        );
+        tprintln!(ctx, "tx ids:");
+        for id in ids {
+            tprintln!(ctx, "  {id}");
+        }
+        Ok(())
+    }
+
+    async fn claim_liquidity(self: Arc<Self>, ctx: Arc<CryptixCli>, mut argv: Vec<String>) -> Result<()> {
+        if argv.len() < 3 || argv.len() > 4 {
+            tprintln!(ctx, "usage: token claim-liquidity <assetId> <recipientIndex> <claimAmountSompi> [senderAddress]");
+            return Ok(());
+        }
+
+        let account = ctx.wallet().account()?;
+        let rpc = ctx.wallet().rpc_api().clone();
+        let asset_id = Self::normalize_asset_id(argv.remove(0).as_str());
+        let recipient_index =
+            argv.remove(0).parse::<u8>().map_err(|err| Error::custom(format!("recipientIndex must fit into u8: {err}")))?;
+        let claim_amount_sompi = Self::parse_positive_u64(argv.remove(0).as_str(), "claimAmountSompi")?;
+        let sender_address =
+            if let Some(sender) = argv.first() { Address::try_from(sender.as_str())? } else { account.receive_address()? };
+
+        let pool = Self::fetch_liquidity_pool(&rpc, asset_id.as_str()).await?;
+        Self::ensure_liquidity_outflow_unlocked(&pool, "liquidity fee claim")?;
+        let operation = TokenOperation::ClaimLiquidityFees {
+            asset_id: asset_id.clone(),
+            expected_pool_nonce: pool.pool_nonce,
+            recipient_index,
+            claim_amount_sompi,
+            claim_receive_output_index: 1,
+        };
+        let (payload, nonce) = Self::encode_operation(&rpc, &operation, &sender_address).await?;
+
+        let vault_value = Self::pool_vault_value(&pool)?
+            .checked_sub(claim_amount_sompi)
+            .ok_or_else(|| Error::custom("vault value underflows after claim"))?;
+        let destination = Self::liquidity_vault_and_payout_destination(vault_value, claim_amount_sompi, &sender_address);
+        let vault_entry = Self::liquidity_vault_utxo_entry(&pool)?;
+        let (summary, ids) =
+            Self::submit_liquidity_transition_tx(&ctx, &account, payload, destination, sender_address.clone(), vault_entry).await?;
+
+        tprintln!(ctx, "Token claim-liquidity - {summary}");
+        tprintln!(
+            ctx,
+            "asset={} recipientIndex={} claimAmountSompi={} sender={} poolNonce={} nonce={}",
+            style(asset_id).dim(),
+            recipient_index,
+            claim_amount_sompi,
+            sender_address,
+            pool.pool_nonce,
+            nonce
+        );
+        tprintln!(ctx, "tx ids:");
+        for id in ids {
+            tprintln!(ctx, "  {id}");
+        }
+        Ok(())
+    }
+
+    async fn create_pair(self: Arc<Self>, ctx: Arc<CryptixCli>, mut argv: Vec<String>) -> Result<()> {
+        if argv.len() < 5 || argv.len() > 6 {
+            tprintln!(ctx, "usage: token create-pair <assetId> <amountRaw> <otherAssetId> <otherAmountRaw> <feeBps> [senderAddress]");
+            tprintln!(ctx, "note: assets may be given in any order; the pool id is the create txid");
+            return Ok(());
+        }
+
+        let account = ctx.wallet().account()?;
+        let rpc = ctx.wallet().rpc_api().clone();
+
+        let first_asset_id = Self::normalize_asset_id(argv.remove(0).as_str());
+        let first_amount = Self::parse_positive_u128(argv.remove(0).as_str(), "amountRaw")?;
+        let second_asset_id = Self::normalize_asset_id(argv.remove(0).as_str());
+        let second_amount = Self::parse_positive_u128(argv.remove(0).as_str(), "otherAmountRaw")?;
+        let fee_bps = argv.remove(0).parse::<u16>().map_err(|_| Error::custom("feeBps must be a valid u16"))?;
+        let sender_address =
+            if let Some(sender) = argv.first() { Address::try_from(sender.as_str())? } else { account.receive_address()? };
+
+        // Pools store their assets in canonical (ascending id) order.
+        let ((asset_a_id, amount_a), (asset_b_id, amount_b)) = match Self::parse_hex_32(first_asset_id.as_str(), "assetId")?
+            .cmp(&Self::parse_hex_32(second_asset_id.as_str(), "otherAssetId")?)
+        {
+            std::cmp::Ordering::Less => ((first_asset_id, first_amount), (second_asset_id, second_amount)),
+            std::cmp::Ordering::Greater => ((second_asset_id, second_amount), (first_asset_id, first_amount)),
+            std::cmp::Ordering::Equal => return Err(Error::custom("pair assets must be distinct")),
+        };
+
+        let operation = TokenOperation::CreatePairPool {
+            asset_a_id: asset_a_id.clone(),
+            asset_b_id: asset_b_id.clone(),
+            fee_bps,
+            amount_a,
+            amount_b,
+        };
+        let (payload, nonce) = Self::encode_operation(&rpc, &operation, &sender_address).await?;
+
+        let (summary, ids) = Self::submit_payload_tx(&ctx, &account, payload, sender_address.clone()).await?;
+        tprintln!(ctx, "Token create-pair - {summary}");
+        tprintln!(
+            ctx,
+            "assetA={} amountA={} assetB={} amountB={} feeBps={} sender={} nonce={}",
+            style(asset_a_id).dim(),
+            amount_a,
+            style(asset_b_id).dim(),
+            amount_b,
+            fee_bps,
+            sender_address,
+            nonce
+        );
+        tprintln!(ctx, "carrier output: {} sompi to sender address", TOKEN_CARRIER_OUTPUT_SOMPI);
+        tprintln!(ctx, "create tx ids (poolId is txid):");
+        for id in ids {
+            tprintln!(ctx, "  {id}");
+        }
+
+        Ok(())
+    }
+
+    async fn add_pair_liquidity(self: Arc<Self>, ctx: Arc<CryptixCli>, mut argv: Vec<String>) -> Result<()> {
+        if argv.len() < 4 || argv.len() > 5 {
+            tprintln!(ctx, "usage: token add-pair-liquidity <poolId> <amountAMaxRaw> <amountBMaxRaw> <minSharesOut> [senderAddress]");
+            tprintln!(ctx, "note: amounts follow the pool's asset order; unused input stays with the sender");
+            return Ok(());
+        }
+
+        let account = ctx.wallet().account()?;
+        let rpc = ctx.wallet().rpc_api().clone();
+
+        let pool_id = Self::normalize_asset_id(argv.remove(0).as_str());
+        let amount_a_max = Self::parse_positive_u128(argv.remove(0).as_str(), "amountAMaxRaw")?;
+        let amount_b_max = Self::parse_positive_u128(argv.remove(0).as_str(), "amountBMaxRaw")?;
+        let min_shares_out = Self::parse_u128(argv.remove(0).as_str(), "minSharesOut")?;
+        let sender_address =
+            if let Some(sender) = argv.first() { Address::try_from(sender.as_str())? } else { account.receive_address()? };
+
+        let operation = TokenOperation::AddPairLiquidity { pool_id: pool_id.clone(), amount_a_max, amount_b_max, min_shares_out };
+        let (payload, nonce) = Self::encode_operation(&rpc, &operation, &sender_address).await?;
+
+        let (summary, ids) = Self::submit_payload_tx(&ctx, &account, payload, sender_address.clone()).await?;
+        tprintln!(ctx, "Token add-pair-liquidity - {summary}");
+        tprintln!(
+            ctx,
+            "pool={} amountAMax={} amountBMax={} minSharesOut={} sender={} nonce={}",
+            style(pool_id).dim(),
+            amount_a_max,
+            amount_b_max,
+            min_shares_out,
+            sender_address,
+            nonce
+        );
+        tprintln!(ctx, "carrier output: {} sompi to sender address", TOKEN_CARRIER_OUTPUT_SOMPI);
+        tprintln!(ctx, "tx ids:");
+        for id in ids {
+            tprintln!(ctx, "  {id}");
+        }
+
+        Ok(())
+    }
+
+    async fn remove_pair_liquidity(self: Arc<Self>, ctx: Arc<CryptixCli>, mut argv: Vec<String>) -> Result<()> {
+        if argv.len() < 4 || argv.len() > 5 {
+            tprintln!(
+                ctx,
+                "usage: token remove-pair-liquidity <poolId> <shares> <minAmountAOutRaw> <minAmountBOutRaw> [senderAddress]"
+            );
+            return Ok(());
+        }
+
+        let account = ctx.wallet().account()?;
+        let rpc = ctx.wallet().rpc_api().clone();
+
+        let pool_id = Self::normalize_asset_id(argv.remove(0).as_str());
+        let shares = Self::parse_positive_u128(argv.remove(0).as_str(), "shares")?;
+        let min_amount_a_out = Self::parse_u128(argv.remove(0).as_str(), "minAmountAOutRaw")?;
+        let min_amount_b_out = Self::parse_u128(argv.remove(0).as_str(), "minAmountBOutRaw")?;
+        let sender_address =
+            if let Some(sender) = argv.first() { Address::try_from(sender.as_str())? } else { account.receive_address()? };
+
+        let operation = TokenOperation::RemovePairLiquidity { pool_id: pool_id.clone(), shares, min_amount_a_out, min_amount_b_out };
+        let (payload, nonce) = Self::encode_operation(&rpc, &operation, &sender_address).await?;
+
+        let (summary, ids) = Self::submit_payload_tx(&ctx, &account, payload, sender_address.clone()).await?;
+        tprintln!(ctx, "Token remove-pair-liquidity - {summary}");
+        tprintln!(
+            ctx,
+            "pool={} shares={} minAmountAOut={} minAmountBOut={} sender={} nonce={}",
+            style(pool_id).dim(),
+            shares,
+            min_amount_a_out,
+            min_amount_b_out,
+            sender_address,
+            nonce
+        );
+        tprintln!(ctx, "carrier output: {} sompi to sender address", TOKEN_CARRIER_OUTPUT_SOMPI);
+        tprintln!(ctx, "tx ids:");
+        for id in ids {
+            tprintln!(ctx, "  {id}");
+        }
+
+        Ok(())
+    }
 
-        let vault_value = Self::pool_vault_value(&pool)?
-            .checked_sub(quoted_cpay_out)
-            .ok_or_else(|| Error::custom("vault value underflows after sell"))?;
-        let destination = Self::liquidity_vault_and_payout_destination(vault_value, quoted_cpay_out, &sender_address);
-        let vault_entry = Self::liquidity_vault_utxo_entry(&pool)?;
-        let (summary, ids) =
-            Self::submit_liquidity_transition_tx(&ctx, &account, payload, destination, sender_address.clone(), vault_entry).await?;
+    async fn swap_pair_in(self: Arc<Self>, ctx: Arc<CryptixCli>, mut argv: Vec<String>) -> Result<()> {
+        if argv.len() < 4 || argv.len() > 5 {
+            tprintln!(ctx, "usage: token swap-pair-in <poolId> <assetInId> <amountInRaw> <minAmountOutRaw> [senderAddress]");
+            tprintln!(ctx, "tip: `rpc get-pair-quote <poolId> <assetInId> in <amountInRaw>` previews the output");
+            return Ok(());
+        }
 
-        tprintln!(ctx, "Token sell-liquidity - {summary}");
+        let account = ctx.wallet().account()?;
+        let rpc = ctx.wallet().rpc_api().clone();
+
+        let pool_id = Self::normalize_asset_id(argv.remove(0).as_str());
+        let asset_in_id = Self::normalize_asset_id(argv.remove(0).as_str());
+        let amount_in = Self::parse_positive_u128(argv.remove(0).as_str(), "amountInRaw")?;
+        let min_amount_out = Self::parse_u128(argv.remove(0).as_str(), "minAmountOutRaw")?;
+        let sender_address =
+            if let Some(sender) = argv.first() { Address::try_from(sender.as_str())? } else { account.receive_address()? };
+
+        let operation =
+            TokenOperation::SwapPairExactIn { pool_id: pool_id.clone(), asset_in_id: asset_in_id.clone(), amount_in, min_amount_out };
+        let (payload, nonce) = Self::encode_operation(&rpc, &operation, &sender_address).await?;
+
+        let (summary, ids) = Self::submit_payload_tx(&ctx, &account, payload, sender_address.clone()).await?;
+        tprintln!(ctx, "Token swap-pair-in - {summary}");
         tprintln!(
             ctx,
-            "asset={} tokenIn={} cpayOutSompi={} minCpayOutSompi={} sender={} poolNonce={} nonce={}",
-            style(asset_id).dim(),
-            token_in,
-            quoted_cpay_out,
-            min_cpay_out_sompi,
+            "pool={} assetIn={} amountIn={} minAmountOut={} sender={} nonce={}",
+            style(pool_id).dim(),
+            style(asset_in_id).dim(),
+            amount_in,
+            min_amount_out,
             sender_address,
-            pool.pool_nonce,
             nonce
         );
+        tprintln!(ctx, "carrier output: {} sompi to sender address", TOKEN_CARRIER_OUTPUT_SOMPI);
         tprintln!(ctx, "tx ids:");
         for id in ids {
             tprintln!(ctx, "  {id}");
         }
+
         Ok(())
     }
 
-    async fn claim_liquidity(self: Arc<Self>, ctx: Arc<CryptixCli>, mut argv: Vec<String>) -> Result<()> {
-        if argv.len() < 3 || argv.len() > 4 {
-            tprintln!(ctx, "usage: token claim-liquidity <assetId> <recipientIndex> <claimAmountSompi> [senderAddress]");
+    async fn swap_pair_out(self: Arc<Self>, ctx: Arc<CryptixCli>, mut argv: Vec<String>) -> Result<()> {
+        if argv.len() < 4 || argv.len() > 5 {
+            tprintln!(ctx, "usage: token swap-pair-out <poolId> <assetOutId> <amountOutRaw> <maxAmountInRaw> [senderAddress]");
             return Ok(());
         }
 
         let account = ctx.wallet().account()?;
         let rpc = ctx.wallet().rpc_api().clone();
-        let asset_id = Self::normalize_asset_id(argv.remove(0).as_str());
-        let recipient_index =
-            argv.remove(0).parse::<u8>().map_err(|err| Error::custom(format!("recipientIndex must fit into u8: {err}")))?;
-        let claim_amount_sompi = Self::parse_positive_u64(argv.remove(0).as_str(), "claimAmountSompi")?;
+
+        let pool_id = Self::normalize_asset_id(argv.remove(0).as_str());
+        let asset_out_id = Self::normalize_asset_id(argv.remove(0).as_str());
+        let amount_out = Self::parse_positive_u128(argv.remove(0).as_str(), "amountOutRaw")?;
+        let max_amount_in = Self::parse_positive_u128(argv.remove(0).as_str(), "maxAmountInRaw")?;
         let sender_address =
             if let Some(sender) = argv.first() { Address::try_from(sender.as_str())? } else { account.receive_address()? };
 
-        let pool = Self::fetch_liquidity_pool(&rpc, asset_id.as_str()).await?;
-        Self::ensure_liquidity_outflow_unlocked(&pool, "liquidity fee claim")?;
-        let sender_owner_id = Self::resolve_owner_id(&rpc, &sender_address, "senderAddress").await?;
-        let nonce = Self::resolve_sender_nonce(&rpc, sender_owner_id.as_str(), Some(asset_id.as_str())).await?;
-        let payload = Self::build_claim_liquidity_payload(
-            asset_id.as_str(),
-            pool.pool_nonce,
-            recipient_index,
-            claim_amount_sompi,
-            1,
-            nonce,
-            LIQUIDITY_AUTH_INPUT_INDEX,
-        )?;
-
-        let vault_value = Self::pool_vault_value(&pool)?
-            .checked_sub(claim_amount_sompi)
-            .ok_or_else(|| Error::custom("vault value underflows after claim"))?;
-        let destination = Self::liquidity_vault_and_payout_destination(vault_value, claim_amount_sompi, &sender_address);
-        let vault_entry = Self::liquidity_vault_utxo_entry(&pool)?;
-        let (summary, ids) =
-            Self::submit_liquidity_transition_tx(&ctx, &account, payload, destination, sender_address.clone(), vault_entry).await?;
+        let operation = TokenOperation::SwapPairExactOut {
+            pool_id: pool_id.clone(),
+            asset_out_id: asset_out_id.clone(),
+            amount_out,
+            max_amount_in,
+        };
+        let (payload, nonce) = Self::encode_operation(&rpc, &operation, &sender_address).await?;
 
-        tprintln!(ctx, "Token claim-liquidity - {summary}");
+        let (summary, ids) = Self::submit_payload_tx(&ctx, &account, payload, sender_address.clone()).await?;
+        tprintln!(ctx, "Token swap-pair-out - {summary}");
         tprintln!(
             ctx,
-            "asset={} recipientIndex={} claimAmountSompi={} sender={} poolNonce={} nonce={}",
-            style(asset_id).dim(),
-            recipient_index,
-            claim_amount_sompi,
+            "pool={} assetOut={} amountOut={} maxAmountIn={} sender={} nonce={}",
+            style(pool_id).dim(),
+            style(asset_out_id).dim(),
+            amount_out,
+            max_amount_in,
             sender_address,
-            pool.pool_nonce,
             nonce
         );
+        tprintln!(ctx, "carrier output: {} sompi to sender address", TOKEN_CARRIER_OUTPUT_SOMPI);
         tprintln!(ctx, "tx ids:");
         for id in ids {
             tprintln!(ctx, "  {id}");
         }
+
         Ok(())
     }
 
@@ -837,6 +1437,143 @@ impl Token {
         }
     }
 
+    /// Resolves the owner ids and the sender nonce of a transfer and encodes its payload.
+    pub(crate) async fn prepare_transfer_payload(
+        rpc: &Arc<DynRpcApi>,
+        asset_id: &str,
+        recipient_address: &Address,
+        amount: u128,
+        sender_address: &Address,
+    ) -> Result<(Vec<u8>, u64)> {
+        let operation = TokenOperation::Transfer { asset_id: asset_id.to_string(), to: recipient_address.clone(), amount };
+        Self::encode_operation(rpc, &operation, sender_address).await
+    }
+
+    /// Resolves the owner ids and the sender nonce of a mint and encodes its payload.
+    pub(crate) async fn prepare_mint_payload(
+        rpc: &Arc<DynRpcApi>,
+        asset_id: &str,
+        recipient_address: &Address,
+        amount: u128,
+        sender_address: &Address,
+    ) -> Result<(Vec<u8>, u64)> {
+        let operation = TokenOperation::Mint { asset_id: asset_id.to_string(), to: recipient_address.clone(), amount };
+        Self::encode_operation(rpc, &operation, sender_address).await
+    }
+
+    /// Quotes a liquidity buy of up to `cpay_budget_sompi` and builds its pool transition.
+    pub(crate) async fn prepare_buy_liquidity(
+        rpc: &Arc<DynRpcApi>,
+        asset_id: &str,
+        cpay_budget_sompi: u64,
+        min_token_out: u128,
+        sender_address: &Address,
+    ) -> Result<LiquidityTrade> {
+        let asset_id = Self::normalize_asset_id(asset_id);
+        let pool = Self::fetch_liquidity_pool(rpc, asset_id.as_str()).await?;
+        let quote = rpc
+            .get_liquidity_quote_call(
+                None,
+                GetLiquidityQuoteRequest {
+                    asset_id: asset_id.clone(),
+                    side: LIQUIDITY_QUOTE_SIDE_BUY,
+                    exact_in_amount: cpay_budget_sompi.to_string(),
+                    at_block_hash: None,
+                },
+            )
+            .await?;
+        let quoted_token_out = Self::parse_u128(quote.amount_out.as_str(), "quote.amountOut")?;
+        let cpay_in_sompi = Self::parse_positive_u64(quote.exact_in_amount.as_str(), "quote.exactInAmount")?;
+        if cpay_in_sompi > cpay_budget_sompi {
+            return Err(Error::custom(format!(
+                "canonical buy input exceeds provided budget: canonical={} budget={}",
+                cpay_in_sompi, cpay_budget_sompi
+            )));
+        }
+        if quoted_token_out < min_token_out {
+            return Err(Error::custom(format!(
+                "minTokenOutRaw is above current quote: min={} quote={}",
+                min_token_out, quoted_token_out
+            )));
+        }
+
+        let operation = TokenOperation::BuyLiquidityExactIn {
+            asset_id: asset_id.clone(),
+            expected_pool_nonce: pool.pool_nonce,
+            cpay_in_sompi,
+            min_token_out,
+        };
+        let (payload, nonce) = Self::encode_operation(rpc, &operation, sender_address).await?;
+
+        let vault_value = Self::pool_vault_value(&pool)?
+            .checked_add(cpay_in_sompi)
+            .ok_or_else(|| Error::custom("vault value overflows u64 after buy"))?;
+        Ok(LiquidityTrade {
+            payload,
+            destination: Self::liquidity_vault_destination(vault_value),
+            vault_entry: Self::liquidity_vault_utxo_entry(&pool)?,
+            asset_id,
+            amount_in: u128::from(cpay_in_sompi),
+            amount_out: quoted_token_out,
+            pool_nonce: pool.pool_nonce,
+            nonce,
+        })
+    }
+
+    /// Quotes a liquidity sell of `token_in` and builds its pool transition paying out to the sender.
+    pub(crate) async fn prepare_sell_liquidity(
+        rpc: &Arc<DynRpcApi>,
+        asset_id: &str,
+        token_in: u128,
+        min_cpay_out_sompi: u64,
+        sender_address: &Address,
+    ) -> Result<LiquidityTrade> {
+        let asset_id = Self::normalize_asset_id(asset_id);
+        let pool = Self::fetch_liquidity_pool(rpc, asset_id.as_str()).await?;
+        Self::ensure_liquidity_outflow_unlocked(&pool, "liquidity sell")?;
+        let quote = rpc
+            .get_liquidity_quote_call(
+                None,
+                GetLiquidityQuoteRequest {
+                    asset_id: asset_id.clone(),
+                    side: LIQUIDITY_QUOTE_SIDE_SELL,
+                    exact_in_amount: token_in.to_string(),
+                    at_block_hash: None,
+                },
+            )
+            .await?;
+        let quoted_cpay_out = Self::parse_u64(quote.amount_out.as_str(), "quote.amountOut")?;
+        if quoted_cpay_out < min_cpay_out_sompi {
+            return Err(Error::custom(format!(
+                "minCpayOutSompi is above current quote: min={} quote={}",
+                min_cpay_out_sompi, quoted_cpay_out
+            )));
+        }
+
+        let operation = TokenOperation::SellLiquidityExactIn {
+            asset_id: asset_id.clone(),
+            expected_pool_nonce: pool.pool_nonce,
+            token_in,
+            min_cpay_out_sompi,
+            cpay_receive_output_index: 1,
+        };
+        let (payload, nonce) = Self::encode_operation(rpc, &operation, sender_address).await?;
+
+        let vault_value = Self::pool_vault_value(&pool)?
+            .checked_sub(quoted_cpay_out)
+            .ok_or_else(|| Error::custom("vault value underflows after sell"))?;
+        Ok(LiquidityTrade {
+            payload,
+            destination: Self::liquidity_vault_and_payout_destination(vault_value, quoted_cpay_out, sender_address),
+            vault_entry: Self::liquidity_vault_utxo_entry(&pool)?,
+            asset_id,
+            amount_in: token_in,
+            amount_out: u128::from(quoted_cpay_out),
+            pool_nonce: pool.pool_nonce,
+            nonce,
+        })
+    }
+
     async fn submit_payload_tx(
         ctx: &Arc<CryptixCli>,
         account: &Arc<dyn Account>,
@@ -970,36 +1707,10 @@ impl Token {
         }
     }
 
-    async fn resolve_sender_nonce(rpc: &Arc<DynRpcApi>, sender_owner_id: &str, asset_id: Option<&str>) -> Result<u64> {
-        let nonce_response = rpc
-            .get_token_nonce_call(
-                None,
-                GetTokenNonceRequest {
-                    owner_id: sender_owner_id.to_string(),
-                    asset_id: asset_id.map(ToString::to_string),
-                    at_block_hash: None,
-                },
-            )
-            .await?;
-        let nonce = nonce_response.expected_next_nonce;
-        if nonce == 0 {
-            return Err(Error::custom("RPC returned expectedNextNonce=0; CAT nonce must be greater than zero"));
-        }
-        Ok(nonce)
-    }
-
-    async fn resolve_owner_id(rpc: &Arc<DynRpcApi>, address: &Address, label: &str) -> Result<String> {
-        let response = rpc
-            .get_token_owner_id_by_address_call(
-                None,
-                GetTokenOwnerIdByAddressRequest { address: address.to_string(), at_block_hash: None },
-            )
-            .await?;
-
-        response.owner_id.ok_or_else(|| {
-            let reason = response.reason.unwrap_or_else(|| "owner id not derivable for address".to_string());
-            Error::custom(format!("{label} {} is not usable for token operations: {reason}", address))
-        })
+    /// Resolves the owner ids and the sender nonce of `operation` and encodes its CAT payload.
+    async fn encode_operation(rpc: &Arc<DynRpcApi>, operation: &TokenOperation, sender_address: &Address) -> Result<(Vec<u8>, u64)> {
+        let TokenOperationPayload { payload, nonce, .. } = operation.resolve(rpc, sender_address, None).await?;
+        Ok((payload, nonce))
     }
 
     fn parse_balance_args(argv: Vec<String>, allow_interval: bool) -> Result<(Vec<Address>, Option<HashSet<String>>, u64, bool)> {
@@ -1203,18 +1914,25 @@ impl Token {
         deltas
     }
 
-    fn parse_supply_mode(value: &str) -> Result<u8> {
+    fn parse_supply_mode(value: &str) -> Result<TokenSupplyMode> {
         match value.to_ascii_lowercase().as_str() {
-            "0" | "uncapped" => Ok(0),
-            "1" | "capped" => Ok(1),
+            "0" | "uncapped" => Ok(TokenSupplyMode::Uncapped),
+            "1" | "capped" => Ok(TokenSupplyMode::Capped),
             _ => Err(Error::custom("supply mode must be `uncapped|capped` or `0|1`")),
         }
     }
 
-    fn validate_supply_mode(supply_mode: u8, max_supply: u128) -> Result<()> {
+    fn supply_mode_label(supply_mode: TokenSupplyMode) -> &'static str {
+        match supply_mode {
+            TokenSupplyMode::Uncapped => "uncapped",
+            TokenSupplyMode::Capped => "capped",
+        }
+    }
+
+    fn validate_supply_mode(supply_mode: TokenSupplyMode, max_supply: u128) -> Result<()> {
         match supply_mode {
-            0 if max_supply != 0 => Err(Error::custom("maxSupplyRaw must be 0 for uncapped supply mode")),
-            1 if max_supply == 0 => Err(Error::custom("maxSupplyRaw must be > 0 for capped supply mode")),
+            TokenSupplyMode::Uncapped if max_supply != 0 => Err(Error::custom("maxSupplyRaw must be 0 for uncapped supply mode")),
+            TokenSupplyMode::Capped if max_supply == 0 => Err(Error::custom("maxSupplyRaw must be > 0 for capped supply mode")),
             _ => Ok(()),
         }
     }
@@ -1388,7 +2106,7 @@ impl Token {
         if !(10..=80).contains(&tenths) {
             return Err(Error::custom("--liquidity-individual-fixed-cpay-million must be between 1.0 and 8.0"));
         }
-        tenths.checked_mul(INDIVIDUAL_VIRTUAL_CPAY_STEP_SOMPI).ok_or_else(|| Error::custom("individual fixed CPAY overflows"))
+        tenths.checked_mul(CAT_INDIVIDUAL_VIRTUAL_CPAY_STEP_SOMPI).ok_or_else(|| Error::custom("individual fixed CPAY overflows"))
     }
 
     fn parse_individual_supply_multiplier_bps(value: &str) -> Result<u16> {
@@ -1427,7 +2145,7 @@ impl Token {
         whole.checked_mul(scale).and_then(|value| value.checked_add(frac)).ok_or_else(|| Error::custom(format!("{label} overflows")))
     }
 
-    fn parse_liquidity_recipients_csv(csv: &str) -> Result<Vec<LiquidityRecipient>> {
+    fn parse_liquidity_recipients_csv(csv: &str) -> Result<Vec<Address>> {
         if csv.trim().is_empty() {
             return Ok(vec![]);
         }
@@ -1444,20 +2162,18 @@ impl Token {
                     address.version
                 )));
             }
-            recipients.push(LiquidityRecipient { address_version: address.version as u8, address_payload: address.payload.to_vec() });
+            recipients.push(address);
         }
 
         if recipients.len() > CAT_MAX_LIQUIDITY_RECIPIENTS {
             return Err(Error::custom(format!("recipient list supports at most {CAT_MAX_LIQUIDITY_RECIPIENTS} entries")));
         }
         if recipients.len() == 2 {
-            if recipients[0].address_version == recipients[1].address_version
-                && recipients[0].address_payload == recipients[1].address_payload
-            {
+            let key_a = (recipients[0].version as u8, recipients[0].payload.as_slice());
+            let key_b = (recipients[1].version as u8, recipients[1].payload.as_slice());
+            if key_a == key_b {
                 return Err(Error::custom("recipient addresses must not contain duplicates"));
             }
-            let key_a = (recipients[0].address_version, recipients[0].address_payload.as_slice());
-            let key_b = (recipients[1].address_version, recipients[1].address_payload.as_slice());
             if key_a > key_b {
                 return Err(Error::custom("recipient addresses must be in canonical lexicographic order"));
             }
@@ -1489,22 +2205,6 @@ impl Token {
         Ok(())
     }
 
-    fn append_platform_tag_tail(payload: &mut Vec<u8>, platform_tag: &str) -> Result<()> {
-        Self::validate_platform_tag(platform_tag)?;
-        let tag_len = u8::try_from(platform_tag.len()).map_err(|_| Error::custom("platform tag length does not fit into u8"))?;
-        payload.push(tag_len);
-        payload.extend_from_slice(platform_tag.as_bytes());
-        Ok(())
-    }
-
-    fn append_optional_platform_tag_tail(payload: &mut Vec<u8>, platform_tag: &str) -> Result<()> {
-        Self::validate_platform_tag(platform_tag)?;
-        if !platform_tag.is_empty() {
-            Self::append_platform_tag_tail(payload, platform_tag)?;
-        }
-        Ok(())
-    }
-
     fn ensure_liquidity_outflow_unlocked(pool: &RpcLiquidityPoolState, operation: &str) -> Result<()> {
         if pool.sell_locked {
             return Err(Error::custom(format!(
@@ -1527,7 +2227,7 @@ impl Token {
         value.parse::<u128>().map_err(|err| Error::custom(format!("{field_name} must be an unsigned integer: {err}")))
     }
 
-    fn parse_positive_u128(value: &str, field_name: &str) -> Result<u128> {
+    pub(crate) fn parse_positive_u128(value: &str, field_name: &str) -> Result<u128> {
         let parsed = Self::parse_u128(value, field_name)?;
         if parsed == 0 {
             return Err(Error::custom(format!("{field_name} must be greater than zero")));
@@ -1539,7 +2239,7 @@ impl Token {
         value.parse::<u64>().map_err(|err| Error::custom(format!("{field_name} must be an unsigned integer: {err}")))
     }
 
-    fn parse_positive_u64(value: &str, field_name: &str) -> Result<u64> {
+    pub(crate) fn parse_positive_u64(value: &str, field_name: &str) -> Result<u64> {
         let parsed = Self::parse_u64(value, field_name)?;
         if parsed == 0 {
             return Err(Error::custom(format!("{field_name} must be greater than zero")));
@@ -1563,209 +2263,6 @@ impl Token {
         value.trim().strip_prefix("0x").unwrap_or(value.trim()).to_lowercase()
     }
 
-    fn build_header(op: u8, nonce: u64, auth_input_index: u16) -> Result<Vec<u8>> {
-        if nonce == 0 {
-            return Err(Error::custom("nonce must be greater than zero"));
-        }
-
-        let mut payload = Vec::with_capacity(64);
-        payload.extend_from_slice(&CAT_MAGIC);
-        payload.push(CAT_VERSION);
-        payload.push(op);
-        payload.push(CAT_FLAGS);
-        payload.extend_from_slice(&auth_input_index.to_le_bytes());
-        payload.extend_from_slice(&nonce.to_le_bytes());
-        Ok(payload)
-    }
-
-    fn push_create_common_fields(
-        payload: &mut Vec<u8>,
-        name: &str,
-        symbol: &str,
-        decimals: u8,
-        supply_mode: u8,
-        max_supply: u128,
-        mint_authority_owner_id: &str,
-        metadata: &[u8],
-    ) -> Result<()> {
-        let mint_authority_owner_id = Self::parse_hex_32(mint_authority_owner_id, "mintAuthorityOwnerId")?;
-        payload.push(CAT_CURRENT_TOKEN_VERSION);
-        payload.push(decimals);
-        payload.push(supply_mode);
-        payload.extend_from_slice(&max_supply.to_le_bytes());
-        payload.extend_from_slice(&mint_authority_owner_id);
-        payload.push(name.len() as u8);
-        payload.push(symbol.len() as u8);
-        payload.extend_from_slice(&(metadata.len() as u16).to_le_bytes());
-        payload.extend_from_slice(name.as_bytes());
-        payload.extend_from_slice(symbol.as_bytes());
-        payload.extend_from_slice(metadata);
-        Ok(())
-    }
-
-    fn build_transfer_payload(asset_id: &str, to_owner_id: &str, amount: u128, nonce: u64, auth_input_index: u16) -> Result<Vec<u8>> {
-        let asset_id = Self::parse_hex_32(asset_id, "assetId")?;
-        let to_owner_id = Self::parse_hex_32(to_owner_id, "toOwnerId")?;
-        let mut payload = Self::build_header(CAT_OP_TRANSFER, nonce, auth_input_index)?;
-        payload.extend_from_slice(&asset_id);
-        payload.extend_from_slice(&to_owner_id);
-        payload.extend_from_slice(&amount.to_le_bytes());
-        Ok(payload)
-    }
-
-    fn build_mint_payload(asset_id: &str, to_owner_id: &str, amount: u128, nonce: u64, auth_input_index: u16) -> Result<Vec<u8>> {
-        let asset_id = Self::parse_hex_32(asset_id, "assetId")?;
-        let to_owner_id = Self::parse_hex_32(to_owner_id, "toOwnerId")?;
-        let mut payload = Self::build_header(CAT_OP_MINT, nonce, auth_input_index)?;
-        payload.extend_from_slice(&asset_id);
-        payload.extend_from_slice(&to_owner_id);
-        payload.extend_from_slice(&amount.to_le_bytes());
-        Ok(payload)
-    }
-
-    fn build_burn_payload(asset_id: &str, amount: u128, nonce: u64, auth_input_index: u16) -> Result<Vec<u8>> {
-        let asset_id = Self::parse_hex_32(asset_id, "assetId")?;
-        let mut payload = Self::build_header(CAT_OP_BURN, nonce, auth_input_index)?;
-        payload.extend_from_slice(&asset_id);
-        payload.extend_from_slice(&amount.to_le_bytes());
-        Ok(payload)
-    }
-
-    fn build_create_asset_payload(
-        name: &str,
-        symbol: &str,
-        decimals: u8,
-        supply_mode: u8,
-        max_supply: u128,
-        mint_authority_owner_id: &str,
-        metadata: &[u8],
-        platform_tag: &str,
-        nonce: u64,
-        auth_input_index: u16,
-    ) -> Result<Vec<u8>> {
-        let mut payload = Self::build_header(CAT_OP_CREATE_ASSET, nonce, auth_input_index)?;
-        Self::push_create_common_fields(
-            &mut payload,
-            name,
-            symbol,
-            decimals,
-            supply_mode,
-            max_supply,
-            mint_authority_owner_id,
-            metadata,
-        )?;
-        Self::append_optional_platform_tag_tail(&mut payload, platform_tag)?;
-        Ok(payload)
-    }
-
-    fn build_create_asset_with_mint_payload(
-        name: &str,
-        symbol: &str,
-        decimals: u8,
-        supply_mode: u8,
-        max_supply: u128,
-        mint_authority_owner_id: &str,
-        metadata: &[u8],
-        initial_mint_amount: u128,
-        initial_mint_to_owner_id: &str,
-        platform_tag: &str,
-        nonce: u64,
-        auth_input_index: u16,
-    ) -> Result<Vec<u8>> {
-        let initial_mint_to_owner_id = Self::parse_hex_32(initial_mint_to_owner_id, "initialMintToOwnerId")?;
-        let mut payload = Self::build_header(CAT_OP_CREATE_ASSET_WITH_MINT, nonce, auth_input_index)?;
-        Self::push_create_common_fields(
-            &mut payload,
-            name,
-            symbol,
-            decimals,
-            supply_mode,
-            max_supply,
-            mint_authority_owner_id,
-            metadata,
-        )?;
-        payload.extend_from_slice(&initial_mint_amount.to_le_bytes());
-        payload.extend_from_slice(&initial_mint_to_owner_id);
-        Self::append_optional_platform_tag_tail(&mut payload, platform_tag)?;
-        Ok(payload)
-    }
-
-    fn build_create_liquidity_asset_payload(
-        name: &str,
-        symbol: &str,
-        decimals: u8,
-        max_supply: u128,
-        metadata: &[u8],
-        seed_reserve_sompi: u64,
-        fee_bps: u16,
-        recipients: &[LiquidityRecipient],
-        launch_buy_sompi: u64,
-        launch_buy_min_token_out: u128,
-        platform_tag: &str,
-        liquidity_unlock_target_sompi: u64,
-        curve_mode: u8,
-        individual_virtual_cpay_reserves_sompi: u64,
-        individual_virtual_token_multiplier_bps: u16,
-        nonce: u64,
-        auth_input_index: u16,
-    ) -> Result<Vec<u8>> {
-        if max_supply == 0 {
-            return Err(Error::custom("maxSupplyRaw must be greater than zero"));
-        }
-        if seed_reserve_sompi == 0 {
-            return Err(Error::custom("seedReserveSompi must be greater than zero"));
-        }
-        Self::validate_platform_tag(platform_tag)?;
-        if liquidity_unlock_target_sompi > MAX_SOMPI {
-            return Err(Error::custom(format!("liquidityUnlockTargetSompi must be 0 or <= MAX_SOMPI ({MAX_SOMPI})")));
-        }
-        Self::validate_liquidity_curve_parameters(
-            curve_mode,
-            individual_virtual_cpay_reserves_sompi,
-            individual_virtual_token_multiplier_bps,
-        )?;
-        Self::validate_liquidity_create_parameters(decimals, max_supply, seed_reserve_sompi)?;
-        if recipients.len() > CAT_MAX_LIQUIDITY_RECIPIENTS {
-            return Err(Error::custom(format!("recipient list supports at most {CAT_MAX_LIQUIDITY_RECIPIENTS} entries")));
-        }
-
-        let mut payload = Self::build_header(CAT_OP_CREATE_LIQUIDITY_ASSET, nonce, auth_input_index)?;
-        payload.push(CAT_CURRENT_TOKEN_VERSION);
-        payload.push(CAT_CURRENT_LIQUIDITY_CURVE_VERSION);
-        payload.push(decimals);
-        payload.extend_from_slice(&max_supply.to_le_bytes());
-        payload.push(name.len() as u8);
-        payload.push(symbol.len() as u8);
-        payload.extend_from_slice(&(metadata.len() as u16).to_le_bytes());
-        payload.extend_from_slice(name.as_bytes());
-        payload.extend_from_slice(symbol.as_bytes());
-        payload.extend_from_slice(metadata);
-        payload.extend_from_slice(&seed_reserve_sompi.to_le_bytes());
-        payload.extend_from_slice(&fee_bps.to_le_bytes());
-        payload.push(recipients.len() as u8);
-        for recipient in recipients {
-            payload.push(recipient.address_version);
-            payload.extend_from_slice(recipient.address_payload.as_slice());
-        }
-        payload.extend_from_slice(&launch_buy_sompi.to_le_bytes());
-        payload.extend_from_slice(&launch_buy_min_token_out.to_le_bytes());
-        if !platform_tag.is_empty()
-            || liquidity_unlock_target_sompi > 0
-            || curve_mode != CAT_DEFAULT_LIQUIDITY_CURVE_MODE
-            || individual_virtual_cpay_reserves_sompi != 0
-            || individual_virtual_token_multiplier_bps != 0
-        {
-            Self::append_platform_tag_tail(&mut payload, platform_tag)?;
-            payload.extend_from_slice(&liquidity_unlock_target_sompi.to_le_bytes());
-            payload.push(curve_mode);
-            if curve_mode == CAT_LIQUIDITY_CURVE_MODE_INDIVIDUAL {
-                payload.extend_from_slice(&individual_virtual_cpay_reserves_sompi.to_le_bytes());
-                payload.extend_from_slice(&individual_virtual_token_multiplier_bps.to_le_bytes());
-            }
-        }
-        Ok(payload)
-    }
-
     fn validate_liquidity_curve_mode(curve_mode: u8) -> Result<()> {
         match curve_mode {
             CAT_LIQUIDITY_CURVE_MODE_BASIC | CAT_LIQUIDITY_CURVE_MODE_AGGRESSIVE | CAT_LIQUIDITY_CURVE_MODE_INDIVIDUAL => Ok(()),
@@ -1774,20 +2271,20 @@ impl Token {
     }
 
     fn validate_individual_liquidity_curve_params(virtual_cpay_reserves_sompi: u64, virtual_token_multiplier_bps: u16) -> Result<()> {
-        if !(INDIVIDUAL_MIN_VIRTUAL_CPAY_RESERVES_SOMPI..=INDIVIDUAL_MAX_VIRTUAL_CPAY_RESERVES_SOMPI)
+        if !(CAT_INDIVIDUAL_MIN_VIRTUAL_CPAY_RESERVES_SOMPI..=CAT_INDIVIDUAL_MAX_VIRTUAL_CPAY_RESERVES_SOMPI)
             .contains(&virtual_cpay_reserves_sompi)
         {
             return Err(Error::custom("individual fixed CPAY must be between 1.0M and 8.0M CPAY"));
         }
-        if virtual_cpay_reserves_sompi % INDIVIDUAL_VIRTUAL_CPAY_STEP_SOMPI != 0 {
+        if virtual_cpay_reserves_sompi % CAT_INDIVIDUAL_VIRTUAL_CPAY_STEP_SOMPI != 0 {
             return Err(Error::custom("individual fixed CPAY must use 0.1M CPAY steps"));
         }
-        if !(INDIVIDUAL_MIN_VIRTUAL_TOKEN_MULTIPLIER_BPS..=INDIVIDUAL_MAX_VIRTUAL_TOKEN_MULTIPLIER_BPS)
+        if !(CAT_INDIVIDUAL_MIN_VIRTUAL_TOKEN_MULTIPLIER_BPS..=CAT_INDIVIDUAL_MAX_VIRTUAL_TOKEN_MULTIPLIER_BPS)
             .contains(&virtual_token_multiplier_bps)
         {
             return Err(Error::custom("individual supply multiplier must be between 1.01x and 2.00x"));
         }
-        if virtual_token_multiplier_bps % INDIVIDUAL_VIRTUAL_TOKEN_MULTIPLIER_STEP_BPS != 0 {
+        if virtual_token_multiplier_bps % CAT_INDIVIDUAL_VIRTUAL_TOKEN_MULTIPLIER_STEP_BPS != 0 {
             return Err(Error::custom("individual supply multiplier must use 0.01x steps"));
         }
         Ok(())
@@ -1816,16 +2313,16 @@ impl Token {
     }
 
     fn validate_liquidity_create_parameters(decimals: u8, max_supply: u128, seed_reserve_sompi: u64) -> Result<()> {
-        if decimals != LIQUIDITY_TOKEN_DECIMALS {
-            return Err(Error::custom(format!("liquidity token decimals must be {LIQUIDITY_TOKEN_DECIMALS}")));
+        if decimals != CAT_LIQUIDITY_TOKEN_DECIMALS {
+            return Err(Error::custom(format!("liquidity token decimals must be {CAT_LIQUIDITY_TOKEN_DECIMALS}")));
         }
-        if !(MIN_LIQUIDITY_TOKEN_SUPPLY_RAW..=MAX_LIQUIDITY_TOKEN_SUPPLY_RAW).contains(&max_supply) {
+        if !(CAT_MIN_LIQUIDITY_SUPPLY_RAW..=CAT_MAX_LIQUIDITY_SUPPLY_RAW).contains(&max_supply) {
             return Err(Error::custom(format!(
-                "maxSupplyRaw for liquidity tokens must be between {MIN_LIQUIDITY_TOKEN_SUPPLY_RAW} and {MAX_LIQUIDITY_TOKEN_SUPPLY_RAW}"
+                "maxSupplyRaw for liquidity tokens must be between {CAT_MIN_LIQUIDITY_SUPPLY_RAW} and {CAT_MAX_LIQUIDITY_SUPPLY_RAW}"
             )));
         }
-        if seed_reserve_sompi != MIN_LIQUIDITY_SEED_RESERVE_SOMPI {
-            return Err(Error::custom(format!("seedReserveSompi must be exactly {MIN_LIQUIDITY_SEED_RESERVE_SOMPI} (1 CPAY)")));
+        if seed_reserve_sompi != CAT_MIN_LIQUIDITY_SEED_RESERVE_SOMPI {
+            return Err(Error::custom(format!("seedReserveSompi must be exactly {CAT_MIN_LIQUIDITY_SEED_RESERVE_SOMPI} (1 CPAY)")));
         }
         Ok(())
     }
@@ -1837,7 +2334,7 @@ impl Token {
             CAT_LIQUIDITY_CURVE_MODE_INDIVIDUAL => {
                 Self::validate_individual_liquidity_curve_params(
                     individual_virtual_cpay_reserves_sompi,
-                    INDIVIDUAL_MIN_VIRTUAL_TOKEN_MULTIPLIER_BPS,
+                    CAT_INDIVIDUAL_MIN_VIRTUAL_TOKEN_MULTIPLIER_BPS,
                 )?;
                 Ok(individual_virtual_cpay_reserves_sompi)
             }
@@ -1850,9 +2347,9 @@ impl Token {
         curve_mode: u8,
         individual_virtual_token_multiplier_bps: u16,
     ) -> Result<u128> {
-        if !(MIN_LIQUIDITY_TOKEN_SUPPLY_RAW..=MAX_LIQUIDITY_TOKEN_SUPPLY_RAW).contains(&max_supply) {
+        if !(CAT_MIN_LIQUIDITY_SUPPLY_RAW..=CAT_MAX_LIQUIDITY_SUPPLY_RAW).contains(&max_supply) {
             return Err(Error::custom(format!(
-                "maxSupplyRaw for liquidity tokens must be between {MIN_LIQUIDITY_TOKEN_SUPPLY_RAW} and {MAX_LIQUIDITY_TOKEN_SUPPLY_RAW}"
+                "maxSupplyRaw for liquidity tokens must be between {CAT_MIN_LIQUIDITY_SUPPLY_RAW} and {CAT_MAX_LIQUIDITY_SUPPLY_RAW}"
             )));
         }
         let (numerator, denominator) = match curve_mode {
@@ -1860,7 +2357,7 @@ impl Token {
             CAT_LIQUIDITY_CURVE_MODE_AGGRESSIVE => (21u128, 20u128),
             CAT_LIQUIDITY_CURVE_MODE_INDIVIDUAL => {
                 Self::validate_individual_liquidity_curve_params(
-                    INDIVIDUAL_MIN_VIRTUAL_CPAY_RESERVES_SOMPI,
+                    CAT_INDIVIDUAL_MIN_VIRTUAL_CPAY_RESERVES_SOMPI,
                     individual_virtual_token_multiplier_bps,
                 )?;
                 (u128::from(individual_virtual_token_multiplier_bps), u128::from(VIRTUAL_TOKEN_MULTIPLIER_BPS_DENOMINATOR))
@@ -1974,69 +2471,32 @@ impl Token {
         Self::min_liquidity_gross_input_for_net_input(net_in, fee_bps)
     }
 
-    fn build_buy_liquidity_payload(
-        asset_id: &str,
-        expected_pool_nonce: u64,
-        cpay_in_sompi: u64,
-        min_token_out: u128,
-        nonce: u64,
-        auth_input_index: u16,
-    ) -> Result<Vec<u8>> {
-        let asset_id = Self::parse_hex_32(asset_id, "assetId")?;
-        let mut payload = Self::build_header(CAT_OP_BUY_LIQUIDITY_EXACT_IN, nonce, auth_input_index)?;
-        payload.extend_from_slice(&asset_id);
-        payload.extend_from_slice(&expected_pool_nonce.to_le_bytes());
-        payload.extend_from_slice(&cpay_in_sompi.to_le_bytes());
-        payload.extend_from_slice(&min_token_out.to_le_bytes());
-        Ok(payload)
-    }
-
-    fn build_sell_liquidity_payload(
-        asset_id: &str,
-        expected_pool_nonce: u64,
-        token_in: u128,
-        min_cpay_out_sompi: u64,
-        cpay_receive_output_index: u16,
-        nonce: u64,
-        auth_input_index: u16,
-    ) -> Result<Vec<u8>> {
-        let asset_id = Self::parse_hex_32(asset_id, "assetId")?;
-        let mut payload = Self::build_header(CAT_OP_SELL_LIQUIDITY_EXACT_IN, nonce, auth_input_index)?;
-        payload.extend_from_slice(&asset_id);
-        payload.extend_from_slice(&expected_pool_nonce.to_le_bytes());
-        payload.extend_from_slice(&token_in.to_le_bytes());
-        payload.extend_from_slice(&min_cpay_out_sompi.to_le_bytes());
-        payload.extend_from_slice(&cpay_receive_output_index.to_le_bytes());
-        Ok(payload)
-    }
-
-    fn build_claim_liquidity_payload(
-        asset_id: &str,
-        expected_pool_nonce: u64,
-        recipient_index: u8,
-        claim_amount_sompi: u64,
-        claim_receive_output_index: u16,
-        nonce: u64,
-        auth_input_index: u16,
-    ) -> Result<Vec<u8>> {
-        let asset_id = Self::parse_hex_32(asset_id, "assetId")?;
-        let mut payload = Self::build_header(CAT_OP_CLAIM_LIQUIDITY_FEES, nonce, auth_input_index)?;
-        payload.extend_from_slice(&asset_id);
-        payload.extend_from_slice(&expected_pool_nonce.to_le_bytes());
-        payload.push(recipient_index);
-        payload.extend_from_slice(&claim_amount_sompi.to_le_bytes());
-        payload.extend_from_slice(&claim_receive_output_index.to_le_bytes());
-        Ok(payload)
-    }
-
     async fn display_help(self: Arc<Self>, ctx: Arc<CryptixCli>, _argv: Vec<String>) -> Result<()> {
         tprintln!(ctx, "Token operations:");
         tprintln!(ctx, "  send <assetId> <toAddress> <amountRaw> [senderAddress]");
         tprintln!(ctx, "    Send CAT tokens from an optional specific sender address.");
+        tprintln!(ctx, "  batch-send <assetId> <toAddress:amountRaw[,toAddress:amountRaw...]> [senderAddress]");
+        tprintln!(
+            ctx,
+            "    Send one CAT asset to up to {} recipients in a single all-or-nothing payload.",
+            CAT_MAX_BATCH_TRANSFER_RECIPIENTS
+        );
+        tprintln!(ctx, "  approve <assetId> <spenderAddress> <amountRaw> [expiresDaaScore] [ownerAddress]");
+        tprintln!(ctx, "    Allow a spender to move up to amountRaw of the owner's tokens (0 revokes).");
+        tprintln!(ctx, "  transfer-from <assetId> <fromAddress> <toAddress> <amountRaw> [spenderAddress]");
+        tprintln!(ctx, "    Move approved CAT tokens out of fromAddress as the spender.");
+        tprintln!(ctx, "  lock <assetId> <beneficiaryAddress> <amountRaw> <releaseStartDaa> <releaseEndDaa> [senderAddress]");
+        tprintln!(ctx, "    Move tokens into a beneficiary balance that vests linearly (or at a cliff) by DAA score.");
         tprintln!(ctx, "  mint <assetId> <toAddress> <amountRaw> [senderAddress]");
         tprintln!(ctx, "    Mint CAT tokens to an address (sender must be mint authority).");
         tprintln!(ctx, "  burn <assetId> <amountRaw> [senderAddress]");
         tprintln!(ctx, "    Burn CAT tokens from sender authority.");
+        tprintln!(ctx, "  transfer-mint-authority <assetId> <newAuthorityAddress> [senderAddress]");
+        tprintln!(ctx, "    Hand the asset's mint authority to another address (sender must be mint authority).");
+        tprintln!(ctx, "  renounce-mint-authority <assetId> [senderAddress]");
+        tprintln!(ctx, "    Permanently give up minting for the asset, fixing its supply.");
+        tprintln!(ctx, "  update-metadata <assetId> <metadataHex|-> [platformTag] [senderAddress]");
+        tprintln!(ctx, "    Replace the asset's metadata and platform tag (sender must be the asset creator).");
         tprintln!(
             ctx,
             "  create <name> <symbol> <decimals> <uncapped|capped> <maxSupplyRaw> [--sender=<address>] [--mint-authority=<address>] [--metadata-hex=<hex>] [--platform-tag=<tag>]"
@@ -2056,6 +2516,10 @@ impl Token {
         tprintln!(ctx, "    Buy liquidity tokens with exact CPAY input using the current vault UTXO.");
         tprintln!(ctx, "  sell-liquidity <assetId> <tokenInRaw> <minCpayOutSompi> [senderAddress]");
         tprintln!(ctx, "    Sell exact liquidity-token input and receive CPAY payout.");
+        tprintln!(ctx, "  buy-liquidity-out <assetId> <tokenOutRaw> <maxCpayInSompi> [senderAddress]");
+        tprintln!(ctx, "    Buy an exact liquidity-token amount, spending at most maxCpayInSompi.");
+        tprintln!(ctx, "  sell-liquidity-out <assetId> <cpayOutSompi> <maxTokenInRaw> [senderAddress]");
+        tprintln!(ctx, "    Receive an exact CPAY payout, selling at most maxTokenInRaw liquidity tokens.");
         tprintln!(ctx, "  claim-liquidity <assetId> <recipientIndex> <claimAmountSompi> [senderAddress]");
         tprintln!(ctx, "    Claim accrued liquidity fees for a configured fee recipient.");
         tprintln!(ctx, "  balances <address> [address2 ...] [--assets=<assetId,assetId2>]");
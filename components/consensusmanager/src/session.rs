@@ -5,6 +5,7 @@
 use cryptix_consensus_core::{
     acceptance_data::AcceptanceData,
     api::{BlockCount, BlockValidationFutures, ConsensusApi, ConsensusStats, DynConsensus},
+    atomic::AtomicConsensusBalanceAnchor,
     block::Block,
     blockstatus::BlockStatus,
     daa_score_timestamp::DaaScoreTimestamp,
@@ -366,6 +367,13 @@ impl ConsensusSessionOwned {
         self.clone().spawn_blocking(move |c| c.get_atomic_p2p_token_audit_hash(block_hash)).await
     }
 
+    pub async fn async_get_atomic_consensus_balance_anchor(
+        &self,
+        block_hash: Hash,
+    ) -> ConsensusResult<Option<AtomicConsensusBalanceAnchor>> {
+        self.clone().spawn_blocking(move |c| c.get_atomic_consensus_balance_anchor(block_hash)).await
+    }
+
     pub async fn async_get_block(&self, hash: Hash) -> ConsensusResult<Block> {
         self.clone().spawn_blocking(move |c| c.get_block(hash)).await
     }
@@ -5,6 +5,7 @@ use std::sync::Arc;
 use crate::{
     acceptance_data::AcceptanceData,
     api::args::{TransactionValidationArgs, TransactionValidationBatchArgs},
+    atomic::AtomicConsensusBalanceAnchor,
     block::{Block, BlockTemplate, TemplateBuildMode, TemplateTransactionSelector, VirtualStateApproxId},
     blockstatus::BlockStatus,
     coinbase::MinerData,
@@ -240,6 +241,10 @@ pub trait ConsensusApi: Send + Sync {
         unimplemented!()
     }
 
+    fn get_atomic_consensus_balance_anchor(&self, block_hash: Hash) -> ConsensusResult<Option<AtomicConsensusBalanceAnchor>> {
+        unimplemented!()
+    }
+
     fn import_pruning_point_utxo_set(&self, new_pruning_point: Hash, imported_utxo_multiset: MuHash) -> PruningImportResult<()> {
         unimplemented!()
     }
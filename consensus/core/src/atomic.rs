@@ -0,0 +1,122 @@
+//!
+//! Cryptix Atomic Token (CAT) payload constants and owner id derivation,
+//! shared by consensus, the mempool, the node RPC service, the atomic index
+//! and the wallets so that every encoder and parser agrees on the wire format.
+//!
+
+use crate::constants::SOMPI_PER_CRYPTIX;
+
+pub use cryptix_atomic_proof::{AtomicConsensusBalance, AtomicConsensusBalanceAnchor, AtomicConsensusNamespaceDigest};
+
+pub const CAT_MAGIC: [u8; 3] = *b"CAT";
+pub const CAT_VERSION: u8 = 1;
+pub const CAT_FLAGS: u8 = 0;
+pub const CAT_CURRENT_TOKEN_VERSION: u8 = 1;
+pub const CAT_CURRENT_LIQUIDITY_CURVE_VERSION: u8 = 1;
+
+pub const CAT_OP_CREATE_ASSET: u8 = 0;
+pub const CAT_OP_TRANSFER: u8 = 1;
+pub const CAT_OP_MINT: u8 = 2;
+pub const CAT_OP_BURN: u8 = 3;
+pub const CAT_OP_CREATE_ASSET_WITH_MINT: u8 = 4;
+pub const CAT_OP_CREATE_LIQUIDITY_ASSET: u8 = 5;
+pub const CAT_OP_BUY_LIQUIDITY_EXACT_IN: u8 = 6;
+pub const CAT_OP_SELL_LIQUIDITY_EXACT_IN: u8 = 7;
+pub const CAT_OP_CLAIM_LIQUIDITY_FEES: u8 = 8;
+pub const CAT_OP_APPROVE: u8 = 9;
+pub const CAT_OP_TRANSFER_FROM: u8 = 10;
+pub const CAT_OP_BATCH_TRANSFER: u8 = 11;
+pub const CAT_OP_LOCK_TOKENS: u8 = 12;
+pub const CAT_OP_TRANSFER_MINT_AUTHORITY: u8 = 13;
+pub const CAT_OP_RENOUNCE_MINT_AUTHORITY: u8 = 14;
+pub const CAT_OP_UPDATE_METADATA: u8 = 15;
+pub const CAT_OP_CREATE_PAIR_POOL: u8 = 16;
+pub const CAT_OP_ADD_PAIR_LIQUIDITY: u8 = 17;
+pub const CAT_OP_REMOVE_PAIR_LIQUIDITY: u8 = 18;
+pub const CAT_OP_SWAP_PAIR_EXACT_IN: u8 = 19;
+pub const CAT_OP_SWAP_PAIR_EXACT_OUT: u8 = 20;
+pub const CAT_OP_BUY_LIQUIDITY_EXACT_OUT: u8 = 21;
+pub const CAT_OP_SELL_LIQUIDITY_EXACT_OUT: u8 = 22;
+
+pub const CAT_MAX_NAME_LEN: usize = 32;
+pub const CAT_MAX_SYMBOL_LEN: usize = 10;
+pub const CAT_MAX_METADATA_LEN: usize = 256;
+pub const CAT_MAX_PLATFORM_TAG_LEN: usize = 50;
+pub const CAT_MAX_DECIMALS: u8 = 18;
+/// Upper bound on recipients in one batch transfer. A full batch encodes to
+/// 16 + 32 + 1 + 64 * 48 = 3,121 payload bytes, well inside the 8,192 byte
+/// consensus payload limit; with the payload weight multiplier the payload
+/// accounts for roughly 12.5k mass, leaving headroom under the standard tx mass cap.
+pub const CAT_MAX_BATCH_TRANSFER_RECIPIENTS: usize = 64;
+pub const CAT_MAX_PAIR_FEE_BPS: u16 = 1000;
+
+pub const CAT_LIQUIDITY_CURVE_MODE_BASIC: u8 = 0;
+pub const CAT_LIQUIDITY_CURVE_MODE_AGGRESSIVE: u8 = 1;
+pub const CAT_LIQUIDITY_CURVE_MODE_INDIVIDUAL: u8 = 2;
+pub const CAT_DEFAULT_LIQUIDITY_CURVE_MODE: u8 = CAT_LIQUIDITY_CURVE_MODE_BASIC;
+pub const CAT_INDIVIDUAL_MIN_VIRTUAL_CPAY_RESERVES_SOMPI: u64 = 100_000_000_000_000;
+pub const CAT_INDIVIDUAL_MAX_VIRTUAL_CPAY_RESERVES_SOMPI: u64 = 800_000_000_000_000;
+pub const CAT_INDIVIDUAL_VIRTUAL_CPAY_STEP_SOMPI: u64 = 10_000_000_000_000;
+pub const CAT_INDIVIDUAL_MIN_VIRTUAL_TOKEN_MULTIPLIER_BPS: u16 = 10_100;
+pub const CAT_INDIVIDUAL_MAX_VIRTUAL_TOKEN_MULTIPLIER_BPS: u16 = 20_000;
+pub const CAT_INDIVIDUAL_VIRTUAL_TOKEN_MULTIPLIER_STEP_BPS: u16 = 100;
+pub const CAT_MAX_LIQUIDITY_RECIPIENTS: usize = 2;
+pub const CAT_MIN_LIQUIDITY_FEE_BPS: u16 = 10;
+pub const CAT_MAX_LIQUIDITY_FEE_BPS: u16 = 1000;
+pub const CAT_LIQUIDITY_TOKEN_DECIMALS: u8 = 0;
+pub const CAT_MIN_LIQUIDITY_SUPPLY_RAW: u128 = 100_000;
+pub const CAT_MAX_LIQUIDITY_SUPPLY_RAW: u128 = 10_000_000;
+pub const CAT_MIN_LIQUIDITY_SEED_RESERVE_SOMPI: u64 = SOMPI_PER_CRYPTIX;
+
+pub const CAT_OWNER_DOMAIN: &[u8] = b"CAT_OWNER_V2";
+pub const CAT_OWNER_AUTH_SCHEME_PUBKEY: u8 = 0;
+pub const CAT_OWNER_AUTH_SCHEME_PUBKEY_ECDSA: u8 = 1;
+pub const CAT_OWNER_AUTH_SCHEME_SCRIPT_HASH: u8 = 2;
+
+/// Derives the CAT owner id of a canonical key (or script hash) under `auth_scheme`:
+/// `blake2b-256(CAT_OWNER_V2 || auth_scheme || len_u16_le || key_bytes)`.
+pub fn cat_owner_id(auth_scheme: u8, key_bytes: &[u8]) -> Option<[u8; 32]> {
+    let key_len = u16::try_from(key_bytes.len()).ok()?;
+    let mut hasher = blake2b_simd::Params::new().hash_length(32).to_state();
+    hasher.update(CAT_OWNER_DOMAIN);
+    hasher.update(&[auth_scheme]);
+    hasher.update(&key_len.to_le_bytes());
+    hasher.update(key_bytes);
+    let mut owner_id = [0u8; 32];
+    owner_id.copy_from_slice(hasher.finalize().as_bytes());
+    Some(owner_id)
+}
+
+/// Maps an address version byte and payload length to the owner auth scheme,
+/// returning `None` for addresses that cannot own tokens.
+pub fn cat_owner_auth_scheme(address_version: u8, address_payload_len: usize) -> Option<u8> {
+    match address_version {
+        0 if address_payload_len == 32 => Some(CAT_OWNER_AUTH_SCHEME_PUBKEY),
+        1 if address_payload_len == 33 => Some(CAT_OWNER_AUTH_SCHEME_PUBKEY_ECDSA),
+        8 if address_payload_len == 32 => Some(CAT_OWNER_AUTH_SCHEME_SCRIPT_HASH),
+        _ => None,
+    }
+}
+
+/// Derives the CAT owner id of the address given by its version byte and payload.
+pub fn cat_owner_id_from_address_components(address_version: u8, address_payload: &[u8]) -> Option<[u8; 32]> {
+    cat_owner_id(cat_owner_auth_scheme(address_version, address_payload.len())?, address_payload)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn owner_id_depends_on_address_scheme() {
+        let pubkey = cat_owner_id_from_address_components(0, &[7; 32]).unwrap();
+        let script_hash = cat_owner_id_from_address_components(8, &[7; 32]).unwrap();
+        let ecdsa = cat_owner_id_from_address_components(1, &[7; 33]).unwrap();
+        assert_eq!(pubkey, cat_owner_id(CAT_OWNER_AUTH_SCHEME_PUBKEY, &[7; 32]).unwrap());
+        assert_ne!(pubkey, script_hash);
+        assert_ne!(pubkey, ecdsa);
+        assert_eq!(cat_owner_id_from_address_components(0, &[7; 33]), None);
+        assert_eq!(cat_owner_id_from_address_components(1, &[7; 32]), None);
+        assert_eq!(cat_owner_id_from_address_components(2, &[7; 32]), None);
+    }
+}
@@ -41,6 +41,8 @@ pub struct Config {
     // TODO: move non-consensus parameters like utxoindex to a higher scoped Config
     /// Enable the UTXO index
     pub utxoindex: bool,
+    /// Enable the messenger inbox index over CXM payloads
+    pub messageindex: bool,
     /// Unsafe/testing-only override: allow Atomic snapshot export/import without finality depth sanity check.
     pub atomic_unsafe_skip_snapshot_finality_check: bool,
 
@@ -103,6 +105,7 @@ impl Config {
             is_archival: false,
             enable_sanity_checks: false,
             utxoindex: false,
+            messageindex: false,
             atomic_unsafe_skip_snapshot_finality_check: false,
             unsafe_rpc: false,
             rpc_diagnostics: false,
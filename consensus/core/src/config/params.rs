@@ -86,6 +86,13 @@ pub struct Params {
     /// DAA score from which payload transactions are activated as a consensus rule
     pub payload_hf_activation_daa_score: u64,
 
+    /// DAA score from which transaction introspection and re-enabled arithmetic and byte-string opcodes are activated as a consensus rule
+    pub covenants_activation_daa_score: u64,
+
+    /// DAA score from which the extended CAT ops (allowances, batch transfers, token locks, mint authority changes,
+    /// metadata updates, pair pools and exact-out liquidity trades) are activated as a consensus rule
+    pub atomic_extended_ops_activation_daa_score: u64,
+
     /// Consensus hard cap for payload transaction payload length
     pub payload_max_len_consensus: usize,
 
@@ -381,6 +388,8 @@ pub const MAINNET_PARAMS: Params = Params {
     storage_mass_parameter: STORAGE_MASS_PARAMETER,
     storage_mass_activation_daa_score: u64::MAX,
     payload_hf_activation_daa_score: 33_739_200,
+    covenants_activation_daa_score: u64::MAX,
+    atomic_extended_ops_activation_daa_score: u64::MAX,
     payload_max_len_consensus: 8192,
     payload_max_len_standard: 2048,
     payload_weight_multiplier: 4,
@@ -451,6 +460,8 @@ pub const TESTNET_PARAMS: Params = Params {
     storage_mass_parameter: STORAGE_MASS_PARAMETER,
     storage_mass_activation_daa_score: u64::MAX,
     payload_hf_activation_daa_score: 33_739_200,
+    covenants_activation_daa_score: u64::MAX,
+    atomic_extended_ops_activation_daa_score: u64::MAX,
     payload_max_len_consensus: 8192,
     payload_max_len_standard: 2048,
     payload_weight_multiplier: 4,
@@ -520,6 +531,8 @@ pub const SIMNET_PARAMS: Params = Params {
     storage_mass_parameter: STORAGE_MASS_PARAMETER,
     storage_mass_activation_daa_score: 0,
     payload_hf_activation_daa_score: 33_739_200,
+    covenants_activation_daa_score: 0,
+    atomic_extended_ops_activation_daa_score: 0,
     payload_max_len_consensus: 8192,
     payload_max_len_standard: 2048,
     payload_weight_multiplier: 4,
@@ -575,6 +588,8 @@ pub const DEVNET_PARAMS: Params = Params {
     storage_mass_parameter: STORAGE_MASS_PARAMETER,
     storage_mass_activation_daa_score: u64::MAX,
     payload_hf_activation_daa_score: 33_739_200,
+    covenants_activation_daa_score: u64::MAX,
+    atomic_extended_ops_activation_daa_score: u64::MAX,
     payload_max_len_consensus: 8192,
     payload_max_len_standard: 2048,
     payload_weight_multiplier: 4,
@@ -26,6 +26,9 @@ pub enum ConfigError {
     #[error("Configuration: --hfa-microblock-interval-ms-normal must be greater than 0, got {0}")]
     HfaMicroblockIntervalMsNormalOutOfRange(u64),
 
+    #[error("Configuration: invalid HFA setting: {0}")]
+    HfaConfigInvalid(String),
+
     #[error("Configuration: --tx-relay-broadcast-interval-ms must be greater than 0, got {0}")]
     TxRelayBroadcastIntervalMsOutOfRange(u64),
 
@@ -9,6 +9,7 @@ pub use cryptix_hashes::Hash;
 
 pub mod acceptance_data;
 pub mod api;
+pub mod atomic;
 pub mod block;
 pub mod blockhash;
 pub mod blockstatus;
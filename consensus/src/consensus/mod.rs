@@ -50,6 +50,7 @@ use cryptix_consensus_core::{
         stats::BlockCount,
         BlockValidationFutures, ConsensusApi, ConsensusStats,
     },
+    atomic::AtomicConsensusBalanceAnchor,
     block::{Block, BlockTemplate, TemplateBuildMode, TemplateTransactionSelector, VirtualStateApproxId},
     blockhash::BlockHashExtensions,
     blockstatus::BlockStatus,
@@ -786,6 +787,10 @@ impl ConsensusApi for Consensus {
         self.virtual_processor.get_atomic_p2p_token_audit_hash(block_hash)
     }
 
+    fn get_atomic_consensus_balance_anchor(&self, block_hash: Hash) -> ConsensusResult<Option<AtomicConsensusBalanceAnchor>> {
+        self.virtual_processor.get_atomic_consensus_balance_anchor(block_hash)
+    }
+
     fn import_pruning_point_utxo_set(&self, new_pruning_point: Hash, imported_utxo_multiset: MuHash) -> PruningImportResult<()> {
         self.virtual_processor.import_pruning_point_utxo_set(new_pruning_point, imported_utxo_multiset)
     }
@@ -154,6 +154,8 @@ impl ConsensusServices {
             mass_calculator.clone(),
             params.storage_mass_activation_daa_score,
             params.payload_hf_activation_daa_score,
+            params.covenants_activation_daa_score,
+            params.atomic_extended_ops_activation_daa_score,
             params.payload_max_len_consensus,
         );
 
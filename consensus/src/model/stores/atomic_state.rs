@@ -1,7 +1,14 @@
 use blake2b_simd::Params as Blake2bParams;
 use cryptix_consensus_core::BlockHasher;
 use cryptix_consensus_core::ChainPath;
-use cryptix_consensus_core::{constants::MAX_SOMPI, tx::TransactionOutpoint};
+use cryptix_consensus_core::{
+    atomic::{
+        self, cat_owner_id_from_address_components as atomic_owner_id_from_address_components, AtomicConsensusBalance,
+        AtomicConsensusBalanceAnchor, AtomicConsensusNamespaceDigest,
+    },
+    constants::MAX_SOMPI,
+    tx::TransactionOutpoint,
+};
 use cryptix_database::prelude::CachePolicy;
 use cryptix_database::prelude::DbKey;
 use cryptix_database::prelude::StoreError;
@@ -11,29 +18,42 @@ use cryptix_database::registry::DatabaseStorePrefixes;
 use cryptix_hashes::Hash;
 use cryptix_utils::mem_size::MemSizeEstimator;
 use rocksdb::WriteBatch;
-use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde::{
+    de::{DeserializeOwned, SeqAccess, Visitor},
+    ser::SerializeTuple,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
 use std::collections::{HashMap, HashSet};
+use std::fmt;
 use std::mem::size_of;
 use std::sync::Arc;
 
 const ATOMIC_CONSENSUS_STATE_HASH_DOMAIN: &[u8] = b"cryptix-atomic-consensus-state-root-v2";
 const ATOMIC_STATE_COMMITMENT_DOMAIN: &[u8] = b"cryptix-utxo-atomic-state-commitment-v1";
 const ATOMIC_STATE_ROOT_SUBPREFIX: u8 = b'R';
-const ATOMIC_STATE_DELTA_SUBPREFIX: u8 = b'D';
+/// Deltas written before the allowance and lock namespaces existed, in the `LegacyAtomicConsensusStateDelta` layout
+const ATOMIC_STATE_LEGACY_DELTA_SUBPREFIX: u8 = b'D';
+const ATOMIC_STATE_DELTA_SUBPREFIX: u8 = b'E';
 const ATOMIC_STATE_CURRENT_META_SUBPREFIX: u8 = b'M';
 const ATOMIC_STATE_CURRENT_NONCE_SUBPREFIX: u8 = b'n';
 const ATOMIC_STATE_CURRENT_ASSET_SUBPREFIX: u8 = b'a';
 const ATOMIC_STATE_CURRENT_BALANCE_SUBPREFIX: u8 = b'b';
 const ATOMIC_STATE_CURRENT_ANCHOR_SUBPREFIX: u8 = b'c';
 const ATOMIC_STATE_CURRENT_VAULT_SUBPREFIX: u8 = b'v';
+const ATOMIC_STATE_CURRENT_ALLOWANCE_SUBPREFIX: u8 = b'l';
+const ATOMIC_STATE_CURRENT_LOCK_SUBPREFIX: u8 = b'k';
 const ATOMIC_STATE_CURRENT_ROOT_KEY: &[u8] = b"current-root";
 const ATOMIC_CONSENSUS_STATE_MAGIC: &[u8] = b"CATCSG02";
 const ATOMIC_CONSENSUS_STATE_ROOT_ONLY_TAG: &[u8] = b"ROOT";
 const ATOMIC_CONSENSUS_ROOT_ACCUMULATOR_VERSION: u8 = 2;
+/// Set on the serialized accumulator version byte when the allowance and lock namespaces follow the original four
+const ATOMIC_ROOT_ACCUMULATOR_EXTENDED_LAYOUT_FLAG: u8 = 0x80;
 const ATOMIC_ROOT_NAMESPACE_NONCE: u8 = b'n';
 const ATOMIC_ROOT_NAMESPACE_ASSET: u8 = b'a';
 const ATOMIC_ROOT_NAMESPACE_BALANCE: u8 = b'b';
 const ATOMIC_ROOT_NAMESPACE_ANCHOR: u8 = b'c';
+const ATOMIC_ROOT_NAMESPACE_ALLOWANCE: u8 = b'l';
+const ATOMIC_ROOT_NAMESPACE_LOCK: u8 = b'k';
 const ATOMIC_P2P_ROOT_BUCKETS: usize = 4096;
 const ATOMIC_P2P_ROOT_LEAF_DOMAIN: &[u8] = b"CRYPTIX_ATOMIC_V2_LEAF";
 const ATOMIC_P2P_ROOT_BUCKET_DOMAIN: &[u8] = b"CRYPTIX_ATOMIC_V2_BUCKETED_ROOT";
@@ -42,32 +62,32 @@ const ATOMIC_P2P_ASSET_ROOT_V1: &[u8] = b"CAT_ASSET_P2P_AUDIT_ROOT_V1";
 const ATOMIC_P2P_LOGICAL_ASSET: u8 = 0x01;
 const ATOMIC_P2P_LOGICAL_BALANCE: u8 = 0x02;
 const ATOMIC_P2P_LOGICAL_NONCE: u8 = 0x03;
-pub const ATOMIC_CURRENT_TOKEN_VERSION: u8 = 1;
-pub const ATOMIC_CURRENT_LIQUIDITY_CURVE_VERSION: u8 = 1;
-pub const ATOMIC_LIQUIDITY_CURVE_MODE_BASIC: u8 = 0;
-pub const ATOMIC_LIQUIDITY_CURVE_MODE_AGGRESSIVE: u8 = 1;
-pub const ATOMIC_LIQUIDITY_CURVE_MODE_INDIVIDUAL: u8 = 2;
-pub const ATOMIC_DEFAULT_LIQUIDITY_CURVE_MODE: u8 = ATOMIC_LIQUIDITY_CURVE_MODE_BASIC;
-const ATOMIC_INDIVIDUAL_MIN_VIRTUAL_CPAY_RESERVES_SOMPI: u64 = 100_000_000_000_000;
-const ATOMIC_INDIVIDUAL_MAX_VIRTUAL_CPAY_RESERVES_SOMPI: u64 = 800_000_000_000_000;
-const ATOMIC_INDIVIDUAL_VIRTUAL_CPAY_STEP_SOMPI: u64 = 10_000_000_000_000;
-const ATOMIC_INDIVIDUAL_MIN_VIRTUAL_TOKEN_MULTIPLIER_BPS: u16 = 10_100;
-const ATOMIC_INDIVIDUAL_MAX_VIRTUAL_TOKEN_MULTIPLIER_BPS: u16 = 20_000;
-const ATOMIC_INDIVIDUAL_VIRTUAL_TOKEN_MULTIPLIER_STEP_BPS: u16 = 100;
+const ATOMIC_P2P_LOGICAL_ALLOWANCE: u8 = 0x05;
+const ATOMIC_P2P_LOGICAL_LOCK: u8 = 0x06;
+pub const ATOMIC_CURRENT_TOKEN_VERSION: u8 = atomic::CAT_CURRENT_TOKEN_VERSION;
+pub const ATOMIC_CURRENT_LIQUIDITY_CURVE_VERSION: u8 = atomic::CAT_CURRENT_LIQUIDITY_CURVE_VERSION;
+pub const ATOMIC_LIQUIDITY_CURVE_MODE_BASIC: u8 = atomic::CAT_LIQUIDITY_CURVE_MODE_BASIC;
+pub const ATOMIC_LIQUIDITY_CURVE_MODE_AGGRESSIVE: u8 = atomic::CAT_LIQUIDITY_CURVE_MODE_AGGRESSIVE;
+pub const ATOMIC_LIQUIDITY_CURVE_MODE_INDIVIDUAL: u8 = atomic::CAT_LIQUIDITY_CURVE_MODE_INDIVIDUAL;
+pub const ATOMIC_DEFAULT_LIQUIDITY_CURVE_MODE: u8 = atomic::CAT_DEFAULT_LIQUIDITY_CURVE_MODE;
+const ATOMIC_INDIVIDUAL_MIN_VIRTUAL_CPAY_RESERVES_SOMPI: u64 = atomic::CAT_INDIVIDUAL_MIN_VIRTUAL_CPAY_RESERVES_SOMPI;
+const ATOMIC_INDIVIDUAL_MAX_VIRTUAL_CPAY_RESERVES_SOMPI: u64 = atomic::CAT_INDIVIDUAL_MAX_VIRTUAL_CPAY_RESERVES_SOMPI;
+const ATOMIC_INDIVIDUAL_VIRTUAL_CPAY_STEP_SOMPI: u64 = atomic::CAT_INDIVIDUAL_VIRTUAL_CPAY_STEP_SOMPI;
+const ATOMIC_INDIVIDUAL_MIN_VIRTUAL_TOKEN_MULTIPLIER_BPS: u16 = atomic::CAT_INDIVIDUAL_MIN_VIRTUAL_TOKEN_MULTIPLIER_BPS;
+const ATOMIC_INDIVIDUAL_MAX_VIRTUAL_TOKEN_MULTIPLIER_BPS: u16 = atomic::CAT_INDIVIDUAL_MAX_VIRTUAL_TOKEN_MULTIPLIER_BPS;
+const ATOMIC_INDIVIDUAL_VIRTUAL_TOKEN_MULTIPLIER_STEP_BPS: u16 = atomic::CAT_INDIVIDUAL_VIRTUAL_TOKEN_MULTIPLIER_STEP_BPS;
 const ATOMIC_MAX_TOKEN_VERSION: u8 = 99;
 const ATOMIC_MAX_LIQUIDITY_CURVE_VERSION: u8 = 99;
-const ATOMIC_OWNER_DOMAIN: &[u8] = b"CAT_OWNER_V2";
-const OWNER_AUTH_SCHEME_PUBKEY: u8 = 0;
-const OWNER_AUTH_SCHEME_PUBKEY_ECDSA: u8 = 1;
-const OWNER_AUTH_SCHEME_SCRIPT_HASH: u8 = 2;
-const MAX_ATOMIC_LIQUIDITY_FEE_RECIPIENTS: usize = 2;
-const MIN_ATOMIC_LIQUIDITY_FEE_BPS: u16 = 10;
-const MAX_ATOMIC_LIQUIDITY_FEE_BPS: u16 = 1000;
-const MAX_ATOMIC_PLATFORM_TAG_LEN: usize = 50;
-const MAX_ATOMIC_NAME_LEN: usize = 32;
-const MAX_ATOMIC_SYMBOL_LEN: usize = 10;
-const MAX_ATOMIC_METADATA_LEN: usize = 256;
-const MAX_ATOMIC_DECIMALS: u8 = 18;
+const MAX_ATOMIC_LIQUIDITY_FEE_RECIPIENTS: usize = atomic::CAT_MAX_LIQUIDITY_RECIPIENTS;
+const MIN_ATOMIC_LIQUIDITY_FEE_BPS: u16 = atomic::CAT_MIN_LIQUIDITY_FEE_BPS;
+const MAX_ATOMIC_LIQUIDITY_FEE_BPS: u16 = atomic::CAT_MAX_LIQUIDITY_FEE_BPS;
+const MAX_ATOMIC_PLATFORM_TAG_LEN: usize = atomic::CAT_MAX_PLATFORM_TAG_LEN;
+const MAX_ATOMIC_NAME_LEN: usize = atomic::CAT_MAX_NAME_LEN;
+const MAX_ATOMIC_SYMBOL_LEN: usize = atomic::CAT_MAX_SYMBOL_LEN;
+const MAX_ATOMIC_METADATA_LEN: usize = atomic::CAT_MAX_METADATA_LEN;
+const MAX_ATOMIC_DECIMALS: u8 = atomic::CAT_MAX_DECIMALS;
+const MAX_ATOMIC_PAIR_FEE_BPS: u16 = atomic::CAT_MAX_PAIR_FEE_BPS;
+pub const ATOMIC_MIN_PAIR_LIQUIDITY_SHARES: u128 = 1000;
 pub const ATOMIC_NONCE_SCOPE_OWNER: u8 = 0;
 pub const ATOMIC_NONCE_SCOPE_ASSET: u8 = 1;
 
@@ -77,6 +97,54 @@ pub struct AtomicBalanceKey {
     pub owner_id: [u8; 32],
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct AtomicAllowanceKey {
+    pub asset_id: [u8; 32],
+    pub owner_id: [u8; 32],
+    pub spender_id: [u8; 32],
+}
+
+/// Remaining amount a spender may move out of an owner's balance. An expiry of `0` never expires,
+/// otherwise the allowance is usable while the accepting DAA score is below `expires_at_daa_score`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AtomicAllowanceState {
+    pub amount: u128,
+    pub expires_at_daa_score: u64,
+}
+
+impl AtomicAllowanceState {
+    pub fn is_expired_at(&self, daa_score: u64) -> bool {
+        self.expires_at_daa_score != 0 && daa_score >= self.expires_at_daa_score
+    }
+}
+
+/// Vesting lock over part of an owner's balance, keyed like the balance it restricts. The locked tokens stay in
+/// the balance but cannot be spent until they release linearly between the start and end DAA scores; equal
+/// scores release everything at once (a cliff).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AtomicTokenLockState {
+    pub amount: u128,
+    pub release_start_daa_score: u64,
+    pub release_end_daa_score: u64,
+}
+
+impl AtomicTokenLockState {
+    /// Amount still locked at `daa_score`. Rounds up so integer division never releases tokens early.
+    pub fn locked_at(&self, daa_score: u64) -> u128 {
+        if daa_score >= self.release_end_daa_score {
+            return 0;
+        }
+        if daa_score <= self.release_start_daa_score {
+            return self.amount;
+        }
+        let span = u128::from(self.release_end_daa_score - self.release_start_daa_score);
+        let remaining = u128::from(self.release_end_daa_score - daa_score);
+        let whole = self.amount / span * remaining;
+        let partial = (self.amount % span * remaining).div_ceil(span);
+        whole + partial
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct AtomicNonceKey {
     pub owner_id: [u8; 32],
@@ -133,6 +201,7 @@ pub enum AtomicAssetClass {
     #[default]
     Standard,
     Liquidity,
+    Pair,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -169,36 +238,127 @@ pub struct AtomicLiquidityPoolState {
     pub unlocked: bool,
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+/// Constant-product pool between two standard assets. Reserves are ordinary balances owned by the pool id
+/// and LP shares are balances of the pool asset itself.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AtomicPairPoolState {
+    pub asset_a_id: [u8; 32],
+    pub asset_b_id: [u8; 32],
+    pub fee_bps: u16,
+    pub accrued_fee_a: u128,
+    pub accrued_fee_b: u128,
+}
+
+impl AtomicPairPoolState {
+    pub fn reserve_key_a(&self, pool_id: [u8; 32]) -> AtomicBalanceKey {
+        AtomicBalanceKey { asset_id: self.asset_a_id, owner_id: pool_id }
+    }
+
+    pub fn reserve_key_b(&self, pool_id: [u8; 32]) -> AtomicBalanceKey {
+        AtomicBalanceKey { asset_id: self.asset_b_id, owner_id: pool_id }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct AtomicAssetState {
-    #[serde(default)]
     pub creator_owner_id: [u8; 32],
-    #[serde(default)]
     pub asset_class: AtomicAssetClass,
-    #[serde(default = "default_atomic_token_version")]
     pub token_version: u8,
     pub mint_authority_owner_id: [u8; 32],
-    #[serde(default)]
     pub decimals: u8,
     pub supply_mode: AtomicSupplyMode,
     pub max_supply: u128,
     pub total_supply: u128,
-    #[serde(default)]
     pub name: Vec<u8>,
-    #[serde(default)]
     pub symbol: Vec<u8>,
-    #[serde(default)]
     pub metadata: Vec<u8>,
-    #[serde(default)]
     pub platform_tag: Vec<u8>,
-    #[serde(default)]
     pub created_block_hash: Option<[u8; 32]>,
-    #[serde(default)]
     pub created_daa_score: Option<u64>,
-    #[serde(default)]
     pub created_at: Option<u64>,
-    #[serde(default)]
     pub liquidity: Option<AtomicLiquidityPoolState>,
+    pub pair_pool: Option<AtomicPairPoolState>,
+}
+
+// Like the canonical state bytes, the bincode layout only carries the pair pool for pair assets, so assets persisted
+// before pair pools existed still decode.
+
+impl Serialize for AtomicAssetState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let is_pair = matches!(self.asset_class, AtomicAssetClass::Pair);
+        let mut tuple = serializer.serialize_tuple(if is_pair { 17 } else { 16 })?;
+        tuple.serialize_element(&self.creator_owner_id)?;
+        tuple.serialize_element(&self.asset_class)?;
+        tuple.serialize_element(&self.token_version)?;
+        tuple.serialize_element(&self.mint_authority_owner_id)?;
+        tuple.serialize_element(&self.decimals)?;
+        tuple.serialize_element(&self.supply_mode)?;
+        tuple.serialize_element(&self.max_supply)?;
+        tuple.serialize_element(&self.total_supply)?;
+        tuple.serialize_element(&self.name)?;
+        tuple.serialize_element(&self.symbol)?;
+        tuple.serialize_element(&self.metadata)?;
+        tuple.serialize_element(&self.platform_tag)?;
+        tuple.serialize_element(&self.created_block_hash)?;
+        tuple.serialize_element(&self.created_daa_score)?;
+        tuple.serialize_element(&self.created_at)?;
+        tuple.serialize_element(&self.liquidity)?;
+        if is_pair {
+            tuple.serialize_element(&self.pair_pool)?;
+        }
+        tuple.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for AtomicAssetState {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct AssetVisitor;
+
+        impl<'de> Visitor<'de> for AssetVisitor {
+            type Value = AtomicAssetState;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("an atomic asset state")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mut asset = AtomicAssetState {
+                    creator_owner_id: next_layout_element(&mut seq)?,
+                    asset_class: next_layout_element(&mut seq)?,
+                    token_version: next_layout_element(&mut seq)?,
+                    mint_authority_owner_id: next_layout_element(&mut seq)?,
+                    decimals: next_layout_element(&mut seq)?,
+                    supply_mode: next_layout_element(&mut seq)?,
+                    max_supply: next_layout_element(&mut seq)?,
+                    total_supply: next_layout_element(&mut seq)?,
+                    name: next_layout_element(&mut seq)?,
+                    symbol: next_layout_element(&mut seq)?,
+                    metadata: next_layout_element(&mut seq)?,
+                    platform_tag: next_layout_element(&mut seq)?,
+                    created_block_hash: next_layout_element(&mut seq)?,
+                    created_daa_score: next_layout_element(&mut seq)?,
+                    created_at: next_layout_element(&mut seq)?,
+                    liquidity: next_layout_element(&mut seq)?,
+                    pair_pool: None,
+                };
+                if matches!(asset.asset_class, AtomicAssetClass::Pair) {
+                    asset.pair_pool = next_layout_element(&mut seq)?;
+                }
+                Ok(asset)
+            }
+        }
+
+        deserializer.deserialize_tuple(17, AssetVisitor)
+    }
 }
 
 #[derive(Clone, Serialize, Deserialize)]
@@ -213,6 +373,10 @@ pub struct AtomicConsensusState {
     pub anchor_counts: HashMap<[u8; 32], u64>,
     #[serde(skip)]
     pub liquidity_vault_outpoints: HashMap<TransactionOutpoint, [u8; 32]>,
+    #[serde(skip)]
+    pub allowances: HashMap<AtomicAllowanceKey, AtomicAllowanceState>,
+    #[serde(skip)]
+    pub locks: HashMap<AtomicBalanceKey, AtomicTokenLockState>,
     #[serde(default)]
     root_accumulator: AtomicConsensusRootAccumulator,
     #[serde(skip)]
@@ -231,6 +395,10 @@ pub struct AtomicConsensusState {
     deleted_anchor_counts: HashSet<[u8; 32]>,
     #[serde(skip)]
     deleted_vault_outpoints: HashSet<TransactionOutpoint>,
+    #[serde(skip)]
+    deleted_allowances: HashSet<AtomicAllowanceKey>,
+    #[serde(skip)]
+    deleted_locks: HashSet<AtomicBalanceKey>,
 }
 
 impl Default for AtomicConsensusState {
@@ -241,6 +409,8 @@ impl Default for AtomicConsensusState {
             balances: HashMap::new(),
             anchor_counts: HashMap::new(),
             liquidity_vault_outpoints: HashMap::new(),
+            allowances: HashMap::new(),
+            locks: HashMap::new(),
             root_accumulator: AtomicConsensusRootAccumulator::default(),
             delta_tracking: false,
             dirty_delta: AtomicConsensusStateDeltaBuilder::default(),
@@ -250,11 +420,13 @@ impl Default for AtomicConsensusState {
             deleted_balances: HashSet::new(),
             deleted_anchor_counts: HashSet::new(),
             deleted_vault_outpoints: HashSet::new(),
+            deleted_allowances: HashSet::new(),
+            deleted_locks: HashSet::new(),
         }
     }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct AtomicConsensusRootAccumulator {
     version: u8,
     nonce_count: u64,
@@ -265,6 +437,10 @@ pub struct AtomicConsensusRootAccumulator {
     balance_xor: [u8; 32],
     anchor_count: u64,
     anchor_xor: [u8; 32],
+    allowance_count: u64,
+    allowance_xor: [u8; 32],
+    lock_count: u64,
+    lock_xor: [u8; 32],
 }
 
 impl Default for AtomicConsensusRootAccumulator {
@@ -279,6 +455,10 @@ impl Default for AtomicConsensusRootAccumulator {
             balance_xor: [0; 32],
             anchor_count: 0,
             anchor_xor: [0; 32],
+            allowance_count: 0,
+            allowance_xor: [0; 32],
+            lock_count: 0,
+            lock_xor: [0; 32],
         }
     }
 }
@@ -300,6 +480,14 @@ impl AtomicConsensusRootAccumulator {
         self.anchor_count
     }
 
+    pub fn allowance_count(&self) -> u64 {
+        self.allowance_count
+    }
+
+    pub fn lock_count(&self) -> u64 {
+        self.lock_count
+    }
+
     fn from_state_maps(state: &AtomicConsensusState) -> Self {
         let mut root = Self::default();
         for (key, value) in state.next_nonces.iter() {
@@ -314,6 +502,12 @@ impl AtomicConsensusRootAccumulator {
         for (owner_id, value) in state.anchor_counts.iter() {
             root.apply_anchor_count(owner_id, None, Some(*value));
         }
+        for (key, value) in state.allowances.iter() {
+            root.apply_allowance(key, None, Some(value));
+        }
+        for (key, value) in state.locks.iter() {
+            root.apply_lock(key, None, Some(value));
+        }
         root
     }
 
@@ -338,12 +532,38 @@ impl AtomicConsensusRootAccumulator {
         hash_u64(&mut hasher, self.anchor_count);
         hasher.update(&self.anchor_xor);
 
+        // Allowances were added after the V2 root was deployed. Leaving the namespace out while it is
+        // empty keeps the root of every state without allowances unchanged.
+        if self.allowance_count != 0 {
+            hash_u8(&mut hasher, ATOMIC_ROOT_NAMESPACE_ALLOWANCE);
+            hash_u64(&mut hasher, self.allowance_count);
+            hasher.update(&self.allowance_xor);
+        }
+        if self.lock_count != 0 {
+            hash_u8(&mut hasher, ATOMIC_ROOT_NAMESPACE_LOCK);
+            hash_u64(&mut hasher, self.lock_count);
+            hasher.update(&self.lock_xor);
+        }
+
         let digest = hasher.finalize();
         let mut out = [0u8; 32];
         out.copy_from_slice(digest.as_bytes());
         out
     }
 
+    fn namespace_digest(&self, namespace: u8) -> AtomicConsensusNamespaceDigest {
+        let (count, xor) = match namespace {
+            ATOMIC_ROOT_NAMESPACE_NONCE => (self.nonce_count, self.nonce_xor),
+            ATOMIC_ROOT_NAMESPACE_ASSET => (self.asset_count, self.asset_xor),
+            ATOMIC_ROOT_NAMESPACE_BALANCE => (self.balance_count, self.balance_xor),
+            ATOMIC_ROOT_NAMESPACE_ANCHOR => (self.anchor_count, self.anchor_xor),
+            ATOMIC_ROOT_NAMESPACE_ALLOWANCE => (self.allowance_count, self.allowance_xor),
+            ATOMIC_ROOT_NAMESPACE_LOCK => (self.lock_count, self.lock_xor),
+            _ => unreachable!("unknown Atomic root namespace"),
+        };
+        AtomicConsensusNamespaceDigest { count, xor }
+    }
+
     fn apply_nonce(&mut self, key: &AtomicNonceKey, old_value: Option<u64>, new_value: Option<u64>) {
         self.apply_entry(
             ATOMIC_ROOT_NAMESPACE_NONCE,
@@ -376,6 +596,32 @@ impl AtomicConsensusRootAccumulator {
         );
     }
 
+    fn apply_allowance(
+        &mut self,
+        key: &AtomicAllowanceKey,
+        old_value: Option<&AtomicAllowanceState>,
+        new_value: Option<&AtomicAllowanceState>,
+    ) {
+        self.apply_entry(
+            ATOMIC_ROOT_NAMESPACE_ALLOWANCE,
+            old_value.map(|value| hash_allowance_entry(key, value)),
+            new_value.map(|value| hash_allowance_entry(key, value)),
+        );
+    }
+
+    fn apply_lock(
+        &mut self,
+        key: &AtomicBalanceKey,
+        old_value: Option<&AtomicTokenLockState>,
+        new_value: Option<&AtomicTokenLockState>,
+    ) {
+        self.apply_entry(
+            ATOMIC_ROOT_NAMESPACE_LOCK,
+            old_value.map(|value| hash_lock_entry(key, value)),
+            new_value.map(|value| hash_lock_entry(key, value)),
+        );
+    }
+
     fn apply_entry(&mut self, namespace: u8, old_hash: Option<[u8; 32]>, new_hash: Option<[u8; 32]>) {
         if old_hash == new_hash {
             return;
@@ -398,17 +644,134 @@ impl AtomicConsensusRootAccumulator {
             ATOMIC_ROOT_NAMESPACE_ASSET => (&mut self.asset_count, &mut self.asset_xor),
             ATOMIC_ROOT_NAMESPACE_BALANCE => (&mut self.balance_count, &mut self.balance_xor),
             ATOMIC_ROOT_NAMESPACE_ANCHOR => (&mut self.anchor_count, &mut self.anchor_xor),
+            ATOMIC_ROOT_NAMESPACE_ALLOWANCE => (&mut self.allowance_count, &mut self.allowance_xor),
+            ATOMIC_ROOT_NAMESPACE_LOCK => (&mut self.lock_count, &mut self.lock_xor),
             _ => unreachable!("unknown atomic root namespace"),
         }
     }
 }
 
+// Accumulators are persisted with bincode, in the virtual state and in the atomic state stores. Like the root hash,
+// their serialized layout only grows the allowance and lock namespaces when one of them is non-empty, so values
+// written before those namespaces existed still decode. Deltas instead keep their original layout under a separate
+// store prefix (see `LegacyAtomicConsensusStateDelta`).
+
+impl AtomicConsensusRootAccumulator {
+    fn has_extended_namespaces(&self) -> bool {
+        self.allowance_count != 0 || self.allowance_xor != [0; 32] || self.lock_count != 0 || self.lock_xor != [0; 32]
+    }
+}
+
+impl Serialize for AtomicConsensusRootAccumulator {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let extended = self.has_extended_namespaces();
+        let mut tuple = serializer.serialize_tuple(if extended { 13 } else { 9 })?;
+        let layout_version = if extended { self.version | ATOMIC_ROOT_ACCUMULATOR_EXTENDED_LAYOUT_FLAG } else { self.version };
+        tuple.serialize_element(&layout_version)?;
+        tuple.serialize_element(&self.nonce_count)?;
+        tuple.serialize_element(&self.nonce_xor)?;
+        tuple.serialize_element(&self.asset_count)?;
+        tuple.serialize_element(&self.asset_xor)?;
+        tuple.serialize_element(&self.balance_count)?;
+        tuple.serialize_element(&self.balance_xor)?;
+        tuple.serialize_element(&self.anchor_count)?;
+        tuple.serialize_element(&self.anchor_xor)?;
+        if extended {
+            tuple.serialize_element(&self.allowance_count)?;
+            tuple.serialize_element(&self.allowance_xor)?;
+            tuple.serialize_element(&self.lock_count)?;
+            tuple.serialize_element(&self.lock_xor)?;
+        }
+        tuple.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for AtomicConsensusRootAccumulator {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct AccumulatorVisitor;
+
+        impl<'de> Visitor<'de> for AccumulatorVisitor {
+            type Value = AtomicConsensusRootAccumulator;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("an atomic consensus root accumulator")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let layout_version: u8 = next_layout_element(&mut seq)?;
+                let mut root = AtomicConsensusRootAccumulator {
+                    version: layout_version & !ATOMIC_ROOT_ACCUMULATOR_EXTENDED_LAYOUT_FLAG,
+                    nonce_count: next_layout_element(&mut seq)?,
+                    nonce_xor: next_layout_element(&mut seq)?,
+                    asset_count: next_layout_element(&mut seq)?,
+                    asset_xor: next_layout_element(&mut seq)?,
+                    balance_count: next_layout_element(&mut seq)?,
+                    balance_xor: next_layout_element(&mut seq)?,
+                    anchor_count: next_layout_element(&mut seq)?,
+                    anchor_xor: next_layout_element(&mut seq)?,
+                    ..Default::default()
+                };
+                if layout_version & ATOMIC_ROOT_ACCUMULATOR_EXTENDED_LAYOUT_FLAG != 0 {
+                    root.allowance_count = next_layout_element(&mut seq)?;
+                    root.allowance_xor = next_layout_element(&mut seq)?;
+                    root.lock_count = next_layout_element(&mut seq)?;
+                    root.lock_xor = next_layout_element(&mut seq)?;
+                }
+                Ok(root)
+            }
+        }
+
+        deserializer.deserialize_tuple(13, AccumulatorVisitor)
+    }
+}
+
+fn next_layout_element<'de, T, A>(seq: &mut A) -> Result<T, A::Error>
+where
+    T: Deserialize<'de>,
+    A: SeqAccess<'de>,
+{
+    seq.next_element()?.ok_or_else(|| serde::de::Error::custom("truncated atomic state encoding"))
+}
+
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct AtomicConsensusStateDelta {
     pub nonce_changes: Vec<AtomicNonceChange>,
     pub asset_changes: Vec<AtomicAssetChange>,
     pub balance_changes: Vec<AtomicBalanceChange>,
     pub anchor_count_changes: Vec<AtomicAnchorCountChange>,
+    pub allowance_changes: Vec<AtomicAllowanceChange>,
+    pub lock_changes: Vec<AtomicTokenLockChange>,
+}
+
+/// The delta layout written before the allowance and lock namespaces existed. Such deltas remain in the store
+/// under `ATOMIC_STATE_LEGACY_DELTA_SUBPREFIX` and inside virtual states persisted by older nodes.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub(crate) struct LegacyAtomicConsensusStateDelta {
+    pub nonce_changes: Vec<AtomicNonceChange>,
+    pub asset_changes: Vec<AtomicAssetChange>,
+    pub balance_changes: Vec<AtomicBalanceChange>,
+    pub anchor_count_changes: Vec<AtomicAnchorCountChange>,
+}
+
+impl From<LegacyAtomicConsensusStateDelta> for AtomicConsensusStateDelta {
+    fn from(delta: LegacyAtomicConsensusStateDelta) -> Self {
+        Self {
+            nonce_changes: delta.nonce_changes,
+            asset_changes: delta.asset_changes,
+            balance_changes: delta.balance_changes,
+            anchor_count_changes: delta.anchor_count_changes,
+            ..Default::default()
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -439,12 +802,28 @@ pub struct AtomicAnchorCountChange {
     pub new_value: Option<u64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AtomicAllowanceChange {
+    pub key: AtomicAllowanceKey,
+    pub old_value: Option<AtomicAllowanceState>,
+    pub new_value: Option<AtomicAllowanceState>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AtomicTokenLockChange {
+    pub key: AtomicBalanceKey,
+    pub old_value: Option<AtomicTokenLockState>,
+    pub new_value: Option<AtomicTokenLockState>,
+}
+
 #[derive(Clone, Debug, Default)]
 struct AtomicConsensusStateDeltaBuilder {
     nonces: HashMap<AtomicNonceKey, DeltaValue<u64>>,
     assets: HashMap<[u8; 32], DeltaValue<AtomicAssetState>>,
     balances: HashMap<AtomicBalanceKey, DeltaValue<u128>>,
     anchor_counts: HashMap<[u8; 32], DeltaValue<u64>>,
+    allowances: HashMap<AtomicAllowanceKey, DeltaValue<AtomicAllowanceState>>,
+    locks: HashMap<AtomicBalanceKey, DeltaValue<AtomicTokenLockState>>,
 }
 
 #[derive(Clone, Debug)]
@@ -457,10 +836,6 @@ fn default_atomic_liquidity_unlocked() -> bool {
     true
 }
 
-fn default_atomic_token_version() -> u8 {
-    ATOMIC_CURRENT_TOKEN_VERSION
-}
-
 fn default_atomic_liquidity_curve_version() -> u8 {
     ATOMIC_CURRENT_LIQUIDITY_CURVE_VERSION
 }
@@ -509,6 +884,24 @@ impl AtomicConsensusStateDeltaBuilder {
         record_delta(&mut self.anchor_counts, owner_id, old_value, new_value);
     }
 
+    fn record_allowance(
+        &mut self,
+        key: AtomicAllowanceKey,
+        old_value: Option<AtomicAllowanceState>,
+        new_value: Option<AtomicAllowanceState>,
+    ) {
+        record_delta(&mut self.allowances, key, old_value, new_value);
+    }
+
+    fn record_lock(
+        &mut self,
+        key: AtomicBalanceKey,
+        old_value: Option<AtomicTokenLockState>,
+        new_value: Option<AtomicTokenLockState>,
+    ) {
+        record_delta(&mut self.locks, key, old_value, new_value);
+    }
+
     fn into_delta(self) -> AtomicConsensusStateDelta {
         let mut nonce_changes: Vec<_> = self
             .nonces
@@ -538,7 +931,28 @@ impl AtomicConsensusStateDeltaBuilder {
             .collect();
         anchor_count_changes.sort_unstable_by(|a, b| a.owner_id.cmp(&b.owner_id));
 
-        AtomicConsensusStateDelta { nonce_changes, asset_changes, balance_changes, anchor_count_changes }
+        let mut allowance_changes: Vec<_> = self
+            .allowances
+            .into_iter()
+            .map(|(key, value)| AtomicAllowanceChange { key, old_value: value.old_value, new_value: value.new_value })
+            .collect();
+        allowance_changes.sort_unstable_by(|a, b| a.key.cmp(&b.key));
+
+        let mut lock_changes: Vec<_> = self
+            .locks
+            .into_iter()
+            .map(|(key, value)| AtomicTokenLockChange { key, old_value: value.old_value, new_value: value.new_value })
+            .collect();
+        lock_changes.sort_unstable_by(|a, b| a.key.cmp(&b.key));
+
+        AtomicConsensusStateDelta {
+            nonce_changes,
+            asset_changes,
+            balance_changes,
+            anchor_count_changes,
+            allowance_changes,
+            lock_changes,
+        }
     }
 }
 
@@ -548,10 +962,17 @@ impl AtomicConsensusStateDelta {
             && self.asset_changes.is_empty()
             && self.balance_changes.is_empty()
             && self.anchor_count_changes.is_empty()
+            && self.allowance_changes.is_empty()
+            && self.lock_changes.is_empty()
     }
 
     pub fn change_count(&self) -> usize {
-        self.nonce_changes.len() + self.asset_changes.len() + self.balance_changes.len() + self.anchor_count_changes.len()
+        self.nonce_changes.len()
+            + self.asset_changes.len()
+            + self.balance_changes.len()
+            + self.anchor_count_changes.len()
+            + self.allowance_changes.len()
+            + self.lock_changes.len()
     }
 }
 
@@ -562,7 +983,12 @@ impl AtomicConsensusState {
 
     pub fn root_accumulator(&self) -> AtomicConsensusRootAccumulator {
         if self.current_store.is_some()
-            || self.next_nonces.is_empty() && self.assets.is_empty() && self.balances.is_empty() && self.anchor_counts.is_empty()
+            || self.next_nonces.is_empty()
+                && self.assets.is_empty()
+                && self.balances.is_empty()
+                && self.anchor_counts.is_empty()
+                && self.allowances.is_empty()
+                && self.locks.is_empty()
         {
             self.root_accumulator
         } else {
@@ -617,6 +1043,37 @@ impl AtomicConsensusState {
             write_u64(&mut out, self.anchor_counts[&owner_id]);
         }
 
+        // Trailing optional sections, tagged and in ascending tag order. Empty sections are omitted so
+        // states without locks or allowances keep their original byte encoding.
+        if !self.locks.is_empty() {
+            let mut lock_keys = self.locks.keys().copied().collect::<Vec<_>>();
+            lock_keys.sort();
+            out.push(ATOMIC_ROOT_NAMESPACE_LOCK);
+            write_len(&mut out, lock_keys.len());
+            for key in lock_keys {
+                let lock = self.locks[&key];
+                out.extend_from_slice(&key.asset_id);
+                out.extend_from_slice(&key.owner_id);
+                write_u128(&mut out, lock.amount);
+                write_u64(&mut out, lock.release_start_daa_score);
+                write_u64(&mut out, lock.release_end_daa_score);
+            }
+        }
+        if !self.allowances.is_empty() {
+            let mut allowance_keys = self.allowances.keys().copied().collect::<Vec<_>>();
+            allowance_keys.sort();
+            out.push(ATOMIC_ROOT_NAMESPACE_ALLOWANCE);
+            write_len(&mut out, allowance_keys.len());
+            for key in allowance_keys {
+                let allowance = self.allowances[&key];
+                out.extend_from_slice(&key.asset_id);
+                out.extend_from_slice(&key.owner_id);
+                out.extend_from_slice(&key.spender_id);
+                write_u128(&mut out, allowance.amount);
+                write_u64(&mut out, allowance.expires_at_daa_score);
+            }
+        }
+
         out
     }
 
@@ -678,6 +1135,49 @@ impl AtomicConsensusState {
             }
         }
 
+        let mut last_section_tag = None;
+        while !reader.is_finished() {
+            let tag = reader.read_u8()?;
+            if last_section_tag.is_some_and(|last| tag <= last) {
+                return Err(format!("atomic state section `{tag}` is out of order"));
+            }
+            last_section_tag = Some(tag);
+            let section_len = reader.read_len()?;
+            if section_len == 0 {
+                return Err(format!("empty atomic state section `{tag}` must be omitted"));
+            }
+            match tag {
+                ATOMIC_ROOT_NAMESPACE_LOCK => {
+                    for _ in 0..section_len {
+                        let asset_id = reader.read_hash32()?;
+                        let owner_id = reader.read_hash32()?;
+                        let key = AtomicBalanceKey { asset_id, owner_id };
+                        let amount = reader.read_u128()?;
+                        let release_start_daa_score = reader.read_u64()?;
+                        let release_end_daa_score = reader.read_u64()?;
+                        let lock = AtomicTokenLockState { amount, release_start_daa_score, release_end_daa_score };
+                        if state.locks.insert(key, lock).is_some() {
+                            return Err("duplicate atomic lock key".to_string());
+                        }
+                    }
+                }
+                ATOMIC_ROOT_NAMESPACE_ALLOWANCE => {
+                    for _ in 0..section_len {
+                        let asset_id = reader.read_hash32()?;
+                        let owner_id = reader.read_hash32()?;
+                        let spender_id = reader.read_hash32()?;
+                        let key = AtomicAllowanceKey { asset_id, owner_id, spender_id };
+                        let amount = reader.read_u128()?;
+                        let expires_at_daa_score = reader.read_u64()?;
+                        if state.allowances.insert(key, AtomicAllowanceState { amount, expires_at_daa_score }).is_some() {
+                            return Err("duplicate atomic allowance key".to_string());
+                        }
+                    }
+                }
+                _ => return Err(format!("unknown atomic state section `{tag}`")),
+            }
+        }
+
         reader.finish()?;
         state.rebuild_liquidity_vault_outpoint_index();
         state.validate_normalized()?;
@@ -703,6 +1203,8 @@ impl AtomicConsensusState {
             || !self.balances.is_empty()
             || !self.anchor_counts.is_empty()
             || !self.liquidity_vault_outpoints.is_empty()
+            || !self.allowances.is_empty()
+            || !self.locks.is_empty()
     }
 
     pub fn attach_current_store(mut self, store: Arc<DbAtomicStateStore>) -> Self {
@@ -778,6 +1280,26 @@ impl AtomicConsensusState {
             .flatten()
     }
 
+    fn read_store_allowance(&self, key: &AtomicAllowanceKey) -> Option<AtomicAllowanceState> {
+        self.current_store
+            .as_ref()
+            .map(|store| {
+                store
+                    .read_current_allowance(key)
+                    .unwrap_or_else(|err| panic!("failed reading current Atomic allowance from DB: {err}"))
+            })
+            .flatten()
+    }
+
+    fn read_store_lock(&self, key: &AtomicBalanceKey) -> Option<AtomicTokenLockState> {
+        self.current_store
+            .as_ref()
+            .map(|store| {
+                store.read_current_lock(key).unwrap_or_else(|err| panic!("failed reading current Atomic lock from DB: {err}"))
+            })
+            .flatten()
+    }
+
     fn nonce_option(&self, key: &AtomicNonceKey) -> Option<u64> {
         if self.deleted_nonces.contains(key) {
             return None;
@@ -841,6 +1363,20 @@ impl AtomicConsensusState {
         self.anchor_counts.get(owner_id).copied().or_else(|| self.read_store_anchor_count(owner_id))
     }
 
+    fn allowance_option(&self, key: &AtomicAllowanceKey) -> Option<AtomicAllowanceState> {
+        if self.deleted_allowances.contains(key) {
+            return None;
+        }
+        self.allowances.get(key).copied().or_else(|| self.read_store_allowance(key))
+    }
+
+    fn lock_option(&self, key: &AtomicBalanceKey) -> Option<AtomicTokenLockState> {
+        if self.deleted_locks.contains(key) {
+            return None;
+        }
+        self.locks.get(key).copied().or_else(|| self.read_store_lock(key))
+    }
+
     fn store_vault_asset(&self, outpoint: TransactionOutpoint) -> Option<[u8; 32]> {
         self.current_store
             .as_ref()
@@ -945,6 +1481,44 @@ impl AtomicConsensusState {
         }
     }
 
+    fn set_allowance_value_without_delta(&mut self, key: AtomicAllowanceKey, value: Option<AtomicAllowanceState>) {
+        let old_value = self.allowance_option(&key);
+        if self.is_disk_backed() {
+            self.root_accumulator.apply_allowance(&key, old_value.as_ref(), value.as_ref());
+        }
+        match value {
+            Some(allowance) => {
+                self.deleted_allowances.remove(&key);
+                self.allowances.insert(key, allowance);
+            }
+            None => {
+                self.allowances.remove(&key);
+                if self.is_disk_backed() {
+                    self.deleted_allowances.insert(key);
+                }
+            }
+        }
+    }
+
+    fn set_lock_value_without_delta(&mut self, key: AtomicBalanceKey, value: Option<AtomicTokenLockState>) {
+        let old_value = self.lock_option(&key);
+        if self.is_disk_backed() {
+            self.root_accumulator.apply_lock(&key, old_value.as_ref(), value.as_ref());
+        }
+        match value {
+            Some(lock) => {
+                self.deleted_locks.remove(&key);
+                self.locks.insert(key, lock);
+            }
+            None => {
+                self.locks.remove(&key);
+                if self.is_disk_backed() {
+                    self.deleted_locks.insert(key);
+                }
+            }
+        }
+    }
+
     pub fn has_asset(&self, asset_id: &[u8; 32]) -> bool {
         self.asset_option(asset_id).is_some()
     }
@@ -1003,6 +1577,48 @@ impl AtomicConsensusState {
         self.set_anchor_count_value_without_delta(owner_id, new_value);
     }
 
+    pub fn has_allowance(&self, key: &AtomicAllowanceKey) -> bool {
+        self.allowance_option(key).is_some()
+    }
+
+    pub fn allowance(&self, key: &AtomicAllowanceKey) -> Option<AtomicAllowanceState> {
+        self.allowance_option(key)
+    }
+
+    /// Stores an allowance; a zero amount removes the key.
+    pub fn set_allowance(&mut self, key: AtomicAllowanceKey, allowance: AtomicAllowanceState) {
+        let old_value = self.allowance_option(&key);
+        let new_value = (allowance.amount != 0).then_some(allowance);
+        if self.delta_tracking {
+            self.dirty_delta.record_allowance(key, old_value, new_value);
+        }
+        self.set_allowance_value_without_delta(key, new_value);
+    }
+
+    pub fn has_lock(&self, key: &AtomicBalanceKey) -> bool {
+        self.lock_option(key).is_some()
+    }
+
+    pub fn lock(&self, key: &AtomicBalanceKey) -> Option<AtomicTokenLockState> {
+        self.lock_option(key)
+    }
+
+    /// Balance the owner may spend at `daa_score`, i.e. the balance minus whatever is still locked.
+    pub fn spendable_balance(&self, key: &AtomicBalanceKey, daa_score: u64) -> u128 {
+        let locked = self.lock_option(key).map(|lock| lock.locked_at(daa_score)).unwrap_or(0);
+        self.balance(key).saturating_sub(locked)
+    }
+
+    /// Stores a lock; a zero amount removes the key.
+    pub fn set_lock(&mut self, key: AtomicBalanceKey, lock: AtomicTokenLockState) {
+        let old_value = self.lock_option(&key);
+        let new_value = (lock.amount != 0).then_some(lock);
+        if self.delta_tracking {
+            self.dirty_delta.record_lock(key, old_value, new_value);
+        }
+        self.set_lock_value_without_delta(key, new_value);
+    }
+
     fn apply_delta_value<T>(map: &mut HashMap<T, u64>, key: T, value: Option<u64>)
     where
         T: Eq + std::hash::Hash,
@@ -1049,6 +1665,16 @@ impl AtomicConsensusState {
             self.set_anchor_count_value_without_delta(change.owner_id, value);
         }
 
+        for change in &delta.allowance_changes {
+            let value = if forward { change.new_value } else { change.old_value };
+            self.set_allowance_value_without_delta(change.key, value);
+        }
+
+        for change in &delta.lock_changes {
+            let value = if forward { change.new_value } else { change.old_value };
+            self.set_lock_value_without_delta(change.key, value);
+        }
+
         self.delta_tracking = delta_tracking;
         if delta_tracking {
             self.dirty_delta = AtomicConsensusStateDeltaBuilder::default();
@@ -1112,6 +1738,30 @@ impl AtomicConsensusState {
         Some(compute_p2p_token_audit_hash(self))
     }
 
+    /// Every balance together with the digests of the other root namespaces, which lets a client recompute
+    /// `canonical_hash` without the rest of the state. Only available for a materialized in-memory state.
+    pub fn consensus_balance_anchor(&self) -> Option<AtomicConsensusBalanceAnchor> {
+        if self.is_root_only() || self.is_disk_backed() {
+            return None;
+        }
+        let root = self.root_accumulator();
+        let mut balances = self
+            .balances
+            .iter()
+            .map(|(key, amount)| AtomicConsensusBalance { asset_id: key.asset_id, owner_id: key.owner_id, amount: *amount })
+            .collect::<Vec<_>>();
+        balances.sort_by(|a, b| (a.asset_id, a.owner_id).cmp(&(b.asset_id, b.owner_id)));
+        Some(AtomicConsensusBalanceAnchor {
+            version: root.version,
+            nonces: root.namespace_digest(ATOMIC_ROOT_NAMESPACE_NONCE),
+            assets: root.namespace_digest(ATOMIC_ROOT_NAMESPACE_ASSET),
+            anchor_counts: root.namespace_digest(ATOMIC_ROOT_NAMESPACE_ANCHOR),
+            allowances: root.namespace_digest(ATOMIC_ROOT_NAMESPACE_ALLOWANCE),
+            locks: root.namespace_digest(ATOMIC_ROOT_NAMESPACE_LOCK),
+            balances,
+        })
+    }
+
     pub fn header_commitment(utxo_commitment: Hash, atomic_state_hash: [u8; 32], payload_hf_active: bool) -> Hash {
         if !payload_hf_active {
             return utxo_commitment;
@@ -1150,6 +1800,43 @@ impl AtomicConsensusState {
             }
         }
 
+        for (key, allowance) in self.allowances.iter() {
+            if allowance.amount == 0 {
+                return Err(format!(
+                    "zero atomic allowance for asset `{}` owner `{}` spender `{}`",
+                    faster_hex::hex_string(&key.asset_id),
+                    faster_hex::hex_string(&key.owner_id),
+                    faster_hex::hex_string(&key.spender_id)
+                ));
+            }
+            if key.owner_id == key.spender_id {
+                return Err(format!("atomic allowance owner `{}` approves itself", faster_hex::hex_string(&key.owner_id)));
+            }
+            if !self.assets.contains_key(&key.asset_id) {
+                return Err(format!("atomic allowance references unknown asset `{}`", faster_hex::hex_string(&key.asset_id)));
+            }
+        }
+
+        for (key, lock) in self.locks.iter() {
+            if lock.amount == 0 {
+                return Err(format!(
+                    "zero atomic lock for asset `{}` owner `{}`",
+                    faster_hex::hex_string(&key.asset_id),
+                    faster_hex::hex_string(&key.owner_id)
+                ));
+            }
+            if lock.release_start_daa_score > lock.release_end_daa_score {
+                return Err(format!(
+                    "atomic lock for asset `{}` owner `{}` releases before it starts",
+                    faster_hex::hex_string(&key.asset_id),
+                    faster_hex::hex_string(&key.owner_id)
+                ));
+            }
+            if !self.assets.contains_key(&key.asset_id) {
+                return Err(format!("atomic lock references unknown asset `{}`", faster_hex::hex_string(&key.asset_id)));
+            }
+        }
+
         let mut balance_totals: HashMap<[u8; 32], u128> = HashMap::new();
         for (key, amount) in self.balances.iter() {
             if *amount == 0 {
@@ -1224,6 +1911,9 @@ impl AtomicConsensusState {
                 ));
             }
 
+            if asset.pair_pool.is_some() && !matches!(asset.asset_class, AtomicAssetClass::Pair) {
+                return Err(format!("non-pair asset `{}` has pair pool state", faster_hex::hex_string(asset_id)));
+            }
             match asset.asset_class {
                 AtomicAssetClass::Standard => {
                     if asset.liquidity.is_some() {
@@ -1236,6 +1926,7 @@ impl AtomicConsensusState {
                     }
                     validate_liquidity_asset_normalized(*asset_id, asset, &mut expected_vault_index)?;
                 }
+                AtomicAssetClass::Pair => self.validate_pair_asset_normalized(*asset_id, asset)?,
             }
         }
 
@@ -1245,6 +1936,53 @@ impl AtomicConsensusState {
 
         Ok(())
     }
+
+    fn validate_pair_asset_normalized(&self, pool_id: [u8; 32], asset: &AtomicAssetState) -> Result<(), String> {
+        let pair = asset
+            .pair_pool
+            .as_ref()
+            .ok_or_else(|| format!("pair asset `{}` is missing pool state", faster_hex::hex_string(&pool_id)))?;
+        if asset.liquidity.is_some() {
+            return Err(format!("pair asset `{}` has liquidity state", faster_hex::hex_string(&pool_id)));
+        }
+        if asset.decimals != 0 || asset.mint_authority_owner_id != [0u8; 32] {
+            return Err(format!("pair asset `{}` has decimals or a mint authority", faster_hex::hex_string(&pool_id)));
+        }
+        if !matches!(asset.supply_mode, AtomicSupplyMode::Uncapped) {
+            return Err(format!("pair asset `{}` is capped", faster_hex::hex_string(&pool_id)));
+        }
+        if pair.asset_a_id >= pair.asset_b_id {
+            return Err(format!("pair asset `{}` has unordered reserve assets", faster_hex::hex_string(&pool_id)));
+        }
+        if pair.fee_bps > MAX_ATOMIC_PAIR_FEE_BPS {
+            return Err(format!("pair asset `{}` fee_bps `{}` above max", faster_hex::hex_string(&pool_id), pair.fee_bps));
+        }
+        for reserve_asset_id in [pair.asset_a_id, pair.asset_b_id] {
+            match self.assets.get(&reserve_asset_id) {
+                Some(reserve_asset) if matches!(reserve_asset.asset_class, AtomicAssetClass::Standard) => {}
+                _ => {
+                    return Err(format!(
+                        "pair asset `{}` references non-standard reserve asset `{}`",
+                        faster_hex::hex_string(&pool_id),
+                        faster_hex::hex_string(&reserve_asset_id)
+                    ))
+                }
+            }
+        }
+        if self.balance(&pair.reserve_key_a(pool_id)) == 0 || self.balance(&pair.reserve_key_b(pool_id)) == 0 {
+            return Err(format!("pair asset `{}` has an empty reserve", faster_hex::hex_string(&pool_id)));
+        }
+        let locked_shares = self.balance(&AtomicBalanceKey { asset_id: pool_id, owner_id: pool_id });
+        if locked_shares < ATOMIC_MIN_PAIR_LIQUIDITY_SHARES {
+            return Err(format!(
+                "pair asset `{}` locked share balance `{}` is below `{}`",
+                faster_hex::hex_string(&pool_id),
+                locked_shares,
+                ATOMIC_MIN_PAIR_LIQUIDITY_SHARES
+            ));
+        }
+        Ok(())
+    }
 }
 
 fn validate_liquidity_asset_normalized(
@@ -1430,25 +2168,6 @@ fn validate_liquidity_curve_parameters(
     }
 }
 
-fn atomic_owner_id_from_address_components(address_version: u8, address_payload: &[u8]) -> Option<[u8; 32]> {
-    let auth_scheme = match address_version {
-        0 if address_payload.len() == 32 => OWNER_AUTH_SCHEME_PUBKEY,
-        1 if address_payload.len() == 33 => OWNER_AUTH_SCHEME_PUBKEY_ECDSA,
-        8 if address_payload.len() == 32 => OWNER_AUTH_SCHEME_SCRIPT_HASH,
-        _ => return None,
-    };
-    let pubkey_len = u16::try_from(address_payload.len()).ok()?;
-    let mut hasher = Blake2bParams::new().hash_length(32).to_state();
-    hasher.update(ATOMIC_OWNER_DOMAIN);
-    hasher.update(&[auth_scheme]);
-    hasher.update(&pubkey_len.to_le_bytes());
-    hasher.update(address_payload);
-    let hash = hasher.finalize();
-    let mut owner_id = [0u8; 32];
-    owner_id.copy_from_slice(hash.as_bytes());
-    Some(owner_id)
-}
-
 fn xor_hash_in_place(target: &mut [u8; 32], hash: &[u8; 32]) {
     for (target, value) in target.iter_mut().zip(hash.iter()) {
         *target ^= *value;
@@ -1500,6 +2219,26 @@ fn hash_anchor_count_entry(owner_id: &[u8; 32], count: u64) -> [u8; 32] {
     finalize_entry_hash(hasher)
 }
 
+fn hash_allowance_entry(key: &AtomicAllowanceKey, allowance: &AtomicAllowanceState) -> [u8; 32] {
+    let mut hasher = new_entry_hasher(ATOMIC_ROOT_NAMESPACE_ALLOWANCE);
+    hasher.update(&key.asset_id);
+    hasher.update(&key.owner_id);
+    hasher.update(&key.spender_id);
+    hash_u128(&mut hasher, allowance.amount);
+    hash_u64(&mut hasher, allowance.expires_at_daa_score);
+    finalize_entry_hash(hasher)
+}
+
+fn hash_lock_entry(key: &AtomicBalanceKey, lock: &AtomicTokenLockState) -> [u8; 32] {
+    let mut hasher = new_entry_hasher(ATOMIC_ROOT_NAMESPACE_LOCK);
+    hasher.update(&key.asset_id);
+    hasher.update(&key.owner_id);
+    hash_u128(&mut hasher, lock.amount);
+    hash_u64(&mut hasher, lock.release_start_daa_score);
+    hash_u64(&mut hasher, lock.release_end_daa_score);
+    finalize_entry_hash(hasher)
+}
+
 fn hash_len(hasher: &mut blake2b_simd::State, len: usize) {
     hash_u64(hasher, len as u64);
 }
@@ -1560,6 +2299,24 @@ fn hash_asset(hasher: &mut blake2b_simd::State, asset: &AtomicAssetState) {
         }
         None => hash_u8(hasher, 0),
     }
+    // Pair state is only committed for pair assets so roots of pre-existing asset classes stay unchanged.
+    if matches!(asset.asset_class, AtomicAssetClass::Pair) {
+        match asset.pair_pool.as_ref() {
+            Some(pair) => {
+                hash_u8(hasher, 1);
+                hash_pair_pool(hasher, pair);
+            }
+            None => hash_u8(hasher, 0),
+        }
+    }
+}
+
+fn hash_pair_pool(hasher: &mut blake2b_simd::State, pair: &AtomicPairPoolState) {
+    hasher.update(&pair.asset_a_id);
+    hasher.update(&pair.asset_b_id);
+    hash_u16(hasher, pair.fee_bps);
+    hash_u128(hasher, pair.accrued_fee_a);
+    hash_u128(hasher, pair.accrued_fee_b);
 }
 
 fn hash_liquidity_pool(hasher: &mut blake2b_simd::State, pool: &AtomicLiquidityPoolState) {
@@ -1620,6 +2377,22 @@ fn compute_p2p_token_audit_hash(state: &AtomicConsensusState) -> [u8; 32] {
         }
     }
 
+    let mut allowance_keys = state.allowances.keys().copied().collect::<Vec<_>>();
+    allowance_keys.sort_unstable();
+    for key in allowance_keys {
+        if let Some(allowance) = state.allowances.get(&key).filter(|allowance| allowance.amount > 0) {
+            apply_p2p_root_leaf(&mut buckets, &p2p_logical_allowance_key(&key), &p2p_allowance_value(allowance));
+        }
+    }
+
+    let mut lock_keys = state.locks.keys().copied().collect::<Vec<_>>();
+    lock_keys.sort_unstable();
+    for key in lock_keys {
+        if let Some(lock) = state.locks.get(&key).filter(|lock| lock.amount > 0) {
+            apply_p2p_root_leaf(&mut buckets, &p2p_logical_lock_key(&key), &p2p_lock_value(lock));
+        }
+    }
+
     p2p_root_from_buckets(&buckets)
 }
 
@@ -1647,6 +2420,38 @@ fn p2p_logical_nonce_key(key: &AtomicNonceKey) -> Vec<u8> {
     logical
 }
 
+fn p2p_logical_allowance_key(key: &AtomicAllowanceKey) -> Vec<u8> {
+    let mut logical = Vec::with_capacity(97);
+    logical.push(ATOMIC_P2P_LOGICAL_ALLOWANCE);
+    logical.extend_from_slice(&key.asset_id);
+    logical.extend_from_slice(&key.owner_id);
+    logical.extend_from_slice(&key.spender_id);
+    logical
+}
+
+fn p2p_allowance_value(allowance: &AtomicAllowanceState) -> Vec<u8> {
+    let mut out = Vec::with_capacity(24);
+    out.extend_from_slice(&allowance.amount.to_le_bytes());
+    out.extend_from_slice(&allowance.expires_at_daa_score.to_le_bytes());
+    out
+}
+
+fn p2p_logical_lock_key(key: &AtomicBalanceKey) -> Vec<u8> {
+    let mut logical = Vec::with_capacity(65);
+    logical.push(ATOMIC_P2P_LOGICAL_LOCK);
+    logical.extend_from_slice(&key.asset_id);
+    logical.extend_from_slice(&key.owner_id);
+    logical
+}
+
+fn p2p_lock_value(lock: &AtomicTokenLockState) -> Vec<u8> {
+    let mut out = Vec::with_capacity(32);
+    out.extend_from_slice(&lock.amount.to_le_bytes());
+    out.extend_from_slice(&lock.release_start_daa_score.to_le_bytes());
+    out.extend_from_slice(&lock.release_end_daa_score.to_le_bytes());
+    out
+}
+
 fn p2p_asset_value(asset_id: &[u8; 32], asset: &AtomicAssetState) -> Vec<u8> {
     let mut out = Vec::with_capacity(192 + asset.platform_tag.len());
     out.extend_from_slice(ATOMIC_P2P_ASSET_ROOT_V1);
@@ -1665,9 +2470,26 @@ fn p2p_asset_value(asset_id: &[u8; 32], asset: &AtomicAssetState) -> Vec<u8> {
         }
         None => out.push(0),
     }
+    if matches!(asset.asset_class, AtomicAssetClass::Pair) {
+        match asset.pair_pool.as_ref() {
+            Some(pair) => {
+                out.push(1);
+                p2p_append_pair_pool(&mut out, pair);
+            }
+            None => out.push(0),
+        }
+    }
     out
 }
 
+fn p2p_append_pair_pool(out: &mut Vec<u8>, pair: &AtomicPairPoolState) {
+    out.extend_from_slice(&pair.asset_a_id);
+    out.extend_from_slice(&pair.asset_b_id);
+    out.extend_from_slice(&pair.fee_bps.to_le_bytes());
+    out.extend_from_slice(&pair.accrued_fee_a.to_le_bytes());
+    out.extend_from_slice(&pair.accrued_fee_b.to_le_bytes());
+}
+
 fn p2p_append_liquidity(out: &mut Vec<u8>, pool: &AtomicLiquidityPoolState) {
     out.extend_from_slice(&pool.pool_nonce.to_le_bytes());
     out.push(pool.curve_version);
@@ -1744,6 +2566,7 @@ fn atomic_asset_class_to_u8(value: AtomicAssetClass) -> u8 {
     match value {
         AtomicAssetClass::Standard => 0,
         AtomicAssetClass::Liquidity => 1,
+        AtomicAssetClass::Pair => 2,
     }
 }
 
@@ -1800,6 +2623,8 @@ impl MemSizeEstimator for AtomicConsensusStateDeltaEntry {
             + delta.asset_changes.len() * size_of::<AtomicAssetChange>()
             + delta.balance_changes.len() * size_of::<AtomicBalanceChange>()
             + delta.anchor_count_changes.len() * size_of::<AtomicAnchorCountChange>()
+            + delta.allowance_changes.len() * size_of::<AtomicAllowanceChange>()
+            + delta.lock_changes.len() * size_of::<AtomicTokenLockChange>()
             + asset_heap
     }
 }
@@ -1839,6 +2664,14 @@ fn encode_balance_key(key: &AtomicBalanceKey) -> [u8; 64] {
     out
 }
 
+fn encode_allowance_key(key: &AtomicAllowanceKey) -> [u8; 96] {
+    let mut out = [0u8; 96];
+    out[..32].copy_from_slice(&key.asset_id);
+    out[32..64].copy_from_slice(&key.owner_id);
+    out[64..].copy_from_slice(&key.spender_id);
+    out
+}
+
 fn encode_outpoint_key(outpoint: TransactionOutpoint) -> [u8; 36] {
     let mut out = [0u8; 36];
     out[..32].copy_from_slice(&outpoint.transaction_id.as_bytes());
@@ -1918,6 +2751,14 @@ impl DbAtomicStateStore {
         read_current_value(&self.db, ATOMIC_STATE_CURRENT_ANCHOR_SUBPREFIX, *owner_id)
     }
 
+    pub fn read_current_allowance(&self, key: &AtomicAllowanceKey) -> Result<Option<AtomicAllowanceState>, StoreError> {
+        read_current_value(&self.db, ATOMIC_STATE_CURRENT_ALLOWANCE_SUBPREFIX, encode_allowance_key(key))
+    }
+
+    pub fn read_current_lock(&self, key: &AtomicBalanceKey) -> Result<Option<AtomicTokenLockState>, StoreError> {
+        read_current_value(&self.db, ATOMIC_STATE_CURRENT_LOCK_SUBPREFIX, encode_balance_key(key))
+    }
+
     pub fn read_current_vault_asset(&self, outpoint: TransactionOutpoint) -> Result<Option<[u8; 32]>, StoreError> {
         read_current_value(&self.db, ATOMIC_STATE_CURRENT_VAULT_SUBPREFIX, encode_outpoint_key(outpoint))
     }
@@ -1990,6 +2831,25 @@ impl DbAtomicStateStore {
             write_current_value(batch, ATOMIC_STATE_CURRENT_ANCHOR_SUBPREFIX, *owner_id, Some(*value))?;
         }
 
+        for key in state.deleted_allowances.iter() {
+            write_current_value::<AtomicAllowanceState, _>(
+                batch,
+                ATOMIC_STATE_CURRENT_ALLOWANCE_SUBPREFIX,
+                encode_allowance_key(key),
+                None,
+            )?;
+        }
+        for (key, value) in state.allowances.iter() {
+            write_current_value(batch, ATOMIC_STATE_CURRENT_ALLOWANCE_SUBPREFIX, encode_allowance_key(key), Some(*value))?;
+        }
+
+        for key in state.deleted_locks.iter() {
+            write_current_value::<AtomicTokenLockState, _>(batch, ATOMIC_STATE_CURRENT_LOCK_SUBPREFIX, encode_balance_key(key), None)?;
+        }
+        for (key, value) in state.locks.iter() {
+            write_current_value(batch, ATOMIC_STATE_CURRENT_LOCK_SUBPREFIX, encode_balance_key(key), Some(*value))?;
+        }
+
         self.write_current_root_batch(batch, state.root_accumulator())
     }
 
@@ -2001,6 +2861,8 @@ impl DbAtomicStateStore {
             ATOMIC_STATE_CURRENT_BALANCE_SUBPREFIX,
             ATOMIC_STATE_CURRENT_ANCHOR_SUBPREFIX,
             ATOMIC_STATE_CURRENT_VAULT_SUBPREFIX,
+            ATOMIC_STATE_CURRENT_ALLOWANCE_SUBPREFIX,
+            ATOMIC_STATE_CURRENT_LOCK_SUBPREFIX,
         ] {
             self.clear_current_subprefix_batch(batch, tag)?;
         }
@@ -2063,6 +2925,28 @@ impl DbAtomicStateStore {
             owner_id.copy_from_slice(&raw_key);
             state.anchor_counts.insert(owner_id, value);
         }
+        for (raw_key, value) in self.current_iterator::<AtomicAllowanceState>(ATOMIC_STATE_CURRENT_ALLOWANCE_SUBPREFIX)? {
+            if raw_key.len() != 96 {
+                return Err(StoreError::DataInconsistency(format!("invalid current Atomic allowance key length {}", raw_key.len())));
+            }
+            let mut asset_id = [0u8; 32];
+            asset_id.copy_from_slice(&raw_key[..32]);
+            let mut owner_id = [0u8; 32];
+            owner_id.copy_from_slice(&raw_key[32..64]);
+            let mut spender_id = [0u8; 32];
+            spender_id.copy_from_slice(&raw_key[64..96]);
+            state.allowances.insert(AtomicAllowanceKey { asset_id, owner_id, spender_id }, value);
+        }
+        for (raw_key, value) in self.current_iterator::<AtomicTokenLockState>(ATOMIC_STATE_CURRENT_LOCK_SUBPREFIX)? {
+            if raw_key.len() != 64 {
+                return Err(StoreError::DataInconsistency(format!("invalid current Atomic lock key length {}", raw_key.len())));
+            }
+            let mut asset_id = [0u8; 32];
+            asset_id.copy_from_slice(&raw_key[..32]);
+            let mut owner_id = [0u8; 32];
+            owner_id.copy_from_slice(&raw_key[32..64]);
+            state.locks.insert(AtomicBalanceKey { asset_id, owner_id }, value);
+        }
         state.rebuild_liquidity_vault_outpoint_index();
         if state.canonical_hash() != root_state.canonical_hash() {
             return Err(StoreError::DataInconsistency(format!(
@@ -2089,6 +2973,8 @@ impl DbAtomicStateStore {
             ATOMIC_STATE_CURRENT_BALANCE_SUBPREFIX,
             ATOMIC_STATE_CURRENT_ANCHOR_SUBPREFIX,
             ATOMIC_STATE_CURRENT_VAULT_SUBPREFIX,
+            ATOMIC_STATE_CURRENT_ALLOWANCE_SUBPREFIX,
+            ATOMIC_STATE_CURRENT_LOCK_SUBPREFIX,
         ] {
             self.clear_current_subprefix_batch(&mut batch, tag).expect("clear current Atomic subprefix");
         }
@@ -2134,6 +3020,14 @@ impl DbAtomicStateStore {
             let value = if forward { change.new_value } else { change.old_value };
             write_current_value(batch, ATOMIC_STATE_CURRENT_ANCHOR_SUBPREFIX, change.owner_id, value)?;
         }
+        for change in &delta.allowance_changes {
+            let value = if forward { change.new_value } else { change.old_value };
+            write_current_value(batch, ATOMIC_STATE_CURRENT_ALLOWANCE_SUBPREFIX, encode_allowance_key(&change.key), value)?;
+        }
+        for change in &delta.lock_changes {
+            let value = if forward { change.new_value } else { change.old_value };
+            write_current_value(batch, ATOMIC_STATE_CURRENT_LOCK_SUBPREFIX, encode_balance_key(&change.key), value)?;
+        }
         Ok(())
     }
 
@@ -2182,7 +3076,9 @@ impl DbAtomicStateStore {
 
     pub fn delete_batch(&self, batch: &mut WriteBatch, hash: Hash) -> Result<(), StoreError> {
         self.root_access.delete(BatchDbWriter::new(batch), hash)?;
-        self.delta_access.delete(BatchDbWriter::new(batch), hash)
+        self.delta_access.delete(BatchDbWriter::new(batch), hash)?;
+        batch.delete(DbKey::new(&atomic_state_subprefix(ATOMIC_STATE_LEGACY_DELTA_SUBPREFIX), hash));
+        Ok(())
     }
 
     pub fn delete_records_above_daa_batch<F>(
@@ -2231,6 +3127,7 @@ impl DbAtomicStateStore {
                 Ok(()) | Err(StoreError::KeyNotFound(_)) => {}
                 Err(err) => return Err(err),
             }
+            batch.delete(DbKey::new(&atomic_state_subprefix(ATOMIC_STATE_LEGACY_DELTA_SUBPREFIX), hash));
         }
 
         Ok((deleted_above_target, deleted_orphans))
@@ -2241,7 +3138,17 @@ impl DbAtomicStateStore {
     }
 
     pub fn get_delta(&self, hash: Hash) -> Result<Arc<AtomicConsensusStateDelta>, StoreError> {
-        Ok(self.delta_access.read(hash)?.0)
+        match self.delta_access.read(hash) {
+            Ok(entry) => Ok(entry.0),
+            Err(StoreError::KeyNotFound(key)) => {
+                // Blocks processed before the allowance and lock namespaces existed keep their original delta record
+                match read_current_value::<LegacyAtomicConsensusStateDelta, _>(&self.db, ATOMIC_STATE_LEGACY_DELTA_SUBPREFIX, hash)? {
+                    Some(legacy) => Ok(Arc::new(legacy.into())),
+                    None => Err(StoreError::KeyNotFound(key)),
+                }
+            }
+            Err(err) => Err(err),
+        }
     }
 
     pub fn delete(&self, hash: Hash) -> Result<(), StoreError> {
@@ -2372,10 +3279,7 @@ fn write_optional_u64(out: &mut Vec<u8>, value: Option<u64>) {
 
 fn write_atomic_asset(out: &mut Vec<u8>, asset: &AtomicAssetState) {
     out.extend_from_slice(&asset.creator_owner_id);
-    out.push(match asset.asset_class {
-        AtomicAssetClass::Standard => 0,
-        AtomicAssetClass::Liquidity => 1,
-    });
+    out.push(atomic_asset_class_to_u8(asset.asset_class));
     out.push(asset.token_version);
     out.extend_from_slice(&asset.mint_authority_owner_id);
     out.push(asset.decimals);
@@ -2403,6 +3307,23 @@ fn write_atomic_asset(out: &mut Vec<u8>, asset: &AtomicAssetState) {
         }
         None => out.push(0),
     }
+    if matches!(asset.asset_class, AtomicAssetClass::Pair) {
+        match asset.pair_pool.as_ref() {
+            Some(pair) => {
+                out.push(1);
+                write_pair_pool(out, pair);
+            }
+            None => out.push(0),
+        }
+    }
+}
+
+fn write_pair_pool(out: &mut Vec<u8>, pair: &AtomicPairPoolState) {
+    out.extend_from_slice(&pair.asset_a_id);
+    out.extend_from_slice(&pair.asset_b_id);
+    write_u16(out, pair.fee_bps);
+    write_u128(out, pair.accrued_fee_a);
+    write_u128(out, pair.accrued_fee_b);
 }
 
 fn write_liquidity_pool(out: &mut Vec<u8>, pool: &AtomicLiquidityPoolState) {
@@ -2537,6 +3458,7 @@ impl<'a> AtomicStateReader<'a> {
         let asset_class = match self.read_u8()? {
             0 => AtomicAssetClass::Standard,
             1 => AtomicAssetClass::Liquidity,
+            2 => AtomicAssetClass::Pair,
             raw => return Err(format!("invalid atomic asset class `{raw}`")),
         };
         let token_version = self.read_u8()?;
@@ -2585,6 +3507,15 @@ impl<'a> AtomicStateReader<'a> {
             1 => Some(self.read_liquidity_pool()?),
             raw => return Err(format!("invalid atomic liquidity presence flag `{raw}`")),
         };
+        let pair_pool = if matches!(asset_class, AtomicAssetClass::Pair) {
+            match self.read_u8()? {
+                0 => None,
+                1 => Some(self.read_pair_pool()?),
+                raw => return Err(format!("invalid atomic pair pool presence flag `{raw}`")),
+            }
+        } else {
+            None
+        };
         Ok(AtomicAssetState {
             creator_owner_id,
             asset_class,
@@ -2602,6 +3533,7 @@ impl<'a> AtomicStateReader<'a> {
             created_daa_score,
             created_at,
             liquidity,
+            pair_pool,
         })
     }
 
@@ -2649,9 +3581,22 @@ impl<'a> AtomicStateReader<'a> {
             created_daa_score: None,
             created_at: None,
             liquidity,
+            pair_pool: None,
         })
     }
 
+    fn read_pair_pool(&mut self) -> Result<AtomicPairPoolState, String> {
+        let asset_a_id = self.read_hash32()?;
+        let asset_b_id = self.read_hash32()?;
+        let fee_bps = self.read_u16()?;
+        if fee_bps > MAX_ATOMIC_PAIR_FEE_BPS {
+            return Err(format!("atomic pair fee_bps `{fee_bps}` above max `{MAX_ATOMIC_PAIR_FEE_BPS}`"));
+        }
+        let accrued_fee_a = self.read_u128()?;
+        let accrued_fee_b = self.read_u128()?;
+        Ok(AtomicPairPoolState { asset_a_id, asset_b_id, fee_bps, accrued_fee_a, accrued_fee_b })
+    }
+
     fn read_liquidity_pool(&mut self) -> Result<AtomicLiquidityPoolState, String> {
         let pool_nonce = self.read_u64()?;
         let curve_version = self.read_u8()?;
@@ -2713,6 +3658,10 @@ impl<'a> AtomicStateReader<'a> {
         })
     }
 
+    fn is_finished(&self) -> bool {
+        self.cursor == self.bytes.len()
+    }
+
     fn finish(&self) -> Result<(), String> {
         if self.cursor == self.bytes.len() {
             Ok(())
@@ -2789,6 +3738,7 @@ mod tests {
             created_daa_score: Some(12_345),
             created_at: Some(1_715_000_000_000),
             liquidity: None,
+            pair_pool: None,
         };
         let liquidity_asset = AtomicAssetState {
             creator_owner_id: owner(0xA2),
@@ -2837,6 +3787,7 @@ mod tests {
                 unlock_target_sompi: 0,
                 unlocked: true,
             }),
+            pair_pool: None,
         };
 
         let mut state = AtomicConsensusState::default();
@@ -2870,6 +3821,28 @@ mod tests {
         })
     }
 
+    #[test]
+    fn consensus_balance_anchor_recomputes_canonical_hash() {
+        let mut state = atomic_interop_vector_state();
+        let anchor = state.consensus_balance_anchor().expect("materialized state has an anchor");
+        assert_eq!(anchor.state_hash().unwrap(), state.canonical_hash());
+        assert_eq!(
+            anchor.balance(&[0x10; 32], &owner(0xB0)),
+            state.balance(&AtomicBalanceKey { asset_id: [0x10; 32], owner_id: owner(0xB0) })
+        );
+
+        state.set_allowance(
+            AtomicAllowanceKey { asset_id: [0x10; 32], owner_id: owner(0xB0), spender_id: owner(0xB1) },
+            AtomicAllowanceState { amount: 5, expires_at_daa_score: 0 },
+        );
+        state.set_lock(
+            AtomicBalanceKey { asset_id: [0x10; 32], owner_id: owner(0xB1) },
+            AtomicTokenLockState { amount: 7, release_start_daa_score: 10, release_end_daa_score: 20 },
+        );
+        let anchor = state.consensus_balance_anchor().unwrap();
+        anchor.verify_against(&state.canonical_hash()).expect("anchor commits to the extended root");
+    }
+
     #[test]
     fn header_commitment_is_legacy_before_hf_and_binds_atomic_state_after_hf() {
         let utxo_commitment = hash(1);
@@ -2919,6 +3892,7 @@ mod tests {
                 created_daa_score: Some(12_345),
                 created_at: Some(1_779_700_001),
                 liquidity: None,
+                pair_pool: None,
             },
         );
         state.assets.insert(
@@ -2970,6 +3944,7 @@ mod tests {
                     unlock_target_sompi: 99_999,
                     unlocked: false,
                 }),
+                pair_pool: None,
             },
         );
         state.balances.insert(AtomicBalanceKey { asset_id: asset_a, owner_id: owner_a }, 555);
@@ -3045,6 +4020,7 @@ mod tests {
                     created_daa_score: Some(200_000 + u64::from(i)),
                     created_at: Some(1_779_900_000_000 + u64::from(i)),
                     liquidity: None,
+                    pair_pool: None,
                 },
             );
             state.balances.insert(AtomicBalanceKey { asset_id, owner_id: owner_main }, owner_balance);
@@ -3109,6 +4085,7 @@ mod tests {
                     unlock_target_sompi: 900_000_000_000,
                     unlocked: true,
                 }),
+                pair_pool: None,
             },
         );
         state.balances.insert(AtomicBalanceKey { asset_id: liquidity_asset_id, owner_id: owner_main }, liquidity_total);
@@ -3175,6 +4152,7 @@ mod tests {
             created_daa_score: None,
             created_at: None,
             liquidity: None,
+            pair_pool: None,
         };
         let fee_recipient_payload = vec![4; 32];
         let fee_recipient_owner =
@@ -3218,6 +4196,7 @@ mod tests {
                 unlock_target_sompi: 0,
                 unlocked: true,
             }),
+            pair_pool: None,
         };
 
         left.next_nonces.insert(AtomicNonceKey::owner(owner(5)), 2);
@@ -3266,6 +4245,7 @@ mod tests {
             created_daa_score: None,
             created_at: None,
             liquidity: None,
+            pair_pool: None,
         };
 
         let mut state = AtomicConsensusState::default();
@@ -3332,6 +4312,7 @@ mod tests {
             created_daa_score: None,
             created_at: None,
             liquidity: None,
+            pair_pool: None,
         };
 
         let base = AtomicConsensusState::default();
@@ -3358,6 +4339,73 @@ mod tests {
         assert!(replayed.anchor_counts.is_empty());
     }
 
+    #[test]
+    fn atomic_token_lock_releases_by_daa_score_and_round_trips() {
+        let linear = AtomicTokenLockState { amount: 1_000, release_start_daa_score: 100, release_end_daa_score: 400 };
+        assert_eq!(linear.locked_at(0), 1_000);
+        assert_eq!(linear.locked_at(100), 1_000);
+        assert_eq!(linear.locked_at(101), 997);
+        assert_eq!(linear.locked_at(250), 500);
+        assert_eq!(linear.locked_at(399), 4);
+        assert_eq!(linear.locked_at(400), 0);
+        let cliff = AtomicTokenLockState { amount: u128::MAX, release_start_daa_score: 50, release_end_daa_score: 50 };
+        assert_eq!(cliff.locked_at(49), u128::MAX);
+        assert_eq!(cliff.locked_at(50), 0);
+        let huge = AtomicTokenLockState { amount: u128::MAX, release_start_daa_score: 0, release_end_daa_score: u64::MAX };
+        assert!(huge.locked_at(u64::MAX / 2) > u128::MAX / 2);
+
+        let asset_id = [0x7A; 32];
+        let owner_id = owner(0x7B);
+        let balance_key = AtomicBalanceKey { asset_id, owner_id };
+        let mut state = AtomicConsensusState::default();
+        state
+            .set_asset(
+                asset_id,
+                AtomicAssetState {
+                    creator_owner_id: owner_id,
+                    asset_class: AtomicAssetClass::Standard,
+                    token_version: ATOMIC_CURRENT_TOKEN_VERSION,
+                    mint_authority_owner_id: owner_id,
+                    decimals: 0,
+                    supply_mode: AtomicSupplyMode::Uncapped,
+                    max_supply: 0,
+                    total_supply: 1_000,
+                    name: b"Vest".to_vec(),
+                    symbol: b"VST".to_vec(),
+                    metadata: Vec::new(),
+                    platform_tag: Vec::new(),
+                    created_block_hash: None,
+                    created_daa_score: None,
+                    created_at: None,
+                    liquidity: None,
+                    pair_pool: None,
+                },
+            )
+            .expect("asset insert");
+        state.set_balance(balance_key, 1_000);
+        state.set_allowance(
+            AtomicAllowanceKey { asset_id, owner_id, spender_id: owner(0x7C) },
+            AtomicAllowanceState { amount: 5, expires_at_daa_score: 0 },
+        );
+        let base_hash = state.canonical_hash();
+
+        state.begin_delta_tracking();
+        state.set_lock(balance_key, linear);
+        let delta = state.take_delta();
+        assert_eq!(delta.lock_changes.len(), 1);
+        assert_ne!(state.canonical_hash(), base_hash);
+        assert_eq!(state.spendable_balance(&balance_key, 250), 500);
+        state.validate_normalized().expect("lock state is normalized");
+
+        let decoded = AtomicConsensusState::try_from_canonical_bytes(&state.canonical_bytes()).expect("canonical bytes decode");
+        assert_eq!(decoded.lock(&balance_key), Some(linear));
+        assert_eq!(decoded.canonical_hash(), state.canonical_hash());
+
+        state.apply_delta_rollback(&delta).expect("rollback delta applies");
+        assert!(!state.has_lock(&balance_key));
+        assert_eq!(state.canonical_hash(), base_hash);
+    }
+
     #[test]
     fn atomic_consensus_state_persists_current_overlay_and_reads_lazily() {
         let (_lifetime, db) = create_temp_db!(ConnBuilder::default().with_files_limit(10));
@@ -3401,6 +4449,7 @@ mod tests {
                 unlock_target_sompi: 0,
                 unlocked: true,
             }),
+            pair_pool: None,
         };
 
         let mut state = store.attach_virtual_state(&AtomicConsensusState::default());
@@ -3452,6 +4501,7 @@ mod tests {
             created_daa_score: None,
             created_at: None,
             liquidity: None,
+            pair_pool: None,
         };
 
         let mut state = store.attach_virtual_state(&AtomicConsensusState::default());
@@ -3484,6 +4534,8 @@ mod tests {
             asset_changes: Vec::new(),
             balance_changes: Vec::new(),
             anchor_count_changes: Vec::new(),
+            allowance_changes: Vec::new(),
+            lock_changes: Vec::new(),
         });
 
         let mut batch = WriteBatch::default();
@@ -3503,6 +4555,168 @@ mod tests {
         ));
     }
 
+    /// The root accumulator layout from before the allowance and lock namespaces existed
+    #[derive(Serialize)]
+    struct LegacyAtomicConsensusRootAccumulator {
+        version: u8,
+        nonce_count: u64,
+        nonce_xor: [u8; 32],
+        asset_count: u64,
+        asset_xor: [u8; 32],
+        balance_count: u64,
+        balance_xor: [u8; 32],
+        anchor_count: u64,
+        anchor_xor: [u8; 32],
+    }
+
+    #[test]
+    fn atomic_state_store_reads_legacy_delta_records() {
+        let (_lifetime, db) = create_temp_db!(ConnBuilder::default().with_files_limit(10));
+        let store = DbAtomicStateStore::new(db.clone(), CachePolicy::Empty);
+        let block_hash = hash(0x47);
+        let nonce_change = AtomicNonceChange { key: AtomicNonceKey::owner(owner(0x48)), old_value: Some(1), new_value: Some(2) };
+        let legacy_delta = LegacyAtomicConsensusStateDelta { nonce_changes: vec![nonce_change.clone()], ..Default::default() };
+
+        let mut batch = WriteBatch::default();
+        batch.put(
+            DbKey::new(&atomic_state_subprefix(ATOMIC_STATE_LEGACY_DELTA_SUBPREFIX), block_hash),
+            bincode::serialize(&legacy_delta).unwrap(),
+        );
+        db.write(batch).expect("commit legacy delta");
+
+        let delta = store.get_delta(block_hash).expect("legacy delta record");
+        assert_eq!(delta.as_ref(), &AtomicConsensusStateDelta { nonce_changes: vec![nonce_change], ..Default::default() });
+
+        let mut batch = WriteBatch::default();
+        store.delete_batch(&mut batch, block_hash).expect("delete records");
+        db.write(batch).expect("commit delete");
+        assert!(matches!(store.get_delta(block_hash), Err(StoreError::KeyNotFound(_))));
+    }
+
+    #[test]
+    fn atomic_delta_and_root_encodings_read_original_layout() {
+        let nonce_change = AtomicNonceChange { key: AtomicNonceKey::owner(owner(0x60)), old_value: Some(1), new_value: Some(2) };
+        let balance_key = AtomicBalanceKey { asset_id: owner(0x61), owner_id: owner(0x62) };
+        let balance_change = AtomicBalanceChange { key: balance_key, old_value: Some(5), new_value: Some(7) };
+        let legacy_delta = LegacyAtomicConsensusStateDelta {
+            nonce_changes: vec![nonce_change.clone()],
+            asset_changes: Vec::new(),
+            balance_changes: vec![balance_change.clone()],
+            anchor_count_changes: Vec::new(),
+        };
+        let legacy_delta_bytes = bincode::serialize(&legacy_delta).unwrap();
+        let delta: AtomicConsensusStateDelta =
+            bincode::deserialize::<LegacyAtomicConsensusStateDelta>(&legacy_delta_bytes).unwrap().into();
+        assert_eq!(
+            delta,
+            AtomicConsensusStateDelta {
+                nonce_changes: vec![nonce_change],
+                balance_changes: vec![balance_change],
+                ..Default::default()
+            }
+        );
+
+        let mut extended_delta = delta.clone();
+        extended_delta.allowance_changes.push(AtomicAllowanceChange {
+            key: AtomicAllowanceKey { asset_id: owner(0x61), owner_id: owner(0x62), spender_id: owner(0x63) },
+            old_value: None,
+            new_value: Some(AtomicAllowanceState { amount: 9, expires_at_daa_score: 0 }),
+        });
+        extended_delta.lock_changes.push(AtomicTokenLockChange {
+            key: balance_key,
+            old_value: None,
+            new_value: Some(AtomicTokenLockState { amount: 3, release_start_daa_score: 10, release_end_daa_score: 20 }),
+        });
+        let extended_delta_bytes = bincode::serialize(&extended_delta).unwrap();
+        assert_eq!(bincode::deserialize::<AtomicConsensusStateDelta>(&extended_delta_bytes).unwrap(), extended_delta);
+
+        let mut state = AtomicConsensusState::default();
+        state.set_balance(balance_key, 7);
+        let root = state.root_accumulator();
+        let legacy_root = LegacyAtomicConsensusRootAccumulator {
+            version: root.version,
+            nonce_count: root.nonce_count,
+            nonce_xor: root.nonce_xor,
+            asset_count: root.asset_count,
+            asset_xor: root.asset_xor,
+            balance_count: root.balance_count,
+            balance_xor: root.balance_xor,
+            anchor_count: root.anchor_count,
+            anchor_xor: root.anchor_xor,
+        };
+        let legacy_root_bytes = bincode::serialize(&legacy_root).unwrap();
+        assert_eq!(bincode::deserialize::<AtomicConsensusRootAccumulator>(&legacy_root_bytes).unwrap(), root);
+        assert_eq!(bincode::serialize(&root).unwrap(), legacy_root_bytes);
+
+        state.set_lock(balance_key, AtomicTokenLockState { amount: 3, release_start_daa_score: 10, release_end_daa_score: 20 });
+        let extended_root = state.root_accumulator();
+        assert_eq!(extended_root.lock_count(), 1);
+        let extended_root_bytes = bincode::serialize(&extended_root).unwrap();
+        assert_eq!(bincode::deserialize::<AtomicConsensusRootAccumulator>(&extended_root_bytes).unwrap(), extended_root);
+    }
+
+    #[test]
+    fn atomic_asset_encoding_reads_original_layout() {
+        let asset = AtomicAssetState {
+            creator_owner_id: owner(0x71),
+            asset_class: AtomicAssetClass::Standard,
+            token_version: ATOMIC_CURRENT_TOKEN_VERSION,
+            mint_authority_owner_id: owner(0x72),
+            decimals: 8,
+            supply_mode: AtomicSupplyMode::Capped,
+            max_supply: 1_000,
+            total_supply: 400,
+            name: b"Legacy".to_vec(),
+            symbol: b"LGC".to_vec(),
+            metadata: b"{}".to_vec(),
+            platform_tag: Vec::new(),
+            created_block_hash: Some(owner(0x73)),
+            created_daa_score: Some(10),
+            created_at: None,
+            liquidity: None,
+            pair_pool: None,
+        };
+        // The layout from before pair pools existed holds every other field in declaration order
+        let legacy_bytes = bincode::serialize(&(
+            &asset.creator_owner_id,
+            &asset.asset_class,
+            &asset.token_version,
+            &asset.mint_authority_owner_id,
+            &asset.decimals,
+            &asset.supply_mode,
+            &asset.max_supply,
+            &asset.total_supply,
+            &asset.name,
+            &asset.symbol,
+            &asset.metadata,
+            &asset.platform_tag,
+            &asset.created_block_hash,
+            &asset.created_daa_score,
+            &asset.created_at,
+            &asset.liquidity,
+        ))
+        .unwrap();
+        assert_eq!(bincode::serialize(&asset).unwrap(), legacy_bytes);
+        assert_eq!(bincode::deserialize::<AtomicAssetState>(&legacy_bytes).unwrap(), asset);
+
+        let pair_asset = AtomicAssetState {
+            asset_class: AtomicAssetClass::Pair,
+            mint_authority_owner_id: [0; 32],
+            decimals: 0,
+            supply_mode: AtomicSupplyMode::Uncapped,
+            pair_pool: Some(AtomicPairPoolState {
+                asset_a_id: owner(0x74),
+                asset_b_id: owner(0x75),
+                fee_bps: 30,
+                accrued_fee_a: 1,
+                accrued_fee_b: 2,
+            }),
+            ..asset
+        };
+        let pair_bytes = bincode::serialize(&pair_asset).unwrap();
+        assert_eq!(bincode::deserialize::<AtomicAssetState>(&pair_bytes).unwrap(), pair_asset);
+    }
+
     #[test]
     fn atomic_state_store_deletes_records_above_target_daa_and_orphans() {
         let (_lifetime, db) = create_temp_db!(ConnBuilder::default().with_files_limit(10));
@@ -12,7 +12,7 @@ use cryptix_consensus_core::{
     utxo::utxo_diff::UtxoDiff,
     BlockHashMap, BlockHashSet, HashMapCustomHasher,
 };
-use cryptix_database::prelude::{BatchDbWriter, CachedDbItem, DbKey, DirectDbWriter, StoreResultExtensions};
+use cryptix_database::prelude::{BatchDbWriter, CachedDbItem, DirectDbWriter, StoreResultExtensions};
 use cryptix_database::prelude::{CachePolicy, StoreResult};
 use cryptix_database::prelude::{StoreError, DB};
 use cryptix_database::registry::DatabaseStorePrefixes;
@@ -21,7 +21,10 @@ use cryptix_muhash::MuHash;
 use rocksdb::WriteBatch;
 use serde::{Deserialize, Serialize};
 
-use super::atomic_state::{AtomicAssetState, AtomicBalanceKey, AtomicConsensusState, AtomicConsensusStateDelta, AtomicNonceKey};
+use super::atomic_state::{
+    AtomicAssetState, AtomicBalanceKey, AtomicConsensusState, AtomicConsensusStateDelta, AtomicNonceKey,
+    LegacyAtomicConsensusStateDelta,
+};
 use super::ghostdag::GhostdagData;
 use super::utxo_set::DbUtxoSetStore;
 
@@ -70,6 +73,43 @@ impl From<LegacyAtomicConsensusState> for AtomicConsensusState {
     }
 }
 
+/// The layout stored under `DatabaseStorePrefixes::VirtualState`, whose atomic diff predates the allowance and
+/// lock namespaces
+#[derive(Clone, Serialize, Deserialize, Default)]
+struct LegacyVirtualStateWithAtomicDiff {
+    parents: Vec<Hash>,
+    ghostdag_data: GhostdagData,
+    daa_score: u64,
+    bits: u32,
+    past_median_time: u64,
+    multiset: MuHash,
+    utxo_diff: UtxoDiff,
+    accepted_tx_ids: Vec<TransactionId>,
+    mergeset_rewards: BlockHashMap<BlockRewardData>,
+    mergeset_non_daa: BlockHashSet,
+    atomic_diff: LegacyAtomicConsensusStateDelta,
+    atomic_state: AtomicConsensusState,
+}
+
+impl From<LegacyVirtualStateWithAtomicDiff> for VirtualState {
+    fn from(value: LegacyVirtualStateWithAtomicDiff) -> Self {
+        Self {
+            parents: value.parents,
+            ghostdag_data: value.ghostdag_data,
+            daa_score: value.daa_score,
+            bits: value.bits,
+            past_median_time: value.past_median_time,
+            multiset: value.multiset,
+            utxo_diff: value.utxo_diff,
+            accepted_tx_ids: value.accepted_tx_ids,
+            mergeset_rewards: value.mergeset_rewards,
+            mergeset_non_daa: value.mergeset_non_daa,
+            atomic_diff: value.atomic_diff.into(),
+            atomic_state: value.atomic_state,
+        }
+    }
+}
+
 #[derive(Clone, Serialize, Deserialize, Default)]
 struct LegacyVirtualStateWithAtomic {
     parents: Vec<Hash>,
@@ -278,7 +318,8 @@ pub trait VirtualStateStore: VirtualStateStoreReader {
 #[derive(Clone)]
 pub struct DbVirtualStateStore {
     db: Arc<DB>,
-    key: Vec<u8>,
+    /// The key virtual states were stored under before `VirtualStateV2`, read only for migration
+    legacy_key: Vec<u8>,
     access: CachedDbItem<Arc<VirtualState>>,
     /// The "last known good" virtual state
     lkg_virtual_state: LkgVirtualState,
@@ -286,9 +327,9 @@ pub struct DbVirtualStateStore {
 
 impl DbVirtualStateStore {
     pub fn new(db: Arc<DB>, lkg_virtual_state: LkgVirtualState) -> Self {
-        let key: Vec<u8> = DatabaseStorePrefixes::VirtualState.into();
-        let access = CachedDbItem::new(db.clone(), key.clone());
-        let store = Self { db, key, access, lkg_virtual_state };
+        let legacy_key: Vec<u8> = DatabaseStorePrefixes::VirtualState.into();
+        let access = CachedDbItem::new(db.clone(), DatabaseStorePrefixes::VirtualStateV2.into());
+        let store = Self { db, legacy_key, access, lkg_virtual_state };
         // Init the LKG cache from DB store data
         store.lkg_virtual_state.store(store.read_compatible().unwrap_option().unwrap_or_default());
         store
@@ -299,8 +340,12 @@ impl DbVirtualStateStore {
     }
 
     fn persist_current_format(&self, state: &Arc<VirtualState>) -> StoreResult<()> {
+        let mut batch = WriteBatch::default();
         let mut access = self.access.clone();
-        access.write(DirectDbWriter::new(&self.db), state)
+        access.write(BatchDbWriter::new(&mut batch), state)?;
+        batch.delete(&self.legacy_key);
+        self.db.write(batch)?;
+        Ok(())
     }
 
     fn legacy_atomic_state_is_empty(state: &AtomicConsensusState) -> bool {
@@ -309,35 +354,39 @@ impl DbVirtualStateStore {
             && state.balances.is_empty()
             && state.anchor_counts.is_empty()
             && state.liquidity_vault_outpoints.is_empty()
+            && state.allowances.is_empty()
+            && state.locks.is_empty()
     }
 
-    fn read_legacy_and_maybe_migrate(&self, current_error: Box<bincode::ErrorKind>) -> StoreResult<Arc<VirtualState>> {
-        let Some(slice) = self.db.get_pinned(&self.key)? else {
-            return Err(StoreError::KeyNotFound(DbKey::prefix_only(&self.key)));
+    fn read_legacy_and_maybe_migrate(&self, current_error: StoreError) -> StoreResult<Arc<VirtualState>> {
+        let Some(slice) = self.db.get_pinned(&self.legacy_key)? else {
+            return Err(current_error);
         };
         let bytes = slice.as_ref();
 
-        if let Ok(legacy) = bincode::deserialize::<LegacyVirtualStateWithAtomic>(bytes) {
+        if let Ok(legacy) = bincode::deserialize::<LegacyVirtualStateWithAtomicDiff>(bytes) {
             let state = Arc::new(VirtualState::from(legacy));
-            if Self::legacy_atomic_state_is_empty(&state.atomic_state) {
-                self.persist_current_format(&state)?;
-            }
+            self.persist_current_format(&state)?;
             return Ok(state);
         }
 
-        if let Ok(legacy) = bincode::deserialize::<LegacyVirtualStateWithoutAtomic>(bytes) {
+        if let Ok(legacy) = bincode::deserialize::<LegacyVirtualStateWithAtomic>(bytes) {
             let state = Arc::new(VirtualState::from(legacy));
-            self.persist_current_format(&state)?;
+            if Self::legacy_atomic_state_is_empty(&state.atomic_state) {
+                self.persist_current_format(&state)?;
+            }
             return Ok(state);
         }
 
-        Err(StoreError::DeserializationError(current_error))
+        let state = Arc::new(VirtualState::from(bincode::deserialize::<LegacyVirtualStateWithoutAtomic>(bytes)?));
+        self.persist_current_format(&state)?;
+        Ok(state)
     }
 
     fn read_compatible(&self) -> StoreResult<Arc<VirtualState>> {
         match self.access.read() {
             Ok(state) => Ok(state),
-            Err(StoreError::DeserializationError(err)) => self.read_legacy_and_maybe_migrate(err),
+            Err(err @ StoreError::KeyNotFound(_)) => self.read_legacy_and_maybe_migrate(err),
             Err(err) => Err(err),
         }
     }
@@ -371,7 +420,7 @@ impl VirtualStateStore for DbVirtualStateStore {
 
 #[cfg(test)]
 mod tests {
-    use super::super::atomic_state::DbAtomicStateStore;
+    use super::super::atomic_state::{AtomicNonceChange, DbAtomicStateStore};
     use super::*;
     use cryptix_database::{create_temp_db, prelude::ConnBuilder};
 
@@ -402,9 +451,43 @@ mod tests {
         assert!(state.atomic_diff.is_empty());
         assert!(state.atomic_state.next_nonces.is_empty());
 
-        let raw = db.get_pinned(&key).unwrap().expect("migrated virtual state must be persisted");
+        let current_key: Vec<u8> = DatabaseStorePrefixes::VirtualStateV2.into();
+        let raw = db.get_pinned(&current_key).unwrap().expect("migrated virtual state must be persisted");
         let migrated: Arc<VirtualState> = bincode::deserialize(raw.as_ref()).unwrap();
         assert_eq!(migrated.daa_score, 42);
+        assert!(db.get_pinned(&key).unwrap().is_none());
+    }
+
+    #[test]
+    fn legacy_virtual_state_with_original_atomic_diff_is_migrated_on_read() {
+        let (_lifetime, db) = create_temp_db!(ConnBuilder::default().with_files_limit(10));
+        let key: Vec<u8> = DatabaseStorePrefixes::VirtualState.into();
+        let base = legacy_without_atomic(55);
+        let nonce_change = AtomicNonceChange { key: AtomicNonceKey::owner([0x43; 32]), old_value: None, new_value: Some(1) };
+        let legacy = LegacyVirtualStateWithAtomicDiff {
+            parents: base.parents,
+            ghostdag_data: base.ghostdag_data,
+            daa_score: base.daa_score,
+            bits: base.bits,
+            past_median_time: base.past_median_time,
+            multiset: base.multiset,
+            utxo_diff: base.utxo_diff,
+            accepted_tx_ids: base.accepted_tx_ids,
+            mergeset_rewards: base.mergeset_rewards,
+            mergeset_non_daa: base.mergeset_non_daa,
+            atomic_diff: LegacyAtomicConsensusStateDelta { nonce_changes: vec![nonce_change.clone()], ..Default::default() },
+            atomic_state: AtomicConsensusState::default(),
+        };
+        db.put(&key, bincode::serialize(&legacy).unwrap()).unwrap();
+
+        let store = DbVirtualStateStore::new(db.clone(), LkgVirtualState::default());
+        let state = store.get().unwrap();
+        assert_eq!(state.daa_score, 55);
+        assert_eq!(state.atomic_diff, AtomicConsensusStateDelta { nonce_changes: vec![nonce_change], ..Default::default() });
+        assert!(db.get_pinned(&key).unwrap().is_none());
+
+        let reopened = DbVirtualStateStore::new(db, LkgVirtualState::default());
+        assert_eq!(reopened.get().unwrap().atomic_diff, state.atomic_diff);
     }
 
     #[test]
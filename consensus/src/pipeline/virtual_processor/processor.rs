@@ -58,6 +58,7 @@ use crate::{
 use cryptix_consensus_core::{
     acceptance_data::AcceptanceData,
     api::args::{TransactionValidationArgs, TransactionValidationBatchArgs},
+    atomic::AtomicConsensusBalanceAnchor,
     block::{BlockTemplate, MutableBlock, TemplateBuildMode, TemplateTransactionSelector},
     blockstatus::BlockStatus::{StatusDisqualifiedFromChain, StatusHeaderOnly, StatusInvalid, StatusUTXOValid},
     coinbase::MinerData,
@@ -1719,7 +1720,10 @@ impl VirtualStateProcessor {
             return Ok(None);
         }
 
-        let Some(parsed_payload) = parse_atomic_payload(tx_ref.payload.as_slice()).map_err(TxRuleError::InvalidAtomicPayload)? else {
+        let extended_ops_active = self.transaction_validator.is_atomic_extended_ops_active(virtual_daa_score);
+        let Some(parsed_payload) =
+            parse_atomic_payload(tx_ref.payload.as_slice(), extended_ops_active).map_err(TxRuleError::InvalidAtomicPayload)?
+        else {
             return Ok(None);
         };
 
@@ -1752,7 +1756,10 @@ impl VirtualStateProcessor {
             return Ok(None);
         }
 
-        let Some(parsed_payload) = parse_atomic_payload(tx.payload.as_slice()).map_err(TxRuleError::InvalidAtomicPayload)? else {
+        let extended_ops_active = self.transaction_validator.is_atomic_extended_ops_active(virtual_daa_score);
+        let Some(parsed_payload) =
+            parse_atomic_payload(tx.payload.as_slice(), extended_ops_active).map_err(TxRuleError::InvalidAtomicPayload)?
+        else {
             return Ok(None);
         };
 
@@ -1778,7 +1785,9 @@ impl VirtualStateProcessor {
         let pool = match op {
             AtomicPayloadOp::BuyLiquidityExactIn { asset_id, expected_pool_nonce, .. }
             | AtomicPayloadOp::SellLiquidityExactIn { asset_id, expected_pool_nonce, .. }
-            | AtomicPayloadOp::ClaimLiquidityFees { asset_id, expected_pool_nonce, .. } => Some((*asset_id, *expected_pool_nonce)),
+            | AtomicPayloadOp::ClaimLiquidityFees { asset_id, expected_pool_nonce, .. }
+            | AtomicPayloadOp::BuyLiquidityExactOut { asset_id, expected_pool_nonce, .. }
+            | AtomicPayloadOp::SellLiquidityExactOut { asset_id, expected_pool_nonce, .. } => Some((*asset_id, *expected_pool_nonce)),
             _ => None,
         };
         let references_asset_id = match op {
@@ -1787,13 +1796,27 @@ impl VirtualStateProcessor {
             | AtomicPayloadOp::Burn { asset_id, .. }
             | AtomicPayloadOp::BuyLiquidityExactIn { asset_id, .. }
             | AtomicPayloadOp::SellLiquidityExactIn { asset_id, .. }
-            | AtomicPayloadOp::ClaimLiquidityFees { asset_id, .. } => Some(*asset_id),
+            | AtomicPayloadOp::ClaimLiquidityFees { asset_id, .. }
+            | AtomicPayloadOp::BuyLiquidityExactOut { asset_id, .. }
+            | AtomicPayloadOp::SellLiquidityExactOut { asset_id, .. }
+            | AtomicPayloadOp::Approve { asset_id, .. }
+            | AtomicPayloadOp::TransferFrom { asset_id, .. }
+            | AtomicPayloadOp::BatchTransfer { asset_id, .. }
+            | AtomicPayloadOp::LockTokens { asset_id, .. }
+            | AtomicPayloadOp::TransferMintAuthority { asset_id, .. }
+            | AtomicPayloadOp::RenounceMintAuthority { asset_id }
+            | AtomicPayloadOp::UpdateMetadata { asset_id, .. } => Some(*asset_id),
+            AtomicPayloadOp::AddPairLiquidity { pool_id, .. }
+            | AtomicPayloadOp::RemovePairLiquidity { pool_id, .. }
+            | AtomicPayloadOp::SwapPairExactIn { pool_id, .. }
+            | AtomicPayloadOp::SwapPairExactOut { pool_id, .. } => Some(*pool_id),
             _ => None,
         };
         let creates_asset_id = match op {
             AtomicPayloadOp::CreateAsset { .. }
             | AtomicPayloadOp::CreateAssetWithMint { .. }
-            | AtomicPayloadOp::CreateLiquidityAsset { .. } => Some(txid_bytes),
+            | AtomicPayloadOp::CreateLiquidityAsset { .. }
+            | AtomicPayloadOp::CreatePairPool { .. } => Some(txid_bytes),
             _ => None,
         };
         let (pool_asset_id, pool_nonce) = pool.unwrap_or(([0u8; 32], 0));
@@ -2753,7 +2776,8 @@ impl VirtualStateProcessor {
                 if !tx.subnetwork_id.is_payload() || tx.payload.is_empty() {
                     continue;
                 }
-                match parse_atomic_payload(tx.payload.as_slice()) {
+                // Any CAT-tagged payload counts here, activated or not
+                match parse_atomic_payload(tx.payload.as_slice(), true) {
                     Ok(None) => {}
                     Ok(Some(_)) | Err(_) => return Ok(true),
                 }
@@ -3308,6 +3332,23 @@ impl VirtualStateProcessor {
         }
     }
 
+    pub fn get_atomic_consensus_balance_anchor(&self, block_hash: Hash) -> ConsensusResult<Option<AtomicConsensusBalanceAnchor>> {
+        let expected_state_hash = match self.atomic_state_store.get_root_record(block_hash) {
+            Ok(root) => root.state_hash,
+            Err(StoreError::KeyNotFound(_)) => return Ok(None),
+            Err(_) => return Err(ConsensusError::General("failed reading atomic consensus root")),
+        };
+
+        match self.materialize_selected_chain_atomic_state_at(block_hash, expected_state_hash) {
+            Ok(Some(state)) => Ok(state.consensus_balance_anchor()),
+            Ok(None) => Ok(None),
+            Err(err) => {
+                warn!("failed materializing Atomic consensus balance anchor for `{block_hash}`: {err}");
+                Ok(None)
+            }
+        }
+    }
+
     fn materialize_selected_chain_atomic_state_at(
         &self,
         target_hash: Hash,
@@ -7,8 +7,9 @@ use crate::{
     },
     model::stores::{
         atomic_state::{
-            AtomicAssetClass, AtomicAssetState, AtomicBalanceKey, AtomicConsensusState, AtomicLiquidityFeeRecipientState,
-            AtomicLiquidityPoolState, AtomicNonceKey, AtomicSupplyMode,
+            AtomicAllowanceKey, AtomicAllowanceState, AtomicAssetClass, AtomicAssetState, AtomicBalanceKey, AtomicConsensusState,
+            AtomicLiquidityFeeRecipientState, AtomicLiquidityPoolState, AtomicNonceKey, AtomicPairPoolState, AtomicSupplyMode,
+            AtomicTokenLockState, ATOMIC_CURRENT_TOKEN_VERSION, ATOMIC_MIN_PAIR_LIQUIDITY_SHARES,
         },
         block_transactions::BlockTransactionsStoreReader,
         daa::DaaStoreReader,
@@ -85,6 +86,9 @@ const INDIVIDUAL_MIN_VIRTUAL_TOKEN_MULTIPLIER_BPS: u16 = 10_100;
 const INDIVIDUAL_MAX_VIRTUAL_TOKEN_MULTIPLIER_BPS: u16 = 20_000;
 const INDIVIDUAL_VIRTUAL_TOKEN_MULTIPLIER_STEP_BPS: u16 = 100;
 const VIRTUAL_TOKEN_MULTIPLIER_BPS_DENOMINATOR: u16 = 10_000;
+const PAIR_FEE_BPS_DENOMINATOR: u64 = 10_000;
+const PAIR_LP_NAME: &[u8] = b"CAT Pair LP";
+const PAIR_LP_SYMBOL: &[u8] = b"CATLP";
 
 #[derive(Clone, Copy, Debug)]
 struct VaultTransition {
@@ -154,18 +158,19 @@ impl AtomicBlockStateGrowth {
 #[cfg(test)]
 mod tests {
     use super::{
-        apply_fee_to_pool, atomic_op_allows_liquidity_vault_output, calculate_trade_fee, cpmm_buy, cpmm_sell,
-        initial_virtual_cpay_reserves_sompi_for_curve, initial_virtual_token_reserves_for_curve, min_gross_input_for_token_out,
-        validate_liquidity_claim_authorization, validate_liquidity_creation_parameters, validate_liquidity_curve_parameters,
-        AtomicBlockStateGrowth, AtomicPayloadOp, AtomicStateGrowth, AtomicStateGrowthLimits,
-        AGGRESSIVE_INITIAL_VIRTUAL_CPAY_RESERVES_SOMPI, DEFAULT_LIQUIDITY_CURVE_MODE, INDIVIDUAL_MAX_VIRTUAL_CPAY_RESERVES_SOMPI,
-        INDIVIDUAL_MAX_VIRTUAL_TOKEN_MULTIPLIER_BPS, INDIVIDUAL_MIN_VIRTUAL_CPAY_RESERVES_SOMPI,
-        INDIVIDUAL_MIN_VIRTUAL_TOKEN_MULTIPLIER_BPS, INDIVIDUAL_VIRTUAL_CPAY_STEP_SOMPI, INDIVIDUAL_VIRTUAL_TOKEN_MULTIPLIER_STEP_BPS,
-        INITIAL_REAL_CPAY_RESERVES_SOMPI, INITIAL_VIRTUAL_CPAY_RESERVES_SOMPI, INITIAL_VIRTUAL_TOKEN_RESERVES,
-        LIQUIDITY_CURVE_MODE_AGGRESSIVE, LIQUIDITY_CURVE_MODE_BASIC, LIQUIDITY_CURVE_MODE_INDIVIDUAL, LIQUIDITY_TOKEN_SUPPLY_RAW,
-        MAX_LIQUIDITY_SUPPLY_RAW, MIN_LIQUIDITY_SUPPLY_RAW, VIRTUAL_TOKEN_MULTIPLIER_BPS_DENOMINATOR,
+        apply_fee_to_pool, atomic_op_allows_liquidity_vault_output, calculate_trade_fee, cpmm_buy, cpmm_buy_exact_out, cpmm_sell,
+        cpmm_sell_exact_out, gross_amount_for_net_amount, initial_virtual_cpay_reserves_sompi_for_curve,
+        initial_virtual_token_reserves_for_curve, min_gross_input_for_token_out, pair_add_liquidity, pair_initial_shares,
+        pair_remove_liquidity, pair_swap_exact_in, pair_swap_exact_out, validate_liquidity_claim_authorization,
+        validate_liquidity_creation_parameters, validate_liquidity_curve_parameters, AtomicBlockStateGrowth, AtomicPayloadOp,
+        AtomicStateGrowth, AtomicStateGrowthLimits, AGGRESSIVE_INITIAL_VIRTUAL_CPAY_RESERVES_SOMPI, DEFAULT_LIQUIDITY_CURVE_MODE,
+        INDIVIDUAL_MAX_VIRTUAL_CPAY_RESERVES_SOMPI, INDIVIDUAL_MAX_VIRTUAL_TOKEN_MULTIPLIER_BPS,
+        INDIVIDUAL_MIN_VIRTUAL_CPAY_RESERVES_SOMPI, INDIVIDUAL_MIN_VIRTUAL_TOKEN_MULTIPLIER_BPS, INDIVIDUAL_VIRTUAL_CPAY_STEP_SOMPI,
+        INDIVIDUAL_VIRTUAL_TOKEN_MULTIPLIER_STEP_BPS, INITIAL_REAL_CPAY_RESERVES_SOMPI, INITIAL_VIRTUAL_CPAY_RESERVES_SOMPI,
+        INITIAL_VIRTUAL_TOKEN_RESERVES, LIQUIDITY_CURVE_MODE_AGGRESSIVE, LIQUIDITY_CURVE_MODE_BASIC, LIQUIDITY_CURVE_MODE_INDIVIDUAL,
+        LIQUIDITY_TOKEN_SUPPLY_RAW, MAX_LIQUIDITY_SUPPLY_RAW, MIN_LIQUIDITY_SUPPLY_RAW, VIRTUAL_TOKEN_MULTIPLIER_BPS_DENOMINATOR,
     };
-    use crate::model::stores::atomic_state::AtomicLiquidityFeeRecipientState;
+    use crate::model::stores::atomic_state::{AtomicLiquidityFeeRecipientState, ATOMIC_MIN_PAIR_LIQUIDITY_SHARES};
 
     #[test]
     fn atomic_state_growth_limits_reject_block_state_spam() {
@@ -767,6 +772,33 @@ mod tests {
             let index_sell =
                 index_math::cpmm_sell(real_cpay_reserves_sompi, virtual_cpay_reserves_sompi, virtual_token_reserves, token_in).ok();
             assert_eq!(consensus_sell, index_sell, "sell drift in case {step}");
+
+            let consensus_buy_exact_out =
+                cpmm_buy_exact_out(real_token_reserves, virtual_cpay_reserves_sompi, virtual_token_reserves, target_token_out).ok();
+            let index_buy_exact_out = index_math::cpmm_buy_exact_out(
+                real_token_reserves,
+                virtual_cpay_reserves_sompi,
+                virtual_token_reserves,
+                target_token_out,
+            )
+            .ok();
+            assert_eq!(consensus_buy_exact_out, index_buy_exact_out, "exact-out buy drift in case {step}");
+            let net_out = 1 + (seed.rotate_left(41) % virtual_cpay_reserves_sompi);
+            assert_eq!(
+                gross_amount_for_net_amount(net_out, fee_bps).ok(),
+                index_math::gross_amount_for_net_amount(net_out, fee_bps).ok(),
+                "exact-out gross drift in case {step}"
+            );
+            let consensus_sell_exact_out =
+                cpmm_sell_exact_out(real_cpay_reserves_sompi, virtual_cpay_reserves_sompi, virtual_token_reserves, net_out).ok();
+            let index_sell_exact_out = index_math::cpmm_sell_exact_out(
+                real_cpay_reserves_sompi,
+                virtual_cpay_reserves_sompi,
+                virtual_token_reserves,
+                net_out,
+            )
+            .ok();
+            assert_eq!(consensus_sell_exact_out, index_sell_exact_out, "exact-out sell drift in case {step}");
         }
 
         let consensus_default_tokens =
@@ -775,6 +807,63 @@ mod tests {
         let consensus_default_cpay = initial_virtual_cpay_reserves_sompi_for_curve(DEFAULT_LIQUIDITY_CURVE_MODE, 0).unwrap();
         assert_eq!(consensus_default_cpay, INITIAL_VIRTUAL_CPAY_RESERVES_SOMPI);
     }
+
+    #[test]
+    fn consensus_pair_math_matches_atomicindex_reference() {
+        use cryptix_atomicindex::liquidity_math as index_math;
+
+        assert_eq!(pair_initial_shares(40_000, 90_000).unwrap(), 60_000);
+        assert!(pair_initial_shares(1_000, 1_000).is_err(), "seed must exceed the locked shares");
+        assert_eq!(pair_add_liquidity(40_000, 90_000, 60_000, 4_000, 20_000).unwrap(), (6_000, 4_000, 9_000));
+        assert_eq!(pair_swap_exact_in(1_000_000, 1_000_000, 10_000, 30).unwrap(), (9_871, 30));
+
+        let fee_schedule = [0u16, 1, 30, 100, 1_000];
+        let mut seed = 0xA11C_E5EE_D00D_F00Du64;
+        for step in 0..5_000 {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            let reserve_a = 1 + u128::from(seed % 1_000_000_000_000);
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            let reserve_b = 1 + u128::from(seed % 1_000_000_000);
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            let amount = 1 + u128::from(seed % 10_000_000);
+            let fee_bps = fee_schedule[step % fee_schedule.len()];
+
+            assert_eq!(
+                pair_initial_shares(reserve_a, reserve_b).ok(),
+                index_math::pair_initial_shares(reserve_a, reserve_b).ok(),
+                "initial shares drift in case {step}"
+            );
+            assert_eq!(
+                pair_swap_exact_in(reserve_a, reserve_b, amount, fee_bps).ok(),
+                index_math::pair_swap_exact_in(reserve_a, reserve_b, amount, fee_bps).ok(),
+                "exact-in drift in case {step}"
+            );
+            let amount_out = amount % reserve_b;
+            let consensus_exact_out = pair_swap_exact_out(reserve_a, reserve_b, amount_out, fee_bps).ok();
+            assert_eq!(
+                consensus_exact_out,
+                index_math::pair_swap_exact_out(reserve_a, reserve_b, amount_out, fee_bps).ok(),
+                "exact-out drift in case {step}"
+            );
+            if let Some((amount_in, _)) = consensus_exact_out {
+                let (out, _) = pair_swap_exact_in(reserve_a, reserve_b, amount_in, fee_bps).unwrap();
+                assert!(out >= amount_out, "exact-out input must cover the requested output in case {step}");
+            }
+
+            let total_shares = ATOMIC_MIN_PAIR_LIQUIDITY_SHARES + 1 + u128::from(seed.rotate_left(21) % 1_000_000_000);
+            assert_eq!(
+                pair_add_liquidity(reserve_a, reserve_b, total_shares, amount, amount).ok(),
+                index_math::pair_add_liquidity(reserve_a, reserve_b, total_shares, amount, amount).ok(),
+                "add liquidity drift in case {step}"
+            );
+            let shares = 1 + amount % (total_shares - ATOMIC_MIN_PAIR_LIQUIDITY_SHARES);
+            assert_eq!(
+                pair_remove_liquidity(reserve_a, reserve_b, total_shares, shares).ok(),
+                index_math::pair_remove_liquidity(reserve_a, reserve_b, total_shares, shares).ok(),
+                "remove liquidity drift in case {step}"
+            );
+        }
+    }
 }
 
 fn calculate_trade_fee(amount: u64, fee_bps: u16) -> TxResult<u64> {
@@ -1004,6 +1093,94 @@ fn cpmm_sell(
     Ok((gross_out, new_real_cpay_reserves_sompi, x_after, y_after))
 }
 
+fn cpmm_buy_exact_out(
+    real_token_reserves: u128,
+    virtual_cpay_reserves_sompi: u64,
+    virtual_token_reserves: u128,
+    token_out: u128,
+) -> TxResult<(u64, u128, u64, u128)> {
+    if token_out == 0 || virtual_cpay_reserves_sompi == 0 || virtual_token_reserves == 0 {
+        return Err(TxRuleError::InvalidAtomicPayload("exact-out buy target token_out is invalid".to_string()));
+    }
+    let spendable_tokens = real_token_reserves
+        .checked_sub(MIN_REAL_TOKEN_RESERVE)
+        .ok_or_else(|| TxRuleError::InvalidAtomicPayload("exact-out buy real token reserve floor reached".to_string()))?;
+    if token_out > spendable_tokens {
+        return Err(TxRuleError::InvalidAtomicPayload("exact-out buy token_out drains final token".to_string()));
+    }
+    let y_after = virtual_token_reserves
+        .checked_sub(token_out)
+        .ok_or_else(|| TxRuleError::InvalidAtomicPayload("exact-out buy y_after underflow".to_string()))?;
+    if y_after == 0 {
+        return Err(TxRuleError::InvalidAtomicPayload("exact-out buy y_after cannot be zero".to_string()));
+    }
+
+    let k = Uint256::from_u64(virtual_cpay_reserves_sompi) * Uint256::from_u128(virtual_token_reserves);
+    let x_after_u256 = ceil_div_u256(k, Uint256::from_u128(y_after));
+    let x_after_u128 = u128::try_from(x_after_u256)
+        .map_err(|_| TxRuleError::InvalidAtomicPayload("exact-out buy x_after conversion overflow".to_string()))?;
+    let x_after = u64::try_from(x_after_u128)
+        .map_err(|_| TxRuleError::InvalidAtomicPayload("exact-out buy x_after does not fit u64".to_string()))?;
+    let net_in = x_after
+        .checked_sub(virtual_cpay_reserves_sompi)
+        .ok_or_else(|| TxRuleError::InvalidAtomicPayload("exact-out buy x_after below x_before".to_string()))?;
+    if net_in == 0 {
+        return Err(TxRuleError::InvalidAtomicPayload("exact-out buy produced zero net input".to_string()));
+    }
+    let new_real_token_reserves = real_token_reserves
+        .checked_sub(token_out)
+        .ok_or_else(|| TxRuleError::InvalidAtomicPayload("exact-out buy real token reserve underflow".to_string()))?;
+    Ok((net_in, new_real_token_reserves, x_after, y_after))
+}
+
+fn cpmm_sell_exact_out(
+    real_cpay_reserves_sompi: u64,
+    virtual_cpay_reserves_sompi: u64,
+    virtual_token_reserves: u128,
+    gross_out: u64,
+) -> TxResult<(u128, u64, u64, u128)> {
+    if gross_out == 0 || virtual_token_reserves == 0 {
+        return Err(TxRuleError::InvalidAtomicPayload("exact-out sell target gross_out is invalid".to_string()));
+    }
+    let spendable_cpay = real_cpay_reserves_sompi
+        .checked_sub(MIN_CPAY_RESERVE_SOMPI)
+        .ok_or_else(|| TxRuleError::InvalidAtomicPayload("exact-out sell real CPAY reserve floor reached".to_string()))?;
+    if gross_out > spendable_cpay {
+        return Err(TxRuleError::InvalidAtomicPayload("exact-out sell would drain final real sompi".to_string()));
+    }
+    let x_after = virtual_cpay_reserves_sompi
+        .checked_sub(gross_out)
+        .ok_or_else(|| TxRuleError::InvalidAtomicPayload("exact-out sell x_after underflow".to_string()))?;
+    if x_after == 0 {
+        return Err(TxRuleError::InvalidAtomicPayload("exact-out sell x_after cannot be zero".to_string()));
+    }
+
+    let k = Uint256::from_u64(virtual_cpay_reserves_sompi) * Uint256::from_u128(virtual_token_reserves);
+    let y_after_u256 = ceil_div_u256(k, Uint256::from_u64(x_after));
+    let y_after = u128::try_from(y_after_u256)
+        .map_err(|_| TxRuleError::InvalidAtomicPayload("exact-out sell y_after conversion overflow".to_string()))?;
+    let token_in = y_after
+        .checked_sub(virtual_token_reserves)
+        .ok_or_else(|| TxRuleError::InvalidAtomicPayload("exact-out sell y_after below y_before".to_string()))?;
+    if token_in == 0 {
+        return Err(TxRuleError::InvalidAtomicPayload("exact-out sell produced zero token_in".to_string()));
+    }
+    let new_real_cpay_reserves_sompi = real_cpay_reserves_sompi
+        .checked_sub(gross_out)
+        .ok_or_else(|| TxRuleError::InvalidAtomicPayload("exact-out sell real CPAY reserve underflow".to_string()))?;
+    Ok((token_in, new_real_cpay_reserves_sompi, x_after, y_after))
+}
+
+/// Returns `(gross, fee)` with `gross - fee == net`; the fee always comes off the CPAY side of a trade.
+fn gross_amount_for_net_amount(net: u64, fee_bps: u16) -> TxResult<(u64, u64)> {
+    let gross = min_gross_input_for_net_input(net, fee_bps)?;
+    let fee = calculate_trade_fee(gross, fee_bps)?;
+    if gross.checked_sub(fee).ok_or_else(|| TxRuleError::InvalidAtomicPayload("exact-out fee underflow".to_string()))? != net {
+        return Err(TxRuleError::InvalidAtomicPayload("exact-out gross amount does not settle the net amount".to_string()));
+    }
+    Ok((gross, fee))
+}
+
 fn ceil_div_u256(numerator: Uint256, denominator: Uint256) -> Uint256 {
     let quotient = numerator / denominator;
     let remainder = numerator % denominator;
@@ -1014,6 +1191,123 @@ fn ceil_div_u256(numerator: Uint256, denominator: Uint256) -> Uint256 {
     }
 }
 
+fn pair_u256_to_u128(value: Uint256, context: &str) -> TxResult<u128> {
+    u128::try_from(value).map_err(|_| TxRuleError::InvalidAtomicPayload(format!("pair {context} does not fit u128")))
+}
+
+fn isqrt_u256(value: Uint256) -> Uint256 {
+    if value.is_zero() {
+        return value;
+    }
+    let mut x = value;
+    let mut y = (x + Uint256::from_u64(1)) >> 1;
+    while y < x {
+        x = y;
+        y = (x + value / x) >> 1;
+    }
+    x
+}
+
+fn calculate_pair_fee(amount: u128, fee_bps: u16) -> TxResult<u128> {
+    let fee = Uint256::from_u128(amount) * Uint256::from_u64(u64::from(fee_bps)) / Uint256::from_u64(PAIR_FEE_BPS_DENOMINATOR);
+    pair_u256_to_u128(fee, "fee")
+}
+
+fn pair_initial_shares(amount_a: u128, amount_b: u128) -> TxResult<u128> {
+    let shares = pair_u256_to_u128(isqrt_u256(Uint256::from_u128(amount_a) * Uint256::from_u128(amount_b)), "initial shares")?;
+    if shares <= ATOMIC_MIN_PAIR_LIQUIDITY_SHARES {
+        return Err(TxRuleError::InvalidAtomicPayload(format!(
+            "pair seed liquidity `{shares}` must exceed the `{ATOMIC_MIN_PAIR_LIQUIDITY_SHARES}` locked shares"
+        )));
+    }
+    Ok(shares)
+}
+
+/// Returns `(shares_out, amount_a_in, amount_b_in)` for a proportional deposit capped by both maxima.
+fn pair_add_liquidity(
+    reserve_a: u128,
+    reserve_b: u128,
+    total_shares: u128,
+    amount_a_max: u128,
+    amount_b_max: u128,
+) -> TxResult<(u128, u128, u128)> {
+    if reserve_a == 0 || reserve_b == 0 || total_shares == 0 {
+        return Err(TxRuleError::InvalidAtomicPayload("pair add liquidity on an empty pool".to_string()));
+    }
+    let total = Uint256::from_u128(total_shares);
+    let shares_a = Uint256::from_u128(amount_a_max) * total / Uint256::from_u128(reserve_a);
+    let shares_b = Uint256::from_u128(amount_b_max) * total / Uint256::from_u128(reserve_b);
+    let shares = pair_u256_to_u128(shares_a.min(shares_b), "shares out")?;
+    if shares == 0 {
+        return Err(TxRuleError::InvalidAtomicPayload("pair add liquidity produced zero shares".to_string()));
+    }
+    let amount_a_in =
+        pair_u256_to_u128(ceil_div_u256(Uint256::from_u128(shares) * Uint256::from_u128(reserve_a), total), "amount_a_in")?;
+    let amount_b_in =
+        pair_u256_to_u128(ceil_div_u256(Uint256::from_u128(shares) * Uint256::from_u128(reserve_b), total), "amount_b_in")?;
+    Ok((shares, amount_a_in, amount_b_in))
+}
+
+/// Returns `(amount_a_out, amount_b_out)` for redeeming `shares` against the current reserves.
+fn pair_remove_liquidity(reserve_a: u128, reserve_b: u128, total_shares: u128, shares: u128) -> TxResult<(u128, u128)> {
+    if shares == 0 || total_shares <= ATOMIC_MIN_PAIR_LIQUIDITY_SHARES || shares > total_shares - ATOMIC_MIN_PAIR_LIQUIDITY_SHARES {
+        return Err(TxRuleError::InvalidAtomicPayload("pair remove liquidity shares exceed redeemable supply".to_string()));
+    }
+    let total = Uint256::from_u128(total_shares);
+    let amount_a_out = pair_u256_to_u128(Uint256::from_u128(shares) * Uint256::from_u128(reserve_a) / total, "amount_a_out")?;
+    let amount_b_out = pair_u256_to_u128(Uint256::from_u128(shares) * Uint256::from_u128(reserve_b) / total, "amount_b_out")?;
+    if amount_a_out == 0 || amount_b_out == 0 {
+        return Err(TxRuleError::InvalidAtomicPayload("pair remove liquidity produced zero output".to_string()));
+    }
+    Ok((amount_a_out, amount_b_out))
+}
+
+/// Returns `(amount_out, fee)`; the fee is charged on the input side and stays in the pool.
+fn pair_swap_exact_in(reserve_in: u128, reserve_out: u128, amount_in: u128, fee_bps: u16) -> TxResult<(u128, u128)> {
+    if reserve_in == 0 || reserve_out == 0 {
+        return Err(TxRuleError::InvalidAtomicPayload("pair swap on an empty pool".to_string()));
+    }
+    let fee = calculate_pair_fee(amount_in, fee_bps)?;
+    let net_in = amount_in - fee;
+    reserve_in
+        .checked_add(amount_in)
+        .ok_or_else(|| TxRuleError::InvalidAtomicPayload("pair swap input reserve overflow".to_string()))?;
+    let denominator = Uint256::from_u128(reserve_in) + Uint256::from_u128(net_in);
+    let amount_out = pair_u256_to_u128(Uint256::from_u128(reserve_out) * Uint256::from_u128(net_in) / denominator, "amount_out")?;
+    if amount_out == 0 {
+        return Err(TxRuleError::InvalidAtomicPayload("pair swap produced zero output".to_string()));
+    }
+    Ok((amount_out, fee))
+}
+
+/// Returns the minimal `(amount_in, fee)` whose exact-in swap yields at least `amount_out`.
+fn pair_swap_exact_out(reserve_in: u128, reserve_out: u128, amount_out: u128, fee_bps: u16) -> TxResult<(u128, u128)> {
+    if reserve_in == 0 || amount_out == 0 || amount_out >= reserve_out {
+        return Err(TxRuleError::InvalidAtomicPayload("pair swap amount_out must be below the output reserve".to_string()));
+    }
+    if u64::from(fee_bps) >= PAIR_FEE_BPS_DENOMINATOR {
+        return Err(TxRuleError::InvalidAtomicPayload("pair swap fee_bps is invalid".to_string()));
+    }
+    let k = Uint256::from_u128(reserve_in) * Uint256::from_u128(reserve_out);
+    let reserve_in_after = ceil_div_u256(k, Uint256::from_u128(reserve_out - amount_out));
+    let net_in = pair_u256_to_u128(reserve_in_after - Uint256::from_u128(reserve_in), "net input")?;
+    // Smallest gross input whose floor fee leaves at least `net_in`.
+    let fee_denominator = Uint256::from_u64(PAIR_FEE_BPS_DENOMINATOR - u64::from(fee_bps));
+    let mut amount_in = pair_u256_to_u128(
+        Uint256::from_u128(net_in - 1) * Uint256::from_u64(PAIR_FEE_BPS_DENOMINATOR) / fee_denominator + Uint256::from_u64(1),
+        "gross input",
+    )?;
+    while amount_in - calculate_pair_fee(amount_in, fee_bps)? < net_in {
+        amount_in =
+            amount_in.checked_add(1).ok_or_else(|| TxRuleError::InvalidAtomicPayload("pair swap gross input overflow".to_string()))?;
+    }
+    let (actual_out, fee) = pair_swap_exact_in(reserve_in, reserve_out, amount_in, fee_bps)?;
+    if actual_out < amount_out {
+        return Err(TxRuleError::InvalidAtomicPayload("pair exact-out verification failed".to_string()));
+    }
+    Ok((amount_in, fee))
+}
+
 fn initial_virtual_token_reserves(max_supply: u128) -> TxResult<u128> {
     initial_virtual_token_reserves_for_mode(max_supply, DEFAULT_LIQUIDITY_CURVE_MODE)
 }
@@ -1147,6 +1441,8 @@ fn atomic_op_allows_liquidity_vault_output(op: &AtomicPayloadOp) -> bool {
             | AtomicPayloadOp::BuyLiquidityExactIn { .. }
             | AtomicPayloadOp::SellLiquidityExactIn { .. }
             | AtomicPayloadOp::ClaimLiquidityFees { .. }
+            | AtomicPayloadOp::BuyLiquidityExactOut { .. }
+            | AtomicPayloadOp::SellLiquidityExactOut { .. }
     )
 }
 
@@ -1193,16 +1489,130 @@ pub(crate) fn atomic_nonce_key_for_op(owner_id: [u8; 32], op: &AtomicPayloadOp)
     match op {
         AtomicPayloadOp::CreateAsset { .. }
         | AtomicPayloadOp::CreateAssetWithMint { .. }
-        | AtomicPayloadOp::CreateLiquidityAsset { .. } => AtomicNonceKey::owner(owner_id),
+        | AtomicPayloadOp::CreateLiquidityAsset { .. }
+        | AtomicPayloadOp::CreatePairPool { .. } => AtomicNonceKey::owner(owner_id),
         AtomicPayloadOp::Transfer { asset_id, .. }
         | AtomicPayloadOp::Mint { asset_id, .. }
         | AtomicPayloadOp::Burn { asset_id, .. }
         | AtomicPayloadOp::BuyLiquidityExactIn { asset_id, .. }
         | AtomicPayloadOp::SellLiquidityExactIn { asset_id, .. }
-        | AtomicPayloadOp::ClaimLiquidityFees { asset_id, .. } => AtomicNonceKey::asset(owner_id, *asset_id),
+        | AtomicPayloadOp::ClaimLiquidityFees { asset_id, .. }
+        | AtomicPayloadOp::BuyLiquidityExactOut { asset_id, .. }
+        | AtomicPayloadOp::SellLiquidityExactOut { asset_id, .. }
+        | AtomicPayloadOp::Approve { asset_id, .. }
+        | AtomicPayloadOp::TransferFrom { asset_id, .. }
+        | AtomicPayloadOp::BatchTransfer { asset_id, .. }
+        | AtomicPayloadOp::LockTokens { asset_id, .. }
+        | AtomicPayloadOp::TransferMintAuthority { asset_id, .. }
+        | AtomicPayloadOp::RenounceMintAuthority { asset_id }
+        | AtomicPayloadOp::UpdateMetadata { asset_id, .. } => AtomicNonceKey::asset(owner_id, *asset_id),
+        AtomicPayloadOp::AddPairLiquidity { pool_id, .. }
+        | AtomicPayloadOp::RemovePairLiquidity { pool_id, .. }
+        | AtomicPayloadOp::SwapPairExactIn { pool_id, .. }
+        | AtomicPayloadOp::SwapPairExactOut { pool_id, .. } => AtomicNonceKey::asset(owner_id, *pool_id),
+    }
+}
+
+/// Rejects a debit of `amount` from `key` that would dip into tokens still locked at `daa_score`.
+/// Debits above the whole balance are left to the caller's own insufficient-balance check.
+fn ensure_atomic_unlocked_balance(
+    atomic_state: &AtomicConsensusState,
+    key: &AtomicBalanceKey,
+    amount: u128,
+    daa_score: u64,
+    action: &str,
+) -> TxResult<()> {
+    let spendable = atomic_state.spendable_balance(key, daa_score);
+    if amount > spendable && amount <= atomic_state.balance(key) {
+        return Err(TxRuleError::InvalidAtomicPayload(format!(
+            "{action} amount `{amount}` exceeds unlocked balance `{spendable}` of asset `{}`",
+            faster_hex::hex_string(&key.asset_id)
+        )));
+    }
+    Ok(())
+}
+
+/// Drops the lock on `key` once it has fully released, so spent balances do not keep stale lock records.
+fn prune_released_atomic_lock(atomic_state: &mut AtomicConsensusState, key: AtomicBalanceKey, daa_score: u64) {
+    if let Some(lock) = atomic_state.lock(&key) {
+        if lock.locked_at(daa_score) == 0 {
+            atomic_state.set_lock(key, AtomicTokenLockState { amount: 0, ..lock });
+        }
+    }
+}
+
+/// Returns the balance of `key` after debiting `amount`, honouring token locks.
+fn atomic_balance_after_debit(
+    atomic_state: &AtomicConsensusState,
+    key: &AtomicBalanceKey,
+    amount: u128,
+    daa_score: u64,
+    action: &str,
+) -> TxResult<u128> {
+    ensure_atomic_unlocked_balance(atomic_state, key, amount, daa_score, action)?;
+    atomic_state.balance(key).checked_sub(amount).ok_or_else(|| {
+        TxRuleError::InvalidAtomicPayload(format!(
+            "insufficient balance for {action} in asset `{}`",
+            faster_hex::hex_string(&key.asset_id)
+        ))
+    })
+}
+
+/// Returns the balance of `key` after crediting `amount`.
+fn atomic_balance_after_credit(
+    atomic_state: &AtomicConsensusState,
+    key: &AtomicBalanceKey,
+    amount: u128,
+    action: &str,
+) -> TxResult<u128> {
+    atomic_state.balance(key).checked_add(amount).ok_or_else(|| {
+        TxRuleError::InvalidAtomicPayload(format!(
+            "balance overflow for {action} in asset `{}`",
+            faster_hex::hex_string(&key.asset_id)
+        ))
+    })
+}
+
+/// Returns whether `asset_id` is the pool's `asset_a` side.
+fn pair_swap_side(pair: &AtomicPairPoolState, asset_id: [u8; 32]) -> TxResult<bool> {
+    if asset_id == pair.asset_a_id {
+        Ok(true)
+    } else if asset_id == pair.asset_b_id {
+        Ok(false)
+    } else {
+        Err(TxRuleError::InvalidAtomicPayload(format!("asset `{}` is not part of the pair pool", faster_hex::hex_string(&asset_id))))
+    }
+}
+
+/// Returns the `(input, output)` reserve keys for a swap direction.
+fn pair_reserve_keys(pair: &AtomicPairPoolState, pool_id: [u8; 32], in_is_a: bool) -> (AtomicBalanceKey, AtomicBalanceKey) {
+    if in_is_a {
+        (pair.reserve_key_a(pool_id), pair.reserve_key_b(pool_id))
+    } else {
+        (pair.reserve_key_b(pool_id), pair.reserve_key_a(pool_id))
     }
 }
 
+fn load_atomic_pair_pool(
+    atomic_state: &AtomicConsensusState,
+    pool_id: [u8; 32],
+    action: &str,
+) -> TxResult<(AtomicAssetState, AtomicPairPoolState)> {
+    let asset = atomic_state.cloned_asset(&pool_id).ok_or_else(|| {
+        TxRuleError::InvalidAtomicPayload(format!("{action} references unknown pool `{}`", faster_hex::hex_string(&pool_id)))
+    })?;
+    let pair = match (asset.asset_class, asset.pair_pool) {
+        (AtomicAssetClass::Pair, Some(pair)) => pair,
+        _ => {
+            return Err(TxRuleError::InvalidAtomicPayload(format!(
+                "{action} is only valid for pair pools (`{}` is not)",
+                faster_hex::hex_string(&pool_id)
+            )))
+        }
+    };
+    Ok((asset, pair))
+}
+
 /// A context for processing the UTXO state of a block with respect to its selected parent.
 /// Note this can also be the virtual block.
 pub(super) struct UtxoProcessingContext<'a> {
@@ -1582,7 +1992,10 @@ impl VirtualStateProcessor {
             return Ok(growth);
         }
 
-        let Some(parsed_payload) = parse_atomic_payload(tx_ref.payload.as_slice()).map_err(TxRuleError::InvalidAtomicPayload)? else {
+        let extended_ops_active = self.transaction_validator.is_atomic_extended_ops_active(pov_daa_score);
+        let Some(parsed_payload) =
+            parse_atomic_payload(tx_ref.payload.as_slice(), extended_ops_active).map_err(TxRuleError::InvalidAtomicPayload)?
+        else {
             return Ok(growth);
         };
 
@@ -1633,15 +2046,97 @@ impl VirtualStateProcessor {
                     growth.new_balance_keys = growth.new_balance_keys.saturating_add(1);
                 }
             }
-            AtomicPayloadOp::BuyLiquidityExactIn { asset_id, .. } => {
+            AtomicPayloadOp::BuyLiquidityExactIn { asset_id, .. } | AtomicPayloadOp::BuyLiquidityExactOut { asset_id, .. } => {
                 let receiver_key = AtomicBalanceKey { asset_id: *asset_id, owner_id };
                 if !atomic_state.has_balance(&receiver_key) {
                     growth.new_balance_keys = growth.new_balance_keys.saturating_add(1);
                 }
             }
+            AtomicPayloadOp::Approve { asset_id, spender_owner_id, amount, .. } => {
+                // Allowance keys share the balance-key growth budget.
+                let allowance_key = AtomicAllowanceKey { asset_id: *asset_id, owner_id, spender_id: *spender_owner_id };
+                if *amount > 0 && !atomic_state.has_allowance(&allowance_key) {
+                    growth.new_balance_keys = growth.new_balance_keys.saturating_add(1);
+                }
+            }
+            AtomicPayloadOp::TransferFrom { asset_id, from_owner_id, to_owner_id, amount } => {
+                let from_key = AtomicBalanceKey { asset_id: *asset_id, owner_id: *from_owner_id };
+                let to_key = AtomicBalanceKey { asset_id: *asset_id, owner_id: *to_owner_id };
+                if *amount > 0 && from_key != to_key && !atomic_state.has_balance(&to_key) {
+                    growth.new_balance_keys = growth.new_balance_keys.saturating_add(1);
+                }
+            }
+            AtomicPayloadOp::BatchTransfer { asset_id, recipients } => {
+                for recipient in recipients.iter().filter(|recipient| recipient.to_owner_id != owner_id) {
+                    let to_key = AtomicBalanceKey { asset_id: *asset_id, owner_id: recipient.to_owner_id };
+                    if !atomic_state.has_balance(&to_key) {
+                        growth.new_balance_keys = growth.new_balance_keys.saturating_add(1);
+                    }
+                }
+            }
+            AtomicPayloadOp::LockTokens { asset_id, beneficiary_owner_id, .. } => {
+                // Lock keys share the balance-key growth budget.
+                let beneficiary_key = AtomicBalanceKey { asset_id: *asset_id, owner_id: *beneficiary_owner_id };
+                if *beneficiary_owner_id != owner_id && !atomic_state.has_balance(&beneficiary_key) {
+                    growth.new_balance_keys = growth.new_balance_keys.saturating_add(1);
+                }
+                if !atomic_state.has_lock(&beneficiary_key) {
+                    growth.new_balance_keys = growth.new_balance_keys.saturating_add(1);
+                }
+            }
+            AtomicPayloadOp::CreatePairPool { asset_a_id, asset_b_id, .. } => {
+                let pool_id = tx_ref.id().as_bytes();
+                if !atomic_state.has_asset(&pool_id) {
+                    growth.new_assets = 1;
+                    growth.new_pools = 1;
+                }
+                // Two reserve balances plus the creator's and the pool's locked share balances.
+                for key in [
+                    AtomicBalanceKey { asset_id: *asset_a_id, owner_id: pool_id },
+                    AtomicBalanceKey { asset_id: *asset_b_id, owner_id: pool_id },
+                    AtomicBalanceKey { asset_id: pool_id, owner_id },
+                    AtomicBalanceKey { asset_id: pool_id, owner_id: pool_id },
+                ] {
+                    if !atomic_state.has_balance(&key) {
+                        growth.new_balance_keys = growth.new_balance_keys.saturating_add(1);
+                    }
+                }
+            }
+            AtomicPayloadOp::AddPairLiquidity { pool_id, .. } => {
+                let shares_key = AtomicBalanceKey { asset_id: *pool_id, owner_id };
+                if !atomic_state.has_balance(&shares_key) {
+                    growth.new_balance_keys = growth.new_balance_keys.saturating_add(1);
+                }
+            }
+            AtomicPayloadOp::RemovePairLiquidity { pool_id, .. } => {
+                if let Some(pair) = atomic_state.cloned_asset(pool_id).and_then(|asset| asset.pair_pool) {
+                    for asset_id in [pair.asset_a_id, pair.asset_b_id] {
+                        if !atomic_state.has_balance(&AtomicBalanceKey { asset_id, owner_id }) {
+                            growth.new_balance_keys = growth.new_balance_keys.saturating_add(1);
+                        }
+                    }
+                }
+            }
+            AtomicPayloadOp::SwapPairExactIn { pool_id, asset_in_id, .. } => {
+                if let Some(pair) = atomic_state.cloned_asset(pool_id).and_then(|asset| asset.pair_pool) {
+                    let asset_out_id = if *asset_in_id == pair.asset_a_id { pair.asset_b_id } else { pair.asset_a_id };
+                    if !atomic_state.has_balance(&AtomicBalanceKey { asset_id: asset_out_id, owner_id }) {
+                        growth.new_balance_keys = growth.new_balance_keys.saturating_add(1);
+                    }
+                }
+            }
+            AtomicPayloadOp::SwapPairExactOut { asset_out_id, .. } => {
+                if !atomic_state.has_balance(&AtomicBalanceKey { asset_id: *asset_out_id, owner_id }) {
+                    growth.new_balance_keys = growth.new_balance_keys.saturating_add(1);
+                }
+            }
             AtomicPayloadOp::Burn { .. }
             | AtomicPayloadOp::SellLiquidityExactIn { .. }
-            | AtomicPayloadOp::ClaimLiquidityFees { .. } => {}
+            | AtomicPayloadOp::SellLiquidityExactOut { .. }
+            | AtomicPayloadOp::ClaimLiquidityFees { .. }
+            | AtomicPayloadOp::TransferMintAuthority { .. }
+            | AtomicPayloadOp::RenounceMintAuthority { .. }
+            | AtomicPayloadOp::UpdateMetadata { .. } => {}
         }
 
         Ok(growth)
@@ -1674,7 +2169,10 @@ impl VirtualStateProcessor {
             return Ok(());
         }
 
-        let Some(parsed_payload) = parse_atomic_payload(tx_ref.payload.as_slice()).map_err(TxRuleError::InvalidAtomicPayload)? else {
+        let extended_ops_active = self.transaction_validator.is_atomic_extended_ops_active(pov_daa_score);
+        let Some(parsed_payload) =
+            parse_atomic_payload(tx_ref.payload.as_slice(), extended_ops_active).map_err(TxRuleError::InvalidAtomicPayload)?
+        else {
             if !spent_vault_inputs.is_empty() || liquidity_vault_output_count > 0 {
                 return Err(TxRuleError::InvalidAtomicPayload(
                     "reserved LiquidityVault scripts require a CAT liquidity payload".to_string(),
@@ -1710,7 +2208,9 @@ impl VirtualStateProcessor {
             match &parsed_payload.op {
                 AtomicPayloadOp::BuyLiquidityExactIn { .. }
                 | AtomicPayloadOp::SellLiquidityExactIn { .. }
-                | AtomicPayloadOp::ClaimLiquidityFees { .. } => {}
+                | AtomicPayloadOp::ClaimLiquidityFees { .. }
+                | AtomicPayloadOp::BuyLiquidityExactOut { .. }
+                | AtomicPayloadOp::SellLiquidityExactOut { .. } => {}
                 _ => {
                     return Err(TxRuleError::InvalidAtomicPayload(
                         "spending a LiquidityVault input is only valid for buy/sell/claim liquidity ops".to_string(),
@@ -1728,7 +2228,15 @@ impl VirtualStateProcessor {
         }
 
         self.validate_replacement_anchor(tx, owner_id, atomic_state)?;
-        self.apply_atomic_op_to_state(tx, tx.tx().id().as_bytes(), owner_id, parsed_payload.op, creation_context, atomic_state)?;
+        self.apply_atomic_op_to_state(
+            tx,
+            tx.tx().id().as_bytes(),
+            owner_id,
+            parsed_payload.op,
+            pov_daa_score,
+            creation_context,
+            atomic_state,
+        )?;
 
         atomic_state.set_next_nonce(nonce_key, next_nonce);
         self.apply_anchor_deltas_to_atomic_state(tx, atomic_state);
@@ -1812,9 +2320,13 @@ impl VirtualStateProcessor {
         tx_id_bytes: [u8; 32],
         owner_id: [u8; 32],
         op: AtomicPayloadOp,
+        accepting_daa_score: u64,
         creation_context: AtomicCreationContext,
         atomic_state: &mut AtomicConsensusState,
     ) -> TxResult<()> {
+        if op.is_extended() && !self.transaction_validator.is_atomic_extended_ops_active(accepting_daa_score) {
+            return Err(TxRuleError::InvalidAtomicPayload("extended CAT op is not activated".to_string()));
+        }
         match op {
             AtomicPayloadOp::CreateAsset {
                 token_version,
@@ -1858,6 +2370,7 @@ impl VirtualStateProcessor {
                         created_daa_score: Some(creation_context.source_block_daa_score),
                         created_at: Some(creation_context.source_block_time),
                         liquidity: None,
+                        pair_pool: None,
                     },
                 )?;
             }
@@ -1925,6 +2438,7 @@ impl VirtualStateProcessor {
                         created_daa_score: Some(creation_context.source_block_daa_score),
                         created_at: Some(creation_context.source_block_time),
                         liquidity: None,
+                        pair_pool: None,
                     },
                 )?;
                 if let Some((receiver_key, receiver_after)) = initial_mint_balance {
@@ -2075,6 +2589,7 @@ impl VirtualStateProcessor {
                         unlock_target_sompi: liquidity_unlock_target_sompi,
                         unlocked,
                     }),
+                    pair_pool: None,
                 };
                 self.validate_liquidity_invariants(asset_id, &asset)?;
                 self.insert_atomic_asset_state(atomic_state, asset_id, asset)?;
@@ -2094,6 +2609,7 @@ impl VirtualStateProcessor {
                 let to_key = AtomicBalanceKey { asset_id, owner_id: to_owner_id };
 
                 let sender_balance = atomic_state.balance(&from_key);
+                ensure_atomic_unlocked_balance(atomic_state, &from_key, amount, accepting_daa_score, "transfer")?;
                 if from_key == to_key {
                     sender_balance.checked_sub(amount).ok_or_else(|| {
                         TxRuleError::InvalidAtomicPayload(format!(
@@ -2122,101 +2638,541 @@ impl VirtualStateProcessor {
                         atomic_state.set_balance(from_key, sender_after);
                     }
                     atomic_state.set_balance(to_key, receiver_after);
+                    prune_released_atomic_lock(atomic_state, from_key, accepting_daa_score);
                 }
             }
-            AtomicPayloadOp::Mint { asset_id, to_owner_id, amount } => {
-                let mut asset = atomic_state.cloned_asset(&asset_id).ok_or_else(|| {
-                    TxRuleError::InvalidAtomicPayload(format!("mint references unknown asset `{}`", faster_hex::hex_string(&asset_id)))
+            AtomicPayloadOp::Approve { asset_id, spender_owner_id, amount, expires_at_daa_score } => {
+                let asset = atomic_state.cloned_asset(&asset_id).ok_or_else(|| {
+                    TxRuleError::InvalidAtomicPayload(format!(
+                        "approve references unknown asset `{}`",
+                        faster_hex::hex_string(&asset_id)
+                    ))
                 })?;
                 if matches!(asset.asset_class, AtomicAssetClass::Liquidity) {
                     return Err(TxRuleError::InvalidAtomicPayload(format!(
-                        "legacy mint is invalid for liquidity asset `{}`",
+                        "approve is invalid for liquidity asset `{}`",
                         faster_hex::hex_string(&asset_id)
                     )));
                 }
-                if asset.mint_authority_owner_id != owner_id {
+                if spender_owner_id == owner_id {
                     return Err(TxRuleError::InvalidAtomicPayload(format!(
-                        "owner `{}` is not mint authority for asset `{}`",
-                        faster_hex::hex_string(&owner_id),
-                        faster_hex::hex_string(&asset_id)
+                        "owner `{}` cannot approve itself as spender",
+                        faster_hex::hex_string(&owner_id)
+                    )));
+                }
+                let allowance = AtomicAllowanceState { amount, expires_at_daa_score };
+                if amount > 0 && allowance.is_expired_at(accepting_daa_score) {
+                    return Err(TxRuleError::InvalidAtomicPayload(format!(
+                        "approve expiry `{expires_at_daa_score}` is not above accepting DAA score `{accepting_daa_score}`"
                     )));
                 }
 
-                let new_total_supply = asset.total_supply.checked_add(amount).ok_or_else(|| {
+                let allowance_key = AtomicAllowanceKey { asset_id, owner_id, spender_id: spender_owner_id };
+                atomic_state.set_allowance(allowance_key, allowance);
+            }
+            AtomicPayloadOp::TransferFrom { asset_id, from_owner_id, to_owner_id, amount } => {
+                let asset = atomic_state.cloned_asset(&asset_id).ok_or_else(|| {
                     TxRuleError::InvalidAtomicPayload(format!(
-                        "supply overflow while minting asset `{}`",
+                        "transfer-from references unknown asset `{}`",
                         faster_hex::hex_string(&asset_id)
                     ))
                 })?;
-                if matches!(asset.supply_mode, AtomicSupplyMode::Capped) && new_total_supply > asset.max_supply {
+                if matches!(asset.asset_class, AtomicAssetClass::Liquidity) {
                     return Err(TxRuleError::InvalidAtomicPayload(format!(
-                        "mint would exceed cap for asset `{}`: cap `{}`, attempted total `{}`",
-                        faster_hex::hex_string(&asset_id),
-                        asset.max_supply,
-                        new_total_supply
+                        "transfer-from is invalid for liquidity asset `{}`",
+                        faster_hex::hex_string(&asset_id)
+                    )));
+                }
+                if from_owner_id == owner_id {
+                    return Err(TxRuleError::InvalidAtomicPayload(format!(
+                        "spender `{}` must use transfer for its own balance",
+                        faster_hex::hex_string(&owner_id)
                     )));
                 }
 
-                let receiver_key = AtomicBalanceKey { asset_id, owner_id: to_owner_id };
-                let receiver_balance = atomic_state.balance(&receiver_key);
-                let receiver_after = receiver_balance.checked_add(amount).ok_or_else(|| {
+                let allowance_key = AtomicAllowanceKey { asset_id, owner_id: from_owner_id, spender_id: owner_id };
+                let allowance = atomic_state.allowance(&allowance_key).ok_or_else(|| {
                     TxRuleError::InvalidAtomicPayload(format!(
-                        "balance overflow while minting asset `{}`",
+                        "spender `{}` has no allowance from owner `{}` for asset `{}`",
+                        faster_hex::hex_string(&owner_id),
+                        faster_hex::hex_string(&from_owner_id),
                         faster_hex::hex_string(&asset_id)
                     ))
                 })?;
-
-                asset.total_supply = new_total_supply;
-                self.insert_atomic_asset_state(atomic_state, asset_id, asset)?;
-                atomic_state.set_balance(receiver_key, receiver_after);
-            }
-            AtomicPayloadOp::Burn { asset_id, amount } => {
-                let mut asset = atomic_state.cloned_asset(&asset_id).ok_or_else(|| {
-                    TxRuleError::InvalidAtomicPayload(format!("burn references unknown asset `{}`", faster_hex::hex_string(&asset_id)))
-                })?;
-                if matches!(asset.asset_class, AtomicAssetClass::Liquidity) {
+                if allowance.is_expired_at(accepting_daa_score) {
                     return Err(TxRuleError::InvalidAtomicPayload(format!(
-                        "legacy burn is invalid for liquidity asset `{}`",
-                        faster_hex::hex_string(&asset_id)
+                        "allowance for spender `{}` on asset `{}` expired at DAA score `{}`",
+                        faster_hex::hex_string(&owner_id),
+                        faster_hex::hex_string(&asset_id),
+                        allowance.expires_at_daa_score
                     )));
                 }
-                let sender_key = AtomicBalanceKey { asset_id, owner_id };
-                let sender_balance = atomic_state.balance(&sender_key);
-
-                let sender_after = sender_balance.checked_sub(amount).ok_or_else(|| {
+                let remaining_allowance = allowance.amount.checked_sub(amount).ok_or_else(|| {
                     TxRuleError::InvalidAtomicPayload(format!(
-                        "insufficient balance for burn in asset `{}`",
+                        "transfer-from amount `{amount}` exceeds allowance `{}` for asset `{}`",
+                        allowance.amount,
                         faster_hex::hex_string(&asset_id)
                     ))
                 })?;
-                let supply_after = asset.total_supply.checked_sub(amount).ok_or_else(|| {
+
+                let from_key = AtomicBalanceKey { asset_id, owner_id: from_owner_id };
+                let to_key = AtomicBalanceKey { asset_id, owner_id: to_owner_id };
+                let from_balance = atomic_state.balance(&from_key);
+                ensure_atomic_unlocked_balance(atomic_state, &from_key, amount, accepting_daa_score, "transfer-from")?;
+                let from_after = from_balance.checked_sub(amount).ok_or_else(|| {
                     TxRuleError::InvalidAtomicPayload(format!(
-                        "supply underflow while burning asset `{}`",
+                        "insufficient balance for transfer-from of asset `{}`",
                         faster_hex::hex_string(&asset_id)
                     ))
                 })?;
-
-                asset.total_supply = supply_after;
-                self.insert_atomic_asset_state(atomic_state, asset_id, asset)?;
-                if sender_after == 0 {
-                    atomic_state.set_balance(sender_key, 0);
-                } else {
-                    atomic_state.set_balance(sender_key, sender_after);
+                if from_key != to_key {
+                    let receiver_after = atomic_state.balance(&to_key).checked_add(amount).ok_or_else(|| {
+                        TxRuleError::InvalidAtomicPayload(format!(
+                            "balance overflow for transfer-from receiver in asset `{}`",
+                            faster_hex::hex_string(&asset_id)
+                        ))
+                    })?;
+                    atomic_state.set_balance(from_key, from_after);
+                    atomic_state.set_balance(to_key, receiver_after);
+                    prune_released_atomic_lock(atomic_state, from_key, accepting_daa_score);
                 }
+                atomic_state.set_allowance(allowance_key, AtomicAllowanceState { amount: remaining_allowance, ..allowance });
             }
-            AtomicPayloadOp::BuyLiquidityExactIn { asset_id, expected_pool_nonce, cpay_in_sompi, min_token_out } => {
-                let mut asset = atomic_state.cloned_asset(&asset_id).ok_or_else(|| {
-                    TxRuleError::InvalidAtomicPayload(format!("buy references unknown asset `{}`", faster_hex::hex_string(&asset_id)))
+            AtomicPayloadOp::BatchTransfer { asset_id, recipients } => {
+                let asset = atomic_state.cloned_asset(&asset_id).ok_or_else(|| {
+                    TxRuleError::InvalidAtomicPayload(format!(
+                        "batch transfer references unknown asset `{}`",
+                        faster_hex::hex_string(&asset_id)
+                    ))
                 })?;
-                if !matches!(asset.asset_class, AtomicAssetClass::Liquidity) {
+                if matches!(asset.asset_class, AtomicAssetClass::Liquidity) {
                     return Err(TxRuleError::InvalidAtomicPayload(format!(
-                        "buy is only valid for liquidity assets (`{}` is standard)",
+                        "batch transfer is invalid for liquidity asset `{}`",
                         faster_hex::hex_string(&asset_id)
                     )));
                 }
-                let mut pool = asset.liquidity.clone().ok_or_else(|| {
+
+                let total =
+                    recipients.iter().try_fold(0u128, |total, recipient| total.checked_add(recipient.amount)).ok_or_else(|| {
+                        TxRuleError::InvalidAtomicPayload(format!(
+                            "batch transfer total overflows for asset `{}`",
+                            faster_hex::hex_string(&asset_id)
+                        ))
+                    })?;
+                let from_key = AtomicBalanceKey { asset_id, owner_id };
+                let self_amount = recipients
+                    .iter()
+                    .filter(|recipient| recipient.to_owner_id == owner_id)
+                    .map(|recipient| recipient.amount)
+                    .sum::<u128>();
+                ensure_atomic_unlocked_balance(atomic_state, &from_key, total, accepting_daa_score, "batch transfer")?;
+                let sender_after = atomic_state.balance(&from_key).checked_sub(total).ok_or_else(|| {
                     TxRuleError::InvalidAtomicPayload(format!(
-                        "liquidity state missing for asset `{}`",
+                        "insufficient balance for batch transfer of asset `{}`",
+                        faster_hex::hex_string(&asset_id)
+                    ))
+                })?;
+
+                // Every receiver balance is computed before anything is written so a
+                // single overflowing recipient rejects the whole batch.
+                let mut receivers_after = Vec::with_capacity(recipients.len());
+                for recipient in recipients.iter().filter(|recipient| recipient.to_owner_id != owner_id) {
+                    let to_key = AtomicBalanceKey { asset_id, owner_id: recipient.to_owner_id };
+                    let receiver_after = atomic_state.balance(&to_key).checked_add(recipient.amount).ok_or_else(|| {
+                        TxRuleError::InvalidAtomicPayload(format!(
+                            "balance overflow for batch transfer receiver in asset `{}`",
+                            faster_hex::hex_string(&asset_id)
+                        ))
+                    })?;
+                    receivers_after.push((to_key, receiver_after));
+                }
+
+                // A recipient equal to the sender nets out against the debited total.
+                atomic_state.set_balance(from_key, sender_after + self_amount);
+                for (to_key, receiver_after) in receivers_after {
+                    atomic_state.set_balance(to_key, receiver_after);
+                }
+                prune_released_atomic_lock(atomic_state, from_key, accepting_daa_score);
+            }
+            AtomicPayloadOp::LockTokens { asset_id, beneficiary_owner_id, amount, release_start_daa_score, release_end_daa_score } => {
+                let asset = atomic_state.cloned_asset(&asset_id).ok_or_else(|| {
+                    TxRuleError::InvalidAtomicPayload(format!("lock references unknown asset `{}`", faster_hex::hex_string(&asset_id)))
+                })?;
+                if matches!(asset.asset_class, AtomicAssetClass::Liquidity) {
+                    return Err(TxRuleError::InvalidAtomicPayload(format!(
+                        "lock is invalid for liquidity asset `{}`",
+                        faster_hex::hex_string(&asset_id)
+                    )));
+                }
+                if release_end_daa_score <= accepting_daa_score {
+                    return Err(TxRuleError::InvalidAtomicPayload(format!(
+                        "lock release end `{release_end_daa_score}` is not above accepting DAA score `{accepting_daa_score}`"
+                    )));
+                }
+                // Pool reserves must stay fully spendable by swaps and withdrawals.
+                if atomic_state.cloned_asset(&beneficiary_owner_id).is_some_and(|pool| pool.pair_pool.is_some()) {
+                    return Err(TxRuleError::InvalidAtomicPayload(format!(
+                        "lock beneficiary `{}` is a pair pool",
+                        faster_hex::hex_string(&beneficiary_owner_id)
+                    )));
+                }
+
+                let from_key = AtomicBalanceKey { asset_id, owner_id };
+                let beneficiary_key = AtomicBalanceKey { asset_id, owner_id: beneficiary_owner_id };
+                ensure_atomic_unlocked_balance(atomic_state, &from_key, amount, accepting_daa_score, "lock")?;
+                let sender_after = atomic_state.balance(&from_key).checked_sub(amount).ok_or_else(|| {
+                    TxRuleError::InvalidAtomicPayload(format!(
+                        "insufficient balance for lock of asset `{}`",
+                        faster_hex::hex_string(&asset_id)
+                    ))
+                })?;
+
+                // An active lock can only be topped up on the same schedule; a fully released one is replaced.
+                let lock = match atomic_state.lock(&beneficiary_key).filter(|lock| lock.locked_at(accepting_daa_score) > 0) {
+                    Some(existing)
+                        if existing.release_start_daa_score == release_start_daa_score
+                            && existing.release_end_daa_score == release_end_daa_score =>
+                    {
+                        let total = existing.amount.checked_add(amount).ok_or_else(|| {
+                            TxRuleError::InvalidAtomicPayload(format!(
+                                "lock amount overflow in asset `{}`",
+                                faster_hex::hex_string(&asset_id)
+                            ))
+                        })?;
+                        AtomicTokenLockState { amount: total, ..existing }
+                    }
+                    Some(_) => {
+                        return Err(TxRuleError::InvalidAtomicPayload(format!(
+                            "owner `{}` already has a lock with a different schedule in asset `{}`",
+                            faster_hex::hex_string(&beneficiary_owner_id),
+                            faster_hex::hex_string(&asset_id)
+                        )))
+                    }
+                    None => AtomicTokenLockState { amount, release_start_daa_score, release_end_daa_score },
+                };
+
+                if from_key != beneficiary_key {
+                    let beneficiary_after = atomic_state.balance(&beneficiary_key).checked_add(amount).ok_or_else(|| {
+                        TxRuleError::InvalidAtomicPayload(format!(
+                            "balance overflow for lock beneficiary in asset `{}`",
+                            faster_hex::hex_string(&asset_id)
+                        ))
+                    })?;
+                    atomic_state.set_balance(from_key, sender_after);
+                    atomic_state.set_balance(beneficiary_key, beneficiary_after);
+                    prune_released_atomic_lock(atomic_state, from_key, accepting_daa_score);
+                }
+                atomic_state.set_lock(beneficiary_key, lock);
+            }
+            AtomicPayloadOp::Mint { asset_id, to_owner_id, amount } => {
+                let mut asset = atomic_state.cloned_asset(&asset_id).ok_or_else(|| {
+                    TxRuleError::InvalidAtomicPayload(format!("mint references unknown asset `{}`", faster_hex::hex_string(&asset_id)))
+                })?;
+                if matches!(asset.asset_class, AtomicAssetClass::Liquidity) {
+                    return Err(TxRuleError::InvalidAtomicPayload(format!(
+                        "legacy mint is invalid for liquidity asset `{}`",
+                        faster_hex::hex_string(&asset_id)
+                    )));
+                }
+                if asset.mint_authority_owner_id != owner_id {
+                    return Err(TxRuleError::InvalidAtomicPayload(format!(
+                        "owner `{}` is not mint authority for asset `{}`",
+                        faster_hex::hex_string(&owner_id),
+                        faster_hex::hex_string(&asset_id)
+                    )));
+                }
+
+                let new_total_supply = asset.total_supply.checked_add(amount).ok_or_else(|| {
+                    TxRuleError::InvalidAtomicPayload(format!(
+                        "supply overflow while minting asset `{}`",
+                        faster_hex::hex_string(&asset_id)
+                    ))
+                })?;
+                if matches!(asset.supply_mode, AtomicSupplyMode::Capped) && new_total_supply > asset.max_supply {
+                    return Err(TxRuleError::InvalidAtomicPayload(format!(
+                        "mint would exceed cap for asset `{}`: cap `{}`, attempted total `{}`",
+                        faster_hex::hex_string(&asset_id),
+                        asset.max_supply,
+                        new_total_supply
+                    )));
+                }
+
+                let receiver_key = AtomicBalanceKey { asset_id, owner_id: to_owner_id };
+                let receiver_balance = atomic_state.balance(&receiver_key);
+                let receiver_after = receiver_balance.checked_add(amount).ok_or_else(|| {
+                    TxRuleError::InvalidAtomicPayload(format!(
+                        "balance overflow while minting asset `{}`",
+                        faster_hex::hex_string(&asset_id)
+                    ))
+                })?;
+
+                asset.total_supply = new_total_supply;
+                self.insert_atomic_asset_state(atomic_state, asset_id, asset)?;
+                atomic_state.set_balance(receiver_key, receiver_after);
+            }
+            AtomicPayloadOp::TransferMintAuthority { asset_id, new_mint_authority_owner_id } => {
+                self.set_atomic_mint_authority(atomic_state, owner_id, asset_id, new_mint_authority_owner_id)?;
+            }
+            AtomicPayloadOp::RenounceMintAuthority { asset_id } => {
+                self.set_atomic_mint_authority(atomic_state, owner_id, asset_id, [0u8; 32])?;
+            }
+            AtomicPayloadOp::UpdateMetadata { asset_id, metadata, platform_tag } => {
+                let mut asset = atomic_state.cloned_asset(&asset_id).ok_or_else(|| {
+                    TxRuleError::InvalidAtomicPayload(format!(
+                        "metadata update references unknown asset `{}`",
+                        faster_hex::hex_string(&asset_id)
+                    ))
+                })?;
+                // The creator is the metadata authority; assets restored without a creator cannot be updated.
+                if asset.creator_owner_id == [0u8; 32] || asset.creator_owner_id != owner_id {
+                    return Err(TxRuleError::InvalidAtomicPayload(format!(
+                        "owner `{}` is not metadata authority for asset `{}`",
+                        faster_hex::hex_string(&owner_id),
+                        faster_hex::hex_string(&asset_id)
+                    )));
+                }
+                asset.metadata = metadata;
+                asset.platform_tag = platform_tag;
+                self.insert_atomic_asset_state(atomic_state, asset_id, asset)?;
+            }
+            AtomicPayloadOp::CreatePairPool { asset_a_id, asset_b_id, fee_bps, amount_a, amount_b } => {
+                let pool_id = tx_id_bytes;
+                if atomic_state.has_asset(&pool_id) {
+                    return Err(TxRuleError::InvalidAtomicPayload(format!(
+                        "asset `{}` already exists",
+                        faster_hex::hex_string(&pool_id)
+                    )));
+                }
+                for reserve_asset_id in [asset_a_id, asset_b_id] {
+                    let reserve_asset = atomic_state.cloned_asset(&reserve_asset_id).ok_or_else(|| {
+                        TxRuleError::InvalidAtomicPayload(format!(
+                            "pair pool references unknown asset `{}`",
+                            faster_hex::hex_string(&reserve_asset_id)
+                        ))
+                    })?;
+                    if !matches!(reserve_asset.asset_class, AtomicAssetClass::Standard) {
+                        return Err(TxRuleError::InvalidAtomicPayload(format!(
+                            "pair pools only accept standard assets (`{}` is not)",
+                            faster_hex::hex_string(&reserve_asset_id)
+                        )));
+                    }
+                }
+                let shares = pair_initial_shares(amount_a, amount_b)?;
+                let pair = AtomicPairPoolState { asset_a_id, asset_b_id, fee_bps, accrued_fee_a: 0, accrued_fee_b: 0 };
+
+                let owner_key_a = AtomicBalanceKey { asset_id: asset_a_id, owner_id };
+                let owner_key_b = AtomicBalanceKey { asset_id: asset_b_id, owner_id };
+                let owner_a_after =
+                    atomic_balance_after_debit(atomic_state, &owner_key_a, amount_a, accepting_daa_score, "pair create")?;
+                let owner_b_after =
+                    atomic_balance_after_debit(atomic_state, &owner_key_b, amount_b, accepting_daa_score, "pair create")?;
+                let reserve_a_after =
+                    atomic_balance_after_credit(atomic_state, &pair.reserve_key_a(pool_id), amount_a, "pair create")?;
+                let reserve_b_after =
+                    atomic_balance_after_credit(atomic_state, &pair.reserve_key_b(pool_id), amount_b, "pair create")?;
+
+                self.insert_atomic_asset_state(
+                    atomic_state,
+                    pool_id,
+                    AtomicAssetState {
+                        creator_owner_id: owner_id,
+                        asset_class: AtomicAssetClass::Pair,
+                        token_version: ATOMIC_CURRENT_TOKEN_VERSION,
+                        mint_authority_owner_id: [0u8; 32],
+                        decimals: 0,
+                        supply_mode: AtomicSupplyMode::Uncapped,
+                        max_supply: 0,
+                        total_supply: shares,
+                        name: PAIR_LP_NAME.to_vec(),
+                        symbol: PAIR_LP_SYMBOL.to_vec(),
+                        metadata: Vec::new(),
+                        platform_tag: Vec::new(),
+                        created_block_hash: Some(creation_context.source_block_hash.as_bytes()),
+                        created_daa_score: Some(creation_context.source_block_daa_score),
+                        created_at: Some(creation_context.source_block_time),
+                        liquidity: None,
+                        pair_pool: Some(pair),
+                    },
+                )?;
+                atomic_state.set_balance(owner_key_a, owner_a_after);
+                atomic_state.set_balance(owner_key_b, owner_b_after);
+                atomic_state.set_balance(pair.reserve_key_a(pool_id), reserve_a_after);
+                atomic_state.set_balance(pair.reserve_key_b(pool_id), reserve_b_after);
+                // The minimum share amount stays locked with the pool itself so the reserves can never be fully drained.
+                atomic_state.set_balance(AtomicBalanceKey { asset_id: pool_id, owner_id }, shares - ATOMIC_MIN_PAIR_LIQUIDITY_SHARES);
+                atomic_state.set_balance(AtomicBalanceKey { asset_id: pool_id, owner_id: pool_id }, ATOMIC_MIN_PAIR_LIQUIDITY_SHARES);
+                prune_released_atomic_lock(atomic_state, owner_key_a, accepting_daa_score);
+                prune_released_atomic_lock(atomic_state, owner_key_b, accepting_daa_score);
+            }
+            AtomicPayloadOp::AddPairLiquidity { pool_id, amount_a_max, amount_b_max, min_shares_out } => {
+                let (mut asset, pair) = load_atomic_pair_pool(atomic_state, pool_id, "add pair liquidity")?;
+                let reserve_key_a = pair.reserve_key_a(pool_id);
+                let reserve_key_b = pair.reserve_key_b(pool_id);
+                let (shares, amount_a_in, amount_b_in) = pair_add_liquidity(
+                    atomic_state.balance(&reserve_key_a),
+                    atomic_state.balance(&reserve_key_b),
+                    asset.total_supply,
+                    amount_a_max,
+                    amount_b_max,
+                )?;
+                if shares < min_shares_out {
+                    return Err(TxRuleError::InvalidAtomicPayload(format!(
+                        "add pair liquidity min_shares_out violated: expected at least `{min_shares_out}`, got `{shares}`"
+                    )));
+                }
+
+                let owner_key_a = AtomicBalanceKey { asset_id: pair.asset_a_id, owner_id };
+                let owner_key_b = AtomicBalanceKey { asset_id: pair.asset_b_id, owner_id };
+                let shares_key = AtomicBalanceKey { asset_id: pool_id, owner_id };
+                let owner_a_after =
+                    atomic_balance_after_debit(atomic_state, &owner_key_a, amount_a_in, accepting_daa_score, "add pair liquidity")?;
+                let owner_b_after =
+                    atomic_balance_after_debit(atomic_state, &owner_key_b, amount_b_in, accepting_daa_score, "add pair liquidity")?;
+                let reserve_a_after = atomic_balance_after_credit(atomic_state, &reserve_key_a, amount_a_in, "add pair liquidity")?;
+                let reserve_b_after = atomic_balance_after_credit(atomic_state, &reserve_key_b, amount_b_in, "add pair liquidity")?;
+                let shares_after = atomic_balance_after_credit(atomic_state, &shares_key, shares, "add pair liquidity")?;
+                asset.total_supply = asset.total_supply.checked_add(shares).ok_or_else(|| {
+                    TxRuleError::InvalidAtomicPayload(format!("share supply overflow for pool `{}`", faster_hex::hex_string(&pool_id)))
+                })?;
+
+                self.insert_atomic_asset_state(atomic_state, pool_id, asset)?;
+                atomic_state.set_balance(owner_key_a, owner_a_after);
+                atomic_state.set_balance(owner_key_b, owner_b_after);
+                atomic_state.set_balance(reserve_key_a, reserve_a_after);
+                atomic_state.set_balance(reserve_key_b, reserve_b_after);
+                atomic_state.set_balance(shares_key, shares_after);
+                prune_released_atomic_lock(atomic_state, owner_key_a, accepting_daa_score);
+                prune_released_atomic_lock(atomic_state, owner_key_b, accepting_daa_score);
+            }
+            AtomicPayloadOp::RemovePairLiquidity { pool_id, shares, min_amount_a_out, min_amount_b_out } => {
+                let (mut asset, pair) = load_atomic_pair_pool(atomic_state, pool_id, "remove pair liquidity")?;
+                let reserve_key_a = pair.reserve_key_a(pool_id);
+                let reserve_key_b = pair.reserve_key_b(pool_id);
+                let reserve_a = atomic_state.balance(&reserve_key_a);
+                let reserve_b = atomic_state.balance(&reserve_key_b);
+                let (amount_a_out, amount_b_out) = pair_remove_liquidity(reserve_a, reserve_b, asset.total_supply, shares)?;
+                if amount_a_out < min_amount_a_out || amount_b_out < min_amount_b_out {
+                    return Err(TxRuleError::InvalidAtomicPayload(format!(
+                        "remove pair liquidity min output violated: expected at least `{min_amount_a_out}`/`{min_amount_b_out}`, got `{amount_a_out}`/`{amount_b_out}`"
+                    )));
+                }
+
+                let shares_key = AtomicBalanceKey { asset_id: pool_id, owner_id };
+                let owner_key_a = AtomicBalanceKey { asset_id: pair.asset_a_id, owner_id };
+                let owner_key_b = AtomicBalanceKey { asset_id: pair.asset_b_id, owner_id };
+                let shares_after =
+                    atomic_balance_after_debit(atomic_state, &shares_key, shares, accepting_daa_score, "remove pair liquidity")?;
+                let owner_a_after = atomic_balance_after_credit(atomic_state, &owner_key_a, amount_a_out, "remove pair liquidity")?;
+                let owner_b_after = atomic_balance_after_credit(atomic_state, &owner_key_b, amount_b_out, "remove pair liquidity")?;
+                asset.total_supply -= shares;
+
+                self.insert_atomic_asset_state(atomic_state, pool_id, asset)?;
+                atomic_state.set_balance(shares_key, shares_after);
+                atomic_state.set_balance(reserve_key_a, reserve_a - amount_a_out);
+                atomic_state.set_balance(reserve_key_b, reserve_b - amount_b_out);
+                atomic_state.set_balance(owner_key_a, owner_a_after);
+                atomic_state.set_balance(owner_key_b, owner_b_after);
+                prune_released_atomic_lock(atomic_state, shares_key, accepting_daa_score);
+            }
+            AtomicPayloadOp::SwapPairExactIn { pool_id, asset_in_id, amount_in, min_amount_out } => {
+                let (mut asset, mut pair) = load_atomic_pair_pool(atomic_state, pool_id, "pair swap")?;
+                let in_is_a = pair_swap_side(&pair, asset_in_id)?;
+                let (reserve_key_in, reserve_key_out) = pair_reserve_keys(&pair, pool_id, in_is_a);
+                let reserve_in = atomic_state.balance(&reserve_key_in);
+                let reserve_out = atomic_state.balance(&reserve_key_out);
+                let (amount_out, fee) = pair_swap_exact_in(reserve_in, reserve_out, amount_in, pair.fee_bps)?;
+                if amount_out < min_amount_out {
+                    return Err(TxRuleError::InvalidAtomicPayload(format!(
+                        "pair swap min_amount_out violated: expected at least `{min_amount_out}`, got `{amount_out}`"
+                    )));
+                }
+                self.apply_pair_swap(
+                    atomic_state,
+                    owner_id,
+                    pool_id,
+                    &mut asset,
+                    &mut pair,
+                    in_is_a,
+                    amount_in,
+                    amount_out,
+                    fee,
+                    accepting_daa_score,
+                )?;
+            }
+            AtomicPayloadOp::SwapPairExactOut { pool_id, asset_out_id, amount_out, max_amount_in } => {
+                let (mut asset, mut pair) = load_atomic_pair_pool(atomic_state, pool_id, "pair swap")?;
+                let in_is_a = !pair_swap_side(&pair, asset_out_id)?;
+                let (reserve_key_in, reserve_key_out) = pair_reserve_keys(&pair, pool_id, in_is_a);
+                let reserve_in = atomic_state.balance(&reserve_key_in);
+                let reserve_out = atomic_state.balance(&reserve_key_out);
+                let (amount_in, fee) = pair_swap_exact_out(reserve_in, reserve_out, amount_out, pair.fee_bps)?;
+                if amount_in > max_amount_in {
+                    return Err(TxRuleError::InvalidAtomicPayload(format!(
+                        "pair swap max_amount_in violated: allowed at most `{max_amount_in}`, needs `{amount_in}`"
+                    )));
+                }
+                self.apply_pair_swap(
+                    atomic_state,
+                    owner_id,
+                    pool_id,
+                    &mut asset,
+                    &mut pair,
+                    in_is_a,
+                    amount_in,
+                    amount_out,
+                    fee,
+                    accepting_daa_score,
+                )?;
+            }
+            AtomicPayloadOp::Burn { asset_id, amount } => {
+                let mut asset = atomic_state.cloned_asset(&asset_id).ok_or_else(|| {
+                    TxRuleError::InvalidAtomicPayload(format!("burn references unknown asset `{}`", faster_hex::hex_string(&asset_id)))
+                })?;
+                if matches!(asset.asset_class, AtomicAssetClass::Liquidity | AtomicAssetClass::Pair) {
+                    return Err(TxRuleError::InvalidAtomicPayload(format!(
+                        "legacy burn is invalid for liquidity or pair asset `{}`",
+                        faster_hex::hex_string(&asset_id)
+                    )));
+                }
+                let sender_key = AtomicBalanceKey { asset_id, owner_id };
+                let sender_balance = atomic_state.balance(&sender_key);
+                ensure_atomic_unlocked_balance(atomic_state, &sender_key, amount, accepting_daa_score, "burn")?;
+
+                let sender_after = sender_balance.checked_sub(amount).ok_or_else(|| {
+                    TxRuleError::InvalidAtomicPayload(format!(
+                        "insufficient balance for burn in asset `{}`",
+                        faster_hex::hex_string(&asset_id)
+                    ))
+                })?;
+                let supply_after = asset.total_supply.checked_sub(amount).ok_or_else(|| {
+                    TxRuleError::InvalidAtomicPayload(format!(
+                        "supply underflow while burning asset `{}`",
+                        faster_hex::hex_string(&asset_id)
+                    ))
+                })?;
+
+                asset.total_supply = supply_after;
+                self.insert_atomic_asset_state(atomic_state, asset_id, asset)?;
+                if sender_after == 0 {
+                    atomic_state.set_balance(sender_key, 0);
+                } else {
+                    atomic_state.set_balance(sender_key, sender_after);
+                }
+                prune_released_atomic_lock(atomic_state, sender_key, accepting_daa_score);
+            }
+            AtomicPayloadOp::BuyLiquidityExactIn { asset_id, expected_pool_nonce, cpay_in_sompi, min_token_out } => {
+                let mut asset = atomic_state.cloned_asset(&asset_id).ok_or_else(|| {
+                    TxRuleError::InvalidAtomicPayload(format!("buy references unknown asset `{}`", faster_hex::hex_string(&asset_id)))
+                })?;
+                if !matches!(asset.asset_class, AtomicAssetClass::Liquidity) {
+                    return Err(TxRuleError::InvalidAtomicPayload(format!(
+                        "buy is only valid for liquidity assets (`{}` is standard)",
+                        faster_hex::hex_string(&asset_id)
+                    )));
+                }
+                let mut pool = asset.liquidity.clone().ok_or_else(|| {
+                    TxRuleError::InvalidAtomicPayload(format!(
+                        "liquidity state missing for asset `{}`",
                         faster_hex::hex_string(&asset_id)
                     ))
                 })?;
@@ -2419,6 +3375,201 @@ impl VirtualStateProcessor {
                     atomic_state.set_balance(sender_key, sender_after);
                 }
             }
+            AtomicPayloadOp::BuyLiquidityExactOut { asset_id, expected_pool_nonce, token_out, max_cpay_in_sompi } => {
+                let mut asset = atomic_state.cloned_asset(&asset_id).ok_or_else(|| {
+                    TxRuleError::InvalidAtomicPayload(format!("buy references unknown asset `{}`", faster_hex::hex_string(&asset_id)))
+                })?;
+                if !matches!(asset.asset_class, AtomicAssetClass::Liquidity) {
+                    return Err(TxRuleError::InvalidAtomicPayload(format!(
+                        "buy is only valid for liquidity assets (`{}` is standard)",
+                        faster_hex::hex_string(&asset_id)
+                    )));
+                }
+                let mut pool = asset.liquidity.clone().ok_or_else(|| {
+                    TxRuleError::InvalidAtomicPayload(format!(
+                        "liquidity state missing for asset `{}`",
+                        faster_hex::hex_string(&asset_id)
+                    ))
+                })?;
+                if pool.pool_nonce != expected_pool_nonce {
+                    return Err(TxRuleError::InvalidAtomicPayload(format!(
+                        "stale liquidity nonce for asset `{}`: expected `{}`, got `{}`",
+                        faster_hex::hex_string(&asset_id),
+                        pool.pool_nonce,
+                        expected_pool_nonce
+                    )));
+                }
+
+                let (net_in, new_real_token_reserves, new_virtual_cpay_reserves_sompi, new_virtual_token_reserves) =
+                    cpmm_buy_exact_out(
+                        pool.real_token_reserves,
+                        pool.virtual_cpay_reserves_sompi,
+                        pool.virtual_token_reserves,
+                        token_out,
+                    )?;
+                let (cpay_in_sompi, fee_trade) = gross_amount_for_net_amount(net_in, pool.fee_bps)?;
+                if cpay_in_sompi > max_cpay_in_sompi {
+                    return Err(TxRuleError::InvalidAtomicPayload(format!(
+                        "buy max_cpay_in violated: expected at most `{}`, got `{}`",
+                        max_cpay_in_sompi, cpay_in_sompi
+                    )));
+                }
+                let vault_transition = self.resolve_liquidity_vault_transition(tx, pool.vault_outpoint)?;
+                let vault_delta = vault_transition
+                    .output_value
+                    .checked_sub(vault_transition.input_value)
+                    .ok_or_else(|| TxRuleError::InvalidAtomicPayload("buy requires vault_value to increase".to_string()))?;
+                if vault_delta != cpay_in_sompi {
+                    return Err(TxRuleError::InvalidAtomicPayload(format!(
+                        "buy vault delta mismatch: expected `{}`, got `{}`",
+                        cpay_in_sompi, vault_delta
+                    )));
+                }
+
+                pool.real_cpay_reserves_sompi = pool
+                    .real_cpay_reserves_sompi
+                    .checked_add(net_in)
+                    .ok_or_else(|| TxRuleError::InvalidAtomicPayload("buy real CPAY reserve overflow".to_string()))?;
+                pool.real_token_reserves = new_real_token_reserves;
+                pool.virtual_cpay_reserves_sompi = new_virtual_cpay_reserves_sompi;
+                pool.virtual_token_reserves = new_virtual_token_reserves;
+                if pool.unlock_target_sompi > 0 && pool.real_cpay_reserves_sompi >= pool.unlock_target_sompi {
+                    pool.unlocked = true;
+                }
+                apply_fee_to_pool(&mut pool.fee_recipients, &mut pool.unclaimed_fee_total_sompi, fee_trade)?;
+                pool.vault_outpoint = TransactionOutpoint::new(tx.tx().id(), vault_transition.output_index);
+                pool.vault_value_sompi = vault_transition.output_value;
+                pool.pool_nonce = pool
+                    .pool_nonce
+                    .checked_add(1)
+                    .ok_or_else(|| TxRuleError::InvalidAtomicPayload("pool nonce overflow".to_string()))?;
+
+                let receiver_key = AtomicBalanceKey { asset_id, owner_id };
+                let receiver_balance = atomic_state.balance(&receiver_key);
+                let receiver_after = receiver_balance.checked_add(token_out).ok_or_else(|| {
+                    TxRuleError::InvalidAtomicPayload(format!(
+                        "receiver balance overflow while buying liquidity asset `{}`",
+                        faster_hex::hex_string(&asset_id)
+                    ))
+                })?;
+                asset.total_supply = asset.total_supply.checked_add(token_out).ok_or_else(|| {
+                    TxRuleError::InvalidAtomicPayload(format!(
+                        "total_supply overflow while buying liquidity asset `{}`",
+                        faster_hex::hex_string(&asset_id)
+                    ))
+                })?;
+                asset.liquidity = Some(pool);
+                self.validate_liquidity_invariants(asset_id, &asset)?;
+                self.insert_atomic_asset_state(atomic_state, asset_id, asset)?;
+                atomic_state.set_balance(receiver_key, receiver_after);
+            }
+            AtomicPayloadOp::SellLiquidityExactOut {
+                asset_id,
+                expected_pool_nonce,
+                cpay_out_sompi,
+                max_token_in,
+                cpay_receive_output_index,
+            } => {
+                let mut asset = atomic_state.cloned_asset(&asset_id).ok_or_else(|| {
+                    TxRuleError::InvalidAtomicPayload(format!("sell references unknown asset `{}`", faster_hex::hex_string(&asset_id)))
+                })?;
+                if !matches!(asset.asset_class, AtomicAssetClass::Liquidity) {
+                    return Err(TxRuleError::InvalidAtomicPayload(format!(
+                        "sell is only valid for liquidity assets (`{}` is standard)",
+                        faster_hex::hex_string(&asset_id)
+                    )));
+                }
+                let mut pool = asset.liquidity.clone().ok_or_else(|| {
+                    TxRuleError::InvalidAtomicPayload(format!(
+                        "liquidity state missing for asset `{}`",
+                        faster_hex::hex_string(&asset_id)
+                    ))
+                })?;
+                if pool.pool_nonce != expected_pool_nonce {
+                    return Err(TxRuleError::InvalidAtomicPayload(format!(
+                        "stale liquidity nonce for asset `{}`: expected `{}`, got `{}`",
+                        faster_hex::hex_string(&asset_id),
+                        pool.pool_nonce,
+                        expected_pool_nonce
+                    )));
+                }
+                if liquidity_sell_locked(&pool) {
+                    return Err(TxRuleError::InvalidAtomicPayload(format!(
+                        "liquidity sell locked for asset `{}` until real CPAY reserve reaches `{}` sompi",
+                        faster_hex::hex_string(&asset_id),
+                        pool.unlock_target_sompi
+                    )));
+                }
+                if cpay_out_sompi < LIQUIDITY_MIN_PAYOUT_SOMPI {
+                    return Err(TxRuleError::InvalidAtomicPayload(format!(
+                        "sell payout `{}` below liquidity_min_payout_sompi `{}`",
+                        cpay_out_sompi, LIQUIDITY_MIN_PAYOUT_SOMPI
+                    )));
+                }
+
+                let (gross_out, fee_trade) = gross_amount_for_net_amount(cpay_out_sompi, pool.fee_bps)?;
+                let (token_in, new_real_cpay_reserves_sompi, new_virtual_cpay_reserves_sompi, new_virtual_token_reserves) =
+                    cpmm_sell_exact_out(
+                        pool.real_cpay_reserves_sompi,
+                        pool.virtual_cpay_reserves_sompi,
+                        pool.virtual_token_reserves,
+                        gross_out,
+                    )?;
+                if token_in > max_token_in {
+                    return Err(TxRuleError::InvalidAtomicPayload(format!(
+                        "sell max_token_in violated: expected at most `{}`, got `{}`",
+                        max_token_in, token_in
+                    )));
+                }
+                let sender_key = AtomicBalanceKey { asset_id, owner_id };
+                let sender_balance = atomic_state.balance(&sender_key);
+                let sender_after = sender_balance.checked_sub(token_in).ok_or_else(|| {
+                    TxRuleError::InvalidAtomicPayload(format!(
+                        "insufficient balance for sell in liquidity asset `{}`",
+                        faster_hex::hex_string(&asset_id)
+                    ))
+                })?;
+                let supply_after = asset.total_supply.checked_sub(token_in).ok_or_else(|| {
+                    TxRuleError::InvalidAtomicPayload(format!(
+                        "total_supply underflow while selling liquidity asset `{}`",
+                        faster_hex::hex_string(&asset_id)
+                    ))
+                })?;
+
+                self.validate_payout_output(tx, cpay_receive_output_index, cpay_out_sompi, None)?;
+                let vault_transition = self.resolve_liquidity_vault_transition(tx, pool.vault_outpoint)?;
+                let vault_delta = vault_transition
+                    .input_value
+                    .checked_sub(vault_transition.output_value)
+                    .ok_or_else(|| TxRuleError::InvalidAtomicPayload("sell requires vault_value to decrease".to_string()))?;
+                if vault_delta != cpay_out_sompi {
+                    return Err(TxRuleError::InvalidAtomicPayload(format!(
+                        "sell vault delta mismatch: expected `{}`, got `{}`",
+                        cpay_out_sompi, vault_delta
+                    )));
+                }
+
+                pool.real_cpay_reserves_sompi = new_real_cpay_reserves_sompi;
+                pool.real_token_reserves = pool
+                    .real_token_reserves
+                    .checked_add(token_in)
+                    .ok_or_else(|| TxRuleError::InvalidAtomicPayload("sell real token reserve overflow".to_string()))?;
+                pool.virtual_cpay_reserves_sompi = new_virtual_cpay_reserves_sompi;
+                pool.virtual_token_reserves = new_virtual_token_reserves;
+                apply_fee_to_pool(&mut pool.fee_recipients, &mut pool.unclaimed_fee_total_sompi, fee_trade)?;
+                pool.vault_outpoint = TransactionOutpoint::new(tx.tx().id(), vault_transition.output_index);
+                pool.vault_value_sompi = vault_transition.output_value;
+                pool.pool_nonce = pool
+                    .pool_nonce
+                    .checked_add(1)
+                    .ok_or_else(|| TxRuleError::InvalidAtomicPayload("pool nonce overflow".to_string()))?;
+
+                asset.total_supply = supply_after;
+                asset.liquidity = Some(pool);
+                self.validate_liquidity_invariants(asset_id, &asset)?;
+                self.insert_atomic_asset_state(atomic_state, asset_id, asset)?;
+                atomic_state.set_balance(sender_key, sender_after);
+            }
             AtomicPayloadOp::ClaimLiquidityFees {
                 asset_id,
                 expected_pool_nonce,
@@ -2512,6 +3663,44 @@ impl VirtualStateProcessor {
         Ok(())
     }
 
+    #[allow(clippy::too_many_arguments)]
+    fn apply_pair_swap(
+        &self,
+        atomic_state: &mut AtomicConsensusState,
+        owner_id: [u8; 32],
+        pool_id: [u8; 32],
+        asset: &mut AtomicAssetState,
+        pair: &mut AtomicPairPoolState,
+        in_is_a: bool,
+        amount_in: u128,
+        amount_out: u128,
+        fee: u128,
+        accepting_daa_score: u64,
+    ) -> TxResult<()> {
+        let (reserve_key_in, reserve_key_out) = pair_reserve_keys(pair, pool_id, in_is_a);
+        let owner_key_in = AtomicBalanceKey { asset_id: reserve_key_in.asset_id, owner_id };
+        let owner_key_out = AtomicBalanceKey { asset_id: reserve_key_out.asset_id, owner_id };
+        let owner_in_after = atomic_balance_after_debit(atomic_state, &owner_key_in, amount_in, accepting_daa_score, "pair swap")?;
+        let owner_out_after = atomic_balance_after_credit(atomic_state, &owner_key_out, amount_out, "pair swap")?;
+        let reserve_in_after = atomic_balance_after_credit(atomic_state, &reserve_key_in, amount_in, "pair swap")?;
+        let reserve_out_after =
+            atomic_balance_after_debit(atomic_state, &reserve_key_out, amount_out, accepting_daa_score, "pair swap")?;
+        if in_is_a {
+            pair.accrued_fee_a = pair.accrued_fee_a.saturating_add(fee);
+        } else {
+            pair.accrued_fee_b = pair.accrued_fee_b.saturating_add(fee);
+        }
+        asset.pair_pool = Some(*pair);
+
+        self.insert_atomic_asset_state(atomic_state, pool_id, asset.clone())?;
+        atomic_state.set_balance(owner_key_in, owner_in_after);
+        atomic_state.set_balance(owner_key_out, owner_out_after);
+        atomic_state.set_balance(reserve_key_in, reserve_in_after);
+        atomic_state.set_balance(reserve_key_out, reserve_out_after);
+        prune_released_atomic_lock(atomic_state, owner_key_in, accepting_daa_score);
+        Ok(())
+    }
+
     fn insert_atomic_asset_state(
         &self,
         atomic_state: &mut AtomicConsensusState,
@@ -2521,6 +3710,37 @@ impl VirtualStateProcessor {
         atomic_state.set_asset(asset_id, asset).map_err(TxRuleError::InvalidAtomicPayload)
     }
 
+    /// Replaces the mint authority of a non-liquidity asset on behalf of its current authority.
+    fn set_atomic_mint_authority(
+        &self,
+        atomic_state: &mut AtomicConsensusState,
+        owner_id: [u8; 32],
+        asset_id: [u8; 32],
+        new_mint_authority_owner_id: [u8; 32],
+    ) -> TxResult<()> {
+        let mut asset = atomic_state.cloned_asset(&asset_id).ok_or_else(|| {
+            TxRuleError::InvalidAtomicPayload(format!(
+                "mint authority change references unknown asset `{}`",
+                faster_hex::hex_string(&asset_id)
+            ))
+        })?;
+        if matches!(asset.asset_class, AtomicAssetClass::Liquidity) {
+            return Err(TxRuleError::InvalidAtomicPayload(format!(
+                "mint authority change is invalid for liquidity asset `{}`",
+                faster_hex::hex_string(&asset_id)
+            )));
+        }
+        if asset.mint_authority_owner_id != owner_id {
+            return Err(TxRuleError::InvalidAtomicPayload(format!(
+                "owner `{}` is not mint authority for asset `{}`",
+                faster_hex::hex_string(&owner_id),
+                faster_hex::hex_string(&asset_id)
+            )));
+        }
+        asset.mint_authority_owner_id = new_mint_authority_owner_id;
+        self.insert_atomic_asset_state(atomic_state, asset_id, asset)
+    }
+
     fn collect_spent_liquidity_vault_inputs(
         &self,
         tx: &impl VerifiableTransaction,
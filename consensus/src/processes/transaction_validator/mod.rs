@@ -32,6 +32,12 @@ pub struct TransactionValidator {
     /// Payload hardfork DAA score
     payload_hf_activation_daa_score: u64,
 
+    /// Covenants hardfork DAA score
+    covenants_activation_daa_score: u64,
+
+    /// Extended CAT ops hardfork DAA score
+    atomic_extended_ops_activation_daa_score: u64,
+
     /// Consensus hard cap for payload transactions
     payload_max_len_consensus: usize,
 }
@@ -49,6 +55,8 @@ impl TransactionValidator {
         mass_calculator: MassCalculator,
         storage_mass_activation_daa_score: u64,
         payload_hf_activation_daa_score: u64,
+        covenants_activation_daa_score: u64,
+        atomic_extended_ops_activation_daa_score: u64,
         payload_max_len_consensus: usize,
     ) -> Self {
         Self {
@@ -63,6 +71,8 @@ impl TransactionValidator {
             mass_calculator,
             storage_mass_activation_daa_score,
             payload_hf_activation_daa_score,
+            covenants_activation_daa_score,
+            atomic_extended_ops_activation_daa_score,
             payload_max_len_consensus,
         }
     }
@@ -89,6 +99,8 @@ impl TransactionValidator {
             mass_calculator: MassCalculator::new(0, 0, 0, 0, 1),
             storage_mass_activation_daa_score: u64::MAX,
             payload_hf_activation_daa_score: 33_739_200,
+            covenants_activation_daa_score: u64::MAX,
+            atomic_extended_ops_activation_daa_score: u64::MAX,
             payload_max_len_consensus: usize::MAX,
         }
     }
@@ -97,4 +109,9 @@ impl TransactionValidator {
     pub(crate) fn is_payload_hf_active(&self, pov_daa_score: u64) -> bool {
         pov_daa_score >= self.payload_hf_activation_daa_score
     }
+
+    #[inline]
+    pub(crate) fn is_atomic_extended_ops_active(&self, pov_daa_score: u64) -> bool {
+        pov_daa_score >= self.atomic_extended_ops_activation_daa_score
+    }
 }
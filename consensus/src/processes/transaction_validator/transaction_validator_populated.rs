@@ -1,12 +1,22 @@
-use crate::constants::{MAX_SOMPI, SEQUENCE_LOCK_TIME_DISABLED, SEQUENCE_LOCK_TIME_MASK, SOMPI_PER_CRYPTIX};
-use blake2b_simd::Params as Blake2bParams;
+use crate::constants::{MAX_SOMPI, SEQUENCE_LOCK_TIME_DISABLED, SEQUENCE_LOCK_TIME_MASK};
 use cryptix_consensus_core::{
+    atomic::{
+        cat_owner_id, CAT_CURRENT_LIQUIDITY_CURVE_VERSION, CAT_CURRENT_TOKEN_VERSION, CAT_DEFAULT_LIQUIDITY_CURVE_MODE,
+        CAT_INDIVIDUAL_MAX_VIRTUAL_CPAY_RESERVES_SOMPI, CAT_INDIVIDUAL_MAX_VIRTUAL_TOKEN_MULTIPLIER_BPS,
+        CAT_INDIVIDUAL_MIN_VIRTUAL_CPAY_RESERVES_SOMPI, CAT_INDIVIDUAL_MIN_VIRTUAL_TOKEN_MULTIPLIER_BPS,
+        CAT_INDIVIDUAL_VIRTUAL_CPAY_STEP_SOMPI, CAT_INDIVIDUAL_VIRTUAL_TOKEN_MULTIPLIER_STEP_BPS, CAT_LIQUIDITY_CURVE_MODE_AGGRESSIVE,
+        CAT_LIQUIDITY_CURVE_MODE_BASIC, CAT_LIQUIDITY_CURVE_MODE_INDIVIDUAL, CAT_LIQUIDITY_TOKEN_DECIMALS, CAT_MAGIC,
+        CAT_MAX_BATCH_TRANSFER_RECIPIENTS, CAT_MAX_DECIMALS, CAT_MAX_LIQUIDITY_FEE_BPS, CAT_MAX_LIQUIDITY_RECIPIENTS,
+        CAT_MAX_LIQUIDITY_SUPPLY_RAW, CAT_MAX_METADATA_LEN, CAT_MAX_NAME_LEN, CAT_MAX_PAIR_FEE_BPS, CAT_MAX_PLATFORM_TAG_LEN,
+        CAT_MAX_SYMBOL_LEN, CAT_MIN_LIQUIDITY_FEE_BPS, CAT_MIN_LIQUIDITY_SEED_RESERVE_SOMPI, CAT_MIN_LIQUIDITY_SUPPLY_RAW,
+        CAT_OWNER_AUTH_SCHEME_PUBKEY, CAT_OWNER_AUTH_SCHEME_PUBKEY_ECDSA, CAT_OWNER_AUTH_SCHEME_SCRIPT_HASH, CAT_VERSION,
+    },
     hashing::sighash::SigHashReusedValues,
     mass::Kip9Version,
     tx::{ScriptPublicKey, TransactionInput, VerifiableTransaction},
 };
 use cryptix_core::warn;
-use cryptix_txscript::{get_sig_op_count, script_class::ScriptClass, TxScriptEngine};
+use cryptix_txscript::{get_sig_op_count, script_class::ScriptClass, EngineFlags, TxScriptEngine};
 use cryptix_txscript_errors::TxScriptError;
 
 use super::{
@@ -57,7 +67,7 @@ impl TransactionValidator {
         match flags {
             TxValidationFlags::Full | TxValidationFlags::SkipMassCheck => {
                 Self::check_sig_op_counts(tx)?;
-                self.check_scripts(tx)?;
+                self.check_scripts(tx, pov_daa_score)?;
             }
             TxValidationFlags::SkipScriptChecks => {}
         }
@@ -79,7 +89,8 @@ impl TransactionValidator {
             return Ok(());
         }
 
-        let parsed_payload = parse_atomic_payload(payload).map_err(TxRuleError::InvalidAtomicPayload)?;
+        let parsed_payload = parse_atomic_payload(payload, self.is_atomic_extended_ops_active(pov_daa_score))
+            .map_err(TxRuleError::InvalidAtomicPayload)?;
         let Some(parsed_payload) = parsed_payload else {
             return Ok(());
         };
@@ -205,10 +216,11 @@ impl TransactionValidator {
         Ok(())
     }
 
-    pub fn check_scripts(&self, tx: &impl VerifiableTransaction) -> TxResult<()> {
+    pub fn check_scripts(&self, tx: &impl VerifiableTransaction, pov_daa_score: u64) -> TxResult<()> {
+        let flags = EngineFlags { covenants_enabled: pov_daa_score >= self.covenants_activation_daa_score };
         let mut reused_values = SigHashReusedValues::new();
         for (i, (input, entry)) in tx.populated_inputs().enumerate() {
-            let mut engine = TxScriptEngine::from_transaction_input(tx, input, i, entry, &mut reused_values, &self.sig_cache)
+            let mut engine = TxScriptEngine::from_transaction_input(tx, input, i, entry, &mut reused_values, &self.sig_cache, flags)
                 .map_err(|err| map_script_err(err, input))?;
             engine.execute().map_err(|err| map_script_err(err, input))?;
         }
@@ -225,38 +237,12 @@ fn map_script_err(script_err: TxScriptError, input: &TransactionInput) -> TxRule
     }
 }
 
-pub(crate) const CAT_MAGIC: [u8; 3] = *b"CAT";
-const CAT_VERSION: u8 = 1;
-const CAT_CURRENT_TOKEN_VERSION: u8 = 1;
-const CAT_CURRENT_LIQUIDITY_CURVE_VERSION: u8 = 1;
-const CAT_LIQUIDITY_CURVE_MODE_BASIC: u8 = 0;
-const CAT_LIQUIDITY_CURVE_MODE_AGGRESSIVE: u8 = 1;
-const CAT_LIQUIDITY_CURVE_MODE_INDIVIDUAL: u8 = 2;
-const CAT_DEFAULT_LIQUIDITY_CURVE_MODE: u8 = CAT_LIQUIDITY_CURVE_MODE_BASIC;
-const CAT_INDIVIDUAL_MIN_VIRTUAL_CPAY_RESERVES_SOMPI: u64 = 100_000_000_000_000;
-const CAT_INDIVIDUAL_MAX_VIRTUAL_CPAY_RESERVES_SOMPI: u64 = 800_000_000_000_000;
-const CAT_INDIVIDUAL_VIRTUAL_CPAY_STEP_SOMPI: u64 = 10_000_000_000_000;
-const CAT_INDIVIDUAL_MIN_VIRTUAL_TOKEN_MULTIPLIER_BPS: u16 = 10_100;
-const CAT_INDIVIDUAL_MAX_VIRTUAL_TOKEN_MULTIPLIER_BPS: u16 = 20_000;
-const CAT_INDIVIDUAL_VIRTUAL_TOKEN_MULTIPLIER_STEP_BPS: u16 = 100;
 const CAT_MAX_TOKEN_VERSION: u8 = 99;
 const CAT_MAX_LIQUIDITY_CURVE_VERSION: u8 = 99;
-const CAT_OWNER_DOMAIN: &[u8] = b"CAT_OWNER_V2";
-const OWNER_AUTH_SCHEME_PUBKEY: u8 = 0;
-const OWNER_AUTH_SCHEME_PUBKEY_ECDSA: u8 = 1;
-const OWNER_AUTH_SCHEME_SCRIPT_HASH: u8 = 2;
-const CAT_MAX_NAME_LEN: usize = 32;
-const CAT_MAX_SYMBOL_LEN: usize = 10;
-const CAT_MAX_METADATA_LEN: usize = 256;
-const CAT_MAX_PLATFORM_TAG_LEN: usize = 50;
-const CAT_MAX_DECIMALS: u8 = 18;
-const CAT_MAX_LIQUIDITY_RECIPIENTS: usize = 2;
-const CAT_MIN_LIQUIDITY_FEE_BPS: u16 = 10;
-const CAT_MAX_LIQUIDITY_FEE_BPS: u16 = 1000;
-const CAT_LIQUIDITY_TOKEN_DECIMALS: u8 = 0;
-const CAT_MIN_LIQUIDITY_SUPPLY_RAW: u128 = 100_000;
-const CAT_MAX_LIQUIDITY_SUPPLY_RAW: u128 = 10_000_000;
-const CAT_MIN_LIQUIDITY_SEED_RESERVE_SOMPI: u64 = SOMPI_PER_CRYPTIX;
+/// Highest op code of the base CAT op set (create, transfer, mint, burn and the liquidity ops)
+const CAT_MAX_BASE_OP: u8 = 8;
+/// Highest op code of the extended CAT op set, gated by the extended ops hardfork
+const CAT_MAX_EXTENDED_OP: u8 = 22;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub(crate) enum AtomicPayloadSupplyMode {
@@ -343,6 +329,109 @@ pub(crate) enum AtomicPayloadOp {
         claim_amount_sompi: u64,
         claim_receive_output_index: u16,
     },
+    Approve {
+        asset_id: [u8; 32],
+        spender_owner_id: [u8; 32],
+        amount: u128,
+        expires_at_daa_score: u64,
+    },
+    TransferFrom {
+        asset_id: [u8; 32],
+        from_owner_id: [u8; 32],
+        to_owner_id: [u8; 32],
+        amount: u128,
+    },
+    BatchTransfer {
+        asset_id: [u8; 32],
+        recipients: Vec<AtomicPayloadBatchRecipient>,
+    },
+    LockTokens {
+        asset_id: [u8; 32],
+        beneficiary_owner_id: [u8; 32],
+        amount: u128,
+        release_start_daa_score: u64,
+        release_end_daa_score: u64,
+    },
+    TransferMintAuthority {
+        asset_id: [u8; 32],
+        new_mint_authority_owner_id: [u8; 32],
+    },
+    RenounceMintAuthority {
+        asset_id: [u8; 32],
+    },
+    UpdateMetadata {
+        asset_id: [u8; 32],
+        metadata: Vec<u8>,
+        platform_tag: Vec<u8>,
+    },
+    CreatePairPool {
+        asset_a_id: [u8; 32],
+        asset_b_id: [u8; 32],
+        fee_bps: u16,
+        amount_a: u128,
+        amount_b: u128,
+    },
+    AddPairLiquidity {
+        pool_id: [u8; 32],
+        amount_a_max: u128,
+        amount_b_max: u128,
+        min_shares_out: u128,
+    },
+    RemovePairLiquidity {
+        pool_id: [u8; 32],
+        shares: u128,
+        min_amount_a_out: u128,
+        min_amount_b_out: u128,
+    },
+    SwapPairExactIn {
+        pool_id: [u8; 32],
+        asset_in_id: [u8; 32],
+        amount_in: u128,
+        min_amount_out: u128,
+    },
+    SwapPairExactOut {
+        pool_id: [u8; 32],
+        asset_out_id: [u8; 32],
+        amount_out: u128,
+        max_amount_in: u128,
+    },
+    BuyLiquidityExactOut {
+        asset_id: [u8; 32],
+        expected_pool_nonce: u64,
+        token_out: u128,
+        max_cpay_in_sompi: u64,
+    },
+    SellLiquidityExactOut {
+        asset_id: [u8; 32],
+        expected_pool_nonce: u64,
+        cpay_out_sompi: u64,
+        max_token_in: u128,
+        cpay_receive_output_index: u16,
+    },
+}
+
+impl AtomicPayloadOp {
+    /// Whether the op belongs to the extended CAT op set, see `Params::atomic_extended_ops_activation_daa_score`
+    pub(crate) fn is_extended(&self) -> bool {
+        !matches!(
+            self,
+            AtomicPayloadOp::CreateAsset { .. }
+                | AtomicPayloadOp::Transfer { .. }
+                | AtomicPayloadOp::Mint { .. }
+                | AtomicPayloadOp::Burn { .. }
+                | AtomicPayloadOp::CreateAssetWithMint { .. }
+                | AtomicPayloadOp::CreateLiquidityAsset { .. }
+                | AtomicPayloadOp::BuyLiquidityExactIn { .. }
+                | AtomicPayloadOp::SellLiquidityExactIn { .. }
+                | AtomicPayloadOp::ClaimLiquidityFees { .. }
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct AtomicPayloadBatchRecipient {
+    pub to_owner_id: [u8; 32],
+    pub amount: u128,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -358,7 +447,9 @@ pub(crate) struct ParsedAtomicPayload {
     pub op: AtomicPayloadOp,
 }
 
-pub(crate) fn parse_atomic_payload(payload: &[u8]) -> Result<Option<ParsedAtomicPayload>, String> {
+/// Parses a CAT payload. Ops above `CAT_MAX_BASE_OP` are rejected unless `extended_ops_active` is set,
+/// see `Params::atomic_extended_ops_activation_daa_score`.
+pub(crate) fn parse_atomic_payload(payload: &[u8], extended_ops_active: bool) -> Result<Option<ParsedAtomicPayload>, String> {
     if payload.len() < CAT_MAGIC.len() || payload[..CAT_MAGIC.len()] != CAT_MAGIC {
         return Ok(None);
     }
@@ -375,9 +466,12 @@ pub(crate) fn parse_atomic_payload(payload: &[u8]) -> Result<Option<ParsedAtomic
     }
 
     let op = take_u8(payload, &mut cursor).ok_or_else(|| "truncated CAT op".to_string())?;
-    if op > 8 {
+    if op > CAT_MAX_EXTENDED_OP {
         return Err(format!("unsupported CAT op `{op}`"));
     }
+    if op > CAT_MAX_BASE_OP && !extended_ops_active {
+        return Err(format!("CAT op `{op}` is not activated"));
+    }
 
     let flags = take_u8(payload, &mut cursor).ok_or_else(|| "truncated CAT flags".to_string())?;
     if flags != 0 {
@@ -667,6 +761,188 @@ pub(crate) fn parse_atomic_payload(payload: &[u8]) -> Result<Option<ParsedAtomic
                 claim_receive_output_index,
             }
         }
+        9 => {
+            let asset_id = take_32(payload, &mut cursor).ok_or_else(|| "truncated CAT asset_id".to_string())?;
+            let spender_owner_id = take_32(payload, &mut cursor).ok_or_else(|| "truncated CAT spender_owner_id".to_string())?;
+            let amount = take_u128_le(payload, &mut cursor).ok_or_else(|| "truncated CAT approve amount".to_string())?;
+            let expires_at_daa_score = take_u64_le(payload, &mut cursor).ok_or_else(|| "truncated CAT approve expiry".to_string())?;
+            if spender_owner_id == [0u8; 32] {
+                return Err("approve spender must be non-zero".to_string());
+            }
+            AtomicPayloadOp::Approve { asset_id, spender_owner_id, amount, expires_at_daa_score }
+        }
+        10 => {
+            let asset_id = take_32(payload, &mut cursor).ok_or_else(|| "truncated CAT asset_id".to_string())?;
+            let from_owner_id = take_32(payload, &mut cursor).ok_or_else(|| "truncated CAT from_owner_id".to_string())?;
+            let to_owner_id = take_32(payload, &mut cursor).ok_or_else(|| "truncated CAT to_owner_id".to_string())?;
+            let amount = take_u128_le(payload, &mut cursor).ok_or_else(|| "truncated CAT transfer-from amount".to_string())?;
+            if amount == 0 {
+                return Err("transfer-from amount must be non-zero".to_string());
+            }
+            AtomicPayloadOp::TransferFrom { asset_id, from_owner_id, to_owner_id, amount }
+        }
+        11 => {
+            let asset_id = take_32(payload, &mut cursor).ok_or_else(|| "truncated CAT asset_id".to_string())?;
+            let recipient_count =
+                take_u8(payload, &mut cursor).ok_or_else(|| "truncated CAT batch recipient count".to_string())? as usize;
+            if !(1..=CAT_MAX_BATCH_TRANSFER_RECIPIENTS).contains(&recipient_count) {
+                return Err(format!("batch recipient_count must be in `1..={CAT_MAX_BATCH_TRANSFER_RECIPIENTS}`"));
+            }
+            let mut recipients: Vec<AtomicPayloadBatchRecipient> = Vec::with_capacity(recipient_count);
+            for _ in 0..recipient_count {
+                let to_owner_id = take_32(payload, &mut cursor).ok_or_else(|| "truncated CAT batch to_owner_id".to_string())?;
+                let amount = take_u128_le(payload, &mut cursor).ok_or_else(|| "truncated CAT batch amount".to_string())?;
+                if amount == 0 {
+                    return Err("batch transfer amount must be non-zero".to_string());
+                }
+                if recipients.iter().any(|recipient| recipient.to_owner_id == to_owner_id) {
+                    return Err("duplicate batch transfer recipients are not allowed".to_string());
+                }
+                recipients.push(AtomicPayloadBatchRecipient { to_owner_id, amount });
+            }
+            AtomicPayloadOp::BatchTransfer { asset_id, recipients }
+        }
+        12 => {
+            let asset_id = take_32(payload, &mut cursor).ok_or_else(|| "truncated CAT asset_id".to_string())?;
+            let beneficiary_owner_id =
+                take_32(payload, &mut cursor).ok_or_else(|| "truncated CAT beneficiary_owner_id".to_string())?;
+            let amount = take_u128_le(payload, &mut cursor).ok_or_else(|| "truncated CAT lock amount".to_string())?;
+            let release_start_daa_score =
+                take_u64_le(payload, &mut cursor).ok_or_else(|| "truncated CAT lock release start".to_string())?;
+            let release_end_daa_score =
+                take_u64_le(payload, &mut cursor).ok_or_else(|| "truncated CAT lock release end".to_string())?;
+            if amount == 0 {
+                return Err("lock amount must be non-zero".to_string());
+            }
+            if release_end_daa_score < release_start_daa_score {
+                return Err("lock release end must not precede release start".to_string());
+            }
+            AtomicPayloadOp::LockTokens { asset_id, beneficiary_owner_id, amount, release_start_daa_score, release_end_daa_score }
+        }
+        13 => {
+            let asset_id = take_32(payload, &mut cursor).ok_or_else(|| "truncated CAT asset_id".to_string())?;
+            let new_mint_authority_owner_id =
+                take_32(payload, &mut cursor).ok_or_else(|| "truncated CAT new_mint_authority_owner_id".to_string())?;
+            if new_mint_authority_owner_id == [0u8; 32] {
+                return Err("new mint authority must be non-zero; use RenounceMintAuthority instead".to_string());
+            }
+            AtomicPayloadOp::TransferMintAuthority { asset_id, new_mint_authority_owner_id }
+        }
+        14 => {
+            let asset_id = take_32(payload, &mut cursor).ok_or_else(|| "truncated CAT asset_id".to_string())?;
+            AtomicPayloadOp::RenounceMintAuthority { asset_id }
+        }
+        15 => {
+            let asset_id = take_32(payload, &mut cursor).ok_or_else(|| "truncated CAT asset_id".to_string())?;
+            let metadata_len = take_u16_le(payload, &mut cursor).ok_or_else(|| "truncated CAT metadata length".to_string())? as usize;
+            if metadata_len > CAT_MAX_METADATA_LEN {
+                return Err("string field exceeds allowed length".to_string());
+            }
+            let metadata = take_vec(payload, &mut cursor, metadata_len).ok_or_else(|| "truncated CAT metadata".to_string())?;
+            let platform_tag = parse_platform_tag(payload, &mut cursor)?;
+            AtomicPayloadOp::UpdateMetadata { asset_id, metadata, platform_tag }
+        }
+        16 => {
+            let asset_a_id = take_32(payload, &mut cursor).ok_or_else(|| "truncated CAT pair asset_a_id".to_string())?;
+            let asset_b_id = take_32(payload, &mut cursor).ok_or_else(|| "truncated CAT pair asset_b_id".to_string())?;
+            let fee_bps = take_u16_le(payload, &mut cursor).ok_or_else(|| "truncated CAT pair fee_bps".to_string())?;
+            let amount_a = take_u128_le(payload, &mut cursor).ok_or_else(|| "truncated CAT pair amount_a".to_string())?;
+            let amount_b = take_u128_le(payload, &mut cursor).ok_or_else(|| "truncated CAT pair amount_b".to_string())?;
+            if asset_a_id >= asset_b_id {
+                return Err("pair assets must be distinct and ordered asset_a < asset_b".to_string());
+            }
+            if fee_bps > CAT_MAX_PAIR_FEE_BPS {
+                return Err(format!("pair fee_bps must be <= {CAT_MAX_PAIR_FEE_BPS}"));
+            }
+            if amount_a == 0 || amount_b == 0 {
+                return Err("pair seed amounts must be non-zero".to_string());
+            }
+            AtomicPayloadOp::CreatePairPool { asset_a_id, asset_b_id, fee_bps, amount_a, amount_b }
+        }
+        17 => {
+            let pool_id = take_32(payload, &mut cursor).ok_or_else(|| "truncated CAT pair pool_id".to_string())?;
+            let amount_a_max = take_u128_le(payload, &mut cursor).ok_or_else(|| "truncated CAT pair amount_a_max".to_string())?;
+            let amount_b_max = take_u128_le(payload, &mut cursor).ok_or_else(|| "truncated CAT pair amount_b_max".to_string())?;
+            let min_shares_out = take_u128_le(payload, &mut cursor).ok_or_else(|| "truncated CAT pair min_shares_out".to_string())?;
+            if amount_a_max == 0 || amount_b_max == 0 {
+                return Err("pair liquidity amounts must be non-zero".to_string());
+            }
+            AtomicPayloadOp::AddPairLiquidity { pool_id, amount_a_max, amount_b_max, min_shares_out }
+        }
+        18 => {
+            let pool_id = take_32(payload, &mut cursor).ok_or_else(|| "truncated CAT pair pool_id".to_string())?;
+            let shares = take_u128_le(payload, &mut cursor).ok_or_else(|| "truncated CAT pair shares".to_string())?;
+            let min_amount_a_out =
+                take_u128_le(payload, &mut cursor).ok_or_else(|| "truncated CAT pair min_amount_a_out".to_string())?;
+            let min_amount_b_out =
+                take_u128_le(payload, &mut cursor).ok_or_else(|| "truncated CAT pair min_amount_b_out".to_string())?;
+            if shares == 0 {
+                return Err("pair shares must be non-zero".to_string());
+            }
+            AtomicPayloadOp::RemovePairLiquidity { pool_id, shares, min_amount_a_out, min_amount_b_out }
+        }
+        19 => {
+            let pool_id = take_32(payload, &mut cursor).ok_or_else(|| "truncated CAT pair pool_id".to_string())?;
+            let asset_in_id = take_32(payload, &mut cursor).ok_or_else(|| "truncated CAT pair asset_in_id".to_string())?;
+            let amount_in = take_u128_le(payload, &mut cursor).ok_or_else(|| "truncated CAT pair amount_in".to_string())?;
+            let min_amount_out = take_u128_le(payload, &mut cursor).ok_or_else(|| "truncated CAT pair min_amount_out".to_string())?;
+            if amount_in == 0 {
+                return Err("pair swap amount_in must be non-zero".to_string());
+            }
+            AtomicPayloadOp::SwapPairExactIn { pool_id, asset_in_id, amount_in, min_amount_out }
+        }
+        20 => {
+            let pool_id = take_32(payload, &mut cursor).ok_or_else(|| "truncated CAT pair pool_id".to_string())?;
+            let asset_out_id = take_32(payload, &mut cursor).ok_or_else(|| "truncated CAT pair asset_out_id".to_string())?;
+            let amount_out = take_u128_le(payload, &mut cursor).ok_or_else(|| "truncated CAT pair amount_out".to_string())?;
+            let max_amount_in = take_u128_le(payload, &mut cursor).ok_or_else(|| "truncated CAT pair max_amount_in".to_string())?;
+            if amount_out == 0 {
+                return Err("pair swap amount_out must be non-zero".to_string());
+            }
+            AtomicPayloadOp::SwapPairExactOut { pool_id, asset_out_id, amount_out, max_amount_in }
+        }
+        21 => {
+            let asset_id = take_32(payload, &mut cursor).ok_or_else(|| "truncated CAT asset_id".to_string())?;
+            let expected_pool_nonce =
+                take_u64_le(payload, &mut cursor).ok_or_else(|| "truncated CAT expected_pool_nonce".to_string())?;
+            if expected_pool_nonce == 0 {
+                return Err("buy expected_pool_nonce must be >= 1".to_string());
+            }
+            let token_out = take_u128_le(payload, &mut cursor).ok_or_else(|| "truncated CAT token out".to_string())?;
+            let max_cpay_in_sompi = take_u64_le(payload, &mut cursor).ok_or_else(|| "truncated CAT max cpay in".to_string())?;
+            if token_out == 0 {
+                return Err("buy token_out must be >0".to_string());
+            }
+            if max_cpay_in_sompi == 0 {
+                return Err("buy max_cpay_in_sompi must be >0".to_string());
+            }
+            AtomicPayloadOp::BuyLiquidityExactOut { asset_id, expected_pool_nonce, token_out, max_cpay_in_sompi }
+        }
+        22 => {
+            let asset_id = take_32(payload, &mut cursor).ok_or_else(|| "truncated CAT asset_id".to_string())?;
+            let expected_pool_nonce =
+                take_u64_le(payload, &mut cursor).ok_or_else(|| "truncated CAT expected_pool_nonce".to_string())?;
+            if expected_pool_nonce == 0 {
+                return Err("sell expected_pool_nonce must be >= 1".to_string());
+            }
+            let cpay_out_sompi = take_u64_le(payload, &mut cursor).ok_or_else(|| "truncated CAT cpay out".to_string())?;
+            let max_token_in = take_u128_le(payload, &mut cursor).ok_or_else(|| "truncated CAT max token in".to_string())?;
+            let cpay_receive_output_index =
+                take_u16_le(payload, &mut cursor).ok_or_else(|| "truncated CAT cpay receive output index".to_string())?;
+            if cpay_out_sompi == 0 {
+                return Err("sell cpay_out_sompi must be >0".to_string());
+            }
+            if max_token_in == 0 {
+                return Err("sell max_token_in must be >0".to_string());
+            }
+            AtomicPayloadOp::SellLiquidityExactOut {
+                asset_id,
+                expected_pool_nonce,
+                cpay_out_sompi,
+                max_token_in,
+                cpay_receive_output_index,
+            }
+        }
         _ => unreachable!(),
     };
 
@@ -856,43 +1132,17 @@ fn recipient_order_key(recipient: &AtomicPayloadRecipientAddress) -> (u8, &[u8])
 
 pub(crate) fn atomic_owner_id_from_script(script_public_key: &ScriptPublicKey) -> Option<[u8; 32]> {
     let (auth_scheme, canonical_pubkey_bytes) = canonical_atomic_owner_identity(script_public_key)?;
-    let pubkey_len = u16::try_from(canonical_pubkey_bytes.len()).ok()?;
-    let mut hasher = Blake2bParams::new().hash_length(32).to_state();
-    hasher.update(CAT_OWNER_DOMAIN);
-    hasher.update(&[auth_scheme]);
-    hasher.update(&pubkey_len.to_le_bytes());
-    hasher.update(canonical_pubkey_bytes);
-    let hash = hasher.finalize();
-    let mut owner_id = [0u8; 32];
-    owner_id.copy_from_slice(hash.as_bytes());
-    Some(owner_id)
+    cat_owner_id(auth_scheme, canonical_pubkey_bytes)
 }
 
-pub(crate) fn atomic_owner_id_from_address_components(address_version: u8, address_payload: &[u8]) -> Option<[u8; 32]> {
-    let auth_scheme = match address_version {
-        0 if address_payload.len() == 32 => OWNER_AUTH_SCHEME_PUBKEY,
-        1 if address_payload.len() == 33 => OWNER_AUTH_SCHEME_PUBKEY_ECDSA,
-        8 if address_payload.len() == 32 => OWNER_AUTH_SCHEME_SCRIPT_HASH,
-        _ => return None,
-    };
-    let pubkey_len = u16::try_from(address_payload.len()).ok()?;
-    let mut hasher = Blake2bParams::new().hash_length(32).to_state();
-    hasher.update(CAT_OWNER_DOMAIN);
-    hasher.update(&[auth_scheme]);
-    hasher.update(&pubkey_len.to_le_bytes());
-    hasher.update(address_payload);
-    let hash = hasher.finalize();
-    let mut owner_id = [0u8; 32];
-    owner_id.copy_from_slice(hash.as_bytes());
-    Some(owner_id)
-}
+pub(crate) use cryptix_consensus_core::atomic::cat_owner_id_from_address_components as atomic_owner_id_from_address_components;
 
 fn canonical_atomic_owner_identity(script_public_key: &ScriptPublicKey) -> Option<(u8, &[u8])> {
     let script_bytes = script_public_key.script();
     match ScriptClass::from_script(script_public_key) {
-        ScriptClass::PubKey if script_bytes.len() == 34 => Some((OWNER_AUTH_SCHEME_PUBKEY, &script_bytes[1..33])),
-        ScriptClass::PubKeyECDSA if script_bytes.len() == 35 => Some((OWNER_AUTH_SCHEME_PUBKEY_ECDSA, &script_bytes[1..34])),
-        ScriptClass::ScriptHash if script_bytes.len() == 35 => Some((OWNER_AUTH_SCHEME_SCRIPT_HASH, &script_bytes[2..34])),
+        ScriptClass::PubKey if script_bytes.len() == 34 => Some((CAT_OWNER_AUTH_SCHEME_PUBKEY, &script_bytes[1..33])),
+        ScriptClass::PubKeyECDSA if script_bytes.len() == 35 => Some((CAT_OWNER_AUTH_SCHEME_PUBKEY_ECDSA, &script_bytes[1..34])),
+        ScriptClass::ScriptHash if script_bytes.len() == 35 => Some((CAT_OWNER_AUTH_SCHEME_SCRIPT_HASH, &script_bytes[2..34])),
         _ => None,
     }
 }
@@ -963,6 +1213,172 @@ mod tests {
         assert!(super::atomic_owner_id_from_script(&script_public_key).is_some());
     }
 
+    #[test]
+    fn atomic_batch_transfer_payload_bounds() {
+        let batch_payload = |recipients: &[([u8; 32], u128)]| {
+            let mut payload = super::CAT_MAGIC.to_vec();
+            payload.extend_from_slice(&[1, 11, 0]);
+            payload.extend_from_slice(&0u16.to_le_bytes());
+            payload.extend_from_slice(&1u64.to_le_bytes());
+            payload.extend_from_slice(&[0x11; 32]);
+            payload.push(recipients.len() as u8);
+            for (to_owner_id, amount) in recipients {
+                payload.extend_from_slice(to_owner_id);
+                payload.extend_from_slice(&amount.to_le_bytes());
+            }
+            payload
+        };
+
+        let full: Vec<_> = (0..super::CAT_MAX_BATCH_TRANSFER_RECIPIENTS).map(|i| ([i as u8 + 1; 32], 1u128)).collect();
+        let payload = batch_payload(&full);
+        assert!(payload.len() <= MAINNET_PARAMS.payload_max_len_consensus);
+        let parsed = super::parse_atomic_payload(&payload, true).unwrap().unwrap();
+        assert!(
+            matches!(parsed.op, super::AtomicPayloadOp::BatchTransfer { recipients, .. } if recipients.len() == super::CAT_MAX_BATCH_TRANSFER_RECIPIENTS)
+        );
+
+        let mut too_many = full.clone();
+        too_many.push(([0xff; 32], 1));
+        assert!(super::parse_atomic_payload(&batch_payload(&too_many), true).is_err());
+        assert!(super::parse_atomic_payload(&batch_payload(&[]), true).is_err());
+        assert!(super::parse_atomic_payload(&batch_payload(&[([0x22; 32], 1), ([0x22; 32], 2)]), true).is_err());
+        assert!(super::parse_atomic_payload(&batch_payload(&[([0x22; 32], 0)]), true).is_err());
+    }
+
+    #[test]
+    fn atomic_update_metadata_payload_bounds() {
+        let update_payload = |metadata: &[u8], platform_tag: &[u8]| {
+            let mut payload = super::CAT_MAGIC.to_vec();
+            payload.extend_from_slice(&[1, 15, 0]);
+            payload.extend_from_slice(&0u16.to_le_bytes());
+            payload.extend_from_slice(&1u64.to_le_bytes());
+            payload.extend_from_slice(&[0x11; 32]);
+            payload.extend_from_slice(&(metadata.len() as u16).to_le_bytes());
+            payload.extend_from_slice(metadata);
+            payload.push(platform_tag.len() as u8);
+            payload.extend_from_slice(platform_tag);
+            payload
+        };
+
+        let parsed = super::parse_atomic_payload(&update_payload(b"ipfs://logo-v2", b"wallet-v2"), true).unwrap().unwrap();
+        assert!(matches!(
+            parsed.op,
+            super::AtomicPayloadOp::UpdateMetadata { metadata, platform_tag, .. }
+                if metadata == b"ipfs://logo-v2" && platform_tag == b"wallet-v2"
+        ));
+        assert!(super::parse_atomic_payload(&update_payload(b"", b""), true).unwrap().is_some());
+
+        assert!(super::parse_atomic_payload(&update_payload(&[0u8; super::CAT_MAX_METADATA_LEN + 1], b""), true).is_err());
+        assert!(super::parse_atomic_payload(&update_payload(b"", &[b'a'; super::CAT_MAX_PLATFORM_TAG_LEN + 1]), true).is_err());
+        assert!(super::parse_atomic_payload(&update_payload(b"", &[0xff]), true).is_err());
+        let mut truncated = update_payload(b"ipfs://logo-v2", b"wallet-v2");
+        truncated.pop();
+        assert!(super::parse_atomic_payload(&truncated, true).is_err());
+    }
+
+    #[test]
+    fn atomic_pair_pool_payload_bounds() {
+        let create_payload = |asset_a_id: [u8; 32], asset_b_id: [u8; 32], fee_bps: u16, amount_a: u128, amount_b: u128| {
+            let mut payload = super::CAT_MAGIC.to_vec();
+            payload.extend_from_slice(&[1, 16, 0]);
+            payload.extend_from_slice(&0u16.to_le_bytes());
+            payload.extend_from_slice(&1u64.to_le_bytes());
+            payload.extend_from_slice(&asset_a_id);
+            payload.extend_from_slice(&asset_b_id);
+            payload.extend_from_slice(&fee_bps.to_le_bytes());
+            payload.extend_from_slice(&amount_a.to_le_bytes());
+            payload.extend_from_slice(&amount_b.to_le_bytes());
+            payload
+        };
+
+        let parsed = super::parse_atomic_payload(&create_payload([0x11; 32], [0x22; 32], 30, 10_000, 40_000), true).unwrap().unwrap();
+        assert!(matches!(parsed.op, super::AtomicPayloadOp::CreatePairPool { fee_bps: 30, amount_a: 10_000, amount_b: 40_000, .. }));
+        assert!(super::parse_atomic_payload(&create_payload([0x22; 32], [0x11; 32], 30, 1, 1), true).is_err());
+        assert!(super::parse_atomic_payload(&create_payload([0x11; 32], [0x11; 32], 30, 1, 1), true).is_err());
+        assert!(
+            super::parse_atomic_payload(&create_payload([0x11; 32], [0x22; 32], super::CAT_MAX_PAIR_FEE_BPS + 1, 1, 1), true).is_err()
+        );
+        assert!(super::parse_atomic_payload(&create_payload([0x11; 32], [0x22; 32], 30, 0, 1), true).is_err());
+
+        let swap_payload = |op: u8, amount: u128, limit: u128| {
+            let mut payload = super::CAT_MAGIC.to_vec();
+            payload.extend_from_slice(&[1, op, 0]);
+            payload.extend_from_slice(&0u16.to_le_bytes());
+            payload.extend_from_slice(&1u64.to_le_bytes());
+            payload.extend_from_slice(&[0x33; 32]);
+            payload.extend_from_slice(&[0x11; 32]);
+            payload.extend_from_slice(&amount.to_le_bytes());
+            payload.extend_from_slice(&limit.to_le_bytes());
+            payload
+        };
+        let parsed = super::parse_atomic_payload(&swap_payload(19, 500, 400), true).unwrap().unwrap();
+        assert!(matches!(parsed.op, super::AtomicPayloadOp::SwapPairExactIn { amount_in: 500, min_amount_out: 400, .. }));
+        let parsed = super::parse_atomic_payload(&swap_payload(20, 400, 500), true).unwrap().unwrap();
+        assert!(matches!(parsed.op, super::AtomicPayloadOp::SwapPairExactOut { amount_out: 400, max_amount_in: 500, .. }));
+        assert!(super::parse_atomic_payload(&swap_payload(19, 0, 0), true).is_err());
+        assert!(super::parse_atomic_payload(&swap_payload(20, 0, 1), true).is_err());
+        let mut truncated = swap_payload(19, 500, 400);
+        truncated.pop();
+        assert!(super::parse_atomic_payload(&truncated, true).is_err());
+    }
+
+    #[test]
+    fn atomic_exact_out_liquidity_payload_bounds() {
+        let buy_payload = |expected_pool_nonce: u64, token_out: u128, max_cpay_in_sompi: u64| {
+            let mut payload = super::CAT_MAGIC.to_vec();
+            payload.extend_from_slice(&[1, 21, 0]);
+            payload.extend_from_slice(&0u16.to_le_bytes());
+            payload.extend_from_slice(&1u64.to_le_bytes());
+            payload.extend_from_slice(&[0x44; 32]);
+            payload.extend_from_slice(&expected_pool_nonce.to_le_bytes());
+            payload.extend_from_slice(&token_out.to_le_bytes());
+            payload.extend_from_slice(&max_cpay_in_sompi.to_le_bytes());
+            payload
+        };
+        let parsed = super::parse_atomic_payload(&buy_payload(2, 10, 5_000), true).unwrap().unwrap();
+        assert!(matches!(
+            parsed.op,
+            super::AtomicPayloadOp::BuyLiquidityExactOut { expected_pool_nonce: 2, token_out: 10, max_cpay_in_sompi: 5_000, .. }
+        ));
+        assert!(super::parse_atomic_payload(&buy_payload(0, 10, 5_000), true).is_err());
+        assert!(super::parse_atomic_payload(&buy_payload(2, 0, 5_000), true).is_err());
+        assert!(super::parse_atomic_payload(&buy_payload(2, 10, 0), true).is_err());
+
+        let sell_payload = |expected_pool_nonce: u64, cpay_out_sompi: u64, max_token_in: u128| {
+            let mut payload = super::CAT_MAGIC.to_vec();
+            payload.extend_from_slice(&[1, 22, 0]);
+            payload.extend_from_slice(&0u16.to_le_bytes());
+            payload.extend_from_slice(&1u64.to_le_bytes());
+            payload.extend_from_slice(&[0x45; 32]);
+            payload.extend_from_slice(&expected_pool_nonce.to_le_bytes());
+            payload.extend_from_slice(&cpay_out_sompi.to_le_bytes());
+            payload.extend_from_slice(&max_token_in.to_le_bytes());
+            payload.extend_from_slice(&1u16.to_le_bytes());
+            payload
+        };
+        let parsed = super::parse_atomic_payload(&sell_payload(3, 7_000, 12), true).unwrap().unwrap();
+        assert!(matches!(
+            parsed.op,
+            super::AtomicPayloadOp::SellLiquidityExactOut {
+                expected_pool_nonce: 3,
+                cpay_out_sompi: 7_000,
+                max_token_in: 12,
+                cpay_receive_output_index: 1,
+                ..
+            }
+        ));
+        assert!(super::parse_atomic_payload(&sell_payload(0, 7_000, 12), true).is_err());
+        assert!(super::parse_atomic_payload(&sell_payload(3, 0, 12), true).is_err());
+        assert!(super::parse_atomic_payload(&sell_payload(3, 7_000, 0), true).is_err());
+        let mut truncated = sell_payload(3, 7_000, 12);
+        truncated.pop();
+        assert!(super::parse_atomic_payload(&truncated, true).is_err());
+
+        let mut unknown_op = buy_payload(2, 10, 5_000);
+        unknown_op[super::CAT_MAGIC.len() + 1] = 23;
+        assert!(super::parse_atomic_payload(&unknown_op, true).is_err());
+    }
+
     #[test]
     fn check_signature_test() {
         let mut params = MAINNET_PARAMS.clone();
@@ -1021,7 +1437,7 @@ mod tests {
             }],
         );
 
-        tv.check_scripts(&populated_tx).expect("Signature check failed");
+        tv.check_scripts(&populated_tx, 0).expect("Signature check failed");
     }
 
     #[test]
@@ -1083,7 +1499,7 @@ mod tests {
             }],
         );
 
-        assert!(tv.check_scripts(&populated_tx).is_err(), "Failing Signature Test Failed");
+        assert!(tv.check_scripts(&populated_tx, 0).is_err(), "Failing Signature Test Failed");
     }
 
     #[test]
@@ -1145,7 +1561,7 @@ mod tests {
                 is_coinbase: false,
             }],
         );
-        tv.check_scripts(&populated_tx).expect("Signature check failed");
+        tv.check_scripts(&populated_tx, 0).expect("Signature check failed");
     }
 
     #[test]
@@ -1208,7 +1624,7 @@ mod tests {
             }],
         );
 
-        assert!(tv.check_scripts(&populated_tx) == Err(TxRuleError::SignatureInvalid(TxScriptError::NullFail)));
+        assert!(tv.check_scripts(&populated_tx, 0) == Err(TxRuleError::SignatureInvalid(TxScriptError::NullFail)));
     }
 
     #[test]
@@ -1271,7 +1687,7 @@ mod tests {
             }],
         );
 
-        assert!(tv.check_scripts(&populated_tx) == Err(TxRuleError::SignatureInvalid(TxScriptError::NullFail)));
+        assert!(tv.check_scripts(&populated_tx, 0) == Err(TxRuleError::SignatureInvalid(TxScriptError::NullFail)));
     }
 
     #[test]
@@ -1334,7 +1750,7 @@ mod tests {
             }],
         );
 
-        let result = tv.check_scripts(&populated_tx);
+        let result = tv.check_scripts(&populated_tx, 0);
         assert!(result == Err(TxRuleError::SignatureInvalid(TxScriptError::EvalFalse)));
     }
 
@@ -1389,7 +1805,7 @@ mod tests {
             }],
         );
 
-        let result = tv.check_scripts(&populated_tx);
+        let result = tv.check_scripts(&populated_tx, 0);
         assert!(result == Err(TxRuleError::SignatureInvalid(TxScriptError::SignatureScriptNotPushOnly)));
     }
 
@@ -1469,7 +1885,7 @@ mod tests {
         let schnorr_key = secp256k1::Keypair::from_seckey_slice(secp256k1::SECP256K1, &secret_key.secret_bytes()).unwrap();
         let signed_tx = sign(MutableTransaction::with_entries(unsigned_tx, entries), schnorr_key);
         let populated_tx = signed_tx.as_verifiable();
-        assert_eq!(tv.check_scripts(&populated_tx), Ok(()));
+        assert_eq!(tv.check_scripts(&populated_tx, 0), Ok(()));
         assert_eq!(TransactionValidator::check_sig_op_counts(&populated_tx), Ok(()));
     }
 
@@ -1488,6 +1904,8 @@ mod tests {
             MassCalculator::new(0, 0, 0, 0, 1),
             u64::MAX,
             0,
+            u64::MAX,
+            u64::MAX,
             8192,
         );
 
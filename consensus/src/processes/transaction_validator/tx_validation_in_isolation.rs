@@ -19,7 +19,12 @@ impl TransactionValidator {
         check_duplicate_transaction_inputs(tx)?;
         check_gas(tx)?;
         check_transaction_subnetwork(tx, payload_hf_activated)?;
-        check_transaction_payload(tx, payload_hf_activated, self.payload_max_len_consensus)?;
+        check_transaction_payload(
+            tx,
+            payload_hf_activated,
+            self.is_atomic_extended_ops_active(pov_daa_score),
+            self.payload_max_len_consensus,
+        )?;
         check_transaction_version(tx)
     }
 
@@ -109,7 +114,12 @@ fn check_gas(tx: &Transaction) -> TxResult<()> {
     Ok(())
 }
 
-fn check_transaction_payload(tx: &Transaction, payload_hf_activated: bool, payload_max_len_consensus: usize) -> TxResult<()> {
+fn check_transaction_payload(
+    tx: &Transaction,
+    payload_hf_activated: bool,
+    atomic_extended_ops_activated: bool,
+    payload_max_len_consensus: usize,
+) -> TxResult<()> {
     if tx.is_coinbase() {
         return Ok(());
     }
@@ -129,7 +139,7 @@ fn check_transaction_payload(tx: &Transaction, payload_hf_activated: bool, paylo
         if payload_len > payload_max_len_consensus {
             return Err(TxRuleError::PayloadLengthAboveMax(payload_len, payload_max_len_consensus));
         }
-        validate_atomic_payload_shape(tx).map_err(TxRuleError::InvalidAtomicPayload)?;
+        validate_atomic_payload_shape(tx, atomic_extended_ops_activated).map_err(TxRuleError::InvalidAtomicPayload)?;
         return Ok(());
     }
 
@@ -139,8 +149,8 @@ fn check_transaction_payload(tx: &Transaction, payload_hf_activated: bool, paylo
     Ok(())
 }
 
-fn validate_atomic_payload_shape(tx: &Transaction) -> Result<(), String> {
-    let Some(parsed_payload) = parse_atomic_payload(tx.payload.as_slice())? else {
+fn validate_atomic_payload_shape(tx: &Transaction, atomic_extended_ops_activated: bool) -> Result<(), String> {
+    let Some(parsed_payload) = parse_atomic_payload(tx.payload.as_slice(), atomic_extended_ops_activated)? else {
         return Ok(());
     };
     if parsed_payload.auth_input_index as usize >= tx.inputs.len() {
@@ -370,6 +380,8 @@ mod tests {
             cryptix_consensus_core::mass::MassCalculator::new(0, 0, 0, 0, 1),
             params.storage_mass_activation_daa_score,
             0,
+            params.covenants_activation_daa_score,
+            params.atomic_extended_ops_activation_daa_score,
             8192,
         );
 
@@ -478,4 +490,164 @@ mod tests {
         tx.payload = bad_create_payload;
         assert_match!(tv_post_hf.validate_tx_in_isolation(&tx, 0), Err(TxRuleError::InvalidAtomicPayload(_)));
     }
+
+    /// Validates a payload transaction carrying the CAT `op` with the given body right before and at the
+    /// extended CAT ops activation score
+    fn assert_extended_op_activation_gate(op: u8, body: &[u8]) {
+        const ACTIVATION_DAA_SCORE: u64 = 1000;
+        let params = MAINNET_PARAMS.clone();
+        let tv = TransactionValidator::new(
+            params.max_tx_inputs,
+            params.max_tx_outputs,
+            params.max_signature_script_len,
+            params.max_script_public_key_len,
+            params.ghostdag_k,
+            params.coinbase_payload_script_public_key_max_len,
+            params.coinbase_maturity,
+            Default::default(),
+            cryptix_consensus_core::mass::MassCalculator::new(0, 0, 0, 0, 1),
+            params.storage_mass_activation_daa_score,
+            0,
+            params.covenants_activation_daa_score,
+            ACTIVATION_DAA_SCORE,
+            8192,
+        );
+
+        let mut payload = Vec::new();
+        payload.extend_from_slice(b"CAT");
+        payload.push(1); // version
+        payload.push(op);
+        payload.push(0); // flags
+        payload.extend_from_slice(&0u16.to_le_bytes()); // auth_input_index
+        payload.extend_from_slice(&1u64.to_le_bytes()); // nonce
+        payload.extend_from_slice(body);
+        let tx = Transaction::new(
+            TX_VERSION,
+            vec![TransactionInput::new(TransactionOutpoint::new(Default::default(), 0), vec![], u64::MAX, 0)],
+            vec![TransactionOutput::new(1000, ScriptPublicKey::new(0, scriptvec![0x51]))],
+            0,
+            SUBNETWORK_ID_PAYLOAD,
+            0,
+            payload,
+        );
+
+        match tv.validate_tx_in_isolation(&tx, ACTIVATION_DAA_SCORE - 1) {
+            Err(TxRuleError::InvalidAtomicPayload(err)) => assert!(err.contains("is not activated"), "op {op}: {err}"),
+            res => panic!("op {op} should be rejected before activation, got {res:?}"),
+        }
+        tv.validate_tx_in_isolation(&tx, ACTIVATION_DAA_SCORE).unwrap();
+    }
+
+    #[test]
+    fn atomic_allowance_ops_activation_gate() {
+        let mut approve = Vec::new();
+        approve.extend_from_slice(&[5u8; 32]); // asset_id
+        approve.extend_from_slice(&[7u8; 32]); // spender_owner_id
+        approve.extend_from_slice(&10u128.to_le_bytes()); // amount
+        approve.extend_from_slice(&0u64.to_le_bytes()); // expires_at_daa_score
+        assert_extended_op_activation_gate(9, &approve);
+
+        let mut transfer_from = Vec::new();
+        transfer_from.extend_from_slice(&[5u8; 32]); // asset_id
+        transfer_from.extend_from_slice(&[7u8; 32]); // from_owner_id
+        transfer_from.extend_from_slice(&[8u8; 32]); // to_owner_id
+        transfer_from.extend_from_slice(&10u128.to_le_bytes()); // amount
+        assert_extended_op_activation_gate(10, &transfer_from);
+    }
+
+    #[test]
+    fn atomic_batch_transfer_activation_gate() {
+        let mut batch_transfer = Vec::new();
+        batch_transfer.extend_from_slice(&[5u8; 32]); // asset_id
+        batch_transfer.push(2); // recipient count
+        for to_owner_id in [[7u8; 32], [8u8; 32]] {
+            batch_transfer.extend_from_slice(&to_owner_id);
+            batch_transfer.extend_from_slice(&10u128.to_le_bytes()); // amount
+        }
+        assert_extended_op_activation_gate(11, &batch_transfer);
+    }
+
+    #[test]
+    fn atomic_lock_tokens_activation_gate() {
+        let mut lock_tokens = Vec::new();
+        lock_tokens.extend_from_slice(&[5u8; 32]); // asset_id
+        lock_tokens.extend_from_slice(&[7u8; 32]); // beneficiary_owner_id
+        lock_tokens.extend_from_slice(&10u128.to_le_bytes()); // amount
+        lock_tokens.extend_from_slice(&2000u64.to_le_bytes()); // release_start_daa_score
+        lock_tokens.extend_from_slice(&3000u64.to_le_bytes()); // release_end_daa_score
+        assert_extended_op_activation_gate(12, &lock_tokens);
+    }
+
+    #[test]
+    fn atomic_mint_authority_ops_activation_gate() {
+        let mut transfer_mint_authority = Vec::new();
+        transfer_mint_authority.extend_from_slice(&[5u8; 32]); // asset_id
+        transfer_mint_authority.extend_from_slice(&[7u8; 32]); // new_mint_authority_owner_id
+        assert_extended_op_activation_gate(13, &transfer_mint_authority);
+
+        assert_extended_op_activation_gate(14, &[5u8; 32]); // asset_id
+    }
+
+    #[test]
+    fn atomic_update_metadata_activation_gate() {
+        let mut update_metadata = Vec::new();
+        update_metadata.extend_from_slice(&[5u8; 32]); // asset_id
+        update_metadata.extend_from_slice(&5u16.to_le_bytes()); // metadata len
+        update_metadata.extend_from_slice(b"hello");
+        update_metadata.push(3); // platform tag len
+        update_metadata.extend_from_slice(b"cpx");
+        assert_extended_op_activation_gate(15, &update_metadata);
+    }
+
+    #[test]
+    fn atomic_pair_pool_ops_activation_gate() {
+        let mut create_pair_pool = Vec::new();
+        create_pair_pool.extend_from_slice(&[5u8; 32]); // asset_a_id
+        create_pair_pool.extend_from_slice(&[6u8; 32]); // asset_b_id
+        create_pair_pool.extend_from_slice(&30u16.to_le_bytes()); // fee_bps
+        create_pair_pool.extend_from_slice(&10u128.to_le_bytes()); // amount_a
+        create_pair_pool.extend_from_slice(&20u128.to_le_bytes()); // amount_b
+        assert_extended_op_activation_gate(16, &create_pair_pool);
+
+        let mut add_pair_liquidity = Vec::new();
+        add_pair_liquidity.extend_from_slice(&[9u8; 32]); // pool_id
+        add_pair_liquidity.extend_from_slice(&10u128.to_le_bytes()); // amount_a_max
+        add_pair_liquidity.extend_from_slice(&20u128.to_le_bytes()); // amount_b_max
+        add_pair_liquidity.extend_from_slice(&1u128.to_le_bytes()); // min_shares_out
+        assert_extended_op_activation_gate(17, &add_pair_liquidity);
+
+        let mut remove_pair_liquidity = Vec::new();
+        remove_pair_liquidity.extend_from_slice(&[9u8; 32]); // pool_id
+        remove_pair_liquidity.extend_from_slice(&5u128.to_le_bytes()); // shares
+        remove_pair_liquidity.extend_from_slice(&1u128.to_le_bytes()); // min_amount_a_out
+        remove_pair_liquidity.extend_from_slice(&1u128.to_le_bytes()); // min_amount_b_out
+        assert_extended_op_activation_gate(18, &remove_pair_liquidity);
+
+        for op in [19, 20] {
+            let mut swap = Vec::new();
+            swap.extend_from_slice(&[9u8; 32]); // pool_id
+            swap.extend_from_slice(&[5u8; 32]); // asset_in_id / asset_out_id
+            swap.extend_from_slice(&10u128.to_le_bytes()); // amount_in / amount_out
+            swap.extend_from_slice(&20u128.to_le_bytes()); // min_amount_out / max_amount_in
+            assert_extended_op_activation_gate(op, &swap);
+        }
+    }
+
+    #[test]
+    fn atomic_exact_out_liquidity_ops_activation_gate() {
+        let mut buy_exact_out = Vec::new();
+        buy_exact_out.extend_from_slice(&[5u8; 32]); // asset_id
+        buy_exact_out.extend_from_slice(&1u64.to_le_bytes()); // expected_pool_nonce
+        buy_exact_out.extend_from_slice(&10u128.to_le_bytes()); // token_out
+        buy_exact_out.extend_from_slice(&5_000u64.to_le_bytes()); // max_cpay_in_sompi
+        assert_extended_op_activation_gate(21, &buy_exact_out);
+
+        let mut sell_exact_out = Vec::new();
+        sell_exact_out.extend_from_slice(&[5u8; 32]); // asset_id
+        sell_exact_out.extend_from_slice(&1u64.to_le_bytes()); // expected_pool_nonce
+        sell_exact_out.extend_from_slice(&5_000u64.to_le_bytes()); // cpay_out_sompi
+        sell_exact_out.extend_from_slice(&10u128.to_le_bytes()); // max_token_in
+        sell_exact_out.extend_from_slice(&0u16.to_le_bytes()); // cpay_receive_output_index
+        assert_extended_op_activation_gate(22, &sell_exact_out);
+    }
 }
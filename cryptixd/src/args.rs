@@ -5,6 +5,14 @@ use cryptix_consensus_core::{
 };
 use cryptix_core::cryptixd_env::version;
 use cryptix_notify::address::tracker::Tracker;
+use cryptix_rpc_service::hfa::{
+    DEFAULT_CLOCK_DRIFT_MAX_MS, DEFAULT_CPU_LOW_WATER_RATIO, DEFAULT_FAST_ARBITER_QUEUE_CAPACITY, DEFAULT_FAST_TTL_MS,
+    DEFAULT_MAX_INPUTS_PER_INTENT, DEFAULT_MAX_LOCKS, DEFAULT_MAX_OUTPUTS_PER_INTENT, DEFAULT_MAX_PENDING_INTENTS,
+    DEFAULT_MAX_TERMINAL_BYTES, DEFAULT_MAX_TERMINAL_ENTRIES, DEFAULT_MICROBLOCK_INTERVAL_MS_DEGRADED,
+    DEFAULT_MICROBLOCK_INTERVAL_MS_NORMAL, DEFAULT_MIN_FEERATE_FLOOR, DEFAULT_NORMAL_ARBITER_QUEUE_CAPACITY,
+    DEFAULT_REVALIDATION_BUDGET_DEGRADED, DEFAULT_REVALIDATION_BUDGET_NORMAL, DEFAULT_SEEN_CACHE_TTL_MS,
+    DEFAULT_TERMINAL_RETENTION_MS,
+};
 use cryptix_utils::networking::ContextualNetAddress;
 use cryptix_wrpc_server::address::WrpcNetAddress;
 use serde::Deserialize;
@@ -57,6 +65,7 @@ pub struct Args {
     #[serde(rename = "uacomment")]
     pub user_agent_comments: Vec<String>,
     pub utxoindex: bool,
+    pub messageindex: bool,
     pub atomic_unsafe_skip_snapshot_finality_check: bool,
     #[serde_as(as = "Vec<DisplayFromStr>")]
     #[serde(rename = "atomic-bootstrap-peer")]
@@ -98,10 +107,27 @@ pub struct Args {
     pub hfa_cpu: f64,
     pub hfa_drift_ms: u64,
     pub hfa_microblock_interval_ms_normal: u64,
+    pub hfa_fast_ttl_ms: u64,
+    pub hfa_terminal_retention_ms: u64,
+    pub hfa_max_pending_intents: usize,
+    pub hfa_max_locks: usize,
+    pub hfa_max_terminal_entries: usize,
+    pub hfa_max_terminal_bytes: usize,
+    pub hfa_max_inputs_per_intent: usize,
+    pub hfa_max_outputs_per_intent: usize,
+    pub hfa_min_feerate_floor: f64,
+    pub hfa_seen_cache_ttl_ms: u64,
+    pub hfa_microblock_interval_ms_degraded: u64,
+    pub hfa_revalidation_budget_normal: usize,
+    pub hfa_revalidation_budget_degraded: usize,
+    pub hfa_normal_arbiter_queue_capacity: usize,
+    pub hfa_fast_arbiter_queue_capacity: usize,
     pub autoban: bool,
     pub banserver: bool,
     pub coinbase_maturity_override: Option<u64>,
     pub payload_hf_activation_daa_score: Option<u64>,
+    pub covenants_activation_daa_score: Option<u64>,
+    pub atomic_extended_ops_activation_daa_score: Option<u64>,
 
     #[cfg(feature = "devnet-prealloc")]
     pub num_prealloc_utxos: Option<u64>,
@@ -132,6 +158,7 @@ impl Default for Args {
             rpc_block_scan_cache_max_mb: 1024,
             async_threads: num_cpus::get(),
             utxoindex: true,
+            messageindex: false,
             atomic_unsafe_skip_snapshot_finality_check: false,
             atomic_bootstrap_peers: vec![],
             disable_atomic_seed_sources: false,
@@ -169,13 +196,30 @@ impl Default for Args {
             tx_relay_broadcast_interval_ms: 250,
             datacenter: false,
             hfa: false,
-            hfa_cpu: 0.7,
-            hfa_drift_ms: 5_000,
-            hfa_microblock_interval_ms_normal: 50,
+            hfa_cpu: DEFAULT_CPU_LOW_WATER_RATIO,
+            hfa_drift_ms: DEFAULT_CLOCK_DRIFT_MAX_MS,
+            hfa_microblock_interval_ms_normal: DEFAULT_MICROBLOCK_INTERVAL_MS_NORMAL,
+            hfa_fast_ttl_ms: DEFAULT_FAST_TTL_MS,
+            hfa_terminal_retention_ms: DEFAULT_TERMINAL_RETENTION_MS,
+            hfa_max_pending_intents: DEFAULT_MAX_PENDING_INTENTS,
+            hfa_max_locks: DEFAULT_MAX_LOCKS,
+            hfa_max_terminal_entries: DEFAULT_MAX_TERMINAL_ENTRIES,
+            hfa_max_terminal_bytes: DEFAULT_MAX_TERMINAL_BYTES,
+            hfa_max_inputs_per_intent: DEFAULT_MAX_INPUTS_PER_INTENT,
+            hfa_max_outputs_per_intent: DEFAULT_MAX_OUTPUTS_PER_INTENT,
+            hfa_min_feerate_floor: DEFAULT_MIN_FEERATE_FLOOR,
+            hfa_seen_cache_ttl_ms: DEFAULT_SEEN_CACHE_TTL_MS,
+            hfa_microblock_interval_ms_degraded: DEFAULT_MICROBLOCK_INTERVAL_MS_DEGRADED,
+            hfa_revalidation_budget_normal: DEFAULT_REVALIDATION_BUDGET_NORMAL,
+            hfa_revalidation_budget_degraded: DEFAULT_REVALIDATION_BUDGET_DEGRADED,
+            hfa_normal_arbiter_queue_capacity: DEFAULT_NORMAL_ARBITER_QUEUE_CAPACITY,
+            hfa_fast_arbiter_queue_capacity: DEFAULT_FAST_ARBITER_QUEUE_CAPACITY,
             autoban: false,
             banserver: true,
             coinbase_maturity_override: None,
             payload_hf_activation_daa_score: None,
+            covenants_activation_daa_score: None,
+            atomic_extended_ops_activation_daa_score: None,
 
             #[cfg(feature = "devnet-prealloc")]
             num_prealloc_utxos: None,
@@ -195,6 +239,7 @@ impl Default for Args {
 impl Args {
     pub fn apply_to_config(&self, config: &mut Config) {
         config.utxoindex = self.utxoindex;
+        config.messageindex = self.messageindex;
         config.atomic_unsafe_skip_snapshot_finality_check = self.atomic_unsafe_skip_snapshot_finality_check;
         config.disable_upnp = self.disable_upnp;
         config.unsafe_rpc = self.unsafe_rpc;
@@ -220,6 +265,12 @@ impl Args {
         if let Some(payload_hf_activation_daa_score) = self.payload_hf_activation_daa_score {
             config.params.payload_hf_activation_daa_score = payload_hf_activation_daa_score;
         }
+        if let Some(covenants_activation_daa_score) = self.covenants_activation_daa_score {
+            config.params.covenants_activation_daa_score = covenants_activation_daa_score;
+        }
+        if let Some(atomic_extended_ops_activation_daa_score) = self.atomic_extended_ops_activation_daa_score {
+            config.params.atomic_extended_ops_activation_daa_score = atomic_extended_ops_activation_daa_score;
+        }
 
         #[cfg(feature = "devnet-prealloc")]
         if let Some(num_prealloc_utxos) = self.num_prealloc_utxos {
@@ -421,6 +472,22 @@ pub fn cli() -> Command {
                 .value_parser(clap::value_parser!(u64))
                 .help("Override payload hardfork activation DAA score."),
         )
+        .arg(
+            Arg::new("covenants-activation-daa-score")
+                .long("covenants-activation-daa-score")
+                .value_name("DAA_SCORE")
+                .require_equals(true)
+                .value_parser(clap::value_parser!(u64))
+                .help("Override covenants hardfork activation DAA score."),
+        )
+        .arg(
+            Arg::new("atomic-extended-ops-activation-daa-score")
+                .long("atomic-extended-ops-activation-daa-score")
+                .value_name("DAA_SCORE")
+                .require_equals(true)
+                .value_parser(clap::value_parser!(u64))
+                .help("Override extended CAT ops hardfork activation DAA score."),
+        )
         .arg(
             Arg::new("atomic-unsafe-skip-snapshot-finality-check")
                 .long("atomic-unsafe-skip-snapshot-finality-check")
@@ -445,6 +512,12 @@ pub fn cli() -> Command {
                 .conflicts_with("utxoindex")
                 .help("Disable the UTXO index."),
         )
+        .arg(
+            Arg::new("messageindex")
+                .long("messageindex")
+                .action(ArgAction::SetTrue)
+                .help("Enable the messenger inbox index over CXM payloads, required by the GetMessagesByRecipientTag RPC."),
+        )
         .arg(
             Arg::new("atomic-bootstrap-peer")
                 .long("atomic-bootstrap-peer")
@@ -558,21 +631,174 @@ Setting to 0 prevents the preallocation and sets the maximum to {}, leading to 0
                 .long("hfa-cpu")
                 .require_equals(true)
                 .value_parser(clap::value_parser!(f64))
-                .help("HFA CPU low-water ratio used by mode control resume logic (default: 0.7)."),
+                .help(format!("HFA CPU low-water ratio used by mode control resume logic (default: {}).", defaults.hfa_cpu)),
         )
         .arg(
             Arg::new("hfa-drift-ms")
                 .long("hfa-drift-ms")
                 .require_equals(true)
                 .value_parser(clap::value_parser!(u64))
-                .help("HFA max accepted clock drift window in milliseconds before correction/reject logic applies (default: 5000)."),
+                .help(format!(
+                    "HFA max accepted clock drift window in milliseconds before correction/reject logic applies (default: {}).",
+                    defaults.hfa_drift_ms
+                )),
         )
         .arg(
             Arg::new("hfa-microblock-interval-ms-normal")
                 .long("hfa-microblock-interval-ms-normal")
                 .require_equals(true)
                 .value_parser(clap::value_parser!(u64))
-                .help("HFA microblock interval in milliseconds while in normal mode (default: 50)."),
+                .help(format!(
+                    "HFA microblock interval in milliseconds while in normal mode (default: {}).",
+                    defaults.hfa_microblock_interval_ms_normal
+                )),
+        )
+        .arg(
+            Arg::new("hfa-fast-ttl-ms")
+                .long("hfa-fast-ttl-ms")
+                .require_equals(true)
+                .value_parser(clap::value_parser!(u64))
+                .help(format!(
+                    "Lifetime of a locked fast intent in milliseconds before it expires (default: {}).",
+                    defaults.hfa_fast_ttl_ms
+                )),
+        )
+        .arg(
+            Arg::new("hfa-terminal-retention-ms")
+                .long("hfa-terminal-retention-ms")
+                .require_equals(true)
+                .value_parser(clap::value_parser!(u64))
+                .help(format!(
+                    "Retention of terminal fast intent states in milliseconds (default: {}).",
+                    defaults.hfa_terminal_retention_ms
+                )),
+        )
+        .arg(
+            Arg::new("hfa-max-pending-intents")
+                .long("hfa-max-pending-intents")
+                .require_equals(true)
+                .value_parser(clap::value_parser!(usize))
+                .help(format!(
+                    "Maximum number of pending (pre-lock and locked) fast intents (default: {}).",
+                    defaults.hfa_max_pending_intents
+                )),
+        )
+        .arg(
+            Arg::new("hfa-max-locks")
+                .long("hfa-max-locks")
+                .require_equals(true)
+                .value_parser(clap::value_parser!(usize))
+                .help(format!("Maximum number of inputs locked by fast intents (default: {}).", defaults.hfa_max_locks)),
+        )
+        .arg(
+            Arg::new("hfa-max-terminal-entries")
+                .long("hfa-max-terminal-entries")
+                .require_equals(true)
+                .value_parser(clap::value_parser!(usize))
+                .help(format!(
+                    "Maximum number of retained terminal fast intent states (default: {}).",
+                    defaults.hfa_max_terminal_entries
+                )),
+        )
+        .arg(
+            Arg::new("hfa-max-terminal-bytes")
+                .long("hfa-max-terminal-bytes")
+                .require_equals(true)
+                .value_parser(clap::value_parser!(usize))
+                .help(format!(
+                    "Maximum estimated memory of retained terminal fast intent states in bytes (default: {}).",
+                    defaults.hfa_max_terminal_bytes
+                )),
+        )
+        .arg(
+            Arg::new("hfa-max-inputs-per-intent")
+                .long("hfa-max-inputs-per-intent")
+                .require_equals(true)
+                .value_parser(clap::value_parser!(usize))
+                .help(format!(
+                    "Maximum number of base transaction inputs per fast intent (default: {}).",
+                    defaults.hfa_max_inputs_per_intent
+                )),
+        )
+        .arg(
+            Arg::new("hfa-max-outputs-per-intent")
+                .long("hfa-max-outputs-per-intent")
+                .require_equals(true)
+                .value_parser(clap::value_parser!(usize))
+                .help(format!(
+                    "Maximum number of base transaction outputs per fast intent (default: {}).",
+                    defaults.hfa_max_outputs_per_intent
+                )),
+        )
+        .arg(
+            Arg::new("hfa-min-feerate-floor")
+                .long("hfa-min-feerate-floor")
+                .require_equals(true)
+                .value_parser(clap::value_parser!(f64))
+                .help(format!(
+                    "Minimum fee rate floor of fast intents in sompi per gram; the effective floor is at least twice the minimum relay fee rate (default: {}).",
+                    defaults.hfa_min_feerate_floor
+                )),
+        )
+        .arg(
+            Arg::new("hfa-seen-cache-ttl-ms")
+                .long("hfa-seen-cache-ttl-ms")
+                .require_equals(true)
+                .value_parser(clap::value_parser!(u64))
+                .help(format!(
+                    "How long seen fast intent ids are remembered for duplicate detection in milliseconds (default: {}).",
+                    defaults.hfa_seen_cache_ttl_ms
+                )),
+        )
+        .arg(
+            Arg::new("hfa-microblock-interval-ms-degraded")
+                .long("hfa-microblock-interval-ms-degraded")
+                .require_equals(true)
+                .value_parser(clap::value_parser!(u64))
+                .help(format!(
+                    "HFA microblock interval in milliseconds while in degraded mode (default: {}).",
+                    defaults.hfa_microblock_interval_ms_degraded
+                )),
+        )
+        .arg(
+            Arg::new("hfa-revalidation-budget-normal")
+                .long("hfa-revalidation-budget-normal")
+                .require_equals(true)
+                .value_parser(clap::value_parser!(usize))
+                .help(format!(
+                    "Number of locked fast intents revalidated per pass while in normal mode (default: {}).",
+                    defaults.hfa_revalidation_budget_normal
+                )),
+        )
+        .arg(
+            Arg::new("hfa-revalidation-budget-degraded")
+                .long("hfa-revalidation-budget-degraded")
+                .require_equals(true)
+                .value_parser(clap::value_parser!(usize))
+                .help(format!(
+                    "Number of locked fast intents revalidated per pass while in degraded mode (default: {}).",
+                    defaults.hfa_revalidation_budget_degraded
+                )),
+        )
+        .arg(
+            Arg::new("hfa-normal-arbiter-queue-capacity")
+                .long("hfa-normal-arbiter-queue-capacity")
+                .require_equals(true)
+                .value_parser(clap::value_parser!(usize))
+                .help(format!(
+                    "Capacity of the normal transaction arbiter queue (default: {}).",
+                    defaults.hfa_normal_arbiter_queue_capacity
+                )),
+        )
+        .arg(
+            Arg::new("hfa-fast-arbiter-queue-capacity")
+                .long("hfa-fast-arbiter-queue-capacity")
+                .require_equals(true)
+                .value_parser(clap::value_parser!(usize))
+                .help(format!(
+                    "Capacity of the fast intent arbiter queue; submissions beyond it are rejected as overloaded (default: {}).",
+                    defaults.hfa_fast_arbiter_queue_capacity
+                )),
         )
         .arg(
             Arg::new("no-hfa")
@@ -725,6 +951,7 @@ impl Args {
             startup_repair_plan: m.get_one::<String>("startup-repair-plan").cloned().or(defaults.startup_repair_plan),
             enable_mainnet_mining: arg_match_unwrap_or::<bool>(&m, "enable-mainnet-mining", defaults.enable_mainnet_mining),
             utxoindex: utxoindex_enabled,
+            messageindex: arg_match_unwrap_or::<bool>(&m, "messageindex", defaults.messageindex),
             atomic_unsafe_skip_snapshot_finality_check: arg_match_unwrap_or::<bool>(
                 &m,
                 "atomic-unsafe-skip-snapshot-finality-check",
@@ -782,6 +1009,49 @@ impl Args {
                 "hfa-microblock-interval-ms-normal",
                 defaults.hfa_microblock_interval_ms_normal,
             ),
+            hfa_fast_ttl_ms: arg_match_unwrap_or::<u64>(&m, "hfa-fast-ttl-ms", defaults.hfa_fast_ttl_ms),
+            hfa_terminal_retention_ms: arg_match_unwrap_or::<u64>(&m, "hfa-terminal-retention-ms", defaults.hfa_terminal_retention_ms),
+            hfa_max_pending_intents: arg_match_unwrap_or::<usize>(&m, "hfa-max-pending-intents", defaults.hfa_max_pending_intents),
+            hfa_max_locks: arg_match_unwrap_or::<usize>(&m, "hfa-max-locks", defaults.hfa_max_locks),
+            hfa_max_terminal_entries: arg_match_unwrap_or::<usize>(&m, "hfa-max-terminal-entries", defaults.hfa_max_terminal_entries),
+            hfa_max_terminal_bytes: arg_match_unwrap_or::<usize>(&m, "hfa-max-terminal-bytes", defaults.hfa_max_terminal_bytes),
+            hfa_max_inputs_per_intent: arg_match_unwrap_or::<usize>(
+                &m,
+                "hfa-max-inputs-per-intent",
+                defaults.hfa_max_inputs_per_intent,
+            ),
+            hfa_max_outputs_per_intent: arg_match_unwrap_or::<usize>(
+                &m,
+                "hfa-max-outputs-per-intent",
+                defaults.hfa_max_outputs_per_intent,
+            ),
+            hfa_min_feerate_floor: arg_match_unwrap_or::<f64>(&m, "hfa-min-feerate-floor", defaults.hfa_min_feerate_floor),
+            hfa_seen_cache_ttl_ms: arg_match_unwrap_or::<u64>(&m, "hfa-seen-cache-ttl-ms", defaults.hfa_seen_cache_ttl_ms),
+            hfa_microblock_interval_ms_degraded: arg_match_unwrap_or::<u64>(
+                &m,
+                "hfa-microblock-interval-ms-degraded",
+                defaults.hfa_microblock_interval_ms_degraded,
+            ),
+            hfa_revalidation_budget_normal: arg_match_unwrap_or::<usize>(
+                &m,
+                "hfa-revalidation-budget-normal",
+                defaults.hfa_revalidation_budget_normal,
+            ),
+            hfa_revalidation_budget_degraded: arg_match_unwrap_or::<usize>(
+                &m,
+                "hfa-revalidation-budget-degraded",
+                defaults.hfa_revalidation_budget_degraded,
+            ),
+            hfa_normal_arbiter_queue_capacity: arg_match_unwrap_or::<usize>(
+                &m,
+                "hfa-normal-arbiter-queue-capacity",
+                defaults.hfa_normal_arbiter_queue_capacity,
+            ),
+            hfa_fast_arbiter_queue_capacity: arg_match_unwrap_or::<usize>(
+                &m,
+                "hfa-fast-arbiter-queue-capacity",
+                defaults.hfa_fast_arbiter_queue_capacity,
+            ),
             autoban: autoban_enabled,
             banserver: banserver_enabled,
             coinbase_maturity_override: m
@@ -792,6 +1062,14 @@ impl Args {
                 .get_one::<u64>("payload-hf-activation-daa-score")
                 .copied()
                 .or(defaults.payload_hf_activation_daa_score),
+            covenants_activation_daa_score: m
+                .get_one::<u64>("covenants-activation-daa-score")
+                .copied()
+                .or(defaults.covenants_activation_daa_score),
+            atomic_extended_ops_activation_daa_score: m
+                .get_one::<u64>("atomic-extended-ops-activation-daa-score")
+                .copied()
+                .or(defaults.atomic_extended_ops_activation_daa_score),
             disable_upnp: arg_match_unwrap_or::<bool>(&m, "disable-upnp", defaults.disable_upnp),
             disable_dns_seeding: arg_match_unwrap_or::<bool>(&m, "nodnsseed", defaults.disable_dns_seeding),
             disable_grpc: arg_match_unwrap_or::<bool>(&m, "nogrpc", defaults.disable_grpc),
@@ -889,6 +1167,15 @@ mod tests {
         assert!(!args.utxoindex);
     }
 
+    #[test]
+    fn messageindex_is_opt_in() {
+        let args = Args::parse(["cryptixd"]).expect("default args should parse");
+        assert!(!args.messageindex);
+
+        let args = Args::parse(["cryptixd", "--messageindex"]).expect("flag args should parse");
+        assert!(args.messageindex);
+    }
+
     #[test]
     fn atomic_bootstrap_peer_quorum_alias_parses() {
         let args = Args::parse(["cryptixd", "--atomic-bootstrap-peer-quorum=2"]).expect("quorum alias args should parse");
@@ -938,6 +1225,23 @@ mod tests {
         assert_eq!(args.rpc_block_scan_cache_max_mb, 1024);
     }
 
+    #[test]
+    fn hfa_limits_parse() {
+        let args = Args::parse([
+            "cryptixd",
+            "--hfa",
+            "--hfa-fast-ttl-ms=5000",
+            "--hfa-max-pending-intents=1000",
+            "--hfa-min-feerate-floor=3.5",
+        ])
+        .expect("hfa args should parse");
+        assert!(args.hfa);
+        assert_eq!(args.hfa_fast_ttl_ms, 5_000);
+        assert_eq!(args.hfa_max_pending_intents, 1_000);
+        assert_eq!(args.hfa_min_feerate_floor, 3.5);
+        assert_eq!(args.hfa_max_locks, 200_000);
+    }
+
     #[test]
     fn rpc_block_scan_cache_values_parse() {
         let args = Args::parse([
@@ -23,6 +23,7 @@ use cryptix_database::prelude::CachePolicy;
 use cryptix_grpc_server::service::GrpcService;
 use cryptix_notify::{address::tracker::Tracker, subscription::context::SubscriptionContext};
 use cryptix_rpc_service::hfa::HfaRuntimeConfig;
+use cryptix_rpc_service::hfa_store::DbHfaIntentStore;
 use cryptix_rpc_service::service::RpcCoreService;
 use cryptix_txscript::caches::TxScriptCacheCounters;
 use cryptix_utils::git;
@@ -38,6 +39,7 @@ use cryptix_consensus::{
 use cryptix_consensusmanager::ConsensusManager;
 use cryptix_core::task::runtime::AsyncRuntime;
 use cryptix_index_processor::service::IndexService;
+use cryptix_messageindex::service::MessageIndexService;
 use cryptix_mining::{
     manager::{MiningManager, MiningManagerProxy},
     monitor::MiningMonitor,
@@ -63,6 +65,7 @@ use crate::args::Args;
 const DEFAULT_DATA_DIR: &str = "datadir";
 const CONSENSUS_DB: &str = "consensus";
 const UTXOINDEX_DB: &str = "utxoindex";
+const MESSAGEINDEX_DB: &str = "messageindex";
 const ATOMIC_DB: &str = "atomic";
 const META_DB: &str = "meta";
 const META_DB_FILE_LIMIT: i32 = 5;
@@ -119,6 +122,7 @@ pub fn validate_args(args: &Args) -> ConfigResult<()> {
     if args.hfa_microblock_interval_ms_normal == 0 {
         return Err(ConfigError::HfaMicroblockIntervalMsNormalOutOfRange(args.hfa_microblock_interval_ms_normal));
     }
+    hfa_runtime_config(args).validate().map_err(ConfigError::HfaConfigInvalid)?;
     if args.tx_relay_broadcast_interval_ms == 0 {
         return Err(ConfigError::TxRelayBroadcastIntervalMsOutOfRange(args.tx_relay_broadcast_interval_ms));
     }
@@ -131,6 +135,31 @@ pub fn validate_args(args: &Args) -> ConfigResult<()> {
     Ok(())
 }
 
+/// Builds the HFA runtime configuration from the command line arguments.
+fn hfa_runtime_config(args: &Args) -> HfaRuntimeConfig {
+    HfaRuntimeConfig {
+        enabled: args.hfa,
+        cpu_low_water_ratio: args.hfa_cpu,
+        fast_ttl_ms: args.hfa_fast_ttl_ms,
+        clock_drift_max_ms: args.hfa_drift_ms,
+        terminal_retention_ms: args.hfa_terminal_retention_ms,
+        max_pending_intents: args.hfa_max_pending_intents,
+        max_locks: args.hfa_max_locks,
+        max_terminal_entries: args.hfa_max_terminal_entries,
+        max_terminal_bytes: args.hfa_max_terminal_bytes,
+        max_inputs_per_intent: args.hfa_max_inputs_per_intent,
+        max_outputs_per_intent: args.hfa_max_outputs_per_intent,
+        min_feerate_floor: args.hfa_min_feerate_floor,
+        seen_cache_ttl_ms: args.hfa_seen_cache_ttl_ms,
+        microblock_interval_ms_normal: args.hfa_microblock_interval_ms_normal,
+        microblock_interval_ms_degraded: args.hfa_microblock_interval_ms_degraded,
+        revalidation_budget_normal: args.hfa_revalidation_budget_normal,
+        revalidation_budget_degraded: args.hfa_revalidation_budget_degraded,
+        normal_arbiter_queue_capacity: args.hfa_normal_arbiter_queue_capacity,
+        fast_arbiter_queue_capacity: args.hfa_fast_arbiter_queue_capacity,
+    }
+}
+
 fn get_user_approval_or_exit(message: &str, approve: bool) {
     if approve {
         return;
@@ -387,6 +416,7 @@ pub fn create_core_with_runtime(runtime: &Runtime, args: &Args, fd_total_budget:
 
     let consensus_db_dir = db_dir.join(CONSENSUS_DB);
     let utxoindex_db_dir = db_dir.join(UTXOINDEX_DB);
+    let messageindex_db_dir = db_dir.join(MESSAGEINDEX_DB);
     let atomic_db_dir = db_dir.join(ATOMIC_DB);
     let meta_db_dir = db_dir.join(META_DB);
 
@@ -407,6 +437,10 @@ do you confirm? (answer y/n or pass --yes to the Cryptixd command line to confir
         info!("Utxoindex Data directory {}", utxoindex_db_dir.display());
         fs::create_dir_all(utxoindex_db_dir.as_path()).unwrap();
     }
+    if args.messageindex {
+        info!("Messageindex Data directory {}", messageindex_db_dir.display());
+        fs::create_dir_all(messageindex_db_dir.as_path()).unwrap();
+    }
     info!("Cryptix Atomic Data directory {}", atomic_db_dir.display());
     fs::create_dir_all(atomic_db_dir.as_path()).unwrap();
 
@@ -480,6 +514,9 @@ do you confirm? (answer y/n or pass --yes to the Cryptixd command line to confir
         if args.utxoindex {
             fs::create_dir_all(utxoindex_db_dir.as_path()).unwrap();
         }
+        if args.messageindex {
+            fs::create_dir_all(messageindex_db_dir.as_path()).unwrap();
+        }
         fs::create_dir_all(atomic_db_dir.as_path()).unwrap();
 
         // Reopen the DB
@@ -584,7 +621,19 @@ do you confirm? (answer y/n or pass --yes to the Cryptixd command line to confir
     } else {
         None
     };
+    let message_index_service: Option<Arc<MessageIndexService>> = if args.messageindex {
+        let notifier = notify_service.notifier();
+        let message_index_service = MessageIndexService::new(&notifier, consensus_manager.clone(), messageindex_db_dir)
+            .unwrap_or_else(|err| {
+                eprintln!("{err}");
+                exit(1);
+            });
+        Some(Arc::new(message_index_service))
+    } else {
+        None
+    };
 
+    let hfa_intents_db = meta_db.clone();
     let (address_manager, port_mapping_extender_svc) =
         AddressManager::new(config.clone(), meta_db, tick_service.clone(), args.datacenter);
 
@@ -651,9 +700,7 @@ do you confirm? (answer y/n or pass --yes to the Cryptixd command line to confir
         p2p_tower_counters.clone(),
     ));
 
-    let mut hfa_runtime_config = HfaRuntimeConfig::new(args.hfa, args.hfa_cpu);
-    hfa_runtime_config.clock_drift_max_ms = args.hfa_drift_ms;
-    hfa_runtime_config.microblock_interval_ms_normal = args.hfa_microblock_interval_ms_normal;
+    let hfa_runtime_config = hfa_runtime_config(args);
 
     let rpc_core_service = Arc::new(RpcCoreService::new(
         consensus_manager.clone(),
@@ -664,6 +711,7 @@ do you confirm? (answer y/n or pass --yes to the Cryptixd command line to confir
         subscription_context,
         index_service.as_ref().map(|x| x.utxoindex().unwrap()),
         atomic_token_service.clone(),
+        message_index_service.clone(),
         config.clone(),
         core.clone(),
         processing_counters,
@@ -674,6 +722,7 @@ do you confirm? (answer y/n or pass --yes to the Cryptixd command line to confir
         grpc_tower_counters.clone(),
         system_info,
         hfa_runtime_config,
+        Some(DbHfaIntentStore::new(hfa_intents_db)),
     ));
     let grpc_service_broadcasters: usize = 3; // TODO: add a command line argument or derive from other arg/config/host-related fields
     let grpc_service = if !args.disable_grpc {
@@ -698,6 +747,9 @@ do you confirm? (answer y/n or pass --yes to the Cryptixd command line to confir
     if let Some(index_service) = index_service {
         async_runtime.register(index_service)
     };
+    if let Some(message_index_service) = message_index_service {
+        async_runtime.register(message_index_service)
+    };
     if let Some(port_mapping_extender_svc) = port_mapping_extender_svc {
         async_runtime.register(Arc::new(port_mapping_extender_svc))
     };
@@ -0,0 +1,284 @@
+//!
+//! Balance anchors against the consensus Atomic state hash.
+//!
+//! `GetConsensusAtomicStateHash` is an accumulator over six namespaces. Each
+//! namespace is committed as the number of its entries and the XOR of their
+//! entry hashes. The accumulator admits no inclusion proofs, so a balance
+//! anchor carries the full balance namespace together with the count and XOR
+//! of the other five. Hashing the balance entries back into their namespace
+//! digest recomputes the consensus state hash, which ties every balance in the
+//! anchor to the consensus commitment of the block.
+//!
+//! The binding is only as strong as the XOR accumulator committed by consensus,
+//! and the anchor grows with the number of non-zero balances in the state.
+//!
+
+use crate::{decode_hex_32, xor_hash, AtomicProofError, AtomicProofResult};
+use blake2b_simd::Params as Blake2bParams;
+use serde::{Deserialize, Serialize};
+
+pub const CONSENSUS_STATE_HASH_DOMAIN: &[u8] = b"cryptix-atomic-consensus-state-root-v2";
+pub const CONSENSUS_STATE_HASH_VERSION: u8 = 2;
+pub const CONSENSUS_NAMESPACE_NONCE: u8 = b'n';
+pub const CONSENSUS_NAMESPACE_ASSET: u8 = b'a';
+pub const CONSENSUS_NAMESPACE_BALANCE: u8 = b'b';
+pub const CONSENSUS_NAMESPACE_ANCHOR: u8 = b'c';
+pub const CONSENSUS_NAMESPACE_ALLOWANCE: u8 = b'l';
+pub const CONSENSUS_NAMESPACE_LOCK: u8 = b'k';
+
+/// Entry hash of a balance in the consensus Atomic state accumulator.
+pub fn consensus_balance_entry_hash(asset_id: &[u8; 32], owner_id: &[u8; 32], amount: u128) -> [u8; 32] {
+    let mut hasher = Blake2bParams::new().hash_length(32).to_state();
+    hasher.update(CONSENSUS_STATE_HASH_DOMAIN);
+    hasher.update(&[CONSENSUS_NAMESPACE_BALANCE]);
+    hasher.update(asset_id);
+    hasher.update(owner_id);
+    hasher.update(&amount.to_le_bytes());
+    let digest = hasher.finalize();
+    let mut out = [0u8; 32];
+    out.copy_from_slice(digest.as_bytes());
+    out
+}
+
+/// Entry count and XOR of the entry hashes of one accumulator namespace.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AtomicConsensusNamespaceDigest {
+    pub count: u64,
+    pub xor: [u8; 32],
+}
+
+/// A non-zero balance of the consensus Atomic state.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AtomicConsensusBalance {
+    pub asset_id: [u8; 32],
+    pub owner_id: [u8; 32],
+    pub amount: u128,
+}
+
+/// Every balance of the consensus Atomic state at one block together with the
+/// digests of the remaining namespaces.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AtomicConsensusBalanceAnchor {
+    pub version: u8,
+    pub nonces: AtomicConsensusNamespaceDigest,
+    pub assets: AtomicConsensusNamespaceDigest,
+    pub anchor_counts: AtomicConsensusNamespaceDigest,
+    pub allowances: AtomicConsensusNamespaceDigest,
+    pub locks: AtomicConsensusNamespaceDigest,
+    /// Balances ordered by `(asset_id, owner_id)`.
+    pub balances: Vec<AtomicConsensusBalance>,
+}
+
+impl AtomicConsensusBalanceAnchor {
+    /// Recomputes the consensus Atomic state hash committed by the anchor.
+    pub fn state_hash(&self) -> AtomicProofResult<[u8; 32]> {
+        if self.version != CONSENSUS_STATE_HASH_VERSION {
+            return Err(AtomicProofError::UnsupportedConsensusVersion(self.version));
+        }
+        let mut balances = AtomicConsensusNamespaceDigest::default();
+        let mut previous: Option<(&[u8; 32], &[u8; 32])> = None;
+        for balance in self.balances.iter() {
+            if balance.amount == 0 {
+                return Err(AtomicProofError::ZeroConsensusBalance);
+            }
+            let key = (&balance.asset_id, &balance.owner_id);
+            if previous.is_some_and(|previous| previous >= key) {
+                return Err(AtomicProofError::UnorderedConsensusBalances);
+            }
+            previous = Some(key);
+            balances.count += 1;
+            xor_hash(&mut balances.xor, consensus_balance_entry_hash(&balance.asset_id, &balance.owner_id, balance.amount));
+        }
+
+        let mut hasher = Blake2bParams::new().hash_length(32).to_state();
+        hasher.update(CONSENSUS_STATE_HASH_DOMAIN);
+        hasher.update(&[self.version]);
+        for (namespace, digest) in [
+            (CONSENSUS_NAMESPACE_NONCE, &self.nonces),
+            (CONSENSUS_NAMESPACE_ASSET, &self.assets),
+            (CONSENSUS_NAMESPACE_BALANCE, &balances),
+            (CONSENSUS_NAMESPACE_ANCHOR, &self.anchor_counts),
+        ] {
+            hash_namespace(&mut hasher, namespace, digest);
+        }
+        // Allowances and locks are only committed once the namespace is non-empty.
+        for (namespace, digest) in [(CONSENSUS_NAMESPACE_ALLOWANCE, &self.allowances), (CONSENSUS_NAMESPACE_LOCK, &self.locks)] {
+            if digest.count != 0 {
+                hash_namespace(&mut hasher, namespace, digest);
+            }
+        }
+        let digest = hasher.finalize();
+        let mut out = [0u8; 32];
+        out.copy_from_slice(digest.as_bytes());
+        Ok(out)
+    }
+
+    /// Checks that the anchor recomputes to `expected_state_hash`, the value returned by `GetConsensusAtomicStateHash`.
+    pub fn verify_against(&self, expected_state_hash: &[u8; 32]) -> AtomicProofResult<()> {
+        if self.state_hash()? != *expected_state_hash {
+            return Err(AtomicProofError::ConsensusStateHashMismatch);
+        }
+        Ok(())
+    }
+
+    /// Balance of `owner_id` in `asset_id`. Balances missing from the anchor are zero.
+    pub fn balance(&self, asset_id: &[u8; 32], owner_id: &[u8; 32]) -> u128 {
+        self.balances
+            .binary_search_by(|balance| (&balance.asset_id, &balance.owner_id).cmp(&(asset_id, owner_id)))
+            .map(|index| self.balances[index].amount)
+            .unwrap_or(0)
+    }
+}
+
+fn hash_namespace(hasher: &mut blake2b_simd::State, namespace: u8, digest: &AtomicConsensusNamespaceDigest) {
+    hasher.update(&[namespace]);
+    hasher.update(&digest.count.to_le_bytes());
+    hasher.update(&digest.xor);
+}
+
+/// Hex representation of a balance anchor, matching the `RpcTokenConsensusBalanceAnchor` JSON layout.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AtomicConsensusBalanceAnchorHex {
+    pub version: u32,
+    pub nonces: AtomicConsensusNamespaceDigestHex,
+    pub assets: AtomicConsensusNamespaceDigestHex,
+    pub anchor_counts: AtomicConsensusNamespaceDigestHex,
+    pub allowances: AtomicConsensusNamespaceDigestHex,
+    pub locks: AtomicConsensusNamespaceDigestHex,
+    pub balances: Vec<AtomicConsensusBalanceHex>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AtomicConsensusNamespaceDigestHex {
+    pub count: u64,
+    pub xor: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AtomicConsensusBalanceHex {
+    pub asset_id: String,
+    pub owner_id: String,
+    pub amount: String,
+}
+
+impl From<&AtomicConsensusNamespaceDigest> for AtomicConsensusNamespaceDigestHex {
+    fn from(digest: &AtomicConsensusNamespaceDigest) -> Self {
+        Self { count: digest.count, xor: faster_hex::hex_string(&digest.xor) }
+    }
+}
+
+impl From<&AtomicConsensusBalanceAnchor> for AtomicConsensusBalanceAnchorHex {
+    fn from(anchor: &AtomicConsensusBalanceAnchor) -> Self {
+        Self {
+            version: anchor.version as u32,
+            nonces: (&anchor.nonces).into(),
+            assets: (&anchor.assets).into(),
+            anchor_counts: (&anchor.anchor_counts).into(),
+            allowances: (&anchor.allowances).into(),
+            locks: (&anchor.locks).into(),
+            balances: anchor
+                .balances
+                .iter()
+                .map(|balance| AtomicConsensusBalanceHex {
+                    asset_id: faster_hex::hex_string(&balance.asset_id),
+                    owner_id: faster_hex::hex_string(&balance.owner_id),
+                    amount: balance.amount.to_string(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<&AtomicConsensusNamespaceDigestHex> for AtomicConsensusNamespaceDigest {
+    type Error = AtomicProofError;
+
+    fn try_from(digest: &AtomicConsensusNamespaceDigestHex) -> AtomicProofResult<Self> {
+        Ok(Self { count: digest.count, xor: decode_hex_32(&digest.xor, "xor")? })
+    }
+}
+
+impl TryFrom<&AtomicConsensusBalanceAnchorHex> for AtomicConsensusBalanceAnchor {
+    type Error = AtomicProofError;
+
+    fn try_from(anchor: &AtomicConsensusBalanceAnchorHex) -> AtomicProofResult<Self> {
+        Ok(Self {
+            version: u8::try_from(anchor.version).map_err(|_| AtomicProofError::InvalidValue("version"))?,
+            nonces: (&anchor.nonces).try_into()?,
+            assets: (&anchor.assets).try_into()?,
+            anchor_counts: (&anchor.anchor_counts).try_into()?,
+            allowances: (&anchor.allowances).try_into()?,
+            locks: (&anchor.locks).try_into()?,
+            balances: anchor
+                .balances
+                .iter()
+                .map(|balance| {
+                    Ok(AtomicConsensusBalance {
+                        asset_id: decode_hex_32(&balance.asset_id, "balances.assetId")?,
+                        owner_id: decode_hex_32(&balance.owner_id, "balances.ownerId")?,
+                        amount: balance.amount.parse().map_err(|_| AtomicProofError::InvalidValue("balances.amount"))?,
+                    })
+                })
+                .collect::<AtomicProofResult<Vec<_>>>()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_anchor() -> AtomicConsensusBalanceAnchor {
+        AtomicConsensusBalanceAnchor {
+            version: CONSENSUS_STATE_HASH_VERSION,
+            nonces: AtomicConsensusNamespaceDigest { count: 3, xor: [1; 32] },
+            assets: AtomicConsensusNamespaceDigest { count: 2, xor: [2; 32] },
+            anchor_counts: AtomicConsensusNamespaceDigest::default(),
+            allowances: AtomicConsensusNamespaceDigest::default(),
+            locks: AtomicConsensusNamespaceDigest { count: 1, xor: [3; 32] },
+            balances: (0u8..8)
+                .map(|i| AtomicConsensusBalance { asset_id: [i / 4; 32], owner_id: [i; 32], amount: u128::from(i) * 10 + 1 })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn balance_anchor_roundtrips_through_hex() {
+        let anchor = sample_anchor();
+        let state_hash = anchor.state_hash().expect("valid anchor");
+        assert_eq!(anchor.balance(&[1; 32], &[5; 32]), 51);
+        assert_eq!(anchor.balance(&[1; 32], &[0; 32]), 0);
+
+        let decoded = AtomicConsensusBalanceAnchor::try_from(&AtomicConsensusBalanceAnchorHex::from(&anchor)).expect("hex roundtrip");
+        assert_eq!(decoded, anchor);
+        decoded.verify_against(&state_hash).expect("valid anchor against state hash");
+    }
+
+    #[test]
+    fn tampered_balance_anchors_are_rejected() {
+        let anchor = sample_anchor();
+        let state_hash = anchor.state_hash().unwrap();
+
+        let mut forged_amount = anchor.clone();
+        forged_amount.balances[2].amount += 1;
+        assert_eq!(forged_amount.verify_against(&state_hash), Err(AtomicProofError::ConsensusStateHashMismatch));
+
+        let mut hidden_balance = anchor.clone();
+        hidden_balance.balances.remove(4);
+        assert_eq!(hidden_balance.verify_against(&state_hash), Err(AtomicProofError::ConsensusStateHashMismatch));
+
+        let mut duplicated = anchor.clone();
+        duplicated.balances.insert(1, anchor.balances[1]);
+        assert_eq!(duplicated.state_hash(), Err(AtomicProofError::UnorderedConsensusBalances));
+
+        let mut zero = anchor.clone();
+        zero.balances[0].amount = 0;
+        assert_eq!(zero.state_hash(), Err(AtomicProofError::ZeroConsensusBalance));
+
+        let mut empty_allowances = anchor.clone();
+        empty_allowances.allowances.xor = [9; 32];
+        assert_eq!(empty_allowances.state_hash(), Ok(state_hash));
+    }
+}
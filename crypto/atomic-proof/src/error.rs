@@ -0,0 +1,51 @@
+use thiserror::Error;
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum AtomicProofError {
+    #[error("proof must carry exactly {expected} bucket hashes, got {actual}")]
+    BucketCount { expected: usize, actual: usize },
+
+    #[error("proof bucket index `{0}` is out of range")]
+    BucketIndexOutOfRange(usize),
+
+    #[error("logical key maps to bucket `{actual}`, proof claims bucket `{claimed}`")]
+    BucketIndexMismatch { claimed: usize, actual: usize },
+
+    #[error("bucket sibling maps to bucket `{actual}` instead of `{expected}`")]
+    SiblingOutsideBucket { expected: usize, actual: usize },
+
+    #[error("bucket sibling repeats a logical key already present in the proof")]
+    DuplicateLeaf,
+
+    #[error("recomputed bucket hash does not match the proof bucket hash")]
+    BucketHashMismatch,
+
+    #[error("recomputed state root does not match the proof state root")]
+    StateRootMismatch,
+
+    #[error("proof state root does not match the expected state root")]
+    UnexpectedStateRoot,
+
+    #[error("logical key has an unexpected layout for {0}")]
+    InvalidLogicalKey(&'static str),
+
+    #[error("leaf value has an unexpected layout for {0}")]
+    InvalidValue(&'static str),
+
+    #[error("unsupported consensus Atomic state hash version `{0}`")]
+    UnsupportedConsensusVersion(u8),
+
+    #[error("consensus balance anchor carries a zero balance")]
+    ZeroConsensusBalance,
+
+    #[error("consensus balance anchor balances are not strictly ordered by asset and owner")]
+    UnorderedConsensusBalances,
+
+    #[error("recomputed consensus Atomic state hash does not match the expected state hash")]
+    ConsensusStateHashMismatch,
+
+    #[error("invalid hex in field `{0}`")]
+    InvalidHex(&'static str),
+}
+
+pub type AtomicProofResult<T> = Result<T, AtomicProofError>;
@@ -0,0 +1,611 @@
+//!
+//! Inclusion proofs for the bucketed Cryptix Atomic state root.
+//!
+//! The Atomic state root hashes 4096 buckets in order. Each bucket is the XOR
+//! of the leaf hashes of every logical key that maps into it. A proof carries
+//! the proven leaf, the full preimages of the other leaves in its bucket and the
+//! hashes of all buckets, which is enough to recompute the root without access
+//! to the rest of the state.
+//!
+//! Absence proofs are expressed with `value: None`: the proven key must then be
+//! missing from the bucket preimages. A zero balance, an unused nonce scope (next
+//! nonce `1`) and an unknown asset are all absent from the root.
+//!
+//! The proof only binds the leaf to a root. That root is the Atomic indexer's
+//! `stateHash`, which is not a consensus commitment: it is not the value returned
+//! by `GetConsensusAtomicStateHash` (an unordered accumulator that admits no
+//! inclusion proofs) and no block header commits to it. Balances are tied to
+//! consensus through an [`AtomicConsensusBalanceAnchor`] instead, which
+//! recomputes `GetConsensusAtomicStateHash` for the same block (see [`consensus`]).
+//!
+
+pub mod consensus;
+pub mod error;
+#[cfg(feature = "wasm32-sdk")]
+pub mod wasm;
+
+pub use consensus::{
+    AtomicConsensusBalance, AtomicConsensusBalanceAnchor, AtomicConsensusBalanceAnchorHex, AtomicConsensusNamespaceDigest,
+};
+pub use error::{AtomicProofError, AtomicProofResult};
+
+use blake2b_simd::Params as Blake2bParams;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+pub const ATOMIC_ROOT_BUCKETS: usize = 4096;
+pub const ROOT_LEAF_DOMAIN: &[u8] = b"CRYPTIX_ATOMIC_V2_LEAF";
+pub const ROOT_BUCKET_DOMAIN: &[u8] = b"CRYPTIX_ATOMIC_V2_BUCKETED_ROOT";
+pub const ROOT_BUCKET_INDEX_DOMAIN: &[u8] = b"CRYPTIX_ATOMIC_V2_BUCKET_INDEX";
+pub const ASSET_ROOT_V5: &[u8] = b"CAT_ASSET_ROOT_V5";
+const ASSET_CLASS_PAIR: u8 = 2;
+// asset_a_id, asset_b_id, fee_bps, accrued_fee_a, accrued_fee_b
+const PAIR_POOL_LEAF_LEN: usize = 32 + 32 + 2 + 16 + 16;
+
+pub const LOGICAL_ASSET: u8 = 0x01;
+pub const LOGICAL_BALANCE: u8 = 0x02;
+pub const LOGICAL_NONCE: u8 = 0x03;
+pub const LOGICAL_ANCHOR_COUNT: u8 = 0x04;
+pub const LOGICAL_ALLOWANCE: u8 = 0x05;
+pub const LOGICAL_LOCK: u8 = 0x06;
+
+pub fn logical_asset_key(asset_id: &[u8; 32]) -> Vec<u8> {
+    let mut key = Vec::with_capacity(33);
+    key.push(LOGICAL_ASSET);
+    key.extend_from_slice(asset_id);
+    key
+}
+
+pub fn logical_balance_key(asset_id: &[u8; 32], owner_id: &[u8; 32]) -> Vec<u8> {
+    let mut key = Vec::with_capacity(65);
+    key.push(LOGICAL_BALANCE);
+    key.extend_from_slice(asset_id);
+    key.extend_from_slice(owner_id);
+    key
+}
+
+pub fn logical_nonce_key(owner_id: &[u8; 32], scope_kind: u8, scope_id: &[u8; 32]) -> Vec<u8> {
+    let mut key = Vec::with_capacity(66);
+    key.push(LOGICAL_NONCE);
+    key.extend_from_slice(owner_id);
+    key.push(scope_kind);
+    key.extend_from_slice(scope_id);
+    key
+}
+
+pub fn logical_anchor_count_key(owner_id: &[u8; 32]) -> Vec<u8> {
+    let mut key = Vec::with_capacity(33);
+    key.push(LOGICAL_ANCHOR_COUNT);
+    key.extend_from_slice(owner_id);
+    key
+}
+
+pub fn logical_allowance_key(asset_id: &[u8; 32], owner_id: &[u8; 32], spender_id: &[u8; 32]) -> Vec<u8> {
+    let mut key = Vec::with_capacity(97);
+    key.push(LOGICAL_ALLOWANCE);
+    key.extend_from_slice(asset_id);
+    key.extend_from_slice(owner_id);
+    key.extend_from_slice(spender_id);
+    key
+}
+
+pub fn logical_lock_key(asset_id: &[u8; 32], owner_id: &[u8; 32]) -> Vec<u8> {
+    let mut key = Vec::with_capacity(65);
+    key.push(LOGICAL_LOCK);
+    key.extend_from_slice(asset_id);
+    key.extend_from_slice(owner_id);
+    key
+}
+
+pub fn leaf_hash(logical_key: &[u8], value: &[u8]) -> [u8; 32] {
+    let mut hasher = Blake2bParams::new().hash_length(32).to_state();
+    hasher.update(ROOT_LEAF_DOMAIN);
+    hasher.update(&(logical_key.len() as u64).to_le_bytes());
+    hasher.update(logical_key);
+    hasher.update(&(value.len() as u64).to_le_bytes());
+    hasher.update(value);
+    let digest = hasher.finalize();
+    let mut out = [0u8; 32];
+    out.copy_from_slice(digest.as_bytes());
+    out
+}
+
+pub fn bucket_index(logical_key: &[u8]) -> usize {
+    let mut hasher = Blake2bParams::new().hash_length(32).to_state();
+    hasher.update(ROOT_BUCKET_INDEX_DOMAIN);
+    hasher.update(logical_key);
+    let digest = hasher.finalize();
+    let bytes = digest.as_bytes();
+    (((bytes[0] as usize) << 4) | ((bytes[1] as usize) >> 4)) & (ATOMIC_ROOT_BUCKETS - 1)
+}
+
+pub fn root_from_buckets(buckets: &[[u8; 32]; ATOMIC_ROOT_BUCKETS]) -> [u8; 32] {
+    let mut hasher = Blake2bParams::new().hash_length(32).to_state();
+    hasher.update(ROOT_BUCKET_DOMAIN);
+    hasher.update(&(ATOMIC_ROOT_BUCKETS as u64).to_le_bytes());
+    for bucket in buckets {
+        hasher.update(bucket);
+    }
+    let digest = hasher.finalize();
+    let mut out = [0u8; 32];
+    out.copy_from_slice(digest.as_bytes());
+    out
+}
+
+pub fn xor_hash(target: &mut [u8; 32], value: [u8; 32]) {
+    for (target, value) in target.iter_mut().zip(value) {
+        *target ^= value;
+    }
+}
+
+/// Full preimage of a leaf committed in the Atomic state root.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AtomicStateLeaf {
+    pub logical_key: Vec<u8>,
+    pub value: Vec<u8>,
+}
+
+impl AtomicStateLeaf {
+    pub fn hash(&self) -> [u8; 32] {
+        leaf_hash(&self.logical_key, &self.value)
+    }
+}
+
+/// Inclusion (or absence) proof of a single logical key against a bucketed Atomic state root.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AtomicStateProof {
+    pub logical_key: Vec<u8>,
+    pub value: Option<Vec<u8>>,
+    pub bucket_index: usize,
+    pub bucket_siblings: Vec<AtomicStateLeaf>,
+    pub buckets: Vec<[u8; 32]>,
+    pub state_root: [u8; 32],
+}
+
+impl AtomicStateProof {
+    /// Checks that the proof is internally consistent and recomputes to `state_root`.
+    pub fn verify(&self) -> AtomicProofResult<()> {
+        if self.buckets.len() != ATOMIC_ROOT_BUCKETS {
+            return Err(AtomicProofError::BucketCount { expected: ATOMIC_ROOT_BUCKETS, actual: self.buckets.len() });
+        }
+        if self.bucket_index >= ATOMIC_ROOT_BUCKETS {
+            return Err(AtomicProofError::BucketIndexOutOfRange(self.bucket_index));
+        }
+        let actual = bucket_index(&self.logical_key);
+        if actual != self.bucket_index {
+            return Err(AtomicProofError::BucketIndexMismatch { claimed: self.bucket_index, actual });
+        }
+
+        let mut seen = HashSet::with_capacity(self.bucket_siblings.len() + 1);
+        seen.insert(self.logical_key.as_slice());
+        let mut bucket = [0u8; 32];
+        if let Some(value) = self.value.as_ref() {
+            xor_hash(&mut bucket, leaf_hash(&self.logical_key, value));
+        }
+        for sibling in self.bucket_siblings.iter() {
+            if !seen.insert(sibling.logical_key.as_slice()) {
+                return Err(AtomicProofError::DuplicateLeaf);
+            }
+            let actual = bucket_index(&sibling.logical_key);
+            if actual != self.bucket_index {
+                return Err(AtomicProofError::SiblingOutsideBucket { expected: self.bucket_index, actual });
+            }
+            xor_hash(&mut bucket, sibling.hash());
+        }
+        if bucket != self.buckets[self.bucket_index] {
+            return Err(AtomicProofError::BucketHashMismatch);
+        }
+
+        let mut buckets = [[0u8; 32]; ATOMIC_ROOT_BUCKETS];
+        buckets.copy_from_slice(&self.buckets);
+        if root_from_buckets(&buckets) != self.state_root {
+            return Err(AtomicProofError::StateRootMismatch);
+        }
+        Ok(())
+    }
+
+    /// Verifies the proof and checks it commits to `expected_root`.
+    pub fn verify_against(&self, expected_root: &[u8; 32]) -> AtomicProofResult<()> {
+        if self.state_root != *expected_root {
+            return Err(AtomicProofError::UnexpectedStateRoot);
+        }
+        self.verify()
+    }
+
+    /// Proven balance for a balance-key proof. Absent leaves are a zero balance.
+    pub fn balance(&self) -> AtomicProofResult<u128> {
+        if self.logical_key.len() != 65 || self.logical_key[0] != LOGICAL_BALANCE {
+            return Err(AtomicProofError::InvalidLogicalKey("balance"));
+        }
+        match self.value.as_deref() {
+            Some(value) => Ok(u128::from_le_bytes(value.try_into().map_err(|_| AtomicProofError::InvalidValue("balance"))?)),
+            None => Ok(0),
+        }
+    }
+
+    /// Proven next nonce for a nonce-key proof. Absent leaves are the initial nonce `1`.
+    pub fn next_nonce(&self) -> AtomicProofResult<u64> {
+        if self.logical_key.len() != 66 || self.logical_key[0] != LOGICAL_NONCE {
+            return Err(AtomicProofError::InvalidLogicalKey("nonce"));
+        }
+        match self.value.as_deref() {
+            Some(value) => Ok(u64::from_le_bytes(value.try_into().map_err(|_| AtomicProofError::InvalidValue("nonce"))?)),
+            None => Ok(1),
+        }
+    }
+
+    /// Proven allowance `(amount, expires_at_daa_score)` for an allowance-key proof. Absent leaves are no allowance.
+    pub fn allowance(&self) -> AtomicProofResult<Option<(u128, u64)>> {
+        if self.logical_key.len() != 97 || self.logical_key[0] != LOGICAL_ALLOWANCE {
+            return Err(AtomicProofError::InvalidLogicalKey("allowance"));
+        }
+        let Some(value) = self.value.as_deref() else {
+            return Ok(None);
+        };
+        if value.len() != 24 {
+            return Err(AtomicProofError::InvalidValue("allowance"));
+        }
+        let amount = u128::from_le_bytes(value[..16].try_into().expect("slice length is checked"));
+        let expires_at_daa_score = u64::from_le_bytes(value[16..].try_into().expect("slice length is checked"));
+        Ok(Some((amount, expires_at_daa_score)))
+    }
+
+    /// Proven lock `(amount, release_start_daa_score, release_end_daa_score)` for a lock-key proof. Absent leaves are no lock.
+    pub fn lock(&self) -> AtomicProofResult<Option<(u128, u64, u64)>> {
+        if self.logical_key.len() != 65 || self.logical_key[0] != LOGICAL_LOCK {
+            return Err(AtomicProofError::InvalidLogicalKey("lock"));
+        }
+        let Some(value) = self.value.as_deref() else {
+            return Ok(None);
+        };
+        if value.len() != 32 {
+            return Err(AtomicProofError::InvalidValue("lock"));
+        }
+        let amount = u128::from_le_bytes(value[..16].try_into().expect("slice length is checked"));
+        let release_start_daa_score = u64::from_le_bytes(value[16..24].try_into().expect("slice length is checked"));
+        let release_end_daa_score = u64::from_le_bytes(value[24..].try_into().expect("slice length is checked"));
+        Ok(Some((amount, release_start_daa_score, release_end_daa_score)))
+    }
+
+    /// Decoded asset leaf for an asset-key proof, or `None` when the asset does not exist.
+    pub fn asset(&self) -> AtomicProofResult<Option<AtomicAssetLeaf>> {
+        if self.logical_key.len() != 33 || self.logical_key[0] != LOGICAL_ASSET {
+            return Err(AtomicProofError::InvalidLogicalKey("asset"));
+        }
+        self.value.as_deref().map(AtomicAssetLeaf::decode).transpose()
+    }
+}
+
+/// Fields of an asset leaf value as committed in the Atomic state root.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AtomicAssetLeaf {
+    pub asset_id: [u8; 32],
+    pub creator_owner_id: [u8; 32],
+    pub asset_class: u8,
+    pub token_version: u8,
+    pub mint_authority_owner_id: [u8; 32],
+    pub decimals: u8,
+    pub supply_mode: u8,
+    pub max_supply: u128,
+    pub total_supply: u128,
+    pub name: Vec<u8>,
+    pub symbol: Vec<u8>,
+    pub metadata: Vec<u8>,
+    pub platform_tag: Vec<u8>,
+    pub created_block_hash: Option<[u8; 32]>,
+    pub created_daa_score: Option<u64>,
+    pub created_at: Option<u64>,
+    /// Encoded liquidity pool state, if the asset is a liquidity asset.
+    pub liquidity: Option<Vec<u8>>,
+    /// Encoded pair pool state, if the asset is a pair pool.
+    pub pair_pool: Option<Vec<u8>>,
+}
+
+impl AtomicAssetLeaf {
+    pub fn decode(value: &[u8]) -> AtomicProofResult<Self> {
+        let mut reader = LeafReader { bytes: value, cursor: 0 };
+        if reader.take(ASSET_ROOT_V5.len())? != ASSET_ROOT_V5 {
+            return Err(AtomicProofError::InvalidValue("asset"));
+        }
+        let asset_id = reader.take_32()?;
+        let creator_owner_id = reader.take_32()?;
+        let asset_class = reader.take_u8()?;
+        let token_version = reader.take_u8()?;
+        let mint_authority_owner_id = reader.take_32()?;
+        let decimals = reader.take_u8()?;
+        let supply_mode = reader.take_u8()?;
+        let max_supply = reader.take_u128()?;
+        let total_supply = reader.take_u128()?;
+        let name = reader.take_bytes()?;
+        let symbol = reader.take_bytes()?;
+        let metadata = reader.take_bytes()?;
+        let platform_tag = reader.take_bytes()?;
+        let created_block_hash = if reader.take_flag()? { Some(reader.take_32()?) } else { None };
+        let created_daa_score = if reader.take_flag()? { Some(reader.take_u64()?) } else { None };
+        let created_at = if reader.take_flag()? { Some(reader.take_u64()?) } else { None };
+        let liquidity = if reader.take_flag()? { Some(reader.rest().to_vec()) } else { None };
+        let pair_pool = if liquidity.is_none() && asset_class == ASSET_CLASS_PAIR && reader.take_flag()? {
+            Some(reader.take(PAIR_POOL_LEAF_LEN)?.to_vec())
+        } else {
+            None
+        };
+        if liquidity.is_none() && !reader.rest().is_empty() {
+            return Err(AtomicProofError::InvalidValue("asset"));
+        }
+        Ok(Self {
+            asset_id,
+            creator_owner_id,
+            asset_class,
+            token_version,
+            mint_authority_owner_id,
+            decimals,
+            supply_mode,
+            max_supply,
+            total_supply,
+            name,
+            symbol,
+            metadata,
+            platform_tag,
+            created_block_hash,
+            created_daa_score,
+            created_at,
+            liquidity,
+            pair_pool,
+        })
+    }
+}
+
+struct LeafReader<'a> {
+    bytes: &'a [u8],
+    cursor: usize,
+}
+
+impl<'a> LeafReader<'a> {
+    fn take(&mut self, len: usize) -> AtomicProofResult<&'a [u8]> {
+        let end =
+            self.cursor.checked_add(len).filter(|end| *end <= self.bytes.len()).ok_or(AtomicProofError::InvalidValue("asset"))?;
+        let out = &self.bytes[self.cursor..end];
+        self.cursor = end;
+        Ok(out)
+    }
+
+    fn take_u8(&mut self) -> AtomicProofResult<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn take_flag(&mut self) -> AtomicProofResult<bool> {
+        match self.take_u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(AtomicProofError::InvalidValue("asset")),
+        }
+    }
+
+    fn take_32(&mut self) -> AtomicProofResult<[u8; 32]> {
+        let mut out = [0u8; 32];
+        out.copy_from_slice(self.take(32)?);
+        Ok(out)
+    }
+
+    fn take_u64(&mut self) -> AtomicProofResult<u64> {
+        let mut out = [0u8; 8];
+        out.copy_from_slice(self.take(8)?);
+        Ok(u64::from_le_bytes(out))
+    }
+
+    fn take_u128(&mut self) -> AtomicProofResult<u128> {
+        let mut out = [0u8; 16];
+        out.copy_from_slice(self.take(16)?);
+        Ok(u128::from_le_bytes(out))
+    }
+
+    fn take_bytes(&mut self) -> AtomicProofResult<Vec<u8>> {
+        let len = usize::try_from(self.take_u64()?).map_err(|_| AtomicProofError::InvalidValue("asset"))?;
+        Ok(self.take(len)?.to_vec())
+    }
+
+    fn rest(&self) -> &'a [u8] {
+        &self.bytes[self.cursor..]
+    }
+}
+
+/// Hex representation of a proof, matching the `RpcTokenStateProof` JSON layout.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AtomicStateProofHex {
+    pub logical_key: String,
+    pub value: Option<String>,
+    pub bucket_index: u32,
+    pub bucket_siblings: Vec<AtomicStateLeafHex>,
+    pub bucket_hashes: Vec<String>,
+    pub state_root: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AtomicStateLeafHex {
+    pub logical_key: String,
+    pub value: String,
+}
+
+impl From<&AtomicStateProof> for AtomicStateProofHex {
+    fn from(proof: &AtomicStateProof) -> Self {
+        Self {
+            logical_key: faster_hex::hex_string(&proof.logical_key),
+            value: proof.value.as_ref().map(|value| faster_hex::hex_string(value)),
+            bucket_index: proof.bucket_index as u32,
+            bucket_siblings: proof
+                .bucket_siblings
+                .iter()
+                .map(|leaf| AtomicStateLeafHex {
+                    logical_key: faster_hex::hex_string(&leaf.logical_key),
+                    value: faster_hex::hex_string(&leaf.value),
+                })
+                .collect(),
+            bucket_hashes: proof.buckets.iter().map(|bucket| faster_hex::hex_string(bucket)).collect(),
+            state_root: faster_hex::hex_string(&proof.state_root),
+        }
+    }
+}
+
+impl TryFrom<&AtomicStateProofHex> for AtomicStateProof {
+    type Error = AtomicProofError;
+
+    fn try_from(proof: &AtomicStateProofHex) -> AtomicProofResult<Self> {
+        Ok(Self {
+            logical_key: decode_hex(&proof.logical_key, "logicalKey")?,
+            value: proof.value.as_deref().map(|value| decode_hex(value, "value")).transpose()?,
+            bucket_index: proof.bucket_index as usize,
+            bucket_siblings: proof
+                .bucket_siblings
+                .iter()
+                .map(|leaf| {
+                    Ok(AtomicStateLeaf {
+                        logical_key: decode_hex(&leaf.logical_key, "bucketSiblings.logicalKey")?,
+                        value: decode_hex(&leaf.value, "bucketSiblings.value")?,
+                    })
+                })
+                .collect::<AtomicProofResult<Vec<_>>>()?,
+            buckets: proof
+                .bucket_hashes
+                .iter()
+                .map(|bucket| decode_hex_32(bucket, "bucketHashes"))
+                .collect::<AtomicProofResult<Vec<_>>>()?,
+            state_root: decode_hex_32(&proof.state_root, "stateRoot")?,
+        })
+    }
+}
+
+pub fn decode_hex(value: &str, field: &'static str) -> AtomicProofResult<Vec<u8>> {
+    if value.len() % 2 != 0 {
+        return Err(AtomicProofError::InvalidHex(field));
+    }
+    let mut out = vec![0u8; value.len() / 2];
+    faster_hex::hex_decode(value.as_bytes(), &mut out).map_err(|_| AtomicProofError::InvalidHex(field))?;
+    Ok(out)
+}
+
+pub fn decode_hex_32(value: &str, field: &'static str) -> AtomicProofResult<[u8; 32]> {
+    decode_hex(value, field)?.try_into().map_err(|_| AtomicProofError::InvalidHex(field))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_buckets(leaves: &[AtomicStateLeaf]) -> Vec<[u8; 32]> {
+        let mut buckets = vec![[0u8; 32]; ATOMIC_ROOT_BUCKETS];
+        for leaf in leaves {
+            xor_hash(&mut buckets[bucket_index(&leaf.logical_key)], leaf.hash());
+        }
+        buckets
+    }
+
+    fn proof_for(leaves: &[AtomicStateLeaf], logical_key: Vec<u8>) -> AtomicStateProof {
+        let buckets = sample_buckets(leaves);
+        let index = bucket_index(&logical_key);
+        let mut root_buckets = [[0u8; 32]; ATOMIC_ROOT_BUCKETS];
+        root_buckets.copy_from_slice(&buckets);
+        AtomicStateProof {
+            value: leaves.iter().find(|leaf| leaf.logical_key == logical_key).map(|leaf| leaf.value.clone()),
+            bucket_index: index,
+            bucket_siblings: leaves
+                .iter()
+                .filter(|leaf| leaf.logical_key != logical_key && bucket_index(&leaf.logical_key) == index)
+                .cloned()
+                .collect(),
+            state_root: root_from_buckets(&root_buckets),
+            buckets,
+            logical_key,
+        }
+    }
+
+    fn sample_leaves() -> Vec<AtomicStateLeaf> {
+        (0u8..64)
+            .map(|i| AtomicStateLeaf {
+                logical_key: logical_balance_key(&[i % 4; 32], &[i; 32]),
+                value: (u128::from(i) * 1_000 + 1).to_le_bytes().to_vec(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn balance_proof_roundtrips_through_hex() {
+        let leaves = sample_leaves();
+        let proof = proof_for(&leaves, leaves[7].logical_key.clone());
+        proof.verify().expect("valid proof");
+        assert_eq!(proof.balance().unwrap(), 7_001);
+
+        let decoded = AtomicStateProof::try_from(&AtomicStateProofHex::from(&proof)).expect("hex roundtrip");
+        assert_eq!(decoded, proof);
+        decoded.verify_against(&proof.state_root).expect("valid proof against root");
+    }
+
+    #[test]
+    fn absence_proof_reports_default_values() {
+        let leaves = sample_leaves();
+        let proof = proof_for(&leaves, logical_balance_key(&[9; 32], &[9; 32]));
+        proof.verify().expect("valid absence proof");
+        assert_eq!(proof.balance().unwrap(), 0);
+
+        let nonce_proof = proof_for(&leaves, logical_nonce_key(&[1; 32], 1, &[2; 32]));
+        nonce_proof.verify().expect("valid absence proof");
+        assert_eq!(nonce_proof.next_nonce().unwrap(), 1);
+    }
+
+    #[test]
+    fn tampered_proofs_are_rejected() {
+        let leaves = sample_leaves();
+        let proof = proof_for(&leaves, leaves[3].logical_key.clone());
+
+        let mut forged_value = proof.clone();
+        forged_value.value = Some(u128::MAX.to_le_bytes().to_vec());
+        assert_eq!(forged_value.verify(), Err(AtomicProofError::BucketHashMismatch));
+
+        let mut hidden_leaf = proof.clone();
+        hidden_leaf.value = None;
+        assert_eq!(hidden_leaf.verify(), Err(AtomicProofError::BucketHashMismatch));
+
+        let mut forged_bucket = proof.clone();
+        forged_bucket.buckets[(proof.bucket_index + 1) % ATOMIC_ROOT_BUCKETS][0] ^= 1;
+        assert_eq!(forged_bucket.verify(), Err(AtomicProofError::StateRootMismatch));
+
+        let mut duplicated = proof.clone();
+        duplicated.bucket_siblings.push(AtomicStateLeaf { logical_key: proof.logical_key.clone(), value: vec![0; 16] });
+        assert_eq!(duplicated.verify(), Err(AtomicProofError::DuplicateLeaf));
+
+        assert_eq!(proof.verify_against(&[0u8; 32]), Err(AtomicProofError::UnexpectedStateRoot));
+    }
+
+    #[test]
+    fn pair_asset_leaf_decodes_pair_state() {
+        let mut value = ASSET_ROOT_V5.to_vec();
+        value.extend_from_slice(&[1; 32]); // asset_id
+        value.extend_from_slice(&[2; 32]); // creator_owner_id
+        value.push(ASSET_CLASS_PAIR);
+        value.push(1); // token_version
+        value.extend_from_slice(&[0; 32]); // mint_authority_owner_id
+        value.push(0); // decimals
+        value.push(0); // supply_mode
+        value.extend_from_slice(&0u128.to_le_bytes());
+        value.extend_from_slice(&2_000u128.to_le_bytes());
+        for bytes in [&b"CAT Pair LP"[..], b"CATLP", b"", b""] {
+            value.extend_from_slice(&(bytes.len() as u64).to_le_bytes());
+            value.extend_from_slice(bytes);
+        }
+        value.extend_from_slice(&[0, 0, 0]); // created_* are absent
+        value.push(0); // no liquidity pool
+        value.push(1);
+        let pair = [7u8; PAIR_POOL_LEAF_LEN];
+        value.extend_from_slice(&pair);
+
+        let leaf = AtomicAssetLeaf::decode(&value).expect("pair leaf decodes");
+        assert_eq!(leaf.asset_class, ASSET_CLASS_PAIR);
+        assert_eq!(leaf.total_supply, 2_000);
+        assert_eq!(leaf.liquidity, None);
+        assert_eq!(leaf.pair_pool, Some(pair.to_vec()));
+
+        value.push(0);
+        assert_eq!(AtomicAssetLeaf::decode(&value), Err(AtomicProofError::InvalidValue("asset")));
+    }
+}
@@ -0,0 +1,83 @@
+//!
+//! WASM32 bindings for the Atomic state proof verifier.
+//!
+
+use crate::{decode_hex_32, AtomicConsensusBalanceAnchor, AtomicConsensusBalanceAnchorHex, AtomicStateProof, AtomicStateProofHex};
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(typescript_custom_section)]
+const TS_TOKEN_STATE_PROOF: &'static str = r#"
+/**
+ * Atomic state proof as returned by `getTokenBalanceProof` / `getTokenAssetProof`.
+ *
+ * @category Node RPC
+ */
+export interface ITokenStateProof {
+    logicalKey : HexString;
+    value? : HexString;
+    bucketIndex : number;
+    bucketSiblings : { logicalKey : HexString, value : HexString }[];
+    bucketHashes : HexString[];
+    stateRoot : HexString;
+}
+"#;
+
+#[wasm_bindgen(typescript_custom_section)]
+const TS_TOKEN_CONSENSUS_BALANCE_ANCHOR: &'static str = r#"
+/**
+ * Consensus balance anchor as returned by `getTokenBalanceProof` with `includeConsensusAnchor`.
+ *
+ * @category Node RPC
+ */
+export interface ITokenConsensusBalanceAnchor {
+    version : number;
+    nonces : { count : number, xor : HexString };
+    assets : { count : number, xor : HexString };
+    anchorCounts : { count : number, xor : HexString };
+    allowances : { count : number, xor : HexString };
+    locks : { count : number, xor : HexString };
+    balances : { assetId : HexString, ownerId : HexString, amount : string }[];
+}
+"#;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(typescript_type = "ITokenStateProof")]
+    pub type ITokenStateProof;
+    #[wasm_bindgen(typescript_type = "ITokenConsensusBalanceAnchor")]
+    pub type ITokenConsensusBalanceAnchor;
+}
+
+/// Verifies an Atomic state proof. When `expectedStateRoot` is supplied the proof
+/// must also commit to that root. Returns `true` for a valid proof; malformed
+/// proofs throw.
+///
+/// The root is the Atomic indexer `stateHash`, not a consensus commitment. Use
+/// `verifyTokenConsensusBalanceAnchor` to check a balance against consensus.
+/// @category Node RPC
+#[wasm_bindgen(js_name = verifyTokenStateProof)]
+pub fn verify_token_state_proof(proof: ITokenStateProof, expected_state_root: Option<String>) -> Result<bool, JsError> {
+    let proof: AtomicStateProofHex = serde_wasm_bindgen::from_value(proof.into()).map_err(|err| JsError::new(&err.to_string()))?;
+    let proof = AtomicStateProof::try_from(&proof)?;
+    let result = match expected_state_root {
+        Some(root) => proof.verify_against(&decode_hex_32(&root, "expectedStateRoot")?),
+        None => proof.verify(),
+    };
+    Ok(result.is_ok())
+}
+
+/// Verifies that a consensus balance anchor recomputes to `expectedConsensusStateHash`,
+/// the value returned by `getConsensusAtomicStateHash` for the anchor's `atBlockHash`.
+/// Returns `true` for a valid anchor; malformed anchors throw. A balance missing
+/// from a valid anchor is zero.
+/// @category Node RPC
+#[wasm_bindgen(js_name = verifyTokenConsensusBalanceAnchor)]
+pub fn verify_token_consensus_balance_anchor(
+    anchor: ITokenConsensusBalanceAnchor,
+    expected_consensus_state_hash: String,
+) -> Result<bool, JsError> {
+    let anchor: AtomicConsensusBalanceAnchorHex =
+        serde_wasm_bindgen::from_value(anchor.into()).map_err(|err| JsError::new(&err.to_string()))?;
+    let anchor = AtomicConsensusBalanceAnchor::try_from(&anchor)?;
+    Ok(anchor.verify_against(&decode_hex_32(&expected_consensus_state_hash, "expectedConsensusStateHash")?).is_ok())
+}
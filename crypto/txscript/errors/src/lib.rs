@@ -69,4 +69,12 @@ pub enum TxScriptError {
     InvalidStackOperation(usize, usize),
     #[error("script of size {0} exceeded maximum allowed size of {1}")]
     ScriptSize(usize, usize),
+    #[error("transaction input index {0} is invalid, the transaction has {1} inputs")]
+    InvalidInputIndex(i32, usize),
+    #[error("transaction output index {0} is invalid, the transaction has {1} outputs")]
+    InvalidOutputIndex(i32, usize),
+    #[error("division by zero")]
+    DivisionByZero,
+    #[error("arithmetic overflow: {0}")]
+    ArithmeticOverflow(String),
 }
@@ -1,3 +1,5 @@
+use cryptix_consensus_core::hashing::sighash_type::SigHashType;
+use cryptix_hashes::Hash;
 use indexmap::IndexMap;
 use parking_lot::RwLock;
 use rand::Rng;
@@ -50,6 +52,38 @@ impl<TKey: Clone + std::hash::Hash + Eq + Send + Sync, TData: Clone + Send + Syn
     }
 }
 
+/// Signature hashes computed while verifying a single transaction input.
+///
+/// The hash only depends on the input and the hash type, so scripts checking several
+/// signatures (multisig and covenant scripts) compute each of them once.
+#[derive(Default)]
+pub(crate) struct SigHashCache {
+    schnorr: Vec<(u8, Hash)>,
+    ecdsa: Vec<(u8, Hash)>,
+}
+
+impl SigHashCache {
+    pub(crate) fn schnorr(&mut self, hash_type: SigHashType, calc: impl FnOnce() -> Hash) -> Hash {
+        Self::get_or_calc(&mut self.schnorr, hash_type, calc)
+    }
+
+    pub(crate) fn ecdsa(&mut self, hash_type: SigHashType, calc: impl FnOnce() -> Hash) -> Hash {
+        Self::get_or_calc(&mut self.ecdsa, hash_type, calc)
+    }
+
+    fn get_or_calc(entries: &mut Vec<(u8, Hash)>, hash_type: SigHashType, calc: impl FnOnce() -> Hash) -> Hash {
+        let key = hash_type.to_u8();
+        match entries.iter().find(|(entry_key, _)| *entry_key == key) {
+            Some((_, hash)) => *hash,
+            None => {
+                let hash = calc();
+                entries.push((key, hash));
+                hash
+            }
+        }
+    }
+}
+
 #[derive(Default)]
 pub struct TxScriptCacheCounters {
     pub insert_counts: AtomicU64,
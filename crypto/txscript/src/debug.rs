@@ -0,0 +1,180 @@
+use crate::{
+    caches::Cache,
+    opcodes::{opcode_name, OpCond},
+    EngineFlags, TxScriptEngine,
+};
+use cryptix_consensus_core::{hashing::sighash::SigHashReusedValues, tx::VerifiableTransaction};
+use cryptix_txscript_errors::TxScriptError;
+use std::fmt::{Display, Formatter};
+
+/// The script a traced opcode belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TracedScript {
+    /// The signature script of the transaction input
+    SignatureScript,
+    /// The script public key of the spent UTXO entry
+    ScriptPublicKey,
+    /// The redeem script of a pay-to-script-hash input
+    RedeemScript,
+    /// A stand-alone script, identified by its position
+    StandAlone(usize),
+}
+
+impl Display for TracedScript {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TracedScript::SignatureScript => write!(f, "signature"),
+            TracedScript::ScriptPublicKey => write!(f, "pubkey"),
+            TracedScript::RedeemScript => write!(f, "redeem"),
+            TracedScript::StandAlone(index) => write!(f, "script #{index}"),
+        }
+    }
+}
+
+/// The engine state right after an opcode was processed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScriptTraceStep {
+    pub script: TracedScript,
+    /// Position of the opcode within its script
+    pub index: usize,
+    pub opcode: u8,
+    /// The data carried by a push opcode, empty for other opcodes
+    pub data: Vec<u8>,
+    /// False if the opcode was skipped because it is in a branch that is not taken
+    pub executed: bool,
+    pub data_stack: Vec<Vec<u8>>,
+    pub alt_stack: Vec<Vec<u8>>,
+    pub cond_stack: Vec<OpCond>,
+    /// The error the opcode failed with, which also ends the execution
+    pub error: Option<TxScriptError>,
+}
+
+impl ScriptTraceStep {
+    pub fn opcode_name(&self) -> &'static str {
+        opcode_name(self.opcode)
+    }
+}
+
+/// The outcome of a traced script execution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScriptDebugResult {
+    pub steps: Vec<ScriptTraceStep>,
+    pub result: Result<(), TxScriptError>,
+}
+
+/// Runs the scripts of a transaction input with the engine trace enabled.
+///
+/// Signatures are always verified from scratch, the signature cache is not used.
+pub struct DebugScript<'a, T: VerifiableTransaction> {
+    tx: &'a T,
+    input_index: usize,
+    flags: EngineFlags,
+}
+
+impl<'a, T: VerifiableTransaction> DebugScript<'a, T> {
+    pub fn new(tx: &'a T, input_index: usize, flags: EngineFlags) -> Self {
+        Self { tx, input_index, flags }
+    }
+
+    pub fn run(&self) -> ScriptDebugResult {
+        let Some((input, entry)) = self.tx.populated_inputs().nth(self.input_index) else {
+            return ScriptDebugResult {
+                steps: vec![],
+                result: Err(TxScriptError::InvalidIndex(self.input_index, self.tx.tx().inputs.len())),
+            };
+        };
+
+        let mut reused_values = SigHashReusedValues::new();
+        let sig_cache = Cache::new(0);
+        let mut engine = match TxScriptEngine::from_transaction_input(
+            self.tx,
+            input,
+            self.input_index,
+            entry,
+            &mut reused_values,
+            &sig_cache,
+            self.flags,
+        ) {
+            Ok(engine) => engine.with_trace(),
+            Err(err) => return ScriptDebugResult { steps: vec![], result: Err(err) },
+        };
+        let result = engine.execute();
+        ScriptDebugResult { steps: engine.take_trace(), result }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        opcodes::codes::{OpBlake2b, OpData32, OpElse, OpEndIf, OpEqual, OpFalse, OpIf, OpTrue, OpVerify},
+        pay_to_script_hash_script, pay_to_script_hash_signature_script,
+    };
+    use cryptix_consensus_core::{
+        subnets::SUBNETWORK_ID_NATIVE,
+        tx::{PopulatedTransaction, Transaction, TransactionInput, TransactionOutpoint, UtxoEntry},
+    };
+
+    fn debug_redeem_script(redeem_script: Vec<u8>) -> ScriptDebugResult {
+        let script_public_key = pay_to_script_hash_script(&redeem_script);
+        let signature_script = pay_to_script_hash_signature_script(redeem_script, vec![]).unwrap();
+        let input = TransactionInput::new(TransactionOutpoint::new(Default::default(), 0), signature_script, 0, 0);
+        let tx = Transaction::new(0, vec![input], vec![], 0, SUBNETWORK_ID_NATIVE, 0, vec![]);
+        let populated = PopulatedTransaction::new(&tx, vec![UtxoEntry::new(1000, script_public_key, 0, false)]);
+        DebugScript::new(&populated, 0, EngineFlags::default()).run()
+    }
+
+    #[test]
+    fn test_debug_script_trace() {
+        let redeem_script = vec![OpFalse, OpIf, OpFalse, OpElse, OpTrue, OpEndIf];
+        let result = debug_redeem_script(redeem_script.clone());
+        assert_eq!(result.result, Ok(()));
+
+        let summary = result.steps.iter().map(|step| (step.script, step.index, step.opcode_name(), step.executed)).collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            vec![
+                (TracedScript::SignatureScript, 0, "OpData6", true),
+                (TracedScript::ScriptPublicKey, 0, "OpBlake2b", true),
+                (TracedScript::ScriptPublicKey, 1, "OpData32", true),
+                (TracedScript::ScriptPublicKey, 2, "OpEqual", true),
+                (TracedScript::RedeemScript, 0, "OpFalse", true),
+                (TracedScript::RedeemScript, 1, "OpIf", true),
+                (TracedScript::RedeemScript, 2, "OpFalse", false),
+                (TracedScript::RedeemScript, 3, "OpElse", true),
+                (TracedScript::RedeemScript, 4, "OpTrue", true),
+                (TracedScript::RedeemScript, 5, "OpEndIf", true),
+            ]
+        );
+        assert_eq!(result.steps[0].data, redeem_script);
+        assert_eq!(result.steps[0].data_stack, vec![redeem_script]);
+        assert_eq!(result.steps[1].opcode, OpBlake2b);
+        assert_eq!(result.steps[2].opcode, OpData32);
+        assert_eq!(result.steps[3].opcode, OpEqual);
+        assert_eq!(result.steps[6].cond_stack, vec![OpCond::False]);
+        assert_eq!(result.steps[7].cond_stack, vec![OpCond::True]);
+        assert!(result.steps[9].cond_stack.is_empty());
+        assert_eq!(result.steps[9].data_stack, vec![vec![1]]);
+        assert!(result.steps.iter().all(|step| step.error.is_none() && step.alt_stack.is_empty()));
+    }
+
+    #[test]
+    fn test_debug_script_failing_opcode() {
+        let result = debug_redeem_script(vec![OpTrue, OpFalse, OpVerify, OpTrue]);
+        assert_eq!(result.result, Err(TxScriptError::VerifyError));
+
+        let last = result.steps.last().unwrap();
+        assert_eq!((last.script, last.index, last.opcode), (TracedScript::RedeemScript, 2, OpVerify));
+        assert_eq!(last.error, Some(TxScriptError::VerifyError));
+        assert_eq!(last.data_stack, vec![vec![1]]);
+    }
+
+    #[test]
+    fn test_debug_script_invalid_input_index() {
+        let tx = Transaction::new(0, vec![], vec![], 0, SUBNETWORK_ID_NATIVE, 0, vec![]);
+        let populated = PopulatedTransaction::new(&tx, vec![]);
+        let result = DebugScript::new(&populated, 1, EngineFlags::default()).run();
+        assert_eq!(result.result, Err(TxScriptError::InvalidIndex(1, 0)));
+        assert!(result.steps.is_empty());
+    }
+}
@@ -3,6 +3,7 @@ extern crate core;
 
 pub mod caches;
 mod data_stack;
+pub mod debug;
 pub mod error;
 pub mod opcodes;
 pub mod result;
@@ -12,8 +13,9 @@ pub mod standard;
 #[cfg(feature = "wasm32-sdk")]
 pub mod wasm;
 
-use crate::caches::Cache;
+use crate::caches::{Cache, SigHashCache};
 use crate::data_stack::{DataStack, Stack};
+use crate::debug::{ScriptTraceStep, TracedScript};
 use crate::opcodes::{deserialize_next_opcode, OpCodeImplementation};
 use cryptix_consensus_core::hashing::sighash::{calc_ecdsa_signature_hash, calc_schnorr_signature_hash, SigHashReusedValues};
 use cryptix_consensus_core::hashing::sighash_type::SigHashType;
@@ -41,6 +43,9 @@ pub const SEQUENCE_LOCK_TIME_MASK: u64 = 0x00000000ffffffff;
 pub const LOCK_TIME_THRESHOLD: u64 = 500_000_000_000;
 pub const MAX_PUB_KEYS_PER_MUTLTISIG: i32 = 20;
 
+/// The maximum length of a script number once covenants are activated.
+pub const COVENANT_SCRIPT_NUM_LEN: usize = 8;
+
 // The last opcode that does not count toward operations.
 // Note that this includes OP_RESERVED which counts as a push operation.
 pub const NO_COST_OPCODE: u8 = 0x60;
@@ -65,6 +70,14 @@ pub struct SigCacheKey {
     message: secp256k1::Message,
 }
 
+/// Consensus rules which the engine applies depending on the activated hardforks.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EngineFlags {
+    /// Enables the transaction introspection opcodes, the re-enabled arithmetic and
+    /// byte-string opcodes, and 8-byte script numbers.
+    pub covenants_enabled: bool,
+}
+
 enum ScriptSource<'a, T: VerifiableTransaction> {
     TxInput { tx: &'a T, input: &'a TransactionInput, id: usize, utxo_entry: &'a UtxoEntry, is_p2sh: bool },
     StandAloneScripts(Vec<&'a [u8]>),
@@ -80,10 +93,15 @@ pub struct TxScriptEngine<'a, T: VerifiableTransaction> {
     // TODO:: make it compatible with threading
     reused_values: &'a mut SigHashReusedValues,
     sig_cache: &'a Cache<SigCacheKey, bool>,
+    sig_hash_cache: SigHashCache,
 
     cond_stack: Vec<OpCond>, // Following if stacks, and whether it is running
 
     num_ops: i32,
+    flags: EngineFlags,
+
+    // Execution trace, only recorded when enabled by `with_trace`
+    trace: Option<Vec<ScriptTraceStep>>,
 }
 
 fn parse_script<T: VerifiableTransaction>(
@@ -147,15 +165,18 @@ pub fn is_unspendable<T: VerifiableTransaction>(script: &[u8]) -> bool {
 }
 
 impl<'a, T: VerifiableTransaction> TxScriptEngine<'a, T> {
-    pub fn new(reused_values: &'a mut SigHashReusedValues, sig_cache: &'a Cache<SigCacheKey, bool>) -> Self {
+    pub fn new(reused_values: &'a mut SigHashReusedValues, sig_cache: &'a Cache<SigCacheKey, bool>, flags: EngineFlags) -> Self {
         Self {
             dstack: vec![],
             astack: vec![],
             script_source: ScriptSource::StandAloneScripts(vec![]),
             reused_values,
             sig_cache,
+            sig_hash_cache: Default::default(),
             cond_stack: vec![],
             num_ops: 0,
+            flags,
+            trace: None,
         }
     }
 
@@ -166,6 +187,7 @@ impl<'a, T: VerifiableTransaction> TxScriptEngine<'a, T> {
         utxo_entry: &'a UtxoEntry,
         reused_values: &'a mut SigHashReusedValues,
         sig_cache: &'a Cache<SigCacheKey, bool>,
+        flags: EngineFlags,
     ) -> Result<Self, TxScriptError> {
         let script_public_key = utxo_entry.script_public_key.script();
         // The script_public_key in P2SH is just validating the hash on the OpMultiSig script
@@ -178,25 +200,47 @@ impl<'a, T: VerifiableTransaction> TxScriptEngine<'a, T> {
                 script_source: ScriptSource::TxInput { tx, input, id: input_idx, utxo_entry, is_p2sh },
                 reused_values,
                 sig_cache,
+                sig_hash_cache: Default::default(),
                 cond_stack: Default::default(),
                 num_ops: 0,
+                flags,
+                trace: None,
             }),
             false => Err(TxScriptError::InvalidIndex(input_idx, tx.tx().inputs.len())),
         }
     }
 
-    pub fn from_script(script: &'a [u8], reused_values: &'a mut SigHashReusedValues, sig_cache: &'a Cache<SigCacheKey, bool>) -> Self {
+    pub fn from_script(
+        script: &'a [u8],
+        reused_values: &'a mut SigHashReusedValues,
+        sig_cache: &'a Cache<SigCacheKey, bool>,
+        flags: EngineFlags,
+    ) -> Self {
         Self {
             dstack: Default::default(),
             astack: Default::default(),
             script_source: ScriptSource::StandAloneScripts(vec![script]),
             reused_values,
             sig_cache,
+            sig_hash_cache: Default::default(),
             cond_stack: Default::default(),
             num_ops: 0,
+            flags,
+            trace: None,
         }
     }
 
+    /// Enables recording a step of the execution trace for every processed opcode.
+    pub fn with_trace(mut self) -> Self {
+        self.trace = Some(vec![]);
+        self
+    }
+
+    /// Returns the execution trace recorded so far, leaving tracing disabled.
+    pub fn take_trace(&mut self) -> Vec<ScriptTraceStep> {
+        self.trace.take().unwrap_or_default()
+    }
+
     #[inline]
     pub fn is_executing(&self) -> bool {
         return self.cond_stack.is_empty() || *self.cond_stack.last().expect("Checked not empty") == OpCond::True;
@@ -224,28 +268,54 @@ impl<'a, T: VerifiableTransaction> TxScriptEngine<'a, T> {
         }
     }
 
-    fn execute_script(&mut self, script: &[u8], verify_only_push: bool) -> Result<(), TxScriptError> {
-        let script_result = parse_script(script).try_for_each(|opcode| {
-            let opcode = opcode?;
-            if opcode.is_disabled() {
-                return Err(TxScriptError::OpcodeDisabled(format!("{:?}", opcode)));
-            }
+    fn execute_script_opcode(
+        &mut self,
+        opcode: Box<dyn OpCodeImplementation<T>>,
+        verify_only_push: bool,
+    ) -> Result<(), TxScriptError> {
+        if opcode.is_disabled(self.flags.covenants_enabled) {
+            return Err(TxScriptError::OpcodeDisabled(format!("{:?}", opcode)));
+        }
 
-            if opcode.always_illegal() {
-                return Err(TxScriptError::OpcodeReserved(format!("{:?}", opcode)));
-            }
+        if opcode.always_illegal() {
+            return Err(TxScriptError::OpcodeReserved(format!("{:?}", opcode)));
+        }
 
-            if verify_only_push && !opcode.is_push_opcode() {
-                return Err(TxScriptError::SignatureScriptNotPushOnly);
-            }
+        if verify_only_push && !opcode.is_push_opcode() {
+            return Err(TxScriptError::SignatureScriptNotPushOnly);
+        }
+
+        self.execute_opcode(opcode)?;
 
-            self.execute_opcode(opcode)?;
+        let combined_size = self.astack.len() + self.dstack.len();
+        if combined_size > MAX_STACK_SIZE {
+            return Err(TxScriptError::StackSizeExceeded(combined_size, MAX_STACK_SIZE));
+        }
+        Ok(())
+    }
 
-            let combined_size = self.astack.len() + self.dstack.len();
-            if combined_size > MAX_STACK_SIZE {
-                return Err(TxScriptError::StackSizeExceeded(combined_size, MAX_STACK_SIZE));
+    fn execute_script(&mut self, script: &[u8], verify_only_push: bool, traced_script: TracedScript) -> Result<(), TxScriptError> {
+        let script_result = parse_script(script).enumerate().try_for_each(|(index, opcode)| {
+            let opcode = opcode?;
+            if self.trace.is_none() {
+                return self.execute_script_opcode(opcode, verify_only_push);
             }
-            Ok(())
+
+            let (value, data, executed) = (opcode.value(), opcode.get_data().to_vec(), self.is_executing() || opcode.is_conditional());
+            let result = self.execute_script_opcode(opcode, verify_only_push);
+            let step = ScriptTraceStep {
+                script: traced_script,
+                index,
+                opcode: value,
+                data,
+                executed,
+                data_stack: self.dstack.clone(),
+                alt_stack: self.astack.clone(),
+                cond_stack: self.cond_stack.clone(),
+                error: result.as_ref().err().cloned(),
+            };
+            self.trace.as_mut().expect("checked above").push(step);
+            result
         });
 
         // Moving between scripts - we can't be inside an if
@@ -298,14 +368,19 @@ impl<'a, T: VerifiableTransaction> TxScriptEngine<'a, T> {
             if is_p2sh && idx == 1 {
                 saved_stack = Some(self.dstack.clone());
             }
-            self.execute_script(s, verify_only_push)
+            let traced_script = match (&self.script_source, idx) {
+                (ScriptSource::TxInput { .. }, 0) => TracedScript::SignatureScript,
+                (ScriptSource::TxInput { .. }, _) => TracedScript::ScriptPublicKey,
+                (ScriptSource::StandAloneScripts(_), _) => TracedScript::StandAlone(idx),
+            };
+            self.execute_script(s, verify_only_push, traced_script)
         })?;
 
         if is_p2sh {
             self.check_error_condition(false)?;
             self.dstack = saved_stack.ok_or(TxScriptError::EmptyStack)?;
             let script = self.dstack.pop().ok_or(TxScriptError::EmptyStack)?;
-            self.execute_script(script.as_slice(), false)?
+            self.execute_script(script.as_slice(), false, TracedScript::RedeemScript)?
         }
 
         self.check_error_condition(true)?;
@@ -444,7 +519,9 @@ impl<'a, T: VerifiableTransaction> TxScriptEngine<'a, T> {
                 Self::check_pub_key_encoding(key)?;
                 let pk = secp256k1::XOnlyPublicKey::from_slice(key).map_err(TxScriptError::InvalidSignature)?;
                 let sig = secp256k1::schnorr::Signature::from_slice(sig).map_err(TxScriptError::InvalidSignature)?;
-                let sig_hash = calc_schnorr_signature_hash(tx, id, hash_type, self.reused_values);
+                let reused_values = &mut *self.reused_values;
+                let sig_hash =
+                    self.sig_hash_cache.schnorr(hash_type, || calc_schnorr_signature_hash(tx, id, hash_type, reused_values));
                 let msg = secp256k1::Message::from_digest_slice(sig_hash.as_bytes().as_slice()).unwrap();
                 let sig_cache_key =
                     SigCacheKey { signature: Signature::Secp256k1(sig), pub_key: PublicKey::Schnorr(pk), message: msg };
@@ -479,7 +556,8 @@ impl<'a, T: VerifiableTransaction> TxScriptEngine<'a, T> {
                 Self::check_pub_key_encoding_ecdsa(key)?;
                 let pk = secp256k1::PublicKey::from_slice(key).map_err(TxScriptError::InvalidSignature)?;
                 let sig = secp256k1::ecdsa::Signature::from_compact(sig).map_err(TxScriptError::InvalidSignature)?;
-                let sig_hash = calc_ecdsa_signature_hash(tx, id, hash_type, self.reused_values);
+                let reused_values = &mut *self.reused_values;
+                let sig_hash = self.sig_hash_cache.ecdsa(hash_type, || calc_ecdsa_signature_hash(tx, id, hash_type, reused_values));
                 let msg = secp256k1::Message::from_digest_slice(sig_hash.as_bytes().as_slice()).unwrap();
                 let sig_cache_key = SigCacheKey { signature: Signature::Ecdsa(sig), pub_key: PublicKey::Ecdsa(pk), message: msg };
 
@@ -565,8 +643,16 @@ mod tests {
 
             let populated_tx = PopulatedTransaction::new(&tx, vec![utxo_entry.clone()]);
 
-            let mut vm = TxScriptEngine::from_transaction_input(&populated_tx, &input, 0, &utxo_entry, &mut reused_values, &sig_cache)
-                .expect("Script creation failed");
+            let mut vm = TxScriptEngine::from_transaction_input(
+                &populated_tx,
+                &input,
+                0,
+                &utxo_entry,
+                &mut reused_values,
+                &sig_cache,
+                EngineFlags::default(),
+            )
+            .expect("Script creation failed");
             assert_eq!(vm.execute(), test.expected_result);
         }
     }
@@ -1027,6 +1113,7 @@ mod bitcoind_tests {
                 &populated_tx.entries[0],
                 &mut reused_values,
                 &sig_cache,
+                EngineFlags::default(),
             )
             .map_err(UnifiedError::TxScriptError)?;
             vm.execute().map_err(UnifiedError::TxScriptError)
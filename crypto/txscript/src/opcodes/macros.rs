@@ -111,6 +111,15 @@ macro_rules! opcode_list {
             )?
         )*
 
+        /// Returns the name of the opcode with the given value.
+        pub fn opcode_name(opcode_num: u8) -> &'static str {
+            match opcode_num {
+                $(
+                    $num => stringify!($name),
+                )*
+            }
+        }
+
         pub fn deserialize_next_opcode<'i, I: Iterator<Item = &'i u8>, T: VerifiableTransaction>(it: &mut I) -> Option<Result<Box<dyn OpCodeImplementation<T>>, TxScriptError>> {
             match it.next() {
                 Some(opcode_num) => match opcode_num {
@@ -1,15 +1,15 @@
 #[macro_use]
 mod macros;
 
-use crate::data_stack::{DataStack, OpcodeData};
+use crate::data_stack::{DataStack, OpcodeData, SizedEncodeInt};
 use crate::{
-    ScriptSource, TxScriptEngine, TxScriptError, LOCK_TIME_THRESHOLD, MAX_TX_IN_SEQUENCE_NUM, NO_COST_OPCODE,
-    SEQUENCE_LOCK_TIME_DISABLED, SEQUENCE_LOCK_TIME_MASK,
+    ScriptSource, TxScriptEngine, TxScriptError, COVENANT_SCRIPT_NUM_LEN, LOCK_TIME_THRESHOLD, MAX_SCRIPT_ELEMENT_SIZE,
+    MAX_TX_IN_SEQUENCE_NUM, NO_COST_OPCODE, SEQUENCE_LOCK_TIME_DISABLED, SEQUENCE_LOCK_TIME_MASK,
 };
 use blake2b_simd::Params;
 use core::cmp::{max, min};
 use cryptix_consensus_core::hashing::sighash_type::SigHashType;
-use cryptix_consensus_core::tx::VerifiableTransaction;
+use cryptix_consensus_core::tx::{ScriptPublicKey, VerifiableTransaction};
 use sha2::{Digest, Sha256};
 use std::fmt::{Debug, Formatter};
 
@@ -24,8 +24,8 @@ pub const OP_DATA_MAX_VAL: u8 = self::codes::OpData75;
 /// Minus 1 value
 pub const OP_1_NEGATE_VAL: u8 = 0x81;
 
-#[derive(Debug, PartialEq, Eq)]
-pub(crate) enum OpCond {
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OpCond {
     False,
     True,
     Skip,
@@ -63,7 +63,9 @@ pub trait OpCodeMetadata: Debug {
     // For push data- check if we can use shorter encoding
     fn check_minimal_data_push(&self) -> Result<(), TxScriptError>;
 
-    fn is_disabled(&self) -> bool;
+    fn is_disabled(&self, covenants_enabled: bool) -> bool;
+    // Opcode is a transaction introspection or a re-enabled arithmetic or byte-string opcode
+    fn is_covenant(&self) -> bool;
     fn always_illegal(&self) -> bool;
     fn is_push_opcode(&self) -> bool;
     fn get_data(&self) -> &[u8];
@@ -101,24 +103,38 @@ impl<const CODE: u8> OpCodeMetadata for OpCode<CODE> {
         CODE
     }
 
-    fn is_disabled(&self) -> bool {
+    fn is_disabled(&self, covenants_enabled: bool) -> bool {
+        match CODE {
+            codes::OpCat | codes::OpSubStr | codes::OpMul | codes::OpDiv | codes::OpMod => !covenants_enabled,
+            codes::OpLeft
+            | codes::OpRight
+            | codes::OpInvert
+            | codes::OpAnd
+            | codes::OpOr
+            | codes::OpXor
+            | codes::Op2Mul
+            | codes::Op2Div
+            | codes::OpLShift
+            | codes::OpRShift => true,
+            _ => false,
+        }
+    }
+
+    fn is_covenant(&self) -> bool {
         matches!(
             CODE,
             codes::OpCat
                 | codes::OpSubStr
-                | codes::OpLeft
-                | codes::OpRight
-                | codes::OpInvert
-                | codes::OpAnd
-                | codes::OpOr
-                | codes::OpXor
-                | codes::Op2Mul
-                | codes::Op2Div
                 | codes::OpMul
                 | codes::OpDiv
                 | codes::OpMod
-                | codes::OpLShift
-                | codes::OpRShift
+                | codes::OpTxInputCount
+                | codes::OpTxOutputCount
+                | codes::OpTxInputIndex
+                | codes::OpTxInputAmount
+                | codes::OpTxInputSpk
+                | codes::OpTxOutputAmount
+                | codes::OpTxOutputSpk
         )
     }
 
@@ -204,6 +220,67 @@ fn push_number<T: VerifiableTransaction>(number: i64, vm: &mut TxScriptEngine<T>
     Ok(())
 }
 
+/// Pops numeric operands, which are 8-byte script numbers once covenants are activated
+/// and 4-byte script numbers before.
+#[inline]
+fn pop_numbers<const SIZE: usize, T: VerifiableTransaction>(vm: &mut TxScriptEngine<T>) -> Result<[i64; SIZE], TxScriptError> {
+    match vm.flags.covenants_enabled {
+        true => Ok(vm.dstack.pop_items::<SIZE, SizedEncodeInt<COVENANT_SCRIPT_NUM_LEN>>()?.map(|number| number.0)),
+        false => vm.dstack.pop_items(),
+    }
+}
+
+/// Pushes the result of a checked arithmetic operation. `i64::MIN` has no script number
+/// encoding, so it is rejected as an overflow as well.
+#[inline]
+fn push_checked_number<T: VerifiableTransaction>(number: Option<i64>, vm: &mut TxScriptEngine<T>) -> OpCodeResult {
+    match number {
+        Some(number) if number != i64::MIN => push_number(number, vm),
+        _ => Err(TxScriptError::ArithmeticOverflow("result exceeds the script number range".to_string())),
+    }
+}
+
+/// Returns the transaction and the index of the input being verified for introspection
+/// opcodes, which are undefined until covenants are activated.
+#[inline]
+fn introspected_tx_input<'a, T: VerifiableTransaction>(
+    opcode: &impl Debug,
+    vm: &TxScriptEngine<'a, T>,
+) -> Result<(&'a T, usize), TxScriptError> {
+    if !vm.flags.covenants_enabled {
+        return Err(TxScriptError::InvalidOpcode(format!("{opcode:?}")));
+    }
+    match vm.script_source {
+        ScriptSource::TxInput { tx, id, .. } => Ok((tx, id)),
+        _ => Err(TxScriptError::NotATransactionInput),
+    }
+}
+
+#[inline]
+fn pop_input_index<T: VerifiableTransaction>(tx: &T, vm: &mut TxScriptEngine<T>) -> Result<usize, TxScriptError> {
+    let [index]: [i32; 1] = vm.dstack.pop_items()?;
+    let inputs = tx.tx().inputs.len();
+    usize::try_from(index).ok().filter(|index| *index < inputs).ok_or(TxScriptError::InvalidInputIndex(index, inputs))
+}
+
+#[inline]
+fn pop_output_index<T: VerifiableTransaction>(tx: &T, vm: &mut TxScriptEngine<T>) -> Result<usize, TxScriptError> {
+    let [index]: [i32; 1] = vm.dstack.pop_items()?;
+    let outputs = tx.tx().outputs.len();
+    usize::try_from(index).ok().filter(|index| *index < outputs).ok_or(TxScriptError::InvalidOutputIndex(index, outputs))
+}
+
+/// Encodes a script public key as its big-endian version followed by the script.
+#[inline]
+fn script_public_key_bytes(script_public_key: &ScriptPublicKey) -> Vec<u8> {
+    script_public_key.version().to_be_bytes().iter().chain(script_public_key.script()).copied().collect()
+}
+
+#[inline]
+fn push_amount<T: VerifiableTransaction>(amount: u64, vm: &mut TxScriptEngine<T>) -> OpCodeResult {
+    push_number(i64::try_from(amount).map_err(|e| TxScriptError::NumberTooBig(e.to_string()))?, vm)
+}
+
 /*
 The following is the implementation and metadata of all opcodes. Each opcode has unique
 number (and template system makes it impossible to use two opcodes), length specification,
@@ -503,8 +580,34 @@ opcode_list! {
     }
 
     // Splice opcodes.
-    opcode OpCat<0x7e, 1>(self, vm) Err(TxScriptError::OpcodeDisabled(format!("{self:?}")))
-    opcode OpSubStr<0x7f, 1>(self, vm) Err(TxScriptError::OpcodeDisabled(format!("{self:?}")))
+    opcode OpCat<0x7e, 1>(self, vm) {
+        if !vm.flags.covenants_enabled {
+            return Err(TxScriptError::OpcodeDisabled(format!("{self:?}")));
+        }
+        let [mut a, b] = vm.dstack.pop_raw()?;
+        if a.len() + b.len() > MAX_SCRIPT_ELEMENT_SIZE {
+            return Err(TxScriptError::ElementTooBig(a.len() + b.len(), MAX_SCRIPT_ELEMENT_SIZE));
+        }
+        a.extend(b);
+        vm.dstack.push(a);
+        Ok(())
+    }
+
+    opcode OpSubStr<0x7f, 1>(self, vm) {
+        if !vm.flags.covenants_enabled {
+            return Err(TxScriptError::OpcodeDisabled(format!("{self:?}")));
+        }
+        let [start, length]: [i32; 2] = vm.dstack.pop_items()?;
+        let [data] = vm.dstack.pop_raw()?;
+        if start < 0 || length < 0 || start as usize + length as usize > data.len() {
+            return Err(TxScriptError::InvalidState(format!(
+                "substring [{start}, {start}+{length}) is out of bounds of a {} bytes element",
+                data.len()
+            )));
+        }
+        vm.dstack.push(data[start as usize..start as usize + length as usize].to_vec());
+        Ok(())
+    }
     opcode OpLeft<0x80, 1>(self, vm) Err(TxScriptError::OpcodeDisabled(format!("{self:?}")))
     opcode OpRight<0x81, 1>(self, vm) Err(TxScriptError::OpcodeDisabled(format!("{self:?}")))
 
@@ -556,82 +659,105 @@ opcode_list! {
 
     // Numeric related opcodes.
     opcode Op1Add<0x8b, 1>(self, vm) {
-        let [value]: [i64; 1] = vm.dstack.pop_items()?;
-        vm.dstack.push_item(value + 1);
-        Ok(())
+        let [value]: [i64; 1] = pop_numbers(vm)?;
+        push_checked_number(value.checked_add(1), vm)
     }
 
     opcode Op1Sub<0x8c, 1>(self, vm) {
-        let [value]: [i64; 1] = vm.dstack.pop_items()?;
-        vm.dstack.push_item(value - 1);
-        Ok(())
+        let [value]: [i64; 1] = pop_numbers(vm)?;
+        push_checked_number(value.checked_sub(1), vm)
     }
 
     opcode Op2Mul<0x8d, 1>(self, vm) Err(TxScriptError::OpcodeDisabled(format!("{self:?}")))
     opcode Op2Div<0x8e, 1>(self, vm) Err(TxScriptError::OpcodeDisabled(format!("{self:?}")))
 
     opcode OpNegate<0x8f, 1>(self, vm) {
-        let [value]: [i64; 1] = vm.dstack.pop_items()?;
+        let [value]: [i64; 1] = pop_numbers(vm)?;
         vm.dstack.push_item(-value);
         Ok(())
     }
 
     opcode OpAbs<0x90, 1>(self, vm) {
-        let [m]: [i64; 1] = vm.dstack.pop_items()?;
+        let [m]: [i64; 1] = pop_numbers(vm)?;
         vm.dstack.push_item(m.abs());
         Ok(())
     }
 
     opcode OpNot<0x91, 1>(self, vm) {
-        let [m]: [i64; 1] = vm.dstack.pop_items()?;
+        let [m]: [i64; 1] = pop_numbers(vm)?;
         vm.dstack.push_item((m == 0) as i64);
         Ok(())
     }
 
     opcode Op0NotEqual<0x92, 1>(self, vm) {
-        let [m]: [i64; 1] = vm.dstack.pop_items()?;
+        let [m]: [i64; 1] = pop_numbers(vm)?;
         vm.dstack.push_item((m != 0) as i64 );
         Ok(())
     }
 
     opcode OpAdd<0x93, 1>(self, vm) {
-        let [a,b]: [i64; 2] = vm.dstack.pop_items()?;
-        vm.dstack.push_item(a+b);
-        Ok(())
+        let [a,b]: [i64; 2] = pop_numbers(vm)?;
+        push_checked_number(a.checked_add(b), vm)
     }
 
     opcode OpSub<0x94, 1>(self, vm) {
-        let [a,b]: [i64; 2] = vm.dstack.pop_items()?;
-        vm.dstack.push_item(a-b);
-        Ok(())
+        let [a,b]: [i64; 2] = pop_numbers(vm)?;
+        push_checked_number(a.checked_sub(b), vm)
+    }
+
+    opcode OpMul<0x95, 1>(self, vm) {
+        if !vm.flags.covenants_enabled {
+            return Err(TxScriptError::OpcodeDisabled(format!("{self:?}")));
+        }
+        let [a,b]: [i64; 2] = pop_numbers(vm)?;
+        push_checked_number(a.checked_mul(b), vm)
+    }
+
+    opcode OpDiv<0x96, 1>(self, vm) {
+        if !vm.flags.covenants_enabled {
+            return Err(TxScriptError::OpcodeDisabled(format!("{self:?}")));
+        }
+        let [a,b]: [i64; 2] = pop_numbers(vm)?;
+        if b == 0 {
+            return Err(TxScriptError::DivisionByZero);
+        }
+        push_checked_number(a.checked_div(b), vm)
+    }
+
+    opcode OpMod<0x97, 1>(self, vm) {
+        if !vm.flags.covenants_enabled {
+            return Err(TxScriptError::OpcodeDisabled(format!("{self:?}")));
+        }
+        let [a,b]: [i64; 2] = pop_numbers(vm)?;
+        if b == 0 {
+            return Err(TxScriptError::DivisionByZero);
+        }
+        push_checked_number(a.checked_rem(b), vm)
     }
 
-    opcode OpMul<0x95, 1>(self, vm) Err(TxScriptError::OpcodeDisabled(format!("{self:?}")))
-    opcode OpDiv<0x96, 1>(self, vm) Err(TxScriptError::OpcodeDisabled(format!("{self:?}")))
-    opcode OpMod<0x97, 1>(self, vm) Err(TxScriptError::OpcodeDisabled(format!("{self:?}")))
     opcode OpLShift<0x98, 1>(self, vm) Err(TxScriptError::OpcodeDisabled(format!("{self:?}")))
     opcode OpRShift<0x99, 1>(self, vm) Err(TxScriptError::OpcodeDisabled(format!("{self:?}")))
 
     opcode OpBoolAnd<0x9a, 1>(self, vm) {
-        let [a,b]: [i64; 2] = vm.dstack.pop_items()?;
+        let [a,b]: [i64; 2] = pop_numbers(vm)?;
         vm.dstack.push_item(((a != 0) && (b != 0)) as i64);
         Ok(())
     }
 
     opcode OpBoolOr<0x9b, 1>(self, vm) {
-        let [a,b]: [i64; 2] = vm.dstack.pop_items()?;
+        let [a,b]: [i64; 2] = pop_numbers(vm)?;
         vm.dstack.push_item(((a != 0) || (b != 0)) as i64);
         Ok(())
     }
 
     opcode OpNumEqual<0x9c, 1>(self, vm) {
-        let [a,b]: [i64; 2] = vm.dstack.pop_items()?;
+        let [a,b]: [i64; 2] = pop_numbers(vm)?;
         vm.dstack.push_item((a == b) as i64);
         Ok(())
     }
 
     opcode OpNumEqualVerify<0x9d, 1>(self, vm) {
-        let [a,b]: [i64; 2] = vm.dstack.pop_items()?;
+        let [a,b]: [i64; 2] = pop_numbers(vm)?;
         match a == b {
             true => Ok(()),
             false => Err(TxScriptError::VerifyError)
@@ -639,49 +765,49 @@ opcode_list! {
     }
 
     opcode OpNumNotEqual<0x9e, 1>(self, vm) {
-        let [a,b]: [i64; 2] = vm.dstack.pop_items()?;
+        let [a,b]: [i64; 2] = pop_numbers(vm)?;
         vm.dstack.push_item((a != b) as i64);
         Ok(())
     }
 
     opcode OpLessThan<0x9f, 1>(self, vm) {
-        let [a,b]: [i64; 2] = vm.dstack.pop_items()?;
+        let [a,b]: [i64; 2] = pop_numbers(vm)?;
         vm.dstack.push_item((a < b) as i64);
         Ok(())
     }
 
     opcode OpGreaterThan<0xa0, 1>(self, vm) {
-        let [a,b]: [i64; 2] = vm.dstack.pop_items()?;
+        let [a,b]: [i64; 2] = pop_numbers(vm)?;
         vm.dstack.push_item((a > b) as i64);
         Ok(())
     }
 
     opcode OpLessThanOrEqual<0xa1, 1>(self, vm) {
-        let [a,b]: [i64; 2] = vm.dstack.pop_items()?;
+        let [a,b]: [i64; 2] = pop_numbers(vm)?;
         vm.dstack.push_item((a <= b) as i64);
         Ok(())
     }
 
     opcode OpGreaterThanOrEqual<0xa2, 1>(self, vm) {
-        let [a,b]: [i64; 2] = vm.dstack.pop_items()?;
+        let [a,b]: [i64; 2] = pop_numbers(vm)?;
         vm.dstack.push_item((a >= b) as i64);
         Ok(())
     }
 
     opcode OpMin<0xa3, 1>(self, vm) {
-        let [a,b]: [i64; 2] = vm.dstack.pop_items()?;
+        let [a,b]: [i64; 2] = pop_numbers(vm)?;
         vm.dstack.push_item(min(a,b));
         Ok(())
     }
 
     opcode OpMax<0xa4, 1>(self, vm) {
-        let [a,b]: [i64; 2] = vm.dstack.pop_items()?;
+        let [a,b]: [i64; 2] = pop_numbers(vm)?;
         vm.dstack.push_item(max(a,b));
         Ok(())
     }
 
     opcode OpWithin<0xa5, 1>(self, vm) {
-        let [x,l,u]: [i64; 3] = vm.dstack.pop_items()?;
+        let [x,l,u]: [i64; 3] = pop_numbers(vm)?;
         vm.dstack.push_item((x >= l && x < u) as i64);
         Ok(())
     }
@@ -876,23 +1002,63 @@ opcode_list! {
 
     // Undefined opcodes.
     opcode OpUnknown178<0xb2, 1>(self, vm) Err(TxScriptError::InvalidOpcode(format!("{self:?}")))
-    opcode OpUnknown179<0xb3, 1>(self, vm) Err(TxScriptError::InvalidOpcode(format!("{self:?}")))
-    opcode OpUnknown180<0xb4, 1>(self, vm) Err(TxScriptError::InvalidOpcode(format!("{self:?}")))
+
+    // Transaction introspection opcodes, undefined until covenants are activated.
+    opcode OpTxInputCount<0xb3, 1>(self, vm) {
+        let (tx, _) = introspected_tx_input(self, vm)?;
+        push_number(tx.tx().inputs.len() as i64, vm)
+    }
+
+    opcode OpTxOutputCount<0xb4, 1>(self, vm) {
+        let (tx, _) = introspected_tx_input(self, vm)?;
+        push_number(tx.tx().outputs.len() as i64, vm)
+    }
+
     opcode OpUnknown181<0xb5, 1>(self, vm) Err(TxScriptError::InvalidOpcode(format!("{self:?}")))
     opcode OpUnknown182<0xb6, 1>(self, vm) Err(TxScriptError::InvalidOpcode(format!("{self:?}")))
     opcode OpUnknown183<0xb7, 1>(self, vm) Err(TxScriptError::InvalidOpcode(format!("{self:?}")))
     opcode OpUnknown184<0xb8, 1>(self, vm) Err(TxScriptError::InvalidOpcode(format!("{self:?}")))
-    opcode OpUnknown185<0xb9, 1>(self, vm) Err(TxScriptError::InvalidOpcode(format!("{self:?}")))
+
+    opcode OpTxInputIndex<0xb9, 1>(self, vm) {
+        let (_, id) = introspected_tx_input(self, vm)?;
+        push_number(id as i64, vm)
+    }
+
     opcode OpUnknown186<0xba, 1>(self, vm) Err(TxScriptError::InvalidOpcode(format!("{self:?}")))
     opcode OpUnknown187<0xbb, 1>(self, vm) Err(TxScriptError::InvalidOpcode(format!("{self:?}")))
     opcode OpUnknown188<0xbc, 1>(self, vm) Err(TxScriptError::InvalidOpcode(format!("{self:?}")))
     opcode OpUnknown189<0xbd, 1>(self, vm) Err(TxScriptError::InvalidOpcode(format!("{self:?}")))
-    opcode OpUnknown190<0xbe, 1>(self, vm) Err(TxScriptError::InvalidOpcode(format!("{self:?}")))
-    opcode OpUnknown191<0xbf, 1>(self, vm) Err(TxScriptError::InvalidOpcode(format!("{self:?}")))
+
+    opcode OpTxInputAmount<0xbe, 1>(self, vm) {
+        let (tx, _) = introspected_tx_input(self, vm)?;
+        let index = pop_input_index(tx, vm)?;
+        let (_, entry) = tx.populated_input(index);
+        push_amount(entry.amount, vm)
+    }
+
+    opcode OpTxInputSpk<0xbf, 1>(self, vm) {
+        let (tx, _) = introspected_tx_input(self, vm)?;
+        let index = pop_input_index(tx, vm)?;
+        let (_, entry) = tx.populated_input(index);
+        push_data(script_public_key_bytes(&entry.script_public_key), vm)
+    }
+
     opcode OpUnknown192<0xc0, 1>(self, vm) Err(TxScriptError::InvalidOpcode(format!("{self:?}")))
     opcode OpUnknown193<0xc1, 1>(self, vm) Err(TxScriptError::InvalidOpcode(format!("{self:?}")))
-    opcode OpUnknown194<0xc2, 1>(self, vm) Err(TxScriptError::InvalidOpcode(format!("{self:?}")))
-    opcode OpUnknown195<0xc3, 1>(self, vm) Err(TxScriptError::InvalidOpcode(format!("{self:?}")))
+
+    opcode OpTxOutputAmount<0xc2, 1>(self, vm) {
+        let (tx, _) = introspected_tx_input(self, vm)?;
+        let index = pop_output_index(tx, vm)?;
+        push_amount(tx.tx().outputs[index].value, vm)
+    }
+
+    opcode OpTxOutputSpk<0xc3, 1>(self, vm) {
+        let (tx, _) = introspected_tx_input(self, vm)?;
+        let index = pop_output_index(tx, vm)?;
+        push_data(script_public_key_bytes(&tx.tx().outputs[index].script_public_key), vm)
+    }
+
+    // Undefined opcodes.
     opcode OpUnknown196<0xc4, 1>(self, vm) Err(TxScriptError::InvalidOpcode(format!("{self:?}")))
     opcode OpUnknown197<0xc5, 1>(self, vm) Err(TxScriptError::InvalidOpcode(format!("{self:?}")))
     opcode OpUnknown198<0xc6, 1>(self, vm) Err(TxScriptError::InvalidOpcode(format!("{self:?}")))
@@ -971,9 +1137,9 @@ pub fn to_small_int<T: VerifiableTransaction>(opcode: &Box<dyn OpCodeImplementat
 #[cfg(test)]
 mod test {
     use crate::caches::Cache;
-    use crate::data_stack::Stack;
+    use crate::data_stack::{OpcodeData, Stack};
     use crate::opcodes::{OpCodeExecution, OpCodeImplementation};
-    use crate::{opcodes, pay_to_address_script, TxScriptEngine, TxScriptError, LOCK_TIME_THRESHOLD};
+    use crate::{opcodes, pay_to_address_script, EngineFlags, TxScriptEngine, TxScriptError, LOCK_TIME_THRESHOLD};
     use cryptix_addresses::{Address, Prefix, Version};
     use cryptix_consensus_core::constants::{SOMPI_PER_CRYPTIX, TX_VERSION};
     use cryptix_consensus_core::hashing::sighash::SigHashReusedValues;
@@ -996,10 +1162,14 @@ mod test {
     }
 
     fn run_success_test_cases(tests: Vec<TestCase>) {
+        run_success_test_cases_with_flags(tests, EngineFlags::default())
+    }
+
+    fn run_success_test_cases_with_flags(tests: Vec<TestCase>, flags: EngineFlags) {
         let cache = Cache::new(10_000);
         let mut reused_values = SigHashReusedValues::new();
         for TestCase { init, code, dstack } in tests {
-            let mut vm = TxScriptEngine::new(&mut reused_values, &cache);
+            let mut vm = TxScriptEngine::new(&mut reused_values, &cache, flags);
             vm.dstack = init;
             code.execute(&mut vm).unwrap_or_else(|_| panic!("Opcode {} should not fail", code.value()));
             assert_eq!(*vm.dstack, dstack, "OpCode {} Pushed wrong value", code.value());
@@ -1007,10 +1177,14 @@ mod test {
     }
 
     fn run_error_test_cases(tests: Vec<ErrorTestCase>) {
+        run_error_test_cases_with_flags(tests, EngineFlags::default())
+    }
+
+    fn run_error_test_cases_with_flags(tests: Vec<ErrorTestCase>, flags: EngineFlags) {
         let cache = Cache::new(10_000);
         let mut reused_values = SigHashReusedValues::new();
         for ErrorTestCase { init, code, error } in tests {
-            let mut vm = TxScriptEngine::new(&mut reused_values, &cache);
+            let mut vm = TxScriptEngine::new(&mut reused_values, &cache, flags);
             vm.dstack.clone_from(&init);
             assert_eq!(
                 code.execute(&mut vm)
@@ -1045,7 +1219,7 @@ mod test {
 
         let cache = Cache::new(10_000);
         let mut reused_values = SigHashReusedValues::new();
-        let mut vm = TxScriptEngine::new(&mut reused_values, &cache);
+        let mut vm = TxScriptEngine::new(&mut reused_values, &cache, EngineFlags::default());
 
         for pop in tests {
             match pop.execute(&mut vm) {
@@ -1068,7 +1242,7 @@ mod test {
 
         let cache = Cache::new(10_000);
         let mut reused_values = SigHashReusedValues::new();
-        let mut vm = TxScriptEngine::new(&mut reused_values, &cache);
+        let mut vm = TxScriptEngine::new(&mut reused_values, &cache, EngineFlags::default());
 
         for pop in tests {
             match pop.execute(&mut vm) {
@@ -1084,23 +1258,23 @@ mod test {
             opcodes::OpUnknown166::empty().expect("Should accept empty"),
             opcodes::OpUnknown167::empty().expect("Should accept empty"),
             opcodes::OpUnknown178::empty().expect("Should accept empty"),
-            opcodes::OpUnknown179::empty().expect("Should accept empty"),
-            opcodes::OpUnknown180::empty().expect("Should accept empty"),
+            opcodes::OpTxInputCount::empty().expect("Should accept empty"),
+            opcodes::OpTxOutputCount::empty().expect("Should accept empty"),
             opcodes::OpUnknown181::empty().expect("Should accept empty"),
             opcodes::OpUnknown182::empty().expect("Should accept empty"),
             opcodes::OpUnknown183::empty().expect("Should accept empty"),
             opcodes::OpUnknown184::empty().expect("Should accept empty"),
-            opcodes::OpUnknown185::empty().expect("Should accept empty"),
+            opcodes::OpTxInputIndex::empty().expect("Should accept empty"),
             opcodes::OpUnknown186::empty().expect("Should accept empty"),
             opcodes::OpUnknown187::empty().expect("Should accept empty"),
             opcodes::OpUnknown188::empty().expect("Should accept empty"),
             opcodes::OpUnknown189::empty().expect("Should accept empty"),
-            opcodes::OpUnknown190::empty().expect("Should accept empty"),
-            opcodes::OpUnknown191::empty().expect("Should accept empty"),
+            opcodes::OpTxInputAmount::empty().expect("Should accept empty"),
+            opcodes::OpTxInputSpk::empty().expect("Should accept empty"),
             opcodes::OpUnknown192::empty().expect("Should accept empty"),
             opcodes::OpUnknown193::empty().expect("Should accept empty"),
-            opcodes::OpUnknown194::empty().expect("Should accept empty"),
-            opcodes::OpUnknown195::empty().expect("Should accept empty"),
+            opcodes::OpTxOutputAmount::empty().expect("Should accept empty"),
+            opcodes::OpTxOutputSpk::empty().expect("Should accept empty"),
             opcodes::OpUnknown196::empty().expect("Should accept empty"),
             opcodes::OpUnknown197::empty().expect("Should accept empty"),
             opcodes::OpUnknown198::empty().expect("Should accept empty"),
@@ -1159,7 +1333,7 @@ mod test {
 
         let cache = Cache::new(10_000);
         let mut reused_values = SigHashReusedValues::new();
-        let mut vm = TxScriptEngine::new(&mut reused_values, &cache);
+        let mut vm = TxScriptEngine::new(&mut reused_values, &cache, EngineFlags::default());
 
         for pop in tests {
             match pop.execute(&mut vm) {
@@ -2751,8 +2925,16 @@ mod test {
         ] {
             let mut tx = base_tx.clone();
             tx.0.lock_time = tx_lock_time;
-            let mut vm = TxScriptEngine::from_transaction_input(&tx, &input, 0, &utxo_entry, &mut reused_values, &sig_cache)
-                .expect("Shouldn't fail");
+            let mut vm = TxScriptEngine::from_transaction_input(
+                &tx,
+                &input,
+                0,
+                &utxo_entry,
+                &mut reused_values,
+                &sig_cache,
+                EngineFlags::default(),
+            )
+            .expect("Shouldn't fail");
             vm.dstack = vec![lock_time.clone()];
             match code.execute(&mut vm) {
                 // Message is based on the should_fail values
@@ -2794,8 +2976,16 @@ mod test {
         ] {
             let mut input = base_input.clone();
             input.sequence = tx_sequence;
-            let mut vm = TxScriptEngine::from_transaction_input(&tx, &input, 0, &utxo_entry, &mut reused_values, &sig_cache)
-                .expect("Shouldn't fail");
+            let mut vm = TxScriptEngine::from_transaction_input(
+                &tx,
+                &input,
+                0,
+                &utxo_entry,
+                &mut reused_values,
+                &sig_cache,
+                EngineFlags::default(),
+            )
+            .expect("Shouldn't fail");
             vm.dstack = vec![sequence.clone()];
             match code.execute(&mut vm) {
                 // Message is based on the should_fail values
@@ -2897,4 +3087,195 @@ mod test {
             TestCase { code: opcodes::OpIfDup::empty().expect("Should accept empty"), init: vec![vec![]], dstack: vec![vec![]] },
         ])
     }
+
+    const COVENANTS: EngineFlags = EngineFlags { covenants_enabled: true };
+
+    #[test]
+    fn test_covenant_splice_opcodes() {
+        run_success_test_cases_with_flags(
+            vec![
+                TestCase {
+                    code: opcodes::OpCat::empty().expect("Should accept empty"),
+                    init: vec![vec![1, 2], vec![3]],
+                    dstack: vec![vec![1, 2, 3]],
+                },
+                TestCase {
+                    code: opcodes::OpCat::empty().expect("Should accept empty"),
+                    init: vec![vec![], vec![]],
+                    dstack: vec![vec![]],
+                },
+                TestCase {
+                    code: opcodes::OpSubStr::empty().expect("Should accept empty"),
+                    init: vec![vec![1, 2, 3, 4], vec![1], vec![2]],
+                    dstack: vec![vec![2, 3]],
+                },
+                TestCase {
+                    code: opcodes::OpSubStr::empty().expect("Should accept empty"),
+                    init: vec![vec![1, 2, 3, 4], vec![4], vec![]],
+                    dstack: vec![vec![]],
+                },
+            ],
+            COVENANTS,
+        );
+
+        run_error_test_cases_with_flags(
+            vec![
+                ErrorTestCase {
+                    code: opcodes::OpCat::empty().expect("Should accept empty"),
+                    init: vec![vec![0; 300], vec![0; 221]],
+                    error: TxScriptError::ElementTooBig(521, 520),
+                },
+                ErrorTestCase {
+                    code: opcodes::OpSubStr::empty().expect("Should accept empty"),
+                    init: vec![vec![1, 2, 3, 4], vec![3], vec![2]],
+                    error: TxScriptError::InvalidState("substring [3, 3+2) is out of bounds of a 4 bytes element".to_string()),
+                },
+                ErrorTestCase {
+                    code: opcodes::OpSubStr::empty().expect("Should accept empty"),
+                    init: vec![vec![1, 2, 3, 4], vec![0x81], vec![2]],
+                    error: TxScriptError::InvalidState("substring [-1, -1+2) is out of bounds of a 4 bytes element".to_string()),
+                },
+            ],
+            COVENANTS,
+        );
+    }
+
+    #[test]
+    fn test_covenant_arithmetic_opcodes() {
+        // 10^10 does not fit a 4-byte script number
+        let ten_billion = <Vec<u8> as OpcodeData<i64>>::serialize(&10_000_000_000);
+        run_success_test_cases_with_flags(
+            vec![
+                TestCase {
+                    code: opcodes::OpMul::empty().expect("Should accept empty"),
+                    init: vec![vec![0x0a], vec![0x81]],
+                    dstack: vec![vec![0x8a]],
+                },
+                TestCase {
+                    code: opcodes::OpMul::empty().expect("Should accept empty"),
+                    init: vec![ten_billion.clone(), vec![2]],
+                    dstack: vec![<Vec<u8> as OpcodeData<i64>>::serialize(&20_000_000_000)],
+                },
+                TestCase {
+                    code: opcodes::OpDiv::empty().expect("Should accept empty"),
+                    init: vec![ten_billion.clone(), vec![3]],
+                    dstack: vec![<Vec<u8> as OpcodeData<i64>>::serialize(&3_333_333_333)],
+                },
+                TestCase {
+                    code: opcodes::OpDiv::empty().expect("Should accept empty"),
+                    init: vec![vec![0x87], vec![2]],
+                    dstack: vec![vec![0x83]],
+                },
+                TestCase {
+                    code: opcodes::OpMod::empty().expect("Should accept empty"),
+                    init: vec![ten_billion.clone(), vec![3]],
+                    dstack: vec![vec![1]],
+                },
+                TestCase {
+                    code: opcodes::OpMod::empty().expect("Should accept empty"),
+                    init: vec![vec![0x87], vec![2]],
+                    dstack: vec![vec![0x81]],
+                },
+                TestCase {
+                    code: opcodes::OpAdd::empty().expect("Should accept empty"),
+                    init: vec![ten_billion.clone(), ten_billion.clone()],
+                    dstack: vec![<Vec<u8> as OpcodeData<i64>>::serialize(&20_000_000_000)],
+                },
+            ],
+            COVENANTS,
+        );
+
+        let max = <Vec<u8> as OpcodeData<i64>>::serialize(&i64::MAX);
+        run_error_test_cases_with_flags(
+            vec![
+                ErrorTestCase {
+                    code: opcodes::OpDiv::empty().expect("Should accept empty"),
+                    init: vec![vec![1], vec![]],
+                    error: TxScriptError::DivisionByZero,
+                },
+                ErrorTestCase {
+                    code: opcodes::OpMod::empty().expect("Should accept empty"),
+                    init: vec![vec![1], vec![]],
+                    error: TxScriptError::DivisionByZero,
+                },
+                ErrorTestCase {
+                    code: opcodes::OpMul::empty().expect("Should accept empty"),
+                    init: vec![max.clone(), vec![2]],
+                    error: TxScriptError::ArithmeticOverflow("result exceeds the script number range".to_string()),
+                },
+                ErrorTestCase {
+                    code: opcodes::Op1Add::empty().expect("Should accept empty"),
+                    init: vec![max.clone()],
+                    error: TxScriptError::ArithmeticOverflow("result exceeds the script number range".to_string()),
+                },
+            ],
+            COVENANTS,
+        );
+
+        // Without covenants, script numbers remain limited to 4 bytes
+        run_error_test_cases(vec![ErrorTestCase {
+            code: opcodes::OpAdd::empty().expect("Should accept empty"),
+            init: vec![ten_billion.clone(), vec![1]],
+            error: TxScriptError::NumberTooBig(
+                "numeric value encoded as [0, e4, b, 54, 2] is 5 bytes which exceeds the max allowed of 4".to_string(),
+            ),
+        }]);
+    }
+
+    #[test]
+    fn test_covenant_introspection_opcodes() {
+        let (base_tx, input, _) = make_mock_transaction(0);
+        let mut tx = base_tx.0.clone();
+        let second_output_spk = ScriptPublicKey::new(0, vec![opcodes::codes::OpTrue].into());
+        tx.outputs.push(TransactionOutput::new(7, second_output_spk));
+        let entry_spk = ScriptPublicKey::new(0, vec![opcodes::codes::OpTrue].into());
+        let utxo_entry = UtxoEntry::new(10_000_000_000, entry_spk, 0, false);
+        let populated = PopulatedTransaction::new(&tx, vec![utxo_entry.clone()]);
+
+        let sig_cache = Cache::new(10_000);
+        let mut reused_values = SigHashReusedValues::new();
+
+        let cases: Vec<(Box<dyn OpCodeImplementation<PopulatedTransaction>>, Vec<Vec<u8>>, Result<Vec<u8>, TxScriptError>)> = vec![
+            (opcodes::OpTxInputCount::empty().expect("Should accept empty"), vec![], Ok(vec![1])),
+            (opcodes::OpTxOutputCount::empty().expect("Should accept empty"), vec![], Ok(vec![2])),
+            (opcodes::OpTxInputIndex::empty().expect("Should accept empty"), vec![], Ok(vec![])),
+            (
+                opcodes::OpTxInputAmount::empty().expect("Should accept empty"),
+                vec![vec![]],
+                Ok(<Vec<u8> as OpcodeData<i64>>::serialize(&10_000_000_000)),
+            ),
+            (opcodes::OpTxInputSpk::empty().expect("Should accept empty"), vec![vec![]], Ok(vec![0, 0, opcodes::codes::OpTrue])),
+            (opcodes::OpTxOutputAmount::empty().expect("Should accept empty"), vec![vec![1]], Ok(vec![7])),
+            (opcodes::OpTxOutputSpk::empty().expect("Should accept empty"), vec![vec![1]], Ok(vec![0, 0, opcodes::codes::OpTrue])),
+            (
+                opcodes::OpTxInputAmount::empty().expect("Should accept empty"),
+                vec![vec![1]],
+                Err(TxScriptError::InvalidInputIndex(1, 1)),
+            ),
+            (
+                opcodes::OpTxOutputSpk::empty().expect("Should accept empty"),
+                vec![vec![0x81]],
+                Err(TxScriptError::InvalidOutputIndex(-1, 2)),
+            ),
+        ];
+
+        for (code, init, expected) in cases {
+            let mut vm =
+                TxScriptEngine::from_transaction_input(&populated, &input, 0, &utxo_entry, &mut reused_values, &sig_cache, COVENANTS)
+                    .expect("Shouldn't fail");
+            vm.dstack = init;
+            match expected {
+                Ok(item) => {
+                    code.execute(&mut vm).unwrap_or_else(|e| panic!("Opcode {} should not fail: {e}", code.value()));
+                    assert_eq!(*vm.dstack, vec![item], "Opcode {} pushed wrong value", code.value());
+                }
+                Err(error) => assert_eq!(code.execute(&mut vm), Err(error), "Opcode {} returned wrong error", code.value()),
+            }
+        }
+
+        // Introspection needs a transaction input to run on
+        let mut vm = TxScriptEngine::<PopulatedTransaction>::new(&mut reused_values, &sig_cache, COVENANTS);
+        let code: Box<dyn OpCodeImplementation<PopulatedTransaction>> = opcodes::OpTxInputCount::empty().expect("Should accept empty");
+        assert_eq!(code.execute(&mut vm), Err(TxScriptError::NotATransactionInput));
+    }
 }
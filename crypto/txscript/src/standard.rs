@@ -1,17 +1,22 @@
 use crate::{
     opcodes::codes::{OpBlake2b, OpCheckSig, OpCheckSigECDSA, OpData32, OpData33, OpEqual},
+    parse_script,
     script_builder::{ScriptBuilder, ScriptBuilderResult},
     script_class::ScriptClass,
 };
 use blake2b_simd::Params;
 use cryptix_addresses::{Address, Prefix, Version};
-use cryptix_consensus_core::tx::{ScriptPublicKey, ScriptVec};
+use cryptix_consensus_core::tx::{PopulatedTransaction, ScriptPublicKey, ScriptVec};
 use cryptix_txscript_errors::TxScriptError;
 use smallvec::SmallVec;
 use std::iter::once;
 
 mod multisig;
 
+/// Maximum number of covenant opcodes (transaction introspection and re-enabled arithmetic
+/// and byte-string opcodes) a standard pay-to-script-hash redeem script may contain.
+pub const MAX_STANDARD_COVENANT_OPS: usize = 32;
+
 pub use multisig::{multisig_redeem_script, multisig_redeem_script_ecdsa, Error as MultisigCreateError};
 
 /// Creates a new script to pay a transaction output to a 32-byte pubkey.
@@ -84,6 +89,45 @@ pub fn extract_script_pub_key_address(script_public_key: &ScriptPublicKey, prefi
     }
 }
 
+/// Checks the standardness rules of a pay-to-script-hash signature script whose redeem
+/// script (its last push) uses covenant opcodes.
+///
+/// Such a redeem script must parse entirely and contain at most [`MAX_STANDARD_COVENANT_OPS`]
+/// covenant opcodes. Signature scripts redeeming scripts without covenant opcodes are not
+/// affected by this check.
+pub fn check_covenant_signature_script(signature_script: &[u8]) -> Result<(), TxScriptError> {
+    let Some(Ok(last)) = parse_script::<PopulatedTransaction>(signature_script).last() else {
+        return Ok(());
+    };
+    if !last.is_push_opcode() {
+        return Ok(());
+    }
+
+    let mut covenant_ops = 0;
+    let mut parse_error = None;
+    for op in parse_script::<PopulatedTransaction>(last.get_data()) {
+        match op {
+            Ok(op) if op.is_covenant() => covenant_ops += 1,
+            Ok(_) => {}
+            Err(err) => {
+                parse_error = Some(err);
+                break;
+            }
+        }
+    }
+
+    if covenant_ops == 0 {
+        return Ok(());
+    }
+    if let Some(err) = parse_error {
+        return Err(err);
+    }
+    if covenant_ops > MAX_STANDARD_COVENANT_OPS {
+        return Err(TxScriptError::TooManyOperations(MAX_STANDARD_COVENANT_OPS as i32));
+    }
+    Ok(())
+}
+
 pub mod test_helpers {
     use super::*;
     use crate::{opcodes::codes::OpTrue, MAX_TX_IN_SEQUENCE_NUM};
@@ -229,4 +273,49 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_check_covenant_signature_script() {
+        use crate::opcodes::codes::{OpData2, OpGreaterThanOrEqual, OpTrue, OpTxInputCount, OpTxOutputCount};
+
+        struct Test {
+            name: &'static str,
+            redeem_script: Vec<u8>,
+            expected: Result<(), TxScriptError>,
+        }
+
+        let tests = vec![
+            Test { name: "redeem script without covenant opcodes", redeem_script: vec![OpTrue], expected: Ok(()) },
+            Test {
+                name: "malformed redeem script without covenant opcodes",
+                redeem_script: vec![OpTrue, OpData2, 0x01],
+                expected: Ok(()),
+            },
+            Test {
+                name: "covenant redeem script",
+                redeem_script: vec![OpTxInputCount, OpTxOutputCount, OpGreaterThanOrEqual],
+                expected: Ok(()),
+            },
+            Test {
+                name: "malformed covenant redeem script",
+                redeem_script: vec![OpTxInputCount, OpData2, 0x01],
+                expected: Err(TxScriptError::MalformedPush(2, 1)),
+            },
+            Test {
+                name: "covenant redeem script at the covenant opcodes limit",
+                redeem_script: vec![OpTxInputCount; MAX_STANDARD_COVENANT_OPS],
+                expected: Ok(()),
+            },
+            Test {
+                name: "covenant redeem script exceeding the covenant opcodes limit",
+                redeem_script: vec![OpTxInputCount; MAX_STANDARD_COVENANT_OPS + 1],
+                expected: Err(TxScriptError::TooManyOperations(MAX_STANDARD_COVENANT_OPS as i32)),
+            },
+        ];
+
+        for test in tests {
+            let signature_script = pay_to_script_hash_signature_script(test.redeem_script, vec![]).unwrap();
+            assert_eq!(check_covenant_signature_script(&signature_script), test.expected, "'{}' failed", test.name);
+        }
+    }
 }
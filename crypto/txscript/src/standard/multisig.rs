@@ -184,7 +184,8 @@ mod tests {
         let (input, entry) = tx.populated_inputs().next().unwrap();
 
         let cache = Cache::new(10_000);
-        let mut engine = TxScriptEngine::from_transaction_input(&tx, input, 0, entry, &mut reused_values, &cache).unwrap();
+        let mut engine =
+            TxScriptEngine::from_transaction_input(&tx, input, 0, entry, &mut reused_values, &cache, Default::default()).unwrap();
         assert_eq!(engine.execute().is_ok(), is_ok);
     }
     #[test]
@@ -215,23 +215,23 @@ pub enum Opcodes {
 
     /// Undefined opcodes.
     OpUnknown178 = 0xb2,
-    OpUnknown179 = 0xb3,
-    OpUnknown180 = 0xb4,
+    OpTxInputCount = 0xb3,
+    OpTxOutputCount = 0xb4,
     OpUnknown181 = 0xb5,
     OpUnknown182 = 0xb6,
     OpUnknown183 = 0xb7,
     OpUnknown184 = 0xb8,
-    OpUnknown185 = 0xb9,
+    OpTxInputIndex = 0xb9,
     OpUnknown186 = 0xba,
     OpUnknown187 = 0xbb,
     OpUnknown188 = 0xbc,
     OpUnknown189 = 0xbd,
-    OpUnknown190 = 0xbe,
-    OpUnknown191 = 0xbf,
+    OpTxInputAmount = 0xbe,
+    OpTxInputSpk = 0xbf,
     OpUnknown192 = 0xc0,
     OpUnknown193 = 0xc1,
-    OpUnknown194 = 0xc2,
-    OpUnknown195 = 0xc3,
+    OpTxOutputAmount = 0xc2,
+    OpTxOutputSpk = 0xc3,
     OpUnknown196 = 0xc4,
     OpUnknown197 = 0xc5,
     OpUnknown198 = 0xc6,
@@ -42,6 +42,7 @@ pub enum DatabaseStorePrefixes {
     ReachabilityTreeChildren = 30,
     ReachabilityFutureCoveringSet = 31,
     AtomicStateV2 = 32,
+    VirtualStateV2 = 33,
 
     // ---- Metadata ----
     MultiConsensusMetadata = 124,
@@ -50,6 +51,7 @@ pub enum DatabaseStorePrefixes {
     // ---- Components ----
     Addresses = 128,
     BannedAddresses = 129,
+    HfaIntents = 130,
 
     // ---- Indexes ----
     UtxoIndex = 192,
@@ -0,0 +1,525 @@
+//! Offline analytics export of the Atomic token state.
+//!
+//! Unlike the bootstrap snapshot, which only a node can re-import, the analytics export is a directory of
+//! line-delimited JSON files meant for reporting tools. Every file is covered by `manifest.json`, which embeds
+//! the Atomic state root of the exported block together with the row count and BLAKE2b-256 digest of each
+//! file, so a report can be audited against `GetTokenStateHash` at the same block. The layout is documented
+//! in `docs/atomic_analytics_export.md`.
+
+use crate::{
+    error::{AtomicTokenError, AtomicTokenResult},
+    state::{BalanceKey, LiquidityTradeSide, TokenAsset, TokenAssetClass, TokenEvent},
+    storage_v2::AtomicStorageV2,
+};
+use blake2b_simd::{Params as Blake2bParams, State as Blake2bState};
+use cryptix_consensus_core::Hash as BlockHash;
+use hex::encode as hex_encode;
+use serde::Serialize;
+use std::{
+    collections::BTreeMap,
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+};
+
+pub const ANALYTICS_EXPORT_FORMAT: &str = "cryptix-atomic-analytics";
+pub const ANALYTICS_EXPORT_FORMAT_VERSION: u32 = 1;
+pub const ANALYTICS_MANIFEST_FILE_NAME: &str = "manifest.json";
+pub const ANALYTICS_ASSETS_FILE_NAME: &str = "assets.jsonl";
+pub const ANALYTICS_BALANCES_FILE_NAME: &str = "balances.jsonl";
+pub const ANALYTICS_HOLDERS_FILE_NAME: &str = "holders.jsonl";
+pub const ANALYTICS_POOLS_FILE_NAME: &str = "pools.jsonl";
+pub const ANALYTICS_EVENTS_FILE_NAME: &str = "events.jsonl";
+
+/// Block the export was taken at. `state_root` is the Atomic state hash recorded for `at_block_hash`.
+#[derive(Clone, Debug)]
+pub struct AnalyticsExportAnchor {
+    pub network_id: String,
+    pub protocol_version: u16,
+    pub at_block_hash: BlockHash,
+    pub at_daa_score: u64,
+    pub state_root: [u8; 32],
+    /// Last event sequence applied at `at_block_hash`; later events are left out.
+    pub event_sequence_cutoff: u64,
+    pub exported_at: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct AnalyticsExportFile {
+    pub name: String,
+    pub rows: u64,
+    pub bytes: u64,
+    pub blake2b_256: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct AnalyticsExportManifest {
+    pub format: String,
+    pub format_version: u32,
+    pub network_id: String,
+    pub protocol_version: u16,
+    pub at_block_hash: BlockHash,
+    pub at_daa_score: u64,
+    pub state_root: String,
+    pub event_sequence_cutoff: u64,
+    pub exported_at: u64,
+    pub files: Vec<AnalyticsExportFile>,
+}
+
+#[derive(Serialize)]
+struct AnalyticsAssetRow {
+    asset_id: String,
+    asset_class: TokenAssetClass,
+    token_version: u8,
+    creator_owner_id: String,
+    mint_authority_owner_id: String,
+    decimals: u8,
+    supply_mode: u8,
+    max_supply: String,
+    total_supply: String,
+    name: String,
+    symbol: String,
+    platform_tag: String,
+    metadata_hex: String,
+    metadata_revisions: u64,
+    created_block_hash: Option<BlockHash>,
+    created_daa_score: Option<u64>,
+    created_at: Option<u64>,
+}
+
+#[derive(Serialize)]
+struct AnalyticsBalanceRow {
+    asset_id: String,
+    owner_id: String,
+    balance: String,
+    locked_balance: String,
+}
+
+#[derive(Serialize)]
+struct AnalyticsHolderRow {
+    asset_id: String,
+    holder_count: u64,
+    held_supply: String,
+}
+
+#[derive(Serialize)]
+struct AnalyticsPoolRow {
+    pool_id: String,
+    pool_class: TokenAssetClass,
+    fee_bps: u16,
+    total_shares: String,
+    asset_a_id: Option<String>,
+    asset_b_id: Option<String>,
+    reserve_a: Option<String>,
+    reserve_b: Option<String>,
+    accrued_fee_a: Option<String>,
+    accrued_fee_b: Option<String>,
+    real_cpay_reserves_sompi: Option<u64>,
+    real_token_reserves: Option<String>,
+    virtual_cpay_reserves_sompi: Option<u64>,
+    virtual_token_reserves: Option<String>,
+    unclaimed_fee_total_sompi: Option<u64>,
+    unlocked: Option<bool>,
+}
+
+#[derive(Serialize)]
+struct AnalyticsEventRow {
+    sequence: u64,
+    event_id: String,
+    accepting_block_hash: BlockHash,
+    txid: BlockHash,
+    event_type: u8,
+    apply_status: u8,
+    noop_reason: u16,
+    ordinal: u32,
+    reorg_of_event_id: Option<String>,
+    op_type: Option<u8>,
+    asset_id: Option<String>,
+    from_owner_id: Option<String>,
+    to_owner_id: Option<String>,
+    amount: Option<String>,
+    trade_side: Option<&'static str>,
+    trade_price_sompi: Option<u64>,
+    trade_cpay_volume_sompi: Option<u64>,
+    trade_fee_sompi: Option<u64>,
+}
+
+impl AnalyticsAssetRow {
+    fn new(asset: &TokenAsset) -> Self {
+        Self {
+            asset_id: hex_encode(asset.asset_id),
+            asset_class: asset.asset_class,
+            token_version: asset.token_version,
+            creator_owner_id: hex_encode(asset.creator_owner_id),
+            mint_authority_owner_id: hex_encode(asset.mint_authority_owner_id),
+            decimals: asset.decimals,
+            supply_mode: asset.supply_mode as u8,
+            max_supply: asset.max_supply.to_string(),
+            total_supply: asset.total_supply.to_string(),
+            name: String::from_utf8_lossy(&asset.name).into_owned(),
+            symbol: String::from_utf8_lossy(&asset.symbol).into_owned(),
+            platform_tag: String::from_utf8_lossy(&asset.platform_tag).into_owned(),
+            metadata_hex: hex_encode(&asset.metadata),
+            metadata_revisions: asset.metadata_history.last().map(|revision| revision.revision).unwrap_or(0),
+            created_block_hash: asset.created_block_hash,
+            created_daa_score: asset.created_daa_score,
+            created_at: asset.created_at,
+        }
+    }
+}
+
+impl AnalyticsPoolRow {
+    fn new(asset: &TokenAsset, store: &AtomicStorageV2) -> AtomicTokenResult<Option<Self>> {
+        let mut row = Self {
+            pool_id: hex_encode(asset.asset_id),
+            pool_class: asset.asset_class,
+            fee_bps: 0,
+            total_shares: asset.total_supply.to_string(),
+            asset_a_id: None,
+            asset_b_id: None,
+            reserve_a: None,
+            reserve_b: None,
+            accrued_fee_a: None,
+            accrued_fee_b: None,
+            real_cpay_reserves_sompi: None,
+            real_token_reserves: None,
+            virtual_cpay_reserves_sompi: None,
+            virtual_token_reserves: None,
+            unclaimed_fee_total_sompi: None,
+            unlocked: None,
+        };
+        if let Some(pair) = asset.pair_pool.as_ref() {
+            row.fee_bps = pair.fee_bps;
+            row.asset_a_id = Some(hex_encode(pair.asset_a_id));
+            row.asset_b_id = Some(hex_encode(pair.asset_b_id));
+            row.reserve_a = Some(store.get_balance(&pair.reserve_key_a(asset.asset_id))?.to_string());
+            row.reserve_b = Some(store.get_balance(&pair.reserve_key_b(asset.asset_id))?.to_string());
+            row.accrued_fee_a = Some(pair.accrued_fee_a.to_string());
+            row.accrued_fee_b = Some(pair.accrued_fee_b.to_string());
+        } else if let Some(pool) = asset.liquidity.as_ref() {
+            row.fee_bps = pool.fee_bps;
+            row.real_cpay_reserves_sompi = Some(pool.real_cpay_reserves_sompi);
+            row.real_token_reserves = Some(pool.real_token_reserves.to_string());
+            row.virtual_cpay_reserves_sompi = Some(pool.virtual_cpay_reserves_sompi);
+            row.virtual_token_reserves = Some(pool.virtual_token_reserves.to_string());
+            row.unclaimed_fee_total_sompi = Some(pool.unclaimed_fee_total_sompi);
+            row.unlocked = Some(pool.unlocked);
+        } else {
+            return Ok(None);
+        }
+        Ok(Some(row))
+    }
+}
+
+impl AnalyticsEventRow {
+    fn new(event: &TokenEvent) -> Self {
+        let details = &event.details;
+        let trade = details.trade.as_ref();
+        Self {
+            sequence: event.sequence,
+            event_id: hex_encode(event.event_id),
+            accepting_block_hash: event.accepting_block_hash,
+            txid: event.txid,
+            event_type: event.event_type as u8,
+            apply_status: event.apply_status as u8,
+            noop_reason: event.noop_reason as u16,
+            ordinal: event.ordinal,
+            reorg_of_event_id: event.reorg_of_event_id.map(hex_encode),
+            op_type: details.op_type.map(|op| op as u8),
+            asset_id: details.asset_id.map(hex_encode),
+            from_owner_id: details.from_owner_id.map(hex_encode),
+            to_owner_id: details.to_owner_id.map(hex_encode),
+            amount: details.amount.map(|amount| amount.to_string()),
+            trade_side: trade.map(|trade| match trade.side {
+                LiquidityTradeSide::Buy => "buy",
+                LiquidityTradeSide::Sell => "sell",
+            }),
+            trade_price_sompi: trade.map(|trade| trade.price_sompi),
+            trade_cpay_volume_sompi: trade.map(|trade| trade.cpay_volume_sompi),
+            trade_fee_sompi: trade.map(|trade| trade.fee_sompi),
+        }
+    }
+}
+
+/// JSONL file writer that counts rows and hashes exactly the bytes written.
+struct JsonlFileWriter {
+    name: &'static str,
+    writer: BufWriter<File>,
+    hasher: Blake2bState,
+    rows: u64,
+    bytes: u64,
+}
+
+impl JsonlFileWriter {
+    fn create(dir: &Path, name: &'static str) -> AtomicTokenResult<Self> {
+        let path = dir.join(name);
+        let file = File::create(&path)
+            .map_err(|e| AtomicTokenError::Processing(format!("analytics export failed: create `{}`: {e}", path.display())))?;
+        Ok(Self { name, writer: BufWriter::new(file), hasher: Blake2bParams::new().hash_length(32).to_state(), rows: 0, bytes: 0 })
+    }
+
+    fn write_row<T: Serialize>(&mut self, row: &T) -> AtomicTokenResult<()> {
+        let mut line = serde_json::to_vec(row)
+            .map_err(|e| AtomicTokenError::Processing(format!("analytics export failed: encode `{}` row: {e}", self.name)))?;
+        line.push(b'\n');
+        self.writer
+            .write_all(&line)
+            .map_err(|e| AtomicTokenError::Processing(format!("analytics export failed: write `{}`: {e}", self.name)))?;
+        self.hasher.update(&line);
+        self.rows += 1;
+        self.bytes += line.len() as u64;
+        Ok(())
+    }
+
+    fn finish(mut self) -> AtomicTokenResult<AnalyticsExportFile> {
+        self.writer
+            .flush()
+            .map_err(|e| AtomicTokenError::Processing(format!("analytics export failed: flush `{}`: {e}", self.name)))?;
+        Ok(AnalyticsExportFile {
+            name: self.name.to_string(),
+            rows: self.rows,
+            bytes: self.bytes,
+            blake2b_256: self.hasher.finalize().to_hex().to_string(),
+        })
+    }
+}
+
+/// Writes the analytics files for the current state of `store` into `dir`, then `manifest.json` last so an
+/// interrupted export never leaves a manifest behind. `store` must already be rolled back to `anchor.at_block_hash`.
+pub(crate) fn write_analytics_export(
+    dir: &Path,
+    anchor: &AnalyticsExportAnchor,
+    store: &AtomicStorageV2,
+) -> AtomicTokenResult<AnalyticsExportManifest> {
+    std::fs::create_dir_all(dir)
+        .map_err(|e| AtomicTokenError::Processing(format!("analytics export failed: create `{}`: {e}", dir.display())))?;
+    let manifest_path = dir.join(ANALYTICS_MANIFEST_FILE_NAME);
+    if manifest_path.exists() {
+        return Err(AtomicTokenError::Processing(format!("analytics export failed: `{}` already contains an export", dir.display())));
+    }
+
+    let mut files = Vec::with_capacity(5);
+    let mut pools = Vec::new();
+    let mut assets = JsonlFileWriter::create(dir, ANALYTICS_ASSETS_FILE_NAME)?;
+    store.visit_all_assets(|_, asset| {
+        if let Some(pool) = AnalyticsPoolRow::new(&asset, store)? {
+            pools.push(pool);
+        }
+        assets.write_row(&AnalyticsAssetRow::new(&asset))
+    })?;
+    files.push(assets.finish()?);
+
+    let mut locks = BTreeMap::<BalanceKey, u128>::new();
+    store.visit_all_locks(|key, lock| {
+        locks.insert(key, lock.locked_at(anchor.at_daa_score));
+        Ok(())
+    })?;
+    let mut holders = BTreeMap::<[u8; 32], (u64, u128)>::new();
+    let mut balances = JsonlFileWriter::create(dir, ANALYTICS_BALANCES_FILE_NAME)?;
+    store.visit_all_balances(|key, amount| {
+        let holder = holders.entry(key.asset_id).or_default();
+        holder.0 += 1;
+        holder.1 = holder.1.saturating_add(amount);
+        balances.write_row(&AnalyticsBalanceRow {
+            asset_id: hex_encode(key.asset_id),
+            owner_id: hex_encode(key.owner_id),
+            balance: amount.to_string(),
+            locked_balance: locks.get(&key).copied().unwrap_or(0).to_string(),
+        })
+    })?;
+    files.push(balances.finish()?);
+
+    let mut holders_file = JsonlFileWriter::create(dir, ANALYTICS_HOLDERS_FILE_NAME)?;
+    for (asset_id, (holder_count, held_supply)) in holders {
+        holders_file.write_row(&AnalyticsHolderRow {
+            asset_id: hex_encode(asset_id),
+            holder_count,
+            held_supply: held_supply.to_string(),
+        })?;
+    }
+    files.push(holders_file.finish()?);
+
+    let mut pools_file = JsonlFileWriter::create(dir, ANALYTICS_POOLS_FILE_NAME)?;
+    for pool in pools.iter() {
+        pools_file.write_row(pool)?;
+    }
+    files.push(pools_file.finish()?);
+
+    let mut events = JsonlFileWriter::create(dir, ANALYTICS_EVENTS_FILE_NAME)?;
+    store.visit_all_events(|event| {
+        if event.sequence > anchor.event_sequence_cutoff {
+            return Ok(());
+        }
+        events.write_row(&AnalyticsEventRow::new(&event))
+    })?;
+    files.push(events.finish()?);
+
+    let manifest = AnalyticsExportManifest {
+        format: ANALYTICS_EXPORT_FORMAT.to_string(),
+        format_version: ANALYTICS_EXPORT_FORMAT_VERSION,
+        network_id: anchor.network_id.clone(),
+        protocol_version: anchor.protocol_version,
+        at_block_hash: anchor.at_block_hash,
+        at_daa_score: anchor.at_daa_score,
+        state_root: hex_encode(anchor.state_root),
+        event_sequence_cutoff: anchor.event_sequence_cutoff,
+        exported_at: anchor.exported_at,
+        files,
+    };
+    let manifest_bytes = serde_json::to_vec_pretty(&manifest)
+        .map_err(|e| AtomicTokenError::Processing(format!("analytics export failed: encode manifest: {e}")))?;
+    std::fs::write(&manifest_path, manifest_bytes)
+        .map_err(|e| AtomicTokenError::Processing(format!("analytics export failed: write `{}`: {e}", manifest_path.display())))?;
+    Ok(manifest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        payload::{ApplyStatus, EventType, NoopReason, SupplyMode, TokenOpCode},
+        state::{AtomicTokenState, PairPoolState, TokenEventDetails, TokenLock},
+    };
+    use std::{
+        fs,
+        path::PathBuf,
+        time::{SystemTime, UNIX_EPOCH},
+    };
+
+    fn unique_temp_dir(label: &str) -> PathBuf {
+        let nanos = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+        let dir = std::env::temp_dir().join(format!("cryptix-atomicindex-{label}-{nanos}"));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn test_asset(asset_id: [u8; 32], total_supply: u128) -> TokenAsset {
+        TokenAsset {
+            asset_id,
+            creator_owner_id: [1u8; 32],
+            asset_class: TokenAssetClass::Standard,
+            token_version: 1,
+            mint_authority_owner_id: [1u8; 32],
+            decimals: 8,
+            supply_mode: SupplyMode::Uncapped,
+            max_supply: 0,
+            total_supply,
+            name: b"Analytics".to_vec(),
+            symbol: b"ANL".to_vec(),
+            metadata: vec![0xab],
+            platform_tag: Vec::new(),
+            created_block_hash: None,
+            created_daa_score: None,
+            created_at: None,
+            liquidity: None,
+            pair_pool: None,
+            metadata_history: Vec::new(),
+        }
+    }
+
+    fn test_event(sequence: u64, asset_id: [u8; 32]) -> TokenEvent {
+        TokenEvent {
+            event_id: [sequence as u8; 32],
+            sequence,
+            accepting_block_hash: BlockHash::from_u64_word(sequence),
+            txid: BlockHash::from_u64_word(100 + sequence),
+            event_type: EventType::Applied,
+            apply_status: ApplyStatus::Applied,
+            noop_reason: NoopReason::None,
+            ordinal: 0,
+            reorg_of_event_id: None,
+            details: TokenEventDetails {
+                op_type: Some(TokenOpCode::Transfer),
+                asset_id: Some(asset_id),
+                amount: Some(5),
+                ..Default::default()
+            },
+        }
+    }
+
+    #[test]
+    fn analytics_export_writes_rows_and_manifest_digests() {
+        let dir = unique_temp_dir("analytics-export");
+        let store_dir = dir.join("store");
+        let export_dir = dir.join("export");
+        let store =
+            AtomicStorageV2::open(&store_dir, 6, "cryptix-simnet".to_string(), BlockHash::from_u64_word(1)).expect("open store");
+
+        let token_a = [2u8; 32];
+        let token_b = [3u8; 32];
+        let pool_id = [4u8; 32];
+        let alice = [5u8; 32];
+        let bob = [6u8; 32];
+        let mut pool = test_asset(pool_id, 1_000);
+        pool.asset_class = TokenAssetClass::Pair;
+        pool.pair_pool =
+            Some(PairPoolState { asset_a_id: token_a, asset_b_id: token_b, fee_bps: 30, accrued_fee_a: 7, accrued_fee_b: 0 });
+
+        let mut state = AtomicTokenState::new(6, "cryptix-simnet".to_string());
+        state.assets.insert(token_a, test_asset(token_a, 600));
+        state.assets.insert(token_b, test_asset(token_b, 900));
+        state.assets.insert(pool_id, pool);
+        state.balances.insert(BalanceKey { asset_id: token_a, owner_id: alice }, 400);
+        state.balances.insert(BalanceKey { asset_id: token_a, owner_id: bob }, 100);
+        state.balances.insert(BalanceKey { asset_id: token_a, owner_id: pool_id }, 100);
+        state.balances.insert(BalanceKey { asset_id: token_b, owner_id: pool_id }, 900);
+        state.balances.insert(BalanceKey { asset_id: pool_id, owner_id: alice }, 1_000);
+        state.locks.insert(
+            BalanceKey { asset_id: token_a, owner_id: alice },
+            TokenLock { amount: 200, release_start_daa_score: 1_000, release_end_daa_score: 2_000 },
+        );
+        state.events = vec![test_event(1, token_a), test_event(2, token_a), test_event(3, token_a)];
+        state.next_event_sequence = 3;
+        store.persist_state(&state).expect("persist state");
+
+        let anchor = AnalyticsExportAnchor {
+            network_id: "cryptix-simnet".to_string(),
+            protocol_version: 6,
+            at_block_hash: BlockHash::from_u64_word(2),
+            at_daa_score: 1_500,
+            state_root: [9u8; 32],
+            event_sequence_cutoff: 2,
+            exported_at: 1_700_000_000_000,
+        };
+        let manifest = write_analytics_export(&export_dir, &anchor, &store).expect("analytics export");
+
+        let rows = |name: &str| -> Vec<serde_json::Value> {
+            fs::read_to_string(export_dir.join(name))
+                .unwrap()
+                .lines()
+                .map(|line| serde_json::from_str(line).expect("row is JSON"))
+                .collect()
+        };
+        assert_eq!(rows(ANALYTICS_ASSETS_FILE_NAME).len(), 3);
+        let balances = rows(ANALYTICS_BALANCES_FILE_NAME);
+        assert_eq!(balances.len(), 5);
+        let alice_a = balances.iter().find(|row| row["owner_id"] == hex_encode(alice) && row["asset_id"] == hex_encode(token_a));
+        assert_eq!(alice_a.unwrap()["locked_balance"], "100");
+        let holders = rows(ANALYTICS_HOLDERS_FILE_NAME);
+        let holders_a = holders.iter().find(|row| row["asset_id"] == hex_encode(token_a)).unwrap();
+        assert_eq!(holders_a["holder_count"], 3);
+        assert_eq!(holders_a["held_supply"], "600");
+        let pools = rows(ANALYTICS_POOLS_FILE_NAME);
+        assert_eq!(pools.len(), 1);
+        assert_eq!(pools[0]["reserve_a"], "100");
+        assert_eq!(pools[0]["reserve_b"], "900");
+        let events = rows(ANALYTICS_EVENTS_FILE_NAME);
+        assert_eq!(events.iter().map(|row| row["sequence"].as_u64().unwrap()).collect::<Vec<_>>(), vec![1, 2]);
+
+        let written: serde_json::Value =
+            serde_json::from_slice(&fs::read(export_dir.join(ANALYTICS_MANIFEST_FILE_NAME)).unwrap()).unwrap();
+        assert_eq!(written["state_root"], hex_encode([9u8; 32]));
+        assert_eq!(written["at_block_hash"], BlockHash::from_u64_word(2).to_string());
+        for file in manifest.files.iter() {
+            let bytes = fs::read(export_dir.join(&file.name)).unwrap();
+            assert_eq!(file.bytes, bytes.len() as u64);
+            assert_eq!(file.blake2b_256, Blake2bParams::new().hash_length(32).hash(&bytes).to_hex().to_string());
+        }
+
+        let err = write_analytics_export(&export_dir, &anchor, &store).expect_err("existing export must not be overwritten");
+        assert!(err.to_string().contains("already contains an export"));
+
+        drop(store);
+        let _ = fs::remove_dir_all(dir);
+    }
+}
@@ -3,11 +3,12 @@ use crate::{
     liquidity_math::{validate_liquidity_curve_mode, validate_liquidity_curve_parameters},
     payload::{
         SupplyMode, CURRENT_LIQUIDITY_CURVE_VERSION, CURRENT_TOKEN_VERSION, MAX_DECIMALS, MAX_LIQUIDITY_FEE_BPS,
-        MAX_LIQUIDITY_RECIPIENTS, MAX_METADATA_LEN, MAX_NAME_LEN, MAX_PLATFORM_TAG_LEN, MAX_SYMBOL_LEN, MIN_LIQUIDITY_FEE_BPS,
+        MAX_LIQUIDITY_RECIPIENTS, MAX_METADATA_LEN, MAX_NAME_LEN, MAX_PAIR_FEE_BPS, MAX_PLATFORM_TAG_LEN, MAX_SYMBOL_LEN,
+        MIN_LIQUIDITY_FEE_BPS,
     },
     state::{
-        AtomicTokenState, BalanceKey, LiquidityFeeRecipientState, LiquidityHolderAddressState, LiquidityPoolState, NonceKey,
-        TokenAsset, TokenAssetClass,
+        AllowanceKey, AtomicTokenState, BalanceKey, LiquidityFeeRecipientState, LiquidityHolderAddressState, LiquidityPoolState,
+        NonceKey, PairPoolState, TokenAllowance, TokenAsset, TokenAssetClass, TokenLock,
     },
 };
 use cryptix_consensus_core::{tx::TransactionOutpoint, Hash as BlockHash};
@@ -15,6 +16,8 @@ use std::collections::HashMap;
 
 const ATOMIC_CONSENSUS_STATE_MAGIC: &[u8] = b"CATCSG02";
 const ATOMIC_CONSENSUS_STATE_ROOT_ONLY_TAG: &[u8] = b"ROOT";
+const ATOMIC_CONSENSUS_STATE_LOCK_SECTION: u8 = b'k';
+const ATOMIC_CONSENSUS_STATE_ALLOWANCE_SECTION: u8 = b'l';
 
 pub(crate) fn token_state_from_consensus_canonical_bytes(
     bytes: &[u8],
@@ -73,6 +76,50 @@ pub(crate) fn token_state_from_consensus_canonical_bytes(
         }
     }
 
+    // Optional trailing sections, tagged and in ascending tag order; empty sections are omitted.
+    let mut last_section_tag = None;
+    while !reader.is_finished() {
+        let tag = reader.read_u8()?;
+        if last_section_tag.is_some_and(|last| tag <= last) {
+            return Err(import_error("consensus Atomic state sections are out of order"));
+        }
+        last_section_tag = Some(tag);
+        let section_len = reader.read_len_usize("section length")?;
+        if section_len == 0 {
+            return Err(import_error("empty consensus Atomic state section must be omitted"));
+        }
+        match tag {
+            ATOMIC_CONSENSUS_STATE_LOCK_SECTION => {
+                for _ in 0..section_len {
+                    let asset_id = reader.read_hash32()?;
+                    let owner_id = reader.read_hash32()?;
+                    let key = BalanceKey { asset_id, owner_id };
+                    let amount = reader.read_u128()?;
+                    let release_start_daa_score = reader.read_u64()?;
+                    let release_end_daa_score = reader.read_u64()?;
+                    let lock = TokenLock { amount, release_start_daa_score, release_end_daa_score };
+                    if state.locks.insert(key, lock).is_some() {
+                        return Err(import_error("duplicate consensus Atomic lock key"));
+                    }
+                }
+            }
+            ATOMIC_CONSENSUS_STATE_ALLOWANCE_SECTION => {
+                for _ in 0..section_len {
+                    let asset_id = reader.read_hash32()?;
+                    let owner_id = reader.read_hash32()?;
+                    let spender_id = reader.read_hash32()?;
+                    let key = AllowanceKey { asset_id, owner_id, spender_id };
+                    let amount = reader.read_u128()?;
+                    let expires_at_daa_score = reader.read_u64()?;
+                    if state.allowances.insert(key, TokenAllowance { amount, expires_at_daa_score }).is_some() {
+                        return Err(import_error("duplicate consensus Atomic allowance key"));
+                    }
+                }
+            }
+            _ => return Err(import_error("unknown consensus Atomic state section")),
+        }
+    }
+
     reader.finish()?;
     state.rebuild_runtime_caches();
     Ok(state)
@@ -191,6 +238,7 @@ impl<'a> AtomicStateReader<'a> {
         let asset_class = match self.read_u8()? {
             0 => TokenAssetClass::Standard,
             1 => TokenAssetClass::Liquidity,
+            2 => TokenAssetClass::Pair,
             raw => return Err(import_error(format!("invalid asset class `{raw}`"))),
         };
         let token_version = self.read_u8()?;
@@ -227,6 +275,15 @@ impl<'a> AtomicStateReader<'a> {
             1 => Some(self.read_liquidity_pool()?),
             raw => return Err(import_error(format!("invalid liquidity presence flag `{raw}`"))),
         };
+        let pair_pool = if asset_class == TokenAssetClass::Pair {
+            match self.read_u8()? {
+                0 => None,
+                1 => Some(self.read_pair_pool()?),
+                raw => return Err(import_error(format!("invalid pair pool presence flag `{raw}`"))),
+            }
+        } else {
+            None
+        };
 
         Ok(TokenAsset {
             asset_id,
@@ -246,9 +303,23 @@ impl<'a> AtomicStateReader<'a> {
             created_daa_score,
             created_at,
             liquidity,
+            pair_pool,
+            metadata_history: Vec::new(),
         })
     }
 
+    fn read_pair_pool(&mut self) -> AtomicTokenResult<PairPoolState> {
+        let asset_a_id = self.read_hash32()?;
+        let asset_b_id = self.read_hash32()?;
+        let fee_bps = self.read_u16()?;
+        if fee_bps > MAX_PAIR_FEE_BPS {
+            return Err(import_error(format!("pair fee_bps `{fee_bps}` exceeds max `{MAX_PAIR_FEE_BPS}`")));
+        }
+        let accrued_fee_a = self.read_u128()?;
+        let accrued_fee_b = self.read_u128()?;
+        Ok(PairPoolState { asset_a_id, asset_b_id, fee_bps, accrued_fee_a, accrued_fee_b })
+    }
+
     fn read_liquidity_pool(&mut self) -> AtomicTokenResult<LiquidityPoolState> {
         let pool_nonce = self.read_u64()?;
         let curve_version = self.read_u8()?;
@@ -322,6 +393,10 @@ impl<'a> AtomicStateReader<'a> {
         })
     }
 
+    fn is_finished(&self) -> bool {
+        self.cursor == self.bytes.len()
+    }
+
     fn finish(&self) -> AtomicTokenResult<()> {
         if self.cursor == self.bytes.len() {
             Ok(())
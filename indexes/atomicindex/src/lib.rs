@@ -1,5 +1,6 @@
 mod consensus_state_import;
 
+pub mod analytics_export;
 pub mod error;
 pub mod liquidity_math;
 pub mod payload;
@@ -27,6 +27,8 @@ pub const INDIVIDUAL_MIN_VIRTUAL_TOKEN_MULTIPLIER_BPS: u16 = 10_100;
 pub const INDIVIDUAL_MAX_VIRTUAL_TOKEN_MULTIPLIER_BPS: u16 = 20_000;
 pub const INDIVIDUAL_VIRTUAL_TOKEN_MULTIPLIER_STEP_BPS: u16 = 100;
 pub const VIRTUAL_TOKEN_MULTIPLIER_BPS_DENOMINATOR: u16 = 10_000;
+pub const MIN_PAIR_LIQUIDITY_SHARES: u128 = 1_000;
+pub const PAIR_FEE_BPS_DENOMINATOR: u64 = 10_000;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum LiquidityMathError {
@@ -320,6 +322,81 @@ pub fn cpmm_sell(
     Ok((gross_out, new_real_cpay_reserves, x_after, y_after))
 }
 
+/// Exact-out counterpart of `cpmm_buy`: takes exactly `token_out` off the curve and returns the
+/// smallest net CPAY input (plus the new reserves) that keeps `x * y` from shrinking.
+pub fn cpmm_buy_exact_out(
+    real_token_reserves: u128,
+    virtual_cpay_reserves_sompi: u64,
+    virtual_token_reserves: u128,
+    token_out: u128,
+) -> Result<(u64, u128, u64, u128), LiquidityMathError> {
+    if token_out == 0 || virtual_cpay_reserves_sompi == 0 || virtual_token_reserves == 0 {
+        return Err(LiquidityMathError::InvalidInput);
+    }
+    let spendable_tokens = real_token_reserves.checked_sub(MIN_REAL_TOKEN_RESERVE).ok_or(LiquidityMathError::InvalidInput)?;
+    if token_out > spendable_tokens {
+        return Err(LiquidityMathError::InvalidInput);
+    }
+    let y_after = virtual_token_reserves.checked_sub(token_out).ok_or(LiquidityMathError::InvalidInput)?;
+    if y_after == 0 {
+        return Err(LiquidityMathError::InvalidInput);
+    }
+
+    let k = Uint256::from_u64(virtual_cpay_reserves_sompi) * Uint256::from_u128(virtual_token_reserves);
+    let x_after_u256 = ceil_div_u256(k, Uint256::from_u128(y_after));
+    let x_after_u128 = u128::try_from(x_after_u256).map_err(|_| LiquidityMathError::Overflow)?;
+    let x_after = u64::try_from(x_after_u128).map_err(|_| LiquidityMathError::Overflow)?;
+    let net_in = x_after.checked_sub(virtual_cpay_reserves_sompi).ok_or(LiquidityMathError::InvalidState)?;
+    if net_in == 0 {
+        return Err(LiquidityMathError::ZeroOutput);
+    }
+    let new_real_token_reserves = real_token_reserves.checked_sub(token_out).ok_or(LiquidityMathError::Overflow)?;
+    Ok((net_in, new_real_token_reserves, x_after, y_after))
+}
+
+/// Exact-out counterpart of `cpmm_sell`: takes exactly `gross_out` sompi off the curve and returns the
+/// smallest token input (plus the new reserves) that keeps `x * y` from shrinking.
+pub fn cpmm_sell_exact_out(
+    real_cpay_reserves_sompi: u64,
+    virtual_cpay_reserves_sompi: u64,
+    virtual_token_reserves: u128,
+    gross_out: u64,
+) -> Result<(u128, u64, u64, u128), LiquidityMathError> {
+    if gross_out == 0 || virtual_token_reserves == 0 {
+        return Err(LiquidityMathError::InvalidInput);
+    }
+    let spendable_cpay = real_cpay_reserves_sompi.checked_sub(MIN_CPAY_RESERVE_SOMPI).ok_or(LiquidityMathError::InvalidInput)?;
+    if gross_out > spendable_cpay {
+        return Err(LiquidityMathError::InvalidInput);
+    }
+    let x_after = virtual_cpay_reserves_sompi.checked_sub(gross_out).ok_or(LiquidityMathError::InvalidInput)?;
+    if x_after == 0 {
+        return Err(LiquidityMathError::InvalidInput);
+    }
+
+    let k = Uint256::from_u64(virtual_cpay_reserves_sompi) * Uint256::from_u128(virtual_token_reserves);
+    let y_after_u256 = ceil_div_u256(k, Uint256::from_u64(x_after));
+    let y_after = u128::try_from(y_after_u256).map_err(|_| LiquidityMathError::Overflow)?;
+    let token_in = y_after.checked_sub(virtual_token_reserves).ok_or(LiquidityMathError::InvalidState)?;
+    if token_in == 0 {
+        return Err(LiquidityMathError::ZeroOutput);
+    }
+    let new_real_cpay_reserves = real_cpay_reserves_sompi.checked_sub(gross_out).ok_or(LiquidityMathError::Overflow)?;
+    Ok((token_in, new_real_cpay_reserves, x_after, y_after))
+}
+
+/// Splits the canonical gross amount of an exact-out trade into `(gross, fee)` so that
+/// `gross - fee == net` exactly. The trade fee is always taken from the CPAY side, so the same
+/// search serves the buy input and the sell output.
+pub fn gross_amount_for_net_amount(net: u64, fee_bps: u16) -> Result<(u64, u64), LiquidityMathError> {
+    let gross = min_gross_input_for_net_input(net, fee_bps)?;
+    let fee = calculate_trade_fee(gross, fee_bps)?;
+    if gross.checked_sub(fee).ok_or(LiquidityMathError::Overflow)? != net {
+        return Err(LiquidityMathError::InvalidState);
+    }
+    Ok((gross, fee))
+}
+
 pub fn max_buy_in_sompi(
     real_token_reserves: u128,
     virtual_cpay_reserves_sompi: u64,
@@ -337,6 +414,127 @@ pub fn max_tokens_out(real_token_reserves: u128) -> u128 {
     real_token_reserves.saturating_sub(MIN_REAL_TOKEN_RESERVE)
 }
 
+fn pair_u256_to_u128(value: Uint256) -> Result<u128, LiquidityMathError> {
+    u128::try_from(value).map_err(|_| LiquidityMathError::Overflow)
+}
+
+pub fn isqrt_u256(value: Uint256) -> Uint256 {
+    if value.is_zero() {
+        return value;
+    }
+    let mut x = value;
+    let mut y = (x + Uint256::from_u64(1)) >> 1;
+    while y < x {
+        x = y;
+        y = (x + value / x) >> 1;
+    }
+    x
+}
+
+pub fn calculate_pair_fee(amount: u128, fee_bps: u16) -> Result<u128, LiquidityMathError> {
+    pair_u256_to_u128(Uint256::from_u128(amount) * Uint256::from_u64(u64::from(fee_bps)) / Uint256::from_u64(PAIR_FEE_BPS_DENOMINATOR))
+}
+
+/// Shares minted for a pool seed, including the `MIN_PAIR_LIQUIDITY_SHARES` that stay locked in the pool.
+pub fn pair_initial_shares(amount_a: u128, amount_b: u128) -> Result<u128, LiquidityMathError> {
+    let shares = pair_u256_to_u128(isqrt_u256(Uint256::from_u128(amount_a) * Uint256::from_u128(amount_b)))?;
+    if shares <= MIN_PAIR_LIQUIDITY_SHARES {
+        return Err(LiquidityMathError::InvalidInput);
+    }
+    Ok(shares)
+}
+
+/// Returns `(shares_out, amount_a_in, amount_b_in)` for a proportional deposit capped by both maxima.
+pub fn pair_add_liquidity(
+    reserve_a: u128,
+    reserve_b: u128,
+    total_shares: u128,
+    amount_a_max: u128,
+    amount_b_max: u128,
+) -> Result<(u128, u128, u128), LiquidityMathError> {
+    if reserve_a == 0 || reserve_b == 0 || total_shares == 0 {
+        return Err(LiquidityMathError::InvalidState);
+    }
+    let total = Uint256::from_u128(total_shares);
+    let shares_a = Uint256::from_u128(amount_a_max) * total / Uint256::from_u128(reserve_a);
+    let shares_b = Uint256::from_u128(amount_b_max) * total / Uint256::from_u128(reserve_b);
+    let shares = pair_u256_to_u128(shares_a.min(shares_b))?;
+    if shares == 0 {
+        return Err(LiquidityMathError::ZeroOutput);
+    }
+    let amount_a_in = pair_u256_to_u128(ceil_div_u256(Uint256::from_u128(shares) * Uint256::from_u128(reserve_a), total))?;
+    let amount_b_in = pair_u256_to_u128(ceil_div_u256(Uint256::from_u128(shares) * Uint256::from_u128(reserve_b), total))?;
+    Ok((shares, amount_a_in, amount_b_in))
+}
+
+/// Returns `(amount_a_out, amount_b_out)` for redeeming `shares` against the current reserves.
+pub fn pair_remove_liquidity(
+    reserve_a: u128,
+    reserve_b: u128,
+    total_shares: u128,
+    shares: u128,
+) -> Result<(u128, u128), LiquidityMathError> {
+    if shares == 0 || total_shares <= MIN_PAIR_LIQUIDITY_SHARES || shares > total_shares - MIN_PAIR_LIQUIDITY_SHARES {
+        return Err(LiquidityMathError::InvalidInput);
+    }
+    let total = Uint256::from_u128(total_shares);
+    let amount_a_out = pair_u256_to_u128(Uint256::from_u128(shares) * Uint256::from_u128(reserve_a) / total)?;
+    let amount_b_out = pair_u256_to_u128(Uint256::from_u128(shares) * Uint256::from_u128(reserve_b) / total)?;
+    if amount_a_out == 0 || amount_b_out == 0 {
+        return Err(LiquidityMathError::ZeroOutput);
+    }
+    Ok((amount_a_out, amount_b_out))
+}
+
+/// Returns `(amount_out, fee)`; the fee is charged on the input side and stays in the pool.
+pub fn pair_swap_exact_in(
+    reserve_in: u128,
+    reserve_out: u128,
+    amount_in: u128,
+    fee_bps: u16,
+) -> Result<(u128, u128), LiquidityMathError> {
+    if reserve_in == 0 || reserve_out == 0 {
+        return Err(LiquidityMathError::InvalidState);
+    }
+    let fee = calculate_pair_fee(amount_in, fee_bps)?;
+    let net_in = amount_in - fee;
+    reserve_in.checked_add(amount_in).ok_or(LiquidityMathError::Overflow)?;
+    let denominator = Uint256::from_u128(reserve_in) + Uint256::from_u128(net_in);
+    let amount_out = pair_u256_to_u128(Uint256::from_u128(reserve_out) * Uint256::from_u128(net_in) / denominator)?;
+    if amount_out == 0 {
+        return Err(LiquidityMathError::ZeroOutput);
+    }
+    Ok((amount_out, fee))
+}
+
+/// Returns the minimal `(amount_in, fee)` whose exact-in swap yields at least `amount_out`.
+pub fn pair_swap_exact_out(
+    reserve_in: u128,
+    reserve_out: u128,
+    amount_out: u128,
+    fee_bps: u16,
+) -> Result<(u128, u128), LiquidityMathError> {
+    if reserve_in == 0 || amount_out == 0 || amount_out >= reserve_out || u64::from(fee_bps) >= PAIR_FEE_BPS_DENOMINATOR {
+        return Err(LiquidityMathError::InvalidInput);
+    }
+    let k = Uint256::from_u128(reserve_in) * Uint256::from_u128(reserve_out);
+    let reserve_in_after = ceil_div_u256(k, Uint256::from_u128(reserve_out - amount_out));
+    let net_in = pair_u256_to_u128(reserve_in_after - Uint256::from_u128(reserve_in))?;
+    // Smallest gross input whose floor fee leaves at least `net_in`.
+    let fee_denominator = Uint256::from_u64(PAIR_FEE_BPS_DENOMINATOR - u64::from(fee_bps));
+    let mut amount_in = pair_u256_to_u128(
+        Uint256::from_u128(net_in - 1) * Uint256::from_u64(PAIR_FEE_BPS_DENOMINATOR) / fee_denominator + Uint256::from_u64(1),
+    )?;
+    while amount_in - calculate_pair_fee(amount_in, fee_bps)? < net_in {
+        amount_in = amount_in.checked_add(1).ok_or(LiquidityMathError::Overflow)?;
+    }
+    let (actual_out, fee) = pair_swap_exact_in(reserve_in, reserve_out, amount_in, fee_bps)?;
+    if actual_out < amount_out {
+        return Err(LiquidityMathError::InvalidState);
+    }
+    Ok((amount_in, fee))
+}
+
 pub fn ceil_div_u256(numerator: Uint256, denominator: Uint256) -> Uint256 {
     let quotient = numerator / denominator;
     let remainder = numerator % denominator;
@@ -1175,4 +1373,99 @@ mod tests {
             assert_eq!(vault_value, real_cpay + unclaimed_fees);
         }
     }
+
+    #[test]
+    fn pair_math_vectors() {
+        assert_eq!(pair_initial_shares(1_000_000, 4_000_000), Ok(2_000_000));
+        assert_eq!(pair_initial_shares(1_000, 1_000), Err(LiquidityMathError::InvalidInput));
+        assert_eq!(isqrt_u256(Uint256::from_u128(u128::MAX) * Uint256::from_u128(u128::MAX)), Uint256::from_u128(u128::MAX));
+
+        // Deposit is capped by the scarcer side and rounded up in the pool's favour.
+        assert_eq!(pair_add_liquidity(1_000_000, 4_000_000, 2_000_000, 500, 10_000), Ok((1_000, 500, 2_000)));
+        assert_eq!(pair_add_liquidity(3, 7, 5, 1, 2), Ok((1, 1, 2)));
+        assert_eq!(pair_add_liquidity(1_000_000, 4_000_000, 2_000_000, 0, 1), Err(LiquidityMathError::ZeroOutput));
+
+        assert_eq!(pair_remove_liquidity(1_000_000, 4_000_000, 2_000_000, 1_000), Ok((500, 2_000)));
+        assert_eq!(pair_remove_liquidity(1_000_000, 4_000_000, 2_000_000, 1_999_001), Err(LiquidityMathError::InvalidInput));
+        assert_eq!(pair_remove_liquidity(1_000_000, 4_000_000, 2_000_000, 1), Err(LiquidityMathError::ZeroOutput));
+
+        assert_eq!(pair_swap_exact_in(1_000_000, 1_000_000, 10_000, 30), Ok((9_871, 30)));
+        assert_eq!(pair_swap_exact_in(1_000_000, 1_000_000, 10_000, 0), Ok((9_900, 0)));
+        assert_eq!(pair_swap_exact_in(1_000_000, 1_000_000, 1, 30), Err(LiquidityMathError::ZeroOutput));
+    }
+
+    #[test]
+    fn pair_exact_out_is_minimal_and_consistent_with_exact_in() {
+        for (reserve_in, reserve_out, fee_bps) in
+            [(1_000_000u128, 1_000_000u128, 30u16), (7_919, 104_729, 0), (123_456_789, 987, 1_000)]
+        {
+            for amount_out in [1u128, 2, 17, reserve_out / 3, reserve_out - 1] {
+                let Ok((amount_in, fee)) = pair_swap_exact_out(reserve_in, reserve_out, amount_out, fee_bps) else {
+                    continue;
+                };
+                let (actual_out, actual_fee) = pair_swap_exact_in(reserve_in, reserve_out, amount_in, fee_bps).unwrap();
+                assert!(actual_out >= amount_out);
+                assert_eq!(fee, actual_fee);
+                if amount_in > 1 {
+                    let smaller = pair_swap_exact_in(reserve_in, reserve_out, amount_in - 1, fee_bps).map(|(out, _)| out).unwrap_or(0);
+                    assert!(smaller < amount_out, "exact-out input {amount_in} is not minimal");
+                }
+            }
+        }
+        assert_eq!(pair_swap_exact_out(1_000, 1_000, 1_000, 30), Err(LiquidityMathError::InvalidInput));
+    }
+
+    #[test]
+    fn liquidity_exact_out_trades_are_exact_and_minimal() {
+        let fee_schedule = [0u16, 10, 100, 250, 1_000];
+        let mut seed = 0xE4AC_700D_5EED_0001u64;
+
+        for step in 0..5_000 {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            let real_token_reserves = 2 + u128::from(seed % 1_000_000);
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            let virtual_token_reserves = real_token_reserves + 1 + u128::from(seed % 2_000_000);
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            let virtual_cpay_reserves_sompi = 2 + (seed % 800_000_000_000_000);
+            let fee_bps = fee_schedule[step % fee_schedule.len()];
+
+            let token_out = 1 + (u128::from(seed.rotate_left(11)) % (real_token_reserves - 1));
+            let (net_in, remaining_tokens, _, y_after) =
+                cpmm_buy_exact_out(real_token_reserves, virtual_cpay_reserves_sompi, virtual_token_reserves, token_out)
+                    .unwrap_or_else(|err| panic!("case {step}: exact-out buy failed: {err:?}"));
+            assert_eq!(virtual_token_reserves - y_after, token_out, "case {step}: buy must remove exactly token_out");
+            assert_eq!(remaining_tokens, real_token_reserves - token_out);
+            let (gross_in, fee) = gross_amount_for_net_amount(net_in, fee_bps).expect("gross input should split");
+            assert_eq!(gross_in - fee, net_in);
+            let canonical = min_gross_input_for_token_out(
+                real_token_reserves,
+                virtual_cpay_reserves_sompi,
+                virtual_token_reserves,
+                token_out,
+                fee_bps,
+            )
+            .expect("canonical input should calculate");
+            assert_eq!(gross_in, canonical, "case {step}: exact-out buy must charge the canonical exact-in input");
+
+            let real_cpay_reserves_sompi = virtual_cpay_reserves_sompi;
+            let gross_out = 1 + (seed.rotate_left(29) % (virtual_cpay_reserves_sompi - 1));
+            let (token_in, _, x_after, _) =
+                cpmm_sell_exact_out(real_cpay_reserves_sompi, virtual_cpay_reserves_sompi, virtual_token_reserves, gross_out)
+                    .unwrap_or_else(|err| panic!("case {step}: exact-out sell failed: {err:?}"));
+            assert_eq!(virtual_cpay_reserves_sompi - x_after, gross_out, "case {step}: sell must remove exactly gross_out");
+            let (sold_gross_out, _, _, _) =
+                cpmm_sell(real_cpay_reserves_sompi, virtual_cpay_reserves_sompi, virtual_token_reserves, token_in).unwrap();
+            assert!(sold_gross_out >= gross_out, "case {step}: exact-out token input does not cover gross_out");
+            if token_in > 1 {
+                let smaller = cpmm_sell(real_cpay_reserves_sompi, virtual_cpay_reserves_sompi, virtual_token_reserves, token_in - 1)
+                    .map(|(out, _, _, _)| out)
+                    .unwrap_or(0);
+                assert!(smaller < gross_out, "case {step}: exact-out token input {token_in} is not minimal");
+            }
+        }
+
+        assert_eq!(cpmm_buy_exact_out(2, 1_000, 3, 2), Err(LiquidityMathError::InvalidInput));
+        assert_eq!(cpmm_sell_exact_out(100, 1_000, 1, 100), Err(LiquidityMathError::InvalidInput));
+        assert_eq!(gross_amount_for_net_amount(9_900, 100), Ok((9_999, 99)));
+    }
 }
@@ -23,6 +23,11 @@ pub const MAX_DECIMALS: u8 = 18;
 pub const MAX_LIQUIDITY_RECIPIENTS: usize = 2;
 pub const MIN_LIQUIDITY_FEE_BPS: u16 = 10;
 pub const MAX_LIQUIDITY_FEE_BPS: u16 = 1000;
+/// Upper bound on recipients in a single `BatchTransfer`. A full batch is
+/// 16 + 32 + 1 + 64 * 48 = 3,121 payload bytes, below the 8,192 byte consensus
+/// payload limit even after the payload weight multiplier is applied to mass.
+pub const MAX_BATCH_TRANSFER_RECIPIENTS: usize = 64;
+pub const MAX_PAIR_FEE_BPS: u16 = 1000;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[repr(u8)]
@@ -36,6 +41,20 @@ pub enum TokenOpCode {
     BuyLiquidityExactIn = 6,
     SellLiquidityExactIn = 7,
     ClaimLiquidityFees = 8,
+    Approve = 9,
+    TransferFrom = 10,
+    BatchTransfer = 11,
+    LockTokens = 12,
+    TransferMintAuthority = 13,
+    RenounceMintAuthority = 14,
+    UpdateMetadata = 15,
+    CreatePairPool = 16,
+    AddPairLiquidity = 17,
+    RemovePairLiquidity = 18,
+    SwapPairExactIn = 19,
+    SwapPairExactOut = 20,
+    BuyLiquidityExactOut = 21,
+    SellLiquidityExactOut = 22,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -107,6 +126,19 @@ pub enum NoopReason {
     BadTokenVersion = 41,
     BadLiquidityCurveVersion = 42,
     BadLiquidityCurveMode = 43,
+    AllowanceNotFound = 44,
+    AllowanceExceeded = 45,
+    AllowanceExpired = 46,
+    BadSpender = 47,
+    BadBatchRecipientCount = 48,
+    BadLockSchedule = 49,
+    LockConflict = 50,
+    BalanceLocked = 51,
+    BadMintAuthority = 52,
+    UnauthorizedMetadataUpdate = 53,
+    BadPairAssets = 54,
+    NotPairPool = 55,
+    PairLiquidityTooLow = 56,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -166,6 +198,113 @@ pub struct BurnOp {
     pub amount: u128,
 }
 
+/// Sets the amount `spender_owner_id` may move out of the auth owner's balance.
+/// `amount == 0` revokes the allowance and `expires_at_daa_score == 0` never expires.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApproveOp {
+    pub asset_id: [u8; 32],
+    pub spender_owner_id: [u8; 32],
+    pub amount: u128,
+    pub expires_at_daa_score: u64,
+}
+
+/// Moves `amount` from `from_owner_id` to `to_owner_id`, authorized by the spender's allowance.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransferFromOp {
+    pub asset_id: [u8; 32],
+    pub from_owner_id: [u8; 32],
+    pub to_owner_id: [u8; 32],
+    pub amount: u128,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BatchTransferRecipient {
+    pub to_owner_id: [u8; 32],
+    pub amount: u128,
+}
+
+/// Moves one asset from the auth owner to up to `MAX_BATCH_TRANSFER_RECIPIENTS`
+/// distinct owners. The batch applies atomically: any failing leg no-ops the whole op.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BatchTransferOp {
+    pub asset_id: [u8; 32],
+    pub recipients: Vec<BatchTransferRecipient>,
+}
+
+/// Moves `amount` from the auth owner to `beneficiary_owner_id` and locks it there, releasing linearly
+/// between the two accepting-block DAA scores (equal scores release at once, as a cliff).
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockTokensOp {
+    pub asset_id: [u8; 32],
+    pub beneficiary_owner_id: [u8; 32],
+    pub amount: u128,
+    pub release_start_daa_score: u64,
+    pub release_end_daa_score: u64,
+}
+
+/// Hands the mint authority of `asset_id` from the current authority to `new_mint_authority_owner_id`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransferMintAuthorityOp {
+    pub asset_id: [u8; 32],
+    pub new_mint_authority_owner_id: [u8; 32],
+}
+
+/// Clears the mint authority of `asset_id` for good; no owner can mint the asset afterwards.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RenounceMintAuthorityOp {
+    pub asset_id: [u8; 32],
+}
+
+/// Replaces the metadata and platform tag of `asset_id`; only its creator may update them.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UpdateMetadataOp {
+    pub asset_id: [u8; 32],
+    pub metadata: Vec<u8>,
+    pub platform_tag: Vec<u8>,
+}
+
+/// Opens a constant-product pool between two standard assets; `asset_a_id` must sort strictly before `asset_b_id`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CreatePairPoolOp {
+    pub asset_a_id: [u8; 32],
+    pub asset_b_id: [u8; 32],
+    pub fee_bps: u16,
+    pub amount_a: u128,
+    pub amount_b: u128,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AddPairLiquidityOp {
+    pub pool_id: [u8; 32],
+    pub amount_a_max: u128,
+    pub amount_b_max: u128,
+    pub min_shares_out: u128,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RemovePairLiquidityOp {
+    pub pool_id: [u8; 32],
+    pub shares: u128,
+    pub min_amount_a_out: u128,
+    pub min_amount_b_out: u128,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SwapPairExactInOp {
+    pub pool_id: [u8; 32],
+    pub asset_in_id: [u8; 32],
+    pub amount_in: u128,
+    pub min_amount_out: u128,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SwapPairExactOutOp {
+    pub pool_id: [u8; 32],
+    pub asset_out_id: [u8; 32],
+    pub amount_out: u128,
+    pub max_amount_in: u128,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct LiquidityRecipientAddress {
     pub address_version: u8,
@@ -219,6 +358,23 @@ pub struct SellLiquidityExactInOp {
     pub cpay_receive_output_index: u16,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BuyLiquidityExactOutOp {
+    pub asset_id: [u8; 32],
+    pub expected_pool_nonce: u64,
+    pub token_out: u128,
+    pub max_cpay_in_sompi: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SellLiquidityExactOutOp {
+    pub asset_id: [u8; 32],
+    pub expected_pool_nonce: u64,
+    pub cpay_out_sompi: u64,
+    pub max_token_in: u128,
+    pub cpay_receive_output_index: u16,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ClaimLiquidityFeesOp {
     pub asset_id: [u8; 32],
@@ -239,6 +395,20 @@ pub enum TokenOp {
     BuyLiquidityExactIn(BuyLiquidityExactInOp),
     SellLiquidityExactIn(SellLiquidityExactInOp),
     ClaimLiquidityFees(ClaimLiquidityFeesOp),
+    Approve(ApproveOp),
+    TransferFrom(TransferFromOp),
+    BatchTransfer(BatchTransferOp),
+    LockTokens(LockTokensOp),
+    TransferMintAuthority(TransferMintAuthorityOp),
+    RenounceMintAuthority(RenounceMintAuthorityOp),
+    UpdateMetadata(UpdateMetadataOp),
+    CreatePairPool(CreatePairPoolOp),
+    AddPairLiquidity(AddPairLiquidityOp),
+    RemovePairLiquidity(RemovePairLiquidityOp),
+    SwapPairExactIn(SwapPairExactInOp),
+    SwapPairExactOut(SwapPairExactOutOp),
+    BuyLiquidityExactOut(BuyLiquidityExactOutOp),
+    SellLiquidityExactOut(SellLiquidityExactOutOp),
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -287,6 +457,20 @@ fn parse_atomic_token_payload_strict(payload: &[u8]) -> Result<ParsedTokenPayloa
         6 => TokenOpCode::BuyLiquidityExactIn,
         7 => TokenOpCode::SellLiquidityExactIn,
         8 => TokenOpCode::ClaimLiquidityFees,
+        9 => TokenOpCode::Approve,
+        10 => TokenOpCode::TransferFrom,
+        11 => TokenOpCode::BatchTransfer,
+        12 => TokenOpCode::LockTokens,
+        13 => TokenOpCode::TransferMintAuthority,
+        14 => TokenOpCode::RenounceMintAuthority,
+        15 => TokenOpCode::UpdateMetadata,
+        16 => TokenOpCode::CreatePairPool,
+        17 => TokenOpCode::AddPairLiquidity,
+        18 => TokenOpCode::RemovePairLiquidity,
+        19 => TokenOpCode::SwapPairExactIn,
+        20 => TokenOpCode::SwapPairExactOut,
+        21 => TokenOpCode::BuyLiquidityExactOut,
+        22 => TokenOpCode::SellLiquidityExactOut,
         _ => return Err(NoopReason::BadOp),
     };
 
@@ -312,6 +496,20 @@ fn parse_atomic_token_payload_strict(payload: &[u8]) -> Result<ParsedTokenPayloa
         TokenOpCode::BuyLiquidityExactIn => TokenOp::BuyLiquidityExactIn(parse_buy_liquidity_exact_in_op(payload, &mut cursor)?),
         TokenOpCode::SellLiquidityExactIn => TokenOp::SellLiquidityExactIn(parse_sell_liquidity_exact_in_op(payload, &mut cursor)?),
         TokenOpCode::ClaimLiquidityFees => TokenOp::ClaimLiquidityFees(parse_claim_liquidity_fees_op(payload, &mut cursor)?),
+        TokenOpCode::Approve => TokenOp::Approve(parse_approve_op(payload, &mut cursor)?),
+        TokenOpCode::TransferFrom => TokenOp::TransferFrom(parse_transfer_from_op(payload, &mut cursor)?),
+        TokenOpCode::BatchTransfer => TokenOp::BatchTransfer(parse_batch_transfer_op(payload, &mut cursor)?),
+        TokenOpCode::LockTokens => TokenOp::LockTokens(parse_lock_tokens_op(payload, &mut cursor)?),
+        TokenOpCode::TransferMintAuthority => TokenOp::TransferMintAuthority(parse_transfer_mint_authority_op(payload, &mut cursor)?),
+        TokenOpCode::RenounceMintAuthority => TokenOp::RenounceMintAuthority(parse_renounce_mint_authority_op(payload, &mut cursor)?),
+        TokenOpCode::UpdateMetadata => TokenOp::UpdateMetadata(parse_update_metadata_op(payload, &mut cursor)?),
+        TokenOpCode::CreatePairPool => TokenOp::CreatePairPool(parse_create_pair_pool_op(payload, &mut cursor)?),
+        TokenOpCode::AddPairLiquidity => TokenOp::AddPairLiquidity(parse_add_pair_liquidity_op(payload, &mut cursor)?),
+        TokenOpCode::RemovePairLiquidity => TokenOp::RemovePairLiquidity(parse_remove_pair_liquidity_op(payload, &mut cursor)?),
+        TokenOpCode::SwapPairExactIn => TokenOp::SwapPairExactIn(parse_swap_pair_exact_in_op(payload, &mut cursor)?),
+        TokenOpCode::SwapPairExactOut => TokenOp::SwapPairExactOut(parse_swap_pair_exact_out_op(payload, &mut cursor)?),
+        TokenOpCode::BuyLiquidityExactOut => TokenOp::BuyLiquidityExactOut(parse_buy_liquidity_exact_out_op(payload, &mut cursor)?),
+        TokenOpCode::SellLiquidityExactOut => TokenOp::SellLiquidityExactOut(parse_sell_liquidity_exact_out_op(payload, &mut cursor)?),
     };
 
     if cursor != payload.len() {
@@ -543,6 +741,45 @@ fn parse_sell_liquidity_exact_in_op(payload: &[u8], cursor: &mut usize) -> Resul
     Ok(SellLiquidityExactInOp { asset_id, expected_pool_nonce, token_in, min_cpay_out_sompi, cpay_receive_output_index })
 }
 
+fn parse_buy_liquidity_exact_out_op(payload: &[u8], cursor: &mut usize) -> Result<BuyLiquidityExactOutOp, NoopReason> {
+    let asset_id = take_32(payload, cursor).ok_or(NoopReason::BadLength)?;
+    let expected_pool_nonce = take_u64_le(payload, cursor).ok_or(NoopReason::BadLength)?;
+    let token_out = take_u128_le(payload, cursor).ok_or(NoopReason::BadLength)?;
+    let max_cpay_in_sompi = take_u64_le(payload, cursor).ok_or(NoopReason::BadLength)?;
+
+    if expected_pool_nonce == 0 {
+        return Err(NoopReason::BadNonce);
+    }
+    if token_out == 0 {
+        return Err(NoopReason::InvalidAmount);
+    }
+    if max_cpay_in_sompi == 0 {
+        return Err(NoopReason::MinOutViolation);
+    }
+
+    Ok(BuyLiquidityExactOutOp { asset_id, expected_pool_nonce, token_out, max_cpay_in_sompi })
+}
+
+fn parse_sell_liquidity_exact_out_op(payload: &[u8], cursor: &mut usize) -> Result<SellLiquidityExactOutOp, NoopReason> {
+    let asset_id = take_32(payload, cursor).ok_or(NoopReason::BadLength)?;
+    let expected_pool_nonce = take_u64_le(payload, cursor).ok_or(NoopReason::BadLength)?;
+    let cpay_out_sompi = take_u64_le(payload, cursor).ok_or(NoopReason::BadLength)?;
+    let max_token_in = take_u128_le(payload, cursor).ok_or(NoopReason::BadLength)?;
+    let cpay_receive_output_index = take_u16_le(payload, cursor).ok_or(NoopReason::BadLength)?;
+
+    if expected_pool_nonce == 0 {
+        return Err(NoopReason::BadNonce);
+    }
+    if cpay_out_sompi == 0 {
+        return Err(NoopReason::InvalidAmount);
+    }
+    if max_token_in == 0 {
+        return Err(NoopReason::MinOutViolation);
+    }
+
+    Ok(SellLiquidityExactOutOp { asset_id, expected_pool_nonce, cpay_out_sompi, max_token_in, cpay_receive_output_index })
+}
+
 fn parse_claim_liquidity_fees_op(payload: &[u8], cursor: &mut usize) -> Result<ClaimLiquidityFeesOp, NoopReason> {
     let asset_id = take_32(payload, cursor).ok_or(NoopReason::BadLength)?;
     let expected_pool_nonce = take_u64_le(payload, cursor).ok_or(NoopReason::BadLength)?;
@@ -560,6 +797,162 @@ fn parse_claim_liquidity_fees_op(payload: &[u8], cursor: &mut usize) -> Result<C
     Ok(ClaimLiquidityFeesOp { asset_id, expected_pool_nonce, recipient_index, claim_amount_sompi, claim_receive_output_index })
 }
 
+fn parse_approve_op(payload: &[u8], cursor: &mut usize) -> Result<ApproveOp, NoopReason> {
+    let asset_id = take_32(payload, cursor).ok_or(NoopReason::BadLength)?;
+    let spender_owner_id = take_32(payload, cursor).ok_or(NoopReason::BadLength)?;
+    let amount = take_u128_le(payload, cursor).ok_or(NoopReason::BadLength)?;
+    let expires_at_daa_score = take_u64_le(payload, cursor).ok_or(NoopReason::BadLength)?;
+
+    if spender_owner_id == [0u8; 32] {
+        return Err(NoopReason::BadSpender);
+    }
+
+    Ok(ApproveOp { asset_id, spender_owner_id, amount, expires_at_daa_score })
+}
+
+fn parse_transfer_from_op(payload: &[u8], cursor: &mut usize) -> Result<TransferFromOp, NoopReason> {
+    let asset_id = take_32(payload, cursor).ok_or(NoopReason::BadLength)?;
+    let from_owner_id = take_32(payload, cursor).ok_or(NoopReason::BadLength)?;
+    let to_owner_id = take_32(payload, cursor).ok_or(NoopReason::BadLength)?;
+    let amount = take_u128_le(payload, cursor).ok_or(NoopReason::BadLength)?;
+
+    if amount == 0 {
+        return Err(NoopReason::InvalidAmount);
+    }
+
+    Ok(TransferFromOp { asset_id, from_owner_id, to_owner_id, amount })
+}
+
+fn parse_batch_transfer_op(payload: &[u8], cursor: &mut usize) -> Result<BatchTransferOp, NoopReason> {
+    let asset_id = take_32(payload, cursor).ok_or(NoopReason::BadLength)?;
+    let recipient_count = take_u8(payload, cursor).ok_or(NoopReason::BadLength)? as usize;
+    if !(1..=MAX_BATCH_TRANSFER_RECIPIENTS).contains(&recipient_count) {
+        return Err(NoopReason::BadBatchRecipientCount);
+    }
+
+    let mut recipients: Vec<BatchTransferRecipient> = Vec::with_capacity(recipient_count);
+    for _ in 0..recipient_count {
+        let to_owner_id = take_32(payload, cursor).ok_or(NoopReason::BadLength)?;
+        let amount = take_u128_le(payload, cursor).ok_or(NoopReason::BadLength)?;
+        if amount == 0 {
+            return Err(NoopReason::InvalidAmount);
+        }
+        if recipients.iter().any(|recipient| recipient.to_owner_id == to_owner_id) {
+            return Err(NoopReason::RecipientDuplicate);
+        }
+        recipients.push(BatchTransferRecipient { to_owner_id, amount });
+    }
+
+    Ok(BatchTransferOp { asset_id, recipients })
+}
+
+fn parse_lock_tokens_op(payload: &[u8], cursor: &mut usize) -> Result<LockTokensOp, NoopReason> {
+    let asset_id = take_32(payload, cursor).ok_or(NoopReason::BadLength)?;
+    let beneficiary_owner_id = take_32(payload, cursor).ok_or(NoopReason::BadLength)?;
+    let amount = take_u128_le(payload, cursor).ok_or(NoopReason::BadLength)?;
+    let release_start_daa_score = take_u64_le(payload, cursor).ok_or(NoopReason::BadLength)?;
+    let release_end_daa_score = take_u64_le(payload, cursor).ok_or(NoopReason::BadLength)?;
+
+    if amount == 0 {
+        return Err(NoopReason::InvalidAmount);
+    }
+    if release_end_daa_score < release_start_daa_score {
+        return Err(NoopReason::BadLockSchedule);
+    }
+
+    Ok(LockTokensOp { asset_id, beneficiary_owner_id, amount, release_start_daa_score, release_end_daa_score })
+}
+
+fn parse_transfer_mint_authority_op(payload: &[u8], cursor: &mut usize) -> Result<TransferMintAuthorityOp, NoopReason> {
+    let asset_id = take_32(payload, cursor).ok_or(NoopReason::BadLength)?;
+    let new_mint_authority_owner_id = take_32(payload, cursor).ok_or(NoopReason::BadLength)?;
+
+    // The zero owner id marks an asset without mint authority; renouncing has its own op.
+    if new_mint_authority_owner_id == [0u8; 32] {
+        return Err(NoopReason::BadMintAuthority);
+    }
+
+    Ok(TransferMintAuthorityOp { asset_id, new_mint_authority_owner_id })
+}
+
+fn parse_renounce_mint_authority_op(payload: &[u8], cursor: &mut usize) -> Result<RenounceMintAuthorityOp, NoopReason> {
+    let asset_id = take_32(payload, cursor).ok_or(NoopReason::BadLength)?;
+    Ok(RenounceMintAuthorityOp { asset_id })
+}
+
+fn parse_update_metadata_op(payload: &[u8], cursor: &mut usize) -> Result<UpdateMetadataOp, NoopReason> {
+    let asset_id = take_32(payload, cursor).ok_or(NoopReason::BadLength)?;
+    let metadata_len = take_u16_le(payload, cursor).ok_or(NoopReason::BadLength)? as usize;
+    if metadata_len > MAX_METADATA_LEN {
+        return Err(NoopReason::BadLength);
+    }
+    let metadata = take_vec(payload, cursor, metadata_len).ok_or(NoopReason::BadLength)?;
+    let platform_tag = parse_platform_tag(payload, cursor)?;
+    Ok(UpdateMetadataOp { asset_id, metadata, platform_tag })
+}
+
+fn parse_create_pair_pool_op(payload: &[u8], cursor: &mut usize) -> Result<CreatePairPoolOp, NoopReason> {
+    let asset_a_id = take_32(payload, cursor).ok_or(NoopReason::BadLength)?;
+    let asset_b_id = take_32(payload, cursor).ok_or(NoopReason::BadLength)?;
+    let fee_bps = take_u16_le(payload, cursor).ok_or(NoopReason::BadLength)?;
+    let amount_a = take_u128_le(payload, cursor).ok_or(NoopReason::BadLength)?;
+    let amount_b = take_u128_le(payload, cursor).ok_or(NoopReason::BadLength)?;
+    if asset_a_id >= asset_b_id {
+        return Err(NoopReason::BadPairAssets);
+    }
+    if fee_bps > MAX_PAIR_FEE_BPS {
+        return Err(NoopReason::BadLiquidityFeeBps);
+    }
+    if amount_a == 0 || amount_b == 0 {
+        return Err(NoopReason::InvalidAmount);
+    }
+    Ok(CreatePairPoolOp { asset_a_id, asset_b_id, fee_bps, amount_a, amount_b })
+}
+
+fn parse_add_pair_liquidity_op(payload: &[u8], cursor: &mut usize) -> Result<AddPairLiquidityOp, NoopReason> {
+    let pool_id = take_32(payload, cursor).ok_or(NoopReason::BadLength)?;
+    let amount_a_max = take_u128_le(payload, cursor).ok_or(NoopReason::BadLength)?;
+    let amount_b_max = take_u128_le(payload, cursor).ok_or(NoopReason::BadLength)?;
+    let min_shares_out = take_u128_le(payload, cursor).ok_or(NoopReason::BadLength)?;
+    if amount_a_max == 0 || amount_b_max == 0 {
+        return Err(NoopReason::InvalidAmount);
+    }
+    Ok(AddPairLiquidityOp { pool_id, amount_a_max, amount_b_max, min_shares_out })
+}
+
+fn parse_remove_pair_liquidity_op(payload: &[u8], cursor: &mut usize) -> Result<RemovePairLiquidityOp, NoopReason> {
+    let pool_id = take_32(payload, cursor).ok_or(NoopReason::BadLength)?;
+    let shares = take_u128_le(payload, cursor).ok_or(NoopReason::BadLength)?;
+    let min_amount_a_out = take_u128_le(payload, cursor).ok_or(NoopReason::BadLength)?;
+    let min_amount_b_out = take_u128_le(payload, cursor).ok_or(NoopReason::BadLength)?;
+    if shares == 0 {
+        return Err(NoopReason::InvalidAmount);
+    }
+    Ok(RemovePairLiquidityOp { pool_id, shares, min_amount_a_out, min_amount_b_out })
+}
+
+fn parse_swap_pair_exact_in_op(payload: &[u8], cursor: &mut usize) -> Result<SwapPairExactInOp, NoopReason> {
+    let pool_id = take_32(payload, cursor).ok_or(NoopReason::BadLength)?;
+    let asset_in_id = take_32(payload, cursor).ok_or(NoopReason::BadLength)?;
+    let amount_in = take_u128_le(payload, cursor).ok_or(NoopReason::BadLength)?;
+    let min_amount_out = take_u128_le(payload, cursor).ok_or(NoopReason::BadLength)?;
+    if amount_in == 0 {
+        return Err(NoopReason::InvalidAmount);
+    }
+    Ok(SwapPairExactInOp { pool_id, asset_in_id, amount_in, min_amount_out })
+}
+
+fn parse_swap_pair_exact_out_op(payload: &[u8], cursor: &mut usize) -> Result<SwapPairExactOutOp, NoopReason> {
+    let pool_id = take_32(payload, cursor).ok_or(NoopReason::BadLength)?;
+    let asset_out_id = take_32(payload, cursor).ok_or(NoopReason::BadLength)?;
+    let amount_out = take_u128_le(payload, cursor).ok_or(NoopReason::BadLength)?;
+    let max_amount_in = take_u128_le(payload, cursor).ok_or(NoopReason::BadLength)?;
+    if amount_out == 0 {
+        return Err(NoopReason::InvalidAmount);
+    }
+    Ok(SwapPairExactOutOp { pool_id, asset_out_id, amount_out, max_amount_in })
+}
+
 fn parse_create_asset_common(
     payload: &[u8],
     cursor: &mut usize,
@@ -1065,6 +1458,56 @@ mod tests {
         assert_eq!(result.unwrap_err(), NoopReason::BadNonce);
     }
 
+    #[test]
+    fn parse_exact_out_liquidity_ops_and_bounds() {
+        let buy_payload = |expected_pool_nonce: u64, token_out: u128, max_cpay_in_sompi: u64| {
+            let mut payload = build_header(TokenOpCode::BuyLiquidityExactOut as u8, 0, 1);
+            payload.extend_from_slice(&[4u8; 32]);
+            payload.extend_from_slice(&expected_pool_nonce.to_le_bytes());
+            payload.extend_from_slice(&token_out.to_le_bytes());
+            payload.extend_from_slice(&max_cpay_in_sompi.to_le_bytes());
+            payload
+        };
+        assert_eq!(
+            parse_atomic_token_payload(&buy_payload(2, 10, 5_000)).unwrap().unwrap().op,
+            TokenOp::BuyLiquidityExactOut(BuyLiquidityExactOutOp {
+                asset_id: [4u8; 32],
+                expected_pool_nonce: 2,
+                token_out: 10,
+                max_cpay_in_sompi: 5_000,
+            })
+        );
+        assert_eq!(parse_atomic_token_payload(&buy_payload(0, 10, 5_000)).unwrap().unwrap_err(), NoopReason::BadNonce);
+        assert_eq!(parse_atomic_token_payload(&buy_payload(2, 0, 5_000)).unwrap().unwrap_err(), NoopReason::InvalidAmount);
+        assert_eq!(parse_atomic_token_payload(&buy_payload(2, 10, 0)).unwrap().unwrap_err(), NoopReason::MinOutViolation);
+
+        let sell_payload = |expected_pool_nonce: u64, cpay_out_sompi: u64, max_token_in: u128| {
+            let mut payload = build_header(TokenOpCode::SellLiquidityExactOut as u8, 0, 1);
+            payload.extend_from_slice(&[5u8; 32]);
+            payload.extend_from_slice(&expected_pool_nonce.to_le_bytes());
+            payload.extend_from_slice(&cpay_out_sompi.to_le_bytes());
+            payload.extend_from_slice(&max_token_in.to_le_bytes());
+            payload.extend_from_slice(&1u16.to_le_bytes());
+            payload
+        };
+        assert_eq!(
+            parse_atomic_token_payload(&sell_payload(3, 7_000, 12)).unwrap().unwrap().op,
+            TokenOp::SellLiquidityExactOut(SellLiquidityExactOutOp {
+                asset_id: [5u8; 32],
+                expected_pool_nonce: 3,
+                cpay_out_sompi: 7_000,
+                max_token_in: 12,
+                cpay_receive_output_index: 1,
+            })
+        );
+        assert_eq!(parse_atomic_token_payload(&sell_payload(0, 7_000, 12)).unwrap().unwrap_err(), NoopReason::BadNonce);
+        assert_eq!(parse_atomic_token_payload(&sell_payload(3, 0, 12)).unwrap().unwrap_err(), NoopReason::InvalidAmount);
+        assert_eq!(parse_atomic_token_payload(&sell_payload(3, 7_000, 0)).unwrap().unwrap_err(), NoopReason::MinOutViolation);
+        let mut truncated = sell_payload(3, 7_000, 12);
+        truncated.pop();
+        assert_eq!(parse_atomic_token_payload(&truncated).unwrap().unwrap_err(), NoopReason::BadLength);
+    }
+
     #[test]
     fn parse_claim_liquidity_rejects_zero_expected_pool_nonce() {
         let mut payload = build_header(8, 0, 1);
@@ -1076,4 +1519,288 @@ mod tests {
         let result = parse_atomic_token_payload(&payload).unwrap();
         assert_eq!(result.unwrap_err(), NoopReason::BadNonce);
     }
+
+    #[test]
+    fn parse_approve_and_transfer_from() {
+        let mut approve = build_header(TokenOpCode::Approve as u8, 0, 4);
+        approve.extend_from_slice(&[1u8; 32]); // asset_id
+        approve.extend_from_slice(&[2u8; 32]); // spender_owner_id
+        approve.extend_from_slice(&500u128.to_le_bytes()); // amount
+        approve.extend_from_slice(&1_000u64.to_le_bytes()); // expires_at_daa_score
+        let parsed = parse_atomic_token_payload(&approve).unwrap().unwrap();
+        assert_eq!(
+            parsed.op,
+            TokenOp::Approve(ApproveOp { asset_id: [1u8; 32], spender_owner_id: [2u8; 32], amount: 500, expires_at_daa_score: 1_000 })
+        );
+
+        let mut transfer_from = build_header(TokenOpCode::TransferFrom as u8, 0, 1);
+        transfer_from.extend_from_slice(&[1u8; 32]); // asset_id
+        transfer_from.extend_from_slice(&[3u8; 32]); // from_owner_id
+        transfer_from.extend_from_slice(&[4u8; 32]); // to_owner_id
+        transfer_from.extend_from_slice(&25u128.to_le_bytes()); // amount
+        let parsed = parse_atomic_token_payload(&transfer_from).unwrap().unwrap();
+        assert_eq!(
+            parsed.op,
+            TokenOp::TransferFrom(TransferFromOp {
+                asset_id: [1u8; 32],
+                from_owner_id: [3u8; 32],
+                to_owner_id: [4u8; 32],
+                amount: 25
+            })
+        );
+    }
+
+    #[test]
+    fn parse_approve_rejects_zero_spender_and_transfer_from_rejects_zero_amount() {
+        let mut approve = build_header(TokenOpCode::Approve as u8, 0, 1);
+        approve.extend_from_slice(&[1u8; 32]);
+        approve.extend_from_slice(&[0u8; 32]);
+        approve.extend_from_slice(&1u128.to_le_bytes());
+        approve.extend_from_slice(&0u64.to_le_bytes());
+        assert_eq!(parse_atomic_token_payload(&approve).unwrap().unwrap_err(), NoopReason::BadSpender);
+
+        let mut transfer_from = build_header(TokenOpCode::TransferFrom as u8, 0, 1);
+        transfer_from.extend_from_slice(&[1u8; 32]);
+        transfer_from.extend_from_slice(&[3u8; 32]);
+        transfer_from.extend_from_slice(&[4u8; 32]);
+        transfer_from.extend_from_slice(&0u128.to_le_bytes());
+        assert_eq!(parse_atomic_token_payload(&transfer_from).unwrap().unwrap_err(), NoopReason::InvalidAmount);
+    }
+
+    #[test]
+    fn parse_batch_transfer_enforces_recipient_rules() {
+        let batch = |recipients: &[([u8; 32], u128)]| {
+            let mut payload = build_header(TokenOpCode::BatchTransfer as u8, 0, 1);
+            payload.extend_from_slice(&[1u8; 32]); // asset_id
+            payload.push(recipients.len() as u8); // recipient_count
+            for (to_owner_id, amount) in recipients {
+                payload.extend_from_slice(to_owner_id);
+                payload.extend_from_slice(&amount.to_le_bytes());
+            }
+            payload
+        };
+
+        let parsed = parse_atomic_token_payload(&batch(&[([2u8; 32], 10), ([3u8; 32], 20)])).unwrap().unwrap();
+        assert_eq!(
+            parsed.op,
+            TokenOp::BatchTransfer(BatchTransferOp {
+                asset_id: [1u8; 32],
+                recipients: vec![
+                    BatchTransferRecipient { to_owner_id: [2u8; 32], amount: 10 },
+                    BatchTransferRecipient { to_owner_id: [3u8; 32], amount: 20 },
+                ],
+            })
+        );
+
+        let full: Vec<_> = (0..MAX_BATCH_TRANSFER_RECIPIENTS).map(|i| ([i as u8 + 1; 32], 1u128)).collect();
+        assert!(parse_atomic_token_payload(&batch(&full)).unwrap().is_ok());
+        let mut too_many = full.clone();
+        too_many.push(([0xffu8; 32], 1));
+        assert_eq!(parse_atomic_token_payload(&batch(&too_many)).unwrap().unwrap_err(), NoopReason::BadBatchRecipientCount);
+        assert_eq!(parse_atomic_token_payload(&batch(&[])).unwrap().unwrap_err(), NoopReason::BadBatchRecipientCount);
+        assert_eq!(
+            parse_atomic_token_payload(&batch(&[([2u8; 32], 1), ([2u8; 32], 2)])).unwrap().unwrap_err(),
+            NoopReason::RecipientDuplicate
+        );
+        assert_eq!(parse_atomic_token_payload(&batch(&[([2u8; 32], 0)])).unwrap().unwrap_err(), NoopReason::InvalidAmount);
+    }
+
+    #[test]
+    fn parse_lock_tokens_validates_amount_and_schedule() {
+        let lock = |amount: u128, start: u64, end: u64| {
+            let mut payload = build_header(TokenOpCode::LockTokens as u8, 0, 1);
+            payload.extend_from_slice(&[1u8; 32]); // asset_id
+            payload.extend_from_slice(&[2u8; 32]); // beneficiary_owner_id
+            payload.extend_from_slice(&amount.to_le_bytes());
+            payload.extend_from_slice(&start.to_le_bytes());
+            payload.extend_from_slice(&end.to_le_bytes());
+            payload
+        };
+
+        let parsed = parse_atomic_token_payload(&lock(500, 100, 200)).unwrap().unwrap();
+        assert_eq!(
+            parsed.op,
+            TokenOp::LockTokens(LockTokensOp {
+                asset_id: [1u8; 32],
+                beneficiary_owner_id: [2u8; 32],
+                amount: 500,
+                release_start_daa_score: 100,
+                release_end_daa_score: 200,
+            })
+        );
+        assert!(parse_atomic_token_payload(&lock(500, 150, 150)).unwrap().is_ok());
+        assert_eq!(parse_atomic_token_payload(&lock(0, 100, 200)).unwrap().unwrap_err(), NoopReason::InvalidAmount);
+        assert_eq!(parse_atomic_token_payload(&lock(500, 201, 200)).unwrap().unwrap_err(), NoopReason::BadLockSchedule);
+        let mut truncated = lock(500, 100, 200);
+        truncated.pop();
+        assert_eq!(parse_atomic_token_payload(&truncated).unwrap().unwrap_err(), NoopReason::BadLength);
+    }
+
+    #[test]
+    fn parse_mint_authority_ops() {
+        let mut transfer = build_header(TokenOpCode::TransferMintAuthority as u8, 0, 1);
+        transfer.extend_from_slice(&[1u8; 32]); // asset_id
+        transfer.extend_from_slice(&[2u8; 32]); // new_mint_authority_owner_id
+        let parsed = parse_atomic_token_payload(&transfer).unwrap().unwrap();
+        assert_eq!(
+            parsed.op,
+            TokenOp::TransferMintAuthority(TransferMintAuthorityOp { asset_id: [1u8; 32], new_mint_authority_owner_id: [2u8; 32] })
+        );
+
+        let mut to_zero = build_header(TokenOpCode::TransferMintAuthority as u8, 0, 1);
+        to_zero.extend_from_slice(&[1u8; 32]);
+        to_zero.extend_from_slice(&[0u8; 32]);
+        assert_eq!(parse_atomic_token_payload(&to_zero).unwrap().unwrap_err(), NoopReason::BadMintAuthority);
+
+        let mut renounce = build_header(TokenOpCode::RenounceMintAuthority as u8, 0, 1);
+        renounce.extend_from_slice(&[1u8; 32]);
+        let parsed = parse_atomic_token_payload(&renounce).unwrap().unwrap();
+        assert_eq!(parsed.op, TokenOp::RenounceMintAuthority(RenounceMintAuthorityOp { asset_id: [1u8; 32] }));
+        renounce.push(0);
+        assert_eq!(parse_atomic_token_payload(&renounce).unwrap().unwrap_err(), NoopReason::BadLength);
+    }
+
+    #[test]
+    fn parse_update_metadata_op_bounds() {
+        let update = |metadata: &[u8], platform_tag: &[u8]| {
+            let mut payload = build_header(TokenOpCode::UpdateMetadata as u8, 0, 1);
+            payload.extend_from_slice(&[1u8; 32]); // asset_id
+            payload.extend_from_slice(&(metadata.len() as u16).to_le_bytes());
+            payload.extend_from_slice(metadata);
+            payload.push(platform_tag.len() as u8);
+            payload.extend_from_slice(platform_tag);
+            payload
+        };
+
+        let parsed = parse_atomic_token_payload(&update(b"ipfs://logo-v2", b"wallet-v2")).unwrap().unwrap();
+        assert_eq!(
+            parsed.op,
+            TokenOp::UpdateMetadata(UpdateMetadataOp {
+                asset_id: [1u8; 32],
+                metadata: b"ipfs://logo-v2".to_vec(),
+                platform_tag: b"wallet-v2".to_vec()
+            })
+        );
+        assert!(parse_atomic_token_payload(&update(b"", b"")).unwrap().is_ok());
+        assert_eq!(
+            parse_atomic_token_payload(&update(&[0u8; MAX_METADATA_LEN + 1], b"")).unwrap().unwrap_err(),
+            NoopReason::BadLength
+        );
+        assert_eq!(parse_atomic_token_payload(&update(b"", &[0xff])).unwrap().unwrap_err(), NoopReason::BadPlatformTag);
+        let mut truncated = update(b"ipfs://logo-v2", b"wallet-v2");
+        truncated.pop();
+        assert_eq!(parse_atomic_token_payload(&truncated).unwrap().unwrap_err(), NoopReason::BadLength);
+    }
+
+    #[test]
+    fn parse_pair_pool_ops_bounds() {
+        let create = |asset_a_id: [u8; 32], asset_b_id: [u8; 32], fee_bps: u16, amount_a: u128, amount_b: u128| {
+            let mut payload = build_header(TokenOpCode::CreatePairPool as u8, 0, 1);
+            payload.extend_from_slice(&asset_a_id);
+            payload.extend_from_slice(&asset_b_id);
+            payload.extend_from_slice(&fee_bps.to_le_bytes());
+            payload.extend_from_slice(&amount_a.to_le_bytes());
+            payload.extend_from_slice(&amount_b.to_le_bytes());
+            payload
+        };
+
+        let parsed = parse_atomic_token_payload(&create([1u8; 32], [2u8; 32], 30, 10, 20)).unwrap().unwrap();
+        assert_eq!(
+            parsed.op,
+            TokenOp::CreatePairPool(CreatePairPoolOp {
+                asset_a_id: [1u8; 32],
+                asset_b_id: [2u8; 32],
+                fee_bps: 30,
+                amount_a: 10,
+                amount_b: 20
+            })
+        );
+        assert!(parse_atomic_token_payload(&create([1u8; 32], [2u8; 32], MAX_PAIR_FEE_BPS, 1, 1)).unwrap().is_ok());
+        assert_eq!(
+            parse_atomic_token_payload(&create([2u8; 32], [1u8; 32], 30, 10, 20)).unwrap().unwrap_err(),
+            NoopReason::BadPairAssets
+        );
+        assert_eq!(
+            parse_atomic_token_payload(&create([1u8; 32], [1u8; 32], 30, 10, 20)).unwrap().unwrap_err(),
+            NoopReason::BadPairAssets
+        );
+        assert_eq!(
+            parse_atomic_token_payload(&create([1u8; 32], [2u8; 32], MAX_PAIR_FEE_BPS + 1, 10, 20)).unwrap().unwrap_err(),
+            NoopReason::BadLiquidityFeeBps
+        );
+        assert_eq!(
+            parse_atomic_token_payload(&create([1u8; 32], [2u8; 32], 30, 0, 20)).unwrap().unwrap_err(),
+            NoopReason::InvalidAmount
+        );
+
+        let pool_op = |op: TokenOpCode, id: Option<[u8; 32]>, first: u128, second: u128, third: u128| {
+            let mut payload = build_header(op as u8, 0, 1);
+            payload.extend_from_slice(&[9u8; 32]); // pool_id
+            if let Some(id) = id {
+                payload.extend_from_slice(&id);
+            }
+            payload.extend_from_slice(&first.to_le_bytes());
+            payload.extend_from_slice(&second.to_le_bytes());
+            if id.is_none() {
+                payload.extend_from_slice(&third.to_le_bytes());
+            }
+            payload
+        };
+
+        let parsed = parse_atomic_token_payload(&pool_op(TokenOpCode::AddPairLiquidity, None, 5, 6, 7)).unwrap().unwrap();
+        assert_eq!(
+            parsed.op,
+            TokenOp::AddPairLiquidity(AddPairLiquidityOp { pool_id: [9u8; 32], amount_a_max: 5, amount_b_max: 6, min_shares_out: 7 })
+        );
+        let parsed = parse_atomic_token_payload(&pool_op(TokenOpCode::RemovePairLiquidity, None, 5, 6, 7)).unwrap().unwrap();
+        assert_eq!(
+            parsed.op,
+            TokenOp::RemovePairLiquidity(RemovePairLiquidityOp {
+                pool_id: [9u8; 32],
+                shares: 5,
+                min_amount_a_out: 6,
+                min_amount_b_out: 7
+            })
+        );
+        let parsed = parse_atomic_token_payload(&pool_op(TokenOpCode::SwapPairExactIn, Some([1u8; 32]), 5, 4, 0)).unwrap().unwrap();
+        assert_eq!(
+            parsed.op,
+            TokenOp::SwapPairExactIn(SwapPairExactInOp {
+                pool_id: [9u8; 32],
+                asset_in_id: [1u8; 32],
+                amount_in: 5,
+                min_amount_out: 4
+            })
+        );
+        let parsed = parse_atomic_token_payload(&pool_op(TokenOpCode::SwapPairExactOut, Some([2u8; 32]), 5, 6, 0)).unwrap().unwrap();
+        assert_eq!(
+            parsed.op,
+            TokenOp::SwapPairExactOut(SwapPairExactOutOp {
+                pool_id: [9u8; 32],
+                asset_out_id: [2u8; 32],
+                amount_out: 5,
+                max_amount_in: 6
+            })
+        );
+
+        assert_eq!(
+            parse_atomic_token_payload(&pool_op(TokenOpCode::AddPairLiquidity, None, 0, 6, 7)).unwrap().unwrap_err(),
+            NoopReason::InvalidAmount
+        );
+        assert_eq!(
+            parse_atomic_token_payload(&pool_op(TokenOpCode::RemovePairLiquidity, None, 0, 6, 7)).unwrap().unwrap_err(),
+            NoopReason::InvalidAmount
+        );
+        assert_eq!(
+            parse_atomic_token_payload(&pool_op(TokenOpCode::SwapPairExactIn, Some([1u8; 32]), 0, 4, 0)).unwrap().unwrap_err(),
+            NoopReason::InvalidAmount
+        );
+        assert_eq!(
+            parse_atomic_token_payload(&pool_op(TokenOpCode::SwapPairExactOut, Some([2u8; 32]), 0, 6, 0)).unwrap().unwrap_err(),
+            NoopReason::InvalidAmount
+        );
+        let mut trailing = pool_op(TokenOpCode::SwapPairExactIn, Some([1u8; 32]), 5, 4, 0);
+        trailing.push(0);
+        assert_eq!(parse_atomic_token_payload(&trailing).unwrap().unwrap_err(), NoopReason::BadLength);
+    }
 }
@@ -1,11 +1,12 @@
 use crate::{
+    analytics_export::{write_analytics_export, AnalyticsExportAnchor, AnalyticsExportManifest},
     consensus_state_import::token_state_from_consensus_canonical_bytes,
     error::{AtomicTokenError, AtomicTokenResult},
     payload::TokenOp,
     state::{
-        AtomicTokenHealth, AtomicTokenReadContext, AtomicTokenReadView, AtomicTokenRuntimeState, AtomicTokenState,
-        AtomicTokenStateFootprint, BalanceKey, LiquidityHolderAddressState, NonceKey, ProcessedOp, TokenAsset, TokenEvent,
-        TokenHolderEntry, TokenOwnerBalanceEntry,
+        AllowanceKey, AtomicTokenHealth, AtomicTokenReadContext, AtomicTokenReadView, AtomicTokenRuntimeState, AtomicTokenState,
+        AtomicTokenStateFootprint, BalanceKey, LiquidityCandle, LiquidityHolderAddressState, NonceKey, ProcessedOp, TokenAllowance,
+        TokenAsset, TokenEvent, TokenEventFilter, TokenHolderEntry, TokenLock, TokenOwnerBalanceEntry,
     },
     storage_v2::{
         compute_p2p_audit_state_root_from_parts, debug_state_root_report_from_parts, AtomicStorageSnapshotCounts, AtomicStorageV2,
@@ -16,6 +17,7 @@ use crate::{
 use async_channel::Receiver;
 use blake2b_simd::Params as Blake2bParams;
 use borsh::{BorshDeserialize, BorshSerialize};
+use cryptix_atomic_proof::AtomicStateProof;
 use cryptix_consensus_core::{
     acceptance_data::AcceptanceData,
     config::Config,
@@ -39,6 +41,7 @@ use cryptix_consensusmanager::ConsensusManager;
 use cryptix_core::{
     debug, info,
     task::service::{AsyncService, AsyncServiceFuture},
+    time::unix_now,
     trace, warn,
 };
 use cryptix_notify::{connection::ChannelType, listener::ListenerLifespan, scope::VirtualChainChangedScope};
@@ -423,6 +426,12 @@ impl AtomicTokenProcessor {
                         if pruned.pruned_processed_ops {
                             trace!("[{IDENT}] Cryptix Atomic pruning removed processed op guard entries");
                         }
+                        if pruned.pruned_liquidity_trades > 0 {
+                            trace!(
+                                "[{IDENT}] Cryptix Atomic pruning removed {} liquidity trade record(s)",
+                                pruned.pruned_liquidity_trades
+                            );
+                        }
                     } else {
                         let retained_blocks = state.applied_chain_order.len();
                         warn!(
@@ -746,6 +755,28 @@ impl AtomicTokenProcessor {
         self.state.read().await.get_events_since_capped(after_sequence, limit, max_sequence)
     }
 
+    async fn filtered_events(
+        &self,
+        filter: TokenEventFilter,
+        after_sequence: u64,
+        limit: usize,
+        max_sequence: u64,
+    ) -> (Vec<TokenEvent>, bool) {
+        self.state.read().await.get_filtered_events(&filter, after_sequence, limit, max_sequence)
+    }
+
+    async fn event_sequence_range_for_daa(&self, from_daa_score: u64, to_daa_score: u64) -> AtomicTokenResult<Option<(u64, u64)>> {
+        let consensus = self.consensus_manager.consensus();
+        let session = consensus.session().await;
+        let session = &session;
+        let state = self.state.read().await;
+        state
+            .event_sequence_range_for_daa(from_daa_score, to_daa_score, |block_hash| async move {
+                Ok::<_, AtomicTokenError>(session.async_get_header(block_hash).await?.daa_score)
+            })
+            .await
+    }
+
     async fn read_context(
         &self,
         requested_at_block_hash: Option<BlockHash>,
@@ -770,7 +801,7 @@ impl AtomicTokenProcessor {
         owner_id: [u8; 32],
         requested_at_block_hash: Option<BlockHash>,
         fallback_block_hash: BlockHash,
-    ) -> Option<(AtomicTokenReadContext, u128)> {
+    ) -> Option<(AtomicTokenReadContext, u128, Option<TokenLock>)> {
         let latest_sink = self.latest_read_sink(requested_at_block_hash).await?;
         let bootstrap_in_progress = self.bootstrap_in_progress.load(Ordering::SeqCst);
         let state = self.state.read().await;
@@ -783,11 +814,11 @@ impl AtomicTokenProcessor {
             None => state.materialize_latest_context(fallback_block_hash, runtime_state),
         };
         let key = BalanceKey { asset_id, owner_id };
-        let balance = match requested_at_block_hash {
-            Some(at_block_hash) => state.get_balance_at_block(key, at_block_hash)?,
-            None => state.get_balance(asset_id, owner_id),
+        let (balance, lock) = match requested_at_block_hash {
+            Some(at_block_hash) => (state.get_balance_at_block(key, at_block_hash)?, state.get_lock_at_block(key, at_block_hash)?),
+            None => (state.get_balance(asset_id, owner_id), state.get_lock(key)),
         };
-        Some((context, balance))
+        Some((context, balance, lock))
     }
 
     async fn nonce_read(
@@ -865,6 +896,116 @@ impl AtomicTokenProcessor {
         Some((context, asset))
     }
 
+    /// Pair pool asset with its `(reserve_a, reserve_b, locked_shares)`, all read at the same context.
+    async fn pair_pool_read(
+        &self,
+        pool_id: [u8; 32],
+        requested_at_block_hash: Option<BlockHash>,
+        fallback_block_hash: BlockHash,
+    ) -> Option<(AtomicTokenReadContext, Option<(TokenAsset, u128, u128, u128)>)> {
+        let latest_sink = self.latest_read_sink(requested_at_block_hash).await?;
+        let bootstrap_in_progress = self.bootstrap_in_progress.load(Ordering::SeqCst);
+        let state = self.state.read().await;
+        if !Self::state_matches_latest_sink(&state, latest_sink) {
+            return None;
+        }
+        let runtime_state = state.runtime_state(bootstrap_in_progress);
+        let context = match requested_at_block_hash {
+            Some(at_block_hash) => state.materialize_context_at_block(at_block_hash, runtime_state)?,
+            None => state.materialize_latest_context(fallback_block_hash, runtime_state),
+        };
+        let read_balance = |asset_id: [u8; 32]| match requested_at_block_hash {
+            Some(at_block_hash) => state.get_balance_at_block(BalanceKey { asset_id, owner_id: pool_id }, at_block_hash),
+            None => Some(state.get_balance(asset_id, pool_id)),
+        };
+        let asset = match requested_at_block_hash {
+            Some(at_block_hash) => state.get_asset_at_block(pool_id, at_block_hash)?,
+            None => state.get_asset(pool_id),
+        };
+        let pool = match asset {
+            Some(asset) => match asset.pair_pool {
+                Some(pair) => {
+                    let reserve_a = read_balance(pair.asset_a_id)?;
+                    let reserve_b = read_balance(pair.asset_b_id)?;
+                    let locked_shares = read_balance(pool_id)?;
+                    Some((asset, reserve_a, reserve_b, locked_shares))
+                }
+                None => None,
+            },
+            None => None,
+        };
+        Some((context, pool))
+    }
+
+    async fn liquidity_candles_read(
+        &self,
+        asset_id: [u8; 32],
+        interval_daa_score: u64,
+        from_daa_score: u64,
+        to_daa_score: u64,
+        fallback_block_hash: BlockHash,
+    ) -> Option<(AtomicTokenReadContext, Vec<LiquidityCandle>)> {
+        let latest_sink = self.latest_read_sink(None).await?;
+        let bootstrap_in_progress = self.bootstrap_in_progress.load(Ordering::SeqCst);
+        let state = self.state.read().await;
+        if !Self::state_matches_latest_sink(&state, latest_sink) {
+            return None;
+        }
+        let runtime_state = state.runtime_state(bootstrap_in_progress);
+        let context = state.materialize_latest_context(fallback_block_hash, runtime_state);
+        let candles = state.get_liquidity_candles(&asset_id, interval_daa_score, from_daa_score, to_daa_score);
+        Some((context, candles))
+    }
+
+    async fn balance_proof_read(
+        &self,
+        asset_id: [u8; 32],
+        owner_id: [u8; 32],
+        requested_at_block_hash: Option<BlockHash>,
+        fallback_block_hash: BlockHash,
+    ) -> Option<(AtomicTokenReadContext, AtomicStateProof, AtomicStateProof)> {
+        let latest_sink = self.latest_read_sink(requested_at_block_hash).await?;
+        let bootstrap_in_progress = self.bootstrap_in_progress.load(Ordering::SeqCst);
+        let state = self.state.read().await;
+        if !Self::state_matches_latest_sink(&state, latest_sink) {
+            return None;
+        }
+        let runtime_state = state.runtime_state(bootstrap_in_progress);
+        let context = match requested_at_block_hash {
+            Some(at_block_hash) => state.materialize_context_at_block(at_block_hash, runtime_state)?,
+            None => state.materialize_latest_context(fallback_block_hash, runtime_state),
+        };
+        let balance_proof = state.get_balance_proof(BalanceKey { asset_id, owner_id }, &context)?;
+        let nonce_key = NonceKey { owner_id, scope_kind: crate::state::NONCE_SCOPE_ASSET, scope_id: asset_id };
+        let nonce_proof = state.get_nonce_proof(nonce_key, &context)?;
+        Some((context, balance_proof, nonce_proof))
+    }
+
+    async fn asset_proof_read(
+        &self,
+        asset_id: [u8; 32],
+        requested_at_block_hash: Option<BlockHash>,
+        fallback_block_hash: BlockHash,
+    ) -> Option<(AtomicTokenReadContext, Option<TokenAsset>, AtomicStateProof)> {
+        let latest_sink = self.latest_read_sink(requested_at_block_hash).await?;
+        let bootstrap_in_progress = self.bootstrap_in_progress.load(Ordering::SeqCst);
+        let state = self.state.read().await;
+        if !Self::state_matches_latest_sink(&state, latest_sink) {
+            return None;
+        }
+        let runtime_state = state.runtime_state(bootstrap_in_progress);
+        let context = match requested_at_block_hash {
+            Some(at_block_hash) => state.materialize_context_at_block(at_block_hash, runtime_state)?,
+            None => state.materialize_latest_context(fallback_block_hash, runtime_state),
+        };
+        let asset = match requested_at_block_hash {
+            Some(at_block_hash) => state.get_asset_at_block(asset_id, at_block_hash)?,
+            None => state.get_asset(asset_id),
+        };
+        let proof = state.get_asset_proof(asset_id, &context)?;
+        Some((context, asset, proof))
+    }
+
     async fn op_status_read(
         &self,
         txid: BlockHash,
@@ -946,6 +1087,8 @@ impl AtomicTokenProcessor {
             balances: HashMap::new(),
             nonces: HashMap::new(),
             anchor_counts: HashMap::new(),
+            allowances: HashMap::new(),
+            locks: HashMap::new(),
             processed_ops: HashMap::new(),
             known_owner_addresses: HashMap::new(),
         };
@@ -984,12 +1127,84 @@ impl AtomicTokenProcessor {
             }
         }
 
+        fn read_lock(
+            state: &AtomicTokenState,
+            requested_at_block_hash: Option<BlockHash>,
+            asset_id: [u8; 32],
+            owner_id: [u8; 32],
+            view: &mut AtomicTokenReadView,
+        ) -> Option<()> {
+            let key = BalanceKey { asset_id, owner_id };
+            let lock = match requested_at_block_hash {
+                Some(at_block_hash) => state.get_lock_at_block(key, at_block_hash)?,
+                None => state.get_lock(key),
+            };
+            if let Some(lock) = lock {
+                view.locks.insert(key, lock);
+            }
+            Some(())
+        }
+
+        fn read_owner_balance_and_lock(
+            state: &AtomicTokenState,
+            requested_at_block_hash: Option<BlockHash>,
+            asset_id: [u8; 32],
+            owner_id: [u8; 32],
+            view: &mut AtomicTokenReadView,
+        ) -> Option<()> {
+            let balance = read_balance(state, requested_at_block_hash, asset_id, owner_id)?;
+            if balance > 0 {
+                view.balances.insert(BalanceKey { asset_id, owner_id }, balance);
+            }
+            read_lock(state, requested_at_block_hash, asset_id, owner_id, view)
+        }
+
+        /// Loads the pool, its reserves and the caller's share and reserve-asset balances.
+        fn read_pair_pool(
+            state: &AtomicTokenState,
+            requested_at_block_hash: Option<BlockHash>,
+            pool_id: [u8; 32],
+            owner_id: [u8; 32],
+            view: &mut AtomicTokenReadView,
+        ) -> Option<()> {
+            let Some(pool) = read_asset(state, requested_at_block_hash, pool_id)? else {
+                return Some(());
+            };
+            let pair = pool.pair_pool;
+            view.assets.insert(pool_id, pool);
+            read_owner_balance_and_lock(state, requested_at_block_hash, pool_id, owner_id, view)?;
+            let Some(pair) = pair else {
+                return Some(());
+            };
+            for asset_id in [pair.asset_a_id, pair.asset_b_id] {
+                let reserve = read_balance(state, requested_at_block_hash, asset_id, pool_id)?;
+                if reserve > 0 {
+                    view.balances.insert(BalanceKey { asset_id, owner_id: pool_id }, reserve);
+                }
+                read_owner_balance_and_lock(state, requested_at_block_hash, asset_id, owner_id, view)?;
+            }
+            Some(())
+        }
+
         match op {
             TokenOp::CreateAsset(_) | TokenOp::CreateAssetWithMint(_) | TokenOp::CreateLiquidityAsset(_) => {}
+            TokenOp::CreatePairPool(op) => {
+                for asset_id in [op.asset_a_id, op.asset_b_id] {
+                    if let Some(asset) = read_asset(&state, requested_at_block_hash, asset_id)? {
+                        view.assets.insert(asset_id, asset);
+                    }
+                    read_owner_balance_and_lock(&state, requested_at_block_hash, asset_id, owner_id, &mut view)?;
+                }
+            }
+            TokenOp::AddPairLiquidity(op) => read_pair_pool(&state, requested_at_block_hash, op.pool_id, owner_id, &mut view)?,
+            TokenOp::RemovePairLiquidity(op) => read_pair_pool(&state, requested_at_block_hash, op.pool_id, owner_id, &mut view)?,
+            TokenOp::SwapPairExactIn(op) => read_pair_pool(&state, requested_at_block_hash, op.pool_id, owner_id, &mut view)?,
+            TokenOp::SwapPairExactOut(op) => read_pair_pool(&state, requested_at_block_hash, op.pool_id, owner_id, &mut view)?,
             TokenOp::Transfer(op) => {
                 if let Some(asset) = read_asset(&state, requested_at_block_hash, op.asset_id)? {
                     view.assets.insert(op.asset_id, asset);
                 }
+                read_lock(&state, requested_at_block_hash, op.asset_id, owner_id, &mut view)?;
                 let sender_key = BalanceKey { asset_id: op.asset_id, owner_id };
                 let sender_balance = read_balance(&state, requested_at_block_hash, op.asset_id, owner_id)?;
                 if sender_balance > 0 {
@@ -1015,6 +1230,7 @@ impl AtomicTokenProcessor {
                 if let Some(asset) = read_asset(&state, requested_at_block_hash, op.asset_id)? {
                     view.assets.insert(op.asset_id, asset);
                 }
+                read_lock(&state, requested_at_block_hash, op.asset_id, owner_id, &mut view)?;
                 let sender_key = BalanceKey { asset_id: op.asset_id, owner_id };
                 let sender_balance = read_balance(&state, requested_at_block_hash, op.asset_id, owner_id)?;
                 if sender_balance > 0 {
@@ -1036,11 +1252,95 @@ impl AtomicTokenProcessor {
                     view.balances.insert(sender_key, sender_balance);
                 }
             }
+            TokenOp::BuyLiquidityExactOut(op) => {
+                if let Some(asset) = read_asset(&state, requested_at_block_hash, op.asset_id)? {
+                    view.assets.insert(op.asset_id, asset);
+                }
+            }
+            TokenOp::SellLiquidityExactOut(op) => {
+                if let Some(asset) = read_asset(&state, requested_at_block_hash, op.asset_id)? {
+                    view.assets.insert(op.asset_id, asset);
+                }
+                let sender_key = BalanceKey { asset_id: op.asset_id, owner_id };
+                let sender_balance = read_balance(&state, requested_at_block_hash, op.asset_id, owner_id)?;
+                if sender_balance > 0 {
+                    view.balances.insert(sender_key, sender_balance);
+                }
+            }
             TokenOp::ClaimLiquidityFees(op) => {
                 if let Some(asset) = read_asset(&state, requested_at_block_hash, op.asset_id)? {
                     view.assets.insert(op.asset_id, asset);
                 }
             }
+            TokenOp::Approve(op) => {
+                if let Some(asset) = read_asset(&state, requested_at_block_hash, op.asset_id)? {
+                    view.assets.insert(op.asset_id, asset);
+                }
+            }
+            TokenOp::TransferMintAuthority(op) => {
+                if let Some(asset) = read_asset(&state, requested_at_block_hash, op.asset_id)? {
+                    view.assets.insert(op.asset_id, asset);
+                }
+            }
+            TokenOp::RenounceMintAuthority(op) => {
+                if let Some(asset) = read_asset(&state, requested_at_block_hash, op.asset_id)? {
+                    view.assets.insert(op.asset_id, asset);
+                }
+            }
+            TokenOp::UpdateMetadata(op) => {
+                if let Some(asset) = read_asset(&state, requested_at_block_hash, op.asset_id)? {
+                    view.assets.insert(op.asset_id, asset);
+                }
+            }
+            TokenOp::TransferFrom(op) => {
+                if let Some(asset) = read_asset(&state, requested_at_block_hash, op.asset_id)? {
+                    view.assets.insert(op.asset_id, asset);
+                }
+                let allowance_key = AllowanceKey { asset_id: op.asset_id, owner_id: op.from_owner_id, spender_id: owner_id };
+                let allowance = match requested_at_block_hash {
+                    Some(at_block_hash) => state.get_allowance_at_block(allowance_key, at_block_hash)?,
+                    None => state.get_allowance(allowance_key),
+                };
+                if let Some(allowance) = allowance {
+                    view.allowances.insert(allowance_key, allowance);
+                }
+                read_lock(&state, requested_at_block_hash, op.asset_id, op.from_owner_id, &mut view)?;
+                for balance_owner_id in [op.from_owner_id, op.to_owner_id] {
+                    let balance = read_balance(&state, requested_at_block_hash, op.asset_id, balance_owner_id)?;
+                    if balance > 0 {
+                        view.balances.insert(BalanceKey { asset_id: op.asset_id, owner_id: balance_owner_id }, balance);
+                    }
+                }
+            }
+            TokenOp::BatchTransfer(op) => {
+                if let Some(asset) = read_asset(&state, requested_at_block_hash, op.asset_id)? {
+                    view.assets.insert(op.asset_id, asset);
+                }
+                let balance_owner_ids = std::iter::once(owner_id).chain(op.recipients.iter().map(|recipient| recipient.to_owner_id));
+                for balance_owner_id in balance_owner_ids {
+                    let balance = read_balance(&state, requested_at_block_hash, op.asset_id, balance_owner_id)?;
+                    if balance > 0 {
+                        view.balances.insert(BalanceKey { asset_id: op.asset_id, owner_id: balance_owner_id }, balance);
+                    }
+                }
+                read_lock(&state, requested_at_block_hash, op.asset_id, owner_id, &mut view)?;
+            }
+            TokenOp::LockTokens(op) => {
+                if let Some(asset) = read_asset(&state, requested_at_block_hash, op.asset_id)? {
+                    view.assets.insert(op.asset_id, asset);
+                }
+                // Pair pools cannot be lock beneficiaries.
+                if let Some(pool) = read_asset(&state, requested_at_block_hash, op.beneficiary_owner_id)? {
+                    view.assets.insert(op.beneficiary_owner_id, pool);
+                }
+                for balance_owner_id in [owner_id, op.beneficiary_owner_id] {
+                    let balance = read_balance(&state, requested_at_block_hash, op.asset_id, balance_owner_id)?;
+                    if balance > 0 {
+                        view.balances.insert(BalanceKey { asset_id: op.asset_id, owner_id: balance_owner_id }, balance);
+                    }
+                    read_lock(&state, requested_at_block_hash, op.asset_id, balance_owner_id, &mut view)?;
+                }
+            }
         }
 
         Some(view)
@@ -1052,7 +1352,7 @@ impl AtomicTokenProcessor {
         include_assets: bool,
         requested_at_block_hash: Option<BlockHash>,
         fallback_block_hash: BlockHash,
-    ) -> Option<(AtomicTokenReadContext, Vec<TokenOwnerBalanceEntry>)> {
+    ) -> Option<(AtomicTokenReadContext, Vec<TokenOwnerBalanceEntry>, HashMap<[u8; 32], TokenLock>)> {
         let latest_sink = self.latest_read_sink(requested_at_block_hash).await?;
         let bootstrap_in_progress = self.bootstrap_in_progress.load(Ordering::SeqCst);
         let state = self.state.read().await;
@@ -1064,12 +1364,24 @@ impl AtomicTokenProcessor {
             Some(at_block_hash) => {
                 let context = state.materialize_context_at_block(at_block_hash, runtime_state)?;
                 let balances = state.indexed_balances_by_owner_at_block(owner_id, include_assets, at_block_hash)?;
-                Some((context, balances))
+                let mut locks = HashMap::new();
+                for (asset_id, _, _) in balances.iter() {
+                    if let Some(lock) = state.get_lock_at_block(BalanceKey { asset_id: *asset_id, owner_id }, at_block_hash)? {
+                        locks.insert(*asset_id, lock);
+                    }
+                }
+                Some((context, balances, locks))
             }
             None => {
                 let context = state.materialize_latest_context(fallback_block_hash, runtime_state);
                 let balances = state.indexed_balances_by_owner(owner_id, include_assets);
-                Some((context, balances))
+                let locks = balances
+                    .iter()
+                    .filter_map(|(asset_id, _, _)| {
+                        state.get_lock(BalanceKey { asset_id: *asset_id, owner_id }).map(|lock| (*asset_id, lock))
+                    })
+                    .collect();
+                Some((context, balances, locks))
             }
         }
     }
@@ -1317,9 +1629,23 @@ impl AtomicTokenService {
             return Ok(());
         };
         if log::log_enabled!(log::Level::Debug) {
-            let p2p_audit_hash =
-                compute_p2p_audit_state_root_from_parts(&view.assets, &view.balances, &view.nonces, &view.anchor_counts);
-            let report = debug_state_root_report_from_parts(&view.assets, &view.balances, &view.nonces, &view.anchor_counts, 4);
+            let p2p_audit_hash = compute_p2p_audit_state_root_from_parts(
+                &view.assets,
+                &view.balances,
+                &view.nonces,
+                &view.anchor_counts,
+                &view.allowances,
+                &view.locks,
+            );
+            let report = debug_state_root_report_from_parts(
+                &view.assets,
+                &view.balances,
+                &view.nonces,
+                &view.anchor_counts,
+                &view.allowances,
+                &view.locks,
+                4,
+            );
             debug!(
                 "[{IDENT}] local Atomic token index debug at DAA-rendezvous block {}: checkpoint_hash={}, recomputed_view_hash={}, p2p_audit_hash={}\n{}",
                 at_block_hash,
@@ -1350,7 +1676,14 @@ impl AtomicTokenService {
             return Ok(None);
         };
         let mut context = view.context();
-        context.state_hash = compute_p2p_audit_state_root_from_parts(&view.assets, &view.balances, &view.nonces, &view.anchor_counts);
+        context.state_hash = compute_p2p_audit_state_root_from_parts(
+            &view.assets,
+            &view.balances,
+            &view.nonces,
+            &view.anchor_counts,
+            &view.allowances,
+            &view.locks,
+        );
         Ok(Some(context))
     }
 
@@ -1401,6 +1734,26 @@ impl AtomicTokenService {
         self.processor.events_since_capped(after_sequence, limit, max_sequence).await
     }
 
+    pub async fn get_filtered_events(
+        &self,
+        filter: TokenEventFilter,
+        after_sequence: u64,
+        limit: usize,
+        max_sequence: u64,
+    ) -> (Vec<TokenEvent>, bool) {
+        self.processor.filtered_events(filter, after_sequence, limit, max_sequence).await
+    }
+
+    /// Event sequence range `(after_sequence, max_sequence]` covering the retained chain blocks with
+    /// `from_daa_score <= daa_score <= to_daa_score`, or `None` if there are none.
+    pub async fn get_event_sequence_range_for_daa(
+        &self,
+        from_daa_score: u64,
+        to_daa_score: u64,
+    ) -> AtomicTokenResult<Option<(u64, u64)>> {
+        self.processor.event_sequence_range_for_daa(from_daa_score, to_daa_score).await
+    }
+
     pub async fn get_read_context(&self, requested_at_block_hash: Option<BlockHash>) -> Option<AtomicTokenReadContext> {
         self.processor.read_context(requested_at_block_hash, self.genesis_hash).await
     }
@@ -1411,6 +1764,18 @@ impl AtomicTokenService {
         owner_id: [u8; 32],
         requested_at_block_hash: Option<BlockHash>,
     ) -> Option<(AtomicTokenReadContext, u128)> {
+        self.get_balance_and_lock_with_context(asset_id, owner_id, requested_at_block_hash)
+            .await
+            .map(|(context, balance, _)| (context, balance))
+    }
+
+    /// Balance together with any vesting lock on it, read at the same context.
+    pub async fn get_balance_and_lock_with_context(
+        &self,
+        asset_id: [u8; 32],
+        owner_id: [u8; 32],
+        requested_at_block_hash: Option<BlockHash>,
+    ) -> Option<(AtomicTokenReadContext, u128, Option<TokenLock>)> {
         self.processor.balance_read(asset_id, owner_id, requested_at_block_hash, self.genesis_hash).await
     }
 
@@ -1438,6 +1803,44 @@ impl AtomicTokenService {
         self.processor.asset_read(asset_id, requested_at_block_hash, self.genesis_hash).await
     }
 
+    /// Balance and asset-scope nonce proofs against the canonical state root of the read context.
+    pub async fn get_balance_proof_with_context(
+        &self,
+        asset_id: [u8; 32],
+        owner_id: [u8; 32],
+        requested_at_block_hash: Option<BlockHash>,
+    ) -> Option<(AtomicTokenReadContext, AtomicStateProof, AtomicStateProof)> {
+        self.processor.balance_proof_read(asset_id, owner_id, requested_at_block_hash, self.genesis_hash).await
+    }
+
+    /// Pair pool asset and its reserves; `None` inside when the asset is missing or not a pair pool.
+    pub async fn get_pair_pool_with_context(
+        &self,
+        pool_id: [u8; 32],
+        requested_at_block_hash: Option<BlockHash>,
+    ) -> Option<(AtomicTokenReadContext, Option<(TokenAsset, u128, u128, u128)>)> {
+        self.processor.pair_pool_read(pool_id, requested_at_block_hash, self.genesis_hash).await
+    }
+
+    /// OHLC candles built from the retained trade records of a liquidity pool at the latest context.
+    pub async fn get_liquidity_candles_with_context(
+        &self,
+        asset_id: [u8; 32],
+        interval_daa_score: u64,
+        from_daa_score: u64,
+        to_daa_score: u64,
+    ) -> Option<(AtomicTokenReadContext, Vec<LiquidityCandle>)> {
+        self.processor.liquidity_candles_read(asset_id, interval_daa_score, from_daa_score, to_daa_score, self.genesis_hash).await
+    }
+
+    pub async fn get_asset_proof_with_context(
+        &self,
+        asset_id: [u8; 32],
+        requested_at_block_hash: Option<BlockHash>,
+    ) -> Option<(AtomicTokenReadContext, Option<TokenAsset>, AtomicStateProof)> {
+        self.processor.asset_proof_read(asset_id, requested_at_block_hash, self.genesis_hash).await
+    }
+
     pub async fn get_op_status_with_context(
         &self,
         txid: BlockHash,
@@ -1568,6 +1971,8 @@ impl AtomicTokenService {
             &imported_state.balances,
             &imported_state.nonces,
             &imported_state.anchor_counts,
+            &imported_state.allowances,
+            &imported_state.locks,
         );
         if let Some(expected_p2p_audit_hash) = expected_p2p_audit_hash {
             if actual_p2p_audit_hash != expected_p2p_audit_hash {
@@ -1722,7 +2127,7 @@ impl AtomicTokenService {
         owner_id: [u8; 32],
         include_assets: bool,
         requested_at_block_hash: Option<BlockHash>,
-    ) -> Option<(AtomicTokenReadContext, Vec<TokenOwnerBalanceEntry>)> {
+    ) -> Option<(AtomicTokenReadContext, Vec<TokenOwnerBalanceEntry>, HashMap<[u8; 32], TokenLock>)> {
         self.processor.indexed_balances_by_owner(owner_id, include_assets, requested_at_block_hash, self.genesis_hash).await
     }
 
@@ -1885,6 +2290,8 @@ impl AtomicTokenService {
                         Vec::<(BalanceKey, Option<u128>)>::new(),
                         Vec::<(NonceKey, Option<u64>)>::new(),
                         Vec::<([u8; 32], Option<u64>)>::new(),
+                        Vec::<(AllowanceKey, Option<TokenAllowance>)>::new(),
+                        Vec::<(BalanceKey, Option<TokenLock>)>::new(),
                         Vec::<(BlockHash, Option<ProcessedOp>)>::new(),
                     )?;
                     {
@@ -2696,6 +3103,70 @@ impl AtomicTokenService {
         Ok(())
     }
 
+    /// Writes the documented JSONL analytics export of assets, balances, holders, pools and retained events
+    /// into `dir`, taken at `at_block_hash` (a retained Atomic chain block) or at the latest applied block.
+    pub async fn export_analytics_to_dir<P: AsRef<Path>>(
+        &self,
+        dir: P,
+        at_block_hash: Option<BlockHash>,
+    ) -> AtomicTokenResult<AnalyticsExportManifest> {
+        let _operation_guard = self.processor.operation_lock.lock().await;
+        self.ensure_bootstrap_serving_ready().await?;
+        let at_block_hash = {
+            let state = self.processor.state.read().await;
+            let at_block_hash = match at_block_hash {
+                Some(hash) => hash,
+                None => *state.applied_chain_order.last().ok_or_else(|| {
+                    AtomicTokenError::Processing("analytics export failed: no local Atomic chain order available".to_string())
+                })?,
+            };
+            if !state.state_hash_by_block.contains_key(&at_block_hash) {
+                return Err(AtomicTokenError::Processing(format!(
+                    "analytics export failed: block `{at_block_hash}` is not a retained Atomic chain block"
+                )));
+            }
+            at_block_hash
+        };
+        let at_daa_score = {
+            let consensus = self.processor.consensus_manager.consensus();
+            let session = consensus.session().await;
+            session.async_get_header(at_block_hash).await?.daa_score
+        };
+
+        let temp_state_dir = unique_atomic_temp_dir(&self.atomic_data_dir, "analytics-export")?;
+        let _temp_state_cleanup = RemoveDirOnDrop::new(temp_state_dir.clone());
+        self.processor.state_store.checkpoint_to(&temp_state_dir)?;
+        let temp_state_store =
+            Arc::new(AtomicStorageV2::open(&temp_state_dir, self.protocol_version, self.network_id.clone(), self.genesis_hash)?);
+        let mut state = temp_state_store.load_runtime_state()?.ok_or_else(|| {
+            AtomicTokenError::Processing("analytics export failed: no persisted Atomic V2 state is available".to_string())
+        })?;
+        state.attach_state_store(temp_state_store.clone());
+        state.rollback_to_block_persisted(at_block_hash)?;
+
+        let state_root = state.state_hash_by_block.get(&at_block_hash).copied().ok_or_else(|| {
+            AtomicTokenError::Processing(format!("analytics export failed: missing state hash for block `{at_block_hash}`"))
+        })?;
+        if state.compute_state_hash() != state_root {
+            return Err(AtomicTokenError::Processing(format!(
+                "analytics export failed: rolled-back state does not match the state root recorded for block `{at_block_hash}`"
+            )));
+        }
+        let anchor = AnalyticsExportAnchor {
+            network_id: self.network_id.clone(),
+            protocol_version: self.protocol_version,
+            at_block_hash,
+            at_daa_score,
+            state_root,
+            event_sequence_cutoff: state.event_sequence_by_block.get(&at_block_hash).copied().unwrap_or(state.next_event_sequence),
+            exported_at: unix_now(),
+        };
+        let manifest = write_analytics_export(dir.as_ref(), &anchor, &temp_state_store)?;
+        drop(state);
+        drop(temp_state_store);
+        Ok(manifest)
+    }
+
     pub async fn import_snapshot_from_file<P: AsRef<Path>>(&self, path: P) -> AtomicTokenResult<()> {
         let _operation_guard = self.processor.operation_lock.lock().await;
         self.processor.set_bootstrap_in_progress(true);
@@ -3141,6 +3612,14 @@ fn write_snapshot_file_from_store(path: &Path, header: &SnapshotFileHeaderV2, st
         bincode::serialize_into(&mut writer, &(owner_id, count))
             .map_err(|e| AtomicTokenError::Processing(format!("snapshot anchor count encode failed: {e}")))
     })?;
+    store.visit_all_allowances(|key, allowance| {
+        bincode::serialize_into(&mut writer, &(key, allowance))
+            .map_err(|e| AtomicTokenError::Processing(format!("snapshot allowance encode failed: {e}")))
+    })?;
+    store.visit_all_locks(|key, lock| {
+        bincode::serialize_into(&mut writer, &(key, lock))
+            .map_err(|e| AtomicTokenError::Processing(format!("snapshot lock encode failed: {e}")))
+    })?;
     store.visit_all_processed_ops(|txid, op| {
         bincode::serialize_into(&mut writer, &(txid, op))
             .map_err(|e| AtomicTokenError::Processing(format!("snapshot processed-op encode failed: {e}")))
@@ -3258,12 +3737,20 @@ struct SnapshotStateImportChunk {
     balances: Vec<(BalanceKey, Option<u128>)>,
     nonces: Vec<(NonceKey, Option<u64>)>,
     anchor_counts: Vec<([u8; 32], Option<u64>)>,
+    allowances: Vec<(AllowanceKey, Option<TokenAllowance>)>,
+    locks: Vec<(BalanceKey, Option<TokenLock>)>,
     processed_ops: Vec<(BlockHash, Option<ProcessedOp>)>,
 }
 
 impl SnapshotStateImportChunk {
     fn len(&self) -> usize {
-        self.assets.len() + self.balances.len() + self.nonces.len() + self.anchor_counts.len() + self.processed_ops.len()
+        self.assets.len()
+            + self.balances.len()
+            + self.nonces.len()
+            + self.anchor_counts.len()
+            + self.allowances.len()
+            + self.locks.len()
+            + self.processed_ops.len()
     }
 
     fn flush_if_full(&mut self, store: &AtomicStorageV2) -> AtomicTokenResult<()> {
@@ -3281,8 +3768,10 @@ impl SnapshotStateImportChunk {
         let balances = std::mem::take(&mut self.balances);
         let nonces = std::mem::take(&mut self.nonces);
         let anchor_counts = std::mem::take(&mut self.anchor_counts);
+        let allowances = std::mem::take(&mut self.allowances);
+        let locks = std::mem::take(&mut self.locks);
         let processed_ops = std::mem::take(&mut self.processed_ops);
-        store.apply_current_state_delta(assets, balances, nonces, anchor_counts, processed_ops)?;
+        store.apply_current_state_delta(assets, balances, nonces, anchor_counts, allowances, locks, processed_ops)?;
         Ok(())
     }
 }
@@ -3538,6 +4027,16 @@ fn import_snapshot_file_into_store(
         chunk.anchor_counts.push((owner_id, (count > 0).then_some(count)));
         chunk.flush_if_full(&store)?;
     }
+    for _ in 0..counts.allowances {
+        let (key, allowance): (AllowanceKey, TokenAllowance) = read_snapshot_record(&mut reader, "allowance")?;
+        chunk.allowances.push((key, (allowance.amount > 0).then_some(allowance)));
+        chunk.flush_if_full(&store)?;
+    }
+    for _ in 0..counts.locks {
+        let (key, lock): (BalanceKey, TokenLock) = read_snapshot_record(&mut reader, "lock")?;
+        chunk.locks.push((key, (lock.amount > 0).then_some(lock)));
+        chunk.flush_if_full(&store)?;
+    }
     for _ in 0..counts.processed_ops {
         let (txid, op): (BlockHash, ProcessedOp) = read_snapshot_record(&mut reader, "processed op")?;
         if accepting_blocks_in_window.contains(&op.accepting_block_hash) {
@@ -3969,6 +4468,8 @@ fn snapshot_counts_from_overlay_footprint(footprint: &AtomicTokenStateFootprint)
         balances: footprint.balances as u64,
         nonces: footprint.nonces as u64,
         anchor_counts: footprint.anchor_counts as u64,
+        allowances: footprint.allowances as u64,
+        locks: footprint.locks as u64,
         processed_ops: footprint.processed_ops as u64,
         state_hashes: footprint.state_hash_checkpoints as u64,
         event_sequences: footprint.event_sequence_checkpoints as u64,
@@ -1,23 +1,39 @@
 use crate::{
     error::{AtomicTokenError, AtomicTokenResult},
     liquidity_math::{
-        calculate_trade_fee, cpmm_buy, cpmm_sell, initial_virtual_cpay_reserves_sompi_for_curve,
-        initial_virtual_token_reserves_for_curve, min_gross_input_for_token_out, validate_liquidity_curve_mode,
-        validate_liquidity_curve_parameters, LiquidityMathError, DEFAULT_LIQUIDITY_CURVE_MODE, INITIAL_REAL_CPAY_RESERVES_SOMPI,
-        LIQUIDITY_MIN_PAYOUT_SOMPI, LIQUIDITY_TOKEN_DECIMALS, MAX_LIQUIDITY_SUPPLY_RAW, MIN_CPAY_RESERVE_SOMPI,
-        MIN_LIQUIDITY_SEED_RESERVE_SOMPI, MIN_LIQUIDITY_SUPPLY_RAW, MIN_REAL_TOKEN_RESERVE,
+        calculate_trade_fee, cpmm_buy, cpmm_buy_exact_out, cpmm_sell, cpmm_sell_exact_out, gross_amount_for_net_amount,
+        initial_virtual_cpay_reserves_sompi_for_curve, initial_virtual_token_reserves_for_curve, min_gross_input_for_token_out,
+        pair_add_liquidity, pair_initial_shares, pair_remove_liquidity, pair_swap_exact_in, pair_swap_exact_out,
+        validate_liquidity_curve_mode, validate_liquidity_curve_parameters, LiquidityMathError, DEFAULT_LIQUIDITY_CURVE_MODE,
+        INITIAL_REAL_CPAY_RESERVES_SOMPI, LIQUIDITY_MIN_PAYOUT_SOMPI, LIQUIDITY_TOKEN_DECIMALS, MAX_LIQUIDITY_SUPPLY_RAW,
+        MIN_CPAY_RESERVE_SOMPI, MIN_LIQUIDITY_SEED_RESERVE_SOMPI, MIN_LIQUIDITY_SUPPLY_RAW, MIN_PAIR_LIQUIDITY_SHARES,
+        MIN_REAL_TOKEN_RESERVE,
     },
     payload::{
-        parse_atomic_token_payload, ApplyStatus, BuyLiquidityExactInOp, ClaimLiquidityFeesOp, CreateAssetOp, CreateAssetWithMintOp,
-        CreateLiquidityAssetOp, EventType, LiquidityRecipientAddress, MintOp, NoopReason, ParsedTokenPayload, SellLiquidityExactInOp,
-        SupplyMode, TokenOp, TokenOpCode, CURRENT_LIQUIDITY_CURVE_VERSION, CURRENT_TOKEN_VERSION,
+        parse_atomic_token_payload, AddPairLiquidityOp, ApplyStatus, ApproveOp, BatchTransferOp, BuyLiquidityExactInOp,
+        BuyLiquidityExactOutOp, ClaimLiquidityFeesOp, CreateAssetOp, CreateAssetWithMintOp, CreateLiquidityAssetOp, CreatePairPoolOp,
+        EventType, LiquidityRecipientAddress, LockTokensOp, MintOp, NoopReason, ParsedTokenPayload, RemovePairLiquidityOp,
+        RenounceMintAuthorityOp, SellLiquidityExactInOp, SellLiquidityExactOutOp, SupplyMode, SwapPairExactInOp, SwapPairExactOutOp,
+        TokenOp, TokenOpCode, TransferFromOp, TransferMintAuthorityOp, UpdateMetadataOp, CURRENT_LIQUIDITY_CURVE_VERSION,
+        CURRENT_TOKEN_VERSION, MAX_BATCH_TRANSFER_RECIPIENTS,
+    },
+    storage_v2::{
+        compute_state_root_from_parts, root_leaf_value_for_allowance, root_leaf_value_for_anchor_count, root_leaf_value_for_asset,
+        root_leaf_value_for_balance, root_leaf_value_for_lock, root_leaf_value_for_nonce, AtomicStorageV2,
     },
-    storage_v2::{compute_state_root_from_parts, AtomicStorageV2},
     IDENT,
 };
 use blake2b_simd::Params as Blake2bParams;
+use cryptix_atomic_proof::{
+    bucket_index, leaf_hash, root_from_buckets, xor_hash, AtomicStateLeaf, AtomicStateProof, ATOMIC_ROOT_BUCKETS, LOGICAL_ALLOWANCE,
+    LOGICAL_ANCHOR_COUNT, LOGICAL_ASSET, LOGICAL_BALANCE, LOGICAL_LOCK, LOGICAL_NONCE,
+};
 use cryptix_consensus_core::{
     acceptance_data::AcceptanceData,
+    atomic::{
+        cat_owner_id, cat_owner_id_from_address_components, CAT_OWNER_AUTH_SCHEME_PUBKEY, CAT_OWNER_AUTH_SCHEME_PUBKEY_ECDSA,
+        CAT_OWNER_AUTH_SCHEME_SCRIPT_HASH,
+    },
     constants::MAX_SOMPI,
     tx::{ScriptPublicKey, Transaction, TransactionOutpoint, UtxoEntry},
     Hash as BlockHash,
@@ -28,22 +44,21 @@ use cryptix_core::{info, warn};
 use cryptix_txscript::script_class::ScriptClass;
 use serde::{Deserialize, Serialize};
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{BTreeMap, HashMap, HashSet},
     sync::Arc,
     time::{Duration, Instant},
 };
 
-const CAT_OWNER_DOMAIN: &[u8] = b"CAT_OWNER_V2";
-const OWNER_AUTH_SCHEME_PUBKEY: u8 = 0;
-const OWNER_AUTH_SCHEME_PUBKEY_ECDSA: u8 = 1;
-const OWNER_AUTH_SCHEME_SCRIPT_HASH: u8 = 2;
 const LONG_ATOMIC_REPLAY_LOG_INTERVAL: Duration = Duration::from_secs(5);
 
 const CAT_EVENT_DOMAIN: &[u8] = b"CAT_EVT_V2";
 const CAT_EVENT_INSTANCE_DOMAIN: &[u8] = b"CAT_EVT_INSTANCE_V2";
-pub const SNAPSHOT_SCHEMA_VERSION: u16 = 2;
+pub const SNAPSHOT_SCHEMA_VERSION: u16 = 7;
 pub const NONCE_SCOPE_OWNER: u8 = 0;
 pub const NONCE_SCOPE_ASSET: u8 = 1;
+pub const MAX_METADATA_HISTORY_REVISIONS: usize = 16;
+const PAIR_LP_NAME: &[u8] = b"CAT Pair LP";
+const PAIR_LP_SYMBOL: &[u8] = b"CATLP";
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct NonceKey {
@@ -96,14 +111,106 @@ pub struct TokenEventDetails {
     pub from_owner_id: Option<[u8; 32]>,
     pub to_owner_id: Option<[u8; 32]>,
     pub amount: Option<u128>,
+    #[serde(default)]
+    pub trade: Option<LiquidityTradeDetails>,
 }
 
 impl Default for TokenEventDetails {
     fn default() -> Self {
-        Self { op_type: None, asset_id: None, from_owner_id: None, to_owner_id: None, amount: None }
+        Self { op_type: None, asset_id: None, from_owner_id: None, to_owner_id: None, amount: None, trade: None }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LiquidityTradeSide {
+    Buy,
+    Sell,
+}
+
+/// Settlement of an applied liquidity buy or sell, carried on its event so the price series
+/// follows event retention, reorgs and pruning.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LiquidityTradeDetails {
+    pub daa_score: u64,
+    pub side: LiquidityTradeSide,
+    /// CPAY that crossed the curve including the trade fee: the buyer's gross input or the seller's gross output.
+    pub cpay_volume_sompi: u64,
+    pub token_amount: u128,
+    pub fee_sompi: u64,
+    /// Execution price in sompi per raw token unit, rounded down.
+    pub price_sompi: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LiquidityTradeRecord {
+    pub sequence: u64,
+    pub event_id: [u8; 32],
+    pub txid: BlockHash,
+    pub accepting_block_hash: BlockHash,
+    pub trade: LiquidityTradeDetails,
+}
+
+/// Server-side filter for token event queries. `None` and empty fields match every event.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TokenEventFilter {
+    /// Matches events where the owner is either the sender or the recipient.
+    pub owner_id: Option<[u8; 32]>,
+    pub asset_id: Option<[u8; 32]>,
+    pub op_types: Vec<u32>,
+}
+
+impl TokenEventFilter {
+    pub fn matches(&self, event: &TokenEvent) -> bool {
+        let details = &event.details;
+        if let Some(owner_id) = self.owner_id.as_ref() {
+            if details.from_owner_id.as_ref() != Some(owner_id) && details.to_owner_id.as_ref() != Some(owner_id) {
+                return false;
+            }
+        }
+        if self.asset_id.is_some() && details.asset_id != self.asset_id {
+            return false;
+        }
+        self.op_types.is_empty() || details.op_type.is_some_and(|op| self.op_types.contains(&(op as u32)))
+    }
+}
+
+struct FilteredEventsPage<'a> {
+    filter: &'a TokenEventFilter,
+    limit: usize,
+    max_sequence: u64,
+    events: Vec<TokenEvent>,
+    has_more: bool,
+}
+
+impl FilteredEventsPage<'_> {
+    /// Offers the next event in sequence order; returns `false` once the page is complete.
+    fn push(&mut self, event: &TokenEvent) -> bool {
+        if event.sequence > self.max_sequence {
+            return false;
+        }
+        if !self.filter.matches(event) {
+            return true;
+        }
+        if self.events.len() >= self.limit {
+            self.has_more = true;
+            return false;
+        }
+        self.events.push(event.clone());
+        true
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LiquidityCandle {
+    pub start_daa_score: u64,
+    pub open_price_sompi: u64,
+    pub high_price_sompi: u64,
+    pub low_price_sompi: u64,
+    pub close_price_sompi: u64,
+    pub volume_sompi: u128,
+    pub trade_count: u64,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TokenEvent {
     pub event_id: [u8; 32],
@@ -141,10 +248,61 @@ pub struct AtomicTokenReadView {
     pub balances: HashMap<BalanceKey, u128>,
     pub nonces: HashMap<NonceKey, u64>,
     pub anchor_counts: HashMap<[u8; 32], u64>,
+    pub allowances: HashMap<AllowanceKey, TokenAllowance>,
+    pub locks: HashMap<BalanceKey, TokenLock>,
     pub processed_ops: HashMap<BlockHash, ProcessedOp>,
     pub known_owner_addresses: HashMap<[u8; 32], LiquidityHolderAddressState>,
 }
 
+/// Logical key of a leaf committed in the canonical Atomic state root.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum RootLeafKey {
+    Asset([u8; 32]),
+    Balance(BalanceKey),
+    Nonce(NonceKey),
+    AnchorCount([u8; 32]),
+    Allowance(AllowanceKey),
+    Lock(BalanceKey),
+}
+
+impl RootLeafKey {
+    fn logical_key(&self) -> Vec<u8> {
+        match self {
+            Self::Asset(asset_id) => cryptix_atomic_proof::logical_asset_key(asset_id),
+            Self::Balance(key) => cryptix_atomic_proof::logical_balance_key(&key.asset_id, &key.owner_id),
+            Self::Nonce(key) => cryptix_atomic_proof::logical_nonce_key(&key.owner_id, key.scope_kind, &key.scope_id),
+            Self::AnchorCount(owner_id) => cryptix_atomic_proof::logical_anchor_count_key(owner_id),
+            Self::Allowance(key) => cryptix_atomic_proof::logical_allowance_key(&key.asset_id, &key.owner_id, &key.spender_id),
+            Self::Lock(key) => cryptix_atomic_proof::logical_lock_key(&key.asset_id, &key.owner_id),
+        }
+    }
+
+    fn from_logical_key(logical_key: &[u8]) -> Option<Self> {
+        let fixed_32 = |bytes: &[u8]| <[u8; 32]>::try_from(bytes).ok();
+        match (logical_key.first().copied()?, logical_key.len()) {
+            (LOGICAL_ASSET, 33) => Some(Self::Asset(fixed_32(&logical_key[1..33])?)),
+            (LOGICAL_BALANCE, 65) => {
+                Some(Self::Balance(BalanceKey { asset_id: fixed_32(&logical_key[1..33])?, owner_id: fixed_32(&logical_key[33..65])? }))
+            }
+            (LOGICAL_NONCE, 66) => Some(Self::Nonce(NonceKey {
+                owner_id: fixed_32(&logical_key[1..33])?,
+                scope_kind: logical_key[33],
+                scope_id: fixed_32(&logical_key[34..66])?,
+            })),
+            (LOGICAL_ANCHOR_COUNT, 33) => Some(Self::AnchorCount(fixed_32(&logical_key[1..33])?)),
+            (LOGICAL_ALLOWANCE, 97) => Some(Self::Allowance(AllowanceKey {
+                asset_id: fixed_32(&logical_key[1..33])?,
+                owner_id: fixed_32(&logical_key[33..65])?,
+                spender_id: fixed_32(&logical_key[65..97])?,
+            })),
+            (LOGICAL_LOCK, 65) => {
+                Some(Self::Lock(BalanceKey { asset_id: fixed_32(&logical_key[1..33])?, owner_id: fixed_32(&logical_key[33..65])? }))
+            }
+            _ => None,
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct AtomicTokenReadContext {
     pub at_block_hash: BlockHash,
@@ -193,6 +351,8 @@ pub struct AtomicTokenSnapshotState {
     pub balances: HashMap<BalanceKey, u128>,
     pub nonces: HashMap<NonceKey, u64>,
     pub anchor_counts: HashMap<[u8; 32], u64>,
+    pub allowances: HashMap<AllowanceKey, TokenAllowance>,
+    pub locks: HashMap<BalanceKey, TokenLock>,
     pub processed_ops: HashMap<BlockHash, ProcessedOp>,
     pub state_hash_by_block: HashMap<BlockHash, [u8; 32]>,
     pub event_sequence_by_block: HashMap<BlockHash, u64>,
@@ -218,6 +378,53 @@ pub struct BalanceKey {
     pub owner_id: [u8; 32],
 }
 
+/// Allowance granted by `owner_id` to `spender_id` over one asset.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct AllowanceKey {
+    pub asset_id: [u8; 32],
+    pub owner_id: [u8; 32],
+    pub spender_id: [u8; 32],
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenAllowance {
+    pub amount: u128,
+    /// Accepting-block DAA score from which the allowance is unusable; `0` never expires.
+    pub expires_at_daa_score: u64,
+}
+
+impl TokenAllowance {
+    pub fn is_expired_at(&self, daa_score: u64) -> bool {
+        self.expires_at_daa_score != 0 && daa_score >= self.expires_at_daa_score
+    }
+}
+
+/// Vesting lock over part of the balance under the same `BalanceKey`. Locked tokens are still counted in
+/// the balance but cannot leave it until they release linearly between the two accepting-block DAA scores.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenLock {
+    pub amount: u128,
+    pub release_start_daa_score: u64,
+    pub release_end_daa_score: u64,
+}
+
+impl TokenLock {
+    /// Amount still locked at `daa_score`, rounded up so nothing releases early.
+    pub fn locked_at(&self, daa_score: u64) -> u128 {
+        if daa_score >= self.release_end_daa_score {
+            return 0;
+        }
+        if daa_score <= self.release_start_daa_score {
+            return self.amount;
+        }
+        let span = u128::from(self.release_end_daa_score - self.release_start_daa_score);
+        let remaining = u128::from(self.release_end_daa_score - daa_score);
+        let whole = self.amount / span * remaining;
+        let partial = (self.amount % span * remaining).div_ceil(span);
+        whole + partial
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
 #[repr(u8)]
@@ -225,6 +432,28 @@ pub enum TokenAssetClass {
     #[default]
     Standard,
     Liquidity,
+    Pair,
+}
+
+/// Constant-product pool between two standard assets. Reserves are ordinary balances owned by the pool id;
+/// the pool asset itself is the LP share token.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PairPoolState {
+    pub asset_a_id: [u8; 32],
+    pub asset_b_id: [u8; 32],
+    pub fee_bps: u16,
+    pub accrued_fee_a: u128,
+    pub accrued_fee_b: u128,
+}
+
+impl PairPoolState {
+    pub fn reserve_key_a(&self, pool_id: [u8; 32]) -> BalanceKey {
+        BalanceKey { asset_id: self.asset_a_id, owner_id: pool_id }
+    }
+
+    pub fn reserve_key_b(&self, pool_id: [u8; 32]) -> BalanceKey {
+        BalanceKey { asset_id: self.asset_b_id, owner_id: pool_id }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -311,6 +540,25 @@ pub struct TokenAsset {
     pub created_at: Option<u64>,
     #[serde(default)]
     pub liquidity: Option<LiquidityPoolState>,
+    #[serde(default)]
+    pub pair_pool: Option<PairPoolState>,
+    /// Metadata revisions applied by `UpdateMetadata`, oldest first, capped at `MAX_METADATA_HISTORY_REVISIONS`.
+    #[serde(default)]
+    pub metadata_history: Vec<TokenMetadataRevision>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenMetadataRevision {
+    /// 1-based count of metadata updates applied to the asset; keeps counting after old revisions are dropped.
+    pub revision: u64,
+    pub txid: BlockHash,
+    pub previous_metadata: Vec<u8>,
+    pub previous_platform_tag: Vec<u8>,
+    pub metadata: Vec<u8>,
+    pub platform_tag: Vec<u8>,
+    pub updated_block_hash: BlockHash,
+    pub updated_daa_score: u64,
+    pub updated_at: u64,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -344,12 +592,28 @@ pub struct ChangedAnchorCount {
     pub old_value: Option<u64>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChangedAllowance {
+    pub key: AllowanceKey,
+    pub old_value: Option<TokenAllowance>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChangedLock {
+    pub key: BalanceKey,
+    pub old_value: Option<TokenLock>,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Default)]
 pub struct BlockJournal {
     pub changed_assets: Vec<ChangedAsset>,
     pub changed_balances: Vec<ChangedBalance>,
     pub changed_nonces: Vec<ChangedNonce>,
     pub changed_anchor_counts: Vec<ChangedAnchorCount>,
+    #[serde(default)]
+    pub changed_allowances: Vec<ChangedAllowance>,
+    #[serde(default)]
+    pub changed_locks: Vec<ChangedLock>,
     pub added_processed_ops: Vec<BlockHash>,
     pub tx_results: Vec<TokenApplyResult>,
 }
@@ -360,12 +624,16 @@ struct JournalBuilder {
     changed_balances: Vec<ChangedBalance>,
     changed_nonces: Vec<ChangedNonce>,
     changed_anchor_counts: Vec<ChangedAnchorCount>,
+    changed_allowances: Vec<ChangedAllowance>,
+    changed_locks: Vec<ChangedLock>,
     added_processed_ops: Vec<BlockHash>,
     tx_results: Vec<TokenApplyResult>,
     seen_assets: HashSet<[u8; 32]>,
     seen_balances: HashSet<BalanceKey>,
     seen_nonces: HashSet<NonceKey>,
     seen_anchor_counts: HashSet<[u8; 32]>,
+    seen_allowances: HashSet<AllowanceKey>,
+    seen_locks: HashSet<BalanceKey>,
 }
 
 #[derive(Default)]
@@ -374,6 +642,8 @@ struct StorageDelta {
     balances: Vec<(BalanceKey, Option<u128>)>,
     nonces: Vec<(NonceKey, Option<u64>)>,
     anchor_counts: Vec<([u8; 32], Option<u64>)>,
+    allowances: Vec<(AllowanceKey, Option<TokenAllowance>)>,
+    locks: Vec<(BalanceKey, Option<TokenLock>)>,
     processed_ops: Vec<(BlockHash, Option<ProcessedOp>)>,
 }
 
@@ -384,6 +654,8 @@ impl JournalBuilder {
             changed_balances: self.changed_balances,
             changed_nonces: self.changed_nonces,
             changed_anchor_counts: self.changed_anchor_counts,
+            changed_allowances: self.changed_allowances,
+            changed_locks: self.changed_locks,
             added_processed_ops: self.added_processed_ops,
             tx_results: self.tx_results,
         }
@@ -404,6 +676,8 @@ fn token_op_allows_liquidity_vault_output(op: &TokenOp) -> bool {
             | TokenOp::BuyLiquidityExactIn(_)
             | TokenOp::SellLiquidityExactIn(_)
             | TokenOp::ClaimLiquidityFees(_)
+            | TokenOp::BuyLiquidityExactOut(_)
+            | TokenOp::SellLiquidityExactOut(_)
     )
 }
 
@@ -416,9 +690,59 @@ pub fn nonce_key_for_op(owner_id: [u8; 32], op: &TokenOp) -> NonceKey {
         TokenOp::BuyLiquidityExactIn(op) => NonceKey::asset(owner_id, op.asset_id),
         TokenOp::SellLiquidityExactIn(op) => NonceKey::asset(owner_id, op.asset_id),
         TokenOp::ClaimLiquidityFees(op) => NonceKey::asset(owner_id, op.asset_id),
+        TokenOp::Approve(op) => NonceKey::asset(owner_id, op.asset_id),
+        TokenOp::TransferFrom(op) => NonceKey::asset(owner_id, op.asset_id),
+        TokenOp::BatchTransfer(op) => NonceKey::asset(owner_id, op.asset_id),
+        TokenOp::LockTokens(op) => NonceKey::asset(owner_id, op.asset_id),
+        TokenOp::TransferMintAuthority(op) => NonceKey::asset(owner_id, op.asset_id),
+        TokenOp::RenounceMintAuthority(op) => NonceKey::asset(owner_id, op.asset_id),
+        TokenOp::UpdateMetadata(op) => NonceKey::asset(owner_id, op.asset_id),
+        TokenOp::CreatePairPool(_) => NonceKey::owner(owner_id),
+        TokenOp::AddPairLiquidity(op) => NonceKey::asset(owner_id, op.pool_id),
+        TokenOp::RemovePairLiquidity(op) => NonceKey::asset(owner_id, op.pool_id),
+        TokenOp::SwapPairExactIn(op) => NonceKey::asset(owner_id, op.pool_id),
+        TokenOp::SwapPairExactOut(op) => NonceKey::asset(owner_id, op.pool_id),
+        TokenOp::BuyLiquidityExactOut(op) => NonceKey::asset(owner_id, op.asset_id),
+        TokenOp::SellLiquidityExactOut(op) => NonceKey::asset(owner_id, op.asset_id),
+    }
+}
+
+fn liquidity_trade_target(op: &TokenOp) -> Option<(LiquidityTradeSide, [u8; 32])> {
+    match op {
+        TokenOp::BuyLiquidityExactIn(op) => Some((LiquidityTradeSide::Buy, op.asset_id)),
+        TokenOp::BuyLiquidityExactOut(op) => Some((LiquidityTradeSide::Buy, op.asset_id)),
+        TokenOp::SellLiquidityExactIn(op) => Some((LiquidityTradeSide::Sell, op.asset_id)),
+        TokenOp::SellLiquidityExactOut(op) => Some((LiquidityTradeSide::Sell, op.asset_id)),
+        _ => None,
     }
 }
 
+/// Derives the settled trade from the pool transition: real token reserves move by the traded amount,
+/// real CPAY reserves by the net CPAY and the unclaimed fee total by the trade fee.
+fn liquidity_trade_details(
+    side: LiquidityTradeSide,
+    before: &LiquidityPoolState,
+    after: &LiquidityPoolState,
+    daa_score: u64,
+) -> Option<LiquidityTradeDetails> {
+    let fee_sompi = after.unclaimed_fee_total_sompi.checked_sub(before.unclaimed_fee_total_sompi)?;
+    let (token_amount, cpay_volume_sompi) = match side {
+        LiquidityTradeSide::Buy => (
+            before.real_token_reserves.checked_sub(after.real_token_reserves)?,
+            after.real_cpay_reserves_sompi.checked_sub(before.real_cpay_reserves_sompi)?.checked_add(fee_sompi)?,
+        ),
+        LiquidityTradeSide::Sell => (
+            after.real_token_reserves.checked_sub(before.real_token_reserves)?,
+            before.real_cpay_reserves_sompi.checked_sub(after.real_cpay_reserves_sompi)?,
+        ),
+    };
+    if token_amount == 0 {
+        return None;
+    }
+    let price_sompi = u64::try_from(u128::from(cpay_volume_sompi) / token_amount).ok()?;
+    Some(LiquidityTradeDetails { daa_score, side, cpay_volume_sompi, token_amount, fee_sompi, price_sompi })
+}
+
 fn asset_matches_query(asset: &TokenAsset, query: &str) -> bool {
     let query = query.trim().to_ascii_lowercase();
     if query.is_empty() {
@@ -516,6 +840,10 @@ pub struct AtomicTokenState {
     pub balances: HashMap<BalanceKey, u128>,
     pub nonces: HashMap<NonceKey, u64>,
     pub anchor_counts: HashMap<[u8; 32], u64>,
+    #[serde(default)]
+    pub allowances: HashMap<AllowanceKey, TokenAllowance>,
+    #[serde(default)]
+    pub locks: HashMap<BalanceKey, TokenLock>,
     pub processed_ops: HashMap<BlockHash, ProcessedOp>,
     pub block_journals: HashMap<BlockHash, BlockJournal>,
     pub state_hash_by_block: HashMap<BlockHash, [u8; 32]>,
@@ -526,6 +854,8 @@ pub struct AtomicTokenState {
     #[serde(skip, default)]
     event_ids: HashSet<[u8; 32]>,
     #[serde(skip, default)]
+    liquidity_trades: HashMap<[u8; 32], Vec<LiquidityTradeRecord>>,
+    #[serde(skip, default)]
     payload_hf_activation_daa_score: u64,
     #[serde(skip, default)]
     liquidity_vault_outpoints: HashMap<TransactionOutpoint, [u8; 32]>,
@@ -546,6 +876,10 @@ pub struct AtomicTokenState {
     #[serde(skip, default)]
     deleted_anchor_counts: HashSet<[u8; 32]>,
     #[serde(skip, default)]
+    deleted_allowances: HashSet<AllowanceKey>,
+    #[serde(skip, default)]
+    deleted_locks: HashSet<BalanceKey>,
+    #[serde(skip, default)]
     deleted_processed_ops: HashSet<BlockHash>,
 }
 
@@ -555,6 +889,8 @@ pub struct AtomicTokenStateFootprint {
     pub balances: usize,
     pub nonces: usize,
     pub anchor_counts: usize,
+    pub allowances: usize,
+    pub locks: usize,
     pub processed_ops: usize,
     pub block_journals: usize,
     pub state_hash_checkpoints: usize,
@@ -572,6 +908,7 @@ pub struct AtomicTokenPruneResult {
     pub pruned_processed_op_txids: Vec<BlockHash>,
     pub last_pruned_event_sequence: Option<u64>,
     pub pruned_processed_ops: bool,
+    pub pruned_liquidity_trades: usize,
 }
 
 impl AtomicTokenState {
@@ -585,6 +922,8 @@ impl AtomicTokenState {
             balances: Default::default(),
             nonces: Default::default(),
             anchor_counts: Default::default(),
+            allowances: Default::default(),
+            locks: Default::default(),
             processed_ops: Default::default(),
             block_journals: Default::default(),
             state_hash_by_block: Default::default(),
@@ -593,6 +932,7 @@ impl AtomicTokenState {
             next_event_sequence: 0,
             events: Default::default(),
             event_ids: Default::default(),
+            liquidity_trades: Default::default(),
             payload_hf_activation_daa_score: 0,
             liquidity_vault_outpoints: Default::default(),
             known_owner_addresses: Default::default(),
@@ -603,6 +943,8 @@ impl AtomicTokenState {
             deleted_balances: Default::default(),
             deleted_nonces: Default::default(),
             deleted_anchor_counts: Default::default(),
+            deleted_allowances: Default::default(),
+            deleted_locks: Default::default(),
             deleted_processed_ops: Default::default(),
         }
     }
@@ -708,6 +1050,11 @@ impl AtomicTokenState {
                     }
                     _ => continue,
                 }
+                if !asset.metadata_history.is_empty() {
+                    // Applied UpdateMetadata ops supersede the values in the create payload.
+                    repaired_asset.metadata = asset.metadata.clone();
+                    repaired_asset.platform_tag = asset.platform_tag.clone();
+                }
                 repaired_asset.created_block_hash = Some(tx_ref.source_block_hash);
                 repaired_asset.created_daa_score = Some(tx_ref.source_block_daa_score);
                 repaired_asset.created_at = Some(tx_ref.source_block_time);
@@ -731,6 +1078,8 @@ impl AtomicTokenState {
             balances: self.balances.len(),
             nonces: self.nonces.len(),
             anchor_counts: self.anchor_counts.len(),
+            allowances: self.allowances.len(),
+            locks: self.locks.len(),
             processed_ops: self.processed_ops.len(),
             block_journals: self.block_journals.len(),
             state_hash_checkpoints: self.state_hash_by_block.len(),
@@ -836,6 +1185,32 @@ impl AtomicTokenState {
         self.state_store.as_ref().and_then(|store| store.get_anchor_count(owner_id).ok()).unwrap_or(0)
     }
 
+    fn allowance_value(&self, key: &AllowanceKey) -> Option<TokenAllowance> {
+        if let Some(allowance) = self.allowances.get(key) {
+            return Some(*allowance);
+        }
+        if self.deleted_allowances.contains(key) {
+            return None;
+        }
+        self.state_store.as_ref().and_then(|store| store.get_allowance(key).ok().flatten())
+    }
+
+    fn lock_value(&self, key: &BalanceKey) -> Option<TokenLock> {
+        if let Some(lock) = self.locks.get(key) {
+            return Some(*lock);
+        }
+        if self.deleted_locks.contains(key) {
+            return None;
+        }
+        self.state_store.as_ref().and_then(|store| store.get_lock(key).ok().flatten())
+    }
+
+    /// Balance the owner may move at `daa_score`: the balance minus whatever is still locked.
+    fn spendable_balance_value(&self, key: &BalanceKey, daa_score: u64) -> u128 {
+        let locked = self.lock_value(key).map(|lock| lock.locked_at(daa_score)).unwrap_or(0);
+        self.balance_value(key).saturating_sub(locked)
+    }
+
     fn processed_op_value(&self, txid: &BlockHash) -> Option<ProcessedOp> {
         if let Some(op) = self.processed_ops.get(txid) {
             return Some(op.clone());
@@ -864,11 +1239,15 @@ impl AtomicTokenState {
         self.balances.clear();
         self.nonces.clear();
         self.anchor_counts.clear();
+        self.allowances.clear();
+        self.locks.clear();
         self.processed_ops.clear();
         self.deleted_assets.clear();
         self.deleted_balances.clear();
         self.deleted_nonces.clear();
         self.deleted_anchor_counts.clear();
+        self.deleted_allowances.clear();
+        self.deleted_locks.clear();
         self.deleted_processed_ops.clear();
         self.liquidity_vault_outpoints.clear();
         self.known_owner_addresses.clear();
@@ -911,6 +1290,12 @@ impl AtomicTokenState {
             let value = self.anchor_count_value(&change.owner_id);
             delta.anchor_counts.push((change.owner_id, (value > 0).then_some(value)));
         }
+        for change in journal.changed_allowances.iter() {
+            delta.allowances.push((change.key, self.allowance_value(&change.key)));
+        }
+        for change in journal.changed_locks.iter() {
+            delta.locks.push((change.key, self.lock_value(&change.key)));
+        }
         for txid in journal.added_processed_ops.iter().copied() {
             delta.processed_ops.push((txid, self.processed_op_value(&txid)));
         }
@@ -931,6 +1316,12 @@ impl AtomicTokenState {
         for change in journal.changed_anchor_counts.iter() {
             delta.anchor_counts.push((change.owner_id, change.old_value));
         }
+        for change in journal.changed_allowances.iter() {
+            delta.allowances.push((change.key, change.old_value));
+        }
+        for change in journal.changed_locks.iter() {
+            delta.locks.push((change.key, change.old_value));
+        }
         for txid in journal.added_processed_ops.iter().copied() {
             delta.processed_ops.push((txid, None));
         }
@@ -1044,6 +1435,34 @@ impl AtomicTokenState {
         self.deleted_anchor_counts.insert(owner_id);
     }
 
+    fn set_allowance_value(&mut self, key: AllowanceKey, allowance: TokenAllowance) {
+        if allowance.amount == 0 {
+            self.remove_allowance_value(key);
+        } else {
+            self.deleted_allowances.remove(&key);
+            self.allowances.insert(key, allowance);
+        }
+    }
+
+    fn remove_allowance_value(&mut self, key: AllowanceKey) {
+        self.allowances.remove(&key);
+        self.deleted_allowances.insert(key);
+    }
+
+    fn set_lock_value(&mut self, key: BalanceKey, lock: TokenLock) {
+        if lock.amount == 0 {
+            self.remove_lock_value(key);
+        } else {
+            self.deleted_locks.remove(&key);
+            self.locks.insert(key, lock);
+        }
+    }
+
+    fn remove_lock_value(&mut self, key: BalanceKey) {
+        self.locks.remove(&key);
+        self.deleted_locks.insert(key);
+    }
+
     fn set_processed_op_value(&mut self, txid: BlockHash, op: ProcessedOp) {
         self.deleted_processed_ops.remove(&txid);
         self.processed_ops.insert(txid, op);
@@ -1103,6 +1522,8 @@ impl AtomicTokenState {
             delta.balances,
             delta.nonces,
             delta.anchor_counts,
+            delta.allowances,
+            delta.locks,
             delta.processed_ops,
             accepting_block_hash,
             journal,
@@ -1131,6 +1552,8 @@ impl AtomicTokenState {
             delta.balances,
             delta.nonces,
             delta.anchor_counts,
+            delta.allowances,
+            delta.locks,
             delta.processed_ops,
             removed_block_hash,
             self.applied_chain_order.last().copied(),
@@ -1407,12 +1830,20 @@ impl AtomicTokenState {
         self.processed_ops.retain(|_, op| !pruned_hashes_set.contains(&op.accepting_block_hash));
         let pruned_processed_ops = self.processed_ops.len() != processed_ops_before || !pruned_processed_op_txids.is_empty();
 
+        let liquidity_trades_before = self.liquidity_trade_count();
         if let Some(last_pruned_event_sequence) = last_pruned_event_sequence {
             self.events.retain(|event| event.sequence > last_pruned_event_sequence);
             self.rebuild_event_id_index();
         }
+        let pruned_liquidity_trades = liquidity_trades_before.saturating_sub(self.liquidity_trade_count());
 
-        Some(AtomicTokenPruneResult { pruned_hashes, pruned_processed_op_txids, last_pruned_event_sequence, pruned_processed_ops })
+        Some(AtomicTokenPruneResult {
+            pruned_hashes,
+            pruned_processed_op_txids,
+            last_pruned_event_sequence,
+            pruned_processed_ops,
+            pruned_liquidity_trades,
+        })
     }
 
     pub fn recompute_state_hashes_for_retained_segment(
@@ -1624,6 +2055,28 @@ impl AtomicTokenState {
             }
         }
 
+        for change in journal.changed_allowances.iter().rev() {
+            match change.old_value {
+                Some(value) => {
+                    self.set_allowance_value(change.key, value);
+                }
+                None => {
+                    self.remove_allowance_value(change.key);
+                }
+            }
+        }
+
+        for change in journal.changed_locks.iter().rev() {
+            match change.old_value {
+                Some(value) => {
+                    self.set_lock_value(change.key, value);
+                }
+                None => {
+                    self.remove_lock_value(change.key);
+                }
+            }
+        }
+
         for txid in journal.added_processed_ops.iter().copied() {
             self.remove_processed_op_value(txid);
         }
@@ -1786,6 +2239,7 @@ impl AtomicTokenState {
                     tx,
                     &parsed,
                     auth_inputs,
+                    accepting_block_daa_score,
                     tx_ref.source_block_hash,
                     tx_ref.source_block_daa_score,
                     tx_ref.source_block_time,
@@ -1859,6 +2313,7 @@ impl AtomicTokenState {
         tx: &Transaction,
         parsed: &ParsedTokenPayload,
         auth_inputs: &HashMap<TransactionOutpoint, UtxoEntry>,
+        accepting_block_daa_score: u64,
         source_block_hash: BlockHash,
         source_block_daa_score: u64,
         source_block_time: u64,
@@ -1881,7 +2336,11 @@ impl AtomicTokenState {
         let next_nonce = expected_nonce.checked_add(1).ok_or(NoopReason::InternalMalformedAcceptance)?;
         if !spent_vault_inputs.is_empty() {
             match &parsed.op {
-                TokenOp::BuyLiquidityExactIn(_) | TokenOp::SellLiquidityExactIn(_) | TokenOp::ClaimLiquidityFees(_) => {}
+                TokenOp::BuyLiquidityExactIn(_)
+                | TokenOp::SellLiquidityExactIn(_)
+                | TokenOp::ClaimLiquidityFees(_)
+                | TokenOp::BuyLiquidityExactOut(_)
+                | TokenOp::SellLiquidityExactOut(_) => {}
                 _ => return Err(NoopReason::VaultInputCount),
             }
         }
@@ -1894,6 +2353,8 @@ impl AtomicTokenState {
         self.validate_replacement_anchor(tx, &auth_context, auth_inputs)?;
 
         let mut details = self.build_event_details(tx, parsed, auth_inputs);
+        let trade_target = liquidity_trade_target(&parsed.op);
+        let trade_pool_before = trade_target.and_then(|(_, asset_id)| self.asset_value(&asset_id)).and_then(|asset| asset.liquidity);
         match &parsed.op {
             TokenOp::CreateAsset(op) => self.execute_create_asset(
                 tx.id().as_bytes(),
@@ -1926,15 +2387,67 @@ impl AtomicTokenState {
                 details.to_owner_id = (token_out > 0).then_some(owner_id);
                 details.amount = (token_out > 0).then_some(token_out);
             }
-            TokenOp::Transfer(op) => self.execute_transfer(owner_id, op.asset_id, op.to_owner_id, op.amount, journal)?,
+            TokenOp::Transfer(op) => {
+                self.execute_transfer(owner_id, op.asset_id, op.to_owner_id, op.amount, accepting_block_daa_score, journal)?
+            }
             TokenOp::Mint(op) => self.execute_mint(owner_id, op, journal)?,
-            TokenOp::Burn(op) => self.execute_burn(owner_id, op.asset_id, op.amount, journal)?,
+            TokenOp::Burn(op) => self.execute_burn(owner_id, op.asset_id, op.amount, accepting_block_daa_score, journal)?,
             TokenOp::BuyLiquidityExactIn(op) => {
                 let token_out = self.execute_buy_liquidity(tx, &auth_context, op, auth_inputs, journal)?;
                 details.amount = Some(token_out);
             }
             TokenOp::SellLiquidityExactIn(op) => self.execute_sell_liquidity(tx, owner_id, op, auth_inputs, journal)?,
             TokenOp::ClaimLiquidityFees(op) => self.execute_claim_liquidity_fees(tx, owner_id, op, auth_inputs, journal)?,
+            TokenOp::Approve(op) => self.execute_approve(owner_id, op, accepting_block_daa_score, journal)?,
+            TokenOp::TransferFrom(op) => self.execute_transfer_from(owner_id, op, accepting_block_daa_score, journal)?,
+            TokenOp::BatchTransfer(op) => self.execute_batch_transfer(owner_id, op, accepting_block_daa_score, journal)?,
+            TokenOp::LockTokens(op) => self.execute_lock_tokens(owner_id, op, accepting_block_daa_score, journal)?,
+            TokenOp::TransferMintAuthority(op) => self.execute_transfer_mint_authority(owner_id, op, journal)?,
+            TokenOp::RenounceMintAuthority(op) => self.execute_renounce_mint_authority(owner_id, op, journal)?,
+            TokenOp::UpdateMetadata(op) => self.execute_update_metadata(
+                tx.id(),
+                owner_id,
+                op,
+                source_block_hash,
+                source_block_daa_score,
+                source_block_time,
+                journal,
+            )?,
+            TokenOp::CreatePairPool(op) => {
+                let shares_out = self.execute_create_pair_pool(
+                    tx.id().as_bytes(),
+                    owner_id,
+                    op,
+                    accepting_block_daa_score,
+                    source_block_hash,
+                    source_block_daa_score,
+                    source_block_time,
+                    journal,
+                )?;
+                details.amount = Some(shares_out);
+            }
+            TokenOp::AddPairLiquidity(op) => {
+                let shares_out = self.execute_add_pair_liquidity(owner_id, op, accepting_block_daa_score, journal)?;
+                details.amount = Some(shares_out);
+            }
+            TokenOp::RemovePairLiquidity(op) => {
+                self.execute_remove_pair_liquidity(owner_id, op, accepting_block_daa_score, journal)?
+            }
+            TokenOp::SwapPairExactIn(op) => {
+                let amount_out = self.execute_swap_pair_exact_in(owner_id, op, accepting_block_daa_score, journal)?;
+                details.amount = Some(amount_out);
+            }
+            TokenOp::SwapPairExactOut(op) => self.execute_swap_pair_exact_out(owner_id, op, accepting_block_daa_score, journal)?,
+            TokenOp::BuyLiquidityExactOut(op) => self.execute_buy_liquidity_exact_out(tx, &auth_context, op, auth_inputs, journal)?,
+            TokenOp::SellLiquidityExactOut(op) => {
+                let token_in = self.execute_sell_liquidity_exact_out(tx, owner_id, op, auth_inputs, journal)?;
+                details.amount = Some(token_in);
+            }
+        }
+        if let (Some((side, asset_id)), Some(pool_before)) = (trade_target, trade_pool_before.as_ref()) {
+            let pool_after = self.asset_value(&asset_id).and_then(|asset| asset.liquidity);
+            details.trade =
+                pool_after.and_then(|pool_after| liquidity_trade_details(side, pool_before, &pool_after, accepting_block_daa_score));
         }
 
         self.record_nonce_before(nonce_key, journal);
@@ -2065,6 +2578,8 @@ impl AtomicTokenState {
                 created_daa_score: Some(source_block_daa_score),
                 created_at: Some(source_block_time),
                 liquidity: None,
+                pair_pool: None,
+                metadata_history: Vec::new(),
             },
         );
         Ok(())
@@ -2129,6 +2644,8 @@ impl AtomicTokenState {
                 created_daa_score: Some(source_block_daa_score),
                 created_at: Some(source_block_time),
                 liquidity: None,
+                pair_pool: None,
+                metadata_history: Vec::new(),
             },
         );
         if let Some((receiver_key, receiver_after)) = initial_mint_balance {
@@ -2144,6 +2661,7 @@ impl AtomicTokenState {
         asset_id: [u8; 32],
         to_owner_id: [u8; 32],
         amount: u128,
+        accepting_block_daa_score: u64,
         journal: &mut JournalBuilder,
     ) -> Result<(), NoopReason> {
         if amount == 0 {
@@ -2158,6 +2676,7 @@ impl AtomicTokenState {
 
         let from_key = BalanceKey { asset_id, owner_id: from_owner_id };
         let to_key = BalanceKey { asset_id, owner_id: to_owner_id };
+        self.ensure_unlocked(&from_key, amount, accepting_block_daa_score)?;
 
         if from_key == to_key {
             // Self-transfers are valid nonce-bearing ops but must not mutate balances.
@@ -2177,6 +2696,7 @@ impl AtomicTokenState {
 
         self.set_balance_amount(from_key, sender_after);
         self.set_balance_amount(to_key, receiver_after);
+        self.prune_released_lock(from_key, accepting_block_daa_score, journal);
         if is_liquidity_asset {
             let mut asset_changed = false;
             if let Some(pool) = asset.liquidity.as_mut() {
@@ -2192,78 +2712,648 @@ impl AtomicTokenState {
         Ok(())
     }
 
-    fn execute_mint(&mut self, sender_owner_id: [u8; 32], op: &MintOp, journal: &mut JournalBuilder) -> Result<(), NoopReason> {
-        if op.amount == 0 {
-            return Err(NoopReason::InvalidAmount);
+    fn execute_approve(
+        &mut self,
+        owner_id: [u8; 32],
+        op: &ApproveOp,
+        accepting_block_daa_score: u64,
+        journal: &mut JournalBuilder,
+    ) -> Result<(), NoopReason> {
+        let asset = self.asset_value(&op.asset_id).ok_or(NoopReason::AssetNotFound)?;
+        if matches!(asset.asset_class, TokenAssetClass::Liquidity) {
+            return Err(NoopReason::LegacyOpForLiquidityAsset);
+        }
+        if op.spender_owner_id == owner_id {
+            return Err(NoopReason::BadSpender);
+        }
+        let allowance = TokenAllowance { amount: op.amount, expires_at_daa_score: op.expires_at_daa_score };
+        if op.amount > 0 && allowance.is_expired_at(accepting_block_daa_score) {
+            return Err(NoopReason::AllowanceExpired);
         }
 
-        let mut asset = self.asset_value(&op.asset_id).ok_or(NoopReason::AssetNotFound)?;
+        let key = AllowanceKey { asset_id: op.asset_id, owner_id, spender_id: op.spender_owner_id };
+        self.record_allowance_before(key, journal);
+        self.set_allowance_value(key, allowance);
+        Ok(())
+    }
+
+    fn execute_transfer_from(
+        &mut self,
+        spender_owner_id: [u8; 32],
+        op: &TransferFromOp,
+        accepting_block_daa_score: u64,
+        journal: &mut JournalBuilder,
+    ) -> Result<(), NoopReason> {
+        let asset = self.asset_value(&op.asset_id).ok_or(NoopReason::AssetNotFound)?;
         if matches!(asset.asset_class, TokenAssetClass::Liquidity) {
             return Err(NoopReason::LegacyOpForLiquidityAsset);
         }
-        if asset.mint_authority_owner_id != sender_owner_id {
-            return Err(NoopReason::UnauthorizedMint);
+        if op.from_owner_id == spender_owner_id {
+            return Err(NoopReason::BadSpender);
         }
 
-        let new_total_supply = asset.total_supply.checked_add(op.amount).ok_or(NoopReason::SupplyOverflow)?;
-        if matches!(asset.supply_mode, SupplyMode::Capped) && new_total_supply > asset.max_supply {
-            return Err(NoopReason::SupplyCapExceeded);
+        let allowance_key = AllowanceKey { asset_id: op.asset_id, owner_id: op.from_owner_id, spender_id: spender_owner_id };
+        let allowance = self.allowance_value(&allowance_key).ok_or(NoopReason::AllowanceNotFound)?;
+        if allowance.is_expired_at(accepting_block_daa_score) {
+            return Err(NoopReason::AllowanceExpired);
         }
+        let remaining = allowance.amount.checked_sub(op.amount).ok_or(NoopReason::AllowanceExceeded)?;
 
-        let receiver_key = BalanceKey { asset_id: op.asset_id, owner_id: op.to_owner_id };
-        let receiver_balance = self.balance_value(&receiver_key);
-        let receiver_after = receiver_balance.checked_add(op.amount).ok_or(NoopReason::BalanceOverflow)?;
-
-        self.record_asset_before(op.asset_id, journal);
-        self.record_balance_before(receiver_key, journal);
+        let from_key = BalanceKey { asset_id: op.asset_id, owner_id: op.from_owner_id };
+        let to_key = BalanceKey { asset_id: op.asset_id, owner_id: op.to_owner_id };
+        self.ensure_unlocked(&from_key, op.amount, accepting_block_daa_score)?;
+        let sender_after = self.balance_value(&from_key).checked_sub(op.amount).ok_or(NoopReason::InsufficientBalance)?;
+        if from_key != to_key {
+            let receiver_after = self.balance_value(&to_key).checked_add(op.amount).ok_or(NoopReason::BalanceOverflow)?;
+            self.record_balance_before(from_key, journal);
+            self.record_balance_before(to_key, journal);
+            self.set_balance_amount(from_key, sender_after);
+            self.set_balance_amount(to_key, receiver_after);
+            self.prune_released_lock(from_key, accepting_block_daa_score, journal);
+        }
 
-        asset.total_supply = new_total_supply;
-        self.set_asset_state(op.asset_id, asset);
-        self.set_balance_amount(receiver_key, receiver_after);
+        self.record_allowance_before(allowance_key, journal);
+        self.set_allowance_value(allowance_key, TokenAllowance { amount: remaining, ..allowance });
         Ok(())
     }
 
-    fn execute_burn(
+    fn execute_batch_transfer(
         &mut self,
-        sender_owner_id: [u8; 32],
-        asset_id: [u8; 32],
-        amount: u128,
+        from_owner_id: [u8; 32],
+        op: &BatchTransferOp,
+        accepting_block_daa_score: u64,
         journal: &mut JournalBuilder,
     ) -> Result<(), NoopReason> {
-        if amount == 0 {
-            return Err(NoopReason::InvalidAmount);
+        if op.recipients.is_empty() || op.recipients.len() > MAX_BATCH_TRANSFER_RECIPIENTS {
+            return Err(NoopReason::BadBatchRecipientCount);
         }
-
-        let mut asset = self.asset_value(&asset_id).ok_or(NoopReason::AssetNotFound)?;
+        let asset = self.asset_value(&op.asset_id).ok_or(NoopReason::AssetNotFound)?;
         if matches!(asset.asset_class, TokenAssetClass::Liquidity) {
             return Err(NoopReason::LegacyOpForLiquidityAsset);
         }
-        let sender_key = BalanceKey { asset_id, owner_id: sender_owner_id };
-        let sender_balance = self.balance_value(&sender_key);
-
-        let sender_after = sender_balance.checked_sub(amount).ok_or(NoopReason::InsufficientBalance)?;
-        let supply_after = asset.total_supply.checked_sub(amount).ok_or(NoopReason::SupplyUnderflow)?;
 
-        self.record_asset_before(asset_id, journal);
-        self.record_balance_before(sender_key, journal);
+        let total = op
+            .recipients
+            .iter()
+            .try_fold(0u128, |total, recipient| total.checked_add(recipient.amount))
+            .ok_or(NoopReason::BalanceOverflow)?;
+        let from_key = BalanceKey { asset_id: op.asset_id, owner_id: from_owner_id };
+        self.ensure_unlocked(&from_key, total, accepting_block_daa_score)?;
+        let sender_balance = self.balance_value(&from_key);
+        sender_balance.checked_sub(total).ok_or(NoopReason::InsufficientBalance)?;
+
+        // Validate every leg before mutating so the batch applies all-or-nothing.
+        let mut sender_after = sender_balance - total;
+        let mut receivers_after = Vec::with_capacity(op.recipients.len());
+        for recipient in op.recipients.iter() {
+            if recipient.to_owner_id == from_owner_id {
+                sender_after += recipient.amount;
+                continue;
+            }
+            let to_key = BalanceKey { asset_id: op.asset_id, owner_id: recipient.to_owner_id };
+            let receiver_after = self.balance_value(&to_key).checked_add(recipient.amount).ok_or(NoopReason::BalanceOverflow)?;
+            receivers_after.push((to_key, receiver_after));
+        }
 
-        asset.total_supply = supply_after;
-        self.set_asset_state(asset_id, asset);
-        self.set_balance_amount(sender_key, sender_after);
+        self.record_balance_before(from_key, journal);
+        self.set_balance_amount(from_key, sender_after);
+        for (to_key, receiver_after) in receivers_after {
+            self.record_balance_before(to_key, journal);
+            self.set_balance_amount(to_key, receiver_after);
+        }
+        self.prune_released_lock(from_key, accepting_block_daa_score, journal);
         Ok(())
     }
 
-    fn execute_create_liquidity_asset(
+    fn execute_lock_tokens(
         &mut self,
-        tx: &Transaction,
-        creator_auth: &AuthContext,
-        op: &CreateLiquidityAssetOp,
-        source_block_hash: BlockHash,
-        source_block_daa_score: u64,
-        source_block_time: u64,
+        sender_owner_id: [u8; 32],
+        op: &LockTokensOp,
+        accepting_block_daa_score: u64,
         journal: &mut JournalBuilder,
-    ) -> Result<u128, NoopReason> {
-        let creator_owner_id = creator_auth.owner_id;
+    ) -> Result<(), NoopReason> {
+        if op.amount == 0 {
+            return Err(NoopReason::InvalidAmount);
+        }
+        if op.release_end_daa_score < op.release_start_daa_score || op.release_end_daa_score <= accepting_block_daa_score {
+            return Err(NoopReason::BadLockSchedule);
+        }
+        let asset = self.asset_value(&op.asset_id).ok_or(NoopReason::AssetNotFound)?;
+        if matches!(asset.asset_class, TokenAssetClass::Liquidity) {
+            return Err(NoopReason::LegacyOpForLiquidityAsset);
+        }
+        // Pool reserves must stay spendable by swaps and withdrawals.
+        if self.asset_value(&op.beneficiary_owner_id).is_some_and(|pool| pool.pair_pool.is_some()) {
+            return Err(NoopReason::LockConflict);
+        }
+
+        let from_key = BalanceKey { asset_id: op.asset_id, owner_id: sender_owner_id };
+        let beneficiary_key = BalanceKey { asset_id: op.asset_id, owner_id: op.beneficiary_owner_id };
+        self.ensure_unlocked(&from_key, op.amount, accepting_block_daa_score)?;
+        let sender_after = self.balance_value(&from_key).checked_sub(op.amount).ok_or(NoopReason::InsufficientBalance)?;
+
+        // An active lock can only be topped up on the same schedule; a fully released one is replaced.
+        let lock = match self.lock_value(&beneficiary_key).filter(|lock| lock.locked_at(accepting_block_daa_score) > 0) {
+            Some(existing)
+                if existing.release_start_daa_score == op.release_start_daa_score
+                    && existing.release_end_daa_score == op.release_end_daa_score =>
+            {
+                TokenLock { amount: existing.amount.checked_add(op.amount).ok_or(NoopReason::BalanceOverflow)?, ..existing }
+            }
+            Some(_) => return Err(NoopReason::LockConflict),
+            None => TokenLock {
+                amount: op.amount,
+                release_start_daa_score: op.release_start_daa_score,
+                release_end_daa_score: op.release_end_daa_score,
+            },
+        };
+
+        if from_key != beneficiary_key {
+            let beneficiary_after = self.balance_value(&beneficiary_key).checked_add(op.amount).ok_or(NoopReason::BalanceOverflow)?;
+            self.record_balance_before(from_key, journal);
+            self.record_balance_before(beneficiary_key, journal);
+            self.set_balance_amount(from_key, sender_after);
+            self.set_balance_amount(beneficiary_key, beneficiary_after);
+            self.prune_released_lock(from_key, accepting_block_daa_score, journal);
+        }
+        self.record_lock_before(beneficiary_key, journal);
+        self.set_lock_value(beneficiary_key, lock);
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn execute_create_pair_pool(
+        &mut self,
+        pool_id: [u8; 32],
+        owner_id: [u8; 32],
+        op: &CreatePairPoolOp,
+        accepting_block_daa_score: u64,
+        source_block_hash: BlockHash,
+        source_block_daa_score: u64,
+        source_block_time: u64,
+        journal: &mut JournalBuilder,
+    ) -> Result<u128, NoopReason> {
+        if self.asset_value(&pool_id).is_some() {
+            return Err(NoopReason::AssetAlreadyExists);
+        }
+        for reserve_asset_id in [op.asset_a_id, op.asset_b_id] {
+            let reserve_asset = self.asset_value(&reserve_asset_id).ok_or(NoopReason::AssetNotFound)?;
+            if !matches!(reserve_asset.asset_class, TokenAssetClass::Standard) {
+                return Err(NoopReason::BadPairAssets);
+            }
+        }
+        let shares = pair_initial_shares(op.amount_a, op.amount_b).map_err(map_pair_liquidity_error)?;
+        let pair = PairPoolState {
+            asset_a_id: op.asset_a_id,
+            asset_b_id: op.asset_b_id,
+            fee_bps: op.fee_bps,
+            accrued_fee_a: 0,
+            accrued_fee_b: 0,
+        };
+
+        let owner_key_a = BalanceKey { asset_id: op.asset_a_id, owner_id };
+        let owner_key_b = BalanceKey { asset_id: op.asset_b_id, owner_id };
+        let reserve_key_a = pair.reserve_key_a(pool_id);
+        let reserve_key_b = pair.reserve_key_b(pool_id);
+        let owner_shares_key = BalanceKey { asset_id: pool_id, owner_id };
+        let locked_shares_key = BalanceKey { asset_id: pool_id, owner_id: pool_id };
+        let owner_a_after = self.balance_after_debit(&owner_key_a, op.amount_a, accepting_block_daa_score)?;
+        let owner_b_after = self.balance_after_debit(&owner_key_b, op.amount_b, accepting_block_daa_score)?;
+        let reserve_a_after = self.balance_after_credit(&reserve_key_a, op.amount_a)?;
+        let reserve_b_after = self.balance_after_credit(&reserve_key_b, op.amount_b)?;
+        let owner_shares = shares - MIN_PAIR_LIQUIDITY_SHARES;
+
+        self.record_asset_before(pool_id, journal);
+        for key in [owner_key_a, owner_key_b, reserve_key_a, reserve_key_b, owner_shares_key, locked_shares_key] {
+            self.record_balance_before(key, journal);
+        }
+        self.set_asset_state(
+            pool_id,
+            TokenAsset {
+                asset_id: pool_id,
+                creator_owner_id: owner_id,
+                asset_class: TokenAssetClass::Pair,
+                token_version: CURRENT_TOKEN_VERSION,
+                mint_authority_owner_id: [0u8; 32],
+                decimals: 0,
+                supply_mode: SupplyMode::Uncapped,
+                max_supply: 0,
+                total_supply: shares,
+                name: PAIR_LP_NAME.to_vec(),
+                symbol: PAIR_LP_SYMBOL.to_vec(),
+                metadata: Vec::new(),
+                platform_tag: Vec::new(),
+                created_block_hash: Some(source_block_hash),
+                created_daa_score: Some(source_block_daa_score),
+                created_at: Some(source_block_time),
+                liquidity: None,
+                pair_pool: Some(pair),
+                metadata_history: Vec::new(),
+            },
+        );
+        self.set_balance_amount(owner_key_a, owner_a_after);
+        self.set_balance_amount(owner_key_b, owner_b_after);
+        self.set_balance_amount(reserve_key_a, reserve_a_after);
+        self.set_balance_amount(reserve_key_b, reserve_b_after);
+        // The minimum share amount stays with the pool itself so the reserves can never be fully drained.
+        self.set_balance_amount(owner_shares_key, owner_shares);
+        self.set_balance_amount(locked_shares_key, MIN_PAIR_LIQUIDITY_SHARES);
+        self.prune_released_lock(owner_key_a, accepting_block_daa_score, journal);
+        self.prune_released_lock(owner_key_b, accepting_block_daa_score, journal);
+        Ok(owner_shares)
+    }
+
+    fn execute_add_pair_liquidity(
+        &mut self,
+        owner_id: [u8; 32],
+        op: &AddPairLiquidityOp,
+        accepting_block_daa_score: u64,
+        journal: &mut JournalBuilder,
+    ) -> Result<u128, NoopReason> {
+        let (mut asset, pair) = self.pair_pool_value(&op.pool_id)?;
+        let reserve_key_a = pair.reserve_key_a(op.pool_id);
+        let reserve_key_b = pair.reserve_key_b(op.pool_id);
+        let (shares, amount_a_in, amount_b_in) = pair_add_liquidity(
+            self.balance_value(&reserve_key_a),
+            self.balance_value(&reserve_key_b),
+            asset.total_supply,
+            op.amount_a_max,
+            op.amount_b_max,
+        )
+        .map_err(map_pair_liquidity_error)?;
+        if shares < op.min_shares_out {
+            return Err(NoopReason::MinOutViolation);
+        }
+
+        let owner_key_a = BalanceKey { asset_id: pair.asset_a_id, owner_id };
+        let owner_key_b = BalanceKey { asset_id: pair.asset_b_id, owner_id };
+        let shares_key = BalanceKey { asset_id: op.pool_id, owner_id };
+        let owner_a_after = self.balance_after_debit(&owner_key_a, amount_a_in, accepting_block_daa_score)?;
+        let owner_b_after = self.balance_after_debit(&owner_key_b, amount_b_in, accepting_block_daa_score)?;
+        let reserve_a_after = self.balance_after_credit(&reserve_key_a, amount_a_in)?;
+        let reserve_b_after = self.balance_after_credit(&reserve_key_b, amount_b_in)?;
+        let shares_after = self.balance_after_credit(&shares_key, shares)?;
+        asset.total_supply = asset.total_supply.checked_add(shares).ok_or(NoopReason::SupplyOverflow)?;
+
+        self.record_asset_before(op.pool_id, journal);
+        for key in [owner_key_a, owner_key_b, reserve_key_a, reserve_key_b, shares_key] {
+            self.record_balance_before(key, journal);
+        }
+        self.set_asset_state(op.pool_id, asset);
+        self.set_balance_amount(owner_key_a, owner_a_after);
+        self.set_balance_amount(owner_key_b, owner_b_after);
+        self.set_balance_amount(reserve_key_a, reserve_a_after);
+        self.set_balance_amount(reserve_key_b, reserve_b_after);
+        self.set_balance_amount(shares_key, shares_after);
+        self.prune_released_lock(owner_key_a, accepting_block_daa_score, journal);
+        self.prune_released_lock(owner_key_b, accepting_block_daa_score, journal);
+        Ok(shares)
+    }
+
+    fn execute_remove_pair_liquidity(
+        &mut self,
+        owner_id: [u8; 32],
+        op: &RemovePairLiquidityOp,
+        accepting_block_daa_score: u64,
+        journal: &mut JournalBuilder,
+    ) -> Result<(), NoopReason> {
+        let (mut asset, pair) = self.pair_pool_value(&op.pool_id)?;
+        let reserve_key_a = pair.reserve_key_a(op.pool_id);
+        let reserve_key_b = pair.reserve_key_b(op.pool_id);
+        let reserve_a = self.balance_value(&reserve_key_a);
+        let reserve_b = self.balance_value(&reserve_key_b);
+        let (amount_a_out, amount_b_out) =
+            pair_remove_liquidity(reserve_a, reserve_b, asset.total_supply, op.shares).map_err(map_pair_liquidity_error)?;
+        if amount_a_out < op.min_amount_a_out || amount_b_out < op.min_amount_b_out {
+            return Err(NoopReason::MinOutViolation);
+        }
+
+        let shares_key = BalanceKey { asset_id: op.pool_id, owner_id };
+        let owner_key_a = BalanceKey { asset_id: pair.asset_a_id, owner_id };
+        let owner_key_b = BalanceKey { asset_id: pair.asset_b_id, owner_id };
+        let shares_after = self.balance_after_debit(&shares_key, op.shares, accepting_block_daa_score)?;
+        let owner_a_after = self.balance_after_credit(&owner_key_a, amount_a_out)?;
+        let owner_b_after = self.balance_after_credit(&owner_key_b, amount_b_out)?;
+        asset.total_supply -= op.shares;
+
+        self.record_asset_before(op.pool_id, journal);
+        for key in [shares_key, reserve_key_a, reserve_key_b, owner_key_a, owner_key_b] {
+            self.record_balance_before(key, journal);
+        }
+        self.set_asset_state(op.pool_id, asset);
+        self.set_balance_amount(shares_key, shares_after);
+        self.set_balance_amount(reserve_key_a, reserve_a - amount_a_out);
+        self.set_balance_amount(reserve_key_b, reserve_b - amount_b_out);
+        self.set_balance_amount(owner_key_a, owner_a_after);
+        self.set_balance_amount(owner_key_b, owner_b_after);
+        self.prune_released_lock(shares_key, accepting_block_daa_score, journal);
+        Ok(())
+    }
+
+    fn execute_swap_pair_exact_in(
+        &mut self,
+        owner_id: [u8; 32],
+        op: &SwapPairExactInOp,
+        accepting_block_daa_score: u64,
+        journal: &mut JournalBuilder,
+    ) -> Result<u128, NoopReason> {
+        let (asset, pair) = self.pair_pool_value(&op.pool_id)?;
+        let in_is_a = pair_swap_side(&pair, op.asset_in_id)?;
+        let (reserve_key_in, reserve_key_out) = pair_reserve_keys(&pair, op.pool_id, in_is_a);
+        let (amount_out, fee) =
+            pair_swap_exact_in(self.balance_value(&reserve_key_in), self.balance_value(&reserve_key_out), op.amount_in, pair.fee_bps)
+                .map_err(map_pair_liquidity_error)?;
+        if amount_out < op.min_amount_out {
+            return Err(NoopReason::MinOutViolation);
+        }
+        self.apply_pair_swap(
+            owner_id,
+            op.pool_id,
+            asset,
+            pair,
+            in_is_a,
+            op.amount_in,
+            amount_out,
+            fee,
+            accepting_block_daa_score,
+            journal,
+        )?;
+        Ok(amount_out)
+    }
+
+    fn execute_swap_pair_exact_out(
+        &mut self,
+        owner_id: [u8; 32],
+        op: &SwapPairExactOutOp,
+        accepting_block_daa_score: u64,
+        journal: &mut JournalBuilder,
+    ) -> Result<(), NoopReason> {
+        let (asset, pair) = self.pair_pool_value(&op.pool_id)?;
+        let in_is_a = !pair_swap_side(&pair, op.asset_out_id)?;
+        let (reserve_key_in, reserve_key_out) = pair_reserve_keys(&pair, op.pool_id, in_is_a);
+        let (amount_in, fee) = pair_swap_exact_out(
+            self.balance_value(&reserve_key_in),
+            self.balance_value(&reserve_key_out),
+            op.amount_out,
+            pair.fee_bps,
+        )
+        .map_err(map_pair_liquidity_error)?;
+        if amount_in > op.max_amount_in {
+            return Err(NoopReason::MinOutViolation);
+        }
+        self.apply_pair_swap(
+            owner_id,
+            op.pool_id,
+            asset,
+            pair,
+            in_is_a,
+            amount_in,
+            op.amount_out,
+            fee,
+            accepting_block_daa_score,
+            journal,
+        )
+    }
+
+    /// Moves the swap input into the pool and the output to the trader; the input-side fee stays in the reserve.
+    #[allow(clippy::too_many_arguments)]
+    fn apply_pair_swap(
+        &mut self,
+        owner_id: [u8; 32],
+        pool_id: [u8; 32],
+        mut asset: TokenAsset,
+        mut pair: PairPoolState,
+        in_is_a: bool,
+        amount_in: u128,
+        amount_out: u128,
+        fee: u128,
+        accepting_block_daa_score: u64,
+        journal: &mut JournalBuilder,
+    ) -> Result<(), NoopReason> {
+        let (reserve_key_in, reserve_key_out) = pair_reserve_keys(&pair, pool_id, in_is_a);
+        let owner_key_in = BalanceKey { asset_id: reserve_key_in.asset_id, owner_id };
+        let owner_key_out = BalanceKey { asset_id: reserve_key_out.asset_id, owner_id };
+        let owner_in_after = self.balance_after_debit(&owner_key_in, amount_in, accepting_block_daa_score)?;
+        let owner_out_after = self.balance_after_credit(&owner_key_out, amount_out)?;
+        let reserve_in_after = self.balance_after_credit(&reserve_key_in, amount_in)?;
+        let reserve_out_after = self.balance_value(&reserve_key_out).checked_sub(amount_out).ok_or(NoopReason::InsufficientBalance)?;
+        if in_is_a {
+            pair.accrued_fee_a = pair.accrued_fee_a.saturating_add(fee);
+        } else {
+            pair.accrued_fee_b = pair.accrued_fee_b.saturating_add(fee);
+        }
+        asset.pair_pool = Some(pair);
+
+        self.record_asset_before(pool_id, journal);
+        for key in [owner_key_in, owner_key_out, reserve_key_in, reserve_key_out] {
+            self.record_balance_before(key, journal);
+        }
+        self.set_asset_state(pool_id, asset);
+        self.set_balance_amount(owner_key_in, owner_in_after);
+        self.set_balance_amount(owner_key_out, owner_out_after);
+        self.set_balance_amount(reserve_key_in, reserve_in_after);
+        self.set_balance_amount(reserve_key_out, reserve_out_after);
+        self.prune_released_lock(owner_key_in, accepting_block_daa_score, journal);
+        Ok(())
+    }
+
+    fn pair_pool_value(&self, pool_id: &[u8; 32]) -> Result<(TokenAsset, PairPoolState), NoopReason> {
+        let asset = self.asset_value(pool_id).ok_or(NoopReason::AssetNotFound)?;
+        match (asset.asset_class, asset.pair_pool) {
+            (TokenAssetClass::Pair, Some(pair)) => Ok((asset, pair)),
+            _ => Err(NoopReason::NotPairPool),
+        }
+    }
+
+    /// Returns the balance of `key` after debiting `amount`, honouring any active lock.
+    fn balance_after_debit(&self, key: &BalanceKey, amount: u128, daa_score: u64) -> Result<u128, NoopReason> {
+        self.ensure_unlocked(key, amount, daa_score)?;
+        self.balance_value(key).checked_sub(amount).ok_or(NoopReason::InsufficientBalance)
+    }
+
+    fn balance_after_credit(&self, key: &BalanceKey, amount: u128) -> Result<u128, NoopReason> {
+        self.balance_value(key).checked_add(amount).ok_or(NoopReason::BalanceOverflow)
+    }
+
+    /// Rejects debits that only fit the balance by dipping into still-locked tokens.
+    fn ensure_unlocked(&self, key: &BalanceKey, amount: u128, daa_score: u64) -> Result<(), NoopReason> {
+        if amount > self.spendable_balance_value(key, daa_score) && amount <= self.balance_value(key) {
+            return Err(NoopReason::BalanceLocked);
+        }
+        Ok(())
+    }
+
+    /// Drops a fully released lock after its owner spends, matching consensus state.
+    fn prune_released_lock(&mut self, key: BalanceKey, daa_score: u64, journal: &mut JournalBuilder) {
+        if self.lock_value(&key).is_some_and(|lock| lock.locked_at(daa_score) == 0) {
+            self.record_lock_before(key, journal);
+            self.remove_lock_value(key);
+        }
+    }
+
+    fn execute_mint(&mut self, sender_owner_id: [u8; 32], op: &MintOp, journal: &mut JournalBuilder) -> Result<(), NoopReason> {
+        if op.amount == 0 {
+            return Err(NoopReason::InvalidAmount);
+        }
+
+        let mut asset = self.asset_value(&op.asset_id).ok_or(NoopReason::AssetNotFound)?;
+        if matches!(asset.asset_class, TokenAssetClass::Liquidity) {
+            return Err(NoopReason::LegacyOpForLiquidityAsset);
+        }
+        if asset.mint_authority_owner_id != sender_owner_id {
+            return Err(NoopReason::UnauthorizedMint);
+        }
+
+        let new_total_supply = asset.total_supply.checked_add(op.amount).ok_or(NoopReason::SupplyOverflow)?;
+        if matches!(asset.supply_mode, SupplyMode::Capped) && new_total_supply > asset.max_supply {
+            return Err(NoopReason::SupplyCapExceeded);
+        }
+
+        let receiver_key = BalanceKey { asset_id: op.asset_id, owner_id: op.to_owner_id };
+        let receiver_balance = self.balance_value(&receiver_key);
+        let receiver_after = receiver_balance.checked_add(op.amount).ok_or(NoopReason::BalanceOverflow)?;
+
+        self.record_asset_before(op.asset_id, journal);
+        self.record_balance_before(receiver_key, journal);
+
+        asset.total_supply = new_total_supply;
+        self.set_asset_state(op.asset_id, asset);
+        self.set_balance_amount(receiver_key, receiver_after);
+        Ok(())
+    }
+
+    fn execute_transfer_mint_authority(
+        &mut self,
+        sender_owner_id: [u8; 32],
+        op: &TransferMintAuthorityOp,
+        journal: &mut JournalBuilder,
+    ) -> Result<(), NoopReason> {
+        if op.new_mint_authority_owner_id == [0u8; 32] {
+            return Err(NoopReason::BadMintAuthority);
+        }
+        self.set_mint_authority(sender_owner_id, op.asset_id, op.new_mint_authority_owner_id, journal)
+    }
+
+    fn execute_renounce_mint_authority(
+        &mut self,
+        sender_owner_id: [u8; 32],
+        op: &RenounceMintAuthorityOp,
+        journal: &mut JournalBuilder,
+    ) -> Result<(), NoopReason> {
+        self.set_mint_authority(sender_owner_id, op.asset_id, [0u8; 32], journal)
+    }
+
+    /// Replaces the mint authority of a non-liquidity asset; only the current authority may do so.
+    fn set_mint_authority(
+        &mut self,
+        sender_owner_id: [u8; 32],
+        asset_id: [u8; 32],
+        new_mint_authority_owner_id: [u8; 32],
+        journal: &mut JournalBuilder,
+    ) -> Result<(), NoopReason> {
+        let mut asset = self.asset_value(&asset_id).ok_or(NoopReason::AssetNotFound)?;
+        if matches!(asset.asset_class, TokenAssetClass::Liquidity) {
+            return Err(NoopReason::LegacyOpForLiquidityAsset);
+        }
+        if asset.mint_authority_owner_id != sender_owner_id {
+            return Err(NoopReason::UnauthorizedMint);
+        }
+
+        self.record_asset_before(asset_id, journal);
+        asset.mint_authority_owner_id = new_mint_authority_owner_id;
+        self.set_asset_state(asset_id, asset);
+        Ok(())
+    }
+
+    /// Replaces asset metadata and platform tag on behalf of the asset creator and appends a revision.
+    fn execute_update_metadata(
+        &mut self,
+        txid: BlockHash,
+        sender_owner_id: [u8; 32],
+        op: &UpdateMetadataOp,
+        source_block_hash: BlockHash,
+        source_block_daa_score: u64,
+        source_block_time: u64,
+        journal: &mut JournalBuilder,
+    ) -> Result<(), NoopReason> {
+        let mut asset = self.asset_value(&op.asset_id).ok_or(NoopReason::AssetNotFound)?;
+        if asset.creator_owner_id == [0u8; 32] || asset.creator_owner_id != sender_owner_id {
+            return Err(NoopReason::UnauthorizedMetadataUpdate);
+        }
+        let revision = asset
+            .metadata_history
+            .last()
+            .map_or(0, |revision| revision.revision)
+            .checked_add(1)
+            .ok_or(NoopReason::InternalMalformedAcceptance)?;
+
+        self.record_asset_before(op.asset_id, journal);
+        let previous_metadata = std::mem::replace(&mut asset.metadata, op.metadata.clone());
+        let previous_platform_tag = std::mem::replace(&mut asset.platform_tag, op.platform_tag.clone());
+        asset.metadata_history.push(TokenMetadataRevision {
+            revision,
+            txid,
+            previous_metadata,
+            previous_platform_tag,
+            metadata: op.metadata.clone(),
+            platform_tag: op.platform_tag.clone(),
+            updated_block_hash: source_block_hash,
+            updated_daa_score: source_block_daa_score,
+            updated_at: source_block_time,
+        });
+        if asset.metadata_history.len() > MAX_METADATA_HISTORY_REVISIONS {
+            let excess = asset.metadata_history.len() - MAX_METADATA_HISTORY_REVISIONS;
+            asset.metadata_history.drain(..excess);
+        }
+        self.set_asset_state(op.asset_id, asset);
+        Ok(())
+    }
+
+    fn execute_burn(
+        &mut self,
+        sender_owner_id: [u8; 32],
+        asset_id: [u8; 32],
+        amount: u128,
+        accepting_block_daa_score: u64,
+        journal: &mut JournalBuilder,
+    ) -> Result<(), NoopReason> {
+        if amount == 0 {
+            return Err(NoopReason::InvalidAmount);
+        }
+
+        let mut asset = self.asset_value(&asset_id).ok_or(NoopReason::AssetNotFound)?;
+        // LP share supply must track the pool reserves, so pair shares can only leave through RemovePairLiquidity.
+        if matches!(asset.asset_class, TokenAssetClass::Liquidity | TokenAssetClass::Pair) {
+            return Err(NoopReason::LegacyOpForLiquidityAsset);
+        }
+        let sender_key = BalanceKey { asset_id, owner_id: sender_owner_id };
+        self.ensure_unlocked(&sender_key, amount, accepting_block_daa_score)?;
+        let sender_balance = self.balance_value(&sender_key);
+
+        let sender_after = sender_balance.checked_sub(amount).ok_or(NoopReason::InsufficientBalance)?;
+        let supply_after = asset.total_supply.checked_sub(amount).ok_or(NoopReason::SupplyUnderflow)?;
+
+        self.record_asset_before(asset_id, journal);
+        self.record_balance_before(sender_key, journal);
+
+        asset.total_supply = supply_after;
+        self.set_asset_state(asset_id, asset);
+        self.set_balance_amount(sender_key, sender_after);
+        self.prune_released_lock(sender_key, accepting_block_daa_score, journal);
+        Ok(())
+    }
+
+    fn execute_create_liquidity_asset(
+        &mut self,
+        tx: &Transaction,
+        creator_auth: &AuthContext,
+        op: &CreateLiquidityAssetOp,
+        source_block_hash: BlockHash,
+        source_block_daa_score: u64,
+        source_block_time: u64,
+        journal: &mut JournalBuilder,
+    ) -> Result<u128, NoopReason> {
+        let creator_owner_id = creator_auth.owner_id;
         let asset_id = tx.id().as_bytes();
         if self.asset_value(&asset_id).is_some() {
             return Err(NoopReason::AssetAlreadyExists);
@@ -2390,6 +3480,8 @@ impl AtomicTokenState {
                 unlocked,
                 holder_addresses,
             }),
+            pair_pool: None,
+            metadata_history: Vec::new(),
         };
         self.validate_liquidity_invariants(&asset)?;
         self.set_asset_state(asset_id, asset);
@@ -2555,14 +3647,15 @@ impl AtomicTokenState {
         Ok(())
     }
 
-    fn execute_claim_liquidity_fees(
+    fn execute_buy_liquidity_exact_out(
         &mut self,
         tx: &Transaction,
-        claimant_owner_id: [u8; 32],
-        op: &ClaimLiquidityFeesOp,
+        buyer_auth: &AuthContext,
+        op: &BuyLiquidityExactOutOp,
         auth_inputs: &HashMap<TransactionOutpoint, UtxoEntry>,
         journal: &mut JournalBuilder,
     ) -> Result<(), NoopReason> {
+        let buyer_owner_id = buyer_auth.owner_id;
         let mut asset = self.asset_value(&op.asset_id).ok_or(NoopReason::AssetNotFound)?;
         if !matches!(asset.asset_class, TokenAssetClass::Liquidity) {
             return Err(NoopReason::LegacyOpForLiquidityAsset);
@@ -2571,14 +3664,157 @@ impl AtomicTokenState {
         if pool.pool_nonce != op.expected_pool_nonce {
             return Err(NoopReason::NonceStale);
         }
-        if liquidity_sell_locked(&pool) {
-            return Err(NoopReason::LiquiditySellLocked);
-        }
-        if op.claim_amount_sompi < LIQUIDITY_MIN_PAYOUT_SOMPI {
-            return Err(NoopReason::InvalidAmount);
+
+        let (net_in, new_real_token_reserves, new_virtual_cpay_reserves_sompi, new_virtual_token_reserves) =
+            cpmm_buy_exact_out(pool.real_token_reserves, pool.virtual_cpay_reserves_sompi, pool.virtual_token_reserves, op.token_out)
+                .map_err(map_liquidity_math_error)?;
+        let (cpay_in, fee_trade) = gross_amount_for_net_amount(net_in, pool.fee_bps).map_err(map_liquidity_math_error)?;
+        if cpay_in > op.max_cpay_in_sompi {
+            return Err(NoopReason::MinOutViolation);
         }
-        let recipient_index = usize::from(op.recipient_index);
-        if recipient_index >= pool.fee_recipients.len() {
+        let vault_transition = self.resolve_liquidity_vault_transition(tx, auth_inputs, pool.vault_outpoint)?;
+        let vault_delta =
+            vault_transition.output_value.checked_sub(vault_transition.input_value).ok_or(NoopReason::SupplyUnderflow)?;
+        if vault_delta != cpay_in {
+            return Err(NoopReason::VaultOutpointMismatch);
+        }
+
+        self.record_asset_before(op.asset_id, journal);
+        let receiver_key = BalanceKey { asset_id: op.asset_id, owner_id: buyer_owner_id };
+        self.record_balance_before(receiver_key, journal);
+
+        pool.real_cpay_reserves_sompi = pool.real_cpay_reserves_sompi.checked_add(net_in).ok_or(NoopReason::SupplyOverflow)?;
+        pool.real_token_reserves = new_real_token_reserves;
+        pool.virtual_cpay_reserves_sompi = new_virtual_cpay_reserves_sompi;
+        pool.virtual_token_reserves = new_virtual_token_reserves;
+        if pool.unlock_target_sompi > 0 && pool.real_cpay_reserves_sompi >= pool.unlock_target_sompi {
+            pool.unlocked = true;
+        }
+        self.apply_fee_to_pool(&mut pool.fee_recipients, &mut pool.unclaimed_fee_total_sompi, fee_trade)?;
+        pool.vault_outpoint = TransactionOutpoint::new(tx.id(), vault_transition.output_index);
+        pool.vault_value_sompi = vault_transition.output_value;
+        pool.pool_nonce = pool.pool_nonce.checked_add(1).ok_or(NoopReason::SupplyOverflow)?;
+        pool.holder_addresses.insert(
+            buyer_owner_id,
+            LiquidityHolderAddressState {
+                address_version: buyer_auth.address_version,
+                address_payload: buyer_auth.address_payload.clone(),
+            },
+        );
+
+        let receiver_balance = self.balance_value(&receiver_key);
+        let receiver_after = receiver_balance.checked_add(op.token_out).ok_or(NoopReason::BalanceOverflow)?;
+
+        asset.total_supply = asset.total_supply.checked_add(op.token_out).ok_or(NoopReason::SupplyOverflow)?;
+        asset.liquidity = Some(pool);
+        self.validate_liquidity_invariants(&asset)?;
+        self.set_asset_state(op.asset_id, asset);
+        self.set_balance_amount(receiver_key, receiver_after);
+        Ok(())
+    }
+
+    /// Pays out exactly `cpay_out_sompi` and returns the token input it cost.
+    fn execute_sell_liquidity_exact_out(
+        &mut self,
+        tx: &Transaction,
+        seller_owner_id: [u8; 32],
+        op: &SellLiquidityExactOutOp,
+        auth_inputs: &HashMap<TransactionOutpoint, UtxoEntry>,
+        journal: &mut JournalBuilder,
+    ) -> Result<u128, NoopReason> {
+        let mut asset = self.asset_value(&op.asset_id).ok_or(NoopReason::AssetNotFound)?;
+        if !matches!(asset.asset_class, TokenAssetClass::Liquidity) {
+            return Err(NoopReason::LegacyOpForLiquidityAsset);
+        }
+        let mut pool = asset.liquidity.clone().ok_or(NoopReason::AssetNotFound)?;
+        if pool.pool_nonce != op.expected_pool_nonce {
+            return Err(NoopReason::NonceStale);
+        }
+        if liquidity_sell_locked(&pool) {
+            return Err(NoopReason::LiquiditySellLocked);
+        }
+        if op.cpay_out_sompi < LIQUIDITY_MIN_PAYOUT_SOMPI {
+            return Err(NoopReason::InvalidAmount);
+        }
+
+        let (gross_out, fee_trade) = gross_amount_for_net_amount(op.cpay_out_sompi, pool.fee_bps).map_err(map_liquidity_math_error)?;
+        let (token_in, new_real_cpay_reserves_sompi, new_virtual_cpay_reserves_sompi, new_virtual_token_reserves) =
+            cpmm_sell_exact_out(
+                pool.real_cpay_reserves_sompi,
+                pool.virtual_cpay_reserves_sompi,
+                pool.virtual_token_reserves,
+                gross_out,
+            )
+            .map_err(map_liquidity_math_error)?;
+        if token_in > op.max_token_in {
+            return Err(NoopReason::MinOutViolation);
+        }
+
+        let sender_key = BalanceKey { asset_id: op.asset_id, owner_id: seller_owner_id };
+        let sender_balance = self.balance_value(&sender_key);
+        let sender_after = sender_balance.checked_sub(token_in).ok_or(NoopReason::InsufficientBalance)?;
+        let supply_after = asset.total_supply.checked_sub(token_in).ok_or(NoopReason::SupplyUnderflow)?;
+
+        self.validate_payout_output(tx, op.cpay_receive_output_index, op.cpay_out_sompi, None)?;
+        let vault_transition = self.resolve_liquidity_vault_transition(tx, auth_inputs, pool.vault_outpoint)?;
+        let vault_delta =
+            vault_transition.input_value.checked_sub(vault_transition.output_value).ok_or(NoopReason::SupplyUnderflow)?;
+        if vault_delta != op.cpay_out_sompi {
+            return Err(NoopReason::VaultOutpointMismatch);
+        }
+
+        self.record_asset_before(op.asset_id, journal);
+        self.record_balance_before(sender_key, journal);
+
+        pool.real_cpay_reserves_sompi = new_real_cpay_reserves_sompi;
+        pool.real_token_reserves = pool.real_token_reserves.checked_add(token_in).ok_or(NoopReason::SupplyOverflow)?;
+        pool.virtual_cpay_reserves_sompi = new_virtual_cpay_reserves_sompi;
+        pool.virtual_token_reserves = new_virtual_token_reserves;
+        self.apply_fee_to_pool(&mut pool.fee_recipients, &mut pool.unclaimed_fee_total_sompi, fee_trade)?;
+        pool.vault_outpoint = TransactionOutpoint::new(tx.id(), vault_transition.output_index);
+        pool.vault_value_sompi = vault_transition.output_value;
+        pool.pool_nonce = pool.pool_nonce.checked_add(1).ok_or(NoopReason::SupplyOverflow)?;
+
+        if sender_after == 0 {
+            pool.holder_addresses.remove(&seller_owner_id);
+        }
+
+        asset.total_supply = supply_after;
+        asset.liquidity = Some(pool);
+        self.validate_liquidity_invariants(&asset)?;
+        self.set_asset_state(op.asset_id, asset);
+        if sender_after == 0 {
+            self.remove_balance(sender_key);
+        } else {
+            self.set_balance_amount(sender_key, sender_after);
+        }
+        Ok(token_in)
+    }
+
+    fn execute_claim_liquidity_fees(
+        &mut self,
+        tx: &Transaction,
+        claimant_owner_id: [u8; 32],
+        op: &ClaimLiquidityFeesOp,
+        auth_inputs: &HashMap<TransactionOutpoint, UtxoEntry>,
+        journal: &mut JournalBuilder,
+    ) -> Result<(), NoopReason> {
+        let mut asset = self.asset_value(&op.asset_id).ok_or(NoopReason::AssetNotFound)?;
+        if !matches!(asset.asset_class, TokenAssetClass::Liquidity) {
+            return Err(NoopReason::LegacyOpForLiquidityAsset);
+        }
+        let mut pool = asset.liquidity.clone().ok_or(NoopReason::AssetNotFound)?;
+        if pool.pool_nonce != op.expected_pool_nonce {
+            return Err(NoopReason::NonceStale);
+        }
+        if liquidity_sell_locked(&pool) {
+            return Err(NoopReason::LiquiditySellLocked);
+        }
+        if op.claim_amount_sompi < LIQUIDITY_MIN_PAYOUT_SOMPI {
+            return Err(NoopReason::InvalidAmount);
+        }
+        let recipient_index = usize::from(op.recipient_index);
+        if recipient_index >= pool.fee_recipients.len() {
             return Err(NoopReason::BadLength);
         }
         let recipient_owner_id = pool.fee_recipients[recipient_index].owner_id;
@@ -2739,22 +3975,7 @@ impl AtomicTokenState {
     }
 
     fn owner_id_from_address_components(&self, address_version: u8, address_payload: &[u8]) -> Option<[u8; 32]> {
-        let auth_scheme = match address_version {
-            0 if address_payload.len() == 32 => OWNER_AUTH_SCHEME_PUBKEY,
-            1 if address_payload.len() == 33 => OWNER_AUTH_SCHEME_PUBKEY_ECDSA,
-            8 if address_payload.len() == 32 => OWNER_AUTH_SCHEME_SCRIPT_HASH,
-            _ => return None,
-        };
-        let pubkey_len = u16::try_from(address_payload.len()).ok()?;
-        let mut hasher = Blake2bParams::new().hash_length(32).to_state();
-        hasher.update(CAT_OWNER_DOMAIN);
-        hasher.update(&[auth_scheme]);
-        hasher.update(&pubkey_len.to_le_bytes());
-        hasher.update(address_payload);
-        let hash = hasher.finalize();
-        let mut owner_id = [0u8; 32];
-        owner_id.copy_from_slice(hash.as_bytes());
-        Some(owner_id)
+        cat_owner_id_from_address_components(address_version, address_payload)
     }
 
     fn apply_fee_to_pool(
@@ -2874,6 +4095,7 @@ impl AtomicTokenState {
                 from_owner_id,
                 to_owner_id: None,
                 amount: None,
+                trade: None,
             },
             TokenOp::Transfer(op) => TokenEventDetails {
                 op_type: Some(TokenOpCode::Transfer),
@@ -2881,6 +4103,7 @@ impl AtomicTokenState {
                 from_owner_id,
                 to_owner_id: Some(op.to_owner_id),
                 amount: Some(op.amount),
+                trade: None,
             },
             TokenOp::Mint(op) => TokenEventDetails {
                 op_type: Some(TokenOpCode::Mint),
@@ -2888,6 +4111,7 @@ impl AtomicTokenState {
                 from_owner_id,
                 to_owner_id: Some(op.to_owner_id),
                 amount: Some(op.amount),
+                trade: None,
             },
             TokenOp::Burn(op) => TokenEventDetails {
                 op_type: Some(TokenOpCode::Burn),
@@ -2895,6 +4119,7 @@ impl AtomicTokenState {
                 from_owner_id,
                 to_owner_id: None,
                 amount: Some(op.amount),
+                trade: None,
             },
             TokenOp::CreateAssetWithMint(op) => TokenEventDetails {
                 op_type: Some(TokenOpCode::CreateAssetWithMint),
@@ -2902,6 +4127,7 @@ impl AtomicTokenState {
                 from_owner_id,
                 to_owner_id: if op.initial_mint_amount > 0 { Some(op.initial_mint_to_owner_id) } else { None },
                 amount: if op.initial_mint_amount > 0 { Some(op.initial_mint_amount) } else { None },
+                trade: None,
             },
             TokenOp::CreateLiquidityAsset(op) => TokenEventDetails {
                 op_type: Some(TokenOpCode::CreateLiquidityAsset),
@@ -2909,6 +4135,7 @@ impl AtomicTokenState {
                 from_owner_id,
                 to_owner_id: None,
                 amount: Some(op.launch_buy_min_token_out),
+                trade: None,
             },
             TokenOp::BuyLiquidityExactIn(op) => TokenEventDetails {
                 op_type: Some(TokenOpCode::BuyLiquidityExactIn),
@@ -2916,6 +4143,7 @@ impl AtomicTokenState {
                 from_owner_id,
                 to_owner_id: from_owner_id,
                 amount: Some(op.min_token_out),
+                trade: None,
             },
             TokenOp::SellLiquidityExactIn(op) => TokenEventDetails {
                 op_type: Some(TokenOpCode::SellLiquidityExactIn),
@@ -2923,6 +4151,7 @@ impl AtomicTokenState {
                 from_owner_id,
                 to_owner_id: None,
                 amount: Some(op.token_in),
+                trade: None,
             },
             TokenOp::ClaimLiquidityFees(op) => TokenEventDetails {
                 op_type: Some(TokenOpCode::ClaimLiquidityFees),
@@ -2930,6 +4159,119 @@ impl AtomicTokenState {
                 from_owner_id,
                 to_owner_id: None,
                 amount: Some(u128::from(op.claim_amount_sompi)),
+                trade: None,
+            },
+            TokenOp::Approve(op) => TokenEventDetails {
+                op_type: Some(TokenOpCode::Approve),
+                asset_id: Some(op.asset_id),
+                from_owner_id,
+                to_owner_id: Some(op.spender_owner_id),
+                amount: Some(op.amount),
+                trade: None,
+            },
+            TokenOp::TransferFrom(op) => TokenEventDetails {
+                op_type: Some(TokenOpCode::TransferFrom),
+                asset_id: Some(op.asset_id),
+                from_owner_id: Some(op.from_owner_id),
+                to_owner_id: Some(op.to_owner_id),
+                amount: Some(op.amount),
+                trade: None,
+            },
+            TokenOp::BatchTransfer(op) => TokenEventDetails {
+                op_type: Some(TokenOpCode::BatchTransfer),
+                asset_id: Some(op.asset_id),
+                from_owner_id,
+                to_owner_id: None,
+                amount: op.recipients.iter().try_fold(0u128, |total, recipient| total.checked_add(recipient.amount)),
+                trade: None,
+            },
+            TokenOp::LockTokens(op) => TokenEventDetails {
+                op_type: Some(TokenOpCode::LockTokens),
+                asset_id: Some(op.asset_id),
+                from_owner_id,
+                to_owner_id: Some(op.beneficiary_owner_id),
+                amount: Some(op.amount),
+                trade: None,
+            },
+            TokenOp::CreatePairPool(_) => TokenEventDetails {
+                op_type: Some(TokenOpCode::CreatePairPool),
+                asset_id: Some(tx.id().as_bytes()),
+                from_owner_id,
+                to_owner_id: from_owner_id,
+                amount: None,
+                trade: None,
+            },
+            TokenOp::AddPairLiquidity(op) => TokenEventDetails {
+                op_type: Some(TokenOpCode::AddPairLiquidity),
+                asset_id: Some(op.pool_id),
+                from_owner_id,
+                to_owner_id: from_owner_id,
+                amount: Some(op.min_shares_out),
+                trade: None,
+            },
+            TokenOp::RemovePairLiquidity(op) => TokenEventDetails {
+                op_type: Some(TokenOpCode::RemovePairLiquidity),
+                asset_id: Some(op.pool_id),
+                from_owner_id,
+                to_owner_id: None,
+                amount: Some(op.shares),
+                trade: None,
+            },
+            TokenOp::SwapPairExactIn(op) => TokenEventDetails {
+                op_type: Some(TokenOpCode::SwapPairExactIn),
+                asset_id: Some(op.pool_id),
+                from_owner_id,
+                to_owner_id: from_owner_id,
+                amount: Some(op.min_amount_out),
+                trade: None,
+            },
+            TokenOp::SwapPairExactOut(op) => TokenEventDetails {
+                op_type: Some(TokenOpCode::SwapPairExactOut),
+                asset_id: Some(op.pool_id),
+                from_owner_id,
+                to_owner_id: from_owner_id,
+                amount: Some(op.amount_out),
+                trade: None,
+            },
+            TokenOp::BuyLiquidityExactOut(op) => TokenEventDetails {
+                op_type: Some(TokenOpCode::BuyLiquidityExactOut),
+                asset_id: Some(op.asset_id),
+                from_owner_id,
+                to_owner_id: from_owner_id,
+                amount: Some(op.token_out),
+                trade: None,
+            },
+            TokenOp::SellLiquidityExactOut(op) => TokenEventDetails {
+                op_type: Some(TokenOpCode::SellLiquidityExactOut),
+                asset_id: Some(op.asset_id),
+                from_owner_id,
+                to_owner_id: None,
+                amount: Some(op.max_token_in),
+                trade: None,
+            },
+            TokenOp::TransferMintAuthority(op) => TokenEventDetails {
+                op_type: Some(TokenOpCode::TransferMintAuthority),
+                asset_id: Some(op.asset_id),
+                from_owner_id,
+                to_owner_id: Some(op.new_mint_authority_owner_id),
+                amount: None,
+                trade: None,
+            },
+            TokenOp::RenounceMintAuthority(op) => TokenEventDetails {
+                op_type: Some(TokenOpCode::RenounceMintAuthority),
+                asset_id: Some(op.asset_id),
+                from_owner_id,
+                to_owner_id: None,
+                amount: None,
+                trade: None,
+            },
+            TokenOp::UpdateMetadata(op) => TokenEventDetails {
+                op_type: Some(TokenOpCode::UpdateMetadata),
+                asset_id: Some(op.asset_id),
+                from_owner_id,
+                to_owner_id: None,
+                amount: None,
+                trade: None,
             },
         }
     }
@@ -2975,33 +4317,24 @@ impl AtomicTokenState {
 
     fn owner_id_from_script_if_whitelisted(&self, script_public_key: &ScriptPublicKey) -> Option<[u8; 32]> {
         let (auth_scheme, canonical_pubkey_bytes) = self.canonical_owner_identity(script_public_key)?;
-        let pubkey_len = u16::try_from(canonical_pubkey_bytes.len()).ok()?;
-        let mut hasher = Blake2bParams::new().hash_length(32).to_state();
-        hasher.update(CAT_OWNER_DOMAIN);
-        hasher.update(&[auth_scheme]);
-        hasher.update(&pubkey_len.to_le_bytes());
-        hasher.update(canonical_pubkey_bytes);
-        let hash = hasher.finalize();
-        let mut owner_id = [0u8; 32];
-        owner_id.copy_from_slice(hash.as_bytes());
-        Some(owner_id)
+        cat_owner_id(auth_scheme, canonical_pubkey_bytes)
     }
 
     fn canonical_owner_identity<'a>(&self, script_public_key: &'a ScriptPublicKey) -> Option<(u8, &'a [u8])> {
         let script_bytes = script_public_key.script();
         match ScriptClass::from_script(script_public_key) {
-            ScriptClass::PubKey if script_bytes.len() == 34 => Some((OWNER_AUTH_SCHEME_PUBKEY, &script_bytes[1..33])),
-            ScriptClass::PubKeyECDSA if script_bytes.len() == 35 => Some((OWNER_AUTH_SCHEME_PUBKEY_ECDSA, &script_bytes[1..34])),
-            ScriptClass::ScriptHash if script_bytes.len() == 35 => Some((OWNER_AUTH_SCHEME_SCRIPT_HASH, &script_bytes[2..34])),
+            ScriptClass::PubKey if script_bytes.len() == 34 => Some((CAT_OWNER_AUTH_SCHEME_PUBKEY, &script_bytes[1..33])),
+            ScriptClass::PubKeyECDSA if script_bytes.len() == 35 => Some((CAT_OWNER_AUTH_SCHEME_PUBKEY_ECDSA, &script_bytes[1..34])),
+            ScriptClass::ScriptHash if script_bytes.len() == 35 => Some((CAT_OWNER_AUTH_SCHEME_SCRIPT_HASH, &script_bytes[2..34])),
             _ => None,
         }
     }
 
     fn address_version_from_auth_scheme(&self, auth_scheme: u8) -> Option<u8> {
         match auth_scheme {
-            OWNER_AUTH_SCHEME_PUBKEY => Some(0),
-            OWNER_AUTH_SCHEME_PUBKEY_ECDSA => Some(1),
-            OWNER_AUTH_SCHEME_SCRIPT_HASH => Some(8),
+            CAT_OWNER_AUTH_SCHEME_PUBKEY => Some(0),
+            CAT_OWNER_AUTH_SCHEME_PUBKEY_ECDSA => Some(1),
+            CAT_OWNER_AUTH_SCHEME_SCRIPT_HASH => Some(8),
             _ => None,
         }
     }
@@ -3098,6 +4431,20 @@ impl AtomicTokenState {
         }
     }
 
+    fn record_allowance_before(&mut self, key: AllowanceKey, journal: &mut JournalBuilder) {
+        if journal.seen_allowances.insert(key) {
+            let old_value = self.allowance_value(&key);
+            journal.changed_allowances.push(ChangedAllowance { key, old_value });
+        }
+    }
+
+    fn record_lock_before(&mut self, key: BalanceKey, journal: &mut JournalBuilder) {
+        if journal.seen_locks.insert(key) {
+            let old_value = self.lock_value(&key);
+            journal.changed_locks.push(ChangedLock { key, old_value });
+        }
+    }
+
     fn reserve_event_id(&mut self, requested_event_id: [u8; 32]) -> [u8; 32] {
         if self.event_ids.insert(requested_event_id) {
             return requested_event_id;
@@ -3125,12 +4472,99 @@ impl AtomicTokenState {
         self.next_event_sequence = self.next_event_sequence.saturating_add(1);
         event.sequence = self.next_event_sequence;
         let event_id = event.event_id;
+        Self::index_liquidity_trade(&mut self.liquidity_trades, &event);
         self.events.push(event);
         event_id
     }
 
+    /// Rebuilds every index derived from the retained events, including the per-pool trade records.
     pub(crate) fn rebuild_event_id_index(&mut self) {
         self.event_ids = self.events.iter().map(|event| event.event_id).collect();
+        self.liquidity_trades.clear();
+        for event in self.events.iter() {
+            Self::index_liquidity_trade(&mut self.liquidity_trades, event);
+        }
+    }
+
+    fn index_liquidity_trade(liquidity_trades: &mut HashMap<[u8; 32], Vec<LiquidityTradeRecord>>, event: &TokenEvent) {
+        let (Some(asset_id), Some(trade)) = (event.details.asset_id, event.details.trade) else {
+            return;
+        };
+        match event.event_type {
+            EventType::Applied => liquidity_trades.entry(asset_id).or_default().push(LiquidityTradeRecord {
+                sequence: event.sequence,
+                event_id: event.event_id,
+                txid: event.txid,
+                accepting_block_hash: event.accepting_block_hash,
+                trade,
+            }),
+            EventType::Reorged => {
+                let Some(reorged_event_id) = event.reorg_of_event_id else {
+                    return;
+                };
+                if let Some(records) = liquidity_trades.get_mut(&asset_id) {
+                    records.retain(|record| record.event_id != reorged_event_id);
+                    if records.is_empty() {
+                        liquidity_trades.remove(&asset_id);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn liquidity_trade_count(&self) -> usize {
+        self.liquidity_trades.values().map(Vec::len).sum()
+    }
+
+    pub fn get_liquidity_trades(&self, asset_id: &[u8; 32]) -> &[LiquidityTradeRecord] {
+        self.liquidity_trades.get(asset_id).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Aggregates the retained trades of a liquidity pool with `from_daa_score <= daa_score < to_daa_score`
+    /// into candles aligned to multiples of `interval_daa_score`. Intervals without trades are omitted.
+    pub fn get_liquidity_candles(
+        &self,
+        asset_id: &[u8; 32],
+        interval_daa_score: u64,
+        from_daa_score: u64,
+        to_daa_score: u64,
+    ) -> Vec<LiquidityCandle> {
+        if interval_daa_score == 0 {
+            return Vec::new();
+        }
+        let mut candles: BTreeMap<u64, LiquidityCandle> = BTreeMap::new();
+        for record in self.get_liquidity_trades(asset_id) {
+            let trade = &record.trade;
+            if trade.daa_score < from_daa_score || trade.daa_score >= to_daa_score {
+                continue;
+            }
+            let start_daa_score = trade.daa_score - trade.daa_score % interval_daa_score;
+            match candles.get_mut(&start_daa_score) {
+                Some(candle) => {
+                    candle.high_price_sompi = candle.high_price_sompi.max(trade.price_sompi);
+                    candle.low_price_sompi = candle.low_price_sompi.min(trade.price_sompi);
+                    candle.close_price_sompi = trade.price_sompi;
+                    candle.volume_sompi = candle.volume_sompi.saturating_add(u128::from(trade.cpay_volume_sompi));
+                    candle.trade_count = candle.trade_count.saturating_add(1);
+                }
+                None => {
+                    candles.insert(
+                        start_daa_score,
+                        LiquidityCandle {
+                            start_daa_score,
+                            open_price_sompi: trade.price_sompi,
+                            high_price_sompi: trade.price_sompi,
+                            low_price_sompi: trade.price_sompi,
+                            close_price_sompi: trade.price_sompi,
+                            volume_sompi: u128::from(trade.cpay_volume_sompi),
+                            trade_count: 1,
+                        },
+                    );
+                }
+            }
+        }
+        candles.into_values().collect()
     }
 
     fn compute_event_id(
@@ -3186,6 +4620,14 @@ impl AtomicTokenState {
         self.anchor_count_value(&owner_id)
     }
 
+    pub fn get_allowance(&self, key: AllowanceKey) -> Option<TokenAllowance> {
+        self.allowance_value(&key)
+    }
+
+    pub fn get_lock(&self, key: BalanceKey) -> Option<TokenLock> {
+        self.lock_value(&key)
+    }
+
     pub fn get_op_status(&self, txid: BlockHash) -> Option<ProcessedOp> {
         self.processed_op_value(&txid)
     }
@@ -3207,6 +4649,92 @@ impl AtomicTokenState {
             .collect()
     }
 
+    /// Returns up to `limit` retained events matching `filter` with `after_sequence < sequence <= max_sequence`
+    /// in sequence order, plus whether more matches follow the last returned one. Owner and asset filters
+    /// walk the Atomic DB event indexes when a state store is attached.
+    pub fn get_filtered_events(
+        &self,
+        filter: &TokenEventFilter,
+        after_sequence: u64,
+        limit: usize,
+        max_sequence: u64,
+    ) -> (Vec<TokenEvent>, bool) {
+        let mut page = FilteredEventsPage { filter, limit, max_sequence, events: Vec::new(), has_more: false };
+        if let Some(store) = self.state_store.as_ref() {
+            let indexed = match (filter.owner_id.as_ref(), filter.asset_id.as_ref()) {
+                (Some(owner_id), _) => Some(store.visit_event_sequences_by_owner(owner_id, after_sequence, |sequence| {
+                    Ok(self.event_by_sequence(sequence).map_or(sequence <= max_sequence, |event| page.push(event)))
+                })),
+                (None, Some(asset_id)) => Some(store.visit_event_sequences_by_asset(asset_id, after_sequence, |sequence| {
+                    Ok(self.event_by_sequence(sequence).map_or(sequence <= max_sequence, |event| page.push(event)))
+                })),
+                (None, None) => None,
+            };
+            match indexed {
+                Some(Ok(())) => return (page.events, page.has_more),
+                Some(Err(err)) => {
+                    warn!("[{IDENT}] Atomic DB event index lookup failed, scanning retained events instead: {err}");
+                    page.events.clear();
+                    page.has_more = false;
+                }
+                None => {}
+            }
+        }
+        let start = self.events.partition_point(|event| event.sequence <= after_sequence);
+        for event in self.events[start..].iter() {
+            if !page.push(event) {
+                break;
+            }
+        }
+        (page.events, page.has_more)
+    }
+
+    fn event_by_sequence(&self, sequence: u64) -> Option<&TokenEvent> {
+        self.events.binary_search_by_key(&sequence, |event| event.sequence).ok().map(|index| &self.events[index])
+    }
+
+    /// Resolves an inclusive DAA score range to the event sequence range `(after_sequence, max_sequence]`
+    /// emitted while applying the retained chain blocks inside it. `daa_score_at` returns the DAA score of
+    /// the chain block at a given `applied_chain_order` index; chain DAA scores increase monotonically so
+    /// both ends are found by binary search. Returns `None` when no retained chain block is in range.
+    pub async fn event_sequence_range_for_daa<F, Fut>(
+        &self,
+        from_daa_score: u64,
+        to_daa_score: u64,
+        mut daa_score_at: F,
+    ) -> AtomicTokenResult<Option<(u64, u64)>>
+    where
+        F: FnMut(BlockHash) -> Fut,
+        Fut: std::future::Future<Output = AtomicTokenResult<u64>>,
+    {
+        let chain = &self.applied_chain_order;
+        let (mut low, mut high) = (0usize, chain.len());
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if daa_score_at(chain[mid]).await? < from_daa_score {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        let first = low;
+        let (mut low, mut high) = (first, chain.len());
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if daa_score_at(chain[mid]).await? <= to_daa_score {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        if low == first {
+            return Ok(None);
+        }
+        let end_sequence = |index: usize| self.event_sequence_by_block.get(&chain[index]).copied().unwrap_or(0);
+        let after_sequence = if first == 0 { 0 } else { end_sequence(first - 1) };
+        Ok(Some((after_sequence, end_sequence(low - 1))))
+    }
+
     pub fn materialize_latest_view(&self, fallback_block_hash: BlockHash) -> AtomicTokenReadView {
         if let Some(last_applied_block_hash) = self.applied_chain_order.last().copied() {
             return AtomicTokenReadView {
@@ -3219,6 +4747,8 @@ impl AtomicTokenState {
                 balances: self.balances.clone(),
                 nonces: self.nonces.clone(),
                 anchor_counts: self.anchor_counts.clone(),
+                allowances: self.allowances.clone(),
+                locks: self.locks.clone(),
                 processed_ops: self.processed_ops.clone(),
                 known_owner_addresses: self.known_owner_addresses.clone(),
             };
@@ -3233,6 +4763,8 @@ impl AtomicTokenState {
             balances: self.balances.clone(),
             nonces: self.nonces.clone(),
             anchor_counts: self.anchor_counts.clone(),
+            allowances: self.allowances.clone(),
+            locks: self.locks.clone(),
             processed_ops: self.processed_ops.clone(),
             known_owner_addresses: self.known_owner_addresses.clone(),
         }
@@ -3327,24 +4859,214 @@ impl AtomicTokenState {
         Some(value)
     }
 
-    pub fn get_processed_op_at_block(&self, txid: BlockHash, at_block_hash: BlockHash) -> Option<Option<ProcessedOp>> {
+    pub fn get_allowance_at_block(&self, key: AllowanceKey, at_block_hash: BlockHash) -> Option<Option<TokenAllowance>> {
         let target_index = self.retained_index(at_block_hash)?;
-        let mut value = self.processed_op_value(&txid);
+        let mut value = self.allowance_value(&key);
         for block_hash in self.applied_chain_order.iter().skip(target_index + 1).rev().copied() {
             let journal = self.block_journals.get(&block_hash)?;
-            if journal.added_processed_ops.iter().any(|added| *added == txid) {
-                value = None;
+            for change in journal.changed_allowances.iter().rev() {
+                if change.key == key {
+                    value = change.old_value;
+                    break;
+                }
             }
         }
         Some(value)
     }
 
-    pub fn indexed_assets_page(&self, offset: usize, limit: usize, query: &str) -> (Vec<TokenAsset>, u64) {
-        if self.assets.is_empty() && self.deleted_assets.is_empty() {
-            if let Some((assets, total)) = self.state_store.as_ref().and_then(|store| store.assets_page(offset, limit, query).ok()) {
-                return (assets, total);
-            }
-        }
+    pub fn get_lock_at_block(&self, key: BalanceKey, at_block_hash: BlockHash) -> Option<Option<TokenLock>> {
+        let target_index = self.retained_index(at_block_hash)?;
+        let mut value = self.lock_value(&key);
+        for block_hash in self.applied_chain_order.iter().skip(target_index + 1).rev().copied() {
+            let journal = self.block_journals.get(&block_hash)?;
+            for change in journal.changed_locks.iter().rev() {
+                if change.key == key {
+                    value = change.old_value;
+                    break;
+                }
+            }
+        }
+        Some(value)
+    }
+
+    pub fn get_processed_op_at_block(&self, txid: BlockHash, at_block_hash: BlockHash) -> Option<Option<ProcessedOp>> {
+        let target_index = self.retained_index(at_block_hash)?;
+        let mut value = self.processed_op_value(&txid);
+        for block_hash in self.applied_chain_order.iter().skip(target_index + 1).rev().copied() {
+            let journal = self.block_journals.get(&block_hash)?;
+            if journal.added_processed_ops.iter().any(|added| *added == txid) {
+                value = None;
+            }
+        }
+        Some(value)
+    }
+
+    pub fn get_asset_proof(&self, asset_id: [u8; 32], context: &AtomicTokenReadContext) -> Option<AtomicStateProof> {
+        self.build_state_proof(RootLeafKey::Asset(asset_id), context)
+    }
+
+    pub fn get_balance_proof(&self, key: BalanceKey, context: &AtomicTokenReadContext) -> Option<AtomicStateProof> {
+        self.build_state_proof(RootLeafKey::Balance(key), context)
+    }
+
+    pub fn get_nonce_proof(&self, key: NonceKey, context: &AtomicTokenReadContext) -> Option<AtomicStateProof> {
+        self.build_state_proof(RootLeafKey::Nonce(key), context)
+    }
+
+    pub fn get_allowance_proof(&self, key: AllowanceKey, context: &AtomicTokenReadContext) -> Option<AtomicStateProof> {
+        self.build_state_proof(RootLeafKey::Allowance(key), context)
+    }
+
+    pub fn get_lock_proof(&self, key: BalanceKey, context: &AtomicTokenReadContext) -> Option<AtomicStateProof> {
+        self.build_state_proof(RootLeafKey::Lock(key), context)
+    }
+
+    /// Builds a bucket inclusion proof for `key` against the canonical root of `context`.
+    ///
+    /// Historical contexts are rebuilt from the current buckets by rolling the retained
+    /// block journals back to `context.at_block_hash`. Returns `None` when that block is
+    /// no longer retained or the rebuilt root does not match `context.state_hash`.
+    fn build_state_proof(&self, key: RootLeafKey, context: &AtomicTokenReadContext) -> Option<AtomicStateProof> {
+        let logical_key = key.logical_key();
+        let target_bucket = bucket_index(&logical_key);
+
+        let persisted_root = self.state_store.as_ref().filter(|_| self.storage_overlay_is_empty());
+        let (mut buckets, mut candidates) = match persisted_root {
+            Some(store) => {
+                let buckets = store.root_buckets().ok()?;
+                let candidates = store
+                    .root_bucket_logical_keys(target_bucket)
+                    .ok()?
+                    .iter()
+                    .filter_map(|logical_key| RootLeafKey::from_logical_key(logical_key))
+                    .collect::<HashSet<_>>();
+                (buckets, candidates)
+            }
+            None => {
+                let mut buckets = [[0u8; 32]; ATOMIC_ROOT_BUCKETS];
+                let mut candidates = HashSet::new();
+                let keys = self
+                    .assets
+                    .keys()
+                    .map(|asset_id| RootLeafKey::Asset(*asset_id))
+                    .chain(self.balances.keys().map(|key| RootLeafKey::Balance(*key)))
+                    .chain(self.nonces.keys().map(|key| RootLeafKey::Nonce(*key)))
+                    .chain(self.anchor_counts.keys().map(|owner_id| RootLeafKey::AnchorCount(*owner_id)))
+                    .chain(self.allowances.keys().map(|key| RootLeafKey::Allowance(*key)))
+                    .chain(self.locks.keys().map(|key| RootLeafKey::Lock(*key)));
+                for key in keys {
+                    let logical_key = key.logical_key();
+                    let index = bucket_index(&logical_key);
+                    if let Some(value) = self.root_leaf_value(key) {
+                        xor_hash(&mut buckets[index], leaf_hash(&logical_key, &value));
+                    }
+                    if index == target_bucket {
+                        candidates.insert(key);
+                    }
+                }
+                (buckets, candidates)
+            }
+        };
+
+        // Roll every key touched after the requested block back to its value at that block.
+        let mut values_at_block = HashMap::new();
+        if let Some(target_index) = self.retained_index(context.at_block_hash) {
+            for block_hash in self.applied_chain_order.iter().skip(target_index + 1).rev() {
+                let journal = self.block_journals.get(block_hash)?;
+                for change in journal.changed_assets.iter() {
+                    values_at_block.insert(RootLeafKey::Asset(change.asset_id), root_leaf_value_for_asset(change.old_value.as_ref()));
+                }
+                for change in journal.changed_balances.iter() {
+                    values_at_block
+                        .insert(RootLeafKey::Balance(change.key), root_leaf_value_for_balance(change.old_value.unwrap_or(0)));
+                }
+                for change in journal.changed_nonces.iter() {
+                    values_at_block.insert(RootLeafKey::Nonce(change.key), root_leaf_value_for_nonce(change.old_value.unwrap_or(1)));
+                }
+                for change in journal.changed_anchor_counts.iter() {
+                    values_at_block.insert(
+                        RootLeafKey::AnchorCount(change.owner_id),
+                        root_leaf_value_for_anchor_count(change.old_value.unwrap_or(0)),
+                    );
+                }
+                for change in journal.changed_allowances.iter() {
+                    values_at_block
+                        .insert(RootLeafKey::Allowance(change.key), root_leaf_value_for_allowance(change.old_value.as_ref()));
+                }
+                for change in journal.changed_locks.iter() {
+                    values_at_block.insert(RootLeafKey::Lock(change.key), root_leaf_value_for_lock(change.old_value.as_ref()));
+                }
+            }
+        }
+        for (key, value_at_block) in values_at_block.iter() {
+            let logical_key = key.logical_key();
+            let index = bucket_index(&logical_key);
+            let current = self.root_leaf_value(*key);
+            if current != *value_at_block {
+                if let Some(value) = current {
+                    xor_hash(&mut buckets[index], leaf_hash(&logical_key, &value));
+                }
+                if let Some(value) = value_at_block {
+                    xor_hash(&mut buckets[index], leaf_hash(&logical_key, value));
+                }
+            }
+            if index == target_bucket {
+                candidates.insert(*key);
+            }
+        }
+
+        let state_root = root_from_buckets(&buckets);
+        if state_root != context.state_hash {
+            return None;
+        }
+
+        let mut value = None;
+        let mut bucket_siblings = Vec::with_capacity(candidates.len());
+        for candidate in candidates {
+            let candidate_value = match values_at_block.get(&candidate) {
+                Some(value_at_block) => value_at_block.clone(),
+                None => self.root_leaf_value(candidate),
+            };
+            let Some(candidate_value) = candidate_value else {
+                continue;
+            };
+            if candidate == key {
+                value = Some(candidate_value);
+            } else {
+                bucket_siblings.push(AtomicStateLeaf { logical_key: candidate.logical_key(), value: candidate_value });
+            }
+        }
+        bucket_siblings.sort_unstable_by(|left, right| left.logical_key.cmp(&right.logical_key));
+
+        let proof = AtomicStateProof {
+            logical_key,
+            value,
+            bucket_index: target_bucket,
+            bucket_siblings,
+            buckets: buckets.to_vec(),
+            state_root,
+        };
+        proof.verify().ok()?;
+        Some(proof)
+    }
+
+    fn root_leaf_value(&self, key: RootLeafKey) -> Option<Vec<u8>> {
+        match key {
+            RootLeafKey::Asset(asset_id) => root_leaf_value_for_asset(self.asset_value(&asset_id).as_ref()),
+            RootLeafKey::Balance(key) => root_leaf_value_for_balance(self.balance_value(&key)),
+            RootLeafKey::Nonce(key) => root_leaf_value_for_nonce(self.nonce_value(&key)),
+            RootLeafKey::AnchorCount(owner_id) => root_leaf_value_for_anchor_count(self.anchor_count_value(&owner_id)),
+            RootLeafKey::Allowance(key) => root_leaf_value_for_allowance(self.allowance_value(&key).as_ref()),
+            RootLeafKey::Lock(key) => root_leaf_value_for_lock(self.lock_value(&key).as_ref()),
+        }
+    }
+
+    pub fn indexed_assets_page(&self, offset: usize, limit: usize, query: &str) -> (Vec<TokenAsset>, u64) {
+        if self.assets.is_empty() && self.deleted_assets.is_empty() {
+            if let Some((assets, total)) = self.state_store.as_ref().and_then(|store| store.assets_page(offset, limit, query).ok()) {
+                return (assets, total);
+            }
+        }
 
         let mut assets = self
             .state_store
@@ -3580,6 +5302,8 @@ impl AtomicTokenState {
         let mut balances = self.balances.clone();
         let mut nonces = self.nonces.clone();
         let mut anchor_counts = self.anchor_counts.clone();
+        let mut allowances = self.allowances.clone();
+        let mut locks = self.locks.clone();
         let mut processed_ops = self.processed_ops.clone();
 
         for block_hash in self.applied_chain_order.iter().skip(target_index + 1).rev().copied() {
@@ -3629,6 +5353,28 @@ impl AtomicTokenState {
                 }
             }
 
+            for change in journal.changed_allowances.iter().rev() {
+                match change.old_value {
+                    Some(value) => {
+                        allowances.insert(change.key, value);
+                    }
+                    None => {
+                        allowances.remove(&change.key);
+                    }
+                }
+            }
+
+            for change in journal.changed_locks.iter().rev() {
+                match change.old_value {
+                    Some(value) => {
+                        locks.insert(change.key, value);
+                    }
+                    None => {
+                        locks.remove(&change.key);
+                    }
+                }
+            }
+
             for txid in journal.added_processed_ops.iter().copied() {
                 processed_ops.remove(&txid);
             }
@@ -3646,6 +5392,8 @@ impl AtomicTokenState {
             balances,
             nonces,
             anchor_counts,
+            allowances,
+            locks,
             processed_ops,
             known_owner_addresses,
         };
@@ -3773,6 +5521,8 @@ impl AtomicTokenState {
                 balances: view.balances,
                 nonces: view.nonces,
                 anchor_counts: view.anchor_counts,
+                allowances: view.allowances,
+                locks: view.locks,
                 processed_ops: view.processed_ops,
                 state_hash_by_block,
                 event_sequence_by_block,
@@ -3886,6 +5636,8 @@ impl AtomicTokenState {
                 balances: self.balances,
                 nonces: self.nonces,
                 anchor_counts: self.anchor_counts,
+                allowances: self.allowances,
+                locks: self.locks,
                 processed_ops: self.processed_ops,
                 state_hash_by_block,
                 event_sequence_by_block,
@@ -3966,6 +5718,8 @@ impl AtomicTokenState {
             balances,
             nonces,
             anchor_counts,
+            allowances,
+            locks,
             processed_ops,
             state_hash_by_block,
             event_sequence_by_block,
@@ -3996,6 +5750,8 @@ impl AtomicTokenState {
         self.balances = balances;
         self.nonces = nonces;
         self.anchor_counts = anchor_counts;
+        self.allowances = allowances;
+        self.locks = locks;
         self.processed_ops = processed_ops;
         self.state_hash_by_block = trusted_state_hash_by_block;
         self.event_sequence_by_block = trusted_event_sequence_by_block;
@@ -4003,6 +5759,7 @@ impl AtomicTokenState {
         self.next_event_sequence = 0;
         self.events = Vec::new();
         self.event_ids.clear();
+        self.liquidity_trades.clear();
         self.block_journals = journals_in_window.into_iter().collect();
         self.rebuild_liquidity_vault_outpoint_index();
         self.rebuild_known_owner_address_cache();
@@ -4203,6 +5960,8 @@ impl AtomicTokenState {
             balances: view.balances,
             nonces: view.nonces,
             anchor_counts: view.anchor_counts,
+            allowances: view.allowances,
+            locks: view.locks,
             processed_ops: view.processed_ops,
             block_journals: Default::default(),
             state_hash_by_block: Default::default(),
@@ -4221,29 +5980,65 @@ impl AtomicTokenState {
             deleted_balances: Default::default(),
             deleted_nonces: Default::default(),
             deleted_anchor_counts: Default::default(),
+            deleted_allowances: Default::default(),
+            deleted_locks: Default::default(),
             deleted_processed_ops: Default::default(),
         };
         state.compute_state_hash_canonical()
     }
 
-    fn compute_state_hash_canonical(&self) -> [u8; 32] {
-        if self.assets.is_empty()
+    fn storage_overlay_is_empty(&self) -> bool {
+        self.assets.is_empty()
             && self.balances.is_empty()
             && self.nonces.is_empty()
             && self.anchor_counts.is_empty()
+            && self.allowances.is_empty()
+            && self.locks.is_empty()
             && self.processed_ops.is_empty()
             && self.deleted_assets.is_empty()
             && self.deleted_balances.is_empty()
             && self.deleted_nonces.is_empty()
             && self.deleted_anchor_counts.is_empty()
+            && self.deleted_allowances.is_empty()
+            && self.deleted_locks.is_empty()
             && self.deleted_processed_ops.is_empty()
-        {
+    }
+
+    fn compute_state_hash_canonical(&self) -> [u8; 32] {
+        if self.storage_overlay_is_empty() {
             if let Some(root) = self.state_store.as_ref().and_then(|store| store.current_root().ok().flatten()) {
                 return root;
             }
         }
 
-        compute_state_root_from_parts(&self.assets, &self.balances, &self.nonces, &self.anchor_counts)
+        compute_state_root_from_parts(&self.assets, &self.balances, &self.nonces, &self.anchor_counts, &self.allowances, &self.locks)
+    }
+}
+
+fn map_pair_liquidity_error(err: LiquidityMathError) -> NoopReason {
+    match err {
+        LiquidityMathError::InvalidInput | LiquidityMathError::InvalidState => NoopReason::PairLiquidityTooLow,
+        other => map_liquidity_math_error(other),
+    }
+}
+
+/// Returns `true` when `asset_id` is the pool's `a` side.
+fn pair_swap_side(pair: &PairPoolState, asset_id: [u8; 32]) -> Result<bool, NoopReason> {
+    if asset_id == pair.asset_a_id {
+        Ok(true)
+    } else if asset_id == pair.asset_b_id {
+        Ok(false)
+    } else {
+        Err(NoopReason::BadPairAssets)
+    }
+}
+
+/// Returns the `(input, output)` reserve keys for a swap direction.
+fn pair_reserve_keys(pair: &PairPoolState, pool_id: [u8; 32], in_is_a: bool) -> (BalanceKey, BalanceKey) {
+    if in_is_a {
+        (pair.reserve_key_a(pool_id), pair.reserve_key_b(pool_id))
+    } else {
+        (pair.reserve_key_b(pool_id), pair.reserve_key_a(pool_id))
     }
 }
 
@@ -4272,6 +6067,8 @@ fn block_journal_touched_atomic_state(journal: &BlockJournal, events_added: usiz
         || !journal.changed_balances.is_empty()
         || !journal.changed_nonces.is_empty()
         || !journal.changed_anchor_counts.is_empty()
+        || !journal.changed_allowances.is_empty()
+        || !journal.changed_locks.is_empty()
 }
 
 fn short_hex_for_log(data: &[u8]) -> String {
@@ -4481,11 +6278,28 @@ mod tests {
         accepting_block_hash: BlockHash,
         refs: Vec<CanonicalTxRef>,
         auth_inputs: &HashMap<TransactionOutpoint, UtxoEntry>,
+    ) {
+        apply_block_at_daa_score(state, accepting_block_hash, 0, refs, auth_inputs);
+    }
+
+    fn apply_block_at_daa_score(
+        state: &mut AtomicTokenState,
+        accepting_block_hash: BlockHash,
+        accepting_block_daa_score: u64,
+        refs: Vec<CanonicalTxRef>,
+        auth_inputs: &HashMap<TransactionOutpoint, UtxoEntry>,
     ) {
         let mut journal = JournalBuilder::default();
         for (ordinal, tx_ref) in refs.iter().enumerate() {
-            let apply_anchor_deltas =
-                state.apply_transaction(accepting_block_hash, 0, 0, tx_ref, ordinal as u32, auth_inputs, &mut journal);
+            let apply_anchor_deltas = state.apply_transaction(
+                accepting_block_hash,
+                accepting_block_daa_score,
+                0,
+                tx_ref,
+                ordinal as u32,
+                auth_inputs,
+                &mut journal,
+            );
             if apply_anchor_deltas {
                 state.apply_anchor_deltas_for_tx(&tx_ref.tx, auth_inputs, &mut journal);
             }
@@ -4798,6 +6612,8 @@ mod tests {
                 created_daa_score: None,
                 created_at: None,
                 liquidity: None,
+                pair_pool: None,
+                metadata_history: Vec::new(),
             },
         );
         state.balances.insert(BalanceKey { asset_id, owner_id: owner }, 900);
@@ -4865,6 +6681,8 @@ mod tests {
                     unlocked: true,
                     holder_addresses,
                 }),
+                pair_pool: None,
+                metadata_history: Vec::new(),
             },
         );
         let before = state.compute_state_hash();
@@ -4903,6 +6721,8 @@ mod tests {
                 created_daa_score: Some(10),
                 created_at: Some(20),
                 liquidity: None,
+                pair_pool: None,
+                metadata_history: Vec::new(),
             },
         );
 
@@ -5186,6 +7006,8 @@ mod tests {
                     unlocked: true,
                     holder_addresses: HashMap::new(),
                 }),
+                pair_pool: None,
+                metadata_history: Vec::new(),
             },
         );
 
@@ -5268,6 +7090,8 @@ mod tests {
                     unlocked: true,
                     holder_addresses: HashMap::new(),
                 }),
+                pair_pool: None,
+                metadata_history: Vec::new(),
             },
         );
         state.rebuild_liquidity_vault_outpoint_index();
@@ -5424,6 +7248,8 @@ mod tests {
                     unlocked: false,
                     holder_addresses: HashMap::new(),
                 }),
+                pair_pool: None,
+                metadata_history: Vec::new(),
             },
         );
 
@@ -5505,6 +7331,223 @@ mod tests {
         assert!(pool_after_sell.real_cpay_reserves_sompi < target);
     }
 
+    #[test]
+    fn liquidity_exact_out_buy_and_sell_settle_exact_amounts() {
+        let mut state = AtomicTokenState::new(1, "cryptix-simnet".to_string());
+        let owner_script = test_script(0x51);
+        let owner_payload = vec![0x51; 32];
+        let owner = state.owner_id_from_address_components(0, owner_payload.as_slice()).expect("owner id should derive");
+        let asset_id = [0x52; 32];
+        let vault_outpoint = TransactionOutpoint::new(BlockHash::from_u64_word(52), 0);
+        let fee_bps = 100;
+
+        state.assets.insert(
+            asset_id,
+            TokenAsset {
+                asset_id,
+                creator_owner_id: owner,
+                asset_class: TokenAssetClass::Liquidity,
+                token_version: CURRENT_TOKEN_VERSION,
+                mint_authority_owner_id: [0u8; 32],
+                decimals: 0,
+                supply_mode: SupplyMode::Capped,
+                max_supply: crate::liquidity_math::LIQUIDITY_TOKEN_SUPPLY_RAW,
+                total_supply: 0,
+                name: b"Exact".to_vec(),
+                symbol: b"EXT".to_vec(),
+                metadata: vec![],
+                platform_tag: Vec::new(),
+                created_block_hash: None,
+                created_daa_score: None,
+                created_at: None,
+                liquidity: Some(LiquidityPoolState {
+                    pool_nonce: 1,
+                    curve_version: CURRENT_LIQUIDITY_CURVE_VERSION,
+                    curve_mode: DEFAULT_LIQUIDITY_CURVE_MODE,
+                    individual_virtual_cpay_reserves_sompi: 0,
+                    individual_virtual_token_multiplier_bps: 0,
+                    real_cpay_reserves_sompi: MIN_LIQUIDITY_SEED_RESERVE_SOMPI,
+                    real_token_reserves: crate::liquidity_math::LIQUIDITY_TOKEN_SUPPLY_RAW,
+                    virtual_cpay_reserves_sompi: INITIAL_VIRTUAL_CPAY_RESERVES_SOMPI,
+                    virtual_token_reserves: crate::liquidity_math::INITIAL_VIRTUAL_TOKEN_RESERVES,
+                    unclaimed_fee_total_sompi: 0,
+                    fee_bps,
+                    fee_recipients: vec![LiquidityFeeRecipientState {
+                        owner_id: owner,
+                        address_version: 0,
+                        address_payload: owner_payload.clone(),
+                        unclaimed_sompi: 0,
+                    }],
+                    vault_outpoint,
+                    vault_value_sompi: MIN_LIQUIDITY_SEED_RESERVE_SOMPI,
+                    unlock_target_sompi: 0,
+                    unlocked: true,
+                    holder_addresses: HashMap::new(),
+                }),
+                pair_pool: None,
+                metadata_history: Vec::new(),
+            },
+        );
+
+        let token_out = 10u128;
+        let (net_in, _, _, _) = cpmm_buy_exact_out(
+            crate::liquidity_math::LIQUIDITY_TOKEN_SUPPLY_RAW,
+            INITIAL_VIRTUAL_CPAY_RESERVES_SOMPI,
+            crate::liquidity_math::INITIAL_VIRTUAL_TOKEN_RESERVES,
+            token_out,
+        )
+        .expect("exact-out buy should quote");
+        let (cpay_in, buy_fee) = gross_amount_for_net_amount(net_in, fee_bps).expect("gross input should split");
+        let buy_tx = tx_with_inputs_outputs(
+            vec![(vault_outpoint, 0)],
+            vec![TransactionOutput::new(MIN_LIQUIDITY_SEED_RESERVE_SOMPI + cpay_in, liquidity_vault_script())],
+            vec![],
+        );
+        let mut buy_auth_inputs = HashMap::new();
+        buy_auth_inputs.insert(vault_outpoint, UtxoEntry::new(MIN_LIQUIDITY_SEED_RESERVE_SOMPI, liquidity_vault_script(), 0, false));
+        let buyer_auth = AuthContext { owner_id: owner, address_version: 0, address_payload: owner_payload };
+        let mut journal = JournalBuilder::default();
+
+        let tight_buy = BuyLiquidityExactOutOp { asset_id, expected_pool_nonce: 1, token_out, max_cpay_in_sompi: cpay_in - 1 };
+        assert_eq!(
+            state.execute_buy_liquidity_exact_out(&buy_tx, &buyer_auth, &tight_buy, &buy_auth_inputs, &mut journal),
+            Err(NoopReason::MinOutViolation)
+        );
+        let buy_op = BuyLiquidityExactOutOp { asset_id, expected_pool_nonce: 1, token_out, max_cpay_in_sompi: cpay_in };
+        state
+            .execute_buy_liquidity_exact_out(&buy_tx, &buyer_auth, &buy_op, &buy_auth_inputs, &mut journal)
+            .expect("exact-out buy should apply");
+        assert_eq!(state.get_balance(asset_id, owner), token_out);
+        let pool = state.assets.get(&asset_id).and_then(|asset| asset.liquidity.clone()).expect("pool should exist");
+        assert_eq!(pool.pool_nonce, 2);
+        assert_eq!(pool.unclaimed_fee_total_sompi, buy_fee);
+        assert_eq!(pool.vault_value_sompi, MIN_LIQUIDITY_SEED_RESERVE_SOMPI + cpay_in);
+        assert_eq!(pool.real_token_reserves, crate::liquidity_math::LIQUIDITY_TOKEN_SUPPLY_RAW - token_out);
+
+        let cpay_out = 5 * MIN_LIQUIDITY_SEED_RESERVE_SOMPI;
+        let (gross_out, sell_fee) = gross_amount_for_net_amount(cpay_out, fee_bps).expect("gross output should split");
+        let (token_in, _, _, _) = cpmm_sell_exact_out(
+            pool.real_cpay_reserves_sompi,
+            pool.virtual_cpay_reserves_sompi,
+            pool.virtual_token_reserves,
+            gross_out,
+        )
+        .expect("exact-out sell should quote");
+        assert!(token_in < token_out);
+        let sell_tx = tx_with_inputs_outputs(
+            vec![(pool.vault_outpoint, 0)],
+            vec![
+                TransactionOutput::new(pool.vault_value_sompi - cpay_out, liquidity_vault_script()),
+                TransactionOutput::new(cpay_out, owner_script),
+            ],
+            vec![],
+        );
+        let mut sell_auth_inputs = HashMap::new();
+        sell_auth_inputs.insert(pool.vault_outpoint, UtxoEntry::new(pool.vault_value_sompi, liquidity_vault_script(), 0, false));
+
+        let tight_sell = SellLiquidityExactOutOp {
+            asset_id,
+            expected_pool_nonce: 2,
+            cpay_out_sompi: cpay_out,
+            max_token_in: token_in - 1,
+            cpay_receive_output_index: 1,
+        };
+        assert_eq!(
+            state.execute_sell_liquidity_exact_out(&sell_tx, owner, &tight_sell, &sell_auth_inputs, &mut journal),
+            Err(NoopReason::MinOutViolation)
+        );
+        let sell_op = SellLiquidityExactOutOp { max_token_in: token_in, ..tight_sell };
+        assert_eq!(state.execute_sell_liquidity_exact_out(&sell_tx, owner, &sell_op, &sell_auth_inputs, &mut journal), Ok(token_in));
+        assert_eq!(state.get_balance(asset_id, owner), token_out - token_in);
+        let pool_after_sell = state.assets.get(&asset_id).and_then(|asset| asset.liquidity.as_ref()).expect("pool should exist");
+        assert_eq!(pool_after_sell.pool_nonce, 3);
+        assert_eq!(pool_after_sell.unclaimed_fee_total_sompi, buy_fee + sell_fee);
+        assert_eq!(pool_after_sell.vault_value_sompi, pool.vault_value_sompi - cpay_out);
+        assert_eq!(pool_after_sell.real_cpay_reserves_sompi, pool.real_cpay_reserves_sompi - gross_out);
+    }
+
+    #[test]
+    fn liquidity_trade_records_build_candles_and_follow_reorgs_and_pruning() {
+        let mut state = AtomicTokenState::new(1, "cryptix-simnet".to_string());
+        let asset_id = [0x61; 32];
+        let trade = |daa_score, price_sompi, cpay_volume_sompi| LiquidityTradeDetails {
+            daa_score,
+            side: LiquidityTradeSide::Buy,
+            cpay_volume_sompi,
+            token_amount: 10,
+            fee_sompi: 0,
+            price_sompi,
+        };
+        let event = |accepting_block_hash, ordinal: u32, event_type, reorg_of_event_id, trade| TokenEvent {
+            event_id: [ordinal as u8 + 1; 32],
+            sequence: 0,
+            accepting_block_hash,
+            txid: BlockHash::from_u64_word(100 + u64::from(ordinal)),
+            event_type,
+            apply_status: ApplyStatus::Applied,
+            noop_reason: NoopReason::None,
+            ordinal,
+            reorg_of_event_id,
+            details: TokenEventDetails {
+                op_type: Some(TokenOpCode::BuyLiquidityExactIn),
+                asset_id: Some(asset_id),
+                trade: Some(trade),
+                ..Default::default()
+            },
+        };
+        let blocks = [BlockHash::from_u64_word(1), BlockHash::from_u64_word(2), BlockHash::from_u64_word(3)];
+        let trades = [
+            (blocks[0], trade(100, 50, 500)),
+            (blocks[1], trade(105, 70, 700)),
+            (blocks[1], trade(108, 40, 400)),
+            (blocks[2], trade(112, 60, 600)),
+        ];
+        let mut applied_event_ids = Vec::new();
+        for (ordinal, (block_hash, trade)) in trades.into_iter().enumerate() {
+            applied_event_ids.push(state.push_event(event(block_hash, ordinal as u32, EventType::Applied, None, trade)));
+            state.event_sequence_by_block.insert(block_hash, state.next_event_sequence);
+        }
+        state.applied_chain_order = blocks.to_vec();
+
+        let candles = state.get_liquidity_candles(&asset_id, 10, 0, u64::MAX);
+        assert_eq!(
+            candles,
+            vec![
+                LiquidityCandle {
+                    start_daa_score: 100,
+                    open_price_sompi: 50,
+                    high_price_sompi: 70,
+                    low_price_sompi: 40,
+                    close_price_sompi: 40,
+                    volume_sompi: 1_600,
+                    trade_count: 3,
+                },
+                LiquidityCandle {
+                    start_daa_score: 110,
+                    open_price_sompi: 60,
+                    high_price_sompi: 60,
+                    low_price_sompi: 60,
+                    close_price_sompi: 60,
+                    volume_sompi: 600,
+                    trade_count: 1,
+                },
+            ]
+        );
+        let windowed = state.get_liquidity_candles(&asset_id, 10, 105, 112);
+        assert_eq!(windowed.len(), 1);
+        assert_eq!((windowed[0].open_price_sompi, windowed[0].trade_count), (70, 2));
+        assert!(state.get_liquidity_candles(&asset_id, 0, 0, u64::MAX).is_empty());
+
+        state.push_event(event(blocks[2], 3, EventType::Reorged, Some(applied_event_ids[3]), trade(112, 60, 600)));
+        assert_eq!(state.get_liquidity_trades(&asset_id).len(), 3);
+        assert_eq!(state.get_liquidity_candles(&asset_id, 10, 0, u64::MAX).len(), 1);
+
+        let pruned = state.prune_history_with_details(2).expect("oldest block should be pruned");
+        assert_eq!(pruned.pruned_liquidity_trades, 1);
+        let retained = state.get_liquidity_trades(&asset_id).iter().map(|record| record.event_id).collect::<Vec<_>>();
+        assert_eq!(retained, applied_event_ids[1..3].to_vec());
+    }
+
     #[test]
     fn liquidity_invariants_reject_mismatched_holder_owner_id() {
         let state = AtomicTokenState::new(1, "cryptix-simnet".to_string());
@@ -5551,6 +7594,8 @@ mod tests {
                 unlocked: true,
                 holder_addresses,
             }),
+            pair_pool: None,
+            metadata_history: Vec::new(),
         };
 
         let err = state.validate_liquidity_invariants(&asset).expect_err("invariants should fail");
@@ -5596,6 +7641,8 @@ mod tests {
                 unlocked: true,
                 holder_addresses: HashMap::new(),
             }),
+            pair_pool: None,
+            metadata_history: Vec::new(),
         };
 
         let err = state.validate_liquidity_invariants(&asset).expect_err("invariants should fail");
@@ -5658,6 +7705,8 @@ mod tests {
                     unlocked: true,
                     holder_addresses,
                 }),
+                pair_pool: None,
+                metadata_history: Vec::new(),
             },
         );
         state.balances.insert(BalanceKey { asset_id, owner_id: sender_owner }, 100);
@@ -5800,6 +7849,8 @@ mod tests {
             created_daa_score: Some(1),
             created_at: Some(1),
             liquidity: None,
+            pair_pool: None,
+            metadata_history: Vec::new(),
         };
         let owner_balance = BalanceKey { asset_id, owner_id: owner };
         let receiver_balance = BalanceKey { asset_id, owner_id: receiver };
@@ -5848,36 +7899,124 @@ mod tests {
     }
 
     #[test]
-    fn first_replayable_block_hash_uses_contiguous_journal_suffix() {
-        let mut state = AtomicTokenState::new(1, "cryptix-simnet".to_string());
-        let blocks = (1..=5u64).map(BlockHash::from_u64_word).collect::<Vec<_>>();
-        state.applied_chain_order = blocks.clone();
-        state.block_journals.insert(blocks[2], BlockJournal::default());
-        state.block_journals.insert(blocks[3], BlockJournal::default());
-        state.block_journals.insert(blocks[4], BlockJournal::default());
-
-        assert_eq!(state.first_replayable_block_hash(), Some(blocks[2]));
-
-        state.block_journals.remove(&blocks[4]);
-        assert_eq!(state.first_replayable_block_hash(), None);
-    }
-
-    #[test]
-    fn export_snapshot_state_chain_is_replay_window_suffix() {
+    fn state_proofs_verify_against_latest_and_retained_roots() {
         let mut state = AtomicTokenState::new(1, "cryptix-simnet".to_string());
-        let auth_inputs = HashMap::new();
-        let block1 = BlockHash::from_u64_word(7001);
-        let block2 = BlockHash::from_u64_word(7002);
-        let block3 = BlockHash::from_u64_word(7003);
-
-        apply_block(&mut state, block1, vec![], &auth_inputs);
-        let window_start_parent_hash = state.get_state_hash_at_block(block1);
-        apply_block(&mut state, block2, vec![], &auth_inputs);
-        apply_block(&mut state, block3, vec![], &auth_inputs);
-
-        let snapshot = state.export_snapshot(block3, 77, block1, &[block2, block3]).expect("snapshot export must succeed");
+        let asset_id = [0xA4; 32];
+        let owner = [0xB5; 32];
+        let receiver = [0xC6; 32];
+        let block1 = BlockHash::from_u64_word(93);
+        let block2 = BlockHash::from_u64_word(94);
+        let owner_balance = BalanceKey { asset_id, owner_id: owner };
+        let receiver_balance = BalanceKey { asset_id, owner_id: receiver };
+        let nonce_key = NonceKey::asset(owner, asset_id);
 
-        assert_eq!(snapshot.state.applied_chain_order, vec![block2, block3]);
+        let asset = TokenAsset {
+            asset_id,
+            creator_owner_id: owner,
+            asset_class: TokenAssetClass::Standard,
+            token_version: CURRENT_TOKEN_VERSION,
+            mint_authority_owner_id: owner,
+            decimals: 0,
+            supply_mode: SupplyMode::Capped,
+            max_supply: 10,
+            total_supply: 10,
+            name: b"Proof".to_vec(),
+            symbol: b"PRF".to_vec(),
+            metadata: Vec::new(),
+            platform_tag: Vec::new(),
+            created_block_hash: Some(block1),
+            created_daa_score: Some(1),
+            created_at: Some(1),
+            liquidity: None,
+            pair_pool: None,
+            metadata_history: Vec::new(),
+        };
+        state.assets.insert(asset_id, asset);
+        state.balances.insert(owner_balance, 10);
+        for filler in 0u8..32 {
+            state.balances.insert(BalanceKey { asset_id, owner_id: [filler; 32] }, u128::from(filler) + 1);
+        }
+        state.nonces.insert(nonce_key, 2);
+        state.applied_chain_order.push(block1);
+        state.block_journals.insert(block1, BlockJournal::default());
+        state.state_hash_by_block.insert(block1, state.compute_state_hash());
+
+        state.balances.insert(owner_balance, 4);
+        state.balances.insert(receiver_balance, 6);
+        state.nonces.insert(nonce_key, 3);
+        state.applied_chain_order.push(block2);
+        state.block_journals.insert(
+            block2,
+            BlockJournal {
+                changed_balances: vec![
+                    ChangedBalance { key: owner_balance, old_value: Some(10) },
+                    ChangedBalance { key: receiver_balance, old_value: None },
+                ],
+                changed_nonces: vec![ChangedNonce { key: nonce_key, old_value: Some(2) }],
+                ..Default::default()
+            },
+        );
+        state.state_hash_by_block.insert(block2, state.compute_state_hash());
+
+        let latest = state.materialize_latest_context(block2, AtomicTokenRuntimeState::Healthy);
+        let retained = state.materialize_context_at_block(block1, AtomicTokenRuntimeState::Healthy).unwrap();
+        assert_ne!(latest.state_hash, retained.state_hash);
+
+        let proof = state.get_balance_proof(receiver_balance, &latest).unwrap();
+        proof.verify_against(&latest.state_hash).unwrap();
+        assert_eq!(proof.balance().unwrap(), 6);
+        let proof = state.get_balance_proof(receiver_balance, &retained).unwrap();
+        proof.verify_against(&retained.state_hash).unwrap();
+        assert_eq!(proof.balance().unwrap(), 0);
+
+        let proof = state.get_balance_proof(owner_balance, &retained).unwrap();
+        proof.verify_against(&retained.state_hash).unwrap();
+        assert_eq!(proof.balance().unwrap(), 10);
+        let proof = state.get_nonce_proof(nonce_key, &retained).unwrap();
+        proof.verify_against(&retained.state_hash).unwrap();
+        assert_eq!(proof.next_nonce().unwrap(), 2);
+
+        let proof = state.get_asset_proof(asset_id, &latest).unwrap();
+        proof.verify_against(&latest.state_hash).unwrap();
+        assert_eq!(proof.asset().unwrap().map(|asset| asset.total_supply), Some(10));
+        let proof = state.get_asset_proof([0xEE; 32], &latest).unwrap();
+        assert_eq!(proof.asset().unwrap(), None);
+
+        let stale = AtomicTokenReadContext { state_hash: [0x55; 32], ..latest };
+        assert!(state.get_balance_proof(owner_balance, &stale).is_none());
+    }
+
+    #[test]
+    fn first_replayable_block_hash_uses_contiguous_journal_suffix() {
+        let mut state = AtomicTokenState::new(1, "cryptix-simnet".to_string());
+        let blocks = (1..=5u64).map(BlockHash::from_u64_word).collect::<Vec<_>>();
+        state.applied_chain_order = blocks.clone();
+        state.block_journals.insert(blocks[2], BlockJournal::default());
+        state.block_journals.insert(blocks[3], BlockJournal::default());
+        state.block_journals.insert(blocks[4], BlockJournal::default());
+
+        assert_eq!(state.first_replayable_block_hash(), Some(blocks[2]));
+
+        state.block_journals.remove(&blocks[4]);
+        assert_eq!(state.first_replayable_block_hash(), None);
+    }
+
+    #[test]
+    fn export_snapshot_state_chain_is_replay_window_suffix() {
+        let mut state = AtomicTokenState::new(1, "cryptix-simnet".to_string());
+        let auth_inputs = HashMap::new();
+        let block1 = BlockHash::from_u64_word(7001);
+        let block2 = BlockHash::from_u64_word(7002);
+        let block3 = BlockHash::from_u64_word(7003);
+
+        apply_block(&mut state, block1, vec![], &auth_inputs);
+        let window_start_parent_hash = state.get_state_hash_at_block(block1);
+        apply_block(&mut state, block2, vec![], &auth_inputs);
+        apply_block(&mut state, block3, vec![], &auth_inputs);
+
+        let snapshot = state.export_snapshot(block3, 77, block1, &[block2, block3]).expect("snapshot export must succeed");
+
+        assert_eq!(snapshot.state.applied_chain_order, vec![block2, block3]);
         assert_eq!(snapshot.state.state_hash_by_block.len(), 2);
         assert!(snapshot.state.state_hash_by_block.contains_key(&block2));
         assert!(snapshot.state.state_hash_by_block.contains_key(&block3));
@@ -6070,6 +8209,739 @@ mod tests {
         assert_eq!(after_supply, before_supply);
     }
 
+    #[test]
+    fn transfer_from_spends_allowance_and_rolls_back() {
+        let mut state = AtomicTokenState::new(1, "cryptix-simnet".to_string());
+        let owner_script = test_script(91);
+        let spender_script = test_script(92);
+        let owner = owner_id(&state, &owner_script);
+        let spender = owner_id(&state, &spender_script);
+        let receiver = [0x93; 32];
+
+        let outpoints = (0..4).map(|i| TransactionOutpoint::new(BlockHash::from_u64_word(920 + i), 0)).collect::<Vec<_>>();
+        let mut auth_inputs = HashMap::new();
+        for outpoint in outpoints.iter().take(3) {
+            auth_inputs.insert(*outpoint, UtxoEntry::new(1000, owner_script.clone(), 0, false));
+        }
+        auth_inputs.insert(outpoints[3], UtxoEntry::new(1000, spender_script.clone(), 0, false));
+
+        let create_tx = token_tx(outpoints[0], owner_script.clone(), payload_create_asset(0, 1, 8, owner, b"Escrow", b"ESC", b""));
+        let asset_id = hash_bytes(create_tx.id());
+        apply_block(
+            &mut state,
+            BlockHash::from_u64_word(1101),
+            vec![tx_ref(create_tx, BlockHash::from_u64_word(2101), 0, 0)],
+            &auth_inputs,
+        );
+        let mint_nonce = state.get_token_nonce(owner, asset_id);
+        let mint_tx = token_tx(outpoints[1], owner_script.clone(), payload_mint(0, mint_nonce, asset_id, owner, 1_000));
+        apply_block(
+            &mut state,
+            BlockHash::from_u64_word(1102),
+            vec![tx_ref(mint_tx, BlockHash::from_u64_word(2102), 0, 0)],
+            &auth_inputs,
+        );
+
+        let mut approve = base_header(TokenOpCode::Approve, 0, state.get_token_nonce(owner, asset_id));
+        approve.extend_from_slice(&asset_id);
+        approve.extend_from_slice(&spender);
+        approve.extend_from_slice(&600u128.to_le_bytes());
+        approve.extend_from_slice(&50u64.to_le_bytes());
+        let approve_tx = token_tx(outpoints[2], owner_script.clone(), approve);
+        apply_block(
+            &mut state,
+            BlockHash::from_u64_word(1103),
+            vec![tx_ref(approve_tx, BlockHash::from_u64_word(2103), 0, 0)],
+            &auth_inputs,
+        );
+        let allowance_key = AllowanceKey { asset_id, owner_id: owner, spender_id: spender };
+        assert_eq!(state.get_allowance(allowance_key), Some(TokenAllowance { amount: 600, expires_at_daa_score: 50 }));
+        let approved_hash = state.compute_state_hash();
+
+        let mut transfer_from = base_header(TokenOpCode::TransferFrom, 0, state.get_token_nonce(spender, asset_id));
+        transfer_from.extend_from_slice(&asset_id);
+        transfer_from.extend_from_slice(&owner);
+        transfer_from.extend_from_slice(&receiver);
+        transfer_from.extend_from_slice(&400u128.to_le_bytes());
+        let transfer_from_tx = token_tx(outpoints[3], spender_script.clone(), transfer_from);
+        apply_block(
+            &mut state,
+            BlockHash::from_u64_word(1104),
+            vec![tx_ref(transfer_from_tx, BlockHash::from_u64_word(2104), 0, 0)],
+            &auth_inputs,
+        );
+
+        assert!(!state.degraded);
+        assert_eq!(state.get_balance(asset_id, owner), 600);
+        assert_eq!(state.get_balance(asset_id, receiver), 400);
+        assert_eq!(state.get_balance(asset_id, spender), 0);
+        assert_eq!(state.get_allowance(allowance_key), Some(TokenAllowance { amount: 200, expires_at_daa_score: 50 }));
+        assert_eq!(state.get_token_nonce(owner, asset_id), mint_nonce + 2, "transfer-from must not consume the owner's nonce");
+
+        state.rollback_block(BlockHash::from_u64_word(1104)).expect("rollback transfer-from block");
+        assert_eq!(state.get_balance(asset_id, owner), 1_000);
+        assert_eq!(state.get_balance(asset_id, receiver), 0);
+        assert_eq!(state.get_allowance(allowance_key), Some(TokenAllowance { amount: 600, expires_at_daa_score: 50 }));
+        assert_eq!(state.compute_state_hash(), approved_hash);
+
+        let allowance = TokenAllowance { amount: 1, expires_at_daa_score: 50 };
+        assert!(!allowance.is_expired_at(49));
+        assert!(allowance.is_expired_at(50));
+        assert!(!TokenAllowance { amount: 1, expires_at_daa_score: 0 }.is_expired_at(u64::MAX));
+    }
+
+    #[test]
+    fn transfer_from_to_the_owner_requires_balance() {
+        let mut state = AtomicTokenState::new(1, "cryptix-simnet".to_string());
+        let owner_script = test_script(97);
+        let spender_script = test_script(98);
+        let owner = owner_id(&state, &owner_script);
+        let spender = owner_id(&state, &spender_script);
+
+        let outpoints = (0..5).map(|i| TransactionOutpoint::new(BlockHash::from_u64_word(970 + i), 0)).collect::<Vec<_>>();
+        let mut auth_inputs = HashMap::new();
+        for outpoint in outpoints.iter().take(3) {
+            auth_inputs.insert(*outpoint, UtxoEntry::new(1000, owner_script.clone(), 0, false));
+        }
+        for outpoint in outpoints.iter().skip(3) {
+            auth_inputs.insert(*outpoint, UtxoEntry::new(1000, spender_script.clone(), 0, false));
+        }
+
+        let create_tx = token_tx(outpoints[0], owner_script.clone(), payload_create_asset(0, 1, 8, owner, b"Loop", b"LOOP", b""));
+        let asset_id = hash_bytes(create_tx.id());
+        apply_block(
+            &mut state,
+            BlockHash::from_u64_word(1151),
+            vec![tx_ref(create_tx, BlockHash::from_u64_word(2151), 0, 0)],
+            &auth_inputs,
+        );
+        let mint_nonce = state.get_token_nonce(owner, asset_id);
+        let mint_tx = token_tx(outpoints[1], owner_script.clone(), payload_mint(0, mint_nonce, asset_id, owner, 100));
+        apply_block(
+            &mut state,
+            BlockHash::from_u64_word(1152),
+            vec![tx_ref(mint_tx, BlockHash::from_u64_word(2152), 0, 0)],
+            &auth_inputs,
+        );
+
+        let mut approve = base_header(TokenOpCode::Approve, 0, state.get_token_nonce(owner, asset_id));
+        approve.extend_from_slice(&asset_id);
+        approve.extend_from_slice(&spender);
+        approve.extend_from_slice(&600u128.to_le_bytes());
+        approve.extend_from_slice(&0u64.to_le_bytes());
+        let approve_tx = token_tx(outpoints[2], owner_script.clone(), approve);
+        apply_block(
+            &mut state,
+            BlockHash::from_u64_word(1153),
+            vec![tx_ref(approve_tx, BlockHash::from_u64_word(2153), 0, 0)],
+            &auth_inputs,
+        );
+        let allowance_key = AllowanceKey { asset_id, owner_id: owner, spender_id: spender };
+
+        let transfer_from_payload = |nonce: u64, amount: u128| {
+            let mut payload = base_header(TokenOpCode::TransferFrom, 0, nonce);
+            payload.extend_from_slice(&asset_id);
+            payload.extend_from_slice(&owner);
+            payload.extend_from_slice(&owner);
+            payload.extend_from_slice(&amount.to_le_bytes());
+            payload
+        };
+
+        // The allowance covers the amount but the owner's balance does not
+        let over_balance_tx =
+            token_tx(outpoints[3], spender_script.clone(), transfer_from_payload(state.get_token_nonce(spender, asset_id), 400));
+        apply_block(
+            &mut state,
+            BlockHash::from_u64_word(1154),
+            vec![tx_ref(over_balance_tx, BlockHash::from_u64_word(2154), 0, 0)],
+            &auth_inputs,
+        );
+        assert!(!state.degraded);
+        assert_eq!(state.get_balance(asset_id, owner), 100);
+        assert_eq!(state.get_allowance(allowance_key), Some(TokenAllowance { amount: 600, expires_at_daa_score: 0 }));
+
+        let covered_tx =
+            token_tx(outpoints[4], spender_script.clone(), transfer_from_payload(state.get_token_nonce(spender, asset_id), 60));
+        apply_block(
+            &mut state,
+            BlockHash::from_u64_word(1155),
+            vec![tx_ref(covered_tx, BlockHash::from_u64_word(2155), 0, 0)],
+            &auth_inputs,
+        );
+        assert_eq!(state.get_balance(asset_id, owner), 100);
+        assert_eq!(state.get_allowance(allowance_key), Some(TokenAllowance { amount: 540, expires_at_daa_score: 0 }));
+    }
+
+    #[test]
+    fn batch_transfer_applies_all_or_nothing_and_rolls_back() {
+        let mut state = AtomicTokenState::new(1, "cryptix-simnet".to_string());
+        let owner_script = test_script(94);
+        let owner = owner_id(&state, &owner_script);
+        let first = [0x95; 32];
+        let second = [0x96; 32];
+
+        let outpoints = (0..4).map(|i| TransactionOutpoint::new(BlockHash::from_u64_word(940 + i), 0)).collect::<Vec<_>>();
+        let mut auth_inputs = HashMap::new();
+        for outpoint in outpoints.iter() {
+            auth_inputs.insert(*outpoint, UtxoEntry::new(1000, owner_script.clone(), 0, false));
+        }
+
+        let create_tx = token_tx(outpoints[0], owner_script.clone(), payload_create_asset(0, 1, 8, owner, b"Payroll", b"PAY", b""));
+        let asset_id = hash_bytes(create_tx.id());
+        apply_block(
+            &mut state,
+            BlockHash::from_u64_word(1201),
+            vec![tx_ref(create_tx, BlockHash::from_u64_word(2201), 0, 0)],
+            &auth_inputs,
+        );
+        let mint_nonce = state.get_token_nonce(owner, asset_id);
+        let mint_tx = token_tx(outpoints[1], owner_script.clone(), payload_mint(0, mint_nonce, asset_id, owner, 1_000));
+        apply_block(
+            &mut state,
+            BlockHash::from_u64_word(1202),
+            vec![tx_ref(mint_tx, BlockHash::from_u64_word(2202), 0, 0)],
+            &auth_inputs,
+        );
+        let minted_hash = state.compute_state_hash();
+
+        let batch_payload = |nonce: u64, recipients: &[([u8; 32], u128)]| {
+            let mut payload = base_header(TokenOpCode::BatchTransfer, 0, nonce);
+            payload.extend_from_slice(&asset_id);
+            payload.push(recipients.len() as u8);
+            for (to_owner_id, amount) in recipients {
+                payload.extend_from_slice(to_owner_id);
+                payload.extend_from_slice(&amount.to_le_bytes());
+            }
+            payload
+        };
+
+        let batch_tx = token_tx(
+            outpoints[2],
+            owner_script.clone(),
+            batch_payload(state.get_token_nonce(owner, asset_id), &[(first, 300), (second, 200), (owner, 100)]),
+        );
+        apply_block(
+            &mut state,
+            BlockHash::from_u64_word(1203),
+            vec![tx_ref(batch_tx, BlockHash::from_u64_word(2203), 0, 0)],
+            &auth_inputs,
+        );
+        assert!(!state.degraded);
+        assert_eq!(state.get_balance(asset_id, owner), 500);
+        assert_eq!(state.get_balance(asset_id, first), 300);
+        assert_eq!(state.get_balance(asset_id, second), 200);
+        assert_eq!(state.get_token_nonce(owner, asset_id), mint_nonce + 2, "a batch consumes a single owner nonce");
+
+        let overdraw_tx = token_tx(
+            outpoints[3],
+            owner_script.clone(),
+            batch_payload(state.get_token_nonce(owner, asset_id), &[(first, 400), (second, 101)]),
+        );
+        let overdraw_txid = overdraw_tx.id();
+        apply_block(
+            &mut state,
+            BlockHash::from_u64_word(1204),
+            vec![tx_ref(overdraw_tx, BlockHash::from_u64_word(2204), 0, 0)],
+            &auth_inputs,
+        );
+        assert_eq!(state.processed_ops.get(&overdraw_txid).map(|processed| processed.apply_status), Some(ApplyStatus::Noop));
+        assert_eq!(state.get_balance(asset_id, owner), 500);
+        assert_eq!(state.get_balance(asset_id, first), 300, "a failing batch must not apply any leg");
+        assert_eq!(state.get_balance(asset_id, second), 200);
+
+        state.rollback_block(BlockHash::from_u64_word(1204)).expect("rollback overdraw block");
+        state.rollback_block(BlockHash::from_u64_word(1203)).expect("rollback batch block");
+        assert_eq!(state.get_balance(asset_id, owner), 1_000);
+        assert_eq!(state.get_balance(asset_id, first), 0);
+        assert_eq!(state.get_balance(asset_id, second), 0);
+        assert_eq!(state.compute_state_hash(), minted_hash);
+    }
+
+    #[test]
+    fn lock_tokens_restricts_spending_releases_and_rolls_back() {
+        let mut state = AtomicTokenState::new(1, "cryptix-simnet".to_string());
+        let owner_script = test_script(97);
+        let owner = owner_id(&state, &owner_script);
+        let beneficiary_script = test_script(98);
+        let beneficiary = owner_id(&state, &beneficiary_script);
+        let recipient = [0x99; 32];
+
+        let outpoints = (0..6).map(|i| TransactionOutpoint::new(BlockHash::from_u64_word(970 + i), 0)).collect::<Vec<_>>();
+        let mut auth_inputs = HashMap::new();
+        for outpoint in outpoints[..3].iter() {
+            auth_inputs.insert(*outpoint, UtxoEntry::new(1000, owner_script.clone(), 0, false));
+        }
+        for outpoint in outpoints[3..].iter() {
+            auth_inputs.insert(*outpoint, UtxoEntry::new(1000, beneficiary_script.clone(), 0, false));
+        }
+
+        let create_tx = token_tx(outpoints[0], owner_script.clone(), payload_create_asset(0, 1, 8, owner, b"Vesting", b"VST", b""));
+        let asset_id = hash_bytes(create_tx.id());
+        apply_block(
+            &mut state,
+            BlockHash::from_u64_word(1301),
+            vec![tx_ref(create_tx, BlockHash::from_u64_word(2301), 0, 0)],
+            &auth_inputs,
+        );
+        let mint_tx = token_tx(
+            outpoints[1],
+            owner_script.clone(),
+            payload_mint(0, state.get_token_nonce(owner, asset_id), asset_id, owner, 1_000),
+        );
+        apply_block(
+            &mut state,
+            BlockHash::from_u64_word(1302),
+            vec![tx_ref(mint_tx, BlockHash::from_u64_word(2302), 0, 0)],
+            &auth_inputs,
+        );
+        let minted_hash = state.compute_state_hash();
+
+        let mut lock_payload = base_header(TokenOpCode::LockTokens, 0, state.get_token_nonce(owner, asset_id));
+        lock_payload.extend_from_slice(&asset_id);
+        lock_payload.extend_from_slice(&beneficiary);
+        lock_payload.extend_from_slice(&600u128.to_le_bytes());
+        lock_payload.extend_from_slice(&100u64.to_le_bytes());
+        lock_payload.extend_from_slice(&200u64.to_le_bytes());
+        let lock_tx = token_tx(outpoints[2], owner_script.clone(), lock_payload);
+        apply_block_at_daa_score(
+            &mut state,
+            BlockHash::from_u64_word(1303),
+            10,
+            vec![tx_ref(lock_tx, BlockHash::from_u64_word(2303), 0, 0)],
+            &auth_inputs,
+        );
+        let balance_key = BalanceKey { asset_id, owner_id: beneficiary };
+        let lock = TokenLock { amount: 600, release_start_daa_score: 100, release_end_daa_score: 200 };
+        assert!(!state.degraded);
+        assert_eq!(state.get_balance(asset_id, owner), 400);
+        assert_eq!(state.get_balance(asset_id, beneficiary), 600);
+        assert_eq!(state.get_lock(balance_key), Some(lock));
+        assert_eq!(lock.locked_at(150), 300);
+
+        let early_tx = token_tx(
+            outpoints[3],
+            beneficiary_script.clone(),
+            payload_transfer(0, state.get_token_nonce(beneficiary, asset_id), asset_id, recipient, 1),
+        );
+        let early_txid = early_tx.id();
+        apply_block_at_daa_score(
+            &mut state,
+            BlockHash::from_u64_word(1304),
+            50,
+            vec![tx_ref(early_tx, BlockHash::from_u64_word(2304), 0, 0)],
+            &auth_inputs,
+        );
+        assert_eq!(state.processed_ops.get(&early_txid).map(|processed| processed.noop_reason), Some(NoopReason::BalanceLocked));
+        assert_eq!(state.get_balance(asset_id, beneficiary), 600);
+
+        let vested_tx = token_tx(
+            outpoints[4],
+            beneficiary_script.clone(),
+            payload_transfer(0, state.get_token_nonce(beneficiary, asset_id), asset_id, recipient, 300),
+        );
+        apply_block_at_daa_score(
+            &mut state,
+            BlockHash::from_u64_word(1305),
+            150,
+            vec![tx_ref(vested_tx, BlockHash::from_u64_word(2305), 0, 0)],
+            &auth_inputs,
+        );
+        assert_eq!(state.get_balance(asset_id, beneficiary), 300);
+        assert_eq!(state.get_balance(asset_id, recipient), 300);
+        assert_eq!(state.get_lock(balance_key), Some(lock), "a partially released lock stays in place");
+
+        let released_tx = token_tx(
+            outpoints[5],
+            beneficiary_script.clone(),
+            payload_transfer(0, state.get_token_nonce(beneficiary, asset_id), asset_id, recipient, 300),
+        );
+        apply_block_at_daa_score(
+            &mut state,
+            BlockHash::from_u64_word(1306),
+            200,
+            vec![tx_ref(released_tx, BlockHash::from_u64_word(2306), 0, 0)],
+            &auth_inputs,
+        );
+        assert_eq!(state.get_balance(asset_id, beneficiary), 0);
+        assert_eq!(state.get_lock(balance_key), None, "a fully released lock is pruned on spend");
+        assert_eq!(state.get_lock_at_block(balance_key, BlockHash::from_u64_word(1305)), Some(Some(lock)));
+
+        for block in (1303..=1306).rev() {
+            state.rollback_block(BlockHash::from_u64_word(block)).expect("rollback lock block");
+        }
+        assert_eq!(state.get_balance(asset_id, owner), 1_000);
+        assert_eq!(state.get_balance(asset_id, beneficiary), 0);
+        assert_eq!(state.get_lock(balance_key), None);
+        assert_eq!(state.compute_state_hash(), minted_hash);
+    }
+
+    #[test]
+    fn mint_authority_transfers_renounces_and_rolls_back() {
+        let mut state = AtomicTokenState::new(1, "cryptix-simnet".to_string());
+        let owner_script = test_script(101);
+        let owner = owner_id(&state, &owner_script);
+        let successor_script = test_script(102);
+        let successor = owner_id(&state, &successor_script);
+
+        let outpoints = (0..6).map(|i| TransactionOutpoint::new(BlockHash::from_u64_word(1010 + i), 0)).collect::<Vec<_>>();
+        let mut auth_inputs = HashMap::new();
+        for outpoint in outpoints[..3].iter() {
+            auth_inputs.insert(*outpoint, UtxoEntry::new(1000, owner_script.clone(), 0, false));
+        }
+        for outpoint in outpoints[3..].iter() {
+            auth_inputs.insert(*outpoint, UtxoEntry::new(1000, successor_script.clone(), 0, false));
+        }
+
+        let create_tx = token_tx(outpoints[0], owner_script.clone(), payload_create_asset(0, 1, 8, owner, b"Handover", b"HND", b""));
+        let asset_id = hash_bytes(create_tx.id());
+        apply_block(
+            &mut state,
+            BlockHash::from_u64_word(1401),
+            vec![tx_ref(create_tx, BlockHash::from_u64_word(2401), 0, 0)],
+            &auth_inputs,
+        );
+        let created_hash = state.compute_state_hash();
+
+        let mut transfer_payload = base_header(TokenOpCode::TransferMintAuthority, 0, state.get_token_nonce(owner, asset_id));
+        transfer_payload.extend_from_slice(&asset_id);
+        transfer_payload.extend_from_slice(&successor);
+        let transfer_tx = token_tx(outpoints[1], owner_script.clone(), transfer_payload);
+        let transfer_txid = transfer_tx.id();
+        apply_block(
+            &mut state,
+            BlockHash::from_u64_word(1402),
+            vec![tx_ref(transfer_tx, BlockHash::from_u64_word(2402), 0, 0)],
+            &auth_inputs,
+        );
+        assert_eq!(state.get_asset(asset_id).map(|asset| asset.mint_authority_owner_id), Some(successor));
+        assert_ne!(state.compute_state_hash(), created_hash, "mint authority is covered by the state hash");
+        let transfer_event = state.events.iter().find(|event| event.txid == transfer_txid).expect("transfer authority event");
+        assert_eq!(transfer_event.details.op_type, Some(TokenOpCode::TransferMintAuthority));
+        assert_eq!(transfer_event.details.from_owner_id, Some(owner));
+        assert_eq!(transfer_event.details.to_owner_id, Some(successor));
+
+        let stale_mint_tx =
+            token_tx(outpoints[2], owner_script.clone(), payload_mint(0, state.get_token_nonce(owner, asset_id), asset_id, owner, 1));
+        let stale_mint_txid = stale_mint_tx.id();
+        let successor_mint_tx = token_tx(
+            outpoints[3],
+            successor_script.clone(),
+            payload_mint(0, state.get_token_nonce(successor, asset_id), asset_id, successor, 50),
+        );
+        apply_block(
+            &mut state,
+            BlockHash::from_u64_word(1403),
+            vec![
+                tx_ref(stale_mint_tx, BlockHash::from_u64_word(2403), 0, 0),
+                tx_ref(successor_mint_tx, BlockHash::from_u64_word(2403), 1, 1),
+            ],
+            &auth_inputs,
+        );
+        assert_eq!(
+            state.processed_ops.get(&stale_mint_txid).map(|processed| processed.noop_reason),
+            Some(NoopReason::UnauthorizedMint)
+        );
+        assert_eq!(state.get_balance(asset_id, successor), 50);
+
+        let mut renounce_payload = base_header(TokenOpCode::RenounceMintAuthority, 0, state.get_token_nonce(successor, asset_id));
+        renounce_payload.extend_from_slice(&asset_id);
+        let renounce_tx = token_tx(outpoints[4], successor_script.clone(), renounce_payload);
+        apply_block(
+            &mut state,
+            BlockHash::from_u64_word(1404),
+            vec![tx_ref(renounce_tx, BlockHash::from_u64_word(2404), 0, 0)],
+            &auth_inputs,
+        );
+        assert_eq!(state.get_asset(asset_id).map(|asset| asset.mint_authority_owner_id), Some([0u8; 32]));
+
+        let renounced_mint_tx = token_tx(
+            outpoints[5],
+            successor_script.clone(),
+            payload_mint(0, state.get_token_nonce(successor, asset_id), asset_id, successor, 1),
+        );
+        let renounced_mint_txid = renounced_mint_tx.id();
+        apply_block(
+            &mut state,
+            BlockHash::from_u64_word(1405),
+            vec![tx_ref(renounced_mint_tx, BlockHash::from_u64_word(2405), 0, 0)],
+            &auth_inputs,
+        );
+        assert_eq!(
+            state.processed_ops.get(&renounced_mint_txid).map(|processed| processed.noop_reason),
+            Some(NoopReason::UnauthorizedMint)
+        );
+
+        for block in (1402..=1405).rev() {
+            state.rollback_block(BlockHash::from_u64_word(block)).expect("rollback mint authority block");
+        }
+        assert_eq!(state.get_asset(asset_id).map(|asset| asset.mint_authority_owner_id), Some(owner));
+        assert_eq!(state.compute_state_hash(), created_hash);
+    }
+
+    #[test]
+    fn update_metadata_records_capped_history_and_rolls_back() {
+        let mut state = AtomicTokenState::new(1, "cryptix-simnet".to_string());
+        let creator_script = test_script(111);
+        let creator = owner_id(&state, &creator_script);
+        let other_script = test_script(112);
+        let other = owner_id(&state, &other_script);
+
+        let update_count = MAX_METADATA_HISTORY_REVISIONS as u64 + 1;
+        let outpoints =
+            (0..update_count + 2).map(|i| TransactionOutpoint::new(BlockHash::from_u64_word(1110 + i), 0)).collect::<Vec<_>>();
+        let mut auth_inputs = HashMap::new();
+        for outpoint in outpoints.iter() {
+            auth_inputs.insert(*outpoint, UtxoEntry::new(1000, creator_script.clone(), 0, false));
+        }
+        auth_inputs.insert(outpoints[1], UtxoEntry::new(1000, other_script.clone(), 0, false));
+        let update_payload = |owner: [u8; 32], state: &AtomicTokenState, asset_id: [u8; 32], metadata: &[u8], platform_tag: &[u8]| {
+            let mut payload = base_header(TokenOpCode::UpdateMetadata, 0, state.get_token_nonce(owner, asset_id));
+            payload.extend_from_slice(&asset_id);
+            payload.extend_from_slice(&(metadata.len() as u16).to_le_bytes());
+            payload.extend_from_slice(metadata);
+            payload.push(platform_tag.len() as u8);
+            payload.extend_from_slice(platform_tag);
+            payload
+        };
+
+        let create_tx =
+            token_tx(outpoints[0], creator_script.clone(), payload_create_asset(0, 1, 8, creator, b"Relabel", b"RLB", b"logo-v0"));
+        let asset_id = hash_bytes(create_tx.id());
+        apply_block(
+            &mut state,
+            BlockHash::from_u64_word(1501),
+            vec![tx_ref(create_tx, BlockHash::from_u64_word(2501), 0, 0)],
+            &auth_inputs,
+        );
+        let created_hash = state.compute_state_hash();
+
+        let other_tx = token_tx(outpoints[1], other_script.clone(), update_payload(other, &state, asset_id, b"spoofed", b""));
+        let other_txid = other_tx.id();
+        apply_block(
+            &mut state,
+            BlockHash::from_u64_word(1502),
+            vec![tx_ref(other_tx, BlockHash::from_u64_word(2502), 0, 0)],
+            &auth_inputs,
+        );
+        assert_eq!(
+            state.processed_ops.get(&other_txid).map(|processed| processed.noop_reason),
+            Some(NoopReason::UnauthorizedMetadataUpdate)
+        );
+        assert_eq!(state.get_asset(asset_id).map(|asset| asset.metadata), Some(b"logo-v0".to_vec()));
+
+        let mut hash_before_last = created_hash;
+        for revision in 1..=update_count {
+            if revision == update_count {
+                hash_before_last = state.compute_state_hash();
+            }
+            let metadata = format!("logo-v{revision}");
+            let update_tx = token_tx(
+                outpoints[revision as usize + 1],
+                creator_script.clone(),
+                update_payload(creator, &state, asset_id, metadata.as_bytes(), b"wallet"),
+            );
+            let update_txid = update_tx.id();
+            apply_block(
+                &mut state,
+                BlockHash::from_u64_word(1502 + revision),
+                vec![tx_ref(update_tx, BlockHash::from_u64_word(2502 + revision), 0, 0)],
+                &auth_inputs,
+            );
+            let event = state.events.iter().find(|event| event.txid == update_txid).expect("update metadata event");
+            assert_eq!(event.apply_status, ApplyStatus::Applied);
+            assert_eq!(event.details.op_type, Some(TokenOpCode::UpdateMetadata));
+            assert_eq!(event.details.asset_id, Some(asset_id));
+        }
+
+        let asset = state.get_asset(asset_id).expect("asset");
+        assert_eq!(asset.metadata, format!("logo-v{update_count}").into_bytes());
+        assert_eq!(asset.platform_tag, b"wallet".to_vec());
+        assert_eq!(asset.metadata_history.len(), MAX_METADATA_HISTORY_REVISIONS);
+        assert_eq!(asset.metadata_history.first().map(|revision| revision.revision), Some(2));
+        let last = asset.metadata_history.last().expect("latest revision");
+        assert_eq!(last.revision, update_count);
+        assert_eq!(last.previous_metadata, format!("logo-v{}", update_count - 1).into_bytes());
+        assert_eq!(last.updated_block_hash, BlockHash::from_u64_word(1502 + update_count));
+
+        state.rollback_block(BlockHash::from_u64_word(1502 + update_count)).expect("rollback latest metadata update");
+        let asset = state.get_asset(asset_id).expect("asset");
+        assert_eq!(asset.metadata, format!("logo-v{}", update_count - 1).into_bytes());
+        assert_eq!(asset.metadata_history.first().map(|revision| revision.revision), Some(1));
+        assert_eq!(asset.metadata_history.len(), MAX_METADATA_HISTORY_REVISIONS);
+        assert_eq!(state.compute_state_hash(), hash_before_last);
+
+        for block in (1502..1502 + update_count).rev() {
+            state.rollback_block(BlockHash::from_u64_word(block)).expect("rollback metadata block");
+        }
+        let asset = state.get_asset(asset_id).expect("asset");
+        assert_eq!(asset.metadata, b"logo-v0".to_vec());
+        assert!(asset.metadata_history.is_empty());
+        assert_eq!(state.compute_state_hash(), created_hash);
+    }
+
+    #[test]
+    fn pair_pool_lifecycle_swaps_both_ways_and_rolls_back() {
+        let mut state = AtomicTokenState::new(1, "cryptix-simnet".to_string());
+        let owner_script = test_script(121);
+        let owner = owner_id(&state, &owner_script);
+        let trader_script = test_script(122);
+        let trader = owner_id(&state, &trader_script);
+
+        let outpoints = (0..12).map(|i| TransactionOutpoint::new(BlockHash::from_u64_word(1210 + i), 0)).collect::<Vec<_>>();
+        let mut auth_inputs = HashMap::new();
+        for outpoint in outpoints[..9].iter() {
+            auth_inputs.insert(*outpoint, UtxoEntry::new(1000, owner_script.clone(), 0, false));
+        }
+        for outpoint in outpoints[9..].iter() {
+            auth_inputs.insert(*outpoint, UtxoEntry::new(1000, trader_script.clone(), 0, false));
+        }
+
+        let create_x = token_tx(outpoints[0], owner_script.clone(), payload_create_asset(0, 1, 0, owner, b"Xeno", b"XEN", b""));
+        let create_y = token_tx(outpoints[1], owner_script.clone(), payload_create_asset(0, 2, 0, owner, b"Ypsilon", b"YPS", b""));
+        let (x_id, y_id) = (hash_bytes(create_x.id()), hash_bytes(create_y.id()));
+        let (asset_a, asset_b) = if x_id < y_id { (x_id, y_id) } else { (y_id, x_id) };
+        apply_block(
+            &mut state,
+            BlockHash::from_u64_word(1501),
+            vec![tx_ref(create_x, BlockHash::from_u64_word(2501), 0, 0), tx_ref(create_y, BlockHash::from_u64_word(2501), 1, 1)],
+            &auth_inputs,
+        );
+        let mut mints = Vec::new();
+        for (index, (asset_id, to_owner_id)) in
+            [(asset_a, owner), (asset_b, owner), (asset_a, trader), (asset_b, trader)].into_iter().enumerate()
+        {
+            let nonce = state.get_token_nonce(owner, asset_id) + u64::from(to_owner_id == trader);
+            let mint = token_tx(outpoints[2 + index], owner_script.clone(), payload_mint(0, nonce, asset_id, to_owner_id, 100_000));
+            mints.push(tx_ref(mint, BlockHash::from_u64_word(2502), index as u32, index as u32));
+        }
+        apply_block(&mut state, BlockHash::from_u64_word(1502), mints, &auth_inputs);
+        assert_eq!(state.get_balance(asset_b, trader), 100_000);
+        let funded_hash = state.compute_state_hash();
+
+        let mut create_payload = base_header(TokenOpCode::CreatePairPool, 0, state.get_owner_nonce(owner));
+        create_payload.extend_from_slice(&asset_a);
+        create_payload.extend_from_slice(&asset_b);
+        create_payload.extend_from_slice(&30u16.to_le_bytes());
+        create_payload.extend_from_slice(&40_000u128.to_le_bytes());
+        create_payload.extend_from_slice(&90_000u128.to_le_bytes());
+        let create_pool_tx = token_tx(outpoints[6], owner_script.clone(), create_payload);
+        let pool_id = hash_bytes(create_pool_tx.id());
+        apply_block(
+            &mut state,
+            BlockHash::from_u64_word(1503),
+            vec![tx_ref(create_pool_tx, BlockHash::from_u64_word(2503), 0, 0)],
+            &auth_inputs,
+        );
+        assert!(!state.degraded);
+        let pool = state.get_asset(pool_id).expect("pair pool asset");
+        assert!(matches!(pool.asset_class, TokenAssetClass::Pair));
+        assert_eq!(pool.total_supply, 60_000);
+        assert_eq!(state.get_balance(pool_id, owner), 60_000 - MIN_PAIR_LIQUIDITY_SHARES);
+        assert_eq!(state.get_balance(pool_id, pool_id), MIN_PAIR_LIQUIDITY_SHARES);
+        assert_eq!(state.get_balance(asset_a, pool_id), 40_000);
+        assert_eq!(state.get_balance(asset_b, pool_id), 90_000);
+
+        let (expected_shares, expected_a_in, expected_b_in) = pair_add_liquidity(40_000, 90_000, 60_000, 4_000, 20_000).unwrap();
+        let mut add_payload = base_header(TokenOpCode::AddPairLiquidity, 0, state.get_token_nonce(owner, pool_id));
+        add_payload.extend_from_slice(&pool_id);
+        add_payload.extend_from_slice(&4_000u128.to_le_bytes());
+        add_payload.extend_from_slice(&20_000u128.to_le_bytes());
+        add_payload.extend_from_slice(&expected_shares.to_le_bytes());
+        let add_tx = token_tx(outpoints[7], owner_script.clone(), add_payload);
+        apply_block(
+            &mut state,
+            BlockHash::from_u64_word(1504),
+            vec![tx_ref(add_tx, BlockHash::from_u64_word(2504), 0, 0)],
+            &auth_inputs,
+        );
+        assert_eq!(expected_shares, 6_000);
+        assert_eq!(state.get_balance(asset_a, pool_id), 40_000 + expected_a_in);
+        assert_eq!(state.get_balance(asset_b, pool_id), 90_000 + expected_b_in);
+        assert_eq!(state.get_balance(asset_b, owner), 100_000 - 90_000 - expected_b_in, "unused max input stays with the owner");
+        let (reserve_a, reserve_b) = (state.get_balance(asset_a, pool_id), state.get_balance(asset_b, pool_id));
+
+        let (expected_out, expected_fee) = pair_swap_exact_in(reserve_a, reserve_b, 1_000, 30).unwrap();
+        let mut too_greedy_payload = base_header(TokenOpCode::SwapPairExactIn, 0, state.get_token_nonce(trader, pool_id));
+        too_greedy_payload.extend_from_slice(&pool_id);
+        too_greedy_payload.extend_from_slice(&asset_a);
+        too_greedy_payload.extend_from_slice(&1_000u128.to_le_bytes());
+        too_greedy_payload.extend_from_slice(&(expected_out + 1).to_le_bytes());
+        let too_greedy_tx = token_tx(outpoints[9], trader_script.clone(), too_greedy_payload);
+        let too_greedy_txid = too_greedy_tx.id();
+        let mut swap_in_payload = base_header(TokenOpCode::SwapPairExactIn, 0, state.get_token_nonce(trader, pool_id));
+        swap_in_payload.extend_from_slice(&pool_id);
+        swap_in_payload.extend_from_slice(&asset_a);
+        swap_in_payload.extend_from_slice(&1_000u128.to_le_bytes());
+        swap_in_payload.extend_from_slice(&expected_out.to_le_bytes());
+        let swap_in_tx = token_tx(outpoints[10], trader_script.clone(), swap_in_payload);
+        apply_block(
+            &mut state,
+            BlockHash::from_u64_word(1505),
+            vec![
+                tx_ref(too_greedy_tx, BlockHash::from_u64_word(2505), 0, 0),
+                tx_ref(swap_in_tx, BlockHash::from_u64_word(2505), 1, 1),
+            ],
+            &auth_inputs,
+        );
+        assert_eq!(
+            state.processed_ops.get(&too_greedy_txid).map(|processed| processed.noop_reason),
+            Some(NoopReason::MinOutViolation)
+        );
+        assert_eq!(state.get_balance(asset_a, trader), 99_000);
+        assert_eq!(state.get_balance(asset_b, trader), 100_000 + expected_out);
+        assert_eq!(state.get_asset(pool_id).and_then(|pool| pool.pair_pool).map(|pair| pair.accrued_fee_a), Some(expected_fee));
+        let (reserve_a, reserve_b) = (state.get_balance(asset_a, pool_id), state.get_balance(asset_b, pool_id));
+        assert_eq!(reserve_a, 40_000 + expected_a_in + 1_000);
+
+        let (expected_in, _) = pair_swap_exact_out(reserve_b, reserve_a, 500, 30).unwrap();
+        let mut swap_out_payload = base_header(TokenOpCode::SwapPairExactOut, 0, state.get_token_nonce(trader, pool_id));
+        swap_out_payload.extend_from_slice(&pool_id);
+        swap_out_payload.extend_from_slice(&asset_a);
+        swap_out_payload.extend_from_slice(&500u128.to_le_bytes());
+        swap_out_payload.extend_from_slice(&expected_in.to_le_bytes());
+        let swap_out_tx = token_tx(outpoints[11], trader_script.clone(), swap_out_payload);
+        apply_block(
+            &mut state,
+            BlockHash::from_u64_word(1506),
+            vec![tx_ref(swap_out_tx, BlockHash::from_u64_word(2506), 0, 0)],
+            &auth_inputs,
+        );
+        assert_eq!(state.get_balance(asset_a, trader), 99_500);
+        assert_eq!(state.get_balance(asset_b, trader), 100_000 + expected_out - expected_in);
+        assert_eq!(state.get_balance(asset_b, pool_id), reserve_b + expected_in);
+
+        let owner_shares = state.get_balance(pool_id, owner);
+        let total_shares = state.get_asset(pool_id).expect("pool").total_supply;
+        let (reserve_a, reserve_b) = (state.get_balance(asset_a, pool_id), state.get_balance(asset_b, pool_id));
+        let (expected_a_out, expected_b_out) = pair_remove_liquidity(reserve_a, reserve_b, total_shares, owner_shares).unwrap();
+        let mut remove_payload = base_header(TokenOpCode::RemovePairLiquidity, 0, state.get_token_nonce(owner, pool_id));
+        remove_payload.extend_from_slice(&pool_id);
+        remove_payload.extend_from_slice(&owner_shares.to_le_bytes());
+        remove_payload.extend_from_slice(&expected_a_out.to_le_bytes());
+        remove_payload.extend_from_slice(&expected_b_out.to_le_bytes());
+        let remove_tx = token_tx(outpoints[8], owner_script.clone(), remove_payload);
+        let owner_a_before = state.get_balance(asset_a, owner);
+        apply_block(
+            &mut state,
+            BlockHash::from_u64_word(1507),
+            vec![tx_ref(remove_tx, BlockHash::from_u64_word(2507), 0, 0)],
+            &auth_inputs,
+        );
+        assert_eq!(state.get_balance(pool_id, owner), 0);
+        assert_eq!(state.get_balance(asset_a, owner), owner_a_before + expected_a_out);
+        assert_eq!(state.get_asset(pool_id).map(|pool| pool.total_supply), Some(MIN_PAIR_LIQUIDITY_SHARES));
+        assert_eq!(state.get_balance(asset_a, pool_id), reserve_a - expected_a_out);
+        assert!(state.get_balance(asset_b, pool_id) > 0, "locked shares keep both reserves non-empty");
+
+        for block in (1503..=1507).rev() {
+            state.rollback_block(BlockHash::from_u64_word(block)).expect("rollback pair pool block");
+        }
+        assert!(state.get_asset(pool_id).is_none());
+        assert_eq!(state.get_balance(asset_a, pool_id), 0);
+        assert_eq!(state.get_balance(asset_b, trader), 100_000);
+        assert_eq!(state.compute_state_hash(), funded_hash);
+    }
+
     #[test]
     fn event_and_asset_metadata_capture_explorer_fields() {
         let mut state = AtomicTokenState::new(1, "cryptix-simnet".to_string());
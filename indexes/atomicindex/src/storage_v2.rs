@@ -1,10 +1,16 @@
 use crate::{
     error::{AtomicTokenError, AtomicTokenResult},
-    state::{AtomicTokenState, BalanceKey, BlockJournal, LiquidityHolderAddressState, NonceKey, ProcessedOp, TokenAsset, TokenEvent},
+    state::{
+        AllowanceKey, AtomicTokenState, BalanceKey, BlockJournal, LiquidityHolderAddressState, NonceKey, ProcessedOp, TokenAllowance,
+        TokenAsset, TokenEvent, TokenLock,
+    },
+};
+use cryptix_atomic_proof::{
+    bucket_index as root_bucket_index, leaf_hash as root_leaf_hash, logical_anchor_count_key, logical_asset_key, root_from_buckets,
+    xor_hash, ASSET_ROOT_V5, ATOMIC_ROOT_BUCKETS,
 };
-use blake2b_simd::Params as Blake2bParams;
 use cryptix_consensus_core::{tx::TransactionOutpoint, Hash as BlockHash};
-use rocksdb::{checkpoint::Checkpoint, Options, WriteBatch, DB};
+use rocksdb::{checkpoint::Checkpoint, Direction, IteratorMode, Options, WriteBatch, DB};
 use serde::{de::DeserializeOwned, Serialize};
 use std::{
     collections::{HashMap, HashSet},
@@ -12,7 +18,7 @@ use std::{
     sync::Mutex,
 };
 
-pub const ATOMIC_DB_SCHEMA_VERSION: u16 = 2;
+pub const ATOMIC_DB_SCHEMA_VERSION: u16 = 9;
 pub const ATOMIC_REVALIDATION_VERSION: u16 = 20;
 
 const META_SCHEMA_VERSION: &[u8] = b"meta/atomic_schema_version";
@@ -31,6 +37,8 @@ const PREFIX_ASSET: &[u8] = b"asset/";
 const PREFIX_BALANCE: &[u8] = b"balance/";
 const PREFIX_NONCE: &[u8] = b"nonce/";
 const PREFIX_ANCHOR_COUNT: &[u8] = b"anchor/";
+const PREFIX_ALLOWANCE: &[u8] = b"allowance/";
+const PREFIX_LOCK: &[u8] = b"lock/";
 const PREFIX_PROCESSED_OP: &[u8] = b"processed_op/";
 const PREFIX_JOURNAL: &[u8] = b"journal/";
 const PREFIX_STATE_HASH: &[u8] = b"root/";
@@ -43,23 +51,19 @@ const PREFIX_OWNER_BALANCE: &[u8] = b"owner_balance/";
 const PREFIX_ASSET_HOLDER: &[u8] = b"asset_holder/";
 const PREFIX_LIQUIDITY_VAULT: &[u8] = b"liquidity_vault/";
 const PREFIX_KNOWN_OWNER_ADDRESS: &[u8] = b"known_owner_address/";
+const PREFIX_OWNER_EVENT: &[u8] = b"owner_event/";
+const PREFIX_ASSET_EVENT: &[u8] = b"asset_event/";
 
-const ATOMIC_ROOT_BUCKETS: usize = 4096;
 const RAW_STATE_COPY_CHUNK_KEYS: usize = 4096;
-const ROOT_LEAF_DOMAIN: &[u8] = b"CRYPTIX_ATOMIC_V2_LEAF";
-const ROOT_BUCKET_DOMAIN: &[u8] = b"CRYPTIX_ATOMIC_V2_BUCKETED_ROOT";
-const ASSET_ROOT_V5: &[u8] = b"CAT_ASSET_ROOT_V5";
 const ASSET_P2P_AUDIT_ROOT_V1: &[u8] = b"CAT_ASSET_P2P_AUDIT_ROOT_V1";
-const LOGICAL_ASSET: u8 = 0x01;
-const LOGICAL_BALANCE: u8 = 0x02;
-const LOGICAL_NONCE: u8 = 0x03;
-const LOGICAL_ANCHOR_COUNT: u8 = 0x04;
 
 const STATE_PREFIXES: &[&[u8]] = &[
     PREFIX_ASSET,
     PREFIX_BALANCE,
     PREFIX_NONCE,
     PREFIX_ANCHOR_COUNT,
+    PREFIX_ALLOWANCE,
+    PREFIX_LOCK,
     PREFIX_PROCESSED_OP,
     PREFIX_JOURNAL,
     PREFIX_STATE_HASH,
@@ -72,6 +76,8 @@ const STATE_PREFIXES: &[&[u8]] = &[
     PREFIX_ASSET_HOLDER,
     PREFIX_LIQUIDITY_VAULT,
     PREFIX_KNOWN_OWNER_ADDRESS,
+    PREFIX_OWNER_EVENT,
+    PREFIX_ASSET_EVENT,
 ];
 
 const CURRENT_STATE_COPY_PREFIXES: &[&[u8]] = &[
@@ -79,6 +85,8 @@ const CURRENT_STATE_COPY_PREFIXES: &[&[u8]] = &[
     PREFIX_BALANCE,
     PREFIX_NONCE,
     PREFIX_ANCHOR_COUNT,
+    PREFIX_ALLOWANCE,
+    PREFIX_LOCK,
     PREFIX_PROCESSED_OP,
     PREFIX_LEAF_HASH,
     PREFIX_ROOT_BUCKET,
@@ -103,6 +111,8 @@ pub struct AtomicStorageSnapshotCounts {
     pub balances: u64,
     pub nonces: u64,
     pub anchor_counts: u64,
+    pub allowances: u64,
+    pub locks: u64,
     pub processed_ops: u64,
     pub state_hashes: u64,
     pub event_sequences: u64,
@@ -193,6 +203,14 @@ impl AtomicStorageV2 {
         Ok(self.get_typed(&anchor_count_key(owner_id))?.unwrap_or(0))
     }
 
+    pub fn get_allowance(&self, key: &AllowanceKey) -> AtomicTokenResult<Option<TokenAllowance>> {
+        self.get_typed(&allowance_key(key))
+    }
+
+    pub fn get_lock(&self, key: &BalanceKey) -> AtomicTokenResult<Option<TokenLock>> {
+        self.get_typed(&lock_key(key))
+    }
+
     pub fn get_processed_op(&self, txid: &BlockHash) -> AtomicTokenResult<Option<ProcessedOp>> {
         self.get_typed(&processed_op_key(txid))
     }
@@ -233,6 +251,24 @@ impl AtomicStorageV2 {
         Ok(entries)
     }
 
+    /// Visits the sequences of retained events touching `owner_id` (as sender or recipient) in ascending
+    /// order, starting after `after_sequence`. The visitor returns `false` to stop.
+    pub fn visit_event_sequences_by_owner<F>(&self, owner_id: &[u8; 32], after_sequence: u64, visitor: F) -> AtomicTokenResult<()>
+    where
+        F: FnMut(u64) -> AtomicTokenResult<bool>,
+    {
+        self.visit_event_sequences(&prefixed_key(PREFIX_OWNER_EVENT, owner_id), after_sequence, "owner event sequence", visitor)
+    }
+
+    /// Visits the sequences of retained events of `asset_id` in ascending order, starting after `after_sequence`.
+    /// The visitor returns `false` to stop.
+    pub fn visit_event_sequences_by_asset<F>(&self, asset_id: &[u8; 32], after_sequence: u64, visitor: F) -> AtomicTokenResult<()>
+    where
+        F: FnMut(u64) -> AtomicTokenResult<bool>,
+    {
+        self.visit_event_sequences(&prefixed_key(PREFIX_ASSET_EVENT, asset_id), after_sequence, "asset event sequence", visitor)
+    }
+
     pub fn assets_page(&self, offset: usize, limit: usize, query: &str) -> AtomicTokenResult<(Vec<TokenAsset>, u64)> {
         self.assets_page_excluding(offset, limit, query, &HashSet::new())
     }
@@ -346,6 +382,22 @@ impl AtomicStorageV2 {
             }
             Ok(())
         })?;
+        self.read_prefix(PREFIX_ALLOWANCE, |suffix, value| {
+            let key = decode_allowance_key(suffix)?;
+            let allowance: TokenAllowance = decode_value(value, "allowance")?;
+            if allowance.amount > 0 {
+                state.allowances.insert(key, allowance);
+            }
+            Ok(())
+        })?;
+        self.read_prefix(PREFIX_LOCK, |suffix, value| {
+            let key = decode_balance_key(suffix)?;
+            let lock: TokenLock = decode_value(value, "lock")?;
+            if lock.amount > 0 {
+                state.locks.insert(key, lock);
+            }
+            Ok(())
+        })?;
         self.read_prefix(PREFIX_PROCESSED_OP, |suffix, value| {
             let txid = decode_block_hash(suffix, "processed op txid")?;
             let op: ProcessedOp = decode_value(value, "processed op")?;
@@ -365,6 +417,8 @@ impl AtomicStorageV2 {
             balances: self.prefix_count(PREFIX_BALANCE)?,
             nonces: self.prefix_count(PREFIX_NONCE)?,
             anchor_counts: self.prefix_count(PREFIX_ANCHOR_COUNT)?,
+            allowances: self.prefix_count(PREFIX_ALLOWANCE)?,
+            locks: self.prefix_count(PREFIX_LOCK)?,
             processed_ops: self.prefix_count(PREFIX_PROCESSED_OP)?,
             state_hashes: self.prefix_count(PREFIX_STATE_HASH)?,
             event_sequences: self.prefix_count(PREFIX_EVENT_SEQUENCE)?,
@@ -417,6 +471,28 @@ impl AtomicStorageV2 {
         })
     }
 
+    pub fn visit_all_allowances<F>(&self, mut visitor: F) -> AtomicTokenResult<()>
+    where
+        F: FnMut(AllowanceKey, TokenAllowance) -> AtomicTokenResult<()>,
+    {
+        self.read_prefix(PREFIX_ALLOWANCE, |suffix, value| {
+            let key = decode_allowance_key(suffix)?;
+            let allowance: TokenAllowance = decode_value(value, "allowance")?;
+            visitor(key, allowance)
+        })
+    }
+
+    pub fn visit_all_locks<F>(&self, mut visitor: F) -> AtomicTokenResult<()>
+    where
+        F: FnMut(BalanceKey, TokenLock) -> AtomicTokenResult<()>,
+    {
+        self.read_prefix(PREFIX_LOCK, |suffix, value| {
+            let key = decode_balance_key(suffix)?;
+            let lock: TokenLock = decode_value(value, "lock")?;
+            visitor(key, lock)
+        })
+    }
+
     pub fn visit_all_processed_ops<F>(&self, mut visitor: F) -> AtomicTokenResult<()>
     where
         F: FnMut(BlockHash, ProcessedOp) -> AtomicTokenResult<()>,
@@ -561,6 +637,18 @@ impl AtomicStorageV2 {
                 root_accumulator.set(logical_anchor_count_key(owner_id), Some(root_value_for_u64(*count)));
             }
         }
+        for (key, allowance) in state.allowances.iter() {
+            if allowance.amount > 0 {
+                batch.put(allowance_key(key), encode_value(allowance, "allowance")?);
+                root_accumulator.set(logical_allowance_key(key), Some(root_value_for_allowance(allowance)));
+            }
+        }
+        for (key, lock) in state.locks.iter() {
+            if lock.amount > 0 {
+                batch.put(lock_key(key), encode_value(lock, "lock")?);
+                root_accumulator.set(logical_lock_key(key), Some(root_value_for_lock(lock)));
+            }
+        }
         for (txid, op) in state.processed_ops.iter() {
             let value = encode_value(op, "processed op")?;
             batch.put(processed_op_key(txid), &value);
@@ -579,6 +667,7 @@ impl AtomicStorageV2 {
         }
         for event in state.events.iter() {
             batch.put(event_key(event), encode_value(event, "event")?);
+            write_event_secondary_indexes(&mut batch, event);
         }
 
         let root = root_accumulator.write_to_batch(&mut batch)?;
@@ -598,6 +687,8 @@ impl AtomicStorageV2 {
         balance_changes: impl IntoIterator<Item = (BalanceKey, Option<u128>)>,
         nonce_changes: impl IntoIterator<Item = (NonceKey, Option<u64>)>,
         anchor_count_changes: impl IntoIterator<Item = ([u8; 32], Option<u64>)>,
+        allowance_changes: impl IntoIterator<Item = (AllowanceKey, Option<TokenAllowance>)>,
+        lock_changes: impl IntoIterator<Item = (BalanceKey, Option<TokenLock>)>,
         processed_op_changes: impl IntoIterator<Item = (BlockHash, Option<ProcessedOp>)>,
     ) -> AtomicTokenResult<[u8; 32]> {
         let mut batch = WriteBatch::default();
@@ -609,6 +700,8 @@ impl AtomicStorageV2 {
             balance_changes,
             nonce_changes,
             anchor_count_changes,
+            allowance_changes,
+            lock_changes,
             processed_op_changes,
         )?;
 
@@ -684,6 +777,8 @@ impl AtomicStorageV2 {
         balance_changes: Vec<(BalanceKey, Option<u128>)>,
         nonce_changes: Vec<(NonceKey, Option<u64>)>,
         anchor_count_changes: Vec<([u8; 32], Option<u64>)>,
+        allowance_changes: Vec<(AllowanceKey, Option<TokenAllowance>)>,
+        lock_changes: Vec<(BalanceKey, Option<TokenLock>)>,
         processed_op_changes: Vec<(BlockHash, Option<ProcessedOp>)>,
         block_hash: BlockHash,
         journal: &BlockJournal,
@@ -703,6 +798,8 @@ impl AtomicStorageV2 {
             balance_changes,
             nonce_changes,
             anchor_count_changes,
+            allowance_changes,
+            lock_changes,
             processed_op_changes,
         )?;
         let root = self.apply_root_changes_to_batch(&mut batch, root_changes)?;
@@ -713,6 +810,7 @@ impl AtomicStorageV2 {
         batch.put(chain_order_key(chain_order_index), encode_value(&block_hash, "chain order block hash")?);
         for event in new_events {
             batch.put(event_key(event), encode_value(event, "event")?);
+            write_event_secondary_indexes(&mut batch, event);
         }
         self.write_current_meta_to_batch(
             &mut batch,
@@ -735,6 +833,8 @@ impl AtomicStorageV2 {
         balance_changes: Vec<(BalanceKey, Option<u128>)>,
         nonce_changes: Vec<(NonceKey, Option<u64>)>,
         anchor_count_changes: Vec<([u8; 32], Option<u64>)>,
+        allowance_changes: Vec<(AllowanceKey, Option<TokenAllowance>)>,
+        lock_changes: Vec<(BalanceKey, Option<TokenLock>)>,
         processed_op_changes: Vec<(BlockHash, Option<ProcessedOp>)>,
         removed_block_hash: BlockHash,
         current_block_hash: Option<BlockHash>,
@@ -753,6 +853,8 @@ impl AtomicStorageV2 {
             balance_changes,
             nonce_changes,
             anchor_count_changes,
+            allowance_changes,
+            lock_changes,
             processed_op_changes,
         )?;
         let root = self.apply_root_changes_to_batch(&mut batch, root_changes)?;
@@ -763,6 +865,7 @@ impl AtomicStorageV2 {
         batch.delete(chain_order_key(chain_order_index));
         for event in new_events {
             batch.put(event_key(event), encode_value(event, "event")?);
+            write_event_secondary_indexes(&mut batch, event);
         }
         self.write_current_meta_to_batch(&mut batch, current_block_hash, chain_len, root, degraded, next_event_sequence)?;
 
@@ -822,6 +925,18 @@ impl AtomicStorageV2 {
             }
             Ok(())
         })?;
+        self.visit_all_allowances(|key, allowance| {
+            if allowance.amount > 0 {
+                root_accumulator.set(logical_allowance_key(&key), Some(root_value_for_allowance(&allowance)));
+            }
+            Ok(())
+        })?;
+        self.visit_all_locks(|key, lock| {
+            if lock.amount > 0 {
+                root_accumulator.set(logical_lock_key(&key), Some(root_value_for_lock(&lock)));
+            }
+            Ok(())
+        })?;
 
         let mut batch = WriteBatch::default();
         for key in self.keys_with_prefix(PREFIX_LEAF_HASH)? {
@@ -911,6 +1026,14 @@ impl AtomicStorageV2 {
                     batch.delete(key);
                 }
             }
+            for prefix in [PREFIX_OWNER_EVENT, PREFIX_ASSET_EVENT] {
+                for key in self.keys_with_prefix(prefix)? {
+                    let sequence = decode_event_index_key_suffix(&key[prefix.len()..])?;
+                    if sequence <= last_pruned_event_sequence {
+                        batch.delete(key);
+                    }
+                }
+            }
         }
         for txid in pruned_processed_op_txids {
             batch.delete(processed_op_key(txid));
@@ -936,6 +1059,8 @@ impl AtomicStorageV2 {
         balance_changes: impl IntoIterator<Item = (BalanceKey, Option<u128>)>,
         nonce_changes: impl IntoIterator<Item = (NonceKey, Option<u64>)>,
         anchor_count_changes: impl IntoIterator<Item = ([u8; 32], Option<u64>)>,
+        allowance_changes: impl IntoIterator<Item = (AllowanceKey, Option<TokenAllowance>)>,
+        lock_changes: impl IntoIterator<Item = (BalanceKey, Option<TokenLock>)>,
         processed_op_changes: impl IntoIterator<Item = (BlockHash, Option<ProcessedOp>)>,
     ) -> AtomicTokenResult<()> {
         let asset_changes: Vec<_> = asset_changes.into_iter().collect();
@@ -1016,6 +1141,34 @@ impl AtomicStorageV2 {
                 }
             }
         }
+        for (key, value) in allowance_changes {
+            let logical_key = logical_allowance_key(&key);
+            match value.filter(|allowance| allowance.amount > 0) {
+                Some(allowance) => {
+                    let encoded = encode_value(&allowance, "allowance")?;
+                    batch.put(allowance_key(&key), &encoded);
+                    root_changes.push((logical_key, Some(root_value_for_allowance(&allowance))));
+                }
+                None => {
+                    batch.delete(allowance_key(&key));
+                    root_changes.push((logical_key, None));
+                }
+            }
+        }
+        for (key, value) in lock_changes {
+            let logical_key = logical_lock_key(&key);
+            match value.filter(|lock| lock.amount > 0) {
+                Some(lock) => {
+                    let encoded = encode_value(&lock, "lock")?;
+                    batch.put(lock_key(&key), &encoded);
+                    root_changes.push((logical_key, Some(root_value_for_lock(&lock))));
+                }
+                None => {
+                    batch.delete(lock_key(&key));
+                    root_changes.push((logical_key, None));
+                }
+            }
+        }
         for (txid, value) in processed_op_changes {
             match value {
                 Some(op) => {
@@ -1174,6 +1327,27 @@ impl AtomicStorageV2 {
         Ok(())
     }
 
+    fn visit_event_sequences<F>(&self, prefix: &[u8], after_sequence: u64, label: &str, mut visitor: F) -> AtomicTokenResult<()>
+    where
+        F: FnMut(u64) -> AtomicTokenResult<bool>,
+    {
+        let Some(first_sequence) = after_sequence.checked_add(1) else {
+            return Ok(());
+        };
+        let start = prefixed_key(prefix, &first_sequence.to_be_bytes());
+        let iter = self.db.iterator(IteratorMode::From(&start, Direction::Forward));
+        for item in iter {
+            let (key, _) = item.map_err(|err| AtomicTokenError::Processing(format!("failed iterating Atomic DB schema v2: {err}")))?;
+            if !key.starts_with(prefix) {
+                break;
+            }
+            if !visitor(decode_u64_suffix(&key[prefix.len()..], label)?)? {
+                break;
+            }
+        }
+        Ok(())
+    }
+
     fn prefix_count(&self, prefix: &[u8]) -> AtomicTokenResult<u64> {
         let mut count = 0u64;
         self.read_prefix(prefix, |_, _| {
@@ -1228,6 +1402,30 @@ impl AtomicStorageV2 {
         Ok(root_from_buckets(&buckets))
     }
 
+    /// Current root bucket hashes, as committed by the last persisted block.
+    pub fn root_buckets(&self) -> AtomicTokenResult<[[u8; 32]; ATOMIC_ROOT_BUCKETS]> {
+        let cache = self
+            .root_buckets_cache
+            .lock()
+            .map_err(|_| AtomicTokenError::Processing("Atomic DB root bucket cache lock is poisoned".to_string()))?;
+        match cache.as_ref() {
+            Some(buckets) => Ok(*buckets),
+            None => self.load_root_buckets(),
+        }
+    }
+
+    /// Logical keys of every persisted leaf mapping into `bucket_index`.
+    ///
+    /// Leaf hashes are keyed by their bucket first, so this only walks the leaves of that bucket.
+    pub fn root_bucket_logical_keys(&self, bucket_index: usize) -> AtomicTokenResult<Vec<Vec<u8>>> {
+        let mut keys = Vec::new();
+        self.read_prefix(&prefixed_key(PREFIX_LEAF_HASH, &(bucket_index as u16).to_be_bytes()), |logical_key, _| {
+            keys.push(logical_key.to_vec());
+            Ok(())
+        })?;
+        Ok(keys)
+    }
+
     fn clear_root_buckets_cache(&self) -> AtomicTokenResult<()> {
         let mut cache = self
             .root_buckets_cache
@@ -1339,6 +1537,21 @@ fn anchor_count_key(owner_id: &[u8; 32]) -> Vec<u8> {
     prefixed_key(PREFIX_ANCHOR_COUNT, owner_id)
 }
 
+fn allowance_key(key: &AllowanceKey) -> Vec<u8> {
+    let mut suffix = Vec::with_capacity(96);
+    suffix.extend_from_slice(&key.asset_id);
+    suffix.extend_from_slice(&key.owner_id);
+    suffix.extend_from_slice(&key.spender_id);
+    prefixed_key(PREFIX_ALLOWANCE, &suffix)
+}
+
+fn lock_key(key: &BalanceKey) -> Vec<u8> {
+    let mut suffix = Vec::with_capacity(64);
+    suffix.extend_from_slice(&key.asset_id);
+    suffix.extend_from_slice(&key.owner_id);
+    prefixed_key(PREFIX_LOCK, &suffix)
+}
+
 fn processed_op_key(txid: &BlockHash) -> Vec<u8> {
     prefixed_key(PREFIX_PROCESSED_OP, &txid.as_bytes())
 }
@@ -1366,6 +1579,20 @@ fn event_key(event: &TokenEvent) -> Vec<u8> {
     prefixed_key(PREFIX_EVENT, &suffix)
 }
 
+fn owner_event_key(owner_id: &[u8; 32], sequence: u64) -> Vec<u8> {
+    let mut suffix = Vec::with_capacity(40);
+    suffix.extend_from_slice(owner_id);
+    suffix.extend_from_slice(&sequence.to_be_bytes());
+    prefixed_key(PREFIX_OWNER_EVENT, &suffix)
+}
+
+fn asset_event_key(asset_id: &[u8; 32], sequence: u64) -> Vec<u8> {
+    let mut suffix = Vec::with_capacity(40);
+    suffix.extend_from_slice(asset_id);
+    suffix.extend_from_slice(&sequence.to_be_bytes());
+    prefixed_key(PREFIX_ASSET_EVENT, &suffix)
+}
+
 fn owner_balance_prefix(owner_id: &[u8; 32]) -> Vec<u8> {
     prefixed_key(PREFIX_OWNER_BALANCE, owner_id)
 }
@@ -1410,6 +1637,16 @@ fn delete_balance_secondary_indexes(batch: &mut WriteBatch, key: &BalanceKey) {
     batch.delete(asset_holder_key(key));
 }
 
+fn write_event_secondary_indexes(batch: &mut WriteBatch, event: &TokenEvent) {
+    let details = &event.details;
+    for owner_id in details.from_owner_id.iter().chain(details.to_owner_id.iter()) {
+        batch.put(owner_event_key(owner_id, event.sequence), b"");
+    }
+    if let Some(asset_id) = details.asset_id.as_ref() {
+        batch.put(asset_event_key(asset_id, event.sequence), b"");
+    }
+}
+
 fn write_asset_secondary_indexes(batch: &mut WriteBatch, asset: &TokenAsset) -> AtomicTokenResult<()> {
     let Some(pool) = asset.liquidity.as_ref() else {
         return Ok(());
@@ -1467,42 +1704,62 @@ fn record_known_owner_addresses_for_asset(
 }
 
 fn leaf_hash_key(logical_key: &[u8]) -> Vec<u8> {
-    prefixed_key(PREFIX_LEAF_HASH, logical_key)
+    let bucket_index = root_bucket_index(logical_key) as u16;
+    let mut key = Vec::with_capacity(PREFIX_LEAF_HASH.len() + 2 + logical_key.len());
+    key.extend_from_slice(PREFIX_LEAF_HASH);
+    key.extend_from_slice(&bucket_index.to_be_bytes());
+    key.extend_from_slice(logical_key);
+    key
 }
 
 fn root_bucket_key(index: u16) -> Vec<u8> {
     prefixed_key(PREFIX_ROOT_BUCKET, &index.to_be_bytes())
 }
 
-fn logical_asset_key(asset_id: &[u8; 32]) -> Vec<u8> {
-    let mut key = Vec::with_capacity(33);
-    key.push(LOGICAL_ASSET);
-    key.extend_from_slice(asset_id);
-    key
-}
-
 fn logical_balance_key(key: &BalanceKey) -> Vec<u8> {
-    let mut logical = Vec::with_capacity(65);
-    logical.push(LOGICAL_BALANCE);
-    logical.extend_from_slice(&key.asset_id);
-    logical.extend_from_slice(&key.owner_id);
-    logical
+    cryptix_atomic_proof::logical_balance_key(&key.asset_id, &key.owner_id)
 }
 
 fn logical_nonce_key(key: &NonceKey) -> Vec<u8> {
-    let mut logical = Vec::with_capacity(66);
-    logical.push(LOGICAL_NONCE);
-    logical.extend_from_slice(&key.owner_id);
-    logical.push(key.scope_kind);
-    logical.extend_from_slice(&key.scope_id);
-    logical
-}
-
-fn logical_anchor_count_key(owner_id: &[u8; 32]) -> Vec<u8> {
-    let mut key = Vec::with_capacity(33);
-    key.push(LOGICAL_ANCHOR_COUNT);
-    key.extend_from_slice(owner_id);
-    key
+    cryptix_atomic_proof::logical_nonce_key(&key.owner_id, key.scope_kind, &key.scope_id)
+}
+
+fn logical_allowance_key(key: &AllowanceKey) -> Vec<u8> {
+    cryptix_atomic_proof::logical_allowance_key(&key.asset_id, &key.owner_id, &key.spender_id)
+}
+
+fn logical_lock_key(key: &BalanceKey) -> Vec<u8> {
+    cryptix_atomic_proof::logical_lock_key(&key.asset_id, &key.owner_id)
+}
+
+/// Root leaf value of an asset, `None` when the asset does not exist.
+pub(crate) fn root_leaf_value_for_asset(asset: Option<&TokenAsset>) -> Option<Vec<u8>> {
+    asset.map(root_value_for_asset)
+}
+
+/// Root leaf value of a balance, `None` for a zero balance.
+pub(crate) fn root_leaf_value_for_balance(amount: u128) -> Option<Vec<u8>> {
+    (amount > 0).then(|| root_value_for_u128(amount))
+}
+
+/// Root leaf value of a nonce scope, `None` while the scope is unused.
+pub(crate) fn root_leaf_value_for_nonce(nonce: u64) -> Option<Vec<u8>> {
+    (nonce != 1).then(|| root_value_for_u64(nonce))
+}
+
+/// Root leaf value of an anchor count, `None` for a zero count.
+pub(crate) fn root_leaf_value_for_anchor_count(count: u64) -> Option<Vec<u8>> {
+    (count > 0).then(|| root_value_for_u64(count))
+}
+
+/// Root leaf value of an allowance, `None` when absent or fully spent.
+pub(crate) fn root_leaf_value_for_allowance(allowance: Option<&TokenAllowance>) -> Option<Vec<u8>> {
+    allowance.filter(|allowance| allowance.amount > 0).map(root_value_for_allowance)
+}
+
+/// Root leaf value of a lock, `None` when absent.
+pub(crate) fn root_leaf_value_for_lock(lock: Option<&TokenLock>) -> Option<Vec<u8>> {
+    lock.filter(|lock| lock.amount > 0).map(root_value_for_lock)
 }
 
 pub(crate) fn compute_state_root_from_parts(
@@ -1510,8 +1767,19 @@ pub(crate) fn compute_state_root_from_parts(
     balances: &HashMap<BalanceKey, u128>,
     nonces: &HashMap<NonceKey, u64>,
     anchor_counts: &HashMap<[u8; 32], u64>,
+    allowances: &HashMap<AllowanceKey, TokenAllowance>,
+    locks: &HashMap<BalanceKey, TokenLock>,
 ) -> [u8; 32] {
-    compute_state_root_from_parts_with_asset_value(assets, balances, nonces, anchor_counts, root_value_for_asset, true)
+    compute_state_root_from_parts_with_asset_value(
+        assets,
+        balances,
+        nonces,
+        anchor_counts,
+        allowances,
+        locks,
+        root_value_for_asset,
+        true,
+    )
 }
 
 pub(crate) fn compute_p2p_audit_state_root_from_parts(
@@ -1519,8 +1787,19 @@ pub(crate) fn compute_p2p_audit_state_root_from_parts(
     balances: &HashMap<BalanceKey, u128>,
     nonces: &HashMap<NonceKey, u64>,
     anchor_counts: &HashMap<[u8; 32], u64>,
+    allowances: &HashMap<AllowanceKey, TokenAllowance>,
+    locks: &HashMap<BalanceKey, TokenLock>,
 ) -> [u8; 32] {
-    compute_state_root_from_parts_with_asset_value(assets, balances, nonces, anchor_counts, p2p_audit_root_value_for_asset, false)
+    compute_state_root_from_parts_with_asset_value(
+        assets,
+        balances,
+        nonces,
+        anchor_counts,
+        allowances,
+        locks,
+        p2p_audit_root_value_for_asset,
+        false,
+    )
 }
 
 fn compute_state_root_from_parts_with_asset_value<F>(
@@ -1528,6 +1807,8 @@ fn compute_state_root_from_parts_with_asset_value<F>(
     balances: &HashMap<BalanceKey, u128>,
     nonces: &HashMap<NonceKey, u64>,
     anchor_counts: &HashMap<[u8; 32], u64>,
+    allowances: &HashMap<AllowanceKey, TokenAllowance>,
+    locks: &HashMap<BalanceKey, TokenLock>,
     asset_value: F,
     include_anchor_counts: bool,
 ) -> [u8; 32]
@@ -1570,6 +1851,22 @@ where
         }
     }
 
+    let mut allowance_keys = allowances.keys().copied().collect::<Vec<_>>();
+    allowance_keys.sort_unstable();
+    for key in allowance_keys {
+        if let Some(allowance) = allowances.get(&key).filter(|allowance| allowance.amount > 0) {
+            apply_root_leaf(&mut buckets, &logical_allowance_key(&key), &root_value_for_allowance(allowance));
+        }
+    }
+
+    let mut lock_keys = locks.keys().copied().collect::<Vec<_>>();
+    lock_keys.sort_unstable();
+    for key in lock_keys {
+        if let Some(lock) = locks.get(&key).filter(|lock| lock.amount > 0) {
+            apply_root_leaf(&mut buckets, &logical_lock_key(&key), &root_value_for_lock(lock));
+        }
+    }
+
     root_from_buckets(&buckets)
 }
 
@@ -1578,18 +1875,22 @@ pub(crate) fn debug_state_root_report_from_parts(
     balances: &HashMap<BalanceKey, u128>,
     nonces: &HashMap<NonceKey, u64>,
     anchor_counts: &HashMap<[u8; 32], u64>,
+    allowances: &HashMap<AllowanceKey, TokenAllowance>,
+    locks: &HashMap<BalanceKey, TokenLock>,
     max_entries: usize,
 ) -> String {
     let max_entries = max_entries.max(1);
     let mut lines = Vec::new();
-    let root = compute_state_root_from_parts(assets, balances, nonces, anchor_counts);
+    let root = compute_state_root_from_parts(assets, balances, nonces, anchor_counts, allowances, locks);
     lines.push(format!(
-        "token_root={} assets={} balances={} nonces={} anchor_counts={}",
+        "token_root={} assets={} balances={} nonces={} anchor_counts={} allowances={} locks={}",
         hex_lower(&root),
         assets.len(),
         balances.values().filter(|amount| **amount > 0).count(),
         nonces.values().filter(|nonce| **nonce != 1).count(),
-        anchor_counts.values().filter(|count| **count > 0).count()
+        anchor_counts.values().filter(|count| **count > 0).count(),
+        allowances.values().filter(|allowance| allowance.amount > 0).count(),
+        locks.values().filter(|lock| lock.amount > 0).count()
     ));
 
     let mut asset_ids = assets.keys().copied().collect::<Vec<_>>();
@@ -1672,6 +1973,54 @@ pub(crate) fn debug_state_root_report_from_parts(
         ));
     }
 
+    let mut allowance_keys = allowances.iter().filter(|(_, allowance)| allowance.amount > 0).map(|(key, _)| *key).collect::<Vec<_>>();
+    allowance_keys.sort_unstable();
+    for (index, key) in allowance_keys.iter().copied().enumerate() {
+        if index >= max_entries {
+            lines.push(format!("allowance_more={}", allowance_keys.len() - index));
+            break;
+        }
+        let Some(allowance) = allowances.get(&key) else {
+            continue;
+        };
+        let logical_key = logical_allowance_key(&key);
+        let leaf = root_leaf_hash(&logical_key, &root_value_for_allowance(allowance));
+        lines.push(format!(
+            "allowance[{index}] bucket={} leaf={} asset={} owner={} spender={} amount={} expires_at_daa_score={}",
+            root_bucket_index(&logical_key),
+            hex_lower(&leaf),
+            hex_lower(&key.asset_id),
+            hex_lower(&key.owner_id),
+            hex_lower(&key.spender_id),
+            allowance.amount,
+            allowance.expires_at_daa_score
+        ));
+    }
+
+    let mut lock_keys = locks.iter().filter(|(_, lock)| lock.amount > 0).map(|(key, _)| *key).collect::<Vec<_>>();
+    lock_keys.sort_unstable();
+    for (index, key) in lock_keys.iter().copied().enumerate() {
+        if index >= max_entries {
+            lines.push(format!("lock_more={}", lock_keys.len() - index));
+            break;
+        }
+        let Some(lock) = locks.get(&key) else {
+            continue;
+        };
+        let logical_key = logical_lock_key(&key);
+        let leaf = root_leaf_hash(&logical_key, &root_value_for_lock(lock));
+        lines.push(format!(
+            "lock[{index}] bucket={} leaf={} asset={} owner={} amount={} release_start_daa_score={} release_end_daa_score={}",
+            root_bucket_index(&logical_key),
+            hex_lower(&leaf),
+            hex_lower(&key.asset_id),
+            hex_lower(&key.owner_id),
+            lock.amount,
+            lock.release_start_daa_score,
+            lock.release_end_daa_score
+        ));
+    }
+
     lines.join("\n")
 }
 
@@ -1695,6 +2044,16 @@ fn debug_asset_summary(asset: &TokenAsset) -> String {
         asset.created_daa_score.map(|value| value.to_string()).unwrap_or_else(|| "<none>".to_string()),
         asset.created_at.map(|value| value.to_string()).unwrap_or_else(|| "<none>".to_string())
     );
+    if let Some(pair) = asset.pair_pool.as_ref() {
+        out.push_str(&format!(
+            " pair={{asset_a={} asset_b={} fee_bps={} accrued_fee_a={} accrued_fee_b={}}}",
+            hex_lower(&pair.asset_a_id),
+            hex_lower(&pair.asset_b_id),
+            pair.fee_bps,
+            pair.accrued_fee_a,
+            pair.accrued_fee_b
+        ));
+    }
     let Some(pool) = asset.liquidity.as_ref() else {
         out.push_str(" liquidity=<none>");
         return out;
@@ -1745,10 +2104,26 @@ fn root_value_for_u128(value: u128) -> Vec<u8> {
     value.to_le_bytes().to_vec()
 }
 
+fn root_value_for_allowance(allowance: &TokenAllowance) -> Vec<u8> {
+    let mut value = Vec::with_capacity(24);
+    value.extend_from_slice(&allowance.amount.to_le_bytes());
+    value.extend_from_slice(&allowance.expires_at_daa_score.to_le_bytes());
+    value
+}
+
+fn root_value_for_lock(lock: &TokenLock) -> Vec<u8> {
+    let mut value = Vec::with_capacity(32);
+    value.extend_from_slice(&lock.amount.to_le_bytes());
+    value.extend_from_slice(&lock.release_start_daa_score.to_le_bytes());
+    value.extend_from_slice(&lock.release_end_daa_score.to_le_bytes());
+    value
+}
+
 fn token_asset_class_tag(asset_class: &crate::state::TokenAssetClass) -> u8 {
     match asset_class {
         crate::state::TokenAssetClass::Standard => 0,
         crate::state::TokenAssetClass::Liquidity => 1,
+        crate::state::TokenAssetClass::Pair => 2,
     }
 }
 
@@ -1803,6 +2178,7 @@ fn root_value_for_asset(asset: &TokenAsset) -> Vec<u8> {
         }
         None => out.push(0),
     }
+    append_root_pair_pool(&mut out, asset);
 
     out
 }
@@ -1825,9 +2201,28 @@ fn p2p_audit_root_value_for_asset(asset: &TokenAsset) -> Vec<u8> {
         }
         None => out.push(0),
     }
+    append_root_pair_pool(&mut out, asset);
     out
 }
 
+/// Pair state is only committed for pair pools so roots of the other asset classes keep their existing layout.
+fn append_root_pair_pool(out: &mut Vec<u8>, asset: &TokenAsset) {
+    if asset.asset_class != crate::state::TokenAssetClass::Pair {
+        return;
+    }
+    match asset.pair_pool.as_ref() {
+        Some(pair) => {
+            out.push(1);
+            out.extend_from_slice(&pair.asset_a_id);
+            out.extend_from_slice(&pair.asset_b_id);
+            out.extend_from_slice(&pair.fee_bps.to_le_bytes());
+            out.extend_from_slice(&pair.accrued_fee_a.to_le_bytes());
+            out.extend_from_slice(&pair.accrued_fee_b.to_le_bytes());
+        }
+        None => out.push(0),
+    }
+}
+
 fn append_root_liquidity(out: &mut Vec<u8>, pool: &crate::state::LiquidityPoolState) {
     out.extend_from_slice(&pool.pool_nonce.to_le_bytes());
     out.push(pool.curve_version);
@@ -1854,47 +2249,6 @@ fn append_root_liquidity(out: &mut Vec<u8>, pool: &crate::state::LiquidityPoolSt
     out.push(u8::from(pool.unlocked));
 }
 
-fn root_leaf_hash(logical_key: &[u8], value: &[u8]) -> [u8; 32] {
-    let mut hasher = Blake2bParams::new().hash_length(32).to_state();
-    hasher.update(ROOT_LEAF_DOMAIN);
-    hasher.update(&(logical_key.len() as u64).to_le_bytes());
-    hasher.update(logical_key);
-    hasher.update(&(value.len() as u64).to_le_bytes());
-    hasher.update(value);
-    let digest = hasher.finalize();
-    let mut out = [0u8; 32];
-    out.copy_from_slice(digest.as_bytes());
-    out
-}
-
-fn root_bucket_index(logical_key: &[u8]) -> usize {
-    let mut hasher = Blake2bParams::new().hash_length(32).to_state();
-    hasher.update(b"CRYPTIX_ATOMIC_V2_BUCKET_INDEX");
-    hasher.update(logical_key);
-    let digest = hasher.finalize();
-    let bytes = digest.as_bytes();
-    (((bytes[0] as usize) << 4) | ((bytes[1] as usize) >> 4)) & (ATOMIC_ROOT_BUCKETS - 1)
-}
-
-fn root_from_buckets(buckets: &[[u8; 32]; ATOMIC_ROOT_BUCKETS]) -> [u8; 32] {
-    let mut hasher = Blake2bParams::new().hash_length(32).to_state();
-    hasher.update(ROOT_BUCKET_DOMAIN);
-    hasher.update(&(ATOMIC_ROOT_BUCKETS as u64).to_le_bytes());
-    for bucket in buckets {
-        hasher.update(bucket);
-    }
-    let digest = hasher.finalize();
-    let mut out = [0u8; 32];
-    out.copy_from_slice(digest.as_bytes());
-    out
-}
-
-fn xor_hash(target: &mut [u8; 32], value: [u8; 32]) {
-    for (target, value) in target.iter_mut().zip(value) {
-        *target ^= value;
-    }
-}
-
 fn remove_archived_wal_dir(path: &Path) -> std::io::Result<()> {
     let archive_dir = path.join("archive");
     if archive_dir.exists() {
@@ -1947,6 +2301,22 @@ fn decode_nonce_key(suffix: &[u8]) -> AtomicTokenResult<NonceKey> {
     Ok(NonceKey { owner_id, scope_kind: suffix[32], scope_id })
 }
 
+fn decode_allowance_key(suffix: &[u8]) -> AtomicTokenResult<AllowanceKey> {
+    if suffix.len() != 96 {
+        return Err(AtomicTokenError::Processing(format!(
+            "Atomic DB allowance key decode failed: expected 96 bytes, got {}",
+            suffix.len()
+        )));
+    }
+    let mut asset_id = [0u8; 32];
+    let mut owner_id = [0u8; 32];
+    let mut spender_id = [0u8; 32];
+    asset_id.copy_from_slice(&suffix[..32]);
+    owner_id.copy_from_slice(&suffix[32..64]);
+    spender_id.copy_from_slice(&suffix[64..96]);
+    Ok(AllowanceKey { asset_id, owner_id, spender_id })
+}
+
 fn decode_u64_suffix(suffix: &[u8], label: &str) -> AtomicTokenResult<u64> {
     if suffix.len() != 8 {
         return Err(AtomicTokenError::Processing(format!(
@@ -1981,14 +2351,24 @@ fn decode_event_key_suffix(suffix: &[u8]) -> AtomicTokenResult<u64> {
     decode_u64_suffix(&suffix[..8], "event sequence")
 }
 
+fn decode_event_index_key_suffix(suffix: &[u8]) -> AtomicTokenResult<u64> {
+    if suffix.len() != 40 {
+        return Err(AtomicTokenError::Processing(format!(
+            "Atomic DB event index key decode failed: expected 40 bytes, got {}",
+            suffix.len()
+        )));
+    }
+    decode_u64_suffix(&suffix[32..], "event index sequence")
+}
+
 #[cfg(test)]
 mod tests {
     use super::{compute_p2p_audit_state_root_from_parts, compute_state_root_from_parts, AtomicStorageV2};
     use crate::{
-        payload::{ApplyStatus, NoopReason, SupplyMode},
+        payload::{ApplyStatus, EventType, NoopReason, SupplyMode, TokenOpCode},
         state::{
             AtomicTokenState, BalanceKey, BlockJournal, LiquidityFeeRecipientState, LiquidityHolderAddressState, LiquidityPoolState,
-            NonceKey, ProcessedOp, TokenAsset, TokenAssetClass,
+            NonceKey, ProcessedOp, TokenAsset, TokenAssetClass, TokenEvent, TokenEventDetails, TokenEventFilter,
         },
     };
     use cryptix_consensus_core::{tx::TransactionOutpoint, Hash as BlockHash};
@@ -1996,6 +2376,7 @@ mod tests {
         collections::HashMap,
         fs,
         path::PathBuf,
+        sync::Arc,
         time::{SystemTime, UNIX_EPOCH},
     };
 
@@ -2053,6 +2434,8 @@ mod tests {
                 unlocked: true,
                 holder_addresses: holders.into_iter().collect::<HashMap<_, _>>(),
             }),
+            pair_pool: None,
+            metadata_history: Vec::new(),
         }
     }
 
@@ -2092,6 +2475,8 @@ mod tests {
                     created_daa_score: Some(12_345),
                     created_at: Some(1_779_700_001),
                     liquidity: None,
+                    pair_pool: None,
+                    metadata_history: Vec::new(),
                 },
             ),
             (
@@ -2145,6 +2530,8 @@ mod tests {
                         unlocked: false,
                         holder_addresses: HashMap::new(),
                     }),
+                    pair_pool: None,
+                    metadata_history: Vec::new(),
                 },
             ),
         ]
@@ -2164,11 +2551,12 @@ mod tests {
                 .collect::<HashMap<_, _>>();
         let anchor_counts = [(owner_a, 3u64), (owner_b, 5u64)].into_iter().collect::<HashMap<_, _>>();
 
-        let root = compute_state_root_from_parts(&assets, &balances, &nonces, &anchor_counts);
+        let root = compute_state_root_from_parts(&assets, &balances, &nonces, &anchor_counts, &HashMap::new(), &HashMap::new());
 
         assert_eq!(super::hex_lower(&root), "47769a46099c386e52f8f0d62a789e1b1b8453b530c6f1385fd92ca53797bd4d");
 
-        let audit_root = compute_p2p_audit_state_root_from_parts(&assets, &balances, &nonces, &anchor_counts);
+        let audit_root =
+            compute_p2p_audit_state_root_from_parts(&assets, &balances, &nonces, &anchor_counts, &HashMap::new(), &HashMap::new());
         assert_eq!(super::hex_lower(&audit_root), "d61e226e9ea824488ff7462e334115a9e5293b4576d58813056dfcc1159f9f92");
     }
 
@@ -2194,6 +2582,8 @@ mod tests {
             created_daa_score: None,
             created_at: None,
             liquidity: None,
+            pair_pool: None,
+            metadata_history: Vec::new(),
         };
 
         let root = compute_state_root_from_parts(
@@ -2201,6 +2591,8 @@ mod tests {
             &[(BalanceKey { asset_id, owner_id }, 900u128)].into_iter().collect(),
             &[(NonceKey::owner(owner_id), 7u64)].into_iter().collect(),
             &HashMap::new(),
+            &HashMap::new(),
+            &HashMap::new(),
         );
 
         assert_eq!(super::hex_lower(&root), "3ad3d91ea19241c69d6a5ab618798ba3086f20b66b38cc329fd913ce42efd8e9");
@@ -2228,14 +2620,18 @@ mod tests {
             created_daa_score: Some(123),
             created_at: Some(456),
             liquidity: None,
+            pair_pool: None,
+            metadata_history: Vec::new(),
         };
         let assets = [(asset_id, asset.clone())].into_iter().collect::<HashMap<_, _>>();
         let balances = [(BalanceKey { asset_id, owner_id }, 500u128)].into_iter().collect::<HashMap<_, _>>();
         let nonces = [(NonceKey::asset(owner_id, asset_id), 2u64)].into_iter().collect::<HashMap<_, _>>();
         let anchor_counts = [(owner_id, 1u64)].into_iter().collect::<HashMap<_, _>>();
 
-        let base_full_root = compute_state_root_from_parts(&assets, &balances, &nonces, &anchor_counts);
-        let base_audit_root = compute_p2p_audit_state_root_from_parts(&assets, &balances, &nonces, &anchor_counts);
+        let base_full_root =
+            compute_state_root_from_parts(&assets, &balances, &nonces, &anchor_counts, &HashMap::new(), &HashMap::new());
+        let base_audit_root =
+            compute_p2p_audit_state_root_from_parts(&assets, &balances, &nonces, &anchor_counts, &HashMap::new(), &HashMap::new());
 
         asset.creator_owner_id = seq_bytes32(0xA1);
         asset.decimals = 8;
@@ -2247,20 +2643,43 @@ mod tests {
         asset.created_at = Some(789);
         let changed_assets = [(asset_id, asset.clone())].into_iter().collect::<HashMap<_, _>>();
 
-        let changed_full_root = compute_state_root_from_parts(&changed_assets, &balances, &nonces, &anchor_counts);
-        let changed_audit_root = compute_p2p_audit_state_root_from_parts(&changed_assets, &balances, &nonces, &anchor_counts);
+        let changed_full_root =
+            compute_state_root_from_parts(&changed_assets, &balances, &nonces, &anchor_counts, &HashMap::new(), &HashMap::new());
+        let changed_audit_root = compute_p2p_audit_state_root_from_parts(
+            &changed_assets,
+            &balances,
+            &nonces,
+            &anchor_counts,
+            &HashMap::new(),
+            &HashMap::new(),
+        );
         assert_ne!(base_full_root, changed_full_root, "full token root should still detect permanent metadata");
         assert_eq!(base_audit_root, changed_audit_root, "P2P token audit root must ignore uncommitted permanent metadata");
 
         let changed_anchor_counts = [(owner_id, 999u64), ([0x66; 32], 123u64)].into_iter().collect::<HashMap<_, _>>();
-        let changed_anchor_full_root = compute_state_root_from_parts(&assets, &balances, &nonces, &changed_anchor_counts);
+        let changed_anchor_full_root =
+            compute_state_root_from_parts(&assets, &balances, &nonces, &changed_anchor_counts, &HashMap::new(), &HashMap::new());
         assert_ne!(base_full_root, changed_anchor_full_root, "full token root must detect anchor-count differences");
-        let changed_anchor_audit_root = compute_p2p_audit_state_root_from_parts(&assets, &balances, &nonces, &changed_anchor_counts);
+        let changed_anchor_audit_root = compute_p2p_audit_state_root_from_parts(
+            &assets,
+            &balances,
+            &nonces,
+            &changed_anchor_counts,
+            &HashMap::new(),
+            &HashMap::new(),
+        );
         assert_eq!(base_audit_root, changed_anchor_audit_root, "P2P token audit root must ignore token-index anchor counts");
 
         asset.total_supply = 501;
         let committed_assets = [(asset_id, asset)].into_iter().collect::<HashMap<_, _>>();
-        let committed_audit_root = compute_p2p_audit_state_root_from_parts(&committed_assets, &balances, &nonces, &anchor_counts);
+        let committed_audit_root = compute_p2p_audit_state_root_from_parts(
+            &committed_assets,
+            &balances,
+            &nonces,
+            &anchor_counts,
+            &HashMap::new(),
+            &HashMap::new(),
+        );
         assert_ne!(base_audit_root, committed_audit_root, "P2P token audit root must detect committed token-state fields");
     }
 
@@ -2361,6 +2780,44 @@ mod tests {
         let _ = fs::remove_dir_all(dir);
     }
 
+    #[test]
+    fn root_bucket_logical_keys_follow_leaf_changes() {
+        let dir = unique_temp_dir("bucket-leaf-keys");
+        let genesis_hash = BlockHash::from_u64_word(8);
+        let store = AtomicStorageV2::open(&dir, 6, "cryptix-simnet".to_string(), genesis_hash).expect("open store");
+        let keys = (1u8..=32).map(|byte| BalanceKey { asset_id: [0x5A; 32], owner_id: [byte; 32] }).collect::<Vec<_>>();
+
+        store
+            .apply_current_state_delta(
+                std::iter::empty(),
+                keys.iter().map(|key| (*key, Some(10u128))),
+                std::iter::empty(),
+                std::iter::empty(),
+                std::iter::empty(),
+            )
+            .expect("write balances");
+        for key in keys.iter() {
+            let logical_key = logical_balance_key(key);
+            let bucket_keys = store.root_bucket_logical_keys(root_bucket_index(&logical_key)).expect("bucket keys");
+            assert!(bucket_keys.contains(&logical_key));
+            assert!(bucket_keys.iter().all(|bucket_key| root_bucket_index(bucket_key) == root_bucket_index(&logical_key)));
+        }
+
+        store
+            .apply_current_state_delta(
+                std::iter::empty(),
+                [(keys[0], None)],
+                std::iter::empty(),
+                std::iter::empty(),
+                std::iter::empty(),
+            )
+            .expect("delete balance");
+        let logical_key = logical_balance_key(&keys[0]);
+        assert!(!store.root_bucket_logical_keys(root_bucket_index(&logical_key)).expect("bucket keys").contains(&logical_key));
+
+        let _ = fs::remove_dir_all(dir);
+    }
+
     #[test]
     fn zero_balance_delete_is_scoped_to_one_asset_owner_pair() {
         let dir = unique_temp_dir("balance-index-scope");
@@ -2534,6 +2991,87 @@ mod tests {
         let _ = fs::remove_dir_all(dir);
     }
 
+    #[test]
+    fn event_indexes_page_by_owner_and_asset_and_follow_pruning() {
+        let dir = unique_temp_dir("event-indexes");
+        let genesis_hash = BlockHash::from_u64_word(13);
+        let store = Arc::new(AtomicStorageV2::open(&dir, 6, "cryptix-simnet".to_string(), genesis_hash).expect("open store"));
+        let first = BlockHash::from_u64_word(1);
+        let second = BlockHash::from_u64_word(2);
+        let (owner_a, owner_b, owner_c) = ([0xA1; 32], [0xB2; 32], [0xC3; 32]);
+        let (asset_x, asset_y) = ([0x11; 32], [0x22; 32]);
+        let event = |sequence: u64, op_type, asset_id, from_owner_id, to_owner_id| TokenEvent {
+            event_id: [sequence as u8; 32],
+            sequence,
+            accepting_block_hash: if sequence == 1 { first } else { second },
+            txid: BlockHash::from_u64_word(100 + sequence),
+            event_type: EventType::Applied,
+            apply_status: ApplyStatus::Applied,
+            noop_reason: NoopReason::None,
+            ordinal: 0,
+            reorg_of_event_id: None,
+            details: TokenEventDetails {
+                op_type: Some(op_type),
+                asset_id: Some(asset_id),
+                from_owner_id,
+                to_owner_id,
+                amount: Some(1),
+                trade: None,
+            },
+        };
+
+        let mut state = AtomicTokenState::new(6, "cryptix-simnet".to_string());
+        state.applied_chain_order.extend([first, second]);
+        state.events.push(event(1, TokenOpCode::Transfer, asset_x, Some(owner_a), Some(owner_b)));
+        state.events.push(event(2, TokenOpCode::Transfer, asset_y, Some(owner_b), Some(owner_c)));
+        state.events.push(event(3, TokenOpCode::Mint, asset_y, None, Some(owner_a)));
+        state.next_event_sequence = 3;
+        store.persist_state(&state).expect("persist state");
+
+        let by_owner = |owner_id: [u8; 32], after_sequence: u64| {
+            let mut out = Vec::new();
+            store
+                .visit_event_sequences_by_owner(&owner_id, after_sequence, |sequence| {
+                    out.push(sequence);
+                    Ok(true)
+                })
+                .expect("visit owner events");
+            out
+        };
+        assert_eq!(by_owner(owner_a, 0), vec![1, 3]);
+        assert_eq!(by_owner(owner_a, 1), vec![3]);
+        assert_eq!(by_owner(owner_b, 0), vec![1, 2]);
+        let mut by_asset = Vec::new();
+        store
+            .visit_event_sequences_by_asset(&asset_y, 0, |sequence| {
+                by_asset.push(sequence);
+                Ok(true)
+            })
+            .expect("visit asset events");
+        assert_eq!(by_asset, vec![2, 3]);
+
+        state.attach_state_store(store.clone());
+        let owner_filter = TokenEventFilter { owner_id: Some(owner_a), ..Default::default() };
+        let (page, has_more) = state.get_filtered_events(&owner_filter, 0, 1, u64::MAX);
+        assert_eq!(page.iter().map(|event| event.sequence).collect::<Vec<_>>(), vec![1]);
+        assert!(has_more);
+        let (page, has_more) = state.get_filtered_events(&owner_filter, 1, 1, u64::MAX);
+        assert_eq!(page.iter().map(|event| event.sequence).collect::<Vec<_>>(), vec![3]);
+        assert!(!has_more);
+        let mint_filter = TokenEventFilter { asset_id: Some(asset_y), op_types: vec![TokenOpCode::Mint as u32], ..Default::default() };
+        let (page, _) = state.get_filtered_events(&mint_filter, 0, 10, u64::MAX);
+        assert_eq!(page.iter().map(|event| event.sequence).collect::<Vec<_>>(), vec![3]);
+        let (page, _) = state.get_filtered_events(&owner_filter, 0, 10, 2);
+        assert_eq!(page.iter().map(|event| event.sequence).collect::<Vec<_>>(), vec![1]);
+
+        store.prune_history(&[first], &[], &[second], Some(1)).expect("prune history");
+        assert_eq!(by_owner(owner_a, 0), vec![3]);
+        assert_eq!(by_owner(owner_b, 0), vec![2]);
+
+        drop(state);
+        let _ = fs::remove_dir_all(dir);
+    }
+
     #[test]
     fn persist_state_does_not_materialize_default_nonce() {
         let dir = unique_temp_dir("default-nonce");
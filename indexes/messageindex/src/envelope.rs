@@ -0,0 +1,148 @@
+//! Strict parsing of the CXM v1 messenger envelope described in `docs/payload-hardfork-v1.md`.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use sha2::{Digest, Sha256};
+
+pub const CXM_MAGIC: [u8; 3] = *b"CXM";
+pub const CXM_V1_VERSION: u8 = 1;
+pub const CXM_V1_HEADER_LEN: usize = 80;
+
+pub const RECIPIENT_TAG_LEN: usize = 16;
+pub const NONCE_LEN: usize = 24;
+pub const SENDER_DATA_LEN: usize = 32;
+
+pub const SENDER_KIND_PUBKEY: u8 = 1;
+pub const SENDER_KIND_REF: u8 = 2;
+const SENDER_REF_LEN: usize = 16;
+
+pub type RecipientTag = [u8; RECIPIENT_TAG_LEN];
+
+/// Sender identity of an envelope in its canonical 32-byte form.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, BorshSerialize, BorshDeserialize)]
+pub struct MessageSender {
+    pub kind: u8,
+    pub data: [u8; SENDER_DATA_LEN],
+}
+
+impl MessageSender {
+    /// Builds a sender from its significant bytes: 32 for a pubkey, 16 for a ref.
+    pub fn new(kind: u8, data: &[u8]) -> Option<Self> {
+        let expected_len = Self::significant_len(kind)?;
+        if data.len() != expected_len {
+            return None;
+        }
+        let mut canonical = [0u8; SENDER_DATA_LEN];
+        canonical[..expected_len].copy_from_slice(data);
+        Some(Self { kind, data: canonical })
+    }
+
+    /// Returns the significant bytes of the sender data, without the zero padding of refs.
+    pub fn significant_data(&self) -> &[u8] {
+        &self.data[..Self::significant_len(self.kind).unwrap_or(SENDER_DATA_LEN)]
+    }
+
+    fn significant_len(kind: u8) -> Option<usize> {
+        match kind {
+            SENDER_KIND_PUBKEY => Some(SENDER_DATA_LEN),
+            SENDER_KIND_REF => Some(SENDER_REF_LEN),
+            _ => None,
+        }
+    }
+}
+
+/// A valid CXM v1 envelope.
+#[derive(Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct CxmEnvelope {
+    pub msg_type: u8,
+    pub flags: u8,
+    pub recipient_tag: RecipientTag,
+    pub nonce: [u8; NONCE_LEN],
+    pub sender: MessageSender,
+    pub body: Vec<u8>,
+}
+
+impl CxmEnvelope {
+    /// Parses a transaction payload as a CXM v1 envelope.
+    ///
+    /// There is no best-effort fallback: raw payloads, unknown versions and malformed headers all
+    /// return `None` and are not indexed.
+    pub fn parse(payload: &[u8]) -> Option<Self> {
+        if payload.len() < CXM_V1_HEADER_LEN || payload[..CXM_MAGIC.len()] != CXM_MAGIC || payload[3] != CXM_V1_VERSION {
+            return None;
+        }
+        let sender_kind = payload[46];
+        let sender_len = payload[47] as usize;
+        let sender_data = &payload[48..80];
+        if MessageSender::significant_len(sender_kind) != Some(sender_len) || sender_data[sender_len..].iter().any(|&byte| byte != 0) {
+            return None;
+        }
+        Some(Self {
+            msg_type: payload[4],
+            flags: payload[5],
+            recipient_tag: payload[6..22].try_into().unwrap(),
+            nonce: payload[22..46].try_into().unwrap(),
+            sender: MessageSender { kind: sender_kind, data: sender_data.try_into().unwrap() },
+            body: payload[CXM_V1_HEADER_LEN..].to_vec(),
+        })
+    }
+
+    /// Returns the secondary dedup key `SHA256(sender_kind || sender_data || nonce)`.
+    pub fn dedup_key(&self) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update([self.sender.kind]);
+        hasher.update(self.sender.data);
+        hasher.update(self.nonce);
+        hasher.finalize().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn envelope_bytes(version: u8, sender_kind: u8, sender_len: u8, sender_data: [u8; 32], body: &[u8]) -> Vec<u8> {
+        let mut payload = Vec::with_capacity(CXM_V1_HEADER_LEN + body.len());
+        payload.extend_from_slice(&CXM_MAGIC);
+        payload.extend_from_slice(&[version, 7, 1]);
+        payload.extend_from_slice(&[0xaa; RECIPIENT_TAG_LEN]);
+        payload.extend_from_slice(&[0xbb; NONCE_LEN]);
+        payload.extend_from_slice(&[sender_kind, sender_len]);
+        payload.extend_from_slice(&sender_data);
+        payload.extend_from_slice(body);
+        payload
+    }
+
+    #[test]
+    fn parses_valid_pubkey_envelope() {
+        let payload = envelope_bytes(CXM_V1_VERSION, SENDER_KIND_PUBKEY, 32, [3; 32], b"hello");
+        let envelope = CxmEnvelope::parse(&payload).expect("valid envelope");
+        assert_eq!(envelope.msg_type, 7);
+        assert_eq!(envelope.flags, 1);
+        assert_eq!(envelope.recipient_tag, [0xaa; RECIPIENT_TAG_LEN]);
+        assert_eq!(envelope.nonce, [0xbb; NONCE_LEN]);
+        assert_eq!(envelope.sender, MessageSender::new(SENDER_KIND_PUBKEY, &[3; 32]).unwrap());
+        assert_eq!(envelope.body, b"hello");
+    }
+
+    #[test]
+    fn parses_ref_sender_with_zero_padding_only() {
+        let mut sender_data = [0u8; 32];
+        sender_data[..16].copy_from_slice(&[9; 16]);
+        let payload = envelope_bytes(CXM_V1_VERSION, SENDER_KIND_REF, 16, sender_data, &[]);
+        let envelope = CxmEnvelope::parse(&payload).expect("valid ref envelope");
+        assert_eq!(envelope.sender.significant_data(), &[9; 16]);
+
+        sender_data[31] = 1;
+        assert!(CxmEnvelope::parse(&envelope_bytes(CXM_V1_VERSION, SENDER_KIND_REF, 16, sender_data, &[])).is_none());
+    }
+
+    #[test]
+    fn rejects_raw_unknown_version_and_malformed_headers() {
+        assert!(CxmEnvelope::parse(b"plain text payload").is_none());
+        assert!(CxmEnvelope::parse(&envelope_bytes(2, SENDER_KIND_PUBKEY, 32, [3; 32], &[])).is_none());
+        assert!(CxmEnvelope::parse(&envelope_bytes(CXM_V1_VERSION, 3, 32, [3; 32], &[])).is_none());
+        assert!(CxmEnvelope::parse(&envelope_bytes(CXM_V1_VERSION, SENDER_KIND_PUBKEY, 16, [3; 32], &[])).is_none());
+        let payload = envelope_bytes(CXM_V1_VERSION, SENDER_KIND_PUBKEY, 32, [3; 32], &[]);
+        assert!(CxmEnvelope::parse(&payload[..CXM_V1_HEADER_LEN - 1]).is_none());
+    }
+}
@@ -0,0 +1,22 @@
+use cryptix_consensus_core::errors::consensus::ConsensusError;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum MessageIndexError {
+    #[error("Message index store failure: {0}")]
+    Store(String),
+
+    #[error("Message index processing failed: {0}")]
+    Processing(String),
+
+    #[error(transparent)]
+    Consensus(#[from] ConsensusError),
+}
+
+impl From<rocksdb::Error> for MessageIndexError {
+    fn from(err: rocksdb::Error) -> Self {
+        Self::Store(err.to_string())
+    }
+}
+
+pub type MessageIndexResult<T> = Result<T, MessageIndexError>;
@@ -0,0 +1,6 @@
+pub mod envelope;
+pub mod error;
+pub mod service;
+pub mod store;
+
+pub const IDENT: &str = "messageindex";
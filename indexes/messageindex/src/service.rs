@@ -0,0 +1,202 @@
+use crate::{
+    envelope::{CxmEnvelope, MessageSender, RecipientTag},
+    error::{MessageIndexError, MessageIndexResult},
+    store::{AcceptedMessage, MessageEntry, MessageIndexStore},
+    IDENT,
+};
+use async_channel::Receiver;
+use cryptix_consensus_core::acceptance_data::AcceptanceData;
+use cryptix_consensus_notify::{
+    connection::ConsensusChannelConnection, notification::Notification as ConsensusNotification, notifier::ConsensusNotifier,
+};
+use cryptix_consensusmanager::{ConsensusManager, ConsensusSessionOwned};
+use cryptix_core::{
+    debug, info,
+    task::service::{AsyncService, AsyncServiceFuture},
+    trace, warn,
+};
+use cryptix_notify::{connection::ChannelType, listener::ListenerLifespan, scope::VirtualChainChangedScope};
+use cryptix_utils::{channel::Channel, triggers::SingleTrigger};
+use std::{path::PathBuf, sync::Arc};
+
+const SERVICE_IDENT: &str = "cryptix-message-index-service";
+
+/// Maximum number of chain blocks indexed per consensus session.
+const SYNC_CHAIN_BLOCKS_PER_ROUND: usize = 1024;
+
+/// Indexes the CXM v1 envelopes of the transactions accepted by the selected chain.
+///
+/// Virtual chain changed notifications only trigger a catch-up from the stored tip to the current
+/// sink, so the same path serves startup, live updates and reorgs.
+pub struct MessageIndexService {
+    consensus_manager: Arc<ConsensusManager>,
+    store: Arc<MessageIndexStore>,
+    recv_channel: Receiver<ConsensusNotification>,
+    shutdown: SingleTrigger,
+}
+
+impl MessageIndexService {
+    pub fn new(
+        consensus_notifier: &Arc<ConsensusNotifier>,
+        consensus_manager: Arc<ConsensusManager>,
+        db_dir: PathBuf,
+    ) -> MessageIndexResult<Self> {
+        let store = Arc::new(MessageIndexStore::open(db_dir)?);
+
+        let consensus_notify_channel = Channel::<ConsensusNotification>::default();
+        let listener_id = consensus_notifier.register_new_listener(
+            ConsensusChannelConnection::new(SERVICE_IDENT, consensus_notify_channel.sender(), ChannelType::Closable),
+            ListenerLifespan::Static(Default::default()),
+        );
+        consensus_notifier.try_start_notify(listener_id, VirtualChainChangedScope::new(false).into()).map_err(|err| {
+            MessageIndexError::Processing(format!("failed to subscribe to virtual chain changed notifications: {err}"))
+        })?;
+
+        Ok(Self { consensus_manager, store, recv_channel: consensus_notify_channel.receiver(), shutdown: Default::default() })
+    }
+
+    /// Returns the sequence of the latest indexed message.
+    pub fn last_sequence(&self) -> u64 {
+        self.store.last_sequence()
+    }
+
+    /// Returns up to `limit` messages sent to `recipient_tag`, optionally by `sender` only, after the `after_sequence` cursor.
+    pub fn get_messages_by_recipient_tag(
+        &self,
+        recipient_tag: &RecipientTag,
+        sender: Option<&MessageSender>,
+        after_sequence: u64,
+        limit: usize,
+    ) -> MessageIndexResult<Vec<MessageEntry>> {
+        self.store.get_messages(recipient_tag, sender, after_sequence, limit)
+    }
+
+    /// Returns up to `limit` messages indexed after the `after_sequence` cursor.
+    pub fn get_messages_since(&self, after_sequence: u64, limit: usize) -> MessageIndexResult<Vec<MessageEntry>> {
+        self.store.get_messages_since(after_sequence, limit)
+    }
+
+    /// Brings the index in line with the current selected chain.
+    async fn sync(&self) -> MessageIndexResult<()> {
+        loop {
+            let session = self.consensus_manager.consensus().session().await;
+            let tip = self.store.tip()?;
+            let low = match tip {
+                Some(tip) => tip,
+                None => session.async_pruning_point().await,
+            };
+            let chain_path = match session.async_get_virtual_chain_from_block(low, Some(SYNC_CHAIN_BLOCKS_PER_ROUND)).await {
+                Ok(chain_path) => chain_path,
+                Err(err) if tip.is_some() => {
+                    // The tip was pruned or reorged out while the node was down, so resume from the pruning point.
+                    // The chain blocks indexed above it can no longer be diffed against the selected chain, so
+                    // their messages are dropped and get indexed again by the blocks which are still on it.
+                    let pruning_point = session.async_pruning_point().await;
+                    let pruning_point_daa_score = session.async_get_header(pruning_point).await?.daa_score;
+                    let removed_count = self.store.remove_chain_blocks_above(pruning_point_daa_score)?;
+                    warn!(
+                        "[{IDENT}] message index tip {low} is no longer usable ({err}); resuming from pruning point {pruning_point} \
+                         after removing {removed_count} message(s) accepted above it"
+                    );
+                    self.store.set_tip(pruning_point)?;
+                    continue;
+                }
+                Err(err) => return Err(err.into()),
+            };
+
+            for removed in chain_path.removed.iter().copied() {
+                let removed_count = self.store.remove_chain_block(removed)?;
+                if removed_count > 0 {
+                    debug!("[{IDENT}] removed {removed_count} message(s) accepted by reorged chain block {removed}");
+                }
+            }
+            if chain_path.added.is_empty() {
+                if let Some(last_removed) = chain_path.removed.last() {
+                    self.store.set_tip(session.async_get_ghostdag_data(*last_removed).await?.selected_parent)?;
+                }
+                return Ok(());
+            }
+
+            let acceptance_data = session.async_get_blocks_acceptance_data(chain_path.added.clone(), None).await?;
+            for (block_hash, block_acceptance_data) in chain_path.added.iter().copied().zip(acceptance_data.iter()) {
+                let header = session.async_get_header(block_hash).await?;
+                let messages = Self::accepted_messages(&session, block_acceptance_data).await;
+                let indexed = self.store.apply_chain_block(block_hash, header.daa_score, header.timestamp, messages)?;
+                if indexed > 0 {
+                    debug!("[{IDENT}] indexed {indexed} message(s) accepted by chain block {block_hash}");
+                }
+            }
+            if chain_path.added.len() < SYNC_CHAIN_BLOCKS_PER_ROUND {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Collects the valid CXM envelopes among the transactions accepted by a chain block.
+    async fn accepted_messages(session: &ConsensusSessionOwned, acceptance_data: &AcceptanceData) -> Vec<AcceptedMessage> {
+        let mut messages = Vec::new();
+        for mergeset_entry in acceptance_data.iter() {
+            let transactions = match session.async_get_block(mergeset_entry.block_hash).await {
+                Ok(block) => block.transactions,
+                Err(err) => {
+                    // Merged blocks below the pruning point may have no body anymore
+                    debug!("[{IDENT}] skipping merged block {} without body: {err}", mergeset_entry.block_hash);
+                    continue;
+                }
+            };
+            for accepted_tx in mergeset_entry.accepted_transactions.iter() {
+                let Some(tx) = transactions.get(accepted_tx.index_within_block as usize) else { continue };
+                if let Some(envelope) = CxmEnvelope::parse(&tx.payload) {
+                    messages.push(AcceptedMessage { transaction_id: accepted_tx.transaction_id, envelope });
+                }
+            }
+        }
+        messages
+    }
+}
+
+impl AsyncService for MessageIndexService {
+    fn ident(self: Arc<Self>) -> &'static str {
+        SERVICE_IDENT
+    }
+
+    fn start(self: Arc<Self>) -> AsyncServiceFuture {
+        trace!("{} starting", SERVICE_IDENT);
+        let shutdown_signal = self.shutdown.listener.clone();
+        Box::pin(async move {
+            info!("[{IDENT}] message index started at sequence {}", self.store.last_sequence());
+            if let Err(err) = self.sync().await {
+                warn!("[{IDENT}] message index startup catch-up failed: {err}");
+            }
+            loop {
+                tokio::select! {
+                    _ = shutdown_signal.clone() => break,
+                    notification = self.recv_channel.recv() => {
+                        match notification {
+                            Ok(ConsensusNotification::VirtualChainChanged(_)) => {
+                                if let Err(err) = self.sync().await {
+                                    warn!("[{IDENT}] message index update failed: {err}");
+                                }
+                            }
+                            Ok(_) => {}
+                            Err(_) => break,
+                        }
+                    }
+                }
+            }
+            Ok(())
+        })
+    }
+
+    fn signal_exit(self: Arc<Self>) {
+        trace!("sending an exit signal to {}", SERVICE_IDENT);
+        self.shutdown.trigger.trigger();
+    }
+
+    fn stop(self: Arc<Self>) -> AsyncServiceFuture {
+        Box::pin(async move {
+            trace!("{} stopped", SERVICE_IDENT);
+            Ok(())
+        })
+    }
+}
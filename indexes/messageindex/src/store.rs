@@ -0,0 +1,352 @@
+use crate::{
+    envelope::{CxmEnvelope, MessageSender, RecipientTag},
+    error::{MessageIndexError, MessageIndexResult},
+};
+use borsh::{BorshDeserialize, BorshSerialize};
+use cryptix_consensus_core::{tx::TransactionId, Hash};
+use rocksdb::{Direction, IteratorMode, Options, WriteBatch, DB};
+use std::{
+    collections::HashSet,
+    path::Path,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+const META_LAST_SEQUENCE_KEY: &[u8] = b"meta/last-sequence";
+const META_TIP_KEY: &[u8] = b"meta/tip";
+/// sequence -> entry
+const ENTRY_PREFIX: &[u8] = b"e/";
+/// recipient tag || sequence -> ()
+const RECIPIENT_PREFIX: &[u8] = b"r/";
+/// recipient tag || sender kind || sender data || sequence -> ()
+const RECIPIENT_SENDER_PREFIX: &[u8] = b"rs/";
+/// transaction id -> sequence
+const TRANSACTION_PREFIX: &[u8] = b"t/";
+/// secondary dedup key -> sequence
+const DEDUP_PREFIX: &[u8] = b"d/";
+/// accepting chain block hash -> sequences
+const BLOCK_PREFIX: &[u8] = b"b/";
+
+/// An indexed message together with the chain block which accepted its transaction.
+#[derive(Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct MessageEntry {
+    pub sequence: u64,
+    pub transaction_id: TransactionId,
+    pub accepting_block_hash: Hash,
+    pub accepting_daa_score: u64,
+    pub accepting_block_time: u64,
+    pub envelope: CxmEnvelope,
+}
+
+/// A message found in the transactions accepted by a chain block, not yet indexed.
+#[derive(Clone, Debug)]
+pub struct AcceptedMessage {
+    pub transaction_id: TransactionId,
+    pub envelope: CxmEnvelope,
+}
+
+/// RocksDB store of the message index.
+///
+/// Entries get a strictly increasing sequence which is never reused, even when the accepting block
+/// leaves the selected chain, so that the sequence can serve as a pagination and notification cursor.
+pub struct MessageIndexStore {
+    db: DB,
+    last_sequence: AtomicU64,
+}
+
+impl MessageIndexStore {
+    pub fn open(path: impl AsRef<Path>) -> MessageIndexResult<Self> {
+        let mut options = Options::default();
+        options.create_if_missing(true);
+        options.set_keep_log_file_num(4);
+        let db = DB::open(&options, path.as_ref())?;
+        let last_sequence = match db.get(META_LAST_SEQUENCE_KEY)? {
+            Some(bytes) => decode_sequence(&bytes)?,
+            None => 0,
+        };
+        Ok(Self { db, last_sequence: AtomicU64::new(last_sequence) })
+    }
+
+    /// Returns the sequence of the latest indexed message, or 0 if none was ever indexed.
+    pub fn last_sequence(&self) -> u64 {
+        self.last_sequence.load(Ordering::SeqCst)
+    }
+
+    /// Returns the last chain block processed by the index.
+    pub fn tip(&self) -> MessageIndexResult<Option<Hash>> {
+        self.db.get(META_TIP_KEY)?.map(|bytes| decode::<Hash>(&bytes)).transpose()
+    }
+
+    pub fn set_tip(&self, tip: Hash) -> MessageIndexResult<()> {
+        Ok(self.db.put(META_TIP_KEY, encode(&tip)?)?)
+    }
+
+    /// Indexes the messages accepted by `block_hash` and moves the tip to this block.
+    ///
+    /// Messages whose transaction id or secondary dedup key is already indexed are replays and get skipped.
+    /// Returns the number of newly indexed messages.
+    pub fn apply_chain_block(
+        &self,
+        block_hash: Hash,
+        daa_score: u64,
+        timestamp: u64,
+        messages: Vec<AcceptedMessage>,
+    ) -> MessageIndexResult<usize> {
+        let mut batch = WriteBatch::default();
+        let mut sequence = self.last_sequence();
+        let mut sequences = Vec::new();
+        let mut seen_transactions = HashSet::new();
+        let mut seen_dedup_keys = HashSet::new();
+        for message in messages {
+            let dedup_key = message.envelope.dedup_key();
+            if !seen_transactions.insert(message.transaction_id)
+                || !seen_dedup_keys.insert(dedup_key)
+                || self.db.get(key(TRANSACTION_PREFIX, &[message.transaction_id.as_bytes().as_slice()]))?.is_some()
+                || self.db.get(key(DEDUP_PREFIX, &[&dedup_key]))?.is_some()
+            {
+                continue;
+            }
+            sequence += 1;
+            let entry = MessageEntry {
+                sequence,
+                transaction_id: message.transaction_id,
+                accepting_block_hash: block_hash,
+                accepting_daa_score: daa_score,
+                accepting_block_time: timestamp,
+                envelope: message.envelope,
+            };
+            for (entry_key, value) in entry_keys(&entry) {
+                batch.put(entry_key, value);
+            }
+            batch.put(key(ENTRY_PREFIX, &[&sequence.to_be_bytes()]), encode(&entry)?);
+            sequences.push(sequence);
+        }
+        if !sequences.is_empty() {
+            batch.put(key(BLOCK_PREFIX, &[block_hash.as_bytes().as_slice()]), encode(&sequences)?);
+            batch.put(META_LAST_SEQUENCE_KEY, sequence.to_be_bytes());
+        }
+        batch.put(META_TIP_KEY, encode(&block_hash)?);
+        self.db.write(batch)?;
+        self.last_sequence.store(sequence, Ordering::SeqCst);
+        Ok(sequences.len())
+    }
+
+    /// Removes the messages accepted by `block_hash`, which left the selected chain.
+    ///
+    /// Returns the number of removed messages.
+    pub fn remove_chain_block(&self, block_hash: Hash) -> MessageIndexResult<usize> {
+        let block_key = key(BLOCK_PREFIX, &[block_hash.as_bytes().as_slice()]);
+        let Some(bytes) = self.db.get(&block_key)? else { return Ok(0) };
+        let sequences = decode::<Vec<u64>>(&bytes)?;
+        let mut batch = WriteBatch::default();
+        for sequence in sequences.iter() {
+            let entry_key = key(ENTRY_PREFIX, &[&sequence.to_be_bytes()]);
+            if let Some(bytes) = self.db.get(&entry_key)? {
+                for (entry_key, _) in entry_keys(&decode::<MessageEntry>(&bytes)?) {
+                    batch.delete(entry_key);
+                }
+            }
+            batch.delete(entry_key);
+        }
+        batch.delete(block_key);
+        self.db.write(batch)?;
+        Ok(sequences.len())
+    }
+
+    /// Removes the messages accepted by chain blocks with a DAA score above `daa_score`.
+    ///
+    /// Used when the index resumes from an earlier chain block and can no longer tell which of the
+    /// blocks it indexed past that point left the selected chain. Returns the number of removed messages.
+    pub fn remove_chain_blocks_above(&self, daa_score: u64) -> MessageIndexResult<usize> {
+        let mut block_hashes = HashSet::new();
+        for item in self.db.iterator(IteratorMode::From(ENTRY_PREFIX, Direction::Forward)) {
+            let (entry_key, value) = item?;
+            if !entry_key.starts_with(ENTRY_PREFIX) {
+                break;
+            }
+            let entry = decode::<MessageEntry>(&value)?;
+            if entry.accepting_daa_score > daa_score {
+                block_hashes.insert(entry.accepting_block_hash);
+            }
+        }
+        let mut removed = 0;
+        for block_hash in block_hashes {
+            removed += self.remove_chain_block(block_hash)?;
+        }
+        Ok(removed)
+    }
+
+    /// Returns up to `limit` messages sent to `recipient_tag`, optionally by `sender` only, with a sequence
+    /// above `after_sequence`, in sequence order.
+    pub fn get_messages(
+        &self,
+        recipient_tag: &RecipientTag,
+        sender: Option<&MessageSender>,
+        after_sequence: u64,
+        limit: usize,
+    ) -> MessageIndexResult<Vec<MessageEntry>> {
+        let prefix = match sender {
+            Some(sender) => key(RECIPIENT_SENDER_PREFIX, &[recipient_tag, &[sender.kind], &sender.data]),
+            None => key(RECIPIENT_PREFIX, &[recipient_tag]),
+        };
+        let mut entries = Vec::new();
+        for sequence in self.sequences_after(&prefix, after_sequence, limit)? {
+            if let Some(bytes) = self.db.get(key(ENTRY_PREFIX, &[&sequence.to_be_bytes()]))? {
+                entries.push(decode(&bytes)?);
+            }
+        }
+        Ok(entries)
+    }
+
+    /// Returns up to `limit` messages with a sequence above `after_sequence`, in sequence order.
+    pub fn get_messages_since(&self, after_sequence: u64, limit: usize) -> MessageIndexResult<Vec<MessageEntry>> {
+        let mut entries = Vec::new();
+        let Some(first_sequence) = after_sequence.checked_add(1) else { return Ok(entries) };
+        let start = key(ENTRY_PREFIX, &[&first_sequence.to_be_bytes()]);
+        for item in self.db.iterator(IteratorMode::From(&start, Direction::Forward)) {
+            let (entry_key, value) = item?;
+            if !entry_key.starts_with(ENTRY_PREFIX) || entries.len() >= limit {
+                break;
+            }
+            entries.push(decode(&value)?);
+        }
+        Ok(entries)
+    }
+
+    fn sequences_after(&self, prefix: &[u8], after_sequence: u64, limit: usize) -> MessageIndexResult<Vec<u64>> {
+        let mut sequences = Vec::new();
+        let Some(first_sequence) = after_sequence.checked_add(1) else { return Ok(sequences) };
+        let start = key(prefix, &[&first_sequence.to_be_bytes()]);
+        for item in self.db.iterator(IteratorMode::From(&start, Direction::Forward)) {
+            let (index_key, _) = item?;
+            if !index_key.starts_with(prefix) || sequences.len() >= limit {
+                break;
+            }
+            sequences.push(decode_sequence(&index_key[prefix.len()..])?);
+        }
+        Ok(sequences)
+    }
+}
+
+/// Returns the secondary keys of an entry along with their values.
+fn entry_keys(entry: &MessageEntry) -> [(Vec<u8>, Vec<u8>); 4] {
+    let sequence = entry.sequence.to_be_bytes();
+    let envelope = &entry.envelope;
+    [
+        (key(RECIPIENT_PREFIX, &[&envelope.recipient_tag, &sequence]), vec![]),
+        (key(RECIPIENT_SENDER_PREFIX, &[&envelope.recipient_tag, &[envelope.sender.kind], &envelope.sender.data, &sequence]), vec![]),
+        (key(TRANSACTION_PREFIX, &[entry.transaction_id.as_bytes().as_slice()]), sequence.to_vec()),
+        (key(DEDUP_PREFIX, &[&envelope.dedup_key()]), sequence.to_vec()),
+    ]
+}
+
+fn key(prefix: &[u8], parts: &[&[u8]]) -> Vec<u8> {
+    let mut key = Vec::with_capacity(prefix.len() + parts.iter().map(|part| part.len()).sum::<usize>());
+    key.extend_from_slice(prefix);
+    parts.iter().for_each(|part| key.extend_from_slice(part));
+    key
+}
+
+fn encode<T: BorshSerialize>(value: &T) -> MessageIndexResult<Vec<u8>> {
+    borsh::to_vec(value).map_err(|err| MessageIndexError::Store(format!("failed encoding value: {err}")))
+}
+
+fn decode<T: BorshDeserialize>(bytes: &[u8]) -> MessageIndexResult<T> {
+    T::try_from_slice(bytes).map_err(|err| MessageIndexError::Store(format!("failed decoding value: {err}")))
+}
+
+fn decode_sequence(bytes: &[u8]) -> MessageIndexResult<u64> {
+    let bytes: [u8; 8] = bytes.try_into().map_err(|_| MessageIndexError::Store(format!("invalid sequence length {}", bytes.len())))?;
+    Ok(u64::from_be_bytes(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::envelope::SENDER_KIND_PUBKEY;
+    use std::{
+        path::PathBuf,
+        time::{SystemTime, UNIX_EPOCH},
+    };
+
+    fn unique_temp_dir(name: &str) -> PathBuf {
+        let nonce = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+        std::env::temp_dir().join(format!("cryptix-messageindex-{name}-{}-{nonce}", std::process::id()))
+    }
+
+    fn message(txid: u8, recipient: u8, sender: u8, nonce: u8) -> AcceptedMessage {
+        AcceptedMessage {
+            transaction_id: TransactionId::from_bytes([txid; 32]),
+            envelope: CxmEnvelope {
+                msg_type: 1,
+                flags: 0,
+                recipient_tag: [recipient; 16],
+                nonce: [nonce; 24],
+                sender: MessageSender::new(SENDER_KIND_PUBKEY, &[sender; 32]).unwrap(),
+                body: vec![txid],
+            },
+        }
+    }
+
+    #[test]
+    fn indexes_paginates_and_removes_chain_blocks() {
+        let path = unique_temp_dir("store");
+        let store = MessageIndexStore::open(&path).unwrap();
+        let block_a = Hash::from_bytes([0xa; 32]);
+        let block_b = Hash::from_bytes([0xb; 32]);
+
+        let indexed =
+            store.apply_chain_block(block_a, 10, 1000, vec![message(1, 1, 1, 1), message(2, 1, 2, 1), message(3, 2, 1, 2)]).unwrap();
+        assert_eq!(indexed, 3);
+        // Same transaction id and replayed sender nonce are both skipped
+        let indexed = store.apply_chain_block(block_b, 11, 1001, vec![message(1, 1, 1, 9), message(4, 1, 1, 1)]).unwrap();
+        assert_eq!(indexed, 0);
+        assert_eq!(store.tip().unwrap(), Some(block_b));
+        assert_eq!(store.last_sequence(), 3);
+
+        let sequences = |entries: Vec<MessageEntry>| entries.into_iter().map(|entry| entry.sequence).collect::<Vec<_>>();
+        assert_eq!(sequences(store.get_messages(&[1; 16], None, 0, 10).unwrap()), vec![1, 2]);
+        assert_eq!(sequences(store.get_messages(&[1; 16], None, 1, 10).unwrap()), vec![2]);
+        assert_eq!(sequences(store.get_messages(&[1; 16], None, 0, 1).unwrap()), vec![1]);
+        let sender = MessageSender::new(SENDER_KIND_PUBKEY, &[2; 32]).unwrap();
+        assert_eq!(sequences(store.get_messages(&[1; 16], Some(&sender), 0, 10).unwrap()), vec![2]);
+        assert_eq!(sequences(store.get_messages_since(1, 10).unwrap()), vec![2, 3]);
+
+        assert_eq!(store.remove_chain_block(block_a).unwrap(), 3);
+        assert!(store.get_messages(&[1; 16], None, 0, 10).unwrap().is_empty());
+        assert!(store.get_messages_since(0, 10).unwrap().is_empty());
+
+        // Removed messages can be indexed again, with fresh sequences
+        assert_eq!(store.apply_chain_block(block_b, 11, 1001, vec![message(1, 1, 1, 1)]).unwrap(), 1);
+        assert_eq!(sequences(store.get_messages(&[1; 16], None, 0, 10).unwrap()), vec![4]);
+
+        drop(store);
+        assert_eq!(MessageIndexStore::open(&path).unwrap().last_sequence(), 4);
+        let _ = std::fs::remove_dir_all(path);
+    }
+
+    #[test]
+    fn removes_chain_blocks_above_daa_score() {
+        let path = unique_temp_dir("above");
+        let store = MessageIndexStore::open(&path).unwrap();
+        let block_a = Hash::from_bytes([0xa; 32]);
+        let block_b = Hash::from_bytes([0xb; 32]);
+        let block_c = Hash::from_bytes([0xc; 32]);
+
+        store.apply_chain_block(block_a, 10, 1000, vec![message(1, 1, 1, 1)]).unwrap();
+        store.apply_chain_block(block_b, 11, 1001, vec![message(2, 1, 1, 2), message(3, 1, 2, 1)]).unwrap();
+        store.apply_chain_block(block_c, 12, 1002, vec![message(4, 1, 1, 3)]).unwrap();
+
+        assert_eq!(store.remove_chain_blocks_above(10).unwrap(), 3);
+        let sequences = store.get_messages(&[1; 16], None, 0, 10).unwrap().into_iter().map(|entry| entry.sequence).collect::<Vec<_>>();
+        assert_eq!(sequences, vec![1]);
+        assert!(store.get_messages_since(1, 10).unwrap().is_empty());
+        assert_eq!(store.remove_chain_blocks_above(10).unwrap(), 0);
+
+        // Messages of the dropped blocks are indexed again once their blocks are back on the selected chain
+        assert_eq!(store.apply_chain_block(block_b, 11, 1001, vec![message(2, 1, 1, 2)]).unwrap(), 1);
+        assert_eq!(store.last_sequence(), 5);
+
+        drop(store);
+        let _ = std::fs::remove_dir_all(path);
+    }
+}
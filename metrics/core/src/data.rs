@@ -13,6 +13,7 @@ pub enum MetricGroup {
     Bandwidth,
     Connections,
     Network,
+    Hfa,
 }
 
 impl std::fmt::Display for MetricGroup {
@@ -23,6 +24,7 @@ impl std::fmt::Display for MetricGroup {
             MetricGroup::Bandwidth => write!(f, "bandwidth"),
             MetricGroup::Connections => write!(f, "connections"),
             MetricGroup::Network => write!(f, "network"),
+            MetricGroup::Hfa => write!(f, "hfa"),
         }
     }
 }
@@ -35,6 +37,7 @@ impl MetricGroup {
             MetricGroup::Bandwidth => "Bandwidth",
             MetricGroup::Connections => "Connections",
             MetricGroup::Network => "Network",
+            MetricGroup::Hfa => "Fast Rail",
         }
     }
 }
@@ -114,6 +117,21 @@ impl MetricGroup {
             ]
             .as_slice()
             .iter(),
+            MetricGroup::Hfa => [
+                Metric::HfaMode,
+                Metric::HfaPendingIntents,
+                Metric::HfaActiveLocks,
+                Metric::HfaSubmittedCount,
+                Metric::HfaFastConfirmedCount,
+                Metric::HfaFastConfirmedPerSecond,
+                Metric::HfaRejectedCount,
+                Metric::HfaConfirmLatencyP95,
+                Metric::HfaLockConflictsPerSecond,
+                Metric::HfaModeTransitionsCount,
+                Metric::HfaArbiterQueueDepth,
+            ]
+            .as_slice()
+            .iter(),
         }
     }
 }
@@ -177,6 +195,18 @@ impl From<Metric> for MetricGroup {
             | Metric::NetworkPastMedianTime
             | Metric::NetworkVirtualParentHashesCount
             | Metric::NetworkVirtualDaaScore => MetricGroup::Network,
+            // --
+            Metric::HfaMode
+            | Metric::HfaPendingIntents
+            | Metric::HfaActiveLocks
+            | Metric::HfaSubmittedCount
+            | Metric::HfaFastConfirmedCount
+            | Metric::HfaFastConfirmedPerSecond
+            | Metric::HfaRejectedCount
+            | Metric::HfaConfirmLatencyP95
+            | Metric::HfaLockConflictsPerSecond
+            | Metric::HfaModeTransitionsCount
+            | Metric::HfaArbiterQueueDepth => MetricGroup::Hfa,
         }
     }
 }
@@ -249,6 +279,18 @@ pub enum Metric {
     NetworkPastMedianTime,
     NetworkVirtualParentHashesCount,
     NetworkVirtualDaaScore,
+    // --
+    HfaMode,
+    HfaPendingIntents,
+    HfaActiveLocks,
+    HfaSubmittedCount,
+    HfaFastConfirmedCount,
+    HfaFastConfirmedPerSecond,
+    HfaRejectedCount,
+    HfaConfirmLatencyP95,
+    HfaLockConflictsPerSecond,
+    HfaModeTransitionsCount,
+    HfaArbiterQueueDepth,
 }
 
 impl Metric {
@@ -414,6 +456,18 @@ impl Metric {
             Metric::NetworkPastMedianTime => format_as_float(f, false),
             Metric::NetworkVirtualParentHashesCount => format_as_float(f, short),
             Metric::NetworkVirtualDaaScore => format_as_float(f, false),
+            // --
+            Metric::HfaMode => hfa_mode_name(f).to_string(),
+            Metric::HfaPendingIntents => format_as_float(f.trunc(), short),
+            Metric::HfaActiveLocks => format_as_float(f.trunc(), short),
+            Metric::HfaSubmittedCount => format_as_float(f, short),
+            Metric::HfaFastConfirmedCount => format_as_float(f, short),
+            Metric::HfaFastConfirmedPerSecond => format_as_float(f, short),
+            Metric::HfaRejectedCount => format_as_float(f, short),
+            Metric::HfaConfirmLatencyP95 => format!("{} ms", format_with_precision(f)),
+            Metric::HfaLockConflictsPerSecond => format_as_float(f, short),
+            Metric::HfaModeTransitionsCount => format_as_float(f, short),
+            Metric::HfaArbiterQueueDepth => format_as_float(f.trunc(), short),
         }
     }
 
@@ -478,6 +532,18 @@ impl Metric {
             Metric::NetworkPastMedianTime => ("Past Median Time", "MT"),
             Metric::NetworkVirtualParentHashesCount => ("Virtual Parent Hashes", "Virt Parents"),
             Metric::NetworkVirtualDaaScore => ("Virtual DAA Score", "DAA"),
+            // --
+            Metric::HfaMode => ("Fast Rail Mode", "Fast Mode"),
+            Metric::HfaPendingIntents => ("Pending Fast Intents", "Fast Pending"),
+            Metric::HfaActiveLocks => ("Fast Input Locks", "Fast Locks"),
+            Metric::HfaSubmittedCount => ("Submitted Fast Intents", "Fast Submitted"),
+            Metric::HfaFastConfirmedCount => ("Fast Confirmed Intents", "Fast Confirmed"),
+            Metric::HfaFastConfirmedPerSecond => ("Fast Confirmations/s", "Fast Conf/s"),
+            Metric::HfaRejectedCount => ("Rejected Fast Intents", "Fast Rejected"),
+            Metric::HfaConfirmLatencyP95 => ("Fast Confirmation Latency p95", "Fast p95"),
+            Metric::HfaLockConflictsPerSecond => ("Fast Lock Conflicts/s", "Lock Conf/s"),
+            Metric::HfaModeTransitionsCount => ("Fast Rail Mode Transitions", "Mode Trans"),
+            Metric::HfaArbiterQueueDepth => ("Fast Arbiter Queue Depth", "Arbiter Queue"),
         }
     }
 }
@@ -535,6 +601,18 @@ pub struct MetricsData {
     pub network_past_median_time: u64,
     pub network_virtual_parent_hashes_count: u32,
     pub network_virtual_daa_score: u64,
+    // --
+    pub hfa_enabled: bool,
+    pub hfa_mode: f64,
+    pub hfa_pending_intents: u64,
+    pub hfa_active_locks: u64,
+    pub hfa_submitted_count: u64,
+    pub hfa_fast_confirmed_count: u64,
+    pub hfa_rejected_count: u64,
+    pub hfa_lock_conflict_count: u64,
+    pub hfa_confirm_latency_ms_p95: f64,
+    pub hfa_mode_transition_count: u64,
+    pub hfa_arbiter_queue_depth: u64,
 }
 
 impl MetricsData {
@@ -554,6 +632,7 @@ impl TryFrom<GetMetricsResponse> for MetricsData {
             process_metrics,
             storage_metrics,
             custom_metrics: _,
+            hfa_metrics,
         } = response; //rpc.get_metrics(true, true, true, true, true, false).await?;
 
         let consensus_metrics = consensus_metrics.ok_or(Error::MissingData("Consensus Metrics"))?;
@@ -561,6 +640,8 @@ impl TryFrom<GetMetricsResponse> for MetricsData {
         let bandwidth_metrics = bandwidth_metrics.ok_or(Error::MissingData("Bandwidth Metrics"))?;
         let process_metrics = process_metrics.ok_or(Error::MissingData("Process Metrics"))?;
         let storage_metrics = storage_metrics.ok_or(Error::MissingData("Storage Metrics"))?;
+        // nodes without the fast rail (or predating it) report no HFA metrics
+        let hfa_metrics = hfa_metrics.unwrap_or_default();
 
         Ok(MetricsData {
             unixtime_millis: server_time as f64,
@@ -620,6 +701,18 @@ impl TryFrom<GetMetricsResponse> for MetricsData {
             node_disk_io_write_per_sec: process_metrics.disk_io_write_per_sec,
 
             node_storage_size_bytes: storage_metrics.storage_size_bytes,
+
+            hfa_enabled: hfa_metrics.enabled,
+            hfa_mode: if hfa_metrics.enabled { hfa_metrics.mode as u8 as f64 } else { -1.0 },
+            hfa_pending_intents: hfa_metrics.pending_intents,
+            hfa_active_locks: hfa_metrics.active_locks,
+            hfa_submitted_count: hfa_metrics.submitted_count,
+            hfa_fast_confirmed_count: hfa_metrics.fast_confirmed_count,
+            hfa_rejected_count: hfa_metrics.rejected_count,
+            hfa_lock_conflict_count: hfa_metrics.lock_conflict_count,
+            hfa_confirm_latency_ms_p95: hfa_metrics.confirm_latency_ms_p95,
+            hfa_mode_transition_count: hfa_metrics.mode_transition_count,
+            hfa_arbiter_queue_depth: hfa_metrics.fast_arbiter_queue_depth,
         })
     }
 }
@@ -692,6 +785,18 @@ pub struct MetricsSnapshot {
     pub network_virtual_daa_score: f64,
     // ---
     pub node_storage_size_bytes: f64,
+    // ---
+    pub hfa_mode: f64,
+    pub hfa_pending_intents: f64,
+    pub hfa_active_locks: f64,
+    pub hfa_submitted_count: f64,
+    pub hfa_fast_confirmed_count: f64,
+    pub hfa_fast_confirmed_per_second: f64,
+    pub hfa_rejected_count: f64,
+    pub hfa_confirm_latency_ms_p95: f64,
+    pub hfa_lock_conflicts_per_second: f64,
+    pub hfa_mode_transition_count: f64,
+    pub hfa_arbiter_queue_depth: f64,
 }
 
 impl MetricsSnapshot {
@@ -756,6 +861,18 @@ impl MetricsSnapshot {
             Metric::NetworkPastMedianTime => self.network_past_median_time,
             Metric::NetworkVirtualParentHashesCount => self.network_virtual_parent_hashes_count,
             Metric::NetworkVirtualDaaScore => self.network_virtual_daa_score,
+            // --
+            Metric::HfaMode => self.hfa_mode,
+            Metric::HfaPendingIntents => self.hfa_pending_intents,
+            Metric::HfaActiveLocks => self.hfa_active_locks,
+            Metric::HfaSubmittedCount => self.hfa_submitted_count,
+            Metric::HfaFastConfirmedCount => self.hfa_fast_confirmed_count,
+            Metric::HfaFastConfirmedPerSecond => self.hfa_fast_confirmed_per_second,
+            Metric::HfaRejectedCount => self.hfa_rejected_count,
+            Metric::HfaConfirmLatencyP95 => self.hfa_confirm_latency_ms_p95,
+            Metric::HfaLockConflictsPerSecond => self.hfa_lock_conflicts_per_second,
+            Metric::HfaModeTransitionsCount => self.hfa_mode_transition_count,
+            Metric::HfaArbiterQueueDepth => self.hfa_arbiter_queue_depth,
         }
     }
 
@@ -789,6 +906,8 @@ impl From<(&MetricsData, &MetricsData)> for MetricsSnapshot {
         let node_grpc_user_bytes_rx_per_second = per_sec(a.node_grpc_user_bytes_rx, b.node_grpc_user_bytes_rx, duration_millis);
         let node_total_bytes_tx_per_second = per_sec(a.node_total_bytes_tx, b.node_total_bytes_tx, duration_millis);
         let node_total_bytes_rx_per_second = per_sec(a.node_total_bytes_rx, b.node_total_bytes_rx, duration_millis);
+        let hfa_fast_confirmed_per_second = per_sec(a.hfa_fast_confirmed_count, b.hfa_fast_confirmed_count, duration_millis);
+        let hfa_lock_conflicts_per_second = per_sec(a.hfa_lock_conflict_count, b.hfa_lock_conflict_count, duration_millis);
 
         Self {
             unixtime_millis: b.unixtime_millis,
@@ -853,6 +972,18 @@ impl From<(&MetricsData, &MetricsData)> for MetricsSnapshot {
             network_past_median_time: b.network_past_median_time as f64,
             network_virtual_parent_hashes_count: b.network_virtual_parent_hashes_count as f64,
             network_virtual_daa_score: b.network_virtual_daa_score as f64,
+            // --
+            hfa_mode: b.hfa_mode,
+            hfa_pending_intents: b.hfa_pending_intents as f64,
+            hfa_active_locks: b.hfa_active_locks as f64,
+            hfa_submitted_count: b.hfa_submitted_count as f64,
+            hfa_fast_confirmed_count: b.hfa_fast_confirmed_count as f64,
+            hfa_fast_confirmed_per_second,
+            hfa_rejected_count: b.hfa_rejected_count as f64,
+            hfa_confirm_latency_ms_p95: b.hfa_confirm_latency_ms_p95,
+            hfa_lock_conflicts_per_second,
+            hfa_mode_transition_count: b.hfa_mode_transition_count as f64,
+            hfa_arbiter_queue_depth: b.hfa_arbiter_queue_depth as f64,
 
             data: b.clone(),
         }
@@ -944,6 +1075,16 @@ pub fn format_as_float(f: f64, short: bool) -> String {
     }
 }
 
+/// Display the fast rail mode encoded as the `RpcHfaMode` discriminant (negative when the rail is disabled).
+fn hfa_mode_name(f: f64) -> &'static str {
+    match f {
+        f if f.is_nan() || f < 0.0 => "---",
+        f if f < 0.5 => "Normal",
+        f if f < 1.5 => "Degraded",
+        _ => "Paused",
+    }
+}
+
 /// Format supplied value as a float with 2 decimal places.
 fn format_with_precision(f: f64) -> String {
     if f.fract() < 0.01 {
@@ -6,6 +6,7 @@ pub use data::{Metric, MetricGroup, MetricsData, MetricsSnapshot};
 
 use crate::result::Result;
 use cryptix_rpc_core::api::rpc::RpcApi;
+use cryptix_rpc_core::GetMetricsRequest;
 use futures::{pin_mut, select, FutureExt, StreamExt};
 use std::{
     future::Future,
@@ -121,6 +122,15 @@ impl Metrics {
     }
 
     async fn sample_metrics(self: &Arc<Self>, rpc: Arc<dyn RpcApi>) -> Result<MetricsData> {
-        MetricsData::try_from(rpc.get_metrics(true, true, true, true, true, false).await?)
+        let request = GetMetricsRequest {
+            process_metrics: true,
+            connection_metrics: true,
+            bandwidth_metrics: true,
+            consensus_metrics: true,
+            storage_metrics: true,
+            custom_metrics: false,
+            hfa_metrics: true,
+        };
+        MetricsData::try_from(rpc.get_metrics_call(None, request).await?)
     }
 }
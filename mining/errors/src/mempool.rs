@@ -141,6 +141,9 @@ pub enum NonStandardError {
 
     #[error("transaction input #{1} has {2} signature operations which is more than the allowed max amount of {3}")]
     RejectSignatureCount(TransactionId, usize, u8, u8),
+
+    #[error("transaction input #{1}: non-standard covenant redeem script: {2}")]
+    RejectCovenantRedeemScript(TransactionId, usize, String),
 }
 
 impl NonStandardError {
@@ -157,6 +160,7 @@ impl NonStandardError {
             NonStandardError::RejectInputScriptClass(id, _) => id,
             NonStandardError::RejectInsufficientFee(id, _, _) => id,
             NonStandardError::RejectSignatureCount(id, _, _, _) => id,
+            NonStandardError::RejectCovenantRedeemScript(id, _, _) => id,
         }
     }
 }
@@ -1,6 +1,9 @@
 use crate::mempool::errors::{RuleError, RuleResult};
-use blake2b_simd::Params as Blake2bParams;
 use cryptix_consensus_core::{
+    atomic::{
+        cat_owner_id, CAT_MAGIC, CAT_OWNER_AUTH_SCHEME_PUBKEY, CAT_OWNER_AUTH_SCHEME_PUBKEY_ECDSA, CAT_OWNER_AUTH_SCHEME_SCRIPT_HASH,
+        CAT_VERSION,
+    },
     errors::tx::TxRuleError,
     subnets::SUBNETWORK_ID_PAYLOAD,
     tx::{MutableTransaction, ScriptPublicKey, Transaction},
@@ -8,12 +11,6 @@ use cryptix_consensus_core::{
 use cryptix_txscript::script_class::ScriptClass;
 use std::fmt::{Display, Formatter};
 
-const CAT_MAGIC: &[u8; 3] = b"CAT";
-const CAT_VERSION: u8 = 1;
-const CAT_OWNER_DOMAIN: &[u8] = b"CAT_OWNER_V2";
-const OWNER_AUTH_SCHEME_PUBKEY: u8 = 0;
-const OWNER_AUTH_SCHEME_PUBKEY_ECDSA: u8 = 1;
-const OWNER_AUTH_SCHEME_SCRIPT_HASH: u8 = 2;
 pub(crate) const ATOMIC_NONCE_SCOPE_OWNER: u8 = 0;
 pub(crate) const ATOMIC_NONCE_SCOPE_ASSET: u8 = 1;
 
@@ -56,7 +53,7 @@ struct ParsedAtomicMempoolPayload {
 }
 
 pub(crate) fn is_cat_transaction(tx: &Transaction) -> bool {
-    tx.subnetwork_id == SUBNETWORK_ID_PAYLOAD && tx.payload.starts_with(CAT_MAGIC)
+    tx.subnetwork_id == SUBNETWORK_ID_PAYLOAD && tx.payload.starts_with(&CAT_MAGIC)
 }
 
 pub(crate) fn atomic_mempool_debug_summary(tx: &Transaction) -> String {
@@ -75,6 +72,20 @@ pub(crate) fn atomic_mempool_debug_summary(tx: &Transaction) -> String {
         Some(6) => "buy_liquidity_exact_in",
         Some(7) => "sell_liquidity_exact_in",
         Some(8) => "claim_liquidity_fees",
+        Some(9) => "approve",
+        Some(10) => "transfer_from",
+        Some(11) => "batch_transfer",
+        Some(12) => "lock_tokens",
+        Some(13) => "transfer_mint_authority",
+        Some(14) => "renounce_mint_authority",
+        Some(15) => "update_metadata",
+        Some(16) => "create_pair_pool",
+        Some(17) => "add_pair_liquidity",
+        Some(18) => "remove_pair_liquidity",
+        Some(19) => "swap_pair_exact_in",
+        Some(20) => "swap_pair_exact_out",
+        Some(21) => "buy_liquidity_exact_out",
+        Some(22) => "sell_liquidity_exact_out",
         Some(other) => return format!("cat=true op=unsupported({other})"),
         None => return "cat=true op=truncated".to_string(),
     };
@@ -129,7 +140,7 @@ pub(crate) fn atomic_mempool_liquidity_pool_slot(transaction: &Transaction) -> R
 }
 
 fn parse_atomic_mempool_payload(payload: &[u8]) -> RuleResult<Option<ParsedAtomicMempoolPayload>> {
-    if !payload.starts_with(CAT_MAGIC) {
+    if !payload.starts_with(&CAT_MAGIC) {
         return Ok(None);
     }
 
@@ -139,7 +150,7 @@ fn parse_atomic_mempool_payload(payload: &[u8]) -> RuleResult<Option<ParsedAtomi
         return Err(invalid_atomic(format!("unsupported CAT version `{version}`")));
     }
     let op = take_u8(payload, &mut cursor, "truncated CAT op")?;
-    if op > 8 {
+    if op > 22 {
         return Err(invalid_atomic(format!("unsupported CAT op `{op}`")));
     }
     let flags = take_u8(payload, &mut cursor, "truncated CAT flags")?;
@@ -153,12 +164,12 @@ fn parse_atomic_mempool_payload(payload: &[u8]) -> RuleResult<Option<ParsedAtomi
     }
 
     let (nonce_scope, pool_slot) = match op {
-        0 | 4 | 5 => (ParsedAtomicNonceScope::Owner, None),
-        1..=3 => {
+        0 | 4 | 5 | 16 => (ParsedAtomicNonceScope::Owner, None),
+        1..=3 | 9..=15 | 17..=20 => {
             let asset_id = take_32(payload, &mut cursor, "truncated CAT asset_id")?;
             (ParsedAtomicNonceScope::Asset(asset_id), None)
         }
-        6..=8 => {
+        6..=8 | 21..=22 => {
             let asset_id = take_32(payload, &mut cursor, "truncated CAT asset_id")?;
             let pool_nonce = take_u64_le(payload, &mut cursor, "truncated CAT expected_pool_nonce")?;
             if pool_nonce == 0 {
@@ -174,24 +185,15 @@ fn parse_atomic_mempool_payload(payload: &[u8]) -> RuleResult<Option<ParsedAtomi
 
 fn atomic_owner_id_from_script(script_public_key: &ScriptPublicKey) -> Option<[u8; 32]> {
     let (auth_scheme, canonical_pubkey_bytes) = canonical_atomic_owner_identity(script_public_key)?;
-    let pubkey_len = u16::try_from(canonical_pubkey_bytes.len()).ok()?;
-    let mut hasher = Blake2bParams::new().hash_length(32).to_state();
-    hasher.update(CAT_OWNER_DOMAIN);
-    hasher.update(&[auth_scheme]);
-    hasher.update(&pubkey_len.to_le_bytes());
-    hasher.update(canonical_pubkey_bytes);
-    let hash = hasher.finalize();
-    let mut owner_id = [0u8; 32];
-    owner_id.copy_from_slice(hash.as_bytes());
-    Some(owner_id)
+    cat_owner_id(auth_scheme, canonical_pubkey_bytes)
 }
 
 fn canonical_atomic_owner_identity(script_public_key: &ScriptPublicKey) -> Option<(u8, &[u8])> {
     let script_bytes = script_public_key.script();
     match ScriptClass::from_script(script_public_key) {
-        ScriptClass::PubKey if script_bytes.len() == 34 => Some((OWNER_AUTH_SCHEME_PUBKEY, &script_bytes[1..33])),
-        ScriptClass::PubKeyECDSA if script_bytes.len() == 35 => Some((OWNER_AUTH_SCHEME_PUBKEY_ECDSA, &script_bytes[1..34])),
-        ScriptClass::ScriptHash if script_bytes.len() == 35 => Some((OWNER_AUTH_SCHEME_SCRIPT_HASH, &script_bytes[2..34])),
+        ScriptClass::PubKey if script_bytes.len() == 34 => Some((CAT_OWNER_AUTH_SCHEME_PUBKEY, &script_bytes[1..33])),
+        ScriptClass::PubKeyECDSA if script_bytes.len() == 35 => Some((CAT_OWNER_AUTH_SCHEME_PUBKEY_ECDSA, &script_bytes[1..34])),
+        ScriptClass::ScriptHash if script_bytes.len() == 35 => Some((CAT_OWNER_AUTH_SCHEME_SCRIPT_HASH, &script_bytes[2..34])),
         _ => None,
     }
 }
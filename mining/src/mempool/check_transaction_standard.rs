@@ -8,7 +8,7 @@ use cryptix_consensus_core::{
     subnets::SUBNETWORK_ID_PAYLOAD,
     tx::{MutableTransaction, PopulatedTransaction, TransactionOutput},
 };
-use cryptix_txscript::{get_sig_op_count, is_unspendable, script_class::ScriptClass};
+use cryptix_txscript::{get_sig_op_count, is_unspendable, script_class::ScriptClass, standard::check_covenant_signature_script};
 
 /// MAX_STANDARD_P2SH_SIG_OPS is the maximum number of signature operations
 /// that are considered standard in a pay-to-script-hash script.
@@ -203,6 +203,8 @@ impl Mempool {
                     if num_sig_ops > MAX_STANDARD_P2SH_SIG_OPS {
                         return Err(NonStandardError::RejectSignatureCount(transaction_id, i, num_sig_ops, MAX_STANDARD_P2SH_SIG_OPS));
                     }
+                    check_covenant_signature_script(&input.signature_script)
+                        .map_err(|err| NonStandardError::RejectCovenantRedeemScript(transaction_id, i, err.to_string()))?;
                 }
                 ScriptClass::LiquidityVault => {}
             }
@@ -251,11 +253,12 @@ mod tests {
         constants::{MAX_TX_IN_SEQUENCE_NUM, SOMPI_PER_CRYPTIX, TX_VERSION},
         network::NetworkType,
         subnets::{SUBNETWORK_ID_NATIVE, SUBNETWORK_ID_PAYLOAD},
-        tx::{ScriptPublicKey, ScriptVec, Transaction, TransactionInput, TransactionOutpoint, TransactionOutput},
+        tx::{ScriptPublicKey, ScriptVec, Transaction, TransactionInput, TransactionOutpoint, TransactionOutput, UtxoEntry},
     };
     use cryptix_txscript::{
-        opcodes::codes::{OpReturn, OpTrue},
+        opcodes::codes::{OpGreaterThanOrEqual, OpReturn, OpTrue, OpTxInputCount, OpTxOutputCount},
         script_builder::ScriptBuilder,
+        standard::{pay_to_script_hash_script, pay_to_script_hash_signature_script, MAX_STANDARD_COVENANT_OPS},
     };
     use smallvec::smallvec;
     use std::sync::Arc;
@@ -622,4 +625,61 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_check_transaction_standard_in_context_covenant_redeem_script() {
+        struct Test {
+            name: &'static str,
+            redeem_script: Vec<u8>,
+            is_standard: bool,
+        }
+
+        let tests = vec![
+            Test {
+                name: "covenant redeem script",
+                redeem_script: vec![OpTxInputCount, OpTxOutputCount, OpGreaterThanOrEqual],
+                is_standard: true,
+            },
+            Test {
+                name: "covenant redeem script exceeding the covenant opcodes limit",
+                redeem_script: vec![OpTxInputCount; MAX_STANDARD_COVENANT_OPS + 1],
+                is_standard: false,
+            },
+        ];
+
+        let addr = Address::new(Prefix::Testnet, Version::PubKey, &[1u8; 32]);
+        let tx_out = TransactionOutput::new(SOMPI_PER_CRYPTIX, cryptix_txscript::pay_to_address_script(&addr));
+
+        for test in tests {
+            let signature_script = pay_to_script_hash_signature_script(test.redeem_script.clone(), vec![]).unwrap();
+            let prev_out = TransactionOutpoint::new(cryptix_hashes::Hash::from_u64_word(1), 0);
+            let tx_in = TransactionInput::new(prev_out, signature_script, MAX_TX_IN_SEQUENCE_NUM, 1);
+            let tx = Transaction::new(TX_VERSION, vec![tx_in], vec![tx_out.clone()], 0, SUBNETWORK_ID_NATIVE, 0, vec![]);
+            tx.set_mass(1000);
+
+            let mut mtx = MutableTransaction::from_tx(tx);
+            mtx.entries[0] = Some(UtxoEntry::new(2 * SOMPI_PER_CRYPTIX, pay_to_script_hash_script(&test.redeem_script), 0, false));
+            mtx.calculated_compute_mass = Some(1000);
+            mtx.calculated_fee = Some(SOMPI_PER_CRYPTIX);
+
+            for net in NetworkType::iter() {
+                let params: Params = net.into();
+                let config = Config::build_default(params.target_time_per_block, false, params.max_block_mass);
+                let counters = Arc::new(MiningCounters::default());
+                let mempool = Mempool::new(Arc::new(config), counters);
+
+                let res = mempool.check_transaction_standard_in_context(&mtx);
+                if test.is_standard {
+                    assert!(res.is_ok(), "'{}': nonstandard when it should not be: {:?}", test.name, res);
+                } else {
+                    assert!(
+                        matches!(res, Err(NonStandardError::RejectCovenantRedeemScript(_, 0, _))),
+                        "'{}': expected a covenant redeem script rejection, got {:?}",
+                        test.name,
+                        res
+                    );
+                }
+            }
+        }
+    }
 }
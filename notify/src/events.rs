@@ -52,10 +52,12 @@ event_type_enum! {
         PruningPointUtxoSetOverride,
         NewBlockTemplate,
         TokenEventsChanged,
+        FastIntentStatusChanged,
+        MessagesReceived,
     }
 }
 
-pub const EVENT_COUNT: usize = 10;
+pub const EVENT_COUNT: usize = 12;
 
 impl FromStr for EventType {
     type Err = Error;
@@ -72,6 +74,8 @@ impl FromStr for EventType {
             "pruning-point-utxo-set-override" => Ok(EventType::PruningPointUtxoSetOverride),
             "new-block-template" => Ok(EventType::NewBlockTemplate),
             "token-events-changed" => Ok(EventType::TokenEventsChanged),
+            "fast-intent-status-changed" => Ok(EventType::FastIntentStatusChanged),
+            "messages-received" => Ok(EventType::MessagesReceived),
             _ => Err(Error::InvalidEventType(s.to_string())),
         }
     }
@@ -3,8 +3,11 @@ use crate::subscription::context::SubscriptionContext;
 use super::{
     events::EventType,
     subscription::{
-        single::{OverallSubscription, UtxosChangedSubscription, VirtualChainChangedSubscription},
-        Single,
+        single::{
+            FastIntentStatusChangedSubscription, MessagesReceivedSubscription, OverallSubscription, TokenEventsChangedSubscription,
+            UtxosChangedSubscription, VirtualChainChangedSubscription,
+        },
+        Single, Subscription,
     },
 };
 use std::fmt::{Debug, Display};
@@ -22,6 +25,42 @@ pub trait Notification: Clone + Debug + Display + Send + Sync + 'static {
     fn apply_utxos_changed_subscription(&self, subscription: &UtxosChangedSubscription, context: &SubscriptionContext)
         -> Option<Self>;
 
+    /// Token events are only produced by the RPC core, so by default the subscription filter is ignored.
+    fn apply_token_events_changed_subscription(
+        &self,
+        subscription: &TokenEventsChangedSubscription,
+        _context: &SubscriptionContext,
+    ) -> Option<Self> {
+        match subscription.active() {
+            true => Some(self.clone()),
+            false => None,
+        }
+    }
+
+    /// Fast intent status changes are only produced by the RPC core, so by default the subscription filter is ignored.
+    fn apply_fast_intent_status_changed_subscription(
+        &self,
+        subscription: &FastIntentStatusChangedSubscription,
+        _context: &SubscriptionContext,
+    ) -> Option<Self> {
+        match subscription.active() {
+            true => Some(self.clone()),
+            false => None,
+        }
+    }
+
+    /// Received messages are only produced by the RPC core, so by default the subscription filter is ignored.
+    fn apply_messages_received_subscription(
+        &self,
+        subscription: &MessagesReceivedSubscription,
+        _context: &SubscriptionContext,
+    ) -> Option<Self> {
+        match subscription.active() {
+            true => Some(self.clone()),
+            false => None,
+        }
+    }
+
     fn apply_subscription(&self, subscription: &dyn Single, context: &SubscriptionContext) -> Option<Self> {
         match subscription.event_type() {
             EventType::VirtualChainChanged => self.apply_virtual_chain_changed_subscription(
@@ -30,6 +69,18 @@ pub trait Notification: Clone + Debug + Display + Send + Sync + 'static {
             ),
             EventType::UtxosChanged => self
                 .apply_utxos_changed_subscription(subscription.as_any().downcast_ref::<UtxosChangedSubscription>().unwrap(), context),
+            EventType::TokenEventsChanged => self.apply_token_events_changed_subscription(
+                subscription.as_any().downcast_ref::<TokenEventsChangedSubscription>().unwrap(),
+                context,
+            ),
+            EventType::FastIntentStatusChanged => self.apply_fast_intent_status_changed_subscription(
+                subscription.as_any().downcast_ref::<FastIntentStatusChangedSubscription>().unwrap(),
+                context,
+            ),
+            EventType::MessagesReceived => self.apply_messages_received_subscription(
+                subscription.as_any().downcast_ref::<MessagesReceivedSubscription>().unwrap(),
+                context,
+            ),
             _ => self.apply_overall_subscription(subscription.as_any().downcast_ref::<OverallSubscription>().unwrap(), context),
         }
     }
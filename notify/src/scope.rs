@@ -1,6 +1,7 @@
 use super::events::EventType;
 use borsh::{BorshDeserialize, BorshSerialize};
 use cryptix_addresses::Address;
+use cryptix_hashes::Hash;
 use derive_more::Display;
 use serde::{Deserialize, Serialize};
 use workflow_serializer::prelude::*;
@@ -46,6 +47,8 @@ pub enum Scope {
     PruningPointUtxoSetOverride,
     NewBlockTemplate,
     TokenEventsChanged,
+    FastIntentStatusChanged,
+    MessagesReceived,
 }
 }
 
@@ -268,19 +271,345 @@ impl Deserializer for NewBlockTemplateScope {
     }
 }
 
-#[derive(Clone, Display, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
-pub struct TokenEventsChangedScope {}
+/// Scope carrying a filter that fully defines the subscription of a listener.
+///
+/// A start mutation replaces the current filter and a stop mutation ends the subscription.
+pub trait FilterScope: Clone + std::fmt::Debug + Default + Eq + std::hash::Hash + Into<Scope> + Send + Sync + 'static {
+    const EVENT_TYPE: EventType;
+
+    fn try_from_scope(scope: Scope) -> Option<Self>;
+
+    /// Returns the canonical form of the filter, so that equal filters compare and hash equally.
+    fn normalized(self) -> Self;
+
+    /// Returns the narrowest filter letting through every notification that passes at least one of `scopes`.
+    fn union<'a>(scopes: impl IntoIterator<Item = &'a Self>) -> Self
+    where
+        Self: 'a;
+}
+
+/// Filter of a TokenEventsChanged subscription.
+///
+/// Every non-empty list narrows the subscription and an event must satisfy all of them. An owner id matches
+/// either side of the event. Leaving all lists empty subscribes to every token event.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+pub struct TokenEventsChangedScope {
+    #[serde(default)]
+    pub asset_ids: Vec<String>,
+    #[serde(default)]
+    pub owner_ids: Vec<String>,
+    #[serde(default)]
+    pub op_types: Vec<u32>,
+    #[serde(default)]
+    pub apply_statuses: Vec<u32>,
+}
+
+impl TokenEventsChangedScope {
+    /// Builds a normalized filter: hex ids are lowercased and every list is sorted and deduplicated,
+    /// so that equal filters compare and hash equally.
+    pub fn new(asset_ids: Vec<String>, owner_ids: Vec<String>, op_types: Vec<u32>, apply_statuses: Vec<u32>) -> Self {
+        fn normalize<T: Ord>(mut values: Vec<T>) -> Vec<T> {
+            values.sort();
+            values.dedup();
+            values
+        }
+        let lowercase = |ids: Vec<String>| ids.into_iter().map(|id| id.trim().to_ascii_lowercase()).collect::<Vec<_>>();
+        Self {
+            asset_ids: normalize(lowercase(asset_ids)),
+            owner_ids: normalize(lowercase(owner_ids)),
+            op_types: normalize(op_types),
+            apply_statuses: normalize(apply_statuses),
+        }
+    }
+
+    pub fn normalized(self) -> Self {
+        Self::new(self.asset_ids, self.owner_ids, self.op_types, self.apply_statuses)
+    }
+
+    /// Returns true if the filter lets every token event through.
+    pub fn is_wildcard(&self) -> bool {
+        self.asset_ids.is_empty() && self.owner_ids.is_empty() && self.op_types.is_empty() && self.apply_statuses.is_empty()
+    }
+
+    /// Returns true if an event with the provided fields passes the filter.
+    ///
+    /// Ids are expected as lowercase hex strings.
+    pub fn matches(
+        &self,
+        asset_id: Option<&str>,
+        from_owner_id: Option<&str>,
+        to_owner_id: Option<&str>,
+        op_type: Option<u32>,
+        apply_status: u32,
+    ) -> bool {
+        let contains_id = |ids: &[String], id: Option<&str>| id.is_some_and(|id| ids.iter().any(|x| x == id));
+        (self.asset_ids.is_empty() || contains_id(&self.asset_ids, asset_id))
+            && (self.owner_ids.is_empty() || contains_id(&self.owner_ids, from_owner_id) || contains_id(&self.owner_ids, to_owner_id))
+            && (self.op_types.is_empty() || op_type.is_some_and(|op_type| self.op_types.contains(&op_type)))
+            && (self.apply_statuses.is_empty() || self.apply_statuses.contains(&apply_status))
+    }
+
+    /// Returns the narrowest filter letting through every event that passes at least one of `scopes`.
+    ///
+    /// The union is computed list by list, so the result may be wider than the exact union of the filters.
+    pub fn union<'a>(scopes: impl IntoIterator<Item = &'a Self>) -> Self {
+        let mut union: Option<Self> = None;
+        for scope in scopes {
+            union = Some(match union {
+                None => scope.clone(),
+                Some(mut union) => {
+                    fn merge<T: Clone + Ord>(target: &mut Vec<T>, source: &[T]) {
+                        if target.is_empty() {
+                            return;
+                        }
+                        if source.is_empty() {
+                            target.clear();
+                            return;
+                        }
+                        target.extend_from_slice(source);
+                        target.sort();
+                        target.dedup();
+                    }
+                    merge(&mut union.asset_ids, &scope.asset_ids);
+                    merge(&mut union.owner_ids, &scope.owner_ids);
+                    merge(&mut union.op_types, &scope.op_types);
+                    merge(&mut union.apply_statuses, &scope.apply_statuses);
+                    union
+                }
+            });
+        }
+        union.unwrap_or_default()
+    }
+}
+
+impl FilterScope for TokenEventsChangedScope {
+    const EVENT_TYPE: EventType = EventType::TokenEventsChanged;
+
+    fn try_from_scope(scope: Scope) -> Option<Self> {
+        match scope {
+            Scope::TokenEventsChanged(scope) => Some(scope),
+            _ => None,
+        }
+    }
+
+    fn normalized(self) -> Self {
+        TokenEventsChangedScope::normalized(self)
+    }
+
+    fn union<'a>(scopes: impl IntoIterator<Item = &'a Self>) -> Self {
+        TokenEventsChangedScope::union(scopes)
+    }
+}
+
+impl std::fmt::Display for TokenEventsChangedScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_wildcard() {
+            return write!(f, "TokenEventsChangedScope (all)");
+        }
+        write!(
+            f,
+            "TokenEventsChangedScope ({} assets, {} owners, {} op types, {} apply statuses)",
+            self.asset_ids.len(),
+            self.owner_ids.len(),
+            self.op_types.len(),
+            self.apply_statuses.len()
+        )
+    }
+}
 
 impl Serializer for TokenEventsChangedScope {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
-        store!(u16, &1, writer)?;
+        store!(u16, &2, writer)?;
+        store!(Vec<String>, &self.asset_ids, writer)?;
+        store!(Vec<String>, &self.owner_ids, writer)?;
+        store!(Vec<u32>, &self.op_types, writer)?;
+        store!(Vec<u32>, &self.apply_statuses, writer)?;
         Ok(())
     }
 }
 
 impl Deserializer for TokenEventsChangedScope {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let version = load!(u16, reader)?;
+        if version < 2 {
+            return Ok(Self::default());
+        }
+        let asset_ids = load!(Vec<String>, reader)?;
+        let owner_ids = load!(Vec<String>, reader)?;
+        let op_types = load!(Vec<u32>, reader)?;
+        let apply_statuses = load!(Vec<u32>, reader)?;
+        Ok(Self { asset_ids, owner_ids, op_types, apply_statuses })
+    }
+}
+
+/// Filter of a FastIntentStatusChanged subscription.
+///
+/// A status change passes if its intent id is listed or if its base transaction touches one of the listed
+/// addresses. Leaving both lists empty subscribes to every status change.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+pub struct FastIntentStatusChangedScope {
+    #[serde(default)]
+    pub intent_ids: Vec<Hash>,
+    #[serde(default)]
+    pub addresses: Vec<Address>,
+}
+
+impl FastIntentStatusChangedScope {
+    /// Builds a normalized filter with sorted and deduplicated lists.
+    pub fn new(mut intent_ids: Vec<Hash>, mut addresses: Vec<Address>) -> Self {
+        intent_ids.sort();
+        intent_ids.dedup();
+        addresses.sort();
+        addresses.dedup();
+        Self { intent_ids, addresses }
+    }
+
+    /// Returns true if the filter lets every status change through.
+    pub fn is_wildcard(&self) -> bool {
+        self.intent_ids.is_empty() && self.addresses.is_empty()
+    }
+
+    /// Returns true if a status change of `intent_id` touching `addresses` passes the filter.
+    pub fn matches(&self, intent_id: &Hash, addresses: &[Address]) -> bool {
+        self.is_wildcard() || self.intent_ids.contains(intent_id) || addresses.iter().any(|address| self.addresses.contains(address))
+    }
+}
+
+impl FilterScope for FastIntentStatusChangedScope {
+    const EVENT_TYPE: EventType = EventType::FastIntentStatusChanged;
+
+    fn try_from_scope(scope: Scope) -> Option<Self> {
+        match scope {
+            Scope::FastIntentStatusChanged(scope) => Some(scope),
+            _ => None,
+        }
+    }
+
+    fn normalized(self) -> Self {
+        Self::new(self.intent_ids, self.addresses)
+    }
+
+    /// Both lists match alternatively, so the union of the lists is exact unless one filter is a wildcard.
+    fn union<'a>(scopes: impl IntoIterator<Item = &'a Self>) -> Self {
+        let mut intent_ids = Vec::new();
+        let mut addresses = Vec::new();
+        for scope in scopes {
+            if scope.is_wildcard() {
+                return Self::default();
+            }
+            intent_ids.extend_from_slice(&scope.intent_ids);
+            addresses.extend_from_slice(&scope.addresses);
+        }
+        Self::new(intent_ids, addresses)
+    }
+}
+
+impl std::fmt::Display for FastIntentStatusChangedScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_wildcard() {
+            return write!(f, "FastIntentStatusChangedScope (all)");
+        }
+        write!(f, "FastIntentStatusChangedScope ({} intents, {} addresses)", self.intent_ids.len(), self.addresses.len())
+    }
+}
+
+impl Serializer for FastIntentStatusChangedScope {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(Vec<Hash>, &self.intent_ids, writer)?;
+        store!(Vec<Address>, &self.addresses, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for FastIntentStatusChangedScope {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
         let _version = load!(u16, reader)?;
-        Ok(Self {})
+        let intent_ids = load!(Vec<Hash>, reader)?;
+        let addresses = load!(Vec<Address>, reader)?;
+        Ok(Self { intent_ids, addresses })
+    }
+}
+
+/// Filter of a MessagesReceived subscription.
+///
+/// A message passes if its recipient tag, as lowercase hex, is listed. Leaving the list empty subscribes to
+/// every indexed message.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+pub struct MessagesReceivedScope {
+    #[serde(default)]
+    pub recipient_tags: Vec<String>,
+}
+
+impl MessagesReceivedScope {
+    /// Builds a normalized filter with lowercased, sorted and deduplicated recipient tags.
+    pub fn new(recipient_tags: Vec<String>) -> Self {
+        let mut recipient_tags = recipient_tags.into_iter().map(|tag| tag.trim().to_ascii_lowercase()).collect::<Vec<_>>();
+        recipient_tags.sort();
+        recipient_tags.dedup();
+        Self { recipient_tags }
+    }
+
+    /// Returns true if the filter lets every message through.
+    pub fn is_wildcard(&self) -> bool {
+        self.recipient_tags.is_empty()
+    }
+
+    /// Returns true if a message sent to `recipient_tag` passes the filter.
+    ///
+    /// The tag is expected as a lowercase hex string.
+    pub fn matches(&self, recipient_tag: &str) -> bool {
+        self.is_wildcard() || self.recipient_tags.iter().any(|tag| tag == recipient_tag)
+    }
+}
+
+impl FilterScope for MessagesReceivedScope {
+    const EVENT_TYPE: EventType = EventType::MessagesReceived;
+
+    fn try_from_scope(scope: Scope) -> Option<Self> {
+        match scope {
+            Scope::MessagesReceived(scope) => Some(scope),
+            _ => None,
+        }
+    }
+
+    fn normalized(self) -> Self {
+        Self::new(self.recipient_tags)
+    }
+
+    fn union<'a>(scopes: impl IntoIterator<Item = &'a Self>) -> Self {
+        let mut recipient_tags = Vec::new();
+        for scope in scopes {
+            if scope.is_wildcard() {
+                return Self::default();
+            }
+            recipient_tags.extend_from_slice(&scope.recipient_tags);
+        }
+        Self::new(recipient_tags)
+    }
+}
+
+impl std::fmt::Display for MessagesReceivedScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_wildcard() {
+            return write!(f, "MessagesReceivedScope (all)");
+        }
+        write!(f, "MessagesReceivedScope ({} recipient tags)", self.recipient_tags.len())
+    }
+}
+
+impl Serializer for MessagesReceivedScope {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(Vec<String>, &self.recipient_tags, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for MessagesReceivedScope {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let recipient_tags = load!(Vec<String>, reader)?;
+        Ok(Self { recipient_tags })
     }
 }
@@ -13,6 +13,9 @@ impl ArrayBuilder {
             let event_type = EventType::try_from(i).unwrap();
             let subscription: DynSubscription = match event_type {
                 EventType::VirtualChainChanged => Arc::<single::VirtualChainChangedSubscription>::default(),
+                EventType::TokenEventsChanged => Arc::<single::TokenEventsChangedSubscription>::default(),
+                EventType::FastIntentStatusChanged => Arc::<single::FastIntentStatusChangedSubscription>::default(),
+                EventType::MessagesReceived => Arc::<single::MessagesReceivedSubscription>::default(),
                 EventType::UtxosChanged => Arc::new(single::UtxosChangedSubscription::with_capacity(
                     single::UtxosChangedState::None,
                     listener_id,
@@ -29,6 +32,9 @@ impl ArrayBuilder {
             let event_type = EventType::try_from(i).unwrap();
             let subscription: CompoundedSubscription = match event_type {
                 EventType::VirtualChainChanged => Box::<compounded::VirtualChainChangedSubscription>::default(),
+                EventType::TokenEventsChanged => Box::<compounded::TokenEventsChangedSubscription>::default(),
+                EventType::FastIntentStatusChanged => Box::<compounded::FastIntentStatusChangedSubscription>::default(),
+                EventType::MessagesReceived => Box::<compounded::MessagesReceivedSubscription>::default(),
                 EventType::UtxosChanged => {
                     Box::new(compounded::UtxosChangedSubscription::with_capacity(utxos_changed_capacity.unwrap_or_default()))
                 }
@@ -1,11 +1,15 @@
 use crate::{
     address::{error::Result, tracker::Counters},
     events::EventType,
-    scope::{Scope, UtxosChangedScope, VirtualChainChangedScope},
+    scope::{
+        FastIntentStatusChangedScope, FilterScope, MessagesReceivedScope, Scope, TokenEventsChangedScope, UtxosChangedScope,
+        VirtualChainChangedScope,
+    },
     subscription::{context::SubscriptionContext, Command, Compounded, Mutation, Subscription},
 };
 use cryptix_addresses::{Address, Prefix};
 use itertools::Itertools;
+use std::collections::HashMap;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct OverallSubscription {
@@ -245,6 +249,76 @@ impl Subscription for UtxosChangedSubscription {
     }
 }
 
+/// Compounded subscription filtered by a [`FilterScope`], counting the listeners of every distinct filter.
+///
+/// The parent is subscribed to the union of all the filters. Since a start mutation replaces the filter
+/// of the parent subscription, every start is reported upwards with the resulting union.
+#[derive(Clone, Default, Debug, PartialEq, Eq)]
+pub struct FilteredSubscription<F: FilterScope> {
+    filters: HashMap<F, usize>,
+}
+
+/// Compounded TokenEventsChanged subscription
+pub type TokenEventsChangedSubscription = FilteredSubscription<TokenEventsChangedScope>;
+
+/// Compounded FastIntentStatusChanged subscription
+pub type FastIntentStatusChangedSubscription = FilteredSubscription<FastIntentStatusChangedScope>;
+
+/// Compounded MessagesReceived subscription
+pub type MessagesReceivedSubscription = FilteredSubscription<MessagesReceivedScope>;
+
+impl<F: FilterScope> FilteredSubscription<F> {
+    fn union(&self) -> F {
+        F::union(self.filters.keys())
+    }
+}
+
+impl<F: FilterScope> Compounded for FilteredSubscription<F> {
+    fn compound(&mut self, mutation: Mutation, _context: &SubscriptionContext) -> Option<Mutation> {
+        assert_eq!(self.event_type(), mutation.event_type());
+        if let Some(scope) = F::try_from_scope(mutation.scope) {
+            let filter = scope.normalized();
+            match mutation.command {
+                Command::Start => {
+                    *self.filters.entry(filter).or_default() += 1;
+                    return Some(Mutation::new(Command::Start, self.union().into()));
+                }
+                Command::Stop => {
+                    let previous = self.union();
+                    let count = self.filters.get_mut(&filter).expect("stopping filter must be active");
+                    *count -= 1;
+                    if *count == 0 {
+                        self.filters.remove(&filter);
+                    }
+                    if self.filters.is_empty() {
+                        return Some(Mutation::new(Command::Stop, F::default().into()));
+                    }
+                    let union = self.union();
+                    if union != previous {
+                        return Some(Mutation::new(Command::Start, union.into()));
+                    }
+                }
+            }
+        }
+        None
+    }
+}
+
+impl<F: FilterScope> Subscription for FilteredSubscription<F> {
+    #[inline(always)]
+    fn event_type(&self) -> EventType {
+        F::EVENT_TYPE
+    }
+
+    fn active(&self) -> bool {
+        !self.filters.is_empty()
+    }
+
+    fn scope(&self, _context: &SubscriptionContext) -> Scope {
+        self.union().into()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use cryptix_core::trace;
@@ -413,4 +487,119 @@ mod tests {
         // let result = std::panic::catch_unwind(AssertUnwindSafe(|| state.compound(remove_0(), &test.context)));
         // assert!(result.is_err(), "{}: trying to remove an address when its counter is zero must panic", test.name);
     }
+
+    #[test]
+    #[allow(clippy::redundant_clone)]
+    fn test_token_events_changed_compounding() {
+        let f = |asset_ids: &[&str], op_types: &[u32]| {
+            TokenEventsChangedScope::new(asset_ids.iter().map(|id| id.to_string()).collect(), vec![], op_types.to_vec(), vec![])
+        };
+        let m = |command: Command, scope: TokenEventsChangedScope| Mutation::new(command, Scope::TokenEventsChanged(scope));
+        let none = Box::<TokenEventsChangedSubscription>::default;
+
+        let a = || f(&["aa"], &[]);
+        let b_op1 = || f(&["bb"], &[1]);
+        let ab = || f(&["aa", "bb"], &[]);
+        let all = TokenEventsChangedScope::default;
+
+        let test = Test {
+            name: "TokenEventsChanged",
+            context: SubscriptionContext::new(),
+            initial_state: none(),
+            steps: vec![
+                Step { name: "add a 1", mutation: m(Command::Start, a()), result: Some(m(Command::Start, a())) },
+                Step { name: "add a 2, union is resent", mutation: m(Command::Start, a()), result: Some(m(Command::Start, a())) },
+                Step { name: "add b op1, union widens", mutation: m(Command::Start, b_op1()), result: Some(m(Command::Start, ab())) },
+                Step { name: "remove a 2, union unchanged", mutation: m(Command::Stop, a()), result: None },
+                Step { name: "add all, union is all", mutation: m(Command::Start, all()), result: Some(m(Command::Start, all())) },
+                Step { name: "remove all, union narrows", mutation: m(Command::Stop, all()), result: Some(m(Command::Start, ab())) },
+                Step { name: "remove a 1, union narrows", mutation: m(Command::Stop, a()), result: Some(m(Command::Start, b_op1())) },
+                Step { name: "remove b op1", mutation: m(Command::Stop, b_op1()), result: Some(m(Command::Stop, all())) },
+            ],
+            final_state: none(),
+        };
+        let mut state = test.run();
+
+        // Removing an inactive filter must panic
+        let result = std::panic::catch_unwind(AssertUnwindSafe(|| state.compound(m(Command::Stop, a()), &test.context)));
+        assert!(result.is_err(), "{}: trying to remove an inactive filter must panic", test.name);
+    }
+
+    #[test]
+    #[allow(clippy::redundant_clone)]
+    fn test_fast_intent_status_changed_compounding() {
+        let addresses = get_3_addresses(false);
+        let intent_id = cryptix_hashes::Hash::from_bytes([7; 32]);
+        let m = |command: Command, scope: FastIntentStatusChangedScope| Mutation::new(command, Scope::FastIntentStatusChanged(scope));
+        let none = Box::<FastIntentStatusChangedSubscription>::default;
+
+        let intent = || FastIntentStatusChangedScope::new(vec![intent_id], vec![]);
+        let address_0 = || FastIntentStatusChangedScope::new(vec![], vec![addresses[0].clone()]);
+        let both = || FastIntentStatusChangedScope::new(vec![intent_id], vec![addresses[0].clone()]);
+        let all = FastIntentStatusChangedScope::default;
+
+        let test = Test {
+            name: "FastIntentStatusChanged",
+            context: SubscriptionContext::new(),
+            initial_state: none(),
+            steps: vec![
+                Step { name: "add intent", mutation: m(Command::Start, intent()), result: Some(m(Command::Start, intent())) },
+                Step {
+                    name: "add address 0, union widens",
+                    mutation: m(Command::Start, address_0()),
+                    result: Some(m(Command::Start, both())),
+                },
+                Step { name: "add all, union is all", mutation: m(Command::Start, all()), result: Some(m(Command::Start, all())) },
+                Step { name: "remove all, union narrows", mutation: m(Command::Stop, all()), result: Some(m(Command::Start, both())) },
+                Step {
+                    name: "remove intent, union narrows",
+                    mutation: m(Command::Stop, intent()),
+                    result: Some(m(Command::Start, address_0())),
+                },
+                Step { name: "remove address 0", mutation: m(Command::Stop, address_0()), result: Some(m(Command::Stop, all())) },
+            ],
+            final_state: none(),
+        };
+        let _ = test.run();
+    }
+
+    #[test]
+    #[allow(clippy::redundant_clone)]
+    fn test_messages_received_compounding() {
+        let m = |command: Command, scope: MessagesReceivedScope| Mutation::new(command, Scope::MessagesReceived(scope));
+        let none = Box::<MessagesReceivedSubscription>::default;
+
+        let tag_a = || MessagesReceivedScope::new(vec!["AA".to_string()]);
+        let tag_b = || MessagesReceivedScope::new(vec!["bb".to_string()]);
+        let tags_ab = || MessagesReceivedScope::new(vec!["aa".to_string(), "bb".to_string()]);
+        let all = MessagesReceivedScope::default;
+
+        let test = Test {
+            name: "MessagesReceived",
+            context: SubscriptionContext::new(),
+            initial_state: none(),
+            steps: vec![
+                Step { name: "add tag a", mutation: m(Command::Start, tag_a()), result: Some(m(Command::Start, tag_a())) },
+                Step {
+                    name: "add tag b, union widens",
+                    mutation: m(Command::Start, tag_b()),
+                    result: Some(m(Command::Start, tags_ab())),
+                },
+                Step { name: "add all, union is all", mutation: m(Command::Start, all()), result: Some(m(Command::Start, all())) },
+                Step {
+                    name: "remove all, union narrows",
+                    mutation: m(Command::Stop, all()),
+                    result: Some(m(Command::Start, tags_ab())),
+                },
+                Step {
+                    name: "remove tag a, union narrows",
+                    mutation: m(Command::Stop, tag_a()),
+                    result: Some(m(Command::Start, tag_b())),
+                },
+                Step { name: "remove tag b", mutation: m(Command::Stop, tag_b()), result: Some(m(Command::Stop, all())) },
+            ],
+            final_state: none(),
+        };
+        let _ = test.run();
+    }
 }
@@ -3,7 +3,10 @@ use crate::{
     error::Result,
     events::EventType,
     listener::ListenerId,
-    scope::{Scope, UtxosChangedScope, VirtualChainChangedScope},
+    scope::{
+        FastIntentStatusChangedScope, FilterScope, MessagesReceivedScope, Scope, TokenEventsChangedScope, UtxosChangedScope,
+        VirtualChainChangedScope,
+    },
     subscription::{
         context::SubscriptionContext, BroadcastingSingle, Command, DynSubscription, Mutation, MutationOutcome, MutationPolicies,
         Single, Subscription, UtxosChangedMutationPolicy,
@@ -172,6 +175,89 @@ impl Subscription for VirtualChainChangedSubscription {
     }
 }
 
+/// Subscription to notifications filtered by a [`FilterScope`]
+///
+/// A start mutation replaces the filter of an active subscription while a stop mutation
+/// always ends the subscription, whatever its scope.
+#[derive(Eq, PartialEq, Hash, Clone, Debug, Default)]
+pub struct FilteredSubscription<F: FilterScope> {
+    active: bool,
+    filter: F,
+}
+
+/// Subscription to TokenEventsChanged notifications
+pub type TokenEventsChangedSubscription = FilteredSubscription<TokenEventsChangedScope>;
+
+/// Subscription to FastIntentStatusChanged notifications
+pub type FastIntentStatusChangedSubscription = FilteredSubscription<FastIntentStatusChangedScope>;
+
+/// Subscription to MessagesReceived notifications
+pub type MessagesReceivedSubscription = FilteredSubscription<MessagesReceivedScope>;
+
+impl<F: FilterScope> FilteredSubscription<F> {
+    pub fn new(active: bool, filter: F) -> Self {
+        Self { active, filter }
+    }
+
+    pub fn filter(&self) -> &F {
+        &self.filter
+    }
+}
+
+impl<F: FilterScope> Single for FilteredSubscription<F> {
+    fn apply_mutation(
+        &self,
+        _: &Arc<dyn Single>,
+        mutation: Mutation,
+        _: MutationPolicies,
+        _: &SubscriptionContext,
+    ) -> Result<MutationOutcome> {
+        assert_eq!(self.event_type(), mutation.event_type());
+        let outcome = if let Some(scope) = F::try_from_scope(mutation.scope) {
+            let filter = scope.normalized();
+            match (self.active, mutation.command) {
+                (false, Command::Stop) => MutationOutcome::new(),
+                (false, Command::Start) => {
+                    let mutated = Self::new(true, filter.clone());
+                    MutationOutcome::with_mutated(Arc::new(mutated), vec![Mutation::new(Command::Start, filter.into())])
+                }
+                (true, Command::Stop) => {
+                    let mutated = Self::new(false, F::default());
+                    MutationOutcome::with_mutated(Arc::new(mutated), vec![Mutation::new(Command::Stop, self.filter.clone().into())])
+                }
+                (true, Command::Start) if filter == self.filter => MutationOutcome::new(),
+                (true, Command::Start) => {
+                    // The stop goes first so that the compounded subscription ends on the start.
+                    let mutated = Self::new(true, filter.clone());
+                    MutationOutcome::with_mutated(
+                        Arc::new(mutated),
+                        vec![Mutation::new(Command::Stop, self.filter.clone().into()), Mutation::new(Command::Start, filter.into())],
+                    )
+                }
+            }
+        } else {
+            MutationOutcome::new()
+        };
+        Ok(outcome)
+    }
+}
+
+impl<F: FilterScope> Subscription for FilteredSubscription<F> {
+    #[inline(always)]
+    fn event_type(&self) -> EventType {
+        F::EVENT_TYPE
+    }
+
+    #[inline(always)]
+    fn active(&self) -> bool {
+        self.active
+    }
+
+    fn scope(&self, _context: &SubscriptionContext) -> Scope {
+        self.filter.clone().into()
+    }
+}
+
 static UTXOS_CHANGED_SUBSCRIPTIONS: AtomicUsize = AtomicUsize::new(0);
 
 fn increment_utxos_changed_subscription_counter() -> usize {
@@ -932,6 +1018,99 @@ mod tests {
         tests.run(&context)
     }
 
+    #[test]
+    fn test_token_events_changed_mutation() {
+        let context = SubscriptionContext::new();
+
+        fn f(asset_ids: &[&str]) -> TokenEventsChangedScope {
+            TokenEventsChangedScope::new(asset_ids.iter().map(|id| id.to_string()).collect(), vec![], vec![], vec![])
+        }
+        fn s(active: bool, filter: TokenEventsChangedScope) -> DynSubscription {
+            Arc::new(TokenEventsChangedSubscription { active, filter })
+        }
+        fn m(command: Command, filter: TokenEventsChangedScope) -> Mutation {
+            Mutation { command, scope: Scope::TokenEventsChanged(filter) }
+        }
+
+        // Subscriptions
+        let none = || s(false, f(&[]));
+        let all = || s(true, f(&[]));
+        let selected_a = || s(true, f(&["aa"]));
+        let selected_ab = || s(true, f(&["aa", "bb"]));
+
+        // Mutations
+        let start_all = || m(Command::Start, f(&[]));
+        let stop_all = || m(Command::Stop, f(&[]));
+        let start_a = || m(Command::Start, f(&["aa"]));
+        let stop_a = || m(Command::Stop, f(&["aa"]));
+        let start_ab = || m(Command::Start, f(&["aa", "bb"]));
+        let stop_ab = || m(Command::Stop, f(&["aa", "bb"]));
+
+        // Tests
+        let tests = MutationTests::new(vec![
+            MutationTest {
+                name: "TokenEventsChangedSubscription None to All",
+                state: none(),
+                mutation: start_all(),
+                new_state: all(),
+                outcome: MutationOutcome::with_mutated(all(), vec![start_all()]),
+            },
+            MutationTest {
+                name: "TokenEventsChangedSubscription None to Selected a (unnormalized)",
+                state: none(),
+                mutation: m(
+                    Command::Start,
+                    TokenEventsChangedScope { asset_ids: vec!["AA".to_string(), "aa".to_string()], ..Default::default() },
+                ),
+                new_state: selected_a(),
+                outcome: MutationOutcome::with_mutated(selected_a(), vec![start_a()]),
+            },
+            MutationTest {
+                name: "TokenEventsChangedSubscription None to None",
+                state: none(),
+                mutation: stop_a(),
+                new_state: none(),
+                outcome: MutationOutcome::new(),
+            },
+            MutationTest {
+                name: "TokenEventsChangedSubscription Selected a to Selected a",
+                state: selected_a(),
+                mutation: start_a(),
+                new_state: selected_a(),
+                outcome: MutationOutcome::new(),
+            },
+            MutationTest {
+                name: "TokenEventsChangedSubscription Selected a to Selected ab",
+                state: selected_a(),
+                mutation: start_ab(),
+                new_state: selected_ab(),
+                outcome: MutationOutcome::with_mutated(selected_ab(), vec![stop_a(), start_ab()]),
+            },
+            MutationTest {
+                name: "TokenEventsChangedSubscription Selected ab to All",
+                state: selected_ab(),
+                mutation: start_all(),
+                new_state: all(),
+                outcome: MutationOutcome::with_mutated(all(), vec![stop_ab(), start_all()]),
+            },
+            MutationTest {
+                name: "TokenEventsChangedSubscription Selected ab to None (stop other scope)",
+                state: selected_ab(),
+                mutation: stop_all(),
+                new_state: none(),
+                outcome: MutationOutcome::with_mutated(none(), vec![stop_ab()]),
+            },
+            MutationTest {
+                name: "TokenEventsChangedSubscription All to None",
+                state: all(),
+                mutation: stop_all(),
+                new_state: none(),
+                outcome: MutationOutcome::with_mutated(none(), vec![stop_all()]),
+            },
+        ]);
+        tests.run(&context)
+    }
+
     #[test]
     fn test_utxos_changed_mutation() {
         let context = SubscriptionContext::new();
@@ -4,7 +4,10 @@ use cryptix_notify::{
     notification::{full_featured, Notification as NotificationTrait},
     subscription::{
         context::SubscriptionContext,
-        single::{OverallSubscription, UtxosChangedSubscription, VirtualChainChangedSubscription},
+        single::{
+            FastIntentStatusChangedSubscription, MessagesReceivedSubscription, OverallSubscription, TokenEventsChangedSubscription,
+            UtxosChangedSubscription, VirtualChainChangedSubscription,
+        },
         Subscription,
     },
 };
@@ -47,6 +50,12 @@ pub enum Notification {
 
     #[display(fmt = "TokenEventsChanged notification: sequences {}..{}, events {}", "_0.from_sequence", "_0.to_sequence", "_0.event_count")]
     TokenEventsChanged(TokenEventsChangedNotification),
+
+    #[display(fmt = "FastIntentStatusChanged notification: intent {} {:?}", "_0.intent_id", "_0.status")]
+    FastIntentStatusChanged(FastIntentStatusChangedNotification),
+
+    #[display(fmt = "MessagesReceived notification: {} message(s)", "_0.entries.len()")]
+    MessagesReceived(MessagesReceivedNotification),
 }
 }
 
@@ -64,6 +73,8 @@ impl Notification {
             Notification::SinkBlueScoreChanged(v) => to_value(&v),
             Notification::VirtualChainChanged(v) => to_value(&v),
             Notification::TokenEventsChanged(v) => to_value(&v),
+            Notification::FastIntentStatusChanged(v) => to_value(&v),
+            Notification::MessagesReceived(v) => to_value(&v),
         }
     }
 }
@@ -112,6 +123,48 @@ impl NotificationTrait for Notification {
         }
     }
 
+    fn apply_token_events_changed_subscription(
+        &self,
+        subscription: &TokenEventsChangedSubscription,
+        _context: &SubscriptionContext,
+    ) -> Option<Self> {
+        match subscription.active() {
+            true => {
+                let Self::TokenEventsChanged(notification) = self else { return None };
+                notification.apply_token_events_changed_subscription(subscription).map(Self::TokenEventsChanged)
+            }
+            false => None,
+        }
+    }
+
+    fn apply_fast_intent_status_changed_subscription(
+        &self,
+        subscription: &FastIntentStatusChangedSubscription,
+        _context: &SubscriptionContext,
+    ) -> Option<Self> {
+        match subscription.active() {
+            true => {
+                let Self::FastIntentStatusChanged(notification) = self else { return None };
+                notification.apply_fast_intent_status_changed_subscription(subscription).map(Self::FastIntentStatusChanged)
+            }
+            false => None,
+        }
+    }
+
+    fn apply_messages_received_subscription(
+        &self,
+        subscription: &MessagesReceivedSubscription,
+        _context: &SubscriptionContext,
+    ) -> Option<Self> {
+        match subscription.active() {
+            true => {
+                let Self::MessagesReceived(notification) = self else { return None };
+                notification.apply_messages_received_subscription(subscription).map(Self::MessagesReceived)
+            }
+            false => None,
+        }
+    }
+
     fn event_type(&self) -> EventType {
         self.into()
     }
@@ -161,6 +214,14 @@ impl Serializer for Notification {
                 store!(u16, &9, writer)?;
                 serialize!(TokenEventsChangedNotification, notification, writer)?;
             }
+            Notification::FastIntentStatusChanged(notification) => {
+                store!(u16, &10, writer)?;
+                serialize!(FastIntentStatusChangedNotification, notification, writer)?;
+            }
+            Notification::MessagesReceived(notification) => {
+                store!(u16, &11, writer)?;
+                serialize!(MessagesReceivedNotification, notification, writer)?;
+            }
         }
         Ok(())
     }
@@ -210,6 +271,14 @@ impl Deserializer for Notification {
                 let notification = deserialize!(TokenEventsChangedNotification, reader)?;
                 Ok(Notification::TokenEventsChanged(notification))
             }
+            10 => {
+                let notification = deserialize!(FastIntentStatusChangedNotification, reader)?;
+                Ok(Notification::FastIntentStatusChanged(notification))
+            }
+            11 => {
+                let notification = deserialize!(MessagesReceivedNotification, reader)?;
+                Ok(Notification::MessagesReceived(notification))
+            }
             _ => Err(std::io::Error::new(std::io::ErrorKind::InvalidData, "Invalid variant")),
         }
     }
@@ -37,6 +37,8 @@ pub enum RpcApiOps {
     NotifyVirtualChainChanged = 17,
     NotifySinkBlueScoreChanged = 18,
     NotifyTokenEvents = 19,
+    NotifyFastIntentStatus = 20,
+    NotifyMessagesReceived = 21,
 
     // Notification ops required by wRPC
 
@@ -52,6 +54,8 @@ pub enum RpcApiOps {
     PruningPointUtxoSetOverrideNotification = 67,
     NewBlockTemplateNotification = 68,
     TokenEventsChangedNotification = 69,
+    FastIntentStatusChangedNotification = 70,
+    MessagesReceivedNotification = 71,
 
     // RPC methods
     /// Ping the node to check if connection is alive
@@ -198,11 +202,31 @@ pub enum RpcApiOps {
     GetTransactionsByIds = 180,
     /// Get Cryptix Atomic next nonce for owner-scope create operations.
     GetOwnerNonce = 181,
+    /// Get Cryptix Atomic balance and nonce inclusion proofs against the Atomic state root.
+    GetTokenBalanceProof = 182,
+    /// Get a Cryptix Atomic asset inclusion proof against the Atomic state root.
+    GetTokenAssetProof = 183,
+    /// Get the retained metadata revision history of a Cryptix Atomic asset.
+    GetTokenAssetHistory = 184,
+    /// Get the reserve and share state of a Cryptix Atomic pair pool.
+    GetPairPool = 185,
+    /// Quote an exact-in or exact-out swap against a Cryptix Atomic pair pool.
+    GetPairQuote = 186,
+    /// Get OHLC candles built from the retained trades of a Cryptix Atomic liquidity pool.
+    GetLiquidityCandles = 187,
+    /// Export Cryptix Atomic token state at a block to the documented JSONL analytics format.
+    ExportTokenAnalytics = 188,
 
     GetSpendableBalancesByAddresses = 190,
     GetTransactionMassEstimate = 191,
     ValidateTransaction = 192,
     GetTransactionStatus = 193,
+    /// Get the HFA fast rail configuration in effect.
+    GetHfaConfig = 194,
+    /// Change HFA fast rail limits or pause/resume the rail at runtime (unsafe RPC).
+    SetHfaConfig = 195,
+    /// Get the CXM messages indexed for a recipient tag (requires the message index).
+    GetMessagesByRecipientTag = 196,
 }
 
 impl RpcApiOps {
@@ -219,6 +243,8 @@ impl RpcApiOps {
                 | RpcApiOps::NotifySinkBlueScoreChanged
                 | RpcApiOps::NotifyVirtualDaaScoreChanged
                 | RpcApiOps::NotifyTokenEvents
+                | RpcApiOps::NotifyFastIntentStatus
+                | RpcApiOps::NotifyMessagesReceived
                 | RpcApiOps::Subscribe
                 | RpcApiOps::Unsubscribe
         )
@@ -246,6 +272,8 @@ impl From<EventType> for RpcApiOps {
             EventType::PruningPointUtxoSetOverride => RpcApiOps::PruningPointUtxoSetOverrideNotification,
             EventType::NewBlockTemplate => RpcApiOps::NewBlockTemplateNotification,
             EventType::TokenEventsChanged => RpcApiOps::TokenEventsChangedNotification,
+            EventType::FastIntentStatusChanged => RpcApiOps::FastIntentStatusChangedNotification,
+            EventType::MessagesReceived => RpcApiOps::MessagesReceivedNotification,
         }
     }
 }
@@ -69,6 +69,7 @@ pub trait RpcApi: Sync + Send + AnySync {
                 consensus_metrics,
                 storage_metrics,
                 custom_metrics,
+                hfa_metrics: false,
             },
         )
         .await
@@ -168,6 +169,81 @@ pub trait RpcApi: Sync + Send + AnySync {
         Err(crate::RpcError::NotImplemented)
     }
 
+    // Get a Cryptix Atomic balance and asset-scope nonce with inclusion proofs against the Atomic indexer state root.
+    // The indexer root is not committed by consensus; request the consensus balance anchor to check the balance
+    // against GetConsensusAtomicStateHash at the same block.
+    async fn get_token_balance_proof(&self, request: GetTokenBalanceProofRequest) -> RpcResult<GetTokenBalanceProofResponse> {
+        self.get_token_balance_proof_call(None, request).await
+    }
+    async fn get_token_balance_proof_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        _request: GetTokenBalanceProofRequest,
+    ) -> RpcResult<GetTokenBalanceProofResponse> {
+        Err(crate::RpcError::NotImplemented)
+    }
+
+    // Get a Cryptix Atomic asset with an inclusion (or absence) proof against the Atomic indexer state root.
+    // The indexer root is not committed by consensus; clients must cross-check it between nodes.
+    async fn get_token_asset_proof(&self, request: GetTokenAssetProofRequest) -> RpcResult<GetTokenAssetProofResponse> {
+        self.get_token_asset_proof_call(None, request).await
+    }
+    async fn get_token_asset_proof_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        _request: GetTokenAssetProofRequest,
+    ) -> RpcResult<GetTokenAssetProofResponse> {
+        Err(crate::RpcError::NotImplemented)
+    }
+
+    // Get the retained metadata revisions applied to a Cryptix Atomic asset by UpdateMetadata.
+    async fn get_token_asset_history(&self, request: GetTokenAssetHistoryRequest) -> RpcResult<GetTokenAssetHistoryResponse> {
+        self.get_token_asset_history_call(None, request).await
+    }
+    async fn get_token_asset_history_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        _request: GetTokenAssetHistoryRequest,
+    ) -> RpcResult<GetTokenAssetHistoryResponse> {
+        Err(crate::RpcError::NotImplemented)
+    }
+
+    // Get the reserve and share state of a Cryptix Atomic pair pool.
+    async fn get_pair_pool(&self, request: GetPairPoolRequest) -> RpcResult<GetPairPoolResponse> {
+        self.get_pair_pool_call(None, request).await
+    }
+    async fn get_pair_pool_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        _request: GetPairPoolRequest,
+    ) -> RpcResult<GetPairPoolResponse> {
+        Err(crate::RpcError::NotImplemented)
+    }
+
+    // Quote an exact-in or exact-out swap against a Cryptix Atomic pair pool.
+    async fn get_pair_quote(&self, request: GetPairQuoteRequest) -> RpcResult<GetPairQuoteResponse> {
+        self.get_pair_quote_call(None, request).await
+    }
+    async fn get_pair_quote_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        _request: GetPairQuoteRequest,
+    ) -> RpcResult<GetPairQuoteResponse> {
+        Err(crate::RpcError::NotImplemented)
+    }
+
+    // Get OHLC candles built from the retained trades of a Cryptix Atomic liquidity pool.
+    async fn get_liquidity_candles(&self, request: GetLiquidityCandlesRequest) -> RpcResult<GetLiquidityCandlesResponse> {
+        self.get_liquidity_candles_call(None, request).await
+    }
+    async fn get_liquidity_candles_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        _request: GetLiquidityCandlesRequest,
+    ) -> RpcResult<GetLiquidityCandlesResponse> {
+        Err(crate::RpcError::NotImplemented)
+    }
+
     async fn get_token_op_status(&self, request: GetTokenOpStatusRequest) -> RpcResult<GetTokenOpStatusResponse> {
         self.get_token_op_status_call(None, request).await
     }
@@ -223,6 +299,18 @@ pub trait RpcApi: Sync + Send + AnySync {
         Err(crate::RpcError::NotImplemented)
     }
 
+    // Export Cryptix Atomic token state at a block to the documented JSONL analytics format.
+    async fn export_token_analytics(&self, path: String, at_block_hash: Option<RpcHash>) -> RpcResult<ExportTokenAnalyticsResponse> {
+        self.export_token_analytics_call(None, ExportTokenAnalyticsRequest { path, at_block_hash }).await
+    }
+    async fn export_token_analytics_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        _request: ExportTokenAnalyticsRequest,
+    ) -> RpcResult<ExportTokenAnalyticsResponse> {
+        Err(crate::RpcError::NotImplemented)
+    }
+
     async fn import_token_snapshot(&self, path: String) -> RpcResult<ImportTokenSnapshotResponse> {
         self.import_token_snapshot_call(None, ImportTokenSnapshotRequest { path }).await
     }
@@ -597,6 +685,45 @@ pub trait RpcApi: Sync + Send + AnySync {
         Err(crate::RpcError::NotImplemented)
     }
 
+    /// Returns the HFA fast rail configuration in effect.
+    async fn get_hfa_config(&self) -> RpcResult<GetHfaConfigResponse> {
+        self.get_hfa_config_call(None, GetHfaConfigRequest {}).await
+    }
+    async fn get_hfa_config_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        _request: GetHfaConfigRequest,
+    ) -> RpcResult<GetHfaConfigResponse> {
+        Err(crate::RpcError::NotImplemented)
+    }
+
+    /// Changes HFA fast rail limits or pauses/resumes the rail without a restart.
+    async fn set_hfa_config(&self, request: SetHfaConfigRequest) -> RpcResult<SetHfaConfigResponse> {
+        self.set_hfa_config_call(None, request).await
+    }
+    async fn set_hfa_config_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        _request: SetHfaConfigRequest,
+    ) -> RpcResult<SetHfaConfigResponse> {
+        Err(crate::RpcError::NotImplemented)
+    }
+
+    /// Returns the CXM messages indexed for a recipient tag, optionally narrowed to a sender, after a sequence cursor.
+    async fn get_messages_by_recipient_tag(
+        &self,
+        request: GetMessagesByRecipientTagRequest,
+    ) -> RpcResult<GetMessagesByRecipientTagResponse> {
+        self.get_messages_by_recipient_tag_call(None, request).await
+    }
+    async fn get_messages_by_recipient_tag_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        _request: GetMessagesByRecipientTagRequest,
+    ) -> RpcResult<GetMessagesByRecipientTagResponse> {
+        Err(crate::RpcError::NotImplemented)
+    }
+
     /// Requests information about a specific block.
     async fn get_block(&self, hash: RpcHash, include_transactions: bool) -> RpcResult<RpcBlock> {
         Ok(self.get_block_call(None, GetBlockRequest::new(hash, include_transactions)).await?.block)
@@ -825,14 +952,8 @@ pub trait RpcApi: Sync + Send + AnySync {
     ) -> RpcResult<GetDaaScoreTimestampEstimateResponse>;
 
     // Defaulted to NotImplemented so only the core service has to implement them.
-    async fn get_spendable_balances_by_addresses(
-        &self,
-        addresses: Vec<RpcAddress>,
-    ) -> RpcResult<Vec<RpcSpendableBalanceEntry>> {
-        Ok(self
-            .get_spendable_balances_by_addresses_call(None, GetSpendableBalancesByAddressesRequest { addresses })
-            .await?
-            .entries)
+    async fn get_spendable_balances_by_addresses(&self, addresses: Vec<RpcAddress>) -> RpcResult<Vec<RpcSpendableBalanceEntry>> {
+        Ok(self.get_spendable_balances_by_addresses_call(None, GetSpendableBalancesByAddressesRequest { addresses }).await?.entries)
     }
     async fn get_spendable_balances_by_addresses_call(
         &self,
@@ -1,7 +1,7 @@
 use crate::{
-    NotifyBlockAddedRequest, NotifyFinalityConflictRequest, NotifyNewBlockTemplateRequest, NotifyPruningPointUtxoSetOverrideRequest,
-    NotifySinkBlueScoreChangedRequest, NotifyTokenEventsRequest, NotifyUtxosChangedRequest, NotifyVirtualChainChangedRequest,
-    NotifyVirtualDaaScoreChangedRequest,
+    NotifyBlockAddedRequest, NotifyFastIntentStatusRequest, NotifyFinalityConflictRequest, NotifyMessagesReceivedRequest,
+    NotifyNewBlockTemplateRequest, NotifyPruningPointUtxoSetOverrideRequest, NotifySinkBlueScoreChangedRequest,
+    NotifyTokenEventsRequest, NotifyUtxosChangedRequest, NotifyVirtualChainChangedRequest, NotifyVirtualDaaScoreChangedRequest,
 };
 use cryptix_notify::scope::*;
 
@@ -62,8 +62,8 @@ from!(PruningPointUtxoSetOverride);
 from!(NewBlockTemplate);
 
 impl From<NotifyTokenEventsRequest> for TokenEventsChangedScope {
-    fn from(_: NotifyTokenEventsRequest) -> Self {
-        Self {}
+    fn from(item: NotifyTokenEventsRequest) -> Self {
+        Self::new(item.asset_ids, item.owner_ids, item.op_types, item.apply_statuses)
     }
 }
 
@@ -72,3 +72,27 @@ impl From<NotifyTokenEventsRequest> for Scope {
         TokenEventsChangedScope::from(item).into()
     }
 }
+
+impl From<NotifyFastIntentStatusRequest> for FastIntentStatusChangedScope {
+    fn from(item: NotifyFastIntentStatusRequest) -> Self {
+        Self::new(item.intent_ids, item.addresses)
+    }
+}
+
+impl From<NotifyFastIntentStatusRequest> for Scope {
+    fn from(item: NotifyFastIntentStatusRequest) -> Self {
+        FastIntentStatusChangedScope::from(item).into()
+    }
+}
+
+impl From<NotifyMessagesReceivedRequest> for MessagesReceivedScope {
+    fn from(item: NotifyMessagesReceivedRequest) -> Self {
+        Self::new(item.recipient_tags)
+    }
+}
+
+impl From<NotifyMessagesReceivedRequest> for Scope {
+    fn from(item: NotifyMessagesReceivedRequest) -> Self {
+        MessagesReceivedScope::from(item).into()
+    }
+}
@@ -59,6 +59,9 @@ pub enum RpcError {
     #[error("Method unavailable. Enable the UTXO index for this node.")]
     NoUtxoIndex,
 
+    #[error("Method unavailable. Enable the message index for this node.")]
+    NoMessageIndex,
+
     #[error("ERR_STALE_CONTEXT")]
     StaleContext,
 
@@ -2,7 +2,13 @@ use crate::model::*;
 use borsh::{BorshDeserialize, BorshSerialize};
 use cryptix_consensus_core::api::stats::BlockCount;
 use cryptix_core::debug;
-use cryptix_notify::subscription::{context::SubscriptionContext, single::UtxosChangedSubscription, Command};
+use cryptix_notify::subscription::{
+    context::SubscriptionContext,
+    single::{
+        FastIntentStatusChangedSubscription, MessagesReceivedSubscription, TokenEventsChangedSubscription, UtxosChangedSubscription,
+    },
+    Command,
+};
 use cryptix_utils::hex::ToHex;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -2073,11 +2079,18 @@ pub struct GetMetricsRequest {
     pub consensus_metrics: bool,
     pub storage_metrics: bool,
     pub custom_metrics: bool,
+    pub hfa_metrics: bool,
 }
 
 impl Serializer for GetMetricsRequest {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
-        let version: u16 = if self.custom_metrics { 2 } else { 1 };
+        let version: u16 = if self.hfa_metrics {
+            3
+        } else if self.custom_metrics {
+            2
+        } else {
+            1
+        };
         store!(u16, &version, writer)?;
         store!(bool, &self.process_metrics, writer)?;
         store!(bool, &self.connection_metrics, writer)?;
@@ -2087,6 +2100,9 @@ impl Serializer for GetMetricsRequest {
         if version >= 2 {
             store!(bool, &self.custom_metrics, writer)?;
         }
+        if version >= 3 {
+            store!(bool, &self.hfa_metrics, writer)?;
+        }
 
         Ok(())
     }
@@ -2101,8 +2117,17 @@ impl Deserializer for GetMetricsRequest {
         let consensus_metrics = load!(bool, reader)?;
         let storage_metrics = load!(bool, reader)?;
         let custom_metrics = if version >= 2 { load!(bool, reader)? } else { false };
+        let hfa_metrics = if version >= 3 { load!(bool, reader)? } else { false };
 
-        Ok(Self { process_metrics, connection_metrics, bandwidth_metrics, consensus_metrics, storage_metrics, custom_metrics })
+        Ok(Self {
+            process_metrics,
+            connection_metrics,
+            bandwidth_metrics,
+            consensus_metrics,
+            storage_metrics,
+            custom_metrics,
+            hfa_metrics,
+        })
     }
 }
 
@@ -2377,6 +2402,95 @@ impl Deserializer for StorageMetrics {
     }
 }
 
+/// Operating mode of the HFA fast rail.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "snake_case")]
+#[borsh(use_discriminant = true)]
+pub enum RpcHfaMode {
+    #[default]
+    Normal = 0,
+    Degraded = 1,
+    Paused = 2,
+}
+
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HfaMetrics {
+    pub enabled: bool,
+    pub operator_paused: bool,
+    pub mode: RpcHfaMode,
+    pub submitted_count: u64,
+    pub fast_confirmed_count: u64,
+    pub rejected_count: u64,
+    pub expired_count: u64,
+    pub dropped_count: u64,
+    pub pending_intents: u64,
+    pub active_locks: u64,
+    pub lock_conflict_count: u64,
+    pub confirm_latency_ms_p95: f64,
+    pub mode_transition_count: u64,
+    pub fast_arbiter_queue_depth: u64,
+}
+
+impl Serializer for HfaMetrics {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(bool, &self.enabled, writer)?;
+        store!(bool, &self.operator_paused, writer)?;
+        store!(RpcHfaMode, &self.mode, writer)?;
+        store!(u64, &self.submitted_count, writer)?;
+        store!(u64, &self.fast_confirmed_count, writer)?;
+        store!(u64, &self.rejected_count, writer)?;
+        store!(u64, &self.expired_count, writer)?;
+        store!(u64, &self.dropped_count, writer)?;
+        store!(u64, &self.pending_intents, writer)?;
+        store!(u64, &self.active_locks, writer)?;
+        store!(u64, &self.lock_conflict_count, writer)?;
+        store!(f64, &self.confirm_latency_ms_p95, writer)?;
+        store!(u64, &self.mode_transition_count, writer)?;
+        store!(u64, &self.fast_arbiter_queue_depth, writer)?;
+
+        Ok(())
+    }
+}
+
+impl Deserializer for HfaMetrics {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let enabled = load!(bool, reader)?;
+        let operator_paused = load!(bool, reader)?;
+        let mode = load!(RpcHfaMode, reader)?;
+        let submitted_count = load!(u64, reader)?;
+        let fast_confirmed_count = load!(u64, reader)?;
+        let rejected_count = load!(u64, reader)?;
+        let expired_count = load!(u64, reader)?;
+        let dropped_count = load!(u64, reader)?;
+        let pending_intents = load!(u64, reader)?;
+        let active_locks = load!(u64, reader)?;
+        let lock_conflict_count = load!(u64, reader)?;
+        let confirm_latency_ms_p95 = load!(f64, reader)?;
+        let mode_transition_count = load!(u64, reader)?;
+        let fast_arbiter_queue_depth = load!(u64, reader)?;
+
+        Ok(Self {
+            enabled,
+            operator_paused,
+            mode,
+            submitted_count,
+            fast_confirmed_count,
+            rejected_count,
+            expired_count,
+            dropped_count,
+            pending_intents,
+            active_locks,
+            lock_conflict_count,
+            confirm_latency_ms_p95,
+            mode_transition_count,
+            fast_arbiter_queue_depth,
+        })
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum CustomMetricValue {
     Placeholder,
@@ -2444,6 +2558,7 @@ pub struct GetMetricsResponse {
     pub storage_metrics: Option<StorageMetrics>,
     // Optional implementation-defined custom metrics map.
     pub custom_metrics: Option<HashMap<String, CustomMetricValue>>,
+    pub hfa_metrics: Option<HfaMetrics>,
 }
 
 impl GetMetricsResponse {
@@ -2455,6 +2570,7 @@ impl GetMetricsResponse {
         consensus_metrics: Option<ConsensusMetrics>,
         storage_metrics: Option<StorageMetrics>,
         custom_metrics: Option<HashMap<String, CustomMetricValue>>,
+        hfa_metrics: Option<HfaMetrics>,
     ) -> Self {
         Self {
             process_metrics,
@@ -2464,13 +2580,20 @@ impl GetMetricsResponse {
             storage_metrics,
             server_time,
             custom_metrics,
+            hfa_metrics,
         }
     }
 }
 
 impl Serializer for GetMetricsResponse {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
-        let version: u16 = if self.custom_metrics.is_some() { 2 } else { 1 };
+        let version: u16 = if self.hfa_metrics.is_some() {
+            3
+        } else if self.custom_metrics.is_some() {
+            2
+        } else {
+            1
+        };
         store!(u16, &version, writer)?;
         store!(u64, &self.server_time, writer)?;
         serialize!(Option<ProcessMetrics>, &self.process_metrics, writer)?;
@@ -2481,6 +2604,9 @@ impl Serializer for GetMetricsResponse {
         if version >= 2 {
             serialize!(Option<HashMap<String, CustomMetricValue>>, &self.custom_metrics, writer)?;
         }
+        if version >= 3 {
+            serialize!(Option<HfaMetrics>, &self.hfa_metrics, writer)?;
+        }
 
         Ok(())
     }
@@ -2496,6 +2622,7 @@ impl Deserializer for GetMetricsResponse {
         let consensus_metrics = deserialize!(Option<ConsensusMetrics>, reader)?;
         let storage_metrics = deserialize!(Option<StorageMetrics>, reader)?;
         let custom_metrics = if version >= 2 { deserialize!(Option<HashMap<String, CustomMetricValue>>, reader)? } else { None };
+        let hfa_metrics = if version >= 3 { deserialize!(Option<HfaMetrics>, reader)? } else { None };
 
         Ok(Self {
             server_time,
@@ -2505,6 +2632,7 @@ impl Deserializer for GetMetricsResponse {
             consensus_metrics,
             storage_metrics,
             custom_metrics,
+            hfa_metrics,
         })
     }
 }
@@ -2858,24 +2986,28 @@ impl Deserializer for GetTokenBalanceRequest {
 #[serde(rename_all = "camelCase")]
 pub struct GetTokenBalanceResponse {
     pub balance: String,
+    /// Part of `balance` still held by a vesting lock at the context DAA score.
+    pub locked_balance: String,
     pub context: RpcTokenContext,
 }
 
 impl Serializer for GetTokenBalanceResponse {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
-        store!(u16, &1, writer)?;
+        store!(u16, &2, writer)?;
         store!(String, &self.balance, writer)?;
         store!(RpcTokenContext, &self.context, writer)?;
+        store!(String, &self.locked_balance, writer)?;
         Ok(())
     }
 }
 
 impl Deserializer for GetTokenBalanceResponse {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
-        let _version = load!(u16, reader)?;
+        let version = load!(u16, reader)?;
         let balance = load!(String, reader)?;
         let context = load!(RpcTokenContext, reader)?;
-        Ok(Self { balance, context })
+        let locked_balance = if version >= 2 { load!(String, reader)? } else { "0".to_string() };
+        Ok(Self { balance, locked_balance, context })
     }
 }
 
@@ -3089,312 +3221,718 @@ impl Deserializer for GetTokenAssetResponse {
     }
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+/// Full preimage of a leaf committed in the Atomic state root.
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct GetTokenOpStatusRequest {
-    pub txid: RpcHash,
-    pub at_block_hash: Option<RpcHash>,
+pub struct RpcTokenStateLeaf {
+    pub logical_key: String,
+    pub value: String,
 }
 
-impl Serializer for GetTokenOpStatusRequest {
+impl Serializer for RpcTokenStateLeaf {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
-        store!(u16, &2, writer)?;
-        store!(RpcHash, &self.txid, writer)?;
-        store!(Option<RpcHash>, &self.at_block_hash, writer)?;
+        store!(u16, &1, writer)?;
+        store!(String, &self.logical_key, writer)?;
+        store!(String, &self.value, writer)?;
         Ok(())
     }
 }
 
-impl Deserializer for GetTokenOpStatusRequest {
+impl Deserializer for RpcTokenStateLeaf {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
-        let version = load!(u16, reader)?;
-        let txid = load!(RpcHash, reader)?;
-        let at_block_hash = if version >= 2 { load!(Option<RpcHash>, reader)? } else { None };
-        Ok(Self { txid, at_block_hash })
+        let _version = load!(u16, reader)?;
+        let logical_key = load!(String, reader)?;
+        let value = load!(String, reader)?;
+        Ok(Self { logical_key, value })
     }
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+/// Bucket inclusion proof of a single logical key against the Atomic state root
+/// reported in [`RpcTokenContext::state_hash`]. A missing `value` proves absence.
+///
+/// The root is computed by the node's Atomic indexer and is not committed by consensus.
+/// Balances are checked against `GetConsensusAtomicStateHash` with [`RpcTokenConsensusBalanceAnchor`].
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct GetTokenOpStatusResponse {
-    pub accepting_block_hash: Option<RpcHash>,
-    pub apply_status: Option<u32>,
-    pub noop_reason: Option<u32>,
-    pub context: RpcTokenContext,
+pub struct RpcTokenStateProof {
+    pub logical_key: String,
+    pub value: Option<String>,
+    pub bucket_index: u32,
+    pub bucket_siblings: Vec<RpcTokenStateLeaf>,
+    pub bucket_hashes: Vec<String>,
+    pub state_root: String,
 }
 
-impl Serializer for GetTokenOpStatusResponse {
+impl Serializer for RpcTokenStateProof {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         store!(u16, &1, writer)?;
-        store!(Option<RpcHash>, &self.accepting_block_hash, writer)?;
-        store!(Option<u32>, &self.apply_status, writer)?;
-        store!(Option<u32>, &self.noop_reason, writer)?;
-        store!(RpcTokenContext, &self.context, writer)?;
+        store!(String, &self.logical_key, writer)?;
+        store!(Option<String>, &self.value, writer)?;
+        store!(u32, &self.bucket_index, writer)?;
+        store!(Vec<RpcTokenStateLeaf>, &self.bucket_siblings, writer)?;
+        store!(Vec<String>, &self.bucket_hashes, writer)?;
+        store!(String, &self.state_root, writer)?;
         Ok(())
     }
 }
 
-impl Deserializer for GetTokenOpStatusResponse {
+impl Deserializer for RpcTokenStateProof {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
         let _version = load!(u16, reader)?;
-        let accepting_block_hash = load!(Option<RpcHash>, reader)?;
-        let apply_status = load!(Option<u32>, reader)?;
-        let noop_reason = load!(Option<u32>, reader)?;
-        let context = load!(RpcTokenContext, reader)?;
-        Ok(Self { accepting_block_hash, apply_status, noop_reason, context })
+        let logical_key = load!(String, reader)?;
+        let value = load!(Option<String>, reader)?;
+        let bucket_index = load!(u32, reader)?;
+        let bucket_siblings = load!(Vec<RpcTokenStateLeaf>, reader)?;
+        let bucket_hashes = load!(Vec<String>, reader)?;
+        let state_root = load!(String, reader)?;
+        Ok(Self { logical_key, value, bucket_index, bucket_siblings, bucket_hashes, state_root })
     }
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+/// Entry count and XOR of the entry hashes of one namespace of the consensus Atomic state accumulator.
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct GetTokenStateHashRequest {
-    pub at_block_hash: Option<RpcHash>,
+pub struct RpcTokenConsensusDigest {
+    pub count: u64,
+    pub xor: String,
 }
 
-impl Serializer for GetTokenStateHashRequest {
+impl Serializer for RpcTokenConsensusDigest {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
-        store!(u16, &2, writer)?;
-        store!(Option<RpcHash>, &self.at_block_hash, writer)?;
+        store!(u16, &1, writer)?;
+        store!(u64, &self.count, writer)?;
+        store!(String, &self.xor, writer)?;
         Ok(())
     }
 }
 
-impl Deserializer for GetTokenStateHashRequest {
+impl Deserializer for RpcTokenConsensusDigest {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
-        let version = load!(u16, reader)?;
-        let at_block_hash = if version >= 2 { load!(Option<RpcHash>, reader)? } else { None };
-        Ok(Self { at_block_hash })
+        let _version = load!(u16, reader)?;
+        let count = load!(u64, reader)?;
+        let xor = load!(String, reader)?;
+        Ok(Self { count, xor })
     }
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct GetTokenStateHashResponse {
-    pub context: RpcTokenContext,
+pub struct RpcTokenConsensusBalance {
+    pub asset_id: String,
+    pub owner_id: String,
+    pub amount: String,
 }
 
-impl Serializer for GetTokenStateHashResponse {
+impl Serializer for RpcTokenConsensusBalance {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         store!(u16, &1, writer)?;
-        store!(RpcTokenContext, &self.context, writer)?;
+        store!(String, &self.asset_id, writer)?;
+        store!(String, &self.owner_id, writer)?;
+        store!(String, &self.amount, writer)?;
         Ok(())
     }
 }
 
-impl Deserializer for GetTokenStateHashResponse {
+impl Deserializer for RpcTokenConsensusBalance {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
         let _version = load!(u16, reader)?;
-        let context = load!(RpcTokenContext, reader)?;
-        Ok(Self { context })
+        let asset_id = load!(String, reader)?;
+        let owner_id = load!(String, reader)?;
+        let amount = load!(String, reader)?;
+        Ok(Self { asset_id, owner_id, amount })
+    }
+}
+
+/// Every balance of the consensus Atomic state at [`RpcTokenContext::at_block_hash`] together with
+/// the digests of the other namespaces. Hashing the balances back into the accumulator recomputes the
+/// `GetConsensusAtomicStateHash` value of that block; a balance missing from the anchor is zero.
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcTokenConsensusBalanceAnchor {
+    pub version: u32,
+    pub nonces: RpcTokenConsensusDigest,
+    pub assets: RpcTokenConsensusDigest,
+    pub anchor_counts: RpcTokenConsensusDigest,
+    pub allowances: RpcTokenConsensusDigest,
+    pub locks: RpcTokenConsensusDigest,
+    pub balances: Vec<RpcTokenConsensusBalance>,
+}
+
+impl Serializer for RpcTokenConsensusBalanceAnchor {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(u32, &self.version, writer)?;
+        store!(RpcTokenConsensusDigest, &self.nonces, writer)?;
+        store!(RpcTokenConsensusDigest, &self.assets, writer)?;
+        store!(RpcTokenConsensusDigest, &self.anchor_counts, writer)?;
+        store!(RpcTokenConsensusDigest, &self.allowances, writer)?;
+        store!(RpcTokenConsensusDigest, &self.locks, writer)?;
+        store!(Vec<RpcTokenConsensusBalance>, &self.balances, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for RpcTokenConsensusBalanceAnchor {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let version = load!(u32, reader)?;
+        let nonces = load!(RpcTokenConsensusDigest, reader)?;
+        let assets = load!(RpcTokenConsensusDigest, reader)?;
+        let anchor_counts = load!(RpcTokenConsensusDigest, reader)?;
+        let allowances = load!(RpcTokenConsensusDigest, reader)?;
+        let locks = load!(RpcTokenConsensusDigest, reader)?;
+        let balances = load!(Vec<RpcTokenConsensusBalance>, reader)?;
+        Ok(Self { version, nonces, assets, anchor_counts, allowances, locks, balances })
     }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct GetTokenSpendabilityRequest {
+pub struct GetTokenBalanceProofRequest {
     pub asset_id: String,
     pub owner_id: String,
-    pub min_daa_for_spend: Option<u64>,
     pub at_block_hash: Option<RpcHash>,
+    /// Also return the consensus balance anchor, which grows with the number of balances in the state.
+    #[serde(default)]
+    pub include_consensus_anchor: bool,
 }
 
-impl Serializer for GetTokenSpendabilityRequest {
+impl Serializer for GetTokenBalanceProofRequest {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         store!(u16, &2, writer)?;
         store!(String, &self.asset_id, writer)?;
         store!(String, &self.owner_id, writer)?;
-        store!(Option<u64>, &self.min_daa_for_spend, writer)?;
         store!(Option<RpcHash>, &self.at_block_hash, writer)?;
+        store!(bool, &self.include_consensus_anchor, writer)?;
         Ok(())
     }
 }
 
-impl Deserializer for GetTokenSpendabilityRequest {
+impl Deserializer for GetTokenBalanceProofRequest {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
         let version = load!(u16, reader)?;
         let asset_id = load!(String, reader)?;
         let owner_id = load!(String, reader)?;
-        let min_daa_for_spend = load!(Option<u64>, reader)?;
-        let at_block_hash = if version >= 2 { load!(Option<RpcHash>, reader)? } else { None };
-        Ok(Self { asset_id, owner_id, min_daa_for_spend, at_block_hash })
+        let at_block_hash = load!(Option<RpcHash>, reader)?;
+        let include_consensus_anchor = if version >= 2 { load!(bool, reader)? } else { false };
+        Ok(Self { asset_id, owner_id, at_block_hash, include_consensus_anchor })
     }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct GetTokenSpendabilityResponse {
-    pub can_spend: bool,
-    pub reason: Option<String>,
+pub struct GetTokenBalanceProofResponse {
     pub balance: String,
+    pub balance_proof: RpcTokenStateProof,
     pub expected_next_nonce: u64,
-    pub min_daa_for_spend: u64,
+    pub nonce_proof: RpcTokenStateProof,
     pub context: RpcTokenContext,
+    pub consensus_anchor: Option<RpcTokenConsensusBalanceAnchor>,
 }
 
-impl Serializer for GetTokenSpendabilityResponse {
+impl Serializer for GetTokenBalanceProofResponse {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
-        store!(u16, &1, writer)?;
-        store!(bool, &self.can_spend, writer)?;
-        store!(Option<String>, &self.reason, writer)?;
+        store!(u16, &2, writer)?;
         store!(String, &self.balance, writer)?;
+        store!(RpcTokenStateProof, &self.balance_proof, writer)?;
         store!(u64, &self.expected_next_nonce, writer)?;
-        store!(u64, &self.min_daa_for_spend, writer)?;
+        store!(RpcTokenStateProof, &self.nonce_proof, writer)?;
         store!(RpcTokenContext, &self.context, writer)?;
+        store!(Option<RpcTokenConsensusBalanceAnchor>, &self.consensus_anchor, writer)?;
         Ok(())
     }
 }
 
-impl Deserializer for GetTokenSpendabilityResponse {
+impl Deserializer for GetTokenBalanceProofResponse {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
-        let _version = load!(u16, reader)?;
-        let can_spend = load!(bool, reader)?;
-        let reason = load!(Option<String>, reader)?;
+        let version = load!(u16, reader)?;
         let balance = load!(String, reader)?;
+        let balance_proof = load!(RpcTokenStateProof, reader)?;
         let expected_next_nonce = load!(u64, reader)?;
-        let min_daa_for_spend = load!(u64, reader)?;
+        let nonce_proof = load!(RpcTokenStateProof, reader)?;
         let context = load!(RpcTokenContext, reader)?;
-        Ok(Self { can_spend, reason, balance, expected_next_nonce, min_daa_for_spend, context })
+        let consensus_anchor = if version >= 2 { load!(Option<RpcTokenConsensusBalanceAnchor>, reader)? } else { None };
+        Ok(Self { balance, balance_proof, expected_next_nonce, nonce_proof, context, consensus_anchor })
     }
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct RpcTokenEvent {
-    pub event_id: String,
-    pub sequence: u64,
-    pub accepting_block_hash: RpcHash,
-    pub txid: RpcHash,
-    pub event_type: u32,
-    pub apply_status: u32,
-    pub noop_reason: u32,
-    pub ordinal: u32,
-    pub reorg_of_event_id: Option<String>,
-    pub op_type: Option<u32>,
-    pub asset_id: Option<String>,
-    pub from_owner_id: Option<String>,
-    pub to_owner_id: Option<String>,
-    pub amount: Option<String>,
+pub struct GetTokenAssetProofRequest {
+    pub asset_id: String,
+    pub at_block_hash: Option<RpcHash>,
 }
 
-impl Serializer for RpcTokenEvent {
+impl Serializer for GetTokenAssetProofRequest {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
-        store!(u16, &2, writer)?;
-        store!(String, &self.event_id, writer)?;
-        store!(u64, &self.sequence, writer)?;
-        store!(RpcHash, &self.accepting_block_hash, writer)?;
-        store!(RpcHash, &self.txid, writer)?;
-        store!(u32, &self.event_type, writer)?;
-        store!(u32, &self.apply_status, writer)?;
-        store!(u32, &self.noop_reason, writer)?;
-        store!(u32, &self.ordinal, writer)?;
-        store!(Option<String>, &self.reorg_of_event_id, writer)?;
-        store!(Option<u32>, &self.op_type, writer)?;
-        store!(Option<String>, &self.asset_id, writer)?;
-        store!(Option<String>, &self.from_owner_id, writer)?;
-        store!(Option<String>, &self.to_owner_id, writer)?;
-        store!(Option<String>, &self.amount, writer)?;
+        store!(u16, &1, writer)?;
+        store!(String, &self.asset_id, writer)?;
+        store!(Option<RpcHash>, &self.at_block_hash, writer)?;
         Ok(())
     }
 }
 
-impl Deserializer for RpcTokenEvent {
+impl Deserializer for GetTokenAssetProofRequest {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
-        let version = load!(u16, reader)?;
-        let event_id = load!(String, reader)?;
-        let sequence = load!(u64, reader)?;
-        let accepting_block_hash = load!(RpcHash, reader)?;
-        let txid = load!(RpcHash, reader)?;
-        let event_type = load!(u32, reader)?;
-        let apply_status = load!(u32, reader)?;
-        let noop_reason = load!(u32, reader)?;
-        let ordinal = load!(u32, reader)?;
-        let reorg_of_event_id = load!(Option<String>, reader)?;
-        let op_type = if version >= 2 { load!(Option<u32>, reader)? } else { None };
-        let asset_id = if version >= 2 { load!(Option<String>, reader)? } else { None };
-        let from_owner_id = if version >= 2 { load!(Option<String>, reader)? } else { None };
-        let to_owner_id = if version >= 2 { load!(Option<String>, reader)? } else { None };
-        let amount = if version >= 2 { load!(Option<String>, reader)? } else { None };
-        Ok(Self {
-            event_id,
-            sequence,
-            accepting_block_hash,
-            txid,
-            event_type,
-            apply_status,
-            noop_reason,
-            ordinal,
-            reorg_of_event_id,
-            op_type,
-            asset_id,
-            from_owner_id,
-            to_owner_id,
-            amount,
-        })
+        let _version = load!(u16, reader)?;
+        let asset_id = load!(String, reader)?;
+        let at_block_hash = load!(Option<RpcHash>, reader)?;
+        Ok(Self { asset_id, at_block_hash })
     }
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct RpcTokenOwnerBalance {
-    pub asset_id: String,
-    pub balance: String,
+pub struct GetTokenAssetProofResponse {
     pub asset: Option<RpcTokenAsset>,
+    pub proof: RpcTokenStateProof,
+    pub context: RpcTokenContext,
 }
 
-impl Serializer for RpcTokenOwnerBalance {
+impl Serializer for GetTokenAssetProofResponse {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         store!(u16, &1, writer)?;
-        store!(String, &self.asset_id, writer)?;
-        store!(String, &self.balance, writer)?;
         store!(Option<RpcTokenAsset>, &self.asset, writer)?;
+        store!(RpcTokenStateProof, &self.proof, writer)?;
+        store!(RpcTokenContext, &self.context, writer)?;
         Ok(())
     }
 }
 
-impl Deserializer for RpcTokenOwnerBalance {
+impl Deserializer for GetTokenAssetProofResponse {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
         let _version = load!(u16, reader)?;
-        let asset_id = load!(String, reader)?;
-        let balance = load!(String, reader)?;
         let asset = load!(Option<RpcTokenAsset>, reader)?;
-        Ok(Self { asset_id, balance, asset })
+        let proof = load!(RpcTokenStateProof, reader)?;
+        let context = load!(RpcTokenContext, reader)?;
+        Ok(Self { asset, proof, context })
     }
 }
 
+/// One `UpdateMetadata` revision of a Cryptix Atomic asset.
 #[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct RpcTokenHolder {
-    pub owner_id: String,
-    pub balance: String,
+pub struct RpcTokenMetadataRevision {
+    pub revision: u64,
+    pub txid: RpcHash,
+    pub previous_metadata_hex: String,
+    pub previous_platform_tag: String,
+    pub metadata_hex: String,
+    pub platform_tag: String,
+    pub updated_block_hash: RpcHash,
+    pub updated_daa_score: u64,
+    pub updated_at: u64,
 }
 
-impl Serializer for RpcTokenHolder {
+impl Serializer for RpcTokenMetadataRevision {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         store!(u16, &1, writer)?;
-        store!(String, &self.owner_id, writer)?;
-        store!(String, &self.balance, writer)?;
+        store!(u64, &self.revision, writer)?;
+        store!(RpcHash, &self.txid, writer)?;
+        store!(String, &self.previous_metadata_hex, writer)?;
+        store!(String, &self.previous_platform_tag, writer)?;
+        store!(String, &self.metadata_hex, writer)?;
+        store!(String, &self.platform_tag, writer)?;
+        store!(RpcHash, &self.updated_block_hash, writer)?;
+        store!(u64, &self.updated_daa_score, writer)?;
+        store!(u64, &self.updated_at, writer)?;
         Ok(())
     }
 }
 
-impl Deserializer for RpcTokenHolder {
+impl Deserializer for RpcTokenMetadataRevision {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
         let _version = load!(u16, reader)?;
-        let owner_id = load!(String, reader)?;
-        let balance = load!(String, reader)?;
-        Ok(Self { owner_id, balance })
+        let revision = load!(u64, reader)?;
+        let txid = load!(RpcHash, reader)?;
+        let previous_metadata_hex = load!(String, reader)?;
+        let previous_platform_tag = load!(String, reader)?;
+        let metadata_hex = load!(String, reader)?;
+        let platform_tag = load!(String, reader)?;
+        let updated_block_hash = load!(RpcHash, reader)?;
+        let updated_daa_score = load!(u64, reader)?;
+        let updated_at = load!(u64, reader)?;
+        Ok(Self {
+            revision,
+            txid,
+            previous_metadata_hex,
+            previous_platform_tag,
+            metadata_hex,
+            platform_tag,
+            updated_block_hash,
+            updated_daa_score,
+            updated_at,
+        })
     }
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct RpcLiquidityFeeRecipient {
-    pub owner_id: String,
-    pub address: String,
-    pub unclaimed_sompi: String,
+pub struct GetTokenAssetHistoryRequest {
+    pub asset_id: String,
+    pub at_block_hash: Option<RpcHash>,
 }
 
-impl Serializer for RpcLiquidityFeeRecipient {
+impl Serializer for GetTokenAssetHistoryRequest {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(String, &self.asset_id, writer)?;
+        store!(Option<RpcHash>, &self.at_block_hash, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for GetTokenAssetHistoryRequest {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let asset_id = load!(String, reader)?;
+        let at_block_hash = load!(Option<RpcHash>, reader)?;
+        Ok(Self { asset_id, at_block_hash })
+    }
+}
+
+/// Retained metadata revisions of an asset, oldest first. `total_revisions` keeps counting after the
+/// node drops the oldest revisions, so it can exceed `revisions.len()`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTokenAssetHistoryResponse {
+    pub asset: Option<RpcTokenAsset>,
+    pub total_revisions: u64,
+    pub revisions: Vec<RpcTokenMetadataRevision>,
+    pub context: RpcTokenContext,
+}
+
+impl Serializer for GetTokenAssetHistoryResponse {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(Option<RpcTokenAsset>, &self.asset, writer)?;
+        store!(u64, &self.total_revisions, writer)?;
+        store!(Vec<RpcTokenMetadataRevision>, &self.revisions, writer)?;
+        store!(RpcTokenContext, &self.context, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for GetTokenAssetHistoryResponse {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let asset = load!(Option<RpcTokenAsset>, reader)?;
+        let total_revisions = load!(u64, reader)?;
+        let revisions = load!(Vec<RpcTokenMetadataRevision>, reader)?;
+        let context = load!(RpcTokenContext, reader)?;
+        Ok(Self { asset, total_revisions, revisions, context })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTokenOpStatusRequest {
+    pub txid: RpcHash,
+    pub at_block_hash: Option<RpcHash>,
+}
+
+impl Serializer for GetTokenOpStatusRequest {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &2, writer)?;
+        store!(RpcHash, &self.txid, writer)?;
+        store!(Option<RpcHash>, &self.at_block_hash, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for GetTokenOpStatusRequest {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let version = load!(u16, reader)?;
+        let txid = load!(RpcHash, reader)?;
+        let at_block_hash = if version >= 2 { load!(Option<RpcHash>, reader)? } else { None };
+        Ok(Self { txid, at_block_hash })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTokenOpStatusResponse {
+    pub accepting_block_hash: Option<RpcHash>,
+    pub apply_status: Option<u32>,
+    pub noop_reason: Option<u32>,
+    pub context: RpcTokenContext,
+}
+
+impl Serializer for GetTokenOpStatusResponse {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(Option<RpcHash>, &self.accepting_block_hash, writer)?;
+        store!(Option<u32>, &self.apply_status, writer)?;
+        store!(Option<u32>, &self.noop_reason, writer)?;
+        store!(RpcTokenContext, &self.context, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for GetTokenOpStatusResponse {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let accepting_block_hash = load!(Option<RpcHash>, reader)?;
+        let apply_status = load!(Option<u32>, reader)?;
+        let noop_reason = load!(Option<u32>, reader)?;
+        let context = load!(RpcTokenContext, reader)?;
+        Ok(Self { accepting_block_hash, apply_status, noop_reason, context })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTokenStateHashRequest {
+    pub at_block_hash: Option<RpcHash>,
+}
+
+impl Serializer for GetTokenStateHashRequest {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &2, writer)?;
+        store!(Option<RpcHash>, &self.at_block_hash, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for GetTokenStateHashRequest {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let version = load!(u16, reader)?;
+        let at_block_hash = if version >= 2 { load!(Option<RpcHash>, reader)? } else { None };
+        Ok(Self { at_block_hash })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTokenStateHashResponse {
+    pub context: RpcTokenContext,
+}
+
+impl Serializer for GetTokenStateHashResponse {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(RpcTokenContext, &self.context, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for GetTokenStateHashResponse {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let context = load!(RpcTokenContext, reader)?;
+        Ok(Self { context })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTokenSpendabilityRequest {
+    pub asset_id: String,
+    pub owner_id: String,
+    pub min_daa_for_spend: Option<u64>,
+    pub at_block_hash: Option<RpcHash>,
+}
+
+impl Serializer for GetTokenSpendabilityRequest {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &2, writer)?;
+        store!(String, &self.asset_id, writer)?;
+        store!(String, &self.owner_id, writer)?;
+        store!(Option<u64>, &self.min_daa_for_spend, writer)?;
+        store!(Option<RpcHash>, &self.at_block_hash, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for GetTokenSpendabilityRequest {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let version = load!(u16, reader)?;
+        let asset_id = load!(String, reader)?;
+        let owner_id = load!(String, reader)?;
+        let min_daa_for_spend = load!(Option<u64>, reader)?;
+        let at_block_hash = if version >= 2 { load!(Option<RpcHash>, reader)? } else { None };
+        Ok(Self { asset_id, owner_id, min_daa_for_spend, at_block_hash })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTokenSpendabilityResponse {
+    pub can_spend: bool,
+    pub reason: Option<String>,
+    pub balance: String,
+    pub expected_next_nonce: u64,
+    pub min_daa_for_spend: u64,
+    pub context: RpcTokenContext,
+}
+
+impl Serializer for GetTokenSpendabilityResponse {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(bool, &self.can_spend, writer)?;
+        store!(Option<String>, &self.reason, writer)?;
+        store!(String, &self.balance, writer)?;
+        store!(u64, &self.expected_next_nonce, writer)?;
+        store!(u64, &self.min_daa_for_spend, writer)?;
+        store!(RpcTokenContext, &self.context, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for GetTokenSpendabilityResponse {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let can_spend = load!(bool, reader)?;
+        let reason = load!(Option<String>, reader)?;
+        let balance = load!(String, reader)?;
+        let expected_next_nonce = load!(u64, reader)?;
+        let min_daa_for_spend = load!(u64, reader)?;
+        let context = load!(RpcTokenContext, reader)?;
+        Ok(Self { can_spend, reason, balance, expected_next_nonce, min_daa_for_spend, context })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcTokenEvent {
+    pub event_id: String,
+    pub sequence: u64,
+    pub accepting_block_hash: RpcHash,
+    pub txid: RpcHash,
+    pub event_type: u32,
+    pub apply_status: u32,
+    pub noop_reason: u32,
+    pub ordinal: u32,
+    pub reorg_of_event_id: Option<String>,
+    pub op_type: Option<u32>,
+    pub asset_id: Option<String>,
+    pub from_owner_id: Option<String>,
+    pub to_owner_id: Option<String>,
+    pub amount: Option<String>,
+}
+
+impl Serializer for RpcTokenEvent {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &2, writer)?;
+        store!(String, &self.event_id, writer)?;
+        store!(u64, &self.sequence, writer)?;
+        store!(RpcHash, &self.accepting_block_hash, writer)?;
+        store!(RpcHash, &self.txid, writer)?;
+        store!(u32, &self.event_type, writer)?;
+        store!(u32, &self.apply_status, writer)?;
+        store!(u32, &self.noop_reason, writer)?;
+        store!(u32, &self.ordinal, writer)?;
+        store!(Option<String>, &self.reorg_of_event_id, writer)?;
+        store!(Option<u32>, &self.op_type, writer)?;
+        store!(Option<String>, &self.asset_id, writer)?;
+        store!(Option<String>, &self.from_owner_id, writer)?;
+        store!(Option<String>, &self.to_owner_id, writer)?;
+        store!(Option<String>, &self.amount, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for RpcTokenEvent {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let version = load!(u16, reader)?;
+        let event_id = load!(String, reader)?;
+        let sequence = load!(u64, reader)?;
+        let accepting_block_hash = load!(RpcHash, reader)?;
+        let txid = load!(RpcHash, reader)?;
+        let event_type = load!(u32, reader)?;
+        let apply_status = load!(u32, reader)?;
+        let noop_reason = load!(u32, reader)?;
+        let ordinal = load!(u32, reader)?;
+        let reorg_of_event_id = load!(Option<String>, reader)?;
+        let op_type = if version >= 2 { load!(Option<u32>, reader)? } else { None };
+        let asset_id = if version >= 2 { load!(Option<String>, reader)? } else { None };
+        let from_owner_id = if version >= 2 { load!(Option<String>, reader)? } else { None };
+        let to_owner_id = if version >= 2 { load!(Option<String>, reader)? } else { None };
+        let amount = if version >= 2 { load!(Option<String>, reader)? } else { None };
+        Ok(Self {
+            event_id,
+            sequence,
+            accepting_block_hash,
+            txid,
+            event_type,
+            apply_status,
+            noop_reason,
+            ordinal,
+            reorg_of_event_id,
+            op_type,
+            asset_id,
+            from_owner_id,
+            to_owner_id,
+            amount,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcTokenOwnerBalance {
+    pub asset_id: String,
+    pub balance: String,
+    pub locked_balance: String,
+    pub asset: Option<RpcTokenAsset>,
+}
+
+impl Serializer for RpcTokenOwnerBalance {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &2, writer)?;
+        store!(String, &self.asset_id, writer)?;
+        store!(String, &self.balance, writer)?;
+        store!(Option<RpcTokenAsset>, &self.asset, writer)?;
+        store!(String, &self.locked_balance, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for RpcTokenOwnerBalance {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let version = load!(u16, reader)?;
+        let asset_id = load!(String, reader)?;
+        let balance = load!(String, reader)?;
+        let asset = load!(Option<RpcTokenAsset>, reader)?;
+        let locked_balance = if version >= 2 { load!(String, reader)? } else { "0".to_string() };
+        Ok(Self { asset_id, balance, locked_balance, asset })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcTokenHolder {
+    pub owner_id: String,
+    pub balance: String,
+}
+
+impl Serializer for RpcTokenHolder {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(String, &self.owner_id, writer)?;
+        store!(String, &self.balance, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for RpcTokenHolder {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let owner_id = load!(String, reader)?;
+        let balance = load!(String, reader)?;
+        Ok(Self { owner_id, balance })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcLiquidityFeeRecipient {
+    pub owner_id: String,
+    pub address: String,
+    pub unclaimed_sompi: String,
+}
+
+impl Serializer for RpcLiquidityFeeRecipient {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         store!(u16, &1, writer)?;
         store!(String, &self.owner_id, writer)?;
@@ -3617,86 +4155,370 @@ pub struct GetLiquidityPoolStateResponse {
 impl Serializer for GetLiquidityPoolStateResponse {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         store!(u16, &1, writer)?;
-        store!(Option<RpcLiquidityPoolState>, &self.pool, writer)?;
+        store!(Option<RpcLiquidityPoolState>, &self.pool, writer)?;
+        store!(RpcTokenContext, &self.context, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for GetLiquidityPoolStateResponse {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let pool = load!(Option<RpcLiquidityPoolState>, reader)?;
+        let context = load!(RpcTokenContext, reader)?;
+        Ok(Self { pool, context })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetLiquidityQuoteRequest {
+    pub asset_id: String,
+    pub side: u32,
+    pub exact_in_amount: String,
+    pub at_block_hash: Option<RpcHash>,
+}
+
+impl Serializer for GetLiquidityQuoteRequest {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(String, &self.asset_id, writer)?;
+        store!(u32, &self.side, writer)?;
+        store!(String, &self.exact_in_amount, writer)?;
+        store!(Option<RpcHash>, &self.at_block_hash, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for GetLiquidityQuoteRequest {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let asset_id = load!(String, reader)?;
+        let side = load!(u32, reader)?;
+        let exact_in_amount = load!(String, reader)?;
+        let at_block_hash = load!(Option<RpcHash>, reader)?;
+        Ok(Self { asset_id, side, exact_in_amount, at_block_hash })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetLiquidityQuoteResponse {
+    pub side: u32,
+    pub exact_in_amount: String,
+    pub fee_amount_sompi: String,
+    pub net_in_amount: String,
+    pub amount_out: String,
+    pub context: RpcTokenContext,
+}
+
+impl Serializer for GetLiquidityQuoteResponse {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(u32, &self.side, writer)?;
+        store!(String, &self.exact_in_amount, writer)?;
+        store!(String, &self.fee_amount_sompi, writer)?;
+        store!(String, &self.net_in_amount, writer)?;
+        store!(String, &self.amount_out, writer)?;
+        store!(RpcTokenContext, &self.context, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for GetLiquidityQuoteResponse {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let side = load!(u32, reader)?;
+        let exact_in_amount = load!(String, reader)?;
+        let fee_amount_sompi = load!(String, reader)?;
+        let net_in_amount = load!(String, reader)?;
+        let amount_out = load!(String, reader)?;
+        let context = load!(RpcTokenContext, reader)?;
+        Ok(Self { side, exact_in_amount, fee_amount_sompi, net_in_amount, amount_out, context })
+    }
+}
+
+/// One OHLC interval of a liquidity pool price series. Prices are execution prices in sompi per raw token unit.
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcLiquidityCandle {
+    pub start_daa_score: u64,
+    pub open_price_sompi: String,
+    pub high_price_sompi: String,
+    pub low_price_sompi: String,
+    pub close_price_sompi: String,
+    pub volume_sompi: String,
+    pub trade_count: u64,
+}
+
+impl Serializer for RpcLiquidityCandle {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(u64, &self.start_daa_score, writer)?;
+        store!(String, &self.open_price_sompi, writer)?;
+        store!(String, &self.high_price_sompi, writer)?;
+        store!(String, &self.low_price_sompi, writer)?;
+        store!(String, &self.close_price_sompi, writer)?;
+        store!(String, &self.volume_sompi, writer)?;
+        store!(u64, &self.trade_count, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for RpcLiquidityCandle {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let start_daa_score = load!(u64, reader)?;
+        let open_price_sompi = load!(String, reader)?;
+        let high_price_sompi = load!(String, reader)?;
+        let low_price_sompi = load!(String, reader)?;
+        let close_price_sompi = load!(String, reader)?;
+        let volume_sompi = load!(String, reader)?;
+        let trade_count = load!(u64, reader)?;
+        Ok(Self { start_daa_score, open_price_sompi, high_price_sompi, low_price_sompi, close_price_sompi, volume_sompi, trade_count })
+    }
+}
+
+/// Requests candles over the retained trades with `from_daa_score <= daaScore < to_daa_score`,
+/// aligned to multiples of `interval_daa_score`. Intervals without trades are omitted.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetLiquidityCandlesRequest {
+    pub asset_id: String,
+    pub interval_daa_score: u64,
+    pub from_daa_score: u64,
+    pub to_daa_score: u64,
+}
+
+impl Serializer for GetLiquidityCandlesRequest {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(String, &self.asset_id, writer)?;
+        store!(u64, &self.interval_daa_score, writer)?;
+        store!(u64, &self.from_daa_score, writer)?;
+        store!(u64, &self.to_daa_score, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for GetLiquidityCandlesRequest {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let asset_id = load!(String, reader)?;
+        let interval_daa_score = load!(u64, reader)?;
+        let from_daa_score = load!(u64, reader)?;
+        let to_daa_score = load!(u64, reader)?;
+        Ok(Self { asset_id, interval_daa_score, from_daa_score, to_daa_score })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetLiquidityCandlesResponse {
+    pub candles: Vec<RpcLiquidityCandle>,
+    pub context: RpcTokenContext,
+}
+
+impl Serializer for GetLiquidityCandlesResponse {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(Vec<RpcLiquidityCandle>, &self.candles, writer)?;
+        store!(RpcTokenContext, &self.context, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for GetLiquidityCandlesResponse {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let candles = load!(Vec<RpcLiquidityCandle>, reader)?;
+        let context = load!(RpcTokenContext, reader)?;
+        Ok(Self { candles, context })
+    }
+}
+
+/// Reserve and share state of a constant-product pair pool.
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcPairPoolState {
+    pub pool_id: String,
+    pub asset_a_id: String,
+    pub asset_b_id: String,
+    pub fee_bps: u32,
+    pub reserve_a: String,
+    pub reserve_b: String,
+    pub total_shares: String,
+    /// Shares held by the pool itself; they can never be redeemed.
+    pub locked_shares: String,
+    pub accrued_fee_a: String,
+    pub accrued_fee_b: String,
+}
+
+impl Serializer for RpcPairPoolState {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(String, &self.pool_id, writer)?;
+        store!(String, &self.asset_a_id, writer)?;
+        store!(String, &self.asset_b_id, writer)?;
+        store!(u32, &self.fee_bps, writer)?;
+        store!(String, &self.reserve_a, writer)?;
+        store!(String, &self.reserve_b, writer)?;
+        store!(String, &self.total_shares, writer)?;
+        store!(String, &self.locked_shares, writer)?;
+        store!(String, &self.accrued_fee_a, writer)?;
+        store!(String, &self.accrued_fee_b, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for RpcPairPoolState {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let pool_id = load!(String, reader)?;
+        let asset_a_id = load!(String, reader)?;
+        let asset_b_id = load!(String, reader)?;
+        let fee_bps = load!(u32, reader)?;
+        let reserve_a = load!(String, reader)?;
+        let reserve_b = load!(String, reader)?;
+        let total_shares = load!(String, reader)?;
+        let locked_shares = load!(String, reader)?;
+        let accrued_fee_a = load!(String, reader)?;
+        let accrued_fee_b = load!(String, reader)?;
+        Ok(Self {
+            pool_id,
+            asset_a_id,
+            asset_b_id,
+            fee_bps,
+            reserve_a,
+            reserve_b,
+            total_shares,
+            locked_shares,
+            accrued_fee_a,
+            accrued_fee_b,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPairPoolRequest {
+    pub pool_id: String,
+    pub at_block_hash: Option<RpcHash>,
+}
+
+impl Serializer for GetPairPoolRequest {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(String, &self.pool_id, writer)?;
+        store!(Option<RpcHash>, &self.at_block_hash, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for GetPairPoolRequest {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let pool_id = load!(String, reader)?;
+        let at_block_hash = load!(Option<RpcHash>, reader)?;
+        Ok(Self { pool_id, at_block_hash })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPairPoolResponse {
+    pub pool: Option<RpcPairPoolState>,
+    pub context: RpcTokenContext,
+}
+
+impl Serializer for GetPairPoolResponse {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(Option<RpcPairPoolState>, &self.pool, writer)?;
         store!(RpcTokenContext, &self.context, writer)?;
         Ok(())
     }
 }
 
-impl Deserializer for GetLiquidityPoolStateResponse {
+impl Deserializer for GetPairPoolResponse {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
         let _version = load!(u16, reader)?;
-        let pool = load!(Option<RpcLiquidityPoolState>, reader)?;
+        let pool = load!(Option<RpcPairPoolState>, reader)?;
         let context = load!(RpcTokenContext, reader)?;
         Ok(Self { pool, context })
     }
 }
 
+/// Quotes a pair swap. With `exact_out` unset, `amount` is the exact input of `asset_in_id`;
+/// otherwise it is the exact output of the opposite asset.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct GetLiquidityQuoteRequest {
-    pub asset_id: String,
-    pub side: u32,
-    pub exact_in_amount: String,
+pub struct GetPairQuoteRequest {
+    pub pool_id: String,
+    pub asset_in_id: String,
+    pub exact_out: bool,
+    pub amount: String,
     pub at_block_hash: Option<RpcHash>,
 }
 
-impl Serializer for GetLiquidityQuoteRequest {
+impl Serializer for GetPairQuoteRequest {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         store!(u16, &1, writer)?;
-        store!(String, &self.asset_id, writer)?;
-        store!(u32, &self.side, writer)?;
-        store!(String, &self.exact_in_amount, writer)?;
+        store!(String, &self.pool_id, writer)?;
+        store!(String, &self.asset_in_id, writer)?;
+        store!(bool, &self.exact_out, writer)?;
+        store!(String, &self.amount, writer)?;
         store!(Option<RpcHash>, &self.at_block_hash, writer)?;
         Ok(())
     }
 }
 
-impl Deserializer for GetLiquidityQuoteRequest {
+impl Deserializer for GetPairQuoteRequest {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
         let _version = load!(u16, reader)?;
-        let asset_id = load!(String, reader)?;
-        let side = load!(u32, reader)?;
-        let exact_in_amount = load!(String, reader)?;
+        let pool_id = load!(String, reader)?;
+        let asset_in_id = load!(String, reader)?;
+        let exact_out = load!(bool, reader)?;
+        let amount = load!(String, reader)?;
         let at_block_hash = load!(Option<RpcHash>, reader)?;
-        Ok(Self { asset_id, side, exact_in_amount, at_block_hash })
+        Ok(Self { pool_id, asset_in_id, exact_out, amount, at_block_hash })
     }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct GetLiquidityQuoteResponse {
-    pub side: u32,
-    pub exact_in_amount: String,
-    pub fee_amount_sompi: String,
-    pub net_in_amount: String,
+pub struct GetPairQuoteResponse {
+    pub asset_in_id: String,
+    pub asset_out_id: String,
+    pub amount_in: String,
+    pub fee_amount: String,
     pub amount_out: String,
     pub context: RpcTokenContext,
 }
 
-impl Serializer for GetLiquidityQuoteResponse {
+impl Serializer for GetPairQuoteResponse {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         store!(u16, &1, writer)?;
-        store!(u32, &self.side, writer)?;
-        store!(String, &self.exact_in_amount, writer)?;
-        store!(String, &self.fee_amount_sompi, writer)?;
-        store!(String, &self.net_in_amount, writer)?;
+        store!(String, &self.asset_in_id, writer)?;
+        store!(String, &self.asset_out_id, writer)?;
+        store!(String, &self.amount_in, writer)?;
+        store!(String, &self.fee_amount, writer)?;
         store!(String, &self.amount_out, writer)?;
         store!(RpcTokenContext, &self.context, writer)?;
         Ok(())
     }
 }
 
-impl Deserializer for GetLiquidityQuoteResponse {
+impl Deserializer for GetPairQuoteResponse {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
         let _version = load!(u16, reader)?;
-        let side = load!(u32, reader)?;
-        let exact_in_amount = load!(String, reader)?;
-        let fee_amount_sompi = load!(String, reader)?;
-        let net_in_amount = load!(String, reader)?;
+        let asset_in_id = load!(String, reader)?;
+        let asset_out_id = load!(String, reader)?;
+        let amount_in = load!(String, reader)?;
+        let fee_amount = load!(String, reader)?;
         let amount_out = load!(String, reader)?;
         let context = load!(RpcTokenContext, reader)?;
-        Ok(Self { side, exact_in_amount, fee_amount_sompi, net_in_amount, amount_out, context })
+        Ok(Self { asset_in_id, asset_out_id, amount_in, fee_amount, amount_out, context })
     }
 }
 
@@ -3886,20 +4708,37 @@ impl Deserializer for GetLiquidityHoldersResponse {
     }
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+/// Pages the token event log in sequence order. All filters are optional and combine with AND;
+/// `owner_id` matches either side of an event and the DAA range is inclusive on both ends.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetTokenEventsRequest {
     pub after_sequence: u64,
     pub limit: u32,
     pub at_block_hash: Option<RpcHash>,
+    #[serde(default)]
+    pub owner_id: Option<String>,
+    #[serde(default)]
+    pub asset_id: Option<String>,
+    #[serde(default)]
+    pub op_types: Vec<u32>,
+    #[serde(default)]
+    pub from_daa_score: Option<u64>,
+    #[serde(default)]
+    pub to_daa_score: Option<u64>,
 }
 
 impl Serializer for GetTokenEventsRequest {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
-        store!(u16, &2, writer)?;
+        store!(u16, &3, writer)?;
         store!(u64, &self.after_sequence, writer)?;
         store!(u32, &self.limit, writer)?;
         store!(Option<RpcHash>, &self.at_block_hash, writer)?;
+        store!(Option<String>, &self.owner_id, writer)?;
+        store!(Option<String>, &self.asset_id, writer)?;
+        store!(Vec<u32>, &self.op_types, writer)?;
+        store!(Option<u64>, &self.from_daa_score, writer)?;
+        store!(Option<u64>, &self.to_daa_score, writer)?;
         Ok(())
     }
 }
@@ -3910,7 +4749,15 @@ impl Deserializer for GetTokenEventsRequest {
         let after_sequence = load!(u64, reader)?;
         let limit = load!(u32, reader)?;
         let at_block_hash = if version >= 2 { load!(Option<RpcHash>, reader)? } else { None };
-        Ok(Self { after_sequence, limit, at_block_hash })
+        let mut request = Self { after_sequence, limit, at_block_hash, ..Default::default() };
+        if version >= 3 {
+            request.owner_id = load!(Option<String>, reader)?;
+            request.asset_id = load!(Option<String>, reader)?;
+            request.op_types = load!(Vec<u32>, reader)?;
+            request.from_daa_score = load!(Option<u64>, reader)?;
+            request.to_daa_score = load!(Option<u64>, reader)?;
+        }
+        Ok(request)
     }
 }
 
@@ -3919,23 +4766,28 @@ impl Deserializer for GetTokenEventsRequest {
 pub struct GetTokenEventsResponse {
     pub events: Vec<RpcTokenEvent>,
     pub context: RpcTokenContext,
+    /// Cursor for the next page (pass as `after_sequence`); `None` once no further events match.
+    #[serde(default)]
+    pub next_after_sequence: Option<u64>,
 }
 
 impl Serializer for GetTokenEventsResponse {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
-        store!(u16, &1, writer)?;
+        store!(u16, &2, writer)?;
         store!(Vec<RpcTokenEvent>, &self.events, writer)?;
         store!(RpcTokenContext, &self.context, writer)?;
+        store!(Option<u64>, &self.next_after_sequence, writer)?;
         Ok(())
     }
 }
 
 impl Deserializer for GetTokenEventsResponse {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
-        let _version = load!(u16, reader)?;
+        let version = load!(u16, reader)?;
         let events = load!(Vec<RpcTokenEvent>, reader)?;
         let context = load!(RpcTokenContext, reader)?;
-        Ok(Self { events, context })
+        let next_after_sequence = if version >= 2 { load!(Option<u64>, reader)? } else { None };
+        Ok(Self { events, context, next_after_sequence })
     }
 }
 
@@ -4219,6 +5071,58 @@ impl Deserializer for ExportTokenSnapshotResponse {
     }
 }
 
+/// Writes the documented JSONL analytics export of the Atomic token state into the `path` directory.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportTokenAnalyticsRequest {
+    pub path: String,
+    pub at_block_hash: Option<RpcHash>,
+}
+
+impl Serializer for ExportTokenAnalyticsRequest {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(String, &self.path, writer)?;
+        store!(Option<RpcHash>, &self.at_block_hash, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for ExportTokenAnalyticsRequest {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let path = load!(String, reader)?;
+        let at_block_hash = load!(Option<RpcHash>, reader)?;
+        Ok(Self { path, at_block_hash })
+    }
+}
+
+/// `context` is read at the exported block, so its state hash is the state root embedded in the manifest.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportTokenAnalyticsResponse {
+    pub manifest_path: String,
+    pub context: RpcTokenContext,
+}
+
+impl Serializer for ExportTokenAnalyticsResponse {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(String, &self.manifest_path, writer)?;
+        store!(RpcTokenContext, &self.context, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for ExportTokenAnalyticsResponse {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let manifest_path = load!(String, reader)?;
+        let context = load!(RpcTokenContext, reader)?;
+        Ok(Self { manifest_path, context })
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ImportTokenSnapshotRequest {
@@ -5076,95 +5980,523 @@ pub struct GetFastIntentStatusResponse {
     pub epoch_changed: Option<bool>,
 }
 
-impl Serializer for GetFastIntentStatusResponse {
+impl Serializer for GetFastIntentStatusResponse {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &2, writer)?;
+        store!(RpcFastIntentStatus, &self.status, writer)?;
+        store!(Option<String>, &self.reason, writer)?;
+        store!(Option<RpcHash>, &self.base_tx_id, writer)?;
+        store!(u64, &self.node_epoch, writer)?;
+        store!(Option<u64>, &self.expires_at_ms, writer)?;
+        store!(Option<u64>, &self.retention_until_ms, writer)?;
+        store!(Option<String>, &self.cancel_token, writer)?;
+        store!(Option<bool>, &self.epoch_changed, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for GetFastIntentStatusResponse {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let version = load!(u16, reader)?;
+        let status = load!(RpcFastIntentStatus, reader)?;
+        let reason = load!(Option<String>, reader)?;
+        let base_tx_id = if version >= 2 { load!(Option<RpcHash>, reader)? } else { None };
+        let node_epoch = load!(u64, reader)?;
+        let expires_at_ms = load!(Option<u64>, reader)?;
+        let retention_until_ms = load!(Option<u64>, reader)?;
+        let cancel_token = load!(Option<String>, reader)?;
+        let epoch_changed = load!(Option<bool>, reader)?;
+        Ok(Self { status, reason, base_tx_id, node_epoch, expires_at_ms, retention_until_ms, cancel_token, epoch_changed })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelFastIntentRequest {
+    pub intent_id: RpcHash,
+    pub cancel_token: String,
+    pub node_epoch: u64,
+}
+
+impl Serializer for CancelFastIntentRequest {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(RpcHash, &self.intent_id, writer)?;
+        store!(String, &self.cancel_token, writer)?;
+        store!(u64, &self.node_epoch, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for CancelFastIntentRequest {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let intent_id = load!(RpcHash, reader)?;
+        let cancel_token = load!(String, reader)?;
+        let node_epoch = load!(u64, reader)?;
+        Ok(Self { intent_id, cancel_token, node_epoch })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelFastIntentResponse {
+    pub status: RpcFastIntentStatus,
+    pub reason: Option<String>,
+    pub node_epoch: u64,
+    pub retention_until_ms: Option<u64>,
+    pub epoch_changed: Option<bool>,
+}
+
+impl Serializer for CancelFastIntentResponse {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(RpcFastIntentStatus, &self.status, writer)?;
+        store!(Option<String>, &self.reason, writer)?;
+        store!(u64, &self.node_epoch, writer)?;
+        store!(Option<u64>, &self.retention_until_ms, writer)?;
+        store!(Option<bool>, &self.epoch_changed, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for CancelFastIntentResponse {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let status = load!(RpcFastIntentStatus, reader)?;
+        let reason = load!(Option<String>, reader)?;
+        let node_epoch = load!(u64, reader)?;
+        let retention_until_ms = load!(Option<u64>, reader)?;
+        let epoch_changed = load!(Option<bool>, reader)?;
+        Ok(Self { status, reason, node_epoch, retention_until_ms, epoch_changed })
+    }
+}
+
+/// Effective HFA fast rail configuration. `paused` reflects an operator pause set through `SetHfaConfig`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcHfaConfig {
+    pub enabled: bool,
+    pub paused: bool,
+    pub cpu_low_water_ratio: f64,
+    pub fast_ttl_ms: u64,
+    pub clock_drift_max_ms: u64,
+    pub terminal_retention_ms: u64,
+    pub max_pending_intents: u64,
+    pub max_locks: u64,
+    pub max_terminal_entries: u64,
+    pub max_terminal_bytes: u64,
+    pub max_inputs_per_intent: u64,
+    pub max_outputs_per_intent: u64,
+    pub min_feerate_floor: f64,
+    pub seen_cache_ttl_ms: u64,
+    pub microblock_interval_ms_normal: u64,
+    pub microblock_interval_ms_degraded: u64,
+    pub revalidation_budget_normal: u64,
+    pub revalidation_budget_degraded: u64,
+    pub normal_arbiter_queue_capacity: u64,
+    pub fast_arbiter_queue_capacity: u64,
+}
+
+impl Serializer for RpcHfaConfig {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(bool, &self.enabled, writer)?;
+        store!(bool, &self.paused, writer)?;
+        store!(f64, &self.cpu_low_water_ratio, writer)?;
+        store!(u64, &self.fast_ttl_ms, writer)?;
+        store!(u64, &self.clock_drift_max_ms, writer)?;
+        store!(u64, &self.terminal_retention_ms, writer)?;
+        store!(u64, &self.max_pending_intents, writer)?;
+        store!(u64, &self.max_locks, writer)?;
+        store!(u64, &self.max_terminal_entries, writer)?;
+        store!(u64, &self.max_terminal_bytes, writer)?;
+        store!(u64, &self.max_inputs_per_intent, writer)?;
+        store!(u64, &self.max_outputs_per_intent, writer)?;
+        store!(f64, &self.min_feerate_floor, writer)?;
+        store!(u64, &self.seen_cache_ttl_ms, writer)?;
+        store!(u64, &self.microblock_interval_ms_normal, writer)?;
+        store!(u64, &self.microblock_interval_ms_degraded, writer)?;
+        store!(u64, &self.revalidation_budget_normal, writer)?;
+        store!(u64, &self.revalidation_budget_degraded, writer)?;
+        store!(u64, &self.normal_arbiter_queue_capacity, writer)?;
+        store!(u64, &self.fast_arbiter_queue_capacity, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for RpcHfaConfig {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let enabled = load!(bool, reader)?;
+        let paused = load!(bool, reader)?;
+        let cpu_low_water_ratio = load!(f64, reader)?;
+        let fast_ttl_ms = load!(u64, reader)?;
+        let clock_drift_max_ms = load!(u64, reader)?;
+        let terminal_retention_ms = load!(u64, reader)?;
+        let max_pending_intents = load!(u64, reader)?;
+        let max_locks = load!(u64, reader)?;
+        let max_terminal_entries = load!(u64, reader)?;
+        let max_terminal_bytes = load!(u64, reader)?;
+        let max_inputs_per_intent = load!(u64, reader)?;
+        let max_outputs_per_intent = load!(u64, reader)?;
+        let min_feerate_floor = load!(f64, reader)?;
+        let seen_cache_ttl_ms = load!(u64, reader)?;
+        let microblock_interval_ms_normal = load!(u64, reader)?;
+        let microblock_interval_ms_degraded = load!(u64, reader)?;
+        let revalidation_budget_normal = load!(u64, reader)?;
+        let revalidation_budget_degraded = load!(u64, reader)?;
+        let normal_arbiter_queue_capacity = load!(u64, reader)?;
+        let fast_arbiter_queue_capacity = load!(u64, reader)?;
+        Ok(Self {
+            enabled,
+            paused,
+            cpu_low_water_ratio,
+            fast_ttl_ms,
+            clock_drift_max_ms,
+            terminal_retention_ms,
+            max_pending_intents,
+            max_locks,
+            max_terminal_entries,
+            max_terminal_bytes,
+            max_inputs_per_intent,
+            max_outputs_per_intent,
+            min_feerate_floor,
+            seen_cache_ttl_ms,
+            microblock_interval_ms_normal,
+            microblock_interval_ms_degraded,
+            revalidation_budget_normal,
+            revalidation_budget_degraded,
+            normal_arbiter_queue_capacity,
+            fast_arbiter_queue_capacity,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetHfaConfigRequest {}
+
+impl Serializer for GetHfaConfigRequest {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for GetHfaConfigRequest {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        Ok(Self {})
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetHfaConfigResponse {
+    pub config: RpcHfaConfig,
+}
+
+impl Serializer for GetHfaConfigResponse {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        serialize!(RpcHfaConfig, &self.config, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for GetHfaConfigResponse {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let config = deserialize!(RpcHfaConfig, reader)?;
+        Ok(Self { config })
+    }
+}
+
+/// Changes HFA fast rail limits at runtime. Unset fields keep their current value; `paused`
+/// stops or resumes the admission of new fast intents.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetHfaConfigRequest {
+    pub cpu_low_water_ratio: Option<f64>,
+    pub fast_ttl_ms: Option<u64>,
+    pub clock_drift_max_ms: Option<u64>,
+    pub terminal_retention_ms: Option<u64>,
+    pub max_pending_intents: Option<u64>,
+    pub max_locks: Option<u64>,
+    pub max_terminal_entries: Option<u64>,
+    pub max_terminal_bytes: Option<u64>,
+    pub max_inputs_per_intent: Option<u64>,
+    pub max_outputs_per_intent: Option<u64>,
+    pub min_feerate_floor: Option<f64>,
+    pub seen_cache_ttl_ms: Option<u64>,
+    pub microblock_interval_ms_normal: Option<u64>,
+    pub microblock_interval_ms_degraded: Option<u64>,
+    pub revalidation_budget_normal: Option<u64>,
+    pub revalidation_budget_degraded: Option<u64>,
+    pub normal_arbiter_queue_capacity: Option<u64>,
+    pub fast_arbiter_queue_capacity: Option<u64>,
+    pub paused: Option<bool>,
+}
+
+impl Serializer for SetHfaConfigRequest {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(Option<f64>, &self.cpu_low_water_ratio, writer)?;
+        store!(Option<u64>, &self.fast_ttl_ms, writer)?;
+        store!(Option<u64>, &self.clock_drift_max_ms, writer)?;
+        store!(Option<u64>, &self.terminal_retention_ms, writer)?;
+        store!(Option<u64>, &self.max_pending_intents, writer)?;
+        store!(Option<u64>, &self.max_locks, writer)?;
+        store!(Option<u64>, &self.max_terminal_entries, writer)?;
+        store!(Option<u64>, &self.max_terminal_bytes, writer)?;
+        store!(Option<u64>, &self.max_inputs_per_intent, writer)?;
+        store!(Option<u64>, &self.max_outputs_per_intent, writer)?;
+        store!(Option<f64>, &self.min_feerate_floor, writer)?;
+        store!(Option<u64>, &self.seen_cache_ttl_ms, writer)?;
+        store!(Option<u64>, &self.microblock_interval_ms_normal, writer)?;
+        store!(Option<u64>, &self.microblock_interval_ms_degraded, writer)?;
+        store!(Option<u64>, &self.revalidation_budget_normal, writer)?;
+        store!(Option<u64>, &self.revalidation_budget_degraded, writer)?;
+        store!(Option<u64>, &self.normal_arbiter_queue_capacity, writer)?;
+        store!(Option<u64>, &self.fast_arbiter_queue_capacity, writer)?;
+        store!(Option<bool>, &self.paused, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for SetHfaConfigRequest {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let cpu_low_water_ratio = load!(Option<f64>, reader)?;
+        let fast_ttl_ms = load!(Option<u64>, reader)?;
+        let clock_drift_max_ms = load!(Option<u64>, reader)?;
+        let terminal_retention_ms = load!(Option<u64>, reader)?;
+        let max_pending_intents = load!(Option<u64>, reader)?;
+        let max_locks = load!(Option<u64>, reader)?;
+        let max_terminal_entries = load!(Option<u64>, reader)?;
+        let max_terminal_bytes = load!(Option<u64>, reader)?;
+        let max_inputs_per_intent = load!(Option<u64>, reader)?;
+        let max_outputs_per_intent = load!(Option<u64>, reader)?;
+        let min_feerate_floor = load!(Option<f64>, reader)?;
+        let seen_cache_ttl_ms = load!(Option<u64>, reader)?;
+        let microblock_interval_ms_normal = load!(Option<u64>, reader)?;
+        let microblock_interval_ms_degraded = load!(Option<u64>, reader)?;
+        let revalidation_budget_normal = load!(Option<u64>, reader)?;
+        let revalidation_budget_degraded = load!(Option<u64>, reader)?;
+        let normal_arbiter_queue_capacity = load!(Option<u64>, reader)?;
+        let fast_arbiter_queue_capacity = load!(Option<u64>, reader)?;
+        let paused = load!(Option<bool>, reader)?;
+        Ok(Self {
+            cpu_low_water_ratio,
+            fast_ttl_ms,
+            clock_drift_max_ms,
+            terminal_retention_ms,
+            max_pending_intents,
+            max_locks,
+            max_terminal_entries,
+            max_terminal_bytes,
+            max_inputs_per_intent,
+            max_outputs_per_intent,
+            min_feerate_floor,
+            seen_cache_ttl_ms,
+            microblock_interval_ms_normal,
+            microblock_interval_ms_degraded,
+            revalidation_budget_normal,
+            revalidation_budget_degraded,
+            normal_arbiter_queue_capacity,
+            fast_arbiter_queue_capacity,
+            paused,
+        })
+    }
+}
+
+/// Carries the configuration in effect after the change.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetHfaConfigResponse {
+    pub config: RpcHfaConfig,
+}
+
+impl Serializer for SetHfaConfigResponse {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        serialize!(RpcHfaConfig, &self.config, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for SetHfaConfigResponse {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let config = deserialize!(RpcHfaConfig, reader)?;
+        Ok(Self { config })
+    }
+}
+
+/// Sender identity of a CXM message: kind 1 is a 32-byte pubkey, kind 2 a 16-byte ref.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcMessageSender {
+    pub kind: u8,
+    /// Hex encoded significant sender bytes, without the zero padding of refs.
+    pub data: String,
+}
+
+impl Serializer for RpcMessageSender {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(u8, &self.kind, writer)?;
+        store!(String, &self.data, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for RpcMessageSender {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let kind = load!(u8, reader)?;
+        let data = load!(String, reader)?;
+        Ok(Self { kind, data })
+    }
+}
+
+/// A CXM v1 message accepted by the selected chain, as indexed by the message index.
+///
+/// `sequence` is the index-wide position of the message and is used as pagination cursor.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcMessageEntry {
+    pub sequence: u64,
+    pub transaction_id: RpcTransactionId,
+    pub accepting_block_hash: RpcHash,
+    pub accepting_daa_score: u64,
+    pub accepting_block_time: u64,
+    pub msg_type: u8,
+    pub flags: u8,
+    pub recipient_tag: String,
+    pub nonce: String,
+    pub sender: RpcMessageSender,
+    pub body_hex: String,
+}
+
+impl Serializer for RpcMessageEntry {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
-        store!(u16, &2, writer)?;
-        store!(RpcFastIntentStatus, &self.status, writer)?;
-        store!(Option<String>, &self.reason, writer)?;
-        store!(Option<RpcHash>, &self.base_tx_id, writer)?;
-        store!(u64, &self.node_epoch, writer)?;
-        store!(Option<u64>, &self.expires_at_ms, writer)?;
-        store!(Option<u64>, &self.retention_until_ms, writer)?;
-        store!(Option<String>, &self.cancel_token, writer)?;
-        store!(Option<bool>, &self.epoch_changed, writer)?;
+        store!(u16, &1, writer)?;
+        store!(u64, &self.sequence, writer)?;
+        store!(RpcTransactionId, &self.transaction_id, writer)?;
+        store!(RpcHash, &self.accepting_block_hash, writer)?;
+        store!(u64, &self.accepting_daa_score, writer)?;
+        store!(u64, &self.accepting_block_time, writer)?;
+        store!(u8, &self.msg_type, writer)?;
+        store!(u8, &self.flags, writer)?;
+        store!(String, &self.recipient_tag, writer)?;
+        store!(String, &self.nonce, writer)?;
+        serialize!(RpcMessageSender, &self.sender, writer)?;
+        store!(String, &self.body_hex, writer)?;
         Ok(())
     }
 }
 
-impl Deserializer for GetFastIntentStatusResponse {
+impl Deserializer for RpcMessageEntry {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
-        let version = load!(u16, reader)?;
-        let status = load!(RpcFastIntentStatus, reader)?;
-        let reason = load!(Option<String>, reader)?;
-        let base_tx_id = if version >= 2 { load!(Option<RpcHash>, reader)? } else { None };
-        let node_epoch = load!(u64, reader)?;
-        let expires_at_ms = load!(Option<u64>, reader)?;
-        let retention_until_ms = load!(Option<u64>, reader)?;
-        let cancel_token = load!(Option<String>, reader)?;
-        let epoch_changed = load!(Option<bool>, reader)?;
-        Ok(Self { status, reason, base_tx_id, node_epoch, expires_at_ms, retention_until_ms, cancel_token, epoch_changed })
+        let _version = load!(u16, reader)?;
+        let sequence = load!(u64, reader)?;
+        let transaction_id = load!(RpcTransactionId, reader)?;
+        let accepting_block_hash = load!(RpcHash, reader)?;
+        let accepting_daa_score = load!(u64, reader)?;
+        let accepting_block_time = load!(u64, reader)?;
+        let msg_type = load!(u8, reader)?;
+        let flags = load!(u8, reader)?;
+        let recipient_tag = load!(String, reader)?;
+        let nonce = load!(String, reader)?;
+        let sender = deserialize!(RpcMessageSender, reader)?;
+        let body_hex = load!(String, reader)?;
+        Ok(Self {
+            sequence,
+            transaction_id,
+            accepting_block_hash,
+            accepting_daa_score,
+            accepting_block_time,
+            msg_type,
+            flags,
+            recipient_tag,
+            nonce,
+            sender,
+            body_hex,
+        })
     }
 }
 
+/// Requests the CXM messages sent to a recipient tag, optionally narrowed to a single sender.
+///
+/// Messages are returned in index order, starting after `cursor`. Pass the `next_cursor` of the
+/// response to get the following page. A `limit` of 0 selects the server default.
+///
+/// This call is only available when this cryptixd has the message index enabled.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct CancelFastIntentRequest {
-    pub intent_id: RpcHash,
-    pub cancel_token: String,
-    pub node_epoch: u64,
+pub struct GetMessagesByRecipientTagRequest {
+    /// Hex encoded 16-byte recipient tag.
+    pub recipient_tag: String,
+    pub sender: Option<RpcMessageSender>,
+    #[serde(default)]
+    pub cursor: u64,
+    #[serde(default)]
+    pub limit: u32,
 }
 
-impl Serializer for CancelFastIntentRequest {
+impl GetMessagesByRecipientTagRequest {
+    pub fn new(recipient_tag: String, sender: Option<RpcMessageSender>, cursor: u64, limit: u32) -> Self {
+        Self { recipient_tag, sender, cursor, limit }
+    }
+}
+
+impl Serializer for GetMessagesByRecipientTagRequest {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         store!(u16, &1, writer)?;
-        store!(RpcHash, &self.intent_id, writer)?;
-        store!(String, &self.cancel_token, writer)?;
-        store!(u64, &self.node_epoch, writer)?;
+        store!(String, &self.recipient_tag, writer)?;
+        store!(Option<RpcMessageSender>, &self.sender, writer)?;
+        store!(u64, &self.cursor, writer)?;
+        store!(u32, &self.limit, writer)?;
         Ok(())
     }
 }
 
-impl Deserializer for CancelFastIntentRequest {
+impl Deserializer for GetMessagesByRecipientTagRequest {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
         let _version = load!(u16, reader)?;
-        let intent_id = load!(RpcHash, reader)?;
-        let cancel_token = load!(String, reader)?;
-        let node_epoch = load!(u64, reader)?;
-        Ok(Self { intent_id, cancel_token, node_epoch })
+        let recipient_tag = load!(String, reader)?;
+        let sender = load!(Option<RpcMessageSender>, reader)?;
+        let cursor = load!(u64, reader)?;
+        let limit = load!(u32, reader)?;
+        Ok(Self { recipient_tag, sender, cursor, limit })
     }
 }
 
+/// `next_cursor` is set when more messages are available after the returned page.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct CancelFastIntentResponse {
-    pub status: RpcFastIntentStatus,
-    pub reason: Option<String>,
-    pub node_epoch: u64,
-    pub retention_until_ms: Option<u64>,
-    pub epoch_changed: Option<bool>,
+pub struct GetMessagesByRecipientTagResponse {
+    pub entries: Vec<RpcMessageEntry>,
+    pub next_cursor: Option<u64>,
 }
 
-impl Serializer for CancelFastIntentResponse {
+impl Serializer for GetMessagesByRecipientTagResponse {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         store!(u16, &1, writer)?;
-        store!(RpcFastIntentStatus, &self.status, writer)?;
-        store!(Option<String>, &self.reason, writer)?;
-        store!(u64, &self.node_epoch, writer)?;
-        store!(Option<u64>, &self.retention_until_ms, writer)?;
-        store!(Option<bool>, &self.epoch_changed, writer)?;
+        serialize!(Vec<RpcMessageEntry>, &self.entries, writer)?;
+        store!(Option<u64>, &self.next_cursor, writer)?;
         Ok(())
     }
 }
 
-impl Deserializer for CancelFastIntentResponse {
+impl Deserializer for GetMessagesByRecipientTagResponse {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
         let _version = load!(u16, reader)?;
-        let status = load!(RpcFastIntentStatus, reader)?;
-        let reason = load!(Option<String>, reader)?;
-        let node_epoch = load!(u64, reader)?;
-        let retention_until_ms = load!(Option<u64>, reader)?;
-        let epoch_changed = load!(Option<bool>, reader)?;
-        Ok(Self { status, reason, node_epoch, retention_until_ms, epoch_changed })
+        let entries = deserialize!(Vec<RpcMessageEntry>, reader)?;
+        let next_cursor = load!(Option<u64>, reader)?;
+        Ok(Self { entries, next_cursor })
     }
 }
 
@@ -5905,32 +7237,65 @@ impl Deserializer for NewBlockTemplateNotification {
 
 /// NotifyTokenEventsRequest registers this connection for tokenEventsChanged notifications.
 ///
+/// Every non-empty filter list narrows the subscription: an event is notified only if its asset id,
+/// one of its owner ids, its op type and its apply status are all listed. Leave all lists empty to
+/// get notified about every token event. A start replaces the current filter and a stop ends the subscription.
+///
 /// See: TokenEventsChangedNotification
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct NotifyTokenEventsRequest {
+    #[serde(default)]
+    pub asset_ids: Vec<String>,
+    #[serde(default)]
+    pub owner_ids: Vec<String>,
+    #[serde(default)]
+    pub op_types: Vec<u32>,
+    #[serde(default)]
+    pub apply_statuses: Vec<u32>,
     pub command: Command,
 }
 
 impl NotifyTokenEventsRequest {
     pub fn new(command: Command) -> Self {
-        Self { command }
+        Self { asset_ids: vec![], owner_ids: vec![], op_types: vec![], apply_statuses: vec![], command }
+    }
+
+    pub fn with_filter(
+        asset_ids: Vec<String>,
+        owner_ids: Vec<String>,
+        op_types: Vec<u32>,
+        apply_statuses: Vec<u32>,
+        command: Command,
+    ) -> Self {
+        Self { asset_ids, owner_ids, op_types, apply_statuses, command }
     }
 }
 
 impl Serializer for NotifyTokenEventsRequest {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
-        store!(u16, &1, writer)?;
+        store!(u16, &2, writer)?;
         store!(Command, &self.command, writer)?;
+        store!(Vec<String>, &self.asset_ids, writer)?;
+        store!(Vec<String>, &self.owner_ids, writer)?;
+        store!(Vec<u32>, &self.op_types, writer)?;
+        store!(Vec<u32>, &self.apply_statuses, writer)?;
         Ok(())
     }
 }
 
 impl Deserializer for NotifyTokenEventsRequest {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
-        let _version = load!(u16, reader)?;
+        let version = load!(u16, reader)?;
         let command = load!(Command, reader)?;
-        Ok(Self { command })
+        if version < 2 {
+            return Ok(Self::new(command));
+        }
+        let asset_ids = load!(Vec<String>, reader)?;
+        let owner_ids = load!(Vec<String>, reader)?;
+        let op_types = load!(Vec<u32>, reader)?;
+        let apply_statuses = load!(Vec<u32>, reader)?;
+        Ok(Self { asset_ids, owner_ids, op_types, apply_statuses, command })
     }
 }
 
@@ -5955,31 +7320,297 @@ impl Deserializer for NotifyTokenEventsResponse {
 /// TokenEventsChangedNotification is sent whenever new Cryptix Atomic token events are recorded.
 ///
 /// `from_sequence` and `to_sequence` provide a best-effort inclusive range hint for follow-up GetTokenEvents polling.
+/// `metadata_changed_asset_ids` lists the assets whose metadata was updated (or rolled back) within that range.
+/// `events` carries the recorded events themselves. For a filtered subscription, only the matching events
+/// are kept and the other fields are narrowed down to them.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TokenEventsChangedNotification {
     pub from_sequence: u64,
     pub to_sequence: u64,
     pub event_count: u32,
+    #[serde(default)]
+    pub metadata_changed_asset_ids: Vec<String>,
+    #[serde(default)]
+    pub events: Vec<RpcTokenEvent>,
+}
+
+impl TokenEventsChangedNotification {
+    pub(crate) fn apply_token_events_changed_subscription(&self, subscription: &TokenEventsChangedSubscription) -> Option<Self> {
+        let filter = subscription.filter();
+        if filter.is_wildcard() {
+            return Some(self.clone());
+        }
+        let events = self
+            .events
+            .iter()
+            .filter(|event| {
+                filter.matches(
+                    event.asset_id.as_deref(),
+                    event.from_owner_id.as_deref(),
+                    event.to_owner_id.as_deref(),
+                    event.op_type,
+                    event.apply_status,
+                )
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        let from_sequence = events.iter().map(|event| event.sequence).min()?;
+        let to_sequence = events.iter().map(|event| event.sequence).max()?;
+        let metadata_changed_asset_ids = self
+            .metadata_changed_asset_ids
+            .iter()
+            .filter(|asset_id| events.iter().any(|event| event.asset_id.as_ref() == Some(*asset_id)))
+            .cloned()
+            .collect();
+        Some(Self {
+            from_sequence,
+            to_sequence,
+            event_count: events.len().min(u32::MAX as usize) as u32,
+            metadata_changed_asset_ids,
+            events,
+        })
+    }
 }
 
 impl Serializer for TokenEventsChangedNotification {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
-        store!(u16, &1, writer)?;
+        store!(u16, &3, writer)?;
         store!(u64, &self.from_sequence, writer)?;
         store!(u64, &self.to_sequence, writer)?;
         store!(u32, &self.event_count, writer)?;
+        store!(Vec<String>, &self.metadata_changed_asset_ids, writer)?;
+        store!(Vec<RpcTokenEvent>, &self.events, writer)?;
         Ok(())
     }
 }
 
 impl Deserializer for TokenEventsChangedNotification {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
-        let _version = load!(u16, reader)?;
+        let version = load!(u16, reader)?;
         let from_sequence = load!(u64, reader)?;
         let to_sequence = load!(u64, reader)?;
         let event_count = load!(u32, reader)?;
-        Ok(Self { from_sequence, to_sequence, event_count })
+        let metadata_changed_asset_ids = if version >= 2 { load!(Vec<String>, reader)? } else { Vec::new() };
+        let events = if version >= 3 { load!(Vec<RpcTokenEvent>, reader)? } else { Vec::new() };
+        Ok(Self { from_sequence, to_sequence, event_count, metadata_changed_asset_ids, events })
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// FastIntentStatusChangedNotification
+
+/// NotifyFastIntentStatusRequest registers this connection for fastIntentStatusChanged notifications.
+///
+/// A status change is notified if its intent id is listed or if its base transaction spends from or pays
+/// to one of the listed addresses. Leave both lists empty to get notified about every status change.
+/// A start replaces the current filter and a stop ends the subscription.
+///
+/// See: FastIntentStatusChangedNotification
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyFastIntentStatusRequest {
+    #[serde(default)]
+    pub intent_ids: Vec<RpcHash>,
+    #[serde(default)]
+    pub addresses: Vec<RpcAddress>,
+    pub command: Command,
+}
+
+impl NotifyFastIntentStatusRequest {
+    pub fn new(intent_ids: Vec<RpcHash>, addresses: Vec<RpcAddress>, command: Command) -> Self {
+        Self { intent_ids, addresses, command }
+    }
+}
+
+impl Serializer for NotifyFastIntentStatusRequest {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(Vec<RpcHash>, &self.intent_ids, writer)?;
+        store!(Vec<RpcAddress>, &self.addresses, writer)?;
+        store!(Command, &self.command, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for NotifyFastIntentStatusRequest {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let intent_ids = load!(Vec<RpcHash>, reader)?;
+        let addresses = load!(Vec<RpcAddress>, reader)?;
+        let command = load!(Command, reader)?;
+        Ok(Self { intent_ids, addresses, command })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyFastIntentStatusResponse {}
+
+impl Serializer for NotifyFastIntentStatusResponse {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for NotifyFastIntentStatusResponse {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        Ok(Self {})
+    }
+}
+
+/// FastIntentStatusChangedNotification is sent whenever an HFA fast intent changes status.
+///
+/// `addresses` lists the addresses the base transaction spends from or pays to, when known.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FastIntentStatusChangedNotification {
+    pub intent_id: RpcHash,
+    pub status: RpcFastIntentStatus,
+    pub reason: Option<String>,
+    pub base_tx_id: Option<RpcHash>,
+    pub node_epoch: u64,
+    pub changed_at_ms: u64,
+    #[serde(default)]
+    pub addresses: Vec<RpcAddress>,
+}
+
+impl FastIntentStatusChangedNotification {
+    pub(crate) fn apply_fast_intent_status_changed_subscription(
+        &self,
+        subscription: &FastIntentStatusChangedSubscription,
+    ) -> Option<Self> {
+        subscription.filter().matches(&self.intent_id, &self.addresses).then(|| self.clone())
+    }
+}
+
+impl Serializer for FastIntentStatusChangedNotification {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(RpcHash, &self.intent_id, writer)?;
+        store!(RpcFastIntentStatus, &self.status, writer)?;
+        store!(Option<String>, &self.reason, writer)?;
+        store!(Option<RpcHash>, &self.base_tx_id, writer)?;
+        store!(u64, &self.node_epoch, writer)?;
+        store!(u64, &self.changed_at_ms, writer)?;
+        store!(Vec<RpcAddress>, &self.addresses, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for FastIntentStatusChangedNotification {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let intent_id = load!(RpcHash, reader)?;
+        let status = load!(RpcFastIntentStatus, reader)?;
+        let reason = load!(Option<String>, reader)?;
+        let base_tx_id = load!(Option<RpcHash>, reader)?;
+        let node_epoch = load!(u64, reader)?;
+        let changed_at_ms = load!(u64, reader)?;
+        let addresses = load!(Vec<RpcAddress>, reader)?;
+        Ok(Self { intent_id, status, reason, base_tx_id, node_epoch, changed_at_ms, addresses })
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// MessagesReceivedNotification
+
+/// NotifyMessagesReceivedRequest registers this connection for messagesReceived notifications.
+///
+/// Only messages sent to one of the listed hex encoded recipient tags are notified. Leave the list
+/// empty to get notified about every indexed message. A start replaces the current filter and a
+/// stop ends the subscription.
+///
+/// This call is only useful when this cryptixd has the message index enabled.
+///
+/// See: MessagesReceivedNotification
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyMessagesReceivedRequest {
+    #[serde(default)]
+    pub recipient_tags: Vec<String>,
+    pub command: Command,
+}
+
+impl NotifyMessagesReceivedRequest {
+    pub fn new(recipient_tags: Vec<String>, command: Command) -> Self {
+        Self { recipient_tags, command }
+    }
+}
+
+impl Serializer for NotifyMessagesReceivedRequest {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(Vec<String>, &self.recipient_tags, writer)?;
+        store!(Command, &self.command, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for NotifyMessagesReceivedRequest {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let recipient_tags = load!(Vec<String>, reader)?;
+        let command = load!(Command, reader)?;
+        Ok(Self { recipient_tags, command })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyMessagesReceivedResponse {}
+
+impl Serializer for NotifyMessagesReceivedResponse {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for NotifyMessagesReceivedResponse {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        Ok(Self {})
+    }
+}
+
+/// MessagesReceivedNotification is sent whenever the message index has indexed new messages.
+///
+/// Each subscriber only gets the entries matching its recipient tag filter.
+///
+/// See: NotifyMessagesReceivedRequest
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MessagesReceivedNotification {
+    pub entries: Vec<RpcMessageEntry>,
+}
+
+impl MessagesReceivedNotification {
+    pub(crate) fn apply_messages_received_subscription(&self, subscription: &MessagesReceivedSubscription) -> Option<Self> {
+        if subscription.filter().is_wildcard() {
+            return Some(self.clone());
+        }
+        let entries =
+            self.entries.iter().filter(|entry| subscription.filter().matches(&entry.recipient_tag)).cloned().collect::<Vec<_>>();
+        (!entries.is_empty()).then_some(Self { entries })
+    }
+}
+
+impl Serializer for MessagesReceivedNotification {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        serialize!(Vec<RpcMessageEntry>, &self.entries, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for MessagesReceivedNotification {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let entries = deserialize!(Vec<RpcMessageEntry>, reader)?;
+        Ok(Self { entries })
     }
 }
 
@@ -425,6 +425,27 @@ mod mockery {
         }
     }
 
+    impl Mock for HfaMetrics {
+        fn mock() -> Self {
+            HfaMetrics {
+                enabled: true,
+                operator_paused: false,
+                mode: RpcHfaMode::Degraded,
+                submitted_count: mock(),
+                fast_confirmed_count: mock(),
+                rejected_count: mock(),
+                expired_count: mock(),
+                dropped_count: mock(),
+                pending_intents: mock(),
+                active_locks: mock(),
+                lock_conflict_count: mock(),
+                confirm_latency_ms_p95: mock(),
+                mode_transition_count: mock(),
+                fast_arbiter_queue_depth: mock(),
+            }
+        }
+    }
+
     // --------------------------------------------
     // implementations for all the rpc request
     // and response data structures.
@@ -1031,6 +1052,7 @@ mod mockery {
                 consensus_metrics: true,
                 storage_metrics: true,
                 custom_metrics: false,
+                hfa_metrics: true,
             }
         }
     }
@@ -1047,6 +1069,7 @@ mod mockery {
                 consensus_metrics: mock(),
                 storage_metrics: mock(),
                 custom_metrics: None,
+                hfa_metrics: mock(),
             }
         }
     }
@@ -1372,7 +1395,13 @@ mod mockery {
 
     impl Mock for NotifyTokenEventsRequest {
         fn mock() -> Self {
-            NotifyTokenEventsRequest { command: Command::Start }
+            NotifyTokenEventsRequest::with_filter(
+                vec![Hash::mock().to_string()],
+                vec![Hash::mock().to_string()],
+                vec![mock()],
+                vec![mock()],
+                Command::Start,
+            )
         }
     }
 
@@ -1388,12 +1417,337 @@ mod mockery {
 
     impl Mock for TokenEventsChangedNotification {
         fn mock() -> Self {
-            TokenEventsChangedNotification { from_sequence: mock(), to_sequence: mock(), event_count: mock() }
+            TokenEventsChangedNotification {
+                from_sequence: mock(),
+                to_sequence: mock(),
+                event_count: mock(),
+                metadata_changed_asset_ids: vec![Hash::mock().to_string()],
+                events: vec![RpcTokenEvent {
+                    event_id: Hash::mock().to_string(),
+                    sequence: mock(),
+                    accepting_block_hash: mock(),
+                    txid: mock(),
+                    event_type: mock(),
+                    apply_status: mock(),
+                    noop_reason: mock(),
+                    ordinal: mock(),
+                    reorg_of_event_id: None,
+                    op_type: mock(),
+                    asset_id: Some(Hash::mock().to_string()),
+                    from_owner_id: Some(Hash::mock().to_string()),
+                    to_owner_id: None,
+                    amount: Some(mock::<u64>().to_string()),
+                }],
+            }
         }
     }
 
     test!(TokenEventsChangedNotification);
 
+    #[test]
+    fn test_token_events_changed_filtering() {
+        use crate::Notification;
+        use cryptix_notify::{
+            scope::TokenEventsChangedScope,
+            subscription::{context::SubscriptionContext, single::TokenEventsChangedSubscription},
+        };
+
+        let event = |sequence: u64, asset_id: &str, owner_id: &str, op_type: u32| RpcTokenEvent {
+            event_id: Hash::mock().to_string(),
+            sequence,
+            accepting_block_hash: mock(),
+            txid: mock(),
+            event_type: 0,
+            apply_status: 0,
+            noop_reason: 0,
+            ordinal: 0,
+            reorg_of_event_id: None,
+            op_type: Some(op_type),
+            asset_id: Some(asset_id.to_string()),
+            from_owner_id: Some(owner_id.to_string()),
+            to_owner_id: None,
+            amount: None,
+        };
+        let notification = Notification::TokenEventsChanged(TokenEventsChangedNotification {
+            from_sequence: 1,
+            to_sequence: 3,
+            event_count: 3,
+            metadata_changed_asset_ids: vec!["aa".to_string(), "bb".to_string()],
+            events: vec![event(1, "aa", "01", 1), event(2, "bb", "02", 18), event(3, "aa", "02", 18)],
+        });
+        let context = SubscriptionContext::new();
+        let apply = |filter: TokenEventsChangedScope| {
+            cryptix_notify::notification::Notification::apply_token_events_changed_subscription(
+                &notification,
+                &TokenEventsChangedSubscription::new(true, filter),
+                &context,
+            )
+            .map(|notification| match notification {
+                Notification::TokenEventsChanged(notification) => notification,
+                _ => unreachable!(),
+            })
+        };
+
+        let all = apply(TokenEventsChangedScope::default()).unwrap();
+        assert_eq!((all.from_sequence, all.to_sequence, all.event_count, all.events.len()), (1, 3, 3, 3));
+
+        let by_asset = apply(TokenEventsChangedScope::new(vec!["AA".to_string()], vec![], vec![], vec![])).unwrap();
+        assert_eq!((by_asset.from_sequence, by_asset.to_sequence, by_asset.event_count), (1, 3, 2));
+        assert_eq!(by_asset.metadata_changed_asset_ids, vec!["aa".to_string()]);
+
+        let by_owner_and_op = apply(TokenEventsChangedScope::new(vec![], vec!["02".to_string()], vec![18], vec![])).unwrap();
+        assert_eq!(by_owner_and_op.events.iter().map(|event| event.sequence).collect::<Vec<_>>(), vec![2, 3]);
+
+        assert!(apply(TokenEventsChangedScope::new(vec!["cc".to_string()], vec![], vec![], vec![])).is_none());
+        assert!(apply(TokenEventsChangedScope::new(vec![], vec![], vec![], vec![1])).is_none());
+    }
+
+    impl Mock for NotifyFastIntentStatusRequest {
+        fn mock() -> Self {
+            NotifyFastIntentStatusRequest::new(vec![mock()], vec![mock()], Command::Start)
+        }
+    }
+
+    test!(NotifyFastIntentStatusRequest);
+
+    impl Mock for NotifyFastIntentStatusResponse {
+        fn mock() -> Self {
+            NotifyFastIntentStatusResponse {}
+        }
+    }
+
+    test!(NotifyFastIntentStatusResponse);
+
+    impl Mock for FastIntentStatusChangedNotification {
+        fn mock() -> Self {
+            FastIntentStatusChangedNotification {
+                intent_id: mock(),
+                status: RpcFastIntentStatus::FastConfirmed,
+                reason: Some("ttl_expired".to_string()),
+                base_tx_id: Some(mock()),
+                node_epoch: mock(),
+                changed_at_ms: mock(),
+                addresses: vec![mock()],
+            }
+        }
+    }
+
+    test!(FastIntentStatusChangedNotification);
+
+    #[test]
+    fn test_fast_intent_status_changed_filtering() {
+        use crate::Notification;
+        use cryptix_notify::{
+            scope::FastIntentStatusChangedScope,
+            subscription::{context::SubscriptionContext, single::FastIntentStatusChangedSubscription},
+        };
+
+        let intent_id = Hash::mock();
+        let address = RpcAddress::mock();
+        let notification = Notification::FastIntentStatusChanged(FastIntentStatusChangedNotification {
+            intent_id,
+            status: RpcFastIntentStatus::FastConfirmed,
+            reason: None,
+            base_tx_id: None,
+            node_epoch: 1,
+            changed_at_ms: 2,
+            addresses: vec![address.clone()],
+        });
+        let context = SubscriptionContext::new();
+        let apply = |filter: FastIntentStatusChangedScope| {
+            cryptix_notify::notification::Notification::apply_fast_intent_status_changed_subscription(
+                &notification,
+                &FastIntentStatusChangedSubscription::new(true, filter),
+                &context,
+            )
+        };
+
+        assert!(apply(FastIntentStatusChangedScope::default()).is_some());
+        assert!(apply(FastIntentStatusChangedScope::new(vec![intent_id], vec![])).is_some());
+        assert!(apply(FastIntentStatusChangedScope::new(vec![Hash::mock()], vec![address])).is_some());
+        assert!(apply(FastIntentStatusChangedScope::new(vec![Hash::mock()], vec![RpcAddress::mock()])).is_none());
+    }
+
+    impl Mock for RpcHfaConfig {
+        fn mock() -> Self {
+            RpcHfaConfig {
+                enabled: mock(),
+                paused: mock(),
+                cpu_low_water_ratio: mock(),
+                fast_ttl_ms: mock(),
+                clock_drift_max_ms: mock(),
+                terminal_retention_ms: mock(),
+                max_pending_intents: mock(),
+                max_locks: mock(),
+                max_terminal_entries: mock(),
+                max_terminal_bytes: mock(),
+                max_inputs_per_intent: mock(),
+                max_outputs_per_intent: mock(),
+                min_feerate_floor: mock(),
+                seen_cache_ttl_ms: mock(),
+                microblock_interval_ms_normal: mock(),
+                microblock_interval_ms_degraded: mock(),
+                revalidation_budget_normal: mock(),
+                revalidation_budget_degraded: mock(),
+                normal_arbiter_queue_capacity: mock(),
+                fast_arbiter_queue_capacity: mock(),
+            }
+        }
+    }
+
+    test!(RpcHfaConfig);
+
+    impl Mock for GetHfaConfigRequest {
+        fn mock() -> Self {
+            GetHfaConfigRequest {}
+        }
+    }
+
+    test!(GetHfaConfigRequest);
+
+    impl Mock for GetHfaConfigResponse {
+        fn mock() -> Self {
+            GetHfaConfigResponse { config: mock() }
+        }
+    }
+
+    test!(GetHfaConfigResponse);
+
+    impl Mock for SetHfaConfigRequest {
+        fn mock() -> Self {
+            SetHfaConfigRequest {
+                cpu_low_water_ratio: mock(),
+                fast_ttl_ms: mock(),
+                clock_drift_max_ms: mock(),
+                terminal_retention_ms: mock(),
+                max_pending_intents: mock(),
+                max_locks: mock(),
+                max_terminal_entries: mock(),
+                max_terminal_bytes: mock(),
+                max_inputs_per_intent: mock(),
+                max_outputs_per_intent: mock(),
+                min_feerate_floor: mock(),
+                seen_cache_ttl_ms: mock(),
+                microblock_interval_ms_normal: mock(),
+                microblock_interval_ms_degraded: mock(),
+                revalidation_budget_normal: mock(),
+                revalidation_budget_degraded: mock(),
+                normal_arbiter_queue_capacity: mock(),
+                fast_arbiter_queue_capacity: mock(),
+                paused: mock(),
+            }
+        }
+    }
+
+    test!(SetHfaConfigRequest);
+
+    impl Mock for SetHfaConfigResponse {
+        fn mock() -> Self {
+            SetHfaConfigResponse { config: mock() }
+        }
+    }
+
+    test!(SetHfaConfigResponse);
+
+    impl Mock for RpcMessageSender {
+        fn mock() -> Self {
+            RpcMessageSender { kind: 2, data: "0102030405060708090a0b0c0d0e0f10".to_string() }
+        }
+    }
+
+    test!(RpcMessageSender);
+
+    impl Mock for RpcMessageEntry {
+        fn mock() -> Self {
+            RpcMessageEntry {
+                sequence: mock(),
+                transaction_id: mock(),
+                accepting_block_hash: mock(),
+                accepting_daa_score: mock(),
+                accepting_block_time: mock(),
+                msg_type: mock(),
+                flags: mock(),
+                recipient_tag: "aa".repeat(16),
+                nonce: "bb".repeat(24),
+                sender: mock(),
+                body_hex: "68656c6c6f".to_string(),
+            }
+        }
+    }
+
+    test!(RpcMessageEntry);
+
+    impl Mock for GetMessagesByRecipientTagRequest {
+        fn mock() -> Self {
+            GetMessagesByRecipientTagRequest::new("aa".repeat(16), Some(mock()), mock(), mock())
+        }
+    }
+
+    test!(GetMessagesByRecipientTagRequest);
+
+    impl Mock for GetMessagesByRecipientTagResponse {
+        fn mock() -> Self {
+            GetMessagesByRecipientTagResponse { entries: vec![mock(), mock()], next_cursor: Some(mock()) }
+        }
+    }
+
+    test!(GetMessagesByRecipientTagResponse);
+
+    impl Mock for NotifyMessagesReceivedRequest {
+        fn mock() -> Self {
+            NotifyMessagesReceivedRequest::new(vec!["aa".repeat(16)], Command::Start)
+        }
+    }
+
+    test!(NotifyMessagesReceivedRequest);
+
+    impl Mock for NotifyMessagesReceivedResponse {
+        fn mock() -> Self {
+            NotifyMessagesReceivedResponse {}
+        }
+    }
+
+    test!(NotifyMessagesReceivedResponse);
+
+    impl Mock for MessagesReceivedNotification {
+        fn mock() -> Self {
+            MessagesReceivedNotification { entries: vec![mock()] }
+        }
+    }
+
+    test!(MessagesReceivedNotification);
+
+    #[test]
+    fn test_messages_received_filtering() {
+        use crate::Notification;
+        use cryptix_notify::{
+            scope::MessagesReceivedScope,
+            subscription::{context::SubscriptionContext, single::MessagesReceivedSubscription},
+        };
+
+        let entry = |sequence: u64, recipient_tag: String| RpcMessageEntry { sequence, recipient_tag, ..RpcMessageEntry::mock() };
+        let notification = Notification::MessagesReceived(MessagesReceivedNotification {
+            entries: vec![entry(1, "aa".repeat(16)), entry(2, "bb".repeat(16))],
+        });
+        let context = SubscriptionContext::new();
+        let apply = |filter: MessagesReceivedScope| {
+            cryptix_notify::notification::Notification::apply_messages_received_subscription(
+                &notification,
+                &MessagesReceivedSubscription::new(true, filter),
+                &context,
+            )
+            .map(|notification| match notification {
+                Notification::MessagesReceived(notification) => notification,
+                _ => unreachable!(),
+            })
+        };
+
+        assert_eq!(apply(MessagesReceivedScope::default()).unwrap().entries.len(), 2);
+        let by_tag = apply(MessagesReceivedScope::new(vec!["BB".repeat(16)])).unwrap();
+        assert_eq!(by_tag.entries.iter().map(|entry| entry.sequence).collect::<Vec<_>>(), vec![2]);
+        assert!(apply(MessagesReceivedScope::new(vec!["cc".repeat(16)])).is_none());
+    }
+
     impl Mock for SubscribeResponse {
         fn mock() -> Self {
             SubscribeResponse::new(mock())
@@ -116,6 +116,7 @@ declare! {
     export interface IRpcTokenOwnerBalance {
         assetId : string;
         balance : string;
+        lockedBalance : string;
         asset? : IRpcTokenAsset;
     }
     "#,
@@ -327,6 +328,7 @@ declare! {
      */
     export interface IGetTokenBalanceResponse {
         balance : string;
+        lockedBalance : string;
         context : IRpcTokenContext;
     }
     "#,
@@ -439,6 +441,326 @@ try_from! ( args: GetTokenAssetResponse, IGetTokenAssetResponse, {
     Ok(to_value(&args)?.into())
 });
 
+declare! {
+    IRpcTokenStateProof,
+    r#"
+    /**
+     * Atomic state proof, verifiable with `verifyTokenStateProof()`.
+     *
+     * @category Node RPC
+     */
+    export interface IRpcTokenStateProof {
+        logicalKey : HexString;
+        value? : HexString;
+        bucketIndex : number;
+        bucketSiblings : { logicalKey : HexString, value : HexString }[];
+        bucketHashes : HexString[];
+        stateRoot : HexString;
+    }
+    "#,
+}
+
+declare! {
+    IRpcTokenConsensusBalanceAnchor,
+    r#"
+    /**
+     * Consensus balance anchor, verifiable against `getConsensusAtomicStateHash`
+     * with `verifyTokenConsensusBalanceAnchor()`.
+     *
+     * @category Node RPC
+     */
+    export interface IRpcTokenConsensusBalanceAnchor {
+        version : number;
+        nonces : { count : number, xor : HexString };
+        assets : { count : number, xor : HexString };
+        anchorCounts : { count : number, xor : HexString };
+        allowances : { count : number, xor : HexString };
+        locks : { count : number, xor : HexString };
+        balances : { assetId : HexString, ownerId : HexString, amount : string }[];
+    }
+    "#,
+}
+
+declare! {
+    IGetTokenBalanceProofRequest,
+    r#"
+    /**
+     * @category Node RPC
+     */
+    export interface IGetTokenBalanceProofRequest {
+        assetId : string;
+        ownerId : string;
+        atBlockHash? : HexString;
+        includeConsensusAnchor? : boolean;
+    }
+    "#,
+}
+
+try_from! ( args: IGetTokenBalanceProofRequest, GetTokenBalanceProofRequest, {
+    Ok(from_value(args.into())?)
+});
+
+declare! {
+    IGetTokenBalanceProofResponse,
+    r#"
+    /**
+     * @category Node RPC
+     */
+    export interface IGetTokenBalanceProofResponse {
+        balance : string;
+        balanceProof : IRpcTokenStateProof;
+        expectedNextNonce : bigint;
+        nonceProof : IRpcTokenStateProof;
+        context : IRpcTokenContext;
+        consensusAnchor? : IRpcTokenConsensusBalanceAnchor;
+    }
+    "#,
+}
+
+try_from! ( args: GetTokenBalanceProofResponse, IGetTokenBalanceProofResponse, {
+    Ok(to_value(&args)?.into())
+});
+
+declare! {
+    IGetTokenAssetProofRequest,
+    r#"
+    /**
+     * @category Node RPC
+     */
+    export interface IGetTokenAssetProofRequest {
+        assetId : string;
+        atBlockHash? : HexString;
+    }
+    "#,
+}
+
+try_from! ( args: IGetTokenAssetProofRequest, GetTokenAssetProofRequest, {
+    Ok(from_value(args.into())?)
+});
+
+declare! {
+    IGetTokenAssetProofResponse,
+    r#"
+    /**
+     * @category Node RPC
+     */
+    export interface IGetTokenAssetProofResponse {
+        asset? : IRpcTokenAsset;
+        proof : IRpcTokenStateProof;
+        context : IRpcTokenContext;
+    }
+    "#,
+}
+
+try_from! ( args: GetTokenAssetProofResponse, IGetTokenAssetProofResponse, {
+    Ok(to_value(&args)?.into())
+});
+
+declare! {
+    IRpcTokenMetadataRevision,
+    r#"
+    /**
+     * Metadata revision applied to a Cryptix Atomic asset by an UpdateMetadata operation.
+     *
+     * @category Node RPC
+     */
+    export interface IRpcTokenMetadataRevision {
+        revision : bigint;
+        txid : HexString;
+        previousMetadataHex : HexString;
+        previousPlatformTag : string;
+        metadataHex : HexString;
+        platformTag : string;
+        updatedBlockHash : HexString;
+        updatedDaaScore : bigint;
+        updatedAt : bigint;
+    }
+    "#,
+}
+
+declare! {
+    IGetTokenAssetHistoryRequest,
+    r#"
+    /**
+     * @category Node RPC
+     */
+    export interface IGetTokenAssetHistoryRequest {
+        assetId : string;
+        atBlockHash? : HexString;
+    }
+    "#,
+}
+
+try_from! ( args: IGetTokenAssetHistoryRequest, GetTokenAssetHistoryRequest, {
+    Ok(from_value(args.into())?)
+});
+
+declare! {
+    IGetTokenAssetHistoryResponse,
+    r#"
+    /**
+     * @category Node RPC
+     */
+    export interface IGetTokenAssetHistoryResponse {
+        asset? : IRpcTokenAsset;
+        totalRevisions : bigint;
+        revisions : IRpcTokenMetadataRevision[];
+        context : IRpcTokenContext;
+    }
+    "#,
+}
+
+try_from! ( args: GetTokenAssetHistoryResponse, IGetTokenAssetHistoryResponse, {
+    Ok(to_value(&args)?.into())
+});
+
+declare! {
+    IRpcPairPoolState,
+    r#"
+    /**
+     * Reserve and share state of a Cryptix Atomic constant-product pair pool.
+     *
+     * @category Node RPC
+     */
+    export interface IRpcPairPoolState {
+        poolId : string;
+        assetAId : string;
+        assetBId : string;
+        feeBps : number;
+        reserveA : string;
+        reserveB : string;
+        totalShares : string;
+        lockedShares : string;
+        accruedFeeA : string;
+        accruedFeeB : string;
+    }
+    "#,
+}
+
+declare! {
+    IGetPairPoolRequest,
+    r#"
+    /**
+     * @category Node RPC
+     */
+    export interface IGetPairPoolRequest {
+        poolId : string;
+        atBlockHash? : HexString;
+    }
+    "#,
+}
+
+try_from! ( args: IGetPairPoolRequest, GetPairPoolRequest, {
+    Ok(from_value(args.into())?)
+});
+
+declare! {
+    IGetPairPoolResponse,
+    r#"
+    /**
+     * @category Node RPC
+     */
+    export interface IGetPairPoolResponse {
+        pool? : IRpcPairPoolState;
+        context : IRpcTokenContext;
+    }
+    "#,
+}
+
+try_from! ( args: GetPairPoolResponse, IGetPairPoolResponse, {
+    Ok(to_value(&args)?.into())
+});
+
+declare! {
+    IGetPairQuoteRequest,
+    r#"
+    /**
+     * @category Node RPC
+     */
+    export interface IGetPairQuoteRequest {
+        poolId : string;
+        assetInId : string;
+        exactOut : boolean;
+        amount : string;
+        atBlockHash? : HexString;
+    }
+    "#,
+}
+
+try_from! ( args: IGetPairQuoteRequest, GetPairQuoteRequest, {
+    Ok(from_value(args.into())?)
+});
+
+declare! {
+    IGetPairQuoteResponse,
+    r#"
+    /**
+     * @category Node RPC
+     */
+    export interface IGetPairQuoteResponse {
+        assetInId : string;
+        assetOutId : string;
+        amountIn : string;
+        feeAmount : string;
+        amountOut : string;
+        context : IRpcTokenContext;
+    }
+    "#,
+}
+
+try_from! ( args: GetPairQuoteResponse, IGetPairQuoteResponse, {
+    Ok(to_value(&args)?.into())
+});
+
+declare! {
+    IGetLiquidityCandlesRequest,
+    r#"
+    /**
+     * @category Node RPC
+     */
+    export interface IGetLiquidityCandlesRequest {
+        assetId : string;
+        intervalDaaScore : bigint;
+        fromDaaScore : bigint;
+        toDaaScore : bigint;
+    }
+    "#,
+}
+
+try_from! ( args: IGetLiquidityCandlesRequest, GetLiquidityCandlesRequest, {
+    Ok(from_value(args.into())?)
+});
+
+declare! {
+    IGetLiquidityCandlesResponse,
+    r#"
+    /**
+     * @category Node RPC
+     */
+    export interface IGetLiquidityCandlesResponse {
+        candles : IRpcLiquidityCandle[];
+        context : IRpcTokenContext;
+    }
+
+    /**
+     * @category Node RPC
+     */
+    export interface IRpcLiquidityCandle {
+        startDaaScore : bigint;
+        openPriceSompi : string;
+        highPriceSompi : string;
+        lowPriceSompi : string;
+        closePriceSompi : string;
+        volumeSompi : string;
+        tradeCount : bigint;
+    }
+    "#,
+}
+
+try_from! ( args: GetLiquidityCandlesResponse, IGetLiquidityCandlesResponse, {
+    Ok(to_value(&args)?.into())
+});
+
 declare! {
     IGetTokenOpStatusRequest,
     r#"
@@ -557,6 +879,11 @@ declare! {
         afterSequence : bigint;
         limit : number;
         atBlockHash? : HexString;
+        ownerId? : HexString;
+        assetId? : HexString;
+        opTypes? : number[];
+        fromDaaScore? : bigint;
+        toDaaScore? : bigint;
     }
     "#,
 }
@@ -574,6 +901,7 @@ declare! {
     export interface IGetTokenEventsResponse {
         events : IRpcTokenEvent[];
         context : IRpcTokenContext;
+        nextAfterSequence? : bigint;
     }
     "#,
 }
@@ -982,6 +1310,40 @@ try_from! ( args: ExportTokenSnapshotResponse, IExportTokenSnapshotResponse, {
     Ok(to_value(&args)?.into())
 });
 
+declare! {
+    IExportTokenAnalyticsRequest,
+    r#"
+    /**
+     * @category Node RPC
+     */
+    export interface IExportTokenAnalyticsRequest {
+        path : string;
+        atBlockHash? : HexString;
+    }
+    "#,
+}
+
+try_from! ( args: IExportTokenAnalyticsRequest, ExportTokenAnalyticsRequest, {
+    Ok(from_value(args.into())?)
+});
+
+declare! {
+    IExportTokenAnalyticsResponse,
+    r#"
+    /**
+     * @category Node RPC
+     */
+    export interface IExportTokenAnalyticsResponse {
+        manifestPath : string;
+        context : IRpcTokenContext;
+    }
+    "#,
+}
+
+try_from! ( args: ExportTokenAnalyticsResponse, IExportTokenAnalyticsResponse, {
+    Ok(to_value(&args)?.into())
+});
+
 declare! {
     IImportTokenSnapshotRequest,
     r#"
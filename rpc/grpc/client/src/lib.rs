@@ -280,11 +280,20 @@ impl RpcApi for GrpcClient {
     route!(submit_fast_intent_call, SubmitFastIntent);
     route!(get_fast_intent_status_call, GetFastIntentStatus);
     route!(cancel_fast_intent_call, CancelFastIntent);
+    route!(get_hfa_config_call, GetHfaConfig);
+    route!(set_hfa_config_call, SetHfaConfig);
+    route!(get_messages_by_recipient_tag_call, GetMessagesByRecipientTag);
     route!(get_strong_nodes_call, GetStrongNodes);
     route!(simulate_token_op_call, SimulateTokenOp);
     route!(get_token_balance_call, GetTokenBalance);
     route!(get_token_nonce_call, GetTokenNonce);
     route!(get_token_asset_call, GetTokenAsset);
+    route!(get_token_balance_proof_call, GetTokenBalanceProof);
+    route!(get_token_asset_proof_call, GetTokenAssetProof);
+    route!(get_token_asset_history_call, GetTokenAssetHistory);
+    route!(get_pair_pool_call, GetPairPool);
+    route!(get_pair_quote_call, GetPairQuote);
+    route!(get_liquidity_candles_call, GetLiquidityCandles);
     route!(get_token_op_status_call, GetTokenOpStatus);
     route!(get_token_state_hash_call, GetTokenStateHash);
     route!(get_token_spendability_call, GetTokenSpendability);
@@ -299,6 +308,7 @@ impl RpcApi for GrpcClient {
     route!(get_liquidity_claim_preview_call, GetLiquidityClaimPreview);
     route!(get_liquidity_holders_call, GetLiquidityHolders);
     route!(export_token_snapshot_call, ExportTokenSnapshot);
+    route!(export_token_analytics_call, ExportTokenAnalytics);
     route!(import_token_snapshot_call, ImportTokenSnapshot);
     route!(get_token_health_call, GetTokenHealth);
     route!(get_sc_bootstrap_sources_call, GetScBootstrapSources);
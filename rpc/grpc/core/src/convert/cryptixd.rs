@@ -66,11 +66,20 @@ pub mod cryptixd_request_convert {
     impl_into_cryptixd_request!(SubmitFastIntent);
     impl_into_cryptixd_request!(GetFastIntentStatus);
     impl_into_cryptixd_request!(CancelFastIntent);
+    impl_into_cryptixd_request!(GetHfaConfig);
+    impl_into_cryptixd_request!(SetHfaConfig);
+    impl_into_cryptixd_request!(GetMessagesByRecipientTag);
     impl_into_cryptixd_request!(GetStrongNodes);
     impl_into_cryptixd_request!(SimulateTokenOp);
     impl_into_cryptixd_request!(GetTokenBalance);
     impl_into_cryptixd_request!(GetTokenNonce);
     impl_into_cryptixd_request!(GetTokenAsset);
+    impl_into_cryptixd_request!(GetTokenBalanceProof);
+    impl_into_cryptixd_request!(GetTokenAssetProof);
+    impl_into_cryptixd_request!(GetTokenAssetHistory);
+    impl_into_cryptixd_request!(GetPairPool);
+    impl_into_cryptixd_request!(GetPairQuote);
+    impl_into_cryptixd_request!(GetLiquidityCandles);
     impl_into_cryptixd_request!(GetTokenOpStatus);
     impl_into_cryptixd_request!(GetTokenStateHash);
     impl_into_cryptixd_request!(GetTokenSpendability);
@@ -85,6 +94,7 @@ pub mod cryptixd_request_convert {
     impl_into_cryptixd_request!(GetLiquidityClaimPreview);
     impl_into_cryptixd_request!(GetLiquidityHolders);
     impl_into_cryptixd_request!(ExportTokenSnapshot);
+    impl_into_cryptixd_request!(ExportTokenAnalytics);
     impl_into_cryptixd_request!(ImportTokenSnapshot);
     impl_into_cryptixd_request!(GetTokenHealth);
     impl_into_cryptixd_request!(GetScBootstrapSources);
@@ -107,6 +117,8 @@ pub mod cryptixd_request_convert {
     impl_into_cryptixd_request!(NotifyVirtualChainChanged);
     impl_into_cryptixd_request!(NotifySinkBlueScoreChanged);
     impl_into_cryptixd_request!(NotifyTokenEvents);
+    impl_into_cryptixd_request!(NotifyFastIntentStatus);
+    impl_into_cryptixd_request!(NotifyMessagesReceived);
 
     macro_rules! impl_into_cryptixd_request {
         ($name:tt) => {
@@ -238,11 +250,20 @@ pub mod cryptixd_response_convert {
     impl_into_cryptixd_response!(SubmitFastIntent);
     impl_into_cryptixd_response!(GetFastIntentStatus);
     impl_into_cryptixd_response!(CancelFastIntent);
+    impl_into_cryptixd_response!(GetHfaConfig);
+    impl_into_cryptixd_response!(SetHfaConfig);
+    impl_into_cryptixd_response!(GetMessagesByRecipientTag);
     impl_into_cryptixd_response!(GetStrongNodes);
     impl_into_cryptixd_response!(SimulateTokenOp);
     impl_into_cryptixd_response!(GetTokenBalance);
     impl_into_cryptixd_response!(GetTokenNonce);
     impl_into_cryptixd_response!(GetTokenAsset);
+    impl_into_cryptixd_response!(GetTokenBalanceProof);
+    impl_into_cryptixd_response!(GetTokenAssetProof);
+    impl_into_cryptixd_response!(GetTokenAssetHistory);
+    impl_into_cryptixd_response!(GetPairPool);
+    impl_into_cryptixd_response!(GetPairQuote);
+    impl_into_cryptixd_response!(GetLiquidityCandles);
     impl_into_cryptixd_response!(GetTokenOpStatus);
     impl_into_cryptixd_response!(GetTokenStateHash);
     impl_into_cryptixd_response!(GetTokenSpendability);
@@ -257,6 +278,7 @@ pub mod cryptixd_response_convert {
     impl_into_cryptixd_response!(GetLiquidityClaimPreview);
     impl_into_cryptixd_response!(GetLiquidityHolders);
     impl_into_cryptixd_response!(ExportTokenSnapshot);
+    impl_into_cryptixd_response!(ExportTokenAnalytics);
     impl_into_cryptixd_response!(ImportTokenSnapshot);
     impl_into_cryptixd_response!(GetTokenHealth);
     impl_into_cryptixd_response!(GetScBootstrapSources);
@@ -279,6 +301,8 @@ pub mod cryptixd_response_convert {
     impl_into_cryptixd_notify_response!(NotifyVirtualChainChanged);
     impl_into_cryptixd_notify_response!(NotifySinkBlueScoreChanged);
     impl_into_cryptixd_notify_response!(NotifyTokenEvents);
+    impl_into_cryptixd_notify_response!(NotifyFastIntentStatus);
+    impl_into_cryptixd_notify_response!(NotifyMessagesReceived);
 
     impl_into_cryptixd_notify_response!(NotifyUtxosChanged, StopNotifyingUtxosChanged);
     impl_into_cryptixd_notify_response!(NotifyPruningPointUtxoSetOverride, StopNotifyingPruningPointUtxoSetOverride);
@@ -122,7 +122,7 @@ macro_rules! try_from {
     };
 }
 
-fn fast_intent_status_to_proto(status: RpcFastIntentStatus) -> &'static str {
+pub(crate) fn fast_intent_status_to_proto(status: RpcFastIntentStatus) -> &'static str {
     match status {
         RpcFastIntentStatus::Received => "received",
         RpcFastIntentStatus::Validated => "validated",
@@ -136,7 +136,7 @@ fn fast_intent_status_to_proto(status: RpcFastIntentStatus) -> &'static str {
     }
 }
 
-fn fast_intent_status_from_proto(status: &str) -> RpcResult<RpcFastIntentStatus> {
+pub(crate) fn fast_intent_status_from_proto(status: &str) -> RpcResult<RpcFastIntentStatus> {
     match status {
         "received" => Ok(RpcFastIntentStatus::Received),
         "validated" => Ok(RpcFastIntentStatus::Validated),
@@ -223,10 +223,30 @@ from!(item: &cryptix_rpc_core::NotifyNewBlockTemplateRequest, protowire::NotifyN
 from!(RpcResult<&cryptix_rpc_core::NotifyNewBlockTemplateResponse>, protowire::NotifyNewBlockTemplateResponseMessage);
 
 from!(item: &cryptix_rpc_core::NotifyTokenEventsRequest, protowire::NotifyTokenEventsRequestMessage, {
-    Self { command: item.command.into() }
+    Self {
+        asset_ids: item.asset_ids.clone(),
+        owner_ids: item.owner_ids.clone(),
+        op_types: item.op_types.clone(),
+        apply_statuses: item.apply_statuses.clone(),
+        command: item.command.into(),
+    }
 });
 from!(RpcResult<&cryptix_rpc_core::NotifyTokenEventsResponse>, protowire::NotifyTokenEventsResponseMessage);
 
+from!(item: &cryptix_rpc_core::NotifyFastIntentStatusRequest, protowire::NotifyFastIntentStatusRequestMessage, {
+    Self {
+        intent_ids: item.intent_ids.iter().map(|x| x.to_string()).collect(),
+        addresses: item.addresses.iter().map(|x| x.into()).collect(),
+        command: item.command.into(),
+    }
+});
+from!(RpcResult<&cryptix_rpc_core::NotifyFastIntentStatusResponse>, protowire::NotifyFastIntentStatusResponseMessage);
+
+from!(item: &cryptix_rpc_core::NotifyMessagesReceivedRequest, protowire::NotifyMessagesReceivedRequestMessage, {
+    Self { recipient_tags: item.recipient_tags.clone(), command: item.command.into() }
+});
+from!(RpcResult<&cryptix_rpc_core::NotifyMessagesReceivedResponse>, protowire::NotifyMessagesReceivedResponseMessage);
+
 // ~~~
 
 from!(&cryptix_rpc_core::GetCurrentNetworkRequest, protowire::GetCurrentNetworkRequestMessage);
@@ -527,6 +547,93 @@ from!(item: RpcResult<&cryptix_rpc_core::CancelFastIntentResponse>, protowire::C
     }
 });
 
+from!(item: &cryptix_rpc_core::RpcHfaConfig, protowire::RpcHfaConfigMessage, {
+    Self {
+        enabled: item.enabled,
+        paused: item.paused,
+        cpu_low_water_ratio: item.cpu_low_water_ratio,
+        fast_ttl_ms: item.fast_ttl_ms,
+        clock_drift_max_ms: item.clock_drift_max_ms,
+        terminal_retention_ms: item.terminal_retention_ms,
+        max_pending_intents: item.max_pending_intents,
+        max_locks: item.max_locks,
+        max_terminal_entries: item.max_terminal_entries,
+        max_terminal_bytes: item.max_terminal_bytes,
+        max_inputs_per_intent: item.max_inputs_per_intent,
+        max_outputs_per_intent: item.max_outputs_per_intent,
+        min_feerate_floor: item.min_feerate_floor,
+        seen_cache_ttl_ms: item.seen_cache_ttl_ms,
+        microblock_interval_ms_normal: item.microblock_interval_ms_normal,
+        microblock_interval_ms_degraded: item.microblock_interval_ms_degraded,
+        revalidation_budget_normal: item.revalidation_budget_normal,
+        revalidation_budget_degraded: item.revalidation_budget_degraded,
+        normal_arbiter_queue_capacity: item.normal_arbiter_queue_capacity,
+        fast_arbiter_queue_capacity: item.fast_arbiter_queue_capacity,
+    }
+});
+
+from!(&cryptix_rpc_core::GetHfaConfigRequest, protowire::GetHfaConfigRequestMessage);
+from!(item: RpcResult<&cryptix_rpc_core::GetHfaConfigResponse>, protowire::GetHfaConfigResponseMessage, {
+    Self { config: Some((&item.config).into()), error: None }
+});
+
+from!(item: &cryptix_rpc_core::SetHfaConfigRequest, protowire::SetHfaConfigRequestMessage, {
+    Self {
+        cpu_low_water_ratio: item.cpu_low_water_ratio,
+        fast_ttl_ms: item.fast_ttl_ms,
+        clock_drift_max_ms: item.clock_drift_max_ms,
+        terminal_retention_ms: item.terminal_retention_ms,
+        max_pending_intents: item.max_pending_intents,
+        max_locks: item.max_locks,
+        max_terminal_entries: item.max_terminal_entries,
+        max_terminal_bytes: item.max_terminal_bytes,
+        max_inputs_per_intent: item.max_inputs_per_intent,
+        max_outputs_per_intent: item.max_outputs_per_intent,
+        min_feerate_floor: item.min_feerate_floor,
+        seen_cache_ttl_ms: item.seen_cache_ttl_ms,
+        microblock_interval_ms_normal: item.microblock_interval_ms_normal,
+        microblock_interval_ms_degraded: item.microblock_interval_ms_degraded,
+        revalidation_budget_normal: item.revalidation_budget_normal,
+        revalidation_budget_degraded: item.revalidation_budget_degraded,
+        normal_arbiter_queue_capacity: item.normal_arbiter_queue_capacity,
+        fast_arbiter_queue_capacity: item.fast_arbiter_queue_capacity,
+        paused: item.paused,
+    }
+});
+from!(item: RpcResult<&cryptix_rpc_core::SetHfaConfigResponse>, protowire::SetHfaConfigResponseMessage, {
+    Self { config: Some((&item.config).into()), error: None }
+});
+
+from!(item: &cryptix_rpc_core::RpcMessageSender, protowire::RpcMessageSender, {
+    Self { kind: item.kind.into(), data: item.data.clone() }
+});
+from!(item: &cryptix_rpc_core::RpcMessageEntry, protowire::RpcMessageEntry, {
+    Self {
+        sequence: item.sequence,
+        transaction_id: item.transaction_id.to_string(),
+        accepting_block_hash: item.accepting_block_hash.to_string(),
+        accepting_daa_score: item.accepting_daa_score,
+        accepting_block_time: item.accepting_block_time,
+        msg_type: item.msg_type.into(),
+        flags: item.flags.into(),
+        recipient_tag: item.recipient_tag.clone(),
+        nonce: item.nonce.clone(),
+        sender: Some((&item.sender).into()),
+        body_hex: item.body_hex.clone(),
+    }
+});
+from!(item: &cryptix_rpc_core::GetMessagesByRecipientTagRequest, protowire::GetMessagesByRecipientTagRequestMessage, {
+    Self {
+        recipient_tag: item.recipient_tag.clone(),
+        sender: item.sender.as_ref().map(Into::into),
+        cursor: item.cursor,
+        limit: item.limit,
+    }
+});
+from!(item: RpcResult<&cryptix_rpc_core::GetMessagesByRecipientTagResponse>, protowire::GetMessagesByRecipientTagResponseMessage, {
+    Self { entries: item.entries.iter().map(Into::into).collect(), next_cursor: item.next_cursor, error: None }
+});
+
 from!(&cryptix_rpc_core::PingRequest, protowire::PingRequestMessage);
 from!(RpcResult<&cryptix_rpc_core::PingResponse>, protowire::PingResponseMessage);
 
@@ -538,6 +645,7 @@ from!(item: &cryptix_rpc_core::GetMetricsRequest, protowire::GetMetricsRequestMe
         consensus_metrics: item.consensus_metrics,
         storage_metrics: item.storage_metrics,
         custom_metrics: item.custom_metrics,
+        hfa_metrics: item.hfa_metrics,
     }
 });
 from!(item: RpcResult<&cryptix_rpc_core::GetMetricsResponse>, protowire::GetMetricsResponseMessage, {
@@ -553,6 +661,7 @@ from!(item: RpcResult<&cryptix_rpc_core::GetMetricsResponse>, protowire::GetMetr
             .as_ref()
             .map(|m| m.iter().map(|(k, v)| (k.clone(), v.into())).collect())
             .unwrap_or_default(),
+        hfa_metrics: item.hfa_metrics.as_ref().map(|x| x.into()),
         error: None,
     }
 });
@@ -680,9 +789,40 @@ from!(item: &cryptix_rpc_core::RpcTokenOwnerBalance, protowire::RpcTokenOwnerBal
     Self {
         asset_id: item.asset_id.clone(),
         balance: item.balance.clone(),
+        locked_balance: item.locked_balance.clone(),
         asset: item.asset.as_ref().map(Into::into),
     }
 });
+from!(item: &cryptix_rpc_core::RpcTokenStateLeaf, protowire::RpcTokenStateLeafMessage, {
+    Self { logical_key: item.logical_key.clone(), value: item.value.clone() }
+});
+from!(item: &cryptix_rpc_core::RpcTokenStateProof, protowire::RpcTokenStateProofMessage, {
+    Self {
+        logical_key: item.logical_key.clone(),
+        value: item.value.clone(),
+        bucket_index: item.bucket_index,
+        bucket_siblings: item.bucket_siblings.iter().map(Into::into).collect(),
+        bucket_hashes: item.bucket_hashes.clone(),
+        state_root: item.state_root.clone(),
+    }
+});
+from!(item: &cryptix_rpc_core::RpcTokenConsensusDigest, protowire::RpcTokenConsensusDigestMessage, {
+    Self { count: item.count, xor: item.xor.clone() }
+});
+from!(item: &cryptix_rpc_core::RpcTokenConsensusBalance, protowire::RpcTokenConsensusBalanceMessage, {
+    Self { asset_id: item.asset_id.clone(), owner_id: item.owner_id.clone(), amount: item.amount.clone() }
+});
+from!(item: &cryptix_rpc_core::RpcTokenConsensusBalanceAnchor, protowire::RpcTokenConsensusBalanceAnchorMessage, {
+    Self {
+        version: item.version,
+        nonces: Some((&item.nonces).into()),
+        assets: Some((&item.assets).into()),
+        anchor_counts: Some((&item.anchor_counts).into()),
+        allowances: Some((&item.allowances).into()),
+        locks: Some((&item.locks).into()),
+        balances: item.balances.iter().map(Into::into).collect(),
+    }
+});
 from!(item: &cryptix_rpc_core::RpcTokenHolder, protowire::RpcTokenHolderMessage, {
     Self { owner_id: item.owner_id.clone(), balance: item.balance.clone() }
 });
@@ -750,7 +890,12 @@ from!(item: &cryptix_rpc_core::GetTokenBalanceRequest, protowire::GetTokenBalanc
     }
 });
 from!(item: RpcResult<&cryptix_rpc_core::GetTokenBalanceResponse>, protowire::GetTokenBalanceResponseMessage, {
-    Self { balance: item.balance.clone(), context: Some((&item.context).into()), error: None }
+    Self {
+        balance: item.balance.clone(),
+        locked_balance: item.locked_balance.clone(),
+        context: Some((&item.context).into()),
+        error: None,
+    }
 });
 from!(item: &cryptix_rpc_core::GetTokenNonceRequest, protowire::GetTokenNonceRequestMessage, {
     Self {
@@ -768,6 +913,123 @@ from!(item: &cryptix_rpc_core::GetTokenAssetRequest, protowire::GetTokenAssetReq
 from!(item: RpcResult<&cryptix_rpc_core::GetTokenAssetResponse>, protowire::GetTokenAssetResponseMessage, {
     Self { asset: item.asset.as_ref().map(Into::into), context: Some((&item.context).into()), error: None }
 });
+from!(item: &cryptix_rpc_core::GetTokenBalanceProofRequest, protowire::GetTokenBalanceProofRequestMessage, {
+    Self {
+        asset_id: item.asset_id.clone(),
+        owner_id: item.owner_id.clone(),
+        at_block_hash: item.at_block_hash.map(|hash| hash.to_string()),
+        include_consensus_anchor: item.include_consensus_anchor,
+    }
+});
+from!(item: RpcResult<&cryptix_rpc_core::GetTokenBalanceProofResponse>, protowire::GetTokenBalanceProofResponseMessage, {
+    Self {
+        balance: item.balance.clone(),
+        balance_proof: Some((&item.balance_proof).into()),
+        expected_next_nonce: item.expected_next_nonce,
+        nonce_proof: Some((&item.nonce_proof).into()),
+        context: Some((&item.context).into()),
+        consensus_anchor: item.consensus_anchor.as_ref().map(Into::into),
+        error: None,
+    }
+});
+from!(item: &cryptix_rpc_core::GetTokenAssetProofRequest, protowire::GetTokenAssetProofRequestMessage, {
+    Self { asset_id: item.asset_id.clone(), at_block_hash: item.at_block_hash.map(|hash| hash.to_string()) }
+});
+from!(item: RpcResult<&cryptix_rpc_core::GetTokenAssetProofResponse>, protowire::GetTokenAssetProofResponseMessage, {
+    Self {
+        asset: item.asset.as_ref().map(Into::into),
+        proof: Some((&item.proof).into()),
+        context: Some((&item.context).into()),
+        error: None,
+    }
+});
+from!(item: &cryptix_rpc_core::RpcTokenMetadataRevision, protowire::RpcTokenMetadataRevisionMessage, {
+    Self {
+        revision: item.revision,
+        txid: item.txid.to_string(),
+        previous_metadata_hex: item.previous_metadata_hex.clone(),
+        previous_platform_tag: item.previous_platform_tag.clone(),
+        metadata_hex: item.metadata_hex.clone(),
+        platform_tag: item.platform_tag.clone(),
+        updated_block_hash: item.updated_block_hash.to_string(),
+        updated_daa_score: item.updated_daa_score,
+        updated_at: item.updated_at,
+    }
+});
+from!(item: &cryptix_rpc_core::GetTokenAssetHistoryRequest, protowire::GetTokenAssetHistoryRequestMessage, {
+    Self { asset_id: item.asset_id.clone(), at_block_hash: item.at_block_hash.map(|hash| hash.to_string()) }
+});
+from!(item: RpcResult<&cryptix_rpc_core::GetTokenAssetHistoryResponse>, protowire::GetTokenAssetHistoryResponseMessage, {
+    Self {
+        asset: item.asset.as_ref().map(Into::into),
+        total_revisions: item.total_revisions,
+        revisions: item.revisions.iter().map(Into::into).collect(),
+        context: Some((&item.context).into()),
+        error: None,
+    }
+});
+from!(item: &cryptix_rpc_core::RpcPairPoolState, protowire::RpcPairPoolStateMessage, {
+    Self {
+        pool_id: item.pool_id.clone(),
+        asset_a_id: item.asset_a_id.clone(),
+        asset_b_id: item.asset_b_id.clone(),
+        fee_bps: item.fee_bps,
+        reserve_a: item.reserve_a.clone(),
+        reserve_b: item.reserve_b.clone(),
+        total_shares: item.total_shares.clone(),
+        locked_shares: item.locked_shares.clone(),
+        accrued_fee_a: item.accrued_fee_a.clone(),
+        accrued_fee_b: item.accrued_fee_b.clone(),
+    }
+});
+from!(item: &cryptix_rpc_core::GetPairPoolRequest, protowire::GetPairPoolRequestMessage, {
+    Self { pool_id: item.pool_id.clone(), at_block_hash: item.at_block_hash.map(|hash| hash.to_string()) }
+});
+from!(item: RpcResult<&cryptix_rpc_core::GetPairPoolResponse>, protowire::GetPairPoolResponseMessage, {
+    Self { pool: item.pool.as_ref().map(Into::into), context: Some((&item.context).into()), error: None }
+});
+from!(item: &cryptix_rpc_core::GetPairQuoteRequest, protowire::GetPairQuoteRequestMessage, {
+    Self {
+        pool_id: item.pool_id.clone(),
+        asset_in_id: item.asset_in_id.clone(),
+        exact_out: item.exact_out,
+        amount: item.amount.clone(),
+        at_block_hash: item.at_block_hash.map(|hash| hash.to_string()),
+    }
+});
+from!(item: RpcResult<&cryptix_rpc_core::GetPairQuoteResponse>, protowire::GetPairQuoteResponseMessage, {
+    Self {
+        asset_in_id: item.asset_in_id.clone(),
+        asset_out_id: item.asset_out_id.clone(),
+        amount_in: item.amount_in.clone(),
+        fee_amount: item.fee_amount.clone(),
+        amount_out: item.amount_out.clone(),
+        context: Some((&item.context).into()),
+        error: None,
+    }
+});
+from!(item: &cryptix_rpc_core::RpcLiquidityCandle, protowire::RpcLiquidityCandleMessage, {
+    Self {
+        start_daa_score: item.start_daa_score,
+        open_price_sompi: item.open_price_sompi.clone(),
+        high_price_sompi: item.high_price_sompi.clone(),
+        low_price_sompi: item.low_price_sompi.clone(),
+        close_price_sompi: item.close_price_sompi.clone(),
+        volume_sompi: item.volume_sompi.clone(),
+        trade_count: item.trade_count,
+    }
+});
+from!(item: &cryptix_rpc_core::GetLiquidityCandlesRequest, protowire::GetLiquidityCandlesRequestMessage, {
+    Self {
+        asset_id: item.asset_id.clone(),
+        interval_daa_score: item.interval_daa_score,
+        from_daa_score: item.from_daa_score,
+        to_daa_score: item.to_daa_score,
+    }
+});
+from!(item: RpcResult<&cryptix_rpc_core::GetLiquidityCandlesResponse>, protowire::GetLiquidityCandlesResponseMessage, {
+    Self { candles: item.candles.iter().map(Into::into).collect(), context: Some((&item.context).into()), error: None }
+});
 from!(item: &cryptix_rpc_core::GetTokenOpStatusRequest, protowire::GetTokenOpStatusRequestMessage, {
     Self { txid: item.txid.to_string(), at_block_hash: item.at_block_hash.map(|hash| hash.to_string()) }
 });
@@ -810,10 +1072,20 @@ from!(item: &cryptix_rpc_core::GetTokenEventsRequest, protowire::GetTokenEventsR
         after_sequence: item.after_sequence,
         limit: item.limit,
         at_block_hash: item.at_block_hash.map(|hash| hash.to_string()),
+        owner_id: item.owner_id.clone(),
+        asset_id: item.asset_id.clone(),
+        op_types: item.op_types.clone(),
+        from_daa_score: item.from_daa_score,
+        to_daa_score: item.to_daa_score,
     }
 });
 from!(item: RpcResult<&cryptix_rpc_core::GetTokenEventsResponse>, protowire::GetTokenEventsResponseMessage, {
-    Self { events: item.events.iter().map(Into::into).collect(), context: Some((&item.context).into()), error: None }
+    Self {
+        events: item.events.iter().map(Into::into).collect(),
+        context: Some((&item.context).into()),
+        next_after_sequence: item.next_after_sequence,
+        error: None,
+    }
 });
 from!(item: &cryptix_rpc_core::GetTokenAssetsRequest, protowire::GetTokenAssetsRequestMessage, {
     Self {
@@ -962,6 +1234,12 @@ from!(item: &cryptix_rpc_core::ExportTokenSnapshotRequest, protowire::ExportToke
 from!(item: RpcResult<&cryptix_rpc_core::ExportTokenSnapshotResponse>, protowire::ExportTokenSnapshotResponseMessage, {
     Self { exported: item.exported, context: Some((&item.context).into()), error: None }
 });
+from!(item: &cryptix_rpc_core::ExportTokenAnalyticsRequest, protowire::ExportTokenAnalyticsRequestMessage, {
+    Self { path: item.path.clone(), at_block_hash: item.at_block_hash.map(|hash| hash.to_string()) }
+});
+from!(item: RpcResult<&cryptix_rpc_core::ExportTokenAnalyticsResponse>, protowire::ExportTokenAnalyticsResponseMessage, {
+    Self { manifest_path: item.manifest_path.clone(), context: Some((&item.context).into()), error: None }
+});
 from!(item: &cryptix_rpc_core::ImportTokenSnapshotRequest, protowire::ImportTokenSnapshotRequestMessage, {
     Self { path: item.path.clone() }
 });
@@ -1536,6 +1814,112 @@ try_from!(item: &protowire::CancelFastIntentResponseMessage, RpcResult<cryptix_r
     }
 });
 
+try_from!(item: &protowire::RpcHfaConfigMessage, cryptix_rpc_core::RpcHfaConfig, {
+    Self {
+        enabled: item.enabled,
+        paused: item.paused,
+        cpu_low_water_ratio: item.cpu_low_water_ratio,
+        fast_ttl_ms: item.fast_ttl_ms,
+        clock_drift_max_ms: item.clock_drift_max_ms,
+        terminal_retention_ms: item.terminal_retention_ms,
+        max_pending_intents: item.max_pending_intents,
+        max_locks: item.max_locks,
+        max_terminal_entries: item.max_terminal_entries,
+        max_terminal_bytes: item.max_terminal_bytes,
+        max_inputs_per_intent: item.max_inputs_per_intent,
+        max_outputs_per_intent: item.max_outputs_per_intent,
+        min_feerate_floor: item.min_feerate_floor,
+        seen_cache_ttl_ms: item.seen_cache_ttl_ms,
+        microblock_interval_ms_normal: item.microblock_interval_ms_normal,
+        microblock_interval_ms_degraded: item.microblock_interval_ms_degraded,
+        revalidation_budget_normal: item.revalidation_budget_normal,
+        revalidation_budget_degraded: item.revalidation_budget_degraded,
+        normal_arbiter_queue_capacity: item.normal_arbiter_queue_capacity,
+        fast_arbiter_queue_capacity: item.fast_arbiter_queue_capacity,
+    }
+});
+
+try_from!(&protowire::GetHfaConfigRequestMessage, cryptix_rpc_core::GetHfaConfigRequest);
+try_from!(item: &protowire::GetHfaConfigResponseMessage, RpcResult<cryptix_rpc_core::GetHfaConfigResponse>, {
+    Self {
+        config: item
+            .config
+            .as_ref()
+            .ok_or_else(|| RpcError::MissingRpcFieldError("GetHfaConfigResponseMessage".to_string(), "config".to_string()))?
+            .try_into()?,
+    }
+});
+
+try_from!(item: &protowire::SetHfaConfigRequestMessage, cryptix_rpc_core::SetHfaConfigRequest, {
+    Self {
+        cpu_low_water_ratio: item.cpu_low_water_ratio,
+        fast_ttl_ms: item.fast_ttl_ms,
+        clock_drift_max_ms: item.clock_drift_max_ms,
+        terminal_retention_ms: item.terminal_retention_ms,
+        max_pending_intents: item.max_pending_intents,
+        max_locks: item.max_locks,
+        max_terminal_entries: item.max_terminal_entries,
+        max_terminal_bytes: item.max_terminal_bytes,
+        max_inputs_per_intent: item.max_inputs_per_intent,
+        max_outputs_per_intent: item.max_outputs_per_intent,
+        min_feerate_floor: item.min_feerate_floor,
+        seen_cache_ttl_ms: item.seen_cache_ttl_ms,
+        microblock_interval_ms_normal: item.microblock_interval_ms_normal,
+        microblock_interval_ms_degraded: item.microblock_interval_ms_degraded,
+        revalidation_budget_normal: item.revalidation_budget_normal,
+        revalidation_budget_degraded: item.revalidation_budget_degraded,
+        normal_arbiter_queue_capacity: item.normal_arbiter_queue_capacity,
+        fast_arbiter_queue_capacity: item.fast_arbiter_queue_capacity,
+        paused: item.paused,
+    }
+});
+try_from!(item: &protowire::SetHfaConfigResponseMessage, RpcResult<cryptix_rpc_core::SetHfaConfigResponse>, {
+    Self {
+        config: item
+            .config
+            .as_ref()
+            .ok_or_else(|| RpcError::MissingRpcFieldError("SetHfaConfigResponseMessage".to_string(), "config".to_string()))?
+            .try_into()?,
+    }
+});
+
+try_from!(item: &protowire::RpcMessageSender, cryptix_rpc_core::RpcMessageSender, {
+    Self { kind: u8::try_from(item.kind)?, data: item.data.clone() }
+});
+try_from!(item: &protowire::RpcMessageEntry, cryptix_rpc_core::RpcMessageEntry, {
+    Self {
+        sequence: item.sequence,
+        transaction_id: RpcHash::from_str(&item.transaction_id)?,
+        accepting_block_hash: RpcHash::from_str(&item.accepting_block_hash)?,
+        accepting_daa_score: item.accepting_daa_score,
+        accepting_block_time: item.accepting_block_time,
+        msg_type: u8::try_from(item.msg_type)?,
+        flags: u8::try_from(item.flags)?,
+        recipient_tag: item.recipient_tag.clone(),
+        nonce: item.nonce.clone(),
+        sender: item
+            .sender
+            .as_ref()
+            .ok_or_else(|| RpcError::MissingRpcFieldError("RpcMessageEntry".to_string(), "sender".to_string()))?
+            .try_into()?,
+        body_hex: item.body_hex.clone(),
+    }
+});
+try_from!(item: &protowire::GetMessagesByRecipientTagRequestMessage, cryptix_rpc_core::GetMessagesByRecipientTagRequest, {
+    Self {
+        recipient_tag: item.recipient_tag.clone(),
+        sender: item.sender.as_ref().map(|x| x.try_into()).transpose()?,
+        cursor: item.cursor,
+        limit: item.limit,
+    }
+});
+try_from!(item: &protowire::GetMessagesByRecipientTagResponseMessage, RpcResult<cryptix_rpc_core::GetMessagesByRecipientTagResponse>, {
+    Self {
+        entries: item.entries.iter().map(|x| x.try_into()).collect::<Result<Vec<_>, _>>()?,
+        next_cursor: item.next_cursor,
+    }
+});
+
 try_from!(&protowire::PingRequestMessage, cryptix_rpc_core::PingRequest);
 try_from!(&protowire::PingResponseMessage, RpcResult<cryptix_rpc_core::PingResponse>);
 
@@ -1547,6 +1931,7 @@ try_from!(item: &protowire::GetMetricsRequestMessage, cryptix_rpc_core::GetMetri
         consensus_metrics: item.consensus_metrics,
         storage_metrics: item.storage_metrics,
         custom_metrics : item.custom_metrics,
+        hfa_metrics: item.hfa_metrics,
     }
 });
 try_from!(item: &protowire::GetMetricsResponseMessage, RpcResult<cryptix_rpc_core::GetMetricsResponse>, {
@@ -1567,6 +1952,7 @@ try_from!(item: &protowire::GetMetricsResponseMessage, RpcResult<cryptix_rpc_cor
                     .collect::<RpcResult<HashMap<String, cryptix_rpc_core::CustomMetricValue>>>()?,
             )
         },
+        hfa_metrics: item.hfa_metrics.as_ref().map(|x| x.try_into()).transpose()?,
     }
 });
 
@@ -1687,7 +2073,62 @@ try_from!(item: &protowire::RpcTokenEventMessage, cryptix_rpc_core::RpcTokenEven
     }
 });
 try_from!(item: &protowire::RpcTokenOwnerBalanceMessage, cryptix_rpc_core::RpcTokenOwnerBalance, {
-    Self { asset_id: item.asset_id.clone(), balance: item.balance.clone(), asset: item.asset.as_ref().map(|a| a.try_into()).transpose()? }
+    Self {
+        asset_id: item.asset_id.clone(),
+        balance: item.balance.clone(),
+        locked_balance: item.locked_balance.clone(),
+        asset: item.asset.as_ref().map(|a| a.try_into()).transpose()?,
+    }
+});
+try_from!(item: &protowire::RpcTokenStateLeafMessage, cryptix_rpc_core::RpcTokenStateLeaf, {
+    Self { logical_key: item.logical_key.clone(), value: item.value.clone() }
+});
+try_from!(item: &protowire::RpcTokenStateProofMessage, cryptix_rpc_core::RpcTokenStateProof, {
+    Self {
+        logical_key: item.logical_key.clone(),
+        value: item.value.clone(),
+        bucket_index: item.bucket_index,
+        bucket_siblings: item.bucket_siblings.iter().map(|leaf| leaf.try_into()).collect::<Result<Vec<_>, _>>()?,
+        bucket_hashes: item.bucket_hashes.clone(),
+        state_root: item.state_root.clone(),
+    }
+});
+try_from!(item: &protowire::RpcTokenConsensusDigestMessage, cryptix_rpc_core::RpcTokenConsensusDigest, {
+    Self { count: item.count, xor: item.xor.clone() }
+});
+try_from!(item: &protowire::RpcTokenConsensusBalanceMessage, cryptix_rpc_core::RpcTokenConsensusBalance, {
+    Self { asset_id: item.asset_id.clone(), owner_id: item.owner_id.clone(), amount: item.amount.clone() }
+});
+try_from!(item: &protowire::RpcTokenConsensusBalanceAnchorMessage, cryptix_rpc_core::RpcTokenConsensusBalanceAnchor, {
+    Self {
+        version: item.version,
+        nonces: item
+            .nonces
+            .as_ref()
+            .ok_or_else(|| RpcError::MissingRpcFieldError("RpcTokenConsensusBalanceAnchorMessage".to_string(), "nonces".to_string()))?
+            .try_into()?,
+        assets: item
+            .assets
+            .as_ref()
+            .ok_or_else(|| RpcError::MissingRpcFieldError("RpcTokenConsensusBalanceAnchorMessage".to_string(), "assets".to_string()))?
+            .try_into()?,
+        anchor_counts: item
+            .anchor_counts
+            .as_ref()
+            .ok_or_else(|| RpcError::MissingRpcFieldError("RpcTokenConsensusBalanceAnchorMessage".to_string(), "anchor_counts".to_string()))?
+            .try_into()?,
+        allowances: item
+            .allowances
+            .as_ref()
+            .ok_or_else(|| RpcError::MissingRpcFieldError("RpcTokenConsensusBalanceAnchorMessage".to_string(), "allowances".to_string()))?
+            .try_into()?,
+        locks: item
+            .locks
+            .as_ref()
+            .ok_or_else(|| RpcError::MissingRpcFieldError("RpcTokenConsensusBalanceAnchorMessage".to_string(), "locks".to_string()))?
+            .try_into()?,
+        balances: item.balances.iter().map(|balance| balance.try_into()).collect::<Result<Vec<_>, _>>()?,
+    }
 });
 try_from!(item: &protowire::RpcTokenHolderMessage, cryptix_rpc_core::RpcTokenHolder, {
     Self { owner_id: item.owner_id.clone(), balance: item.balance.clone() }
@@ -1765,6 +2206,7 @@ try_from!(item: &protowire::GetTokenBalanceRequestMessage, cryptix_rpc_core::Get
 try_from!(item: &protowire::GetTokenBalanceResponseMessage, RpcResult<cryptix_rpc_core::GetTokenBalanceResponse>, {
     Self {
         balance: item.balance.clone(),
+        locked_balance: item.locked_balance.clone(),
         context: item
             .context
             .as_ref()
@@ -1802,6 +2244,161 @@ try_from!(item: &protowire::GetTokenAssetResponseMessage, RpcResult<cryptix_rpc_
             .try_into()?,
     }
 });
+try_from!(item: &protowire::GetTokenBalanceProofRequestMessage, cryptix_rpc_core::GetTokenBalanceProofRequest, {
+    Self {
+        asset_id: item.asset_id.clone(),
+        owner_id: item.owner_id.clone(),
+        at_block_hash: item.at_block_hash.as_ref().map(|hash| RpcHash::from_str(hash)).transpose()?,
+        include_consensus_anchor: item.include_consensus_anchor,
+    }
+});
+try_from!(item: &protowire::GetTokenBalanceProofResponseMessage, RpcResult<cryptix_rpc_core::GetTokenBalanceProofResponse>, {
+    Self {
+        balance: item.balance.clone(),
+        balance_proof: item
+            .balance_proof
+            .as_ref()
+            .ok_or_else(|| RpcError::MissingRpcFieldError("GetTokenBalanceProofResponseMessage".to_string(), "balance_proof".to_string()))?
+            .try_into()?,
+        expected_next_nonce: item.expected_next_nonce,
+        nonce_proof: item
+            .nonce_proof
+            .as_ref()
+            .ok_or_else(|| RpcError::MissingRpcFieldError("GetTokenBalanceProofResponseMessage".to_string(), "nonce_proof".to_string()))?
+            .try_into()?,
+        context: item
+            .context
+            .as_ref()
+            .ok_or_else(|| RpcError::MissingRpcFieldError("GetTokenBalanceProofResponseMessage".to_string(), "context".to_string()))?
+            .try_into()?,
+        consensus_anchor: item.consensus_anchor.as_ref().map(|anchor| anchor.try_into()).transpose()?,
+    }
+});
+try_from!(item: &protowire::GetTokenAssetProofRequestMessage, cryptix_rpc_core::GetTokenAssetProofRequest, {
+    Self { asset_id: item.asset_id.clone(), at_block_hash: item.at_block_hash.as_ref().map(|hash| RpcHash::from_str(hash)).transpose()? }
+});
+try_from!(item: &protowire::GetTokenAssetProofResponseMessage, RpcResult<cryptix_rpc_core::GetTokenAssetProofResponse>, {
+    Self {
+        asset: item.asset.as_ref().map(|asset| asset.try_into()).transpose()?,
+        proof: item
+            .proof
+            .as_ref()
+            .ok_or_else(|| RpcError::MissingRpcFieldError("GetTokenAssetProofResponseMessage".to_string(), "proof".to_string()))?
+            .try_into()?,
+        context: item
+            .context
+            .as_ref()
+            .ok_or_else(|| RpcError::MissingRpcFieldError("GetTokenAssetProofResponseMessage".to_string(), "context".to_string()))?
+            .try_into()?,
+    }
+});
+try_from!(item: &protowire::RpcTokenMetadataRevisionMessage, cryptix_rpc_core::RpcTokenMetadataRevision, {
+    Self {
+        revision: item.revision,
+        txid: RpcHash::from_str(&item.txid)?,
+        previous_metadata_hex: item.previous_metadata_hex.clone(),
+        previous_platform_tag: item.previous_platform_tag.clone(),
+        metadata_hex: item.metadata_hex.clone(),
+        platform_tag: item.platform_tag.clone(),
+        updated_block_hash: RpcHash::from_str(&item.updated_block_hash)?,
+        updated_daa_score: item.updated_daa_score,
+        updated_at: item.updated_at,
+    }
+});
+try_from!(item: &protowire::GetTokenAssetHistoryRequestMessage, cryptix_rpc_core::GetTokenAssetHistoryRequest, {
+    Self { asset_id: item.asset_id.clone(), at_block_hash: item.at_block_hash.as_ref().map(|hash| RpcHash::from_str(hash)).transpose()? }
+});
+try_from!(item: &protowire::GetTokenAssetHistoryResponseMessage, RpcResult<cryptix_rpc_core::GetTokenAssetHistoryResponse>, {
+    Self {
+        asset: item.asset.as_ref().map(|asset| asset.try_into()).transpose()?,
+        total_revisions: item.total_revisions,
+        revisions: item.revisions.iter().map(|revision| revision.try_into()).collect::<Result<Vec<_>, _>>()?,
+        context: item
+            .context
+            .as_ref()
+            .ok_or_else(|| RpcError::MissingRpcFieldError("GetTokenAssetHistoryResponseMessage".to_string(), "context".to_string()))?
+            .try_into()?,
+    }
+});
+try_from!(item: &protowire::RpcPairPoolStateMessage, cryptix_rpc_core::RpcPairPoolState, {
+    Self {
+        pool_id: item.pool_id.clone(),
+        asset_a_id: item.asset_a_id.clone(),
+        asset_b_id: item.asset_b_id.clone(),
+        fee_bps: item.fee_bps,
+        reserve_a: item.reserve_a.clone(),
+        reserve_b: item.reserve_b.clone(),
+        total_shares: item.total_shares.clone(),
+        locked_shares: item.locked_shares.clone(),
+        accrued_fee_a: item.accrued_fee_a.clone(),
+        accrued_fee_b: item.accrued_fee_b.clone(),
+    }
+});
+try_from!(item: &protowire::GetPairPoolRequestMessage, cryptix_rpc_core::GetPairPoolRequest, {
+    Self { pool_id: item.pool_id.clone(), at_block_hash: item.at_block_hash.as_ref().map(|hash| RpcHash::from_str(hash)).transpose()? }
+});
+try_from!(item: &protowire::GetPairPoolResponseMessage, RpcResult<cryptix_rpc_core::GetPairPoolResponse>, {
+    Self {
+        pool: item.pool.as_ref().map(|pool| pool.try_into()).transpose()?,
+        context: item
+            .context
+            .as_ref()
+            .ok_or_else(|| RpcError::MissingRpcFieldError("GetPairPoolResponseMessage".to_string(), "context".to_string()))?
+            .try_into()?,
+    }
+});
+try_from!(item: &protowire::GetPairQuoteRequestMessage, cryptix_rpc_core::GetPairQuoteRequest, {
+    Self {
+        pool_id: item.pool_id.clone(),
+        asset_in_id: item.asset_in_id.clone(),
+        exact_out: item.exact_out,
+        amount: item.amount.clone(),
+        at_block_hash: item.at_block_hash.as_ref().map(|hash| RpcHash::from_str(hash)).transpose()?,
+    }
+});
+try_from!(item: &protowire::GetPairQuoteResponseMessage, RpcResult<cryptix_rpc_core::GetPairQuoteResponse>, {
+    Self {
+        asset_in_id: item.asset_in_id.clone(),
+        asset_out_id: item.asset_out_id.clone(),
+        amount_in: item.amount_in.clone(),
+        fee_amount: item.fee_amount.clone(),
+        amount_out: item.amount_out.clone(),
+        context: item
+            .context
+            .as_ref()
+            .ok_or_else(|| RpcError::MissingRpcFieldError("GetPairQuoteResponseMessage".to_string(), "context".to_string()))?
+            .try_into()?,
+    }
+});
+try_from!(item: &protowire::RpcLiquidityCandleMessage, cryptix_rpc_core::RpcLiquidityCandle, {
+    Self {
+        start_daa_score: item.start_daa_score,
+        open_price_sompi: item.open_price_sompi.clone(),
+        high_price_sompi: item.high_price_sompi.clone(),
+        low_price_sompi: item.low_price_sompi.clone(),
+        close_price_sompi: item.close_price_sompi.clone(),
+        volume_sompi: item.volume_sompi.clone(),
+        trade_count: item.trade_count,
+    }
+});
+try_from!(item: &protowire::GetLiquidityCandlesRequestMessage, cryptix_rpc_core::GetLiquidityCandlesRequest, {
+    Self {
+        asset_id: item.asset_id.clone(),
+        interval_daa_score: item.interval_daa_score,
+        from_daa_score: item.from_daa_score,
+        to_daa_score: item.to_daa_score,
+    }
+});
+try_from!(item: &protowire::GetLiquidityCandlesResponseMessage, RpcResult<cryptix_rpc_core::GetLiquidityCandlesResponse>, {
+    Self {
+        candles: item.candles.iter().map(|candle| candle.try_into()).collect::<Result<Vec<_>, _>>()?,
+        context: item
+            .context
+            .as_ref()
+            .ok_or_else(|| RpcError::MissingRpcFieldError("GetLiquidityCandlesResponseMessage".to_string(), "context".to_string()))?
+            .try_into()?,
+    }
+});
 try_from!(item: &protowire::GetTokenOpStatusRequestMessage, cryptix_rpc_core::GetTokenOpStatusRequest, {
     Self {
         txid: RpcHash::from_str(&item.txid)?,
@@ -1865,6 +2462,11 @@ try_from!(item: &protowire::GetTokenEventsRequestMessage, cryptix_rpc_core::GetT
         after_sequence: item.after_sequence,
         limit: item.limit,
         at_block_hash: item.at_block_hash.as_ref().map(|hash| RpcHash::from_str(hash)).transpose()?,
+        owner_id: item.owner_id.clone(),
+        asset_id: item.asset_id.clone(),
+        op_types: item.op_types.clone(),
+        from_daa_score: item.from_daa_score,
+        to_daa_score: item.to_daa_score,
     }
 });
 try_from!(item: &protowire::GetTokenEventsResponseMessage, RpcResult<cryptix_rpc_core::GetTokenEventsResponse>, {
@@ -1875,6 +2477,7 @@ try_from!(item: &protowire::GetTokenEventsResponseMessage, RpcResult<cryptix_rpc
             .as_ref()
             .ok_or_else(|| RpcError::MissingRpcFieldError("GetTokenEventsResponseMessage".to_string(), "context".to_string()))?
             .try_into()?,
+        next_after_sequence: item.next_after_sequence,
     }
 });
 try_from!(item: &protowire::GetTokenAssetsRequestMessage, cryptix_rpc_core::GetTokenAssetsRequest, {
@@ -2082,6 +2685,25 @@ try_from!(
         }
     }
 );
+try_from!(item: &protowire::ExportTokenAnalyticsRequestMessage, cryptix_rpc_core::ExportTokenAnalyticsRequest, {
+    Self { path: item.path.clone(), at_block_hash: item.at_block_hash.as_ref().map(|hash| RpcHash::from_str(hash)).transpose()? }
+});
+try_from!(
+    item: &protowire::ExportTokenAnalyticsResponseMessage,
+    RpcResult<cryptix_rpc_core::ExportTokenAnalyticsResponse>,
+    {
+        Self {
+            manifest_path: item.manifest_path.clone(),
+            context: item
+                .context
+                .as_ref()
+                .ok_or_else(|| {
+                    RpcError::MissingRpcFieldError("ExportTokenAnalyticsResponseMessage".to_string(), "context".to_string())
+                })?
+                .try_into()?,
+        }
+    }
+);
 try_from!(item: &protowire::ImportTokenSnapshotRequestMessage, cryptix_rpc_core::ImportTokenSnapshotRequest, {
     Self { path: item.path.clone() }
 });
@@ -2272,10 +2894,30 @@ try_from!(item: &protowire::NotifySinkBlueScoreChangedRequestMessage, cryptix_rp
 try_from!(&protowire::NotifySinkBlueScoreChangedResponseMessage, RpcResult<cryptix_rpc_core::NotifySinkBlueScoreChangedResponse>);
 
 try_from!(item: &protowire::NotifyTokenEventsRequestMessage, cryptix_rpc_core::NotifyTokenEventsRequest, {
-    Self { command: item.command.into() }
+    Self {
+        asset_ids: item.asset_ids.clone(),
+        owner_ids: item.owner_ids.clone(),
+        op_types: item.op_types.clone(),
+        apply_statuses: item.apply_statuses.clone(),
+        command: item.command.into(),
+    }
 });
 try_from!(&protowire::NotifyTokenEventsResponseMessage, RpcResult<cryptix_rpc_core::NotifyTokenEventsResponse>);
 
+try_from!(item: &protowire::NotifyFastIntentStatusRequestMessage, cryptix_rpc_core::NotifyFastIntentStatusRequest, {
+    Self {
+        intent_ids: item.intent_ids.iter().map(|x| RpcHash::from_str(x)).collect::<Result<Vec<_>, _>>()?,
+        addresses: item.addresses.iter().map(|x| x.as_str().try_into()).collect::<Result<Vec<_>, _>>()?,
+        command: item.command.into(),
+    }
+});
+try_from!(&protowire::NotifyFastIntentStatusResponseMessage, RpcResult<cryptix_rpc_core::NotifyFastIntentStatusResponse>);
+
+try_from!(item: &protowire::NotifyMessagesReceivedRequestMessage, cryptix_rpc_core::NotifyMessagesReceivedRequest, {
+    Self { recipient_tags: item.recipient_tags.clone(), command: item.command.into() }
+});
+try_from!(&protowire::NotifyMessagesReceivedResponseMessage, RpcResult<cryptix_rpc_core::NotifyMessagesReceivedResponse>);
+
 // ----------------------------------------------------------------------------
 // Unit tests
 // ----------------------------------------------------------------------------
@@ -1,6 +1,23 @@
 use crate::protowire;
 use crate::{from, try_from};
-use cryptix_rpc_core::RpcError;
+use cryptix_rpc_core::{RpcError, RpcHfaMode, RpcResult};
+
+fn hfa_mode_to_proto(mode: RpcHfaMode) -> &'static str {
+    match mode {
+        RpcHfaMode::Normal => "normal",
+        RpcHfaMode::Degraded => "degraded",
+        RpcHfaMode::Paused => "paused",
+    }
+}
+
+fn hfa_mode_from_proto(mode: &str) -> RpcResult<RpcHfaMode> {
+    match mode {
+        "normal" => Ok(RpcHfaMode::Normal),
+        "degraded" => Ok(RpcHfaMode::Degraded),
+        "paused" => Ok(RpcHfaMode::Paused),
+        _ => Err(RpcError::General(format!("invalid HFA mode: {mode}"))),
+    }
+}
 
 // ----------------------------------------------------------------------------
 // rpc_core to protowire
@@ -80,6 +97,25 @@ from!(item: &cryptix_rpc_core::StorageMetrics, protowire::StorageMetrics, {
     }
 });
 
+from!(item: &cryptix_rpc_core::HfaMetrics, protowire::HfaMetrics, {
+    Self {
+        enabled: item.enabled,
+        operator_paused: item.operator_paused,
+        mode: hfa_mode_to_proto(item.mode).to_string(),
+        submitted_count: item.submitted_count,
+        fast_confirmed_count: item.fast_confirmed_count,
+        rejected_count: item.rejected_count,
+        expired_count: item.expired_count,
+        dropped_count: item.dropped_count,
+        pending_intents: item.pending_intents,
+        active_locks: item.active_locks,
+        lock_conflict_count: item.lock_conflict_count,
+        confirm_latency_ms_p95: item.confirm_latency_ms_p95,
+        mode_transition_count: item.mode_transition_count,
+        fast_arbiter_queue_depth: item.fast_arbiter_queue_depth,
+    }
+});
+
 from!(item: &cryptix_rpc_core::CustomMetricValue, protowire::CustomMetricValue, {
     let value = match item {
         cryptix_rpc_core::CustomMetricValue::Placeholder => None,
@@ -165,6 +201,25 @@ try_from!(item: &protowire::StorageMetrics, cryptix_rpc_core::StorageMetrics, {
     }
 });
 
+try_from!(item: &protowire::HfaMetrics, cryptix_rpc_core::HfaMetrics, {
+    Self {
+        enabled: item.enabled,
+        operator_paused: item.operator_paused,
+        mode: hfa_mode_from_proto(&item.mode)?,
+        submitted_count: item.submitted_count,
+        fast_confirmed_count: item.fast_confirmed_count,
+        rejected_count: item.rejected_count,
+        expired_count: item.expired_count,
+        dropped_count: item.dropped_count,
+        pending_intents: item.pending_intents,
+        active_locks: item.active_locks,
+        lock_conflict_count: item.lock_conflict_count,
+        confirm_latency_ms_p95: item.confirm_latency_ms_p95,
+        mode_transition_count: item.mode_transition_count,
+        fast_arbiter_queue_depth: item.fast_arbiter_queue_depth,
+    }
+});
+
 try_from!(item: &protowire::CustomMetricValue, cryptix_rpc_core::CustomMetricValue, {
     match item.value.as_ref() {
         None => cryptix_rpc_core::CustomMetricValue::Placeholder,
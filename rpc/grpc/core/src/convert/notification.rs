@@ -1,6 +1,7 @@
+use crate::convert::message::{fast_intent_status_from_proto, fast_intent_status_to_proto};
 use crate::protowire::{
-    cryptixd_response::Payload, BlockAddedNotificationMessage, CryptixdResponse, NewBlockTemplateNotificationMessage,
-    RpcNotifyCommand, TokenEventsChangedNotificationMessage,
+    cryptixd_response::Payload, BlockAddedNotificationMessage, CryptixdResponse, FastIntentStatusChangedNotificationMessage,
+    MessagesReceivedNotificationMessage, NewBlockTemplateNotificationMessage, RpcNotifyCommand, TokenEventsChangedNotificationMessage,
 };
 use crate::protowire::{
     FinalityConflictNotificationMessage, FinalityConflictResolvedNotificationMessage, NotifyPruningPointUtxoSetOverrideRequestMessage,
@@ -36,6 +37,8 @@ from!(item: &cryptix_rpc_core::Notification, Payload, {
             Payload::PruningPointUtxoSetOverrideNotification(notification.into())
         }
         Notification::TokenEventsChanged(ref notification) => Payload::TokenEventsChangedNotification(notification.into()),
+        Notification::FastIntentStatusChanged(ref notification) => Payload::FastIntentStatusChangedNotification(notification.into()),
+        Notification::MessagesReceived(ref notification) => Payload::MessagesReceivedNotification(notification.into()),
     }
 });
 
@@ -77,7 +80,29 @@ from!(item: &cryptix_rpc_core::VirtualDaaScoreChangedNotification, VirtualDaaSco
 from!(&cryptix_rpc_core::PruningPointUtxoSetOverrideNotification, PruningPointUtxoSetOverrideNotificationMessage);
 
 from!(item: &cryptix_rpc_core::TokenEventsChangedNotification, TokenEventsChangedNotificationMessage, {
-    Self { from_sequence: item.from_sequence, to_sequence: item.to_sequence, event_count: item.event_count }
+    Self {
+        from_sequence: item.from_sequence,
+        to_sequence: item.to_sequence,
+        event_count: item.event_count,
+        metadata_changed_asset_ids: item.metadata_changed_asset_ids.clone(),
+        events: item.events.iter().map(|event| event.into()).collect(),
+    }
+});
+
+from!(item: &cryptix_rpc_core::FastIntentStatusChangedNotification, FastIntentStatusChangedNotificationMessage, {
+    Self {
+        intent_id: item.intent_id.to_string(),
+        status: fast_intent_status_to_proto(item.status).to_string(),
+        reason: item.reason.clone().unwrap_or_default(),
+        base_tx_id: item.base_tx_id.as_ref().map(|id| id.to_string()).unwrap_or_default(),
+        node_epoch: item.node_epoch,
+        changed_at_ms: item.changed_at_ms,
+        addresses: item.addresses.iter().map(|x| x.into()).collect(),
+    }
+});
+
+from!(item: &cryptix_rpc_core::MessagesReceivedNotification, MessagesReceivedNotificationMessage, {
+    Self { entries: item.entries.iter().map(Into::into).collect() }
 });
 
 from!(item: Command, RpcNotifyCommand, {
@@ -124,6 +149,10 @@ try_from!(item: &Payload, cryptix_rpc_core::Notification, {
             Notification::PruningPointUtxoSetOverride(notification.try_into()?)
         }
         Payload::TokenEventsChangedNotification(ref notification) => Notification::TokenEventsChanged(notification.try_into()?),
+        Payload::FastIntentStatusChangedNotification(ref notification) => {
+            Notification::FastIntentStatusChanged(notification.try_into()?)
+        }
+        Payload::MessagesReceivedNotification(ref notification) => Notification::MessagesReceived(notification.try_into()?),
         _ => Err(RpcError::UnsupportedFeature)?,
     }
 });
@@ -179,7 +208,29 @@ try_from!(item: &VirtualDaaScoreChangedNotificationMessage, cryptix_rpc_core::Vi
 try_from!(&PruningPointUtxoSetOverrideNotificationMessage, cryptix_rpc_core::PruningPointUtxoSetOverrideNotification);
 
 try_from!(item: &TokenEventsChangedNotificationMessage, cryptix_rpc_core::TokenEventsChangedNotification, {
-    Self { from_sequence: item.from_sequence, to_sequence: item.to_sequence, event_count: item.event_count }
+    Self {
+        from_sequence: item.from_sequence,
+        to_sequence: item.to_sequence,
+        event_count: item.event_count,
+        metadata_changed_asset_ids: item.metadata_changed_asset_ids.clone(),
+        events: item.events.iter().map(|event| event.try_into()).collect::<Result<Vec<_>, _>>()?,
+    }
+});
+
+try_from!(item: &FastIntentStatusChangedNotificationMessage, cryptix_rpc_core::FastIntentStatusChangedNotification, {
+    Self {
+        intent_id: RpcHash::from_str(&item.intent_id)?,
+        status: fast_intent_status_from_proto(&item.status)?,
+        reason: (!item.reason.is_empty()).then(|| item.reason.clone()),
+        base_tx_id: (!item.base_tx_id.is_empty()).then(|| RpcHash::from_str(&item.base_tx_id)).transpose()?,
+        node_epoch: item.node_epoch,
+        changed_at_ms: item.changed_at_ms,
+        addresses: item.addresses.iter().map(|x| x.as_str().try_into()).collect::<Result<Vec<_>, _>>()?,
+    }
+});
+
+try_from!(item: &MessagesReceivedNotificationMessage, cryptix_rpc_core::MessagesReceivedNotification, {
+    Self { entries: item.entries.iter().map(|x| x.try_into()).collect::<Result<Vec<_>, _>>()? }
 });
 
 from!(item: RpcNotifyCommand, Command, {
@@ -2,9 +2,10 @@ use cryptix_notify::{scope::Scope, subscription::Command};
 
 use crate::protowire::{
     cryptixd_request, cryptixd_response, CryptixdRequest, CryptixdResponse, NotifyBlockAddedRequestMessage,
-    NotifyFinalityConflictRequestMessage, NotifyNewBlockTemplateRequestMessage, NotifyPruningPointUtxoSetOverrideRequestMessage,
-    NotifySinkBlueScoreChangedRequestMessage, NotifyTokenEventsRequestMessage, NotifyUtxosChangedRequestMessage,
-    NotifyVirtualChainChangedRequestMessage, NotifyVirtualDaaScoreChangedRequestMessage,
+    NotifyFastIntentStatusRequestMessage, NotifyFinalityConflictRequestMessage, NotifyMessagesReceivedRequestMessage,
+    NotifyNewBlockTemplateRequestMessage, NotifyPruningPointUtxoSetOverrideRequestMessage, NotifySinkBlueScoreChangedRequestMessage,
+    NotifyTokenEventsRequestMessage, NotifyUtxosChangedRequestMessage, NotifyVirtualChainChangedRequestMessage,
+    NotifyVirtualDaaScoreChangedRequestMessage,
 };
 
 impl CryptixdRequest {
@@ -64,8 +65,27 @@ impl cryptixd_request::Payload {
                     command: command.into(),
                 })
             }
-            Scope::TokenEventsChanged(_) => {
-                cryptixd_request::Payload::NotifyTokenEventsRequest(NotifyTokenEventsRequestMessage { command: command.into() })
+            Scope::TokenEventsChanged(ref scope) => {
+                cryptixd_request::Payload::NotifyTokenEventsRequest(NotifyTokenEventsRequestMessage {
+                    asset_ids: scope.asset_ids.clone(),
+                    owner_ids: scope.owner_ids.clone(),
+                    op_types: scope.op_types.clone(),
+                    apply_statuses: scope.apply_statuses.clone(),
+                    command: command.into(),
+                })
+            }
+            Scope::FastIntentStatusChanged(ref scope) => {
+                cryptixd_request::Payload::NotifyFastIntentStatusRequest(NotifyFastIntentStatusRequestMessage {
+                    intent_ids: scope.intent_ids.iter().map(|x| x.to_string()).collect(),
+                    addresses: scope.addresses.iter().map(|x| x.into()).collect(),
+                    command: command.into(),
+                })
+            }
+            Scope::MessagesReceived(ref scope) => {
+                cryptixd_request::Payload::NotifyMessagesReceivedRequest(NotifyMessagesReceivedRequestMessage {
+                    recipient_tags: scope.recipient_tags.clone(),
+                    command: command.into(),
+                })
             }
         }
     }
@@ -83,6 +103,8 @@ impl cryptixd_request::Payload {
                 | Payload::NotifyPruningPointUtxoSetOverrideRequest(_)
                 | Payload::NotifyNewBlockTemplateRequest(_)
                 | Payload::NotifyTokenEventsRequest(_)
+                | Payload::NotifyFastIntentStatusRequest(_)
+                | Payload::NotifyMessagesReceivedRequest(_)
                 | Payload::StopNotifyingUtxosChangedRequest(_)
                 | Payload::StopNotifyingPruningPointUtxoSetOverrideRequest(_)
         )
@@ -113,6 +135,8 @@ impl cryptixd_response::Payload {
             Payload::PruningPointUtxoSetOverrideNotification(_) => true,
             Payload::NewBlockTemplateNotification(_) => true,
             Payload::TokenEventsChangedNotification(_) => true,
+            Payload::FastIntentStatusChangedNotification(_) => true,
+            Payload::MessagesReceivedNotification(_) => true,
             _ => false,
         }
     }
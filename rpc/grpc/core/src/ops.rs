@@ -90,11 +90,20 @@ pub enum CryptixdPayloadOps {
     SubmitFastIntent,
     GetFastIntentStatus,
     CancelFastIntent,
+    GetHfaConfig,
+    SetHfaConfig,
+    GetMessagesByRecipientTag,
     GetStrongNodes,
     SimulateTokenOp,
     GetTokenBalance,
     GetTokenNonce,
     GetTokenAsset,
+    GetTokenBalanceProof,
+    GetTokenAssetProof,
+    GetTokenAssetHistory,
+    GetPairPool,
+    GetPairQuote,
+    GetLiquidityCandles,
     GetTokenOpStatus,
     GetTokenStateHash,
     GetTokenSpendability,
@@ -109,6 +118,7 @@ pub enum CryptixdPayloadOps {
     GetLiquidityClaimPreview,
     GetLiquidityHolders,
     ExportTokenSnapshot,
+    ExportTokenAnalytics,
     ImportTokenSnapshot,
     GetTokenHealth,
     GetScBootstrapSources,
@@ -132,6 +142,8 @@ pub enum CryptixdPayloadOps {
     NotifyVirtualDaaScoreChanged,
     NotifyVirtualChainChanged,
     NotifyTokenEvents,
+    NotifyFastIntentStatus,
+    NotifyMessagesReceived,
 
     // Legacy stop subscription commands
     StopNotifyingUtxosChanged,
@@ -85,10 +85,19 @@ impl Factory {
                 SubmitFastIntent,
                 GetFastIntentStatus,
                 CancelFastIntent,
+                GetHfaConfig,
+                SetHfaConfig,
+                GetMessagesByRecipientTag,
                 SimulateTokenOp,
                 GetTokenBalance,
                 GetTokenNonce,
                 GetTokenAsset,
+                GetTokenBalanceProof,
+                GetTokenAssetProof,
+                GetTokenAssetHistory,
+                GetPairPool,
+                GetPairQuote,
+                GetLiquidityCandles,
                 GetTokenOpStatus,
                 GetTokenStateHash,
                 GetTokenSpendability,
@@ -103,6 +112,7 @@ impl Factory {
                 GetLiquidityClaimPreview,
                 GetLiquidityHolders,
                 ExportTokenSnapshot,
+                ExportTokenAnalytics,
                 ImportTokenSnapshot,
                 GetTokenHealth,
                 GetScBootstrapSources,
@@ -124,6 +134,8 @@ impl Factory {
                 NotifyVirtualDaaScoreChanged,
                 NotifyVirtualChainChanged,
                 NotifyTokenEvents,
+                NotifyFastIntentStatus,
+                NotifyMessagesReceived,
                 StopNotifyingUtxosChanged,
                 StopNotifyingPruningPointUtxoSetOverride,
             ]
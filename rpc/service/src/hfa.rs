@@ -1,9 +1,10 @@
+use crate::hfa_store::{DbHfaIntentStore, StoredFastIntent};
 use async_trait::async_trait;
 use cryptix_consensus_core::{
     api::args::TransactionValidationArgs,
     errors::tx::TxRuleError,
     hashing::tx::hash as hash_tx,
-    tx::{MutableTransaction, Transaction, TransactionOutpoint},
+    tx::{MutableTransaction, ScriptPublicKey, Transaction, TransactionOutpoint},
 };
 use cryptix_consensusmanager::ConsensusProxy;
 use cryptix_core::time::unix_now;
@@ -17,11 +18,11 @@ use cryptix_mining::{
 use cryptix_p2p_flows::hfa::{FastIntentP2pData, FastMicroblockP2pData, HfaP2pBridge};
 use cryptix_rpc_core::{
     CancelFastIntentRequest, CancelFastIntentResponse, GetFastIntentStatusRequest, GetFastIntentStatusResponse, RpcFastIntentStatus,
-    RpcHash, RpcTransaction, SubmitFastIntentRequest, SubmitFastIntentResponse,
+    RpcHash, RpcHfaMode, RpcTransaction, SubmitFastIntentRequest, SubmitFastIntentResponse,
 };
-use std::collections::{HashMap, VecDeque};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::atomic::{AtomicUsize, Ordering};
-use std::sync::Mutex;
+use std::sync::{Mutex, RwLock};
 
 const REASON_TEMPORARILY_REJECTED_FAST_LOCK_CONFLICT: &str = "temporarily_rejected_fast_lock_conflict";
 const REASON_CLOCK_DRIFT_EXCEEDED: &str = "clock_drift_exceeded";
@@ -30,6 +31,7 @@ const REASON_MAX_FEE_EXCEEDED: &str = "max_fee_exceeded";
 const REASON_TTL_EXPIRED: &str = "ttl_expired";
 const REASON_RAIL_OVERLOADED: &str = "rail_overloaded";
 const REASON_RAIL_DISABLED: &str = "rail_disabled";
+const REASON_RAIL_PAUSED: &str = "rail_paused";
 const REASON_NODE_UNSYNCED: &str = "node_unsynced";
 const REASON_NODE_RESTART: &str = "node_restart";
 const REASON_INVALID_BASE_TX: &str = "invalid_base_tx";
@@ -44,30 +46,33 @@ const REASON_CANCEL_TOKEN_INVALID: &str = "cancel_token_invalid";
 const REASON_CANCEL_UNAUTHORIZED: &str = "cancel_unauthorized";
 const REASON_CANCELLED_BY_USER: &str = "cancelled_by_user";
 
-const DEFAULT_FAST_TTL_MS: u64 = 3000;
-const DEFAULT_CLOCK_DRIFT_MAX_MS: u64 = 5_000;
-const DEFAULT_TERMINAL_RETENTION_MS: u64 = 120_000;
-const DEFAULT_MAX_PENDING_INTENTS: usize = 50_000;
-const DEFAULT_MAX_LOCKS: usize = 200_000;
-const DEFAULT_MAX_TERMINAL_ENTRIES: usize = 200_000;
-const DEFAULT_MAX_TERMINAL_BYTES: usize = 128 * 1024 * 1024;
-const DEFAULT_MAX_INPUTS_PER_INTENT: usize = 32;
-const DEFAULT_MAX_OUTPUTS_PER_INTENT: usize = 64;
-const DEFAULT_MIN_FEERATE_FLOOR: f64 = 2.0;
+pub const DEFAULT_CPU_LOW_WATER_RATIO: f64 = 0.7;
+pub const DEFAULT_FAST_TTL_MS: u64 = 3000;
+pub const DEFAULT_CLOCK_DRIFT_MAX_MS: u64 = 5_000;
+pub const DEFAULT_TERMINAL_RETENTION_MS: u64 = 120_000;
+pub const DEFAULT_MAX_PENDING_INTENTS: usize = 50_000;
+pub const DEFAULT_MAX_LOCKS: usize = 200_000;
+pub const DEFAULT_MAX_TERMINAL_ENTRIES: usize = 200_000;
+pub const DEFAULT_MAX_TERMINAL_BYTES: usize = 128 * 1024 * 1024;
+pub const DEFAULT_MAX_INPUTS_PER_INTENT: usize = 32;
+pub const DEFAULT_MAX_OUTPUTS_PER_INTENT: usize = 64;
+pub const DEFAULT_MIN_FEERATE_FLOOR: f64 = 2.0;
 const MIN_FEERATE_FLOOR_MULTIPLIER: f64 = 2.0;
-const DEFAULT_SEEN_CACHE_TTL_MS: u64 = 600_000;
-const DEFAULT_MICROBLOCK_INTERVAL_MS_NORMAL: u64 = 50;
-const DEFAULT_MICROBLOCK_INTERVAL_MS_DEGRADED: u64 = 200;
-const DEFAULT_REVALIDATION_BUDGET_NORMAL: usize = 64;
-const DEFAULT_REVALIDATION_BUDGET_DEGRADED: usize = 16;
-const DEFAULT_NORMAL_ARBITER_QUEUE_CAPACITY: usize = 4096;
-const DEFAULT_FAST_ARBITER_QUEUE_CAPACITY: usize = 4096;
+pub const DEFAULT_SEEN_CACHE_TTL_MS: u64 = 600_000;
+pub const DEFAULT_MICROBLOCK_INTERVAL_MS_NORMAL: u64 = 50;
+pub const DEFAULT_MICROBLOCK_INTERVAL_MS_DEGRADED: u64 = 200;
+pub const DEFAULT_REVALIDATION_BUDGET_NORMAL: usize = 64;
+pub const DEFAULT_REVALIDATION_BUDGET_DEGRADED: usize = 16;
+pub const DEFAULT_NORMAL_ARBITER_QUEUE_CAPACITY: usize = 4096;
+pub const DEFAULT_FAST_ARBITER_QUEUE_CAPACITY: usize = 4096;
 const REMOTE_MICROBLOCK_HINT_TTL_MS: u64 = 1_000;
 const MODE_SAMPLE_WINDOW_MS: u64 = 10_000;
 const ARBITER_SAMPLE_WINDOW_MS: u64 = 10_000;
+const CONFIRM_LATENCY_SAMPLE_WINDOW_MS: u64 = 60_000;
 const REMOTE_SOFT_DRIFT_TOLERANCE_MS: u64 = 30_000;
+const MAX_PENDING_STATUS_CHANGES: usize = 65_536;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Copy, Debug)]
 pub struct HfaRuntimeConfig {
     pub enabled: bool,
     pub cpu_low_water_ratio: f64,
@@ -114,6 +119,40 @@ impl HfaRuntimeConfig {
             fast_arbiter_queue_capacity: DEFAULT_FAST_ARBITER_QUEUE_CAPACITY,
         }
     }
+
+    /// Checks that every limit is usable by the engine, returning a description of the first offending field.
+    pub fn validate(&self) -> Result<(), String> {
+        if !(self.cpu_low_water_ratio > 0.0 && self.cpu_low_water_ratio <= 1.0) {
+            return Err(format!("cpu_low_water_ratio must be within (0.0, 1.0], got {}", self.cpu_low_water_ratio));
+        }
+        if !(100..=600_000).contains(&self.clock_drift_max_ms) {
+            return Err(format!("clock_drift_max_ms must be within [100, 600000], got {}", self.clock_drift_max_ms));
+        }
+        if !(self.min_feerate_floor.is_finite() && self.min_feerate_floor >= 0.0) {
+            return Err(format!("min_feerate_floor must be a non-negative number, got {}", self.min_feerate_floor));
+        }
+        let positive = [
+            ("fast_ttl_ms", self.fast_ttl_ms),
+            ("terminal_retention_ms", self.terminal_retention_ms),
+            ("max_pending_intents", self.max_pending_intents as u64),
+            ("max_locks", self.max_locks as u64),
+            ("max_terminal_entries", self.max_terminal_entries as u64),
+            ("max_terminal_bytes", self.max_terminal_bytes as u64),
+            ("max_inputs_per_intent", self.max_inputs_per_intent as u64),
+            ("max_outputs_per_intent", self.max_outputs_per_intent as u64),
+            ("seen_cache_ttl_ms", self.seen_cache_ttl_ms),
+            ("microblock_interval_ms_normal", self.microblock_interval_ms_normal),
+            ("microblock_interval_ms_degraded", self.microblock_interval_ms_degraded),
+            ("revalidation_budget_normal", self.revalidation_budget_normal as u64),
+            ("revalidation_budget_degraded", self.revalidation_budget_degraded as u64),
+            ("normal_arbiter_queue_capacity", self.normal_arbiter_queue_capacity as u64),
+            ("fast_arbiter_queue_capacity", self.fast_arbiter_queue_capacity as u64),
+        ];
+        match positive.into_iter().find(|(_, value)| *value == 0) {
+            Some((name, _)) => Err(format!("{name} must be greater than 0")),
+            None => Ok(()),
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -146,8 +185,45 @@ struct IntentRecord {
     cancel_token: Option<String>,
     base_tx_fingerprint: RpcHash,
     inputs: Vec<TransactionOutpoint>,
+    input_script_public_keys: Vec<ScriptPublicKey>,
     estimated_bytes: usize,
     p2p_relayed: bool,
+    // Local receipt time, used for confirmation latency. Not persisted; zero for restored intents.
+    received_at_ms: u64,
+}
+
+/// A status transition of a fast intent, queued until it is published as a
+/// `FastIntentStatusChanged` notification.
+#[derive(Clone, Debug)]
+pub struct FastIntentStatusChange {
+    pub intent_id: RpcHash,
+    pub status: RpcFastIntentStatus,
+    pub reason: Option<String>,
+    pub base_tx_id: Option<RpcHash>,
+    pub node_epoch: u64,
+    pub changed_at_ms: u64,
+    /// Script public keys of the spent inputs (once validated) and of the base tx outputs.
+    pub script_public_keys: Vec<ScriptPublicKey>,
+}
+
+impl FastIntentStatusChange {
+    fn from_record(record: &IntentRecord, changed_at_ms: u64) -> Self {
+        let mut script_public_keys = record.input_script_public_keys.clone();
+        if let Some(base_tx) = record.base_tx.as_ref() {
+            script_public_keys.extend(base_tx.outputs.iter().map(|output| output.script_public_key.clone()));
+        }
+        script_public_keys.sort_unstable_by(|a, b| a.script().cmp(b.script()).then(a.version().cmp(&b.version())));
+        script_public_keys.dedup();
+        Self {
+            intent_id: record.intent_id,
+            status: record.status,
+            reason: record.reason.clone(),
+            base_tx_id: derive_base_tx_id_from_record(record),
+            node_epoch: record.node_epoch,
+            changed_at_ms,
+            script_public_keys,
+        }
+    }
 }
 
 impl IntentRecord {
@@ -159,6 +235,7 @@ impl IntentRecord {
         client_created_at_ms: u64,
         max_fee: u64,
         base_tx_fingerprint: RpcHash,
+        received_at_ms: u64,
     ) -> Self {
         let estimated_bytes = estimate_record_bytes(base_tx.as_ref());
         Self {
@@ -177,8 +254,62 @@ impl IntentRecord {
             cancel_token: None,
             base_tx_fingerprint,
             inputs: Vec::new(),
+            input_script_public_keys: Vec::new(),
             estimated_bytes,
             p2p_relayed: false,
+            received_at_ms,
+        }
+    }
+}
+
+impl From<&IntentRecord> for StoredFastIntent {
+    fn from(record: &IntentRecord) -> Self {
+        Self {
+            intent_id: record.intent_id,
+            status: record.status,
+            reason: record.reason.clone(),
+            node_epoch: record.node_epoch,
+            base_tx: record.base_tx.clone().and_then(|base_tx| base_tx.try_into().ok()),
+            intent_nonce: record.intent_nonce,
+            client_created_at_ms: record.client_created_at_ms,
+            max_fee: record.max_fee,
+            expires_at_ms: record.expires_at_ms,
+            confirm_after_ms: record.confirm_after_ms,
+            retention_until_ms: record.retention_until_ms,
+            terminal_entered_at_ms: record.terminal_entered_at_ms,
+            cancel_token: record.cancel_token.clone(),
+            base_tx_fingerprint: record.base_tx_fingerprint,
+            inputs: record.inputs.clone(),
+            input_script_public_keys: record.input_script_public_keys.clone(),
+        }
+    }
+}
+
+impl From<StoredFastIntent> for IntentRecord {
+    fn from(stored: StoredFastIntent) -> Self {
+        let base_tx = stored.base_tx.as_ref().map(RpcTransaction::from);
+        let estimated_bytes = estimate_record_bytes(base_tx.as_ref());
+        Self {
+            intent_id: stored.intent_id,
+            status: stored.status,
+            reason: stored.reason,
+            node_epoch: stored.node_epoch,
+            base_tx,
+            intent_nonce: stored.intent_nonce,
+            client_created_at_ms: stored.client_created_at_ms,
+            max_fee: stored.max_fee,
+            expires_at_ms: stored.expires_at_ms,
+            confirm_after_ms: stored.confirm_after_ms,
+            retention_until_ms: stored.retention_until_ms,
+            terminal_entered_at_ms: stored.terminal_entered_at_ms,
+            cancel_token: stored.cancel_token,
+            base_tx_fingerprint: stored.base_tx_fingerprint,
+            inputs: stored.inputs,
+            input_script_public_keys: stored.input_script_public_keys,
+            estimated_bytes,
+            // Relay state is not persisted; restored intents are re-announced on demand.
+            p2p_relayed: false,
+            received_at_ms: 0,
         }
     }
 }
@@ -195,6 +326,7 @@ struct HfaState {
     seen_cache: HashMap<RpcHash, u64>,
     remote_microblock_hints: HashMap<RpcHash, u64>,
     outbound_microblocks: Vec<FastMicroblockP2pData>,
+    status_changes: VecDeque<FastIntentStatusChange>,
     revalidation_queue: VecDeque<RpcHash>,
     fast_tx_routes: HashMap<RpcHash, u64>,
     submit_total: u64,
@@ -202,6 +334,8 @@ struct HfaState {
     submit_p2p_total: u64,
     overload_reject_total: u64,
     normal_conflict_reject_total: u64,
+    fast_lock_conflict_total: u64,
+    fast_confirmed_total: u64,
     rejected_total: u64,
     dropped_total: u64,
     expired_total: u64,
@@ -213,6 +347,7 @@ struct HfaState {
     fast_arbiter_hold_samples_ms: VecDeque<(u64, u64)>,
     latest_fast_arbiter_wait_ms_p95: f64,
     latest_fast_arbiter_hold_ms_p95: f64,
+    fast_confirm_latency_samples_ms: VecDeque<(u64, u64)>,
     basechain_block_latency_ms: f64,
     basechain_latency_baseline_ms: f64,
     basechain_latency_delta_vs_baseline_ms: f64,
@@ -234,6 +369,11 @@ struct HfaState {
     terminal_bytes: usize,
     paused_since_ms: Option<u64>,
     paused_stuck_alerted: bool,
+    dirty: HashSet<RpcHash>,
+    /// Set when an intent became locked or fast confirmed since the last flush
+    dirty_locked: bool,
+    restored: Vec<RpcHash>,
+    operator_paused: bool,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -266,6 +406,7 @@ impl HfaState {
             seen_cache: HashMap::new(),
             remote_microblock_hints: HashMap::new(),
             outbound_microblocks: Vec::new(),
+            status_changes: VecDeque::new(),
             revalidation_queue: VecDeque::new(),
             fast_tx_routes: HashMap::new(),
             submit_total: 0,
@@ -273,6 +414,8 @@ impl HfaState {
             submit_p2p_total: 0,
             overload_reject_total: 0,
             normal_conflict_reject_total: 0,
+            fast_lock_conflict_total: 0,
+            fast_confirmed_total: 0,
             rejected_total: 0,
             dropped_total: 0,
             expired_total: 0,
@@ -284,6 +427,7 @@ impl HfaState {
             fast_arbiter_hold_samples_ms: VecDeque::new(),
             latest_fast_arbiter_wait_ms_p95: 0.0,
             latest_fast_arbiter_hold_ms_p95: 0.0,
+            fast_confirm_latency_samples_ms: VecDeque::new(),
             basechain_block_latency_ms: 0.0,
             basechain_latency_baseline_ms: 0.0,
             basechain_latency_delta_vs_baseline_ms: 0.0,
@@ -305,20 +449,27 @@ impl HfaState {
             terminal_bytes: 0,
             paused_since_ms: None,
             paused_stuck_alerted: false,
+            dirty: HashSet::new(),
+            dirty_locked: false,
+            restored: Vec::new(),
+            operator_paused: false,
         }
     }
 }
 
 #[derive(Debug)]
 pub struct HfaEngine {
-    config: HfaRuntimeConfig,
+    config: RwLock<HfaRuntimeConfig>,
     state: Mutex<HfaState>,
     fast_arbiter_inflight: AtomicUsize,
+    store: Option<DbHfaIntentStore>,
 }
 
 #[derive(Clone, Debug)]
 pub struct HfaMetricsSnapshot {
     pub enabled: bool,
+    pub operator_paused: bool,
+    pub config: HfaRuntimeConfig,
     pub node_epoch: u64,
     pub mode: &'static str,
     pub paused_for_ms: u64,
@@ -334,6 +485,8 @@ pub struct HfaMetricsSnapshot {
     pub rejected_total: u64,
     pub overload_reject_total: u64,
     pub normal_conflict_reject_total: u64,
+    pub fast_lock_conflict_total: u64,
+    pub fast_confirmed_total: u64,
     pub dropped_total: u64,
     pub expired_total: u64,
     pub pull_miss_total: u64,
@@ -341,6 +494,7 @@ pub struct HfaMetricsSnapshot {
     pub fast_arbiter_queue_len: usize,
     pub fast_arbiter_wait_ms: f64,
     pub fast_arbiter_hold_ms: f64,
+    pub fast_confirm_latency_ms_p95: f64,
     pub basechain_block_latency_ms: f64,
     pub basechain_latency_delta_vs_baseline_ms: f64,
     pub mode_transition_total: u64,
@@ -376,19 +530,213 @@ impl Drop for FastArbiterInflightTicket<'_> {
 impl HfaEngine {
     pub fn new(config: HfaRuntimeConfig) -> Self {
         let node_epoch = unix_now();
-        Self { config, state: Mutex::new(HfaState::new(node_epoch)), fast_arbiter_inflight: AtomicUsize::new(0) }
+        Self {
+            config: RwLock::new(config),
+            state: Mutex::new(HfaState::new(node_epoch)),
+            fast_arbiter_inflight: AtomicUsize::new(0),
+            store: None,
+        }
     }
 
-    pub fn config(&self) -> &HfaRuntimeConfig {
-        &self.config
+    /// Creates an engine backed by `store`. Pending intents, their input locks and recent terminal
+    /// statuses from the previous run are restored; restored active intents must be revalidated
+    /// against the current virtual UTXO set via [`Self::revalidate_restored`].
+    pub fn with_store(config: HfaRuntimeConfig, store: DbHfaIntentStore) -> Self {
+        let engine = Self { store: Some(store), ..Self::new(config) };
+        engine.restore();
+        engine
+    }
+
+    fn restore(&self) {
+        let Some(store) = self.store.as_ref() else {
+            return;
+        };
+        if !self.config().enabled {
+            if let Err(err) = store.delete_all() {
+                warn!("Fastchain store: failed clearing persisted intents: {}", err);
+            }
+            return;
+        }
+
+        let (records, corrupted) = store.load_all();
+        if corrupted > 0 {
+            warn!("Fastchain store: skipped {} undecodable persisted intents", corrupted);
+        }
+
+        let now_ms = unix_now();
+        let mut state = self.lock_state();
+        let (mut restored_active, mut restored_terminal, mut interrupted) = (0usize, 0usize, 0usize);
+        for stored in records {
+            let mut record = IntentRecord::from(stored);
+            let intent_id = record.intent_id;
+            match record.status {
+                RpcFastIntentStatus::Received | RpcFastIntentStatus::Validated => {
+                    // Submission was interrupted before locks were acquired.
+                    record.status = RpcFastIntentStatus::Rejected;
+                    record.reason = Some(REASON_NODE_RESTART.to_string());
+                    record.expires_at_ms = None;
+                    record.confirm_after_ms = None;
+                    record.cancel_token = None;
+                    record.retention_until_ms = Some(now_ms.saturating_add(self.config().terminal_retention_ms));
+                    record.terminal_entered_at_ms = Some(now_ms);
+                    state.seen_cache.insert(intent_id, now_ms.saturating_add(self.config().seen_cache_ttl_ms));
+                    state.dirty.insert(intent_id);
+                    self.insert_terminal(&mut state, record, now_ms);
+                    interrupted += 1;
+                }
+                RpcFastIntentStatus::Locked | RpcFastIntentStatus::FastConfirmed => {
+                    if record.base_tx.is_none()
+                        || state.active_fingerprint.contains_key(&record.base_tx_fingerprint)
+                        || record.inputs.iter().any(|input| state.input_locks.contains_key(input))
+                    {
+                        state.dirty.insert(intent_id);
+                        continue;
+                    }
+                    for input in &record.inputs {
+                        state.input_locks.insert(*input, intent_id);
+                    }
+                    state.active_fingerprint.insert(record.base_tx_fingerprint, intent_id);
+                    state.seen_cache.insert(intent_id, now_ms.saturating_add(self.config().seen_cache_ttl_ms));
+                    state.revalidation_queue.push_back(intent_id);
+                    state.restored.push(intent_id);
+                    state.active.insert(intent_id, record);
+                    restored_active += 1;
+                }
+                _ => {
+                    if record.retention_until_ms.is_some_and(|until| until <= now_ms) {
+                        state.dirty.insert(intent_id);
+                        continue;
+                    }
+                    state.seen_cache.insert(intent_id, now_ms.saturating_add(self.config().seen_cache_ttl_ms));
+                    self.insert_terminal(&mut state, record, now_ms);
+                    restored_terminal += 1;
+                }
+            }
+        }
+
+        if restored_active + restored_terminal + interrupted > 0 {
+            info!(
+                "Fastchain store: restored {} active and {} terminal intents, {} interrupted submissions rejected",
+                restored_active, restored_terminal, interrupted
+            );
+        }
+    }
+
+    /// Revalidates intents restored from the store against the current virtual UTXO set and drops
+    /// those whose inputs are no longer available.
+    pub async fn revalidate_restored(&self, session: ConsensusProxy) {
+        let candidates: Vec<(RpcHash, Transaction)> = {
+            let mut state = self.lock_state();
+            let restored = std::mem::take(&mut state.restored);
+            restored
+                .into_iter()
+                .filter_map(|intent_id| {
+                    let base_tx = state.active.get(&intent_id)?.base_tx.clone()?;
+                    Some((intent_id, TryInto::<Transaction>::try_into(base_tx).ok()?))
+                })
+                .collect()
+        };
+
+        let mut invalidated = Vec::new();
+        for (intent_id, tx) in candidates {
+            let mut mtx = MutableTransaction::from_tx(tx);
+            let validation_result = session
+                .clone()
+                .spawn_blocking(move |c| c.validate_mempool_transaction(&mut mtx, &TransactionValidationArgs::default()))
+                .await;
+            if validation_result.is_err() {
+                invalidated.push(intent_id);
+            }
+        }
+
+        if invalidated.is_empty() {
+            return;
+        }
+
+        let now_ms = unix_now();
+        let mut state = self.lock_state();
+        for intent_id in invalidated {
+            if state.active.contains_key(&intent_id) {
+                let _ = self.transition_active_to_terminal(
+                    &mut state,
+                    intent_id,
+                    RpcFastIntentStatus::Dropped,
+                    Some(REASON_INVALIDATED_BY_CHAIN_UPDATE.to_string()),
+                    now_ms,
+                );
+            }
+        }
+    }
+
+    /// Flushes intents changed since the last call to the store, if any.
+    pub fn persist_dirty(&self) {
+        let Some(store) = self.store.as_ref() else {
+            return;
+        };
+        let (upserts, deletes) = {
+            let mut state = self.lock_state();
+            if state.dirty.is_empty() {
+                return;
+            }
+            let dirty = std::mem::take(&mut state.dirty);
+            state.dirty_locked = false;
+            let mut upserts = Vec::new();
+            let mut deletes = Vec::new();
+            for intent_id in dirty {
+                let record =
+                    state.active.get(&intent_id).or_else(|| state.terminal.get(&intent_id)).or_else(|| state.prelock.get(&intent_id));
+                match record {
+                    Some(record) => upserts.push(StoredFastIntent::from(record)),
+                    None => deletes.push(intent_id),
+                }
+            }
+            (upserts, deletes)
+        };
+        if let Err(err) = store.apply(upserts, deletes) {
+            warn!("Fastchain store: failed persisting intents: {}", err);
+        }
+    }
+
+    /// Flushes the changed intents right away if one of them became locked or fast confirmed, so that
+    /// a lock which was already reported to a client or peer survives a restart before the next tick.
+    fn persist_locked(&self) {
+        if self.store.is_some() && self.lock_state().dirty_locked {
+            self.persist_dirty();
+        }
+    }
+
+    pub fn config(&self) -> HfaRuntimeConfig {
+        *self.config.read().expect("hfa config lock poisoned")
+    }
+
+    /// Replaces the runtime limits. Whether the rail is enabled is fixed at startup and is kept as is.
+    pub fn set_config(&self, mut config: HfaRuntimeConfig) -> Result<HfaRuntimeConfig, String> {
+        config.validate()?;
+        let mut current = self.config.write().expect("hfa config lock poisoned");
+        config.enabled = current.enabled;
+        *current = config;
+        Ok(config)
+    }
+
+    pub fn is_operator_paused(&self) -> bool {
+        self.lock_state().operator_paused
+    }
+
+    /// Pauses or resumes the admission of new fast intents. Intents already locked keep progressing.
+    pub fn set_operator_paused(&self, paused: bool) {
+        let mut state = self.lock_state();
+        if state.operator_paused != paused {
+            info!("HFA rail {} by operator", if paused { "paused" } else { "resumed" });
+        }
+        state.operator_paused = paused;
     }
 
     pub fn is_enabled(&self) -> bool {
-        self.config.enabled
+        self.config().enabled
     }
 
     pub fn effective_feerate_floor(&self, minimum_relay_feerate: f64) -> f64 {
-        self.config.min_feerate_floor.max(minimum_relay_feerate.max(0.0) * MIN_FEERATE_FLOOR_MULTIPLIER)
+        self.config().min_feerate_floor.max(minimum_relay_feerate.max(0.0) * MIN_FEERATE_FLOOR_MULTIPLIER)
     }
 
     pub fn node_epoch(&self) -> u64 {
@@ -405,7 +753,7 @@ impl HfaEngine {
             return sample.cpu_ratio.clamp(0.0, 1.0);
         }
 
-        self.config.cpu_low_water_ratio.max(0.5).clamp(0.0, 1.0)
+        self.config().cpu_low_water_ratio.max(0.5).clamp(0.0, 1.0)
     }
 
     pub async fn submit_fast_intent(
@@ -418,6 +766,33 @@ impl HfaEngine {
         cpu_ratio: f64,
         basechain_block_latency_ms: f64,
         source: FastIngressSource,
+    ) -> SubmitFastIntentResponse {
+        let response = self
+            .process_fast_intent(
+                network_id,
+                request,
+                session,
+                mining_manager,
+                is_synced,
+                cpu_ratio,
+                basechain_block_latency_ms,
+                source,
+            )
+            .await;
+        self.persist_locked();
+        response
+    }
+
+    async fn process_fast_intent(
+        &self,
+        network_id: &str,
+        request: SubmitFastIntentRequest,
+        session: ConsensusProxy,
+        mining_manager: MiningManagerProxy,
+        is_synced: bool,
+        cpu_ratio: f64,
+        basechain_block_latency_ms: f64,
+        source: FastIngressSource,
     ) -> SubmitFastIntentResponse {
         let now_ms = unix_now();
         let cpu_ratio = self.effective_cpu_ratio(cpu_ratio);
@@ -441,9 +816,12 @@ impl HfaEngine {
                 FastIngressSource::Rpc => state.submit_rpc_total = state.submit_rpc_total.saturating_add(1),
                 FastIngressSource::P2p => state.submit_p2p_total = state.submit_p2p_total.saturating_add(1),
             }
-            if !self.config.enabled {
+            if !self.config().enabled {
                 return self.submit_rejected_with_state(&state, tentative_intent_id, REASON_RAIL_DISABLED, None);
             }
+            if state.operator_paused {
+                return self.submit_rejected_with_state(&state, tentative_intent_id, REASON_RAIL_PAUSED, None);
+            }
             if state.mode == HfaMode::Paused {
                 return self.submit_rejected_with_state(&state, tentative_intent_id, REASON_RAIL_OVERLOADED, None);
             }
@@ -464,7 +842,7 @@ impl HfaEngine {
             derive_intent_id(network_id, base_tx_fingerprint, request.intent_nonce, request.client_created_at_ms, request.max_fee);
 
         // 2) Structure checks
-        if tx.inputs.len() > self.config.max_inputs_per_intent || tx.outputs.len() > self.config.max_outputs_per_intent {
+        if tx.inputs.len() > self.config().max_inputs_per_intent || tx.outputs.len() > self.config().max_outputs_per_intent {
             return self.submit_rejected(intent_id, REASON_NONSTANDARD_BASE_TX, now_ms, None);
         }
 
@@ -484,8 +862,8 @@ impl HfaEngine {
                 return self.submit_rejected_with_state(&state, intent_id, REASON_DUPLICATE_BASE_TX_ACTIVE, None);
             }
             let queue_limit = match state.mode {
-                HfaMode::Normal => self.config.max_pending_intents,
-                HfaMode::Degraded => self.config.max_pending_intents / 2,
+                HfaMode::Normal => self.config().max_pending_intents,
+                HfaMode::Degraded => self.config().max_pending_intents / 2,
                 HfaMode::Paused => 0,
             };
             if state.prelock.len() + state.active.len() >= queue_limit {
@@ -499,13 +877,16 @@ impl HfaEngine {
                 request.client_created_at_ms,
                 request.max_fee,
                 base_tx_fingerprint,
+                now_ms,
             );
+            self.record_status_change(&mut state, &pre, now_ms);
             state.prelock.insert(intent_id, pre);
+            state.dirty.insert(intent_id);
         }
 
         // 4) Drift / time rules
         let drift = now_ms.abs_diff(request.client_created_at_ms);
-        if drift > self.config.clock_drift_max_ms {
+        if drift > self.config().clock_drift_max_ms {
             if matches!(source, FastIngressSource::P2p) {
                 if drift > REMOTE_SOFT_DRIFT_TOLERANCE_MS {
                     let mut state = self.lock_state();
@@ -515,7 +896,9 @@ impl HfaEngine {
                 // remote intents solely on drift if all other validation passes.
                 warn!(
                     "Fastchain receive drift tolerance: intent {} drift={}ms exceeds {}ms (accepting with soft drift)",
-                    intent_id, drift, self.config.clock_drift_max_ms
+                    intent_id,
+                    drift,
+                    self.config().clock_drift_max_ms
                 );
             } else {
                 let mut state = self.lock_state();
@@ -529,7 +912,7 @@ impl HfaEngine {
             return self.reject_from_prelock(&mut state, intent_id, REASON_MAX_FEE_EXCEEDED, now_ms);
         }
         let minimum_relay_feerate = mining_manager.clone().minimum_relay_feerate().await.max(0.0);
-        let effective_feerate_floor = self.config.min_feerate_floor.max(minimum_relay_feerate * MIN_FEERATE_FLOOR_MULTIPLIER);
+        let effective_feerate_floor = self.config().min_feerate_floor.max(minimum_relay_feerate * MIN_FEERATE_FLOOR_MULTIPLIER);
 
         // 6) Full base transaction validation
         if mining_manager.clone().has_transaction(tx.id(), TransactionQuery::All).await {
@@ -571,17 +954,21 @@ impl HfaEngine {
             let mut state = self.lock_state();
             if let Some(pre) = state.prelock.get_mut(&intent_id) {
                 pre.status = RpcFastIntentStatus::Validated;
+                pre.input_script_public_keys = mtx.entries.iter().flatten().map(|entry| entry.script_public_key.clone()).collect();
+                let pre = pre.clone();
+                self.record_status_change(&mut state, &pre, now_ms);
+                state.dirty.insert(intent_id);
             }
         }
 
         // 7) Arbiter conflict check
         // 8) Atomic input lock acquisition
-        let expires_at_ms = now_ms.saturating_add(self.config.fast_ttl_ms);
-        let retention_until_ms = expires_at_ms.saturating_add(self.config.terminal_retention_ms);
+        let expires_at_ms = now_ms.saturating_add(self.config().fast_ttl_ms);
+        let retention_until_ms = expires_at_ms.saturating_add(self.config().terminal_retention_ms);
         let inputs: Vec<TransactionOutpoint> = tx.inputs.iter().map(|i| i.previous_outpoint).collect();
 
         let inflight_before = self.fast_arbiter_inflight.fetch_add(1, Ordering::SeqCst);
-        if inflight_before >= self.config.fast_arbiter_queue_capacity {
+        if inflight_before >= self.config().fast_arbiter_queue_capacity {
             self.fast_arbiter_inflight.fetch_sub(1, Ordering::SeqCst);
             let mut state = self.lock_state();
             self.sync_fast_arbiter_queue_len(&mut state);
@@ -597,11 +984,16 @@ impl HfaEngine {
         self.sweep(&mut state, now_ms);
         self.update_mode(&mut state, cpu_ratio, is_synced, basechain_block_latency_ms, now_ms);
 
-        if !self.config.enabled {
+        if !self.config().enabled {
             let response = self.reject_from_prelock(&mut state, intent_id, REASON_RAIL_DISABLED, now_ms);
             self.finish_fast_arbiter_section(&mut state, now_ms, arbiter_wait_ms, unix_now().saturating_sub(arbiter_hold_started_ms));
             return response;
         }
+        if state.operator_paused {
+            let response = self.reject_from_prelock(&mut state, intent_id, REASON_RAIL_PAUSED, now_ms);
+            self.finish_fast_arbiter_section(&mut state, now_ms, arbiter_wait_ms, unix_now().saturating_sub(arbiter_hold_started_ms));
+            return response;
+        }
         if state.mode == HfaMode::Paused {
             let response = self.reject_from_prelock(&mut state, intent_id, REASON_RAIL_OVERLOADED, now_ms);
             self.finish_fast_arbiter_section(&mut state, now_ms, arbiter_wait_ms, unix_now().saturating_sub(arbiter_hold_started_ms));
@@ -614,11 +1006,12 @@ impl HfaEngine {
         }
 
         if inputs.iter().any(|input| state.input_locks.contains_key(input)) {
+            state.fast_lock_conflict_total = state.fast_lock_conflict_total.saturating_add(1);
             let response = self.reject_from_prelock(&mut state, intent_id, REASON_TEMPORARILY_REJECTED_FAST_LOCK_CONFLICT, now_ms);
             self.finish_fast_arbiter_section(&mut state, now_ms, arbiter_wait_ms, unix_now().saturating_sub(arbiter_hold_started_ms));
             return response;
         }
-        if state.input_locks.len().saturating_add(inputs.len()) > self.config.max_locks {
+        if state.input_locks.len().saturating_add(inputs.len()) > self.config().max_locks {
             let response = self.reject_from_prelock(&mut state, intent_id, REASON_RAIL_OVERLOADED, now_ms);
             self.finish_fast_arbiter_section(&mut state, now_ms, arbiter_wait_ms, unix_now().saturating_sub(arbiter_hold_started_ms));
             return response;
@@ -628,7 +1021,7 @@ impl HfaEngine {
             state.input_locks.insert(*input, intent_id);
         }
         state.active_fingerprint.insert(base_tx_fingerprint, intent_id);
-        state.seen_cache.insert(intent_id, now_ms.saturating_add(self.config.seen_cache_ttl_ms));
+        state.seen_cache.insert(intent_id, now_ms.saturating_add(self.config().seen_cache_ttl_ms));
 
         // 9) Store active state
         let cancel_token = build_cancel_token(intent_id, state.node_epoch, now_ms);
@@ -642,6 +1035,7 @@ impl HfaEngine {
                 request.client_created_at_ms,
                 request.max_fee,
                 base_tx_fingerprint,
+                now_ms,
             )
         });
         active.inputs = inputs;
@@ -662,7 +1056,9 @@ impl HfaEngine {
             active.status = RpcFastIntentStatus::FastConfirmed;
             active.confirm_after_ms = None;
         }
+        self.record_status_change(&mut state, &active, now_ms);
         state.active.insert(intent_id, active.clone());
+        state.dirty.insert(intent_id);
         state.revalidation_queue.push_back(intent_id);
         self.finish_fast_arbiter_section(&mut state, now_ms, arbiter_wait_ms, unix_now().saturating_sub(arbiter_hold_started_ms));
         drop(fast_arbiter_ticket);
@@ -682,6 +1078,12 @@ impl HfaEngine {
     }
 
     pub fn get_fast_intent_status(&self, request: GetFastIntentStatusRequest) -> GetFastIntentStatusResponse {
+        let response = self.fast_intent_status(request);
+        self.persist_locked();
+        response
+    }
+
+    fn fast_intent_status(&self, request: GetFastIntentStatusRequest) -> GetFastIntentStatusResponse {
         let now_ms = unix_now();
         let mut state = self.lock_state();
         self.sweep(&mut state, now_ms);
@@ -733,7 +1135,8 @@ impl HfaEngine {
 
         let epoch_changed = request.node_epoch != state.node_epoch;
         if let Some(active) = state.active.get(&request.intent_id).cloned() {
-            if epoch_changed {
+            // Intents restored from the store keep the epoch their cancel token was issued under.
+            if request.node_epoch != active.node_epoch {
                 return CancelFastIntentResponse {
                     status: RpcFastIntentStatus::Rejected,
                     reason: Some(REASON_NODE_RESTART.to_string()),
@@ -818,7 +1221,7 @@ impl HfaEngine {
         let now_ms = unix_now();
         let mut state = self.lock_state();
         self.sweep(&mut state, now_ms);
-        let retention_ms = self.config.seen_cache_ttl_ms.max(self.config.terminal_retention_ms).max(60_000);
+        let retention_ms = self.config().seen_cache_ttl_ms.max(self.config().terminal_retention_ms).max(60_000);
         state.fast_tx_routes.insert(tx_id, now_ms.saturating_add(retention_ms));
     }
 
@@ -876,6 +1279,12 @@ impl HfaEngine {
     }
 
     pub fn on_remote_fast_microblock(&self, intent_ids: &[RpcHash], now_ms: u64) -> Vec<RpcHash> {
+        let missing = self.apply_remote_fast_microblock(intent_ids, now_ms);
+        self.persist_locked();
+        missing
+    }
+
+    fn apply_remote_fast_microblock(&self, intent_ids: &[RpcHash], now_ms: u64) -> Vec<RpcHash> {
         let mut state = self.lock_state();
         self.sweep(&mut state, now_ms);
 
@@ -885,6 +1294,9 @@ impl HfaEngine {
                 if active.status == RpcFastIntentStatus::Locked {
                     active.status = RpcFastIntentStatus::FastConfirmed;
                     active.confirm_after_ms = None;
+                    let active = active.clone();
+                    self.record_status_change(&mut state, &active, now_ms);
+                    state.dirty.insert(*intent_id);
                 }
                 continue;
             }
@@ -913,6 +1325,12 @@ impl HfaEngine {
         std::mem::take(&mut state.outbound_microblocks)
     }
 
+    /// Drains the status transitions queued since the last call, oldest first.
+    pub fn take_status_changes(&self) -> Vec<FastIntentStatusChange> {
+        let mut state = self.lock_state();
+        std::mem::take(&mut state.status_changes).into()
+    }
+
     pub fn should_broadcast_intent_once(&self, intent_id: RpcHash) -> bool {
         let mut state = self.lock_state();
 
@@ -944,7 +1362,7 @@ impl HfaEngine {
         let now_ms = unix_now();
         let mut state = self.lock_state();
         state.normal_arbiter_queue_len =
-            state.normal_arbiter_queue_len.saturating_add(1).min(self.config.normal_arbiter_queue_capacity);
+            state.normal_arbiter_queue_len.saturating_add(1).min(self.config().normal_arbiter_queue_capacity);
         self.sweep(&mut state, now_ms);
         let has_conflict = inputs.iter().any(|input| state.input_locks.contains_key(input));
         if has_conflict {
@@ -958,7 +1376,7 @@ impl HfaEngine {
         let now_ms = unix_now();
         let mut state = self.lock_state();
         state.normal_arbiter_queue_len =
-            state.normal_arbiter_queue_len.saturating_add(1).min(self.config.normal_arbiter_queue_capacity);
+            state.normal_arbiter_queue_len.saturating_add(1).min(self.config().normal_arbiter_queue_capacity);
         self.sweep(&mut state, now_ms);
         let tx_fingerprint = hash_tx(tx, false);
         let same_tx_owner = state.active_fingerprint.get(&tx_fingerprint).copied();
@@ -989,7 +1407,7 @@ impl HfaEngine {
             let mut state = self.lock_state();
             self.sweep(&mut state, now_ms);
             self.update_mode(&mut state, cpu_ratio.clamp(0.0, 1.0), is_synced, basechain_block_latency_ms, now_ms);
-            if !self.config.enabled || state.mode == HfaMode::Paused {
+            if !self.config().enabled || state.mode == HfaMode::Paused {
                 return;
             }
 
@@ -1090,8 +1508,11 @@ impl HfaEngine {
             basechain_latency_delta_vs_baseline_ms: state.basechain_latency_delta_vs_baseline_ms,
         });
 
+        let config = self.config();
         HfaMetricsSnapshot {
-            enabled: self.config.enabled,
+            enabled: config.enabled,
+            operator_paused: state.operator_paused,
+            config,
             node_epoch: state.node_epoch,
             mode: mode_name(state.mode),
             paused_for_ms: if state.mode == HfaMode::Paused {
@@ -1111,6 +1532,8 @@ impl HfaEngine {
             rejected_total: state.rejected_total,
             overload_reject_total: state.overload_reject_total,
             normal_conflict_reject_total: state.normal_conflict_reject_total,
+            fast_lock_conflict_total: state.fast_lock_conflict_total,
+            fast_confirmed_total: state.fast_confirmed_total,
             dropped_total: state.dropped_total,
             expired_total: state.expired_total,
             pull_miss_total: state.pull_miss_total,
@@ -1118,6 +1541,7 @@ impl HfaEngine {
             fast_arbiter_queue_len: state.fast_arbiter_queue_len,
             fast_arbiter_wait_ms: latest.arbiter_wait_p95_ms,
             fast_arbiter_hold_ms: latest.arbiter_hold_p95_ms,
+            fast_confirm_latency_ms_p95: sample_p95(&state.fast_confirm_latency_samples_ms),
             basechain_block_latency_ms: latest.basechain_block_latency_ms,
             basechain_latency_delta_vs_baseline_ms: latest.basechain_latency_delta_vs_baseline_ms,
             mode_transition_total: state.mode_transition_total,
@@ -1196,7 +1620,7 @@ impl HfaEngine {
             pre.reason = Some(reason.to_string());
             pre.expires_at_ms = None;
             pre.confirm_after_ms = None;
-            pre.retention_until_ms = Some(now_ms.saturating_add(self.config.terminal_retention_ms));
+            pre.retention_until_ms = Some(now_ms.saturating_add(self.config().terminal_retention_ms));
             pre.terminal_entered_at_ms = Some(now_ms);
             pre.cancel_token = None;
             pre
@@ -1212,13 +1636,15 @@ impl HfaEngine {
                 max_fee: 0,
                 expires_at_ms: None,
                 confirm_after_ms: None,
-                retention_until_ms: Some(now_ms.saturating_add(self.config.terminal_retention_ms)),
+                retention_until_ms: Some(now_ms.saturating_add(self.config().terminal_retention_ms)),
                 terminal_entered_at_ms: Some(now_ms),
                 cancel_token: None,
                 base_tx_fingerprint: RpcHash::from_bytes([0u8; 32]),
                 inputs: Vec::new(),
+                input_script_public_keys: Vec::new(),
                 estimated_bytes: estimate_record_bytes(None),
                 p2p_relayed: false,
+                received_at_ms: now_ms,
             }
         };
 
@@ -1226,7 +1652,8 @@ impl HfaEngine {
             state.overload_reject_total = state.overload_reject_total.saturating_add(1);
         }
         state.rejected_total = state.rejected_total.saturating_add(1);
-        state.seen_cache.insert(intent_id, now_ms.saturating_add(self.config.seen_cache_ttl_ms));
+        state.seen_cache.insert(intent_id, now_ms.saturating_add(self.config().seen_cache_ttl_ms));
+        self.record_status_change(state, &record, now_ms);
         self.insert_terminal(state, record.clone(), now_ms);
         self.submit_rejected_with_state(state, intent_id, reason, record.retention_until_ms)
     }
@@ -1249,18 +1676,43 @@ impl HfaEngine {
         active.expires_at_ms = None;
         active.confirm_after_ms = None;
         active.cancel_token = None;
-        active.retention_until_ms = Some(now_ms.saturating_add(self.config.terminal_retention_ms));
+        active.retention_until_ms = Some(now_ms.saturating_add(self.config().terminal_retention_ms));
         active.terminal_entered_at_ms = Some(now_ms);
         match status {
             RpcFastIntentStatus::Dropped => state.dropped_total = state.dropped_total.saturating_add(1),
             RpcFastIntentStatus::Expired => state.expired_total = state.expired_total.saturating_add(1),
             _ => {}
         }
+        self.record_status_change(state, &active, now_ms);
         self.insert_terminal(state, active.clone(), now_ms);
         active
     }
 
+    fn record_status_change(&self, state: &mut HfaState, record: &IntentRecord, now_ms: u64) {
+        if matches!(record.status, RpcFastIntentStatus::Locked | RpcFastIntentStatus::FastConfirmed) {
+            state.dirty_locked = true;
+        }
+        if record.status == RpcFastIntentStatus::FastConfirmed {
+            state.fast_confirmed_total = state.fast_confirmed_total.saturating_add(1);
+            if record.received_at_ms > 0 {
+                state.fast_confirm_latency_samples_ms.push_back((now_ms, now_ms.saturating_sub(record.received_at_ms)));
+            }
+            while state
+                .fast_confirm_latency_samples_ms
+                .front()
+                .is_some_and(|(ts_ms, _)| ts_ms.saturating_add(CONFIRM_LATENCY_SAMPLE_WINDOW_MS) < now_ms)
+            {
+                state.fast_confirm_latency_samples_ms.pop_front();
+            }
+        }
+        if state.status_changes.len() >= MAX_PENDING_STATUS_CHANGES {
+            state.status_changes.pop_front();
+        }
+        state.status_changes.push_back(FastIntentStatusChange::from_record(record, now_ms));
+    }
+
     fn insert_terminal(&self, state: &mut HfaState, record: IntentRecord, now_ms: u64) {
+        state.dirty.insert(record.intent_id);
         if let Some(existing) = state.terminal.insert(record.intent_id, record.clone()) {
             state.terminal_bytes = state.terminal_bytes.saturating_sub(existing.estimated_bytes);
         }
@@ -1298,7 +1750,10 @@ impl HfaEngine {
             if let Some(active) = state.active.get_mut(&intent_id) {
                 active.status = RpcFastIntentStatus::FastConfirmed;
                 active.confirm_after_ms = None;
+                let active = active.clone();
+                self.record_status_change(state, &active, now_ms);
             }
+            state.dirty.insert(intent_id);
             state.outbound_microblocks.push(FastMicroblockP2pData { microblock_time_ms: now_ms, intent_ids: vec![intent_id] });
         }
     }
@@ -1383,7 +1838,7 @@ impl HfaEngine {
             }
         }
 
-        if projected_len > self.config.max_terminal_entries || projected_bytes > self.config.max_terminal_bytes {
+        if projected_len > self.config().max_terminal_entries || projected_bytes > self.config().max_terminal_bytes {
             let mut expired_terminal_candidates: Vec<(RpcHash, u64)> = state
                 .terminal
                 .iter()
@@ -1395,7 +1850,7 @@ impl HfaEngine {
             expired_terminal_candidates.sort_by_key(|(_, entered_at_ms)| *entered_at_ms);
 
             for (id, _) in expired_terminal_candidates {
-                if projected_len <= self.config.max_terminal_entries && projected_bytes <= self.config.max_terminal_bytes {
+                if projected_len <= self.config().max_terminal_entries && projected_bytes <= self.config().max_terminal_bytes {
                     break;
                 }
                 ids_to_evict.push(id);
@@ -1407,7 +1862,7 @@ impl HfaEngine {
             }
         }
 
-        if projected_len > self.config.max_terminal_entries || projected_bytes > self.config.max_terminal_bytes {
+        if projected_len > self.config().max_terminal_entries || projected_bytes > self.config().max_terminal_bytes {
             let mut oldest_terminal_candidates: Vec<(RpcHash, u64)> = state
                 .terminal
                 .iter()
@@ -1416,7 +1871,7 @@ impl HfaEngine {
             oldest_terminal_candidates.sort_by_key(|(_, entered_at_ms)| *entered_at_ms);
 
             for (id, _) in oldest_terminal_candidates {
-                if projected_len <= self.config.max_terminal_entries && projected_bytes <= self.config.max_terminal_bytes {
+                if projected_len <= self.config().max_terminal_entries && projected_bytes <= self.config().max_terminal_bytes {
                     break;
                 }
                 ids_to_evict.push(id);
@@ -1432,6 +1887,7 @@ impl HfaEngine {
         ids_to_evict.dedup();
         for id in ids_to_evict {
             if let Some(record) = state.terminal.remove(&id) {
+                state.dirty.insert(id);
                 state.terminal_bytes = state.terminal_bytes.saturating_sub(record.estimated_bytes);
                 match cause_by_id.get(&id).copied() {
                     Some("retention") => {
@@ -1453,15 +1909,15 @@ impl HfaEngine {
         }
         state.last_mode_eval_ms = now_ms;
 
-        let queue_ratio = if self.config.fast_arbiter_queue_capacity == 0 {
+        let queue_ratio = if self.config().fast_arbiter_queue_capacity == 0 {
             1.0
         } else {
-            state.fast_arbiter_queue_len as f64 / self.config.fast_arbiter_queue_capacity as f64
+            state.fast_arbiter_queue_len as f64 / self.config().fast_arbiter_queue_capacity as f64
         };
-        let validation_queue_ratio = if self.config.max_pending_intents == 0 {
+        let validation_queue_ratio = if self.config().max_pending_intents == 0 {
             1.0
         } else {
-            state.prelock.len() as f64 / self.config.max_pending_intents as f64
+            state.prelock.len() as f64 / self.config().max_pending_intents as f64
         };
         self.evict_arbiter_samples(state, now_ms);
         let arbiter_wait_p95_ms = sample_p95(&state.fast_arbiter_wait_samples_ms);
@@ -1547,7 +2003,7 @@ impl HfaEngine {
         let low_water = newest.queue_ratio <= 0.40
             && newest.validation_queue_ratio <= 0.40
             && newest.arbiter_wait_p95_ms <= 8.0
-            && newest.cpu_ratio <= self.config.cpu_low_water_ratio
+            && newest.cpu_ratio <= self.config().cpu_low_water_ratio
             && overload_reject_rate_10s <= 0.002
             && pull_fail_rate_10s <= 0.02;
         state.pause_recovery_streak = if !pause_trigger && low_water { state.pause_recovery_streak.saturating_add(1) } else { 0 };
@@ -1622,16 +2078,16 @@ impl HfaEngine {
 
     fn microblock_interval_ms_for_mode(&self, mode: HfaMode) -> u64 {
         match mode {
-            HfaMode::Normal => self.config.microblock_interval_ms_normal,
-            HfaMode::Degraded => self.config.microblock_interval_ms_degraded,
-            HfaMode::Paused => self.config.microblock_interval_ms_degraded,
+            HfaMode::Normal => self.config().microblock_interval_ms_normal,
+            HfaMode::Degraded => self.config().microblock_interval_ms_degraded,
+            HfaMode::Paused => self.config().microblock_interval_ms_degraded,
         }
     }
 
     fn revalidation_budget_for_mode(&self, mode: HfaMode) -> usize {
         match mode {
-            HfaMode::Normal => self.config.revalidation_budget_normal,
-            HfaMode::Degraded | HfaMode::Paused => self.config.revalidation_budget_degraded,
+            HfaMode::Normal => self.config().revalidation_budget_normal,
+            HfaMode::Degraded | HfaMode::Paused => self.config().revalidation_budget_degraded,
         }
     }
 }
@@ -1720,6 +2176,18 @@ fn map_tx_error_to_reason(err: TxRuleError) -> &'static str {
     }
 }
 
+impl HfaMetricsSnapshot {
+    /// Operating mode as exposed over RPC; an operator pause reports as paused.
+    pub fn rpc_mode(&self) -> RpcHfaMode {
+        match self.mode {
+            _ if self.operator_paused => RpcHfaMode::Paused,
+            "degraded" => RpcHfaMode::Degraded,
+            "paused" => RpcHfaMode::Paused,
+            _ => RpcHfaMode::Normal,
+        }
+    }
+}
+
 fn mode_name(mode: HfaMode) -> &'static str {
     match mode {
         HfaMode::Normal => "normal",
@@ -1771,7 +2239,7 @@ fn derive_base_tx_id_from_record(record: &IntentRecord) -> Option<RpcHash> {
 #[async_trait]
 impl HfaP2pBridge for HfaEngine {
     fn hfa_enabled(&self) -> bool {
-        self.config.enabled
+        self.config().enabled
     }
 
     fn has_fast_intent(&self, intent_id: RpcHash) -> bool {
@@ -1906,8 +2374,10 @@ mod tests {
             cancel_token: None,
             base_tx_fingerprint: sample_hash(42),
             inputs: Vec::new(),
+            input_script_public_keys: Vec::new(),
             estimated_bytes: 1,
             p2p_relayed: false,
+            received_at_ms: 0,
         }
     }
 
@@ -1949,6 +2419,31 @@ mod tests {
         assert!(active.confirm_after_ms.is_none());
         assert_eq!(state.outbound_microblocks.len(), 1);
         assert_eq!(state.outbound_microblocks[0].intent_ids, vec![intent_id]);
+        assert_eq!(state.status_changes.len(), 1);
+        assert_eq!(state.status_changes[0].intent_id, intent_id);
+        assert_eq!(state.status_changes[0].status, RpcFastIntentStatus::FastConfirmed);
+        assert_eq!(state.status_changes[0].changed_at_ms, 100);
+    }
+
+    #[test]
+    fn fast_confirmation_records_latency_sample() {
+        let engine = HfaEngine::new(HfaRuntimeConfig::new(true, 0.7));
+        let confirmed_id = sample_hash(11);
+        let restored_id = sample_hash(12);
+
+        let mut state = HfaState::new(1);
+        let mut confirmed = dummy_record(confirmed_id, RpcFastIntentStatus::Locked, None);
+        confirmed.confirm_after_ms = Some(100);
+        confirmed.received_at_ms = 40;
+        state.active.insert(confirmed_id, confirmed);
+        let mut restored = dummy_record(restored_id, RpcFastIntentStatus::Locked, None);
+        restored.confirm_after_ms = Some(100);
+        state.active.insert(restored_id, restored);
+
+        engine.confirm_ready_active(&mut state, 100);
+        assert_eq!(state.fast_confirmed_total, 2);
+        assert_eq!(state.fast_confirm_latency_samples_ms.iter().copied().collect::<Vec<_>>(), vec![(100, 60)]);
+        assert_eq!(sample_p95(&state.fast_confirm_latency_samples_ms), 60.0);
     }
 
     #[test]
@@ -2147,4 +2642,132 @@ mod tests {
         engine.update_mode(&mut state, 0.2, true, 120.0, 2000);
         assert_eq!(state.mode, HfaMode::Paused);
     }
+
+    #[test]
+    fn set_config_validates_and_keeps_enabled() {
+        let engine = HfaEngine::new(HfaRuntimeConfig::new(true, 0.7));
+
+        let mut config = engine.config();
+        config.max_pending_intents = 0;
+        assert!(engine.set_config(config).is_err());
+        assert_eq!(engine.config().max_pending_intents, DEFAULT_MAX_PENDING_INTENTS);
+
+        let mut config = engine.config();
+        config.enabled = false;
+        config.fast_ttl_ms = 7_000;
+        let applied = engine.set_config(config).expect("valid config must apply");
+        assert!(applied.enabled);
+        assert_eq!(engine.config().fast_ttl_ms, 7_000);
+        assert!(engine.metrics_snapshot().config.enabled);
+    }
+
+    #[test]
+    fn operator_pause_is_surfaced_in_metrics() {
+        let engine = HfaEngine::new(HfaRuntimeConfig::new(true, 0.7));
+        assert!(!engine.metrics_snapshot().operator_paused);
+        engine.set_operator_paused(true);
+        assert!(engine.is_operator_paused());
+        assert!(engine.metrics_snapshot().operator_paused);
+        engine.set_operator_paused(false);
+        assert!(!engine.metrics_snapshot().operator_paused);
+    }
+
+    #[test]
+    fn persisted_intents_are_restored_with_locks() {
+        use cryptix_database::{create_temp_db, prelude::ConnBuilder};
+
+        let (_lifetime, db) = create_temp_db!(ConnBuilder::default().with_files_limit(10));
+        let engine = HfaEngine::with_store(HfaRuntimeConfig::new(true, 0.7), DbHfaIntentStore::new(db.clone()));
+        let now_ms = unix_now();
+        let active_id = sample_hash(50);
+        let prelock_id = sample_hash(51);
+        let outpoint = TransactionOutpoint::new(sample_hash(52), 0);
+        let tx = Transaction::new(
+            1,
+            vec![cryptix_consensus_core::tx::TransactionInput::new(outpoint, vec![0x51], 0, 0)],
+            Vec::new(),
+            0,
+            Default::default(),
+            0,
+            vec![1],
+        );
+        {
+            let mut state = engine.lock_state();
+            let mut active = dummy_record(active_id, RpcFastIntentStatus::FastConfirmed, None);
+            active.base_tx = Some(RpcTransaction::from(&tx));
+            active.base_tx_fingerprint = hash_tx(&tx, false);
+            active.inputs = vec![outpoint];
+            active.expires_at_ms = Some(now_ms + 60_000);
+            active.retention_until_ms = Some(now_ms + 120_000);
+            state.active.insert(active_id, active);
+            state.prelock.insert(prelock_id, dummy_record(prelock_id, RpcFastIntentStatus::Validated, None));
+            state.dirty.extend([active_id, prelock_id]);
+        }
+        engine.persist_dirty();
+        drop(engine);
+
+        let restored = HfaEngine::with_store(HfaRuntimeConfig::new(true, 0.7), DbHfaIntentStore::new(db));
+        let state = restored.lock_state();
+        assert_eq!(state.active.get(&active_id).map(|record| record.status), Some(RpcFastIntentStatus::FastConfirmed));
+        assert_eq!(state.input_locks.get(&outpoint), Some(&active_id));
+        assert_eq!(state.active_fingerprint.get(&hash_tx(&tx, false)), Some(&active_id));
+        assert_eq!(state.restored, vec![active_id]);
+        let interrupted = state.terminal.get(&prelock_id).expect("interrupted submission must become terminal");
+        assert_eq!(interrupted.status, RpcFastIntentStatus::Rejected);
+        assert_eq!(interrupted.reason.as_deref(), Some(REASON_NODE_RESTART));
+    }
+
+    #[test]
+    fn fast_confirmations_between_ticks_survive_restart() {
+        use cryptix_database::{create_temp_db, prelude::ConnBuilder};
+
+        let (_lifetime, db) = create_temp_db!(ConnBuilder::default().with_files_limit(10));
+        let engine = HfaEngine::with_store(HfaRuntimeConfig::new(true, 0.7), DbHfaIntentStore::new(db.clone()));
+        let now_ms = unix_now();
+        let locked_record = |intent_id: RpcHash, byte: u8, confirm_after_ms: u64| {
+            let outpoint = TransactionOutpoint::new(sample_hash(byte), 0);
+            let tx = Transaction::new(
+                1,
+                vec![cryptix_consensus_core::tx::TransactionInput::new(outpoint, vec![0x51], 0, 0)],
+                Vec::new(),
+                0,
+                Default::default(),
+                0,
+                vec![byte],
+            );
+            let mut record = dummy_record(intent_id, RpcFastIntentStatus::Locked, None);
+            record.base_tx = Some(RpcTransaction::from(&tx));
+            record.base_tx_fingerprint = hash_tx(&tx, false);
+            record.inputs = vec![outpoint];
+            record.expires_at_ms = Some(now_ms + 60_000);
+            record.retention_until_ms = Some(now_ms + 120_000);
+            record.confirm_after_ms = Some(confirm_after_ms);
+            (record, outpoint)
+        };
+        // One intent is confirmed by a remote microblock, the other by the local cadence on a status query
+        let (remote_id, local_id) = (sample_hash(60), sample_hash(61));
+        let (remote, remote_outpoint) = locked_record(remote_id, 62, now_ms + 60_000);
+        let (local, local_outpoint) = locked_record(local_id, 63, now_ms.saturating_sub(1));
+        {
+            let mut state = engine.lock_state();
+            for record in [remote, local] {
+                state.input_locks.insert(record.inputs[0], record.intent_id);
+                state.active_fingerprint.insert(record.base_tx_fingerprint, record.intent_id);
+                state.active.insert(record.intent_id, record);
+            }
+        }
+
+        assert!(engine.on_remote_fast_microblock(&[remote_id], now_ms).is_empty());
+        let status = engine.get_fast_intent_status(GetFastIntentStatusRequest { intent_id: local_id, client_last_node_epoch: None });
+        assert_eq!(status.status, RpcFastIntentStatus::FastConfirmed);
+        // No tick ran, so nothing but the transitions above flushed the intents
+        drop(engine);
+
+        let restored = HfaEngine::with_store(HfaRuntimeConfig::new(true, 0.7), DbHfaIntentStore::new(db));
+        let state = restored.lock_state();
+        for (intent_id, outpoint) in [(remote_id, remote_outpoint), (local_id, local_outpoint)] {
+            assert_eq!(state.active.get(&intent_id).map(|record| record.status), Some(RpcFastIntentStatus::FastConfirmed));
+            assert_eq!(state.input_locks.get(&outpoint), Some(&intent_id));
+        }
+    }
 }
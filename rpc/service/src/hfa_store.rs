@@ -0,0 +1,83 @@
+use cryptix_consensus_core::tx::{ScriptPublicKey, Transaction, TransactionOutpoint};
+use cryptix_database::{
+    prelude::{BatchDbWriter, CachePolicy, CachedDbAccess, DirectDbWriter, StoreResult, DB},
+    registry::DatabaseStorePrefixes,
+};
+use cryptix_hashes::Hash;
+use cryptix_rpc_core::RpcFastIntentStatus;
+use cryptix_utils::mem_size::MemSizeEstimator;
+use rocksdb::WriteBatch;
+use serde::{Deserialize, Serialize};
+use std::{fmt, sync::Arc};
+
+/// Persisted form of a fast intent. Input locks and base tx fingerprints
+/// are rebuilt from the stored records on restore.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StoredFastIntent {
+    pub intent_id: Hash,
+    pub status: RpcFastIntentStatus,
+    pub reason: Option<String>,
+    pub node_epoch: u64,
+    pub base_tx: Option<Transaction>,
+    pub intent_nonce: u64,
+    pub client_created_at_ms: u64,
+    pub max_fee: u64,
+    pub expires_at_ms: Option<u64>,
+    pub confirm_after_ms: Option<u64>,
+    pub retention_until_ms: Option<u64>,
+    pub terminal_entered_at_ms: Option<u64>,
+    pub cancel_token: Option<String>,
+    pub base_tx_fingerprint: Hash,
+    pub inputs: Vec<TransactionOutpoint>,
+    pub input_script_public_keys: Vec<ScriptPublicKey>,
+}
+
+impl MemSizeEstimator for StoredFastIntent {}
+
+/// Fast intent records of the HFA rail, kept in the node meta database.
+#[derive(Clone)]
+pub struct DbHfaIntentStore {
+    db: Arc<DB>,
+    access: CachedDbAccess<Hash, StoredFastIntent>,
+}
+
+impl DbHfaIntentStore {
+    pub fn new(db: Arc<DB>) -> Self {
+        Self { db: Arc::clone(&db), access: CachedDbAccess::new(db, CachePolicy::Empty, DatabaseStorePrefixes::HfaIntents.into()) }
+    }
+
+    /// Loads all stored intents. Records that fail to decode are skipped and returned as a count.
+    pub fn load_all(&self) -> (Vec<StoredFastIntent>, usize) {
+        let mut records = Vec::new();
+        let mut corrupted = 0usize;
+        for entry in self.access.iterator() {
+            match entry {
+                Ok((_, record)) => records.push(record),
+                Err(_) => corrupted += 1,
+            }
+        }
+        (records, corrupted)
+    }
+
+    /// Writes `upserts` and removes `deletes` in a single batch.
+    pub fn apply(&self, upserts: Vec<StoredFastIntent>, deletes: Vec<Hash>) -> StoreResult<()> {
+        if upserts.is_empty() && deletes.is_empty() {
+            return Ok(());
+        }
+        let mut batch = WriteBatch::default();
+        self.access.write_many(BatchDbWriter::new(&mut batch), &mut upserts.into_iter().map(|record| (record.intent_id, record)))?;
+        self.access.delete_many(BatchDbWriter::new(&mut batch), &mut deletes.into_iter())?;
+        self.db.write(batch)?;
+        Ok(())
+    }
+
+    pub fn delete_all(&self) -> StoreResult<()> {
+        self.access.delete_all(DirectDbWriter::new(&self.db))
+    }
+}
+
+impl fmt::Debug for DbHfaIntentStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DbHfaIntentStore").finish_non_exhaustive()
+    }
+}
@@ -2,4 +2,5 @@ pub mod block_scan_cache;
 pub mod collector;
 pub mod converter;
 pub mod hfa;
+pub mod hfa_store;
 pub mod service;
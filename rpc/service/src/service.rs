@@ -5,31 +5,42 @@ use crate::block_scan_cache::{RpcBlockScanCache, RpcBlockScanCacheActivity, RpcB
 use crate::converter::feerate_estimate::{FeeEstimateConverter, FeeEstimateVerboseConverter};
 use crate::converter::{consensus::ConsensusConverter, index::IndexConverter, protocol::ProtocolConverter};
 use crate::hfa::{FastIngressSource, HfaEngine, HfaRuntimeConfig};
+use crate::hfa_store::DbHfaIntentStore;
 use crate::service::NetworkType::{Mainnet, Testnet};
 use async_trait::async_trait;
-use blake2b_simd::Params as Blake2bParams;
 use cryptix_addresses::{Address, Version as AddressVersion};
+use cryptix_atomic_proof::{
+    consensus::AtomicConsensusNamespaceDigestHex, AtomicConsensusBalanceAnchor, AtomicConsensusBalanceAnchorHex, AtomicProofError,
+    AtomicStateProof, AtomicStateProofHex,
+};
 use cryptix_atomicindex::{
+    analytics_export::ANALYTICS_MANIFEST_FILE_NAME,
     liquidity_math::{
-        calculate_trade_fee, cpmm_buy, cpmm_sell, initial_virtual_cpay_reserves_sompi_for_curve,
-        initial_virtual_token_reserves_for_curve, liquidity_curve_mode_label, max_buy_in_sompi, max_tokens_out,
-        min_gross_input_for_token_out, validate_liquidity_curve_mode, validate_liquidity_curve_parameters, LiquidityMathError,
-        INITIAL_REAL_CPAY_RESERVES_SOMPI, LIQUIDITY_MIN_PAYOUT_SOMPI, LIQUIDITY_TOKEN_DECIMALS, MAX_LIQUIDITY_SUPPLY_RAW,
-        MIN_CPAY_RESERVE_SOMPI, MIN_LIQUIDITY_SEED_RESERVE_SOMPI, MIN_LIQUIDITY_SUPPLY_RAW,
+        calculate_trade_fee, cpmm_buy, cpmm_buy_exact_out, cpmm_sell, cpmm_sell_exact_out, gross_amount_for_net_amount,
+        initial_virtual_cpay_reserves_sompi_for_curve, initial_virtual_token_reserves_for_curve, liquidity_curve_mode_label,
+        max_buy_in_sompi, max_tokens_out, min_gross_input_for_token_out, pair_add_liquidity, pair_initial_shares,
+        pair_remove_liquidity, pair_swap_exact_in, pair_swap_exact_out, validate_liquidity_curve_mode,
+        validate_liquidity_curve_parameters, LiquidityMathError, INITIAL_REAL_CPAY_RESERVES_SOMPI, LIQUIDITY_MIN_PAYOUT_SOMPI,
+        LIQUIDITY_TOKEN_DECIMALS, MAX_LIQUIDITY_SUPPLY_RAW, MIN_CPAY_RESERVE_SOMPI, MIN_LIQUIDITY_SEED_RESERVE_SOMPI,
+        MIN_LIQUIDITY_SUPPLY_RAW,
     },
     payload::{
-        parse_atomic_token_payload, BuyLiquidityExactInOp, CreateAssetWithMintOp, CreateLiquidityAssetOp, NoopReason,
-        SellLiquidityExactInOp, SupplyMode, TokenOp,
+        parse_atomic_token_payload, ApplyStatus, BuyLiquidityExactInOp, BuyLiquidityExactOutOp, CreateAssetWithMintOp,
+        CreateLiquidityAssetOp, NoopReason, RenounceMintAuthorityOp, SellLiquidityExactInOp, SellLiquidityExactOutOp, SupplyMode,
+        TokenOp, TokenOpCode, TransferMintAuthorityOp, UpdateMetadataOp,
     },
     service::{AtomicTokenService, ScBootstrapSource, ScSnapshotChunk, ScSnapshotManifestSignature},
     state::{
         nonce_key_for_op, AtomicTokenHealth, AtomicTokenReadContext, AtomicTokenReadView, AtomicTokenRuntimeState,
-        LiquidityFeeRecipientState, LiquidityPoolState, NonceKey, ProcessedOp, TokenAsset, TokenAssetClass, TokenEvent,
-        TokenHolderEntry, TokenOwnerBalanceEntry,
+        LiquidityFeeRecipientState, LiquidityPoolState, NonceKey, PairPoolState, ProcessedOp, TokenAsset, TokenAssetClass, TokenEvent,
+        TokenEventFilter, TokenHolderEntry, TokenMetadataRevision, TokenOwnerBalanceEntry,
     },
 };
 use cryptix_consensus_core::api::counters::ProcessingCounters;
 use cryptix_consensus_core::api::args::TransactionValidationArgs;
+use cryptix_consensus_core::atomic::{
+    cat_owner_id, CAT_OWNER_AUTH_SCHEME_PUBKEY, CAT_OWNER_AUTH_SCHEME_PUBKEY_ECDSA, CAT_OWNER_AUTH_SCHEME_SCRIPT_HASH,
+};
 use cryptix_consensus_core::errors::block::RuleError;
 use cryptix_consensus_core::mass::{calc_storage_mass, Kip9Version, MassCalculator};
 use cryptix_consensus_core::{
@@ -62,6 +73,11 @@ use cryptix_index_core::{
     connection::IndexChannelConnection, indexed_utxos::UtxoSetByScriptPublicKey, notification::Notification as IndexNotification,
     notifier::IndexNotifier,
 };
+use cryptix_messageindex::{
+    envelope::{MessageSender, RecipientTag},
+    service::MessageIndexService,
+    store::MessageEntry,
+};
 use cryptix_mining::feerate::FeeEstimateVerbose;
 use cryptix_mining::model::tx_query::TransactionQuery;
 use cryptix_mining::{manager::MiningManagerProxy, mempool::tx::Orphan};
@@ -102,6 +118,7 @@ use std::time::{Duration, Instant};
 use std::{
     collections::{HashMap, HashSet},
     iter::once,
+    path::Path,
     sync::{atomic::Ordering, Arc, Mutex},
     vec,
 };
@@ -135,6 +152,7 @@ pub struct RpcCoreService {
     flow_context: Arc<FlowContext>,
     utxoindex: Option<UtxoIndexProxy>,
     atomic_token_service: Arc<AtomicTokenService>,
+    message_index_service: Option<Arc<MessageIndexService>>,
     config: Arc<Config>,
     consensus_converter: Arc<ConsensusConverter>,
     index_converter: Arc<IndexConverter>,
@@ -161,12 +179,15 @@ const RPC_CORE: &str = "rpc-core";
 const NORMAL_POLICY_REJECT_FAST_LOCK_CONFLICT: &str = "normal_policy_reject_fast_lock_conflict";
 const HFA_MAINTENANCE_INTERVAL: Duration = Duration::from_millis(100);
 const TOKEN_EVENTS_NOTIFY_POLL_INTERVAL: Duration = Duration::from_millis(250);
+const MESSAGES_NOTIFY_POLL_INTERVAL: Duration = Duration::from_millis(250);
 const GET_BLOCK_TEMPLATE_UNSYNCED_LOG_INTERVAL: Duration = Duration::from_secs(60);
 const TOKEN_EVENTS_LIMIT_MAX: usize = 4096;
 const TOKEN_ASSETS_LIMIT_MAX: usize = 2048;
 const TOKEN_OWNER_BALANCES_LIMIT_MAX: usize = 4096;
 const TOKEN_HOLDERS_LIMIT_MAX: usize = 4096;
 const TOKEN_LIQUIDITY_HOLDERS_LIMIT_MAX: usize = 4096;
+const MESSAGES_LIMIT_DEFAULT: usize = 100;
+const MESSAGES_LIMIT_MAX: usize = 1000;
 const LIQUIDITY_SUBMIT_READY_RECHECK_ATTEMPTS: usize = 6;
 const LIQUIDITY_SUBMIT_READY_RECHECK_DELAY: Duration = Duration::from_millis(150);
 const RPC_DIAGNOSTICS_SLOW_THRESHOLD: Duration = Duration::from_millis(500);
@@ -185,12 +206,10 @@ const TX_LOOKUP_ARCHIVAL_LOOKBACK_MAX_HEADERS: u64 = 1_000_000;
 const TX_LOOKUP_DAA_WINDOW: u64 = 16;
 const TX_LOOKUP_MAX_CANDIDATE_BLOCKS: usize = 512;
 const TX_LOOKUP_MAX_SCANNED_BLOCKS: usize = 4096;
-const CAT_OWNER_DOMAIN: &[u8] = b"CAT_OWNER_V2";
-const OWNER_AUTH_SCHEME_PUBKEY: u8 = 0;
-const OWNER_AUTH_SCHEME_PUBKEY_ECDSA: u8 = 1;
-const OWNER_AUTH_SCHEME_SCRIPT_HASH: u8 = 2;
 const LIQUIDITY_QUOTE_SIDE_BUY: u32 = 0;
 const LIQUIDITY_QUOTE_SIDE_SELL: u32 = 1;
+const LIQUIDITY_QUOTE_SIDE_BUY_EXACT_OUT: u32 = 2;
+const LIQUIDITY_QUOTE_SIDE_SELL_EXACT_OUT: u32 = 3;
 const CAT_ERR_HISTORICAL_STATE_UNAVAILABLE: &str = "CAT_ERR_HISTORICAL_STATE_UNAVAILABLE";
 const CAT_ERR_MIN_OUT_VIOLATION: &str = "CAT_ERR_MIN_OUT_VIOLATION";
 const CAT_ERR_ZERO_OUTPUT: &str = "CAT_ERR_ZERO_OUTPUT";
@@ -427,6 +446,7 @@ impl RpcCoreService {
         subscription_context: SubscriptionContext,
         utxoindex: Option<UtxoIndexProxy>,
         atomic_token_service: Arc<AtomicTokenService>,
+        message_index_service: Option<Arc<MessageIndexService>>,
         config: Arc<Config>,
         core: Arc<Core>,
         processing_counters: Arc<ProcessingCounters>,
@@ -437,6 +457,7 @@ impl RpcCoreService {
         grpc_tower_counters: Arc<TowerConnectionCounters>,
         system_info: SystemInfo,
         hfa_config: HfaRuntimeConfig,
+        hfa_store: Option<DbHfaIntentStore>,
     ) -> Self {
         // This notifier UTXOs subscription granularity to index-processor or consensus notifier
         let policies = match index_notifier {
@@ -494,7 +515,10 @@ impl RpcCoreService {
         let notifier =
             Arc::new(Notifier::new(RPC_CORE, EVENT_TYPE_ARRAY[..].into(), collectors, subscribers, subscription_context, 1, policies));
 
-        let hfa_engine = Arc::new(HfaEngine::new(hfa_config));
+        let hfa_engine = Arc::new(match hfa_store {
+            Some(store) => HfaEngine::with_store(hfa_config, store),
+            None => HfaEngine::new(hfa_config),
+        });
         flow_context.set_hfa_bridge(hfa_engine.clone());
         let block_scan_cache = RpcBlockScanCache::new(RpcBlockScanCacheConfig::new(
             config.rpc_block_scan_cache,
@@ -518,6 +542,7 @@ impl RpcCoreService {
             flow_context,
             utxoindex,
             atomic_token_service,
+            message_index_service,
             config,
             consensus_converter,
             index_converter,
@@ -580,10 +605,38 @@ impl RpcCoreService {
                             let delta = current_sequence.saturating_sub(last_sequence);
                             let event_count = delta.min(u64::from(u32::MAX)) as u32;
 
+                            // The events are carried in full so that filtered subscriptions can be served by the notifier.
+                            let mut events = Vec::new();
+                            let mut after_sequence = last_sequence;
+                            while after_sequence < current_sequence {
+                                let chunk = atomic_token_service
+                                    .get_events_since_capped(after_sequence, TOKEN_EVENTS_LIMIT_MAX, current_sequence)
+                                    .await;
+                                let Some(last_event) = chunk.last() else { break };
+                                after_sequence = last_event.sequence;
+                                events.extend(chunk);
+                            }
+
+                            // Applied and reorged UpdateMetadata events both change the asset's current metadata.
+                            let mut metadata_changed_asset_ids = Vec::new();
+                            for event in events.iter() {
+                                if event.apply_status != ApplyStatus::Applied || event.details.op_type != Some(TokenOpCode::UpdateMetadata) {
+                                    continue;
+                                }
+                                if let Some(asset_id) = event.details.asset_id.map(|asset_id| asset_id.as_slice().to_hex()) {
+                                    if !metadata_changed_asset_ids.contains(&asset_id) {
+                                        metadata_changed_asset_ids.push(asset_id);
+                                    }
+                                }
+                            }
+                            let events = events.into_iter().map(Self::map_token_event).collect();
+
                             if let Err(err) = notifier.notify(Notification::TokenEventsChanged(TokenEventsChangedNotification {
                                 from_sequence,
                                 to_sequence,
                                 event_count,
+                                metadata_changed_asset_ids,
+                                events,
                             })) {
                                 warn!("failed broadcasting token-events-changed notification: {err}");
                             }
@@ -594,6 +647,43 @@ impl RpcCoreService {
             }
         });
 
+        if let Some(message_index_service) = self.message_index_service.clone() {
+            let shutdown_listener = self.shutdown.listener.clone();
+            let notifier = self.notifier.clone();
+            tokio::spawn(async move {
+                let mut tick = interval(MESSAGES_NOTIFY_POLL_INTERVAL);
+                tick.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+                let shutdown = shutdown_listener;
+                tokio::pin!(shutdown);
+
+                let mut last_sequence = message_index_service.last_sequence();
+                loop {
+                    select! {
+                        _ = &mut shutdown => break,
+                        _ = tick.tick() => {
+                            if message_index_service.last_sequence() <= last_sequence {
+                                continue;
+                            }
+                            let entries = match message_index_service.get_messages_since(last_sequence, MESSAGES_LIMIT_MAX) {
+                                Ok(entries) => entries,
+                                Err(err) => {
+                                    warn!("failed reading indexed messages after sequence {last_sequence}: {err}");
+                                    continue;
+                                }
+                            };
+                            let Some(last_entry) = entries.last() else { continue };
+                            last_sequence = last_entry.sequence;
+                            let entries = entries.into_iter().map(Self::map_message_entry).collect();
+                            if let Err(err) = notifier.notify(Notification::MessagesReceived(MessagesReceivedNotification { entries })) {
+                                warn!("failed broadcasting messages-received notification: {err}");
+                            }
+                        }
+                    }
+                }
+            });
+        }
+
         self.clone().start_rpc_block_scan_cache_warmer();
 
         if !self.hfa_engine.is_enabled() {
@@ -605,6 +695,8 @@ impl RpcCoreService {
         let flow_context = self.flow_context.clone();
         let consensus_manager = self.consensus_manager.clone();
         let perf_monitor = self.perf_monitor.clone();
+        let notifier = self.notifier.clone();
+        let prefix = self.config.prefix();
 
         tokio::spawn(async move {
             let mut tick = interval(HFA_MAINTENANCE_INTERVAL);
@@ -624,11 +716,16 @@ impl RpcCoreService {
                         let basechain_block_latency_ms = unix_now().saturating_sub(sink_timestamp_ms) as f64;
                         let cpu_ratio = (perf_monitor.snapshot().cpu_usage as f64 / 100.0).clamp(0.0, 1.0);
 
+                        hfa_engine.revalidate_restored(session.clone()).await;
                         hfa_engine.revalidate_active_budgeted(session, is_synced, cpu_ratio, basechain_block_latency_ms).await;
+                        // persist before peers and subscribers learn about the new statuses
+                        hfa_engine.persist_dirty();
                         flow_context.broadcast_outbound_fast_microblocks().await;
+                        Self::notify_fast_intent_status_changes(&hfa_engine, &notifier, prefix);
                     }
                 }
             }
+            hfa_engine.persist_dirty();
         });
     }
 
@@ -1170,6 +1267,15 @@ impl RpcCoreService {
             .map_err(|err| RpcError::General(format!("failed reading consensus atomic state hash: {err}")))
     }
 
+    async fn consensus_balance_anchor(&self, block_hash: BlockHash) -> RpcResult<AtomicConsensusBalanceAnchor> {
+        let session = self.consensus_manager.consensus().session().await;
+        session
+            .async_get_atomic_consensus_balance_anchor(block_hash)
+            .await
+            .map_err(|err| RpcError::General(format!("failed reading consensus Atomic balance anchor: {err}")))?
+            .ok_or_else(|| RpcError::General(format!("consensus Atomic state at block `{block_hash}` is unavailable")))
+    }
+
     async fn virtual_utxo_entry_exact(&self, outpoint: TransactionOutpoint) -> Option<UtxoEntry> {
         let session = self.consensus_manager.consensus().session().await;
         session
@@ -1326,6 +1432,44 @@ impl RpcCoreService {
         })
     }
 
+    fn map_token_state_proof(proof: &AtomicStateProof) -> RpcTokenStateProof {
+        let proof = AtomicStateProofHex::from(proof);
+        RpcTokenStateProof {
+            logical_key: proof.logical_key,
+            value: proof.value,
+            bucket_index: proof.bucket_index,
+            bucket_siblings: proof
+                .bucket_siblings
+                .into_iter()
+                .map(|leaf| RpcTokenStateLeaf { logical_key: leaf.logical_key, value: leaf.value })
+                .collect(),
+            bucket_hashes: proof.bucket_hashes,
+            state_root: proof.state_root,
+        }
+    }
+
+    fn map_token_consensus_balance_anchor(anchor: &AtomicConsensusBalanceAnchor) -> RpcTokenConsensusBalanceAnchor {
+        let anchor = AtomicConsensusBalanceAnchorHex::from(anchor);
+        let digest = |digest: AtomicConsensusNamespaceDigestHex| RpcTokenConsensusDigest { count: digest.count, xor: digest.xor };
+        RpcTokenConsensusBalanceAnchor {
+            version: anchor.version,
+            nonces: digest(anchor.nonces),
+            assets: digest(anchor.assets),
+            anchor_counts: digest(anchor.anchor_counts),
+            allowances: digest(anchor.allowances),
+            locks: digest(anchor.locks),
+            balances: anchor
+                .balances
+                .into_iter()
+                .map(|balance| RpcTokenConsensusBalance {
+                    asset_id: balance.asset_id,
+                    owner_id: balance.owner_id,
+                    amount: balance.amount,
+                })
+                .collect(),
+        }
+    }
+
     fn map_token_asset(asset: TokenAsset) -> RpcTokenAsset {
         let safe_name = Self::sanitize_token_display_text(&asset.name);
         let safe_symbol = Self::sanitize_token_display_text(&asset.symbol);
@@ -1349,6 +1493,36 @@ impl RpcCoreService {
         }
     }
 
+    fn map_token_metadata_revision(revision: TokenMetadataRevision) -> RpcTokenMetadataRevision {
+        RpcTokenMetadataRevision {
+            revision: revision.revision,
+            txid: revision.txid,
+            previous_metadata_hex: revision.previous_metadata.to_hex(),
+            previous_platform_tag: Self::sanitize_token_display_text(&revision.previous_platform_tag),
+            metadata_hex: revision.metadata.to_hex(),
+            platform_tag: Self::sanitize_token_display_text(&revision.platform_tag),
+            updated_block_hash: revision.updated_block_hash,
+            updated_daa_score: revision.updated_daa_score,
+            updated_at: revision.updated_at,
+        }
+    }
+
+    fn map_pair_pool_state(pool: &TokenAsset, reserve_a: u128, reserve_b: u128, locked_shares: u128) -> Option<RpcPairPoolState> {
+        let pair = pool.pair_pool?;
+        Some(RpcPairPoolState {
+            pool_id: pool.asset_id.as_slice().to_hex(),
+            asset_a_id: pair.asset_a_id.as_slice().to_hex(),
+            asset_b_id: pair.asset_b_id.as_slice().to_hex(),
+            fee_bps: u32::from(pair.fee_bps),
+            reserve_a: reserve_a.to_string(),
+            reserve_b: reserve_b.to_string(),
+            total_shares: pool.total_supply.to_string(),
+            locked_shares: locked_shares.to_string(),
+            accrued_fee_a: pair.accrued_fee_a.to_string(),
+            accrued_fee_b: pair.accrued_fee_b.to_string(),
+        })
+    }
+
     fn map_token_event(event: TokenEvent) -> RpcTokenEvent {
         RpcTokenEvent {
             event_id: event.event_id.as_slice().to_hex(),
@@ -1368,10 +1542,81 @@ impl RpcCoreService {
         }
     }
 
-    fn map_token_owner_balance(entry: ([u8; 32], u128, Option<TokenAsset>)) -> RpcTokenOwnerBalance {
+    fn map_message_entry(entry: MessageEntry) -> RpcMessageEntry {
+        let envelope = entry.envelope;
+        RpcMessageEntry {
+            sequence: entry.sequence,
+            transaction_id: entry.transaction_id,
+            accepting_block_hash: entry.accepting_block_hash,
+            accepting_daa_score: entry.accepting_daa_score,
+            accepting_block_time: entry.accepting_block_time,
+            msg_type: envelope.msg_type,
+            flags: envelope.flags,
+            recipient_tag: envelope.recipient_tag.as_slice().to_hex(),
+            nonce: envelope.nonce.as_slice().to_hex(),
+            sender: RpcMessageSender { kind: envelope.sender.kind, data: envelope.sender.significant_data().to_hex() },
+            body_hex: envelope.body.to_hex(),
+        }
+    }
+
+    /// Current HFA runtime configuration together with the operator pause flag.
+    fn rpc_hfa_config(&self) -> RpcHfaConfig {
+        let config = self.hfa_engine.config();
+        RpcHfaConfig {
+            enabled: config.enabled,
+            paused: self.hfa_engine.is_operator_paused(),
+            cpu_low_water_ratio: config.cpu_low_water_ratio,
+            fast_ttl_ms: config.fast_ttl_ms,
+            clock_drift_max_ms: config.clock_drift_max_ms,
+            terminal_retention_ms: config.terminal_retention_ms,
+            max_pending_intents: config.max_pending_intents as u64,
+            max_locks: config.max_locks as u64,
+            max_terminal_entries: config.max_terminal_entries as u64,
+            max_terminal_bytes: config.max_terminal_bytes as u64,
+            max_inputs_per_intent: config.max_inputs_per_intent as u64,
+            max_outputs_per_intent: config.max_outputs_per_intent as u64,
+            min_feerate_floor: config.min_feerate_floor,
+            seen_cache_ttl_ms: config.seen_cache_ttl_ms,
+            microblock_interval_ms_normal: config.microblock_interval_ms_normal,
+            microblock_interval_ms_degraded: config.microblock_interval_ms_degraded,
+            revalidation_budget_normal: config.revalidation_budget_normal as u64,
+            revalidation_budget_degraded: config.revalidation_budget_degraded as u64,
+            normal_arbiter_queue_capacity: config.normal_arbiter_queue_capacity as u64,
+            fast_arbiter_queue_capacity: config.fast_arbiter_queue_capacity as u64,
+        }
+    }
+
+    /// Publishes the fast intent status transitions queued by the HFA engine since the last call.
+    fn notify_fast_intent_status_changes(
+        hfa_engine: &HfaEngine,
+        notifier: &Notifier<Notification, ChannelConnection>,
+        prefix: cryptix_addresses::Prefix,
+    ) {
+        for change in hfa_engine.take_status_changes() {
+            let addresses = change
+                .script_public_keys
+                .iter()
+                .filter_map(|script_public_key| extract_script_pub_key_address(script_public_key, prefix).ok())
+                .collect();
+            if let Err(err) = notifier.notify(Notification::FastIntentStatusChanged(FastIntentStatusChangedNotification {
+                intent_id: change.intent_id,
+                status: change.status,
+                reason: change.reason,
+                base_tx_id: change.base_tx_id,
+                node_epoch: change.node_epoch,
+                changed_at_ms: change.changed_at_ms,
+                addresses,
+            })) {
+                warn!("failed broadcasting fast-intent-status-changed notification: {err}");
+            }
+        }
+    }
+
+    fn map_token_owner_balance(entry: ([u8; 32], u128, Option<TokenAsset>), locked_balance: u128) -> RpcTokenOwnerBalance {
         RpcTokenOwnerBalance {
             asset_id: entry.0.as_slice().to_hex(),
             balance: entry.1.to_string(),
+            locked_balance: locked_balance.to_string(),
             asset: entry.2.map(Self::map_token_asset),
         }
     }
@@ -1488,9 +1733,27 @@ impl RpcCoreService {
                 TokenOp::SellLiquidityExactIn(op) => {
                     ("sell_liquidity_exact_in".to_string(), Some(op.asset_id), Some(op.expected_pool_nonce))
                 }
+                TokenOp::BuyLiquidityExactOut(op) => {
+                    ("buy_liquidity_exact_out".to_string(), Some(op.asset_id), Some(op.expected_pool_nonce))
+                }
+                TokenOp::SellLiquidityExactOut(op) => {
+                    ("sell_liquidity_exact_out".to_string(), Some(op.asset_id), Some(op.expected_pool_nonce))
+                }
                 TokenOp::ClaimLiquidityFees(op) => {
                     ("claim_liquidity_fees".to_string(), Some(op.asset_id), Some(op.expected_pool_nonce))
                 }
+                TokenOp::Approve(op) => ("approve".to_string(), Some(op.asset_id), None),
+                TokenOp::TransferFrom(op) => ("transfer_from".to_string(), Some(op.asset_id), None),
+                TokenOp::BatchTransfer(op) => ("batch_transfer".to_string(), Some(op.asset_id), None),
+                TokenOp::LockTokens(op) => ("lock_tokens".to_string(), Some(op.asset_id), None),
+                TokenOp::TransferMintAuthority(op) => ("transfer_mint_authority".to_string(), Some(op.asset_id), None),
+                TokenOp::RenounceMintAuthority(op) => ("renounce_mint_authority".to_string(), Some(op.asset_id), None),
+                TokenOp::UpdateMetadata(op) => ("update_metadata".to_string(), Some(op.asset_id), None),
+                TokenOp::CreatePairPool(_) => ("create_pair_pool".to_string(), None, None),
+                TokenOp::AddPairLiquidity(op) => ("add_pair_liquidity".to_string(), Some(op.pool_id), None),
+                TokenOp::RemovePairLiquidity(op) => ("remove_pair_liquidity".to_string(), Some(op.pool_id), None),
+                TokenOp::SwapPairExactIn(op) => ("swap_pair_exact_in".to_string(), Some(op.pool_id), None),
+                TokenOp::SwapPairExactOut(op) => ("swap_pair_exact_out".to_string(), Some(op.pool_id), None),
             },
             Some(Err(reason)) => (format!("cat_parse_error:{reason:?}"), None, None),
             None => ("non_cat".to_string(), None, None),
@@ -1815,6 +2078,76 @@ impl RpcCoreService {
         None
     }
 
+    fn simulate_buy_liquidity_exact_out_noop_reason(
+        asset: &TokenAsset,
+        pool: &LiquidityPoolState,
+        op: &BuyLiquidityExactOutOp,
+    ) -> Option<NoopReason> {
+        if pool.pool_nonce != op.expected_pool_nonce {
+            return Some(NoopReason::NonceStale);
+        }
+        let (net_in, _, _, _) = match cpmm_buy_exact_out(
+            pool.real_token_reserves,
+            pool.virtual_cpay_reserves_sompi,
+            pool.virtual_token_reserves,
+            op.token_out,
+        ) {
+            Ok(state) => state,
+            Err(err) => return Some(Self::map_liquidity_math_noop_reason(err)),
+        };
+        let (cpay_in, _) = match gross_amount_for_net_amount(net_in, pool.fee_bps) {
+            Ok(value) => value,
+            Err(err) => return Some(Self::map_liquidity_math_noop_reason(err)),
+        };
+        if cpay_in > op.max_cpay_in_sompi {
+            return Some(NoopReason::MinOutViolation);
+        }
+        if asset.total_supply.checked_add(op.token_out).is_none() {
+            return Some(NoopReason::SupplyOverflow);
+        }
+        Self::validate_real_cpay_reserve(pool.real_cpay_reserves_sompi)
+    }
+
+    fn simulate_sell_liquidity_exact_out_noop_reason(
+        asset: &TokenAsset,
+        pool: &LiquidityPoolState,
+        sender_balance: u128,
+        op: &SellLiquidityExactOutOp,
+    ) -> Option<NoopReason> {
+        if pool.pool_nonce != op.expected_pool_nonce {
+            return Some(NoopReason::NonceStale);
+        }
+        if Self::liquidity_sell_locked(pool) {
+            return Some(NoopReason::LiquiditySellLocked);
+        }
+        if op.cpay_out_sompi < LIQUIDITY_MIN_PAYOUT_SOMPI {
+            return Some(NoopReason::InvalidAmount);
+        }
+        let (gross_out, _) = match gross_amount_for_net_amount(op.cpay_out_sompi, pool.fee_bps) {
+            Ok(value) => value,
+            Err(err) => return Some(Self::map_liquidity_math_noop_reason(err)),
+        };
+        let (token_in, _, _, _) = match cpmm_sell_exact_out(
+            pool.real_cpay_reserves_sompi,
+            pool.virtual_cpay_reserves_sompi,
+            pool.virtual_token_reserves,
+            gross_out,
+        ) {
+            Ok(state) => state,
+            Err(err) => return Some(Self::map_liquidity_math_noop_reason(err)),
+        };
+        if token_in > op.max_token_in {
+            return Some(NoopReason::MinOutViolation);
+        }
+        if sender_balance < token_in {
+            return Some(NoopReason::InsufficientBalance);
+        }
+        if asset.total_supply < token_in {
+            return Some(NoopReason::SupplyUnderflow);
+        }
+        None
+    }
+
     fn sanitize_token_display_text(bytes: &[u8]) -> String {
         let decoded = String::from_utf8_lossy(bytes);
         let mut out = String::with_capacity(decoded.len());
@@ -1840,21 +2173,12 @@ impl RpcCoreService {
     fn owner_id_from_script(script_public_key: &ScriptPublicKey) -> Option<[u8; 32]> {
         let script_bytes = script_public_key.script();
         let (auth_scheme, canonical_pubkey_bytes) = match ScriptClass::from_script(script_public_key) {
-            ScriptClass::PubKey if script_bytes.len() == 34 => (OWNER_AUTH_SCHEME_PUBKEY, &script_bytes[1..33]),
-            ScriptClass::PubKeyECDSA if script_bytes.len() == 35 => (OWNER_AUTH_SCHEME_PUBKEY_ECDSA, &script_bytes[1..34]),
-            ScriptClass::ScriptHash if script_bytes.len() == 35 => (OWNER_AUTH_SCHEME_SCRIPT_HASH, &script_bytes[2..34]),
+            ScriptClass::PubKey if script_bytes.len() == 34 => (CAT_OWNER_AUTH_SCHEME_PUBKEY, &script_bytes[1..33]),
+            ScriptClass::PubKeyECDSA if script_bytes.len() == 35 => (CAT_OWNER_AUTH_SCHEME_PUBKEY_ECDSA, &script_bytes[1..34]),
+            ScriptClass::ScriptHash if script_bytes.len() == 35 => (CAT_OWNER_AUTH_SCHEME_SCRIPT_HASH, &script_bytes[2..34]),
             _ => return None,
         };
-        let pubkey_len = u16::try_from(canonical_pubkey_bytes.len()).ok()?;
-        let mut hasher = Blake2bParams::new().hash_length(32).to_state();
-        hasher.update(CAT_OWNER_DOMAIN);
-        hasher.update(&[auth_scheme]);
-        hasher.update(&pubkey_len.to_le_bytes());
-        hasher.update(canonical_pubkey_bytes);
-        let digest = hasher.finalize();
-        let mut owner_id = [0u8; 32];
-        owner_id.copy_from_slice(digest.as_bytes());
-        Some(owner_id)
+        cat_owner_id(auth_scheme, canonical_pubkey_bytes)
     }
 
     fn map_sc_bootstrap_source(source: ScBootstrapSource) -> RpcScBootstrapSource {
@@ -1915,11 +2239,16 @@ impl RpcCoreService {
         view.nonces.get(&key).copied().unwrap_or(1)
     }
 
+    fn simulated_locked_balance(view: &AtomicTokenReadView, key: &cryptix_atomicindex::state::BalanceKey, daa_score: u64) -> u128 {
+        view.locks.get(key).map(|lock| lock.locked_at(daa_score)).unwrap_or(0)
+    }
+
     fn simulate_token_noop_reason(
         &self,
         view: &AtomicTokenReadView,
         owner_id: [u8; 32],
         parsed: &cryptix_atomicindex::payload::ParsedTokenPayload,
+        simulation_daa_score: u64,
     ) -> Option<NoopReason> {
         let expected_next_nonce = Self::expected_nonce_for_op(view, owner_id, &parsed.op);
         if parsed.header.nonce != expected_next_nonce {
@@ -1944,6 +2273,14 @@ impl RpcCoreService {
                 if sender_balance < op.amount {
                     return Some(NoopReason::InsufficientBalance);
                 }
+                let sender_locked = Self::simulated_locked_balance(
+                    view,
+                    &cryptix_atomicindex::state::BalanceKey { asset_id: op.asset_id, owner_id },
+                    simulation_daa_score,
+                );
+                if sender_balance - op.amount < sender_locked {
+                    return Some(NoopReason::BalanceLocked);
+                }
                 let receiver_balance = view
                     .balances
                     .get(&cryptix_atomicindex::state::BalanceKey { asset_id: op.asset_id, owner_id: op.to_owner_id })
@@ -1981,6 +2318,9 @@ impl RpcCoreService {
                 let Some(asset) = view.assets.get(&op.asset_id) else {
                     return Some(NoopReason::AssetNotFound);
                 };
+                if matches!(asset.asset_class, TokenAssetClass::Liquidity | TokenAssetClass::Pair) {
+                    return Some(NoopReason::LegacyOpForLiquidityAsset);
+                }
                 let sender_balance = view
                     .balances
                     .get(&cryptix_atomicindex::state::BalanceKey { asset_id: op.asset_id, owner_id })
@@ -1989,6 +2329,14 @@ impl RpcCoreService {
                 if sender_balance < op.amount {
                     return Some(NoopReason::InsufficientBalance);
                 }
+                let sender_locked = Self::simulated_locked_balance(
+                    view,
+                    &cryptix_atomicindex::state::BalanceKey { asset_id: op.asset_id, owner_id },
+                    simulation_daa_score,
+                );
+                if sender_balance - op.amount < sender_locked {
+                    return Some(NoopReason::BalanceLocked);
+                }
                 if asset.total_supply < op.amount {
                     return Some(NoopReason::SupplyUnderflow);
                 }
@@ -2025,6 +2373,35 @@ impl RpcCoreService {
                     .unwrap_or(0);
                 Self::simulate_sell_liquidity_noop_reason(asset, pool, sender_balance, op)
             }
+            TokenOp::BuyLiquidityExactOut(op) => {
+                let Some(asset) = view.assets.get(&op.asset_id) else {
+                    return Some(NoopReason::AssetNotFound);
+                };
+                if !matches!(asset.asset_class, TokenAssetClass::Liquidity) {
+                    return Some(NoopReason::LegacyOpForLiquidityAsset);
+                }
+                let Some(pool) = asset.liquidity.as_ref() else {
+                    return Some(NoopReason::AssetNotFound);
+                };
+                Self::simulate_buy_liquidity_exact_out_noop_reason(asset, pool, op)
+            }
+            TokenOp::SellLiquidityExactOut(op) => {
+                let Some(asset) = view.assets.get(&op.asset_id) else {
+                    return Some(NoopReason::AssetNotFound);
+                };
+                if !matches!(asset.asset_class, TokenAssetClass::Liquidity) {
+                    return Some(NoopReason::LegacyOpForLiquidityAsset);
+                }
+                let Some(pool) = asset.liquidity.as_ref() else {
+                    return Some(NoopReason::AssetNotFound);
+                };
+                let sender_balance = view
+                    .balances
+                    .get(&cryptix_atomicindex::state::BalanceKey { asset_id: op.asset_id, owner_id })
+                    .copied()
+                    .unwrap_or(0);
+                Self::simulate_sell_liquidity_exact_out_noop_reason(asset, pool, sender_balance, op)
+            }
             TokenOp::ClaimLiquidityFees(op) => {
                 let Some(asset) = view.assets.get(&op.asset_id) else {
                     return Some(NoopReason::AssetNotFound);
@@ -2053,6 +2430,315 @@ impl RpcCoreService {
                 }
                 None
             }
+            TokenOp::Approve(op) => {
+                let Some(asset) = view.assets.get(&op.asset_id) else {
+                    return Some(NoopReason::AssetNotFound);
+                };
+                if matches!(asset.asset_class, TokenAssetClass::Liquidity) {
+                    return Some(NoopReason::LegacyOpForLiquidityAsset);
+                }
+                if op.spender_owner_id == owner_id {
+                    return Some(NoopReason::BadSpender);
+                }
+                if op.amount > 0 && op.expires_at_daa_score != 0 && simulation_daa_score >= op.expires_at_daa_score {
+                    return Some(NoopReason::AllowanceExpired);
+                }
+                None
+            }
+            TokenOp::TransferFrom(op) => {
+                let Some(asset) = view.assets.get(&op.asset_id) else {
+                    return Some(NoopReason::AssetNotFound);
+                };
+                if matches!(asset.asset_class, TokenAssetClass::Liquidity) {
+                    return Some(NoopReason::LegacyOpForLiquidityAsset);
+                }
+                if op.from_owner_id == owner_id {
+                    return Some(NoopReason::BadSpender);
+                }
+                let Some(allowance) = view.allowances.get(&cryptix_atomicindex::state::AllowanceKey {
+                    asset_id: op.asset_id,
+                    owner_id: op.from_owner_id,
+                    spender_id: owner_id,
+                }) else {
+                    return Some(NoopReason::AllowanceNotFound);
+                };
+                if allowance.is_expired_at(simulation_daa_score) {
+                    return Some(NoopReason::AllowanceExpired);
+                }
+                if allowance.amount < op.amount {
+                    return Some(NoopReason::AllowanceExceeded);
+                }
+                let sender_balance = view
+                    .balances
+                    .get(&cryptix_atomicindex::state::BalanceKey { asset_id: op.asset_id, owner_id: op.from_owner_id })
+                    .copied()
+                    .unwrap_or(0);
+                if sender_balance < op.amount {
+                    return Some(NoopReason::InsufficientBalance);
+                }
+                let sender_locked = Self::simulated_locked_balance(
+                    view,
+                    &cryptix_atomicindex::state::BalanceKey { asset_id: op.asset_id, owner_id: op.from_owner_id },
+                    simulation_daa_score,
+                );
+                if sender_balance - op.amount < sender_locked {
+                    return Some(NoopReason::BalanceLocked);
+                }
+                let receiver_balance = view
+                    .balances
+                    .get(&cryptix_atomicindex::state::BalanceKey { asset_id: op.asset_id, owner_id: op.to_owner_id })
+                    .copied()
+                    .unwrap_or(0);
+                if op.from_owner_id != op.to_owner_id && receiver_balance.checked_add(op.amount).is_none() {
+                    return Some(NoopReason::BalanceOverflow);
+                }
+                None
+            }
+            TokenOp::BatchTransfer(op) => {
+                let Some(asset) = view.assets.get(&op.asset_id) else {
+                    return Some(NoopReason::AssetNotFound);
+                };
+                if matches!(asset.asset_class, TokenAssetClass::Liquidity) {
+                    return Some(NoopReason::LegacyOpForLiquidityAsset);
+                }
+                let Some(total) = op.recipients.iter().try_fold(0u128, |total, recipient| total.checked_add(recipient.amount)) else {
+                    return Some(NoopReason::BalanceOverflow);
+                };
+                let sender_balance = view
+                    .balances
+                    .get(&cryptix_atomicindex::state::BalanceKey { asset_id: op.asset_id, owner_id })
+                    .copied()
+                    .unwrap_or(0);
+                if sender_balance < total {
+                    return Some(NoopReason::InsufficientBalance);
+                }
+                let sender_locked = Self::simulated_locked_balance(
+                    view,
+                    &cryptix_atomicindex::state::BalanceKey { asset_id: op.asset_id, owner_id },
+                    simulation_daa_score,
+                );
+                if sender_balance - total < sender_locked {
+                    return Some(NoopReason::BalanceLocked);
+                }
+                for recipient in op.recipients.iter().filter(|recipient| recipient.to_owner_id != owner_id) {
+                    let receiver_balance = view
+                        .balances
+                        .get(&cryptix_atomicindex::state::BalanceKey { asset_id: op.asset_id, owner_id: recipient.to_owner_id })
+                        .copied()
+                        .unwrap_or(0);
+                    if receiver_balance.checked_add(recipient.amount).is_none() {
+                        return Some(NoopReason::BalanceOverflow);
+                    }
+                }
+                None
+            }
+            TokenOp::LockTokens(op) => {
+                let Some(asset) = view.assets.get(&op.asset_id) else {
+                    return Some(NoopReason::AssetNotFound);
+                };
+                if matches!(asset.asset_class, TokenAssetClass::Liquidity) {
+                    return Some(NoopReason::LegacyOpForLiquidityAsset);
+                }
+                if op.release_end_daa_score <= simulation_daa_score {
+                    return Some(NoopReason::BadLockSchedule);
+                }
+                if view.assets.get(&op.beneficiary_owner_id).is_some_and(|pool| pool.pair_pool.is_some()) {
+                    return Some(NoopReason::LockConflict);
+                }
+                let sender_key = cryptix_atomicindex::state::BalanceKey { asset_id: op.asset_id, owner_id };
+                let sender_balance = view.balances.get(&sender_key).copied().unwrap_or(0);
+                if sender_balance < op.amount {
+                    return Some(NoopReason::InsufficientBalance);
+                }
+                if sender_balance - op.amount < Self::simulated_locked_balance(view, &sender_key, simulation_daa_score) {
+                    return Some(NoopReason::BalanceLocked);
+                }
+                let beneficiary_key =
+                    cryptix_atomicindex::state::BalanceKey { asset_id: op.asset_id, owner_id: op.beneficiary_owner_id };
+                if let Some(existing) = view.locks.get(&beneficiary_key).filter(|lock| lock.locked_at(simulation_daa_score) > 0) {
+                    if existing.release_start_daa_score != op.release_start_daa_score
+                        || existing.release_end_daa_score != op.release_end_daa_score
+                    {
+                        return Some(NoopReason::LockConflict);
+                    }
+                    if existing.amount.checked_add(op.amount).is_none() {
+                        return Some(NoopReason::BalanceOverflow);
+                    }
+                }
+                let beneficiary_balance = view.balances.get(&beneficiary_key).copied().unwrap_or(0);
+                if op.beneficiary_owner_id != owner_id && beneficiary_balance.checked_add(op.amount).is_none() {
+                    return Some(NoopReason::BalanceOverflow);
+                }
+                None
+            }
+            TokenOp::TransferMintAuthority(TransferMintAuthorityOp { asset_id, .. })
+            | TokenOp::RenounceMintAuthority(RenounceMintAuthorityOp { asset_id }) => {
+                let Some(asset) = view.assets.get(asset_id) else {
+                    return Some(NoopReason::AssetNotFound);
+                };
+                if matches!(asset.asset_class, TokenAssetClass::Liquidity) {
+                    return Some(NoopReason::LegacyOpForLiquidityAsset);
+                }
+                if asset.mint_authority_owner_id != owner_id {
+                    return Some(NoopReason::UnauthorizedMint);
+                }
+                None
+            }
+            TokenOp::UpdateMetadata(UpdateMetadataOp { asset_id, .. }) => {
+                let Some(asset) = view.assets.get(asset_id) else {
+                    return Some(NoopReason::AssetNotFound);
+                };
+                if asset.creator_owner_id == [0u8; 32] || asset.creator_owner_id != owner_id {
+                    return Some(NoopReason::UnauthorizedMetadataUpdate);
+                }
+                None
+            }
+            TokenOp::CreatePairPool(op) => {
+                for asset_id in [op.asset_a_id, op.asset_b_id] {
+                    let Some(asset) = view.assets.get(&asset_id) else {
+                        return Some(NoopReason::AssetNotFound);
+                    };
+                    if !matches!(asset.asset_class, TokenAssetClass::Standard) {
+                        return Some(NoopReason::BadPairAssets);
+                    }
+                }
+                if let Err(err) = pair_initial_shares(op.amount_a, op.amount_b) {
+                    return Some(Self::pair_math_noop_reason(err));
+                }
+                Self::simulated_debit_noop_reason(view, op.asset_a_id, owner_id, op.amount_a, simulation_daa_score)
+                    .or_else(|| Self::simulated_debit_noop_reason(view, op.asset_b_id, owner_id, op.amount_b, simulation_daa_score))
+            }
+            TokenOp::AddPairLiquidity(op) => {
+                let (asset, pair) = match Self::simulated_pair_pool(view, &op.pool_id) {
+                    Ok(pool) => pool,
+                    Err(reason) => return Some(reason),
+                };
+                let (reserve_a, reserve_b) = Self::simulated_pair_reserves(view, op.pool_id, pair);
+                let (shares, amount_a_in, amount_b_in) =
+                    match pair_add_liquidity(reserve_a, reserve_b, asset.total_supply, op.amount_a_max, op.amount_b_max) {
+                        Ok(quote) => quote,
+                        Err(err) => return Some(Self::pair_math_noop_reason(err)),
+                    };
+                if shares < op.min_shares_out {
+                    return Some(NoopReason::MinOutViolation);
+                }
+                Self::simulated_debit_noop_reason(view, pair.asset_a_id, owner_id, amount_a_in, simulation_daa_score)
+                    .or_else(|| Self::simulated_debit_noop_reason(view, pair.asset_b_id, owner_id, amount_b_in, simulation_daa_score))
+            }
+            TokenOp::RemovePairLiquidity(op) => {
+                let (asset, pair) = match Self::simulated_pair_pool(view, &op.pool_id) {
+                    Ok(pool) => pool,
+                    Err(reason) => return Some(reason),
+                };
+                let (reserve_a, reserve_b) = Self::simulated_pair_reserves(view, op.pool_id, pair);
+                let (amount_a_out, amount_b_out) = match pair_remove_liquidity(reserve_a, reserve_b, asset.total_supply, op.shares) {
+                    Ok(quote) => quote,
+                    Err(err) => return Some(Self::pair_math_noop_reason(err)),
+                };
+                if amount_a_out < op.min_amount_a_out || amount_b_out < op.min_amount_b_out {
+                    return Some(NoopReason::MinOutViolation);
+                }
+                Self::simulated_debit_noop_reason(view, op.pool_id, owner_id, op.shares, simulation_daa_score)
+            }
+            TokenOp::SwapPairExactIn(op) => {
+                let (_, pair) = match Self::simulated_pair_pool(view, &op.pool_id) {
+                    Ok(pool) => pool,
+                    Err(reason) => return Some(reason),
+                };
+                let Some((reserve_in, reserve_out)) = Self::simulated_pair_swap_reserves(view, op.pool_id, pair, op.asset_in_id, true)
+                else {
+                    return Some(NoopReason::BadPairAssets);
+                };
+                let amount_out = match pair_swap_exact_in(reserve_in, reserve_out, op.amount_in, pair.fee_bps) {
+                    Ok((amount_out, _)) => amount_out,
+                    Err(err) => return Some(Self::pair_math_noop_reason(err)),
+                };
+                if amount_out < op.min_amount_out {
+                    return Some(NoopReason::MinOutViolation);
+                }
+                Self::simulated_debit_noop_reason(view, op.asset_in_id, owner_id, op.amount_in, simulation_daa_score)
+            }
+            TokenOp::SwapPairExactOut(op) => {
+                let (_, pair) = match Self::simulated_pair_pool(view, &op.pool_id) {
+                    Ok(pool) => pool,
+                    Err(reason) => return Some(reason),
+                };
+                let Some((reserve_in, reserve_out)) =
+                    Self::simulated_pair_swap_reserves(view, op.pool_id, pair, op.asset_out_id, false)
+                else {
+                    return Some(NoopReason::BadPairAssets);
+                };
+                let amount_in = match pair_swap_exact_out(reserve_in, reserve_out, op.amount_out, pair.fee_bps) {
+                    Ok((amount_in, _)) => amount_in,
+                    Err(err) => return Some(Self::pair_math_noop_reason(err)),
+                };
+                if amount_in > op.max_amount_in {
+                    return Some(NoopReason::MinOutViolation);
+                }
+                let asset_in_id = if op.asset_out_id == pair.asset_a_id { pair.asset_b_id } else { pair.asset_a_id };
+                Self::simulated_debit_noop_reason(view, asset_in_id, owner_id, amount_in, simulation_daa_score)
+            }
+        }
+    }
+
+    fn simulated_pair_pool<'a>(
+        view: &'a AtomicTokenReadView,
+        pool_id: &[u8; 32],
+    ) -> Result<(&'a TokenAsset, PairPoolState), NoopReason> {
+        let asset = view.assets.get(pool_id).ok_or(NoopReason::AssetNotFound)?;
+        match (asset.asset_class, asset.pair_pool) {
+            (TokenAssetClass::Pair, Some(pair)) => Ok((asset, pair)),
+            _ => Err(NoopReason::NotPairPool),
+        }
+    }
+
+    fn simulated_pair_reserves(view: &AtomicTokenReadView, pool_id: [u8; 32], pair: PairPoolState) -> (u128, u128) {
+        let reserve = |key| view.balances.get(&key).copied().unwrap_or(0);
+        (reserve(pair.reserve_key_a(pool_id)), reserve(pair.reserve_key_b(pool_id)))
+    }
+
+    /// Returns `(reserve_in, reserve_out)` for a swap naming `asset_id` as its input (`is_input`) or output side.
+    fn simulated_pair_swap_reserves(
+        view: &AtomicTokenReadView,
+        pool_id: [u8; 32],
+        pair: PairPoolState,
+        asset_id: [u8; 32],
+        is_input: bool,
+    ) -> Option<(u128, u128)> {
+        let (reserve_a, reserve_b) = Self::simulated_pair_reserves(view, pool_id, pair);
+        let named_is_a = if asset_id == pair.asset_a_id {
+            true
+        } else if asset_id == pair.asset_b_id {
+            false
+        } else {
+            return None;
+        };
+        Some(if named_is_a == is_input { (reserve_a, reserve_b) } else { (reserve_b, reserve_a) })
+    }
+
+    fn simulated_debit_noop_reason(
+        view: &AtomicTokenReadView,
+        asset_id: [u8; 32],
+        owner_id: [u8; 32],
+        amount: u128,
+        simulation_daa_score: u64,
+    ) -> Option<NoopReason> {
+        let key = cryptix_atomicindex::state::BalanceKey { asset_id, owner_id };
+        let balance = view.balances.get(&key).copied().unwrap_or(0);
+        if balance < amount {
+            return Some(NoopReason::InsufficientBalance);
+        }
+        if balance - amount < Self::simulated_locked_balance(view, &key, simulation_daa_score) {
+            return Some(NoopReason::BalanceLocked);
+        }
+        None
+    }
+
+    fn pair_math_noop_reason(err: LiquidityMathError) -> NoopReason {
+        match err {
+            LiquidityMathError::InvalidInput | LiquidityMathError::InvalidState => NoopReason::PairLiquidityTooLow,
+            LiquidityMathError::ZeroOutput => NoopReason::ZeroOutput,
+            LiquidityMathError::Overflow => NoopReason::SupplyOverflow,
         }
     }
 
@@ -2279,6 +2965,7 @@ NOTE: This error usually indicates an RPC conversion error between the node and
                 FastIngressSource::Rpc,
             )
             .await;
+        Self::notify_fast_intent_status_changes(&self.hfa_engine, &self.notifier, self.config.prefix());
 
         let base_tx_for_normal: Transaction = match request_for_p2p.base_tx.clone().try_into() {
             Ok(tx) => tx,
@@ -2304,6 +2991,7 @@ NOTE: This error usually indicates an RPC conversion error between the node and
                         cancel_token,
                         node_epoch: response.node_epoch,
                     });
+                    Self::notify_fast_intent_status_changes(&self.hfa_engine, &self.notifier, self.config.prefix());
                 }
                 return Err(RpcError::RejectedTransaction(tx_id, err.to_string()));
             }
@@ -2354,6 +3042,7 @@ NOTE: This error usually indicates an RPC conversion error between the node and
         let cpu_ratio = (self.perf_monitor.snapshot().cpu_usage as f64 / 100.0).clamp(0.0, 1.0);
         self.hfa_engine.revalidate_active_budgeted(session, is_synced, cpu_ratio, basechain_block_latency_ms).await;
         let response = self.hfa_engine.get_fast_intent_status(request);
+        Self::notify_fast_intent_status_changes(&self.hfa_engine, &self.notifier, self.config.prefix());
         self.flow_context.broadcast_outbound_fast_microblocks().await;
         Ok(response)
     }
@@ -2370,10 +3059,124 @@ NOTE: This error usually indicates an RPC conversion error between the node and
         let cpu_ratio = (self.perf_monitor.snapshot().cpu_usage as f64 / 100.0).clamp(0.0, 1.0);
         self.hfa_engine.revalidate_active_budgeted(session, is_synced, cpu_ratio, basechain_block_latency_ms).await;
         let response = self.hfa_engine.cancel_fast_intent(request);
+        Self::notify_fast_intent_status_changes(&self.hfa_engine, &self.notifier, self.config.prefix());
         self.flow_context.broadcast_outbound_fast_microblocks().await;
         Ok(response)
     }
 
+    async fn get_hfa_config_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        _request: GetHfaConfigRequest,
+    ) -> RpcResult<GetHfaConfigResponse> {
+        Ok(GetHfaConfigResponse { config: self.rpc_hfa_config() })
+    }
+
+    async fn set_hfa_config_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        request: SetHfaConfigRequest,
+    ) -> RpcResult<SetHfaConfigResponse> {
+        if !self.config.unsafe_rpc {
+            warn!("SetHfaConfig RPC command called while node in safe RPC mode -- rejecting.");
+            return Err(RpcError::UnavailableInSafeMode);
+        }
+        let mut config = self.hfa_engine.config();
+        if let Some(value) = request.cpu_low_water_ratio {
+            config.cpu_low_water_ratio = value;
+        }
+        if let Some(value) = request.fast_ttl_ms {
+            config.fast_ttl_ms = value;
+        }
+        if let Some(value) = request.clock_drift_max_ms {
+            config.clock_drift_max_ms = value;
+        }
+        if let Some(value) = request.terminal_retention_ms {
+            config.terminal_retention_ms = value;
+        }
+        if let Some(value) = request.max_pending_intents {
+            config.max_pending_intents = value as usize;
+        }
+        if let Some(value) = request.max_locks {
+            config.max_locks = value as usize;
+        }
+        if let Some(value) = request.max_terminal_entries {
+            config.max_terminal_entries = value as usize;
+        }
+        if let Some(value) = request.max_terminal_bytes {
+            config.max_terminal_bytes = value as usize;
+        }
+        if let Some(value) = request.max_inputs_per_intent {
+            config.max_inputs_per_intent = value as usize;
+        }
+        if let Some(value) = request.max_outputs_per_intent {
+            config.max_outputs_per_intent = value as usize;
+        }
+        if let Some(value) = request.min_feerate_floor {
+            config.min_feerate_floor = value;
+        }
+        if let Some(value) = request.seen_cache_ttl_ms {
+            config.seen_cache_ttl_ms = value;
+        }
+        if let Some(value) = request.microblock_interval_ms_normal {
+            config.microblock_interval_ms_normal = value;
+        }
+        if let Some(value) = request.microblock_interval_ms_degraded {
+            config.microblock_interval_ms_degraded = value;
+        }
+        if let Some(value) = request.revalidation_budget_normal {
+            config.revalidation_budget_normal = value as usize;
+        }
+        if let Some(value) = request.revalidation_budget_degraded {
+            config.revalidation_budget_degraded = value as usize;
+        }
+        if let Some(value) = request.normal_arbiter_queue_capacity {
+            config.normal_arbiter_queue_capacity = value as usize;
+        }
+        if let Some(value) = request.fast_arbiter_queue_capacity {
+            config.fast_arbiter_queue_capacity = value as usize;
+        }
+        self.hfa_engine.set_config(config).map_err(RpcError::General)?;
+        if let Some(paused) = request.paused {
+            self.hfa_engine.set_operator_paused(paused);
+        }
+        Ok(SetHfaConfigResponse { config: self.rpc_hfa_config() })
+    }
+
+    async fn get_messages_by_recipient_tag_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        request: GetMessagesByRecipientTagRequest,
+    ) -> RpcResult<GetMessagesByRecipientTagResponse> {
+        let Some(ref message_index_service) = self.message_index_service else {
+            return Err(RpcError::NoMessageIndex);
+        };
+        let GetMessagesByRecipientTagRequest { recipient_tag, sender, cursor, limit } = request;
+        let recipient_tag =
+            RecipientTag::from_hex(&recipient_tag).map_err(|err| RpcError::General(format!("invalid `recipientTag` hex: {err}")))?;
+        let sender = sender
+            .map(|sender| {
+                let data =
+                    Vec::<u8>::from_hex(&sender.data).map_err(|err| RpcError::General(format!("invalid `sender.data` hex: {err}")))?;
+                MessageSender::new(sender.kind, &data)
+                    .ok_or_else(|| RpcError::General(format!("invalid sender: kind {} with {} data bytes", sender.kind, data.len())))
+            })
+            .transpose()?;
+        let limit = match usize::try_from(limit).map_err(|e| RpcError::General(e.to_string()))? {
+            0 => MESSAGES_LIMIT_DEFAULT,
+            limit => limit.min(MESSAGES_LIMIT_MAX),
+        };
+
+        let mut entries = message_index_service
+            .get_messages_by_recipient_tag(&recipient_tag, sender.as_ref(), cursor, limit.saturating_add(1))
+            .map_err(|err| RpcError::General(err.to_string()))?;
+        let has_more = entries.len() > limit;
+        entries.truncate(limit);
+        let next_cursor = has_more.then(|| entries.last().map_or(cursor, |entry| entry.sequence));
+        let entries = entries.into_iter().map(Self::map_message_entry).collect();
+        Ok(GetMessagesByRecipientTagResponse { entries, next_cursor })
+    }
+
     async fn get_blocks_call(
         &self,
         _connection: Option<&DynRpcConnection>,
@@ -3470,6 +4273,26 @@ NOTE: This error usually indicates an RPC conversion error between the node and
         let atomic_state_store_bytes =
             if diagnostics_metrics { self.atomic_token_service.approximate_state_store_size_bytes() } else { None };
 
+        let hfa_metrics = req.hfa_metrics.then(|| {
+            let hfa = self.hfa_engine.metrics_snapshot();
+            HfaMetrics {
+                enabled: hfa.enabled,
+                operator_paused: hfa.operator_paused,
+                mode: hfa.rpc_mode(),
+                submitted_count: hfa.submit_total,
+                fast_confirmed_count: hfa.fast_confirmed_total,
+                rejected_count: hfa.rejected_total,
+                expired_count: hfa.expired_total,
+                dropped_count: hfa.dropped_total,
+                pending_intents: hfa.pending_intents as u64,
+                active_locks: hfa.active_locks as u64,
+                lock_conflict_count: hfa.fast_lock_conflict_total,
+                confirm_latency_ms_p95: hfa.fast_confirm_latency_ms_p95,
+                mode_transition_count: hfa.mode_transition_total,
+                fast_arbiter_queue_depth: hfa.fast_arbiter_queue_len as u64,
+            }
+        });
+
         let custom_metrics: Option<HashMap<String, CustomMetricValue>> = req.custom_metrics.then(|| {
             let hfa = self.hfa_engine.metrics_snapshot();
             let minimum_relay_feerate = minimum_relay_feerate.unwrap_or(0.0);
@@ -3572,6 +4395,24 @@ NOTE: This error usually indicates an RPC conversion error between the node and
             out.insert("hfa_enabled".to_string(), CustomMetricValue::Bool(hfa.enabled));
             out.insert("hfa_node_epoch".to_string(), CustomMetricValue::U64(hfa.node_epoch));
             out.insert("hfa_mode".to_string(), CustomMetricValue::Text(hfa.mode.to_string()));
+            out.insert("hfa_operator_paused".to_string(), CustomMetricValue::Bool(hfa.operator_paused));
+            out.insert("hfa_fast_ttl_ms".to_string(), CustomMetricValue::U64(hfa.config.fast_ttl_ms));
+            out.insert("hfa_clock_drift_max_ms".to_string(), CustomMetricValue::U64(hfa.config.clock_drift_max_ms));
+            out.insert("hfa_terminal_retention_ms".to_string(), CustomMetricValue::U64(hfa.config.terminal_retention_ms));
+            out.insert("hfa_max_pending_intents".to_string(), CustomMetricValue::U64(hfa.config.max_pending_intents as u64));
+            out.insert("hfa_max_locks".to_string(), CustomMetricValue::U64(hfa.config.max_locks as u64));
+            out.insert("hfa_max_terminal_entries".to_string(), CustomMetricValue::U64(hfa.config.max_terminal_entries as u64));
+            out.insert("hfa_max_terminal_bytes".to_string(), CustomMetricValue::U64(hfa.config.max_terminal_bytes as u64));
+            out.insert("hfa_max_inputs_per_intent".to_string(), CustomMetricValue::U64(hfa.config.max_inputs_per_intent as u64));
+            out.insert("hfa_max_outputs_per_intent".to_string(), CustomMetricValue::U64(hfa.config.max_outputs_per_intent as u64));
+            out.insert(
+                "hfa_fast_arbiter_queue_capacity".to_string(),
+                CustomMetricValue::U64(hfa.config.fast_arbiter_queue_capacity as u64),
+            );
+            out.insert(
+                "hfa_normal_arbiter_queue_capacity".to_string(),
+                CustomMetricValue::U64(hfa.config.normal_arbiter_queue_capacity as u64),
+            );
             let fast_recent_route_ids =
                 self.hfa_engine.recent_fast_tx_route_ids(128).into_iter().map(|tx_id| tx_id.to_string()).collect::<Vec<_>>();
             out.insert("fast_recent_tx_ids".to_string(), CustomMetricValue::Text(fast_recent_route_ids.join(",")));
@@ -3591,6 +4432,9 @@ NOTE: This error usually indicates an RPC conversion error between the node and
             out.insert("fast_reject_total".to_string(), CustomMetricValue::U64(hfa.rejected_total));
             out.insert("fast_overload_reject_total".to_string(), CustomMetricValue::U64(hfa.overload_reject_total));
             out.insert("fast_normal_conflict_reject_total".to_string(), CustomMetricValue::U64(hfa.normal_conflict_reject_total));
+            out.insert("fast_lock_conflict_total".to_string(), CustomMetricValue::U64(hfa.fast_lock_conflict_total));
+            out.insert("fast_confirmed_total".to_string(), CustomMetricValue::U64(hfa.fast_confirmed_total));
+            out.insert("fast_confirm_latency_ms_p95".to_string(), CustomMetricValue::F64(hfa.fast_confirm_latency_ms_p95));
             out.insert("fast_drop_total".to_string(), CustomMetricValue::U64(hfa.dropped_total));
             out.insert("fast_expired_total".to_string(), CustomMetricValue::U64(hfa.expired_total));
             out.insert("fast_terminal_entries".to_string(), CustomMetricValue::U64(hfa.terminal_entries as u64));
@@ -3651,6 +4495,7 @@ NOTE: This error usually indicates an RPC conversion error between the node and
             consensus_metrics,
             storage_metrics,
             custom_metrics,
+            hfa_metrics,
         };
 
         Ok(response)
@@ -3755,7 +4600,10 @@ NOTE: This error usually indicates an RPC conversion error between the node and
                 let view = atomic.get_simulation_view(owner_id, &parsed.op, at_block_hash).await.ok_or(RpcError::StaleContext)?;
                 Self::ensure_token_simulation_ready(&view)?;
                 let expected_next_nonce = Self::expected_nonce_for_op(&view, owner_id, &parsed.op);
-                let noop_reason = self.simulate_token_noop_reason(&view, owner_id, &parsed).map(|reason| reason as u32);
+                // Allowance expiry is judged against the accepting block, approximated here by the virtual DAA score.
+                let simulation_daa_score = self.consensus_manager.consensus().unguarded_session().get_virtual_daa_score();
+                let noop_reason =
+                    self.simulate_token_noop_reason(&view, owner_id, &parsed, simulation_daa_score).map(|reason| reason as u32);
                 if noop_reason.is_some() {
                     ("noop".to_string(), noop_reason, expected_next_nonce, view.context())
                 } else {
@@ -3777,12 +4625,13 @@ NOTE: This error usually indicates an RPC conversion error between the node and
         let atomic = self.atomic_service()?;
         let asset_id = Self::parse_hex_32(&asset_id, "assetId")?;
         let owner_id = Self::parse_hex_32(&owner_id, "ownerId")?;
-        let (read_context, balance) =
-            atomic.get_balance_with_context(asset_id, owner_id, at_block_hash).await.ok_or(RpcError::StaleContext)?;
+        let (read_context, balance, lock) =
+            atomic.get_balance_and_lock_with_context(asset_id, owner_id, at_block_hash).await.ok_or(RpcError::StaleContext)?;
         Self::ensure_token_context_read_ready(&read_context)?;
-        let balance = balance.to_string();
         let context = self.atomic_context_from_read_context(&read_context).await?;
-        Ok(GetTokenBalanceResponse { balance, context })
+        let locked_balance = lock.map(|lock| lock.locked_at(context.at_daa_score)).unwrap_or(0).min(balance).to_string();
+        let balance = balance.to_string();
+        Ok(GetTokenBalanceResponse { balance, locked_balance, context })
     }
 
     async fn get_token_nonce_call(
@@ -3835,6 +4684,179 @@ NOTE: This error usually indicates an RPC conversion error between the node and
         Ok(GetTokenAssetResponse { asset, context })
     }
 
+    async fn get_token_balance_proof_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        request: GetTokenBalanceProofRequest,
+    ) -> RpcResult<GetTokenBalanceProofResponse> {
+        let GetTokenBalanceProofRequest { asset_id, owner_id, at_block_hash, include_consensus_anchor } = request;
+        let atomic = self.atomic_service()?;
+        let asset_id = Self::parse_hex_32(&asset_id, "assetId")?;
+        let owner_id = Self::parse_hex_32(&owner_id, "ownerId")?;
+        let (read_context, balance_proof, nonce_proof) =
+            atomic.get_balance_proof_with_context(asset_id, owner_id, at_block_hash).await.ok_or(RpcError::StaleContext)?;
+        Self::ensure_token_context_read_ready(&read_context)?;
+        let proof_error = |err: AtomicProofError| RpcError::General(format!("invalid Atomic state proof: {err}"));
+        let balance = balance_proof.balance().map_err(proof_error)?.to_string();
+        let expected_next_nonce = nonce_proof.next_nonce().map_err(proof_error)?;
+        let consensus_anchor = if include_consensus_anchor {
+            let anchor = self.consensus_balance_anchor(read_context.at_block_hash).await?;
+            if anchor.balance(&asset_id, &owner_id).to_string() != balance {
+                return Err(RpcError::General(format!(
+                    "Atomic indexer balance disagrees with consensus at block `{}`",
+                    read_context.at_block_hash
+                )));
+            }
+            Some(Self::map_token_consensus_balance_anchor(&anchor))
+        } else {
+            None
+        };
+        let context = self.atomic_context_from_read_context(&read_context).await?;
+        Ok(GetTokenBalanceProofResponse {
+            balance,
+            balance_proof: Self::map_token_state_proof(&balance_proof),
+            expected_next_nonce,
+            nonce_proof: Self::map_token_state_proof(&nonce_proof),
+            context,
+            consensus_anchor,
+        })
+    }
+
+    async fn get_token_asset_proof_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        request: GetTokenAssetProofRequest,
+    ) -> RpcResult<GetTokenAssetProofResponse> {
+        let GetTokenAssetProofRequest { asset_id, at_block_hash } = request;
+        let atomic = self.atomic_service()?;
+        let asset_id = Self::parse_hex_32(&asset_id, "assetId")?;
+        let (read_context, asset, proof) =
+            atomic.get_asset_proof_with_context(asset_id, at_block_hash).await.ok_or(RpcError::StaleContext)?;
+        Self::ensure_token_context_read_ready(&read_context)?;
+        let asset = asset.map(Self::map_token_asset);
+        let context = self.atomic_context_from_read_context(&read_context).await?;
+        Ok(GetTokenAssetProofResponse { asset, proof: Self::map_token_state_proof(&proof), context })
+    }
+
+    async fn get_token_asset_history_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        request: GetTokenAssetHistoryRequest,
+    ) -> RpcResult<GetTokenAssetHistoryResponse> {
+        let GetTokenAssetHistoryRequest { asset_id, at_block_hash } = request;
+        let atomic = self.atomic_service()?;
+        let asset_id = Self::parse_hex_32(&asset_id, "assetId")?;
+        let (read_context, asset) = atomic.get_asset_with_context(asset_id, at_block_hash).await.ok_or(RpcError::StaleContext)?;
+        Self::ensure_token_context_read_ready(&read_context)?;
+        let (total_revisions, revisions) = match asset.as_ref() {
+            Some(asset) => (
+                asset.metadata_history.last().map(|revision| revision.revision).unwrap_or(0),
+                asset.metadata_history.iter().cloned().map(Self::map_token_metadata_revision).collect(),
+            ),
+            None => (0, Vec::new()),
+        };
+        let asset = asset.map(Self::map_token_asset);
+        let context = self.atomic_context_from_read_context(&read_context).await?;
+        Ok(GetTokenAssetHistoryResponse { asset, total_revisions, revisions, context })
+    }
+
+    async fn get_pair_pool_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        request: GetPairPoolRequest,
+    ) -> RpcResult<GetPairPoolResponse> {
+        let GetPairPoolRequest { pool_id, at_block_hash } = request;
+        let atomic = self.atomic_service()?;
+        let pool_id = Self::parse_hex_32(&pool_id, "poolId")?;
+        let (read_context, pool) = atomic.get_pair_pool_with_context(pool_id, at_block_hash).await.ok_or(RpcError::StaleContext)?;
+        Self::ensure_token_context_read_ready(&read_context)?;
+        let pool = pool.and_then(|(pool, reserve_a, reserve_b, locked_shares)| {
+            Self::map_pair_pool_state(&pool, reserve_a, reserve_b, locked_shares)
+        });
+        let context = self.atomic_context_from_read_context(&read_context).await?;
+        Ok(GetPairPoolResponse { pool, context })
+    }
+
+    async fn get_pair_quote_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        request: GetPairQuoteRequest,
+    ) -> RpcResult<GetPairQuoteResponse> {
+        let GetPairQuoteRequest { pool_id, asset_in_id, exact_out, amount, at_block_hash } = request;
+        let atomic = self.atomic_service()?;
+        let pool_id = Self::parse_hex_32(&pool_id, "poolId")?;
+        let asset_in_id = Self::parse_hex_32(&asset_in_id, "assetInId")?;
+        let amount = amount.parse::<u128>().map_err(|e| RpcError::General(format!("invalid `amount`: {e}")))?;
+        if amount == 0 {
+            return Err(Self::cat_error_with_detail(CAT_ERR_ZERO_OUTPUT, "pair quote amount must be > 0"));
+        }
+        let (read_context, pool) = atomic.get_pair_pool_with_context(pool_id, at_block_hash).await.ok_or(RpcError::StaleContext)?;
+        Self::ensure_token_context_read_ready(&read_context)?;
+        let (pool, reserve_a, reserve_b, _) = pool.ok_or_else(|| RpcError::General("pair pool not found".to_string()))?;
+        let pair = pool.pair_pool.ok_or_else(|| RpcError::General("pair pool state missing for asset".to_string()))?;
+        let (asset_out_id, reserve_in, reserve_out) = if asset_in_id == pair.asset_a_id {
+            (pair.asset_b_id, reserve_a, reserve_b)
+        } else if asset_in_id == pair.asset_b_id {
+            (pair.asset_a_id, reserve_b, reserve_a)
+        } else {
+            return Err(RpcError::General("`assetInId` is not an asset of this pair pool".to_string()));
+        };
+        let (amount_in, fee_amount, amount_out) = if exact_out {
+            let (amount_in, fee) =
+                pair_swap_exact_out(reserve_in, reserve_out, amount, pair.fee_bps).map_err(Self::map_liquidity_math_error)?;
+            (amount_in, fee, amount)
+        } else {
+            let (amount_out, fee) =
+                pair_swap_exact_in(reserve_in, reserve_out, amount, pair.fee_bps).map_err(Self::map_liquidity_math_error)?;
+            (amount, fee, amount_out)
+        };
+
+        let context = self.atomic_context_from_read_context(&read_context).await?;
+        Ok(GetPairQuoteResponse {
+            asset_in_id: asset_in_id.as_slice().to_hex(),
+            asset_out_id: asset_out_id.as_slice().to_hex(),
+            amount_in: amount_in.to_string(),
+            fee_amount: fee_amount.to_string(),
+            amount_out: amount_out.to_string(),
+            context,
+        })
+    }
+
+    async fn get_liquidity_candles_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        request: GetLiquidityCandlesRequest,
+    ) -> RpcResult<GetLiquidityCandlesResponse> {
+        let GetLiquidityCandlesRequest { asset_id, interval_daa_score, from_daa_score, to_daa_score } = request;
+        let atomic = self.atomic_service()?;
+        let asset_id = Self::parse_hex_32(&asset_id, "assetId")?;
+        if interval_daa_score == 0 {
+            return Err(RpcError::General("`intervalDaaScore` must be > 0".to_string()));
+        }
+        if to_daa_score <= from_daa_score {
+            return Err(RpcError::General("`toDaaScore` must be greater than `fromDaaScore`".to_string()));
+        }
+        let (read_context, candles) = atomic
+            .get_liquidity_candles_with_context(asset_id, interval_daa_score, from_daa_score, to_daa_score)
+            .await
+            .ok_or(RpcError::StaleContext)?;
+        Self::ensure_token_context_read_ready(&read_context)?;
+        let candles = candles
+            .into_iter()
+            .map(|candle| RpcLiquidityCandle {
+                start_daa_score: candle.start_daa_score,
+                open_price_sompi: candle.open_price_sompi.to_string(),
+                high_price_sompi: candle.high_price_sompi.to_string(),
+                low_price_sompi: candle.low_price_sompi.to_string(),
+                close_price_sompi: candle.close_price_sompi.to_string(),
+                volume_sompi: candle.volume_sompi.to_string(),
+                trade_count: candle.trade_count,
+            })
+            .collect();
+        let context = self.atomic_context_from_read_context(&read_context).await?;
+        Ok(GetLiquidityCandlesResponse { candles, context })
+    }
+
     async fn get_token_op_status_call(
         &self,
         _connection: Option<&DynRpcConnection>,
@@ -3913,19 +4935,48 @@ NOTE: This error usually indicates an RPC conversion error between the node and
         _connection: Option<&DynRpcConnection>,
         request: GetTokenEventsRequest,
     ) -> RpcResult<GetTokenEventsResponse> {
-        let GetTokenEventsRequest { after_sequence, limit, at_block_hash } = request;
+        let GetTokenEventsRequest { after_sequence, limit, at_block_hash, owner_id, asset_id, op_types, from_daa_score, to_daa_score } =
+            request;
         let atomic = self.atomic_service()?;
         let limit = usize::try_from(limit).map_err(|e| RpcError::General(e.to_string()))?.min(TOKEN_EVENTS_LIMIT_MAX);
+        let filter = TokenEventFilter {
+            owner_id: owner_id.as_deref().map(|owner_id| Self::parse_hex_32(owner_id, "ownerId")).transpose()?,
+            asset_id: asset_id.as_deref().map(|asset_id| Self::parse_hex_32(asset_id, "assetId")).transpose()?,
+            op_types,
+        };
+        if let (Some(from_daa_score), Some(to_daa_score)) = (from_daa_score, to_daa_score) {
+            if to_daa_score < from_daa_score {
+                return Err(RpcError::General("`toDaaScore` must not be lower than `fromDaaScore`".to_string()));
+            }
+        }
         let read_context = atomic.get_read_context(at_block_hash).await.ok_or(RpcError::StaleContext)?;
         Self::ensure_token_context_read_ready(&read_context)?;
-        let events = atomic
-            .get_events_since_capped(after_sequence, limit, read_context.event_sequence_cutoff)
-            .await
-            .into_iter()
-            .map(Self::map_token_event)
-            .collect();
         let context = self.atomic_context_from_read_context(&read_context).await?;
-        Ok(GetTokenEventsResponse { events, context })
+
+        let (mut after_sequence, mut max_sequence) = (after_sequence, read_context.event_sequence_cutoff);
+        if from_daa_score.is_some() || to_daa_score.is_some() {
+            let range = atomic
+                .get_event_sequence_range_for_daa(from_daa_score.unwrap_or(0), to_daa_score.unwrap_or(u64::MAX))
+                .await
+                .map_err(|err| RpcError::General(err.to_string()))?;
+            let Some((range_after_sequence, range_max_sequence)) = range else {
+                return Ok(GetTokenEventsResponse { events: vec![], context, next_after_sequence: None });
+            };
+            after_sequence = after_sequence.max(range_after_sequence);
+            max_sequence = max_sequence.min(range_max_sequence);
+        }
+
+        let (events, has_more) = if filter == TokenEventFilter::default() {
+            let mut events = atomic.get_events_since_capped(after_sequence, limit.saturating_add(1), max_sequence).await;
+            let has_more = events.len() > limit;
+            events.truncate(limit);
+            (events, has_more)
+        } else {
+            atomic.get_filtered_events(filter, after_sequence, limit, max_sequence).await
+        };
+        let next_after_sequence = has_more.then(|| events.last().map_or(after_sequence, |event| event.sequence));
+        let events = events.into_iter().map(Self::map_token_event).collect();
+        Ok(GetTokenEventsResponse { events, context, next_after_sequence })
     }
 
     async fn get_token_assets_call(
@@ -3956,13 +5007,19 @@ NOTE: This error usually indicates an RPC conversion error between the node and
         let owner_id = Self::parse_hex_32(&owner_id, "ownerId")?;
         let limit = usize::try_from(limit).map_err(|e| RpcError::General(e.to_string()))?.min(TOKEN_OWNER_BALANCES_LIMIT_MAX);
         let offset = usize::try_from(offset).map_err(|e| RpcError::General(e.to_string()))?;
-        let (read_context, balances) =
+        let (read_context, balances, locks) =
             atomic.get_indexed_balances_by_owner(owner_id, include_assets, at_block_hash).await.ok_or(RpcError::StaleContext)?;
         Self::ensure_token_context_read_ready(&read_context)?;
 
         let (balances, total) = Self::page_token_owner_balances(balances, offset, limit);
-        let balances = balances.into_iter().map(Self::map_token_owner_balance).collect();
         let context = self.atomic_context_from_read_context(&read_context).await?;
+        let balances = balances
+            .into_iter()
+            .map(|entry| {
+                let locked_balance = locks.get(&entry.0).map(|lock| lock.locked_at(context.at_daa_score)).unwrap_or(0).min(entry.1);
+                Self::map_token_owner_balance(entry, locked_balance)
+            })
+            .collect();
         Ok(GetTokenBalancesByOwnerResponse { balances, total, context })
     }
 
@@ -4101,8 +5158,52 @@ NOTE: This error usually indicates an RPC conversion error between the node and
                 }
                 (exact_in_amount.clone(), fee_trade.to_string(), token_in.to_string(), cpay_out.to_string())
             }
+            LIQUIDITY_QUOTE_SIDE_BUY_EXACT_OUT => {
+                let token_out = exact_in_amount
+                    .parse::<u128>()
+                    .map_err(|e| RpcError::General(format!("invalid `exactInAmount` for buy exact-out side: {e}")))?;
+                if token_out == 0 {
+                    return Err(Self::cat_error_with_detail(CAT_ERR_ZERO_OUTPUT, "buy exact-out token amount must be > 0"));
+                }
+                let (cpay_net_in, _, _, _) = cpmm_buy_exact_out(
+                    pool.real_token_reserves,
+                    pool.virtual_cpay_reserves_sompi,
+                    pool.virtual_token_reserves,
+                    token_out,
+                )
+                .map_err(Self::map_liquidity_math_error)?;
+                let (cpay_in, fee_trade) =
+                    gross_amount_for_net_amount(cpay_net_in, pool.fee_bps).map_err(Self::map_liquidity_math_error)?;
+                (cpay_in.to_string(), fee_trade.to_string(), cpay_net_in.to_string(), token_out.to_string())
+            }
+            LIQUIDITY_QUOTE_SIDE_SELL_EXACT_OUT => {
+                let cpay_out = exact_in_amount
+                    .parse::<u64>()
+                    .map_err(|e| RpcError::General(format!("invalid `exactInAmount` for sell exact-out side: {e}")))?;
+                if cpay_out == 0 {
+                    return Err(Self::cat_error_with_detail(CAT_ERR_ZERO_OUTPUT, "sell exact-out CPAY amount must be > 0"));
+                }
+                if cpay_out < LIQUIDITY_MIN_PAYOUT_SOMPI {
+                    return Err(Self::cat_error_with_detail(
+                        CAT_ERR_MIN_OUT_VIOLATION,
+                        format!("liquidity quote output below minimum payout: {cpay_out} < {LIQUIDITY_MIN_PAYOUT_SOMPI}"),
+                    ));
+                }
+                let (gross_out, fee_trade) =
+                    gross_amount_for_net_amount(cpay_out, pool.fee_bps).map_err(Self::map_liquidity_math_error)?;
+                let (token_in, _, _, _) = cpmm_sell_exact_out(
+                    pool.real_cpay_reserves_sompi,
+                    pool.virtual_cpay_reserves_sompi,
+                    pool.virtual_token_reserves,
+                    gross_out,
+                )
+                .map_err(Self::map_liquidity_math_error)?;
+                (token_in.to_string(), fee_trade.to_string(), token_in.to_string(), cpay_out.to_string())
+            }
             _ => {
-                return Err(RpcError::General("invalid `side` (expected 0=buy or 1=sell)".to_string()));
+                return Err(RpcError::General(
+                    "invalid `side` (expected 0=buy, 1=sell, 2=buy exact-out or 3=sell exact-out)".to_string(),
+                ));
             }
         };
 
@@ -4254,6 +5355,26 @@ NOTE: This error usually indicates an RPC conversion error between the node and
         Ok(ExportTokenSnapshotResponse { exported: true, context })
     }
 
+    async fn export_token_analytics_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        request: ExportTokenAnalyticsRequest,
+    ) -> RpcResult<ExportTokenAnalyticsResponse> {
+        let atomic = self.atomic_service()?;
+        if !self.config.unsafe_rpc {
+            warn!("ExportTokenAnalytics RPC command called while node in safe RPC mode -- rejecting.");
+            return Err(RpcError::UnavailableInSafeMode);
+        }
+        let manifest = atomic
+            .export_analytics_to_dir(&request.path, request.at_block_hash)
+            .await
+            .map_err(|err| RpcError::General(err.to_string()))?;
+        let manifest_path = Path::new(&request.path).join(ANALYTICS_MANIFEST_FILE_NAME);
+        let read_context = atomic.get_read_context(Some(manifest.at_block_hash)).await.ok_or(RpcError::StaleContext)?;
+        let context = self.atomic_context_from_read_context(&read_context).await?;
+        Ok(ExportTokenAnalyticsResponse { manifest_path: manifest_path.display().to_string(), context })
+    }
+
     async fn import_token_snapshot_call(
         &self,
         _connection: Option<&DynRpcConnection>,
@@ -4512,6 +5633,8 @@ mod tests {
             created_daa_score: None,
             created_at: None,
             liquidity: Some(pool),
+            pair_pool: None,
+            metadata_history: Vec::new(),
         }
     }
 
@@ -4630,6 +5753,54 @@ mod tests {
         assert_eq!(RpcCoreService::simulate_sell_liquidity_noop_reason(&asset, &pool, 100, &op), Some(NoopReason::InvalidAmount));
     }
 
+    #[test]
+    fn simulate_exact_out_liquidity_enforces_max_input_bounds() {
+        let pool = sample_liquidity_pool(900, 1_000, 100);
+        let asset = sample_liquidity_asset(1_000, 100, pool.clone());
+        let (net_in, _, _, _) =
+            cpmm_buy_exact_out(pool.real_token_reserves, pool.virtual_cpay_reserves_sompi, pool.virtual_token_reserves, 10)
+                .expect("exact-out buy quote should work");
+        let (cpay_in, _) = gross_amount_for_net_amount(net_in, pool.fee_bps).expect("gross input should settle");
+        let mut buy = BuyLiquidityExactOutOp {
+            asset_id: asset.asset_id,
+            expected_pool_nonce: pool.pool_nonce,
+            token_out: 10,
+            max_cpay_in_sompi: cpay_in,
+        };
+        assert_eq!(RpcCoreService::simulate_buy_liquidity_exact_out_noop_reason(&asset, &pool, &buy), None);
+        buy.max_cpay_in_sompi = cpay_in - 1;
+        assert_eq!(
+            RpcCoreService::simulate_buy_liquidity_exact_out_noop_reason(&asset, &pool, &buy),
+            Some(NoopReason::MinOutViolation)
+        );
+
+        let (gross_out, _) = gross_amount_for_net_amount(100, pool.fee_bps).expect("gross output should settle");
+        let (token_in, _, _, _) = cpmm_sell_exact_out(
+            pool.real_cpay_reserves_sompi,
+            pool.virtual_cpay_reserves_sompi,
+            pool.virtual_token_reserves,
+            gross_out,
+        )
+        .expect("exact-out sell quote should work");
+        let mut sell = SellLiquidityExactOutOp {
+            asset_id: asset.asset_id,
+            expected_pool_nonce: pool.pool_nonce,
+            cpay_out_sompi: 100,
+            max_token_in: token_in,
+            cpay_receive_output_index: 0,
+        };
+        assert_eq!(RpcCoreService::simulate_sell_liquidity_exact_out_noop_reason(&asset, &pool, token_in, &sell), None);
+        assert_eq!(
+            RpcCoreService::simulate_sell_liquidity_exact_out_noop_reason(&asset, &pool, token_in - 1, &sell),
+            Some(NoopReason::InsufficientBalance)
+        );
+        sell.max_token_in = token_in - 1;
+        assert_eq!(
+            RpcCoreService::simulate_sell_liquidity_exact_out_noop_reason(&asset, &pool, token_in, &sell),
+            Some(NoopReason::MinOutViolation)
+        );
+    }
+
     #[test]
     fn simulate_sell_liquidity_rejects_active_lock() {
         let mut pool = sample_liquidity_pool(900, 1_000, 0);
@@ -75,6 +75,8 @@ impl Inner {
             RpcApiOps::PruningPointUtxoSetOverrideNotification,
             RpcApiOps::NewBlockTemplateNotification,
             RpcApiOps::TokenEventsChangedNotification,
+            RpcApiOps::FastIntentStatusChangedNotification,
+            RpcApiOps::MessagesReceivedNotification,
         ]
         .into_iter()
         .for_each(|notification_op| {
@@ -645,6 +647,12 @@ impl RpcApi for CryptixRpcClient {
             GetTokenNonce,
             GetOwnerNonce,
             GetTokenAsset,
+            GetTokenBalanceProof,
+            GetTokenAssetProof,
+            GetTokenAssetHistory,
+            GetPairPool,
+            GetPairQuote,
+            GetLiquidityCandles,
             GetTokenOpStatus,
             GetTokenStateHash,
             GetTokenSpendability,
@@ -659,6 +667,7 @@ impl RpcApi for CryptixRpcClient {
             GetLiquidityClaimPreview,
             GetLiquidityHolders,
             ExportTokenSnapshot,
+            ExportTokenAnalytics,
             ImportTokenSnapshot,
             GetTokenHealth,
             GetScBootstrapSources,
@@ -679,6 +688,9 @@ impl RpcApi for CryptixRpcClient {
             SubmitFastIntent,
             GetFastIntentStatus,
             CancelFastIntent,
+            GetHfaConfig,
+            SetHfaConfig,
+            GetMessagesByRecipientTag,
             Unban,
         ]
     );
@@ -71,6 +71,12 @@ impl Router {
                 GetTokenNonce,
                 GetOwnerNonce,
                 GetTokenAsset,
+                GetTokenBalanceProof,
+                GetTokenAssetProof,
+                GetTokenAssetHistory,
+                GetPairPool,
+                GetPairQuote,
+                GetLiquidityCandles,
                 GetTokenOpStatus,
                 GetTokenStateHash,
                 GetTokenSpendability,
@@ -85,6 +91,7 @@ impl Router {
                 GetLiquidityClaimPreview,
                 GetLiquidityHolders,
                 ExportTokenSnapshot,
+                ExportTokenAnalytics,
                 ImportTokenSnapshot,
                 GetTokenHealth,
                 GetScBootstrapSources,
@@ -105,6 +112,9 @@ impl Router {
                 SubmitFastIntent,
                 GetFastIntentStatus,
                 CancelFastIntent,
+                GetHfaConfig,
+                SetHfaConfig,
+                GetMessagesByRecipientTag,
                 GetSpendableBalancesByAddresses,
                 GetTransactionMassEstimate,
                 ValidateTransaction,
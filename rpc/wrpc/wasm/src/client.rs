@@ -56,6 +56,134 @@ declare! {
     "#,
 }
 
+declare! {
+    ITokenEventsFilter,
+    r#"
+    /**
+     * Token events subscription filter. Empty or omitted lists match everything.
+     * 
+     * @category Node RPC
+     */
+    export interface ITokenEventsFilter {
+        /**
+         * Asset ids (hex) to match
+         */
+        assetIds?: HexString[];
+        /**
+         * Owner ids (hex) to match against either side of an event
+         */
+        ownerIds?: HexString[];
+        /**
+         * Token op types to match
+         */
+        opTypes?: number[];
+        /**
+         * Apply statuses to match
+         */
+        applyStatuses?: number[];
+    }
+    "#,
+}
+
+declare! {
+    IFastIntentStatusFilter,
+    r#"
+    /**
+     * Fast intent status subscription filter. Empty or omitted lists match everything.
+     * An intent matches when either its id or any of the addresses it touches is listed.
+     * 
+     * @category Node RPC
+     */
+    export interface IFastIntentStatusFilter {
+        /**
+         * Intent ids (hex) to match
+         */
+        intentIds?: HexString[];
+        /**
+         * Addresses to match against the intent inputs and outputs
+         */
+        addresses?: string[];
+    }
+    "#,
+}
+
+declare! {
+    IMessagesReceivedFilter,
+    r#"
+    /**
+     * Messages received subscription filter. An empty or omitted list matches every message.
+     * 
+     * @category Node RPC
+     */
+    export interface IMessagesReceivedFilter {
+        /**
+         * 16-byte recipient tags (hex) to match
+         */
+        recipientTags?: HexString[];
+    }
+    "#,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TokenEventsFilter {
+    #[serde(default)]
+    asset_ids: Vec<String>,
+    #[serde(default)]
+    owner_ids: Vec<String>,
+    #[serde(default)]
+    op_types: Vec<u32>,
+    #[serde(default)]
+    apply_statuses: Vec<u32>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FastIntentStatusFilter {
+    #[serde(default)]
+    intent_ids: Vec<String>,
+    #[serde(default)]
+    addresses: Vec<String>,
+}
+
+impl TryFrom<FastIntentStatusFilter> for FastIntentStatusChangedScope {
+    type Error = Error;
+
+    fn try_from(filter: FastIntentStatusFilter) -> Result<Self> {
+        let intent_ids = filter
+            .intent_ids
+            .iter()
+            .map(|id| RpcHash::from_str(id).map_err(|err| Error::custom(format!("Invalid intent id `{id}`: {err}"))))
+            .collect::<Result<Vec<_>>>()?;
+        let addresses = filter
+            .addresses
+            .iter()
+            .map(|address| {
+                Address::try_from(address.as_str()).map_err(|err| Error::custom(format!("Invalid address `{address}`: {err}")))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(FastIntentStatusChangedScope::new(intent_ids, addresses))
+    }
+}
+
+#[derive(Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MessagesReceivedFilter {
+    #[serde(default)]
+    recipient_tags: Vec<String>,
+}
+
+impl TryFrom<MessagesReceivedFilter> for MessagesReceivedScope {
+    type Error = Error;
+
+    fn try_from(filter: MessagesReceivedFilter) -> Result<Self> {
+        if let Some(tag) = filter.recipient_tags.iter().find(|tag| tag.len() != 32 || !tag.chars().all(|c| c.is_ascii_hexdigit())) {
+            return Err(Error::custom(format!("Invalid recipient tag `{tag}`: expected 16 hex encoded bytes")));
+        }
+        Ok(MessagesReceivedScope::new(filter.recipient_tags))
+    }
+}
+
 pub struct RpcConfig {
     pub resolver: Option<Resolver>,
     pub url: Option<String>,
@@ -867,6 +995,100 @@ impl RpcClient {
         }
         Ok(())
     }
+
+    /// Manage subscription for token events changed notifications.
+    /// Token events changed notification carries the token events matching
+    /// the supplied filter. Omitting the filter (or leaving all of its lists
+    /// empty) subscribes to all token events.
+    #[wasm_bindgen(js_name = subscribeTokenEventsChanged)]
+    pub async fn subscribe_token_events_changed(&self, filter: Option<ITokenEventsFilter>) -> Result<()> {
+        if let Some(listener_id) = self.listener_id() {
+            let filter: TokenEventsFilter = match filter {
+                Some(filter) if !filter.is_undefined() => from_value(filter.into())?,
+                _ => TokenEventsFilter::default(),
+            };
+            let scope = TokenEventsChangedScope::new(filter.asset_ids, filter.owner_ids, filter.op_types, filter.apply_statuses);
+            self.inner.client.start_notify(listener_id, Scope::TokenEventsChanged(scope)).await?;
+        } else {
+            log_error!("RPC subscribe on a closed connection");
+        }
+        Ok(())
+    }
+
+    /// Unsubscribe from token events changed notifications.
+    #[wasm_bindgen(js_name = unsubscribeTokenEventsChanged)]
+    pub async fn unsubscribe_token_events_changed(&self) -> Result<()> {
+        if let Some(listener_id) = self.listener_id() {
+            self.inner.client.stop_notify(listener_id, Scope::TokenEventsChanged(TokenEventsChangedScope::default())).await?;
+        } else {
+            log_error!("RPC unsubscribe on a closed connection");
+        }
+        Ok(())
+    }
+
+    /// Manage subscription for fast intent status changed notifications.
+    /// A notification is produced on every HFA fast intent status transition
+    /// matching the supplied filter. Omitting the filter (or leaving all of
+    /// its lists empty) subscribes to all fast intents.
+    #[wasm_bindgen(js_name = subscribeFastIntentStatusChanged)]
+    pub async fn subscribe_fast_intent_status_changed(&self, filter: Option<IFastIntentStatusFilter>) -> Result<()> {
+        if let Some(listener_id) = self.listener_id() {
+            let filter: FastIntentStatusFilter = match filter {
+                Some(filter) if !filter.is_undefined() => from_value(filter.into())?,
+                _ => FastIntentStatusFilter::default(),
+            };
+            let scope = FastIntentStatusChangedScope::try_from(filter)?;
+            self.inner.client.start_notify(listener_id, Scope::FastIntentStatusChanged(scope)).await?;
+        } else {
+            log_error!("RPC subscribe on a closed connection");
+        }
+        Ok(())
+    }
+
+    /// Unsubscribe from fast intent status changed notifications.
+    #[wasm_bindgen(js_name = unsubscribeFastIntentStatusChanged)]
+    pub async fn unsubscribe_fast_intent_status_changed(&self) -> Result<()> {
+        if let Some(listener_id) = self.listener_id() {
+            self.inner
+                .client
+                .stop_notify(listener_id, Scope::FastIntentStatusChanged(FastIntentStatusChangedScope::default()))
+                .await?;
+        } else {
+            log_error!("RPC unsubscribe on a closed connection");
+        }
+        Ok(())
+    }
+
+    /// Manage subscription for messages received notifications.
+    /// A notification is produced whenever the node message index accepts
+    /// CXM messages sent to one of the supplied recipient tags. Omitting the
+    /// filter (or leaving its list empty) subscribes to all messages.
+    /// Requires the node to run with the message index enabled.
+    #[wasm_bindgen(js_name = subscribeMessagesReceived)]
+    pub async fn subscribe_messages_received(&self, filter: Option<IMessagesReceivedFilter>) -> Result<()> {
+        if let Some(listener_id) = self.listener_id() {
+            let filter: MessagesReceivedFilter = match filter {
+                Some(filter) if !filter.is_undefined() => from_value(filter.into())?,
+                _ => MessagesReceivedFilter::default(),
+            };
+            let scope = MessagesReceivedScope::try_from(filter)?;
+            self.inner.client.start_notify(listener_id, Scope::MessagesReceived(scope)).await?;
+        } else {
+            log_error!("RPC subscribe on a closed connection");
+        }
+        Ok(())
+    }
+
+    /// Unsubscribe from messages received notifications.
+    #[wasm_bindgen(js_name = unsubscribeMessagesReceived)]
+    pub async fn unsubscribe_messages_received(&self) -> Result<()> {
+        if let Some(listener_id) = self.listener_id() {
+            self.inner.client.stop_notify(listener_id, Scope::MessagesReceived(MessagesReceivedScope::default())).await?;
+        } else {
+            log_error!("RPC unsubscribe on a closed connection");
+        }
+        Ok(())
+    }
 }
 
 // Build subscribe functions
@@ -875,6 +1097,9 @@ build_wrpc_wasm_bindgen_subscriptions!([
     // - VirtualChainChanged, // can't used this here due to non-C-style enum variant
     // - UtxosChanged, // can't used this here due to non-C-style enum variant
     // - VirtualDaaScoreChanged,
+    // - TokenEventsChanged, // can't used this here due to non-C-style enum variant
+    // - FastIntentStatusChanged, // can't used this here due to non-C-style enum variant
+    // - MessagesReceived, // can't used this here due to non-C-style enum variant
     /// Manage subscription for a block added notification event.
     /// Block added notification event is produced when a new
     /// block is added to the Cryptix BlockDAG.
@@ -900,9 +1125,6 @@ build_wrpc_wasm_bindgen_subscriptions!([
     /// New block template notification event is produced when a new block
     /// template is generated for mining in the Cryptix BlockDAG.
     NewBlockTemplate,
-    /// Manage subscription for token events changed notifications.
-    /// Token events changed notification indicates that new token events are available via GetTokenEvents.
-    TokenEventsChanged,
 ]);
 
 // Build RPC method invocation functions. This macro
@@ -1049,6 +1271,18 @@ build_wrpc_wasm_bindgen_interface!(
         GetOwnerNonce,
         /// Returns Cryptix Atomic asset metadata.
         GetTokenAsset,
+        /// Returns Cryptix Atomic token balance and nonce with Atomic state root inclusion proofs.
+        GetTokenBalanceProof,
+        /// Returns Cryptix Atomic asset metadata with an Atomic state root inclusion proof.
+        GetTokenAssetProof,
+        /// Returns the retained metadata revision history of a Cryptix Atomic asset.
+        GetTokenAssetHistory,
+        /// Returns the reserve and share state of a Cryptix Atomic pair pool.
+        GetPairPool,
+        /// Returns an exact-in or exact-out swap quote against a Cryptix Atomic pair pool.
+        GetPairQuote,
+        /// Returns OHLC candles built from the retained trades of a Cryptix Atomic liquidity pool.
+        GetLiquidityCandles,
         /// Returns Cryptix Atomic operation status by txid.
         GetTokenOpStatus,
         /// Returns wallet-policy spendability signal for a token owner/asset pair.
@@ -1075,6 +1309,8 @@ build_wrpc_wasm_bindgen_interface!(
         GetLiquidityHolders,
         /// Exports Cryptix Atomic snapshot to a file path.
         ExportTokenSnapshot,
+        /// Exports Cryptix Atomic token state at a block to the JSONL analytics format.
+        ExportTokenAnalytics,
         /// Imports Cryptix Atomic snapshot from a file path.
         ImportTokenSnapshot,
         /// Returns a specific snapshot manifest by snapshot id.
@@ -1211,7 +1211,10 @@ async fn atomic_token_atomic_enabled_e2e_transfer_mint_burn_snapshot() {
     assert_eq!(token_nonce.expected_next_nonce, 4);
 
     let events = client
-        .get_token_events_call(None, GetTokenEventsRequest { after_sequence: 0, limit: 100, at_block_hash: None })
+        .get_token_events_call(
+            None,
+            GetTokenEventsRequest { after_sequence: 0, limit: 100, at_block_hash: None, ..Default::default() },
+        )
         .await
         .unwrap();
     assert!(events.events.len() >= 4);
@@ -1717,7 +1720,10 @@ async fn atomic_token_atomic_enabled_mixed_mempool_stress_drains_deterministical
     assert!(liquidity_holders.total >= 1);
 
     let events = client
-        .get_token_events_call(None, GetTokenEventsRequest { after_sequence: 0, limit: 2000, at_block_hash: None })
+        .get_token_events_call(
+            None,
+            GetTokenEventsRequest { after_sequence: 0, limit: 2000, at_block_hash: None, ..Default::default() },
+        )
         .await
         .unwrap();
     assert!(
@@ -2703,7 +2709,12 @@ async fn atomic_token_ignored_long_full_chain_stress_mempool_blocks_and_state()
         let events = client
             .get_token_events_call(
                 None,
-                GetTokenEventsRequest { after_sequence, limit: TOKEN_EVENTS_RPC_PAGE_LIMIT, at_block_hash: None },
+                GetTokenEventsRequest {
+                    after_sequence,
+                    limit: TOKEN_EVENTS_RPC_PAGE_LIMIT,
+                    at_block_hash: None,
+                    ..Default::default()
+                },
             )
             .await
             .unwrap();
@@ -2821,7 +2832,10 @@ async fn atomic_token_atomic_enabled_reorg_emits_reorged_event() {
         let status_after =
             client1.get_token_op_status_call(None, GetTokenOpStatusRequest { txid, at_block_hash: None }).await.unwrap();
         let events = client1
-            .get_token_events_call(None, GetTokenEventsRequest { after_sequence: 0, limit: 2000, at_block_hash: None })
+            .get_token_events_call(
+                None,
+                GetTokenEventsRequest { after_sequence: 0, limit: 2000, at_block_hash: None, ..Default::default() },
+            )
             .await
             .unwrap();
         if status_after.apply_status.is_none() && events.events.iter().any(|event| event.txid == txid && event.event_type == 2) {
@@ -2920,7 +2934,10 @@ async fn atomic_token_reorg_across_payload_hardfork_reverts_state_and_accepts_fr
 
     let losing_txids = vec![create_tx.id(), mint_tx.id(), transfer_tx.id(), burn_tx.id()];
     let events_before = client1
-        .get_token_events_call(None, GetTokenEventsRequest { after_sequence: 0, limit: 1000, at_block_hash: None })
+        .get_token_events_call(
+            None,
+            GetTokenEventsRequest { after_sequence: 0, limit: 1000, at_block_hash: None, ..Default::default() },
+        )
         .await
         .unwrap();
     let mut applied_event_ids = Vec::with_capacity(losing_txids.len());
@@ -3025,7 +3042,10 @@ async fn atomic_token_reorg_across_payload_hardfork_reverts_state_and_accepts_fr
     assert_ne!(state_after_reorg.context.state_hash, state_before_reorg.context.state_hash);
 
     let events_after = client1
-        .get_token_events_call(None, GetTokenEventsRequest { after_sequence: 0, limit: 2000, at_block_hash: None })
+        .get_token_events_call(
+            None,
+            GetTokenEventsRequest { after_sequence: 0, limit: 2000, at_block_hash: None, ..Default::default() },
+        )
         .await
         .unwrap();
     for (txid, applied_event_id) in &applied_event_ids {
@@ -4342,7 +4362,7 @@ async fn atomic_token_ignored_reorg_stress_mempool_blocks_and_state() {
     let events_before = client1
         .get_token_events_call(
             None,
-            GetTokenEventsRequest { after_sequence: 0, limit: TOKEN_EVENTS_RPC_PAGE_LIMIT, at_block_hash: None },
+            GetTokenEventsRequest { after_sequence: 0, limit: TOKEN_EVENTS_RPC_PAGE_LIMIT, at_block_hash: None, ..Default::default() },
         )
         .await
         .unwrap();
@@ -4403,7 +4423,7 @@ async fn atomic_token_ignored_reorg_stress_mempool_blocks_and_state() {
     let events_after = client1
         .get_token_events_call(
             None,
-            GetTokenEventsRequest { after_sequence: 0, limit: TOKEN_EVENTS_RPC_PAGE_LIMIT, at_block_hash: None },
+            GetTokenEventsRequest { after_sequence: 0, limit: TOKEN_EVENTS_RPC_PAGE_LIMIT, at_block_hash: None, ..Default::default() },
         )
         .await
         .unwrap();
@@ -4598,7 +4618,10 @@ async fn atomic_token_atomic_enabled_rpc_smoke_simulate_and_snapshot() {
         .await
         .unwrap();
     let events_before = client
-        .get_token_events_call(None, GetTokenEventsRequest { after_sequence: 0, limit: 1000, at_block_hash: None })
+        .get_token_events_call(
+            None,
+            GetTokenEventsRequest { after_sequence: 0, limit: 1000, at_block_hash: None, ..Default::default() },
+        )
         .await
         .unwrap();
     assert!(events_before.events.len() >= tracked_txids.len());
@@ -4708,7 +4731,10 @@ async fn atomic_token_atomic_enabled_rpc_smoke_simulate_and_snapshot() {
     assert_eq!(receiver_nonce_after.expected_next_nonce, receiver_nonce_before.expected_next_nonce);
 
     let events_after = client
-        .get_token_events_call(None, GetTokenEventsRequest { after_sequence: 0, limit: 1000, at_block_hash: None })
+        .get_token_events_call(
+            None,
+            GetTokenEventsRequest { after_sequence: 0, limit: 1000, at_block_hash: None, ..Default::default() },
+        )
         .await
         .unwrap();
     let events_after_fingerprint = events_after
@@ -832,6 +832,8 @@ impl CryptixdGoParams {
             storage_mass_parameter: STORAGE_MASS_PARAMETER,
             storage_mass_activation_daa_score: u64::MAX,
             payload_hf_activation_daa_score: 33_739_200,
+            covenants_activation_daa_score: u64::MAX,
+            atomic_extended_ops_activation_daa_score: u64::MAX,
             payload_max_len_consensus: 8192,
             payload_max_len_standard: 2048,
             payload_weight_multiplier: 4,
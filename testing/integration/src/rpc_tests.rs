@@ -10,8 +10,9 @@ use cryptix_hashes::Hash;
 use cryptix_notify::{
     connection::{ChannelConnection, ChannelType},
     scope::{
-        BlockAddedScope, FinalityConflictScope, NewBlockTemplateScope, PruningPointUtxoSetOverrideScope, Scope,
-        SinkBlueScoreChangedScope, TokenEventsChangedScope, UtxosChangedScope, VirtualChainChangedScope, VirtualDaaScoreChangedScope,
+        BlockAddedScope, FastIntentStatusChangedScope, FinalityConflictScope, MessagesReceivedScope, NewBlockTemplateScope,
+        PruningPointUtxoSetOverrideScope, Scope, SinkBlueScoreChangedScope, TokenEventsChangedScope, UtxosChangedScope,
+        VirtualChainChangedScope, VirtualDaaScoreChangedScope,
     },
 };
 use cryptix_rpc_core::{api::rpc::RpcApi, model::*, Notification};
@@ -519,12 +520,14 @@ async fn sanity_test() {
                                 process_metrics: true,
                                 storage_metrics: true,
                                 custom_metrics: true,
+                                hfa_metrics: true,
                             },
                         )
                         .await
                         .unwrap();
                     assert!(get_metrics_call_response.process_metrics.is_some());
                     assert!(get_metrics_call_response.consensus_metrics.is_some());
+                    assert!(get_metrics_call_response.hfa_metrics.is_some_and(|hfa| !hfa.enabled));
 
                     let get_metrics_call_response = rpc_client
                         .get_metrics_call(
@@ -536,6 +539,7 @@ async fn sanity_test() {
                                 process_metrics: true,
                                 storage_metrics: true,
                                 custom_metrics: true,
+                                hfa_metrics: false,
                             },
                         )
                         .await
@@ -553,6 +557,7 @@ async fn sanity_test() {
                                 process_metrics: false,
                                 storage_metrics: false,
                                 custom_metrics: true,
+                                hfa_metrics: false,
                             },
                         )
                         .await
@@ -570,12 +575,14 @@ async fn sanity_test() {
                                 process_metrics: false,
                                 storage_metrics: false,
                                 custom_metrics: true,
+                                hfa_metrics: false,
                             },
                         )
                         .await
                         .unwrap();
                     assert!(get_metrics_call_response.process_metrics.is_none());
                     assert!(get_metrics_call_response.consensus_metrics.is_none());
+                    assert!(get_metrics_call_response.hfa_metrics.is_none());
                 })
             }
 
@@ -734,6 +741,45 @@ async fn sanity_test() {
                 })
             }
 
+            CryptixdPayloadOps::GetHfaConfig => {
+                let rpc_client = client.clone();
+                tst!(op, {
+                    let response = rpc_client.get_hfa_config_call(None, GetHfaConfigRequest {}).await.unwrap();
+                    assert!(!response.config.enabled);
+                    assert!(!response.config.paused);
+                })
+            }
+
+            CryptixdPayloadOps::SetHfaConfig => {
+                let rpc_client = client.clone();
+                tst!(op, {
+                    let response = rpc_client
+                        .set_hfa_config_call(
+                            None,
+                            SetHfaConfigRequest { fast_ttl_ms: Some(4_000), paused: Some(true), ..Default::default() },
+                        )
+                        .await
+                        .unwrap();
+                    assert_eq!(response.config.fast_ttl_ms, 4_000);
+                    assert!(response.config.paused);
+
+                    let result =
+                        rpc_client.set_hfa_config_call(None, SetHfaConfigRequest { max_locks: Some(0), ..Default::default() }).await;
+                    assert!(result.is_err());
+                })
+            }
+
+            CryptixdPayloadOps::GetMessagesByRecipientTag => {
+                let rpc_client = client.clone();
+                tst!(op, {
+                    // The message index is opt-in and disabled on this node
+                    let result = rpc_client
+                        .get_messages_by_recipient_tag_call(None, GetMessagesByRecipientTagRequest::new("00".repeat(16), None, 0, 0))
+                        .await;
+                    assert!(result.is_err());
+                })
+            }
+
             CryptixdPayloadOps::GetStrongNodes => {
                 let rpc_client = client.clone();
                 tst!(op, {
@@ -792,6 +838,93 @@ async fn sanity_test() {
                 })
             }
 
+            CryptixdPayloadOps::GetTokenBalanceProof => {
+                let rpc_client = client.clone();
+                tst!(op, {
+                    let result = rpc_client
+                        .get_token_balance_proof_call(
+                            None,
+                            GetTokenBalanceProofRequest {
+                                asset_id: String::new(),
+                                owner_id: String::new(),
+                                at_block_hash: None,
+                                include_consensus_anchor: false,
+                            },
+                        )
+                        .await;
+                    assert!(result.is_err());
+                })
+            }
+
+            CryptixdPayloadOps::GetTokenAssetProof => {
+                let rpc_client = client.clone();
+                tst!(op, {
+                    let result = rpc_client
+                        .get_token_asset_proof_call(None, GetTokenAssetProofRequest { asset_id: String::new(), at_block_hash: None })
+                        .await;
+                    assert!(result.is_err());
+                })
+            }
+
+            CryptixdPayloadOps::GetTokenAssetHistory => {
+                let rpc_client = client.clone();
+                tst!(op, {
+                    let result = rpc_client
+                        .get_token_asset_history_call(
+                            None,
+                            GetTokenAssetHistoryRequest { asset_id: String::new(), at_block_hash: None },
+                        )
+                        .await;
+                    assert!(result.is_err());
+                })
+            }
+
+            CryptixdPayloadOps::GetPairPool => {
+                let rpc_client = client.clone();
+                tst!(op, {
+                    let result =
+                        rpc_client.get_pair_pool_call(None, GetPairPoolRequest { pool_id: String::new(), at_block_hash: None }).await;
+                    assert!(result.is_err());
+                })
+            }
+
+            CryptixdPayloadOps::GetPairQuote => {
+                let rpc_client = client.clone();
+                tst!(op, {
+                    let result = rpc_client
+                        .get_pair_quote_call(
+                            None,
+                            GetPairQuoteRequest {
+                                pool_id: String::new(),
+                                asset_in_id: String::new(),
+                                exact_out: false,
+                                amount: String::new(),
+                                at_block_hash: None,
+                            },
+                        )
+                        .await;
+                    assert!(result.is_err());
+                })
+            }
+
+            CryptixdPayloadOps::GetLiquidityCandles => {
+                let rpc_client = client.clone();
+                tst!(op, {
+                    let result = rpc_client
+                        .get_liquidity_candles_call(
+                            None,
+                            GetLiquidityCandlesRequest {
+                                asset_id: String::new(),
+                                interval_daa_score: 0,
+                                from_daa_score: 0,
+                                to_daa_score: 0,
+                            },
+                        )
+                        .await;
+                    assert!(result.is_err());
+                })
+            }
+
             CryptixdPayloadOps::GetTokenOpStatus => {
                 let rpc_client = client.clone();
                 tst!(op, {
@@ -832,7 +965,10 @@ async fn sanity_test() {
                 let rpc_client = client.clone();
                 tst!(op, {
                     let result = rpc_client
-                        .get_token_events_call(None, GetTokenEventsRequest { after_sequence: 0, limit: 10, at_block_hash: None })
+                        .get_token_events_call(
+                            None,
+                            GetTokenEventsRequest { after_sequence: 0, limit: 10, at_block_hash: None, ..Default::default() },
+                        )
                         .await;
                     assert!(result.is_err());
                 })
@@ -977,6 +1113,19 @@ async fn sanity_test() {
                 })
             }
 
+            CryptixdPayloadOps::ExportTokenAnalytics => {
+                let rpc_client = client.clone();
+                tst!(op, {
+                    let result = rpc_client
+                        .export_token_analytics_call(
+                            None,
+                            ExportTokenAnalyticsRequest { path: "atomic-analytics".to_string(), at_block_hash: None },
+                        )
+                        .await;
+                    assert!(result.is_err());
+                })
+            }
+
             CryptixdPayloadOps::ImportTokenSnapshot => {
                 let rpc_client = client.clone();
                 tst!(op, {
@@ -1132,7 +1281,21 @@ async fn sanity_test() {
                 let rpc_client = client.clone();
                 let id = listener_id;
                 tst!(op, {
-                    rpc_client.start_notify(id, TokenEventsChangedScope {}.into()).await.unwrap();
+                    rpc_client.start_notify(id, TokenEventsChangedScope::default().into()).await.unwrap();
+                })
+            }
+            CryptixdPayloadOps::NotifyFastIntentStatus => {
+                let rpc_client = client.clone();
+                let id = listener_id;
+                tst!(op, {
+                    rpc_client.start_notify(id, FastIntentStatusChangedScope::default().into()).await.unwrap();
+                })
+            }
+            CryptixdPayloadOps::NotifyMessagesReceived => {
+                let rpc_client = client.clone();
+                let id = listener_id;
+                tst!(op, {
+                    rpc_client.start_notify(id, MessagesReceivedScope::default().into()).await.unwrap();
                 })
             }
             CryptixdPayloadOps::StopNotifyingUtxosChanged => {
@@ -11,8 +11,8 @@ use cryptix_hashes::Hash;
 use cryptix_wallet_pskt::bundle::Bundle;
 pub use kind::*;
 use pskb::{
-    bundle_from_pskt_generator, bundle_to_finalizer_stream, pskb_signer_for_address, pskt_to_pending_transaction, PSKBSigner,
-    PSKTGenerator,
+    bundle_from_pskt_generator, bundle_to_finalizer_stream, bundle_with_token_vault_input, pskb_signer_for_address,
+    pskt_to_pending_transaction, PSKBSigner, PSKTGenerator,
 };
 pub use variants::*;
 
@@ -26,7 +26,7 @@ use crate::tx::PaymentOutput;
 use crate::tx::{
     FastSubmitOptions, Fees, Generator, GeneratorSettings, GeneratorSummary, PaymentDestination, PendingTransaction, Signer,
 };
-use crate::utxo::balance::{AtomicBalance, BalanceStrings};
+use crate::utxo::balance::{AtomicBalance, BalanceStrings, TokenBalance};
 use crate::utxo::UtxoContextBinding;
 use cryptix_bip32::{ChildNumber, ExtendedPrivateKey, PrivateKey};
 use cryptix_consensus_client::UtxoEntryReference;
@@ -126,6 +126,10 @@ pub trait Account: AnySync + Send + Sync + 'static {
         self.utxo_context().balance()
     }
 
+    fn token_balances(&self) -> Vec<TokenBalance> {
+        self.utxo_context().token_balances()
+    }
+
     fn balance_as_strings(&self, padding: Option<usize>) -> Result<BalanceStrings> {
         Ok(BalanceStrings::from((self.balance().as_ref(), &self.wallet().network_id()?.into(), padding)))
     }
@@ -272,6 +276,9 @@ pub trait Account: AnySync + Send + Sync + 'static {
         }
 
         self.utxo_context().update_balance().await?;
+        if let Err(err) = self.utxo_context().refresh_token_balances().await {
+            log_warn!("unable to refresh token balances: {err}");
+        }
 
         Ok(())
     }
@@ -381,6 +388,9 @@ pub trait Account: AnySync + Send + Sync + 'static {
         }
 
         self.utxo_context().update_balance().await?;
+        if let Err(err) = self.utxo_context().refresh_token_balances().await {
+            log_warn!("unable to refresh token balances: {err}");
+        }
 
         Ok(summaries)
     }
@@ -530,6 +540,52 @@ pub trait Account: AnySync + Send + Sync + 'static {
         bundle_from_pskt_generator(pskt_generator).await
     }
 
+    /// Creates a PSKB for an atomic token operation `payload`. Only `sender_address`
+    /// UTXOs fund the transaction, keeping the CAT auth input owned by the sender.
+    /// Liquidity trades pass the pool `vault_entry`, which is spent without a signature.
+    async fn pskb_from_token_generator(
+        self: Arc<Self>,
+        destination: PaymentDestination,
+        priority_fee_sompi: Fees,
+        payload: Vec<u8>,
+        sender_address: Address,
+        vault_entry: Option<UtxoEntryReference>,
+        wallet_secret: Secret,
+        payment_secret: Option<Secret>,
+        abortable: &Abortable,
+    ) -> Result<Bundle, Error> {
+        let vault_outpoint = vault_entry.as_ref().map(|entry| (&entry.outpoint()).into());
+        // The pool vault is not part of the account UTXO context, so it can not be tracked there.
+        let settings = if vault_entry.is_some() {
+            GeneratorSettings::try_new_with_account_and_priority_untracked(
+                self.clone().as_dyn_arc(),
+                destination,
+                priority_fee_sompi,
+                Some(payload),
+                Some(sender_address),
+                vault_entry.map(|entry| vec![entry]),
+            )?
+        } else {
+            GeneratorSettings::try_new_with_account_and_priority(
+                self.clone().as_dyn_arc(),
+                destination,
+                priority_fee_sompi,
+                Some(payload),
+                Some(sender_address),
+                None,
+            )?
+        };
+        let keydata = self.prv_key_data(wallet_secret).await?;
+        let signer = Arc::new(PSKBSigner::new(self.clone().as_dyn_arc(), keydata, payment_secret));
+        let generator = Generator::try_new(settings, None, Some(abortable))?;
+        let pskt_generator = PSKTGenerator::new(generator, signer, self.wallet().address_prefix()?);
+        let bundle = bundle_from_pskt_generator(pskt_generator).await?;
+        match vault_outpoint {
+            Some(vault_outpoint) => bundle_with_token_vault_input(bundle, vault_outpoint),
+            None => Ok(bundle),
+        }
+    }
+
     async fn pskb_sign(
         self: Arc<Self>,
         bundle: &Bundle,
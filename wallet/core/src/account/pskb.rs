@@ -1,11 +1,11 @@
 pub use crate::error::Error;
 use crate::imports::*;
-use crate::tx::PaymentOutputs;
+use crate::tx::{PaymentOutputs, ScriptPaymentOutput, ScriptPaymentOutputs};
 use cryptix_bip32::{DerivationPath, KeyFingerprint, PrivateKey};
 use cryptix_consensus_client::UtxoEntry as ClientUTXO;
 use cryptix_consensus_core::hashing::sighash::{calc_schnorr_signature_hash, SigHashReusedValues};
 use cryptix_consensus_core::tx::VerifiableTransaction;
-use cryptix_consensus_core::tx::{TransactionInput, UtxoEntry};
+use cryptix_consensus_core::tx::{TransactionInput, TransactionOutpoint, UtxoEntry};
 use cryptix_txscript::extract_script_pub_key_address;
 use cryptix_txscript::opcodes::codes::OpData65;
 use cryptix_txscript::script_builder::ScriptBuilder;
@@ -180,6 +180,9 @@ pub async fn pskb_signer_for_address(
 
     for pskt_inner in bundle.iter().cloned() {
         let pskt: PSKT<Signer> = PSKT::from(pskt_inner);
+        // Token operations are reviewed before signing, the liquidity vault input takes no signature.
+        pskt.verify_token_op().map_err(cryptix_wallet_pskt::error::Error::from)?;
+        let token_op = pskt.global.token_op;
 
         let mut sign = |signer_pskt: PSKT<Signer>| {
             signer_pskt
@@ -188,21 +191,25 @@ pub async fn pskb_signer_for_address(
                         .inputs
                         .iter()
                         .enumerate()
+                        .filter(|(idx, _input)| !token_op.is_some_and(|token_op| token_op.is_vault_input(*idx)))
                         .map(|(idx, _input)| {
                             let hash = calc_schnorr_signature_hash(&tx.as_verifiable(), idx, sighash[idx], &mut reused_values);
                             let msg = secp256k1::Message::from_digest_slice(hash.as_bytes().as_slice()).unwrap();
 
                             // When address represents a locked UTXO, no private key is available.
                             // Instead, use the account receive address' private key.
-                            let address: &Address = match sign_for_address {
-                                Some(address) => address,
-                                None => addresses.get(idx).expect("Input indexed address"),
+                            let address: Address = match sign_for_address {
+                                Some(address) => address.clone(),
+                                None => tx.entries[idx]
+                                    .as_ref()
+                                    .and_then(|entry| extract_script_pub_key_address(&entry.script_public_key, network_id.into()).ok())
+                                    .expect("Input indexed address"),
                             };
 
-                            let public_key = signer.public_key(address).expect("Public key for input indexed address");
+                            let public_key = signer.public_key(&address).expect("Public key for input indexed address");
 
                             Ok(SignInputOk {
-                                signature: Signature::Schnorr(signer.sign_schnorr(address, msg).unwrap()),
+                                signature: Signature::Schnorr(signer.sign_schnorr(&address, msg).unwrap()),
                                 pub_key: public_key,
                                 key_source: Some(KeySource { key_fingerprint, derivation_path: derivation_path.clone() }),
                             })
@@ -216,6 +223,26 @@ pub async fn pskb_signer_for_address(
     Ok(signed_bundle)
 }
 
+/// Marks the input spending `vault_outpoint` as the liquidity pool vault input of
+/// the token operation. A liquidity transition has to fit a single transaction.
+pub fn bundle_with_token_vault_input(bundle: Bundle, vault_outpoint: TransactionOutpoint) -> Result<Bundle, Error> {
+    if bundle.0.len() != 1 {
+        return Err(Error::custom("liquidity transition requires a single final transaction; consolidate sender UTXOs and retry"));
+    }
+
+    let mut marked = Bundle::new();
+    for pskt_inner in bundle.iter().cloned() {
+        let index = pskt_inner
+            .inputs
+            .iter()
+            .position(|input| input.previous_outpoint == vault_outpoint)
+            .ok_or_else(|| Error::custom("liquidity vault input is missing from the transaction"))?;
+        let pskt = PSKT::<Creator>::from(pskt_inner).constructor().updater().token_vault_input(index)?;
+        marked.add_pskt(pskt);
+    }
+    Ok(marked)
+}
+
 pub fn finalize_pskt_one_or_more_sig_and_redeem_script(pskt: PSKT<Finalizer>) -> Result<PSKT<Finalizer>, Error> {
     let result = pskt.finalize_sync(|inner: &Inner| -> Result<Vec<Vec<u8>>, String> {
         Ok(inner
@@ -301,7 +328,7 @@ pub fn pskt_to_pending_transaction(
             if let Some(ue) = input.clone().utxo_entry {
                 return Some(UtxoEntryReference {
                     utxo: Arc::new(ClientUTXO {
-                        address: Some(extract_script_pub_key_address(&ue.script_public_key, network_id.into()).unwrap()),
+                        address: extract_script_pub_key_address(&ue.script_public_key, network_id.into()).ok(),
                         amount: ue.amount,
                         outpoint: input.previous_outpoint.into(),
                         script_public_key: ue.script_public_key,
@@ -315,13 +342,18 @@ pub fn pskt_to_pending_transaction(
         .collect();
 
     let output: Vec<cryptix_consensus_core::tx::TransactionOutput> = signed_tx.outputs.clone();
-    let recipient = extract_script_pub_key_address(&output[0].script_public_key, network_id.into())?;
     let fee_u: u64 = 0;
 
     let utxo_iterator: Box<dyn Iterator<Item = UtxoEntryReference> + Send + Sync + 'static> =
         Box::new(utxo_entries_ref.clone().into_iter());
 
-    let final_transaction_destination = PaymentDestination::PaymentOutputs(PaymentOutputs::from((recipient.clone(), output[0].value)));
+    // Token liquidity transitions pay to the non-standard pool vault script first.
+    let final_transaction_destination = match extract_script_pub_key_address(&output[0].script_public_key, network_id.into()) {
+        Ok(recipient) => PaymentDestination::PaymentOutputs(PaymentOutputs::from((recipient, output[0].value))),
+        Err(_) => PaymentDestination::from(ScriptPaymentOutputs {
+            outputs: vec![ScriptPaymentOutput::new(output[0].value, output[0].script_public_key.clone())],
+        }),
+    };
 
     let settings = GeneratorSettings {
         network_id,
@@ -6,7 +6,7 @@
 //!
 
 use crate::imports::*;
-use crate::tx::{Fees, GeneratorSummary, PaymentDestination};
+use crate::tx::{Fees, GeneratorSummary, PaymentDestination, TokenOperation};
 use cryptix_addresses::Address;
 
 #[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
@@ -646,6 +646,27 @@ pub struct AccountsSendResponse {
     pub basechain_submitted: bool,
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountsTokenOperationRequest {
+    pub account_id: AccountId,
+    pub wallet_secret: Secret,
+    pub payment_secret: Option<Secret>,
+    pub sender_address: Option<Address>,
+    pub operation: TokenOperation,
+    pub nonce: Option<u64>,
+    pub priority_fee_sompi: Fees,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountsTokenOperationResponse {
+    pub generator_summary: GeneratorSummary,
+    pub transaction_ids: Vec<TransactionId>,
+    pub sender_owner_id: String,
+    pub nonce: u64,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AccountsTransferRequest {
@@ -409,6 +409,16 @@ pub trait WalletApi: Send + Sync + AnySync {
     /// well `transaction_ids` containing a list of submitted transaction ids.
     async fn accounts_send_call(self: Arc<Self>, request: AccountsSendRequest) -> Result<AccountsSendResponse>;
 
+    /// Submit a Cryptix Atomic token operation signed by `sender_address` (the
+    /// account receive address when not set). Owner ids and, unless supplied,
+    /// the sender nonce are resolved through the node RPC. Returns an
+    /// [`AccountsTokenOperationResponse`] with the submitted transaction ids
+    /// and the nonce used.
+    async fn accounts_token_operation_call(
+        self: Arc<Self>,
+        request: AccountsTokenOperationRequest,
+    ) -> Result<AccountsTokenOperationResponse>;
+
     /// Transfer funds to another account. Returns an [`AccountsTransferResponse`]
     /// struct that contains a [`GeneratorSummary`] as well `transaction_ids`
     /// containing a list of submitted transaction ids. Unlike funds sent to an
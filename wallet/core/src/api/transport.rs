@@ -108,6 +108,7 @@ impl WalletApi for WalletClient {
         AccountsUtxos,
         AccountsCreateNewAddress,
         AccountsSend,
+        AccountsTokenOperation,
         AccountsTransfer,
         AccountsEstimate,
         TransactionsDataGet,
@@ -188,6 +189,7 @@ impl WalletServer {
         AccountsUtxos,
         AccountsCreateNewAddress,
         AccountsSend,
+        AccountsTokenOperation,
         AccountsTransfer,
         AccountsEstimate,
         TransactionsDataGet,
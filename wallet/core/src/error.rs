@@ -286,6 +286,9 @@ pub enum Error {
     #[error("Invalid messenger payload envelope: {details}")]
     WalletInvalidMessengerEnvelope { details: String },
 
+    #[error("Messenger: {0}")]
+    Messenger(String),
+
     #[error("Storage mass exceeds maximum")]
     StorageMassExceedsMaximumTransactionMass { storage_mass: u64 },
 
@@ -7,6 +7,7 @@
 use crate::imports::*;
 use crate::storage::{Hint, PrvKeyDataInfo, StorageDescriptor, TransactionRecord, WalletDescriptor};
 use crate::utxo::context::UtxoContextId;
+use crate::utxo::TokenBalance;
 use transaction::TransactionRecordNotification;
 
 /// Sync state of the cryptixd node
@@ -213,6 +214,16 @@ pub enum Events {
         /// contain a developer-assigned internal id.
         id: UtxoContextId,
     },
+    /// UtxoContext (Account) Cryptix Atomic token balance update.
+    /// Carries the full set of non-zero token balances of the
+    /// UtxoContext, one entry per asset.
+    TokenBalance {
+        balances: Vec<TokenBalance>,
+        /// If UtxoContext is bound to a Runtime Account, this
+        /// field will contain the account id. Otherwise, it will
+        /// contain a developer-assigned internal id.
+        id: UtxoContextId,
+    },
     /// Periodic metrics updates (on-request)
     Metrics {
         #[serde(rename = "networkId")]
@@ -277,6 +288,7 @@ pub enum EventKind {
     Maturity,
     Discovery,
     Balance,
+    TokenBalance,
     Metrics,
     Error,
 }
@@ -313,6 +325,7 @@ impl From<&Events> for EventKind {
             Events::Maturity { .. } => EventKind::Maturity,
             Events::Discovery { .. } => EventKind::Discovery,
             Events::Balance { .. } => EventKind::Balance,
+            Events::TokenBalance { .. } => EventKind::TokenBalance,
             Events::Metrics { .. } => EventKind::Metrics,
             Events::Error { .. } => EventKind::Error,
         }
@@ -352,6 +365,7 @@ impl FromStr for EventKind {
             "maturity" => Ok(EventKind::Maturity),
             "discovery" => Ok(EventKind::Discovery),
             "balance" => Ok(EventKind::Balance),
+            "token-balance" => Ok(EventKind::TokenBalance),
             "metrics" => Ok(EventKind::Metrics),
             "error" => Ok(EventKind::Error),
             _ => Err(Error::custom("Invalid event kind")),
@@ -399,6 +413,7 @@ impl std::fmt::Display for EventKind {
             EventKind::Maturity => "maturity",
             EventKind::Discovery => "discovery",
             EventKind::Balance => "balance",
+            EventKind::TokenBalance => "token-balance",
             EventKind::Metrics => "metrics",
             EventKind::Error => "error",
         };
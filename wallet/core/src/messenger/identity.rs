@@ -0,0 +1,139 @@
+//!
+//! Messenger identity derived from the wallet private key data.
+//!
+
+use crate::cryptobox::{CryptoBox, PublicKey, SecretKey};
+use crate::imports::*;
+use crate::messenger::payload::{compose_message, messenger_payload_limits, ComposedMessage, MESSENGER_FLAG_ENCRYPTED};
+use crate::storage::PrvKeyData;
+use crate::tx::{MessengerEnvelopeV1, MessengerSenderKind, MESSENGER_RECIPIENT_TAG_LEN};
+use sha2::{Digest, Sha256};
+
+const MESSENGER_IDENTITY_DOMAIN: &[u8] = b"cryptix-messenger-identity-v1";
+
+/// CryptoBox key pair used to encrypt, decrypt and address messenger payloads.
+#[derive(Clone)]
+pub struct MessengerIdentity {
+    secret_key: SecretKey,
+    public_key: PublicKey,
+}
+
+impl MessengerIdentity {
+    pub fn from_secret_bytes(secret_bytes: [u8; 32]) -> Self {
+        let secret_key = SecretKey::from(secret_bytes);
+        let public_key = secret_key.public_key();
+        Self { secret_key, public_key }
+    }
+
+    /// Derives the identity from the root private key of the key data (the extended private key
+    /// of BIP32 key data or the secret key of keypair key data), so all accounts sharing the same
+    /// key data share one messenger identity.
+    pub fn try_from_prv_key_data(prv_key_data: &PrvKeyData, payment_secret: Option<&Secret>) -> Result<Self> {
+        let payload = prv_key_data.payload.decrypt(payment_secret)?;
+        let private_key_bytes = match payload.as_secret_key()? {
+            Some(secret_key) => Zeroizing::new(secret_key.secret_bytes()),
+            None => Zeroizing::new(payload.get_xprv(payment_secret)?.private_key().secret_bytes()),
+        };
+
+        let mut hasher = Sha256::new();
+        hasher.update(MESSENGER_IDENTITY_DOMAIN);
+        hasher.update(private_key_bytes.as_slice());
+        let secret_bytes = Zeroizing::new(<[u8; 32]>::from(hasher.finalize()));
+        Ok(Self::from_secret_bytes(*secret_bytes))
+    }
+
+    /// Derives the identity of the private key data of `account`.
+    pub async fn try_from_account(account: &Arc<dyn Account>, wallet_secret: Secret, payment_secret: Option<&Secret>) -> Result<Self> {
+        let prv_key_data = account.prv_key_data(wallet_secret).await?;
+        Self::try_from_prv_key_data(&prv_key_data, payment_secret)
+    }
+
+    pub fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+
+    /// Recipient tag under which messages for this identity are indexed.
+    pub fn recipient_tag(&self) -> [u8; MESSENGER_RECIPIENT_TAG_LEN] {
+        Self::recipient_tag_for(&self.public_key)
+    }
+
+    /// Returns the first 16 bytes of `sha256(public_key)`.
+    pub fn recipient_tag_for(public_key: &PublicKey) -> [u8; MESSENGER_RECIPIENT_TAG_LEN] {
+        let digest = Sha256::digest(public_key.as_bytes());
+        let mut recipient_tag = [0u8; MESSENGER_RECIPIENT_TAG_LEN];
+        recipient_tag.copy_from_slice(&digest[..MESSENGER_RECIPIENT_TAG_LEN]);
+        recipient_tag
+    }
+
+    /// Composes a CXM v1 payload for `recipient` whose body is the CryptoBox ciphertext of
+    /// `plaintext`, with this identity as the pubkey sender.
+    pub fn encrypt_message(&self, recipient: &PublicKey, msg_type: u8, plaintext: &[u8]) -> Result<ComposedMessage> {
+        let max_plaintext_bytes = messenger_payload_limits().max_cryptobox_plaintext_bytes;
+        if plaintext.len() > max_plaintext_bytes {
+            return Err(Error::Messenger(format!(
+                "message is {} bytes, encrypted messages are limited to {max_plaintext_bytes} bytes",
+                plaintext.len()
+            )));
+        }
+
+        let body = CryptoBox::new(&self.secret_key, recipient).encrypt(plaintext)?;
+        compose_message(
+            msg_type,
+            MESSENGER_FLAG_ENCRYPTED,
+            Self::recipient_tag_for(recipient),
+            MessengerSenderKind::Pubkey,
+            self.public_key.as_bytes(),
+            &body,
+        )
+    }
+
+    /// Decrypts the body of an encrypted envelope sent to this identity by a pubkey sender.
+    pub fn decrypt_message(&self, envelope: &MessengerEnvelopeV1<'_>) -> Result<Vec<u8>> {
+        if envelope.header.flags & MESSENGER_FLAG_ENCRYPTED == 0 {
+            return Err(Error::Messenger("message body is not encrypted".to_string()));
+        }
+        if envelope.header.sender_kind != MessengerSenderKind::Pubkey as u8 {
+            return Err(Error::Messenger("only messages from pubkey senders can be decrypted".to_string()));
+        }
+
+        let sender = PublicKey::from(envelope.header.sender_data);
+        CryptoBox::new(&self.secret_key, &sender).decrypt(envelope.body)
+    }
+}
+
+/// Parses a hex encoded 32-byte messenger public key.
+pub fn parse_messenger_public_key(public_key: &str) -> Result<PublicKey> {
+    let public_key = public_key.trim();
+    let bytes = Vec::<u8>::from_hex(public_key.strip_prefix("0x").unwrap_or(public_key))?;
+    Ok(PublicKey::from_slice(&bytes)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tx::parse_messenger_v1;
+
+    #[test]
+    fn encrypted_message_round_trip() {
+        let alice = MessengerIdentity::from_secret_bytes([1; 32]);
+        let bob = MessengerIdentity::from_secret_bytes([2; 32]);
+        let eve = MessengerIdentity::from_secret_bytes([3; 32]);
+
+        let message = alice.encrypt_message(bob.public_key(), 1, b"hello bob").unwrap();
+        let envelope = parse_messenger_v1(&message.payload).unwrap();
+        assert_eq!(envelope.header.recipient_tag, bob.recipient_tag());
+        assert_eq!(&envelope.header.sender_data, alice.public_key().as_bytes());
+        assert_eq!(bob.decrypt_message(&envelope).unwrap(), b"hello bob");
+        assert!(eve.decrypt_message(&envelope).is_err());
+    }
+
+    #[test]
+    fn rejects_oversized_plaintext() {
+        let alice = MessengerIdentity::from_secret_bytes([1; 32]);
+        let bob = MessengerIdentity::from_secret_bytes([2; 32]);
+        let max_plaintext_bytes = messenger_payload_limits().max_cryptobox_plaintext_bytes;
+
+        assert!(alice.encrypt_message(bob.public_key(), 1, &vec![0; max_plaintext_bytes]).is_ok());
+        assert!(alice.encrypt_message(bob.public_key(), 1, &vec![0; max_plaintext_bytes + 1]).is_err());
+    }
+}
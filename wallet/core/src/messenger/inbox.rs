@@ -0,0 +1,253 @@
+//!
+//! Sending messages and syncing the inbox from the node message index.
+//!
+
+use crate::cryptobox::PublicKey;
+use crate::imports::*;
+use crate::messenger::identity::MessengerIdentity;
+use crate::messenger::payload::{envelope_error, MESSENGER_MSG_TYPE_TEXT};
+use crate::messenger::store::{ConversationStore, MessageDirection, StoredMessage};
+use crate::tx::{
+    Fees, GeneratorSummary, MessengerEnvelopeV1, MessengerEnvelopeV1Header, PaymentDestination, PaymentOutput,
+    MESSENGER_SENDER_DATA_LEN,
+};
+use cryptix_consensus_core::constants::SOMPI_PER_CRYPTIX;
+use cryptix_hashes::Hash;
+use cryptix_rpc_core::{GetMessagesByRecipientTagRequest, RpcMessageEntry};
+use workflow_core::abortable::Abortable;
+use workflow_core::time::unixtime_as_millis_u64;
+
+/// Number of message index entries requested per page while syncing the inbox.
+const INBOX_SYNC_PAGE_LIMIT: u32 = 500;
+
+/// Value of the output a message transaction pays back to the sending account. It is kept at
+/// 1 CPAY so the storage mass of the output stays well below the standard transaction mass limit.
+pub const MESSENGER_CARRIER_OUTPUT_SOMPI: u64 = SOMPI_PER_CRYPTIX;
+
+/// Destination of a message transaction: a single carrier output to `carrier_address`.
+pub fn message_destination(carrier_address: Address) -> PaymentDestination {
+    PaymentDestination::from(PaymentOutput::new(carrier_address, MESSENGER_CARRIER_OUTPUT_SOMPI))
+}
+
+/// Encrypts `text` for `recipient` and sends it as the payload of a transaction paying a
+/// [`MESSENGER_CARRIER_OUTPUT_SOMPI`] output to the change address of `account`.
+///
+/// The message is recorded in `store` as an outgoing message once the transaction is submitted.
+#[allow(clippy::too_many_arguments)]
+pub async fn send_message(
+    account: &Arc<dyn Account>,
+    identity: &MessengerIdentity,
+    store: &ConversationStore,
+    recipient: &PublicKey,
+    text: &str,
+    priority_fee_sompi: Fees,
+    wallet_secret: Secret,
+    payment_secret: Option<Secret>,
+    abortable: &Abortable,
+) -> Result<(GeneratorSummary, Vec<Hash>)> {
+    let message = identity.encrypt_message(recipient, MESSENGER_MSG_TYPE_TEXT, text.as_bytes())?;
+    let (summary, ids, _fast_summary) = account
+        .clone()
+        .send(
+            message_destination(account.change_address()?),
+            priority_fee_sompi,
+            Some(message.payload),
+            None,
+            None,
+            wallet_secret,
+            payment_secret,
+            abortable,
+            None,
+        )
+        .await?;
+
+    store.insert(StoredMessage {
+        dedup_key: message.dedup_key,
+        transaction_id: ids.last().copied(),
+        direction: MessageDirection::Outgoing,
+        peer: recipient.as_bytes().as_slice().to_hex(),
+        msg_type: MESSENGER_MSG_TYPE_TEXT,
+        timestamp: unixtime_as_millis_u64(),
+        text: text.to_string(),
+        read: true,
+    });
+    store.try_store().await?;
+
+    Ok((summary, ids))
+}
+
+/// Fetches the messages sent to `identity` from the node message index, starting at the store
+/// cursor, and records the ones that decrypt as incoming messages.
+///
+/// Messages already in the store (same sender and envelope nonce) are skipped, as are envelopes
+/// that are not encrypted for this identity. Returns the number of new messages.
+pub async fn sync_inbox(rpc: &Arc<DynRpcApi>, identity: &MessengerIdentity, store: &ConversationStore) -> Result<usize> {
+    let recipient_tag = identity.recipient_tag().as_slice().to_hex();
+    let mut received = 0;
+    loop {
+        let request = GetMessagesByRecipientTagRequest::new(recipient_tag.clone(), None, store.cursor(), INBOX_SYNC_PAGE_LIMIT);
+        let response = rpc.get_messages_by_recipient_tag_call(None, request).await?;
+        for entry in response.entries.iter() {
+            match incoming_message(identity, store, entry) {
+                Ok(Some(message)) => received += usize::from(store.insert(message)),
+                Ok(None) => {}
+                Err(err) => log_debug!("messenger: skipping message {}: {err}", entry.transaction_id),
+            }
+            store.set_cursor(entry.sequence);
+        }
+        if response.entries.is_empty() || response.next_cursor.is_none() {
+            break;
+        }
+    }
+    store.try_store().await?;
+
+    Ok(received)
+}
+
+/// Decrypts a message index entry, or returns `None` if the message is already stored.
+fn incoming_message(
+    identity: &MessengerIdentity,
+    store: &ConversationStore,
+    entry: &RpcMessageEntry,
+) -> Result<Option<StoredMessage>> {
+    let sender_data = Vec::<u8>::from_hex(&entry.sender.data)?;
+    if sender_data.len() > MESSENGER_SENDER_DATA_LEN {
+        return Err(Error::Messenger(format!("sender data is {} bytes", sender_data.len())));
+    }
+    let mut padded_sender_data = [0u8; MESSENGER_SENDER_DATA_LEN];
+    padded_sender_data[..sender_data.len()].copy_from_slice(&sender_data);
+    let header = MessengerEnvelopeV1Header::new(
+        entry.msg_type,
+        entry.flags,
+        fixed_from_hex("recipient tag", &entry.recipient_tag)?,
+        fixed_from_hex("nonce", &entry.nonce)?,
+        entry.sender.kind,
+        sender_data.len() as u8,
+        padded_sender_data,
+    )
+    .map_err(envelope_error)?;
+    let body = Vec::<u8>::from_hex(&entry.body_hex)?;
+    let envelope = MessengerEnvelopeV1 { header, body: &body };
+
+    let dedup_key = envelope.secondary_dedup_key().map_err(envelope_error)?;
+    if store.contains(&dedup_key) {
+        return Ok(None);
+    }
+    let plaintext = identity.decrypt_message(&envelope)?;
+
+    Ok(Some(StoredMessage {
+        dedup_key,
+        transaction_id: Some(entry.transaction_id),
+        direction: MessageDirection::Incoming,
+        peer: sender_data.to_hex(),
+        msg_type: entry.msg_type,
+        timestamp: entry.accepting_block_time,
+        text: String::from_utf8_lossy(&plaintext).into_owned(),
+        read: false,
+    }))
+}
+
+fn fixed_from_hex<const N: usize>(field_name: &str, value: &str) -> Result<[u8; N]> {
+    let bytes = Vec::<u8>::from_hex(value)?;
+    bytes.try_into().map_err(|bytes: Vec<u8>| Error::Messenger(format!("{field_name} must be {N} bytes, got {}", bytes.len())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messenger::payload::ComposedMessage;
+    use crate::storage::local::Storage;
+    use crate::tests::RpcCoreMock;
+    use crate::tx::{parse_messenger_v1, Generator, GeneratorSettings};
+    use crate::utxo::UtxoEntryReference;
+    use cryptix_rpc_core::RpcMessageSender;
+    use cryptix_txscript::pay_to_address_script;
+
+    fn message_entry(sequence: u64, message: &ComposedMessage) -> RpcMessageEntry {
+        let envelope = parse_messenger_v1(&message.payload).unwrap();
+        let header = envelope.header;
+        let sender_data = &header.sender_data[..header.sender_len as usize];
+        RpcMessageEntry {
+            sequence,
+            transaction_id: Hash::from_u64_word(sequence),
+            accepting_block_hash: Hash::from_u64_word(sequence),
+            accepting_daa_score: sequence,
+            accepting_block_time: 1_000 + sequence,
+            msg_type: header.msg_type,
+            flags: header.flags,
+            recipient_tag: header.recipient_tag.as_slice().to_hex(),
+            nonce: header.nonce.as_slice().to_hex(),
+            sender: RpcMessageSender { kind: header.sender_kind, data: sender_data.to_hex() },
+            body_hex: envelope.body.to_hex(),
+        }
+    }
+
+    #[test]
+    fn message_transaction_pays_a_carrier_output_and_sender_fees() {
+        let network_id = NetworkId::new(NetworkType::Testnet);
+        let alice = MessengerIdentity::from_secret_bytes([1; 32]);
+        let bob = MessengerIdentity::from_secret_bytes([2; 32]);
+        let message = alice.encrypt_message(bob.public_key(), MESSENGER_MSG_TYPE_TEXT, b"hello bob").unwrap();
+        let carrier_address = Address::new(Prefix::Testnet, cryptix_addresses::Version::PubKey, &[1u8; 32]);
+
+        let utxo_entries = [10, 20, 30, 40].map(|cpay| UtxoEntryReference::simulated(cpay * SOMPI_PER_CRYPTIX));
+        let settings = GeneratorSettings {
+            network_id,
+            multiplexer: None,
+            sig_op_count: 1,
+            minimum_signatures: 1,
+            change_address: carrier_address.clone(),
+            utxo_iterator: Box::new(utxo_entries.into_iter()),
+            source_utxo_context: None,
+            priority_utxo_entries: None,
+            destination_utxo_context: None,
+            final_transaction_priority_fee: Fees::SenderPays(0),
+            final_transaction_destination: message_destination(carrier_address.clone()),
+            final_transaction_payload: Some(message.payload.clone()),
+        };
+        let generator = Generator::try_new(settings, None, None).unwrap();
+        let pending = generator.generate_transaction().unwrap().expect("message transaction");
+        assert!(generator.generate_transaction().unwrap().is_none());
+
+        let transaction = pending.transaction();
+        assert_eq!(transaction.payload, message.payload);
+        assert_eq!(transaction.outputs[0].value, MESSENGER_CARRIER_OUTPUT_SOMPI);
+        assert_eq!(transaction.outputs[0].script_public_key, pay_to_address_script(&carrier_address));
+        // the account is not swept into the message transaction
+        assert!(transaction.inputs.len() < 4);
+        assert!(pending.fees() > 0);
+        assert_eq!(pending.aggregate_input_value(), MESSENGER_CARRIER_OUTPUT_SOMPI + pending.change_value() + pending.fees());
+    }
+
+    #[tokio::test]
+    async fn sync_inbox_stores_each_message_once() {
+        let alice = MessengerIdentity::from_secret_bytes([1; 32]);
+        let bob = MessengerIdentity::from_secret_bytes([2; 32]);
+        let eve = MessengerIdentity::from_secret_bytes([3; 32]);
+        let first = alice.encrypt_message(bob.public_key(), MESSENGER_MSG_TYPE_TEXT, b"hello bob").unwrap();
+        let to_eve = alice.encrypt_message(eve.public_key(), MESSENGER_MSG_TYPE_TEXT, b"hello eve").unwrap();
+        let second = alice.encrypt_message(bob.public_key(), MESSENGER_MSG_TYPE_TEXT, b"second").unwrap();
+
+        let rpc_mock = Arc::new(RpcCoreMock::new());
+        rpc_mock.set_messages(vec![message_entry(1, &first), message_entry(2, &to_eve), message_entry(3, &second)]);
+        let rpc: Arc<DynRpcApi> = rpc_mock.clone();
+
+        let folder = std::env::temp_dir().join(format!("cryptix-messenger-inbox-test-{}", std::process::id()));
+        let storage = Storage::try_new_with_folder(folder.to_str().unwrap(), "inbox.messenger").unwrap();
+        let store = ConversationStore::new_with_storage(storage);
+
+        assert_eq!(sync_inbox(&rpc, &bob, &store).await.unwrap(), 2);
+        assert_eq!(store.cursor(), 3);
+        let conversation = store.conversation(&alice.public_key().as_bytes().as_slice().to_hex());
+        assert_eq!(conversation.iter().map(|message| message.text.as_str()).collect::<Vec<_>>(), vec!["hello bob", "second"]);
+        assert!(conversation.iter().all(|message| message.direction == MessageDirection::Incoming && !message.read));
+        assert_eq!(conversation[0].transaction_id, Some(Hash::from_u64_word(1)));
+
+        // Replaying the index from the start does not duplicate stored messages.
+        store.set_cursor(0);
+        assert_eq!(sync_inbox(&rpc, &bob, &store).await.unwrap(), 0);
+        assert_eq!(store.conversation(&alice.public_key().as_bytes().as_slice().to_hex()).len(), 2);
+
+        std::fs::remove_dir_all(folder).ok();
+    }
+}
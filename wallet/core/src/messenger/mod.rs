@@ -0,0 +1,14 @@
+//!
+//! Native messenger API: CXM v1 message composition, CryptoBox encryption,
+//! sending, inbox synchronization and a local conversation store.
+//!
+
+pub mod identity;
+pub mod inbox;
+pub mod payload;
+pub mod store;
+
+pub use self::identity::*;
+pub use self::inbox::*;
+pub use self::payload::*;
+pub use self::store::*;
@@ -0,0 +1,127 @@
+//!
+//! Messenger payload limits and CXM v1 message composition.
+//!
+
+use crate::imports::*;
+use crate::tx::{
+    secondary_dedup_key, serialize_messenger_v1, validate_wallet_payload, MessengerEnvelopeError, MessengerEnvelopeV1Header,
+    MessengerSenderKind, MESSENGER_ENVELOPE_V1_HEADER_LEN, MESSENGER_NONCE_LEN, MESSENGER_RECIPIENT_TAG_LEN,
+    MESSENGER_SENDER_DATA_LEN, WALLET_PAYLOAD_HARD_LIMIT_BYTES,
+};
+use cryptix_hashes::Hash;
+
+pub const CRYPTOBOX_NONCE_BYTES: usize = 24;
+pub const CRYPTOBOX_TAG_BYTES: usize = 16;
+pub const CRYPTOBOX_OVERHEAD_BYTES: usize = CRYPTOBOX_NONCE_BYTES + CRYPTOBOX_TAG_BYTES;
+
+/// Message type of the text messages sent by the wallet messenger.
+pub const MESSENGER_MSG_TYPE_TEXT: u8 = 1;
+/// Envelope flag set when the body is a CryptoBox ciphertext.
+pub const MESSENGER_FLAG_ENCRYPTED: u8 = 0x01;
+
+/// Payload size limits and practical budgeting for messenger payloads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MessengerPayloadLimits {
+    /// Wallet v1 hard cap for total payload bytes.
+    pub max_payload_bytes: usize,
+    /// Messenger v1 fixed header length.
+    pub header_bytes: usize,
+    /// Maximum bytes available for the messenger body.
+    pub max_body_bytes: usize,
+    /// CryptoBox ciphertext overhead in bytes (nonce + authentication tag).
+    pub cryptobox_overhead_bytes: usize,
+    /// Maximum plaintext bytes when the body stores a CryptoBox ciphertext.
+    pub max_cryptobox_plaintext_bytes: usize,
+}
+
+pub fn messenger_payload_limits() -> MessengerPayloadLimits {
+    let max_body_bytes = WALLET_PAYLOAD_HARD_LIMIT_BYTES.saturating_sub(MESSENGER_ENVELOPE_V1_HEADER_LEN);
+    MessengerPayloadLimits {
+        max_payload_bytes: WALLET_PAYLOAD_HARD_LIMIT_BYTES,
+        header_bytes: MESSENGER_ENVELOPE_V1_HEADER_LEN,
+        max_body_bytes,
+        cryptobox_overhead_bytes: CRYPTOBOX_OVERHEAD_BYTES,
+        max_cryptobox_plaintext_bytes: max_body_bytes.saturating_sub(CRYPTOBOX_OVERHEAD_BYTES),
+    }
+}
+
+/// A CXM v1 payload ready to be attached to a transaction.
+#[derive(Debug, Clone)]
+pub struct ComposedMessage {
+    pub payload: Vec<u8>,
+    pub nonce: [u8; MESSENGER_NONCE_LEN],
+    /// Secondary dedup key `sha256(sender || nonce)` of the envelope.
+    pub dedup_key: Hash,
+}
+
+/// Builds a CXM v1 payload with a random envelope nonce.
+///
+/// `sender_data` holds the significant sender bytes: 32 for a pubkey sender, 16 for a ref sender.
+pub fn compose_message(
+    msg_type: u8,
+    flags: u8,
+    recipient_tag: [u8; MESSENGER_RECIPIENT_TAG_LEN],
+    sender_kind: MessengerSenderKind,
+    sender_data: &[u8],
+    body: &[u8],
+) -> Result<ComposedMessage> {
+    let sender_len = match sender_kind {
+        MessengerSenderKind::Pubkey => MESSENGER_SENDER_DATA_LEN,
+        MessengerSenderKind::Ref => 16,
+    };
+    if sender_data.len() != sender_len {
+        return Err(Error::Messenger(format!(
+            "sender data for {sender_kind:?} senders must be {sender_len} bytes, got {}",
+            sender_data.len()
+        )));
+    }
+    let mut padded_sender_data = [0u8; MESSENGER_SENDER_DATA_LEN];
+    padded_sender_data[..sender_len].copy_from_slice(sender_data);
+
+    let nonce: [u8; MESSENGER_NONCE_LEN] = rand::random();
+    let header =
+        MessengerEnvelopeV1Header::new(msg_type, flags, recipient_tag, nonce, sender_kind as u8, sender_len as u8, padded_sender_data)
+            .map_err(envelope_error)?;
+    let payload = serialize_messenger_v1(&header, body).map_err(envelope_error)?;
+    validate_wallet_payload(Some(&payload))?;
+    let dedup_key = secondary_dedup_key(header.sender_kind, &header.sender_data, &header.nonce).map_err(envelope_error)?;
+
+    Ok(ComposedMessage { payload, nonce, dedup_key })
+}
+
+pub(crate) fn envelope_error(err: MessengerEnvelopeError) -> Error {
+    Error::WalletInvalidMessengerEnvelope { details: err.to_string() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tx::{classify_messenger_payload, MessengerPayloadClass};
+
+    #[test]
+    fn limits_match_wallet_hard_limit() {
+        let limits = messenger_payload_limits();
+        assert_eq!(limits.max_body_bytes, 1968);
+        assert_eq!(limits.max_cryptobox_plaintext_bytes, 1928);
+    }
+
+    #[test]
+    fn composes_valid_envelope_with_random_nonce() {
+        let first = compose_message(7, 0, [1; 16], MessengerSenderKind::Ref, &[2; 16], b"hello").unwrap();
+        let second = compose_message(7, 0, [1; 16], MessengerSenderKind::Ref, &[2; 16], b"hello").unwrap();
+        assert_ne!(first.nonce, second.nonce);
+        assert_ne!(first.dedup_key, second.dedup_key);
+
+        match classify_messenger_payload(&first.payload).unwrap() {
+            MessengerPayloadClass::MessengerV1(envelope) => {
+                assert_eq!(envelope.header.msg_type, 7);
+                assert_eq!(envelope.header.sender_len, 16);
+                assert_eq!(envelope.body, b"hello");
+                assert_eq!(envelope.secondary_dedup_key().unwrap(), first.dedup_key);
+            }
+            other => panic!("expected a messenger v1 payload, got {other:?}"),
+        }
+
+        assert!(compose_message(7, 0, [1; 16], MessengerSenderKind::Pubkey, &[2; 16], b"hello").is_err());
+    }
+}
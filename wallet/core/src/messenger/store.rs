@@ -0,0 +1,192 @@
+//!
+//! Local conversation store of the wallet messenger.
+//!
+
+use crate::imports::*;
+use crate::storage::local::Storage;
+use cryptix_hashes::Hash;
+use workflow_store::fs;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MessageDirection {
+    Incoming,
+    Outgoing,
+}
+
+/// A conversation message kept in the local store.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StoredMessage {
+    /// Secondary dedup key `sha256(sender || nonce)` of the envelope.
+    pub dedup_key: Hash,
+    pub transaction_id: Option<TransactionId>,
+    pub direction: MessageDirection,
+    /// Hex encoded public key of the other side of the conversation.
+    pub peer: String,
+    pub msg_type: u8,
+    /// Accepting block time of incoming messages and send time of outgoing messages, in unix milliseconds.
+    pub timestamp: u64,
+    pub text: String,
+    pub read: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConversationSummary {
+    pub peer: String,
+    pub messages: usize,
+    pub unread: usize,
+    pub last_timestamp: u64,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ConversationStoreData {
+    /// Message index sequence the inbox has been synced to.
+    cursor: u64,
+    messages: Vec<StoredMessage>,
+}
+
+#[derive(Default)]
+struct Inner {
+    data: ConversationStoreData,
+    dedup_keys: HashSet<Hash>,
+}
+
+/// Messenger conversations of a wallet, persisted as JSON next to the wallet file.
+pub struct ConversationStore {
+    storage: Storage,
+    inner: Mutex<Inner>,
+}
+
+impl ConversationStore {
+    pub fn try_new(wallet_filename: &str) -> Result<Self> {
+        Ok(Self::new_with_storage(Storage::try_new(&format!("{wallet_filename}.messenger"))?))
+    }
+
+    pub fn new_with_storage(storage: Storage) -> Self {
+        Self { storage, inner: Mutex::new(Inner::default()) }
+    }
+
+    fn inner(&self) -> MutexGuard<'_, Inner> {
+        self.inner.lock().unwrap()
+    }
+
+    pub async fn try_load(&self) -> Result<()> {
+        let data = if self.storage.exists().await? {
+            fs::read_json::<ConversationStoreData>(self.storage.filename()).await?
+        } else {
+            ConversationStoreData::default()
+        };
+        let dedup_keys = data.messages.iter().map(|message| message.dedup_key).collect();
+        *self.inner() = Inner { data, dedup_keys };
+        Ok(())
+    }
+
+    pub async fn try_store(&self) -> Result<()> {
+        let data = serde_json::to_value(&self.inner().data)?;
+        self.storage.ensure_dir().await?;
+        fs::write_json(self.storage.filename(), &data).await?;
+        Ok(())
+    }
+
+    pub fn cursor(&self) -> u64 {
+        self.inner().data.cursor
+    }
+
+    pub fn set_cursor(&self, cursor: u64) {
+        self.inner().data.cursor = cursor;
+    }
+
+    pub fn contains(&self, dedup_key: &Hash) -> bool {
+        self.inner().dedup_keys.contains(dedup_key)
+    }
+
+    /// Adds `message` unless a message with the same dedup key is already stored.
+    /// Returns `true` if the message was added.
+    pub fn insert(&self, message: StoredMessage) -> bool {
+        let mut inner = self.inner();
+        if !inner.dedup_keys.insert(message.dedup_key) {
+            return false;
+        }
+        inner.data.messages.push(message);
+        true
+    }
+
+    /// Returns a summary of every conversation, most recent first.
+    pub fn conversations(&self) -> Vec<ConversationSummary> {
+        let inner = self.inner();
+        let mut conversations = HashMap::<&str, ConversationSummary>::new();
+        for message in inner.data.messages.iter() {
+            let summary = conversations.entry(message.peer.as_str()).or_insert_with(|| ConversationSummary {
+                peer: message.peer.clone(),
+                messages: 0,
+                unread: 0,
+                last_timestamp: 0,
+            });
+            summary.messages += 1;
+            summary.unread += usize::from(!message.read);
+            summary.last_timestamp = summary.last_timestamp.max(message.timestamp);
+        }
+        let mut conversations = conversations.into_values().collect::<Vec<_>>();
+        conversations.sort_by(|a, b| b.last_timestamp.cmp(&a.last_timestamp).then_with(|| a.peer.cmp(&b.peer)));
+        conversations
+    }
+
+    /// Returns the messages exchanged with `peer` in chronological order.
+    pub fn conversation(&self, peer: &str) -> Vec<StoredMessage> {
+        let peer = peer.to_lowercase();
+        let mut messages = self.inner().data.messages.iter().filter(|message| message.peer == peer).cloned().collect::<Vec<_>>();
+        messages.sort_by_key(|message| message.timestamp);
+        messages
+    }
+
+    /// Marks the messages received from `peer` as read and returns how many were unread.
+    pub fn mark_read(&self, peer: &str) -> usize {
+        let peer = peer.to_lowercase();
+        let mut inner = self.inner();
+        let mut marked = 0;
+        for message in inner.data.messages.iter_mut().filter(|message| message.peer == peer && !message.read) {
+            message.read = true;
+            marked += 1;
+        }
+        marked
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(dedup_byte: u8, peer: &str, timestamp: u64, read: bool) -> StoredMessage {
+        StoredMessage {
+            dedup_key: Hash::from_bytes([dedup_byte; 32]),
+            transaction_id: None,
+            direction: MessageDirection::Incoming,
+            peer: peer.to_string(),
+            msg_type: 1,
+            timestamp,
+            text: format!("message {dedup_byte}"),
+            read,
+        }
+    }
+
+    #[test]
+    fn deduplicates_and_tracks_unread_messages() {
+        let store = ConversationStore::new_with_storage(Storage::try_new("messenger-test").unwrap());
+        assert!(store.insert(message(1, "aa", 10, false)));
+        assert!(store.insert(message(2, "aa", 30, false)));
+        assert!(store.insert(message(3, "bb", 20, true)));
+        assert!(!store.insert(message(1, "aa", 40, false)));
+
+        let conversations = store.conversations();
+        assert_eq!(conversations.len(), 2);
+        assert_eq!(conversations[0], ConversationSummary { peer: "aa".to_string(), messages: 2, unread: 2, last_timestamp: 30 });
+        assert_eq!(conversations[1].unread, 0);
+
+        assert_eq!(store.conversation("AA").iter().map(|message| message.timestamp).collect::<Vec<_>>(), vec![10, 30]);
+        assert_eq!(store.mark_read("aa"), 2);
+        assert_eq!(store.mark_read("aa"), 0);
+        assert_eq!(store.conversations()[0].unread, 0);
+    }
+}
@@ -2,7 +2,7 @@
 //! Wallet transaction data variants.
 //!
 
-use super::UtxoRecord;
+use super::{TokenTransferRecord, UtxoRecord};
 use crate::imports::*;
 use cryptix_consensus_core::tx::Transaction;
 pub use cryptix_consensus_core::tx::TransactionId;
@@ -131,11 +131,45 @@ pub enum TransactionData {
         #[serde(rename = "utxoEntries")]
         utxo_entries: Vec<UtxoRecord>,
     },
+    /// Cryptix Atomic tokens received by the account.
+    TokenIncoming {
+        /// Id of the transaction that carried the token operation.
+        #[serde(rename = "transactionId")]
+        txid: TransactionId,
+        transfers: Vec<TokenTransferRecord>,
+        #[serde(rename = "transaction", skip_serializing_if = "Option::is_none")]
+        #[serde(default)]
+        transaction: Option<Transaction>,
+    },
+    /// Cryptix Atomic tokens sent or burned by the account.
+    TokenOutgoing {
+        /// Id of the transaction that carried the token operation.
+        #[serde(rename = "transactionId")]
+        txid: TransactionId,
+        transfers: Vec<TokenTransferRecord>,
+        #[serde(rename = "transaction", skip_serializing_if = "Option::is_none")]
+        #[serde(default)]
+        transaction: Option<Transaction>,
+    },
+    /// Cryptix Atomic tokens moved between addresses of the account.
+    TokenInternal {
+        /// Id of the transaction that carried the token operation.
+        #[serde(rename = "transactionId")]
+        txid: TransactionId,
+        transfers: Vec<TokenTransferRecord>,
+        #[serde(rename = "transaction", skip_serializing_if = "Option::is_none")]
+        #[serde(default)]
+        transaction: Option<Transaction>,
+    },
 }
 
 impl TransactionData {
     const STORAGE_MAGIC: u32 = 0x54445854;
     const STORAGE_VERSION: u32 = 1;
+    // Token variants are stored under their own header, leaving
+    // the sealed [`TransactionKind`] and the legacy layout intact.
+    const TOKEN_STORAGE_MAGIC: u32 = 0x544b4454;
+    const TOKEN_STORAGE_VERSION: u32 = 0;
 
     pub fn kind(&self) -> TransactionKind {
         match self {
@@ -148,6 +182,45 @@ impl TransactionData {
             TransactionData::TransferIncoming { .. } => TransactionKind::TransferIncoming,
             TransactionData::TransferOutgoing { .. } => TransactionKind::TransferOutgoing,
             TransactionData::Change { .. } => TransactionKind::Change,
+            TransactionData::TokenIncoming { .. } => TransactionKind::Incoming,
+            TransactionData::TokenOutgoing { .. } => TransactionKind::Outgoing,
+            TransactionData::TokenInternal { .. } => TransactionKind::Change,
+        }
+    }
+
+    /// Name of the data variant, as used by the serialized `type` tag.
+    pub fn data_type(&self) -> &'static str {
+        match self {
+            TransactionData::Reorg { .. } => "reorg",
+            TransactionData::Stasis { .. } => "stasis",
+            TransactionData::Incoming { .. } => "incoming",
+            TransactionData::External { .. } => "external",
+            TransactionData::Outgoing { .. } => "outgoing",
+            TransactionData::Batch { .. } => "batch",
+            TransactionData::TransferIncoming { .. } => "transfer-incoming",
+            TransactionData::TransferOutgoing { .. } => "transfer-outgoing",
+            TransactionData::Change { .. } => "change",
+            TransactionData::TokenIncoming { .. } => "token-incoming",
+            TransactionData::TokenOutgoing { .. } => "token-outgoing",
+            TransactionData::TokenInternal { .. } => "token-internal",
+        }
+    }
+
+    pub fn is_token(&self) -> bool {
+        matches!(
+            self,
+            TransactionData::TokenIncoming { .. } | TransactionData::TokenOutgoing { .. } | TransactionData::TokenInternal { .. }
+        )
+    }
+
+    /// Id of the transaction behind a token record. Token records are stored
+    /// under [`token_record_id`](super::token_record_id), so this differs from the record id.
+    pub fn token_txid(&self) -> Option<&TransactionId> {
+        match self {
+            TransactionData::TokenIncoming { txid, .. }
+            | TransactionData::TokenOutgoing { txid, .. }
+            | TransactionData::TokenInternal { txid, .. } => Some(txid),
+            _ => None,
         }
     }
 
@@ -166,6 +239,9 @@ impl TransactionData {
                 utxo_entries.iter().any(|utxo| utxo.address.as_ref() == Some(address))
             }
             TransactionData::Change { utxo_entries, .. } => utxo_entries.iter().any(|utxo| utxo.address.as_ref() == Some(address)),
+            TransactionData::TokenIncoming { .. } | TransactionData::TokenOutgoing { .. } | TransactionData::TokenInternal { .. } => {
+                false
+            }
         }
     }
 
@@ -174,7 +250,10 @@ impl TransactionData {
             TransactionData::Reorg { transaction, .. }
             | TransactionData::Incoming { transaction, .. }
             | TransactionData::Stasis { transaction, .. }
-            | TransactionData::External { transaction, .. } => transaction.as_ref(),
+            | TransactionData::External { transaction, .. }
+            | TransactionData::TokenIncoming { transaction, .. }
+            | TransactionData::TokenOutgoing { transaction, .. }
+            | TransactionData::TokenInternal { transaction, .. } => transaction.as_ref(),
             TransactionData::Batch { transaction, .. }
             | TransactionData::Outgoing { transaction, .. }
             | TransactionData::TransferIncoming { transaction, .. }
@@ -188,7 +267,10 @@ impl TransactionData {
             TransactionData::Reorg { transaction: tx, .. }
             | TransactionData::Incoming { transaction: tx, .. }
             | TransactionData::Stasis { transaction: tx, .. }
-            | TransactionData::External { transaction: tx, .. } => {
+            | TransactionData::External { transaction: tx, .. }
+            | TransactionData::TokenIncoming { transaction: tx, .. }
+            | TransactionData::TokenOutgoing { transaction: tx, .. }
+            | TransactionData::TokenInternal { transaction: tx, .. } => {
                 *tx = Some(transaction);
                 true
             }
@@ -203,7 +285,11 @@ impl TransactionData {
 
 impl BorshSerialize for TransactionData {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
-        StorageHeader::new(Self::STORAGE_MAGIC, Self::STORAGE_VERSION).serialize(writer)?;
+        if self.is_token() {
+            StorageHeader::new(Self::TOKEN_STORAGE_MAGIC, Self::TOKEN_STORAGE_VERSION).serialize(writer)?;
+        } else {
+            StorageHeader::new(Self::STORAGE_MAGIC, Self::STORAGE_VERSION).serialize(writer)?;
+        }
 
         let kind = self.kind();
         BorshSerialize::serialize(&kind, writer)?;
@@ -322,6 +408,13 @@ impl BorshSerialize for TransactionData {
                 BorshSerialize::serialize(accepted_daa_score, writer)?;
                 BorshSerialize::serialize(utxo_entries, writer)?;
             }
+            TransactionData::TokenIncoming { txid, transfers, transaction }
+            | TransactionData::TokenOutgoing { txid, transfers, transaction }
+            | TransactionData::TokenInternal { txid, transfers, transaction } => {
+                BorshSerialize::serialize(txid, writer)?;
+                BorshSerialize::serialize(transfers, writer)?;
+                BorshSerialize::serialize(transaction, writer)?;
+            }
         }
 
         Ok(())
@@ -330,8 +423,22 @@ impl BorshSerialize for TransactionData {
 
 impl BorshDeserialize for TransactionData {
     fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> IoResult<Self> {
-        let StorageHeader { version, .. } =
-            StorageHeader::deserialize_reader(reader)?.try_magic(Self::STORAGE_MAGIC)?.try_version(Self::STORAGE_VERSION)?;
+        let header = StorageHeader::deserialize_reader(reader)?;
+        if header.magic == Self::TOKEN_STORAGE_MAGIC {
+            header.try_version(Self::TOKEN_STORAGE_VERSION)?;
+            let kind: TransactionKind = BorshDeserialize::deserialize_reader(reader)?;
+            let txid: TransactionId = BorshDeserialize::deserialize_reader(reader)?;
+            let transfers: Vec<TokenTransferRecord> = BorshDeserialize::deserialize_reader(reader)?;
+            let transaction: Option<Transaction> = BorshDeserialize::deserialize_reader(reader)?;
+            return match kind {
+                TransactionKind::Incoming => Ok(TransactionData::TokenIncoming { txid, transfers, transaction }),
+                TransactionKind::Outgoing => Ok(TransactionData::TokenOutgoing { txid, transfers, transaction }),
+                TransactionKind::Change => Ok(TransactionData::TokenInternal { txid, transfers, transaction }),
+                kind => Err(IoError::new(IoErrorKind::Other, format!("invalid token transaction kind: {kind}"))),
+            };
+        }
+
+        let StorageHeader { version, .. } = header.try_magic(Self::STORAGE_MAGIC)?.try_version(Self::STORAGE_VERSION)?;
 
         let kind: TransactionKind = BorshDeserialize::deserialize_reader(reader)?;
 
@@ -493,4 +600,31 @@ mod tests {
         assert!(data.attach_transaction_if_supported(tx.clone()));
         assert_eq!(data.transaction().unwrap().payload, tx.payload);
     }
+
+    #[test]
+    fn token_outgoing_round_trip() {
+        let transfer = TokenTransferRecord {
+            event_id: "11".repeat(32),
+            asset_id: "22".repeat(32),
+            op_type: Some(1),
+            from_owner_id: Some("33".repeat(32)),
+            to_owner_id: Some("44".repeat(32)),
+            amount: u128::MAX,
+        };
+        let txid = TransactionId::from_slice(&[7; 32]);
+        let data = TransactionData::TokenOutgoing { txid, transfers: vec![transfer.clone()], transaction: None };
+        let bytes = borsh::to_vec(&data).unwrap();
+
+        let decoded = TransactionData::deserialize_reader(&mut bytes.as_slice()).unwrap();
+        assert_eq!(decoded.kind(), TransactionKind::Outgoing);
+        assert_eq!(decoded.data_type(), "token-outgoing");
+        assert_eq!(decoded.token_txid(), Some(&txid));
+        match decoded {
+            TransactionData::TokenOutgoing { transfers, transaction, .. } => {
+                assert_eq!(transfers, vec![transfer]);
+                assert!(transaction.is_none());
+            }
+            other => panic!("unexpected variant: {other:?}"),
+        }
+    }
 }
@@ -5,9 +5,11 @@
 pub mod data;
 pub mod kind;
 pub mod record;
+pub mod token;
 pub mod utxo;
 
 pub use data::*;
 pub use kind::*;
 pub use record::*;
+pub use token::*;
 pub use utxo::*;
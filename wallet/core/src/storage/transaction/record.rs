@@ -84,6 +84,21 @@ export enum TransactionDataType {
      * @see {@link ITransactionDataChange}
      */
     Change = "change",
+    /**
+     * Cryptix Atomic tokens received by the account. The record value is `0`.
+     * @see {@link ITransactionDataTokenIncoming}
+     */
+    TokenIncoming = "token-incoming",
+    /**
+     * Cryptix Atomic tokens sent or burned by the account. The record value is `0`.
+     * @see {@link ITransactionDataTokenOutgoing}
+     */
+    TokenOutgoing = "token-outgoing",
+    /**
+     * Cryptix Atomic tokens moved between addresses of the account. The record value is `0`.
+     * @see {@link ITransactionDataTokenInternal}
+     */
+    TokenInternal = "token-internal",
 }
 
 /**
@@ -219,6 +234,50 @@ export interface ITransactionDataChange {
     utxoEntries: IUtxoRecord[];
 }
 
+/**
+ * Single Cryptix Atomic token movement.
+ * Asset and owner ids are 64-char hex strings.
+ * @category Wallet SDK
+ */
+export interface ITokenTransferRecord {
+    eventId: HexString;
+    assetId: HexString;
+    opType?: number;
+    fromOwnerId?: HexString;
+    toOwnerId?: HexString;
+    amount: bigint;
+}
+
+/**
+ * Incoming token transaction data.
+ * @category Wallet SDK
+ */
+export interface ITransactionDataTokenIncoming {
+    transactionId: HexString;
+    transfers: ITokenTransferRecord[];
+    transaction?: ITransaction;
+}
+
+/**
+ * Outgoing token transaction data.
+ * @category Wallet SDK
+ */
+export interface ITransactionDataTokenOutgoing {
+    transactionId: HexString;
+    transfers: ITokenTransferRecord[];
+    transaction?: ITransaction;
+}
+
+/**
+ * Token transaction data for transfers between addresses of the account.
+ * @category Wallet SDK
+ */
+export interface ITransactionDataTokenInternal {
+    transactionId: HexString;
+    transfers: ITokenTransferRecord[];
+    transaction?: ITransaction;
+}
+
 /**
  * Transaction record data variants.
  * @category Wallet SDK
@@ -232,7 +291,10 @@ export type ITransactionDataVariant =
     | ITransactionDataBatch
     | ITransactionDataTransferIncoming
     | ITransactionDataTransferOutgoing
-    | ITransactionDataChange;
+    | ITransactionDataChange
+    | ITransactionDataTokenIncoming
+    | ITransactionDataTokenOutgoing
+    | ITransactionDataTokenInternal;
 
 /**
  * Internal transaction data contained within the transaction record.
@@ -385,6 +447,12 @@ impl TransactionRecord {
         &self.id
     }
 
+    /// Id of the on-chain transaction behind this record. Equals [`id`](Self::id)
+    /// for everything except token records.
+    pub fn transaction_id(&self) -> &TransactionId {
+        self.transaction_data.token_txid().unwrap_or(&self.id)
+    }
+
     pub fn unixtime_msec(&self) -> Option<u64> {
         self.unixtime_msec
     }
@@ -456,6 +524,10 @@ impl TransactionRecord {
         matches!(&self.transaction_data, TransactionData::Batch { .. })
     }
 
+    pub fn is_token(&self) -> bool {
+        self.transaction_data.is_token()
+    }
+
     pub fn is_transfer(&self) -> bool {
         matches!(&self.transaction_data, TransactionData::TransferIncoming { .. } | TransactionData::TransferOutgoing { .. })
     }
@@ -535,6 +607,7 @@ impl TransactionRecord {
             | TransactionData::TransferIncoming { aggregate_input_value, .. }
             | TransactionData::TransferOutgoing { aggregate_input_value, .. }
             | TransactionData::Change { aggregate_input_value, .. } => *aggregate_input_value,
+            TransactionData::TokenIncoming { .. } | TransactionData::TokenOutgoing { .. } | TransactionData::TokenInternal { .. } => 0,
         }
     }
 
@@ -909,6 +982,40 @@ impl TransactionRecord {
         record.refresh_payload_availability(utxo_context.processor().current_daa_score());
         Ok(record)
     }
+
+    pub fn new_token(
+        utxo_context: &UtxoContext,
+        kind: TransactionKind,
+        txid: TransactionId,
+        transfers: Vec<TokenTransferRecord>,
+    ) -> Result<Self> {
+        let binding = Binding::from(utxo_context.binding());
+        let block_daa_score =
+            utxo_context.processor().current_daa_score().ok_or(Error::MissingDaaScore("TransactionRecord::new_token()"))?;
+        let transaction = utxo_context.processor().confirmed_transaction(&txid);
+
+        let transaction_data = match kind {
+            TransactionKind::Incoming => TransactionData::TokenIncoming { txid, transfers, transaction },
+            TransactionKind::Outgoing => TransactionData::TokenOutgoing { txid, transfers, transaction },
+            TransactionKind::Change => TransactionData::TokenInternal { txid, transfers, transaction },
+            kind => return Err(Error::custom(format!("invalid token transaction kind: {kind}"))),
+        };
+
+        let mut record = TransactionRecord {
+            id: token_record_id(&txid),
+            unixtime_msec: Some(unixtime_as_millis_u64()),
+            value: 0,
+            binding,
+            transaction_data,
+            block_daa_score,
+            network_id: utxo_context.processor().network_id().expect("network expected for transaction record generation"),
+            payload_availability: PayloadAvailability::Missing,
+            metadata: None,
+            note: None,
+        };
+        record.refresh_payload_availability(utxo_context.processor().current_daa_score());
+        Ok(record)
+    }
 }
 
 #[wasm_bindgen]
@@ -935,7 +1042,11 @@ impl TransactionRecord {
 
     #[wasm_bindgen(getter, js_name = "type")]
     pub fn data_type(&self) -> String {
-        self.transaction_data.kind().to_string()
+        if self.transaction_data.is_token() {
+            self.transaction_data.data_type().to_string()
+        } else {
+            self.transaction_data.kind().to_string()
+        }
     }
 
     /// Check if the transaction record has the given address within the associated UTXO set.
@@ -0,0 +1,64 @@
+//!
+//! Token transfer record representation used by wallet transactions.
+//!
+
+use crate::encryption::sha256_hash;
+use crate::imports::*;
+use cryptix_consensus_core::tx::TransactionId;
+use cryptix_rpc_core::RpcTokenEvent;
+use serde::{Deserialize, Serialize};
+
+const TOKEN_RECORD_ID_DOMAIN: &[u8] = b"TokenTransactionRecord";
+
+/// Storage id of the token record for `txid`. A token operation pays its
+/// fee with a regular transaction, whose UTXO record is already stored
+/// under `txid`; the token record needs a key of its own next to it.
+pub fn token_record_id(txid: &TransactionId) -> TransactionId {
+    let mut bytes = TOKEN_RECORD_ID_DOMAIN.to_vec();
+    bytes.extend_from_slice(txid.as_bytes().as_slice());
+    TransactionId::from_slice(sha256_hash(&bytes).as_ref())
+}
+
+/// [`TokenTransferRecord`] represents a single Cryptix Atomic token
+/// movement stored within [`TransactionRecord`]. Owner ids and the
+/// asset id are 64-char hex strings.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenTransferRecord {
+    pub event_id: String,
+    pub asset_id: String,
+    pub op_type: Option<u32>,
+    pub from_owner_id: Option<String>,
+    pub to_owner_id: Option<String>,
+    pub amount: u128,
+}
+
+impl TokenTransferRecord {
+    /// Creates a record from an applied token event carrying an asset and a
+    /// non-zero amount. Returns `None` for any other event.
+    pub fn try_from_event(event: &RpcTokenEvent) -> Option<Self> {
+        let asset_id = event.asset_id.clone()?;
+        let amount = event.amount.as_deref()?.parse::<u128>().ok().filter(|amount| *amount > 0)?;
+        Some(TokenTransferRecord {
+            event_id: event.event_id.clone(),
+            asset_id,
+            op_type: event.op_type,
+            from_owner_id: event.from_owner_id.clone(),
+            to_owner_id: event.to_owner_id.clone(),
+            amount,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn token_record_id_does_not_collide_with_txid() {
+        let txid = TransactionId::from_slice(&[7; 32]);
+        assert_ne!(token_record_id(&txid), txid);
+        assert_eq!(token_record_id(&txid), token_record_id(&txid));
+        assert_ne!(token_record_id(&txid), token_record_id(&TransactionId::from_slice(&[8; 32])));
+    }
+}
@@ -11,7 +11,7 @@ use cryptix_notify::subscription::{MutationPolicies, UtxosChangedMutationPolicy}
 use cryptix_rpc_core::api::ctl::RpcCtl;
 use cryptix_rpc_core::{api::connection::DynRpcConnection, api::rpc::RpcApi, *};
 use cryptix_rpc_core::{notify::connection::ChannelConnection, RpcResult};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 pub type RpcCoreNotifier = Notifier<Notification, ChannelConnection>;
 
@@ -25,6 +25,7 @@ pub struct RpcCoreMock {
     ctl: RpcCtl,
     core_notifier: Arc<RpcCoreNotifier>,
     _sync_receiver: Receiver<()>,
+    messages: Mutex<Vec<RpcMessageEntry>>,
 }
 
 impl RpcCoreMock {
@@ -41,7 +42,7 @@ impl RpcCoreMock {
             policies,
             Some(sync_sender),
         ));
-        Self { core_notifier, _sync_receiver: sync_receiver, ctl: RpcCtl::new() }
+        Self { core_notifier, _sync_receiver: sync_receiver, ctl: RpcCtl::new(), messages: Mutex::new(vec![]) }
     }
 
     pub fn core_notifier(&self) -> Arc<RpcCoreNotifier> {
@@ -72,6 +73,11 @@ impl RpcCoreMock {
     pub fn ctl(&self) -> RpcCtl {
         self.ctl.clone()
     }
+
+    /// Sets the entries served by the message index, ordered by sequence.
+    pub fn set_messages(&self, messages: Vec<RpcMessageEntry>) {
+        *self.messages.lock().unwrap() = messages;
+    }
 }
 
 impl Default for RpcCoreMock {
@@ -231,6 +237,23 @@ impl RpcApi for RpcCoreMock {
         Err(RpcError::NotImplemented)
     }
 
+    async fn get_messages_by_recipient_tag_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        request: GetMessagesByRecipientTagRequest,
+    ) -> RpcResult<GetMessagesByRecipientTagResponse> {
+        let messages = self.messages.lock().unwrap();
+        let mut matching = messages
+            .iter()
+            .filter(|entry| entry.recipient_tag == request.recipient_tag && entry.sequence > request.cursor)
+            .cloned()
+            .collect::<Vec<_>>();
+        let more = matching.len() > request.limit as usize;
+        matching.truncate(request.limit as usize);
+        let next_cursor = if more { matching.last().map(|entry| entry.sequence) } else { None };
+        Ok(GetMessagesByRecipientTagResponse { entries: matching, next_cursor })
+    }
+
     async fn get_virtual_chain_from_block_call(
         &self,
         _connection: Option<&DynRpcConnection>,
@@ -379,6 +402,24 @@ impl RpcApi for RpcCoreMock {
         Err(RpcError::NotImplemented)
     }
 
+    // Token balances are re-fetched after every token event; an empty index lets that flow complete
+    async fn get_token_balances_by_owner_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        _request: GetTokenBalancesByOwnerRequest,
+    ) -> RpcResult<GetTokenBalancesByOwnerResponse> {
+        Ok(GetTokenBalancesByOwnerResponse {
+            balances: vec![],
+            total: 0,
+            context: RpcTokenContext {
+                at_block_hash: Default::default(),
+                at_daa_score: 0,
+                state_hash: String::new(),
+                is_degraded: false,
+            },
+        })
+    }
+
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
     // Notification API
 
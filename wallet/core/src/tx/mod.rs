@@ -8,6 +8,7 @@ pub mod generator;
 pub mod mass;
 pub mod payload;
 pub mod payment;
+pub mod token;
 
 pub use self::consensus::*;
 pub use self::fees::*;
@@ -15,3 +16,4 @@ pub use self::generator::*;
 pub use self::mass::*;
 pub use self::payload::*;
 pub use self::payment::*;
+pub use self::token::*;
@@ -0,0 +1,1078 @@
+//!
+//! Cryptix Atomic Token (CAT) operations.
+//!
+//! [`TokenOperation`] describes a token operation in terms of wallet addresses.
+//! [`TokenOperation::resolve()`] looks up owner ids and the sender nonce via RPC
+//! and encodes the CAT payload, producing a [`TokenOperationPayload`] that can be
+//! handed to the transaction [`Generator`](crate::tx::Generator).
+//!
+//! Liquidity pool ops are encoded here as well, but the transaction carrying
+//! them must create or spend the pool vault, so they cannot be submitted with
+//! the plain carrier output of [`TokenOperationPayload::destination()`].
+//!
+
+use crate::imports::*;
+use crate::result::Result;
+use crate::rpc::DynRpcApi;
+use crate::tx::{validate_wallet_payload, PaymentDestination, PaymentOutput};
+use cryptix_consensus_core::constants::MAX_SOMPI;
+use cryptix_rpc_core::{GetTokenNonceRequest, GetTokenOwnerIdByAddressRequest};
+
+pub use cryptix_consensus_core::atomic::*;
+
+/// Input index the CAT header points at. The generator is restricted to sender
+/// UTXOs, so whichever input lands first is owned by the sender.
+pub const TOKEN_AUTH_INPUT_INDEX: u16 = 0;
+/// Input index the CAT header points at for ops that spend the pool vault,
+/// which always occupies input 0.
+pub const TOKEN_LIQUIDITY_AUTH_INPUT_INDEX: u16 = 1;
+/// Value of the output returned to the sender that carries a token payload.
+pub const TOKEN_CARRIER_OUTPUT_SOMPI: u64 = 1_000;
+
+/// 32-byte CAT owner id, as derived by the node from an address.
+pub type TokenOwnerId = [u8; 32];
+
+/// Derives the CAT owner id of `address` the same way the node does,
+/// returning `None` for addresses that cannot own tokens.
+pub fn token_owner_id(address: &Address) -> Option<TokenOwnerId> {
+    cat_owner_id_from_address_components(address.version as u8, address.payload.as_slice())
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+#[borsh(use_discriminant = true)]
+#[repr(u8)]
+pub enum TokenSupplyMode {
+    Uncapped = 0,
+    Capped = 1,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenRecipient {
+    pub address: Address,
+    pub amount: u128,
+}
+
+/// Bonding curve of a liquidity asset. `Individual` sets the virtual reserves
+/// explicitly instead of using a preset.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(tag = "mode", rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum TokenLiquidityCurve {
+    #[default]
+    Basic,
+    Aggressive,
+    Individual {
+        virtual_cpay_reserves_sompi: u64,
+        virtual_token_multiplier_bps: u16,
+    },
+}
+
+impl TokenLiquidityCurve {
+    pub fn mode(&self) -> u8 {
+        match self {
+            TokenLiquidityCurve::Basic => CAT_LIQUIDITY_CURVE_MODE_BASIC,
+            TokenLiquidityCurve::Aggressive => CAT_LIQUIDITY_CURVE_MODE_AGGRESSIVE,
+            TokenLiquidityCurve::Individual { .. } => CAT_LIQUIDITY_CURVE_MODE_INDIVIDUAL,
+        }
+    }
+}
+
+/// A CAT operation signed by the sender address. Asset and pool ids are
+/// 64-char hex strings; every other owner is given as an address.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(tag = "op", rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum TokenOperation {
+    /// Creates a standard asset. The mint authority defaults to the sender.
+    CreateAsset {
+        name: String,
+        symbol: String,
+        decimals: u8,
+        supply_mode: TokenSupplyMode,
+        max_supply: u128,
+        mint_authority: Option<Address>,
+        metadata: Vec<u8>,
+        platform_tag: Option<String>,
+    },
+    /// Creates a standard asset and mints `initial_mint_amount` to `initial_mint_to`
+    /// (the sender when not set).
+    CreateAssetWithMint {
+        name: String,
+        symbol: String,
+        decimals: u8,
+        supply_mode: TokenSupplyMode,
+        max_supply: u128,
+        mint_authority: Option<Address>,
+        metadata: Vec<u8>,
+        initial_mint_amount: u128,
+        initial_mint_to: Option<Address>,
+        platform_tag: Option<String>,
+    },
+    Transfer {
+        asset_id: String,
+        to: Address,
+        amount: u128,
+    },
+    BatchTransfer {
+        asset_id: String,
+        recipients: Vec<TokenRecipient>,
+    },
+    Mint {
+        asset_id: String,
+        to: Address,
+        amount: u128,
+    },
+    Burn {
+        asset_id: String,
+        amount: u128,
+    },
+    Approve {
+        asset_id: String,
+        spender: Address,
+        amount: u128,
+        expires_at_daa_score: u64,
+    },
+    /// Spends an allowance granted to the sender by `from`.
+    TransferFrom {
+        asset_id: String,
+        from: Address,
+        to: Address,
+        amount: u128,
+    },
+    LockTokens {
+        asset_id: String,
+        beneficiary: Address,
+        amount: u128,
+        release_start_daa_score: u64,
+        release_end_daa_score: u64,
+    },
+    TransferMintAuthority {
+        asset_id: String,
+        new_authority: Address,
+    },
+    RenounceMintAuthority {
+        asset_id: String,
+    },
+    UpdateMetadata {
+        asset_id: String,
+        metadata: Vec<u8>,
+        platform_tag: Option<String>,
+    },
+    CreatePairPool {
+        asset_a_id: String,
+        asset_b_id: String,
+        fee_bps: u16,
+        amount_a: u128,
+        amount_b: u128,
+    },
+    AddPairLiquidity {
+        pool_id: String,
+        amount_a_max: u128,
+        amount_b_max: u128,
+        min_shares_out: u128,
+    },
+    RemovePairLiquidity {
+        pool_id: String,
+        shares: u128,
+        min_amount_a_out: u128,
+        min_amount_b_out: u128,
+    },
+    SwapPairExactIn {
+        pool_id: String,
+        asset_in_id: String,
+        amount_in: u128,
+        min_amount_out: u128,
+    },
+    SwapPairExactOut {
+        pool_id: String,
+        asset_out_id: String,
+        amount_out: u128,
+        max_amount_in: u128,
+    },
+    /// Creates a liquidity asset whose pool vault is funded with `seed_reserve_sompi`
+    /// plus `launch_buy_sompi`. Fee recipients must be in canonical (version, payload) order.
+    CreateLiquidityAsset {
+        name: String,
+        symbol: String,
+        max_supply: u128,
+        metadata: Vec<u8>,
+        seed_reserve_sompi: u64,
+        fee_bps: u16,
+        fee_recipients: Vec<Address>,
+        launch_buy_sompi: u64,
+        launch_buy_min_token_out: u128,
+        platform_tag: Option<String>,
+        liquidity_unlock_target_sompi: u64,
+        curve: TokenLiquidityCurve,
+    },
+    BuyLiquidityExactIn {
+        asset_id: String,
+        expected_pool_nonce: u64,
+        cpay_in_sompi: u64,
+        min_token_out: u128,
+    },
+    SellLiquidityExactIn {
+        asset_id: String,
+        expected_pool_nonce: u64,
+        token_in: u128,
+        min_cpay_out_sompi: u64,
+        cpay_receive_output_index: u16,
+    },
+    ClaimLiquidityFees {
+        asset_id: String,
+        expected_pool_nonce: u64,
+        recipient_index: u8,
+        claim_amount_sompi: u64,
+        claim_receive_output_index: u16,
+    },
+    BuyLiquidityExactOut {
+        asset_id: String,
+        expected_pool_nonce: u64,
+        token_out: u128,
+        max_cpay_in_sompi: u64,
+    },
+    SellLiquidityExactOut {
+        asset_id: String,
+        expected_pool_nonce: u64,
+        cpay_out_sompi: u64,
+        max_token_in: u128,
+        cpay_receive_output_index: u16,
+    },
+}
+
+/// An encoded [`TokenOperation`] ready to be submitted by `sender`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenOperationPayload {
+    pub sender: Address,
+    pub sender_owner_id: String,
+    pub nonce: u64,
+    pub auth_input_index: u16,
+    pub payload: Vec<u8>,
+}
+
+impl TokenOperationPayload {
+    /// Carrier output paying [`TOKEN_CARRIER_OUTPUT_SOMPI`] back to the sender.
+    pub fn destination(&self) -> PaymentDestination {
+        PaymentDestination::from(PaymentOutput::new(self.sender.clone(), TOKEN_CARRIER_OUTPUT_SOMPI))
+    }
+}
+
+impl TokenOperation {
+    pub fn op_code(&self) -> u8 {
+        match self {
+            TokenOperation::CreateAsset { .. } => CAT_OP_CREATE_ASSET,
+            TokenOperation::CreateAssetWithMint { .. } => CAT_OP_CREATE_ASSET_WITH_MINT,
+            TokenOperation::Transfer { .. } => CAT_OP_TRANSFER,
+            TokenOperation::BatchTransfer { .. } => CAT_OP_BATCH_TRANSFER,
+            TokenOperation::Mint { .. } => CAT_OP_MINT,
+            TokenOperation::Burn { .. } => CAT_OP_BURN,
+            TokenOperation::Approve { .. } => CAT_OP_APPROVE,
+            TokenOperation::TransferFrom { .. } => CAT_OP_TRANSFER_FROM,
+            TokenOperation::LockTokens { .. } => CAT_OP_LOCK_TOKENS,
+            TokenOperation::TransferMintAuthority { .. } => CAT_OP_TRANSFER_MINT_AUTHORITY,
+            TokenOperation::RenounceMintAuthority { .. } => CAT_OP_RENOUNCE_MINT_AUTHORITY,
+            TokenOperation::UpdateMetadata { .. } => CAT_OP_UPDATE_METADATA,
+            TokenOperation::CreatePairPool { .. } => CAT_OP_CREATE_PAIR_POOL,
+            TokenOperation::AddPairLiquidity { .. } => CAT_OP_ADD_PAIR_LIQUIDITY,
+            TokenOperation::RemovePairLiquidity { .. } => CAT_OP_REMOVE_PAIR_LIQUIDITY,
+            TokenOperation::SwapPairExactIn { .. } => CAT_OP_SWAP_PAIR_EXACT_IN,
+            TokenOperation::SwapPairExactOut { .. } => CAT_OP_SWAP_PAIR_EXACT_OUT,
+            TokenOperation::CreateLiquidityAsset { .. } => CAT_OP_CREATE_LIQUIDITY_ASSET,
+            TokenOperation::BuyLiquidityExactIn { .. } => CAT_OP_BUY_LIQUIDITY_EXACT_IN,
+            TokenOperation::SellLiquidityExactIn { .. } => CAT_OP_SELL_LIQUIDITY_EXACT_IN,
+            TokenOperation::ClaimLiquidityFees { .. } => CAT_OP_CLAIM_LIQUIDITY_FEES,
+            TokenOperation::BuyLiquidityExactOut { .. } => CAT_OP_BUY_LIQUIDITY_EXACT_OUT,
+            TokenOperation::SellLiquidityExactOut { .. } => CAT_OP_SELL_LIQUIDITY_EXACT_OUT,
+        }
+    }
+
+    /// Whether the carrying transaction spends the pool vault as input 0.
+    pub fn spends_liquidity_vault(&self) -> bool {
+        matches!(
+            self,
+            TokenOperation::BuyLiquidityExactIn { .. }
+                | TokenOperation::SellLiquidityExactIn { .. }
+                | TokenOperation::ClaimLiquidityFees { .. }
+                | TokenOperation::BuyLiquidityExactOut { .. }
+                | TokenOperation::SellLiquidityExactOut { .. }
+        )
+    }
+
+    /// Whether the carrying transaction must create or spend a pool vault output.
+    pub fn requires_liquidity_vault(&self) -> bool {
+        matches!(self, TokenOperation::CreateLiquidityAsset { .. }) || self.spends_liquidity_vault()
+    }
+
+    /// Input index the CAT header should point at for this operation.
+    pub fn auth_input_index(&self) -> u16 {
+        if self.spends_liquidity_vault() {
+            TOKEN_LIQUIDITY_AUTH_INPUT_INDEX
+        } else {
+            TOKEN_AUTH_INPUT_INDEX
+        }
+    }
+
+    /// Asset or pool id the sender nonce is scoped to, `None` for asset creation.
+    pub fn nonce_scope(&self) -> Option<&str> {
+        match self {
+            TokenOperation::CreateAsset { .. }
+            | TokenOperation::CreateAssetWithMint { .. }
+            | TokenOperation::CreatePairPool { .. }
+            | TokenOperation::CreateLiquidityAsset { .. } => None,
+            TokenOperation::Transfer { asset_id, .. }
+            | TokenOperation::BatchTransfer { asset_id, .. }
+            | TokenOperation::Mint { asset_id, .. }
+            | TokenOperation::Burn { asset_id, .. }
+            | TokenOperation::Approve { asset_id, .. }
+            | TokenOperation::TransferFrom { asset_id, .. }
+            | TokenOperation::LockTokens { asset_id, .. }
+            | TokenOperation::TransferMintAuthority { asset_id, .. }
+            | TokenOperation::RenounceMintAuthority { asset_id }
+            | TokenOperation::UpdateMetadata { asset_id, .. }
+            | TokenOperation::BuyLiquidityExactIn { asset_id, .. }
+            | TokenOperation::SellLiquidityExactIn { asset_id, .. }
+            | TokenOperation::ClaimLiquidityFees { asset_id, .. }
+            | TokenOperation::BuyLiquidityExactOut { asset_id, .. }
+            | TokenOperation::SellLiquidityExactOut { asset_id, .. } => Some(asset_id.as_str()),
+            TokenOperation::AddPairLiquidity { pool_id, .. }
+            | TokenOperation::RemovePairLiquidity { pool_id, .. }
+            | TokenOperation::SwapPairExactIn { pool_id, .. }
+            | TokenOperation::SwapPairExactOut { pool_id, .. } => Some(pool_id.as_str()),
+        }
+    }
+
+    /// Addresses other than the sender whose owner ids the payload needs.
+    pub fn owner_addresses(&self) -> Vec<&Address> {
+        match self {
+            TokenOperation::CreateAsset { mint_authority, .. } => mint_authority.iter().collect(),
+            TokenOperation::CreateAssetWithMint { mint_authority, initial_mint_to, .. } => {
+                mint_authority.iter().chain(initial_mint_to.iter()).collect()
+            }
+            TokenOperation::Transfer { to, .. } | TokenOperation::Mint { to, .. } => vec![to],
+            TokenOperation::BatchTransfer { recipients, .. } => recipients.iter().map(|recipient| &recipient.address).collect(),
+            TokenOperation::Approve { spender, .. } => vec![spender],
+            TokenOperation::TransferFrom { from, to, .. } => vec![from, to],
+            TokenOperation::LockTokens { beneficiary, .. } => vec![beneficiary],
+            TokenOperation::TransferMintAuthority { new_authority, .. } => vec![new_authority],
+            _ => vec![],
+        }
+    }
+
+    /// Resolves the owner ids and, unless `nonce` is given, the next sender nonce
+    /// for this operation, then encodes its payload.
+    pub async fn resolve(&self, rpc: &Arc<DynRpcApi>, sender: &Address, nonce: Option<u64>) -> Result<TokenOperationPayload> {
+        let sender_owner_id = resolve_owner_id(rpc, sender).await?;
+        let mut owner_ids = HashMap::from([(sender.clone(), sender_owner_id)]);
+        for address in self.owner_addresses() {
+            if !owner_ids.contains_key(address) {
+                owner_ids.insert(address.clone(), resolve_owner_id(rpc, address).await?);
+            }
+        }
+
+        let nonce = match nonce {
+            Some(nonce) => nonce,
+            None => resolve_nonce(rpc, &sender_owner_id, self.nonce_scope()).await?,
+        };
+
+        let auth_input_index = self.auth_input_index();
+        let payload = self.encode(sender, nonce, auth_input_index, |address| {
+            owner_ids.get(address).copied().ok_or_else(|| Error::custom(format!("owner id for {address} was not resolved")))
+        })?;
+
+        Ok(TokenOperationPayload {
+            sender: sender.clone(),
+            sender_owner_id: sender_owner_id.as_slice().to_hex(),
+            nonce,
+            auth_input_index,
+            payload,
+        })
+    }
+
+    /// Encodes the CAT payload, mapping addresses to owner ids with `owner_id`.
+    pub fn encode<F>(&self, sender: &Address, nonce: u64, auth_input_index: u16, owner_id: F) -> Result<Vec<u8>>
+    where
+        F: Fn(&Address) -> Result<TokenOwnerId>,
+    {
+        let mut payload = encode_header(self.op_code(), nonce, auth_input_index)?;
+        match self {
+            TokenOperation::CreateAsset {
+                name,
+                symbol,
+                decimals,
+                supply_mode,
+                max_supply,
+                mint_authority,
+                metadata,
+                platform_tag,
+            } => {
+                let mint_authority_owner_id = owner_id(mint_authority.as_ref().unwrap_or(sender))?;
+                push_create_common(
+                    &mut payload,
+                    name,
+                    symbol,
+                    *decimals,
+                    *supply_mode,
+                    *max_supply,
+                    &mint_authority_owner_id,
+                    metadata,
+                )?;
+                push_optional_platform_tag(&mut payload, platform_tag.as_deref())?;
+            }
+            TokenOperation::CreateAssetWithMint {
+                name,
+                symbol,
+                decimals,
+                supply_mode,
+                max_supply,
+                mint_authority,
+                metadata,
+                initial_mint_amount,
+                initial_mint_to,
+                platform_tag,
+            } => {
+                let mint_authority_owner_id = owner_id(mint_authority.as_ref().unwrap_or(sender))?;
+                let initial_mint_to_owner_id =
+                    if *initial_mint_amount == 0 { [0u8; 32] } else { owner_id(initial_mint_to.as_ref().unwrap_or(sender))? };
+                push_create_common(
+                    &mut payload,
+                    name,
+                    symbol,
+                    *decimals,
+                    *supply_mode,
+                    *max_supply,
+                    &mint_authority_owner_id,
+                    metadata,
+                )?;
+                payload.extend_from_slice(&initial_mint_amount.to_le_bytes());
+                payload.extend_from_slice(&initial_mint_to_owner_id);
+                push_optional_platform_tag(&mut payload, platform_tag.as_deref())?;
+            }
+            TokenOperation::Transfer { asset_id, to, amount } | TokenOperation::Mint { asset_id, to, amount } => {
+                payload.extend_from_slice(&parse_id(asset_id, "assetId")?);
+                payload.extend_from_slice(&owner_id(to)?);
+                payload.extend_from_slice(&amount.to_le_bytes());
+            }
+            TokenOperation::BatchTransfer { asset_id, recipients } => {
+                if recipients.is_empty() || recipients.len() > CAT_MAX_BATCH_TRANSFER_RECIPIENTS {
+                    return Err(Error::custom(format!("batch transfer requires 1..={CAT_MAX_BATCH_TRANSFER_RECIPIENTS} recipients")));
+                }
+                payload.extend_from_slice(&parse_id(asset_id, "assetId")?);
+                payload.push(recipients.len() as u8);
+                let mut seen = HashSet::with_capacity(recipients.len());
+                for recipient in recipients {
+                    let to_owner_id = owner_id(&recipient.address)?;
+                    if !seen.insert(to_owner_id) {
+                        return Err(Error::custom("batch transfer recipients must be distinct owners"));
+                    }
+                    payload.extend_from_slice(&to_owner_id);
+                    payload.extend_from_slice(&recipient.amount.to_le_bytes());
+                }
+            }
+            TokenOperation::Burn { asset_id, amount } => {
+                payload.extend_from_slice(&parse_id(asset_id, "assetId")?);
+                payload.extend_from_slice(&amount.to_le_bytes());
+            }
+            TokenOperation::Approve { asset_id, spender, amount, expires_at_daa_score } => {
+                payload.extend_from_slice(&parse_id(asset_id, "assetId")?);
+                payload.extend_from_slice(&owner_id(spender)?);
+                payload.extend_from_slice(&amount.to_le_bytes());
+                payload.extend_from_slice(&expires_at_daa_score.to_le_bytes());
+            }
+            TokenOperation::TransferFrom { asset_id, from, to, amount } => {
+                payload.extend_from_slice(&parse_id(asset_id, "assetId")?);
+                payload.extend_from_slice(&owner_id(from)?);
+                payload.extend_from_slice(&owner_id(to)?);
+                payload.extend_from_slice(&amount.to_le_bytes());
+            }
+            TokenOperation::LockTokens { asset_id, beneficiary, amount, release_start_daa_score, release_end_daa_score } => {
+                payload.extend_from_slice(&parse_id(asset_id, "assetId")?);
+                payload.extend_from_slice(&owner_id(beneficiary)?);
+                payload.extend_from_slice(&amount.to_le_bytes());
+                payload.extend_from_slice(&release_start_daa_score.to_le_bytes());
+                payload.extend_from_slice(&release_end_daa_score.to_le_bytes());
+            }
+            TokenOperation::TransferMintAuthority { asset_id, new_authority } => {
+                payload.extend_from_slice(&parse_id(asset_id, "assetId")?);
+                payload.extend_from_slice(&owner_id(new_authority)?);
+            }
+            TokenOperation::RenounceMintAuthority { asset_id } => {
+                payload.extend_from_slice(&parse_id(asset_id, "assetId")?);
+            }
+            TokenOperation::UpdateMetadata { asset_id, metadata, platform_tag } => {
+                if metadata.len() > CAT_MAX_METADATA_LEN {
+                    return Err(Error::custom(format!("metadata must be <= {CAT_MAX_METADATA_LEN} bytes")));
+                }
+                payload.extend_from_slice(&parse_id(asset_id, "assetId")?);
+                payload.extend_from_slice(&(metadata.len() as u16).to_le_bytes());
+                payload.extend_from_slice(metadata);
+                push_platform_tag(&mut payload, platform_tag.as_deref().unwrap_or_default())?;
+            }
+            TokenOperation::CreatePairPool { asset_a_id, asset_b_id, fee_bps, amount_a, amount_b } => {
+                let asset_a_id = parse_id(asset_a_id, "assetAId")?;
+                let asset_b_id = parse_id(asset_b_id, "assetBId")?;
+                if asset_a_id >= asset_b_id {
+                    return Err(Error::custom("pair assets must be distinct and in ascending id order"));
+                }
+                if *fee_bps > CAT_MAX_PAIR_FEE_BPS {
+                    return Err(Error::custom(format!("feeBps must be <= {CAT_MAX_PAIR_FEE_BPS}")));
+                }
+                payload.extend_from_slice(&asset_a_id);
+                payload.extend_from_slice(&asset_b_id);
+                payload.extend_from_slice(&fee_bps.to_le_bytes());
+                payload.extend_from_slice(&amount_a.to_le_bytes());
+                payload.extend_from_slice(&amount_b.to_le_bytes());
+            }
+            TokenOperation::AddPairLiquidity { pool_id, amount_a_max, amount_b_max, min_shares_out } => {
+                payload.extend_from_slice(&parse_id(pool_id, "poolId")?);
+                payload.extend_from_slice(&amount_a_max.to_le_bytes());
+                payload.extend_from_slice(&amount_b_max.to_le_bytes());
+                payload.extend_from_slice(&min_shares_out.to_le_bytes());
+            }
+            TokenOperation::RemovePairLiquidity { pool_id, shares, min_amount_a_out, min_amount_b_out } => {
+                payload.extend_from_slice(&parse_id(pool_id, "poolId")?);
+                payload.extend_from_slice(&shares.to_le_bytes());
+                payload.extend_from_slice(&min_amount_a_out.to_le_bytes());
+                payload.extend_from_slice(&min_amount_b_out.to_le_bytes());
+            }
+            TokenOperation::SwapPairExactIn { pool_id, asset_in_id, amount_in, min_amount_out } => {
+                payload.extend_from_slice(&parse_id(pool_id, "poolId")?);
+                payload.extend_from_slice(&parse_id(asset_in_id, "assetInId")?);
+                payload.extend_from_slice(&amount_in.to_le_bytes());
+                payload.extend_from_slice(&min_amount_out.to_le_bytes());
+            }
+            TokenOperation::SwapPairExactOut { pool_id, asset_out_id, amount_out, max_amount_in } => {
+                payload.extend_from_slice(&parse_id(pool_id, "poolId")?);
+                payload.extend_from_slice(&parse_id(asset_out_id, "assetOutId")?);
+                payload.extend_from_slice(&amount_out.to_le_bytes());
+                payload.extend_from_slice(&max_amount_in.to_le_bytes());
+            }
+            TokenOperation::CreateLiquidityAsset {
+                name,
+                symbol,
+                max_supply,
+                metadata,
+                seed_reserve_sompi,
+                fee_bps,
+                fee_recipients,
+                launch_buy_sompi,
+                launch_buy_min_token_out,
+                platform_tag,
+                liquidity_unlock_target_sompi,
+                curve,
+            } => {
+                validate_identity(name, symbol, metadata)?;
+                if !(CAT_MIN_LIQUIDITY_SUPPLY_RAW..=CAT_MAX_LIQUIDITY_SUPPLY_RAW).contains(max_supply) {
+                    return Err(Error::custom(format!(
+                        "maxSupply must be between {CAT_MIN_LIQUIDITY_SUPPLY_RAW} and {CAT_MAX_LIQUIDITY_SUPPLY_RAW}"
+                    )));
+                }
+                if *seed_reserve_sompi != CAT_MIN_LIQUIDITY_SEED_RESERVE_SOMPI {
+                    return Err(Error::custom(format!("seedReserveSompi must be exactly {CAT_MIN_LIQUIDITY_SEED_RESERVE_SOMPI}")));
+                }
+                if !(*fee_bps == 0 || (CAT_MIN_LIQUIDITY_FEE_BPS..=CAT_MAX_LIQUIDITY_FEE_BPS).contains(fee_bps)) {
+                    return Err(Error::custom(format!(
+                        "feeBps must be 0 or between {CAT_MIN_LIQUIDITY_FEE_BPS} and {CAT_MAX_LIQUIDITY_FEE_BPS}"
+                    )));
+                }
+                if (*fee_bps == 0) != fee_recipients.is_empty() || fee_recipients.len() > CAT_MAX_LIQUIDITY_RECIPIENTS {
+                    return Err(Error::custom(format!(
+                        "feeRecipients must be empty when feeBps is 0 and hold 1..={CAT_MAX_LIQUIDITY_RECIPIENTS} addresses otherwise"
+                    )));
+                }
+                let recipient_keys = fee_recipients
+                    .iter()
+                    .map(|address| {
+                        if address.payload.len() != address.version.public_key_len() {
+                            return Err(Error::custom(format!("fee recipient {address} has an invalid payload length")));
+                        }
+                        Ok((address.version as u8, address.payload.as_slice()))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                if recipient_keys.windows(2).any(|pair| pair[0] >= pair[1]) {
+                    return Err(Error::custom("feeRecipients must be distinct and in canonical (version, payload) order"));
+                }
+                if (*launch_buy_sompi == 0) != (*launch_buy_min_token_out == 0) {
+                    return Err(Error::custom("launchBuyMinTokenOut must be set exactly when launchBuySompi is set"));
+                }
+                if *liquidity_unlock_target_sompi > MAX_SOMPI {
+                    return Err(Error::custom(format!("liquidityUnlockTargetSompi must be <= {MAX_SOMPI}")));
+                }
+                if let TokenLiquidityCurve::Individual { virtual_cpay_reserves_sompi, virtual_token_multiplier_bps } = curve {
+                    validate_individual_curve(*virtual_cpay_reserves_sompi, *virtual_token_multiplier_bps)?;
+                }
+
+                payload.push(CAT_CURRENT_TOKEN_VERSION);
+                payload.push(CAT_CURRENT_LIQUIDITY_CURVE_VERSION);
+                payload.push(CAT_LIQUIDITY_TOKEN_DECIMALS);
+                payload.extend_from_slice(&max_supply.to_le_bytes());
+                push_identity(&mut payload, name, symbol, metadata);
+                payload.extend_from_slice(&seed_reserve_sompi.to_le_bytes());
+                payload.extend_from_slice(&fee_bps.to_le_bytes());
+                payload.push(recipient_keys.len() as u8);
+                for (version, address_payload) in recipient_keys {
+                    payload.push(version);
+                    payload.extend_from_slice(address_payload);
+                }
+                payload.extend_from_slice(&launch_buy_sompi.to_le_bytes());
+                payload.extend_from_slice(&launch_buy_min_token_out.to_le_bytes());
+
+                // The tail is optional; the curve mode defaults to basic when omitted.
+                let platform_tag = platform_tag.as_deref().unwrap_or_default();
+                let curve_mode = curve.mode();
+                if !platform_tag.is_empty() || *liquidity_unlock_target_sompi > 0 || curve_mode != CAT_DEFAULT_LIQUIDITY_CURVE_MODE {
+                    push_platform_tag(&mut payload, platform_tag)?;
+                    payload.extend_from_slice(&liquidity_unlock_target_sompi.to_le_bytes());
+                    if curve_mode != CAT_DEFAULT_LIQUIDITY_CURVE_MODE {
+                        payload.push(curve_mode);
+                    }
+                    if let TokenLiquidityCurve::Individual { virtual_cpay_reserves_sompi, virtual_token_multiplier_bps } = curve {
+                        payload.extend_from_slice(&virtual_cpay_reserves_sompi.to_le_bytes());
+                        payload.extend_from_slice(&virtual_token_multiplier_bps.to_le_bytes());
+                    }
+                }
+            }
+            TokenOperation::BuyLiquidityExactIn { asset_id, expected_pool_nonce, cpay_in_sompi, min_token_out } => {
+                payload.extend_from_slice(&parse_id(asset_id, "assetId")?);
+                payload.extend_from_slice(&expected_pool_nonce.to_le_bytes());
+                payload.extend_from_slice(&cpay_in_sompi.to_le_bytes());
+                payload.extend_from_slice(&min_token_out.to_le_bytes());
+            }
+            TokenOperation::SellLiquidityExactIn {
+                asset_id,
+                expected_pool_nonce,
+                token_in,
+                min_cpay_out_sompi,
+                cpay_receive_output_index,
+            } => {
+                payload.extend_from_slice(&parse_id(asset_id, "assetId")?);
+                payload.extend_from_slice(&expected_pool_nonce.to_le_bytes());
+                payload.extend_from_slice(&token_in.to_le_bytes());
+                payload.extend_from_slice(&min_cpay_out_sompi.to_le_bytes());
+                payload.extend_from_slice(&cpay_receive_output_index.to_le_bytes());
+            }
+            TokenOperation::ClaimLiquidityFees {
+                asset_id,
+                expected_pool_nonce,
+                recipient_index,
+                claim_amount_sompi,
+                claim_receive_output_index,
+            } => {
+                payload.extend_from_slice(&parse_id(asset_id, "assetId")?);
+                payload.extend_from_slice(&expected_pool_nonce.to_le_bytes());
+                payload.push(*recipient_index);
+                payload.extend_from_slice(&claim_amount_sompi.to_le_bytes());
+                payload.extend_from_slice(&claim_receive_output_index.to_le_bytes());
+            }
+            TokenOperation::BuyLiquidityExactOut { asset_id, expected_pool_nonce, token_out, max_cpay_in_sompi } => {
+                payload.extend_from_slice(&parse_id(asset_id, "assetId")?);
+                payload.extend_from_slice(&expected_pool_nonce.to_le_bytes());
+                payload.extend_from_slice(&token_out.to_le_bytes());
+                payload.extend_from_slice(&max_cpay_in_sompi.to_le_bytes());
+            }
+            TokenOperation::SellLiquidityExactOut {
+                asset_id,
+                expected_pool_nonce,
+                cpay_out_sompi,
+                max_token_in,
+                cpay_receive_output_index,
+            } => {
+                payload.extend_from_slice(&parse_id(asset_id, "assetId")?);
+                payload.extend_from_slice(&expected_pool_nonce.to_le_bytes());
+                payload.extend_from_slice(&cpay_out_sompi.to_le_bytes());
+                payload.extend_from_slice(&max_token_in.to_le_bytes());
+                payload.extend_from_slice(&cpay_receive_output_index.to_le_bytes());
+            }
+        }
+
+        validate_wallet_payload(Some(&payload))?;
+        Ok(payload)
+    }
+}
+
+async fn resolve_owner_id(rpc: &Arc<DynRpcApi>, address: &Address) -> Result<TokenOwnerId> {
+    let response = rpc
+        .get_token_owner_id_by_address_call(
+            None,
+            GetTokenOwnerIdByAddressRequest { address: address.to_string(), at_block_hash: None },
+        )
+        .await?;
+    let owner_id = response.owner_id.ok_or_else(|| {
+        let reason = response.reason.unwrap_or_else(|| "owner id not derivable for address".to_string());
+        Error::custom(format!("address {address} is not usable for token operations: {reason}"))
+    })?;
+    parse_id(&owner_id, "ownerId")
+}
+
+async fn resolve_nonce(rpc: &Arc<DynRpcApi>, sender_owner_id: &TokenOwnerId, scope: Option<&str>) -> Result<u64> {
+    let response = rpc
+        .get_token_nonce_call(
+            None,
+            GetTokenNonceRequest {
+                owner_id: sender_owner_id.as_slice().to_hex(),
+                asset_id: scope.map(ToString::to_string),
+                at_block_hash: None,
+            },
+        )
+        .await?;
+    if response.expected_next_nonce == 0 {
+        return Err(Error::custom("RPC returned expectedNextNonce=0; CAT nonce must be greater than zero"));
+    }
+    Ok(response.expected_next_nonce)
+}
+
+fn encode_header(op: u8, nonce: u64, auth_input_index: u16) -> Result<Vec<u8>> {
+    if nonce == 0 {
+        return Err(Error::custom("nonce must be greater than zero"));
+    }
+
+    let mut payload = Vec::with_capacity(64);
+    payload.extend_from_slice(&CAT_MAGIC);
+    payload.push(CAT_VERSION);
+    payload.push(op);
+    payload.push(CAT_FLAGS);
+    payload.extend_from_slice(&auth_input_index.to_le_bytes());
+    payload.extend_from_slice(&nonce.to_le_bytes());
+    Ok(payload)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn push_create_common(
+    payload: &mut Vec<u8>,
+    name: &str,
+    symbol: &str,
+    decimals: u8,
+    supply_mode: TokenSupplyMode,
+    max_supply: u128,
+    mint_authority_owner_id: &TokenOwnerId,
+    metadata: &[u8],
+) -> Result<()> {
+    if decimals > CAT_MAX_DECIMALS {
+        return Err(Error::custom(format!("decimals must be <= {CAT_MAX_DECIMALS}")));
+    }
+    validate_identity(name, symbol, metadata)?;
+    match supply_mode {
+        TokenSupplyMode::Uncapped if max_supply != 0 => {
+            return Err(Error::custom("maxSupply must be 0 when supplyMode is Uncapped"));
+        }
+        TokenSupplyMode::Capped if max_supply == 0 => {
+            return Err(Error::custom("maxSupply must be > 0 when supplyMode is Capped"));
+        }
+        _ => {}
+    }
+
+    payload.push(CAT_CURRENT_TOKEN_VERSION);
+    payload.push(decimals);
+    payload.push(supply_mode as u8);
+    payload.extend_from_slice(&max_supply.to_le_bytes());
+    payload.extend_from_slice(mint_authority_owner_id);
+    push_identity(payload, name, symbol, metadata);
+    Ok(())
+}
+
+fn validate_identity(name: &str, symbol: &str, metadata: &[u8]) -> Result<()> {
+    if name.len() > CAT_MAX_NAME_LEN {
+        return Err(Error::custom(format!("name must be <= {CAT_MAX_NAME_LEN} bytes")));
+    }
+    if symbol.len() > CAT_MAX_SYMBOL_LEN {
+        return Err(Error::custom(format!("symbol must be <= {CAT_MAX_SYMBOL_LEN} bytes")));
+    }
+    if metadata.len() > CAT_MAX_METADATA_LEN {
+        return Err(Error::custom(format!("metadata must be <= {CAT_MAX_METADATA_LEN} bytes")));
+    }
+    Ok(())
+}
+
+fn push_identity(payload: &mut Vec<u8>, name: &str, symbol: &str, metadata: &[u8]) {
+    payload.push(name.len() as u8);
+    payload.push(symbol.len() as u8);
+    payload.extend_from_slice(&(metadata.len() as u16).to_le_bytes());
+    payload.extend_from_slice(name.as_bytes());
+    payload.extend_from_slice(symbol.as_bytes());
+    payload.extend_from_slice(metadata);
+}
+
+fn validate_individual_curve(virtual_cpay_reserves_sompi: u64, virtual_token_multiplier_bps: u16) -> Result<()> {
+    if !(CAT_INDIVIDUAL_MIN_VIRTUAL_CPAY_RESERVES_SOMPI..=CAT_INDIVIDUAL_MAX_VIRTUAL_CPAY_RESERVES_SOMPI)
+        .contains(&virtual_cpay_reserves_sompi)
+        || virtual_cpay_reserves_sompi % CAT_INDIVIDUAL_VIRTUAL_CPAY_STEP_SOMPI != 0
+    {
+        return Err(Error::custom(format!(
+            "virtualCpayReservesSompi must be between {CAT_INDIVIDUAL_MIN_VIRTUAL_CPAY_RESERVES_SOMPI} and \
+             {CAT_INDIVIDUAL_MAX_VIRTUAL_CPAY_RESERVES_SOMPI} in steps of {CAT_INDIVIDUAL_VIRTUAL_CPAY_STEP_SOMPI}"
+        )));
+    }
+    if !(CAT_INDIVIDUAL_MIN_VIRTUAL_TOKEN_MULTIPLIER_BPS..=CAT_INDIVIDUAL_MAX_VIRTUAL_TOKEN_MULTIPLIER_BPS)
+        .contains(&virtual_token_multiplier_bps)
+        || virtual_token_multiplier_bps % CAT_INDIVIDUAL_VIRTUAL_TOKEN_MULTIPLIER_STEP_BPS != 0
+    {
+        return Err(Error::custom(format!(
+            "virtualTokenMultiplierBps must be between {CAT_INDIVIDUAL_MIN_VIRTUAL_TOKEN_MULTIPLIER_BPS} and \
+             {CAT_INDIVIDUAL_MAX_VIRTUAL_TOKEN_MULTIPLIER_BPS} in steps of {CAT_INDIVIDUAL_VIRTUAL_TOKEN_MULTIPLIER_STEP_BPS}"
+        )));
+    }
+    Ok(())
+}
+
+fn push_platform_tag(payload: &mut Vec<u8>, platform_tag: &str) -> Result<()> {
+    if platform_tag.len() > CAT_MAX_PLATFORM_TAG_LEN {
+        return Err(Error::custom(format!("platformTag must be <= {CAT_MAX_PLATFORM_TAG_LEN} UTF-8 bytes")));
+    }
+    payload.push(platform_tag.len() as u8);
+    payload.extend_from_slice(platform_tag.as_bytes());
+    Ok(())
+}
+
+fn push_optional_platform_tag(payload: &mut Vec<u8>, platform_tag: Option<&str>) -> Result<()> {
+    match platform_tag {
+        Some(platform_tag) if !platform_tag.is_empty() => push_platform_tag(payload, platform_tag),
+        _ => Ok(()),
+    }
+}
+
+fn parse_id(value: &str, field_name: &str) -> Result<[u8; 32]> {
+    let value = value.trim();
+    let value = value.strip_prefix("0x").unwrap_or(value);
+    let bytes = Vec::<u8>::from_hex(value).map_err(|err| Error::custom(format!("{field_name} must be valid hex: {err}")))?;
+    <[u8; 32]>::try_from(bytes.as_slice())
+        .map_err(|_| Error::custom(format!("{field_name} must be 32 bytes (64 hex chars), got {}", bytes.len())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cryptix_addresses::Version;
+    use cryptix_atomicindex::payload::{parse_atomic_token_payload, SupplyMode, TokenOp};
+
+    const ASSET_ID: &str = "1111111111111111111111111111111111111111111111111111111111111111";
+
+    fn address(byte: u8) -> Address {
+        Address::new(Prefix::Testnet, Version::PubKey, &[byte; 32])
+    }
+
+    // Test owner ids are the address payload, which keeps them distinct per address.
+    fn owner_id(address: &Address) -> Result<TokenOwnerId> {
+        Ok(<[u8; 32]>::try_from(address.payload.as_slice()).unwrap())
+    }
+
+    #[test]
+    fn transfer_payload_parses_with_atomic_parser() {
+        let operation = TokenOperation::Transfer { asset_id: ASSET_ID.to_string(), to: address(2), amount: 12_345 };
+        let payload = operation.encode(&address(1), 7, TOKEN_AUTH_INPUT_INDEX, owner_id).unwrap();
+
+        let parsed = parse_atomic_token_payload(&payload).unwrap().unwrap();
+        assert_eq!(parsed.header.nonce, 7);
+        assert_eq!(parsed.header.auth_input_index, TOKEN_AUTH_INPUT_INDEX);
+        let TokenOp::Transfer(op) = parsed.op else { panic!("expected transfer, got {:?}", parsed.op) };
+        assert_eq!(op.asset_id, [0x11; 32]);
+        assert_eq!(op.to_owner_id, [2; 32]);
+        assert_eq!(op.amount, 12_345);
+    }
+
+    #[test]
+    fn create_asset_with_mint_defaults_owners_to_sender() {
+        let operation = TokenOperation::CreateAssetWithMint {
+            name: "Token".to_string(),
+            symbol: "TKN".to_string(),
+            decimals: 8,
+            supply_mode: TokenSupplyMode::Capped,
+            max_supply: 1_000_000,
+            mint_authority: None,
+            metadata: b"meta".to_vec(),
+            initial_mint_amount: 500,
+            initial_mint_to: None,
+            platform_tag: Some("cli".to_string()),
+        };
+        assert_eq!(operation.nonce_scope(), None);
+        let payload = operation.encode(&address(1), 1, TOKEN_AUTH_INPUT_INDEX, owner_id).unwrap();
+
+        let parsed = parse_atomic_token_payload(&payload).unwrap().unwrap();
+        let TokenOp::CreateAssetWithMint(op) = parsed.op else { panic!("expected create-with-mint, got {:?}", parsed.op) };
+        assert_eq!(op.supply_mode, SupplyMode::Capped);
+        assert_eq!(op.mint_authority_owner_id, [1; 32]);
+        assert_eq!(op.initial_mint_to_owner_id, [1; 32]);
+        assert_eq!(op.initial_mint_amount, 500);
+        assert_eq!(op.platform_tag, b"cli".to_vec());
+    }
+
+    #[test]
+    fn batch_transfer_and_pair_swap_parse_with_atomic_parser() {
+        let recipients = vec![TokenRecipient { address: address(2), amount: 10 }, TokenRecipient { address: address(3), amount: 20 }];
+        let operation = TokenOperation::BatchTransfer { asset_id: ASSET_ID.to_string(), recipients };
+        let payload = operation.encode(&address(1), 2, TOKEN_AUTH_INPUT_INDEX, owner_id).unwrap();
+        let TokenOp::BatchTransfer(op) = parse_atomic_token_payload(&payload).unwrap().unwrap().op else { panic!("expected batch") };
+        assert_eq!(op.recipients.len(), 2);
+        assert_eq!(op.recipients[1].to_owner_id, [3; 32]);
+
+        let operation = TokenOperation::SwapPairExactOut {
+            pool_id: ASSET_ID.to_string(),
+            asset_out_id: format!("0x{}", "22".repeat(32)),
+            amount_out: 5,
+            max_amount_in: 9,
+        };
+        assert_eq!(operation.nonce_scope(), Some(ASSET_ID));
+        let payload = operation.encode(&address(1), 3, TOKEN_AUTH_INPUT_INDEX, owner_id).unwrap();
+        let TokenOp::SwapPairExactOut(op) = parse_atomic_token_payload(&payload).unwrap().unwrap().op else { panic!("expected swap") };
+        assert_eq!(op.asset_out_id, [0x22; 32]);
+        assert_eq!(op.max_amount_in, 9);
+    }
+
+    #[test]
+    fn encode_rejects_invalid_operations() {
+        let sender = address(1);
+        let transfer = TokenOperation::Transfer { asset_id: ASSET_ID.to_string(), to: address(2), amount: 1 };
+        assert!(transfer.encode(&sender, 0, TOKEN_AUTH_INPUT_INDEX, owner_id).is_err());
+
+        let duplicate = TokenOperation::BatchTransfer {
+            asset_id: ASSET_ID.to_string(),
+            recipients: vec![TokenRecipient { address: address(2), amount: 1 }, TokenRecipient { address: address(2), amount: 2 }],
+        };
+        assert!(duplicate.encode(&sender, 1, TOKEN_AUTH_INPUT_INDEX, owner_id).is_err());
+
+        let unordered = TokenOperation::CreatePairPool {
+            asset_a_id: "22".repeat(32),
+            asset_b_id: ASSET_ID.to_string(),
+            fee_bps: 30,
+            amount_a: 1,
+            amount_b: 1,
+        };
+        assert!(unordered.encode(&sender, 1, TOKEN_AUTH_INPUT_INDEX, owner_id).is_err());
+    }
+
+    #[test]
+    fn create_liquidity_asset_parses_with_atomic_parser() {
+        let operation = TokenOperation::CreateLiquidityAsset {
+            name: "Pool".to_string(),
+            symbol: "POOL".to_string(),
+            max_supply: CAT_MIN_LIQUIDITY_SUPPLY_RAW,
+            metadata: vec![],
+            seed_reserve_sompi: CAT_MIN_LIQUIDITY_SEED_RESERVE_SOMPI,
+            fee_bps: 30,
+            fee_recipients: vec![address(2), address(3)],
+            launch_buy_sompi: 1_000,
+            launch_buy_min_token_out: 1,
+            platform_tag: Some("cli".to_string()),
+            liquidity_unlock_target_sompi: 0,
+            curve: TokenLiquidityCurve::Individual {
+                virtual_cpay_reserves_sompi: CAT_INDIVIDUAL_MIN_VIRTUAL_CPAY_RESERVES_SOMPI,
+                virtual_token_multiplier_bps: CAT_INDIVIDUAL_MIN_VIRTUAL_TOKEN_MULTIPLIER_BPS,
+            },
+        };
+        assert_eq!(operation.nonce_scope(), None);
+        assert_eq!(operation.auth_input_index(), TOKEN_AUTH_INPUT_INDEX);
+        assert!(operation.requires_liquidity_vault());
+        let payload = operation.encode(&address(1), 4, operation.auth_input_index(), owner_id).unwrap();
+
+        let TokenOp::CreateLiquidityAsset(op) = parse_atomic_token_payload(&payload).unwrap().unwrap().op else {
+            panic!("expected liquidity create")
+        };
+        assert_eq!(op.decimals, CAT_LIQUIDITY_TOKEN_DECIMALS);
+        assert_eq!(op.fee_bps, 30);
+        assert_eq!(op.recipients.len(), 2);
+        assert_eq!(op.recipients[1].address_version, Version::PubKey as u8);
+        assert_eq!(op.recipients[1].address_payload, vec![3; 32]);
+        assert_eq!(op.launch_buy_sompi, 1_000);
+        assert_eq!(op.platform_tag, b"cli".to_vec());
+        assert_eq!(op.curve_mode, CAT_LIQUIDITY_CURVE_MODE_INDIVIDUAL);
+        assert_eq!(op.individual_virtual_cpay_reserves_sompi, CAT_INDIVIDUAL_MIN_VIRTUAL_CPAY_RESERVES_SOMPI);
+        assert_eq!(op.individual_virtual_token_multiplier_bps, CAT_INDIVIDUAL_MIN_VIRTUAL_TOKEN_MULTIPLIER_BPS);
+
+        let unordered = TokenOperation::CreateLiquidityAsset {
+            name: "Pool".to_string(),
+            symbol: "POOL".to_string(),
+            max_supply: CAT_MIN_LIQUIDITY_SUPPLY_RAW,
+            metadata: vec![],
+            seed_reserve_sompi: CAT_MIN_LIQUIDITY_SEED_RESERVE_SOMPI,
+            fee_bps: 30,
+            fee_recipients: vec![address(3), address(2)],
+            launch_buy_sompi: 0,
+            launch_buy_min_token_out: 0,
+            platform_tag: None,
+            liquidity_unlock_target_sompi: 0,
+            curve: TokenLiquidityCurve::Basic,
+        };
+        assert!(unordered.encode(&address(1), 4, TOKEN_AUTH_INPUT_INDEX, owner_id).is_err());
+    }
+
+    #[test]
+    fn liquidity_trades_point_auth_at_the_input_after_the_vault() {
+        let sender = address(1);
+        let buy = TokenOperation::BuyLiquidityExactIn {
+            asset_id: ASSET_ID.to_string(),
+            expected_pool_nonce: 3,
+            cpay_in_sompi: 500,
+            min_token_out: 10,
+        };
+        assert_eq!(buy.nonce_scope(), Some(ASSET_ID));
+        assert_eq!(buy.auth_input_index(), TOKEN_LIQUIDITY_AUTH_INPUT_INDEX);
+        let parsed = parse_atomic_token_payload(&buy.encode(&sender, 5, buy.auth_input_index(), owner_id).unwrap()).unwrap().unwrap();
+        assert_eq!(parsed.header.auth_input_index, TOKEN_LIQUIDITY_AUTH_INPUT_INDEX);
+        let TokenOp::BuyLiquidityExactIn(op) = parsed.op else { panic!("expected buy exact in") };
+        assert_eq!((op.expected_pool_nonce, op.cpay_in_sompi, op.min_token_out), (3, 500, 10));
+
+        let sell = TokenOperation::SellLiquidityExactIn {
+            asset_id: ASSET_ID.to_string(),
+            expected_pool_nonce: 3,
+            token_in: 10,
+            min_cpay_out_sompi: 400,
+            cpay_receive_output_index: 1,
+        };
+        let payload = sell.encode(&sender, 5, sell.auth_input_index(), owner_id).unwrap();
+        let TokenOp::SellLiquidityExactIn(op) = parse_atomic_token_payload(&payload).unwrap().unwrap().op else {
+            panic!("expected sell")
+        };
+        assert_eq!((op.token_in, op.min_cpay_out_sompi, op.cpay_receive_output_index), (10, 400, 1));
+
+        let claim = TokenOperation::ClaimLiquidityFees {
+            asset_id: ASSET_ID.to_string(),
+            expected_pool_nonce: 3,
+            recipient_index: 1,
+            claim_amount_sompi: 250,
+            claim_receive_output_index: 1,
+        };
+        let payload = claim.encode(&sender, 5, claim.auth_input_index(), owner_id).unwrap();
+        let TokenOp::ClaimLiquidityFees(op) = parse_atomic_token_payload(&payload).unwrap().unwrap().op else {
+            panic!("expected claim")
+        };
+        assert_eq!((op.recipient_index, op.claim_amount_sompi, op.claim_receive_output_index), (1, 250, 1));
+
+        let buy_exact_out = TokenOperation::BuyLiquidityExactOut {
+            asset_id: ASSET_ID.to_string(),
+            expected_pool_nonce: 3,
+            token_out: 10,
+            max_cpay_in_sompi: 600,
+        };
+        let payload = buy_exact_out.encode(&sender, 5, buy_exact_out.auth_input_index(), owner_id).unwrap();
+        let TokenOp::BuyLiquidityExactOut(op) = parse_atomic_token_payload(&payload).unwrap().unwrap().op else {
+            panic!("expected buy exact out")
+        };
+        assert_eq!((op.token_out, op.max_cpay_in_sompi), (10, 600));
+
+        let sell_exact_out = TokenOperation::SellLiquidityExactOut {
+            asset_id: ASSET_ID.to_string(),
+            expected_pool_nonce: 3,
+            cpay_out_sompi: 400,
+            max_token_in: 12,
+            cpay_receive_output_index: 1,
+        };
+        assert!(sell_exact_out.spends_liquidity_vault());
+        let payload = sell_exact_out.encode(&sender, 5, sell_exact_out.auth_input_index(), owner_id).unwrap();
+        let TokenOp::SellLiquidityExactOut(op) = parse_atomic_token_payload(&payload).unwrap().unwrap().op else {
+            panic!("expected sell exact out")
+        };
+        assert_eq!((op.cpay_out_sompi, op.max_token_in, op.cpay_receive_output_index), (400, 12, 1));
+    }
+
+    #[test]
+    fn token_owner_id_depends_on_address_scheme() {
+        let pubkey = Address::new(Prefix::Testnet, Version::PubKey, &[7; 32]);
+        let script_hash = Address::new(Prefix::Testnet, Version::ScriptHash, &[7; 32]);
+        let ecdsa = Address::new(Prefix::Testnet, Version::PubKeyECDSA, &[7; 33]);
+
+        let owner_id = token_owner_id(&pubkey).unwrap();
+        assert_eq!(token_owner_id(&Address::new(Prefix::Mainnet, Version::PubKey, &[7; 32])), Some(owner_id));
+        assert_ne!(token_owner_id(&script_hash), Some(owner_id));
+        assert_ne!(token_owner_id(&ecdsa), Some(owner_id));
+        assert_eq!(token_owner_id(&Address::new(Prefix::Testnet, Version::PubKey, &[7; 20])), None);
+    }
+}
@@ -181,6 +181,57 @@ impl From<AtomicBalance> for Balance {
     }
 }
 
+#[wasm_bindgen(typescript_custom_section)]
+const TS_TOKEN_BALANCE: &'static str = r#"
+/**
+ * {@link UtxoContext} (wallet account) Cryptix Atomic token balance.
+ * @category Wallet SDK
+ */
+export interface ITokenBalance {
+    /**
+     * Asset id (64-char hex).
+     */
+    assetId: HexString;
+    /**
+     * Total token balance held by the account addresses,
+     * including the locked portion.
+     */
+    balance: bigint;
+    /**
+     * Portion of the balance still locked by a vesting schedule.
+     */
+    lockedBalance: bigint;
+}
+"#;
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenBalance {
+    pub asset_id: String,
+    pub balance: u128,
+    pub locked_balance: u128,
+}
+
+impl TokenBalance {
+    pub fn new(asset_id: String, balance: u128, locked_balance: u128) -> Self {
+        Self { asset_id, balance, locked_balance }
+    }
+
+    /// Sums per-owner balances into one entry per asset, sorted by asset id.
+    pub fn aggregate<'a>(balances: impl IntoIterator<Item = &'a TokenBalance>) -> Vec<TokenBalance> {
+        let mut totals: std::collections::BTreeMap<&str, (u128, u128)> = Default::default();
+        for entry in balances {
+            let total = totals.entry(entry.asset_id.as_str()).or_default();
+            total.0 = total.0.saturating_add(entry.balance);
+            total.1 = total.1.saturating_add(entry.locked_balance);
+        }
+        totals
+            .into_iter()
+            .map(|(asset_id, (balance, locked_balance))| TokenBalance::new(asset_id.to_string(), balance, locked_balance))
+            .collect()
+    }
+}
+
 pub struct BalanceStrings {
     pub mature: String,
     pub pending: Option<String>,
@@ -8,13 +8,20 @@ use crate::encryption::sha256_hash;
 use crate::events::Events;
 use crate::imports::*;
 use crate::result::Result;
-use crate::storage::TransactionRecord;
-use crate::tx::PendingTransaction;
+use crate::storage::{TokenTransferRecord, TransactionKind, TransactionRecord};
+use crate::tx::{token_owner_id, PendingTransaction};
 use crate::utxo::{
-    Maturity, NetworkParams, OutgoingTransaction, PendingUtxoEntryReference, UtxoContextBinding, UtxoEntryId, UtxoEntryReference,
-    UtxoEntryReferenceExtension, UtxoProcessor,
+    Maturity, NetworkParams, OutgoingTransaction, PendingUtxoEntryReference, TokenBalance, UtxoContextBinding, UtxoEntryId,
+    UtxoEntryReference, UtxoEntryReferenceExtension, UtxoProcessor,
 };
 use cryptix_hashes::Hash;
+use cryptix_rpc_core::{GetTokenBalancesByOwnerRequest, RpcTokenEvent};
+
+const TOKEN_BALANCES_PAGE_LIMIT: u32 = 512;
+// RpcTokenEvent::event_type / apply_status codes
+const TOKEN_EVENT_TYPE_APPLIED: u32 = 0;
+const TOKEN_EVENT_TYPE_REORGED: u32 = 2;
+const TOKEN_APPLY_STATUS_APPLIED: u32 = 0;
 use sorted_insert::SortedInsertBinaryByKey;
 
 static UTXO_CONTEXT_ID_SEQUENCER: AtomicU64 = AtomicU64::new(0);
@@ -96,6 +103,8 @@ pub struct Context {
     balance: Option<Balance>,
     /// Addresses monitored by this UTXO context
     addresses: Arc<DashSet<Arc<Address>>>,
+    /// Cryptix Atomic token balances indexed by owner id (hex)
+    token_balances: AHashMap<String, Vec<TokenBalance>>,
 }
 
 impl Default for Context {
@@ -108,6 +117,7 @@ impl Default for Context {
             outgoing: AHashMap::default(),
             balance: None,
             addresses: Arc::new(DashSet::new()),
+            token_balances: AHashMap::default(),
         }
     }
 }
@@ -125,6 +135,7 @@ impl Context {
         self.outgoing.clear();
         self.addresses.clear();
         self.balance = None;
+        self.token_balances.clear();
     }
 }
 
@@ -214,6 +225,153 @@ impl UtxoContext {
         self.context().addresses.clone()
     }
 
+    /// Token balances of all addresses in this context, one entry per asset.
+    pub fn token_balances(&self) -> Vec<TokenBalance> {
+        TokenBalance::aggregate(self.context().token_balances.values().flatten())
+    }
+
+    /// Owner ids (hex) of all addresses monitored by this context.
+    pub fn token_owner_ids(&self) -> Vec<String> {
+        self.addresses().iter().filter_map(|address| token_owner_id(&address)).map(|owner_id| owner_id.as_slice().to_hex()).collect()
+    }
+
+    /// Re-fetches token balances of all addresses monitored by this context.
+    pub async fn refresh_token_balances(&self) -> Result<Vec<TokenBalance>> {
+        self.update_token_balances(self.token_owner_ids()).await
+    }
+
+    /// Re-fetches token balances of the given owner ids and posts
+    /// [`Events::TokenBalance`] carrying the updated aggregate.
+    pub async fn update_token_balances(&self, owner_ids: Vec<String>) -> Result<Vec<TokenBalance>> {
+        let futures = owner_ids.into_iter().map(|owner_id| async move {
+            let balances = self.fetch_token_balances(&owner_id).await?;
+            Ok::<_, Error>((owner_id, balances))
+        });
+        let updates = join_all(futures).await.into_iter().collect::<Result<Vec<_>>>()?;
+
+        let balances = {
+            let mut context = self.context();
+            for (owner_id, balances) in updates.into_iter() {
+                if balances.is_empty() {
+                    context.token_balances.remove(&owner_id);
+                } else {
+                    context.token_balances.insert(owner_id, balances);
+                }
+            }
+            TokenBalance::aggregate(context.token_balances.values().flatten())
+        };
+        self.processor().notify(Events::TokenBalance { balances: balances.clone(), id: self.id() }).await?;
+
+        Ok(balances)
+    }
+
+    async fn fetch_token_balances(&self, owner_id: &str) -> Result<Vec<TokenBalance>> {
+        let rpc = self.processor().rpc_api();
+        let mut balances = vec![];
+        let mut offset = 0u32;
+        loop {
+            let response = rpc
+                .get_token_balances_by_owner_call(
+                    None,
+                    GetTokenBalancesByOwnerRequest {
+                        owner_id: owner_id.to_string(),
+                        offset,
+                        limit: TOKEN_BALANCES_PAGE_LIMIT,
+                        include_assets: false,
+                        at_block_hash: None,
+                    },
+                )
+                .await?;
+            if response.balances.is_empty() {
+                break;
+            }
+
+            offset = offset.saturating_add(response.balances.len() as u32);
+            for entry in response.balances.into_iter() {
+                let parse = |value: &str| {
+                    value
+                        .parse::<u128>()
+                        .map_err(|err| Error::custom(format!("invalid token balance `{value}` for asset `{}`: {err}", entry.asset_id)))
+                };
+                let balance = parse(&entry.balance)?;
+                let locked_balance = parse(&entry.locked_balance)?;
+                if balance > 0 {
+                    balances.push(TokenBalance::new(entry.asset_id.to_ascii_lowercase(), balance, locked_balance));
+                }
+            }
+
+            if u64::from(offset) >= response.total {
+                break;
+            }
+        }
+        Ok(balances)
+    }
+
+    /// Handles token events touching owner ids of this context: applied transfers
+    /// produce token transaction records, reorged ones retract them, and token
+    /// balances of the affected owners are re-fetched.
+    pub(crate) async fn handle_token_events(&self, events: Vec<RpcTokenEvent>) -> Result<()> {
+        let owner_ids = self.token_owner_ids().into_iter().collect::<HashSet<_>>();
+        let is_own = |owner_id: &Option<String>| owner_id.as_ref().is_some_and(|owner_id| owner_ids.contains(owner_id));
+
+        let mut applied: Vec<(TransactionId, Vec<TokenTransferRecord>)> = vec![];
+        let mut reorged: Vec<(TransactionId, Vec<TokenTransferRecord>)> = vec![];
+        let mut affected = HashSet::new();
+
+        for event in events.iter() {
+            let from_own = is_own(&event.from_owner_id);
+            let to_own = is_own(&event.to_owner_id);
+            if !from_own && !to_own {
+                continue;
+            }
+            if from_own {
+                affected.extend(event.from_owner_id.clone());
+            }
+            if to_own {
+                affected.extend(event.to_owner_id.clone());
+            }
+
+            let target = match (event.event_type, event.apply_status) {
+                (TOKEN_EVENT_TYPE_APPLIED, TOKEN_APPLY_STATUS_APPLIED) => &mut applied,
+                (TOKEN_EVENT_TYPE_REORGED, _) => &mut reorged,
+                _ => continue,
+            };
+            if let Some(transfer) = TokenTransferRecord::try_from_event(event) {
+                match target.iter_mut().find(|(txid, _)| *txid == event.txid) {
+                    Some((_, transfers)) => transfers.push(transfer),
+                    None => target.push((event.txid, vec![transfer])),
+                }
+            }
+        }
+
+        // transfers between this context's own addresses are recorded as internal
+        let kind_of = |transfers: &[TokenTransferRecord]| {
+            if transfers.iter().all(|transfer| is_own(&transfer.from_owner_id) && is_own(&transfer.to_owner_id)) {
+                TransactionKind::Change
+            } else if transfers.iter().any(|transfer| is_own(&transfer.from_owner_id)) {
+                TransactionKind::Outgoing
+            } else {
+                TransactionKind::Incoming
+            }
+        };
+
+        for (txid, transfers) in applied.into_iter() {
+            let record = TransactionRecord::new_token(self, kind_of(&transfers), txid, transfers)?;
+            self.processor().notify(Events::Maturity { record }).await?;
+        }
+
+        for (txid, transfers) in reorged.into_iter() {
+            let record = TransactionRecord::new_token(self, kind_of(&transfers), txid, transfers)?;
+            self.processor().notify(Events::Reorg { record }).await?;
+        }
+
+        if !affected.is_empty() {
+            self.update_token_balances(affected.into_iter().collect()).await?;
+        }
+
+        Ok(())
+    }
+
     pub async fn clear(&self) -> Result<()> {
         let local = self.addresses();
         let addresses = local.iter().map(|v| v.clone()).collect::<Vec<_>>();
@@ -9,14 +9,14 @@ use crate::imports::*;
 // use futures::pin_mut;
 use cryptix_notify::{
     listener::ListenerId,
-    scope::{Scope, UtxosChangedScope, VirtualChainChangedScope, VirtualDaaScoreChangedScope},
+    scope::{Scope, TokenEventsChangedScope, UtxosChangedScope, VirtualChainChangedScope, VirtualDaaScoreChangedScope},
 };
 use cryptix_rpc_core::{
     api::{
         ctl::{RpcCtl, RpcState},
         ops::{RPC_API_REVISION, RPC_API_VERSION},
     },
-    message::{UtxosChangedNotification, VirtualChainChangedNotification},
+    message::{TokenEventsChangedNotification, UtxosChangedNotification, VirtualChainChangedNotification},
     GetServerInfoResponse,
 };
 use cryptix_wrpc_client::CryptixRpcClient;
@@ -25,16 +25,17 @@ use workflow_core::task::spawn;
 
 use crate::events::Events;
 use crate::result::Result;
+use crate::tx::token_owner_id;
 use crate::utxo::{
-    Maturity, OutgoingTransaction, PendingUtxoEntryReference, SyncMonitor, UtxoContext, UtxoEntryId, UtxoEntryReference,
+    Maturity, OutgoingTransaction, PendingUtxoEntryReference, SyncMonitor, UtxoContext, UtxoContextId, UtxoEntryId, UtxoEntryReference,
 };
 use crate::wallet::WalletBusMessage;
 use cryptix_consensus_core::tx::Transaction;
-use cryptix_rpc_core::RpcHash;
 use cryptix_rpc_core::{
     notify::connection::{ChannelConnection, ChannelType},
     Notification,
 };
+use cryptix_rpc_core::{RpcHash, RpcTokenEvent};
 
 #[derive(Clone)]
 struct ConfirmedTransactionCacheEntry {
@@ -57,6 +58,9 @@ pub struct Inner {
     /// Address to UtxoContext map (maps all addresses used by
     /// all UtxoContexts to their respective UtxoContexts)
     address_to_utxo_context_map: DashMap<Arc<Address>, UtxoContext>,
+    /// Cryptix Atomic owner id (hex) to UtxoContext map, derived from
+    /// the registered addresses and used to route token events.
+    owner_id_to_utxo_context_map: DashMap<String, UtxoContext>,
     /// Recently confirmed transactions indexed by txid. Used to enrich
     /// incoming/external records with payload/subnetwork data when available.
     confirmed_transactions: DashMap<TransactionId, ConfirmedTransactionCacheEntry>,
@@ -74,6 +78,7 @@ pub struct Inner {
     wallet_bus: Option<Channel<WalletBusMessage>>,
     notification_guard: AsyncRwLock<()>,
     connect_disconnect_guard: AsyncMutex<()>,
+    token_subscription_guard: AsyncMutex<()>,
     metrics: Arc<Metrics>,
     metrics_kinds: Mutex<Vec<MetricsUpdateKind>>,
     connection_signaler: Mutex<Option<Sender<std::result::Result<(), String>>>>,
@@ -91,6 +96,7 @@ impl Inner {
             pending: DashMap::new(),
             outgoing: DashMap::new(),
             address_to_utxo_context_map: DashMap::new(),
+            owner_id_to_utxo_context_map: DashMap::new(),
             confirmed_transactions: DashMap::new(),
             current_daa_score: Arc::new(AtomicU64::new(0)),
             network_id: Arc::new(Mutex::new(network_id)),
@@ -105,6 +111,7 @@ impl Inner {
             wallet_bus,
             notification_guard: Default::default(),
             connect_disconnect_guard: Default::default(),
+            token_subscription_guard: Default::default(),
             metrics: Arc::new(Metrics::default()),
             metrics_kinds: Mutex::new(vec![]),
             connection_signaler: Mutex::new(None),
@@ -227,6 +234,10 @@ impl UtxoProcessor {
         self.inner.address_to_utxo_context_map.get(address).map(|v| v.clone())
     }
 
+    pub fn owner_id_to_utxo_context(&self, owner_id: &str) -> Option<UtxoContext> {
+        self.inner.owner_id_to_utxo_context_map.get(owner_id).map(|v| v.clone())
+    }
+
     pub fn confirmed_transaction(&self, txid: &TransactionId) -> Option<Transaction> {
         self.inner.confirmed_transactions.get(txid).map(|entry| (*entry.transaction).clone())
     }
@@ -234,6 +245,9 @@ impl UtxoProcessor {
     pub async fn register_addresses(&self, addresses: Vec<Arc<Address>>, utxo_context: &UtxoContext) -> Result<()> {
         addresses.iter().for_each(|address| {
             self.inner.address_to_utxo_context_map.insert(address.clone(), utxo_context.clone());
+            if let Some(owner_id) = token_owner_id(address) {
+                self.inner.owner_id_to_utxo_context_map.insert(owner_id.as_slice().to_hex(), utxo_context.clone());
+            }
         });
 
         if self.is_connected() {
@@ -241,6 +255,7 @@ impl UtxoProcessor {
                 let addresses = addresses.into_iter().map(|address| (*address).clone()).collect::<Vec<_>>();
                 let utxos_changed_scope = UtxosChangedScope::new(addresses);
                 self.rpc_api().start_notify(self.listener_id()?, utxos_changed_scope.into()).await?;
+                self.update_token_events_subscription().await;
             } else {
                 log_error!("registering an empty address list!");
             }
@@ -251,6 +266,9 @@ impl UtxoProcessor {
     pub async fn unregister_addresses(&self, addresses: Vec<Arc<Address>>) -> Result<()> {
         addresses.iter().for_each(|address| {
             self.inner.address_to_utxo_context_map.remove(address);
+            if let Some(owner_id) = token_owner_id(address) {
+                self.inner.owner_id_to_utxo_context_map.remove(&owner_id.as_slice().to_hex());
+            }
         });
 
         if self.is_connected() {
@@ -258,6 +276,7 @@ impl UtxoProcessor {
                 let addresses = addresses.into_iter().map(|address| (*address).clone()).collect::<Vec<_>>();
                 let utxos_changed_scope = UtxosChangedScope::new(addresses);
                 self.rpc_api().stop_notify(self.listener_id()?, utxos_changed_scope.into()).await?;
+                self.update_token_events_subscription().await;
             } else {
                 log_error!("unregistering empty address list!");
             }
@@ -265,6 +284,31 @@ impl UtxoProcessor {
         Ok(())
     }
 
+    /// Re-subscribes for TokenEventsChanged notifications of all registered owner ids.
+    /// A subscription replaces the previous filter of the listener, so the full
+    /// owner id set is always sent. Nodes without the Atomic index only produce
+    /// a warning; token tracking is then limited to explicit balance refreshes.
+    async fn update_token_events_subscription(&self) {
+        let _guard = self.inner.token_subscription_guard.lock().await;
+
+        let listener_id = match self.listener_id() {
+            Ok(listener_id) => listener_id,
+            Err(_) => return,
+        };
+
+        let owner_ids = self.inner.owner_id_to_utxo_context_map.iter().map(|entry| entry.key().clone()).collect::<Vec<_>>();
+        let result = if owner_ids.is_empty() {
+            self.rpc_api().stop_notify(listener_id, Scope::TokenEventsChanged(TokenEventsChangedScope::default())).await
+        } else {
+            let scope = TokenEventsChangedScope::new(vec![], owner_ids, vec![], vec![]);
+            self.rpc_api().start_notify(listener_id, Scope::TokenEventsChanged(scope)).await
+        };
+
+        if let Err(err) = result {
+            log_warn!("unable to subscribe for TokenEventsChanged notifications (token tracking disabled): {err}");
+        }
+    }
+
     pub async fn notify(&self, event: Events) -> Result<()> {
         self.multiplexer()
             .try_broadcast(Box::new(event))
@@ -648,6 +692,7 @@ impl UtxoProcessor {
         self.inner.stasis.clear();
         self.inner.outgoing.clear();
         self.inner.address_to_utxo_context_map.clear();
+        self.inner.owner_id_to_utxo_context_map.clear();
         self.inner.confirmed_transactions.clear();
         Ok(())
     }
@@ -703,6 +748,11 @@ impl UtxoProcessor {
                 self.handle_virtual_chain_changed(virtual_chain_changed_notification).await?;
             }
 
+            Notification::TokenEventsChanged(token_events_changed_notification) => {
+                // Balance refreshes issue RPC calls; keep them outside notification_guard.write().
+                self.handle_token_events_changed(token_events_changed_notification).await?;
+            }
+
             _ => {
                 log_warn!("unknown notification: {:?}", notification);
             }
@@ -711,6 +761,27 @@ impl UtxoProcessor {
         Ok(())
     }
 
+    async fn handle_token_events_changed(&self, notification: TokenEventsChangedNotification) -> Result<()> {
+        let mut context_events: HashMap<UtxoContextId, (UtxoContext, Vec<RpcTokenEvent>)> = HashMap::new();
+        for event in notification.events.into_iter() {
+            let mut contexts = [event.from_owner_id.as_deref(), event.to_owner_id.as_deref()]
+                .into_iter()
+                .flatten()
+                .filter_map(|owner_id| self.owner_id_to_utxo_context(owner_id))
+                .collect::<Vec<_>>();
+            contexts.dedup_by_key(|context| context.id());
+            for context in contexts {
+                context_events.entry(context.id()).or_insert_with(|| (context, vec![])).1.push(event.clone());
+            }
+        }
+
+        for (context, events) in context_events.into_values() {
+            context.handle_token_events(events).await?;
+        }
+
+        Ok(())
+    }
+
     fn deliver_metrics_snapshot(&self, snapshot: Box<MetricsSnapshot>) -> Result<()> {
         let metrics_kinds = self.inner.metrics_kinds.lock().unwrap().clone();
         for kind in metrics_kinds.into_iter() {
@@ -5,6 +5,8 @@ use crate::tx::generator::test::*;
 use crate::tx::*;
 use crate::utils::*;
 use crate::utxo::*;
+use cryptix_addresses::Version;
+use cryptix_rpc_core::RpcTokenEvent;
 
 #[tokio::test]
 async fn test_utxo_subsystem_bootstrap() -> Result<()> {
@@ -32,3 +34,138 @@ fn test_utxo_generator_empty_utxo_noop() -> Result<()> {
     // assert!(tx.is_none());
     Ok(())
 }
+
+fn token_owner_hex(address: &Address) -> String {
+    token_owner_id(address).unwrap().as_slice().to_hex()
+}
+
+fn token_transfer_event(txid: TransactionId, ordinal: u32, from: &Address, to: &Address) -> RpcTokenEvent {
+    RpcTokenEvent {
+        event_id: format!("{ordinal:064x}"),
+        sequence: ordinal as u64,
+        accepting_block_hash: Default::default(),
+        txid,
+        event_type: 0,
+        apply_status: 0,
+        noop_reason: 0,
+        ordinal,
+        reorg_of_event_id: None,
+        op_type: Some(1),
+        asset_id: Some("aa".repeat(32)),
+        from_owner_id: Some(token_owner_hex(from)),
+        to_owner_id: Some(token_owner_hex(to)),
+        amount: Some("100".to_string()),
+    }
+}
+
+#[tokio::test]
+async fn test_utxo_context_token_events() -> Result<()> {
+    let network_id = NetworkId::new(NetworkType::Testnet);
+    let rpc_api_mock = Arc::new(RpcCoreMock::new());
+    let processor = UtxoProcessor::new(Some(rpc_api_mock.clone().into()), Some(network_id), None, None);
+    let context = UtxoContext::new(&processor, UtxoContextBinding::default());
+
+    processor.mock_set_connected(true);
+    processor.handle_daa_score_change(1).await?;
+
+    let own = Address::new(Prefix::Testnet, Version::PubKey, &[1; 32]);
+    let change = Address::new(Prefix::Testnet, Version::PubKey, &[2; 32]);
+    let foreign = Address::new(Prefix::Testnet, Version::PubKey, &[3; 32]);
+    context.addresses().insert(Arc::new(own.clone()));
+    context.addresses().insert(Arc::new(change.clone()));
+
+    let incoming = TransactionId::from_u64_word(1);
+    let outgoing = TransactionId::from_u64_word(2);
+    let internal = TransactionId::from_u64_word(3);
+    let unrelated = TransactionId::from_u64_word(4);
+
+    let channel = processor.multiplexer().channel();
+    context
+        .handle_token_events(vec![
+            token_transfer_event(incoming, 0, &foreign, &own),
+            token_transfer_event(outgoing, 1, &own, &foreign),
+            token_transfer_event(internal, 2, &own, &change),
+            token_transfer_event(unrelated, 3, &foreign, &foreign),
+        ])
+        .await?;
+
+    let mut records = vec![];
+    while let Ok(event) = channel.try_recv() {
+        if let Events::Maturity { record } = *event {
+            records.push(record);
+        }
+    }
+
+    let summary = records.iter().map(|record| (*record.transaction_id(), record.data_type())).collect::<Vec<_>>();
+    assert_eq!(
+        summary,
+        vec![
+            (incoming, "token-incoming".to_string()),
+            (outgoing, "token-outgoing".to_string()),
+            (internal, "token-internal".to_string()),
+        ]
+    );
+    assert_eq!(records[2].kind(), TransactionKind::Change);
+    assert!(records.iter().all(|record| record.id() != record.transaction_id()));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_utxo_context_token_record_does_not_overwrite_fee_record() -> Result<()> {
+    use crate::storage::local::transaction::fsio::TransactionStore;
+    use cryptix_consensus_core::subnets::SUBNETWORK_ID_PAYLOAD;
+    use cryptix_consensus_core::tx::Transaction;
+
+    let network_id = NetworkId::new(NetworkType::Testnet);
+    let rpc_api_mock = Arc::new(RpcCoreMock::new());
+    let processor = UtxoProcessor::new(Some(rpc_api_mock.clone().into()), Some(network_id), None, None);
+    let context = UtxoContext::new(&processor, UtxoContextBinding::default());
+
+    processor.mock_set_connected(true);
+    processor.handle_daa_score_change(1).await?;
+
+    let own = Address::new(Prefix::Testnet, Version::PubKey, &[1; 32]);
+    let foreign = Address::new(Prefix::Testnet, Version::PubKey, &[3; 32]);
+    context.addresses().insert(Arc::new(own.clone()));
+
+    // the CPAY side of a token transfer is recorded under the on-chain txid
+    let transaction = Transaction::new(0, vec![], vec![], 0, SUBNETWORK_ID_PAYLOAD, 0, vec![1, 2, 3]);
+    let txid = transaction.id();
+
+    let channel = processor.multiplexer().channel();
+    context.handle_token_events(vec![token_transfer_event(txid, 0, &own, &foreign)]).await?;
+    let token_record = loop {
+        if let Events::Maturity { record } = *channel.try_recv().expect("token record") {
+            break record;
+        }
+    };
+
+    let mut fee_record = token_record.clone();
+    fee_record.id = txid;
+    fee_record.transaction_data = TransactionData::Outgoing {
+        fees: 1_000,
+        aggregate_input_value: 11_000,
+        aggregate_output_value: 10_000,
+        transaction,
+        payment_value: None,
+        change_value: 10_000,
+        accepted_daa_score: None,
+        utxo_entries: vec![],
+    };
+
+    let folder = std::env::temp_dir().join(format!("cryptix-token-record-test-{}", std::process::id()));
+    let store = TransactionStore::new(&folder, "token");
+    store.store(&[&fee_record, &token_record]).await?;
+
+    let binding = token_record.binding().clone();
+    let fee = store.load_single(&binding, &network_id, &txid).await?;
+    let token = store.load_single(&binding, &network_id, token_record.id()).await?;
+    assert_eq!(fee.kind(), TransactionKind::Outgoing);
+    assert!(!fee.transaction_data().is_token());
+    assert_eq!(token.data_type(), "token-outgoing");
+    assert_eq!(token.transaction_id(), &txid);
+
+    std::fs::remove_dir_all(folder).ok();
+    Ok(())
+}
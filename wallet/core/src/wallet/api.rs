@@ -703,6 +703,70 @@ impl WalletApi for super::Wallet {
         })
     }
 
+    async fn accounts_token_operation_call(
+        self: Arc<Self>,
+        request: AccountsTokenOperationRequest,
+    ) -> Result<AccountsTokenOperationResponse> {
+        let AccountsTokenOperationRequest {
+            account_id,
+            wallet_secret,
+            payment_secret,
+            sender_address,
+            operation,
+            nonce,
+            priority_fee_sompi,
+        } = request;
+
+        if operation.requires_liquidity_vault() {
+            return Err(Error::custom("liquidity pool operations must create or spend the pool vault and are not supported here"));
+        }
+
+        let guard = self.guard();
+        let guard = guard.lock().await;
+        let account = self.get_account_by_id(&account_id, &guard).await?.ok_or(Error::AccountNotFound(account_id))?;
+        let sender_address = match sender_address {
+            Some(sender_address) => sender_address,
+            None => account.receive_address()?,
+        };
+        let token_payload = operation.resolve(&self.rpc_api(), &sender_address, nonce).await?;
+
+        let legacy_account = account.clone().as_legacy_account().ok();
+        if let Some(legacy_account) = legacy_account.as_ref() {
+            legacy_account.create_private_context(&wallet_secret, payment_secret.as_ref(), None).await?;
+        }
+
+        let abortable = Abortable::new();
+        let send_result = account
+            .send(
+                token_payload.destination(),
+                priority_fee_sompi,
+                Some(token_payload.payload),
+                Some(sender_address),
+                None,
+                wallet_secret,
+                payment_secret,
+                &abortable,
+                None,
+            )
+            .await;
+        if let Some(legacy_account) = legacy_account.as_ref() {
+            let clear_result = legacy_account.clear_private_context().await;
+            if send_result.is_ok() {
+                clear_result?;
+            } else if let Err(err) = clear_result {
+                log_warn!("failed to clear legacy private context after token operation error: {err}");
+            }
+        }
+        let (generator_summary, transaction_ids, _) = send_result?;
+
+        Ok(AccountsTokenOperationResponse {
+            generator_summary,
+            transaction_ids,
+            sender_owner_id: token_payload.sender_owner_id,
+            nonce: token_payload.nonce,
+        })
+    }
+
     async fn accounts_transfer_call(self: Arc<Self>, request: AccountsTransferRequest) -> Result<AccountsTransferResponse> {
         let AccountsTransferRequest {
             source_account_id,
@@ -801,7 +865,7 @@ impl WalletApi for super::Wallet {
             if let Ok(resolved_transactions) = self.resolve_records_transactions_by_ids(&records, &enrichment_indices).await {
                 batch_lookup_available = true;
                 for index in enrichment_indices.iter().copied() {
-                    let Some(transaction) = resolved_transactions.get(records[index].id()).cloned() else {
+                    let Some(transaction) = resolved_transactions.get(records[index].transaction_id()).cloned() else {
                         continue;
                     };
                     if records[index].try_attach_transaction(transaction) {
@@ -1186,6 +1186,9 @@ impl Wallet {
                 | TransactionData::External { .. }
                 | TransactionData::Reorg { .. }
                 | TransactionData::Stasis { .. }
+                | TransactionData::TokenIncoming { .. }
+                | TransactionData::TokenOutgoing { .. }
+                | TransactionData::TokenInternal { .. }
         )
     }
 
@@ -1201,7 +1204,7 @@ impl Wallet {
         &self,
         record: &TransactionRecord,
     ) -> Option<cryptix_consensus_core::tx::Transaction> {
-        let target_txid = *record.id();
+        let target_txid = *record.transaction_id();
         let target_block_daa_score = record.block_daa_score();
         let header_limit = self.transaction_enrichment_header_limit(target_block_daa_score);
 
@@ -1291,11 +1294,11 @@ impl Wallet {
         record: &TransactionRecord,
         allow_chain_lookup: bool,
     ) -> Option<cryptix_consensus_core::tx::Transaction> {
-        if let Some(transaction) = self.utxo_processor().confirmed_transaction(record.id()) {
+        if let Some(transaction) = self.utxo_processor().confirmed_transaction(record.transaction_id()) {
             return Some(transaction);
         }
 
-        if let Ok(mempool_entry) = self.rpc_api().get_mempool_entry(*record.id(), true, false).await {
+        if let Ok(mempool_entry) = self.rpc_api().get_mempool_entry(*record.transaction_id(), true, false).await {
             if let Ok(transaction) = cryptix_consensus_core::tx::Transaction::try_from(mempool_entry.transaction) {
                 return Some(transaction);
             }
@@ -1321,7 +1324,7 @@ impl Wallet {
             .iter()
             .filter_map(|index| records.get(*index))
             .map(|record| RpcTransactionLookupRequest {
-                transaction_id: *record.id(),
+                transaction_id: *record.transaction_id(),
                 block_daa_score: Some(record.block_daa_score()),
             })
             .collect::<Vec<_>>();
@@ -1431,6 +1434,14 @@ impl Wallet {
                     return Ok(None);
                 }
 
+                if enriched_record.is_token() {
+                    self.store()
+                        .as_transaction_record_store()?
+                        .remove(enriched_record.binding(), enriched_record.network_id(), &[enriched_record.id()])
+                        .await?;
+                    return Ok(Some(Events::Reorg { record: enriched_record }));
+                }
+
                 self.store().as_transaction_record_store()?.store(&[&enriched_record]).await?;
                 Ok(Some(Events::Reorg { record: enriched_record }))
             }
@@ -4,7 +4,7 @@ use super::extensions::*;
 use crate::account::descriptor::IAccountDescriptor;
 use crate::api::message::*;
 use crate::imports::*;
-use crate::tx::{payment_destination_from_js_outputs, Fees};
+use crate::tx::{payment_destination_from_js_outputs, Fees, TokenOperation};
 use crate::wasm::tx::fees::IFees;
 use crate::wasm::tx::GeneratorSummary;
 use js_sys::Array;
@@ -1749,6 +1749,125 @@ try_from!(args: AccountsSendResponse, IAccountsSendResponse, {
 
 // ---
 
+declare! {
+    IAccountsTokenOperationRequest,
+    r#"
+    /**
+     * Cryptix Atomic token operation. `op` selects the operation; asset and
+     * pool ids are hex strings, owners are addresses and token amounts are bigints.
+     *
+     * @category Wallet API
+     */
+    export type ITokenOperation =
+        | { op : "createAsset", name : string, symbol : string, decimals : number, supplyMode : "uncapped" | "capped", maxSupply : bigint, mintAuthority? : Address | string, metadata : Uint8Array | number[], platformTag? : string }
+        | { op : "createAssetWithMint", name : string, symbol : string, decimals : number, supplyMode : "uncapped" | "capped", maxSupply : bigint, mintAuthority? : Address | string, metadata : Uint8Array | number[], initialMintAmount : bigint, initialMintTo? : Address | string, platformTag? : string }
+        | { op : "transfer", assetId : HexString, to : Address | string, amount : bigint }
+        | { op : "batchTransfer", assetId : HexString, recipients : { address : Address | string, amount : bigint }[] }
+        | { op : "mint", assetId : HexString, to : Address | string, amount : bigint }
+        | { op : "burn", assetId : HexString, amount : bigint }
+        | { op : "approve", assetId : HexString, spender : Address | string, amount : bigint, expiresAtDaaScore : bigint }
+        | { op : "transferFrom", assetId : HexString, from : Address | string, to : Address | string, amount : bigint }
+        | { op : "lockTokens", assetId : HexString, beneficiary : Address | string, amount : bigint, releaseStartDaaScore : bigint, releaseEndDaaScore : bigint }
+        | { op : "transferMintAuthority", assetId : HexString, newAuthority : Address | string }
+        | { op : "renounceMintAuthority", assetId : HexString }
+        | { op : "updateMetadata", assetId : HexString, metadata : Uint8Array | number[], platformTag? : string }
+        | { op : "createPairPool", assetAId : HexString, assetBId : HexString, feeBps : number, amountA : bigint, amountB : bigint }
+        | { op : "addPairLiquidity", poolId : HexString, amountAMax : bigint, amountBMax : bigint, minSharesOut : bigint }
+        | { op : "removePairLiquidity", poolId : HexString, shares : bigint, minAmountAOut : bigint, minAmountBOut : bigint }
+        | { op : "swapPairExactIn", poolId : HexString, assetInId : HexString, amountIn : bigint, minAmountOut : bigint }
+        | { op : "swapPairExactOut", poolId : HexString, assetOutId : HexString, amountOut : bigint, maxAmountIn : bigint };
+
+    /**
+     * 
+     *  
+     * @category Wallet API
+     */
+    export interface IAccountsTokenOperationRequest {
+        /**
+         * Hex identifier of the account.
+         */
+        accountId : HexString;
+        /**
+         * Address that signs the operation. Defaults to the account receive address.
+         * Only UTXOs of this address are spent.
+         */
+        senderAddress? : Address | string;
+        /**
+         * Wallet encryption secret.
+         */
+        walletSecret : string;
+        /**
+         * Optional key encryption secret or BIP39 passphrase.
+         */
+        paymentSecret? : string;
+        /**
+         * Token operation to submit.
+         */
+        operation : ITokenOperation;
+        /**
+         * Sender nonce. Resolved from the node when not supplied.
+         */
+        nonce? : bigint;
+        /**
+         * Priority fee.
+         */
+        priorityFeeSompi? : IFees | bigint;
+    }
+    "#,
+}
+
+try_from! ( args: IAccountsTokenOperationRequest, AccountsTokenOperationRequest, {
+    let account_id = args.get_account_id("accountId")?;
+    let sender_address = args.try_cast_into::<Address>("senderAddress")?;
+    let wallet_secret = args.get_secret("walletSecret")?;
+    let payment_secret = args.try_get_secret("paymentSecret")?;
+    let operation = from_value::<TokenOperation>(args.get_value("operation")?)?;
+    let nonce = args.try_get_value("nonce")?.map(|v| v.try_as_u64()).transpose()?;
+    let priority_fee_sompi = args.get::<IFees>("priorityFeeSompi")?.try_into()?;
+
+    Ok(AccountsTokenOperationRequest { account_id, wallet_secret, payment_secret, sender_address, operation, nonce, priority_fee_sompi })
+});
+
+declare! {
+    IAccountsTokenOperationResponse,
+    r#"
+    /**
+     * 
+     *  
+     * @category Wallet API
+     */
+    export interface IAccountsTokenOperationResponse {
+        /**
+         * Summary produced by the transaction generator.
+         */
+        generatorSummary : GeneratorSummary;
+        /**
+         * Hex identifiers of successfully submitted transactions.
+         */
+        transactionIds : HexString[];
+        /**
+         * Hex owner id of the sender address.
+         */
+        senderOwnerId : HexString;
+        /**
+         * Sender nonce carried by the operation.
+         */
+        nonce : bigint;
+    }
+    "#,
+}
+
+try_from!(args: AccountsTokenOperationResponse, IAccountsTokenOperationResponse, {
+    let response = IAccountsTokenOperationResponse::default();
+    response.set("generatorSummary", &GeneratorSummary::from(args.generator_summary).into())?;
+    response.set("transactionIds", &to_value(&args.transaction_ids)?)?;
+    response.set("senderOwnerId", &to_value(&args.sender_owner_id)?)?;
+    response.set("nonce", &BigInt::from(args.nonce).into())?;
+    Ok(response)
+});
+
+// ---
+
 declare! {
     IAccountsTransferRequest,
     r#"
@@ -50,6 +50,7 @@ declare_wasm_handlers!([
     AccountsUtxos,
     AccountsCreateNewAddress,
     AccountsSend,
+    AccountsTokenOperation,
     AccountsTransfer,
     AccountsEstimate,
     TransactionsDataGet,
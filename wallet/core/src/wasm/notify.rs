@@ -28,6 +28,7 @@ cfg_if! {
             Maturity = "maturity",
             Discovery = "discovery",
             Balance = "balance",
+            TokenBalance = "token-balance",
             Error = "error",
         }
 
@@ -52,6 +53,7 @@ cfg_if! {
             "maturity": IMaturityEvent,
             "discovery": IDiscoveryEvent,
             "balance": IBalanceEvent,
+            "token-balance": ITokenBalanceEvent,
             "error": IErrorEvent
         }
 
@@ -130,6 +132,7 @@ cfg_if! {
             Maturity = "maturity",
             Discovery = "discovery",
             Balance = "balance",
+            TokenBalance = "token-balance",
             Error = "error",
         }
 
@@ -166,6 +169,7 @@ cfg_if! {
             "maturity": IMaturityEvent,
             "discovery": IDiscoveryEvent,
             "balance": IBalanceEvent,
+            "token-balance": ITokenBalanceEvent,
             "error": IErrorEvent,
         }
         
@@ -593,6 +597,23 @@ declare! {
     "#,
 }
 
+declare! {
+    ITokenBalanceEvent,
+    r#"
+    /**
+     * Emitted by {@link UtxoContext} when the Cryptix Atomic token balances
+     * of its addresses change. Carries the full set of non-zero token
+     * balances, one entry per asset.
+     * 
+     * @category Wallet Events
+     */
+    export interface ITokenBalanceEvent {
+        id : HexString;
+        balances : ITokenBalance[];
+    }
+    "#,
+}
+
 declare! {
     IErrorEvent,
     r#"
@@ -1,16 +1,13 @@
 use crate::imports::*;
+use crate::messenger::messenger_payload_limits;
 use crate::result::Result;
 use crate::tx::{
     classify_messenger_payload, serialize_messenger_v1, validate_wallet_payload, MessengerEnvelopeV1Header, MessengerPayloadClass,
-    MESSENGER_ENVELOPE_V1_HEADER_LEN, MESSENGER_NONCE_LEN, MESSENGER_RECIPIENT_TAG_LEN, MESSENGER_SENDER_DATA_LEN,
-    WALLET_PAYLOAD_HARD_LIMIT_BYTES,
+    MESSENGER_NONCE_LEN, MESSENGER_RECIPIENT_TAG_LEN, MESSENGER_SENDER_DATA_LEN,
 };
 use cryptix_consensus_core::constants::{MAX_SOMPI, SOMPI_PER_CRYPTIX};
 use cryptix_wasm_core::types::BinaryT;
 
-const CRYPTOBOX_NONCE_BYTES: usize = 24;
-const CRYPTOBOX_TAG_BYTES: usize = 16;
-const CRYPTOBOX_OVERHEAD_BYTES: usize = CRYPTOBOX_NONCE_BYTES + CRYPTOBOX_TAG_BYTES;
 const CAT_MAGIC: [u8; 3] = *b"CAT";
 const CAT_VERSION: u8 = 1;
 const CAT_CURRENT_TOKEN_VERSION: u8 = 1;
@@ -329,17 +326,14 @@ fn parse_liquidity_recipients(recipients: Array) -> Result<Vec<LiquidityRecipien
 /// @category Wallet SDK
 #[wasm_bindgen(js_name = messengerPayloadLimits)]
 pub fn messenger_payload_limits_js() -> Result<Object> {
-    let max_payload_bytes = WALLET_PAYLOAD_HARD_LIMIT_BYTES;
-    let header_bytes = MESSENGER_ENVELOPE_V1_HEADER_LEN;
-    let max_body_bytes = max_payload_bytes.saturating_sub(header_bytes);
-    let max_cryptobox_plaintext_bytes = max_body_bytes.saturating_sub(CRYPTOBOX_OVERHEAD_BYTES);
+    let limits = messenger_payload_limits();
 
     let object = Object::new();
-    object.set("maxPayloadBytes", &JsValue::from_f64(max_payload_bytes as f64))?;
-    object.set("headerBytes", &JsValue::from_f64(header_bytes as f64))?;
-    object.set("maxBodyBytes", &JsValue::from_f64(max_body_bytes as f64))?;
-    object.set("cryptoboxOverheadBytes", &JsValue::from_f64(CRYPTOBOX_OVERHEAD_BYTES as f64))?;
-    object.set("maxCryptoboxPlaintextBytes", &JsValue::from_f64(max_cryptobox_plaintext_bytes as f64))?;
+    object.set("maxPayloadBytes", &JsValue::from_f64(limits.max_payload_bytes as f64))?;
+    object.set("headerBytes", &JsValue::from_f64(limits.header_bytes as f64))?;
+    object.set("maxBodyBytes", &JsValue::from_f64(limits.max_body_bytes as f64))?;
+    object.set("cryptoboxOverheadBytes", &JsValue::from_f64(limits.cryptobox_overhead_bytes as f64))?;
+    object.set("maxCryptoboxPlaintextBytes", &JsValue::from_f64(limits.max_cryptobox_plaintext_bytes as f64))?;
 
     Ok(object)
 }
@@ -236,6 +236,18 @@ impl UtxoContext {
             Ok(None)
         }
     }
+
+    /// Current Cryptix Atomic token balances of the UtxoContext, one entry per asset.
+    #[wasm_bindgen(getter, js_name = "tokenBalances")]
+    pub fn token_balances(&self) -> Result<TokenBalanceArrayT> {
+        Ok(serde_wasm_bindgen::to_value(&self.inner().token_balances())?.unchecked_into())
+    }
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(extends = Array, typescript_type = "ITokenBalance[]")]
+    pub type TokenBalanceArrayT;
 }
 
 impl From<native::UtxoContext> for UtxoContext {
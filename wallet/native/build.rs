@@ -1,9 +1,10 @@
 fn main() {
     println!("cargo:rerun-if-changed=proto/cryptixwalletd.proto");
+    println!("cargo:rerun-if-changed=proto/partially_signed_transaction.proto");
 
     tonic_build::configure()
         .build_server(true)
         .build_client(true)
-        .compile(&["proto/cryptixwalletd.proto"], &["proto"])
-        .expect("failed to compile cryptixwalletd.proto");
+        .compile(&["proto/cryptixwalletd.proto", "proto/partially_signed_transaction.proto"], &["proto"])
+        .expect("failed to compile wallet daemon protos");
 }
@@ -1,4 +1,5 @@
-use cryptix_addresses::Address;
+use crate::serialization::{self, LegacyAccountKeys, SigningKeys, TransactionFormat};
+use cryptix_addresses::{Address, Prefix};
 use cryptix_consensus_client::{TransactionOutpoint as ClientTransactionOutpoint, UtxoEntry, UtxoEntryReference};
 use cryptix_consensus_core::config::params::Params;
 use cryptix_consensus_core::constants::{MAX_SOMPI, SOMPI_PER_CRYPTIX};
@@ -9,20 +10,31 @@ use cryptix_rpc_core::model::message::{
     GetLiquidityPoolStateRequest, GetLiquidityQuoteRequest, GetTokenBalancesByOwnerRequest, GetTokenNonceRequest,
     GetTokenOwnerIdByAddressRequest, RpcLiquidityPoolState,
 };
-use cryptix_wallet_core::account::{descriptor::AccountDescriptor, Account, BIP32_ACCOUNT_KIND};
+use cryptix_rpc_core::model::tx::RpcTransaction;
+use cryptix_wallet_core::account::descriptor::{AccountDescriptor, AccountDescriptorProperty, AccountDescriptorValue};
+use cryptix_wallet_core::account::{Account, BIP32_ACCOUNT_KIND};
 use cryptix_wallet_core::api::message::{
     AccountsActivateRequest, AccountsCreateNewAddressRequest, AccountsEnsureDefaultRequest, AccountsEnumerateRequest,
     AccountsEstimateRequest, AccountsSelectRequest, AccountsSendRequest, ConnectRequest, NewAddressKind, WalletOpenRequest,
 };
 use cryptix_wallet_core::api::traits::WalletApi;
+use cryptix_wallet_core::error::Error as WalletError;
 use cryptix_wallet_core::prelude::Secret;
+use cryptix_wallet_core::storage::{Binding, TransactionData};
 use cryptix_wallet_core::tx::{
-    Fees, Generator, GeneratorSettings, PaymentDestination, PaymentOutput, ScriptPaymentOutput, ScriptPaymentOutputs,
+    Fees, Generator, GeneratorSettings, PaymentDestination, PaymentOutput, PendingTransaction, ScriptPaymentOutput,
+    ScriptPaymentOutputs, TokenLiquidityCurve, TokenOperation, TokenRecipient, TokenSupplyMode, CAT_LIQUIDITY_TOKEN_DECIMALS,
+    CAT_MAGIC, CAT_MAX_BATCH_TRANSFER_RECIPIENTS, CAT_MAX_DECIMALS, CAT_MAX_LIQUIDITY_FEE_BPS, CAT_MAX_LIQUIDITY_RECIPIENTS,
+    CAT_MAX_LIQUIDITY_SUPPLY_RAW, CAT_MAX_METADATA_LEN, CAT_MAX_NAME_LEN, CAT_MAX_PLATFORM_TAG_LEN, CAT_MAX_SYMBOL_LEN,
+    CAT_MIN_LIQUIDITY_FEE_BPS, CAT_MIN_LIQUIDITY_SEED_RESERVE_SOMPI, CAT_MIN_LIQUIDITY_SUPPLY_RAW, TOKEN_AUTH_INPUT_INDEX,
+    TOKEN_CARRIER_OUTPUT_SOMPI, TOKEN_LIQUIDITY_AUTH_INPUT_INDEX,
 };
+use cryptix_wallet_core::utxo::UtxoIterator;
 use cryptix_wallet_core::wallet::Wallet;
 use cryptix_wrpc_client::Resolver;
 use futures::TryStreamExt;
-use std::collections::{BTreeMap, HashSet};
+use prost::Message as _;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::net::SocketAddr;
 use std::str::FromStr;
 use std::sync::Arc;
@@ -36,49 +48,127 @@ pub mod pb {
 
 const DEFAULT_DAEMON_LISTEN: &str = "localhost:8082";
 const DEFAULT_RPC_SERVER: &str = "localhost";
-const CAT_MAGIC: [u8; 3] = *b"CAT";
-const CAT_VERSION: u8 = 1;
-const CAT_CURRENT_TOKEN_VERSION: u8 = 1;
-const CAT_CURRENT_LIQUIDITY_CURVE_VERSION: u8 = 1;
-const CAT_FLAGS: u8 = 0;
-const CAT_OP_CREATE_ASSET: u8 = 0;
-const CAT_OP_TRANSFER: u8 = 1;
-const CAT_OP_MINT: u8 = 2;
-const CAT_OP_BURN: u8 = 3;
-const CAT_OP_CREATE_ASSET_WITH_MINT: u8 = 4;
-const CAT_OP_CREATE_LIQUIDITY_ASSET: u8 = 5;
-const CAT_OP_BUY_LIQUIDITY_EXACT_IN: u8 = 6;
-const CAT_OP_SELL_LIQUIDITY_EXACT_IN: u8 = 7;
-const CAT_OP_CLAIM_LIQUIDITY_FEES: u8 = 8;
-const CAT_MAX_NAME_LEN: usize = 32;
-const CAT_MAX_SYMBOL_LEN: usize = 10;
-const CAT_MAX_METADATA_LEN: usize = 256;
-const CAT_MAX_PLATFORM_TAG_LEN: usize = 50;
-const CAT_MAX_DECIMALS: u8 = 18;
-const CAT_MAX_LIQUIDITY_RECIPIENTS: usize = 2;
-const CAT_MIN_LIQUIDITY_FEE_BPS: u16 = 10;
-const CAT_MAX_LIQUIDITY_FEE_BPS: u16 = 1000;
-const LIQUIDITY_TOKEN_DECIMALS: u8 = 0;
-const MIN_LIQUIDITY_TOKEN_SUPPLY_RAW: u128 = 100_000;
-const MAX_LIQUIDITY_TOKEN_SUPPLY_RAW: u128 = 10_000_000;
-const MIN_LIQUIDITY_SEED_RESERVE_SOMPI: u64 = SOMPI_PER_CRYPTIX;
 const MIN_LIQUIDITY_REAL_TOKEN_RESERVE_RAW: u128 = 1;
 const INITIAL_VIRTUAL_CPAY_RESERVES_SOMPI: u64 = 250_000_000_000_000;
-const DEFAULT_AUTH_INPUT_INDEX: u16 = 0;
-const LIQUIDITY_AUTH_INPUT_INDEX: u16 = 1;
 const LIQUIDITY_QUOTE_SIDE_BUY: u32 = 0;
 const LIQUIDITY_QUOTE_SIDE_SELL: u32 = 1;
-const TOKEN_CARRIER_OUTPUT_SOMPI: u64 = 1_000;
 const TOKEN_OWNER_BALANCES_PAGE_LIMIT: u32 = 512;
 const LIQUIDITY_VAULT_SCRIPT_VERSION: u16 = 0;
 const LIQUIDITY_VAULT_SCRIPT: [u8; 7] = [0x04, b'C', b'L', b'V', b'1', 0x75, 0x51];
 const MIN_FEE_RATE_SOMPI_PER_GRAM: f64 = 1.0;
 const DEFAULT_SEND_MAX_FEE_SOMPI: u64 = SOMPI_PER_CRYPTIX;
+const FEE_BUMP_MAX_ATTEMPTS: usize = 3;
 
-#[derive(Clone)]
-struct LiquidityRecipient {
-    address_version: u8,
-    address_payload: Vec<u8>,
+/// Validated send parameters shared by `Send` and `CreateUnsignedTransactions`.
+struct PreparedSend {
+    sender_address: Option<Address>,
+    destination: PaymentDestination,
+    fees: Fees,
+}
+
+/// A pending wallet transaction, as needed to build its fee bump replacement.
+struct FeeBumpOriginal {
+    transaction: cryptix_consensus_core::tx::Transaction,
+    change_output_index: Option<usize>,
+    inputs: Vec<UtxoEntryReference>,
+    fees: u64,
+    mass: u64,
+}
+
+/// Outputs of a fee bump replacement.
+enum FeeBumpOutputs {
+    /// The non-change outputs of the original, paid unchanged. The raised fee is taken from the change.
+    Payments(Vec<ScriptPaymentOutput>),
+    /// The only output of a change-only original, such as a compound. The generator charges no fees on a
+    /// change-only destination, so the output is kept explicit and pays `fee_increase` plus the priority fee.
+    SelfOutput { output: ScriptPaymentOutput, fee_increase: u64 },
+}
+
+impl FeeBumpOutputs {
+    /// `refund_sompi` is the change of a previous build, returned to the self output so the replacement keeps a
+    /// single output.
+    fn destination(&self, priority_fee_sompi: u64, refund_sompi: u64) -> Result<PaymentDestination, Status> {
+        match self {
+            FeeBumpOutputs::Payments(outputs) => Ok(PaymentDestination::from(ScriptPaymentOutputs { outputs: outputs.clone() })),
+            FeeBumpOutputs::SelfOutput { output, fee_increase } => {
+                let amount = output
+                    .amount
+                    .saturating_add(refund_sompi)
+                    .checked_sub(fee_increase.saturating_add(priority_fee_sompi))
+                    .filter(|amount| *amount > 0)
+                    .ok_or_else(|| Status::failed_precondition("the transaction output is too small to pay the raised fee"))?;
+                Ok(PaymentDestination::from(ScriptPaymentOutput::new(amount, output.script_public_key.clone())))
+            }
+        }
+    }
+}
+
+/// A fee bump replacement, before its priority fee is known.
+struct FeeBumpPlan {
+    network_id: NetworkId,
+    change_address: Address,
+    sig_op_count: u8,
+    minimum_signatures: u16,
+    outputs: FeeBumpOutputs,
+    payload: Option<Vec<u8>>,
+    /// UTXOs spent by the original.
+    inputs: Vec<UtxoEntryReference>,
+    /// Account UTXOs that may be added when the original inputs cannot cover the raised fee.
+    extra_inputs: Vec<UtxoEntryReference>,
+}
+
+impl FeeBumpPlan {
+    /// Builds the replacement paying `priority_fee_sompi`. It spends the original inputs only, unless they do not
+    /// cover the outputs and fees; only then are extra account UTXOs added, as many as needed. The replacement of a
+    /// change-only original is rebuilt once with its change moved into the self output.
+    async fn generate(&self, priority_fee_sompi: u64) -> Result<PendingTransaction, Status> {
+        let transaction = self.generate_with_refund(priority_fee_sompi, 0).await?;
+        match self.outputs {
+            FeeBumpOutputs::SelfOutput { .. } if transaction.change_value() > 0 => {
+                self.generate_with_refund(priority_fee_sompi, transaction.change_value()).await
+            }
+            _ => Ok(transaction),
+        }
+    }
+
+    async fn generate_with_refund(&self, priority_fee_sompi: u64, refund_sompi: u64) -> Result<PendingTransaction, Status> {
+        let destination = self.outputs.destination(priority_fee_sompi, refund_sompi)?;
+        let transaction = match self.generate_from(destination.clone(), priority_fee_sompi, vec![]).await {
+            Err(cryptix_wallet_core::error::Error::InsufficientFunds { .. }) if !self.extra_inputs.is_empty() => {
+                self.generate_from(destination, priority_fee_sompi, self.extra_inputs.clone()).await
+            }
+            result => result,
+        }
+        .map_err(WalletDaemonService::status_internal)?
+        .ok_or_else(|| Status::internal("generator produced no replacement transaction"))?;
+        if !transaction.is_final() {
+            return Err(Status::failed_precondition(
+                "fee bump requires a single final transaction; consolidate account UTXOs and retry",
+            ));
+        }
+        Ok(transaction)
+    }
+
+    async fn generate_from(
+        &self,
+        destination: PaymentDestination,
+        priority_fee_sompi: u64,
+        extra_inputs: Vec<UtxoEntryReference>,
+    ) -> cryptix_wallet_core::error::Result<Option<PendingTransaction>> {
+        let settings = GeneratorSettings::try_new_with_iterator(
+            self.network_id,
+            Box::new(extra_inputs.into_iter()),
+            Some(self.inputs.clone()),
+            self.change_address.clone(),
+            self.sig_op_count,
+            self.minimum_signatures,
+            destination,
+            Fees::SenderPays(priority_fee_sompi),
+            self.payload.clone(),
+            None,
+        )?;
+        Generator::try_new(settings, None, None)?.stream().try_next().await
+    }
 }
 
 #[derive(Clone)]
@@ -224,6 +314,228 @@ impl WalletDaemonService {
         Ok(available_total)
     }
 
+    async fn prepare_send(
+        &self,
+        account: Arc<dyn Account>,
+        to_address: &str,
+        amount: u64,
+        from: &[String],
+        is_send_all: bool,
+        fee_policy: Option<&pb::FeePolicy>,
+    ) -> Result<PreparedSend, Status> {
+        let to_address = Address::try_from(to_address).map_err(|err| Status::invalid_argument(format!("invalid toAddress: {err}")))?;
+
+        let sender_address = match from {
+            [] => None,
+            [single] => Some(
+                Address::try_from(single.as_str()).map_err(|err| Status::invalid_argument(format!("invalid from-address: {err}")))?,
+            ),
+            _ => {
+                return Err(Status::unimplemented("multiple from-address values are not implemented in rust wallet daemon yet"));
+            }
+        };
+
+        let account_id = *account.id();
+        let send_value = if is_send_all {
+            let spendable = self.spendable_balance_for_send_scope(account, sender_address.as_ref()).await?;
+            if spendable == 0 {
+                return Err(Status::failed_precondition(
+                    "isSendAll requested but no spendable balance is available for the selected send scope",
+                ));
+            }
+            spendable
+        } else {
+            if amount == 0 {
+                return Err(Status::invalid_argument("amount must be greater than zero when isSendAll=false"));
+            }
+            amount
+        };
+        let destination = PaymentDestination::from(PaymentOutput::new(to_address, send_value));
+
+        let baseline_fee_mode = Self::fee_from_send_mode(is_send_all, 0);
+        let baseline_total_fees =
+            self.estimate_send_total_fees(account_id, sender_address.clone(), destination.clone(), baseline_fee_mode).await?;
+        let baseline_feerate = self.network_normal_feerate().await?;
+        let (priority_fee_sompi, max_total_fee_sompi) =
+            Self::apply_fee_policy_to_estimate(fee_policy, baseline_total_fees, baseline_feerate)?;
+        let selected_fee_mode = Self::fee_from_send_mode(is_send_all, priority_fee_sompi);
+
+        let estimated_total_fees = if priority_fee_sompi == 0 {
+            baseline_total_fees
+        } else {
+            self.estimate_send_total_fees(account_id, sender_address.clone(), destination.clone(), selected_fee_mode.clone()).await?
+        };
+
+        if let Some(max_total_fee) = max_total_fee_sompi {
+            if estimated_total_fees > max_total_fee {
+                return Err(Status::failed_precondition(format!(
+                    "estimated total fees {estimated_total_fees} exceed configured max fee {max_total_fee} (policy: {})",
+                    Self::describe_fee_policy(fee_policy)
+                )));
+            }
+        }
+        if is_send_all && estimated_total_fees >= send_value {
+            return Err(Status::failed_precondition(format!(
+                "isSendAll leaves no spendable output after fees (estimated total fees: {estimated_total_fees}, spendable balance: {send_value})"
+            )));
+        }
+
+        Ok(PreparedSend { sender_address, destination, fees: selected_fee_mode })
+    }
+
+    /// Selects the feerate of a fee bump replacing a transaction that pays `original_feerate`, along with the
+    /// max total fee of the replacement.
+    ///
+    /// The mempool only accepts a replacement paying a higher feerate than the replaced transaction, so the
+    /// default target is the network normal feerate, raised to at least `original_feerate + MIN_FEE_RATE_SOMPI_PER_GRAM`.
+    fn bump_fee_target(
+        policy: Option<&pb::FeePolicy>,
+        original_feerate: f64,
+        network_feerate: f64,
+    ) -> Result<(f64, Option<u64>), Status> {
+        let minimum_feerate = original_feerate + MIN_FEE_RATE_SOMPI_PER_GRAM;
+        let default_feerate = network_feerate.max(minimum_feerate);
+        match policy.and_then(|policy| policy.fee_policy.as_ref()) {
+            Some(pb::fee_policy::FeePolicy::ExactFeeRate(exact_fee_rate)) => {
+                if !exact_fee_rate.is_finite() {
+                    return Err(Status::invalid_argument("exactFeeRate must be a finite number"));
+                }
+                if *exact_fee_rate < minimum_feerate {
+                    return Err(Status::failed_precondition(format!(
+                        "exactFeeRate={exact_fee_rate} must be >= {minimum_feerate:.4} sompi/gram to replace a transaction paying {original_feerate:.4} sompi/gram"
+                    )));
+                }
+                Ok((*exact_fee_rate, None))
+            }
+            Some(pb::fee_policy::FeePolicy::MaxFeeRate(max_fee_rate)) => {
+                if !max_fee_rate.is_finite() {
+                    return Err(Status::invalid_argument("maxFeeRate must be a finite number"));
+                }
+                if *max_fee_rate < minimum_feerate {
+                    return Err(Status::failed_precondition(format!(
+                        "maxFeeRate={max_fee_rate} is below {minimum_feerate:.4} sompi/gram required to replace a transaction paying {original_feerate:.4} sompi/gram"
+                    )));
+                }
+                Ok((default_feerate.min(*max_fee_rate), None))
+            }
+            Some(pb::fee_policy::FeePolicy::MaxFee(max_fee)) => Ok((default_feerate, Some(*max_fee))),
+            None => Ok((default_feerate, Some(DEFAULT_SEND_MAX_FEE_SOMPI))),
+        }
+    }
+
+    /// Rebuilds the replacement described by `plan`, raising the priority fee until the transaction pays at least
+    /// `target_feerate`.
+    async fn build_fee_bump_transaction(plan: &FeeBumpPlan, target_feerate: f64) -> Result<PendingTransaction, Status> {
+        Self::raise_priority_fee(target_feerate, move |priority_fee_sompi| async move {
+            let transaction = plan.generate(priority_fee_sompi).await?;
+            let (fees, mass) = (transaction.fees(), transaction.mass());
+            Ok((transaction, fees, mass))
+        })
+        .await
+    }
+
+    /// Builds a transaction with `build(priority_fee_sompi)`, which yields the transaction with its fees and mass,
+    /// raising the priority fee until the transaction pays at least `target_feerate`. Gives up after
+    /// `FEE_BUMP_MAX_ATTEMPTS` builds.
+    async fn raise_priority_fee<T, F, Fut>(target_feerate: f64, mut build: F) -> Result<T, Status>
+    where
+        F: FnMut(u64) -> Fut,
+        Fut: std::future::Future<Output = Result<(T, u64, u64), Status>>,
+    {
+        let mut priority_fee_sompi = 0u64;
+        for _ in 0..FEE_BUMP_MAX_ATTEMPTS {
+            let (transaction, fees, mass) = build(priority_fee_sompi).await?;
+            let target_fees = Self::fee_from_mass_and_feerate(mass, target_feerate)?;
+            if fees >= target_fees {
+                return Ok(transaction);
+            }
+            // the mass grows when more inputs are needed to cover the fee, so retry with the missing difference
+            priority_fee_sompi = priority_fee_sompi.saturating_add(target_fees - fees);
+        }
+
+        Err(Status::failed_precondition(format!("unable to build a replacement paying {target_feerate:.4} sompi/gram")))
+    }
+
+    /// Selects the outputs of the replacement of `original`: its non-change outputs, or its change output alone when it
+    /// has no other, reduced by the fee needed to reach `target_feerate`.
+    fn fee_bump_outputs(original: &FeeBumpOriginal, target_feerate: f64) -> Result<FeeBumpOutputs, Status> {
+        let outputs = original
+            .transaction
+            .outputs
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| Some(*index) != original.change_output_index)
+            .map(|(_, output)| ScriptPaymentOutput::new(output.value, output.script_public_key.clone()))
+            .collect::<Vec<_>>();
+        if !outputs.is_empty() {
+            return Ok(FeeBumpOutputs::Payments(outputs));
+        }
+
+        let change = original
+            .change_output_index
+            .and_then(|index| original.transaction.outputs.get(index))
+            .ok_or_else(|| Status::failed_precondition("transaction has no outputs to replace"))?;
+        let fee_increase = Self::fee_from_mass_and_feerate(original.mass, target_feerate)?.saturating_sub(original.fees);
+        Ok(FeeBumpOutputs::SelfOutput {
+            output: ScriptPaymentOutput::new(change.value, change.script_public_key.clone()),
+            fee_increase,
+        })
+    }
+
+    /// Loads a pending transaction that is no longer tracked in memory from the wallet transaction record store.
+    ///
+    /// The record does not keep the outpoints of the spent UTXOs, so the entries are fetched back from the node by
+    /// the addresses they paid. The node's mempool entry confirms that the transaction is still pending and
+    /// provides its fee and mass.
+    async fn stored_fee_bump_original(&self, account: &Arc<dyn Account>, tx_id: RpcHash) -> Result<FeeBumpOriginal, Status> {
+        let not_pending = || Status::failed_precondition(format!("transaction {tx_id} is not a pending transaction of this wallet"));
+        let record = self
+            .wallet()
+            .store()
+            .as_transaction_record_store()
+            .map_err(Self::status_internal)?
+            .load_single(&Binding::from(account), &self.runtime.network_id, &tx_id)
+            .await
+            .map_err(|_| not_pending())?;
+        let TransactionData::Outgoing { transaction, change_value, accepted_daa_score, utxo_entries, .. } = record.transaction_data()
+        else {
+            return Err(not_pending());
+        };
+        if accepted_daa_score.is_some() {
+            return Err(Status::failed_precondition(format!("transaction {tx_id} is already accepted")));
+        }
+        let mempool_entry = self.rpc().get_mempool_entry(tx_id, false, false).await.map_err(|_| not_pending())?;
+        let mass = mempool_entry
+            .transaction
+            .verbose_data
+            .map(|verbose_data| verbose_data.compute_mass)
+            .ok_or_else(|| Status::internal(format!("mempool entry of transaction {tx_id} has no verbose data")))?;
+
+        let addresses = utxo_entries.iter().filter_map(|entry| entry.address.clone()).collect::<HashSet<_>>();
+        let outpoints = transaction.inputs.iter().map(|input| input.previous_outpoint).collect::<HashSet<_>>();
+        let inputs = self
+            .rpc()
+            .get_utxos_by_addresses(addresses.into_iter().collect())
+            .await
+            .map_err(|err| Status::internal(format!("GetUtxosByAddresses failed: {err}")))?
+            .into_iter()
+            .filter(|entry| outpoints.contains(&cryptix_consensus_core::tx::TransactionOutpoint::from(entry.outpoint)))
+            .map(UtxoEntryReference::from)
+            .collect::<Vec<_>>();
+        if inputs.len() != outpoints.len() {
+            return Err(Status::failed_precondition(format!("unable to fetch the UTXO entries spent by transaction {tx_id}")));
+        }
+
+        Ok(FeeBumpOriginal {
+            transaction: transaction.clone(),
+            // the generator appends the change output last
+            change_output_index: (*change_value > 0).then(|| transaction.outputs.len().saturating_sub(1)),
+            inputs,
+            fees: mempool_entry.fee,
+            mass,
+        })
+    }
+
     async fn estimate_send_total_fees(
         &self,
         account_id: cryptix_wallet_core::prelude::AccountId,
@@ -411,17 +723,17 @@ impl WalletDaemonService {
         Ok(decimals)
     }
 
-    fn validate_supply_mode(capped: bool, max_supply: u128) -> Result<u8, Status> {
+    fn validate_supply_mode(capped: bool, max_supply: u128) -> Result<TokenSupplyMode, Status> {
         if capped {
             if max_supply == 0 {
                 return Err(Status::invalid_argument("max_supply_raw must be > 0 when capped=true"));
             }
-            Ok(1)
+            Ok(TokenSupplyMode::Capped)
         } else {
             if max_supply != 0 {
                 return Err(Status::invalid_argument("max_supply_raw must be 0 when capped=false"));
             }
-            Ok(0)
+            Ok(TokenSupplyMode::Uncapped)
         }
     }
 
@@ -448,23 +760,6 @@ impl WalletDaemonService {
         Ok(())
     }
 
-    fn append_platform_tag_tail(payload: &mut Vec<u8>, platform_tag: &str) -> Result<(), Status> {
-        Self::validate_platform_tag(platform_tag)?;
-        let tag_len = u8::try_from(platform_tag.len())
-            .map_err(|_| Status::invalid_argument(format!("platform_tag must be <= {CAT_MAX_PLATFORM_TAG_LEN} UTF-8 bytes")))?;
-        payload.push(tag_len);
-        payload.extend_from_slice(platform_tag.as_bytes());
-        Ok(())
-    }
-
-    fn append_optional_platform_tag_tail(payload: &mut Vec<u8>, platform_tag: &str) -> Result<(), Status> {
-        Self::validate_platform_tag(platform_tag)?;
-        if !platform_tag.is_empty() {
-            Self::append_platform_tag_tail(payload, platform_tag)?;
-        }
-        Ok(())
-    }
-
     fn ensure_liquidity_outflow_unlocked(pool: &RpcLiquidityPoolState, operation: &str) -> Result<(), Status> {
         if pool.sell_locked {
             return Err(Status::failed_precondition(format!(
@@ -496,7 +791,7 @@ impl WalletDaemonService {
         u16::try_from(value).map_err(|_| Status::invalid_argument("auth_input_index must be <= 65535"))
     }
 
-    fn parse_liquidity_recipients(recipient_addresses: &[String]) -> Result<Vec<LiquidityRecipient>, Status> {
+    fn parse_liquidity_recipients(recipient_addresses: &[String]) -> Result<Vec<Address>, Status> {
         if recipient_addresses.len() > CAT_MAX_LIQUIDITY_RECIPIENTS {
             return Err(Status::invalid_argument(format!(
                 "recipient_addresses supports at most {CAT_MAX_LIQUIDITY_RECIPIENTS} entries"
@@ -515,17 +810,15 @@ impl WalletDaemonService {
                 )));
             }
 
-            recipients.push(LiquidityRecipient { address_version: address.version as u8, address_payload: address.payload.to_vec() });
+            recipients.push(address);
         }
 
         if recipients.len() == 2 {
-            if recipients[0].address_version == recipients[1].address_version
-                && recipients[0].address_payload == recipients[1].address_payload
-            {
+            let key_a = (recipients[0].version as u8, recipients[0].payload.as_slice());
+            let key_b = (recipients[1].version as u8, recipients[1].payload.as_slice());
+            if key_a == key_b {
                 return Err(Status::invalid_argument("recipient_addresses must not contain duplicates"));
             }
-            let key_a = (recipients[0].address_version, recipients[0].address_payload.as_slice());
-            let key_b = (recipients[1].address_version, recipients[1].address_payload.as_slice());
             if key_a > key_b {
                 return Err(Status::invalid_argument("recipient_addresses must be in canonical lexicographic order"));
             }
@@ -609,6 +902,57 @@ impl WalletDaemonService {
         Ok(response.transaction_ids.into_iter().map(|txid| txid.to_string()).collect())
     }
 
+    async fn token_send_batch(&self, request: pb::TokenSendRequest) -> Result<Response<pb::TokenSendResponse>, Status> {
+        if !request.to_address.trim().is_empty() || !request.amount_raw.trim().is_empty() {
+            return Err(Status::invalid_argument("to_address and amount_raw must be empty when recipients are set"));
+        }
+        if request.recipients.len() > CAT_MAX_BATCH_TRANSFER_RECIPIENTS {
+            return Err(Status::invalid_argument(format!(
+                "recipients must contain at most {CAT_MAX_BATCH_TRANSFER_RECIPIENTS} entries"
+            )));
+        }
+        let wallet_secret = Self::require_password(request.password)?;
+        let auth_input_index = if request.auth_input_index == 0 {
+            TOKEN_AUTH_INPUT_INDEX
+        } else {
+            Self::parse_auth_input_index(request.auth_input_index)?
+        };
+
+        let account = self.current_account()?;
+        let sender_address = if request.sender_address.trim().is_empty() {
+            account.receive_address().map_err(Self::status_internal)?
+        } else {
+            Address::try_from(request.sender_address.as_str())
+                .map_err(|err| Status::invalid_argument(format!("invalid sender_address: {err}")))?
+        };
+
+        let mut recipients = Vec::with_capacity(request.recipients.len());
+        let mut recipient_owner_ids = Vec::with_capacity(request.recipients.len());
+        for (index, recipient) in request.recipients.iter().enumerate() {
+            let recipient_address = Address::try_from(recipient.to_address.as_str())
+                .map_err(|err| Status::invalid_argument(format!("invalid recipients[{index}].to_address: {err}")))?;
+            let amount = Self::parse_positive_u128(recipient.amount_raw.as_str(), &format!("recipients[{index}].amount_raw"))?;
+            recipient_owner_ids.push(self.resolve_owner_id(&recipient_address, "recipients.to_address").await?);
+            recipients.push(TokenRecipient { address: recipient_address, amount });
+        }
+
+        let sender_owner_id = self.resolve_owner_id(&sender_address, "sender_address").await?;
+        let nonce = self.resolve_sender_nonce(sender_owner_id.as_str(), Some(request.asset_id.as_str())).await?;
+        let operation = TokenOperation::BatchTransfer { asset_id: request.asset_id.clone(), recipients };
+        let owner_ids =
+            operation.owner_addresses().into_iter().zip(recipient_owner_ids.iter().map(String::as_str)).collect::<Vec<_>>();
+        let payload = Self::encode_token_operation(&operation, &sender_address, &owner_ids, nonce, auth_input_index)?;
+
+        let tx_ids = self.submit_payload_tx(account, wallet_secret, payload, sender_address, TOKEN_CARRIER_OUTPUT_SOMPI).await?;
+        Ok(Response::new(pb::TokenSendResponse {
+            tx_ids,
+            nonce,
+            sender_owner_id,
+            recipient_owner_id: String::new(),
+            recipient_owner_ids,
+        }))
+    }
+
     async fn fetch_liquidity_pool(&self, asset_id: &str) -> Result<RpcLiquidityPoolState, Status> {
         self.fetch_liquidity_pool_at(asset_id, None).await
     }
@@ -698,6 +1042,122 @@ impl WalletDaemonService {
         }
     }
 
+    /// Collects the keys of the selected account that can spend the inputs of the serialized transactions.
+    async fn signing_keys_for_transactions(
+        &self,
+        account: Arc<dyn Account>,
+        wallet_secret: Secret,
+        transactions: &[Vec<u8>],
+    ) -> Result<SigningKeys, Status> {
+        let prefix = Prefix::from(self.runtime.network_id);
+        let mut seen = HashSet::new();
+        let mut addresses = Vec::new();
+        for transaction in transactions {
+            for script_public_key in serialization::input_script_public_keys(transaction)? {
+                if let Ok(address) = cryptix_txscript::extract_script_pub_key_address(&script_public_key, prefix) {
+                    if seen.insert(address.to_string()) {
+                        addresses.push(address);
+                    }
+                }
+            }
+        }
+
+        let secret_keys = self.private_keys_for_addresses(account, wallet_secret, &addresses).await?;
+        Ok(SigningKeys::new(secret_keys.iter()))
+    }
+
+    /// Returns the account key and the derivation paths of the addresses spent by `entries`, which the legacy
+    /// serialized format records for every input. Only single key accounts with an extended public key have one.
+    fn legacy_account_keys(
+        &self,
+        account: &Arc<dyn Account>,
+        entries: &[cryptix_consensus_core::tx::UtxoEntry],
+    ) -> Result<Option<LegacyAccountKeys>, Status> {
+        let descriptor = account.descriptor().map_err(Self::status_internal)?;
+        let Some(AccountDescriptorValue::XPubKeys(xpub_keys)) = descriptor.properties.get(&AccountDescriptorProperty::XpubKeys) else {
+            return Ok(None);
+        };
+        let ([extended_public_key], Ok(derivation)) = (xpub_keys.as_slice(), account.clone().as_derivation_capable()) else {
+            return Ok(None);
+        };
+        if account.minimum_signatures() > 1 {
+            return Ok(None);
+        }
+
+        let prefix = Prefix::from(self.runtime.network_id);
+        let addresses = entries
+            .iter()
+            .filter_map(|entry| cryptix_txscript::extract_script_pub_key_address(&entry.script_public_key, prefix).ok())
+            .collect::<HashSet<_>>();
+        let addresses = addresses.iter().collect::<Vec<_>>();
+        let (receive, change) = derivation.derivation().addresses_indexes(&addresses).map_err(Self::status_internal)?;
+        let derivation_paths = [(0, receive), (1, change)]
+            .into_iter()
+            .flat_map(|(keychain, indexes)| indexes.into_iter().map(move |(address, index)| (keychain, address, index)))
+            .map(|(keychain, address, index)| {
+                let path = format!("m/{keychain}/{index}").parse::<cryptix_bip32::DerivationPath>().map_err(Self::status_internal)?;
+                Ok((cryptix_txscript::pay_to_address_script(address), path))
+            })
+            .collect::<Result<Vec<_>, Status>>()?;
+
+        Ok(Some(LegacyAccountKeys::new(
+            extended_public_key.clone(),
+            cryptix_bip32::Prefix::from(self.runtime.network_id),
+            derivation_paths,
+        )))
+    }
+
+    /// Returns the private keys of the selected account for `addresses`, skipping addresses the account does not derive.
+    async fn private_keys_for_addresses(
+        &self,
+        account: Arc<dyn Account>,
+        wallet_secret: Secret,
+        addresses: &[Address],
+    ) -> Result<Vec<secp256k1::SecretKey>, Status> {
+        let keydata = account.prv_key_data(wallet_secret).await.map_err(Self::status_internal)?;
+        if let Ok(derivation) = account.clone().as_derivation_capable() {
+            let mut receive = Vec::new();
+            let mut change = Vec::new();
+            for address in addresses.iter() {
+                // addresses outside of the account derivation are left for the signing step to report
+                if let Ok((mut receive_indexes, mut change_indexes)) = derivation.derivation().addresses_indexes(&[address]) {
+                    receive.append(&mut receive_indexes);
+                    change.append(&mut change_indexes);
+                }
+            }
+            Ok(derivation
+                .create_private_keys(&keydata, &self.runtime.payment_secret, &receive, &change)
+                .map_err(Self::status_internal)?
+                .into_iter()
+                .map(|(_, key)| key)
+                .collect())
+        } else {
+            let payload = keydata.payload.decrypt(self.runtime.payment_secret.as_ref()).map_err(Self::status_internal)?;
+            Ok(payload.as_secret_key().map_err(Self::status_internal)?.into_iter().collect())
+        }
+    }
+
+    async fn submit_serialized_transactions(&self, request: pb::BroadcastRequest, replacement: bool) -> Result<Vec<String>, Status> {
+        if request.transactions.is_empty() {
+            return Err(Status::invalid_argument("transactions must not be empty"));
+        }
+
+        let rpc = self.rpc();
+        let mut tx_ids = Vec::new();
+        for serialized in request.transactions.iter() {
+            for transaction in serialization::extract_transactions(serialized, request.is_domain)? {
+                let transaction = RpcTransaction::from(&transaction);
+                let tx_id = if replacement {
+                    rpc.submit_transaction_replacement(transaction).await.map_err(Self::status_internal)?.transaction_id
+                } else {
+                    rpc.submit_transaction(transaction, false).await.map_err(Self::status_internal)?
+                };
+                tx_ids.push(tx_id.to_string());
+            }
+        }
+        Ok(tx_ids)
+    }
+
     async fn token_balances_for_addresses(
         &self,
         addresses: &[Address],
@@ -824,226 +1284,47 @@ impl WalletDaemonService {
         Ok((per_address, totals))
     }
 
-    fn build_header(op: u8, nonce: u64, auth_input_index: u16) -> Result<Vec<u8>, Status> {
-        if nonce == 0 {
-            return Err(Status::invalid_argument("nonce must be greater than zero"));
-        }
-
-        let mut payload = Vec::with_capacity(64);
-        payload.extend_from_slice(&CAT_MAGIC);
-        payload.push(CAT_VERSION);
-        payload.push(op);
-        payload.push(CAT_FLAGS);
-        payload.extend_from_slice(&auth_input_index.to_le_bytes());
-        payload.extend_from_slice(&nonce.to_le_bytes());
-        Ok(payload)
-    }
-
-    fn push_create_common_fields(
-        payload: &mut Vec<u8>,
-        name: &str,
-        symbol: &str,
-        decimals: u8,
-        supply_mode: u8,
-        max_supply: u128,
-        mint_authority_owner_id: &str,
-        metadata: &[u8],
-    ) -> Result<(), Status> {
-        let mint_authority_owner_id = Self::parse_hex_32(mint_authority_owner_id, "mint_authority_owner_id")?;
-        payload.push(CAT_CURRENT_TOKEN_VERSION);
-        payload.push(decimals);
-        payload.push(supply_mode);
-        payload.extend_from_slice(&max_supply.to_le_bytes());
-        payload.extend_from_slice(&mint_authority_owner_id);
-        payload.push(name.len() as u8);
-        payload.push(symbol.len() as u8);
-        payload.extend_from_slice(&(metadata.len() as u16).to_le_bytes());
-        payload.extend_from_slice(name.as_bytes());
-        payload.extend_from_slice(symbol.as_bytes());
-        payload.extend_from_slice(metadata);
-        Ok(())
-    }
-
-    fn build_transfer_payload(
-        asset_id: &str,
-        to_owner_id: &str,
-        amount: u128,
-        nonce: u64,
-        auth_input_index: u16,
-    ) -> Result<Vec<u8>, Status> {
-        let asset_id = Self::parse_hex_32(asset_id, "asset_id")?;
-        let to_owner_id = Self::parse_hex_32(to_owner_id, "to_owner_id")?;
-        let mut payload = Self::build_header(CAT_OP_TRANSFER, nonce, auth_input_index)?;
-        payload.extend_from_slice(&asset_id);
-        payload.extend_from_slice(&to_owner_id);
-        payload.extend_from_slice(&amount.to_le_bytes());
-        Ok(payload)
-    }
-
-    fn build_mint_payload(
-        asset_id: &str,
-        to_owner_id: &str,
-        amount: u128,
-        nonce: u64,
-        auth_input_index: u16,
-    ) -> Result<Vec<u8>, Status> {
-        let asset_id = Self::parse_hex_32(asset_id, "asset_id")?;
-        let to_owner_id = Self::parse_hex_32(to_owner_id, "to_owner_id")?;
-        let mut payload = Self::build_header(CAT_OP_MINT, nonce, auth_input_index)?;
-        payload.extend_from_slice(&asset_id);
-        payload.extend_from_slice(&to_owner_id);
-        payload.extend_from_slice(&amount.to_le_bytes());
-        Ok(payload)
-    }
-
-    fn build_burn_payload(asset_id: &str, amount: u128, nonce: u64, auth_input_index: u16) -> Result<Vec<u8>, Status> {
-        let asset_id = Self::parse_hex_32(asset_id, "asset_id")?;
-        let mut payload = Self::build_header(CAT_OP_BURN, nonce, auth_input_index)?;
-        payload.extend_from_slice(&asset_id);
-        payload.extend_from_slice(&amount.to_le_bytes());
-        Ok(payload)
-    }
-
-    fn build_create_asset_payload(
-        name: &str,
-        symbol: &str,
-        decimals: u8,
-        supply_mode: u8,
-        max_supply: u128,
-        mint_authority_owner_id: &str,
-        metadata: &[u8],
-        platform_tag: &str,
+    /// Encodes `operation` for `sender`, mapping addresses to the owner ids
+    /// already resolved over RPC.
+    fn encode_token_operation(
+        operation: &TokenOperation,
+        sender: &Address,
+        owner_ids: &[(&Address, &str)],
         nonce: u64,
         auth_input_index: u16,
     ) -> Result<Vec<u8>, Status> {
-        let mut payload = Self::build_header(CAT_OP_CREATE_ASSET, nonce, auth_input_index)?;
-        Self::push_create_common_fields(
-            &mut payload,
-            name,
-            symbol,
-            decimals,
-            supply_mode,
-            max_supply,
-            mint_authority_owner_id,
-            metadata,
-        )?;
-        Self::append_optional_platform_tag_tail(&mut payload, platform_tag)?;
-        Ok(payload)
-    }
-
-    fn build_create_asset_with_mint_payload(
-        name: &str,
-        symbol: &str,
-        decimals: u8,
-        supply_mode: u8,
-        max_supply: u128,
-        mint_authority_owner_id: &str,
-        metadata: &[u8],
-        initial_mint_amount: u128,
-        initial_mint_to_owner_id: &str,
-        platform_tag: &str,
-        nonce: u64,
-        auth_input_index: u16,
-    ) -> Result<Vec<u8>, Status> {
-        let initial_mint_to_owner_id = Self::parse_hex_32(initial_mint_to_owner_id, "initial_mint_to_owner_id")?;
-        let mut payload = Self::build_header(CAT_OP_CREATE_ASSET_WITH_MINT, nonce, auth_input_index)?;
-        Self::push_create_common_fields(
-            &mut payload,
-            name,
-            symbol,
-            decimals,
-            supply_mode,
-            max_supply,
-            mint_authority_owner_id,
-            metadata,
-        )?;
-        payload.extend_from_slice(&initial_mint_amount.to_le_bytes());
-        payload.extend_from_slice(&initial_mint_to_owner_id);
-        Self::append_optional_platform_tag_tail(&mut payload, platform_tag)?;
-        Ok(payload)
-    }
-
-    fn build_create_liquidity_asset_payload(
-        name: &str,
-        symbol: &str,
-        decimals: u8,
-        max_supply: u128,
-        metadata: &[u8],
-        seed_reserve_sompi: u64,
-        fee_bps: u16,
-        recipients: &[LiquidityRecipient],
-        launch_buy_sompi: u64,
-        launch_buy_min_token_out: u128,
-        platform_tag: &str,
-        liquidity_unlock_target_sompi: u64,
-        nonce: u64,
-        auth_input_index: u16,
-    ) -> Result<Vec<u8>, Status> {
-        if max_supply == 0 {
-            return Err(Status::invalid_argument("max_supply_raw must be greater than zero"));
-        }
-        if seed_reserve_sompi == 0 {
-            return Err(Status::invalid_argument("seed_reserve_sompi must be greater than zero"));
-        }
-        Self::validate_platform_tag(platform_tag)?;
-        if liquidity_unlock_target_sompi > MAX_SOMPI {
-            return Err(Status::invalid_argument(format!("liquidity_unlock_target_sompi must be 0 or <= MAX_SOMPI ({MAX_SOMPI})")));
-        }
-        Self::validate_liquidity_create_parameters(decimals, max_supply, seed_reserve_sompi)?;
-        if recipients.len() > CAT_MAX_LIQUIDITY_RECIPIENTS {
-            return Err(Status::invalid_argument(format!(
-                "recipient_addresses supports at most {CAT_MAX_LIQUIDITY_RECIPIENTS} entries"
-            )));
-        }
-
-        let mut payload = Self::build_header(CAT_OP_CREATE_LIQUIDITY_ASSET, nonce, auth_input_index)?;
-        payload.push(CAT_CURRENT_TOKEN_VERSION);
-        payload.push(CAT_CURRENT_LIQUIDITY_CURVE_VERSION);
-        payload.push(decimals);
-        payload.extend_from_slice(&max_supply.to_le_bytes());
-        payload.push(name.len() as u8);
-        payload.push(symbol.len() as u8);
-        payload.extend_from_slice(&(metadata.len() as u16).to_le_bytes());
-        payload.extend_from_slice(name.as_bytes());
-        payload.extend_from_slice(symbol.as_bytes());
-        payload.extend_from_slice(metadata);
-        payload.extend_from_slice(&seed_reserve_sompi.to_le_bytes());
-        payload.extend_from_slice(&fee_bps.to_le_bytes());
-        payload.push(recipients.len() as u8);
-        for recipient in recipients {
-            payload.push(recipient.address_version);
-            payload.extend_from_slice(recipient.address_payload.as_slice());
-        }
-        payload.extend_from_slice(&launch_buy_sompi.to_le_bytes());
-        payload.extend_from_slice(&launch_buy_min_token_out.to_le_bytes());
-        if !platform_tag.is_empty() || liquidity_unlock_target_sompi > 0 {
-            Self::append_platform_tag_tail(&mut payload, platform_tag)?;
-            payload.extend_from_slice(&liquidity_unlock_target_sompi.to_le_bytes());
-        }
-        Ok(payload)
+        let owner_ids = owner_ids
+            .iter()
+            .map(|(address, owner_id)| Ok(((*address).clone(), Self::parse_hex_32(owner_id, "owner_id")?)))
+            .collect::<Result<HashMap<_, _>, Status>>()?;
+        operation
+            .encode(sender, nonce, auth_input_index, |address| {
+                owner_ids.get(address).copied().ok_or_else(|| WalletError::custom(format!("owner id for {address} was not resolved")))
+            })
+            .map_err(|err| Status::invalid_argument(err.to_string()))
     }
 
     fn validate_liquidity_create_parameters(decimals: u8, max_supply: u128, seed_reserve_sompi: u64) -> Result<(), Status> {
-        if decimals != LIQUIDITY_TOKEN_DECIMALS {
-            return Err(Status::invalid_argument(format!("liquidity token decimals must be {LIQUIDITY_TOKEN_DECIMALS}")));
+        if decimals != CAT_LIQUIDITY_TOKEN_DECIMALS {
+            return Err(Status::invalid_argument(format!("liquidity token decimals must be {CAT_LIQUIDITY_TOKEN_DECIMALS}")));
         }
-        if !(MIN_LIQUIDITY_TOKEN_SUPPLY_RAW..=MAX_LIQUIDITY_TOKEN_SUPPLY_RAW).contains(&max_supply) {
+        if !(CAT_MIN_LIQUIDITY_SUPPLY_RAW..=CAT_MAX_LIQUIDITY_SUPPLY_RAW).contains(&max_supply) {
             return Err(Status::invalid_argument(format!(
-                "max_supply_raw for liquidity tokens must be between {MIN_LIQUIDITY_TOKEN_SUPPLY_RAW} and {MAX_LIQUIDITY_TOKEN_SUPPLY_RAW}"
+                "max_supply_raw for liquidity tokens must be between {CAT_MIN_LIQUIDITY_SUPPLY_RAW} and {CAT_MAX_LIQUIDITY_SUPPLY_RAW}"
             )));
         }
-        if seed_reserve_sompi != MIN_LIQUIDITY_SEED_RESERVE_SOMPI {
+        if seed_reserve_sompi != CAT_MIN_LIQUIDITY_SEED_RESERVE_SOMPI {
             return Err(Status::invalid_argument(format!(
-                "seed_reserve_sompi must be exactly {MIN_LIQUIDITY_SEED_RESERVE_SOMPI} (1 CPAY)"
+                "seed_reserve_sompi must be exactly {CAT_MIN_LIQUIDITY_SEED_RESERVE_SOMPI} (1 CPAY)"
             )));
         }
         Ok(())
     }
 
     fn initial_liquidity_virtual_token_reserves(max_supply: u128) -> Result<u128, Status> {
-        if !(MIN_LIQUIDITY_TOKEN_SUPPLY_RAW..=MAX_LIQUIDITY_TOKEN_SUPPLY_RAW).contains(&max_supply) {
+        if !(CAT_MIN_LIQUIDITY_SUPPLY_RAW..=CAT_MAX_LIQUIDITY_SUPPLY_RAW).contains(&max_supply) {
             return Err(Status::invalid_argument(format!(
-                "max_supply_raw for liquidity tokens must be between {MIN_LIQUIDITY_TOKEN_SUPPLY_RAW} and {MAX_LIQUIDITY_TOKEN_SUPPLY_RAW}"
+                "max_supply_raw for liquidity tokens must be between {CAT_MIN_LIQUIDITY_SUPPLY_RAW} and {CAT_MAX_LIQUIDITY_SUPPLY_RAW}"
             )));
         }
         max_supply
@@ -1155,61 +1436,6 @@ impl WalletDaemonService {
         Self::min_liquidity_gross_input_for_net_input(net_in, fee_bps)
     }
 
-    fn build_buy_liquidity_payload(
-        asset_id: &str,
-        expected_pool_nonce: u64,
-        cpay_in_sompi: u64,
-        min_token_out: u128,
-        nonce: u64,
-        auth_input_index: u16,
-    ) -> Result<Vec<u8>, Status> {
-        let asset_id = Self::parse_hex_32(asset_id, "asset_id")?;
-        let mut payload = Self::build_header(CAT_OP_BUY_LIQUIDITY_EXACT_IN, nonce, auth_input_index)?;
-        payload.extend_from_slice(&asset_id);
-        payload.extend_from_slice(&expected_pool_nonce.to_le_bytes());
-        payload.extend_from_slice(&cpay_in_sompi.to_le_bytes());
-        payload.extend_from_slice(&min_token_out.to_le_bytes());
-        Ok(payload)
-    }
-
-    fn build_sell_liquidity_payload(
-        asset_id: &str,
-        expected_pool_nonce: u64,
-        token_in: u128,
-        min_cpay_out_sompi: u64,
-        cpay_receive_output_index: u16,
-        nonce: u64,
-        auth_input_index: u16,
-    ) -> Result<Vec<u8>, Status> {
-        let asset_id = Self::parse_hex_32(asset_id, "asset_id")?;
-        let mut payload = Self::build_header(CAT_OP_SELL_LIQUIDITY_EXACT_IN, nonce, auth_input_index)?;
-        payload.extend_from_slice(&asset_id);
-        payload.extend_from_slice(&expected_pool_nonce.to_le_bytes());
-        payload.extend_from_slice(&token_in.to_le_bytes());
-        payload.extend_from_slice(&min_cpay_out_sompi.to_le_bytes());
-        payload.extend_from_slice(&cpay_receive_output_index.to_le_bytes());
-        Ok(payload)
-    }
-
-    fn build_claim_liquidity_payload(
-        asset_id: &str,
-        expected_pool_nonce: u64,
-        recipient_index: u8,
-        claim_amount_sompi: u64,
-        claim_receive_output_index: u16,
-        nonce: u64,
-        auth_input_index: u16,
-    ) -> Result<Vec<u8>, Status> {
-        let asset_id = Self::parse_hex_32(asset_id, "asset_id")?;
-        let mut payload = Self::build_header(CAT_OP_CLAIM_LIQUIDITY_FEES, nonce, auth_input_index)?;
-        payload.extend_from_slice(&asset_id);
-        payload.extend_from_slice(&expected_pool_nonce.to_le_bytes());
-        payload.push(recipient_index);
-        payload.extend_from_slice(&claim_amount_sompi.to_le_bytes());
-        payload.extend_from_slice(&claim_receive_output_index.to_le_bytes());
-        Ok(payload)
-    }
-
     fn liquidity_vault_destination(vault_value: u64) -> PaymentDestination {
         let output = ScriptPaymentOutput::new(vault_value, Self::liquidity_vault_script_public_key());
         PaymentDestination::from(ScriptPaymentOutputs { outputs: vec![output] })
@@ -1296,11 +1522,47 @@ impl pb::cryptixwalletd_server::Cryptixwalletd for WalletDaemonService {
 
     async fn create_unsigned_transactions(
         &self,
-        _request: Request<pb::CreateUnsignedTransactionsRequest>,
+        request: Request<pb::CreateUnsignedTransactionsRequest>,
     ) -> Result<Response<pb::CreateUnsignedTransactionsResponse>, Status> {
-        Err(Status::unimplemented(
-            "CreateUnsignedTransactions is not implemented in rust wallet daemon yet (PSKB format differs from legacy go bytes format)",
-        ))
+        let request = request.into_inner();
+        let format = match request.format() {
+            pb::SerializedTransactionFormat::Legacy => TransactionFormat::Legacy,
+            pb::SerializedTransactionFormat::Pskb => TransactionFormat::Pskb,
+        };
+
+        let account = self.current_account()?;
+        let PreparedSend { sender_address, destination, fees } = self
+            .prepare_send(
+                account.clone(),
+                request.address.as_str(),
+                request.amount,
+                request.from.as_slice(),
+                request.is_send_all,
+                request.fee_policy.as_ref(),
+            )
+            .await?;
+
+        let settings =
+            GeneratorSettings::try_new_with_account_and_priority_untracked(account, destination, fees, None, sender_address, None)
+                .map_err(Self::status_internal)?;
+        let generator = Generator::try_new(settings, None, None).map_err(Self::status_internal)?;
+        let mut stream = generator.stream();
+        let mut unsigned_transactions = Vec::new();
+        while let Some(transaction) = stream.try_next().await.map_err(Self::status_internal)? {
+            let signable = transaction.signable_transaction();
+            let entries = signable
+                .entries
+                .into_iter()
+                .collect::<Option<Vec<_>>>()
+                .ok_or_else(|| Status::internal("generated transaction is missing utxo entries"))?;
+            let legacy_keys = match format {
+                TransactionFormat::Legacy => self.legacy_account_keys(&account, &entries)?,
+                TransactionFormat::Pskb => None,
+            };
+            unsigned_transactions.push(serialization::serialize_unsigned(format, &signable.tx, &entries, legacy_keys.as_ref())?);
+        }
+
+        Ok(Response::new(pb::CreateUnsignedTransactionsResponse { unsigned_transactions }))
     }
 
     async fn show_addresses(
@@ -1334,80 +1596,34 @@ impl pb::cryptixwalletd_server::Cryptixwalletd for WalletDaemonService {
         Ok(Response::new(pb::ShutdownResponse {}))
     }
 
-    async fn broadcast(&self, _request: Request<pb::BroadcastRequest>) -> Result<Response<pb::BroadcastResponse>, Status> {
-        Err(Status::unimplemented(
-            "Broadcast is not implemented in rust wallet daemon yet (legacy serialized transaction format is not supported)",
-        ))
+    async fn broadcast(&self, request: Request<pb::BroadcastRequest>) -> Result<Response<pb::BroadcastResponse>, Status> {
+        let tx_ids = self.submit_serialized_transactions(request.into_inner(), false).await?;
+        Ok(Response::new(pb::BroadcastResponse { tx_i_ds: tx_ids }))
     }
 
-    async fn broadcast_replacement(&self, _request: Request<pb::BroadcastRequest>) -> Result<Response<pb::BroadcastResponse>, Status> {
-        Err(Status::unimplemented("BroadcastReplacement is not implemented in rust wallet daemon yet"))
-    }
-
-    async fn send(&self, request: Request<pb::SendRequest>) -> Result<Response<pb::SendResponse>, Status> {
-        let request = request.into_inner();
-
-        let to_address = Address::try_from(request.to_address.as_str())
-            .map_err(|err| Status::invalid_argument(format!("invalid toAddress: {err}")))?;
-
-        let sender_address = match request.from.as_slice() {
-            [] => None,
-            [single] => Some(
-                Address::try_from(single.as_str()).map_err(|err| Status::invalid_argument(format!("invalid from-address: {err}")))?,
-            ),
-            _ => {
-                return Err(Status::unimplemented("multiple from-address values are not implemented in rust wallet daemon yet"));
-            }
-        };
-
-        let account = self.current_account()?;
-        let account_id = *account.id();
-        let wallet_secret = Self::require_password(request.password)?;
-        let payment_secret = self.runtime.payment_secret.clone();
-        let send_value = if request.is_send_all {
-            let spendable = self.spendable_balance_for_send_scope(account.clone(), sender_address.as_ref()).await?;
-            if spendable == 0 {
-                return Err(Status::failed_precondition(
-                    "isSendAll requested but no spendable balance is available for the selected send scope",
-                ));
-            }
-            spendable
-        } else {
-            if request.amount == 0 {
-                return Err(Status::invalid_argument("amount must be greater than zero when isSendAll=false"));
-            }
-            request.amount
-        };
-        let destination = PaymentDestination::from(PaymentOutput::new(to_address, send_value));
-
-        let baseline_fee_mode = Self::fee_from_send_mode(request.is_send_all, 0);
-        let baseline_total_fees =
-            self.estimate_send_total_fees(account_id, sender_address.clone(), destination.clone(), baseline_fee_mode).await?;
-        let baseline_feerate = self.network_normal_feerate().await?;
-        let (priority_fee_sompi, max_total_fee_sompi) =
-            Self::apply_fee_policy_to_estimate(request.fee_policy.as_ref(), baseline_total_fees, baseline_feerate)?;
-        let selected_fee_mode = Self::fee_from_send_mode(request.is_send_all, priority_fee_sompi);
-
-        let estimated_total_fees = if priority_fee_sompi == 0 {
-            baseline_total_fees
-        } else {
-            self.estimate_send_total_fees(account_id, sender_address.clone(), destination.clone(), selected_fee_mode.clone()).await?
-        };
-
-        if let Some(max_total_fee) = max_total_fee_sompi {
-            if estimated_total_fees > max_total_fee {
-                return Err(Status::failed_precondition(format!(
-                    "estimated total fees {estimated_total_fees} exceed configured max fee {max_total_fee} (policy: {})",
-                    Self::describe_fee_policy(request.fee_policy.as_ref())
-                )));
-            }
-        }
-        if request.is_send_all && estimated_total_fees >= send_value {
-            return Err(Status::failed_precondition(format!(
-                "isSendAll leaves no spendable output after fees (estimated total fees: {estimated_total_fees}, spendable balance: {send_value})"
-            )));
-        }
-
+    async fn broadcast_replacement(&self, request: Request<pb::BroadcastRequest>) -> Result<Response<pb::BroadcastResponse>, Status> {
+        let tx_ids = self.submit_serialized_transactions(request.into_inner(), true).await?;
+        Ok(Response::new(pb::BroadcastResponse { tx_i_ds: tx_ids }))
+    }
+
+    async fn send(&self, request: Request<pb::SendRequest>) -> Result<Response<pb::SendResponse>, Status> {
+        let request = request.into_inner();
+
+        let account = self.current_account()?;
+        let account_id = *account.id();
+        let wallet_secret = Self::require_password(request.password)?;
+        let payment_secret = self.runtime.payment_secret.clone();
+        let PreparedSend { sender_address, destination, fees } = self
+            .prepare_send(
+                account,
+                request.to_address.as_str(),
+                request.amount,
+                request.from.as_slice(),
+                request.is_send_all,
+                request.fee_policy.as_ref(),
+            )
+            .await?;
+
         let response = self
             .wallet()
             .accounts_send_call(AccountsSendRequest {
@@ -1416,7 +1632,7 @@ impl pb::cryptixwalletd_server::Cryptixwalletd for WalletDaemonService {
                 payment_secret,
                 sender_address,
                 destination,
-                priority_fee_sompi: selected_fee_mode,
+                priority_fee_sompi: fees,
                 payload: None,
                 fast_path: None,
             })
@@ -1456,10 +1672,13 @@ impl pb::cryptixwalletd_server::Cryptixwalletd for WalletDaemonService {
 
     async fn token_send(&self, request: Request<pb::TokenSendRequest>) -> Result<Response<pb::TokenSendResponse>, Status> {
         let request = request.into_inner();
+        if !request.recipients.is_empty() {
+            return self.token_send_batch(request).await;
+        }
         let wallet_secret = Self::require_password(request.password)?;
         let amount = Self::parse_positive_u128(request.amount_raw.as_str(), "amount_raw")?;
         let auth_input_index = if request.auth_input_index == 0 {
-            DEFAULT_AUTH_INPUT_INDEX
+            TOKEN_AUTH_INPUT_INDEX
         } else {
             Self::parse_auth_input_index(request.auth_input_index)?
         };
@@ -1477,11 +1696,18 @@ impl pb::cryptixwalletd_server::Cryptixwalletd for WalletDaemonService {
         let sender_owner_id = self.resolve_owner_id(&sender_address, "sender_address").await?;
         let recipient_owner_id = self.resolve_owner_id(&recipient_address, "to_address").await?;
         let nonce = self.resolve_sender_nonce(sender_owner_id.as_str(), Some(request.asset_id.as_str())).await?;
-        let payload =
-            Self::build_transfer_payload(request.asset_id.as_str(), recipient_owner_id.as_str(), amount, nonce, auth_input_index)?;
+        let operation = TokenOperation::Transfer { asset_id: request.asset_id.clone(), to: recipient_address.clone(), amount };
+        let owner_ids = [(&recipient_address, recipient_owner_id.as_str())];
+        let payload = Self::encode_token_operation(&operation, &sender_address, &owner_ids, nonce, auth_input_index)?;
 
         let tx_ids = self.submit_payload_tx(account, wallet_secret, payload, sender_address, TOKEN_CARRIER_OUTPUT_SOMPI).await?;
-        Ok(Response::new(pb::TokenSendResponse { tx_ids, nonce, sender_owner_id, recipient_owner_id }))
+        Ok(Response::new(pb::TokenSendResponse {
+            tx_ids,
+            nonce,
+            sender_owner_id,
+            recipient_owner_ids: vec![recipient_owner_id.clone()],
+            recipient_owner_id,
+        }))
     }
 
     async fn token_mint(&self, request: Request<pb::TokenMintRequest>) -> Result<Response<pb::TokenMintResponse>, Status> {
@@ -1489,7 +1715,7 @@ impl pb::cryptixwalletd_server::Cryptixwalletd for WalletDaemonService {
         let wallet_secret = Self::require_password(request.password)?;
         let amount = Self::parse_positive_u128(request.amount_raw.as_str(), "amount_raw")?;
         let auth_input_index = if request.auth_input_index == 0 {
-            DEFAULT_AUTH_INPUT_INDEX
+            TOKEN_AUTH_INPUT_INDEX
         } else {
             Self::parse_auth_input_index(request.auth_input_index)?
         };
@@ -1507,8 +1733,9 @@ impl pb::cryptixwalletd_server::Cryptixwalletd for WalletDaemonService {
         let sender_owner_id = self.resolve_owner_id(&sender_address, "sender_address").await?;
         let recipient_owner_id = self.resolve_owner_id(&recipient_address, "to_address").await?;
         let nonce = self.resolve_sender_nonce(sender_owner_id.as_str(), Some(request.asset_id.as_str())).await?;
-        let payload =
-            Self::build_mint_payload(request.asset_id.as_str(), recipient_owner_id.as_str(), amount, nonce, auth_input_index)?;
+        let operation = TokenOperation::Mint { asset_id: request.asset_id.clone(), to: recipient_address.clone(), amount };
+        let owner_ids = [(&recipient_address, recipient_owner_id.as_str())];
+        let payload = Self::encode_token_operation(&operation, &sender_address, &owner_ids, nonce, auth_input_index)?;
 
         let tx_ids = self.submit_payload_tx(account, wallet_secret, payload, sender_address, TOKEN_CARRIER_OUTPUT_SOMPI).await?;
         Ok(Response::new(pb::TokenMintResponse { tx_ids, nonce, sender_owner_id, recipient_owner_id }))
@@ -1519,7 +1746,7 @@ impl pb::cryptixwalletd_server::Cryptixwalletd for WalletDaemonService {
         let wallet_secret = Self::require_password(request.password)?;
         let amount = Self::parse_positive_u128(request.amount_raw.as_str(), "amount_raw")?;
         let auth_input_index = if request.auth_input_index == 0 {
-            DEFAULT_AUTH_INPUT_INDEX
+            TOKEN_AUTH_INPUT_INDEX
         } else {
             Self::parse_auth_input_index(request.auth_input_index)?
         };
@@ -1534,12 +1761,110 @@ impl pb::cryptixwalletd_server::Cryptixwalletd for WalletDaemonService {
 
         let sender_owner_id = self.resolve_owner_id(&sender_address, "sender_address").await?;
         let nonce = self.resolve_sender_nonce(sender_owner_id.as_str(), Some(request.asset_id.as_str())).await?;
-        let payload = Self::build_burn_payload(request.asset_id.as_str(), amount, nonce, auth_input_index)?;
+        let operation = TokenOperation::Burn { asset_id: request.asset_id.clone(), amount };
+        let payload = Self::encode_token_operation(&operation, &sender_address, &[], nonce, auth_input_index)?;
 
         let tx_ids = self.submit_payload_tx(account, wallet_secret, payload, sender_address, TOKEN_CARRIER_OUTPUT_SOMPI).await?;
         Ok(Response::new(pb::TokenBurnResponse { tx_ids, nonce, sender_owner_id }))
     }
 
+    async fn token_transfer_mint_authority(
+        &self,
+        request: Request<pb::TokenTransferMintAuthorityRequest>,
+    ) -> Result<Response<pb::TokenTransferMintAuthorityResponse>, Status> {
+        let request = request.into_inner();
+        let wallet_secret = Self::require_password(request.password)?;
+        let auth_input_index = if request.auth_input_index == 0 {
+            TOKEN_AUTH_INPUT_INDEX
+        } else {
+            Self::parse_auth_input_index(request.auth_input_index)?
+        };
+
+        let account = self.current_account()?;
+        let new_authority_address = Address::try_from(request.new_authority_address.as_str())
+            .map_err(|err| Status::invalid_argument(format!("invalid new_authority_address: {err}")))?;
+        let sender_address = if request.sender_address.trim().is_empty() {
+            account.receive_address().map_err(Self::status_internal)?
+        } else {
+            Address::try_from(request.sender_address.as_str())
+                .map_err(|err| Status::invalid_argument(format!("invalid sender_address: {err}")))?
+        };
+
+        let sender_owner_id = self.resolve_owner_id(&sender_address, "sender_address").await?;
+        let new_authority_owner_id = self.resolve_owner_id(&new_authority_address, "new_authority_address").await?;
+        let nonce = self.resolve_sender_nonce(sender_owner_id.as_str(), Some(request.asset_id.as_str())).await?;
+        let operation =
+            TokenOperation::TransferMintAuthority { asset_id: request.asset_id.clone(), new_authority: new_authority_address.clone() };
+        let owner_ids = [(&new_authority_address, new_authority_owner_id.as_str())];
+        let payload = Self::encode_token_operation(&operation, &sender_address, &owner_ids, nonce, auth_input_index)?;
+
+        let tx_ids = self.submit_payload_tx(account, wallet_secret, payload, sender_address, TOKEN_CARRIER_OUTPUT_SOMPI).await?;
+        Ok(Response::new(pb::TokenTransferMintAuthorityResponse { tx_ids, nonce, sender_owner_id, new_authority_owner_id }))
+    }
+
+    async fn token_renounce_mint_authority(
+        &self,
+        request: Request<pb::TokenRenounceMintAuthorityRequest>,
+    ) -> Result<Response<pb::TokenRenounceMintAuthorityResponse>, Status> {
+        let request = request.into_inner();
+        let wallet_secret = Self::require_password(request.password)?;
+        let auth_input_index = if request.auth_input_index == 0 {
+            TOKEN_AUTH_INPUT_INDEX
+        } else {
+            Self::parse_auth_input_index(request.auth_input_index)?
+        };
+
+        let account = self.current_account()?;
+        let sender_address = if request.sender_address.trim().is_empty() {
+            account.receive_address().map_err(Self::status_internal)?
+        } else {
+            Address::try_from(request.sender_address.as_str())
+                .map_err(|err| Status::invalid_argument(format!("invalid sender_address: {err}")))?
+        };
+
+        let sender_owner_id = self.resolve_owner_id(&sender_address, "sender_address").await?;
+        let nonce = self.resolve_sender_nonce(sender_owner_id.as_str(), Some(request.asset_id.as_str())).await?;
+        let operation = TokenOperation::RenounceMintAuthority { asset_id: request.asset_id.clone() };
+        let payload = Self::encode_token_operation(&operation, &sender_address, &[], nonce, auth_input_index)?;
+
+        let tx_ids = self.submit_payload_tx(account, wallet_secret, payload, sender_address, TOKEN_CARRIER_OUTPUT_SOMPI).await?;
+        Ok(Response::new(pb::TokenRenounceMintAuthorityResponse { tx_ids, nonce, sender_owner_id }))
+    }
+
+    async fn token_update_metadata(
+        &self,
+        request: Request<pb::TokenUpdateMetadataRequest>,
+    ) -> Result<Response<pb::TokenUpdateMetadataResponse>, Status> {
+        let request = request.into_inner();
+        let wallet_secret = Self::require_password(request.password)?;
+        let auth_input_index = if request.auth_input_index == 0 {
+            TOKEN_AUTH_INPUT_INDEX
+        } else {
+            Self::parse_auth_input_index(request.auth_input_index)?
+        };
+        let metadata = Self::parse_metadata_hex(request.metadata_hex.as_str())?;
+
+        let account = self.current_account()?;
+        let sender_address = if request.sender_address.trim().is_empty() {
+            account.receive_address().map_err(Self::status_internal)?
+        } else {
+            Address::try_from(request.sender_address.as_str())
+                .map_err(|err| Status::invalid_argument(format!("invalid sender_address: {err}")))?
+        };
+
+        let sender_owner_id = self.resolve_owner_id(&sender_address, "sender_address").await?;
+        let nonce = self.resolve_sender_nonce(sender_owner_id.as_str(), Some(request.asset_id.as_str())).await?;
+        let operation = TokenOperation::UpdateMetadata {
+            asset_id: request.asset_id.clone(),
+            metadata,
+            platform_tag: Some(request.platform_tag.clone()),
+        };
+        let payload = Self::encode_token_operation(&operation, &sender_address, &[], nonce, auth_input_index)?;
+
+        let tx_ids = self.submit_payload_tx(account, wallet_secret, payload, sender_address, TOKEN_CARRIER_OUTPUT_SOMPI).await?;
+        Ok(Response::new(pb::TokenUpdateMetadataResponse { tx_ids, nonce, sender_owner_id }))
+    }
+
     async fn token_create(&self, request: Request<pb::TokenCreateRequest>) -> Result<Response<pb::TokenCreateResponse>, Status> {
         let request = request.into_inner();
         let wallet_secret = Self::require_password(request.password)?;
@@ -1550,7 +1875,7 @@ impl pb::cryptixwalletd_server::Cryptixwalletd for WalletDaemonService {
         Self::validate_platform_tag(request.platform_tag.as_str())?;
         Self::validate_asset_identity_fields(request.name.as_str(), request.symbol.as_str(), metadata.as_slice(), decimals)?;
         let auth_input_index = if request.auth_input_index == 0 {
-            DEFAULT_AUTH_INPUT_INDEX
+            TOKEN_AUTH_INPUT_INDEX
         } else {
             Self::parse_auth_input_index(request.auth_input_index)?
         };
@@ -1572,18 +1897,18 @@ impl pb::cryptixwalletd_server::Cryptixwalletd for WalletDaemonService {
         let sender_owner_id = self.resolve_owner_id(&sender_address, "sender_address").await?;
         let mint_authority_owner_id = self.resolve_owner_id(&mint_authority_address, "mint_authority_address").await?;
         let nonce = self.resolve_sender_nonce(sender_owner_id.as_str(), None).await?;
-        let payload = Self::build_create_asset_payload(
-            request.name.as_str(),
-            request.symbol.as_str(),
+        let operation = TokenOperation::CreateAsset {
+            name: request.name.clone(),
+            symbol: request.symbol.clone(),
             decimals,
             supply_mode,
             max_supply,
-            mint_authority_owner_id.as_str(),
-            metadata.as_slice(),
-            request.platform_tag.as_str(),
-            nonce,
-            auth_input_index,
-        )?;
+            mint_authority: Some(mint_authority_address.clone()),
+            metadata,
+            platform_tag: Some(request.platform_tag.clone()),
+        };
+        let owner_ids = [(&mint_authority_address, mint_authority_owner_id.as_str())];
+        let payload = Self::encode_token_operation(&operation, &sender_address, &owner_ids, nonce, auth_input_index)?;
 
         let tx_ids = self.submit_payload_tx(account, wallet_secret, payload, sender_address, TOKEN_CARRIER_OUTPUT_SOMPI).await?;
         let asset_id = tx_ids.first().cloned().unwrap_or_default();
@@ -1600,7 +1925,7 @@ impl pb::cryptixwalletd_server::Cryptixwalletd for WalletDaemonService {
         let max_supply = Self::parse_u128(request.max_supply_raw.as_str(), "max_supply_raw")?;
         let supply_mode = Self::validate_supply_mode(request.capped, max_supply)?;
         let initial_mint_amount = Self::parse_positive_u128(request.initial_mint_amount_raw.as_str(), "initial_mint_amount_raw")?;
-        if supply_mode == 1 && initial_mint_amount > max_supply {
+        if supply_mode == TokenSupplyMode::Capped && initial_mint_amount > max_supply {
             return Err(Status::invalid_argument("initial_mint_amount_raw exceeds max_supply_raw for capped token"));
         }
 
@@ -1608,7 +1933,7 @@ impl pb::cryptixwalletd_server::Cryptixwalletd for WalletDaemonService {
         Self::validate_platform_tag(request.platform_tag.as_str())?;
         Self::validate_asset_identity_fields(request.name.as_str(), request.symbol.as_str(), metadata.as_slice(), decimals)?;
         let auth_input_index = if request.auth_input_index == 0 {
-            DEFAULT_AUTH_INPUT_INDEX
+            TOKEN_AUTH_INPUT_INDEX
         } else {
             Self::parse_auth_input_index(request.auth_input_index)?
         };
@@ -1633,20 +1958,23 @@ impl pb::cryptixwalletd_server::Cryptixwalletd for WalletDaemonService {
         let mint_authority_owner_id = self.resolve_owner_id(&mint_authority_address, "mint_authority_address").await?;
         let initial_mint_to_owner_id = self.resolve_owner_id(&initial_mint_to_address, "initial_mint_to_address").await?;
         let nonce = self.resolve_sender_nonce(sender_owner_id.as_str(), None).await?;
-        let payload = Self::build_create_asset_with_mint_payload(
-            request.name.as_str(),
-            request.symbol.as_str(),
+        let operation = TokenOperation::CreateAssetWithMint {
+            name: request.name.clone(),
+            symbol: request.symbol.clone(),
             decimals,
             supply_mode,
             max_supply,
-            mint_authority_owner_id.as_str(),
-            metadata.as_slice(),
+            mint_authority: Some(mint_authority_address.clone()),
+            metadata,
             initial_mint_amount,
-            initial_mint_to_owner_id.as_str(),
-            request.platform_tag.as_str(),
-            nonce,
-            auth_input_index,
-        )?;
+            initial_mint_to: Some(initial_mint_to_address.clone()),
+            platform_tag: Some(request.platform_tag.clone()),
+        };
+        let owner_ids = [
+            (&mint_authority_address, mint_authority_owner_id.as_str()),
+            (&initial_mint_to_address, initial_mint_to_owner_id.as_str()),
+        ];
+        let payload = Self::encode_token_operation(&operation, &sender_address, &owner_ids, nonce, auth_input_index)?;
 
         let tx_ids = self.submit_payload_tx(account, wallet_secret, payload, sender_address, TOKEN_CARRIER_OUTPUT_SOMPI).await?;
         let asset_id = tx_ids.first().cloned().unwrap_or_default();
@@ -1677,6 +2005,7 @@ impl pb::cryptixwalletd_server::Cryptixwalletd for WalletDaemonService {
         if request.seed_reserve_sompi == 0 {
             return Err(Status::invalid_argument("seed_reserve_sompi must be greater than zero"));
         }
+        Self::validate_liquidity_create_parameters(decimals, max_supply, request.seed_reserve_sompi)?;
 
         let fee_bps = u16::try_from(request.fee_bps).map_err(|_| Status::invalid_argument("fee_bps must be <= 65535"))?;
         if !(fee_bps == 0 || (CAT_MIN_LIQUIDITY_FEE_BPS..=CAT_MAX_LIQUIDITY_FEE_BPS).contains(&fee_bps)) {
@@ -1721,7 +2050,7 @@ impl pb::cryptixwalletd_server::Cryptixwalletd for WalletDaemonService {
         };
 
         let auth_input_index = if request.auth_input_index == 0 {
-            DEFAULT_AUTH_INPUT_INDEX
+            TOKEN_AUTH_INPUT_INDEX
         } else {
             Self::parse_auth_input_index(request.auth_input_index)?
         };
@@ -1736,22 +2065,21 @@ impl pb::cryptixwalletd_server::Cryptixwalletd for WalletDaemonService {
 
         let sender_owner_id = self.resolve_owner_id(&sender_address, "sender_address").await?;
         let nonce = self.resolve_sender_nonce(sender_owner_id.as_str(), None).await?;
-        let payload = Self::build_create_liquidity_asset_payload(
-            request.name.as_str(),
-            request.symbol.as_str(),
-            decimals,
+        let operation = TokenOperation::CreateLiquidityAsset {
+            name: request.name.clone(),
+            symbol: request.symbol.clone(),
             max_supply,
-            metadata.as_slice(),
-            request.seed_reserve_sompi,
+            metadata,
+            seed_reserve_sompi: request.seed_reserve_sompi,
             fee_bps,
-            recipients.as_slice(),
+            fee_recipients: recipients,
             launch_buy_sompi,
             launch_buy_min_token_out,
-            request.platform_tag.as_str(),
-            request.liquidity_unlock_target_sompi,
-            nonce,
-            auth_input_index,
-        )?;
+            platform_tag: Some(request.platform_tag.clone()),
+            liquidity_unlock_target_sompi: request.liquidity_unlock_target_sompi,
+            curve: TokenLiquidityCurve::Basic,
+        };
+        let payload = Self::encode_token_operation(&operation, &sender_address, &[], nonce, auth_input_index)?;
 
         let vault_value = request
             .seed_reserve_sompi
@@ -1774,10 +2102,10 @@ impl pb::cryptixwalletd_server::Cryptixwalletd for WalletDaemonService {
         }
         let min_token_out = Self::parse_positive_u128(request.min_token_out_raw.as_str(), "min_token_out_raw")?;
         let auth_input_index = if request.auth_input_index == 0 {
-            LIQUIDITY_AUTH_INPUT_INDEX
+            TOKEN_LIQUIDITY_AUTH_INPUT_INDEX
         } else {
             let parsed = Self::parse_auth_input_index(request.auth_input_index)?;
-            if parsed != LIQUIDITY_AUTH_INPUT_INDEX {
+            if parsed != TOKEN_LIQUIDITY_AUTH_INPUT_INDEX {
                 return Err(Status::invalid_argument("liquidity transitions require auth_input_index=1"));
             }
             parsed
@@ -1826,14 +2154,13 @@ impl pb::cryptixwalletd_server::Cryptixwalletd for WalletDaemonService {
 
         let sender_owner_id = self.resolve_owner_id(&sender_address, "sender_address").await?;
         let nonce = self.resolve_sender_nonce(sender_owner_id.as_str(), Some(request.asset_id.as_str())).await?;
-        let payload = Self::build_buy_liquidity_payload(
-            asset_id.as_str(),
-            pool.pool_nonce,
+        let operation = TokenOperation::BuyLiquidityExactIn {
+            asset_id: asset_id.clone(),
+            expected_pool_nonce: pool.pool_nonce,
             cpay_in_sompi,
             min_token_out,
-            nonce,
-            auth_input_index,
-        )?;
+        };
+        let payload = Self::encode_token_operation(&operation, &sender_address, &[], nonce, auth_input_index)?;
         let vault_value = Self::pool_vault_value(&pool)?
             .checked_add(cpay_in_sompi)
             .ok_or_else(|| Status::invalid_argument("vault value overflows u64 after buy"))?;
@@ -1862,10 +2189,10 @@ impl pb::cryptixwalletd_server::Cryptixwalletd for WalletDaemonService {
             return Err(Status::invalid_argument("min_cpay_out_sompi must be greater than zero"));
         }
         let auth_input_index = if request.auth_input_index == 0 {
-            LIQUIDITY_AUTH_INPUT_INDEX
+            TOKEN_LIQUIDITY_AUTH_INPUT_INDEX
         } else {
             let parsed = Self::parse_auth_input_index(request.auth_input_index)?;
-            if parsed != LIQUIDITY_AUTH_INPUT_INDEX {
+            if parsed != TOKEN_LIQUIDITY_AUTH_INPUT_INDEX {
                 return Err(Status::invalid_argument("liquidity transitions require auth_input_index=1"));
             }
             parsed
@@ -1904,15 +2231,14 @@ impl pb::cryptixwalletd_server::Cryptixwalletd for WalletDaemonService {
 
         let sender_owner_id = self.resolve_owner_id(&sender_address, "sender_address").await?;
         let nonce = self.resolve_sender_nonce(sender_owner_id.as_str(), Some(request.asset_id.as_str())).await?;
-        let payload = Self::build_sell_liquidity_payload(
-            asset_id.as_str(),
-            pool.pool_nonce,
+        let operation = TokenOperation::SellLiquidityExactIn {
+            asset_id: asset_id.clone(),
+            expected_pool_nonce: pool.pool_nonce,
             token_in,
-            request.min_cpay_out_sompi,
-            1,
-            nonce,
-            auth_input_index,
-        )?;
+            min_cpay_out_sompi: request.min_cpay_out_sompi,
+            cpay_receive_output_index: 1,
+        };
+        let payload = Self::encode_token_operation(&operation, &sender_address, &[], nonce, auth_input_index)?;
         let vault_value = Self::pool_vault_value(&pool)?
             .checked_sub(cpay_out)
             .ok_or_else(|| Status::invalid_argument("vault value underflows after sell"))?;
@@ -1942,10 +2268,10 @@ impl pb::cryptixwalletd_server::Cryptixwalletd for WalletDaemonService {
             return Err(Status::invalid_argument("claim_amount_sompi must be greater than zero"));
         }
         let auth_input_index = if request.auth_input_index == 0 {
-            LIQUIDITY_AUTH_INPUT_INDEX
+            TOKEN_LIQUIDITY_AUTH_INPUT_INDEX
         } else {
             let parsed = Self::parse_auth_input_index(request.auth_input_index)?;
-            if parsed != LIQUIDITY_AUTH_INPUT_INDEX {
+            if parsed != TOKEN_LIQUIDITY_AUTH_INPUT_INDEX {
                 return Err(Status::invalid_argument("liquidity transitions require auth_input_index=1"));
             }
             parsed
@@ -1963,15 +2289,14 @@ impl pb::cryptixwalletd_server::Cryptixwalletd for WalletDaemonService {
         Self::ensure_liquidity_outflow_unlocked(&pool, "liquidity fee claim")?;
         let sender_owner_id = self.resolve_owner_id(&sender_address, "sender_address").await?;
         let nonce = self.resolve_sender_nonce(sender_owner_id.as_str(), Some(request.asset_id.as_str())).await?;
-        let payload = Self::build_claim_liquidity_payload(
-            asset_id.as_str(),
-            pool.pool_nonce,
+        let operation = TokenOperation::ClaimLiquidityFees {
+            asset_id: asset_id.clone(),
+            expected_pool_nonce: pool.pool_nonce,
             recipient_index,
-            request.claim_amount_sompi,
-            1,
-            nonce,
-            auth_input_index,
-        )?;
+            claim_amount_sompi: request.claim_amount_sompi,
+            claim_receive_output_index: 1,
+        };
+        let payload = Self::encode_token_operation(&operation, &sender_address, &[], nonce, auth_input_index)?;
         let vault_value = Self::pool_vault_value(&pool)?
             .checked_sub(request.claim_amount_sompi)
             .ok_or_else(|| Status::invalid_argument("vault value underflows after claim"))?;
@@ -2031,18 +2356,133 @@ impl pb::cryptixwalletd_server::Cryptixwalletd for WalletDaemonService {
         }))
     }
 
-    async fn sign(&self, _request: Request<pb::SignRequest>) -> Result<Response<pb::SignResponse>, Status> {
-        Err(Status::unimplemented(
-            "Sign is not implemented in rust wallet daemon yet (legacy unsigned transaction bytes format is not supported)",
-        ))
+    async fn sign(&self, request: Request<pb::SignRequest>) -> Result<Response<pb::SignResponse>, Status> {
+        let request = request.into_inner();
+        if request.unsigned_transactions.is_empty() {
+            return Err(Status::invalid_argument("unsignedTransactions must not be empty"));
+        }
+        let wallet_secret = Self::require_password(request.password)?;
+        let account = self.current_account()?;
+
+        let keys = self.signing_keys_for_transactions(account, wallet_secret, &request.unsigned_transactions).await?;
+        if keys.is_empty() {
+            return Err(Status::failed_precondition("none of the transaction inputs are spendable by the selected account"));
+        }
+        let signed_transactions = request
+            .unsigned_transactions
+            .iter()
+            .map(|unsigned| serialization::sign_serialized(unsigned, &keys))
+            .collect::<Result<Vec<_>, Status>>()?;
+
+        Ok(Response::new(pb::SignResponse { signed_transactions }))
     }
 
     async fn get_version(&self, _request: Request<pb::GetVersionRequest>) -> Result<Response<pb::GetVersionResponse>, Status> {
         Ok(Response::new(pb::GetVersionResponse { version: env!("CARGO_PKG_VERSION").to_string() }))
     }
 
-    async fn bump_fee(&self, _request: Request<pb::BumpFeeRequest>) -> Result<Response<pb::BumpFeeResponse>, Status> {
-        Err(Status::unimplemented("BumpFee is not implemented in rust wallet daemon yet"))
+    async fn bump_fee(&self, request: Request<pb::BumpFeeRequest>) -> Result<Response<pb::BumpFeeResponse>, Status> {
+        let request = request.into_inner();
+        let wallet_secret = Self::require_password(request.password)?;
+        let tx_id = RpcHash::from_str(request.tx_id.trim()).map_err(|err| Status::invalid_argument(format!("invalid txID: {err}")))?;
+        let sender_address = match request.from.as_slice() {
+            [] => None,
+            [single] => Some(
+                Address::try_from(single.as_str()).map_err(|err| Status::invalid_argument(format!("invalid from-address: {err}")))?,
+            ),
+            _ => {
+                return Err(Status::unimplemented("multiple from-address values are not implemented in rust wallet daemon yet"));
+            }
+        };
+
+        let account = self.current_account()?;
+        // outgoing transactions are only tracked in memory, so after a restart the original is loaded from the record store
+        let outgoing = self.wallet().utxo_processor().outgoing().get(&tx_id).map(|entry| entry.value().clone());
+        let original = match outgoing.as_ref() {
+            Some(outgoing) => {
+                if outgoing.is_accepted() {
+                    return Err(Status::failed_precondition(format!("transaction {tx_id} is already accepted")));
+                }
+                if outgoing.is_batch() {
+                    return Err(Status::failed_precondition(format!(
+                        "transaction {tx_id} is a batch transaction; bump the final transaction of the send instead"
+                    )));
+                }
+                let pending = outgoing.pending_transaction();
+                FeeBumpOriginal {
+                    transaction: pending.transaction(),
+                    change_output_index: pending.change_output_index(),
+                    inputs: pending.utxo_entries().values().cloned().collect(),
+                    fees: pending.fees(),
+                    mass: pending.mass(),
+                }
+            }
+            None => self.stored_fee_bump_original(&account, tx_id).await?,
+        };
+
+        if original.transaction.payload.starts_with(&CAT_MAGIC) {
+            return Err(Status::failed_precondition("atomic token transactions cannot be replaced"));
+        }
+        let original_feerate = original.fees as f64 / original.mass.max(1) as f64;
+        let network_feerate = self.network_normal_feerate().await?;
+        let (target_feerate, max_total_fee_sompi) =
+            Self::bump_fee_target(request.fee_policy.as_ref(), original_feerate, network_feerate)?;
+
+        let (change_address, extra_inputs) = match sender_address {
+            Some(sender_address) => {
+                let extra_inputs = UtxoIterator::new(account.utxo_context())
+                    .filter(|entry| entry.address().as_ref() == Some(&sender_address))
+                    .collect::<Vec<_>>();
+                (sender_address, extra_inputs)
+            }
+            None => (account.change_address().map_err(Self::status_internal)?, UtxoIterator::new(account.utxo_context()).collect()),
+        };
+        let plan = FeeBumpPlan {
+            network_id: self.runtime.network_id,
+            change_address,
+            sig_op_count: account.sig_op_count(),
+            minimum_signatures: account.minimum_signatures(),
+            outputs: Self::fee_bump_outputs(&original, target_feerate)?,
+            payload: (!original.transaction.payload.is_empty()).then(|| original.transaction.payload.clone()),
+            inputs: original.inputs,
+            extra_inputs,
+        };
+        let transaction = Self::build_fee_bump_transaction(&plan, target_feerate).await?;
+        if let Some(max_total_fee) = max_total_fee_sompi {
+            if transaction.fees() > max_total_fee {
+                return Err(Status::failed_precondition(format!(
+                    "replacement fees {} exceed configured max fee {max_total_fee} (policy: {})",
+                    transaction.fees(),
+                    Self::describe_fee_policy(request.fee_policy.as_ref())
+                )));
+            }
+        }
+
+        let mut private_keys = self
+            .private_keys_for_addresses(account, wallet_secret, transaction.addresses())
+            .await?
+            .into_iter()
+            .map(|key| key.secret_bytes())
+            .collect::<Vec<_>>();
+        let signed = transaction.try_sign_with_keys(&private_keys, Some(true));
+        Self::clear_private_keys(&mut private_keys);
+        signed.map_err(Self::status_internal)?;
+
+        let response =
+            self.rpc().submit_transaction_replacement(transaction.rpc_transaction()).await.map_err(Self::status_internal)?;
+        let replaced_tx_id =
+            cryptix_consensus_core::tx::Transaction::try_from(response.replaced_transaction).map(|tx| tx.id()).unwrap_or(tx_id);
+        // the replacement double spends the original inputs, so the original can no longer be accepted
+        if let Some(outgoing) = outgoing {
+            outgoing.originating_context().remove_outgoing_transaction(&tx_id);
+            self.wallet().utxo_processor().cancel_outgoing_transaction(tx_id);
+        }
+
+        Ok(Response::new(pb::BumpFeeResponse {
+            transactions: vec![serialization::transaction_to_proto(&transaction.transaction()).encode_to_vec()],
+            tx_i_ds: vec![response.transaction_id.to_string()],
+            replaced_tx_id: replaced_tx_id.to_string(),
+        }))
     }
 }
 
@@ -2486,7 +2926,7 @@ fn print_start_daemon_help() {
     println!("  --account <name-or-id-prefix>   selected account");
     println!("  --create-if-missing             create a default account if wallet has none");
     println!("  coin RPC methods: GetBalance, ShowAddresses, NewAddress, Send, SendPayload, GetExternalSpendableUTXOs");
-    println!("  token RPC methods: TokenSend, TokenMint, TokenBurn, TokenCreate, TokenCreateMint, TokenCreateLiquidity, TokenBuyLiquidity, TokenSellLiquidity, TokenClaimLiquidity");
+    println!("  token RPC methods: TokenSend, TokenMint, TokenBurn, TokenTransferMintAuthority, TokenRenounceMintAuthority, TokenUpdateMetadata, TokenCreate, TokenCreateMint, TokenCreateLiquidity, TokenBuyLiquidity, TokenSellLiquidity, TokenClaimLiquidity");
     println!("  watch/scan RPC methods: TokenBalances, ScanAddresses");
 }
 
@@ -2500,6 +2940,7 @@ mod tests {
     use super::*;
     use cryptix_atomicindex::liquidity_math::DEFAULT_LIQUIDITY_CURVE_MODE;
     use cryptix_atomicindex::payload::{parse_atomic_token_payload, SupplyMode, TokenOp};
+    use cryptix_wallet_core::tx::{CAT_CURRENT_LIQUIDITY_CURVE_VERSION, CAT_CURRENT_TOKEN_VERSION};
 
     const TEST_AUTH_INPUT_INDEX: u16 = 2;
     const TEST_NONCE: u64 = 7;
@@ -2508,17 +2949,31 @@ mod tests {
         hex::encode([byte; 32])
     }
 
+    fn address(byte: u8) -> Address {
+        Address::new(Prefix::Testnet, cryptix_addresses::Version::PubKey, &[byte; 32])
+    }
+
+    fn create_asset(platform_tag: &str) -> TokenOperation {
+        TokenOperation::CreateAsset {
+            name: "Gold".to_string(),
+            symbol: "GLD".to_string(),
+            decimals: 8,
+            supply_mode: TokenSupplyMode::Capped,
+            max_supply: 100,
+            mint_authority: Some(address(7)),
+            metadata: b"hello".to_vec(),
+            platform_tag: Some(platform_tag.to_string()),
+        }
+    }
+
     #[test]
     fn native_create_asset_payload_matches_atomic_parser() {
-        let payload = WalletDaemonService::build_create_asset_payload(
-            "Gold",
-            "GLD",
-            8,
-            SupplyMode::Capped as u8,
-            100,
-            &owner_id(7),
-            b"hello",
-            "Bridge",
+        let mint_authority = owner_id(7);
+        let owner_ids = [(&address(7), mint_authority.as_str())];
+        let payload = WalletDaemonService::encode_token_operation(
+            &create_asset("Bridge"),
+            &address(1),
+            &owner_ids,
             TEST_NONCE,
             TEST_AUTH_INPUT_INDEX,
         )
@@ -2541,25 +2996,30 @@ mod tests {
             }
             _ => panic!("expected create asset"),
         }
+
+        // Owner ids are only taken from RPC; an unresolved address is an error.
+        assert!(WalletDaemonService::encode_token_operation(&create_asset(""), &address(1), &[], TEST_NONCE, 0).is_err());
     }
 
     #[test]
     fn native_create_asset_with_mint_payload_matches_atomic_parser() {
-        let payload = WalletDaemonService::build_create_asset_with_mint_payload(
-            "Gold",
-            "GLD",
-            8,
-            SupplyMode::Capped as u8,
-            100,
-            &owner_id(7),
-            b"hello",
-            42,
-            &owner_id(9),
-            "",
-            TEST_NONCE,
-            TEST_AUTH_INPUT_INDEX,
-        )
-        .unwrap();
+        let operation = TokenOperation::CreateAssetWithMint {
+            name: "Gold".to_string(),
+            symbol: "GLD".to_string(),
+            decimals: 8,
+            supply_mode: TokenSupplyMode::Capped,
+            max_supply: 100,
+            mint_authority: Some(address(7)),
+            metadata: b"hello".to_vec(),
+            initial_mint_amount: 42,
+            initial_mint_to: Some(address(9)),
+            platform_tag: Some(String::new()),
+        };
+        let (mint_authority, mint_to) = (owner_id(7), owner_id(9));
+        let owner_ids = [(&address(7), mint_authority.as_str()), (&address(9), mint_to.as_str())];
+        let payload =
+            WalletDaemonService::encode_token_operation(&operation, &address(1), &owner_ids, TEST_NONCE, TEST_AUTH_INPUT_INDEX)
+                .unwrap();
 
         let parsed = parse_atomic_token_payload(&payload).unwrap().unwrap();
         match parsed.op {
@@ -2575,23 +3035,22 @@ mod tests {
 
     #[test]
     fn native_create_liquidity_payload_matches_atomic_parser() {
-        let payload = WalletDaemonService::build_create_liquidity_asset_payload(
-            "Pool",
-            "POOL",
-            LIQUIDITY_TOKEN_DECIMALS,
-            MIN_LIQUIDITY_TOKEN_SUPPLY_RAW,
-            b"",
-            MIN_LIQUIDITY_SEED_RESERVE_SOMPI,
-            0,
-            &[],
-            0,
-            0,
-            "Bridge",
-            SOMPI_PER_CRYPTIX,
-            TEST_NONCE,
-            TEST_AUTH_INPUT_INDEX,
-        )
-        .unwrap();
+        let operation = TokenOperation::CreateLiquidityAsset {
+            name: "Pool".to_string(),
+            symbol: "POOL".to_string(),
+            max_supply: CAT_MIN_LIQUIDITY_SUPPLY_RAW,
+            metadata: vec![],
+            seed_reserve_sompi: CAT_MIN_LIQUIDITY_SEED_RESERVE_SOMPI,
+            fee_bps: 0,
+            fee_recipients: vec![],
+            launch_buy_sompi: 0,
+            launch_buy_min_token_out: 0,
+            platform_tag: Some("Bridge".to_string()),
+            liquidity_unlock_target_sompi: SOMPI_PER_CRYPTIX,
+            curve: TokenLiquidityCurve::Basic,
+        };
+        let payload =
+            WalletDaemonService::encode_token_operation(&operation, &address(1), &[], TEST_NONCE, TEST_AUTH_INPUT_INDEX).unwrap();
 
         let parsed = parse_atomic_token_payload(&payload).unwrap().unwrap();
         match parsed.op {
@@ -2599,13 +3058,262 @@ mod tests {
                 assert_eq!(op.token_version, CAT_CURRENT_TOKEN_VERSION);
                 assert_eq!(op.curve_version, CAT_CURRENT_LIQUIDITY_CURVE_VERSION);
                 assert_eq!(op.curve_mode, DEFAULT_LIQUIDITY_CURVE_MODE);
-                assert_eq!(op.decimals, LIQUIDITY_TOKEN_DECIMALS);
-                assert_eq!(op.max_supply, MIN_LIQUIDITY_TOKEN_SUPPLY_RAW);
-                assert_eq!(op.seed_reserve_sompi, MIN_LIQUIDITY_SEED_RESERVE_SOMPI);
+                assert_eq!(op.decimals, CAT_LIQUIDITY_TOKEN_DECIMALS);
+                assert_eq!(op.max_supply, CAT_MIN_LIQUIDITY_SUPPLY_RAW);
+                assert_eq!(op.seed_reserve_sompi, CAT_MIN_LIQUIDITY_SEED_RESERVE_SOMPI);
                 assert_eq!(op.platform_tag, b"Bridge");
                 assert_eq!(op.liquidity_unlock_target_sompi, SOMPI_PER_CRYPTIX);
             }
             _ => panic!("expected liquidity create asset"),
         }
     }
+
+    #[test]
+    fn native_batch_transfer_payload_matches_atomic_parser() {
+        let batch = |recipients: Vec<TokenRecipient>| TokenOperation::BatchTransfer { asset_id: owner_id(1), recipients };
+        let (first, second) = (owner_id(3), owner_id(4));
+        let owner_ids = [(&address(3), first.as_str()), (&address(4), second.as_str())];
+        let operation =
+            batch(vec![TokenRecipient { address: address(3), amount: 10 }, TokenRecipient { address: address(4), amount: 20 }]);
+        let payload =
+            WalletDaemonService::encode_token_operation(&operation, &address(1), &owner_ids, TEST_NONCE, TEST_AUTH_INPUT_INDEX)
+                .unwrap();
+
+        let parsed = parse_atomic_token_payload(&payload).unwrap().unwrap();
+        assert_eq!(parsed.header.nonce, TEST_NONCE);
+        assert_eq!(parsed.header.auth_input_index, TEST_AUTH_INPUT_INDEX);
+        match parsed.op {
+            TokenOp::BatchTransfer(op) => {
+                assert_eq!(op.asset_id, [1u8; 32]);
+                assert_eq!(op.recipients.len(), 2);
+                assert_eq!(op.recipients[0].to_owner_id, [3u8; 32]);
+                assert_eq!(op.recipients[0].amount, 10);
+                assert_eq!(op.recipients[1].to_owner_id, [4u8; 32]);
+                assert_eq!(op.recipients[1].amount, 20);
+            }
+            _ => panic!("expected batch transfer"),
+        }
+
+        let duplicate =
+            batch(vec![TokenRecipient { address: address(3), amount: 10 }, TokenRecipient { address: address(3), amount: 20 }]);
+        assert!(WalletDaemonService::encode_token_operation(&duplicate, &address(1), &owner_ids, TEST_NONCE, 0).is_err());
+        assert!(WalletDaemonService::encode_token_operation(&batch(vec![]), &address(1), &owner_ids, TEST_NONCE, 0).is_err());
+    }
+
+    #[test]
+    fn native_mint_authority_payloads_match_atomic_parser() {
+        let operation = TokenOperation::TransferMintAuthority { asset_id: owner_id(1), new_authority: address(5) };
+        let new_authority = owner_id(5);
+        let owner_ids = [(&address(5), new_authority.as_str())];
+        let payload =
+            WalletDaemonService::encode_token_operation(&operation, &address(2), &owner_ids, TEST_NONCE, TEST_AUTH_INPUT_INDEX)
+                .unwrap();
+        let parsed = parse_atomic_token_payload(&payload).unwrap().unwrap();
+        assert_eq!(parsed.header.nonce, TEST_NONCE);
+        match parsed.op {
+            TokenOp::TransferMintAuthority(op) => {
+                assert_eq!(op.asset_id, [1u8; 32]);
+                assert_eq!(op.new_mint_authority_owner_id, [5u8; 32]);
+            }
+            _ => panic!("expected transfer mint authority"),
+        }
+
+        let operation = TokenOperation::RenounceMintAuthority { asset_id: owner_id(1) };
+        let payload =
+            WalletDaemonService::encode_token_operation(&operation, &address(2), &[], TEST_NONCE, TEST_AUTH_INPUT_INDEX).unwrap();
+        let parsed = parse_atomic_token_payload(&payload).unwrap().unwrap();
+        match parsed.op {
+            TokenOp::RenounceMintAuthority(op) => assert_eq!(op.asset_id, [1u8; 32]),
+            _ => panic!("expected renounce mint authority"),
+        }
+    }
+
+    #[test]
+    fn native_update_metadata_payload_matches_atomic_parser() {
+        let update = |metadata: &[u8], platform_tag: &str| TokenOperation::UpdateMetadata {
+            asset_id: owner_id(1),
+            metadata: metadata.to_vec(),
+            platform_tag: Some(platform_tag.to_string()),
+        };
+        let payload = WalletDaemonService::encode_token_operation(
+            &update(b"ipfs://logo-v2", "wallet"),
+            &address(2),
+            &[],
+            TEST_NONCE,
+            TEST_AUTH_INPUT_INDEX,
+        )
+        .unwrap();
+        let parsed = parse_atomic_token_payload(&payload).unwrap().unwrap();
+        assert_eq!(parsed.header.nonce, TEST_NONCE);
+        match parsed.op {
+            TokenOp::UpdateMetadata(op) => {
+                assert_eq!(op.asset_id, [1u8; 32]);
+                assert_eq!(op.metadata, b"ipfs://logo-v2".to_vec());
+                assert_eq!(op.platform_tag, b"wallet".to_vec());
+            }
+            _ => panic!("expected update metadata"),
+        }
+
+        assert!(WalletDaemonService::encode_token_operation(
+            &update(&[0u8; CAT_MAX_METADATA_LEN + 1], ""),
+            &address(2),
+            &[],
+            TEST_NONCE,
+            TEST_AUTH_INPUT_INDEX
+        )
+        .is_err());
+    }
+
+    fn fee_bump_utxo(address: &Address, byte: u8, amount: u64) -> UtxoEntryReference {
+        UtxoEntryReference::from(UtxoEntry {
+            address: Some(address.clone()),
+            outpoint: ClientTransactionOutpoint::new(cryptix_consensus_core::tx::TransactionId::from_bytes([byte; 32]), 0),
+            amount,
+            script_public_key: cryptix_txscript::pay_to_address_script(address),
+            block_daa_score: 0,
+            is_coinbase: false,
+        })
+    }
+
+    fn fee_bump_plan(outputs: FeeBumpOutputs, inputs: &[UtxoEntryReference], extra_inputs: &[UtxoEntryReference]) -> FeeBumpPlan {
+        FeeBumpPlan {
+            network_id: NetworkId::new(NetworkType::Mainnet),
+            change_address: Address::new(Prefix::Mainnet, cryptix_addresses::Version::PubKey, &[0x01; 32]),
+            sig_op_count: 1,
+            minimum_signatures: 1,
+            outputs,
+            payload: None,
+            inputs: inputs.to_vec(),
+            extra_inputs: extra_inputs.to_vec(),
+        }
+    }
+
+    fn spent_outpoints(transaction: &PendingTransaction) -> HashSet<cryptix_consensus_core::tx::TransactionOutpoint> {
+        transaction.transaction().inputs.iter().map(|input| input.previous_outpoint).collect()
+    }
+
+    fn outpoints(entries: &[UtxoEntryReference]) -> HashSet<cryptix_consensus_core::tx::TransactionOutpoint> {
+        entries.iter().map(|entry| cryptix_consensus_core::tx::TransactionOutpoint::from(&entry.as_ref().outpoint)).collect()
+    }
+
+    #[tokio::test]
+    async fn fee_bump_keeps_original_inputs_and_raises_fee() {
+        let address = Address::new(Prefix::Mainnet, cryptix_addresses::Version::PubKey, &[0x03; 32]);
+        let destination = Address::new(Prefix::Mainnet, cryptix_addresses::Version::PubKey, &[0x02; 32]);
+        let inputs = vec![fee_bump_utxo(&address, 0x10, 100 * SOMPI_PER_CRYPTIX)];
+        let account_utxos = vec![fee_bump_utxo(&address, 0x11, 1_000 * SOMPI_PER_CRYPTIX)];
+        let payment = || {
+            FeeBumpOutputs::Payments(vec![ScriptPaymentOutput::new(
+                50 * SOMPI_PER_CRYPTIX,
+                cryptix_txscript::pay_to_address_script(&destination),
+            )])
+        };
+
+        let original = fee_bump_plan(payment(), &inputs, &[]);
+        let original = WalletDaemonService::build_fee_bump_transaction(&original, MIN_FEE_RATE_SOMPI_PER_GRAM).await.unwrap();
+        let target_feerate = 50.0;
+        let plan = fee_bump_plan(payment(), &inputs, &account_utxos);
+        let replacement = WalletDaemonService::build_fee_bump_transaction(&plan, target_feerate).await.unwrap();
+
+        // the original input covers the raised fee, so no account UTXO is added
+        assert_eq!(spent_outpoints(&replacement), outpoints(&inputs));
+        assert!(replacement.fees() > original.fees());
+        assert!(replacement.fees() >= WalletDaemonService::fee_from_mass_and_feerate(replacement.mass(), target_feerate).unwrap());
+
+        // a raised fee the original input cannot cover pulls in the account UTXO
+        let small_inputs = vec![fee_bump_utxo(&address, 0x12, 50 * SOMPI_PER_CRYPTIX + 10_000)];
+        let plan = fee_bump_plan(payment(), &small_inputs, &account_utxos);
+        let replacement = WalletDaemonService::build_fee_bump_transaction(&plan, target_feerate).await.unwrap();
+        assert_eq!(spent_outpoints(&replacement), &outpoints(&small_inputs) | &outpoints(&account_utxos));
+    }
+
+    #[tokio::test]
+    async fn fee_bump_replaces_change_only_original() {
+        let address = Address::new(Prefix::Mainnet, cryptix_addresses::Version::PubKey, &[0x03; 32]);
+        let inputs =
+            vec![fee_bump_utxo(&address, 0x20, 10 * SOMPI_PER_CRYPTIX), fee_bump_utxo(&address, 0x21, 20 * SOMPI_PER_CRYPTIX)];
+        let account_utxos = vec![fee_bump_utxo(&address, 0x22, 1_000 * SOMPI_PER_CRYPTIX)];
+
+        // a compound of the two inputs into the account change address
+        let settings = GeneratorSettings::try_new_with_iterator(
+            NetworkId::new(NetworkType::Mainnet),
+            Box::new(inputs.clone().into_iter()),
+            None,
+            address.clone(),
+            1,
+            1,
+            PaymentDestination::Change,
+            Fees::None,
+            None,
+            None,
+        )
+        .unwrap();
+        let pending = Generator::try_new(settings, None, None).unwrap().stream().try_next().await.unwrap().unwrap();
+        let original = FeeBumpOriginal {
+            transaction: pending.transaction(),
+            change_output_index: pending.change_output_index(),
+            inputs: pending.utxo_entries().values().cloned().collect(),
+            fees: pending.fees(),
+            mass: pending.mass(),
+        };
+
+        let target_feerate = 50.0;
+        let outputs = WalletDaemonService::fee_bump_outputs(&original, target_feerate).unwrap();
+        assert!(matches!(outputs, FeeBumpOutputs::SelfOutput { .. }));
+        let plan = FeeBumpPlan { change_address: address.clone(), ..fee_bump_plan(outputs, &original.inputs, &account_utxos) };
+        let replacement = WalletDaemonService::build_fee_bump_transaction(&plan, target_feerate).await.unwrap();
+
+        let transaction = replacement.transaction();
+        assert_eq!(spent_outpoints(&replacement), outpoints(&inputs));
+        assert_eq!(transaction.outputs.len(), 1);
+        assert_eq!(transaction.outputs[0].script_public_key, original.transaction.outputs[0].script_public_key);
+        assert!(replacement.fees() > original.fees);
+        assert!(replacement.fees() >= WalletDaemonService::fee_from_mass_and_feerate(replacement.mass(), target_feerate).unwrap());
+        assert_eq!(transaction.outputs[0].value + replacement.fees(), 30 * SOMPI_PER_CRYPTIX);
+    }
+
+    #[tokio::test]
+    async fn fee_bump_gives_up_after_max_attempts() {
+        // every build needs one more input than the previous one, so the raised priority fee never catches up
+        let mut builds = 0u64;
+        let result = WalletDaemonService::raise_priority_fee(2.0, |priority_fee_sompi| {
+            builds += 1;
+            let mass = 1_000 * builds;
+            async move { Ok::<_, Status>(((), mass + priority_fee_sompi, mass)) }
+        })
+        .await;
+        assert!(result.is_err());
+        assert_eq!(builds, FEE_BUMP_MAX_ATTEMPTS as u64);
+
+        let mut builds = 0u64;
+        let result = WalletDaemonService::raise_priority_fee(2.0, |priority_fee_sompi| {
+            builds += 1;
+            async move { Ok::<_, Status>(((), 1_000 + priority_fee_sompi, 1_000)) }
+        })
+        .await;
+        assert!(result.is_ok());
+        assert_eq!(builds, 2);
+    }
+
+    fn fee_policy(policy: pb::fee_policy::FeePolicy) -> pb::FeePolicy {
+        pb::FeePolicy { fee_policy: Some(policy) }
+    }
+
+    #[test]
+    fn bump_fee_target_exceeds_original_feerate() {
+        assert_eq!(WalletDaemonService::bump_fee_target(None, 2.0, 1.0).unwrap(), (3.0, Some(DEFAULT_SEND_MAX_FEE_SOMPI)));
+        assert_eq!(WalletDaemonService::bump_fee_target(None, 2.0, 5.0).unwrap().0, 5.0);
+
+        let exact = fee_policy(pb::fee_policy::FeePolicy::ExactFeeRate(10.0));
+        assert_eq!(WalletDaemonService::bump_fee_target(Some(&exact), 2.0, 1.0).unwrap(), (10.0, None));
+        let too_low = fee_policy(pb::fee_policy::FeePolicy::ExactFeeRate(2.5));
+        assert!(WalletDaemonService::bump_fee_target(Some(&too_low), 2.0, 1.0).is_err());
+
+        let max_rate = fee_policy(pb::fee_policy::FeePolicy::MaxFeeRate(4.0));
+        assert_eq!(WalletDaemonService::bump_fee_target(Some(&max_rate), 2.0, 8.0).unwrap(), (4.0, None));
+        assert!(WalletDaemonService::bump_fee_target(Some(&max_rate), 3.5, 1.0).is_err());
+
+        let max_fee = fee_policy(pb::fee_policy::FeePolicy::MaxFee(5_000));
+        assert_eq!(WalletDaemonService::bump_fee_target(Some(&max_fee), 2.0, 1.0).unwrap(), (3.0, Some(5_000)));
+    }
 }
@@ -1,4 +1,5 @@
 mod daemon;
+mod serialization;
 
 use cryptix_cli_lib::{cryptix_cli, cryptix_cli_command, TerminalOptions};
 
@@ -0,0 +1,665 @@
+//!
+//! Serialized transaction formats exchanged by the `CreateUnsignedTransactions`, `Sign` and
+//! `Broadcast` daemon RPCs.
+//!
+//! Two formats are supported:
+//! - legacy: a protobuf encoded `protoserialization.PartiallySignedTransaction`, the byte format
+//!   of the Go wallet daemon;
+//! - PSKB: the bytes of the `PSKB`-prefixed hex string produced by `Bundle::serialize`.
+//!
+//! Sign and Broadcast detect the format from the `PSKB` prefix and sign in the same format.
+//!
+
+use cryptix_bip32::{DerivationPath, ExtendedPublicKey, Prefix};
+use cryptix_consensus_core::hashing::sighash::{calc_schnorr_signature_hash, SigHashReusedValues};
+use cryptix_consensus_core::hashing::sighash_type::SIG_HASH_ALL;
+use cryptix_consensus_core::subnets::SubnetworkId;
+use cryptix_consensus_core::tx::{
+    ScriptPublicKey, SignableTransaction, Transaction, TransactionId, TransactionInput, TransactionOutpoint, TransactionOutput,
+    UtxoEntry,
+};
+use cryptix_txscript::script_builder::ScriptBuilder;
+use cryptix_wallet_core::account::pskb::finalize_pskt_one_or_more_sig_and_redeem_script;
+use cryptix_wallet_pskt::prelude::{Bundle, Finalizer, Inner, SignInputOk, Signature, Signer, PSKT};
+use prost::Message as _;
+use secp256k1::{Keypair, Message, SecretKey, XOnlyPublicKey};
+use std::collections::HashMap;
+use std::str::FromStr;
+use tonic::Status;
+
+pub mod protoserialization {
+    tonic::include_proto!("protoserialization");
+}
+
+const PSKB_PREFIX: &[u8] = b"PSKB";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransactionFormat {
+    Legacy,
+    Pskb,
+}
+
+impl TransactionFormat {
+    pub fn detect(bytes: &[u8]) -> Self {
+        if bytes.starts_with(PSKB_PREFIX) {
+            TransactionFormat::Pskb
+        } else {
+            TransactionFormat::Legacy
+        }
+    }
+}
+
+/// Schnorr signing keys indexed by the pay-to-pubkey script they can spend.
+pub struct SigningKeys {
+    keys: HashMap<Vec<u8>, Keypair>,
+}
+
+impl SigningKeys {
+    pub fn new<'a>(secret_keys: impl IntoIterator<Item = &'a SecretKey>) -> Self {
+        let keys = secret_keys
+            .into_iter()
+            .map(|secret_key| {
+                let keypair = Keypair::from_secret_key(secp256k1::SECP256K1, secret_key);
+                (p2pk_script(&keypair), keypair)
+            })
+            .collect();
+        Self { keys }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    fn get(&self, script_public_key: &ScriptPublicKey) -> Option<&Keypair> {
+        self.keys.get(script_public_key.script())
+    }
+}
+
+/// The account key recorded by the legacy format for every input, as the Go wallet does: `extendedPubKey` is the
+/// account extended public key derived along the `derivationPath` of the address the input spends.
+pub struct LegacyAccountKeys {
+    extended_public_key: ExtendedPublicKey<secp256k1::PublicKey>,
+    prefix: Prefix,
+    derivation_paths: HashMap<Vec<u8>, DerivationPath>,
+}
+
+impl LegacyAccountKeys {
+    /// `derivation_paths` holds the path of each account address below the account key, such as `m/0/5`, by the
+    /// address script public key.
+    pub fn new(
+        extended_public_key: ExtendedPublicKey<secp256k1::PublicKey>,
+        prefix: Prefix,
+        derivation_paths: impl IntoIterator<Item = (ScriptPublicKey, DerivationPath)>,
+    ) -> Self {
+        let derivation_paths =
+            derivation_paths.into_iter().map(|(script_public_key, path)| (script_public_key.script().to_vec(), path)).collect();
+        Self { extended_public_key, prefix, derivation_paths }
+    }
+
+    fn pub_key_signature_pair(
+        &self,
+        index: usize,
+        script_public_key: &ScriptPublicKey,
+    ) -> Result<(protoserialization::PubKeySignaturePair, String), Status> {
+        let path = self
+            .derivation_paths
+            .get(script_public_key.script())
+            .ok_or_else(|| Status::failed_precondition(format!("input {index} does not spend an address of the selected account")))?;
+        let derived = self.extended_public_key.clone().derive_path(path).map_err(status_internal)?;
+        let pair =
+            protoserialization::PubKeySignaturePair { extended_pub_key: derived.to_string(Some(self.prefix)), signature: vec![] };
+        Ok((pair, path.to_string()))
+    }
+}
+
+/// Tells whether the `extendedPubKey` of a legacy input is `key`. The Go wallet records the account key already
+/// derived along the input `derivationPath`; a key recorded underived is derived along the path first.
+fn is_pair_key(extended_pub_key: &str, derivation_path: &str, key: &XOnlyPublicKey) -> bool {
+    let Ok(extended_public_key) = ExtendedPublicKey::<secp256k1::PublicKey>::from_str(extended_pub_key) else {
+        return false;
+    };
+    extended_public_key.public_key().x_only_public_key().0 == *key
+        || DerivationPath::from_str(derivation_path)
+            .and_then(|path| extended_public_key.derive_path(&path))
+            .is_ok_and(|derived| derived.public_key().x_only_public_key().0 == *key)
+}
+
+fn p2pk_script(keypair: &Keypair) -> Vec<u8> {
+    std::iter::once(0x20).chain(keypair.x_only_public_key().0.serialize()).chain(std::iter::once(0xac)).collect()
+}
+
+/// Serializes an unsigned transaction whose inputs spend `entries`.
+///
+/// The legacy format lists the account key of every input from `legacy_keys`; inputs of accounts without an
+/// extended public key list no key, and signing adds the signing key.
+pub fn serialize_unsigned(
+    format: TransactionFormat,
+    transaction: &Transaction,
+    entries: &[UtxoEntry],
+    legacy_keys: Option<&LegacyAccountKeys>,
+) -> Result<Vec<u8>, Status> {
+    if transaction.inputs.len() != entries.len() {
+        return Err(Status::internal(format!(
+            "transaction has {} inputs but {} utxo entries",
+            transaction.inputs.len(),
+            entries.len()
+        )));
+    }
+
+    match format {
+        TransactionFormat::Legacy => {
+            let partially_signed_inputs = entries
+                .iter()
+                .enumerate()
+                .map(|(index, entry)| {
+                    let (pub_key_signature_pairs, derivation_path) = match legacy_keys {
+                        Some(keys) => {
+                            let (pair, derivation_path) = keys.pub_key_signature_pair(index, &entry.script_public_key)?;
+                            (vec![pair], derivation_path)
+                        }
+                        None => (vec![], String::new()),
+                    };
+                    Ok(protoserialization::PartiallySignedInput {
+                        redeem_script: vec![],
+                        prev_output: Some(output_to_proto(&TransactionOutput::new(entry.amount, entry.script_public_key.clone()))),
+                        minimum_signatures: 1,
+                        pub_key_signature_pairs,
+                        derivation_path,
+                    })
+                })
+                .collect::<Result<Vec<_>, Status>>()?;
+            let partially_signed = protoserialization::PartiallySignedTransaction {
+                tx: Some(transaction_to_proto(transaction)),
+                partially_signed_inputs,
+            };
+            Ok(partially_signed.encode_to_vec())
+        }
+        TransactionFormat::Pskb => {
+            let populated_inputs = transaction.inputs.iter().zip(entries.iter()).collect::<Vec<_>>();
+            let mut inner = Inner::try_from((transaction.clone(), populated_inputs)).map_err(status_internal)?;
+            // keep the version and input sequences so the transaction id (and chained batch transactions) stay unchanged
+            inner.global.tx_version = transaction.version;
+            for (input, tx_input) in inner.inputs.iter_mut().zip(transaction.inputs.iter()) {
+                input.sequence = Some(tx_input.sequence);
+            }
+            let mut bundle = Bundle::new();
+            bundle.add_inner(inner);
+            Ok(bundle.serialize().map_err(status_internal)?.into_bytes())
+        }
+    }
+}
+
+/// Signs every input of a serialized transaction with `keys`, keeping its format.
+pub fn sign_serialized(bytes: &[u8], keys: &SigningKeys) -> Result<Vec<u8>, Status> {
+    match TransactionFormat::detect(bytes) {
+        TransactionFormat::Legacy => sign_legacy(bytes, keys),
+        TransactionFormat::Pskb => sign_pskb(bytes, keys),
+    }
+}
+
+/// Returns the script public keys spent by the inputs of a serialized transaction.
+pub fn input_script_public_keys(bytes: &[u8]) -> Result<Vec<ScriptPublicKey>, Status> {
+    match TransactionFormat::detect(bytes) {
+        TransactionFormat::Legacy => decode_partially_signed(bytes)?
+            .partially_signed_inputs
+            .iter()
+            .map(|input| Ok(output_from_proto(input.prev_output.as_ref())?.script_public_key))
+            .collect(),
+        TransactionFormat::Pskb => Ok(decode_bundle(bytes)?
+            .iter()
+            .flat_map(|inner| inner.inputs.iter())
+            .filter_map(|input| input.utxo_entry.as_ref().map(|entry| entry.script_public_key.clone()))
+            .collect()),
+    }
+}
+
+fn sign_legacy(bytes: &[u8], keys: &SigningKeys) -> Result<Vec<u8>, Status> {
+    let mut partially_signed = decode_partially_signed(bytes)?;
+    let transaction = transaction_from_proto(partially_signed.tx.as_ref())?;
+    if transaction.inputs.len() != partially_signed.partially_signed_inputs.len() {
+        return Err(Status::invalid_argument("partially signed transaction input count mismatch"));
+    }
+    let entries = partially_signed
+        .partially_signed_inputs
+        .iter()
+        .map(|input| {
+            let prev_output = output_from_proto(input.prev_output.as_ref())?;
+            Ok(UtxoEntry::new(prev_output.value, prev_output.script_public_key, 0, false))
+        })
+        .collect::<Result<Vec<_>, Status>>()?;
+    let signable = SignableTransaction::with_entries(transaction, entries);
+
+    let mut reused_values = SigHashReusedValues::new();
+    for (index, input) in partially_signed.partially_signed_inputs.iter_mut().enumerate() {
+        if input.minimum_signatures > 1 || input.pub_key_signature_pairs.len() > 1 || !input.redeem_script.is_empty() {
+            return Err(Status::unimplemented(format!("input {index} is a multisig input, which the rust wallet daemon cannot sign")));
+        }
+        let entry = signable.entries[index].as_ref().expect("entries are populated");
+        let keypair = keys
+            .get(&entry.script_public_key)
+            .ok_or_else(|| Status::failed_precondition(format!("input {index} is not spendable by the selected account")))?;
+        let signature = sign_input(&signable, index, keypair, &mut reused_values)?;
+
+        let mut signature_bytes = signature.serialize().to_vec();
+        signature_bytes.push(SIG_HASH_ALL.to_u8());
+        let derivation_path = &input.derivation_path;
+        match input.pub_key_signature_pairs.first_mut() {
+            Some(pair) if is_pair_key(&pair.extended_pub_key, derivation_path, &keypair.x_only_public_key().0) => {
+                pair.signature = signature_bytes
+            }
+            Some(_) => {
+                return Err(Status::failed_precondition(format!("input {index} is not signed by the key of the selected account")))
+            }
+            // inputs of accounts without an extended public key list no key
+            None => input
+                .pub_key_signature_pairs
+                .push(protoserialization::PubKeySignaturePair { extended_pub_key: String::new(), signature: signature_bytes }),
+        }
+    }
+
+    Ok(partially_signed.encode_to_vec())
+}
+
+fn sign_pskb(bytes: &[u8], keys: &SigningKeys) -> Result<Vec<u8>, Status> {
+    let bundle = decode_bundle(bytes)?;
+    let mut signed_bundle = Bundle::new();
+    for inner in bundle.iter() {
+        let pskt = PSKT::<Signer>::from(inner.clone());
+        let pskt = pskt.pass_signature_sync(|signable, sighash_types| -> Result<Vec<SignInputOk>, Status> {
+            if signable.entries.len() != signable.tx.inputs.len() {
+                return Err(Status::invalid_argument("PSKT inputs must carry their utxo entries"));
+            }
+            let mut reused_values = SigHashReusedValues::new();
+            sighash_types
+                .iter()
+                .enumerate()
+                .map(|(index, sighash_type)| {
+                    if *sighash_type != SIG_HASH_ALL {
+                        return Err(Status::invalid_argument(format!("input {index} uses an unsupported sighash type")));
+                    }
+                    let entry = signable.entries[index].as_ref().expect("entries are populated");
+                    let keypair = keys.get(&entry.script_public_key).ok_or_else(|| {
+                        Status::failed_precondition(format!("input {index} is not spendable by the selected account"))
+                    })?;
+                    let signature = sign_input(&signable, index, keypair, &mut reused_values)?;
+                    Ok(SignInputOk { signature: Signature::Schnorr(signature), pub_key: keypair.public_key(), key_source: None })
+                })
+                .collect()
+        })?;
+        signed_bundle.add_pskt(pskt);
+    }
+
+    Ok(signed_bundle.serialize().map_err(status_internal)?.into_bytes())
+}
+
+fn sign_input(
+    signable: &SignableTransaction,
+    index: usize,
+    keypair: &Keypair,
+    reused_values: &mut SigHashReusedValues,
+) -> Result<secp256k1::schnorr::Signature, Status> {
+    let sig_hash = calc_schnorr_signature_hash(&signable.as_verifiable(), index, SIG_HASH_ALL, reused_values);
+    let message = Message::from_digest_slice(sig_hash.as_bytes().as_slice()).map_err(status_internal)?;
+    Ok(keypair.sign_schnorr(message))
+}
+
+/// Builds the final transactions of a serialized signed transaction.
+///
+/// With `is_domain`, `bytes` is a protobuf encoded `protoserialization.TransactionMessage`
+/// that is already fully signed.
+pub fn extract_transactions(bytes: &[u8], is_domain: bool) -> Result<Vec<Transaction>, Status> {
+    if is_domain {
+        let message = protoserialization::TransactionMessage::decode(bytes)
+            .map_err(|err| Status::invalid_argument(format!("invalid domain transaction: {err}")))?;
+        return Ok(vec![transaction_from_proto(Some(&message))?]);
+    }
+
+    match TransactionFormat::detect(bytes) {
+        TransactionFormat::Legacy => Ok(vec![extract_legacy(bytes)?]),
+        TransactionFormat::Pskb => decode_bundle(bytes)?
+            .iter()
+            .map(|inner| {
+                let pskt = finalize_pskt_one_or_more_sig_and_redeem_script(PSKT::<Finalizer>::from(inner.clone()))
+                    .map_err(|err| Status::invalid_argument(format!("unable to finalize PSKT: {err}")))?;
+                let extractor = pskt.extractor().map_err(|err| Status::invalid_argument(err.to_string()))?;
+                let (transaction, _) = extractor
+                    .extract_tx()
+                    .map_err(|err| Status::invalid_argument(format!("PSKT signature verification failed: {err}")))?(
+                    0
+                );
+                Ok(transaction)
+            })
+            .collect(),
+    }
+}
+
+fn extract_legacy(bytes: &[u8]) -> Result<Transaction, Status> {
+    let partially_signed = decode_partially_signed(bytes)?;
+    let mut transaction = transaction_from_proto(partially_signed.tx.as_ref())?;
+    if transaction.inputs.len() != partially_signed.partially_signed_inputs.len() {
+        return Err(Status::invalid_argument("partially signed transaction input count mismatch"));
+    }
+
+    for (index, input) in partially_signed.partially_signed_inputs.iter().enumerate() {
+        let mut script_builder = ScriptBuilder::new();
+        if input.pub_key_signature_pairs.len() > 1 {
+            if input.redeem_script.is_empty() {
+                return Err(Status::invalid_argument(format!("multisig input {index} is missing its redeem script")));
+            }
+            let signatures = input
+                .pub_key_signature_pairs
+                .iter()
+                .filter(|pair| !pair.signature.is_empty())
+                .take(input.minimum_signatures as usize)
+                .collect::<Vec<_>>();
+            if signatures.len() < input.minimum_signatures as usize {
+                return Err(Status::invalid_argument(format!(
+                    "input {index} is missing {} signatures",
+                    input.minimum_signatures as usize - signatures.len()
+                )));
+            }
+            for pair in signatures {
+                script_builder.add_data(&pair.signature).map_err(status_invalid_script)?;
+            }
+            script_builder.add_data(&input.redeem_script).map_err(status_invalid_script)?;
+        } else {
+            let signature = input.pub_key_signature_pairs.first().map(|pair| pair.signature.as_slice()).unwrap_or_default();
+            if signature.is_empty() {
+                return Err(Status::invalid_argument(format!("input {index} is missing a signature")));
+            }
+            script_builder.add_data(signature).map_err(status_invalid_script)?;
+        }
+        transaction.inputs[index].signature_script = script_builder.drain();
+    }
+    transaction.finalize();
+
+    Ok(transaction)
+}
+
+fn decode_partially_signed(bytes: &[u8]) -> Result<protoserialization::PartiallySignedTransaction, Status> {
+    protoserialization::PartiallySignedTransaction::decode(bytes)
+        .map_err(|err| Status::invalid_argument(format!("invalid partially signed transaction: {err}")))
+}
+
+fn decode_bundle(bytes: &[u8]) -> Result<Bundle, Status> {
+    let serialized = std::str::from_utf8(bytes).map_err(|err| Status::invalid_argument(format!("invalid PSKB encoding: {err}")))?;
+    Bundle::deserialize(serialized).map_err(|err| Status::invalid_argument(format!("invalid PSKB: {err}")))
+}
+
+pub fn transaction_to_proto(transaction: &Transaction) -> protoserialization::TransactionMessage {
+    protoserialization::TransactionMessage {
+        version: u32::from(transaction.version),
+        inputs: transaction
+            .inputs
+            .iter()
+            .map(|input| protoserialization::TransactionInput {
+                previous_outpoint: Some(protoserialization::Outpoint {
+                    transaction_id: Some(protoserialization::TransactionId {
+                        bytes: input.previous_outpoint.transaction_id.as_bytes().to_vec(),
+                    }),
+                    index: input.previous_outpoint.index,
+                }),
+                signature_script: input.signature_script.clone(),
+                sequence: input.sequence,
+                sig_op_count: u32::from(input.sig_op_count),
+            })
+            .collect(),
+        outputs: transaction.outputs.iter().map(output_to_proto).collect(),
+        lock_time: transaction.lock_time,
+        subnetwork_id: Some(protoserialization::SubnetworkId { bytes: AsRef::<[u8]>::as_ref(&transaction.subnetwork_id).to_vec() }),
+        gas: transaction.gas,
+        payload: transaction.payload.clone(),
+    }
+}
+
+fn transaction_from_proto(message: Option<&protoserialization::TransactionMessage>) -> Result<Transaction, Status> {
+    let message = message.ok_or_else(|| Status::invalid_argument("transaction is missing"))?;
+    let version = u16::try_from(message.version).map_err(|_| Status::invalid_argument("transaction version is out of range"))?;
+    let inputs = message
+        .inputs
+        .iter()
+        .map(|input| {
+            let outpoint = input.previous_outpoint.as_ref().ok_or_else(|| Status::invalid_argument("input outpoint is missing"))?;
+            let transaction_id = outpoint
+                .transaction_id
+                .as_ref()
+                .and_then(|transaction_id| TransactionId::try_from_slice(&transaction_id.bytes).ok())
+                .ok_or_else(|| Status::invalid_argument("input outpoint transaction id must be 32 bytes"))?;
+            let sig_op_count =
+                u8::try_from(input.sig_op_count).map_err(|_| Status::invalid_argument("input sig op count is out of range"))?;
+            Ok(TransactionInput::new(
+                TransactionOutpoint::new(transaction_id, outpoint.index),
+                input.signature_script.clone(),
+                input.sequence,
+                sig_op_count,
+            ))
+        })
+        .collect::<Result<Vec<_>, Status>>()?;
+    let outputs = message.outputs.iter().map(|output| output_from_proto(Some(output))).collect::<Result<Vec<_>, Status>>()?;
+    let subnetwork_id = message
+        .subnetwork_id
+        .as_ref()
+        .and_then(|subnetwork_id| SubnetworkId::try_from(subnetwork_id.bytes.as_slice()).ok())
+        .ok_or_else(|| Status::invalid_argument("transaction subnetwork id must be 20 bytes"))?;
+
+    Ok(Transaction::new(version, inputs, outputs, message.lock_time, subnetwork_id, message.gas, message.payload.clone()))
+}
+
+fn output_to_proto(output: &TransactionOutput) -> protoserialization::TransactionOutput {
+    protoserialization::TransactionOutput {
+        value: output.value,
+        script_public_key: Some(protoserialization::ScriptPublicKey {
+            script: output.script_public_key.script().to_vec(),
+            version: u32::from(output.script_public_key.version()),
+        }),
+    }
+}
+
+fn output_from_proto(output: Option<&protoserialization::TransactionOutput>) -> Result<TransactionOutput, Status> {
+    let output = output.ok_or_else(|| Status::invalid_argument("transaction output is missing"))?;
+    let script_public_key =
+        output.script_public_key.as_ref().ok_or_else(|| Status::invalid_argument("output script public key is missing"))?;
+    let version =
+        u16::try_from(script_public_key.version).map_err(|_| Status::invalid_argument("script public key version is out of range"))?;
+    Ok(TransactionOutput::new(output.value, ScriptPublicKey::from_vec(version, script_public_key.script.clone())))
+}
+
+fn status_internal(err: impl std::fmt::Display) -> Status {
+    Status::internal(err.to_string())
+}
+
+fn status_invalid_script(err: impl std::fmt::Display) -> Status {
+    Status::invalid_argument(format!("unable to build signature script: {err}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::daemon::pb;
+    use cryptix_bip32::ExtendedPrivateKey;
+    use cryptix_consensus_core::subnets::{SUBNETWORK_ID_NATIVE, SUBNETWORK_ID_PAYLOAD};
+    use cryptix_consensus_core::tx::{PopulatedTransaction, VerifiableTransaction};
+    use cryptix_txscript::caches::Cache;
+    use cryptix_txscript::TxScriptEngine;
+
+    /// Account key `m/44'/111111'/0'` of the Go wallet fixture.
+    const FIXTURE_ACCOUNT_KPRV: &str =
+        "kprv64tGBhS2F13vTeK8WcWbt9a8WTBP1Ut5vkAYreepL2UzTuFm36rs4j2jBrgZ1TCVVmmNgAmjPxWrDQEGpTGveqF5p3CN4gMpi5Wb2WmtYHg";
+    /// An unsigned transaction laid out as the Go wallet's `createUnsignedTransaction` writes it: one input spending
+    /// the address at `m/0/0` of the fixture account, with `extendedPubKey` set to the account kpub derived along
+    /// that path, and one 9 000 sompi output.
+    const GO_WALLET_UNSIGNED_FIXTURE: &str = concat!(
+        "0a6f122a0a260a220a203131313131313131313131313131313131313131313131313131313131313131100120011a2908a84612240a22",
+        "207777777777777777777777777777777777777777777777777777777777777777ac2a160a140000000000000000000000000000000000",
+        "00000012a701122908904e12240a22202035d4b0d07d05731f91879f77de5bdd8d498cc6a52ba0538ab17a36d8a2fd67ac180122710a6f",
+        "6b7075623250386a61735273436e4b53647066506d484b704e67643976334a6e5839533932355331766d6e5a384e643567385151506a46",
+        "436d54533458385441663352374e4e4b415a476a6b776e346b46487553724c5658374e714c75417045555336555a344e577659386d7333",
+        "592a056d2f302f30",
+    );
+
+    fn secret_key(byte: u8) -> SecretKey {
+        SecretKey::from_slice(&[byte; 32]).unwrap()
+    }
+
+    fn fixture_account_key() -> ExtendedPrivateKey<SecretKey> {
+        FIXTURE_ACCOUNT_KPRV.parse().unwrap()
+    }
+
+    fn fixture_path() -> DerivationPath {
+        "m/0/0".parse().unwrap()
+    }
+
+    fn fixture_secret_key() -> SecretKey {
+        *fixture_account_key().derive_path(&fixture_path()).unwrap().private_key()
+    }
+
+    fn fixture_legacy_keys() -> LegacyAccountKeys {
+        let keypair = Keypair::from_secret_key(secp256k1::SECP256K1, &fixture_secret_key());
+        LegacyAccountKeys::new(
+            fixture_account_key().public_key(),
+            Prefix::KPUB,
+            [(ScriptPublicKey::from_vec(0, p2pk_script(&keypair)), fixture_path())],
+        )
+    }
+
+    fn unsigned_transaction(secret_key: &SecretKey, payload: Vec<u8>) -> (Transaction, Vec<UtxoEntry>) {
+        let keypair = Keypair::from_secret_key(secp256k1::SECP256K1, secret_key);
+        let script_public_key = ScriptPublicKey::from_vec(0, p2pk_script(&keypair));
+        let inputs = (0..2u8)
+            .map(|index| {
+                TransactionInput::new(
+                    TransactionOutpoint::new(TransactionId::from_bytes([index + 1; 32]), u32::from(index)),
+                    vec![],
+                    0,
+                    1,
+                )
+            })
+            .collect();
+        let entries = vec![
+            UtxoEntry::new(5_000, script_public_key.clone(), 10, false),
+            UtxoEntry::new(7_000, script_public_key.clone(), 11, false),
+        ];
+        let outputs = vec![TransactionOutput::new(11_000, script_public_key)];
+        let subnetwork_id = if payload.is_empty() { SUBNETWORK_ID_NATIVE } else { SUBNETWORK_ID_PAYLOAD };
+        (Transaction::new(0, inputs, outputs, 0, subnetwork_id, 0, payload), entries)
+    }
+
+    fn verify_signatures(transaction: &Transaction, entries: Vec<UtxoEntry>) {
+        let populated = PopulatedTransaction::new(transaction, entries);
+        let cache = Cache::new(10);
+        let mut reused_values = SigHashReusedValues::new();
+        for (index, (input, entry)) in populated.populated_inputs().enumerate() {
+            TxScriptEngine::from_transaction_input(&populated, input, index, entry, &mut reused_values, &cache, Default::default())
+                .unwrap()
+                .execute()
+                .unwrap();
+        }
+    }
+
+    /// Runs create -> sign -> broadcast through the encoded `cryptixwalletd` messages.
+    fn round_trip(format: TransactionFormat, payload: Vec<u8>, secret_key: SecretKey, legacy_keys: Option<&LegacyAccountKeys>) {
+        let (transaction, entries) = unsigned_transaction(&secret_key, payload);
+
+        let unsigned = serialize_unsigned(format, &transaction, &entries, legacy_keys).unwrap();
+        assert_eq!(TransactionFormat::detect(&unsigned), format);
+        let response = pb::CreateUnsignedTransactionsResponse { unsigned_transactions: vec![unsigned] };
+        let response = pb::CreateUnsignedTransactionsResponse::decode(response.encode_to_vec().as_slice()).unwrap();
+        let unsigned = &response.unsigned_transactions[0];
+        assert_eq!(input_script_public_keys(unsigned).unwrap().len(), 2);
+        assert!(extract_transactions(unsigned, false).is_err());
+
+        let request = pb::SignRequest { unsigned_transactions: response.unsigned_transactions.clone(), password: "secret".into() };
+        let request = pb::SignRequest::decode(request.encode_to_vec().as_slice()).unwrap();
+        let keys = SigningKeys::new([&secret_key]);
+        let signed = request.unsigned_transactions.iter().map(|unsigned| sign_serialized(unsigned, &keys)).collect::<Result<_, _>>();
+        let response = pb::SignResponse { signed_transactions: signed.unwrap() };
+        let response = pb::SignResponse::decode(response.encode_to_vec().as_slice()).unwrap();
+        assert_eq!(TransactionFormat::detect(&response.signed_transactions[0]), format);
+
+        let request = pb::BroadcastRequest { is_domain: false, transactions: response.signed_transactions };
+        let request = pb::BroadcastRequest::decode(request.encode_to_vec().as_slice()).unwrap();
+        let extracted = extract_transactions(&request.transactions[0], request.is_domain).unwrap();
+        assert_eq!(extracted.len(), 1);
+        assert_eq!(extracted[0].id(), transaction.id());
+        assert!(extracted[0].inputs.iter().all(|input| input.signature_script.len() == 66));
+        verify_signatures(&extracted[0], entries);
+    }
+
+    #[test]
+    fn legacy_round_trip() {
+        round_trip(TransactionFormat::Legacy, vec![], secret_key(1), None);
+        round_trip(TransactionFormat::Legacy, vec![], fixture_secret_key(), Some(&fixture_legacy_keys()));
+    }
+
+    #[test]
+    fn legacy_go_wallet_fixture_signs_and_extracts() {
+        let unsigned = hex::decode(GO_WALLET_UNSIGNED_FIXTURE).unwrap();
+        let partially_signed = decode_partially_signed(&unsigned).unwrap();
+        let transaction = transaction_from_proto(partially_signed.tx.as_ref()).unwrap();
+        let entries = partially_signed
+            .partially_signed_inputs
+            .iter()
+            .map(|input| {
+                let prev_output = output_from_proto(input.prev_output.as_ref()).unwrap();
+                UtxoEntry::new(prev_output.value, prev_output.script_public_key, 0, false)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            serialize_unsigned(TransactionFormat::Legacy, &transaction, &entries, Some(&fixture_legacy_keys())).unwrap(),
+            unsigned
+        );
+
+        let signed = sign_serialized(&unsigned, &SigningKeys::new([&fixture_secret_key()])).unwrap();
+        let pairs = decode_partially_signed(&signed).unwrap().partially_signed_inputs[0].pub_key_signature_pairs.clone();
+        assert_eq!(pairs.len(), 1);
+        assert_eq!(pairs[0].extended_pub_key, partially_signed.partially_signed_inputs[0].pub_key_signature_pairs[0].extended_pub_key);
+        assert_eq!(pairs[0].signature.len(), 65);
+
+        let extracted = extract_transactions(&signed, false).unwrap();
+        assert_eq!(extracted[0].id(), transaction.id());
+        verify_signatures(&extracted[0], entries);
+    }
+
+    #[test]
+    fn legacy_multisig_input_requires_redeem_script() {
+        let mut partially_signed = decode_partially_signed(&hex::decode(GO_WALLET_UNSIGNED_FIXTURE).unwrap()).unwrap();
+        let pair = protoserialization::PubKeySignaturePair { extended_pub_key: String::new(), signature: vec![1; 65] };
+        partially_signed.partially_signed_inputs[0].pub_key_signature_pairs = vec![pair.clone(), pair];
+        let err = extract_transactions(&partially_signed.encode_to_vec(), false).unwrap_err();
+        assert_eq!(err.code(), tonic::Code::InvalidArgument);
+        assert!(err.message().contains("redeem script"));
+    }
+
+    #[test]
+    fn pskb_round_trip() {
+        round_trip(TransactionFormat::Pskb, vec![], secret_key(1), None);
+    }
+
+    #[test]
+    fn pskb_round_trip_keeps_token_payload() {
+        let mut payload = b"CAT".to_vec();
+        payload.extend_from_slice(&[1, 1, 0, 0, 0]);
+        payload.extend_from_slice(&3u64.to_le_bytes());
+        round_trip(TransactionFormat::Pskb, payload, secret_key(1), None);
+    }
+
+    #[test]
+    fn signing_requires_keys_for_every_input() {
+        let (transaction, entries) = unsigned_transaction(&secret_key(1), vec![]);
+        let keys = SigningKeys::new([&secret_key(2)]);
+        for format in [TransactionFormat::Legacy, TransactionFormat::Pskb] {
+            let unsigned = serialize_unsigned(format, &transaction, &entries, None).unwrap();
+            assert_eq!(sign_serialized(&unsigned, &keys).unwrap_err().code(), tonic::Code::FailedPrecondition);
+        }
+    }
+
+    #[test]
+    fn domain_transactions_are_decoded_as_is() {
+        let (transaction, _) = unsigned_transaction(&secret_key(1), vec![]);
+        let serialized = transaction_to_proto(&transaction).encode_to_vec();
+        let extracted = extract_transactions(&serialized, true).unwrap();
+        assert_eq!(extracted.len(), 1);
+        assert_eq!(extracted[0].id(), transaction.id());
+        assert_eq!(extracted[0].outputs, transaction.outputs);
+    }
+}
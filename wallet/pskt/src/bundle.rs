@@ -85,11 +85,7 @@ impl Bundle {
 
                 if let Some(utxo_entry) = &input.utxo_entry {
                     result.push_str(&format!("  amount: {}\r\n", sompi_formatter(utxo_entry.amount, &NetworkType::from(network_id))));
-                    result.push_str(&format!(
-                        "  address: {}\r\n",
-                        extract_script_pub_key_address(&utxo_entry.script_public_key, Prefix::from(network_id))
-                            .expect("Input address")
-                    ));
+                    result.push_str(&format_script_public_key(&utxo_entry.script_public_key, network_id));
                 }
             }
 
@@ -98,10 +94,23 @@ impl Bundle {
             for (key_inner, output) in pskt.clone().outputs.iter().enumerate() {
                 result.push_str(&format!("Output #{:02}\r\n", key_inner + 1));
                 result.push_str(&format!("  amount: {}\r\n", sompi_formatter(output.amount, &NetworkType::from(network_id))));
-                result.push_str(&format!(
-                    "  address: {}\r\n",
-                    extract_script_pub_key_address(&output.script_public_key, Prefix::from(network_id)).expect("Input address")
-                ));
+                result.push_str(&format_script_public_key(&output.script_public_key, network_id));
+            }
+
+            if let Some(payload) = &pskt.global.payload {
+                result.push_str("---\r\n");
+                if let Some(token_op) = &pskt.global.token_op {
+                    result.push_str(&format!("Token operation: {} (op {})\r\n", token_op.name(), token_op.op));
+                    result.push_str(&format!("  nonce: {}\r\n", token_op.nonce));
+                    result.push_str(&format!("  auth input: #{:02}\r\n", token_op.auth_input_index as usize + 1));
+                    if let Some(vault_input_index) = token_op.vault_input_index {
+                        result.push_str(&format!("  vault input: #{:02}\r\n", vault_input_index as usize + 1));
+                    }
+                    if let Err(err) = pskt.verify_token_op() {
+                        result.push_str(&format!("  invalid: {err}\r\n"));
+                    }
+                }
+                result.push_str(&format!("Payload: {}\r\n", hex::encode(payload)));
             }
         }
         result
@@ -143,6 +152,14 @@ impl Default for Bundle {
     }
 }
 
+fn format_script_public_key(script_public_key: &ScriptPublicKey, network_id: NetworkId) -> String {
+    match extract_script_pub_key_address(script_public_key, Prefix::from(network_id)) {
+        Ok(address) => format!("  address: {}\r\n", address),
+        // Non-standard scripts, such as a liquidity pool vault, have no address.
+        Err(_) => format!("  script: {}\r\n", hex::encode(script_public_key.script())),
+    }
+}
+
 pub fn lock_script_sig_templating(payload: String, pubkey_bytes: Option<&[u8]>) -> Result<Vec<u8>, Error> {
     let mut payload_bytes: Vec<u8> = hex::decode(payload)?;
 
@@ -237,10 +254,13 @@ mod tests {
     use crate::prelude::*;
     use crate::role::Creator;
     use crate::role::*;
+    use cryptix_consensus_core::hashing::sighash::{calc_schnorr_signature_hash, SigHashReusedValues};
+    use cryptix_consensus_core::subnets::SUBNETWORK_ID_PAYLOAD;
     use cryptix_consensus_core::tx::{TransactionId, TransactionOutpoint, UtxoEntry};
-    use cryptix_txscript::{multisig_redeem_script, pay_to_script_hash_script};
+    use cryptix_txscript::{multisig_redeem_script, opcodes::codes::OpData65, pay_to_script_hash_script};
     use secp256k1::Secp256k1;
     use secp256k1::{rand::thread_rng, Keypair};
+    use std::iter;
     use std::str::FromStr;
     use std::sync::Once;
 
@@ -335,6 +355,143 @@ mod tests {
         assert_eq!(bundle.0.len(), 1);
     }
 
+    fn mock_token_payload(op: u8, auth_input_index: u16, nonce: u64) -> Vec<u8> {
+        let mut payload = b"CAT".to_vec();
+        payload.extend_from_slice(&[1, op, 0]);
+        payload.extend_from_slice(&auth_input_index.to_le_bytes());
+        payload.extend_from_slice(&nonce.to_le_bytes());
+        payload.extend_from_slice(&[0x11; 32]);
+        payload
+    }
+
+    #[test]
+    fn test_pskb_token_payload_round_trip() {
+        let payload = mock_token_payload(1, 0, 7);
+        let constructor = PSKT::<Creator>::default().payload(payload.clone()).unwrap().constructor();
+        let pskt = constructor.input(mock_pskt_constructor().inputs[0].clone()).signer();
+
+        let token_op = pskt.global.token_op.expect("token op decoded from payload");
+        assert_eq!((token_op.op, token_op.auth_input_index, token_op.nonce), (1, 0, 7));
+        assert_eq!(token_op.name(), "transfer");
+        pskt.verify_token_op().unwrap();
+
+        let bundle = Bundle::deserialize(&Bundle::from(pskt).serialize().unwrap()).unwrap();
+        let inner = bundle.0.first().unwrap();
+        assert_eq!(inner.global.payload.as_ref(), Some(&payload));
+        assert_eq!(inner.global.token_op, Some(token_op));
+
+        let display = bundle.display_format(NetworkId::new(NetworkType::Testnet), |sompi, _| sompi.to_string());
+        assert!(display.contains("Token operation: transfer (op 1)"));
+        assert!(display.contains(&hex::encode(&payload)));
+    }
+
+    #[test]
+    fn test_pskt_token_op_verification() {
+        let input = mock_pskt_constructor().inputs[0].clone();
+
+        let out_of_bounds =
+            PSKT::<Creator>::default().payload(mock_token_payload(2, 1, 1)).unwrap().constructor().input(input.clone());
+        assert_eq!(out_of_bounds.verify_token_op(), Err(TokenOpError::AuthInputOutOfBounds { index: 1, inputs: 1 }));
+
+        let mut tampered =
+            PSKT::<Creator>::default().payload(mock_token_payload(2, 0, 1)).unwrap().constructor().input(input).signer();
+        let mut inner = tampered.deref().clone();
+        inner.global.token_op.as_mut().unwrap().nonce = 2;
+        tampered = PSKT::from(inner);
+        assert_eq!(tampered.verify_token_op(), Err(TokenOpError::HeaderMismatch));
+
+        assert!(PSKT::<Creator>::default().payload(b"CAT".to_vec()).is_err());
+        assert_eq!(PSKT::<Creator>::default().payload(b"memo".to_vec()).unwrap().global.token_op, None);
+    }
+
+    #[test]
+    fn test_pskt_combine_rejects_payload_mismatch() {
+        let lhs = PSKT::<Creator>::default().payload(mock_token_payload(1, 0, 1)).unwrap().constructor().combiner();
+        let rhs = PSKT::<Creator>::default().payload(mock_token_payload(1, 0, 2)).unwrap().constructor();
+        assert!(matches!(lhs + rhs, Err(CombineError::Global(crate::global::CombineError::PayloadMismatch))));
+    }
+
+    #[test]
+    fn test_pskt_token_vault_input_finalizes_without_signature() {
+        let (kps, _) = mock_context();
+        let sender = Address::new(Prefix::Testnet, cryptix_addresses::Version::PubKey, &kps[0].x_only_public_key().0.serialize());
+        let vault_script = ScriptPublicKey::from_vec(0, vec![0x04, b'C', b'L', b'V', b'1', 0x75, 0x51]);
+        let outpoint = |index| TransactionOutpoint {
+            transaction_id: TransactionId::from_str("63020db736215f8b1105a9281f7bcbb6473d965ecc45bb2fb5da59bd35e6ff84").unwrap(),
+            index,
+        };
+        let vault = InputBuilder::default()
+            .utxo_entry(UtxoEntry { amount: 5_000, script_public_key: vault_script.clone(), block_daa_score: 1, is_coinbase: false })
+            .previous_outpoint(outpoint(0))
+            .sig_op_count(1)
+            .build()
+            .unwrap();
+        let funding = InputBuilder::default()
+            .utxo_entry(UtxoEntry {
+                amount: 100_000,
+                script_public_key: pay_to_address_script(&sender),
+                block_daa_score: 1,
+                is_coinbase: false,
+            })
+            .previous_outpoint(outpoint(1))
+            .sig_op_count(1)
+            .build()
+            .unwrap();
+        let payload = mock_token_payload(6, 1, 3);
+
+        let updater = PSKT::<Creator>::default()
+            .payload(payload.clone())
+            .unwrap()
+            .constructor()
+            .input(vault)
+            .input(funding)
+            .output(OutputBuilder::default().amount(6_000).script_public_key(vault_script).build().unwrap())
+            .output(OutputBuilder::default().amount(98_000).script_public_key(pay_to_address_script(&sender)).build().unwrap())
+            .updater();
+        assert!(PSKT::<Creator>::default().constructor().updater().token_vault_input(0).is_err());
+        let signer = updater.token_vault_input(0).unwrap().signer();
+        assert!(signer.is_token_vault_input(0) && !signer.is_token_vault_input(1));
+        assert_eq!(signer.inputs[0].sig_op_count, Some(0));
+
+        let mut reused_values = SigHashReusedValues::new();
+        let signed = signer
+            .pass_signature_sync(|tx, sighash| -> Result<Vec<SignInputOk>, String> {
+                let hash = calc_schnorr_signature_hash(&tx.as_verifiable(), 1, sighash[1], &mut reused_values);
+                let msg = secp256k1::Message::from_digest_slice(hash.as_bytes().as_slice()).unwrap();
+                Ok(vec![SignInputOk {
+                    signature: Signature::Schnorr(kps[0].sign_schnorr(msg)),
+                    pub_key: kps[0].public_key(),
+                    key_source: None,
+                }])
+            })
+            .unwrap();
+        assert!(signed.inputs[0].partial_sigs.is_empty());
+        assert_eq!(signed.inputs[1].partial_sigs.len(), 1);
+
+        let finalized = signed
+            .finalizer()
+            .finalize_sync(|inner: &PSKTInner| -> Result<Vec<Vec<u8>>, String> {
+                Ok(inner
+                    .inputs
+                    .iter()
+                    .map(|input| {
+                        input
+                            .partial_sigs
+                            .values()
+                            .flat_map(|signature| {
+                                iter::once(OpData65).chain(signature.into_bytes()).chain([input.sighash_type.to_u8()])
+                            })
+                            .collect()
+                    })
+                    .collect())
+            })
+            .unwrap();
+        let (tx, _) = finalized.extractor().unwrap().extract_tx().unwrap()(0);
+        assert_eq!(tx.subnetwork_id, SUBNETWORK_ID_PAYLOAD);
+        assert_eq!(tx.payload, payload);
+        assert!(tx.inputs[0].signature_script.is_empty());
+    }
+
     #[test]
     fn test_pskb_merge_bundles() {
         let mut bundle1 = Bundle::new();
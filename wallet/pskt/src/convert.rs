@@ -2,6 +2,7 @@ use crate::error::Error;
 use crate::input::{Input, InputBuilder};
 use crate::output::{Output, OutputBuilder};
 use crate::pskt::{Global, Inner};
+use crate::token::TokenOp;
 use cryptix_consensus_client::{Transaction, TransactionInput, TransactionInputInner, TransactionOutput, TransactionOutputInner};
 use cryptix_consensus_core::tx as cctx;
 
@@ -81,7 +82,7 @@ impl TryFrom<(cctx::Transaction, Vec<(&cctx::TransactionInput, &cctx::UtxoEntry)
             })
             .collect::<Result<_, _>>();
 
-        Ok(Inner { global: Global::default(), inputs: inputs?, outputs: outputs? })
+        Ok(Inner { global: global_from_payload(&transaction.payload)?, inputs: inputs?, outputs: outputs? })
     }
 }
 
@@ -104,6 +105,13 @@ impl TryFrom<cctx::Transaction> for Inner {
             })
             .collect::<Result<_, _>>()?;
 
-        Ok(Inner { global: Global::default(), inputs, outputs })
+        Ok(Inner { global: global_from_payload(&transaction.payload)?, inputs, outputs })
     }
 }
+
+fn global_from_payload(payload: &[u8]) -> Result<Global, Error> {
+    if payload.is_empty() {
+        return Ok(Global::default());
+    }
+    Ok(Global { payload: Some(payload.to_vec()), token_op: TokenOp::from_payload(payload)?, ..Default::default() })
+}
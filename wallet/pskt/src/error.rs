@@ -40,6 +40,10 @@ pub enum Error {
     PskbPrefixError,
     #[error("PSKT serialization requires 'PSKT' prefix")]
     PsktPrefixError,
+    #[error(transparent)]
+    TokenOp(#[from] crate::token::TokenOpError),
+    #[error("PSKT has no token operation")]
+    MissingTokenOp,
 }
 #[derive(thiserror::Error, Debug)]
 pub enum ConstructorError {
@@ -1,4 +1,5 @@
 use crate::pskt::{KeySource, Version};
+use crate::token::TokenOp;
 use crate::utils::combine_if_no_conflicts;
 use cryptix_consensus_core::tx::TransactionId;
 use derive_builder::Builder;
@@ -32,6 +33,14 @@ pub struct Global {
     /// A map from xpub to the used key fingerprint and derivation path as defined by BIP 32.
     pub xpubs: BTreeMap<Xpub, KeySource>,
     pub id: Option<TransactionId>,
+    #[serde(default, with = "cryptix_utils::serde_bytes_optional")]
+    #[builder(setter(strip_option))]
+    /// The payload of the transaction being built.
+    pub payload: Option<Vec<u8>>,
+    #[serde(default)]
+    #[builder(setter(strip_option))]
+    /// The atomic token operation decoded from the payload.
+    pub token_op: Option<TokenOp>,
     /// Proprietary key-value pairs for this output.
     pub proprietaries: BTreeMap<String, serde_value::Value>,
     /// Unknown key-value pairs for this output.
@@ -98,6 +107,16 @@ impl Add for Global {
             (Some(v), _) | (_, Some(v)) => Some(v),
             _ => None,
         };
+        self.payload = match (self.payload, rhs.payload) {
+            (Some(lhs), Some(rhs)) if lhs != rhs => return Err(CombineError::PayloadMismatch),
+            (Some(v), _) | (_, Some(v)) => Some(v),
+            _ => None,
+        };
+        self.token_op = match (self.token_op, rhs.token_op) {
+            (Some(lhs), Some(rhs)) if lhs != rhs => return Err(CombineError::TokenOpMismatch { this: lhs, that: rhs }),
+            (Some(v), _) | (_, Some(v)) => Some(v),
+            _ => None,
+        };
 
         self.proprietaries =
             combine_if_no_conflicts(self.proprietaries, rhs.proprietaries).map_err(CombineError::NotCompatibleProprietary)?;
@@ -118,6 +137,8 @@ impl Default for Global {
             output_count: 0,
             xpubs: Default::default(),
             id: None,
+            payload: None,
+            token_op: None,
             proprietaries: Default::default(),
             unknowns: Default::default(),
         }
@@ -156,6 +177,15 @@ pub enum CombineError {
         /// Into a PSKT with `that` tx id.
         that: TransactionId,
     },
+    #[error("The transaction payloads are not the same")]
+    PayloadMismatch,
+    #[error("The token operations are not the same")]
+    TokenOpMismatch {
+        /// Attempted to combine a PSKT with `this` token operation.
+        this: TokenOp,
+        /// Into a PSKT with `that` token operation.
+        that: TokenOp,
+    },
 
     #[error("combining PSKT, key-source conflict for xpub {0}")]
     /// Xpubs have inconsistent key sources.
@@ -12,6 +12,7 @@ pub mod input;
 pub mod output;
 pub mod pskt;
 pub mod role;
+pub mod token;
 pub mod wasm;
 
 mod convert;
@@ -24,6 +25,7 @@ pub mod prelude {
     pub use crate::input::Input;
     pub use crate::output::Output;
     pub use crate::pskt::*;
+    pub use crate::token::{TokenOp, TokenOpError};
 
     // not quite sure why it warns of unused imports,
     // perhaps due to the fact that enums have no variants?
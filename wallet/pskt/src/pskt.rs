@@ -8,13 +8,14 @@ pub use crate::global::{Global, GlobalBuilder};
 pub use crate::input::{Input, InputBuilder};
 pub use crate::output::{Output, OutputBuilder};
 pub use crate::role::{Combiner, Constructor, Creator, Extractor, Finalizer, Signer, Updater};
+use crate::token::{TokenOp, TokenOpError};
 use cryptix_consensus_core::tx::UtxoEntry;
 use cryptix_consensus_core::{
     hashing::{sighash::SigHashReusedValues, sighash_type::SigHashType},
-    subnets::SUBNETWORK_ID_NATIVE,
+    subnets::{SUBNETWORK_ID_NATIVE, SUBNETWORK_ID_PAYLOAD},
     tx::{MutableTransaction, SignableTransaction, Transaction, TransactionId, TransactionInput, TransactionOutput},
 };
-use cryptix_txscript::{caches::Cache, TxScriptEngine};
+use cryptix_txscript::{caches::Cache, EngineFlags, TxScriptEngine};
 
 #[derive(Debug, Default, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -27,6 +28,14 @@ pub struct Inner {
     pub outputs: Vec<Output>,
 }
 
+impl Inner {
+    /// Returns `true` if the input at `index` is the liquidity pool vault input
+    /// of a token operation, which is spent without a signature.
+    pub fn is_token_vault_input(&self, index: usize) -> bool {
+        self.global.token_op.is_some_and(|token_op| token_op.is_vault_input(index))
+    }
+}
+
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize_repr, Deserialize_repr)]
 #[repr(u8)]
 pub enum Version {
@@ -126,9 +135,9 @@ impl<R> PSKT<R> {
                 })
                 .collect(),
             self.determine_lock_time(),
-            SUBNETWORK_ID_NATIVE,
+            if self.global.payload.is_some() { SUBNETWORK_ID_PAYLOAD } else { SUBNETWORK_ID_NATIVE },
             0,
-            vec![],
+            self.global.payload.clone().unwrap_or_default(),
         );
         let entries = self.inputs.iter().filter_map(|Input { utxo_entry, .. }| utxo_entry.clone()).collect();
         SignableTransaction::with_entries(tx, entries)
@@ -138,6 +147,12 @@ impl<R> PSKT<R> {
         self.unsigned_tx().tx.id()
     }
 
+    /// Checks that the token metadata matches the payload and points at
+    /// inputs of the transaction.
+    pub fn verify_token_op(&self) -> Result<(), TokenOpError> {
+        crate::token::verify(self)
+    }
+
     fn determine_lock_time(&self) -> u64 {
         self.inputs.iter().map(|input: &Input| input.min_time).max().unwrap_or(self.global.fallback_lock_time).unwrap_or(0)
     }
@@ -168,6 +183,14 @@ impl PSKT<Creator> {
         self
     }
 
+    /// Sets the transaction payload, decoding its token metadata if the
+    /// payload is an atomic token operation.
+    pub fn payload(mut self, payload: Vec<u8>) -> Result<Self, Error> {
+        self.inner_pskt.global.token_op = TokenOp::from_payload(&payload)?;
+        self.inner_pskt.global.payload = Some(payload);
+        Ok(self)
+    }
+
     // todo generic const
     /// Sets the inputs modifiable bit in the transaction modifiable flags.
     pub fn inputs_modifiable(mut self) -> Self {
@@ -235,6 +258,16 @@ impl PSKT<Updater> {
         Ok(self)
     }
 
+    /// Marks the input at `input_index` as the liquidity pool vault input of the
+    /// token operation. The vault is spent with no signature and no sig ops.
+    pub fn token_vault_input(mut self, input_index: usize) -> Result<Self, Error> {
+        let token_op = self.inner_pskt.global.token_op.as_mut().ok_or(Error::MissingTokenOp)?;
+        let input = self.inner_pskt.inputs.get_mut(input_index).ok_or(Error::OutOfBounds)?;
+        token_op.vault_input_index = Some(u16::try_from(input_index).map_err(|_| Error::OutOfBounds)?);
+        input.sig_op_count = Some(0);
+        Ok(self)
+    }
+
     pub fn signer(self) -> PSKT<Signer> {
         PSKT { inner_pskt: self.inner_pskt, role: Default::default() }
     }
@@ -253,12 +286,8 @@ impl PSKT<Signer> {
     {
         let unsigned_tx = self.unsigned_tx();
         let sighashes = self.inputs.iter().map(|input| input.sighash_type).collect();
-        self.inner_pskt.inputs.iter_mut().zip(sign_fn(unsigned_tx, sighashes)?).for_each(
-            |(input, SignInputOk { signature, pub_key, key_source })| {
-                input.bip32_derivations.insert(pub_key, key_source);
-                input.partial_sigs.insert(pub_key, signature);
-            },
-        );
+        let signatures = sign_fn(unsigned_tx, sighashes)?;
+        self.insert_signatures(signatures);
 
         Ok(self)
     }
@@ -271,13 +300,25 @@ impl PSKT<Signer> {
     {
         let unsigned_tx = self.unsigned_tx();
         let sighashes = self.inputs.iter().map(|input| input.sighash_type).collect();
-        self.inner_pskt.inputs.iter_mut().zip(sign_fn(unsigned_tx, sighashes).await?).for_each(
-            |(input, SignInputOk { signature, pub_key, key_source })| {
+        let signatures = sign_fn(unsigned_tx, sighashes).await?;
+        self.insert_signatures(signatures);
+        Ok(self)
+    }
+
+    /// Stores the signatures in input order. A token vault input is skipped,
+    /// so signing functions return no signature for it.
+    fn insert_signatures(&mut self, signatures: Vec<SignInputOk>) {
+        let token_op = self.inner_pskt.global.token_op;
+        self.inner_pskt
+            .inputs
+            .iter_mut()
+            .enumerate()
+            .filter(|(idx, _)| !token_op.is_some_and(|token_op| token_op.is_vault_input(*idx)))
+            .zip(signatures)
+            .for_each(|((_, input), SignInputOk { signature, pub_key, key_source })| {
                 input.bip32_derivations.insert(pub_key, key_source);
                 input.partial_sigs.insert(pub_key, signature);
-            },
-        );
-        Ok(self)
+            });
     }
 
     pub fn calculate_id(&self) -> TransactionId {
@@ -375,8 +416,10 @@ impl PSKT<Finalizer> {
         if sigs.len() != self.inputs.len() {
             return Err(FinalizeError::WrongFinalizedSigsCount { expected: self.inputs.len(), actual: sigs.len() });
         }
+        self.verify_token_op().map_err(FinalizeError::TokenOp)?;
+        let token_op = self.inner_pskt.global.token_op;
         self.inner_pskt.inputs.iter_mut().enumerate().zip(sigs).try_for_each(|((idx, input), sig)| {
-            if sig.is_empty() {
+            if sig.is_empty() && !token_op.is_some_and(|token_op| token_op.is_vault_input(idx)) {
                 return Err(FinalizeError::EmptySignature(idx));
             }
             input.sequence = Some(input.sequence.unwrap_or(u64::MAX)); // todo discussable
@@ -413,8 +456,10 @@ impl PSKT<Extractor> {
             let cache = Cache::new(10_000);
             let mut reused_values = SigHashReusedValues::new();
 
+            // Covenant scripts are accepted here, consensus decides whether they are activated.
+            let flags = EngineFlags { covenants_enabled: true };
             tx.populated_inputs().enumerate().try_for_each(|(idx, (input, entry))| {
-                TxScriptEngine::from_transaction_input(&tx, input, idx, entry, &mut reused_values, &cache)?.execute()?;
+                TxScriptEngine::from_transaction_input(&tx, input, idx, entry, &mut reused_values, &cache, flags)?.execute()?;
                 <Result<(), ExtractError>>::Ok(())
             })?;
         }
@@ -446,6 +491,8 @@ pub enum FinalizeError<E> {
     #[error("Signatures at index: {0} is empty")]
     EmptySignature(usize),
     #[error(transparent)]
+    TokenOp(TokenOpError),
+    #[error(transparent)]
     FinalaziCb(#[from] E),
 }
 
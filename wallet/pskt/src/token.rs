@@ -0,0 +1,147 @@
+//!
+//! Atomic token (CAT) metadata of PSKTs carrying a token operation payload.
+//!
+//! The CAT header at the start of the payload names the operation, the sender
+//! nonce and the input whose owner authorizes the operation. [`TokenOp`] keeps
+//! a decoded copy of the header next to the payload, so co-signers can review
+//! and verify the operation without parsing the payload themselves.
+//!
+
+use crate::pskt::Inner;
+use serde::{Deserialize, Serialize};
+
+pub use cryptix_consensus_core::atomic::{CAT_MAGIC, CAT_VERSION};
+/// Magic, version, op, flags, auth input index and nonce.
+pub const CAT_HEADER_LEN: usize = 16;
+
+const CAT_OP_NAMES: [&str; 23] = [
+    "create-asset",
+    "transfer",
+    "mint",
+    "burn",
+    "create-asset-with-mint",
+    "create-liquidity-asset",
+    "buy-liquidity-exact-in",
+    "sell-liquidity-exact-in",
+    "claim-liquidity-fees",
+    "approve",
+    "transfer-from",
+    "batch-transfer",
+    "lock-tokens",
+    "transfer-mint-authority",
+    "renounce-mint-authority",
+    "update-metadata",
+    "create-pair-pool",
+    "add-pair-liquidity",
+    "remove-pair-liquidity",
+    "swap-pair-exact-in",
+    "swap-pair-exact-out",
+    "buy-liquidity-exact-out",
+    "sell-liquidity-exact-out",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenOp {
+    /// The operation code of the CAT header.
+    pub op: u8,
+    /// The sender nonce of the CAT header.
+    pub nonce: u64,
+    /// The index of the input whose owner authorizes the operation.
+    pub auth_input_index: u16,
+    /// The index of the liquidity pool vault input, which is spent with an
+    /// empty signature script.
+    pub vault_input_index: Option<u16>,
+}
+
+impl TokenOp {
+    /// Decodes the CAT header of `payload`, returning `None` for payloads
+    /// that are not token operations.
+    pub fn from_payload(payload: &[u8]) -> Result<Option<Self>, TokenOpError> {
+        if !payload.starts_with(&CAT_MAGIC) {
+            return Ok(None);
+        }
+        if payload.len() < CAT_HEADER_LEN {
+            return Err(TokenOpError::TruncatedHeader);
+        }
+        if payload[3] != CAT_VERSION {
+            return Err(TokenOpError::UnsupportedVersion(payload[3]));
+        }
+        Ok(Some(TokenOp {
+            op: payload[4],
+            auth_input_index: u16::from_le_bytes([payload[6], payload[7]]),
+            nonce: u64::from_le_bytes(payload[8..16].try_into().expect("8 byte nonce")),
+            vault_input_index: None,
+        }))
+    }
+
+    /// Returns the name of the operation, `unknown` for op codes this crate does not know.
+    pub fn name(&self) -> &'static str {
+        CAT_OP_NAMES.get(self.op as usize).copied().unwrap_or("unknown")
+    }
+
+    /// Returns `true` if the input at `index` is the liquidity pool vault input.
+    pub fn is_vault_input(&self, index: usize) -> bool {
+        self.vault_input_index.is_some_and(|vault| vault as usize == index)
+    }
+
+    fn same_header(&self, other: &TokenOp) -> bool {
+        self.op == other.op && self.nonce == other.nonce && self.auth_input_index == other.auth_input_index
+    }
+}
+
+/// Checks that the token metadata of `inner` matches its payload and
+/// points at inputs of the transaction.
+pub(crate) fn verify(inner: &Inner) -> Result<(), TokenOpError> {
+    let decoded = match inner.global.payload.as_deref() {
+        Some(payload) => TokenOp::from_payload(payload)?,
+        None => None,
+    };
+    let token_op = match (inner.global.token_op, decoded) {
+        (None, None) => return Ok(()),
+        (Some(_), None) => return Err(TokenOpError::MissingPayload),
+        (None, Some(_)) => return Err(TokenOpError::MissingMetadata),
+        (Some(token_op), Some(decoded)) if !token_op.same_header(&decoded) => return Err(TokenOpError::HeaderMismatch),
+        (Some(token_op), Some(_)) => token_op,
+    };
+
+    let inputs = inner.inputs.len();
+    let auth_input = inner
+        .inputs
+        .get(token_op.auth_input_index as usize)
+        .ok_or(TokenOpError::AuthInputOutOfBounds { index: token_op.auth_input_index, inputs })?;
+    if auth_input.utxo_entry.is_none() {
+        return Err(TokenOpError::MissingAuthUtxoEntry(token_op.auth_input_index));
+    }
+    if let Some(index) = token_op.vault_input_index {
+        if index as usize >= inputs {
+            return Err(TokenOpError::VaultInputOutOfBounds { index, inputs });
+        }
+        if index == token_op.auth_input_index {
+            return Err(TokenOpError::AuthInputIsVault(index));
+        }
+    }
+    Ok(())
+}
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum TokenOpError {
+    #[error("Token payload header is truncated")]
+    TruncatedHeader,
+    #[error("Unsupported token payload version {0}")]
+    UnsupportedVersion(u8),
+    #[error("Token metadata is set but the PSKT has no token payload")]
+    MissingPayload,
+    #[error("Token payload is set but the PSKT has no token metadata")]
+    MissingMetadata,
+    #[error("Token metadata does not match the payload header")]
+    HeaderMismatch,
+    #[error("Auth input index {index} is out of bounds, the transaction has {inputs} inputs")]
+    AuthInputOutOfBounds { index: u16, inputs: usize },
+    #[error("Auth input {0} is missing its UTXO entry")]
+    MissingAuthUtxoEntry(u16),
+    #[error("Vault input index {index} is out of bounds, the transaction has {inputs} inputs")]
+    VaultInputOutOfBounds { index: u16, inputs: usize },
+    #[error("Input {0} can not be both the auth input and the vault input")]
+    AuthInputIsVault(u16),
+}
@@ -239,6 +239,16 @@ impl PSKT {
         self.replace(state)
     }
 
+    #[wasm_bindgen(js_name = transactionPayload)]
+    pub fn transaction_payload(&self, payload: Vec<u8>) -> Result<PSKT> {
+        let state = match self.take() {
+            State::Creator(pskt) => State::Creator(pskt.payload(payload)?),
+            state => Err(Error::state(state))?,
+        };
+
+        self.replace(state)
+    }
+
     #[wasm_bindgen(js_name = inputsModifiable)]
     pub fn inputs_modifiable(&self) -> Result<PSKT> {
         let state = match self.take() {
@@ -309,6 +319,16 @@ impl PSKT {
         self.replace(state)
     }
 
+    #[wasm_bindgen(js_name = tokenVaultInput)]
+    pub fn token_vault_input(&self, input_index: usize) -> Result<PSKT> {
+        let state = match self.take() {
+            State::Updater(pskt) => State::Updater(pskt.token_vault_input(input_index)?),
+            state => Err(Error::state(state))?,
+        };
+
+        self.replace(state)
+    }
+
     #[wasm_bindgen(js_name = calculateId)]
     pub fn calculate_id(&self) -> Result<TransactionId> {
         let state = self.state();
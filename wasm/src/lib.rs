@@ -159,6 +159,7 @@ cfg_if::cfg_if! {
             }
             pub use cryptix_rpc_core::api::rpc::RpcApi;
             pub use cryptix_rpc_core::wasm::message::*;
+            pub use cryptix_atomic_proof::wasm::*;
 
             pub use cryptix_wrpc_wasm::client::*;
             pub use cryptix_wrpc_wasm::resolver::*;
@@ -186,6 +187,7 @@ cfg_if::cfg_if! {
             }
             pub use cryptix_rpc_core::api::rpc::RpcApi;
             pub use cryptix_rpc_core::wasm::message::*;
+            pub use cryptix_atomic_proof::wasm::*;
 
             pub use cryptix_wrpc_wasm::client::*;
             pub use cryptix_wrpc_wasm::resolver::*;
@@ -201,6 +203,7 @@ cfg_if::cfg_if! {
         pub use cryptix_rpc_core::api::rpc::RpcApi;
         pub use cryptix_rpc_core::wasm::message::*;
         pub use cryptix_rpc_core::wasm::message::IPingRequest;
+        pub use cryptix_atomic_proof::wasm::*;
         pub use cryptix_wrpc_wasm::client::*;
         pub use cryptix_wrpc_wasm::resolver::*;
         pub use cryptix_wrpc_wasm::notify::*;